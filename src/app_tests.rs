@@ -0,0 +1,3555 @@
+//! Headless App Tests
+//!
+//! Builds the real App on `MinimalPlugins` (no window, GPU or disk access),
+//! walks the menu flow with synthetic key presses and checks what exists in
+//! each state - catching broken transitions and missing despawns.
+
+use std::time::Duration;
+
+use bevy::input::keyboard::{Key, KeyboardInput, NativeKey};
+use bevy::input::mouse::MouseMotion;
+use bevy::input::ButtonState;
+use bevy::prelude::*;
+use bevy::render::render_resource::Shader;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+
+use crate::assets::{
+    FactionEmblemCache, PowerupIconCache, ProjectileSpriteCache, ShipModelCache, ShipSpriteCache,
+};
+use crate::core::{
+    act_entry_id, faction_entry_id, refugee_souls, scene_lighting, ship_entry_id, Act, ActBoon,
+    AspectMode, AttackResult, BattleEntryKind, BattleLog, BerserkSystem, CampaignCompleteEvent,
+    CampaignState, CollectibleType, DailyChallenge, DailyModifier, Difficulty, DisplayMode,
+    DisplaySettings, Encounter, EndlessMode, EnemyDestroyedEvent, ExplosionEvent, ExplosionSize,
+    Faction, GameProgress, GameSession, GameSet, GameState, InputLatency, InterpolatedTransform,
+    LightingProfile, MinmatarShip, NewGamePlus, NgPlusModifiers, NgPlusRecord, ObjectiveDef,
+    ObjectiveKind, ObjectiveTier, ObjectiveTracker, PlayerFireEvent, Playfield, RouteState,
+    RunCode, RunCodes, RunMode, RunModifier, RunRng, SaveData, ScoreCategory, ScoreEvent,
+    ScoreSystem, SelectedShip, WaveCompleteEvent, WaveTally, WeaponType, WindowPlacement,
+    BOON_DAMAGE_MULT, ELDER_FLEET, RUN_CODE_LENGTH, SALVAGED_PLATING_ARMOR, SCREEN_HEIGHT,
+    SCREEN_WIDTH, SHIP_SKINS, SORTIE_FATIGUE, TIME_ATTACK_KILLS,
+};
+use crate::entities::{
+    get_boss_for_stage, get_phase_threshold, spawn_boss, spawn_collectible, spawn_enemy,
+    spawn_enemy_projectile, spawn_enemy_projectile_typed, spawn_escape_pods, ArenaHazard, Boss,
+    BossAttack, BossData, BossState, CaptureStructure, CargoCrate, Collectible, CollectibleData,
+    Enemy, EnemyAI, EnemyBehavior, EnemyStats, EnemyTactics, EnemyTank, EnemyTankFill, EnemyWeapon,
+    EscapePod, Hardpoints, Player, PlayerProjectileBundle, PowerupEffects, ProjectileDamage,
+    ProjectileOwner, ProjectilePhysics, SeekingProjectile, ShipStats, Team, TurretMount, Weapon,
+    Wingman, WingmanPilot, WingmanRoster, WingmanStats, WingmanTier, WingmanTracker,
+};
+use crate::games::{ActiveModule, ModuleRegistry};
+use crate::systems::{
+    act_clouds, begin_entry, commander_bonus, AllyFleet, AllyFleetShip, AuraBuff, AuraKind,
+    BerserkGlow, BerserkTrailRestore, BossPhaseChangeEvent, BossPhaseTransition, BossProjectile,
+    BossWarning, BulletPalette, CacheChoice, CacheOffer, CapitalShipEvent, Commander,
+    ConnectedPads, DeathSequence, DialogueEvent, DialogueSystem, EjectedCorpse, EntryRun,
+    FocusSettings, Ghost, GhostLibrary, GhostRun, GraphicsQuality, HangarBay, HazardShape,
+    HazardZone, HitLayer, HostileCarrier, KeyLight, LayerHitParticle, LightWash, LowHullFeedback,
+    LowHullVignette, ManeuverConfig, ManeuverState, MotionSettings, Nebula, ObjectiveMarker,
+    ObjectiveMarkerLabel, PadAssignment, PadInfo, Phased, ProjectileFlash, RunInputLog,
+    SceneLighting, ScoreChip, ScorePopup, ShieldBubble, ShieldBubbleState, SoundSettings,
+    SpawnEdge, SpawnIndicator, SpeedLine, Star, StatusEffects, StatusKind, SupplyCache,
+    VoiceOverClip, VoiceOverState, VolatileWreck, WaveManager, Wreckage, AURA_RADIUS,
+    BERSERK_BURST_RADIUS, BUBBLE_DURATION, BUBBLE_RADIUS, DEATH_HOLD, MISSION_FADE,
+    PHASE_TRANSITION_DURATION, VOICE_LINE_TAIL, WRECK_FUSE,
+};
+use crate::ui::menu::{
+    DifficultyMenuRoot, FactionSelectRoot, GameOverRoot, MainMenuRoot, MenuItem, MenuSelection,
+    ProfileRoot, ShipMenuRoot,
+};
+use crate::ui::{
+    BattleLogRoot, BossBarShard, BossPhaseTick, BossWarningBanner, BubbleText, ClassicStatusBars,
+    CodexRoot, CodexView, CommsLogRoot, ControllerLostRoot, DialogueContainer, DifficultyChip,
+    FactionEmblem, HudDetailsHint, HudDetailsPanel, HudMetrics, HudRoot, HudStyle, HullBar,
+    InterludeRoot, InterludeView, MutatorsRoot, ObjectiveRow, PadPrompt, PowerupStatusBox,
+    PowerupType, RevealStagger, RevealVeil, RouteMapRoot, RouteMapView, RunCodeEntry, ScoreText,
+    ScreenReveal, ShipAbilityText, ShipSkinText, UiTheme, WaveTallyOverlay, WingmanTierText,
+    COMPACT_MIN_FONT,
+};
+
+/// Simulated frame length
+const FRAME: f32 = 1.0 / 30.0;
+
+/// Longest a single step of the flow may take before the test gives up
+const STATE_TIMEOUT: f32 = 10.0;
+
+/// The game App without rendering, windowing or the platform plugins
+/// (panic hook, save files, sprite downloads)
+fn headless_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        StatesPlugin,
+        bevy::input::InputPlugin,
+        // Opened at the base size, like the real window
+        bevy::window::WindowPlugin {
+            primary_window: Some(Window {
+                resolution: (SCREEN_WIDTH, SCREEN_HEIGHT).into(),
+                ..default()
+            }),
+            ..default()
+        },
+        AssetPlugin::default(),
+        ImagePlugin::default(),
+        TransformPlugin,
+        HierarchyPlugin,
+        bevy::audio::AudioPlugin::default(),
+        bevy::scene::ScenePlugin,
+    ))
+    // Gizmos and egui register their shaders even without a render app
+    .init_asset::<Shader>()
+    .add_plugins((bevy::gizmos::GizmoPlugin, bevy_egui::EguiPlugin))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+        FRAME,
+    )))
+    // Normally provided by the platform plugins
+    .init_resource::<SaveData>()
+    .init_resource::<ShipSpriteCache>()
+    .init_resource::<ShipModelCache>()
+    .init_resource::<PowerupIconCache>()
+    .init_resource::<FactionEmblemCache>()
+    .init_resource::<ProjectileSpriteCache>();
+
+    super::add_game(&mut app);
+    app
+}
+
+fn state(app: &App) -> GameState {
+    *app.world().resource::<State<GameState>>().get()
+}
+
+fn count<C: Component>(app: &mut App) -> usize {
+    app.world_mut()
+        .query_filtered::<(), With<C>>()
+        .iter(app.world())
+        .count()
+}
+
+/// Projectiles in flight for one side
+fn count_shots(app: &mut App, team: Team) -> usize {
+    app.world_mut()
+        .query::<&ProjectileOwner>()
+        .iter(app.world())
+        .filter(|owner| owner.team == team)
+        .count()
+}
+
+/// Step frames until `target` is reached
+fn run_until(app: &mut App, target: GameState) {
+    let mut elapsed = 0.0;
+    while state(app) != target {
+        assert!(
+            elapsed < STATE_TIMEOUT,
+            "stuck in {:?} waiting for {:?}",
+            state(app),
+            target
+        );
+        app.update();
+        elapsed += FRAME;
+    }
+}
+
+/// Feed a raw key event through the input plugin, as a window would
+fn send_key(app: &mut App, key_code: KeyCode, state: ButtonState) {
+    app.world_mut().send_event(KeyboardInput {
+        key_code,
+        logical_key: Key::Unidentified(NativeKey::Unidentified),
+        state,
+        repeat: false,
+        window: Entity::PLACEHOLDER,
+    });
+}
+
+/// Tap a key: one frame pressed, one frame released
+fn press(app: &mut App, key_code: KeyCode) {
+    for state in [ButtonState::Pressed, ButtonState::Released] {
+        send_key(app, key_code, state);
+        app.update();
+    }
+}
+
+/// Confirm menu entries until `target`, letting each screen settle first
+fn confirm_until(app: &mut App, target: GameState) {
+    let mut presses = 0;
+    while state(app) != target {
+        assert!(presses < 10, "confirm never reached {:?}", target);
+        let before = state(app);
+        press(app, KeyCode::Enter);
+        // Fades and menu cooldowns take a few frames
+        for _ in 0..30 {
+            if state(app) != before {
+                break;
+            }
+            app.update();
+        }
+        app.update();
+        presses += 1;
+    }
+}
+
+/// One step of a scripted menu session
+#[derive(Clone, Copy, Debug)]
+enum Step {
+    /// Tap a key, then wait out the menu's navigation cooldown
+    Tap(KeyCode),
+    /// Hold a key for a number of frames (faction select reads held arrows)
+    Hold(KeyCode, u32),
+    /// Wait for a state, then let its fade in finish
+    Expect(GameState),
+}
+
+/// Feed a scripted key sequence through the real menu input systems
+fn replay(app: &mut App, script: &[Step]) {
+    for (i, step) in script.iter().enumerate() {
+        match *step {
+            Step::Tap(key_code) => {
+                press(app, key_code);
+                for _ in 0..6 {
+                    app.update();
+                }
+            }
+            Step::Hold(key_code, frames) => {
+                send_key(app, key_code, ButtonState::Pressed);
+                for _ in 0..frames {
+                    app.update();
+                }
+                send_key(app, key_code, ButtonState::Released);
+                app.update();
+            }
+            Step::Expect(target) => {
+                let mut elapsed = 0.0;
+                while state(app) != target {
+                    assert!(
+                        elapsed < STATE_TIMEOUT,
+                        "step {} {:?}: stuck in {:?}",
+                        i,
+                        step,
+                        state(app)
+                    );
+                    app.update();
+                    elapsed += FRAME;
+                }
+                for _ in 0..30 {
+                    app.update();
+                }
+            }
+        }
+    }
+}
+
+/// Boot and walk the default menu path into gameplay
+fn start_playing(app: &mut App) {
+    run_until(app, GameState::MainMenu);
+    confirm_until(app, GameState::FactionSelect);
+    confirm_until(app, GameState::ShipSelect);
+    confirm_until(app, GameState::Playing);
+    // Let OnEnter spawns and the first fixed ticks land
+    for _ in 0..5 {
+        app.update();
+    }
+}
+
+#[test]
+fn boots_through_loading_to_main_menu() {
+    let mut app = headless_app();
+    app.update();
+    assert_eq!(state(&app), GameState::Loading);
+
+    run_until(&mut app, GameState::MainMenu);
+    app.update();
+    assert!(count::<MainMenuRoot>(&mut app) > 0);
+    assert_eq!(count::<Player>(&mut app), 0);
+}
+
+#[test]
+fn button_prompts_follow_player_ones_pad() {
+    let mut app = headless_app();
+    run_until(&mut app, GameState::MainMenu);
+    let prompts = |app: &mut App| -> Vec<String> {
+        app.world_mut()
+            .query_filtered::<&Text, With<PadPrompt>>()
+            .iter(app.world())
+            .map(|text| text.0.clone())
+            .collect()
+    };
+    assert!(prompts(&mut app).iter().any(|p| p.contains("A/ENTER")));
+
+    // A DualSense plugged in alongside an Xbox pad, then picked for player one
+    app.world_mut().resource_mut::<ConnectedPads>().0 = vec![
+        PadInfo::new(0, "Xbox Wireless Controller"),
+        PadInfo::new(1, "DualSense Wireless Controller"),
+    ];
+    app.update();
+    assert_eq!(
+        app.world().resource::<PadAssignment>().players,
+        [Some(0), Some(1)]
+    );
+    assert!(prompts(&mut app).iter().any(|p| p.contains("A/ENTER")));
+
+    let pads = app.world().resource::<ConnectedPads>().0.clone();
+    app.world_mut()
+        .resource_mut::<PadAssignment>()
+        .step_player_one(1, &pads);
+    app.update();
+    assert!(prompts(&mut app).iter().any(|p| p.contains("CROSS/ENTER")));
+}
+
+#[test]
+fn menu_flow_reaches_playing_with_one_player_and_hud() {
+    let mut app = headless_app();
+    run_until(&mut app, GameState::MainMenu);
+
+    confirm_until(&mut app, GameState::FactionSelect);
+    app.update();
+    assert_eq!(count::<MainMenuRoot>(&mut app), 0);
+    assert!(count::<FactionSelectRoot>(&mut app) > 0);
+    assert_eq!(count::<FactionEmblem>(&mut app), 2);
+
+    confirm_until(&mut app, GameState::ShipSelect);
+    app.update();
+    assert_eq!(count::<FactionSelectRoot>(&mut app), 0);
+    assert!(count::<ShipMenuRoot>(&mut app) > 0);
+    assert_eq!(count::<FactionEmblem>(&mut app), 1);
+
+    confirm_until(&mut app, GameState::Playing);
+    for _ in 0..5 {
+        app.update();
+    }
+    assert_eq!(count::<ShipMenuRoot>(&mut app), 0);
+    assert_eq!(count::<Player>(&mut app), 1);
+    assert_eq!(count::<HudRoot>(&mut app), 1);
+    assert_eq!(count::<FactionEmblem>(&mut app), 1);
+}
+
+#[test]
+fn ship_preview_follows_selection_and_skin_reaches_the_player() {
+    let mut app = headless_app();
+    run_until(&mut app, GameState::MainMenu);
+    confirm_until(&mut app, GameState::ShipSelect);
+    app.update();
+
+    let text_of = |app: &mut App| {
+        let world = app.world_mut();
+        world
+            .query_filtered::<&Text, With<ShipAbilityText>>()
+            .single(world)
+            .0
+            .clone()
+    };
+    let first = text_of(&mut app);
+    assert!(!first.is_empty());
+
+    // Next ship in the list updates the pane live
+    press(&mut app, KeyCode::ArrowDown);
+    app.update();
+    assert_ne!(text_of(&mut app), first);
+    press(&mut app, KeyCode::ArrowUp);
+
+    press(&mut app, KeyCode::Tab);
+    app.update();
+    let skin = *app.world().resource::<GameSession>().selected_skin();
+    assert_eq!(skin, SHIP_SKINS[1]);
+    let world = app.world_mut();
+    let skin_text = world
+        .query_filtered::<&Text, With<ShipSkinText>>()
+        .single(world);
+    assert!(skin_text.0.contains(skin.name));
+
+    confirm_until(&mut app, GameState::Playing);
+    for _ in 0..5 {
+        app.update();
+    }
+    let faction = app.world().resource::<GameSession>().player_faction;
+    let world = app.world_mut();
+    let sprite = world
+        .query_filtered::<&Sprite, With<Player>>()
+        .single(world);
+    assert_eq!(sprite.color, skin.apply(faction.primary_color()));
+}
+
+#[test]
+fn scripted_menu_run_picks_module_faction_difficulty_and_ship() {
+    use Step::*;
+
+    let mut app = headless_app();
+    replay(
+        &mut app,
+        &[
+            Expect(GameState::MainMenu),
+            Tap(KeyCode::Enter),
+            Expect(GameState::ModuleSelect),
+            // Endless is the third card and goes through difficulty select
+            Tap(KeyCode::ArrowDown),
+            Tap(KeyCode::ArrowDown),
+            Tap(KeyCode::Enter),
+            Expect(GameState::FactionSelect),
+            Hold(KeyCode::ArrowRight, 3),
+            Tap(KeyCode::Enter),
+            Expect(GameState::DifficultySelect),
+            Tap(KeyCode::ArrowDown),
+            Tap(KeyCode::Enter),
+            Expect(GameState::ShipSelect),
+            // The Crusader is locked until Act 1 is cleared
+            Tap(KeyCode::ArrowDown),
+            Tap(KeyCode::ArrowDown),
+            Tap(KeyCode::ArrowDown),
+            Tap(KeyCode::Enter),
+        ],
+    );
+    // Longer than the fade into gameplay would take
+    for _ in 0..40 {
+        app.update();
+    }
+    assert_eq!(state(&app), GameState::ShipSelect);
+
+    replay(
+        &mut app,
+        &[
+            Tap(KeyCode::ArrowUp),
+            Tap(KeyCode::Enter),
+            Expect(GameState::Playing),
+        ],
+    );
+    assert!(app.world().resource::<EndlessMode>().active);
+    assert_eq!(*app.world().resource::<Difficulty>(), Difficulty::BitterVet);
+    let session = app.world().resource::<GameSession>();
+    assert_eq!(session.player_faction, Faction::Amarr);
+    assert_eq!(session.enemy_faction, Faction::Minmatar);
+    assert_eq!(session.selected_ship().name, "Tormentor");
+}
+
+#[test]
+fn caldari_pilots_get_a_steel_blue_interface() {
+    use Step::*;
+
+    let mut app = headless_app();
+    run_until(&mut app, GameState::MainMenu);
+    assert_eq!(app.world().resource::<UiTheme>().faction, Faction::Minmatar);
+
+    // Battle of Caldari Prime, Caldari side, then past the mode select
+    replay(
+        &mut app,
+        &[
+            Tap(KeyCode::Enter),
+            Expect(GameState::ModuleSelect),
+            Tap(KeyCode::ArrowDown),
+            Tap(KeyCode::Enter),
+            Expect(GameState::FactionSelect),
+            Tap(KeyCode::Enter),
+        ],
+    );
+    confirm_until(&mut app, GameState::DifficultySelect);
+    app.update();
+
+    let theme = *app.world().resource::<UiTheme>();
+    assert_eq!(theme, UiTheme::for_faction(Faction::Caldari));
+    let selected_border = {
+        let world = app.world_mut();
+        let selected = world.resource::<MenuSelection>().index;
+        let mut query =
+            world.query_filtered::<(&MenuItem, &BorderColor), With<DifficultyMenuRoot>>();
+        query
+            .iter(world)
+            .find(|(item, _)| item.index == selected)
+            .map(|(_, border)| border.0)
+    };
+    assert_eq!(selected_border, Some(Faction::Caldari.primary_color()));
+}
+
+#[test]
+fn codex_fills_in_during_a_run_and_opens_from_both_menus() {
+    use Step::*;
+
+    let mut app = headless_app();
+    start_playing(&mut app);
+    for _ in 0..10 {
+        app.update();
+    }
+    {
+        let codex = &app.world().resource::<SaveData>().codex;
+        let session = app.world().resource::<GameSession>();
+        assert!(codex.is_unlocked(&faction_entry_id(session.player_faction)));
+        assert!(codex.is_unlocked(&faction_entry_id(session.enemy_faction)));
+        assert!(codex.is_unlocked(&ship_entry_id(session.selected_ship().type_id)));
+        assert!(codex.is_unlocked(&act_entry_id(Act::Act1)));
+    }
+
+    // Pause, scroll to CODEX; escape closes it without resuming
+    press(&mut app, KeyCode::Escape);
+    run_until(&mut app, GameState::Paused);
+    let mut script = vec![Tap(KeyCode::ArrowDown); 14];
+    script.push(Tap(KeyCode::Enter));
+    replay(&mut app, &script);
+    assert_eq!(count::<CodexRoot>(&mut app), 1);
+    replay(&mut app, &[Tap(KeyCode::ArrowRight), Tap(KeyCode::Escape)]);
+    assert_eq!(state(&app), GameState::Paused);
+    assert_eq!(count::<CodexRoot>(&mut app), 0);
+
+    // From the main menu it sits on top and keeps the menu's selection
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::MainMenu);
+    replay(
+        &mut app,
+        &[
+            Expect(GameState::MainMenu),
+            Tap(KeyCode::ArrowDown),
+            Tap(KeyCode::ArrowDown),
+            Tap(KeyCode::ArrowDown),
+            Tap(KeyCode::Enter),
+            Tap(KeyCode::ArrowDown),
+        ],
+    );
+    assert_eq!(
+        app.world().get_resource::<CodexView>().map(|v| v.index),
+        Some(1)
+    );
+    assert_eq!(app.world().resource::<MenuSelection>().index, 3);
+    replay(&mut app, &[Tap(KeyCode::Escape)]);
+    assert_eq!(state(&app), GameState::MainMenu);
+    assert_eq!(count::<CodexRoot>(&mut app), 0);
+}
+
+#[test]
+fn mutators_open_from_ship_select_and_scale_the_run() {
+    use Step::*;
+
+    let mut app = headless_app();
+    run_until(&mut app, GameState::MainMenu);
+    app.world_mut()
+        .resource_mut::<SaveData>()
+        .prestige
+        .complete_campaign(ELDER_FLEET, Difficulty::Newbro);
+    confirm_until(&mut app, GameState::FactionSelect);
+    confirm_until(&mut app, GameState::ShipSelect);
+
+    // Bullet Hell is third; its first-clear achievement is earned
+    replay(
+        &mut app,
+        &[
+            Expect(GameState::ShipSelect),
+            Tap(KeyCode::KeyM),
+            Tap(KeyCode::ArrowDown),
+            Tap(KeyCode::ArrowDown),
+            Tap(KeyCode::Enter),
+        ],
+    );
+    assert_eq!(count::<MutatorsRoot>(&mut app), 1);
+    assert_eq!(state(&app), GameState::ShipSelect);
+    assert!(app
+        .world()
+        .resource::<SaveData>()
+        .prestige
+        .modifier_active(RunModifier::BulletHell));
+
+    replay(&mut app, &[Tap(KeyCode::Escape)]);
+    assert_eq!(count::<MutatorsRoot>(&mut app), 0);
+    assert_eq!(state(&app), GameState::ShipSelect);
+
+    confirm_until(&mut app, GameState::Playing);
+    for _ in 0..5 {
+        app.update();
+    }
+    assert_eq!(
+        app.world().resource::<ScoreSystem>().difficulty_multiplier,
+        RunModifier::BulletHell.score_mult()
+    );
+    // The chip shows the multiplier scores are paid at
+    let expected = format!(
+        "{}  x{:.1} SCORE",
+        app.world().resource::<Difficulty>().name(),
+        RunModifier::BulletHell.score_mult()
+    );
+    assert_eq!(chip_text(&mut app), expected);
+}
+
+#[test]
+fn new_game_plus_depth_is_picked_on_ship_select_and_scales_the_run() {
+    use Step::*;
+
+    let mut app = headless_app();
+    run_until(&mut app, GameState::MainMenu);
+    app.world_mut().resource_mut::<SaveData>().ng_plus = NgPlusRecord {
+        unlocked: 2,
+        deepest_cleared: 1,
+    };
+    confirm_until(&mut app, GameState::FactionSelect);
+    confirm_until(&mut app, GameState::ShipSelect);
+
+    let depth = |app: &App| app.world().resource::<NewGamePlus>().depth;
+    replay(
+        &mut app,
+        &[Expect(GameState::ShipSelect), Tap(KeyCode::KeyN)],
+    );
+    assert_eq!(depth(&app), 1);
+    replay(&mut app, &[Tap(KeyCode::KeyN), Tap(KeyCode::KeyN)]);
+    assert_eq!(depth(&app), 0, "cycles back off past the deepest unlocked");
+    replay(&mut app, &[Tap(KeyCode::KeyN)]);
+
+    confirm_until(&mut app, GameState::Playing);
+    for _ in 0..5 {
+        app.update();
+    }
+    assert_eq!(depth(&app), 1);
+    assert_eq!(
+        app.world().resource::<ScoreSystem>().difficulty_multiplier,
+        NgPlusModifiers::for_depth(1).score
+    );
+}
+
+#[test]
+fn dialogue_lines_skip_and_replay_in_the_comms_log() {
+    use Step::*;
+
+    let mut app = headless_app();
+    start_playing(&mut app);
+    {
+        let mut dialogue = app.world_mut().resource_mut::<DialogueSystem>();
+        dialogue.show("Hold the line.".into(), 30.0, 20);
+        dialogue.show("Contacts bearing north.".into(), 30.0, 15);
+    }
+
+    // Enter skips to the queued line instead of waiting out the timer
+    press(&mut app, KeyCode::Enter);
+    let active = |app: &App| app.world().resource::<DialogueSystem>().active_text.clone();
+    assert_eq!(active(&app).as_deref(), Some("Contacts bearing north."));
+    let history = &app.world().resource::<DialogueSystem>().history;
+    assert!(history.iter().any(|line| line.text == "Hold the line."));
+
+    // Pause, scroll to COMMS LOG; escape closes it without resuming
+    press(&mut app, KeyCode::Escape);
+    run_until(&mut app, GameState::Paused);
+    let mut script = vec![Tap(KeyCode::ArrowDown); 15];
+    script.push(Tap(KeyCode::Enter));
+    replay(&mut app, &script);
+    assert_eq!(count::<CommsLogRoot>(&mut app), 1);
+    replay(&mut app, &[Tap(KeyCode::Escape)]);
+    assert_eq!(state(&app), GameState::Paused);
+    assert_eq!(count::<CommsLogRoot>(&mut app), 0);
+}
+
+#[test]
+fn voiced_lines_play_their_clip_and_hold_the_subtitle_for_its_length() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+    app.world_mut().send_event(DialogueEvent {
+        voice: Some("vo/elder/briefing_01.ogg".into()),
+        ..DialogueEvent::stage_briefing(1)
+    });
+    app.update();
+    app.update();
+
+    // The clip plays at the voice volume
+    let clip_volume = |app: &mut App| {
+        let world = app.world_mut();
+        world
+            .query_filtered::<&PlaybackSettings, With<VoiceOverClip>>()
+            .iter(world)
+            .map(|settings| settings.volume.get())
+            .next()
+    };
+    let sound = app.world().resource::<SoundSettings>();
+    let expected = sound.voice_volume * sound.master_volume;
+    assert_eq!(clip_volume(&mut app), Some(expected));
+
+    // Once the clip is in, the line stays up for as long as it runs
+    let half_second =
+        crate::systems::audio::create_audio_source(&[0.0; 22_050], 44_100).expect("wav encodes");
+    let clip = app
+        .world_mut()
+        .resource_mut::<Assets<AudioSource>>()
+        .add(half_second);
+    app.world_mut().resource_mut::<VoiceOverState>().clip = Some(clip);
+    app.update();
+    let dialogue = app.world().resource::<DialogueSystem>();
+    assert!(dialogue.voiced);
+    assert!(dialogue.timer <= 0.5 + VOICE_LINE_TAIL);
+    assert!(dialogue.timer > 0.5);
+
+    // Skipping the line cuts the clip off
+    press(&mut app, KeyCode::Enter);
+    app.update();
+    assert_eq!(clip_volume(&mut app), None);
+}
+
+#[test]
+fn scripted_back_navigation_returns_to_the_main_menu() {
+    use Step::*;
+
+    let mut app = headless_app();
+    replay(
+        &mut app,
+        &[
+            Expect(GameState::MainMenu),
+            Tap(KeyCode::Enter),
+            Expect(GameState::ModuleSelect),
+            Tap(KeyCode::Enter),
+            Expect(GameState::FactionSelect),
+            Tap(KeyCode::Enter),
+            Expect(GameState::StageSelect),
+            Tap(KeyCode::Escape),
+            Expect(GameState::FactionSelect),
+            Tap(KeyCode::Escape),
+            Expect(GameState::ModuleSelect),
+            Tap(KeyCode::Escape),
+            Expect(GameState::MainMenu),
+            // Options, then the profile, each back out with escape
+            Tap(KeyCode::ArrowDown),
+            Tap(KeyCode::Enter),
+            Expect(GameState::Options),
+            Tap(KeyCode::Escape),
+            Expect(GameState::MainMenu),
+            Tap(KeyCode::ArrowDown),
+            Tap(KeyCode::ArrowDown),
+            Tap(KeyCode::Enter),
+            Expect(GameState::Profile),
+            Tap(KeyCode::Escape),
+            Expect(GameState::MainMenu),
+        ],
+    );
+    let session = app.world().resource::<GameSession>();
+    assert_eq!(session.player_faction, Faction::Minmatar);
+    // One root and its five buttons, not a stale copy
+    assert_eq!(count::<MainMenuRoot>(&mut app), 1 + 5);
+}
+
+/// Veils still covering part of a screen
+fn covering_veils(app: &mut App) -> usize {
+    app.world_mut()
+        .query_filtered::<&BackgroundColor, With<RevealVeil>>()
+        .iter(app.world())
+        .filter(|color| color.0.alpha() > 0.0)
+        .count()
+}
+
+#[test]
+fn menu_screens_and_cards_reveal_unless_motion_is_reduced() {
+    let mut app = headless_app();
+    run_until(&mut app, GameState::MainMenu);
+    confirm_until(&mut app, GameState::ModuleSelect);
+    // One veil for the screen and one per module card, staggered
+    let cards = count::<RevealStagger>(&mut app);
+    assert!(cards > 1);
+    assert_eq!(count::<RevealVeil>(&mut app), 1 + cards);
+    let mut delays: Vec<f32> = app
+        .world_mut()
+        .query_filtered::<&ScreenReveal, With<RevealStagger>>()
+        .iter(app.world())
+        .map(|reveal| reveal.delay)
+        .collect();
+    delays.sort_by(f32::total_cmp);
+    assert_eq!(delays[0], 0.0);
+    assert!(delays[1] > 0.0);
+
+    for _ in 0..30 {
+        app.update();
+    }
+    assert_eq!(covering_veils(&mut app), 0);
+    let settled = app
+        .world_mut()
+        .query::<(&ScreenReveal, &Node)>()
+        .iter(app.world())
+        .all(|(reveal, node)| reveal.is_done() && node.top == Val::Px(0.0));
+    assert!(settled);
+
+    // Reduced motion shows screens as they are
+    app.insert_resource(MotionSettings {
+        reduced_motion: true,
+    });
+    press(&mut app, KeyCode::Escape);
+    run_until(&mut app, GameState::MainMenu);
+    app.update();
+    assert_eq!(count::<RevealVeil>(&mut app), 0);
+    assert_eq!(count::<ScreenReveal>(&mut app), 1);
+}
+
+#[test]
+fn player_input_moves_and_fires() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+
+    let player_x = |app: &mut App| {
+        app.world_mut()
+            .query_filtered::<&Transform, With<Player>>()
+            .single(app.world())
+            .translation
+            .x
+    };
+    let start_x = player_x(&mut app);
+
+    // Hold strafe-right and fire for half a second
+    send_key(&mut app, KeyCode::KeyD, ButtonState::Pressed);
+    send_key(&mut app, KeyCode::Space, ButtonState::Pressed);
+    for _ in 0..15 {
+        app.update();
+    }
+
+    assert!(player_x(&mut app) > start_x);
+    assert!(count_shots(&mut app, Team::Player) > 0);
+}
+
+#[test]
+fn low_latency_mode_moves_the_ship_the_frame_a_key_lands() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+    app.world_mut()
+        .resource_mut::<DisplaySettings>()
+        .low_latency = true;
+    app.update();
+
+    let ship = |app: &mut App| {
+        let (transform, interp) = app
+            .world_mut()
+            .query_filtered::<(&Transform, &InterpolatedTransform), With<Player>>()
+            .single(app.world());
+        (transform.translation, interp.current)
+    };
+    let (start, _) = ship(&mut app);
+
+    send_key(&mut app, KeyCode::KeyD, ButtonState::Pressed);
+    app.update();
+
+    // Moved this frame and drawn where it is, not a tick behind
+    let (rendered, simulated) = ship(&mut app);
+    assert!(rendered.x > start.x);
+    assert_eq!(rendered, simulated);
+    assert!(app.world().resource::<InputLatency>().last_ms().is_some());
+    let frame_latency = app
+        .world_mut()
+        .query_filtered::<&Window, With<bevy::window::PrimaryWindow>>()
+        .single(app.world())
+        .desired_maximum_frame_latency;
+    assert_eq!(frame_latency, std::num::NonZeroU32::new(1));
+}
+
+#[test]
+fn shots_trail_streaks_and_flash_at_the_muzzle_on_fallback_quads() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+
+    send_key(&mut app, KeyCode::Space, ButtonState::Pressed);
+    let mut saw_flash = false;
+    for _ in 0..10 {
+        app.update();
+        saw_flash |= count::<ProjectileFlash>(&mut app) > 0;
+    }
+    assert!(saw_flash);
+
+    // No sprites bundled: bolts stay colored quads, each with halo and core
+    let world = app.world_mut();
+    let shots: Vec<_> = world
+        .query::<(&Sprite, Option<&Children>, &ProjectileOwner)>()
+        .iter(world)
+        .filter(|(.., owner)| owner.is_player())
+        .map(|(sprite, children, _)| (sprite.image.clone(), children.map_or(0, |c| c.len())))
+        .collect();
+    assert!(shots.iter().all(|(image, _)| *image == Handle::default()));
+    assert!(shots.iter().any(|(_, streaks)| *streaks == 2));
+}
+
+#[test]
+fn kill_chains_merge_into_one_pooled_score_popup() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+
+    let kill = |x: f32| EnemyDestroyedEvent {
+        entity: Entity::PLACEHOLDER,
+        position: Vec2::new(x, 100.0),
+        enemy_type: "Punisher".into(),
+        type_id: 597,
+        score_value: 100,
+        points_awarded: 120,
+        was_boss: false,
+    };
+    for i in 0..5 {
+        app.world_mut().send_event(kill(i as f32 * 10.0));
+        app.update();
+    }
+    {
+        let world = app.world_mut();
+        let popup = world.query::<&ScorePopup>().single(world);
+        assert_eq!((popup.points, popup.kills), (600, 5));
+    }
+
+    // Once it fades, the next kill reuses the same entity
+    for _ in 0..120 {
+        app.update();
+    }
+    app.world_mut().send_event(kill(-200.0));
+    app.update();
+    let world = app.world_mut();
+    let popups: Vec<_> = world.query::<&ScorePopup>().iter(world).cloned().collect();
+    assert_eq!(popups.len(), 1);
+    assert_eq!(popups[0].kills, 1);
+}
+
+#[test]
+fn supply_cache_slows_time_and_grants_the_choice_flown_through() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+
+    let player_pos = {
+        let world = app.world_mut();
+        let transform = world
+            .query_filtered::<&Transform, With<Player>>()
+            .single(world);
+        transform.translation
+    };
+    app.world_mut()
+        .spawn((SupplyCache, Transform::from_translation(player_pos)));
+    app.update();
+
+    assert_eq!(count::<CacheChoice>(&mut app), 3);
+    assert!(app.world().resource::<Time<Virtual>>().relative_speed() < 1.0);
+
+    // Fly through the middle choice
+    {
+        let world = app.world_mut();
+        let mut choices = world.query::<(&mut Transform, &CacheChoice)>();
+        let mut sorted: Vec<_> = choices.iter_mut(world).collect();
+        sorted.sort_by(|a, b| a.0.translation.x.total_cmp(&b.0.translation.x));
+        sorted[1].0.translation = player_pos;
+    }
+    app.update();
+    app.update();
+
+    assert_eq!(count::<CacheOffer>(&mut app), 0);
+    assert_eq!(
+        app.world().resource::<Time<Virtual>>().relative_speed(),
+        1.0
+    );
+}
+
+#[test]
+fn objective_panel_lists_primary_and_bonus_objectives() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+
+    let lines: Vec<String> = app
+        .world_mut()
+        .query::<(&ObjectiveRow, &Text)>()
+        .iter(app.world())
+        .filter(|(_, text)| !text.is_empty())
+        .map(|(_, text)| text.0.clone())
+        .collect();
+    // Two primaries and the bonus; the hidden objective stays off the list
+    assert_eq!(lines.len(), 3, "{:?}", lines);
+    assert!(lines
+        .iter()
+        .any(|line| line.starts_with("◯ Waves cleared 0/")));
+}
+
+#[test]
+fn capture_progress_pauses_while_enemies_contest_the_structure() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+    app.world_mut()
+        .resource_mut::<ObjectiveTracker>()
+        .start(vec![ObjectiveDef::new(
+            ObjectiveTier::Primary,
+            "Seize the relay",
+            ObjectiveKind::Capture {
+                radius: 100.0,
+                duration: 2.0,
+            },
+        )]);
+    app.update();
+
+    let structure = app
+        .world_mut()
+        .query_filtered::<&Transform, With<CaptureStructure>>()
+        .single(app.world())
+        .translation
+        .truncate();
+    let hold = |app: &mut App, frames: usize| {
+        for _ in 0..frames {
+            app.world_mut()
+                .query_filtered::<&mut Transform, With<Player>>()
+                .single_mut(app.world_mut())
+                .translation = structure.extend(0.0);
+            app.update();
+        }
+    };
+    let capture = |app: &mut App| {
+        app.world_mut()
+            .query::<&CaptureStructure>()
+            .single(app.world())
+            .clone()
+    };
+
+    hold(&mut app, 15);
+    let held = capture(&mut app);
+    assert!(held.occupied && !held.contested);
+    assert!(held.progress > 0.0 && held.progress < 1.0);
+
+    // An enemy inside the radius stops the clock
+    let mut commands = app.world_mut().commands();
+    let enemy = spawn_enemy(
+        &mut commands,
+        597,
+        structure + Vec2::new(60.0, 0.0),
+        EnemyBehavior::Linear,
+        None,
+        None,
+    );
+    app.world_mut().flush();
+    hold(&mut app, 3);
+    let contested = capture(&mut app);
+    assert!(contested.contested);
+    assert_eq!(contested.progress, held.progress);
+    assert!(app
+        .world_mut()
+        .query::<(&ObjectiveRow, &Text)>()
+        .iter(app.world())
+        .any(|(_, text)| text.0.contains("CONTESTED")));
+
+    app.world_mut().entity_mut(enemy).despawn_recursive();
+    hold(&mut app, 60);
+    assert!(capture(&mut app).captured());
+    assert!(app
+        .world()
+        .resource::<ObjectiveTracker>()
+        .tier_complete(ObjectiveTier::Primary));
+}
+
+#[test]
+fn finished_missions_checkpoint_the_run_for_continue_campaign() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+    app.world_mut().resource_mut::<ScoreSystem>().score = 4_200;
+
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::StageComplete);
+    run_until(&mut app, GameState::StageComplete);
+    let checkpoint = app.world().resource::<SaveData>().checkpoint.clone();
+    let checkpoint = checkpoint.expect("a finished mission leaves a checkpoint");
+    assert_eq!((checkpoint.act, checkpoint.mission_index), (1, 1));
+    assert_eq!(checkpoint.score, 4_200);
+
+    // Quit, start over from scratch, and continue from the menu
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::MainMenu);
+    run_until(&mut app, GameState::MainMenu);
+    *app.world_mut().resource_mut::<CampaignState>() = CampaignState::default();
+    app.world_mut().resource_mut::<ScoreSystem>().score = 0;
+    for _ in 0..10 {
+        app.update();
+    }
+    press(&mut app, KeyCode::Enter);
+    run_until(&mut app, GameState::Playing);
+    app.update();
+    assert_eq!(app.world().resource::<CampaignState>().mission_index, 1);
+    assert!(app.world().resource::<CampaignState>().in_mission);
+    assert!(app.world().resource::<ScoreSystem>().score >= 4_200);
+
+    // Losing the run clears it
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::GameOver);
+    run_until(&mut app, GameState::GameOver);
+    assert!(app.world().resource::<SaveData>().checkpoint.is_none());
+}
+
+#[test]
+fn ironman_loses_the_flown_ship_and_continues_in_the_next() {
+    let mut app = headless_app();
+    run_until(&mut app, GameState::MainMenu);
+    {
+        let mut save = app.world_mut().resource_mut::<SaveData>();
+        save.fleet_roster.ironman = true;
+        let every_ship = Faction::all().iter().flat_map(|f| f.player_ships());
+        save.unlocked_ships = every_ship.map(|ship| ship.type_id).collect();
+    }
+    start_playing(&mut app);
+    let flown = app
+        .world()
+        .resource::<GameSession>()
+        .selected_ship()
+        .type_id;
+
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::StageComplete);
+    run_until(&mut app, GameState::StageComplete);
+    let save = app.world().resource::<SaveData>();
+    assert_eq!(save.fleet_roster.fatigue(flown), SORTIE_FATIGUE);
+    assert_eq!(save.fleet_roster.member(flown).unwrap().sorties, 1);
+
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::GameOver);
+    run_until(&mut app, GameState::GameOver);
+    let save = app.world().resource::<SaveData>();
+    assert!(!save.fleet_roster.is_available(flown));
+    let checkpoint = save
+        .checkpoint
+        .clone()
+        .expect("ironman keeps the run going");
+    let session = app.world().resource::<GameSession>();
+    let next = &session.player_ships()[checkpoint.ship_index];
+    assert_ne!(next.type_id, flown);
+    assert!(save.fleet_roster.is_available(next.type_id));
+}
+
+#[test]
+fn route_map_between_missions_detours_through_a_side_system() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::StageComplete);
+    run_until(&mut app, GameState::StageComplete);
+    confirm_until(&mut app, GameState::RouteMap);
+    for _ in 0..5 {
+        app.update();
+    }
+    assert!(count::<RouteMapRoot>(&mut app) > 0);
+
+    // Mission 2's leg: refugees off to the left
+    let view = app.world().resource::<RouteMapView>().clone();
+    assert_eq!(view.leg[0].encounter, Encounter::Refugees);
+    let souls = app.world().resource::<ScoreSystem>().souls_liberated;
+    let credits = app.world().resource::<GameProgress>().credits;
+
+    press(&mut app, KeyCode::ArrowLeft);
+    press(&mut app, KeyCode::Enter);
+    let view = app.world().resource::<RouteMapView>();
+    assert_eq!(view.taken, Some(0));
+    assert_eq!(
+        app.world().resource::<ScoreSystem>().souls_liberated,
+        souls + refugee_souls(Act::Act1)
+    );
+    assert!(app.world().resource::<GameProgress>().credits > credits);
+
+    confirm_until(&mut app, GameState::Playing);
+    app.update();
+    assert_eq!(count::<RouteMapRoot>(&mut app), 0);
+    assert_eq!(app.world().resource::<RouteState>().detours, 1);
+    assert_eq!(app.world().resource::<CampaignState>().mission_index, 1);
+}
+
+#[test]
+fn a_new_act_opens_on_an_interlude_whose_boon_fits_the_next_launch() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+    let stock_damage = app
+        .world_mut()
+        .query_filtered::<&Weapon, With<Player>>()
+        .single(app.world())
+        .damage;
+
+    // Finish Act 1's last mission
+    app.world_mut()
+        .resource_mut::<CampaignState>()
+        .mission_index = Act::Act1.missions().len() - 1;
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::StageComplete);
+    run_until(&mut app, GameState::StageComplete);
+    confirm_until(&mut app, GameState::RouteMap);
+    for _ in 0..5 {
+        app.update();
+    }
+    assert!(app.world().contains_resource::<InterludeView>());
+    assert!(count::<InterludeRoot>(&mut app) > 0);
+    assert_eq!(app.world().resource::<CampaignState>().act, Act::Act2);
+
+    // The middle card
+    press(&mut app, KeyCode::Enter);
+    app.update();
+    assert!(!app.world().contains_resource::<InterludeView>());
+    assert_eq!(count::<InterludeRoot>(&mut app), 0);
+    assert_eq!(
+        state(&app),
+        GameState::RouteMap,
+        "the pick stays on the map"
+    );
+    let flags = app.world().resource::<CampaignState>().flags.clone();
+    assert_eq!(flags.boon_for(Act::Act2), Some(ActBoon::HeavierRounds));
+    let checkpoint = app.world().resource::<SaveData>().checkpoint.clone();
+    assert_eq!(checkpoint.expect("mid-campaign").flags, flags);
+
+    confirm_until(&mut app, GameState::Playing);
+    for _ in 0..5 {
+        app.update();
+    }
+    let damage = app
+        .world_mut()
+        .query_filtered::<&Weapon, With<Player>>()
+        .single(app.world())
+        .damage;
+    assert!((damage - stock_damage * BOON_DAMAGE_MULT).abs() < 1e-3);
+}
+
+#[test]
+fn time_attack_run_posts_to_the_leaderboard() {
+    let mut app = headless_app();
+    app.insert_resource(RunMode::TimeAttack);
+    start_playing(&mut app);
+
+    for _ in 0..TIME_ATTACK_KILLS {
+        app.world_mut().send_event(EnemyDestroyedEvent {
+            entity: Entity::PLACEHOLDER,
+            position: Vec2::ZERO,
+            enemy_type: "Punisher".into(),
+            type_id: 597,
+            score_value: 100,
+            points_awarded: 100,
+            was_boss: false,
+        });
+    }
+    run_until(&mut app, GameState::StageComplete);
+
+    let save = app.world().resource::<SaveData>();
+    assert_eq!(save.leaderboards.len(), 1);
+    assert_eq!(save.leaderboards[0].mode, RunMode::TimeAttack);
+    assert_eq!(save.leaderboards[0].entries.len(), 1);
+
+    // Forty kills inside a second is platinum on any mission
+    let mission_id = save.leaderboards[0].mission_id.clone();
+    assert_eq!(
+        save.prestige.medal(RunMode::TimeAttack, &mission_id),
+        Some(Medal::Platinum)
+    );
+    let result = app.world().resource::<AttackResult>();
+    assert_eq!(result.medal, Some(Medal::Platinum));
+    assert!(result.new_medal);
+
+    // The entry carries the sealed run, checkable against its seed
+    let entry = &save.leaderboards[0].entries[0];
+    let integrity = entry.integrity.expect("sealed run");
+    assert_eq!(integrity.seed, app.world().resource::<RunRng>().seed);
+    assert_eq!(integrity.flag, None);
+    assert!(integrity.verifies(RunMode::TimeAttack, &mission_id, entry.value));
+    assert_eq!(result.integrity, Some(integrity));
+}
+
+#[test]
+fn attack_run_inputs_keep_their_clock_through_the_boss_fight() {
+    let mut app = headless_app();
+    app.insert_resource(RunMode::ScoreAttack);
+    start_playing(&mut app);
+    boss_in_battle(&mut app);
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::BossFight);
+    run_until(&mut app, GameState::BossFight);
+
+    // Ordinary, uneven presses, a few a second
+    let start = app.world().resource::<RunInputLog>().tick;
+    for gap in [8, 10, 7, 9, 11].iter().cycle().take(30) {
+        press(&mut app, KeyCode::Space);
+        for _ in 0..*gap {
+            app.update();
+        }
+    }
+    assert_eq!(state(&app), GameState::BossFight);
+
+    let input_log = app.world().resource::<RunInputLog>();
+    assert!(input_log.active);
+    // Two ticks a frame at 60 Hz, whether or not the mission clock runs
+    assert!(input_log.tick - start >= 600, "{}", input_log.tick - start);
+    assert_eq!(input_log.log.changes, 60);
+    assert_eq!(input_log.log.flag, None);
+}
+
+#[test]
+fn time_attack_races_the_best_ghost_and_keeps_a_faster_run() {
+    let mut app = headless_app();
+    app.insert_resource(RunMode::TimeAttack);
+    let slow = GhostRun {
+        mission_id: "m1_convoy_raid".to_string(),
+        time_ms: 3_600_000,
+        ship_type_id: 587,
+        samples: vec![[0.0, -200.0], [0.0, -180.0]],
+    };
+    app.world_mut()
+        .resource_mut::<GhostLibrary>()
+        .runs
+        .insert(slow.mission_id.clone(), slow);
+    start_playing(&mut app);
+    assert_eq!(count::<Ghost>(&mut app), 1);
+
+    for _ in 0..TIME_ATTACK_KILLS {
+        app.world_mut().send_event(EnemyDestroyedEvent {
+            entity: Entity::PLACEHOLDER,
+            position: Vec2::ZERO,
+            enemy_type: "Punisher".into(),
+            type_id: 597,
+            score_value: 100,
+            points_awarded: 100,
+            was_boss: false,
+        });
+    }
+    run_until(&mut app, GameState::StageComplete);
+
+    let library = app.world().resource::<GhostLibrary>();
+    let best = library.best("m1_convoy_raid").unwrap();
+    assert!(best.time_ms < 3_600_000);
+    assert!(!best.samples.is_empty());
+    assert_eq!(library.unsaved, vec!["m1_convoy_raid"]);
+}
+
+#[test]
+fn allied_fleet_joins_the_final_push_and_leaves_before_the_boss() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+    {
+        // Wave 3 of the Avatar mission has just spawned
+        let mut campaign = app.world_mut().resource_mut::<CampaignState>();
+        campaign.act = Act::Act3;
+        campaign.mission_index = 3;
+        campaign.current_wave = 4;
+    }
+    for _ in 0..30 {
+        app.update();
+    }
+    let arrived = app
+        .world()
+        .resource::<CampaignState>()
+        .fleet_cameo(3)
+        .unwrap();
+    assert_eq!(count::<AllyFleetShip>(&mut app), arrived.ships.len());
+    assert_eq!(app.world().resource::<AllyFleet>().called, vec![3]);
+
+    app.world_mut().resource_mut::<BossWarning>().start(5.0);
+    for _ in 0..60 {
+        app.update();
+    }
+    assert_eq!(count::<AllyFleetShip>(&mut app), 0);
+    assert!(app.world().resource::<AllyFleet>().cameo.is_none());
+}
+
+#[test]
+fn daily_run_spends_the_attempt_and_applies_modifiers() {
+    let mut app = headless_app();
+    app.insert_resource(RunMode::Daily);
+    // Stage select launches today's challenge whichever card is highlighted
+    start_playing(&mut app);
+
+    let daily = *app.world().resource::<DailyChallenge>();
+    assert_eq!(daily, DailyChallenge::today());
+    let save = app.world().resource::<SaveData>();
+    assert!(save.daily_result(daily.day).is_some());
+
+    let world = app.world_mut();
+    let stats = world
+        .query_filtered::<&ShipStats, With<Player>>()
+        .single(world);
+    assert_eq!(stats.max_shield == 0.0, daily.has(DailyModifier::NoShields));
+}
+
+#[test]
+fn classic_hud_swaps_the_wheel_for_status_bars() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+
+    let display = |app: &mut App| {
+        let world = app.world_mut();
+        world
+            .query_filtered::<&Node, With<ClassicStatusBars>>()
+            .single(world)
+            .display
+    };
+    assert_eq!(*app.world().resource::<HudStyle>(), HudStyle::Wheel);
+    assert_eq!(display(&mut app), Display::None);
+
+    app.insert_resource(HudStyle::Classic);
+    {
+        let world = app.world_mut();
+        let mut query = world.query_filtered::<&mut ShipStats, With<Player>>();
+        let mut stats = query.single_mut(world);
+        stats.hull = stats.max_hull * 0.5;
+    }
+    app.update();
+
+    assert_eq!(display(&mut app), Display::Flex);
+    let world = app.world_mut();
+    let hull_bar = world.query_filtered::<&Node, With<HullBar>>().single(world);
+    assert_eq!(hull_bar.width, Val::Percent(50.0));
+}
+
+#[test]
+fn display_mode_and_placement_reach_the_window() {
+    let mut app = headless_app();
+    run_until(&mut app, GameState::MainMenu);
+    let window = |app: &mut App| {
+        app.world_mut()
+            .query_filtered::<&Window, With<bevy::window::PrimaryWindow>>()
+            .single(app.world())
+            .clone()
+    };
+
+    app.world_mut().resource_mut::<DisplaySettings>().mode = DisplayMode::Borderless;
+    app.update();
+    assert_eq!(
+        window(&mut app).mode,
+        bevy::window::WindowMode::BorderlessFullscreen(bevy::window::MonitorSelection::Index(0))
+    );
+
+    // Back to windowed, it goes where it was left
+    {
+        let mut display = app.world_mut().resource_mut::<DisplaySettings>();
+        display.mode = DisplayMode::Windowed;
+        display.placement = Some(WindowPlacement {
+            position: IVec2::new(200, 100),
+            size: UVec2::new(1024, 896),
+        });
+    }
+    app.update();
+    let window = window(&mut app);
+    assert_eq!(window.mode, bevy::window::WindowMode::Windowed);
+    assert_eq!(
+        window.position,
+        bevy::window::WindowPosition::At(IVec2::new(200, 100))
+    );
+    assert_eq!(window.width(), 1024.0);
+}
+
+#[test]
+fn steam_deck_display_gets_the_compact_hud() {
+    let mut app = headless_app();
+    app.world_mut().spawn((
+        bevy::window::Monitor {
+            name: None,
+            physical_height: 800,
+            physical_width: 1280,
+            physical_position: IVec2::ZERO,
+            refresh_rate_millihertz: None,
+            scale_factor: 1.0,
+            video_modes: Vec::new(),
+        },
+        bevy::window::PrimaryMonitor,
+    ));
+    start_playing(&mut app);
+    assert!(app.world().resource::<HudMetrics>().compact);
+
+    // Every label in the HUD and dialogue box is readable at arm's length
+    let world = app.world_mut();
+    let mut pending: Vec<Entity> = world
+        .query_filtered::<Entity, Or<(With<HudRoot>, With<DialogueContainer>)>>()
+        .iter(world)
+        .collect();
+    let mut labels = 0;
+    while let Some(entity) = pending.pop() {
+        if let Some(font) = world.get::<TextFont>(entity) {
+            assert!(
+                font.font_size >= COMPACT_MIN_FONT,
+                "{}pt label in the compact HUD",
+                font.font_size
+            );
+            labels += 1;
+        }
+        if let Some(children) = world.get::<Children>(entity) {
+            pending.extend(children.iter());
+        }
+    }
+    assert!(labels > 10);
+
+    // The wheel's small print gives way to the classic bars
+    let classic = world
+        .query_filtered::<&Node, With<ClassicStatusBars>>()
+        .single(world);
+    assert_eq!(classic.display, Display::Flex);
+
+    // Objectives and souls start folded away, and TAB brings them back
+    let shown = |app: &mut App| {
+        let world = app.world_mut();
+        let panel = world
+            .query_filtered::<&Node, With<HudDetailsPanel>>()
+            .single(world)
+            .display;
+        let hint = world
+            .query_filtered::<&Node, With<HudDetailsHint>>()
+            .single(world)
+            .display;
+        (panel, hint)
+    };
+    assert_eq!(shown(&mut app), (Display::None, Display::Flex));
+    press(&mut app, KeyCode::Tab);
+    assert_eq!(shown(&mut app), (Display::Flex, Display::None));
+}
+
+#[test]
+fn losing_focus_pauses_unless_set_up_for_streaming() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+
+    let set_focus = |app: &mut App, focused: bool| {
+        let world = app.world_mut();
+        let window = world
+            .query_filtered::<Entity, With<bevy::window::PrimaryWindow>>()
+            .single(world);
+        world.send_event(bevy::window::WindowFocused { window, focused });
+        for _ in 0..3 {
+            app.update();
+        }
+    };
+
+    // Streaming: the run carries on in the background
+    *app.world_mut().resource_mut::<FocusSettings>() = FocusSettings::streaming();
+    set_focus(&mut app, false);
+    assert_eq!(state(&app), GameState::Playing);
+    set_focus(&mut app, true);
+
+    // Default: tabbing away opens the pause menu, and menus idle along
+    *app.world_mut().resource_mut::<FocusSettings>() = FocusSettings::default();
+    let playing_mode = app
+        .world()
+        .resource::<bevy::winit::WinitSettings>()
+        .unfocused_mode;
+    set_focus(&mut app, false);
+    assert_eq!(state(&app), GameState::Paused);
+    assert_ne!(
+        app.world()
+            .resource::<bevy::winit::WinitSettings>()
+            .unfocused_mode,
+        playing_mode
+    );
+
+    // Coming back doesn't resume by itself
+    set_focus(&mut app, true);
+    assert_eq!(state(&app), GameState::Paused);
+}
+
+#[test]
+fn unplugging_player_ones_pad_pauses_until_input_comes_back() {
+    let mut app = headless_app();
+    app.world_mut().resource_mut::<ConnectedPads>().0 =
+        vec![PadInfo::new(0, "Xbox Wireless Controller")];
+    start_playing(&mut app);
+
+    app.world_mut().resource_mut::<ConnectedPads>().0.clear();
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(state(&app), GameState::Paused);
+    assert_eq!(count::<ControllerLostRoot>(&mut app), 1);
+
+    // Any key picks the run back up without touching the pause menu
+    press(&mut app, KeyCode::Space);
+    app.update();
+    assert_eq!(state(&app), GameState::Playing);
+    assert_eq!(count::<ControllerLostRoot>(&mut app), 0);
+
+    // A pad that wasn't there can't go missing
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(state(&app), GameState::Playing);
+}
+
+#[test]
+fn ultrawide_windows_widen_the_playfield_or_letterbox_it() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+    assert_eq!(app.world().resource::<Playfield>().size, Playfield::BASE);
+
+    let resize = |app: &mut App, width: f32| {
+        let world = app.world_mut();
+        let mut window = world
+            .query_filtered::<&mut Window, With<bevy::window::PrimaryWindow>>()
+            .single_mut(world);
+        window.resolution.set(width, SCREEN_HEIGHT);
+        app.update();
+    };
+    let viewport = |app: &mut App| {
+        let world = app.world_mut();
+        world
+            .query_filtered::<&Camera, With<Camera2d>>()
+            .single(world)
+            .viewport
+            .is_some()
+    };
+
+    // Fill: the extra width is extra playfield, and the player can use it
+    resize(&mut app, SCREEN_WIDTH * 1.6);
+    let playfield = *app.world().resource::<Playfield>();
+    assert!(playfield.size.x > SCREEN_WIDTH * 1.5);
+    assert_eq!(playfield.size.y, SCREEN_HEIGHT);
+    assert!(!viewport(&mut app));
+    send_key(&mut app, KeyCode::ArrowRight, ButtonState::Pressed);
+    for _ in 0..90 {
+        app.update();
+    }
+    send_key(&mut app, KeyCode::ArrowRight, ButtonState::Released);
+    let x = {
+        let world = app.world_mut();
+        world
+            .query_filtered::<&Transform, With<Player>>()
+            .single(world)
+            .translation
+            .x
+    };
+    assert!(x > SCREEN_WIDTH / 2.0);
+
+    // Letterbox: back to the base playfield, barred at the sides
+    *app.world_mut().resource_mut::<AspectMode>() = AspectMode::Letterbox;
+    app.update();
+    assert_eq!(app.world().resource::<Playfield>().size, Playfield::BASE);
+    assert!(viewport(&mut app));
+}
+
+#[test]
+fn low_hull_raises_the_vignette_and_the_elder_warns() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+    assert_eq!(count::<LowHullVignette>(&mut app), 0);
+
+    {
+        let world = app.world_mut();
+        let mut query = world.query_filtered::<&mut ShipStats, With<Player>>();
+        let mut stats = query.single_mut(world);
+        stats.hull = stats.max_hull * 0.2;
+    }
+    for _ in 0..20 {
+        app.update();
+    }
+
+    assert_eq!(count::<LowHullVignette>(&mut app), 1);
+    let feedback = app.world().resource::<LowHullFeedback>();
+    assert!(feedback.intensity > 0.5);
+    assert!(feedback.warned);
+
+    // The briefing may still be up; the warning waits behind it
+    let dialogue = app.world().resource::<DialogueSystem>();
+    let lines = dialogue
+        .active_text
+        .iter()
+        .chain(dialogue.queue.iter().map(|(text, ..)| text));
+    assert!(lines
+        .into_iter()
+        .any(|text| text.to_lowercase().contains("hull")));
+
+    press(&mut app, KeyCode::Escape);
+    app.update();
+    assert_eq!(state(&app), GameState::Paused);
+    assert_eq!(count::<LowHullVignette>(&mut app), 0);
+}
+
+fn key_light(app: &mut App) -> (Color, f32) {
+    let world = app.world_mut();
+    let light = world
+        .query_filtered::<&DirectionalLight, With<KeyLight>>()
+        .single(world);
+    (light.color, light.illuminance)
+}
+
+#[test]
+fn mission_lighting_fades_up_for_the_act_and_flashes_on_big_explosions() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+
+    // Still fading up from the warp-in
+    let target = {
+        let world = app.world();
+        scene_lighting(
+            world.resource::<CampaignState>(),
+            world.resource::<GameSession>().enemy_faction,
+        )
+    };
+    assert!(app.world().resource::<SceneLighting>().transitioning());
+    assert!(key_light(&mut app).1 < target.key_lux);
+    assert_eq!(count::<LightWash>(&mut app), 1);
+
+    for _ in 0..((MISSION_FADE / FRAME) as usize + 2) {
+        app.update();
+    }
+    assert_eq!(key_light(&mut app), (target.key(), target.key_lux));
+
+    app.world_mut().send_event(ExplosionEvent {
+        position: Vec2::ZERO,
+        size: ExplosionSize::Massive,
+        color: Color::srgb(1.0, 0.5, 0.1),
+    });
+    app.update();
+    assert!(key_light(&mut app).1 > target.key_lux * 2.0);
+    for _ in 0..30 {
+        app.update();
+    }
+    assert_eq!(key_light(&mut app).1, target.key_lux);
+
+    // Menus get plain light back
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::MainMenu);
+    app.update();
+    assert_eq!(key_light(&mut app).1, LightingProfile::NEUTRAL.key_lux);
+}
+
+#[test]
+fn act_nebula_follows_the_act_and_drops_out_on_low_quality() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+    let acts = |app: &mut App| {
+        let world = app.world_mut();
+        let mut query = world.query::<&Nebula>();
+        query.iter(world).map(|n| n.act).collect::<Vec<_>>()
+    };
+    let act = app.world().resource::<CampaignState>().act;
+    assert_eq!(acts(&mut app), vec![act; act_clouds(act).len()]);
+
+    app.world_mut().resource_mut::<CampaignState>().act = Act::Act3;
+    app.update();
+    app.update();
+    assert_eq!(acts(&mut app), vec![Act::Act3; act_clouds(Act::Act3).len()]);
+
+    *app.world_mut().resource_mut::<GraphicsQuality>() = GraphicsQuality::Low;
+    app.update();
+    app.update();
+    assert!(acts(&mut app).is_empty());
+    assert!(count::<Star>(&mut app) > 0);
+}
+
+/// Layers that currently have hit feedback on screen
+fn hit_layers(app: &mut App) -> Vec<HitLayer> {
+    let world = app.world_mut();
+    let mut query = world.query::<&LayerHitParticle>();
+    let mut layers: Vec<_> = query.iter(world).map(|p| p.layer).collect();
+    layers.dedup();
+    layers
+}
+
+/// Shoot the player from straight above and let the hit land
+fn shoot_player(app: &mut App, damage: f32) {
+    let player_pos = {
+        let world = app.world_mut();
+        let mut query = world.query_filtered::<&Transform, With<Player>>();
+        query.single(world).translation.truncate()
+    };
+    let mut commands = app.world_mut().commands();
+    spawn_enemy_projectile(&mut commands, player_pos, Vec2::NEG_Y, damage, 1.0);
+    app.world_mut().flush();
+    app.update();
+    app.update();
+}
+
+#[test]
+fn battle_log_names_the_shooter_and_breaks_damage_down_after_the_mission() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+
+    let player_pos = {
+        let world = app.world_mut();
+        let mut query = world.query_filtered::<&Transform, With<Player>>();
+        query.single(world).translation.truncate()
+    };
+    let mut commands = app.world_mut().commands();
+    let shooter = spawn_enemy(
+        &mut commands,
+        597,
+        Vec2::new(150.0, SCREEN_HEIGHT * 0.4),
+        EnemyBehavior::Linear,
+        None,
+        None,
+    );
+    spawn_enemy_projectile_typed(
+        &mut commands,
+        player_pos,
+        Vec2::NEG_Y,
+        8.0,
+        0.0,
+        WeaponType::Laser,
+        ProjectileOwner::enemy().fired_by(shooter),
+    );
+    app.world_mut().flush();
+    app.update();
+    app.update();
+
+    let name = app.world().get::<EnemyStats>(shooter).unwrap().name.clone();
+    let log = app.world().resource::<BattleLog>();
+    let tally = log.breakdown()[0].clone();
+    assert_eq!(tally.source, name);
+    assert_eq!(tally.hits, 1);
+    assert!(log
+        .entries()
+        .any(|e| e.kind == BattleEntryKind::Hit && e.message.ends_with(&name)));
+
+    // Hidden until toggled
+    assert_eq!(count::<BattleLogRoot>(&mut app), 0);
+    press(&mut app, KeyCode::KeyG);
+    app.update();
+    assert_eq!(count::<BattleLogRoot>(&mut app), 1);
+
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::StageComplete);
+    run_until(&mut app, GameState::StageComplete);
+    app.update();
+    assert_eq!(count::<BattleLogRoot>(&mut app), 0);
+    let texts: Vec<String> = app
+        .world_mut()
+        .query::<&Text>()
+        .iter(app.world())
+        .map(|t| t.0.clone())
+        .collect();
+    assert!(texts.iter().any(|t| t.starts_with("DAMAGE TAKEN: ")));
+    assert!(texts.iter().any(|t| t.starts_with(&format!("{}: ", name))));
+}
+
+#[test]
+fn hits_show_the_tank_layer_that_took_them() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+
+    shoot_player(&mut app, 5.0);
+    assert_eq!(hit_layers(&mut app), vec![HitLayer::Shield]);
+
+    // Let the ripple fade, then knock the shields out
+    for _ in 0..30 {
+        app.update();
+    }
+    assert!(hit_layers(&mut app).is_empty());
+    {
+        let world = app.world_mut();
+        let mut query = world.query_filtered::<&mut ShipStats, With<Player>>();
+        let mut stats = query.single_mut(world);
+        stats.shield = 0.0;
+        stats.shield_timer = 10.0;
+    }
+    shoot_player(&mut app, 5.0);
+    assert_eq!(hit_layers(&mut app), vec![HitLayer::Armor]);
+}
+
+/// Frames in which the score label was rewritten
+#[derive(Resource, Default)]
+struct ScoreLabelWrites(u32);
+
+fn count_score_label_writes(
+    labels: Query<(), (With<ScoreText>, Changed<Text>)>,
+    mut writes: ResMut<ScoreLabelWrites>,
+) {
+    if !labels.is_empty() {
+        writes.0 += 1;
+    }
+}
+
+#[test]
+fn hud_labels_stay_untouched_until_their_values_change() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+    app.init_resource::<ScoreLabelWrites>()
+        .add_systems(Update, count_score_label_writes.after(GameSet::Ui));
+    // The first run sees the freshly built HUD
+    app.update();
+    app.world_mut().resource_mut::<ScoreLabelWrites>().0 = 0;
+
+    for _ in 0..30 {
+        app.update();
+    }
+    assert_eq!(app.world().resource::<ScoreLabelWrites>().0, 0);
+
+    app.world_mut().resource_mut::<ScoreSystem>().award(100);
+    app.update();
+    app.update();
+    assert_eq!(app.world().resource::<ScoreLabelWrites>().0, 1);
+    let label = app
+        .world_mut()
+        .query_filtered::<&Text, With<ScoreText>>()
+        .single(app.world())
+        .0
+        .clone();
+    assert_eq!(label, "SCORE: 100");
+}
+
+#[test]
+fn score_events_pay_out_at_the_active_modules_weights() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+    let weights = {
+        let world = app.world();
+        let registry = world.resource::<ModuleRegistry>();
+        registry.score_weights(world.resource::<ActiveModule>())
+    };
+    // Elder Fleet pays extra for lives brought home
+    assert!(weights.escort > 1.0);
+    let before = {
+        let mut score = app.world_mut().resource_mut::<ScoreSystem>();
+        score.chain = 0;
+        score.multiplier = 1.0;
+        score.score
+    };
+
+    app.world_mut()
+        .send_event(ScoreEvent::new(ScoreCategory::Escort, 1000).at(Vec2::ZERO));
+    app.update();
+
+    let score = app.world().resource::<ScoreSystem>();
+    let expected = score.difficulty_scaled((1000.0 * weights.escort).round() as u64);
+    assert_eq!(score.score - before, expected);
+    // Only kills build the chain
+    assert_eq!(score.chain, 0);
+}
+
+#[test]
+fn side_entries_are_announced_and_hold_fire_until_in() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+
+    let mut rng = fastrand::Rng::with_seed(4);
+    let playfield = *app.world().resource::<Playfield>();
+    let (from, to) = SpawnEdge::Left.entry_path(&playfield, &mut rng).unwrap();
+    let mut commands = app.world_mut().commands();
+    let enemy = spawn_enemy(&mut commands, 597, from, EnemyBehavior::Linear, None, None);
+    begin_entry(&mut commands, &playfield, enemy, from, to, SpawnEdge::Left);
+    app.world_mut().flush();
+
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(count::<SpawnIndicator>(&mut app), 1);
+    assert!(!app.world().get::<EnemyAI>(enemy).unwrap().active);
+
+    // The arrow is gone after its second and the ship has flown in
+    for _ in 0..45 {
+        app.update();
+    }
+    assert_eq!(count::<SpawnIndicator>(&mut app), 0);
+    assert!(app.world().get::<EntryRun>(enemy).is_none());
+    assert!(app.world().get::<EnemyAI>(enemy).unwrap().active);
+    let x = app.world().get::<Transform>(enemy).unwrap().translation.x;
+    assert!(x > -playfield.half_width());
+}
+
+#[test]
+fn only_ecm_confused_fire_hits_other_enemies() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+
+    let mut commands = app.world_mut().commands();
+    let shooter = spawn_enemy(
+        &mut commands,
+        597,
+        Vec2::new(-200.0, 200.0),
+        EnemyBehavior::Linear,
+        None,
+        None,
+    );
+    let target = spawn_enemy(
+        &mut commands,
+        597,
+        Vec2::new(200.0, 200.0),
+        EnemyBehavior::Linear,
+        None,
+        None,
+    );
+    app.world_mut().flush();
+    app.update();
+    let health = |app: &App| app.world().get::<EnemyStats>(target).unwrap().health;
+    let full = health(&app);
+    let target_pos = |app: &App| {
+        app.world()
+            .get::<Transform>(target)
+            .unwrap()
+            .translation
+            .truncate()
+    };
+
+    // Ordinary enemy fire passes through its own side
+    let pos = target_pos(&app);
+    let mut commands = app.world_mut().commands();
+    spawn_enemy_projectile(&mut commands, pos, Vec2::NEG_Y, 5.0, 0.0);
+    app.world_mut().flush();
+    app.update();
+    app.update();
+    assert_eq!(health(&app), full);
+
+    // Scrambled fire doesn't tell friend from foe
+    let pos = target_pos(&app);
+    let mut commands = app.world_mut().commands();
+    spawn_enemy_projectile_typed(
+        &mut commands,
+        pos,
+        Vec2::NEG_Y,
+        5.0,
+        0.0,
+        WeaponType::Autocannon,
+        ProjectileOwner::enemy().fired_by(shooter).confused(),
+    );
+    app.world_mut().flush();
+    app.update();
+    app.update();
+    assert!(health(&app) < full);
+}
+
+#[test]
+fn enemy_fire_follows_faction_doctrine() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+
+    let player_pos = {
+        let world = app.world_mut();
+        let mut query = world.query_filtered::<&Transform, With<Player>>();
+        query.single(world).translation.truncate()
+    };
+    let mut commands = app.world_mut().commands();
+    // A Gallente blaster boat well outside its range, a Caldari missile boat
+    let tristan = spawn_enemy(
+        &mut commands,
+        593,
+        player_pos + Vec2::new(0.0, 500.0),
+        EnemyBehavior::Linear,
+        None,
+        None,
+    );
+    let kestrel = spawn_enemy(
+        &mut commands,
+        602,
+        player_pos + Vec2::new(-300.0, 400.0),
+        EnemyBehavior::Linear,
+        None,
+        None,
+    );
+    app.world_mut().flush();
+    for ship in [tristan, kestrel] {
+        app.world_mut().entity_mut(ship).remove::<EnemyTactics>();
+        app.world_mut()
+            .get_mut::<EnemyWeapon>(ship)
+            .unwrap()
+            .cooldown = 0.0;
+    }
+    app.update();
+
+    let fired_by = |app: &mut App, ship: Entity| {
+        app.world_mut()
+            .query::<(&ProjectileOwner, Has<SeekingProjectile>)>()
+            .iter(app.world())
+            .filter(|(owner, _)| owner.shooter == Some(ship))
+            .map(|(_, seeking)| seeking)
+            .collect::<Vec<_>>()
+    };
+    // The missile homes; the blaster boat saves its shot for close range
+    assert_eq!(fired_by(&mut app, kestrel), vec![true]);
+    assert!(fired_by(&mut app, tristan).is_empty());
+    assert!(app.world().get::<EnemyWeapon>(tristan).unwrap().cooldown <= 0.0);
+}
+
+#[test]
+fn parry_turns_nearby_enemy_fire_around_with_bonus_damage() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+
+    let player_pos = {
+        let world = app.world_mut();
+        let mut query = world.query_filtered::<&Transform, With<Player>>();
+        query.single(world).translation.truncate()
+    };
+    let mut commands = app.world_mut().commands();
+    spawn_enemy_projectile(
+        &mut commands,
+        player_pos + Vec2::new(0.0, 45.0),
+        Vec2::NEG_Y,
+        10.0,
+        0.0,
+    );
+    app.world_mut().flush();
+
+    press(&mut app, KeyCode::KeyC);
+
+    let world = app.world_mut();
+    let shots: Vec<_> = world
+        .query::<(&ProjectileOwner, &ProjectileDamage, &ProjectilePhysics)>()
+        .iter(world)
+        .map(|(owner, damage, physics)| (owner.team, damage.damage, physics.velocity))
+        .collect();
+    assert_eq!(shots.len(), 1);
+    let (team, damage, velocity) = shots[0];
+    assert_eq!(team, Team::Player);
+    assert_eq!(damage, 10.0 * ManeuverConfig::PARRY_DAMAGE_MULT);
+    assert!(velocity.y > 0.0);
+
+    // Capacitor spent and the cooldown running
+    let maneuver = world
+        .query_filtered::<&ManeuverState, With<Player>>()
+        .single(world);
+    assert!(maneuver.parry_cooldown > 0.0);
+}
+
+#[test]
+fn shield_bubble_swallows_enemy_fire_except_phased_shots() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+
+    press(&mut app, KeyCode::KeyV);
+    assert_eq!(count::<ShieldBubble>(&mut app), 1);
+    assert!(!app.world().resource::<ShieldBubbleState>().ready());
+
+    let bubble_pos = {
+        let world = app.world_mut();
+        let mut query = world.query_filtered::<&Transform, With<ShieldBubble>>();
+        query.single(world).translation.truncate()
+    };
+    let mut commands = app.world_mut().commands();
+    for side in [1.0, -1.0] {
+        spawn_enemy_projectile(
+            &mut commands,
+            bubble_pos + Vec2::new(side * BUBBLE_RADIUS * 0.7, 0.0),
+            Vec2::NEG_Y,
+            0.0,
+            1.0,
+        );
+    }
+    app.world_mut().flush();
+    // The left one is a phased boss shot
+    let inside = |world: &mut World| -> Vec<(Entity, f32)> {
+        world
+            .query::<(Entity, &Transform, &ProjectileOwner)>()
+            .iter(world)
+            .filter(|(_, t, owner)| {
+                owner.team == Team::Enemy
+                    && t.translation.truncate().distance(bubble_pos) < BUBBLE_RADIUS
+            })
+            .map(|(entity, t, _)| (entity, t.translation.x - bubble_pos.x))
+            .collect()
+    };
+    let shots = inside(app.world_mut());
+    assert_eq!(shots.len(), 2);
+    for (entity, dx) in shots {
+        if dx < 0.0 {
+            app.world_mut().entity_mut(entity).insert(Phased);
+        }
+    }
+    app.update();
+
+    let shots = inside(app.world_mut());
+    assert_eq!(shots.len(), 1);
+    assert!(shots[0].1 < 0.0);
+
+    let label = app
+        .world_mut()
+        .query_filtered::<&Text, With<BubbleText>>()
+        .single(app.world())
+        .0
+        .clone();
+    assert!(label.starts_with("BUBBLE ") && label.ends_with('s'));
+
+    // Drops after its duration
+    for _ in 0..((BUBBLE_DURATION / FRAME) as usize + 2) {
+        app.update();
+    }
+    assert_eq!(count::<ShieldBubble>(&mut app), 0);
+}
+
+#[test]
+fn destroyer_wrecks_cook_off_and_take_nearby_enemies_with_them() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+
+    let player_pos = {
+        let world = app.world_mut();
+        let mut query = world.query_filtered::<&Transform, With<Player>>();
+        query.single(world).translation.truncate()
+    };
+    let player_hull = |world: &mut World| {
+        world
+            .query_filtered::<&ShipStats, With<Player>>()
+            .single(world)
+            .hull
+    };
+    let hull_before = player_hull(app.world_mut());
+
+    // A Coercer goes down well clear of the player
+    let wreck_pos = player_pos + Vec2::new(0.0, 400.0);
+    app.world_mut().send_event(EnemyDestroyedEvent {
+        entity: Entity::PLACEHOLDER,
+        position: wreck_pos,
+        enemy_type: "Coercer".into(),
+        type_id: 16236,
+        score_value: 250,
+        points_awarded: 250,
+        was_boss: false,
+    });
+    app.update();
+    assert_eq!(count::<VolatileWreck>(&mut app), 1);
+
+    // A Punisher drifts over the wreck just before it goes up
+    for _ in 0..((WRECK_FUSE / FRAME) as usize - 3) {
+        app.update();
+    }
+    assert_eq!(count::<VolatileWreck>(&mut app), 1);
+    let mut commands = app.world_mut().commands();
+    let punisher = spawn_enemy(
+        &mut commands,
+        597,
+        wreck_pos,
+        EnemyBehavior::Linear,
+        None,
+        None,
+    );
+    app.world_mut().flush();
+    for _ in 0..5 {
+        app.update();
+    }
+
+    assert_eq!(count::<VolatileWreck>(&mut app), 0);
+    assert!(app.world().get_entity(punisher).is_err());
+    assert!(app.world().resource::<BerserkSystem>().meter > 0.0);
+    assert!(app.world().resource::<ScoreSystem>().score > 0);
+    // Too far away to feel it
+    assert_eq!(player_hull(app.world_mut()), hull_before);
+}
+
+#[test]
+fn barrel_roll_i_frames_stretch_on_easier_difficulties() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+    *app.world_mut().resource_mut::<Difficulty>() = Difficulty::Carebear;
+
+    press(&mut app, KeyCode::KeyQ);
+
+    let world = app.world_mut();
+    let maneuver = world
+        .query_filtered::<&ManeuverState, With<Player>>()
+        .single(world);
+    assert!(maneuver.invincible);
+    assert!(maneuver.invincibility_timer > ManeuverConfig::BARREL_ROLL_INVINCIBILITY);
+    assert!(maneuver.roll_ready() < 1.0);
+}
+
+#[test]
+fn clearing_a_wave_vacuums_up_distant_pickups() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+
+    // Far outside the magnet radius of a ship at the bottom of the screen
+    let mut commands = app.world_mut().commands();
+    spawn_collectible(
+        &mut commands,
+        Vec2::new(350.0, 300.0),
+        CollectibleType::Credits,
+        None,
+    );
+    app.world_mut().flush();
+    let pickup = app
+        .world_mut()
+        .query_filtered::<Entity, With<Collectible>>()
+        .iter(app.world())
+        .last()
+        .unwrap();
+
+    app.update();
+    assert!(app.world().get_entity(pickup).is_ok());
+
+    app.world_mut()
+        .send_event(WaveCompleteEvent { wave_number: 1 });
+    for _ in 0..60 {
+        app.update();
+    }
+    assert!(app.world().get_entity(pickup).is_err());
+}
+
+#[test]
+fn bullet_palette_recolors_enemy_fire_but_not_the_players() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+    app.insert_resource(BulletPalette::Classic);
+
+    // Far from the ship, drifting nowhere
+    let mut commands = app.world_mut().commands();
+    let needle = spawn_enemy_projectile_typed(
+        &mut commands,
+        Vec2::new(300.0, 300.0),
+        Vec2::NEG_Y,
+        1.0,
+        0.0,
+        WeaponType::Laser,
+        ProjectileOwner::enemy(),
+    );
+    let round = spawn_enemy_projectile_typed(
+        &mut commands,
+        Vec2::new(-300.0, 300.0),
+        Vec2::NEG_Y,
+        1.0,
+        0.0,
+        WeaponType::Drone,
+        ProjectileOwner::enemy(),
+    );
+    let friendly = spawn_enemy_projectile_typed(
+        &mut commands,
+        Vec2::new(0.0, 300.0),
+        Vec2::NEG_Y,
+        1.0,
+        0.0,
+        WeaponType::Drone,
+        ProjectileOwner::player(),
+    );
+    app.world_mut().flush();
+    app.update();
+
+    let color = |app: &App, entity: Entity| app.world().get::<Sprite>(entity).unwrap().color;
+    let (round_color, needle_color) = BulletPalette::Classic.colors().unwrap();
+    assert_eq!(color(&app, needle), needle_color);
+    assert_eq!(color(&app, round), round_color);
+    assert_ne!(color(&app, friendly), round_color);
+}
+
+#[test]
+fn nosferatu_leeches_hull_from_kills_while_its_box_is_up() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+
+    let player_pos = {
+        let world = app.world_mut();
+        let transform = world
+            .query_filtered::<&Transform, With<Player>>()
+            .single(world);
+        transform.translation.truncate()
+    };
+    let mut commands = app.world_mut().commands();
+    spawn_collectible(&mut commands, player_pos, CollectibleType::Nosferatu, None);
+    app.world_mut().flush();
+    app.update();
+    app.update();
+
+    let leech_box_shown = |app: &mut App| {
+        let world = app.world_mut();
+        world
+            .query::<(&PowerupStatusBox, &Node)>()
+            .iter(world)
+            .any(|(status, node)| {
+                status.powerup_type == PowerupType::Leech && node.display != Display::None
+            })
+    };
+    assert!(leech_box_shown(&mut app));
+
+    let hull_before = {
+        let world = app.world_mut();
+        let mut stats = world
+            .query_filtered::<&mut ShipStats, With<Player>>()
+            .single_mut(world);
+        stats.hull = stats.max_hull * 0.5;
+        stats.hull
+    };
+    app.world_mut().send_event(EnemyDestroyedEvent {
+        entity: Entity::PLACEHOLDER,
+        position: player_pos + Vec2::Y * 200.0,
+        enemy_type: "Punisher".to_string(),
+        type_id: 597,
+        score_value: 0,
+        points_awarded: 0,
+        was_boss: false,
+    });
+    app.update();
+
+    let world = app.world_mut();
+    let (stats, effects) = world
+        .query_filtered::<(&ShipStats, &PowerupEffects), With<Player>>()
+        .single(world);
+    assert!(stats.hull > hull_before);
+    assert!(effects.leech_recent > 0.0);
+}
+
+/// The HUD's difficulty chip label
+fn chip_text(app: &mut App) -> String {
+    let world = app.world_mut();
+    let chip = world
+        .query_filtered::<&Children, With<DifficultyChip>>()
+        .single(world)[0];
+    world.get::<Text>(chip).unwrap().0.clone()
+}
+
+#[test]
+fn difficulty_multiplier_is_shown_and_applied() {
+    let mut app = headless_app();
+    app.insert_resource(Difficulty::BitterVet);
+    start_playing(&mut app);
+
+    assert_eq!(chip_text(&mut app), "BITTER VET  x1.5 SCORE");
+
+    let mut score = app.world_mut().resource_mut::<ScoreSystem>();
+    assert_eq!(score.difficulty_multiplier, 1.5);
+    let before = score.score;
+    score.on_kill(100);
+    // Chain x1.1 on the first kill, then difficulty
+    assert_eq!(score.score - before, 165);
+}
+
+#[test]
+fn wave_tally_pays_out_and_firing_skips_it() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+
+    {
+        let mut tally = app.world_mut().resource_mut::<WaveTally>();
+        tally.kills = 4;
+        tally.shots_fired = 8;
+        tally.shots_hit = 4;
+    }
+    app.world_mut()
+        .send_event(WaveCompleteEvent { wave_number: 2 });
+    app.update();
+
+    let bonus = {
+        let world = app.world_mut();
+        let overlay = world.query::<&WaveTallyOverlay>().single(world);
+        overlay.bonus
+    };
+    assert_eq!(bonus.wave, 1);
+    assert_eq!(bonus.kills, 4);
+    assert_eq!(bonus.accuracy_bonus(), 500);
+    assert_eq!(app.world().resource::<WaveTally>().kills, 0);
+
+    // Play carries on underneath; firing pays out the rest at once
+    let before = app.world().resource::<ScoreSystem>().score;
+    press(&mut app, KeyCode::Space);
+    {
+        let world = app.world_mut();
+        let overlay = world.query::<&WaveTallyOverlay>().single(world);
+        assert_eq!(overlay.awarded, bonus.total());
+    }
+    let after = app.world().resource::<ScoreSystem>().score;
+    assert!(after - before >= bonus.total());
+    assert_eq!(state(&app), GameState::Playing);
+
+    for _ in 0..30 {
+        app.update();
+    }
+    assert_eq!(count::<WaveTallyOverlay>(&mut app), 0);
+}
+
+#[test]
+fn named_wingman_joins_and_is_remembered_when_lost() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+    app.world_mut().resource_mut::<SelectedShip>().ship = MinmatarShip::Rifter;
+
+    {
+        let mut tracker = app.world_mut().resource_mut::<WingmanTracker>();
+        tracker.kill_count = tracker.kills_per_wingman - 1;
+    }
+    app.world_mut().send_event(EnemyDestroyedEvent {
+        entity: Entity::PLACEHOLDER,
+        position: Vec2::ZERO,
+        enemy_type: "Punisher".into(),
+        type_id: 597,
+        score_value: 100,
+        points_awarded: 100,
+        was_boss: false,
+    });
+    app.update();
+    app.update();
+
+    let (wingman, position) = {
+        let world = app.world_mut();
+        let mut query =
+            world.query_filtered::<(Entity, &WingmanPilot, &Transform), With<Wingman>>();
+        let (entity, pilot, transform) = query.single(world);
+        assert_eq!(pilot.profile().callsign, "Ember");
+        (entity, transform.translation.truncate())
+    };
+    assert_eq!(app.world().resource::<WingmanRoster>().flying, vec![0]);
+
+    // One more hit finishes the wingman
+    app.world_mut()
+        .get_mut::<WingmanStats>(wingman)
+        .unwrap()
+        .health = 1.0;
+    let mut commands = app.world_mut().commands();
+    spawn_enemy_projectile(&mut commands, position, Vec2::NEG_Y, 10.0, 0.0);
+    app.world_mut().flush();
+    app.update();
+    app.update();
+
+    assert!(app.world().get_entity(wingman).is_err());
+    let roster = app.world().resource::<WingmanRoster>();
+    assert!(roster.flying.is_empty());
+    assert!(roster.memorial()[0].starts_with("Keva 'Ember' Arnoldt"));
+
+    let dialogue = app.world().resource::<DialogueSystem>();
+    let lines: Vec<&String> = dialogue
+        .active_text
+        .iter()
+        .chain(dialogue.queue.iter().map(|(text, ..)| text))
+        .collect();
+    assert!(lines.iter().any(|text| text.contains("Ember has fallen")));
+}
+
+#[test]
+fn any_ship_calls_in_tiered_reinforcements_just_slower_than_a_rifter() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+    assert_eq!(
+        app.world().resource::<WingmanTracker>().kills_per_wingman,
+        15
+    );
+
+    app.world_mut().resource_mut::<SelectedShip>().ship = MinmatarShip::Wolf;
+    app.update();
+    assert_eq!(
+        app.world().resource::<WingmanTracker>().kills_per_wingman,
+        25
+    );
+
+    let label = |app: &mut App| {
+        app.world_mut()
+            .query_filtered::<&Text, With<WingmanTierText>>()
+            .single(app.world())
+            .0
+            .clone()
+    };
+    assert_eq!(label(&mut app), "WINGMAN: SLASHER");
+
+    for _ in 0..4 {
+        {
+            let mut tracker = app.world_mut().resource_mut::<WingmanTracker>();
+            tracker.kill_count = tracker.kills_per_wingman - 1;
+        }
+        app.world_mut().send_event(EnemyDestroyedEvent {
+            entity: Entity::PLACEHOLDER,
+            position: Vec2::ZERO,
+            enemy_type: "Punisher".into(),
+            type_id: 597,
+            score_value: 100,
+            points_awarded: 100,
+            was_boss: false,
+        });
+        app.update();
+        app.update();
+    }
+
+    let mut tiers: Vec<WingmanTier> = app
+        .world_mut()
+        .query_filtered::<&WingmanTier, With<Wingman>>()
+        .iter(app.world())
+        .copied()
+        .collect();
+    tiers.sort_by_key(|tier| tier.type_id());
+    assert_eq!(
+        tiers,
+        [
+            WingmanTier::Slasher,
+            WingmanTier::Slasher,
+            WingmanTier::Rifter,
+            WingmanTier::Wolf,
+        ]
+    );
+    assert_eq!(label(&mut app), "WING FULL");
+}
+
+#[test]
+fn campaign_clear_earns_prestige_that_fits_the_next_launch() {
+    let player_loadout = |app: &mut App| {
+        let world = app.world_mut();
+        let (stats, weapon) = world
+            .query_filtered::<(&ShipStats, &Weapon), With<Player>>()
+            .single(world);
+        (stats.max_armor, weapon.damage)
+    };
+
+    let mut app = headless_app();
+    start_playing(&mut app);
+    let (stock_armor, stock_damage) = player_loadout(&mut app);
+    app.world_mut().send_event(CampaignCompleteEvent {
+        module: ELDER_FLEET,
+    });
+    app.update();
+    let prestige = app.world().resource::<SaveData>().prestige.clone();
+    assert_eq!(prestige.points, 200);
+    assert_eq!(prestige.module(ELDER_FLEET).unwrap().campaign_clears, 1);
+
+    // A veteran profile switches on Glass Cannon from the profile screen
+    let mut app = headless_app();
+    app.world_mut().resource_mut::<SaveData>().prestige.points = 300;
+    run_until(&mut app, GameState::MainMenu);
+    for _ in 0..2 {
+        press(&mut app, KeyCode::ArrowDown);
+        for _ in 0..6 {
+            app.update();
+        }
+    }
+    confirm_until(&mut app, GameState::Profile);
+    // Let the fade in finish
+    for _ in 0..30 {
+        app.update();
+    }
+    assert!(count::<ProfileRoot>(&mut app) > 0);
+    press(&mut app, KeyCode::Enter);
+    let save = app.world().resource::<SaveData>();
+    assert!(save.prestige.modifier_active(RunModifier::GlassCannon));
+
+    press(&mut app, KeyCode::Escape);
+    run_until(&mut app, GameState::MainMenu);
+    app.update();
+    start_playing(&mut app);
+    let (armor, damage) = player_loadout(&mut app);
+    assert!((armor - stock_armor * SALVAGED_PLATING_ARMOR * 0.5).abs() < 0.01);
+    assert!((damage - stock_damage * 1.5).abs() < 0.01);
+}
+
+#[test]
+fn boss_bar_marks_phases_and_shatters_on_a_phase_change() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+
+    let boss = get_boss_for_stage(6).unwrap();
+    assert_eq!(boss.total_phases, 3);
+    let mut commands = app.world_mut().commands();
+    spawn_boss(
+        &mut commands,
+        &Playfield::default(),
+        6,
+        Faction::Amarr,
+        None,
+        None,
+    );
+    app.world_mut().flush();
+    app.update();
+    app.update();
+
+    let ticks: Vec<(u32, Display, Val)> = {
+        let world = app.world_mut();
+        let mut query = world.query::<(&BossPhaseTick, &Node)>();
+        let mut ticks: Vec<_> = query
+            .iter(world)
+            .map(|(tick, node)| (tick.0, node.display, node.left))
+            .collect();
+        ticks.sort_by_key(|(phase, ..)| *phase);
+        ticks
+    };
+    let shown: Vec<_> = ticks
+        .iter()
+        .filter(|(_, display, _)| *display != Display::None)
+        .map(|(phase, _, left)| (*phase, *left))
+        .collect();
+    assert_eq!(
+        shown,
+        vec![
+            (2, Val::Percent(get_phase_threshold(2, 3) * 100.0)),
+            (3, Val::Percent(get_phase_threshold(3, 3) * 100.0)),
+        ]
+    );
+
+    app.world_mut().send_event(BossPhaseChangeEvent {
+        boss_name: boss.name.clone(),
+        phase: 2,
+        total_phases: 3,
+        position: Vec2::ZERO,
+    });
+    app.update();
+    assert!(count::<BossBarShard>(&mut app) > 0);
+    for _ in 0..30 {
+        app.update();
+    }
+    assert_eq!(count::<BossBarShard>(&mut app), 0);
+}
+
+/// A stage 6 boss in battle mid-arena, holding its fire
+fn boss_in_battle(app: &mut App) -> Entity {
+    let mut commands = app.world_mut().commands();
+    spawn_boss(
+        &mut commands,
+        &Playfield::default(),
+        6,
+        Faction::Amarr,
+        None,
+        None,
+    );
+    app.world_mut().flush();
+    let boss = app
+        .world_mut()
+        .query_filtered::<Entity, With<Boss>>()
+        .single(app.world());
+    let mut entity = app.world_mut().entity_mut(boss);
+    *entity.get_mut::<BossState>().unwrap() = BossState::Battle;
+    entity.get_mut::<Transform>().unwrap().translation = Vec3::new(0.0, 150.0, 0.0);
+    let mut attack = entity.get_mut::<BossAttack>().unwrap();
+    attack.fire_timer = 0.0;
+    attack.fire_rate = 1000.0;
+    boss
+}
+
+/// Put a stationary player shot on the boss
+fn shoot_boss(app: &mut App, boss: Entity) {
+    let at = app.world().get::<Transform>(boss).unwrap().translation;
+    app.world_mut().spawn(PlayerProjectileBundle {
+        physics: ProjectilePhysics {
+            velocity: Vec2::ZERO,
+            lifetime: 1.0,
+        },
+        transform: Transform::from_translation(at),
+        ..default()
+    });
+}
+
+#[test]
+fn boss_phase_change_shields_clears_shots_and_holds_for_the_transition() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+    let boss = boss_in_battle(&mut app);
+    app.update();
+
+    // Shots in flight from the boss and its escorts
+    let mut commands = app.world_mut().commands();
+    spawn_enemy_projectile(&mut commands, Vec2::new(200.0, 0.0), Vec2::NEG_Y, 10.0, 0.0);
+    commands.spawn((
+        BossProjectile,
+        ProjectileOwner::enemy(),
+        ProjectileDamage::default(),
+        ProjectilePhysics {
+            velocity: Vec2::ZERO,
+            lifetime: 4.0,
+        },
+        Transform::from_xyz(-200.0, 0.0, 0.0),
+    ));
+    app.world_mut().flush();
+    assert_eq!(count_shots(&mut app, Team::Enemy), 2);
+
+    // Crossing the threshold starts the transition and clears the shots
+    {
+        let mut data = app.world_mut().get_mut::<BossData>(boss).unwrap();
+        data.health = data.max_health * (get_phase_threshold(2, 3) - 0.01);
+    }
+    app.update();
+    app.update();
+    assert_eq!(
+        *app.world().get::<BossState>(boss).unwrap(),
+        BossState::PhaseTransition
+    );
+    assert_eq!(count_shots(&mut app, Team::Enemy), 0);
+
+    // Shielded: hits are swallowed without damage
+    let health = app.world().get::<BossData>(boss).unwrap().health;
+    shoot_boss(&mut app, boss);
+    app.update();
+    assert_eq!(count_shots(&mut app, Team::Player), 0);
+    assert_eq!(app.world().get::<BossData>(boss).unwrap().health, health);
+
+    // Held for the whole transition, then back to battle
+    for _ in 0..((PHASE_TRANSITION_DURATION / FRAME) as usize - 6) {
+        app.update();
+    }
+    assert_eq!(
+        *app.world().get::<BossState>(boss).unwrap(),
+        BossState::PhaseTransition
+    );
+    for _ in 0..6 {
+        app.update();
+    }
+    assert_eq!(
+        *app.world().get::<BossState>(boss).unwrap(),
+        BossState::Battle
+    );
+    assert!(app.world().get::<BossPhaseTransition>(boss).is_none());
+
+    shoot_boss(&mut app, boss);
+    app.update();
+    assert!(app.world().get::<BossData>(boss).unwrap().health < health);
+}
+
+#[test]
+fn cancelled_boss_bullets_become_chips_that_pay_out_at_the_player() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+
+    let mut commands = app.world_mut().commands();
+    for x in [-150.0, 0.0, 150.0] {
+        spawn_enemy_projectile(&mut commands, Vec2::new(x, 250.0), Vec2::NEG_Y, 10.0, 0.0);
+    }
+    app.world_mut().flush();
+    app.update();
+    assert_eq!(count_shots(&mut app, Team::Enemy), 3);
+    let score_before = app.world().resource::<ScoreSystem>().score;
+
+    // A phase ending turns every enemy bullet into a chip
+    app.world_mut().send_event(BossPhaseChangeEvent {
+        boss_name: "Test".into(),
+        phase: 2,
+        total_phases: 3,
+        position: Vec2::ZERO,
+    });
+    app.update();
+    app.update();
+    assert_eq!(count_shots(&mut app, Team::Enemy), 0);
+    assert_eq!(count::<ScoreChip>(&mut app), 3);
+
+    // They home in on the player and pay out on arrival
+    for _ in 0..(3.5 / FRAME) as usize {
+        app.update();
+    }
+    assert_eq!(count::<ScoreChip>(&mut app), 0);
+    assert!(app.world().resource::<ScoreSystem>().score > score_before);
+}
+
+#[test]
+fn battlestation_vents_are_telegraphed_and_go_quiet_with_the_boss() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+
+    let mut commands = app.world_mut().commands();
+    spawn_boss(
+        &mut commands,
+        &Playfield::default(),
+        9,
+        Faction::Amarr,
+        None,
+        None,
+    );
+    app.world_mut().flush();
+    let boss = app
+        .world_mut()
+        .query_filtered::<Entity, With<ArenaHazard>>()
+        .single(app.world());
+    {
+        let mut entity = app.world_mut().entity_mut(boss);
+        *entity.get_mut::<BossState>().unwrap() = BossState::Battle;
+        entity.get_mut::<ArenaHazard>().unwrap().timer = 0.0;
+    }
+    app.update();
+
+    let zones: Vec<HazardZone> = app
+        .world_mut()
+        .query::<&HazardZone>()
+        .iter(app.world())
+        .cloned()
+        .collect();
+    assert_eq!(zones.len(), 1);
+    assert!(matches!(zones[0].shape, HazardShape::Cone { .. }));
+    assert!(!zones[0].is_live(), "a vent warns before it burns");
+
+    *app.world_mut().get_mut::<BossState>(boss).unwrap() = BossState::Defeated;
+    app.update();
+    app.update();
+    assert_eq!(count::<HazardZone>(&mut app), 0);
+}
+
+#[test]
+fn game_over_despawns_player_hud_and_battlefield() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+
+    // Leave stray fire on screen that outlives the player
+    let mut commands = app.world_mut().commands();
+    spawn_enemy_projectile(&mut commands, Vec2::new(300.0, 300.0), Vec2::Y, 1.0, 1.0);
+    app.world_mut().flush();
+
+    // Strip the player's tank and drop a bullet on them
+    let player_pos = {
+        let world = app.world_mut();
+        let mut query = world.query_filtered::<(&Transform, &mut ShipStats), With<Player>>();
+        let (transform, mut stats) = query.single_mut(world);
+        stats.shield = 0.0;
+        stats.armor = 0.0;
+        stats.hull = 1.0;
+        transform.translation.truncate()
+    };
+    let mut commands = app.world_mut().commands();
+    spawn_enemy_projectile(&mut commands, player_pos, Vec2::NEG_Y, 1000.0, 1.0);
+    app.world_mut().flush();
+
+    run_until(&mut app, GameState::GameOver);
+    app.update();
+
+    assert_eq!(count::<Player>(&mut app), 0);
+    assert_eq!(count::<HudRoot>(&mut app), 0);
+    assert!(count::<GameOverRoot>(&mut app) > 0);
+    assert_eq!(count::<Enemy>(&mut app), 0);
+    assert_eq!(count::<ProjectileOwner>(&mut app), 0);
+}
+
+#[test]
+fn losing_the_ship_plays_the_wreck_before_the_death_screen() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+
+    let player_pos = {
+        let world = app.world_mut();
+        let mut query = world.query_filtered::<(&Transform, &mut ShipStats), With<Player>>();
+        let (transform, mut stats) = query.single_mut(world);
+        stats.shield = 0.0;
+        stats.armor = 0.0;
+        stats.hull = 1.0;
+        transform.translation.truncate()
+    };
+    let mut commands = app.world_mut().commands();
+    spawn_enemy_projectile(&mut commands, player_pos, Vec2::NEG_Y, 1000.0, 1.0);
+    app.world_mut().flush();
+    app.update();
+    app.update();
+
+    // The ship is gone but the run holds on its wreck
+    assert_eq!(state(&app), GameState::Playing);
+    assert_eq!(count::<Player>(&mut app), 0);
+    assert_eq!(count::<DeathSequence>(&mut app), 1);
+    assert_eq!(count::<EjectedCorpse>(&mut app), 1);
+    assert!(count::<Wreckage>(&mut app) > 1);
+
+    let corpse_start = player_pos.y;
+    let mut elapsed = 0.0;
+    while elapsed < DEATH_HOLD - 0.5 {
+        app.update();
+        elapsed += FRAME;
+    }
+    assert_eq!(state(&app), GameState::Playing);
+    let corpse_y = {
+        let world = app.world_mut();
+        let mut query = world.query_filtered::<&Transform, With<EjectedCorpse>>();
+        query.single(world).translation.y
+    };
+    assert!(
+        corpse_y > corpse_start,
+        "the corpse drifts clear of the wreck"
+    );
+
+    run_until(&mut app, GameState::GameOver);
+    app.update();
+    assert!(count::<GameOverRoot>(&mut app) > 0);
+    assert_eq!(count::<Wreckage>(&mut app), 0);
+    assert_eq!(count::<DeathSequence>(&mut app), 0);
+}
+
+#[test]
+fn boss_warning_sounds_then_clears_stragglers_before_the_intro() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+
+    let mut commands = app.world_mut().commands();
+    let straggler = spawn_enemy(
+        &mut commands,
+        597,
+        Vec2::new(0.0, 350.0),
+        EnemyBehavior::Linear,
+        None,
+        None,
+    );
+    app.world_mut().flush();
+    {
+        let mut campaign = app.world_mut().resource_mut::<CampaignState>();
+        let waves = campaign.enemy_waves();
+        campaign.current_wave = waves + 1;
+        campaign.waves_cleared = waves;
+    }
+    app.update();
+
+    assert!(app.world().resource::<BossWarning>().is_sounding());
+    assert_eq!(state(&app), GameState::Playing);
+    let banner_display = |app: &mut App| {
+        app.world_mut()
+            .query_filtered::<&Node, With<BossWarningBanner>>()
+            .single(app.world())
+            .display
+    };
+    assert_eq!(banner_display(&mut app), Display::Flex);
+    // The straggler is still there to be mopped up
+    assert!(app.world().get_entity(straggler).is_ok());
+
+    run_until(&mut app, GameState::BossIntro);
+    assert!(app.world().get_entity(straggler).is_err());
+    assert_eq!(*app.world().resource::<BossWarning>(), BossWarning::Over);
+}
+
+#[test]
+fn pausing_keeps_the_battlefield() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+
+    let mut commands = app.world_mut().commands();
+    spawn_enemy_projectile(&mut commands, Vec2::new(300.0, 300.0), Vec2::X, 1.0, 0.0);
+    app.world_mut().flush();
+
+    press(&mut app, KeyCode::Escape);
+    run_until(&mut app, GameState::Paused);
+    app.update();
+    assert_eq!(count_shots(&mut app, Team::Enemy), 1);
+
+    // Quitting to the menu from pause clears it
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::MainMenu);
+    run_until(&mut app, GameState::MainMenu);
+    app.update();
+    assert_eq!(count_shots(&mut app, Team::Enemy), 0);
+}
+
+#[test]
+fn turrets_track_the_fire_direction_and_shots_leave_their_muzzles() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+    app.update();
+
+    let turrets = |app: &mut App| {
+        let world = app.world_mut();
+        let (transform, hardpoints) = world
+            .query_filtered::<(&Transform, &Hardpoints), With<Player>>()
+            .single(world);
+        (*transform, hardpoints.clone())
+    };
+    let (_, hardpoints) = turrets(&mut app);
+    assert!(!hardpoints.is_empty());
+    assert_eq!(count::<TurretMount>(&mut app), hardpoints.len());
+
+    // Aim right: every turret swings round to follow
+    send_key(&mut app, KeyCode::KeyL, ButtonState::Pressed);
+    for _ in 0..30 {
+        app.update();
+    }
+    let (transform, hardpoints) = turrets(&mut app);
+    assert!(hardpoints.facing.iter().all(|facing| facing.x > 0.8));
+
+    // Shots leave from the muzzles, not the middle of the hull
+    let center = transform.translation.truncate();
+    send_key(&mut app, KeyCode::Space, ButtonState::Pressed);
+    let mut origins = Vec::new();
+    for _ in 0..20 {
+        app.update();
+        let events = app.world().resource::<Events<PlayerFireEvent>>();
+        origins.extend(events.iter_current_update_events().map(|e| e.position));
+    }
+    assert!(origins.len() > 1);
+    assert!(origins.iter().all(|origin| origin.distance(center) > 1.0));
+    // Each shot sits a barrel's length off one of the mounts
+    let barrel = hardpoints
+        .muzzle(0, center)
+        .distance(hardpoints.mount(0, center));
+    assert!(origins.iter().all(|origin| {
+        (0..hardpoints.len())
+            .any(|i| (hardpoints.mount(i, center).distance(*origin) - barrel).abs() < 0.5)
+    }));
+}
+
+#[test]
+fn menus_follow_the_pointer_and_outline_keyboard_focus() {
+    let mut app = headless_app();
+    run_until(&mut app, GameState::MainMenu);
+    for _ in 0..10 {
+        app.update();
+    }
+
+    let item = |app: &mut App, index: usize| {
+        let world = app.world_mut();
+        world
+            .query::<(Entity, &MenuItem)>()
+            .iter(world)
+            .find(|(_, item)| item.index == index)
+            .map(|(entity, _)| entity)
+            .unwrap()
+    };
+    let outline = |app: &mut App, index: usize| {
+        let entity = item(app, index);
+        app.world().get::<Outline>(entity).unwrap().color
+    };
+    let selected = |app: &App| app.world().resource::<MenuSelection>().index;
+
+    // Keyboard focus is outlined
+    press(&mut app, KeyCode::ArrowDown);
+    app.update();
+    assert_eq!(selected(&app), 1);
+    assert_ne!(outline(&mut app, 1), Color::NONE);
+    assert_eq!(outline(&mut app, 0), Color::NONE);
+
+    // Once the mouse moves, hovering selects and the outline goes
+    app.world_mut().send_event(MouseMotion { delta: Vec2::X });
+    let profile = item(&mut app, 2);
+    app.world_mut()
+        .entity_mut(profile)
+        .insert(Interaction::Hovered);
+    app.update();
+    app.update();
+    assert_eq!(selected(&app), 2);
+    assert_eq!(outline(&mut app, 2), Color::NONE);
+
+    // Clicking an item confirms it
+    let options = item(&mut app, 1);
+    app.world_mut()
+        .entity_mut(options)
+        .insert(Interaction::Pressed);
+    run_until(&mut app, GameState::Options);
+}
+
+#[test]
+fn going_berserk_wipes_nearby_shots_and_lights_the_ship_up() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+    let player_pos = app
+        .world_mut()
+        .query_filtered::<&Transform, With<Player>>()
+        .single(app.world())
+        .translation
+        .truncate();
+
+    let mut commands = app.world_mut().commands();
+    let near = player_pos + Vec2::new(0.0, BERSERK_BURST_RADIUS * 0.6);
+    let far = player_pos + Vec2::new(0.0, BERSERK_BURST_RADIUS * 1.5);
+    spawn_enemy_projectile(&mut commands, near, Vec2::NEG_Y, 5.0, 0.0);
+    spawn_enemy_projectile(&mut commands, far, Vec2::NEG_Y, 5.0, 0.0);
+    app.world_mut().flush();
+    app.update();
+    assert_eq!(count_shots(&mut app, Team::Enemy), 2);
+
+    app.world_mut().resource_mut::<BerserkSystem>().meter = 100.0;
+    press(&mut app, KeyCode::KeyB);
+    app.update();
+    assert!(app.world().resource::<BerserkSystem>().is_active);
+    assert_eq!(
+        count_shots(&mut app, Team::Enemy),
+        1,
+        "only the near shot goes"
+    );
+    assert_eq!(count::<BerserkGlow>(&mut app), 1);
+    assert_eq!(count::<BerserkTrailRestore>(&mut app), 1);
+    assert!(count::<SpeedLine>(&mut app) > 0);
+
+    // When it runs out the ship goes back to normal
+    app.world_mut().resource_mut::<BerserkSystem>().timer = 0.0;
+    for _ in 0..3 {
+        app.update();
+    }
+    assert!(!app.world().resource::<BerserkSystem>().is_active);
+    assert_eq!(count::<BerserkGlow>(&mut app), 0);
+    assert_eq!(count::<BerserkTrailRestore>(&mut app), 0);
+}
+
+#[test]
+fn destroyers_fly_with_layered_tank_and_bars() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+
+    let mut commands = app.world_mut().commands();
+    let cormorant = spawn_enemy(
+        &mut commands,
+        16238,
+        Vec2::new(0.0, 200.0),
+        EnemyBehavior::Linear,
+        None,
+        None,
+    );
+    let merlin = spawn_enemy(
+        &mut commands,
+        603,
+        Vec2::new(80.0, 200.0),
+        EnemyBehavior::Linear,
+        None,
+        None,
+    );
+    app.world_mut().flush();
+    app.update();
+
+    assert!(app.world().get::<EnemyTank>(merlin).is_none());
+    let tank = app.world().get::<EnemyTank>(cormorant).unwrap().clone();
+    assert!(tank.max_shield > tank.max_armor, "Caldari tank shields");
+    assert_eq!(count::<EnemyTankFill>(&mut app), 3);
+
+    // Damage that skips the layers (a burn, a wreck going up) still comes
+    // off the shield first
+    app.world_mut()
+        .get_mut::<EnemyStats>(cormorant)
+        .unwrap()
+        .health -= 20.0;
+    app.update();
+    let after = app.world().get::<EnemyTank>(cormorant).unwrap();
+    assert!(after.shield < tank.max_shield - 15.0);
+    assert_eq!(after.armor, tank.max_armor);
+    let health = app.world().get::<EnemyStats>(cormorant).unwrap().health;
+    assert!((health - after.total()).abs() < 0.01);
+}
+
+/// Liberation pods waiting to be collected
+fn liberation_pods(app: &mut App) -> usize {
+    app.world_mut()
+        .query::<&CollectibleData>()
+        .iter(app.world())
+        .filter(|data| data.collectible_type == CollectibleType::LiberationPod)
+        .count()
+}
+
+#[test]
+fn transports_launch_escape_pods_that_extract_or_die_to_enemy_fire() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+
+    // A Bestower goes down to a player shot and launches its pods
+    let mut commands = app.world_mut().commands();
+    let bestower = spawn_enemy(
+        &mut commands,
+        20185,
+        Vec2::new(0.0, 200.0),
+        EnemyBehavior::Linear,
+        None,
+        None,
+    );
+    app.world_mut().flush();
+    app.update();
+    app.world_mut()
+        .get_mut::<EnemyStats>(bestower)
+        .unwrap()
+        .health = 1.0;
+    let position = app.world().get::<Transform>(bestower).unwrap().translation;
+    app.world_mut().spawn(PlayerProjectileBundle {
+        transform: Transform::from_translation(position),
+        ..default()
+    });
+    app.update();
+    app.update();
+    assert!(app.world().get_entity(bestower).is_err());
+    assert!(count::<EscapePod>(&mut app) > 0);
+    // Its souls ride in the pods alone
+    assert_eq!(liberation_pods(&mut app), 0);
+
+    // Burned down, it launches them just the same
+    let mut commands = app.world_mut().commands();
+    let burning = spawn_enemy(
+        &mut commands,
+        20185,
+        Vec2::new(-150.0, 200.0),
+        EnemyBehavior::Linear,
+        None,
+        None,
+    );
+    app.world_mut().flush();
+    app.update();
+    let pods_before = count::<EscapePod>(&mut app);
+    let mut status = StatusEffects::default();
+    status.apply(StatusKind::Burning, 2.0);
+    let mut entity = app.world_mut().entity_mut(burning);
+    entity.get_mut::<EnemyStats>().unwrap().health = 0.1;
+    entity.insert(status);
+    for _ in 0..15 {
+        app.update();
+    }
+    assert!(app.world().get_entity(burning).is_err());
+    assert!(count::<EscapePod>(&mut app) > pods_before);
+    assert_eq!(liberation_pods(&mut app), 0);
+
+    // Two pods side by side: one shot can only take one of them
+    let pods: Vec<Entity> = app
+        .world_mut()
+        .query_filtered::<Entity, With<EscapePod>>()
+        .iter(app.world())
+        .collect();
+    for pod in pods {
+        app.world_mut().entity_mut(pod).despawn_recursive();
+    }
+    let at = Vec2::new(100.0, 100.0);
+    let mut commands = app.world_mut().commands();
+    spawn_escape_pods(&mut commands, at, 4, &mut fastrand::Rng::with_seed(1));
+    spawn_enemy_projectile(&mut commands, at, Vec2::NEG_Y, 30.0, 0.0);
+    app.world_mut().flush();
+    app.update();
+    assert_eq!(count::<EscapePod>(&mut app), 1);
+    assert_eq!(count_shots(&mut app, Team::Enemy), 0);
+
+    // The survivor reaches the edge and its souls come aboard
+    let souls_before = app.world().resource::<ScoreSystem>().souls_liberated;
+    let mission_before = app.world().resource::<CampaignState>().mission_souls;
+    let playfield = *app.world().resource::<Playfield>();
+    let souls = {
+        let world = app.world_mut();
+        let mut query = world.query::<(&mut Transform, &EscapePod)>();
+        let (mut transform, pod) = query.single_mut(world);
+        transform.translation.x = playfield.half_width() + 5.0;
+        pod.souls + 1
+    };
+    app.update();
+    assert_eq!(count::<EscapePod>(&mut app), 0);
+    assert_eq!(
+        app.world().resource::<ScoreSystem>().souls_liberated,
+        souls_before + souls
+    );
+    assert_eq!(
+        app.world().resource::<CampaignState>().mission_souls,
+        mission_before + souls
+    );
+}
+
+/// Enemies that appeared since `seen` was last updated, by where they appeared
+fn new_enemy_positions(app: &mut App, seen: &mut Vec<Entity>) -> Vec<Vec2> {
+    let world = app.world_mut();
+    let mut query = world.query_filtered::<(Entity, &Transform), With<Enemy>>();
+    let mut positions = Vec::new();
+    for (entity, transform) in query.iter(world) {
+        if !seen.contains(&entity) {
+            seen.push(entity);
+            positions.push(transform.translation.truncate());
+        }
+    }
+    positions
+}
+
+#[test]
+fn destroying_a_carriers_hangar_bay_stops_its_launches() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+
+    // A carrier on station with a single bay about to launch
+    let playfield = *app.world().resource::<Playfield>();
+    let carrier_pos = Vec2::new(0.0, playfield.half_height() - 130.0);
+    let bay_offset = Vec2::new(0.0, -55.0);
+    let stage = app.world().resource::<WaveManager>().current_stage;
+    *app.world_mut().resource_mut::<CapitalShipEvent>() = CapitalShipEvent {
+        active: true,
+        time_remaining: 30.0,
+        bays_remaining: 1,
+        last_stage: stage,
+    };
+    app.world_mut().spawn((
+        HostileCarrier {
+            timer: 0.0,
+            warp_progress: 1.0,
+            departing: false,
+        },
+        Transform::from_translation(carrier_pos.extend(0.0)),
+    ));
+    let bay = app
+        .world_mut()
+        .spawn((
+            HangarBay {
+                offset: bay_offset,
+                health: 5.0,
+                launch_timer: 0.1,
+            },
+            Sprite::default(),
+            Transform::from_translation((carrier_pos + bay_offset).extend(0.0)),
+        ))
+        .id();
+
+    // Fighters appear just below the bay
+    let mut seen = Vec::new();
+    new_enemy_positions(&mut app, &mut seen);
+    let launch_point = carrier_pos + bay_offset - Vec2::new(0.0, 20.0);
+    let fly = |app: &mut App, seen: &mut Vec<Entity>, seconds: f32| {
+        let mut launched = 0;
+        for _ in 0..(seconds / FRAME) as usize {
+            app.update();
+            launched += new_enemy_positions(app, seen)
+                .iter()
+                .filter(|p| p.distance(launch_point) < 30.0)
+                .count();
+        }
+        launched
+    };
+
+    // The bay launches a fighter
+    assert_eq!(fly(&mut app, &mut seen, 1.0), 1);
+    let bay_pos = app
+        .world()
+        .get::<Transform>(bay)
+        .unwrap()
+        .translation
+        .truncate();
+
+    // One shot takes it out, which ends the event
+    app.world_mut().spawn(PlayerProjectileBundle {
+        physics: ProjectilePhysics {
+            velocity: Vec2::ZERO,
+            lifetime: 1.0,
+        },
+        damage: ProjectileDamage {
+            damage: 50.0,
+            ..default()
+        },
+        transform: Transform::from_translation(bay_pos.extend(0.0)),
+        ..default()
+    });
+    app.update();
+    app.update();
+    assert!(app.world().get_entity(bay).is_err());
+    let event = app.world().resource::<CapitalShipEvent>();
+    assert_eq!(event.bays_remaining, 0);
+    assert!(!event.active);
+
+    // Well past the launch interval, nothing else comes out of the hangar
+    assert_eq!(fly(&mut app, &mut seen, 6.0), 0);
+    assert_eq!(
+        count::<HostileCarrier>(&mut app),
+        0,
+        "the carrier warps off"
+    );
+}
+
+#[test]
+fn commander_auras_buff_allies_until_the_commander_falls() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+
+    let mut commands = app.world_mut().commands();
+    let commander = spawn_enemy(
+        &mut commands,
+        597,
+        Vec2::new(0.0, 200.0),
+        EnemyBehavior::Linear,
+        None,
+        None,
+    );
+    commands.entity(commander).insert(Commander {
+        aura: AuraKind::ShieldLink,
+    });
+    let ally = spawn_enemy(
+        &mut commands,
+        597,
+        Vec2::new(60.0, 200.0),
+        EnemyBehavior::Linear,
+        None,
+        None,
+    );
+    let loner = spawn_enemy(
+        &mut commands,
+        597,
+        Vec2::new(-AURA_RADIUS * 2.0, 200.0),
+        EnemyBehavior::Linear,
+        None,
+        None,
+    );
+    app.world_mut().flush();
+    app.update();
+    app.update();
+
+    // The commander is tougher than its class, and links the ally in range
+    let stats = app.world().get::<EnemyStats>(commander).unwrap();
+    assert!(stats.max_health > app.world().get::<EnemyStats>(ally).unwrap().max_health);
+    let buff = app.world().get::<AuraBuff>(ally).expect("ally is linked");
+    assert!(buff.damage_taken_mult < 1.0);
+    assert_eq!(buff.commanders, vec![commander]);
+    assert!(app.world().get::<AuraBuff>(loner).is_none());
+    assert!(app.world().get::<AuraBuff>(commander).is_none());
+
+    // Downing it strips the link and pays for the ship it was covering
+    let before = app.world().resource::<ScoreSystem>().score;
+    let position = app
+        .world()
+        .get::<Transform>(commander)
+        .unwrap()
+        .translation
+        .truncate();
+    app.world_mut().entity_mut(commander).despawn_recursive();
+    app.world_mut().send_event(EnemyDestroyedEvent {
+        entity: commander,
+        position,
+        enemy_type: "Commander".to_string(),
+        type_id: 597,
+        score_value: 0,
+        points_awarded: 0,
+        was_boss: false,
+    });
+    app.update();
+    app.update();
+
+    assert!(app.world().get::<AuraBuff>(ally).is_none());
+    let gained = app.world().resource::<ScoreSystem>().score - before;
+    assert!(gained >= commander_bonus(1), "bonus was {}", gained);
+}
+
+#[test]
+fn objective_markers_follow_targets_and_clamp_off_screen() {
+    let mut app = headless_app();
+    start_playing(&mut app);
+
+    let playfield = *app.world().resource::<Playfield>();
+    let off_screen = Vec2::new(playfield.half_width() + 200.0, 0.0);
+    let cargo = app
+        .world_mut()
+        .spawn((
+            CargoCrate,
+            Transform::from_translation(off_screen.extend(0.0)),
+        ))
+        .id();
+    app.update();
+
+    let marker_state = |app: &mut App| {
+        let world = app.world_mut();
+        let mut markers = world.query::<(&ObjectiveMarker, &Transform)>();
+        let (marker, transform) = markers.single(world);
+        assert_eq!(marker.target, cargo);
+        let position = transform.translation.truncate();
+        let mut labels = world.query_filtered::<&Text2d, With<ObjectiveMarkerLabel>>();
+        (position, labels.single(world).0.clone())
+    };
+
+    // Off screen: pinned to the near edge with the distance
+    let (position, label) = marker_state(&mut app);
+    assert!(playfield.contains(position, 0.0));
+    assert!(position.x > playfield.half_width() - 40.0);
+    assert!(
+        label.starts_with("CARGO ") && label.ends_with('m'),
+        "{}",
+        label
+    );
+
+    // On screen: over the crate, name only
+    app.world_mut()
+        .entity_mut(cargo)
+        .insert(Transform::from_xyz(0.0, 100.0, 0.0));
+    app.update();
+    let (position, label) = marker_state(&mut app);
+    assert!(position.y > 100.0 && position.x.abs() < 1.0);
+    assert_eq!(label, "CARGO");
+
+    // Picked up: the marker goes with it
+    app.world_mut().entity_mut(cargo).despawn_recursive();
+    app.update();
+    assert_eq!(count::<ObjectiveMarker>(&mut app), 0);
+}
+
+/// Type text into whatever is reading characters
+fn type_text(app: &mut App, text: &str) {
+    for c in text.chars() {
+        for state in [ButtonState::Pressed, ButtonState::Released] {
+            app.world_mut().send_event(KeyboardInput {
+                key_code: KeyCode::Unidentified(bevy::input::keyboard::NativeKeyCode::Unidentified),
+                logical_key: Key::Character(c.to_string().into()),
+                state,
+                repeat: false,
+                window: Entity::PLACEHOLDER,
+            });
+            app.update();
+        }
+    }
+}
+
+#[test]
+fn an_entered_run_code_sets_up_the_same_run() {
+    let mut app = headless_app();
+    run_until(&mut app, GameState::MainMenu);
+    confirm_until(&mut app, GameState::StageSelect);
+    for _ in 0..30 {
+        app.update();
+    }
+
+    let session = GameSession::new(Faction::Minmatar, Faction::Minmatar.rival());
+    let code = RunCode::for_run(
+        RunMode::ScoreAttack,
+        &session,
+        Difficulty::Newbro,
+        1,
+        &[],
+        0x5eed,
+    );
+
+    // A bad code is refused and the box stays open
+    press(&mut app, KeyCode::KeyC);
+    assert!(app.world().get_resource::<RunCodeEntry>().is_some());
+    type_text(&mut app, "ZZZZ-ZZZZ-ZZZZ");
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(state(&app), GameState::StageSelect);
+    let entry = app.world().resource::<RunCodeEntry>();
+    assert!(entry.error.is_some());
+
+    // Clear it and type the real code
+    for _ in 0..RUN_CODE_LENGTH {
+        press(&mut app, KeyCode::Backspace);
+    }
+    type_text(&mut app, &code.encode().to_lowercase());
+    press(&mut app, KeyCode::Enter);
+    run_until(&mut app, GameState::ShipSelect);
+    assert!(app.world().get_resource::<RunCodeEntry>().is_none());
+    assert_eq!(*app.world().resource::<RunMode>(), RunMode::ScoreAttack);
+    assert_eq!(*app.world().resource::<Difficulty>(), Difficulty::Newbro);
+    assert_eq!(
+        app.world().resource::<RunCodes>().imported.as_ref(),
+        Some(&code)
+    );
+
+    // Flown unchanged, the run uses the code's seed and carries its code
+    confirm_until(&mut app, GameState::Playing);
+    for _ in 0..5 {
+        app.update();
+    }
+    assert_eq!(app.world().resource::<RunRng>().seed, 0x5eed);
+    assert_eq!(
+        app.world().resource::<RunCodes>().current.as_ref(),
+        Some(&code)
+    );
+}
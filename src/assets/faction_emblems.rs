@@ -0,0 +1,154 @@
+//! Faction Emblems
+//!
+//! White emblem masks, one per faction, tinted with the faction palette
+//! wherever they are shown. A bundled `assets/emblems/{faction}.png` wins;
+//! otherwise the insignia is drawn procedurally at startup.
+
+use bevy::prelude::*;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use super::powerup_icons::load_image_file;
+use crate::core::Faction;
+
+/// Side length of a generated emblem (pixels)
+pub const EMBLEM_SIZE: u32 = 64;
+
+/// Subsamples per axis when rasterizing (anti-aliasing)
+const SUPERSAMPLE: u32 = 4;
+
+/// Faction emblems plugin
+pub struct FactionEmblemsPlugin;
+
+impl Plugin for FactionEmblemsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FactionEmblemCache>()
+            .add_systems(Startup, load_faction_emblems);
+    }
+}
+
+/// Emblem mask per faction
+#[derive(Resource, Default)]
+pub struct FactionEmblemCache {
+    pub emblems: HashMap<Faction, Handle<Image>>,
+}
+
+impl FactionEmblemCache {
+    pub fn get(&self, faction: Faction) -> Option<Handle<Image>> {
+        self.emblems.get(&faction).cloned()
+    }
+}
+
+/// Load bundled emblems, drawing any that are missing
+fn load_faction_emblems(mut cache: ResMut<FactionEmblemCache>, mut images: ResMut<Assets<Image>>) {
+    let emblems_dir = std::env::current_dir()
+        .unwrap_or_else(|_| PathBuf::from("."))
+        .join("assets")
+        .join("emblems");
+
+    for &faction in Faction::all() {
+        let path = emblems_dir.join(format!("{}.png", faction.short_name().to_lowercase()));
+        let image = match load_image_file(&path) {
+            Ok(image) => {
+                info!("Loaded faction emblem: {:?}", path);
+                image
+            }
+            Err(_) => emblem_image(faction),
+        };
+        cache.emblems.insert(faction, images.add(image));
+    }
+}
+
+/// Rasterize a faction's insignia as a white mask
+pub fn emblem_image(faction: Faction) -> Image {
+    let mut data = Vec::with_capacity((EMBLEM_SIZE * EMBLEM_SIZE * 4) as usize);
+    let step = 2.0 / (EMBLEM_SIZE * SUPERSAMPLE) as f32;
+
+    for py in 0..EMBLEM_SIZE {
+        for px in 0..EMBLEM_SIZE {
+            let mut hits = 0;
+            for sy in 0..SUPERSAMPLE {
+                for sx in 0..SUPERSAMPLE {
+                    let x = -1.0 + ((px * SUPERSAMPLE + sx) as f32 + 0.5) * step;
+                    // Image rows run top to bottom
+                    let y = 1.0 - ((py * SUPERSAMPLE + sy) as f32 + 0.5) * step;
+                    if emblem_covers(faction, Vec2::new(x, y)) {
+                        hits += 1;
+                    }
+                }
+            }
+            let alpha = (hits * 255 / (SUPERSAMPLE * SUPERSAMPLE)) as u8;
+            data.extend_from_slice(&[255, 255, 255, alpha]);
+        }
+    }
+
+    Image::new(
+        Extent3d {
+            width: EMBLEM_SIZE,
+            height: EMBLEM_SIZE,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD,
+    )
+}
+
+/// Whether a point (each axis -1..1, y up) is inside the faction's insignia
+pub fn emblem_covers(faction: Faction, p: Vec2) -> bool {
+    let r = p.length();
+    match faction {
+        // Broken ring pierced by a spear
+        Faction::Minmatar => {
+            let ring = (0.62..0.84).contains(&r) && p.y.abs() > 0.16;
+            let spear = p.x.abs() < 0.1 && p.y.abs() < 0.95;
+            ring || spear
+        }
+        // Eight-rayed sun
+        Faction::Amarr => {
+            let core = r < 0.3;
+            let rays = (0.4..0.92).contains(&r) && (p.y.atan2(p.x) * 8.0).cos() > 0.55;
+            core || rays
+        }
+        // Hexagon with a horizontal bar
+        Faction::Caldari => {
+            let hex = (p.x.abs() * 0.866 + p.y.abs() * 0.5).max(p.y.abs());
+            let ring = (0.62..0.82).contains(&hex);
+            let bar = p.y.abs() < 0.1 && p.x.abs() < 0.5;
+            ring || bar
+        }
+        // Diamond around a star point
+        Faction::Gallente => {
+            let diamond = p.x.abs() + p.y.abs();
+            (0.66..0.9).contains(&diamond) || r < 0.25
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn emblems_are_distinct_white_masks() {
+        let masks: Vec<Vec<u8>> = Faction::all()
+            .iter()
+            .map(|&f| emblem_image(f).data)
+            .collect();
+
+        for (i, mask) in masks.iter().enumerate() {
+            assert_eq!(mask.len(), (EMBLEM_SIZE * EMBLEM_SIZE * 4) as usize);
+            // Pure white so the faction tint comes through unchanged
+            assert!(mask.chunks(4).all(|px| px[..3] == [255, 255, 255]));
+            // Something drawn, corners left clear
+            assert!(mask.chunks(4).any(|px| px[3] == 255));
+            assert_eq!(mask[3], 0);
+            for other in &masks[i + 1..] {
+                assert_ne!(mask, other);
+            }
+        }
+    }
+}
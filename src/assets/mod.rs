@@ -1,12 +1,17 @@
 //! Asset Management
 //!
-//! Handles loading EVE ship sprites, 3D models, and powerup icons.
+//! Handles loading EVE ship sprites, 3D models, powerup icons, faction emblems
+//! and projectile sprites.
 
+pub mod faction_emblems;
 pub mod powerup_icons;
+pub mod projectile_sprites;
 pub mod ship_models;
 pub mod ship_sprites;
 
+pub use faction_emblems::*;
 pub use powerup_icons::*;
+pub use projectile_sprites::*;
 pub use ship_models::*;
 pub use ship_sprites::*;
 
@@ -17,6 +22,12 @@ pub struct AssetsPlugin;
 
 impl Plugin for AssetsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins((ShipSpritesPlugin, ShipModelsPlugin, PowerupIconsPlugin));
+        app.add_plugins((
+            ShipSpritesPlugin,
+            ShipModelsPlugin,
+            PowerupIconsPlugin,
+            FactionEmblemsPlugin,
+            ProjectileSpritesPlugin,
+        ));
     }
 }
@@ -100,7 +100,7 @@ fn load_powerup_icons(mut cache: ResMut<PowerupIconCache>, mut images: ResMut<As
 }
 
 /// Load an image file and convert to Bevy Image
-fn load_image_file(path: &PathBuf) -> Result<Image, String> {
+pub(crate) fn load_image_file(path: &PathBuf) -> Result<Image, String> {
     let bytes = fs::read(path).map_err(|e| e.to_string())?;
 
     let img = image::load_from_memory(&bytes)
@@ -0,0 +1,90 @@
+//! Projectile Sprite Loading
+//!
+//! Bolt sprites per weapon doctrine, plus muzzle and impact flash sprites,
+//! from the assets/projectiles directory. Anything missing keeps the
+//! colored quads projectiles have always been drawn with.
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use super::powerup_icons::load_image_file;
+use crate::core::WeaponDoctrine;
+
+/// Projectile sprites plugin
+pub struct ProjectileSpritesPlugin;
+
+impl Plugin for ProjectileSpritesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ProjectileSpriteCache>()
+            .add_systems(Startup, load_projectile_sprites);
+    }
+}
+
+/// Loaded projectile sprites (white masks, tinted by the projectile color)
+#[derive(Resource, Default)]
+pub struct ProjectileSpriteCache {
+    pub bolts: HashMap<WeaponDoctrine, Handle<Image>>,
+    pub muzzle_flash: Option<Handle<Image>>,
+    pub impact_flash: Option<Handle<Image>>,
+}
+
+impl ProjectileSpriteCache {
+    /// Bolt sprite for a doctrine
+    pub fn bolt(&self, doctrine: WeaponDoctrine) -> Option<Handle<Image>> {
+        self.bolts.get(&doctrine).cloned()
+    }
+}
+
+/// Bolt sprite filename per doctrine
+fn bolt_filename(doctrine: WeaponDoctrine) -> &'static str {
+    match doctrine {
+        WeaponDoctrine::Projectile => "autocannon.png",
+        WeaponDoctrine::Laser => "laser.png",
+        WeaponDoctrine::Missile => "missile.png",
+        WeaponDoctrine::Hybrid => "hybrid.png",
+    }
+}
+
+/// Load projectile sprites from the assets directory
+fn load_projectile_sprites(
+    mut cache: ResMut<ProjectileSpriteCache>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    let assets_dir = std::env::current_dir()
+        .unwrap_or_else(|_| PathBuf::from("."))
+        .join("assets")
+        .join("projectiles");
+
+    if !assets_dir.exists() {
+        info!(
+            "No projectile sprites at {:?}, using colored quads",
+            assets_dir
+        );
+        return;
+    }
+
+    let mut load = |filename: &str| match load_image_file(&assets_dir.join(filename)) {
+        Ok(image) => {
+            info!("Loaded projectile sprite: {}", filename);
+            Some(images.add(image))
+        }
+        Err(e) => {
+            warn!("Projectile sprite {} unavailable: {}", filename, e);
+            None
+        }
+    };
+
+    for doctrine in [
+        WeaponDoctrine::Projectile,
+        WeaponDoctrine::Laser,
+        WeaponDoctrine::Missile,
+        WeaponDoctrine::Hybrid,
+    ] {
+        if let Some(handle) = load(bolt_filename(doctrine)) {
+            cache.bolts.insert(doctrine, handle);
+        }
+    }
+    cache.muzzle_flash = load("muzzle_flash.png");
+    cache.impact_flash = load("impact_flash.png");
+}
@@ -2,11 +2,17 @@
 //!
 //! Loads ship sprites from bundled assets, with fallback to CCP's Image Server.
 //! Priority: assets/ships/{type_id}.png -> cache -> download
+//!
+//! Once loaded, the ships the current faction pair can field are packed into
+//! one atlas texture on a background task, so a wave of mixed hulls draws
+//! from a single image instead of binding one texture per ship.
 
 #![allow(dead_code)]
 
 use bevy::prelude::*;
 use bevy::render::render_asset::RenderAssetUsages;
+use bevy::sprite::TextureAtlasBuilder;
+use bevy::tasks::{block_on, futures_lite::future, AsyncComputeTaskPool, Task};
 use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
@@ -22,18 +28,29 @@ const RENDER_SIZE: u32 = 128;
 /// Bundled assets directory
 const BUNDLED_SHIPS_DIR: &str = "assets/ships";
 
+/// Largest atlas we'll pack before giving up and drawing ships separately
+const ATLAS_MAX_SIZE: u32 = 4096;
+
+/// Transparent gap around each packed ship so filtering doesn't bleed
+const ATLAS_PADDING: u32 = 2;
+
 /// Ship sprites plugin
 pub struct ShipSpritesPlugin;
 
 impl Plugin for ShipSpritesPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<ShipSpriteCache>()
+            .init_resource::<ShipAtlasJob>()
             .add_systems(Startup, setup_sprite_cache)
             .add_systems(OnEnter(GameState::Loading), start_loading_sprites)
-            .add_systems(
-                Update,
-                check_sprite_loading.run_if(in_state(GameState::Loading)),
-            );
+            .add_systems(Update, (start_atlas_packing, finish_atlas_packing).chain());
+
+        // Web builds only load bundled sprites, all on entering Loading
+        #[cfg(not(target_arch = "wasm32"))]
+        app.add_systems(
+            Update,
+            check_sprite_loading.run_if(in_state(GameState::Loading)),
+        );
     }
 }
 
@@ -42,6 +59,10 @@ impl Plugin for ShipSpritesPlugin {
 pub struct ShipSpriteCache {
     /// Map of type_id -> texture handle
     pub sprites: HashMap<u32, Handle<Image>>,
+    /// CPU-side copies of each sprite, kept for packing atlases
+    pub pixels: HashMap<u32, Image>,
+    /// Packed atlas for the current faction pair
+    pub atlas: Option<ShipAtlas>,
     /// Ships currently being loaded
     pub loading: Vec<u32>,
     /// Whether initial load is complete
@@ -51,9 +72,185 @@ pub struct ShipSpriteCache {
 }
 
 impl ShipSpriteCache {
-    /// Get sprite for a ship type, returns None if not loaded
-    pub fn get(&self, type_id: u32) -> Option<Handle<Image>> {
-        self.sprites.get(&type_id).cloned()
+    /// Get sprite for a ship type, returns None if not loaded.
+    /// Ships in the packed atlas come back as a region of it; anything
+    /// else falls back to its own texture.
+    pub fn get(&self, type_id: u32) -> Option<ShipSprite> {
+        if let Some(atlas) = &self.atlas {
+            if let Some(&index) = atlas.indices.get(&type_id) {
+                return Some(ShipSprite {
+                    image: atlas.image.clone(),
+                    texture_atlas: Some(TextureAtlas {
+                        layout: atlas.layout.clone(),
+                        index,
+                    }),
+                });
+            }
+        }
+        self.sprites.get(&type_id).cloned().map(|image| ShipSprite {
+            image,
+            texture_atlas: None,
+        })
+    }
+
+    /// Store a freshly decoded sprite
+    fn insert(&mut self, type_id: u32, image: Image, images: &mut Assets<Image>) {
+        self.pixels.insert(type_id, image.clone());
+        self.sprites.insert(type_id, images.add(image));
+    }
+}
+
+/// Where to draw a ship from: its own texture, or a region of the atlas
+#[derive(Debug, Clone)]
+pub struct ShipSprite {
+    pub image: Handle<Image>,
+    pub texture_atlas: Option<TextureAtlas>,
+}
+
+impl ShipSprite {
+    /// A sprite showing this ship at its texture's size
+    pub fn sprite(self) -> Sprite {
+        Sprite {
+            image: self.image,
+            texture_atlas: self.texture_atlas,
+            ..default()
+        }
+    }
+}
+
+/// Ship sprites packed into one texture
+pub struct ShipAtlas {
+    pub image: Handle<Image>,
+    pub layout: Handle<TextureAtlasLayout>,
+    /// Map of type_id -> region index in the layout
+    pub indices: HashMap<u32, usize>,
+}
+
+/// A packed atlas waiting to be turned into assets
+pub struct PackedShips {
+    pub layout: TextureAtlasLayout,
+    pub image: Image,
+    pub indices: HashMap<u32, usize>,
+}
+
+/// Background atlas packing for the current faction pair
+#[derive(Resource, Default)]
+pub struct ShipAtlasJob {
+    /// Faction pair last packed (or being packed)
+    pub pair: Option<(Faction, Faction)>,
+    task: Option<Task<Result<PackedShips, String>>>,
+}
+
+impl ShipAtlasJob {
+    /// Whether the atlas for `session` is done, or packing it has failed
+    pub fn settled(&self, session: &GameSession) -> bool {
+        self.task.is_none() && self.pair == Some(session_pair(session))
+    }
+}
+
+fn session_pair(session: &GameSession) -> (Faction, Faction) {
+    (session.player_faction, session.enemy_faction)
+}
+
+/// Every ship the faction pair can put on screen: the player's hulls
+/// (also flown by wingmen), the enemy line-up and the enemy carrier
+pub fn faction_pair_type_ids(session: &GameSession) -> Vec<u32> {
+    let mut type_ids: Vec<u32> = session
+        .player_ships()
+        .iter()
+        .map(|ship| ship.type_id)
+        .chain(session.enemy_ships().iter().map(|ship| ship.type_id))
+        .chain(std::iter::once(session.enemy_faction.carrier_type_id()))
+        .collect();
+    type_ids.sort_unstable();
+    type_ids.dedup();
+    type_ids
+}
+
+/// Pack ship images into one atlas, indexed by type_id
+pub fn pack_ship_atlas(ships: &[(u32, Image)]) -> Result<PackedShips, String> {
+    if ships.is_empty() {
+        return Err("no ships to pack".to_string());
+    }
+    let mut builder = TextureAtlasBuilder::default();
+    builder
+        .max_size(UVec2::splat(ATLAS_MAX_SIZE))
+        .padding(UVec2::splat(ATLAS_PADDING));
+    for (_, image) in ships {
+        builder.add_texture(None, image);
+    }
+    let (layout, _, mut image) = builder.build().map_err(|e| e.to_string())?;
+    // The GPU copy is all we draw from
+    image.asset_usage = RenderAssetUsages::RENDER_WORLD;
+
+    // Layout indices follow insertion order
+    let indices = ships
+        .iter()
+        .enumerate()
+        .map(|(index, (type_id, _))| (*type_id, index))
+        .collect();
+    Ok(PackedShips {
+        layout,
+        image,
+        indices,
+    })
+}
+
+/// Kick off packing once sprites are in and whenever the faction pair changes
+fn start_atlas_packing(
+    cache: Res<ShipSpriteCache>,
+    session: Res<GameSession>,
+    mut job: ResMut<ShipAtlasJob>,
+) {
+    let pair = session_pair(&session);
+    if !cache.ready || job.pair == Some(pair) {
+        return;
+    }
+
+    let ships: Vec<(u32, Image)> = faction_pair_type_ids(&session)
+        .into_iter()
+        .filter_map(|id| cache.pixels.get(&id).map(|image| (id, image.clone())))
+        .collect();
+    info!("Packing {} ship sprites into an atlas", ships.len());
+    job.pair = Some(pair);
+    // A newer pair replaces any job still running for an older one
+    job.task = Some(AsyncComputeTaskPool::get().spawn(async move { pack_ship_atlas(&ships) }));
+}
+
+/// Swap in the atlas when the background job finishes
+fn finish_atlas_packing(
+    mut cache: ResMut<ShipSpriteCache>,
+    mut job: ResMut<ShipAtlasJob>,
+    mut images: ResMut<Assets<Image>>,
+    mut layouts: ResMut<Assets<TextureAtlasLayout>>,
+) {
+    let Some(task) = job.task.as_mut() else {
+        return;
+    };
+    let Some(result) = block_on(future::poll_once(task)) else {
+        return;
+    };
+    job.task = None;
+
+    match result {
+        Ok(packed) => {
+            info!(
+                "Ship atlas packed: {} ships in {}x{}",
+                packed.indices.len(),
+                packed.image.width(),
+                packed.image.height()
+            );
+            cache.atlas = Some(ShipAtlas {
+                image: images.add(packed.image),
+                layout: layouts.add(packed.layout),
+                indices: packed.indices,
+            });
+        }
+        Err(e) => {
+            // Separate textures still work, just with more binds
+            warn!("Failed to pack ship atlas: {}", e);
+            cache.atlas = None;
+        }
     }
 }
 
@@ -148,8 +345,7 @@ fn start_loading_sprites(mut cache: ResMut<ShipSpriteCache>, mut images: ResMut<
         if bundled_path.exists() {
             match load_image_file(&bundled_path) {
                 Ok(image) => {
-                    let handle = images.add(image);
-                    cache.sprites.insert(type_id, handle);
+                    cache.insert(type_id, image, &mut images);
                     loaded_bundled += 1;
                     continue;
                 }
@@ -164,8 +360,7 @@ fn start_loading_sprites(mut cache: ResMut<ShipSpriteCache>, mut images: ResMut<
         if cache_path.exists() {
             match load_image_file(&cache_path) {
                 Ok(image) => {
-                    let handle = images.add(image);
-                    cache.sprites.insert(type_id, handle);
+                    cache.insert(type_id, image, &mut images);
                     loaded_cached += 1;
                     continue;
                 }
@@ -211,8 +406,7 @@ fn start_loading_sprites(mut cache: ResMut<ShipSpriteCache>, mut images: ResMut<
         if bundled_path.exists() {
             match load_image_file(&bundled_path) {
                 Ok(image) => {
-                    let handle = images.add(image);
-                    cache.sprites.insert(type_id, handle);
+                    cache.insert(type_id, image, &mut images);
                     loaded += 1;
                 }
                 Err(e) => {
@@ -351,16 +545,18 @@ fn download_image(
     Ok(())
 }
 
-/// Check if sprites are loaded and transition state (native - handles downloads)
+/// Pick up finished sprite downloads (native). The loading screen watches
+/// `ready` and the atlas job to decide when to move on.
 #[cfg(not(target_arch = "wasm32"))]
 fn check_sprite_loading(
     mut cache: ResMut<ShipSpriteCache>,
     mut images: ResMut<Assets<Image>>,
     time: Res<Time>,
     mut timer: Local<f32>,
-    mut next_state: ResMut<NextState<GameState>>,
+    mut waited: Local<f32>,
 ) {
     *timer += time.delta_secs();
+    *waited += time.delta_secs();
 
     // Check every 0.5 seconds
     if *timer < 0.5 {
@@ -369,7 +565,6 @@ fn check_sprite_loading(
     *timer = 0.0;
 
     if cache.ready {
-        next_state.set(GameState::MainMenu);
         return;
     }
 
@@ -381,8 +576,7 @@ fn check_sprite_loading(
             // Load downloaded image (needs background removal)
             match load_downloaded_image(&cache_path) {
                 Ok(image) => {
-                    let handle = images.add(image);
-                    cache.sprites.insert(type_id, handle);
+                    cache.insert(type_id, image, &mut images);
                     info!("Loaded downloaded sprite for type {}", type_id);
                 }
                 Err(e) => {
@@ -402,20 +596,12 @@ fn check_sprite_loading(
     }
 
     // Timeout after 10 seconds - proceed anyway
-    if *timer > 10.0 && !cache.ready {
+    if *waited > 10.0 && !cache.ready {
         warn!("Sprite loading timeout, proceeding without all sprites");
         cache.ready = true;
     }
 }
 
-/// Check if sprites are loaded and transition state (WASM - immediate)
-#[cfg(target_arch = "wasm32")]
-fn check_sprite_loading(cache: Res<ShipSpriteCache>, mut next_state: ResMut<NextState<GameState>>) {
-    if cache.ready {
-        next_state.set(GameState::MainMenu);
-    }
-}
-
 /// Helper to get cache dir (native only)
 #[cfg(not(target_arch = "wasm32"))]
 pub fn get_sprite_cache_dir() -> PathBuf {
@@ -430,3 +616,51 @@ pub fn get_sprite_cache_dir() -> PathBuf {
 pub fn get_sprite_cache_dir() -> PathBuf {
     PathBuf::new()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+    fn solid(size: u32, rgba: [u8; 4]) -> Image {
+        Image::new_fill(
+            Extent3d {
+                width: size,
+                height: size,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            &rgba,
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::MAIN_WORLD,
+        )
+    }
+
+    #[test]
+    fn packed_ships_keep_their_own_regions() {
+        let ships = vec![
+            (587, solid(16, [255, 0, 0, 255])),
+            (589, solid(8, [0, 0, 255, 255])),
+        ];
+        let packed = pack_ship_atlas(&ships).unwrap();
+        assert_eq!(packed.layout.len(), 2);
+
+        for (type_id, size, rgba) in [(587, 16, [255, 0, 0, 255]), (589, 8, [0, 0, 255, 255])] {
+            let rect = packed.layout.textures[packed.indices[&type_id]];
+            assert_eq!(rect.size(), UVec2::splat(size));
+            let offset = ((rect.min.y * packed.image.width() + rect.min.x) * 4) as usize;
+            assert_eq!(packed.image.data[offset..offset + 4], rgba);
+        }
+    }
+
+    #[test]
+    fn faction_pair_ships_include_the_enemy_carrier_once() {
+        let session = GameSession::new(Faction::Minmatar, Faction::Amarr);
+        let type_ids = faction_pair_type_ids(&session);
+        assert!(type_ids.contains(&Faction::Amarr.carrier_type_id()));
+        assert!(type_ids.contains(&session.selected_ship().type_id));
+        let mut unique = type_ids.clone();
+        unique.dedup();
+        assert_eq!(unique.len(), type_ids.len());
+    }
+}
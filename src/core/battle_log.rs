@@ -0,0 +1,284 @@
+//! Battle Log
+//!
+//! The pilot-facing combat feed: hits taken (with damage, type and who
+//! fired), kills, ability activations and boss phase changes, newest last.
+//! Damage taken is also totalled by source for the current mission, for
+//! the breakdown on the mission complete screen. The feed is a ring buffer
+//! that goes into crash dumps and can be copied from the debug event log.
+
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+use std::collections::VecDeque;
+
+use super::campaign::MissionStartEvent;
+use super::events::*;
+use crate::systems::{AbilityActivatedEvent, BossPhaseChangeEvent};
+
+/// Entries kept in the feed (oldest dropped first)
+pub const BATTLE_LOG_CAPACITY: usize = 120;
+
+/// Battle log plugin
+pub struct BattleLogPlugin;
+
+impl Plugin for BattleLogPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BattleLog>()
+            .add_systems(Update, (reset_mission_breakdown, log_battle_events).chain());
+    }
+}
+
+/// Kind of feed entry (for colouring)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BattleEntryKind {
+    /// Damage taken
+    Hit,
+    Kill,
+    Ability,
+    Boss,
+}
+
+impl BattleEntryKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            BattleEntryKind::Hit => "HIT",
+            BattleEntryKind::Kill => "KILL",
+            BattleEntryKind::Ability => "MODULE",
+            BattleEntryKind::Boss => "BOSS",
+        }
+    }
+}
+
+/// A single feed entry
+#[derive(Debug, Clone)]
+pub struct BattleEntry {
+    /// Seconds since app start
+    pub time: f32,
+    pub kind: BattleEntryKind,
+    pub message: String,
+}
+
+/// Damage taken from one source this mission
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DamageTally {
+    pub source: String,
+    pub hits: u32,
+    /// Damage absorbed by each tank layer
+    pub layers: DamageLayers,
+}
+
+impl DamageTally {
+    pub fn total(&self) -> f32 {
+        self.layers.shield + self.layers.armor + self.layers.hull
+    }
+}
+
+/// Combat feed and this mission's damage breakdown
+#[derive(Resource, Default)]
+pub struct BattleLog {
+    entries: VecDeque<BattleEntry>,
+    breakdown: Vec<DamageTally>,
+}
+
+impl BattleLog {
+    /// Add a feed entry, dropping the oldest once full
+    pub fn push(&mut self, time: f32, kind: BattleEntryKind, message: impl Into<String>) {
+        if self.entries.len() >= BATTLE_LOG_CAPACITY {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(BattleEntry {
+            time,
+            kind,
+            message: message.into(),
+        });
+    }
+
+    /// Log a hit on the player and add it to its source's tally
+    pub fn record_hit(&mut self, time: f32, event: &PlayerDamagedEvent) {
+        self.push(
+            time,
+            BattleEntryKind::Hit,
+            format!(
+                "{:.0} {} from {}",
+                event.damage,
+                event.damage_type.label(),
+                event.source
+            ),
+        );
+
+        let tally = match self.breakdown.iter().position(|t| t.source == event.source) {
+            Some(index) => &mut self.breakdown[index],
+            None => {
+                let index = self.breakdown.len();
+                self.breakdown.push(DamageTally {
+                    source: event.source.clone(),
+                    ..default()
+                });
+                &mut self.breakdown[index]
+            }
+        };
+        tally.hits += 1;
+        tally.layers.shield += event.layers.shield;
+        tally.layers.armor += event.layers.armor;
+        tally.layers.hull += event.layers.hull;
+    }
+
+    /// Entries, oldest first
+    pub fn entries(&self) -> impl DoubleEndedIterator<Item = &BattleEntry> {
+        self.entries.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// This mission's damage taken by source, heaviest first
+    pub fn breakdown(&self) -> Vec<&DamageTally> {
+        let mut tallies: Vec<&DamageTally> = self.breakdown.iter().collect();
+        tallies.sort_by(|a, b| b.total().total_cmp(&a.total()));
+        tallies
+    }
+
+    /// All damage taken this mission
+    pub fn damage_taken(&self) -> f32 {
+        self.breakdown.iter().map(DamageTally::total).sum()
+    }
+
+    /// Start a new mission's breakdown; the feed carries on
+    pub fn reset_breakdown(&mut self) {
+        self.breakdown.clear();
+    }
+
+    /// Plain-text dump for bug reports: the feed, then the breakdown
+    pub fn to_report(&self) -> String {
+        let mut report: String = self
+            .entries
+            .iter()
+            .map(|e| format!("[{:>8.2}] {:<6} {}\n", e.time, e.kind.label(), e.message))
+            .collect();
+        for tally in self.breakdown() {
+            report.push_str(&format!(
+                "DAMAGE {:<24} {:>7.0} in {} hits (S {:.0} / A {:.0} / H {:.0})\n",
+                tally.source,
+                tally.total(),
+                tally.hits,
+                tally.layers.shield,
+                tally.layers.armor,
+                tally.layers.hull
+            ));
+        }
+        report
+    }
+}
+
+// =============================================================================
+// RECORDING
+// =============================================================================
+
+fn reset_mission_breakdown(mut events: EventReader<MissionStartEvent>, mut log: ResMut<BattleLog>) {
+    if events.read().last().is_some() {
+        log.reset_breakdown();
+    }
+}
+
+fn log_battle_events(
+    time: Res<Time>,
+    mut log: ResMut<BattleLog>,
+    mut damage: EventReader<PlayerDamagedEvent>,
+    mut kills: EventReader<EnemyDestroyedEvent>,
+    mut abilities: EventReader<AbilityActivatedEvent>,
+    mut phases: EventReader<BossPhaseChangeEvent>,
+) {
+    let now = time.elapsed_secs();
+
+    for event in damage.read() {
+        log.record_hit(now, event);
+    }
+    for event in kills.read() {
+        log.push(
+            now,
+            BattleEntryKind::Kill,
+            format!("{} destroyed (+{})", event.enemy_type, event.points_awarded),
+        );
+    }
+    for event in abilities.read() {
+        log.push(
+            now,
+            BattleEntryKind::Ability,
+            format!("{} activated", event.ability_type.name()),
+        );
+    }
+    for event in phases.read() {
+        log.push(
+            now,
+            BattleEntryKind::Boss,
+            format!(
+                "{} enters phase {}/{}",
+                event.boss_name, event.phase, event.total_phases
+            ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hit(source: &str, shield: f32, hull: f32) -> PlayerDamagedEvent {
+        PlayerDamagedEvent {
+            damage: shield + hull,
+            damage_type: DamageType::Kinetic,
+            source_position: Vec2::ZERO,
+            source: source.to_string(),
+            layers: DamageLayers {
+                shield,
+                armor: 0.0,
+                hull,
+            },
+        }
+    }
+
+    #[test]
+    fn hits_are_tallied_by_source_heaviest_first() {
+        let mut log = BattleLog::default();
+        log.record_hit(1.0, &hit("Punisher", 20.0, 0.0));
+        log.record_hit(2.0, &hit("Coercer", 30.0, 10.0));
+        log.record_hit(3.0, &hit("Punisher", 5.0, 0.0));
+
+        let breakdown = log.breakdown();
+        assert_eq!(breakdown[0].source, "Coercer");
+        assert_eq!(breakdown[1].hits, 2);
+        assert_eq!(breakdown[1].total(), 25.0);
+        assert_eq!(log.damage_taken(), 65.0);
+        assert_eq!(
+            log.entries().last().map(|e| e.message.as_str()),
+            Some("5 Kinetic from Punisher")
+        );
+
+        log.reset_breakdown();
+        assert!(log.breakdown().is_empty());
+        assert_eq!(log.len(), 3);
+    }
+
+    #[test]
+    fn feed_drops_oldest_and_reports_the_breakdown() {
+        let mut log = BattleLog::default();
+        for i in 0..(BATTLE_LOG_CAPACITY + 5) {
+            log.push(i as f32, BattleEntryKind::Kill, format!("Kill {}", i));
+        }
+        assert_eq!(log.len(), BATTLE_LOG_CAPACITY);
+        assert_eq!(
+            log.entries().next().map(|e| e.message.as_str()),
+            Some("Kill 5")
+        );
+
+        log.record_hit(200.0, &hit("Wreck detonation", 0.0, 12.0));
+        let report = log.to_report();
+        assert_eq!(report.lines().count(), BATTLE_LOG_CAPACITY + 1);
+        assert!(report.contains("DAMAGE Wreck detonation"));
+    }
+}
@@ -5,59 +5,15 @@
 #![allow(dead_code)]
 
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-/// Campaign acts - progression through the story
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
-pub enum Act {
-    #[default]
-    Act1, // "The Call" - Rifter, proving yourself
-    Act2, // "The Storm" - Wolf, full assault
-    Act3, // "Liberation" - Jaguar, final push
-}
+use super::interlude::CampaignFlags;
+use super::lighting::LightingProfile;
+use super::objectives::{ObjectiveDef, ObjectiveKind, ObjectiveTier};
+use super::spawn_budget::{PacingCurve, DEFAULT_WAVE_BUDGET};
+use crate::core_logic::Act;
 
 impl Act {
-    pub fn name(&self) -> &'static str {
-        match self {
-            Act::Act1 => "THE CALL",
-            Act::Act2 => "THE STORM",
-            Act::Act3 => "LIBERATION",
-        }
-    }
-
-    pub fn catchphrase(&self) -> &'static str {
-        match self {
-            Act::Act1 => "In Rust We Trust!",
-            Act::Act2 => "No more rust - just steel!",
-            Act::Act3 => "For Freedom! For the Republic!",
-        }
-    }
-
-    pub fn description(&self) -> &'static str {
-        match self {
-            Act::Act1 => {
-                "Prove yourself worthy in a rust-bucket Rifter. Early raids on slave convoys."
-            }
-            Act::Act2 => "The invasion begins in earnest. You've earned an assault frigate.",
-            Act::Act3 => "The final push. Strike at the heart of the Empire.",
-        }
-    }
-
-    pub fn number(&self) -> u32 {
-        match self {
-            Act::Act1 => 1,
-            Act::Act2 => 2,
-            Act::Act3 => 3,
-        }
-    }
-
-    pub fn next(&self) -> Option<Act> {
-        match self {
-            Act::Act1 => Some(Act::Act2),
-            Act::Act2 => Some(Act::Act3),
-            Act::Act3 => None,
-        }
-    }
-
     pub fn missions(&self) -> &'static [Mission] {
         match self {
             Act::Act1 => &ACT1_MISSIONS,
@@ -75,11 +31,142 @@ pub struct Mission {
     pub description: &'static str,
     pub primary_objective: &'static str,
     pub bonus_objective: Option<&'static str>,
+    /// How the bonus objective is tracked (`None` if it can't be measured yet)
+    pub bonus_kind: Option<ObjectiveKind>,
+    /// Extra primary objective (escort, defend, courier) on top of waves and boss
+    pub mission_objective: Option<(&'static str, ObjectiveKind)>,
     pub boss: BossType,
+    /// Seconds the boss warning sounds before the intro; stragglers left
+    /// when it ends warp out
+    pub boss_warning: f32,
     pub enemy_waves: u32,
+    /// What ends each wave, in order; waves past the end need every enemy dead
+    pub wave_triggers: &'static [WaveTrigger],
+    /// Friendly fleets that join the fight at story beats
+    pub ally_fleet: &'static [FleetCameo],
     pub souls_to_liberate: u32,
 }
 
+/// Allied Minmatar ships that warp in as a wave spawns, fight alongside
+/// the player for a while, then warp out again (always before the boss)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FleetCameo {
+    /// Wave whose spawn brings the fleet in
+    pub wave: u32,
+    /// Ships in the fleet, left to right
+    pub ships: &'static [u32],
+    /// Seconds on the field before warping out
+    pub duration: f32,
+    /// Who speaks for the fleet on comms
+    pub commander: &'static str,
+    pub arrival: &'static str,
+    pub departure: &'static str,
+}
+
+/// What moves a mission on from one wave to the next. Clearing the field
+/// always counts, whatever the trigger.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum WaveTrigger {
+    /// Every enemy on the field destroyed
+    #[default]
+    KillAll,
+    /// This fraction of the wave destroyed (0.0 - 1.0); stragglers stay
+    KillPercent(f32),
+    /// Hold out for this many seconds
+    Survive(f32),
+    /// Destroy the wave's marked ship; its escorts can be left behind
+    DestroyTarget,
+}
+
+/// How the running wave is going, for checking its trigger
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct WaveProgress {
+    /// Enemies the wave spawned with
+    pub spawned: u32,
+    /// Of those, how many are gone
+    pub destroyed: u32,
+    /// Seconds since the wave spawned
+    pub elapsed: f32,
+    /// No enemies left anywhere on the field
+    pub field_clear: bool,
+    /// The wave's marked ship is still flying
+    pub target_alive: bool,
+}
+
+impl WaveTrigger {
+    pub fn is_met(&self, progress: &WaveProgress) -> bool {
+        if progress.field_clear {
+            return true;
+        }
+        match *self {
+            WaveTrigger::KillAll => false,
+            WaveTrigger::KillPercent(_) => {
+                progress.destroyed >= self.kills_needed(progress.spawned).max(1)
+            }
+            WaveTrigger::Survive(seconds) => progress.elapsed >= seconds,
+            WaveTrigger::DestroyTarget => !progress.target_alive,
+        }
+    }
+
+    /// Kills that end a `KillPercent` wave of `spawned` enemies
+    pub fn kills_needed(&self, spawned: u32) -> u32 {
+        match *self {
+            WaveTrigger::KillPercent(fraction) => {
+                (spawned as f32 * fraction.clamp(0.0, 1.0)).ceil() as u32
+            }
+            _ => spawned,
+        }
+    }
+
+    /// Whether the wave spawns a marked ship
+    pub fn marks_target(&self) -> bool {
+        matches!(self, WaveTrigger::DestroyTarget)
+    }
+}
+
+/// Data overrides for a mission, loaded from `assets/missions/<id>.ron`
+/// in dev builds. Any field left out keeps the compiled-in value.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MissionOverride {
+    pub enemy_waves: Option<u32>,
+    pub souls_to_liberate: Option<u32>,
+    pub primary_objective: Option<String>,
+    pub bonus_objective: Option<String>,
+    /// Spawn budget of the first wave, in points
+    pub wave_budget: Option<u32>,
+    /// How the budget rises and falls across the waves
+    pub pacing: Option<PacingCurve>,
+    /// Replaces the per-wave advancement triggers
+    pub wave_triggers: Option<Vec<WaveTrigger>>,
+    /// Replaces the generated objective list entirely
+    pub objectives: Option<Vec<ObjectiveDef>>,
+    /// Replaces the key light and ambient fill
+    pub lighting: Option<LightingProfile>,
+    /// Seconds of boss warning (0 goes straight to the intro)
+    pub boss_warning: Option<f32>,
+    /// Tuning for the mission's boss
+    pub boss: Option<BossOverride>,
+}
+
+impl MissionOverride {
+    /// Parse from RON text
+    pub fn from_ron(text: &str) -> Result<Self, String> {
+        ron::from_str(text).map_err(|e| e.to_string())
+    }
+}
+
+/// Boss data overrides; any field left out keeps the compiled-in value
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BossOverride {
+    pub health: Option<f32>,
+    pub phases: Option<u32>,
+    /// Health fraction (0.0 - 1.0) below which the boss enrages
+    pub enrage_threshold: Option<f32>,
+    pub score_value: Option<u64>,
+}
+
 /// Boss types for each mission
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum BossType {
@@ -179,6 +266,9 @@ impl BossType {
     }
 }
 
+/// Kills needed for the hidden "Wrecking crew" objective
+const HIDDEN_KILL_TARGET: u32 = 50;
+
 // Act 1 Missions - "The Call"
 const ACT1_MISSIONS: [Mission; 4] = [
     Mission {
@@ -187,8 +277,13 @@ const ACT1_MISSIONS: [Mission; 4] = [
         description: "Intercept a slave transport in the Arzad corridor.",
         primary_objective: "Destroy the slave transport",
         bonus_objective: Some("Liberate 10+ slaves"),
+        bonus_kind: Some(ObjectiveKind::LiberateSouls(10)),
+        mission_objective: None,
         boss: BossType::TransportOverseer,
+        boss_warning: 2.5,
         enemy_waves: 3,
+        wave_triggers: &[],
+        ally_fleet: &[],
         souls_to_liberate: 10,
     },
     Mission {
@@ -197,8 +292,13 @@ const ACT1_MISSIONS: [Mission; 4] = [
         description: "Amarr patrols hunt our scouts. Turn the tables.",
         primary_objective: "Destroy all patrol ships",
         bonus_objective: Some("No damage taken"),
+        bonus_kind: Some(ObjectiveKind::NoDamage),
+        mission_objective: None,
         boss: BossType::PatrolCommander,
+        boss_warning: 3.0,
         enemy_waves: 4,
+        wave_triggers: &[],
+        ally_fleet: &[],
         souls_to_liberate: 5,
     },
     Mission {
@@ -207,8 +307,23 @@ const ACT1_MISSIONS: [Mission; 4] = [
         description: "Disable orbital station defenses for extraction teams.",
         primary_objective: "Destroy defense turrets",
         bonus_objective: Some("Liberate 30+ slaves"),
+        bonus_kind: Some(ObjectiveKind::LiberateSouls(30)),
+        mission_objective: Some((
+            "Cover the extraction transport",
+            ObjectiveKind::Escort {
+                health: 400.0,
+                duration: 75.0,
+            },
+        )),
         boss: BossType::StationBattery,
+        boss_warning: 3.0,
         enemy_waves: 5,
+        wave_triggers: &[
+            WaveTrigger::KillAll,
+            WaveTrigger::KillPercent(0.75),
+            WaveTrigger::Survive(25.0),
+        ],
+        ally_fleet: &[],
         souls_to_liberate: 30,
     },
     Mission {
@@ -217,8 +332,18 @@ const ACT1_MISSIONS: [Mission; 4] = [
         description: "A slave lord flees with his 'property.' End his escape.",
         primary_objective: "Destroy the Holder's escort",
         bonus_objective: Some("Complete in under 3 minutes"),
+        bonus_kind: Some(ObjectiveKind::TimeLimit(180.0)),
+        mission_objective: None,
         boss: BossType::HolderEscort,
+        boss_warning: 3.0,
         enemy_waves: 4,
+        wave_triggers: &[
+            WaveTrigger::KillAll,
+            WaveTrigger::DestroyTarget,
+            WaveTrigger::KillAll,
+            WaveTrigger::DestroyTarget,
+        ],
+        ally_fleet: &[],
         souls_to_liberate: 20,
     },
 ];
@@ -231,8 +356,13 @@ const ACT2_MISSIONS: [Mission; 5] = [
         description: "Imperial Customs bleeds our supply lines. Remove them.",
         primary_objective: "Destroy the Customs station",
         bonus_objective: Some("Destroy all cargo pods"),
+        bonus_kind: None,
+        mission_objective: Some(("Recover supply crates", ObjectiveKind::Courier(3))),
         boss: BossType::CustomsCommandant,
+        boss_warning: 3.0,
         enemy_waves: 5,
+        wave_triggers: &[],
+        ally_fleet: &[],
         souls_to_liberate: 15,
     },
     Mission {
@@ -241,8 +371,25 @@ const ACT2_MISSIONS: [Mission; 5] = [
         description: "The Inquisition sends a vessel to 'cleanse' liberated systems.",
         primary_objective: "Destroy the Inquisitor vessel",
         bonus_objective: Some("No allied losses"),
+        bonus_kind: Some(ObjectiveKind::NoPodsLost),
+        mission_objective: Some((
+            "Hold the liberated relay",
+            ObjectiveKind::Defend {
+                health: 800.0,
+                duration: 120.0,
+            },
+        )),
         boss: BossType::InquisitorVessel,
+        boss_warning: 4.0,
         enemy_waves: 6,
+        wave_triggers: &[
+            WaveTrigger::KillAll,
+            WaveTrigger::Survive(30.0),
+            WaveTrigger::Survive(30.0),
+            WaveTrigger::KillAll,
+            WaveTrigger::KillPercent(0.8),
+        ],
+        ally_fleet: &[],
         souls_to_liberate: 25,
     },
     Mission {
@@ -251,8 +398,25 @@ const ACT2_MISSIONS: [Mission; 5] = [
         description: "A Navy Harbinger strike group threatens our liberation fleet.",
         primary_objective: "Destroy the strike lead",
         bonus_objective: Some("Destroy all escorts first"),
+        bonus_kind: None,
+        mission_objective: None,
         boss: BossType::HarbingerStrike,
+        boss_warning: 4.0,
         enemy_waves: 6,
+        wave_triggers: &[
+            WaveTrigger::KillPercent(0.7),
+            WaveTrigger::KillPercent(0.7),
+            WaveTrigger::DestroyTarget,
+        ],
+        ally_fleet: &[FleetCameo {
+            wave: 3,
+            ships: &[587, 598, 587],
+            duration: 25.0,
+            commander: "Captain Irek Vaar",
+            arrival:
+                "Liberation fleet, screening element. We'll hold their flank - you break the line.",
+            departure: "Transports are clear. Screen pulling back - the strike lead is yours.",
+        }],
         souls_to_liberate: 20,
     },
     Mission {
@@ -261,8 +425,19 @@ const ACT2_MISSIONS: [Mission; 5] = [
         description: "The stargate to Arzad Prime is heavily fortified.",
         primary_objective: "Disable the gate defenses",
         bonus_objective: Some("Under 4 minutes"),
+        bonus_kind: Some(ObjectiveKind::TimeLimit(240.0)),
+        mission_objective: Some((
+            "Seize the gate control relay",
+            ObjectiveKind::Capture {
+                radius: 110.0,
+                duration: 30.0,
+            },
+        )),
         boss: BossType::StargateDefense,
+        boss_warning: 4.0,
         enemy_waves: 7,
+        wave_triggers: &[WaveTrigger::KillPercent(0.6); 7],
+        ally_fleet: &[],
         souls_to_liberate: 30,
     },
     Mission {
@@ -271,8 +446,13 @@ const ACT2_MISSIONS: [Mission; 5] = [
         description: "An Amarr battlestation guards the slave processing hub.",
         primary_objective: "Destroy the battlestation core",
         bonus_objective: Some("Liberate 50+ slaves"),
+        bonus_kind: Some(ObjectiveKind::LiberateSouls(50)),
+        mission_objective: None,
         boss: BossType::BattlestationCore,
+        boss_warning: 5.0,
         enemy_waves: 8,
+        wave_triggers: &[],
+        ally_fleet: &[],
         souls_to_liberate: 50,
     },
 ];
@@ -285,8 +465,18 @@ const ACT3_MISSIONS: [Mission; 4] = [
         description: "The Amarr Navy deploys Abaddon battleships to stop our advance.",
         primary_objective: "Destroy the Abaddon flagship",
         bonus_objective: Some("No damage taken in phase 1"),
+        bonus_kind: None,
+        mission_objective: None,
         boss: BossType::AbaddonBattleship,
+        boss_warning: 5.0,
         enemy_waves: 8,
+        wave_triggers: &[
+            WaveTrigger::KillAll,
+            WaveTrigger::DestroyTarget,
+            WaveTrigger::KillAll,
+            WaveTrigger::DestroyTarget,
+        ],
+        ally_fleet: &[],
         souls_to_liberate: 40,
     },
     Mission {
@@ -295,8 +485,21 @@ const ACT3_MISSIONS: [Mission; 4] = [
         description: "The Avatar titan's escort fleet blocks the approach.",
         primary_objective: "Clear the escort fleet",
         bonus_objective: Some("Destroy all in one chain"),
+        bonus_kind: None,
+        mission_objective: None,
         boss: BossType::TitanEscort,
+        boss_warning: 6.0,
         enemy_waves: 9,
+        wave_triggers: &[],
+        ally_fleet: &[FleetCameo {
+            wave: 5,
+            ships: &[11371, 587, 585, 587, 11371],
+            duration: 30.0,
+            commander: "Commander Sela Drann",
+            arrival: "Wolf pack on grid! Thin that escort screen and we'll reach the titan yet.",
+            departure:
+                "Capacitors are dry - we're warping out. Keep their guns on you, not the fleet.",
+        }],
         souls_to_liberate: 50,
     },
     Mission {
@@ -305,8 +508,13 @@ const ACT3_MISSIONS: [Mission; 4] = [
         description: "The Empress's personal champion challenges you.",
         primary_objective: "Defeat the champion",
         bonus_objective: Some("Perfect no-damage victory"),
+        bonus_kind: Some(ObjectiveKind::NoDamage),
+        mission_objective: None,
         boss: BossType::EmpressChampion,
+        boss_warning: 4.0,
         enemy_waves: 7,
+        wave_triggers: &[],
+        ally_fleet: &[],
         souls_to_liberate: 30,
     },
     Mission {
@@ -315,8 +523,36 @@ const ACT3_MISSIONS: [Mission; 4] = [
         description: "The Avatar titan. The symbol of Amarr oppression. End it.",
         primary_objective: "Destroy the Avatar",
         bonus_objective: Some("Complete the liberation"),
+        bonus_kind: Some(ObjectiveKind::LiberateSouls(100)),
+        mission_objective: None,
         boss: BossType::AvatarTitan,
+        boss_warning: 8.0,
         enemy_waves: 10,
+        wave_triggers: &[
+            WaveTrigger::KillAll,
+            WaveTrigger::KillAll,
+            WaveTrigger::KillPercent(0.75),
+            WaveTrigger::Survive(30.0),
+            WaveTrigger::DestroyTarget,
+        ],
+        ally_fleet: &[
+            FleetCameo {
+                wave: 3,
+                ships: &[11400, 11371, 598, 11371, 11400],
+                duration: 35.0,
+                commander: "Commander Sela Drann",
+                arrival: "The whole Republic is watching, pilot. Every ship that can fly is here!",
+                departure: "We've opened the way. Falling back to cover the transports.",
+            },
+            FleetCameo {
+                wave: 7,
+                ships: &[585, 587, 11400, 11400, 587, 585],
+                duration: 40.0,
+                commander: "Captain Irek Vaar",
+                arrival: "Last push! All wings, on the pilot - break them before the titan wakes!",
+                departure: "Fleet's falling back. The Avatar is yours - finish it.",
+            },
+        ],
         souls_to_liberate: 100,
     },
 ];
@@ -334,6 +570,14 @@ pub struct CampaignState {
     pub current_wave: u32,
     /// Enemies remaining in current wave
     pub enemies_remaining: u32,
+    /// Waves whose trigger has been met
+    pub waves_cleared: u32,
+    /// Enemies the latest wave spawned with
+    pub wave_spawned: u32,
+    /// Of those, how many are gone
+    pub wave_destroyed: u32,
+    /// Seconds since the latest wave spawned
+    pub wave_timer: f32,
     /// Boss spawned flag
     pub boss_spawned: bool,
     /// Boss defeated flag
@@ -348,6 +592,10 @@ pub struct CampaignState {
     pub primary_complete: bool,
     /// Bonus objective complete
     pub bonus_complete: bool,
+    /// Dev override for the current mission's data
+    pub mission_override: Option<MissionOverride>,
+    /// Choices carried from act to act
+    pub flags: CampaignFlags,
 }
 
 impl Default for CampaignState {
@@ -358,6 +606,10 @@ impl Default for CampaignState {
             in_mission: false,
             current_wave: 0,
             enemies_remaining: 0,
+            waves_cleared: 0,
+            wave_spawned: 0,
+            wave_destroyed: 0,
+            wave_timer: 0.0,
             boss_spawned: false,
             boss_defeated: false,
             mission_timer: 0.0,
@@ -365,6 +617,8 @@ impl Default for CampaignState {
             no_damage_taken: true,
             primary_complete: false,
             bonus_complete: false,
+            mission_override: None,
+            flags: CampaignFlags::default(),
         }
     }
 }
@@ -381,6 +635,10 @@ impl CampaignState {
         self.in_mission = true;
         self.current_wave = 1;
         self.enemies_remaining = 0;
+        self.waves_cleared = 0;
+        self.wave_spawned = 0;
+        self.wave_destroyed = 0;
+        self.wave_timer = 0.0;
         self.boss_spawned = false;
         self.boss_defeated = false;
         self.mission_timer = 0.0;
@@ -388,6 +646,7 @@ impl CampaignState {
         self.no_damage_taken = true;
         self.primary_complete = false;
         self.bonus_complete = false;
+        self.mission_override = None;
     }
 
     /// Complete current mission and advance
@@ -412,10 +671,162 @@ impl CampaignState {
         }
     }
 
+    /// Enemy waves before the boss (honors dev overrides)
+    pub fn enemy_waves(&self) -> u32 {
+        self.mission_override
+            .as_ref()
+            .and_then(|o| o.enemy_waves)
+            .or_else(|| self.current_mission().map(|m| m.enemy_waves))
+            .unwrap_or(0)
+    }
+
+    /// Souls needed for the bonus objective (honors dev overrides)
+    pub fn souls_to_liberate(&self) -> u32 {
+        self.mission_override
+            .as_ref()
+            .and_then(|o| o.souls_to_liberate)
+            .or_else(|| self.current_mission().map(|m| m.souls_to_liberate))
+            .unwrap_or(0)
+    }
+
+    /// Primary objective text (honors dev overrides)
+    pub fn primary_objective(&self) -> &str {
+        self.mission_override
+            .as_ref()
+            .and_then(|o| o.primary_objective.as_deref())
+            .or_else(|| self.current_mission().map(|m| m.primary_objective))
+            .unwrap_or("")
+    }
+
+    /// Bonus objective text (honors dev overrides)
+    pub fn bonus_objective(&self) -> Option<&str> {
+        self.mission_override
+            .as_ref()
+            .and_then(|o| o.bonus_objective.as_deref())
+            .or_else(|| self.current_mission().and_then(|m| m.bonus_objective))
+    }
+
+    /// Objectives for the current mission (honors dev overrides)
+    pub fn objectives(&self) -> Vec<ObjectiveDef> {
+        if let Some(objectives) = self
+            .mission_override
+            .as_ref()
+            .and_then(|o| o.objectives.clone())
+        {
+            return objectives;
+        }
+        let Some(mission) = self.current_mission() else {
+            return Vec::new();
+        };
+
+        let mut objectives = vec![
+            ObjectiveDef::new(
+                ObjectiveTier::Primary,
+                "Waves cleared",
+                ObjectiveKind::ClearWaves(self.enemy_waves()),
+            ),
+            ObjectiveDef::new(
+                ObjectiveTier::Primary,
+                self.primary_objective(),
+                ObjectiveKind::DefeatBoss,
+            ),
+        ];
+        if let Some((label, kind)) = mission.mission_objective {
+            objectives.push(ObjectiveDef::new(ObjectiveTier::Primary, label, kind));
+        }
+        if let (Some(label), Some(kind)) = (self.bonus_objective(), mission.bonus_kind) {
+            let kind = match kind {
+                ObjectiveKind::LiberateSouls(_) => {
+                    ObjectiveKind::LiberateSouls(self.souls_to_liberate())
+                }
+                other => other,
+            };
+            objectives.push(ObjectiveDef::new(ObjectiveTier::Bonus, label, kind));
+        }
+        objectives.push(ObjectiveDef::new(
+            ObjectiveTier::Hidden,
+            "Wrecking crew",
+            ObjectiveKind::DestroyEnemies {
+                count: HIDDEN_KILL_TARGET,
+                type_id: None,
+            },
+        ));
+        objectives
+    }
+
+    /// Spawn budget of the first wave (honors dev overrides)
+    pub fn wave_budget(&self) -> u32 {
+        self.mission_override
+            .as_ref()
+            .and_then(|o| o.wave_budget)
+            .unwrap_or(DEFAULT_WAVE_BUDGET)
+    }
+
+    /// Pacing of the wave budgets (honors dev overrides)
+    pub fn wave_pacing(&self) -> PacingCurve {
+        self.mission_override
+            .as_ref()
+            .and_then(|o| o.pacing)
+            .unwrap_or_default()
+    }
+
+    /// Seconds of boss warning before the intro (honors dev overrides)
+    pub fn boss_warning(&self) -> f32 {
+        self.mission_override
+            .as_ref()
+            .and_then(|o| o.boss_warning)
+            .or_else(|| self.current_mission().map(|m| m.boss_warning))
+            .unwrap_or(0.0)
+            .max(0.0)
+    }
+
+    /// Boss tuning for the current mission (dev overrides only)
+    pub fn boss_override(&self) -> BossOverride {
+        self.mission_override
+            .as_ref()
+            .and_then(|o| o.boss)
+            .unwrap_or_default()
+    }
+
+    /// What ends wave `wave` (1-indexed; honors dev overrides)
+    pub fn wave_trigger(&self, wave: u32) -> WaveTrigger {
+        let index = wave.saturating_sub(1) as usize;
+        let trigger = match self
+            .mission_override
+            .as_ref()
+            .and_then(|o| o.wave_triggers.as_ref())
+        {
+            Some(triggers) => triggers.get(index).copied(),
+            None => self
+                .current_mission()
+                .and_then(|m| m.wave_triggers.get(index).copied()),
+        };
+        trigger.unwrap_or_default()
+    }
+
+    /// Friendly fleet that arrives with `wave` of the current mission
+    pub fn fleet_cameo(&self, wave: u32) -> Option<&'static FleetCameo> {
+        self.current_mission()?
+            .ally_fleet
+            .iter()
+            .find(|cameo| cameo.wave == wave)
+    }
+
+    /// The latest wave to spawn (`current_wave` moves past a wave as soon
+    /// as it spawns), or 0 before the first
+    pub fn spawned_wave(&self) -> u32 {
+        self.current_wave.saturating_sub(1)
+    }
+
+    /// The latest wave is still waiting on its trigger
+    pub fn wave_in_progress(&self) -> bool {
+        self.waves_cleared < self.spawned_wave()
+    }
+
     /// Check if current wave is the boss wave
     pub fn is_boss_wave(&self) -> bool {
-        if let Some(mission) = self.current_mission() {
-            self.current_wave > mission.enemy_waves
+        if self.current_mission().is_some() {
+            self.current_wave > self.enemy_waves()
         } else {
             false
         }
@@ -423,8 +834,8 @@ impl CampaignState {
 
     /// Advance to next wave
     pub fn next_wave(&mut self) -> bool {
-        if let Some(mission) = self.current_mission() {
-            if self.current_wave <= mission.enemy_waves {
+        if self.current_mission().is_some() {
+            if self.current_wave <= self.enemy_waves() {
                 self.current_wave += 1;
                 true
             } else {
@@ -471,6 +882,43 @@ impl CampaignState {
     }
 }
 
+/// A campaign run saved after a mission, picked up again from the main
+/// menu after a quit or a crash
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(default)]
+pub struct CampaignCheckpoint {
+    pub player_faction: String,
+    pub enemy_faction: String,
+    pub difficulty: String,
+    /// Index into the faction's ship list
+    pub ship_index: usize,
+    /// Index into `SHIP_SKINS`
+    pub skin_index: usize,
+    /// Act number (1-3) of the mission to fly next
+    pub act: u32,
+    pub mission_index: usize,
+    pub mission_name: String,
+    pub score: u64,
+    pub souls_liberated: u32,
+    /// Wing pilots still flying with the player
+    pub wingmen: Vec<usize>,
+    /// Boons and other choices made at interludes
+    pub flags: CampaignFlags,
+}
+
+impl CampaignCheckpoint {
+    /// Campaign state at the start of the checkpoint's mission
+    pub fn campaign(&self) -> CampaignState {
+        let act = Act::from_number(self.act);
+        CampaignState {
+            act,
+            mission_index: self.mission_index.min(act.missions().len() - 1),
+            flags: self.flags.clone(),
+            ..default()
+        }
+    }
+}
+
 /// Mission events
 #[derive(Event)]
 pub struct MissionStartEvent {
@@ -508,39 +956,6 @@ mod tests {
 
     // ==================== Act Tests ====================
 
-    #[test]
-    fn act_default_is_act1() {
-        assert_eq!(Act::default(), Act::Act1);
-    }
-
-    #[test]
-    fn act_names() {
-        assert_eq!(Act::Act1.name(), "THE CALL");
-        assert_eq!(Act::Act2.name(), "THE STORM");
-        assert_eq!(Act::Act3.name(), "LIBERATION");
-    }
-
-    #[test]
-    fn act_catchphrases() {
-        assert_eq!(Act::Act1.catchphrase(), "In Rust We Trust!");
-        assert_eq!(Act::Act2.catchphrase(), "No more rust - just steel!");
-        assert_eq!(Act::Act3.catchphrase(), "For Freedom! For the Republic!");
-    }
-
-    #[test]
-    fn act_numbers() {
-        assert_eq!(Act::Act1.number(), 1);
-        assert_eq!(Act::Act2.number(), 2);
-        assert_eq!(Act::Act3.number(), 3);
-    }
-
-    #[test]
-    fn act_progression() {
-        assert_eq!(Act::Act1.next(), Some(Act::Act2));
-        assert_eq!(Act::Act2.next(), Some(Act::Act3));
-        assert_eq!(Act::Act3.next(), None); // Final act
-    }
-
     #[test]
     fn act_missions_not_empty() {
         assert!(!Act::Act1.missions().is_empty());
@@ -555,6 +970,27 @@ mod tests {
         assert_eq!(Act::Act3.missions().len(), 4);
     }
 
+    #[test]
+    fn checkpoint_resumes_at_its_mission() {
+        let checkpoint = CampaignCheckpoint {
+            act: 2,
+            mission_index: 3,
+            ..default()
+        };
+        let campaign = checkpoint.campaign();
+        assert_eq!(campaign.act, Act::Act2);
+        assert_eq!(campaign.mission_index, 3);
+        assert!(!campaign.in_mission);
+
+        // A mission list that's since got shorter can't strand the run
+        let stale = CampaignCheckpoint {
+            act: 3,
+            mission_index: 9,
+            ..default()
+        };
+        assert!(stale.campaign().current_mission().is_some());
+    }
+
     // ==================== BossType Tests ====================
 
     #[test]
@@ -843,6 +1279,31 @@ mod tests {
 
     // ==================== Mission Data Integrity ====================
 
+    #[test]
+    fn ally_fleets_arrive_during_the_waves_and_join_the_final_push() {
+        for act in [Act::Act1, Act::Act2, Act::Act3] {
+            for mission in act.missions() {
+                for cameo in mission.ally_fleet {
+                    assert!(
+                        (1..=mission.enemy_waves).contains(&cameo.wave),
+                        "{}'s fleet arrives outside its waves",
+                        mission.name
+                    );
+                    assert!(!cameo.ships.is_empty() && cameo.duration > 0.0);
+                }
+            }
+        }
+
+        let mut state = CampaignState {
+            act: Act::Act3,
+            mission_index: 3, // The Avatar
+            ..Default::default()
+        };
+        state.start_mission();
+        assert!(state.fleet_cameo(1).is_none());
+        assert!(state.fleet_cameo(7).is_some());
+    }
+
     #[test]
     fn mission_ids_are_unique() {
         let mut ids = std::collections::HashSet::new();
@@ -879,4 +1340,204 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn mission_override_replaces_wave_count_and_objective() {
+        let mut state = CampaignState::default();
+        state.start_mission();
+        let default_waves = state.enemy_waves();
+
+        state.mission_override = Some(
+            MissionOverride::from_ron(r#"(enemy_waves: Some(1), primary_objective: Some("Test"))"#)
+                .unwrap_or_default(),
+        );
+        assert_eq!(state.enemy_waves(), 1);
+        assert_eq!(state.primary_objective(), "Test");
+        // Untouched fields keep the compiled-in values
+        assert_eq!(
+            state.souls_to_liberate(),
+            state
+                .current_mission()
+                .map(|m| m.souls_to_liberate)
+                .unwrap_or(0)
+        );
+
+        state.start_mission();
+        assert_eq!(state.enemy_waves(), default_waves);
+    }
+
+    #[test]
+    fn boss_override_retunes_the_boss_keeping_its_health_fraction() {
+        let mut state = CampaignState::default();
+        state.start_mission();
+        assert_eq!(state.boss_override(), BossOverride::default());
+
+        state.mission_override = Some(
+            MissionOverride::from_ron("(boss: Some((health: Some(2000.0), phases: Some(3))))")
+                .unwrap(),
+        );
+        let mut data = crate::entities::get_boss_for_stage(1).unwrap();
+        let enrage = data.enrage_threshold;
+        data.health = data.max_health * 0.5;
+        data.apply_override(&state.boss_override());
+        assert_eq!(data.max_health, 2000.0);
+        assert_eq!(data.health, 1000.0);
+        assert_eq!(data.total_phases, 3);
+        assert_eq!(data.enrage_threshold, enrage);
+    }
+
+    #[test]
+    fn mission_override_rejects_bad_ron() {
+        assert!(MissionOverride::from_ron("(enemy_waves: \"lots\")").is_err());
+        assert_eq!(
+            MissionOverride::from_ron("()"),
+            Ok(MissionOverride::default())
+        );
+    }
+
+    #[test]
+    fn objectives_come_from_mission_data_or_override() {
+        let mut state = CampaignState::default();
+        state.start_mission();
+
+        let objectives = state.objectives();
+        let kinds: Vec<_> = objectives.iter().map(|o| (o.tier, o.kind)).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (ObjectiveTier::Primary, ObjectiveKind::ClearWaves(3)),
+                (ObjectiveTier::Primary, ObjectiveKind::DefeatBoss),
+                (ObjectiveTier::Bonus, ObjectiveKind::LiberateSouls(10)),
+                (
+                    ObjectiveTier::Hidden,
+                    ObjectiveKind::DestroyEnemies {
+                        count: HIDDEN_KILL_TARGET,
+                        type_id: None
+                    }
+                ),
+            ]
+        );
+
+        state.mission_override = Some(
+            MissionOverride::from_ron(
+                r#"(objectives: Some([
+                    (tier: Primary, label: "Turrets destroyed",
+                     kind: DestroyEnemies(count: 5, type_id: Some(42))),
+                    (tier: Bonus, label: "Quick", kind: TimeLimit(90.0)),
+                ]))"#,
+            )
+            .unwrap_or_default(),
+        );
+        let objectives = state.objectives();
+        assert_eq!(objectives.len(), 2);
+        assert_eq!(objectives[0].label, "Turrets destroyed");
+        assert_eq!(
+            objectives[0].kind,
+            ObjectiveKind::DestroyEnemies {
+                count: 5,
+                type_id: Some(42)
+            }
+        );
+    }
+
+    // ==================== Wave Triggers ====================
+
+    #[test]
+    fn wave_triggers_each_wait_on_their_own_condition() {
+        let running = WaveProgress {
+            spawned: 8,
+            destroyed: 5,
+            elapsed: 10.0,
+            field_clear: false,
+            target_alive: true,
+        };
+        assert!(!WaveTrigger::KillAll.is_met(&running));
+        assert!(!WaveTrigger::KillPercent(0.75).is_met(&running));
+        assert!(WaveTrigger::KillPercent(0.6).is_met(&running));
+        assert!(!WaveTrigger::Survive(20.0).is_met(&running));
+        assert!(WaveTrigger::Survive(10.0).is_met(&running));
+        assert!(!WaveTrigger::DestroyTarget.is_met(&running));
+        assert!(WaveTrigger::DestroyTarget.is_met(&WaveProgress {
+            target_alive: false,
+            ..running
+        }));
+
+        // Clearing the field ends any wave
+        let clear = WaveProgress {
+            field_clear: true,
+            ..running
+        };
+        for trigger in [
+            WaveTrigger::KillAll,
+            WaveTrigger::KillPercent(1.0),
+            WaveTrigger::Survive(60.0),
+            WaveTrigger::DestroyTarget,
+        ] {
+            assert!(trigger.is_met(&clear));
+        }
+        assert_eq!(WaveTrigger::KillPercent(0.75).kills_needed(8), 6);
+        assert_eq!(WaveTrigger::KillPercent(0.6).kills_needed(5), 3);
+    }
+
+    #[test]
+    fn wave_triggers_come_from_mission_data_or_override() {
+        let mut state = CampaignState {
+            mission_index: 3, // The Holder's Flight
+            ..default()
+        };
+        state.start_mission();
+        assert_eq!(state.wave_trigger(1), WaveTrigger::KillAll);
+        assert_eq!(state.wave_trigger(2), WaveTrigger::DestroyTarget);
+        // Waves past the declared list need every enemy dead
+        assert_eq!(state.wave_trigger(9), WaveTrigger::KillAll);
+
+        state.mission_override = Some(
+            MissionOverride::from_ron(r#"(wave_triggers: Some([Survive(15.0)]))"#)
+                .unwrap_or_default(),
+        );
+        assert_eq!(state.wave_trigger(1), WaveTrigger::Survive(15.0));
+        assert_eq!(state.wave_trigger(2), WaveTrigger::KillAll);
+    }
+
+    #[test]
+    fn bigger_bosses_get_a_longer_warning_unless_overridden() {
+        let mut state = CampaignState::default();
+        let first = state.boss_warning();
+        assert!(first > 0.0);
+        state.act = Act::Act3;
+        state.mission_index = 3; // The Avatar
+        assert!(state.boss_warning() > first);
+
+        state.mission_override =
+            Some(MissionOverride::from_ron("(boss_warning: Some(0.0))").unwrap_or_default());
+        assert_eq!(state.boss_warning(), 0.0);
+    }
+
+    #[test]
+    fn declared_wave_triggers_fit_their_missions() {
+        for act in [Act::Act1, Act::Act2, Act::Act3] {
+            for mission in act.missions() {
+                assert!(
+                    mission.wave_triggers.len() <= mission.enemy_waves as usize,
+                    "Mission {} declares triggers for waves it doesn't have",
+                    mission.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn a_wave_is_in_progress_until_its_trigger_clears_it() {
+        let mut state = CampaignState::default();
+        state.start_mission();
+        assert_eq!(state.spawned_wave(), 0);
+        assert!(!state.wave_in_progress());
+
+        state.current_wave += 1; // Wave 1 spawned
+        assert_eq!(state.spawned_wave(), 1);
+        assert!(state.wave_in_progress());
+
+        state.waves_cleared = 1;
+        assert!(!state.wave_in_progress());
+    }
 }
@@ -0,0 +1,179 @@
+//! State Cleanup
+//!
+//! Entities carry a `StateScope` saying which part of the game they belong
+//! to and are despawned as soon as the state leaves it. Gameplay markers
+//! (`Enemy`, projectiles, pickups, ...) require the component, so every spawn
+//! is tagged automatically. Debug builds also audit each menu state for
+//! gameplay entities that slipped through.
+
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+
+use super::game_state::GameState;
+
+/// State cleanup plugin
+pub struct StateCleanupPlugin;
+
+impl Plugin for StateCleanupPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            despawn_out_of_scope
+                .before(super::GameSet::Input)
+                .run_if(state_changed::<GameState>),
+        );
+
+        #[cfg(debug_assertions)]
+        app.add_systems(
+            Update,
+            audit_lingering_entities
+                .after(despawn_out_of_scope)
+                .run_if(state_changed::<GameState>),
+        );
+    }
+}
+
+/// Part of the game an entity lives in
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StateScope {
+    /// A run in progress: despawned on reaching a menu or results screen,
+    /// kept through pause and boss intros
+    #[default]
+    Gameplay,
+    /// Despawned when leaving this exact state
+    Only(GameState),
+}
+
+impl StateScope {
+    pub fn contains(&self, state: GameState) -> bool {
+        match self {
+            StateScope::Gameplay => state.is_gameplay(),
+            StateScope::Only(owner) => *owner == state,
+        }
+    }
+}
+
+/// Despawn everything whose scope doesn't include the new state
+fn despawn_out_of_scope(
+    mut commands: Commands,
+    state: Res<State<GameState>>,
+    query: Query<(Entity, &StateScope)>,
+) {
+    let current = *state.get();
+    for (entity, scope) in query.iter() {
+        if !scope.contains(current) {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+// =============================================================================
+// DEBUG AUDIT
+// =============================================================================
+
+/// Human-readable summary of gameplay entities found in a menu state,
+/// or `None` if everything was cleaned up
+pub fn lingering_report(state: GameState, counts: &[(&str, usize)]) -> Option<String> {
+    let found: Vec<String> = counts
+        .iter()
+        .filter(|(_, count)| *count > 0)
+        .map(|(kind, count)| format!("{} {}", count, kind))
+        .collect();
+    (!found.is_empty()).then(|| {
+        format!(
+            "Gameplay entities survived into {:?}: {}",
+            state,
+            found.join(", ")
+        )
+    })
+}
+
+#[cfg(debug_assertions)]
+fn audit_lingering_entities(
+    state: Res<State<GameState>>,
+    ships: Query<
+        (),
+        Or<(
+            With<crate::entities::Player>,
+            With<crate::entities::Enemy>,
+            With<crate::entities::Boss>,
+            With<crate::entities::Wingman>,
+            With<crate::entities::Drone>,
+            With<crate::entities::ProtectedTarget>,
+            With<crate::entities::CaptureStructure>,
+        )>,
+    >,
+    projectiles: Query<(), With<crate::entities::ProjectileOwner>>,
+    pickups: Query<
+        (),
+        Or<(
+            With<crate::entities::Collectible>,
+            With<crate::entities::EscapePod>,
+            With<crate::entities::CargoCrate>,
+            With<crate::systems::SupplyCache>,
+            With<crate::systems::CacheOffer>,
+        )>,
+    >,
+    particles: Query<
+        (),
+        Or<(
+            With<crate::systems::ExplosionParticle>,
+            With<crate::systems::EngineParticle>,
+            With<crate::systems::BulletTrailParticle>,
+            With<crate::systems::ProjectileFlash>,
+            With<crate::systems::AbilityEffectParticle>,
+            With<crate::systems::DamageNumber>,
+            With<crate::systems::ScorePopup>,
+            With<crate::systems::ShieldBubble>,
+            With<crate::systems::VolatileWreck>,
+        )>,
+    >,
+) {
+    let current = *state.get();
+    if current.is_gameplay() {
+        return;
+    }
+
+    let counts = [
+        ("ships", ships.iter().count()),
+        ("projectiles", projectiles.iter().count()),
+        ("pickups", pickups.iter().count()),
+        ("particles", particles.iter().count()),
+    ];
+    if let Some(report) = lingering_report(current, &counts) {
+        warn!("{}", report);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gameplay_scope_survives_pause_but_not_menus() {
+        let scope = StateScope::default();
+        assert!(scope.contains(GameState::Playing));
+        assert!(scope.contains(GameState::Paused));
+        assert!(scope.contains(GameState::BossFight));
+        assert!(!scope.contains(GameState::GameOver));
+        assert!(!scope.contains(GameState::MainMenu));
+
+        let menu = StateScope::Only(GameState::MainMenu);
+        assert!(menu.contains(GameState::MainMenu));
+        assert!(!menu.contains(GameState::Options));
+    }
+
+    #[test]
+    fn lingering_report_lists_only_survivors() {
+        assert_eq!(
+            lingering_report(GameState::MainMenu, &[("ships", 0), ("projectiles", 0)]),
+            None
+        );
+        let report = lingering_report(GameState::GameOver, &[("ships", 2), ("particles", 0)]);
+        assert_eq!(
+            report.as_deref(),
+            Some("Gameplay entities survived into GameOver: 2 ships")
+        );
+    }
+}
@@ -0,0 +1,768 @@
+//! Save Cloud Sync
+//!
+//! Storage for the save blob sits behind `SaveBackend`: the local file,
+//! a Steam Cloud stub, or a WebDAV share. `save.json` stays the working
+//! copy; a remote backend is pulled at startup and pushed from menus.
+//! Backends block, so each sync runs on the IO task pool and lands a few
+//! frames later; an unreachable share never stalls a frame.
+//! Each save is stamped with when it was written and how many runs it
+//! holds, and a remote copy that is newer by one measure but behind on the
+//! other is flagged as a conflict for the player to settle on the profile
+//! screen instead of being overwritten.
+//!
+//! The backend is picked in `cloud.ron` next to the save file, so
+//! credentials never ride along in the synced blob and each machine keeps
+//! its own choice. The WebDAV password is stored there in plain text; set
+//! `EVE_REBELLION_WEBDAV_PASSWORD` instead to keep it out of the file.
+//! Native only - web builds keep saves in localStorage.
+
+#![allow(dead_code)]
+
+use super::daily::unix_now;
+use super::save::SaveData;
+use super::GameState;
+use bevy::prelude::*;
+use bevy::tasks::{block_on, futures_lite::future, IoTaskPool, Task};
+use serde::{Deserialize, Serialize};
+#[cfg(not(target_arch = "wasm32"))]
+use std::fs;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Fewest seconds between automatic pushes
+const MIN_PUSH_INTERVAL: f32 = 30.0;
+
+/// WebDAV request timeout (seconds)
+const WEBDAV_TIMEOUT: u64 = 5;
+
+/// Environment variable that overrides `webdav_password` in `cloud.ron`
+const WEBDAV_PASSWORD_ENV: &str = "EVE_REBELLION_WEBDAV_PASSWORD";
+
+/// Cloud sync plugin
+pub struct CloudSyncPlugin;
+
+impl Plugin for CloudSyncPlugin {
+    fn build(&self, app: &mut App) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            app.add_systems(Startup, init_cloud_sync)
+                .add_systems(PostStartup, pull_on_startup)
+                .add_systems(
+                    Update,
+                    (
+                        mark_save_dirty.run_if(resource_changed::<SaveData>),
+                        finish_cloud_sync,
+                        run_cloud_sync,
+                    )
+                        .chain()
+                        .run_if(resource_exists::<CloudSync>),
+                );
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            let _ = app;
+        }
+    }
+}
+
+/// Somewhere a save blob can be stored
+pub trait SaveBackend: Send + Sync {
+    fn name(&self) -> &'static str;
+    /// The stored blob, or `None` if nothing has been saved there yet
+    fn read(&self) -> Result<Option<String>, String>;
+    fn write(&self, data: &str) -> Result<(), String>;
+}
+
+/// `save.json` in the user data directory
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone)]
+pub struct LocalFileBackend {
+    pub path: PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl LocalFileBackend {
+    /// The game's own save file
+    pub fn save_file() -> Self {
+        Self {
+            path: data_dir().join("save.json"),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl SaveBackend for LocalFileBackend {
+    fn name(&self) -> &'static str {
+        "LOCAL"
+    }
+
+    fn read(&self) -> Result<Option<String>, String> {
+        if !self.path.exists() {
+            return Ok(None);
+        }
+        fs::read_to_string(&self.path)
+            .map(Some)
+            .map_err(|e| e.to_string())
+    }
+
+    fn write(&self, data: &str) -> Result<(), String> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        fs::write(&self.path, data).map_err(|e| e.to_string())
+    }
+}
+
+/// Placeholder until the Steamworks SDK is linked; always unavailable
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SteamCloudBackend;
+
+impl SaveBackend for SteamCloudBackend {
+    fn name(&self) -> &'static str {
+        "STEAM CLOUD"
+    }
+
+    fn read(&self) -> Result<Option<String>, String> {
+        Err("Steam Cloud is not linked in this build".to_string())
+    }
+
+    fn write(&self, _data: &str) -> Result<(), String> {
+        Err("Steam Cloud is not linked in this build".to_string())
+    }
+}
+
+/// A file on a WebDAV share (Nextcloud, a NAS, ...), fetched with GET and
+/// stored with PUT
+#[cfg(not(target_arch = "wasm32"))]
+pub struct WebDavBackend {
+    /// Full URL of the save file, e.g. `https://host/remote.php/dav/files/me/eve_rebellion.json`
+    pub url: String,
+    pub username: String,
+    pub password: String,
+    client: reqwest::blocking::Client,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl WebDavBackend {
+    pub fn new(url: &str, username: &str, password: &str) -> Result<Self, String> {
+        let client = reqwest::blocking::Client::builder()
+            .timeout(std::time::Duration::from_secs(WEBDAV_TIMEOUT))
+            .build()
+            .map_err(|e| e.to_string())?;
+        Ok(Self {
+            url: url.to_string(),
+            username: username.to_string(),
+            password: password.to_string(),
+            client,
+        })
+    }
+
+    fn authed(
+        &self,
+        request: reqwest::blocking::RequestBuilder,
+    ) -> reqwest::blocking::RequestBuilder {
+        if self.username.is_empty() {
+            request
+        } else {
+            request.basic_auth(&self.username, Some(&self.password))
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl SaveBackend for WebDavBackend {
+    fn name(&self) -> &'static str {
+        "WEBDAV"
+    }
+
+    fn read(&self) -> Result<Option<String>, String> {
+        let response = self
+            .authed(self.client.get(&self.url))
+            .send()
+            .map_err(|e| e.to_string())?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !response.status().is_success() {
+            return Err(format!("HTTP {}", response.status()));
+        }
+        response.text().map(Some).map_err(|e| e.to_string())
+    }
+
+    fn write(&self, data: &str) -> Result<(), String> {
+        let response = self
+            .authed(self.client.put(&self.url))
+            .header("Content-Type", "application/json")
+            .body(data.to_string())
+            .send()
+            .map_err(|e| e.to_string())?;
+        if !response.status().is_success() {
+            return Err(format!("HTTP {}", response.status()));
+        }
+        Ok(())
+    }
+}
+
+/// Which remote backend to sync with
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CloudBackend {
+    /// Local save only
+    #[default]
+    Off,
+    SteamCloud,
+    WebDav,
+}
+
+/// Contents of `cloud.ron`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(default)]
+pub struct CloudSettings {
+    pub backend: CloudBackend,
+    pub webdav_url: String,
+    pub webdav_username: String,
+    /// Plain text; `EVE_REBELLION_WEBDAV_PASSWORD` takes precedence
+    pub webdav_password: String,
+}
+
+impl CloudSettings {
+    pub fn from_ron(text: &str) -> Result<Self, String> {
+        ron::from_str(text).map_err(|e| e.to_string())
+    }
+}
+
+/// When a save was written and how many runs it holds
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(default)]
+pub struct SaveStamp {
+    /// Unix seconds
+    pub saved_at: u64,
+    /// Lifetime runs across every module
+    pub runs: u32,
+}
+
+impl SaveStamp {
+    pub fn now(save: &SaveData) -> Self {
+        Self {
+            saved_at: unix_now(),
+            runs: save.prestige.totals().runs,
+        }
+    }
+}
+
+/// What to do with a local and a remote save
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncDecision {
+    UpToDate,
+    /// The remote copy is ahead; replace the local one
+    Pull,
+    /// The local copy is ahead (or there's no remote); upload it
+    Push,
+    /// Each copy is ahead by one measure; ask the player
+    Conflict,
+}
+
+/// Compare stamps: a copy wins when it is at least as new *and* holds at
+/// least as many runs. Newer-but-fewer-runs means both machines played
+/// since the last sync.
+pub fn decide_sync(local: SaveStamp, remote: Option<SaveStamp>) -> SyncDecision {
+    let Some(remote) = remote else {
+        return SyncDecision::Push;
+    };
+    if local == remote {
+        SyncDecision::UpToDate
+    } else if remote.saved_at >= local.saved_at && remote.runs >= local.runs {
+        SyncDecision::Pull
+    } else if local.saved_at >= remote.saved_at && local.runs >= remote.runs {
+        SyncDecision::Push
+    } else {
+        SyncDecision::Conflict
+    }
+}
+
+/// Last sync outcome, shown on the profile screen
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum SyncStatus {
+    /// No remote backend configured
+    #[default]
+    LocalOnly,
+    UpToDate,
+    /// Local changes waiting for the next push
+    Pending,
+    /// Waiting on the backend
+    Syncing,
+    Pulled,
+    Pushed,
+    /// Both copies changed; waiting on the player
+    Conflict,
+    /// The backend couldn't be reached
+    Failed(String),
+}
+
+impl SyncStatus {
+    pub fn label(&self) -> String {
+        match self {
+            SyncStatus::LocalOnly => "LOCAL SAVE ONLY".to_string(),
+            SyncStatus::UpToDate => "IN SYNC".to_string(),
+            SyncStatus::Pending => "CHANGES PENDING".to_string(),
+            SyncStatus::Syncing => "SYNCING...".to_string(),
+            SyncStatus::Pulled => "DOWNLOADED FROM CLOUD".to_string(),
+            SyncStatus::Pushed => "UPLOADED".to_string(),
+            SyncStatus::Conflict => "CONFLICT - CHOOSE A COPY".to_string(),
+            SyncStatus::Failed(error) => format!("OFFLINE ({})", error),
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            SyncStatus::LocalOnly => Color::srgb(0.5, 0.5, 0.5),
+            SyncStatus::UpToDate | SyncStatus::Pulled | SyncStatus::Pushed => {
+                Color::srgb(0.4, 0.9, 0.5)
+            }
+            SyncStatus::Pending | SyncStatus::Syncing => Color::srgb(0.9, 0.8, 0.4),
+            SyncStatus::Conflict | SyncStatus::Failed(_) => Color::srgb(1.0, 0.4, 0.3),
+        }
+    }
+}
+
+/// A sync the player asked for from the profile screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncRequest {
+    /// Sync now, by the usual rules
+    Sync,
+    /// Settle a conflict by uploading this machine's save
+    KeepLocal,
+    /// Settle a conflict by taking the cloud save
+    UseCloud,
+}
+
+/// What one sync came back with
+#[derive(Debug)]
+pub struct SyncOutcome {
+    pub status: SyncStatus,
+    /// The remote save, when it replaces the local one
+    pub pulled: Option<SaveData>,
+}
+
+/// Fetch and parse the remote save
+fn fetch(backend: &dyn SaveBackend) -> Result<Option<SaveData>, String> {
+    match backend.read()? {
+        Some(data) => serde_json::from_str(&data)
+            .map(Some)
+            .map_err(|e| format!("bad remote save: {}", e)),
+        None => Ok(None),
+    }
+}
+
+fn upload(backend: &dyn SaveBackend, save: &SaveData) -> Result<(), String> {
+    let data = serde_json::to_string_pretty(save).map_err(|e| e.to_string())?;
+    backend.write(&data)
+}
+
+/// Run one sync of `save` against `backend`, forced one way by `request` if
+/// given. Blocks until the backend answers.
+///
+/// A pulled save keeps this machine's settings: `settings.ron` wins over
+/// the save's copy at startup anyway, and the pull can land mid-session.
+pub fn sync_with(backend: &dyn SaveBackend, save: &SaveData, request: SyncRequest) -> SyncOutcome {
+    let remote = match fetch(backend) {
+        Ok(remote) => remote,
+        Err(e) => {
+            warn!("Cloud sync ({}) failed: {}", backend.name(), e);
+            return SyncOutcome {
+                status: SyncStatus::Failed(e),
+                pulled: None,
+            };
+        }
+    };
+
+    let decision = match request {
+        SyncRequest::KeepLocal => SyncDecision::Push,
+        SyncRequest::UseCloud if remote.is_some() => SyncDecision::Pull,
+        SyncRequest::UseCloud => SyncDecision::UpToDate,
+        SyncRequest::Sync => decide_sync(save.stamp, remote.as_ref().map(|r| r.stamp)),
+    };
+
+    let mut pulled = None;
+    let status = match (decision, remote) {
+        (SyncDecision::Pull, Some(mut remote)) => {
+            info!("Cloud save is ahead - downloaded from {}", backend.name());
+            remote.settings = save.settings.clone();
+            pulled = Some(remote);
+            SyncStatus::Pulled
+        }
+        (SyncDecision::Push, _) | (SyncDecision::Pull, None) => match upload(backend, save) {
+            Ok(()) => {
+                info!("Uploaded save to {}", backend.name());
+                SyncStatus::Pushed
+            }
+            Err(e) => {
+                warn!("Cloud upload ({}) failed: {}", backend.name(), e);
+                SyncStatus::Failed(e)
+            }
+        },
+        (SyncDecision::UpToDate, _) => SyncStatus::UpToDate,
+        (SyncDecision::Conflict, _) => {
+            warn!("Cloud save conflict - waiting for the player");
+            SyncStatus::Conflict
+        }
+    };
+    SyncOutcome { status, pulled }
+}
+
+/// The remote backend and sync state; absent when no plugin is loaded
+#[derive(Resource)]
+pub struct CloudSync {
+    backend: Option<Arc<dyn SaveBackend>>,
+    pub status: SyncStatus,
+    /// Local changes not yet pushed
+    pub dirty: bool,
+    pub request: Option<SyncRequest>,
+    /// Sync running on the IO task pool
+    task: Option<Task<SyncOutcome>>,
+    /// The save changed while `task` was running
+    changed_while_syncing: bool,
+}
+
+impl CloudSync {
+    pub fn new(backend: Option<Box<dyn SaveBackend>>) -> Self {
+        Self {
+            backend: backend.map(Arc::from),
+            status: SyncStatus::default(),
+            dirty: false,
+            request: None,
+            task: None,
+            changed_while_syncing: false,
+        }
+    }
+
+    pub fn backend_name(&self) -> &'static str {
+        self.backend.as_ref().map_or("OFF", |b| b.name())
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.backend.is_some()
+    }
+
+    /// Whether a sync is waiting on the backend
+    pub fn is_syncing(&self) -> bool {
+        self.task.is_some()
+    }
+
+    /// Run one sync against `save` on this thread, forced one way by
+    /// `request` if given. Replaces `save` when the remote copy wins.
+    pub fn sync(&mut self, save: &mut SaveData, request: SyncRequest) {
+        let Some(backend) = self.backend.clone() else {
+            self.status = SyncStatus::LocalOnly;
+            return;
+        };
+        let outcome = sync_with(backend.as_ref(), save, request);
+        if let Some(remote) = self.finish(outcome) {
+            *save = remote;
+        }
+    }
+
+    /// Start a sync of a copy of `save` on the IO task pool; `poll` picks
+    /// up the result
+    pub fn start(&mut self, save: &SaveData, request: SyncRequest) {
+        let Some(backend) = self.backend.clone() else {
+            self.status = SyncStatus::LocalOnly;
+            return;
+        };
+        let save = save.clone();
+        self.changed_while_syncing = false;
+        self.status = SyncStatus::Syncing;
+        self.task = Some(
+            IoTaskPool::get().spawn(async move { sync_with(backend.as_ref(), &save, request) }),
+        );
+    }
+
+    /// The running sync's result once it's in, with the save to replace
+    /// the local one when the remote copy won
+    pub fn poll(&mut self) -> Option<SaveData> {
+        let task = self.task.as_mut()?;
+        let outcome = block_on(future::poll_once(task))?;
+        self.task = None;
+        self.finish(outcome)
+    }
+
+    /// Record a sync's outcome. A save that changed while the sync was out
+    /// stays dirty, and isn't overwritten by a pull.
+    fn finish(&mut self, outcome: SyncOutcome) -> Option<SaveData> {
+        let changed = std::mem::take(&mut self.changed_while_syncing);
+        if outcome.pulled.is_some() && changed {
+            warn!(
+                "Cloud save is ahead, but this one changed while syncing - waiting for the player"
+            );
+            self.status = SyncStatus::Conflict;
+            return None;
+        }
+        self.status = match outcome.status {
+            SyncStatus::Pulled | SyncStatus::Pushed | SyncStatus::UpToDate if changed => {
+                self.dirty = true;
+                SyncStatus::Pending
+            }
+            status @ (SyncStatus::Pulled | SyncStatus::Pushed | SyncStatus::UpToDate) => {
+                self.dirty = false;
+                status
+            }
+            status => status,
+        };
+        outcome.pulled
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn data_dir() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("eve_rebellion")
+}
+
+/// Read `cloud.ron`, or no remote if it's missing or unreadable
+#[cfg(not(target_arch = "wasm32"))]
+fn load_cloud_settings() -> CloudSettings {
+    let path = data_dir().join("cloud.ron");
+    let Ok(text) = fs::read_to_string(&path) else {
+        return CloudSettings::default();
+    };
+    CloudSettings::from_ron(&text).unwrap_or_else(|e| {
+        warn!("Failed to parse {:?}: {}", path, e);
+        CloudSettings::default()
+    })
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn build_backend(settings: &CloudSettings) -> Option<Box<dyn SaveBackend>> {
+    match settings.backend {
+        CloudBackend::Off => None,
+        CloudBackend::SteamCloud => Some(Box::new(SteamCloudBackend)),
+        CloudBackend::WebDav => match WebDavBackend::new(
+            &settings.webdav_url,
+            &settings.webdav_username,
+            &std::env::var(WEBDAV_PASSWORD_ENV)
+                .unwrap_or_else(|_| settings.webdav_password.clone()),
+        ) {
+            Ok(backend) => Some(Box::new(backend)),
+            Err(e) => {
+                warn!("WebDAV backend unavailable: {}", e);
+                None
+            }
+        },
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn init_cloud_sync(mut commands: Commands) {
+    let backend = build_backend(&load_cloud_settings());
+    if let Some(backend) = &backend {
+        info!("Cloud sync via {}", backend.name());
+    }
+    commands.insert_resource(CloudSync::new(backend));
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn pull_on_startup(mut cloud: ResMut<CloudSync>, save: Res<SaveData>) {
+    if cloud.is_enabled() {
+        cloud.start(&save, SyncRequest::Sync);
+    }
+}
+
+fn mark_save_dirty(mut cloud: ResMut<CloudSync>, save: Res<SaveData>) {
+    // Loading the save isn't a change to push
+    if save.is_added() || !cloud.is_enabled() || cloud.status == SyncStatus::Conflict {
+        return;
+    }
+    cloud.dirty = true;
+    if cloud.is_syncing() {
+        cloud.changed_while_syncing = true;
+    } else {
+        cloud.status = SyncStatus::Pending;
+    }
+}
+
+/// Swap in the remote save when a sync pulls it. A pull isn't a local edit:
+/// it skips change detection, so it isn't marked dirty, restamped by the
+/// autosave and pushed straight back up, and is written to disk as it came.
+fn finish_cloud_sync(mut cloud: ResMut<CloudSync>, mut save: ResMut<SaveData>) {
+    if let Some(remote) = cloud.poll() {
+        let save = save.bypass_change_detection();
+        *save = remote;
+        save.save();
+    }
+}
+
+/// Start profile screen requests, and pushes of pending changes from the
+/// main menu, so a run's autosaves don't each go up
+fn run_cloud_sync(
+    mut cloud: ResMut<CloudSync>,
+    save: Res<SaveData>,
+    state: Res<State<GameState>>,
+    time: Res<Time<Real>>,
+    mut since_sync: Local<f32>,
+) {
+    *since_sync += time.delta_secs();
+    // One sync at a time; requests wait for the running one
+    if cloud.is_syncing() {
+        return;
+    }
+
+    if let Some(request) = cloud.request.take() {
+        cloud.start(&save, request);
+        *since_sync = 0.0;
+        return;
+    }
+
+    let idle = matches!(state.get(), GameState::MainMenu | GameState::Profile);
+    if idle && cloud.dirty && *since_sync >= MIN_PUSH_INTERVAL {
+        *since_sync = 0.0;
+        cloud.start(&save, SyncRequest::Sync);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stamp(saved_at: u64, runs: u32) -> SaveStamp {
+        SaveStamp { saved_at, runs }
+    }
+
+    #[test]
+    fn newer_copy_with_as_many_runs_wins() {
+        assert_eq!(decide_sync(stamp(100, 5), None), SyncDecision::Push);
+        assert_eq!(
+            decide_sync(stamp(100, 5), Some(stamp(100, 5))),
+            SyncDecision::UpToDate
+        );
+        assert_eq!(
+            decide_sync(stamp(100, 5), Some(stamp(200, 7))),
+            SyncDecision::Pull
+        );
+        assert_eq!(
+            decide_sync(stamp(300, 7), Some(stamp(200, 7))),
+            SyncDecision::Push
+        );
+        // A fresh install takes whatever is in the cloud
+        assert_eq!(
+            decide_sync(SaveStamp::default(), Some(stamp(200, 3))),
+            SyncDecision::Pull
+        );
+    }
+
+    #[test]
+    fn newer_but_fewer_runs_is_a_conflict() {
+        assert_eq!(
+            decide_sync(stamp(300, 4), Some(stamp(200, 6))),
+            SyncDecision::Conflict
+        );
+        assert_eq!(
+            decide_sync(stamp(200, 6), Some(stamp(300, 4))),
+            SyncDecision::Conflict
+        );
+    }
+
+    /// In-memory backend standing in for a remote
+    struct MemoryBackend(std::sync::Mutex<Option<String>>);
+
+    impl SaveBackend for MemoryBackend {
+        fn name(&self) -> &'static str {
+            "MEMORY"
+        }
+
+        fn read(&self) -> Result<Option<String>, String> {
+            Ok(self.0.lock().unwrap().clone())
+        }
+
+        fn write(&self, data: &str) -> Result<(), String> {
+            *self.0.lock().unwrap() = Some(data.to_string());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn conflicts_wait_for_the_player_to_pick_a_copy() {
+        let mut remote = SaveData::default();
+        remote.stamp = stamp(300, 4);
+        remote.lifetime_credits = 999;
+        let blob = serde_json::to_string(&remote).unwrap();
+        let mut cloud = CloudSync::new(Some(Box::new(MemoryBackend(std::sync::Mutex::new(Some(
+            blob,
+        ))))));
+
+        let mut local = SaveData::default();
+        local.stamp = stamp(200, 6);
+        cloud.sync(&mut local, SyncRequest::Sync);
+        assert_eq!(cloud.status, SyncStatus::Conflict);
+        assert_eq!(local.lifetime_credits, 0);
+
+        cloud.sync(&mut local, SyncRequest::UseCloud);
+        assert_eq!(cloud.status, SyncStatus::Pulled);
+        assert_eq!(local.lifetime_credits, 999);
+
+        assert_eq!(
+            CloudSync::new(None).status.label(),
+            SyncStatus::LocalOnly.label()
+        );
+        assert_eq!(CloudSync::new(None).backend_name(), "OFF");
+    }
+
+    /// Poll until the running sync is in
+    fn wait(cloud: &mut CloudSync) -> Option<SaveData> {
+        loop {
+            let pulled = cloud.poll();
+            if !cloud.is_syncing() {
+                return pulled;
+            }
+            std::thread::yield_now();
+        }
+    }
+
+    #[test]
+    fn background_syncs_keep_changes_made_while_they_run() {
+        IoTaskPool::get_or_init(bevy::tasks::TaskPool::new);
+        let backend = MemoryBackend(std::sync::Mutex::new(None));
+        let mut cloud = CloudSync::new(Some(Box::new(backend)));
+
+        let mut local = SaveData::default();
+        local.stamp = stamp(200, 6);
+        cloud.dirty = true;
+        cloud.start(&local, SyncRequest::Sync);
+        assert_eq!(cloud.status, SyncStatus::Syncing);
+        // The player saves again before the upload is in
+        cloud.changed_while_syncing = true;
+        assert!(wait(&mut cloud).is_none());
+        assert_eq!(cloud.status, SyncStatus::Pending);
+        assert!(cloud.dirty);
+
+        cloud.start(&local, SyncRequest::Sync);
+        assert!(wait(&mut cloud).is_none());
+        assert_eq!(cloud.status, SyncStatus::UpToDate);
+        assert!(!cloud.dirty);
+
+        // Another machine got ahead; a local change mid-pull isn't overwritten
+        let mut remote = local.clone();
+        remote.stamp = stamp(300, 7);
+        remote.lifetime_credits = 999;
+        cloud.start(&remote, SyncRequest::KeepLocal);
+        wait(&mut cloud);
+        cloud.start(&local, SyncRequest::Sync);
+        cloud.changed_while_syncing = true;
+        assert!(wait(&mut cloud).is_none());
+        assert_eq!(cloud.status, SyncStatus::Conflict);
+
+        cloud.start(&local, SyncRequest::UseCloud);
+        let pulled = wait(&mut cloud).expect("cloud copy wins");
+        assert_eq!(pulled.lifetime_credits, 999);
+        assert_eq!(cloud.status, SyncStatus::Pulled);
+    }
+
+    #[test]
+    fn steam_cloud_stub_reports_unavailable() {
+        let mut cloud = CloudSync::new(Some(Box::new(SteamCloudBackend)));
+        let mut save = SaveData::default();
+        cloud.sync(&mut save, SyncRequest::Sync);
+        assert!(matches!(cloud.status, SyncStatus::Failed(_)));
+    }
+}
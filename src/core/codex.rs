@@ -0,0 +1,265 @@
+//! Codex
+//!
+//! The story journal: factions, ships, bosses and campaign events, each
+//! unlocked the first time the player meets it and kept in `SaveData`. The
+//! catalog is built from the game's own definitions (faction lore, ship
+//! tables, boss intro and defeat lines, act and mission briefs), so new
+//! content shows up in the codex without a second copy of its text.
+
+#![allow(dead_code)]
+
+use super::campaign::Act;
+use super::factions::Faction;
+use crate::entities::boss::get_boss_for_stage;
+use serde::{Deserialize, Serialize};
+
+/// Campaign stages with a boss
+pub const BOSS_STAGES: std::ops::RangeInclusive<u32> = 1..=13;
+
+/// Codex sections, in display order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CodexCategory {
+    Factions,
+    Ships,
+    Bosses,
+    Events,
+}
+
+impl CodexCategory {
+    pub const ALL: [CodexCategory; 4] = [
+        CodexCategory::Factions,
+        CodexCategory::Ships,
+        CodexCategory::Bosses,
+        CodexCategory::Events,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            CodexCategory::Factions => "FACTIONS",
+            CodexCategory::Ships => "SHIPS",
+            CodexCategory::Bosses => "BOSSES",
+            CodexCategory::Events => "CAMPAIGN",
+        }
+    }
+}
+
+/// One codex page
+#[derive(Debug, Clone, PartialEq)]
+pub struct CodexEntry {
+    /// Stable key stored in the save
+    pub id: String,
+    pub category: CodexCategory,
+    pub title: String,
+    pub subtitle: String,
+    pub body: String,
+}
+
+pub fn faction_entry_id(faction: Faction) -> String {
+    format!("faction:{}", faction.short_name().to_lowercase())
+}
+
+pub fn ship_entry_id(type_id: u32) -> String {
+    format!("ship:{}", type_id)
+}
+
+pub fn boss_entry_id(stage: u32) -> String {
+    format!("boss:{}", stage)
+}
+
+pub fn act_entry_id(act: Act) -> String {
+    format!("act:{}", act.number())
+}
+
+pub fn mission_entry_id(mission_id: &str) -> String {
+    format!("mission:{}", mission_id)
+}
+
+/// Codex id of the campaign boss with this name
+pub fn boss_entry_id_by_name(name: &str) -> Option<String> {
+    BOSS_STAGES
+        .filter_map(get_boss_for_stage)
+        .find(|boss| boss.name == name)
+        .map(|boss| boss_entry_id(boss.stage))
+}
+
+/// The act a campaign mission belongs to
+pub fn act_of_mission(mission_id: &str) -> Option<Act> {
+    [Act::Act1, Act::Act2, Act::Act3]
+        .into_iter()
+        .find(|act| act.missions().iter().any(|m| m.id == mission_id))
+}
+
+/// Every codex entry, grouped by category
+pub fn codex_entries() -> Vec<CodexEntry> {
+    let mut entries = Vec::new();
+
+    for faction in Faction::all() {
+        let weapons = faction.weapon_type();
+        let tank = faction.tank_type();
+        entries.push(CodexEntry {
+            id: faction_entry_id(*faction),
+            category: CodexCategory::Factions,
+            title: faction.name().to_string(),
+            subtitle: faction.tagline().to_string(),
+            body: format!(
+                "{}\n\nDoctrine: {} and {} tanking. {}.",
+                faction.story_intro(),
+                weapons.name(),
+                tank.name().to_lowercase(),
+                tank.description()
+            ),
+        });
+    }
+
+    // Flyable hulls first, then enemy-only hulls
+    let mut ship_ids = Vec::new();
+    for faction in Faction::all() {
+        for ship in faction.player_ships() {
+            ship_ids.push(ship.type_id);
+            entries.push(CodexEntry {
+                id: ship_entry_id(ship.type_id),
+                category: CodexCategory::Ships,
+                title: ship.name.to_string(),
+                subtitle: format!(
+                    "{} {} - {}",
+                    faction.short_name(),
+                    ship.class.name(),
+                    ship.role
+                ),
+                body: format!(
+                    "{}\n\nHull {:.0} - speed {:.0} - {:.1} shots/s at {:.0} damage.",
+                    ship.special, ship.health, ship.speed, ship.fire_rate, ship.damage
+                ),
+            });
+        }
+    }
+    for faction in Faction::all() {
+        for ship in faction.enemy_ships() {
+            if ship_ids.contains(&ship.type_id) {
+                continue;
+            }
+            ship_ids.push(ship.type_id);
+            entries.push(CodexEntry {
+                id: ship_entry_id(ship.type_id),
+                category: CodexCategory::Ships,
+                title: ship.name.to_string(),
+                subtitle: format!("{} {}", faction.short_name(), ship.class.name()),
+                body: format!(
+                    "Hostile contact.\n\nHull {:.0} - speed {:.0} - {:.0} damage per shot. Worth {} points.",
+                    ship.health, ship.speed, ship.damage, ship.score
+                ),
+            });
+        }
+    }
+
+    for boss in BOSS_STAGES.filter_map(get_boss_for_stage) {
+        entries.push(CodexEntry {
+            id: boss_entry_id(boss.stage),
+            category: CodexCategory::Bosses,
+            title: boss.title.clone(),
+            subtitle: format!("{} - {}", boss.name, boss.ship_class),
+            body: format!(
+                "\"{}\"\n\nIn defeat: \"{}\"",
+                boss.dialogue_intro, boss.dialogue_defeat
+            ),
+        });
+    }
+
+    for act in [Act::Act1, Act::Act2, Act::Act3] {
+        entries.push(CodexEntry {
+            id: act_entry_id(act),
+            category: CodexCategory::Events,
+            title: format!("ACT {}: {}", act.number(), act.name()),
+            subtitle: act.catchphrase().to_string(),
+            body: act.description().to_string(),
+        });
+        for mission in act.missions() {
+            entries.push(CodexEntry {
+                id: mission_entry_id(mission.id),
+                category: CodexCategory::Events,
+                title: mission.name.to_string(),
+                subtitle: format!("Act {} mission", act.number()),
+                body: format!(
+                    "{}\n\nObjective: {}.",
+                    mission.description, mission.primary_objective
+                ),
+            });
+        }
+    }
+
+    entries
+}
+
+/// Codex entries the player has unlocked, in discovery order
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct CodexLog {
+    pub unlocked: Vec<String>,
+}
+
+impl CodexLog {
+    pub fn is_unlocked(&self, id: &str) -> bool {
+        self.unlocked.iter().any(|u| u == id)
+    }
+
+    /// Unlock an entry; returns true the first time
+    pub fn unlock(&mut self, id: String) -> bool {
+        if self.is_unlocked(&id) {
+            return false;
+        }
+        self.unlocked.push(id);
+        true
+    }
+
+    /// Unlocked and total entries in a category
+    pub fn progress(&self, entries: &[CodexEntry], category: CodexCategory) -> (usize, usize) {
+        let in_category = entries.iter().filter(|e| e.category == category);
+        let total = in_category.clone().count();
+        let unlocked = in_category.filter(|e| self.is_unlocked(&e.id)).count();
+        (unlocked, total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn catalog_has_unique_ids_in_every_category() {
+        let entries = codex_entries();
+        for category in CodexCategory::ALL {
+            assert!(entries.iter().any(|e| e.category == category));
+        }
+        let mut ids: Vec<_> = entries.iter().map(|e| e.id.as_str()).collect();
+        let total = ids.len();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), total);
+    }
+
+    #[test]
+    fn bosses_keep_their_lines_and_resolve_by_name() {
+        let entries = codex_entries();
+        let krador = entries.iter().find(|e| e.id == boss_entry_id(1)).unwrap();
+        assert_eq!(krador.title, "Convoy Master Krador");
+        assert!(krador.body.contains("Foolish rebel"));
+        assert!(krador.body.contains("they're escaping"));
+        assert_eq!(
+            boss_entry_id_by_name("Slave Transport Overseer"),
+            Some(boss_entry_id(1))
+        );
+        assert_eq!(act_of_mission("m1_convoy_raid"), Some(Act::Act1));
+    }
+
+    #[test]
+    fn unlocking_is_once_and_counts_toward_progress() {
+        let entries = codex_entries();
+        let mut log = CodexLog::default();
+        assert!(log.unlock(faction_entry_id(Faction::Caldari)));
+        assert!(!log.unlock(faction_entry_id(Faction::Caldari)));
+        assert_eq!(
+            log.progress(&entries, CodexCategory::Factions),
+            (1, Faction::all().len())
+        );
+        assert_eq!(log.progress(&entries, CodexCategory::Bosses).0, 0);
+    }
+}
@@ -56,6 +56,15 @@ pub const PLAYER_SHIELD_RECHARGE_RATE: f32 = 5.0;
 /// Delay before shields start recharging (seconds)
 pub const PLAYER_SHIELD_RECHARGE_DELAY: f32 = 3.0;
 
+/// Armor repairer cycle time (armor doctrine, seconds)
+pub const ARMOR_REPAIR_CYCLE: f32 = 2.0;
+
+/// Armor repaired per cycle
+pub const ARMOR_REPAIR_AMOUNT: f32 = 8.0;
+
+/// Capacitor consumed per repair cycle (GJ)
+pub const ARMOR_REPAIR_CAP_COST: f32 = 12.0;
+
 // =============================================================================
 // ENEMIES
 // =============================================================================
@@ -0,0 +1,427 @@
+//! Crash Handler
+//!
+//! Installs a panic hook that writes a crash dump (save progress, run stats,
+//! recent event and battle logs) to `<data_dir>/eve_rebellion/crashes/`.
+//! On the next launch the pending dump is loaded so the menu can offer to
+//! restore the interrupted campaign. Native only.
+
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use super::{
+    Act, BattleLog, CampaignState, CodexLog, EventLog, FactionProgress, GameSession, GameState,
+    HighScore, SaveData, ScoreSystem,
+};
+use crate::games::ActiveModule;
+
+/// How often the crash snapshot is refreshed (seconds)
+const SNAPSHOT_INTERVAL: f32 = 1.0;
+
+/// Timestamped dumps kept on disk (oldest pruned first)
+const MAX_CRASH_DUMPS: usize = 10;
+
+/// Crash handler plugin
+pub struct CrashPlugin;
+
+impl Plugin for CrashPlugin {
+    fn build(&self, app: &mut App) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            install_panic_hook();
+            app.init_resource::<CrashSnapshotTimer>()
+                .add_systems(Startup, load_pending_crash)
+                .add_systems(
+                    Update,
+                    (
+                        update_crash_save.run_if(resource_changed::<SaveData>),
+                        update_crash_snapshot,
+                    ),
+                );
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            let _ = app;
+        }
+    }
+}
+
+/// Summary of the run in progress
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(default)]
+pub struct RunStats {
+    /// Game state when the snapshot was taken
+    pub state: String,
+    pub module_id: Option<String>,
+    pub player_faction: String,
+    pub endless: bool,
+    /// Act number (1-3)
+    pub act: u32,
+    pub mission_index: usize,
+    pub mission_name: String,
+    pub in_mission: bool,
+    pub wave: u32,
+    pub mission_timer: f32,
+    pub score: u64,
+    pub souls_liberated: u32,
+}
+
+impl RunStats {
+    /// Whether this run can be resumed from the campaign menu
+    pub fn is_restorable(&self) -> bool {
+        self.in_mission
+            && !self.endless
+            && self.module_id.as_deref() == Some("elder_fleet")
+            && (1..=3).contains(&self.act)
+    }
+
+    /// Campaign act for `act`
+    pub fn campaign_act(&self) -> Act {
+        match self.act {
+            2 => Act::Act2,
+            3 => Act::Act3,
+            _ => Act::Act1,
+        }
+    }
+}
+
+/// The save progress a crash dump carries back. Field names match
+/// `SaveData`, so dumps written with the whole save still load.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct CrashSave {
+    pub stage_progress: Vec<FactionProgress>,
+    pub high_scores: Vec<HighScore>,
+    pub unlocked_ships: HashSet<u32>,
+    pub lifetime_credits: u64,
+    pub codex: CodexLog,
+}
+
+impl From<&SaveData> for CrashSave {
+    fn from(save: &SaveData) -> Self {
+        Self {
+            stage_progress: save.stage_progress.clone(),
+            high_scores: save.high_scores.clone(),
+            unlocked_ships: save.unlocked_ships.clone(),
+            lifetime_credits: save.lifetime_credits,
+            codex: save.codex.clone(),
+        }
+    }
+}
+
+/// Contents of a crash dump
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct CrashDump {
+    /// Unix time of the crash
+    pub timestamp: u64,
+    pub panic_message: String,
+    pub save: CrashSave,
+    pub run: RunStats,
+    /// Recent gameplay events, one per line
+    pub event_log: String,
+    /// Recent combat feed and the mission's damage breakdown
+    pub battle_log: String,
+}
+
+/// Crash dump from the previous session awaiting a restore decision
+#[derive(Resource, Clone, Debug)]
+pub struct PendingCrashRestore(pub CrashDump);
+
+#[derive(Resource, Default)]
+struct CrashSnapshotTimer(f32);
+
+/// Latest snapshot, read by the panic hook
+#[cfg(not(target_arch = "wasm32"))]
+static CRASH_SNAPSHOT: std::sync::Mutex<Option<CrashDump>> = std::sync::Mutex::new(None);
+
+#[cfg(not(target_arch = "wasm32"))]
+fn crash_dir() -> std::path::PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| std::path::PathBuf::from("."))
+        .join("eve_rebellion")
+        .join("crashes")
+}
+
+/// Dump awaiting a restore decision on next launch
+#[cfg(not(target_arch = "wasm32"))]
+fn pending_path() -> std::path::PathBuf {
+    crash_dir().join("pending.json")
+}
+
+fn act_number(act: Act) -> u32 {
+    match act {
+        Act::Act1 => 1,
+        Act::Act2 => 2,
+        Act::Act3 => 3,
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        write_crash_dump(&info.to_string());
+        default_hook(info);
+    }));
+}
+
+/// Write the latest snapshot to disk. Called from the panic hook, so it
+/// must not panic itself and must not block on the snapshot lock.
+#[cfg(not(target_arch = "wasm32"))]
+fn write_crash_dump(panic_message: &str) {
+    let snapshot = match CRASH_SNAPSHOT.try_lock() {
+        Ok(guard) => guard.clone(),
+        Err(std::sync::TryLockError::Poisoned(poisoned)) => poisoned.into_inner().clone(),
+        Err(std::sync::TryLockError::WouldBlock) => None,
+    };
+    let mut dump = snapshot.unwrap_or_default();
+    dump.panic_message = panic_message.to_string();
+    dump.timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    let Ok(data) = serde_json::to_string_pretty(&dump) else {
+        return;
+    };
+    let dir = crash_dir();
+    if std::fs::create_dir_all(&dir).is_err() {
+        return;
+    }
+
+    let path = dir.join(format!("crash-{}.json", dump.timestamp));
+    if std::fs::write(&path, &data).is_ok() {
+        eprintln!("Crash dump written to {:?}", path);
+    }
+    // Don't clobber an unanswered restore offer with a crash outside a run
+    if dump.run.is_restorable() || !pending_path().exists() {
+        let _ = std::fs::write(pending_path(), &data);
+    }
+    prune_crash_dumps(&dir);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn prune_crash_dumps(dir: &std::path::Path) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    let mut dumps: Vec<_> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| {
+            p.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.starts_with("crash-"))
+        })
+        .collect();
+    if dumps.len() <= MAX_CRASH_DUMPS {
+        return;
+    }
+    // Names embed the unix time, so lexical order is chronological
+    dumps.sort();
+    for path in &dumps[..dumps.len() - MAX_CRASH_DUMPS] {
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+/// Look for a dump left by the previous session
+#[cfg(not(target_arch = "wasm32"))]
+fn load_pending_crash(mut commands: Commands) {
+    let path = pending_path();
+    let Ok(data) = std::fs::read_to_string(&path) else {
+        return;
+    };
+    match serde_json::from_str::<CrashDump>(&data) {
+        Ok(dump) => {
+            warn!(
+                "Previous session crashed during {} ({}), offering restore",
+                dump.run.state, dump.panic_message
+            );
+            commands.insert_resource(PendingCrashRestore(dump));
+        }
+        Err(e) => {
+            warn!("Discarding unreadable crash dump: {}", e);
+            let _ = std::fs::remove_file(&path);
+        }
+    }
+}
+
+/// Copy the save progress into the snapshot whenever the save changes
+fn update_crash_save(save: Res<SaveData>) {
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Ok(mut guard) = CRASH_SNAPSHOT.lock() {
+        guard.get_or_insert_with(CrashDump::default).save = CrashSave::from(&*save);
+    }
+    #[cfg(target_arch = "wasm32")]
+    let _ = save;
+}
+
+/// Keep the snapshot the panic hook will write reasonably fresh
+#[allow(clippy::too_many_arguments)]
+fn update_crash_snapshot(
+    time: Res<Time>,
+    mut timer: ResMut<CrashSnapshotTimer>,
+    state: Res<State<GameState>>,
+    campaign: Res<CampaignState>,
+    score: Res<ScoreSystem>,
+    session: Res<GameSession>,
+    active_module: Res<ActiveModule>,
+    endless: Res<super::EndlessMode>,
+    log: Res<EventLog>,
+    battle_log: Res<BattleLog>,
+) {
+    timer.0 -= time.delta_secs();
+    if timer.0 > 0.0 && !state.is_changed() {
+        return;
+    }
+    timer.0 = SNAPSHOT_INTERVAL;
+
+    let run = RunStats {
+        state: format!("{:?}", state.get()),
+        module_id: active_module.module_id.clone(),
+        player_faction: session.player_faction.name().to_string(),
+        endless: endless.active,
+        act: act_number(campaign.act),
+        mission_index: campaign.mission_index,
+        mission_name: campaign.current_mission_name().to_string(),
+        in_mission: campaign.in_mission,
+        wave: campaign.current_wave,
+        mission_timer: campaign.mission_timer,
+        score: score.score,
+        souls_liberated: score.souls_liberated,
+    };
+    let event_log = log.to_report();
+    let battle_log = battle_log.to_report();
+
+    // The save half is kept by `update_crash_save`
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Ok(mut guard) = CRASH_SNAPSHOT.lock() {
+        let dump = guard.get_or_insert_with(CrashDump::default);
+        dump.run = run;
+        dump.event_log = event_log;
+        dump.battle_log = battle_log;
+    }
+    #[cfg(target_arch = "wasm32")]
+    let _ = (run, event_log, battle_log);
+}
+
+/// Clear the pending restore offer (after restoring or dismissing)
+pub fn clear_pending_crash(commands: &mut Commands) {
+    commands.remove_resource::<PendingCrashRestore>();
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Err(e) = std::fs::remove_file(pending_path()) {
+        warn!("Failed to remove pending crash dump: {}", e);
+    }
+}
+
+/// Merge progress from a crash dump into the live save (never regresses)
+pub fn merge_crash_save(save: &mut SaveData, crashed: &CrashSave) {
+    for progress in &crashed.stage_progress {
+        save.complete_stage(
+            &progress.player_faction,
+            &progress.enemy_faction,
+            progress.highest_stage,
+            progress.highest_mission,
+        );
+    }
+    for hs in &crashed.high_scores {
+        save.record_score(&hs.player_faction, &hs.enemy_faction, hs.score, hs.stage);
+    }
+    save.unlocked_ships
+        .extend(crashed.unlocked_ships.iter().copied());
+    save.lifetime_credits = save.lifetime_credits.max(crashed.lifetime_credits);
+    for id in &crashed.codex.unlocked {
+        save.codex.unlock(id.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::boss_entry_id;
+
+    fn campaign_run() -> RunStats {
+        RunStats {
+            module_id: Some("elder_fleet".to_string()),
+            act: 2,
+            mission_index: 1,
+            in_mission: true,
+            ..default()
+        }
+    }
+
+    #[test]
+    fn run_stats_restorable_only_mid_campaign_mission() {
+        assert!(campaign_run().is_restorable());
+        assert!(!RunStats {
+            in_mission: false,
+            ..campaign_run()
+        }
+        .is_restorable());
+        assert!(!RunStats {
+            endless: true,
+            ..campaign_run()
+        }
+        .is_restorable());
+        assert!(!RunStats {
+            module_id: Some("caldari_gallente".to_string()),
+            ..campaign_run()
+        }
+        .is_restorable());
+        assert_eq!(campaign_run().campaign_act(), Act::Act2);
+    }
+
+    #[test]
+    fn merge_crash_save_never_regresses() {
+        let mut live = SaveData::default();
+        live.complete_stage("Minmatar", "Amarr", 6, 1);
+        live.record_score("Minmatar", "Amarr", 90_000, 6);
+
+        let mut crashed = SaveData::default();
+        crashed.complete_stage("Minmatar", "Amarr", 4, 2);
+        crashed.record_score("Minmatar", "Amarr", 120_000, 4);
+        crashed.unlock_ship(587);
+        crashed.codex.unlock(boss_entry_id(1));
+
+        merge_crash_save(&mut live, &CrashSave::from(&crashed));
+        assert_eq!(live.get_highest_stage("Minmatar", "Amarr"), 6);
+        assert_eq!(live.get_high_score("Minmatar", "Amarr"), 120_000);
+        assert!(live.unlocked_ships.contains(&587));
+        assert!(live.codex.is_unlocked(&boss_entry_id(1)));
+    }
+
+    #[test]
+    fn dumps_holding_the_whole_save_still_load() {
+        let mut save = SaveData::default();
+        save.complete_stage("Minmatar", "Amarr", 4, 2);
+        save.unlock_ship(587);
+        let json = serde_json::json!({ "timestamp": 7, "save": save }).to_string();
+
+        let parsed: CrashDump = serde_json::from_str(&json).unwrap_or_default();
+        assert_eq!(parsed.timestamp, 7);
+        assert_eq!(parsed.save.stage_progress.len(), 1);
+        assert!(parsed.save.unlocked_ships.contains(&587));
+    }
+
+    #[test]
+    fn crash_dump_round_trips_through_json() {
+        let dump = CrashDump {
+            timestamp: 42,
+            panic_message: "boom".to_string(),
+            run: campaign_run(),
+            event_log: "[    1.00] WAVE     Wave 1 complete\n".to_string(),
+            battle_log: "[    1.50] HIT    12 EM from Punisher\n".to_string(),
+            ..default()
+        };
+        let json = serde_json::to_string(&dump).unwrap_or_default();
+        let parsed: CrashDump = serde_json::from_str(&json).unwrap_or_default();
+        assert_eq!(parsed.timestamp, 42);
+        assert_eq!(parsed.run, dump.run);
+        assert_eq!(parsed.event_log, dump.event_log);
+        assert_eq!(parsed.battle_log, dump.battle_log);
+    }
+}
@@ -0,0 +1,185 @@
+//! Daily Challenge
+//!
+//! One run per UTC day, the same for every player: the date picks the
+//! mission, seeds enemy spawns and powerup drops, and rolls two modifiers.
+//! It is flown as a three-minute score run with a single attempt, and the
+//! result is kept in the save file.
+
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+use bevy::utils::SystemTime;
+use serde::{Deserialize, Serialize};
+
+/// Modifiers rolled per day
+pub const DAILY_MODIFIER_COUNT: usize = 2;
+
+/// Daily results kept in the save file
+pub const DAILY_HISTORY: usize = 30;
+
+/// Stages a daily can land on
+const DAILY_STAGES: u32 = 13;
+
+/// Mixed into the day number so the daily seed isn't just the date
+const DAILY_SEED_SALT: u64 = 0x45_56_45_52_45_42_45_4c;
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Rule change applied to a daily run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DailyModifier {
+    /// Player ships fly without shields
+    NoShields,
+    /// Enemies move twice as fast
+    DoubleEnemySpeed,
+    /// Enemies have double health
+    ArmoredEnemies,
+    /// Enemies fire 50% faster
+    RapidFireEnemies,
+    /// Player armor and hull halved
+    FragileHull,
+}
+
+impl DailyModifier {
+    pub const ALL: [DailyModifier; 5] = [
+        DailyModifier::NoShields,
+        DailyModifier::DoubleEnemySpeed,
+        DailyModifier::ArmoredEnemies,
+        DailyModifier::RapidFireEnemies,
+        DailyModifier::FragileHull,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            DailyModifier::NoShields => "No shields",
+            DailyModifier::DoubleEnemySpeed => "Double enemy speed",
+            DailyModifier::ArmoredEnemies => "Armored enemies",
+            DailyModifier::RapidFireEnemies => "Rapid-fire enemies",
+            DailyModifier::FragileHull => "Fragile hull",
+        }
+    }
+}
+
+/// The challenge for one day (the resource holds the run being flown)
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DailyChallenge {
+    /// Days since the Unix epoch (UTC)
+    pub day: u64,
+    /// Seed for spawns and drops
+    pub seed: u64,
+    /// Stage number (1-13)
+    pub stage: u32,
+    pub modifiers: [DailyModifier; DAILY_MODIFIER_COUNT],
+}
+
+impl Default for DailyChallenge {
+    fn default() -> Self {
+        Self::today()
+    }
+}
+
+impl DailyChallenge {
+    /// Derive a day's challenge; identical on every machine
+    pub fn for_day(day: u64) -> Self {
+        let mut rng = fastrand::Rng::with_seed(day ^ DAILY_SEED_SALT);
+        let seed = rng.u64(..);
+        let stage = rng.u32(1..=DAILY_STAGES);
+
+        let count = DailyModifier::ALL.len();
+        let first = rng.usize(..count);
+        let mut second = rng.usize(..count - 1);
+        if second >= first {
+            second += 1;
+        }
+
+        Self {
+            day,
+            seed,
+            stage,
+            modifiers: [DailyModifier::ALL[first], DailyModifier::ALL[second]],
+        }
+    }
+
+    pub fn today() -> Self {
+        Self::for_day(day_of(unix_now()))
+    }
+
+    pub fn has(&self, modifier: DailyModifier) -> bool {
+        self.modifiers.contains(&modifier)
+    }
+
+    /// "No shields + Armored enemies"
+    pub fn modifier_names(&self) -> String {
+        self.modifiers
+            .iter()
+            .map(|m| m.name())
+            .collect::<Vec<_>>()
+            .join(" + ")
+    }
+}
+
+/// One day's attempt
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DailyResult {
+    pub day: u64,
+    pub mission_id: String,
+    pub score: u64,
+    /// Survived the full run
+    pub completed: bool,
+}
+
+/// Seconds since the Unix epoch
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// UTC day number of a timestamp
+pub fn day_of(unix_secs: u64) -> u64 {
+    unix_secs / SECONDS_PER_DAY
+}
+
+/// Seconds until the next daily unlocks (UTC midnight)
+pub fn seconds_until_next_daily(unix_secs: u64) -> u64 {
+    SECONDS_PER_DAY - unix_secs % SECONDS_PER_DAY
+}
+
+/// "HH:MM:SS"
+pub fn format_countdown(seconds: u64) -> String {
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        (seconds / 60) % 60,
+        seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_day_always_rolls_the_same_challenge() {
+        let day = 20_000;
+        let challenge = DailyChallenge::for_day(day);
+        assert_eq!(challenge, DailyChallenge::for_day(day));
+        assert!((1..=DAILY_STAGES).contains(&challenge.stage));
+        assert_ne!(challenge.modifiers[0], challenge.modifiers[1]);
+
+        // Consecutive days don't share a seed
+        assert_ne!(challenge.seed, DailyChallenge::for_day(day + 1).seed);
+    }
+
+    #[test]
+    fn countdown_runs_to_utc_midnight() {
+        let noon = 20_000 * SECONDS_PER_DAY + 12 * 3600;
+        assert_eq!(day_of(noon), 20_000);
+        assert_eq!(seconds_until_next_daily(noon), 12 * 3600);
+        assert_eq!(
+            format_countdown(seconds_until_next_daily(noon - 61)),
+            "12:01:01"
+        );
+    }
+}
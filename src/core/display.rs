@@ -0,0 +1,297 @@
+//! Display Settings
+//!
+//! Window mode (windowed, borderless fullscreen or exclusive fullscreen)
+//! and the monitor the game goes fullscreen on, both saved. While windowed,
+//! the window's position and size are remembered once it settles after a
+//! move or resize, and restored on the next startup.
+//!
+//! Low-latency mode trims the frames the GPU may queue up to one, so a
+//! press reaches the screen a frame sooner (see `schedule` for the gameplay
+//! side of it).
+
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+use bevy::window::{
+    Monitor, MonitorSelection, PrimaryWindow, WindowMode, WindowMoved, WindowPosition,
+    WindowResized,
+};
+use serde::{Deserialize, Serialize};
+use std::num::NonZeroU32;
+
+/// Seconds the window has to sit still before its placement is saved
+const PLACEMENT_SETTLE: f32 = 0.5;
+
+/// Display plugin
+pub struct DisplayPlugin;
+
+impl Plugin for DisplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DisplaySettings>().add_systems(
+            Update,
+            (
+                apply_display_settings.run_if(resource_changed::<DisplaySettings>),
+                track_window_placement,
+            )
+                .chain(),
+        );
+    }
+}
+
+/// How the window fills the screen
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DisplayMode {
+    #[default]
+    Windowed,
+    /// Fullscreen-sized window without decorations; quick to alt-tab
+    Borderless,
+    /// Exclusive fullscreen at the monitor's native mode
+    Exclusive,
+}
+
+impl DisplayMode {
+    pub const ALL: [DisplayMode; 3] = [
+        DisplayMode::Windowed,
+        DisplayMode::Borderless,
+        DisplayMode::Exclusive,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            DisplayMode::Windowed => "WINDOWED",
+            DisplayMode::Borderless => "BORDERLESS",
+            DisplayMode::Exclusive => "FULLSCREEN",
+        }
+    }
+
+    /// Next mode in `step`'s direction, wrapping around
+    pub fn stepped(&self, step: i32) -> Self {
+        let index = Self::ALL.iter().position(|m| m == self).unwrap_or(0) as i32;
+        Self::ALL[(index + step).rem_euclid(Self::ALL.len() as i32) as usize]
+    }
+
+    pub fn is_fullscreen(&self) -> bool {
+        *self != DisplayMode::Windowed
+    }
+}
+
+/// Where the window sat while windowed
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowPlacement {
+    /// Top-left corner (physical pixels, desktop coordinates)
+    pub position: IVec2,
+    /// Size (logical pixels)
+    pub size: UVec2,
+}
+
+/// Window mode, monitor and windowed placement (saved)
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(default)]
+pub struct DisplaySettings {
+    pub mode: DisplayMode,
+    /// Index into the connected monitors
+    pub monitor: usize,
+    pub placement: Option<WindowPlacement>,
+    /// Player moves and fires the frame input arrives; one queued frame
+    pub low_latency: bool,
+}
+
+impl DisplaySettings {
+    /// Frames the GPU may queue ahead of the display; `None` is Bevy's own
+    /// default (two)
+    pub fn frame_latency(&self) -> Option<NonZeroU32> {
+        if self.low_latency {
+            NonZeroU32::new(1)
+        } else {
+            None
+        }
+    }
+
+    /// The chosen monitor, or the primary one if it's been unplugged.
+    /// Before any monitors are known the saved index is trusted.
+    pub fn monitor_selection(&self, monitor_count: usize) -> MonitorSelection {
+        if monitor_count == 0 || self.monitor < monitor_count {
+            MonitorSelection::Index(self.monitor)
+        } else {
+            MonitorSelection::Primary
+        }
+    }
+
+    pub fn window_mode(&self, monitor_count: usize) -> WindowMode {
+        let monitor = self.monitor_selection(monitor_count);
+        match self.mode {
+            DisplayMode::Windowed => WindowMode::Windowed,
+            DisplayMode::Borderless => WindowMode::BorderlessFullscreen(monitor),
+            DisplayMode::Exclusive => WindowMode::Fullscreen(monitor),
+        }
+    }
+
+    /// Next monitor in `step`'s direction, wrapping around
+    pub fn stepped_monitor(&self, step: i32, monitor_count: usize) -> usize {
+        if monitor_count == 0 {
+            return 0;
+        }
+        (self.monitor.min(monitor_count - 1) as i32 + step).rem_euclid(monitor_count as i32)
+            as usize
+    }
+}
+
+/// Options label for a monitor: its name if it reports one
+pub fn monitor_label(index: usize, name: Option<&str>) -> String {
+    match name {
+        Some(name) if !name.trim().is_empty() => format!("{}: {}", index + 1, name.trim()),
+        _ => format!("MONITOR {}", index + 1),
+    }
+}
+
+/// Run condition: low-latency mode is on
+pub fn low_latency_mode(display: Res<DisplaySettings>) -> bool {
+    display.low_latency
+}
+
+/// Put the window in the chosen mode on the chosen monitor; a windowed game
+/// goes back where it was left. The frame queue depth follows low-latency
+/// mode.
+fn apply_display_settings(
+    display: Res<DisplaySettings>,
+    monitors: Query<&Monitor>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+    mut applied: Local<Option<(DisplayMode, usize)>>,
+) {
+    let Ok(mut window) = windows.get_single_mut() else {
+        return;
+    };
+    let frame_latency = display.frame_latency();
+    if window.desired_maximum_frame_latency != frame_latency {
+        window.desired_maximum_frame_latency = frame_latency;
+    }
+
+    let first = applied.is_none();
+    if *applied == Some((display.mode, display.monitor)) {
+        // Only the placement moved; that's the window reporting in
+        return;
+    }
+    let monitor_changed = applied.is_some_and(|(_, monitor)| monitor != display.monitor);
+    *applied = Some((display.mode, display.monitor));
+
+    let monitor_count = monitors.iter().count();
+    let mode = display.window_mode(monitor_count);
+    if window.mode != mode {
+        window.mode = mode;
+    }
+    if display.mode.is_fullscreen() {
+        return;
+    }
+
+    match display.placement {
+        // Back where it was left, unless it's headed to another monitor
+        Some(placement) if !monitor_changed => {
+            window.position = WindowPosition::At(placement.position);
+            window
+                .resolution
+                .set(placement.size.x as f32, placement.size.y as f32);
+        }
+        // Startup on the default monitor keeps the platform's placement
+        None if first && display.monitor == 0 => {}
+        _ => {
+            window.position = WindowPosition::Centered(display.monitor_selection(monitor_count));
+        }
+    }
+}
+
+/// Remember the windowed placement once the window stops moving
+fn track_window_placement(
+    time: Res<Time>,
+    mut display: ResMut<DisplaySettings>,
+    mut moved: EventReader<WindowMoved>,
+    mut resized: EventReader<WindowResized>,
+    windows: Query<(Entity, &Window), With<PrimaryWindow>>,
+    mut settle: Local<Option<f32>>,
+    mut last_position: Local<Option<IVec2>>,
+) {
+    let Ok((entity, window)) = windows.get_single() else {
+        return;
+    };
+    let mut touched = resized.read().any(|e| e.window == entity);
+    for event in moved.read().filter(|e| e.window == entity) {
+        *last_position = Some(event.position);
+        touched = true;
+    }
+    if display.mode.is_fullscreen() || window.mode != WindowMode::Windowed {
+        *settle = None;
+        return;
+    }
+    if touched {
+        *settle = Some(PLACEMENT_SETTLE);
+    }
+
+    let Some(left) = settle.as_mut() else {
+        return;
+    };
+    *left -= time.delta_secs();
+    if *left > 0.0 {
+        return;
+    }
+    *settle = None;
+
+    // The latest move beats whatever position was last asked for
+    let requested = match window.position {
+        WindowPosition::At(position) => Some(position),
+        _ => None,
+    };
+    let Some(position) = last_position.or(requested) else {
+        return;
+    };
+    let placement = Some(WindowPlacement {
+        position,
+        size: UVec2::new(window.width() as u32, window.height() as u32),
+    });
+    if display.placement != placement {
+        display.placement = placement;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn modes_cycle_and_fall_back_to_the_primary_monitor() {
+        assert_eq!(DisplayMode::Windowed.stepped(1), DisplayMode::Borderless);
+        assert_eq!(DisplayMode::Windowed.stepped(-1), DisplayMode::Exclusive);
+
+        let display = DisplaySettings {
+            mode: DisplayMode::Exclusive,
+            monitor: 2,
+            placement: None,
+            low_latency: false,
+        };
+        assert_eq!(
+            display.window_mode(3),
+            WindowMode::Fullscreen(MonitorSelection::Index(2))
+        );
+        // Unplugged since last time
+        assert_eq!(
+            display.window_mode(1),
+            WindowMode::Fullscreen(MonitorSelection::Primary)
+        );
+        assert_eq!(display.stepped_monitor(1, 3), 0);
+        assert_eq!(display.stepped_monitor(1, 2), 0);
+        assert_eq!(display.stepped_monitor(-1, 0), 0);
+    }
+
+    #[test]
+    fn low_latency_caps_the_frame_queue() {
+        let mut display = DisplaySettings::default();
+        assert_eq!(display.frame_latency(), None);
+        display.low_latency = true;
+        assert_eq!(display.frame_latency(), NonZeroU32::new(1));
+    }
+
+    #[test]
+    fn monitors_are_labelled_by_name_when_they_have_one() {
+        assert_eq!(monitor_label(0, Some("DELL U2720Q ")), "1: DELL U2720Q");
+        assert_eq!(monitor_label(1, None), "MONITOR 2");
+        assert_eq!(monitor_label(2, Some("")), "MONITOR 3");
+    }
+}
@@ -0,0 +1,342 @@
+//! Gameplay Event Log
+//!
+//! Timestamped ring buffer of recent gameplay events (mission flow, waves,
+//! bosses, pickups, dialogue). Shown by the debug overlay and attached to
+//! bug reports.
+
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+use std::collections::VecDeque;
+
+use super::campaign::{
+    ActCompleteEvent, BossSpawnEvent, MissionCompleteEvent, MissionStartEvent, WaveCompleteEvent,
+};
+use super::events::*;
+use crate::systems::DialogueEvent;
+
+/// Events kept in the log (oldest dropped first)
+pub const EVENT_LOG_CAPACITY: usize = 200;
+
+/// Event log plugin
+pub struct EventLogPlugin;
+
+impl Plugin for EventLogPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EventLog>().add_systems(
+            Update,
+            (
+                log_mission_events,
+                log_boss_events,
+                log_pickup_events,
+                log_dialogue_events,
+                log_combat_events,
+            ),
+        );
+    }
+}
+
+/// Event category (for filtering and display)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventCategory {
+    Mission,
+    Wave,
+    Boss,
+    Pickup,
+    Dialogue,
+    Combat,
+}
+
+impl EventCategory {
+    pub const ALL: [EventCategory; 6] = [
+        EventCategory::Mission,
+        EventCategory::Wave,
+        EventCategory::Boss,
+        EventCategory::Pickup,
+        EventCategory::Dialogue,
+        EventCategory::Combat,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            EventCategory::Mission => "MISSION",
+            EventCategory::Wave => "WAVE",
+            EventCategory::Boss => "BOSS",
+            EventCategory::Pickup => "PICKUP",
+            EventCategory::Dialogue => "DIALOGUE",
+            EventCategory::Combat => "COMBAT",
+        }
+    }
+}
+
+/// A single logged event
+#[derive(Debug, Clone)]
+pub struct LoggedEvent {
+    /// Seconds since app start
+    pub time: f32,
+    pub category: EventCategory,
+    pub message: String,
+}
+
+/// Ring buffer of recent events
+#[derive(Resource, Default)]
+pub struct EventLog {
+    entries: VecDeque<LoggedEvent>,
+}
+
+impl EventLog {
+    /// Record an event, dropping the oldest once full
+    pub fn push(&mut self, time: f32, category: EventCategory, message: impl Into<String>) {
+        if self.entries.len() >= EVENT_LOG_CAPACITY {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(LoggedEvent {
+            time,
+            category,
+            message: message.into(),
+        });
+    }
+
+    /// Entries, oldest first
+    pub fn entries(&self) -> impl Iterator<Item = &LoggedEvent> {
+        self.entries.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Plain-text dump for bug reports, one event per line
+    pub fn to_report(&self) -> String {
+        self.entries
+            .iter()
+            .map(|e| {
+                format!(
+                    "[{:>8.2}] {:<8} {}\n",
+                    e.time,
+                    e.category.label(),
+                    e.message
+                )
+            })
+            .collect()
+    }
+}
+
+// =============================================================================
+// RECORDING
+// =============================================================================
+
+fn log_mission_events(
+    time: Res<Time>,
+    mut log: ResMut<EventLog>,
+    mut start_events: EventReader<MissionStartEvent>,
+    mut complete_events: EventReader<MissionCompleteEvent>,
+    mut wave_events: EventReader<WaveCompleteEvent>,
+    mut act_events: EventReader<ActCompleteEvent>,
+    mut stage_events: EventReader<StageCompleteEvent>,
+) {
+    let now = time.elapsed_secs();
+
+    for event in start_events.read() {
+        log.push(
+            now,
+            EventCategory::Mission,
+            format!(
+                "Mission start: {} ({})",
+                event.mission.name, event.mission.id
+            ),
+        );
+    }
+    for event in complete_events.read() {
+        log.push(
+            now,
+            EventCategory::Mission,
+            format!(
+                "Mission complete: {} in {:.1}s, {} souls, bonus {}",
+                event.mission_id, event.time_taken, event.souls_liberated, event.bonus_achieved
+            ),
+        );
+    }
+    for event in act_events.read() {
+        log.push(
+            now,
+            EventCategory::Mission,
+            format!("Act complete: {}", event.act.name()),
+        );
+    }
+    for event in stage_events.read() {
+        log.push(
+            now,
+            EventCategory::Mission,
+            format!(
+                "Stage {} complete: score {}",
+                event.stage_number, event.score
+            ),
+        );
+    }
+    for event in wave_events.read() {
+        log.push(
+            now,
+            EventCategory::Wave,
+            format!("Wave {} complete", event.wave_number),
+        );
+    }
+}
+
+fn log_boss_events(
+    time: Res<Time>,
+    mut log: ResMut<EventLog>,
+    mut campaign_spawns: EventReader<BossSpawnEvent>,
+    mut stage_spawns: EventReader<crate::systems::BossSpawnEvent>,
+    mut defeats: EventReader<BossDefeatedEvent>,
+    mut stage_defeats: EventReader<crate::systems::BossDefeatedEvent>,
+    mut phases: EventReader<crate::systems::BossPhaseChangeEvent>,
+) {
+    let now = time.elapsed_secs();
+
+    for event in campaign_spawns.read() {
+        log.push(
+            now,
+            EventCategory::Boss,
+            format!("Boss spawned: {:?}", event.boss_type),
+        );
+    }
+    for event in stage_spawns.read() {
+        log.push(
+            now,
+            EventCategory::Boss,
+            format!("Stage boss spawned (stage {})", event.stage),
+        );
+    }
+    for event in defeats.read() {
+        log.push(
+            now,
+            EventCategory::Boss,
+            format!(
+                "Boss defeated: {} (+{})",
+                event.boss_type, event.score_value
+            ),
+        );
+    }
+    for event in phases.read() {
+        log.push(
+            now,
+            EventCategory::Boss,
+            format!(
+                "{} phase {}/{}",
+                event.boss_name, event.phase, event.total_phases
+            ),
+        );
+    }
+    for event in stage_defeats.read() {
+        log.push(
+            now,
+            EventCategory::Boss,
+            format!(
+                "Stage boss defeated: {} (+{})",
+                event.boss_name, event.score
+            ),
+        );
+    }
+}
+
+fn log_pickup_events(
+    time: Res<Time>,
+    mut log: ResMut<EventLog>,
+    mut pickups: EventReader<CollectiblePickedUpEvent>,
+    mut extracted: EventReader<EscapePodExtractedEvent>,
+    mut lost: EventReader<EscapePodLostEvent>,
+) {
+    let now = time.elapsed_secs();
+
+    for event in pickups.read() {
+        log.push(
+            now,
+            EventCategory::Pickup,
+            format!("Picked up {:?} x{}", event.collectible_type, event.value),
+        );
+    }
+    for event in extracted.read() {
+        log.push(
+            now,
+            EventCategory::Pickup,
+            format!("Escape pod extracted ({} souls)", event.souls),
+        );
+    }
+    for event in lost.read() {
+        log.push(
+            now,
+            EventCategory::Pickup,
+            format!("Escape pod lost ({} souls)", event.souls),
+        );
+    }
+}
+
+fn log_dialogue_events(
+    time: Res<Time>,
+    mut log: ResMut<EventLog>,
+    mut dialogue: EventReader<DialogueEvent>,
+) {
+    let now = time.elapsed_secs();
+
+    for event in dialogue.read() {
+        let message = match &event.custom_text {
+            Some(text) => format!("{:?}: \"{}\"", event.trigger, text),
+            None => format!("{:?}", event.trigger),
+        };
+        log.push(now, EventCategory::Dialogue, message);
+    }
+}
+
+fn log_combat_events(
+    time: Res<Time>,
+    mut log: ResMut<EventLog>,
+    mut berserk_on: EventReader<BerserkActivatedEvent>,
+    mut berserk_off: EventReader<BerserkEndedEvent>,
+) {
+    let now = time.elapsed_secs();
+
+    for _ in berserk_on.read() {
+        log.push(now, EventCategory::Combat, "Berserk activated");
+    }
+    for _ in berserk_off.read() {
+        log.push(now, EventCategory::Combat, "Berserk ended");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_log_drops_oldest_when_full() {
+        let mut log = EventLog::default();
+        for i in 0..(EVENT_LOG_CAPACITY + 10) {
+            log.push(i as f32, EventCategory::Wave, format!("Wave {}", i));
+        }
+
+        assert_eq!(log.len(), EVENT_LOG_CAPACITY);
+        let first = log.entries().next().map(|e| e.message.clone());
+        assert_eq!(first.as_deref(), Some("Wave 10"));
+    }
+
+    #[test]
+    fn event_log_report_has_one_line_per_event() {
+        let mut log = EventLog::default();
+        log.push(1.5, EventCategory::Mission, "Mission start");
+        log.push(2.25, EventCategory::Boss, "Boss spawned");
+
+        let report = log.to_report();
+        assert_eq!(report.lines().count(), 2);
+        assert!(report.contains("MISSION"));
+        assert!(report.contains("Boss spawned"));
+    }
+}
@@ -6,20 +6,54 @@
 
 use bevy::prelude::*;
 
+use super::campaign::{
+    ActCompleteEvent, BossSpawnEvent, MissionCompleteEvent, MissionStartEvent, WaveCompleteEvent,
+};
+use super::resources::ScoreCategory;
+
 /// Player took damage
 #[derive(Event)]
 pub struct PlayerDamagedEvent {
     pub damage: f32,
     pub damage_type: DamageType,
     pub source_position: Vec2,
+    /// What dealt it: the shooter's name, or the hazard
+    pub source: String,
+    /// How much each tank layer absorbed
+    pub layers: DamageLayers,
+}
+
+/// Damage absorbed by each tank layer in one hit
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DamageLayers {
+    pub shield: f32,
+    pub armor: f32,
+    pub hull: f32,
+}
+
+/// Player's ship was destroyed; the death sequence plays before game over
+#[derive(Event)]
+pub struct PlayerDestroyedEvent {
+    pub position: Vec2,
+}
+
+/// A module's whole campaign was cleared (by module id)
+#[derive(Event)]
+pub struct CampaignCompleteEvent {
+    pub module: &'static str,
 }
 
 /// Enemy was destroyed
 #[derive(Event)]
 pub struct EnemyDestroyedEvent {
+    pub entity: Entity,
     pub position: Vec2,
     pub enemy_type: String,
+    /// EVE type ID of the destroyed ship
+    pub type_id: u32,
     pub score_value: u64,
+    /// Points the kill actually added to the score (all multipliers applied)
+    pub points_awarded: u64,
     pub was_boss: bool,
 }
 
@@ -78,6 +112,82 @@ pub struct CollectiblePickedUpEvent {
     pub value: u32,
 }
 
+/// Escape pod reached the screen edge with its souls aboard
+#[derive(Event)]
+pub struct EscapePodExtractedEvent {
+    pub position: Vec2,
+    pub souls: u32,
+}
+
+/// Escape pod destroyed by enemy fire before extraction
+#[derive(Event)]
+pub struct EscapePodLostEvent {
+    pub position: Vec2,
+    pub souls: u32,
+}
+
+/// Escorted transport made it across the screen
+#[derive(Event)]
+pub struct EscortArrivedEvent {
+    /// Index of the objective in the `ObjectiveTracker`
+    pub objective: usize,
+    pub position: Vec2,
+}
+
+/// Escorted or defended target destroyed
+#[derive(Event)]
+pub struct ProtectedTargetLostEvent {
+    /// Index of the objective in the `ObjectiveTracker`
+    pub objective: usize,
+    pub position: Vec2,
+}
+
+/// Capture progress on a structure changed
+#[derive(Event)]
+pub struct CaptureProgressEvent {
+    /// Index of the objective in the `ObjectiveTracker`
+    pub objective: usize,
+    /// 0.0 - 1.0
+    pub progress: f32,
+    /// Enemies inside the capture radius
+    pub contested: bool,
+}
+
+/// Player flew a cargo crate into the drop zone
+#[derive(Event)]
+pub struct CargoDeliveredEvent {
+    pub position: Vec2,
+}
+
+/// Points earned for anything other than a direct kill. Any system can
+/// send one; the category's weight for the active module is applied when
+/// it's scored.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ScoreEvent {
+    pub category: ScoreCategory,
+    /// Points before the category weight and score multipliers
+    pub base: u64,
+    /// Where it happened, if anywhere in particular
+    pub position: Option<Vec2>,
+}
+
+impl ScoreEvent {
+    pub fn new(category: ScoreCategory, base: u64) -> Self {
+        Self {
+            category,
+            base,
+            position: None,
+        }
+    }
+
+    pub fn at(self, position: Vec2) -> Self {
+        Self {
+            position: Some(position),
+            ..self
+        }
+    }
+}
+
 /// Berserk mode activated
 #[derive(Event)]
 pub struct BerserkActivatedEvent;
@@ -121,6 +231,17 @@ pub enum DamageType {
     Explosive, // Missiles, artillery
 }
 
+impl DamageType {
+    pub fn label(&self) -> &'static str {
+        match self {
+            DamageType::EM => "EM",
+            DamageType::Thermal => "Thermal",
+            DamageType::Kinetic => "Kinetic",
+            DamageType::Explosive => "Explosive",
+        }
+    }
+}
+
 /// Weapon types
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WeaponType {
@@ -156,6 +277,8 @@ pub enum CollectibleType {
     DamageBoost,     // Temporary damage boost
     Invulnerability, // Temporary invincibility
     Nanite,          // Reduces weapon heat
+    TractorBeam,     // Widens the pickup magnet
+    Nosferatu,       // Kills repair hull and armor for a while
     ExtraLife,
 }
 
@@ -202,12 +325,25 @@ pub enum SoundType {
     GameOver,
 }
 
-/// Plugin to register all events
+/// Plugin to register all shared gameplay events
+///
+/// Plugin-local events (boss stage events, dialogue, etc.) are registered by
+/// their owning plugins; everything else goes through here.
 pub struct GameEventsPlugin;
 
 impl Plugin for GameEventsPlugin {
     fn build(&self, app: &mut App) {
+        // Campaign flow
+        app.add_event::<MissionStartEvent>()
+            .add_event::<MissionCompleteEvent>()
+            .add_event::<WaveCompleteEvent>()
+            .add_event::<BossSpawnEvent>()
+            .add_event::<ActCompleteEvent>()
+            .add_event::<CampaignCompleteEvent>();
+
+        // Gameplay
         app.add_event::<PlayerDamagedEvent>()
+            .add_event::<PlayerDestroyedEvent>()
             .add_event::<EnemyDestroyedEvent>()
             .add_event::<PlayerFireEvent>()
             .add_event::<SpawnEnemyEvent>()
@@ -215,6 +351,13 @@ impl Plugin for GameEventsPlugin {
             .add_event::<StageCompleteEvent>()
             .add_event::<BossDefeatedEvent>()
             .add_event::<CollectiblePickedUpEvent>()
+            .add_event::<EscapePodExtractedEvent>()
+            .add_event::<EscapePodLostEvent>()
+            .add_event::<EscortArrivedEvent>()
+            .add_event::<ProtectedTargetLostEvent>()
+            .add_event::<CargoDeliveredEvent>()
+            .add_event::<ScoreEvent>()
+            .add_event::<CaptureProgressEvent>()
             .add_event::<BerserkActivatedEvent>()
             .add_event::<BerserkEndedEvent>()
             .add_event::<ScreenShakeEvent>()
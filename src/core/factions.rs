@@ -4,59 +4,12 @@
 
 #![allow(dead_code)]
 
+use super::events::WeaponType;
 use bevy::prelude::*;
 
-/// The four major factions of New Eden
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
-pub enum Faction {
-    #[default]
-    Minmatar,
-    Amarr,
-    Caldari,
-    Gallente,
-}
+use crate::core_logic::Faction;
 
 impl Faction {
-    /// All factions
-    pub fn all() -> &'static [Faction] {
-        &[
-            Faction::Minmatar,
-            Faction::Amarr,
-            Faction::Caldari,
-            Faction::Gallente,
-        ]
-    }
-
-    /// Faction display name
-    pub fn name(&self) -> &'static str {
-        match self {
-            Faction::Minmatar => "Minmatar Republic",
-            Faction::Amarr => "Amarr Empire",
-            Faction::Caldari => "Caldari State",
-            Faction::Gallente => "Gallente Federation",
-        }
-    }
-
-    /// Short name
-    pub fn short_name(&self) -> &'static str {
-        match self {
-            Faction::Minmatar => "MINMATAR",
-            Faction::Amarr => "AMARR",
-            Faction::Caldari => "CALDARI",
-            Faction::Gallente => "GALLENTE",
-        }
-    }
-
-    /// Faction tagline
-    pub fn tagline(&self) -> &'static str {
-        match self {
-            Faction::Minmatar => "In Rust We Trust",
-            Faction::Amarr => "Amarr Victor",
-            Faction::Caldari => "The State Provides",
-            Faction::Gallente => "Liberty or Death",
-        }
-    }
-
     /// Primary color (bright accent)
     pub fn primary_color(&self) -> Color {
         match self {
@@ -77,6 +30,16 @@ impl Faction {
         }
     }
 
+    /// Accent color (light, for UI text and highlights)
+    pub fn accent_color(&self) -> Color {
+        match self {
+            Faction::Minmatar => Color::srgb(0.85, 0.62, 0.32), // Amber
+            Faction::Amarr => Color::srgb(1.0, 0.92, 0.6),      // Pale gold
+            Faction::Caldari => Color::srgb(0.62, 0.8, 0.95),   // Ice blue
+            Faction::Gallente => Color::srgb(0.65, 0.85, 0.5),  // Sage
+        }
+    }
+
     /// Engine trail color
     pub fn engine_color(&self) -> Color {
         match self {
@@ -107,36 +70,6 @@ impl Faction {
         }
     }
 
-    /// Default enemy faction
-    pub fn rival(&self) -> Faction {
-        match self {
-            Faction::Minmatar => Faction::Amarr,
-            Faction::Amarr => Faction::Minmatar,
-            Faction::Caldari => Faction::Gallente,
-            Faction::Gallente => Faction::Caldari,
-        }
-    }
-
-    /// Campaign intro text
-    pub fn story_intro(&self) -> &'static str {
-        match self {
-            Faction::Minmatar => "Your ancestors were enslaved by the Amarr Empire. Generations suffered under their golden heel. But the Minmatar spirit cannot be broken. Today, you strike back.",
-            Faction::Amarr => "The Minmatar rebels threaten the divine order of the Empire. As a loyal servant of the Empress, you will crush this insurrection and restore peace through strength.",
-            Faction::Caldari => "The Gallente Federation encroaches on State interests. Corporate profits demand action. You are the blade of the megacorporations.",
-            Faction::Gallente => "The Caldari State oppresses its workers and threatens Federation sovereignty. Fight for liberty against corporate tyranny.",
-        }
-    }
-
-    /// Victory text
-    pub fn victory_text(&self) -> &'static str {
-        match self {
-            Faction::Minmatar => "The Amarr fleet lies in ruins. Slaves are free. The Republic stands defiant. You are legend.",
-            Faction::Amarr => "The rebellion is crushed. Order is restored. The Empire endures eternal. Glory to Amarr.",
-            Faction::Caldari => "Gallente forces are scattered. The trade lanes are secure. The State prospers.",
-            Faction::Gallente => "The Caldari fleet retreats. Freedom rings across the stars. Vive la Fédération!",
-        }
-    }
-
     /// Get player ships for this faction
     pub fn player_ships(&self) -> &'static [ShipDef] {
         match self {
@@ -157,41 +90,17 @@ impl Faction {
         }
     }
 
-    /// Get carrier type_id for this faction (used for wave spawning visuals)
-    pub fn carrier_type_id(&self) -> u32 {
-        match self {
-            Faction::Minmatar => 24483, // Nidhoggur
-            Faction::Amarr => 23757,    // Archon
-            Faction::Caldari => 23915,  // Chimera
-            Faction::Gallente => 23911, // Thanatos
-        }
-    }
-
-    /// Get a fighter/drone type_id for this faction (used by boss spawners)
-    /// Returns a small, fast frigate appropriate for the faction
-    pub fn fighter_type_id(&self) -> u32 {
-        match self {
-            Faction::Minmatar => 585, // Slasher - fast interceptor
-            Faction::Amarr => 589,    // Executioner - fast interceptor
-            Faction::Caldari => 583,  // Condor - fast frigate
-            Faction::Gallente => 608, // Atron - fast frigate
-        }
-    }
-
-    /// Get a tougher drone type_id for this faction (used by boss spawners)
-    /// Returns a tankier frigate appropriate for the faction
-    pub fn tough_fighter_type_id(&self) -> u32 {
-        match self {
-            Faction::Minmatar => 598, // Breacher - tanky missile boat
-            Faction::Amarr => 591,    // Tormentor - tanky laser boat
-            Faction::Caldari => 602,  // Kestrel - tanky missile boat
-            Faction::Gallente => 594, // Incursus - tanky blaster boat
-        }
+    /// Find an enemy definition by type ID across all factions
+    pub fn find_enemy_def(type_id: u32) -> Option<&'static EnemyShipDef> {
+        Faction::all()
+            .iter()
+            .flat_map(|f| f.enemy_ships().iter())
+            .find(|e| e.type_id == type_id)
     }
 }
 
 /// Weapon doctrine types
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum WeaponDoctrine {
     Projectile, // Minmatar - autocannons, fast ROF, selectable damage
     Laser,      // Amarr - pulse/beam, instant hit, capacitor hungry
@@ -209,6 +118,37 @@ impl WeaponDoctrine {
         }
     }
 
+    /// Distance (pixels) the doctrine is built to fight at. Damage doesn't
+    /// fall off with range yet; the targeting aid marks the band already.
+    pub fn optimal_range(&self) -> f32 {
+        match self {
+            WeaponDoctrine::Projectile => 280.0,
+            WeaponDoctrine::Laser => 380.0,
+            WeaponDoctrine::Missile => 460.0,
+            WeaponDoctrine::Hybrid => 200.0,
+        }
+    }
+
+    /// Distance past optimal over which damage would taper off
+    pub fn falloff(&self) -> f32 {
+        match self {
+            WeaponDoctrine::Projectile => 200.0,
+            WeaponDoctrine::Laser => 100.0,
+            WeaponDoctrine::Missile => 120.0,
+            WeaponDoctrine::Hybrid => 140.0,
+        }
+    }
+
+    /// Doctrine a weapon belongs to (drones fly with the Gallente hybrids)
+    pub fn for_weapon(weapon: WeaponType) -> Self {
+        match weapon {
+            WeaponType::Autocannon | WeaponType::Artillery => WeaponDoctrine::Projectile,
+            WeaponType::Laser => WeaponDoctrine::Laser,
+            WeaponType::MissileLauncher => WeaponDoctrine::Missile,
+            WeaponType::Railgun | WeaponType::Drone => WeaponDoctrine::Hybrid,
+        }
+    }
+
     /// Projectile color
     pub fn bullet_color(&self) -> Color {
         match self {
@@ -228,6 +168,62 @@ pub enum TankDoctrine {
     Speed,  // Minmatar - low HP, high speed/evasion
 }
 
+impl TankDoctrine {
+    pub fn name(&self) -> &'static str {
+        match self {
+            TankDoctrine::Shield => "Shield",
+            TankDoctrine::Armor => "Armor",
+            TankDoctrine::Speed => "Speed",
+        }
+    }
+
+    /// One-line summary for the ship select panel
+    pub fn description(&self) -> &'static str {
+        match self {
+            TankDoctrine::Shield => "Fast passive shield regen after 2s out of fire",
+            TankDoctrine::Armor => "Armor repairer cycles on capacitor while damaged",
+            TankDoctrine::Speed => "+15% speed, smaller signature (hitbox)",
+        }
+    }
+
+    /// Shield recharge rate multiplier
+    pub fn shield_recharge_mult(&self) -> f32 {
+        match self {
+            TankDoctrine::Shield => 2.0,
+            TankDoctrine::Armor | TankDoctrine::Speed => 0.5,
+        }
+    }
+
+    /// Shield recharge delay multiplier
+    pub fn shield_delay_mult(&self) -> f32 {
+        match self {
+            TankDoctrine::Shield => 2.0 / 3.0, // 2s instead of 3s
+            TankDoctrine::Armor | TankDoctrine::Speed => 1.0,
+        }
+    }
+
+    /// Whether the ship runs an active armor repairer
+    pub fn has_armor_repairer(&self) -> bool {
+        matches!(self, TankDoctrine::Armor)
+    }
+
+    /// Max speed and acceleration multiplier
+    pub fn speed_mult(&self) -> f32 {
+        match self {
+            TankDoctrine::Speed => 1.15,
+            TankDoctrine::Shield | TankDoctrine::Armor => 1.0,
+        }
+    }
+
+    /// Hitbox radius multiplier
+    pub fn hitbox_mult(&self) -> f32 {
+        match self {
+            TankDoctrine::Speed => 0.75,
+            TankDoctrine::Shield | TankDoctrine::Armor => 1.0,
+        }
+    }
+}
+
 // ============================================================================
 // SHIP DEFINITIONS
 // ============================================================================
@@ -245,8 +241,36 @@ pub struct ShipDef {
     pub damage: f32,
     pub special: &'static str,
     pub unlock_stage: u32, // 0 = always available
+    /// Turret mounts (see `HARDPOINTS_*`)
+    pub hardpoints: &'static [Vec2],
 }
 
+// Turret layouts: mount offsets as fractions of hull size, bow at +Y
+
+/// One turret on the nose
+pub const HARDPOINTS_NOSE: &[Vec2] = &[Vec2::new(0.0, 0.34)];
+
+/// A pair flanking the bow
+pub const HARDPOINTS_TWIN: &[Vec2] = &[Vec2::new(-0.2, 0.2), Vec2::new(0.2, 0.2)];
+
+/// Wide pair on the wings (launcher hulls)
+pub const HARDPOINTS_WING: &[Vec2] = &[Vec2::new(-0.36, -0.02), Vec2::new(0.36, -0.02)];
+
+/// Nose turret plus a pair amidships
+pub const HARDPOINTS_TRIPLE: &[Vec2] = &[
+    Vec2::new(0.0, 0.32),
+    Vec2::new(-0.26, 0.04),
+    Vec2::new(0.26, 0.04),
+];
+
+/// Two pairs, bow and amidships
+pub const HARDPOINTS_QUAD: &[Vec2] = &[
+    Vec2::new(-0.16, 0.26),
+    Vec2::new(0.16, 0.26),
+    Vec2::new(-0.32, -0.04),
+    Vec2::new(0.32, -0.04),
+];
+
 /// Enemy ship definition
 #[derive(Debug, Clone, Copy)]
 pub struct EnemyShipDef {
@@ -258,6 +282,187 @@ pub struct EnemyShipDef {
     pub damage: f32,
     pub spawn_weight: u32,
     pub score: u32,
+    /// Tactical AI weights
+    pub ai: AiProfile,
+    /// Faction firing doctrine
+    pub attack: AttackProfile,
+}
+
+/// Utility weights for enemy tactics (0.0 = never, 1.0 = strongly preferred)
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct AiProfile {
+    /// Swing out to the player's side
+    pub flank: f32,
+    /// Health fraction below which the ship pulls back (0.0 = never)
+    pub retreat_health: f32,
+    /// Guard a boss when one is on the field
+    pub escort: f32,
+    /// Ram the player when hurt or close
+    pub kamikaze: f32,
+    /// Hold a firing line and shoot in sync with other gunships
+    pub volley: f32,
+}
+
+impl AiProfile {
+    /// Just follows its movement pattern
+    pub const PASSIVE: Self = Self {
+        flank: 0.0,
+        retreat_health: 0.0,
+        escort: 0.0,
+        kamikaze: 0.0,
+        volley: 0.0,
+    };
+
+    /// Fast frigates that work the player's flanks
+    pub const SKIRMISHER: Self = Self {
+        flank: 0.9,
+        retreat_health: 0.3,
+        escort: 0.2,
+        kamikaze: 0.0,
+        volley: 0.3,
+    };
+
+    /// Interceptors that dive in and ram when damaged
+    pub const RAIDER: Self = Self {
+        flank: 0.6,
+        retreat_health: 0.0,
+        escort: 0.0,
+        kamikaze: 0.8,
+        volley: 0.0,
+    };
+
+    /// Armored frigates that guard the big ships
+    pub const BRAWLER: Self = Self {
+        flank: 0.4,
+        retreat_health: 0.0,
+        escort: 0.7,
+        kamikaze: 0.3,
+        volley: 0.3,
+    };
+
+    /// Support ships that stay glued to the boss
+    pub const ESCORT: Self = Self {
+        flank: 0.3,
+        retreat_health: 0.25,
+        escort: 1.0,
+        kamikaze: 0.0,
+        volley: 0.4,
+    };
+
+    /// Long-range gunships that fire coordinated volleys
+    pub const GUNLINE: Self = Self {
+        flank: 0.2,
+        retreat_health: 0.35,
+        escort: 0.3,
+        kamikaze: 0.0,
+        volley: 0.9,
+    };
+
+    /// Battlecruisers: slow, hold the line, pull back when crippled
+    pub const HEAVY: Self = Self {
+        flank: 0.1,
+        retreat_health: 0.2,
+        escort: 0.4,
+        kamikaze: 0.0,
+        volley: 0.7,
+    };
+}
+
+/// How an enemy ship delivers its fire
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AttackProfile {
+    /// Shots per trigger pull
+    pub burst: u32,
+    /// Seconds between shots within a burst
+    pub burst_gap: f32,
+    /// Arc (radians) a burst sweeps across the aim line
+    pub sweep: f32,
+    /// Turn rate toward the player (radians/s, 0.0 = unguided)
+    pub homing: f32,
+    /// Projectile speed multiplier
+    pub speed_mult: f32,
+    /// Per-shot damage multiplier
+    pub damage_mult: f32,
+    /// Range the ship closes to before opening fire (0.0 = any range)
+    pub engage_range: f32,
+}
+
+impl Default for AttackProfile {
+    fn default() -> Self {
+        Self::DIRECT
+    }
+}
+
+impl AttackProfile {
+    /// One aimed shot per cycle
+    pub const DIRECT: Self = Self {
+        burst: 1,
+        burst_gap: 0.0,
+        sweep: 0.0,
+        homing: 0.0,
+        speed_mult: 1.0,
+        damage_mult: 1.0,
+        engage_range: 0.0,
+    };
+
+    /// Amarr: quick bursts of thin, fast beam pulses
+    pub const BEAM_BURST: Self = Self {
+        burst: 3,
+        burst_gap: 0.06,
+        sweep: 0.0,
+        homing: 0.0,
+        speed_mult: 1.6,
+        damage_mult: 0.45,
+        engage_range: 0.0,
+    };
+
+    /// Caldari: slow missiles that bend toward the player
+    pub const HOMING_MISSILE: Self = Self {
+        burst: 1,
+        burst_gap: 0.0,
+        sweep: 0.0,
+        homing: 1.4,
+        speed_mult: 0.7,
+        damage_mult: 1.0,
+        engage_range: 0.0,
+    };
+
+    /// Gallente: press in to blaster range, then unload a double tap
+    pub const BLASTER: Self = Self {
+        burst: 2,
+        burst_gap: 0.08,
+        sweep: 0.0,
+        homing: 0.0,
+        speed_mult: 1.1,
+        damage_mult: 0.8,
+        engage_range: 260.0,
+    };
+
+    /// Minmatar: tracer streams raked across the player's lane
+    pub const TRACER_STREAM: Self = Self {
+        burst: 6,
+        burst_gap: 0.05,
+        sweep: 0.35,
+        homing: 0.0,
+        speed_mult: 1.2,
+        damage_mult: 0.25,
+        engage_range: 0.0,
+    };
+
+    /// Whether a target `distance` away is close enough to shoot at
+    pub fn in_range(&self, distance: f32) -> bool {
+        self.engage_range <= 0.0 || distance <= self.engage_range
+    }
+
+    /// Aim offset (radians) for the `shot`th round of a burst, walking
+    /// evenly from one edge of the sweep to the other
+    pub fn sweep_angle(&self, shot: u32) -> f32 {
+        if self.burst <= 1 {
+            return 0.0;
+        }
+        let t = shot.min(self.burst - 1) as f32 / (self.burst - 1) as f32;
+        self.sweep * (t - 0.5)
+    }
 }
 
 /// Ship class
@@ -319,6 +524,7 @@ pub const MINMATAR_SHIPS: &[ShipDef] = &[
         damage: 10.0,
         special: "Overdrive: +50% speed burst",
         unlock_stage: 0,
+        hardpoints: HARDPOINTS_TRIPLE,
     },
     ShipDef {
         type_id: 585,
@@ -331,6 +537,7 @@ pub const MINMATAR_SHIPS: &[ShipDef] = &[
         damage: 7.0,
         special: "Afterburner: Invulnerable dash",
         unlock_stage: 0,
+        hardpoints: HARDPOINTS_TWIN,
     },
     ShipDef {
         type_id: 598,
@@ -343,6 +550,7 @@ pub const MINMATAR_SHIPS: &[ShipDef] = &[
         damage: 18.0,
         special: "Rocket Barrage: Triple spread",
         unlock_stage: 0,
+        hardpoints: HARDPOINTS_WING,
     },
     ShipDef {
         type_id: 11371,
@@ -355,6 +563,7 @@ pub const MINMATAR_SHIPS: &[ShipDef] = &[
         damage: 15.0,
         special: "Gyrostabilizer: +100% fire rate",
         unlock_stage: 4, // Unlocks after Act 1
+        hardpoints: HARDPOINTS_QUAD,
     },
     ShipDef {
         type_id: 11400,
@@ -367,6 +576,7 @@ pub const MINMATAR_SHIPS: &[ShipDef] = &[
         damage: 25.0,
         special: "Rocket Swarm: Tracking missiles",
         unlock_stage: 9, // Unlocks after Act 2
+        hardpoints: HARDPOINTS_WING,
     },
 ];
 
@@ -380,6 +590,8 @@ pub const MINMATAR_ENEMIES: &[EnemyShipDef] = &[
         damage: 8.0,
         spawn_weight: 30,
         score: 100,
+        ai: AiProfile::SKIRMISHER,
+        attack: AttackProfile::TRACER_STREAM,
     },
     EnemyShipDef {
         type_id: 585,
@@ -390,6 +602,8 @@ pub const MINMATAR_ENEMIES: &[EnemyShipDef] = &[
         damage: 5.0,
         spawn_weight: 25,
         score: 75,
+        ai: AiProfile::RAIDER,
+        attack: AttackProfile::TRACER_STREAM,
     },
     EnemyShipDef {
         type_id: 598,
@@ -400,6 +614,8 @@ pub const MINMATAR_ENEMIES: &[EnemyShipDef] = &[
         damage: 12.0,
         spawn_weight: 20,
         score: 125,
+        ai: AiProfile::GUNLINE,
+        attack: AttackProfile::TRACER_STREAM,
     },
 ];
 
@@ -419,6 +635,7 @@ pub const AMARR_SHIPS: &[ShipDef] = &[
         damage: 12.0,
         special: "Scorch: Extended laser range",
         unlock_stage: 0,
+        hardpoints: HARDPOINTS_TWIN,
     },
     ShipDef {
         type_id: 597,
@@ -431,6 +648,7 @@ pub const AMARR_SHIPS: &[ShipDef] = &[
         damage: 14.0,
         special: "Armor Hardener: -50% damage",
         unlock_stage: 0,
+        hardpoints: HARDPOINTS_TRIPLE,
     },
     ShipDef {
         type_id: 591,
@@ -443,6 +661,7 @@ pub const AMARR_SHIPS: &[ShipDef] = &[
         damage: 10.0,
         special: "Deploy Drone: Autonomous fighter",
         unlock_stage: 0,
+        hardpoints: HARDPOINTS_NOSE,
     },
     ShipDef {
         type_id: 11186,
@@ -455,6 +674,7 @@ pub const AMARR_SHIPS: &[ShipDef] = &[
         damage: 8.0,
         special: "Microwarpdrive: Extreme speed",
         unlock_stage: 4, // Unlocks after Act 1
+        hardpoints: HARDPOINTS_TWIN,
     },
     ShipDef {
         type_id: 11184,
@@ -467,6 +687,7 @@ pub const AMARR_SHIPS: &[ShipDef] = &[
         damage: 15.0,
         special: "Tackle: Slow enemies on hit",
         unlock_stage: 9, // Unlocks after Act 2
+        hardpoints: HARDPOINTS_WING,
     },
 ];
 
@@ -480,6 +701,8 @@ pub const AMARR_ENEMIES: &[EnemyShipDef] = &[
         damage: 10.0,
         spawn_weight: 30,
         score: 100,
+        ai: AiProfile::RAIDER,
+        attack: AttackProfile::BEAM_BURST,
     },
     EnemyShipDef {
         type_id: 597,
@@ -490,6 +713,8 @@ pub const AMARR_ENEMIES: &[EnemyShipDef] = &[
         damage: 12.0,
         spawn_weight: 25,
         score: 150,
+        ai: AiProfile::BRAWLER,
+        attack: AttackProfile::BEAM_BURST,
     },
     EnemyShipDef {
         type_id: 591,
@@ -500,6 +725,8 @@ pub const AMARR_ENEMIES: &[EnemyShipDef] = &[
         damage: 8.0,
         spawn_weight: 20,
         score: 100,
+        ai: AiProfile::ESCORT,
+        attack: AttackProfile::BEAM_BURST,
     },
     EnemyShipDef {
         type_id: 16236,
@@ -510,6 +737,8 @@ pub const AMARR_ENEMIES: &[EnemyShipDef] = &[
         damage: 18.0,
         spawn_weight: 15,
         score: 250,
+        ai: AiProfile::GUNLINE,
+        attack: AttackProfile::BEAM_BURST,
     },
     EnemyShipDef {
         type_id: 24690,
@@ -520,6 +749,8 @@ pub const AMARR_ENEMIES: &[EnemyShipDef] = &[
         damage: 30.0,
         spawn_weight: 5,
         score: 500,
+        ai: AiProfile::HEAVY,
+        attack: AttackProfile::BEAM_BURST,
     },
 ];
 
@@ -539,6 +770,7 @@ pub const CALDARI_SHIPS: &[ShipDef] = &[
         damage: 16.0,
         special: "Salvo: 4 missiles at once",
         unlock_stage: 0,
+        hardpoints: HARDPOINTS_WING,
     },
     ShipDef {
         type_id: 603,
@@ -551,6 +783,7 @@ pub const CALDARI_SHIPS: &[ShipDef] = &[
         damage: 11.0,
         special: "Shield Boost: Instant regen",
         unlock_stage: 0,
+        hardpoints: HARDPOINTS_TRIPLE,
     },
     ShipDef {
         type_id: 583,
@@ -563,6 +796,7 @@ pub const CALDARI_SHIPS: &[ShipDef] = &[
         damage: 12.0,
         special: "Warp Disruptor: Slow enemies",
         unlock_stage: 0,
+        hardpoints: HARDPOINTS_TWIN,
     },
     ShipDef {
         type_id: 11381,
@@ -575,6 +809,7 @@ pub const CALDARI_SHIPS: &[ShipDef] = &[
         damage: 20.0,
         special: "Assault Launchers: +50% damage",
         unlock_stage: 4, // Unlocks after Act 1
+        hardpoints: HARDPOINTS_QUAD,
     },
     ShipDef {
         type_id: 11387,
@@ -587,6 +822,7 @@ pub const CALDARI_SHIPS: &[ShipDef] = &[
         damage: 28.0,
         special: "Optimal Range: Bonus at distance",
         unlock_stage: 4, // Unlocks after Act 1
+        hardpoints: HARDPOINTS_TWIN,
     },
     ShipDef {
         type_id: 35683,
@@ -599,6 +835,7 @@ pub const CALDARI_SHIPS: &[ShipDef] = &[
         damage: 22.0,
         special: "Mode Switch: Defense/Speed/Sniper",
         unlock_stage: 9, // Unlocks after Act 2
+        hardpoints: HARDPOINTS_QUAD,
     },
 ];
 
@@ -612,6 +849,8 @@ pub const CALDARI_ENEMIES: &[EnemyShipDef] = &[
         damage: 12.0,
         spawn_weight: 30,
         score: 100,
+        ai: AiProfile::GUNLINE,
+        attack: AttackProfile::HOMING_MISSILE,
     },
     EnemyShipDef {
         type_id: 603,
@@ -622,6 +861,8 @@ pub const CALDARI_ENEMIES: &[EnemyShipDef] = &[
         damage: 9.0,
         spawn_weight: 25,
         score: 125,
+        ai: AiProfile::BRAWLER,
+        attack: AttackProfile::DIRECT,
     },
     EnemyShipDef {
         type_id: 583,
@@ -632,6 +873,8 @@ pub const CALDARI_ENEMIES: &[EnemyShipDef] = &[
         damage: 8.0,
         spawn_weight: 25,
         score: 75,
+        ai: AiProfile::RAIDER,
+        attack: AttackProfile::HOMING_MISSILE,
     },
     EnemyShipDef {
         type_id: 16238,
@@ -642,6 +885,8 @@ pub const CALDARI_ENEMIES: &[EnemyShipDef] = &[
         damage: 15.0,
         spawn_weight: 12,
         score: 200,
+        ai: AiProfile::GUNLINE,
+        attack: AttackProfile::DIRECT,
     },
     EnemyShipDef {
         type_id: 24688,
@@ -652,6 +897,8 @@ pub const CALDARI_ENEMIES: &[EnemyShipDef] = &[
         damage: 25.0,
         spawn_weight: 5,
         score: 500,
+        ai: AiProfile::HEAVY,
+        attack: AttackProfile::HOMING_MISSILE,
     },
 ];
 
@@ -671,6 +918,7 @@ pub const GALLENTE_SHIPS: &[ShipDef] = &[
         damage: 8.0,
         special: "Drones: 2 autonomous fighters",
         unlock_stage: 0,
+        hardpoints: HARDPOINTS_NOSE,
     },
     ShipDef {
         type_id: 594,
@@ -683,6 +931,7 @@ pub const GALLENTE_SHIPS: &[ShipDef] = &[
         damage: 10.0,
         special: "Armor Repair: Heal over time",
         unlock_stage: 0,
+        hardpoints: HARDPOINTS_TRIPLE,
     },
     ShipDef {
         type_id: 608,
@@ -695,6 +944,7 @@ pub const GALLENTE_SHIPS: &[ShipDef] = &[
         damage: 6.0,
         special: "Close Range: +100% damage in melee",
         unlock_stage: 0,
+        hardpoints: HARDPOINTS_TWIN,
     },
     ShipDef {
         type_id: 11373,
@@ -707,6 +957,7 @@ pub const GALLENTE_SHIPS: &[ShipDef] = &[
         damage: 14.0,
         special: "Void: Maximum damage ammo",
         unlock_stage: 4, // Unlocks after Act 1
+        hardpoints: HARDPOINTS_QUAD,
     },
     ShipDef {
         type_id: 11377,
@@ -719,6 +970,7 @@ pub const GALLENTE_SHIPS: &[ShipDef] = &[
         damage: 10.0,
         special: "Heavy Drones: 3 strong fighters",
         unlock_stage: 4, // Unlocks after Act 1
+        hardpoints: HARDPOINTS_TWIN,
     },
     ShipDef {
         type_id: 35685,
@@ -731,6 +983,7 @@ pub const GALLENTE_SHIPS: &[ShipDef] = &[
         damage: 18.0,
         special: "Mode Switch: Defense/Speed/Sniper",
         unlock_stage: 9, // Unlocks after Act 2
+        hardpoints: HARDPOINTS_QUAD,
     },
 ];
 
@@ -744,6 +997,8 @@ pub const GALLENTE_ENEMIES: &[EnemyShipDef] = &[
         damage: 7.0,
         spawn_weight: 30,
         score: 100,
+        ai: AiProfile::ESCORT,
+        attack: AttackProfile::BLASTER,
     },
     EnemyShipDef {
         type_id: 594,
@@ -754,6 +1009,8 @@ pub const GALLENTE_ENEMIES: &[EnemyShipDef] = &[
         damage: 9.0,
         spawn_weight: 25,
         score: 125,
+        ai: AiProfile::BRAWLER,
+        attack: AttackProfile::BLASTER,
     },
     EnemyShipDef {
         type_id: 608,
@@ -764,6 +1021,8 @@ pub const GALLENTE_ENEMIES: &[EnemyShipDef] = &[
         damage: 6.0,
         spawn_weight: 25,
         score: 75,
+        ai: AiProfile::RAIDER,
+        attack: AttackProfile::BLASTER,
     },
     EnemyShipDef {
         type_id: 16242,
@@ -774,6 +1033,8 @@ pub const GALLENTE_ENEMIES: &[EnemyShipDef] = &[
         damage: 20.0,
         spawn_weight: 12,
         score: 200,
+        ai: AiProfile::SKIRMISHER,
+        attack: AttackProfile::BLASTER,
     },
     EnemyShipDef {
         type_id: 24700,
@@ -784,6 +1045,74 @@ pub const GALLENTE_ENEMIES: &[EnemyShipDef] = &[
         damage: 22.0,
         spawn_weight: 5,
         score: 450,
+        ai: AiProfile::HEAVY,
+        attack: AttackProfile::BLASTER,
+    },
+];
+
+// ============================================================================
+// SHIP SKINS
+// ============================================================================
+
+/// Paint job multiplied over the ship sprite
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShipSkin {
+    pub name: &'static str,
+    pub tint: Color,
+    /// Prestige points needed to paint it (0 = always available)
+    pub prestige: u32,
+}
+
+impl ShipSkin {
+    /// Tint a base color with this paint job
+    pub fn apply(&self, color: Color) -> Color {
+        let base = color.to_linear();
+        let tint = self.tint.to_linear();
+        Color::LinearRgba(LinearRgba::new(
+            base.red * tint.red,
+            base.green * tint.green,
+            base.blue * tint.blue,
+            base.alpha,
+        ))
+    }
+}
+
+/// Paint jobs selectable for any hull
+pub const SHIP_SKINS: &[ShipSkin] = &[
+    ShipSkin {
+        name: "Factory Finish",
+        tint: Color::WHITE,
+        prestige: 0,
+    },
+    ShipSkin {
+        name: "Blood Raider",
+        tint: Color::srgb(1.0, 0.55, 0.55),
+        prestige: 0,
+    },
+    ShipSkin {
+        name: "Sansha's Nation",
+        tint: Color::srgb(0.6, 1.0, 0.65),
+        prestige: 0,
+    },
+    ShipSkin {
+        name: "Serpentis",
+        tint: Color::srgb(0.8, 0.65, 1.0),
+        prestige: 0,
+    },
+    ShipSkin {
+        name: "Sisters of EVE",
+        tint: Color::srgb(1.0, 0.85, 0.75),
+        prestige: 0,
+    },
+    ShipSkin {
+        name: "Elder's Chosen",
+        tint: Color::srgb(1.0, 0.85, 0.4),
+        prestige: 100,
+    },
+    ShipSkin {
+        name: "Caldari Prime Ace",
+        tint: Color::srgb(0.6, 0.75, 1.0),
+        prestige: 450,
     },
 ];
 
@@ -797,6 +1126,8 @@ pub struct GameSession {
     pub player_faction: Faction,
     pub enemy_faction: Faction,
     pub selected_ship_index: usize,
+    /// Index into `SHIP_SKINS`
+    pub skin_index: usize,
 }
 
 impl GameSession {
@@ -805,6 +1136,7 @@ impl GameSession {
             player_faction: player,
             enemy_faction: enemy,
             selected_ship_index: 0,
+            skin_index: 0,
         }
     }
 
@@ -821,11 +1153,30 @@ impl GameSession {
         &ships[self.selected_ship_index.min(ships.len() - 1)]
     }
 
+    pub fn selected_skin(&self) -> &'static ShipSkin {
+        &SHIP_SKINS[self.skin_index % SHIP_SKINS.len()]
+    }
+
+    /// Cycle to the next paint job the player's prestige has unlocked
+    pub fn next_skin(&mut self, prestige: u32) {
+        for _ in 0..SHIP_SKINS.len() {
+            self.skin_index = (self.skin_index + 1) % SHIP_SKINS.len();
+            if SHIP_SKINS[self.skin_index].prestige <= prestige {
+                return;
+            }
+        }
+    }
+
     /// Get a random enemy based on spawn weights
     pub fn random_enemy(&self) -> &'static EnemyShipDef {
+        self.pick_enemy(&mut fastrand::Rng::new())
+    }
+
+    /// Weighted enemy pick from a caller-owned generator (seeded runs)
+    pub fn pick_enemy(&self, rng: &mut fastrand::Rng) -> &'static EnemyShipDef {
         let enemies = self.enemy_ships();
         let total_weight: u32 = enemies.iter().map(|e| e.spawn_weight).sum();
-        let roll = fastrand::u32(0..total_weight);
+        let roll = rng.u32(0..total_weight);
 
         let mut cumulative = 0;
         for enemy in enemies {
@@ -842,62 +1193,6 @@ impl GameSession {
 mod tests {
     use super::*;
 
-    // ==================== Faction Basics ====================
-
-    #[test]
-    fn faction_all_returns_four() {
-        assert_eq!(Faction::all().len(), 4);
-    }
-
-    #[test]
-    fn faction_default_is_minmatar() {
-        assert_eq!(Faction::default(), Faction::Minmatar);
-    }
-
-    #[test]
-    fn faction_names() {
-        assert_eq!(Faction::Minmatar.name(), "Minmatar Republic");
-        assert_eq!(Faction::Amarr.name(), "Amarr Empire");
-        assert_eq!(Faction::Caldari.name(), "Caldari State");
-        assert_eq!(Faction::Gallente.name(), "Gallente Federation");
-    }
-
-    #[test]
-    fn faction_short_names() {
-        assert_eq!(Faction::Minmatar.short_name(), "MINMATAR");
-        assert_eq!(Faction::Amarr.short_name(), "AMARR");
-        assert_eq!(Faction::Caldari.short_name(), "CALDARI");
-        assert_eq!(Faction::Gallente.short_name(), "GALLENTE");
-    }
-
-    #[test]
-    fn faction_taglines() {
-        assert_eq!(Faction::Minmatar.tagline(), "In Rust We Trust");
-        assert_eq!(Faction::Amarr.tagline(), "Amarr Victor");
-        assert_eq!(Faction::Caldari.tagline(), "The State Provides");
-        assert_eq!(Faction::Gallente.tagline(), "Liberty or Death");
-    }
-
-    // ==================== Faction Rivals ====================
-
-    #[test]
-    fn faction_rivals_are_bidirectional() {
-        // Minmatar ↔ Amarr
-        assert_eq!(Faction::Minmatar.rival(), Faction::Amarr);
-        assert_eq!(Faction::Amarr.rival(), Faction::Minmatar);
-
-        // Caldari ↔ Gallente
-        assert_eq!(Faction::Caldari.rival(), Faction::Gallente);
-        assert_eq!(Faction::Gallente.rival(), Faction::Caldari);
-    }
-
-    #[test]
-    fn faction_rival_of_rival_is_self() {
-        for faction in Faction::all() {
-            assert_eq!(faction.rival().rival(), *faction);
-        }
-    }
-
     // ==================== Weapon Doctrine ====================
 
     #[test]
@@ -926,6 +1221,24 @@ mod tests {
         assert_eq!(Faction::Gallente.tank_type(), TankDoctrine::Armor);
     }
 
+    #[test]
+    fn tank_doctrines_have_distinct_mechanics() {
+        // Only shield tanks get the fast regen, only armor tanks rep
+        assert!(
+            TankDoctrine::Shield.shield_recharge_mult()
+                > TankDoctrine::Armor.shield_recharge_mult()
+        );
+        assert!(TankDoctrine::Shield.shield_delay_mult() < 1.0);
+        assert!(TankDoctrine::Armor.has_armor_repairer());
+        assert!(!TankDoctrine::Shield.has_armor_repairer());
+        assert!(!TankDoctrine::Speed.has_armor_repairer());
+
+        // Speed tanks are faster and harder to hit
+        assert!(TankDoctrine::Speed.speed_mult() > 1.0);
+        assert!(TankDoctrine::Speed.hitbox_mult() < 1.0);
+        assert_eq!(TankDoctrine::Armor.speed_mult(), 1.0);
+    }
+
     // ==================== Ship Type IDs ====================
 
     #[test]
@@ -1058,6 +1371,57 @@ mod tests {
         }
     }
 
+    #[test]
+    fn enemy_defs_carry_ai_profiles() {
+        let rifter = Faction::find_enemy_def(587).map(|e| e.ai);
+        assert_eq!(rifter, Some(AiProfile::SKIRMISHER));
+        assert!(Faction::find_enemy_def(1).is_none());
+        for faction in Faction::all() {
+            for enemy in faction.enemy_ships() {
+                assert!(
+                    enemy.ai != AiProfile::PASSIVE,
+                    "{} should have a tactical profile",
+                    enemy.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn enemy_attacks_follow_faction_doctrine() {
+        let attacks = |faction: Faction| faction.enemy_ships().iter().map(|e| e.attack);
+        assert!(attacks(Faction::Amarr).all(|a| a == AttackProfile::BEAM_BURST));
+        assert!(attacks(Faction::Minmatar).all(|a| a == AttackProfile::TRACER_STREAM));
+        assert!(attacks(Faction::Gallente).all(|a| a == AttackProfile::BLASTER));
+        // Caldari missile boats home; the railgun hulls shoot straight
+        assert!(attacks(Faction::Caldari).any(|a| a.homing > 0.0));
+        let drake = Faction::find_enemy_def(24688).map(|e| e.attack);
+        assert_eq!(drake, Some(AttackProfile::HOMING_MISSILE));
+        // Homing missiles are slow enough to outfly
+        assert!(AttackProfile::HOMING_MISSILE.speed_mult < 1.0);
+    }
+
+    #[test]
+    fn only_blasters_hold_fire_at_range() {
+        assert!(AttackProfile::DIRECT.in_range(2000.0));
+        assert!(AttackProfile::TRACER_STREAM.in_range(2000.0));
+        let blaster = AttackProfile::BLASTER;
+        assert!(blaster.in_range(blaster.engage_range - 1.0));
+        assert!(!blaster.in_range(blaster.engage_range + 1.0));
+    }
+
+    #[test]
+    fn streams_sweep_evenly_edge_to_edge() {
+        let stream = AttackProfile::TRACER_STREAM;
+        let last = stream.burst - 1;
+        assert!((stream.sweep_angle(0) + stream.sweep / 2.0).abs() < 1e-6);
+        assert!((stream.sweep_angle(last) - stream.sweep / 2.0).abs() < 1e-6);
+        assert!((0..last).all(|i| stream.sweep_angle(i) < stream.sweep_angle(i + 1)));
+        // Single shots and unswept bursts fly straight
+        assert_eq!(AttackProfile::DIRECT.sweep_angle(0), 0.0);
+        assert_eq!(AttackProfile::BEAM_BURST.sweep_angle(2), 0.0);
+    }
+
     // ==================== GameSession ====================
 
     #[test]
@@ -1153,6 +1517,7 @@ mod tests {
             let primary = faction.primary_color();
             let secondary = faction.secondary_color();
             let engine = faction.engine_color();
+            let accent = faction.accent_color();
 
             // Colors should exist (non-default)
             // We can't easily extract sRGB components without bevy internals,
@@ -1160,6 +1525,7 @@ mod tests {
             assert_ne!(primary, Color::BLACK);
             assert_ne!(secondary, Color::BLACK);
             assert_ne!(engine, Color::BLACK);
+            assert_ne!(accent, Color::BLACK);
         }
     }
 
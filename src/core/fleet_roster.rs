@@ -0,0 +1,213 @@
+//! Fleet Roster
+//!
+//! Over a campaign every ship the pilot has unlocked joins the fleet
+//! roster. Flying a mission tires the hull that flew it while the rest of
+//! the hangar stands down and recovers, and a tired ship launches with
+//! thinner tank and slower engines, so rotating the fleet pays. In Ironman
+//! a ship destroyed in combat is gone for the rest of the campaign; the run
+//! carries on in whatever is left, and ends when the hangar is empty.
+
+#![allow(dead_code)]
+
+use serde::{Deserialize, Serialize};
+
+use super::factions::ShipDef;
+
+/// Fatigue a ship picks up from one mission
+pub const SORTIE_FATIGUE: f32 = 0.35;
+
+/// Fatigue shed by every ship that sat a mission out
+pub const REST_RECOVERY: f32 = 0.2;
+
+/// Share of tank lost at full fatigue
+pub const FATIGUE_TANK_PENALTY: f32 = 0.3;
+
+/// Share of engine speed lost at full fatigue
+pub const FATIGUE_SPEED_PENALTY: f32 = 0.15;
+
+/// One ship in the hangar
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct RosterMember {
+    pub type_id: u32,
+    /// 0.0 (fresh) to 1.0 (spent)
+    pub fatigue: f32,
+    /// Missions flown this campaign
+    pub sorties: u32,
+    /// Destroyed in Ironman; can't launch again this campaign
+    pub lost: bool,
+}
+
+impl RosterMember {
+    pub fn new(type_id: u32) -> Self {
+        Self {
+            type_id,
+            fatigue: 0.0,
+            sorties: 0,
+            lost: false,
+        }
+    }
+
+    /// "FRESH", "TIRED" and so on for a fatigue level
+    pub fn condition(&self) -> &'static str {
+        if self.lost {
+            "LOST"
+        } else if self.fatigue < 0.2 {
+            "FRESH"
+        } else if self.fatigue < 0.5 {
+            "READY"
+        } else if self.fatigue < 0.8 {
+            "TIRED"
+        } else {
+            "SPENT"
+        }
+    }
+}
+
+/// The campaign's ships, how worn they are and which are gone
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct FleetRoster {
+    pub members: Vec<RosterMember>,
+    /// Ships destroyed in combat are lost for the campaign
+    pub ironman: bool,
+}
+
+impl FleetRoster {
+    /// Start a new campaign: every ship rested and back in the hangar.
+    /// Ironman is the pilot's choice and carries over.
+    pub fn begin_campaign(&mut self) {
+        self.members.clear();
+    }
+
+    /// Add any unlocked ship the roster hasn't seen yet
+    pub fn enlist(&mut self, type_ids: impl IntoIterator<Item = u32>) {
+        for type_id in type_ids {
+            if self.member(type_id).is_none() {
+                self.members.push(RosterMember::new(type_id));
+            }
+        }
+    }
+
+    pub fn member(&self, type_id: u32) -> Option<&RosterMember> {
+        self.members.iter().find(|m| m.type_id == type_id)
+    }
+
+    fn member_mut(&mut self, type_id: u32) -> &mut RosterMember {
+        if let Some(index) = self.members.iter().position(|m| m.type_id == type_id) {
+            &mut self.members[index]
+        } else {
+            let index = self.members.len();
+            self.members.push(RosterMember::new(type_id));
+            &mut self.members[index]
+        }
+    }
+
+    /// Fatigue of a ship (0.0 if it hasn't joined yet)
+    pub fn fatigue(&self, type_id: u32) -> f32 {
+        self.member(type_id).map_or(0.0, |m| m.fatigue)
+    }
+
+    /// Whether a ship can still launch
+    pub fn is_available(&self, type_id: u32) -> bool {
+        self.member(type_id).is_none_or(|m| !m.lost)
+    }
+
+    /// `type_id` flew a mission: it tires, everyone else rests
+    pub fn fly_sortie(&mut self, type_id: u32) {
+        self.member_mut(type_id);
+        for member in self.members.iter_mut() {
+            if member.type_id == type_id {
+                member.fatigue = (member.fatigue + SORTIE_FATIGUE).min(1.0);
+                member.sorties += 1;
+            } else {
+                member.fatigue = (member.fatigue - REST_RECOVERY).max(0.0);
+            }
+        }
+    }
+
+    /// `type_id` was destroyed. Only Ironman loses it; returns whether it did.
+    pub fn lose(&mut self, type_id: u32) -> bool {
+        if !self.ironman {
+            return false;
+        }
+        let member = self.member_mut(type_id);
+        let newly_lost = !member.lost;
+        member.lost = true;
+        newly_lost
+    }
+
+    /// Stat scale for a launch: (tank, speed)
+    pub fn launch_mults(&self, type_id: u32) -> (f32, f32) {
+        let fatigue = self.fatigue(type_id);
+        (
+            1.0 - fatigue * FATIGUE_TANK_PENALTY,
+            1.0 - fatigue * FATIGUE_SPEED_PENALTY,
+        )
+    }
+
+    /// Index in `ships` of the first ship still able to launch, among the
+    /// unlocked ones (`unlocked` lines up with `ships`)
+    pub fn first_available(&self, ships: &[ShipDef], unlocked: &[bool]) -> Option<usize> {
+        ships
+            .iter()
+            .zip(unlocked)
+            .position(|(ship, &unlocked)| unlocked && self.is_available(ship.type_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Faction;
+
+    #[test]
+    fn flying_tires_the_ship_and_rests_the_others() {
+        let mut roster = FleetRoster::default();
+        roster.enlist([1, 2]);
+        roster.fly_sortie(1);
+        assert_eq!(roster.fatigue(1), SORTIE_FATIGUE);
+        assert_eq!(roster.fatigue(2), 0.0);
+        assert_eq!(roster.member(1).unwrap().sorties, 1);
+
+        roster.fly_sortie(2);
+        assert!((roster.fatigue(1) - (SORTIE_FATIGUE - REST_RECOVERY)).abs() < 1e-6);
+
+        for _ in 0..10 {
+            roster.fly_sortie(2);
+        }
+        assert_eq!(roster.fatigue(2), 1.0);
+        assert_eq!(roster.fatigue(1), 0.0);
+        assert_eq!(roster.member(2).unwrap().condition(), "SPENT");
+
+        let (tank, speed) = roster.launch_mults(2);
+        assert_eq!(tank, 1.0 - FATIGUE_TANK_PENALTY);
+        assert_eq!(speed, 1.0 - FATIGUE_SPEED_PENALTY);
+        assert_eq!(roster.launch_mults(1), (1.0, 1.0));
+    }
+
+    #[test]
+    fn only_ironman_loses_ships() {
+        let mut roster = FleetRoster::default();
+        roster.enlist([1, 2]);
+        assert!(!roster.lose(1));
+        assert!(roster.is_available(1));
+
+        roster.ironman = true;
+        assert!(roster.lose(1));
+        assert!(!roster.lose(1), "already gone");
+        assert!(!roster.is_available(1));
+        assert!(roster.is_available(2));
+
+        let ships = Faction::Minmatar.player_ships();
+        let unlocked = vec![true; ships.len()];
+        roster.lose(ships[0].type_id);
+        assert_eq!(roster.first_available(ships, &unlocked), Some(1));
+        for ship in ships {
+            roster.lose(ship.type_id);
+        }
+        assert_eq!(roster.first_available(ships, &unlocked), None);
+
+        roster.begin_campaign();
+        assert!(roster.ironman);
+        assert_eq!(roster.first_available(ships, &unlocked), Some(0));
+    }
+}
@@ -4,6 +4,10 @@
 
 #![allow(dead_code)]
 
+use super::resources::{
+    BossModifiers, DifficultyLevel, DifficultySettings, EnemyModifiers, PlayerModifiers,
+    ScoringModifiers,
+};
 use bevy::prelude::*;
 
 /// Main game state - controls which systems run and what's displayed
@@ -13,6 +17,7 @@ pub enum GameState {
     Loading,
     MainMenu,
     Options,       // Audio, display, and control settings
+    Profile,       // Prestige track and lifetime stats across modules
     ModuleSelect,  // Choose game module (Elder Fleet, Caldari vs Gallente, etc.)
     FactionSelect, // Choose faction (for Caldari/Gallente module)
     StageSelect,   // Choose which stage (1-13) to play
@@ -23,11 +28,22 @@ pub enum GameState {
     BossIntro,
     BossFight,
     StageComplete,
+    RouteMap, // Choose the route to the next campaign mission
     GameOver,
     Victory,
     Paused,
 }
 
+impl GameState {
+    /// A run is in progress (including pause and boss intros layered on it)
+    pub fn is_gameplay(&self) -> bool {
+        matches!(
+            self,
+            GameState::Playing | GameState::Paused | GameState::BossIntro | GameState::BossFight
+        )
+    }
+}
+
 /// Game difficulty settings - EVE-themed
 /// (Wraps DifficultyLevel from resources.rs for backwards compatibility)
 #[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, Resource)]
@@ -127,6 +143,35 @@ impl Difficulty {
         }
     }
 
+    /// Loot drop chance multiplier
+    pub fn loot_drop_mult(&self) -> f32 {
+        match self {
+            Difficulty::Carebear => 1.3,
+            Difficulty::Newbro => 1.0,
+            Difficulty::BitterVet => 0.85,
+            Difficulty::Triglavian => 0.7,
+        }
+    }
+
+    /// Chance a scattered spawn ambushes from behind the player
+    pub fn ambush_chance(&self) -> f32 {
+        match self {
+            Difficulty::Carebear | Difficulty::Newbro => 0.0,
+            Difficulty::BitterVet => 0.08,
+            Difficulty::Triglavian => 0.15,
+        }
+    }
+
+    /// Parry window multiplier
+    pub fn parry_window_mult(&self) -> f32 {
+        match self {
+            Difficulty::Carebear => 1.5,
+            Difficulty::Newbro => 1.0,
+            Difficulty::BitterVet => 0.75,
+            Difficulty::Triglavian => 0.5,
+        }
+    }
+
     /// Player shield multiplier
     pub fn player_shield_mult(&self) -> f32 {
         match self {
@@ -139,14 +184,39 @@ impl Difficulty {
 
     /// Score multiplier for this difficulty
     pub fn score_mult(&self) -> f32 {
+        self.scoring().base_score_multiplier
+    }
+
+    /// The matching settings level
+    pub fn level(&self) -> DifficultyLevel {
         match self {
-            Difficulty::Carebear => 0.5,
-            Difficulty::Newbro => 1.0,
-            Difficulty::BitterVet => 1.5,
-            Difficulty::Triglavian => 3.0,
+            Difficulty::Carebear => DifficultyLevel::Carebear,
+            Difficulty::Newbro => DifficultyLevel::Newbro,
+            Difficulty::BitterVet => DifficultyLevel::BitterVet,
+            Difficulty::Triglavian => DifficultyLevel::Triglavian,
         }
     }
 
+    /// Scoring modifiers for this difficulty
+    pub fn scoring(&self) -> ScoringModifiers {
+        DifficultySettings::from_level(self.level()).scoring
+    }
+
+    /// Player stat modifiers for this difficulty
+    pub fn player_modifiers(&self) -> PlayerModifiers {
+        DifficultySettings::from_level(self.level()).player
+    }
+
+    /// Enemy stat modifiers for this difficulty
+    pub fn enemy_modifiers(&self) -> EnemyModifiers {
+        DifficultySettings::from_level(self.level()).enemy
+    }
+
+    /// Boss modifiers for this difficulty
+    pub fn boss_modifiers(&self) -> BossModifiers {
+        DifficultySettings::from_level(self.level()).boss
+    }
+
     pub fn color(&self) -> Color {
         match self {
             Difficulty::Carebear => Color::srgb(0.4, 0.8, 0.4), // Green
@@ -0,0 +1,173 @@
+//! Act Interludes
+//!
+//! Between acts of the Elder Fleet campaign the route map opens on an
+//! interlude: a short piece of the story so far, a look at the coming
+//! act's missions, and a choice of one of three boons that last for that
+//! act. Chosen boons are campaign flags - they ride along in the
+//! checkpoint and are gone when a new campaign starts.
+
+#![allow(dead_code)]
+
+use serde::{Deserialize, Serialize};
+
+use crate::core_logic::Act;
+
+/// Extra wing pilots allowed at once with Wing Command
+pub const BOON_WINGMAN_CAP: u32 = 1;
+
+/// Player projectile damage with Heavier Rounds
+pub const BOON_DAMAGE_MULT: f32 = 1.1;
+
+/// Capacitor recharge with Capacitor Flow
+pub const BOON_CAP_RECHARGE_MULT: f32 = 1.25;
+
+/// An act-long upgrade picked at an interlude
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ActBoon {
+    /// One more wingman in the air at once
+    WingCommand,
+    /// +10% projectile damage
+    HeavierRounds,
+    /// Faster capacitor recharge
+    CapacitorFlow,
+}
+
+impl ActBoon {
+    pub const ALL: [ActBoon; 3] = [
+        ActBoon::WingCommand,
+        ActBoon::HeavierRounds,
+        ActBoon::CapacitorFlow,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ActBoon::WingCommand => "WING COMMAND",
+            ActBoon::HeavierRounds => "HEAVIER ROUNDS",
+            ActBoon::CapacitorFlow => "CAPACITOR FLOW",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            ActBoon::WingCommand => "+1 wingman in the air at once",
+            ActBoon::HeavierRounds => "+10% projectile damage",
+            ActBoon::CapacitorFlow => "+25% capacitor recharge",
+        }
+    }
+
+    /// Extra wingmen allowed at once
+    pub fn wingman_bonus(&self) -> u32 {
+        match self {
+            ActBoon::WingCommand => BOON_WINGMAN_CAP,
+            _ => 0,
+        }
+    }
+
+    pub fn damage_mult(&self) -> f32 {
+        match self {
+            ActBoon::HeavierRounds => BOON_DAMAGE_MULT,
+            _ => 1.0,
+        }
+    }
+
+    pub fn cap_recharge_mult(&self) -> f32 {
+        match self {
+            ActBoon::CapacitorFlow => BOON_CAP_RECHARGE_MULT,
+            _ => 1.0,
+        }
+    }
+}
+
+/// A boon and the act it was picked for
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct ChosenBoon {
+    /// Act number (2-3)
+    pub act: u32,
+    pub boon: ActBoon,
+}
+
+/// Choices made over a campaign that shape its later acts
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(default)]
+pub struct CampaignFlags {
+    pub boons: Vec<ChosenBoon>,
+}
+
+impl CampaignFlags {
+    /// The boon in force for `act`, if one was picked
+    pub fn boon_for(&self, act: Act) -> Option<ActBoon> {
+        self.boons
+            .iter()
+            .find(|chosen| chosen.act == act.number())
+            .map(|chosen| chosen.boon)
+    }
+
+    /// Pick `boon` for `act`, replacing any earlier pick for it
+    pub fn choose(&mut self, act: Act, boon: ActBoon) {
+        self.boons.retain(|chosen| chosen.act != act.number());
+        self.boons.push(ChosenBoon {
+            act: act.number(),
+            boon,
+        });
+    }
+
+    /// Whether the route map should open on an interlude: the first
+    /// mission of a later act, with no boon picked for it yet
+    pub fn wants_interlude(&self, act: Act, mission_index: usize) -> bool {
+        act != Act::Act1 && mission_index == 0 && self.boon_for(act).is_none()
+    }
+}
+
+/// Story so far, told going into `act`
+pub fn interlude_vignette(act: Act) -> &'static str {
+    match act {
+        Act::Act1 => "",
+        Act::Act2 => {
+            "Word of the convoy raids has run through every clan hall in the \
+             Republic. Elders who called you reckless are sending ships now. \
+             The Empire has noticed too: patrols are doubling and the customs \
+             lanes are closing. The storm is coming - meet it head on."
+        }
+        Act::Act3 => {
+            "The battlestation still burns behind you. Across Amarr space the \
+             enslaved have laid down their tools, and the Empire is pulling \
+             its fleets home to hold the core worlds. One push remains, \
+             straight at the heart of it."
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn boons_are_picked_once_per_act() {
+        let mut flags = CampaignFlags::default();
+        assert!(!flags.wants_interlude(Act::Act1, 0));
+        assert!(flags.wants_interlude(Act::Act2, 0));
+        assert!(!flags.wants_interlude(Act::Act2, 1));
+
+        flags.choose(Act::Act2, ActBoon::HeavierRounds);
+        assert_eq!(flags.boon_for(Act::Act2), Some(ActBoon::HeavierRounds));
+        assert_eq!(flags.boon_for(Act::Act3), None);
+        assert!(!flags.wants_interlude(Act::Act2, 0));
+        assert!(flags.wants_interlude(Act::Act3, 0));
+
+        flags.choose(Act::Act2, ActBoon::WingCommand);
+        assert_eq!(flags.boons.len(), 1);
+        assert_eq!(flags.boon_for(Act::Act2), Some(ActBoon::WingCommand));
+    }
+
+    #[test]
+    fn each_boon_does_one_thing() {
+        for boon in ActBoon::ALL {
+            let active = [
+                boon.wingman_bonus() > 0,
+                boon.damage_mult() > 1.0,
+                boon.cap_recharge_mult() > 1.0,
+            ];
+            assert_eq!(active.iter().filter(|on| **on).count(), 1, "{:?}", boon);
+        }
+    }
+}
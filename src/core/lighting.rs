@@ -0,0 +1,258 @@
+//! Scene Lighting
+//!
+//! The key light and ambient fill a mission is flown under. Each act of the
+//! Elder Fleet campaign has its own mood - rust haze for the early raids,
+//! cold storm light for the invasion, harsh gold over Amarr space - and
+//! fights against other factions use their home space (cold blue for
+//! Caldari). A few missions script their own lighting, and dev mission
+//! overrides can replace it with a `lighting: Some((...))` block.
+
+#![allow(dead_code)]
+
+use super::campaign::{Act, CampaignState};
+use super::factions::Faction;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Key light and ambient fill, in RON-friendly form
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LightingProfile {
+    /// Key light color (sRGB)
+    pub key_color: [f32; 3],
+    /// Key light strength (lux)
+    pub key_lux: f32,
+    /// Key light angle from straight down the screen (degrees)
+    pub key_angle: f32,
+    /// Ambient fill color (sRGB)
+    pub ambient_color: [f32; 3],
+    /// Ambient fill strength
+    pub ambient_brightness: f32,
+    /// Alpha of the key light's wash over the playfield
+    pub wash: f32,
+}
+
+impl Default for LightingProfile {
+    fn default() -> Self {
+        Self::NEUTRAL
+    }
+}
+
+impl LightingProfile {
+    /// Plain white light, with Bevy's default ambient fill
+    pub const NEUTRAL: Self = Self {
+        key_color: [1.0, 1.0, 1.0],
+        key_lux: 6000.0,
+        key_angle: 30.0,
+        ambient_color: [1.0, 1.0, 1.0],
+        ambient_brightness: 80.0,
+        wash: 0.0,
+    };
+
+    /// Near-dark the scene fades up from as a mission starts
+    pub const WARP_IN: Self = Self {
+        key_color: [0.6, 0.7, 1.0],
+        key_lux: 500.0,
+        key_angle: 30.0,
+        ambient_color: [0.4, 0.45, 0.6],
+        ambient_brightness: 15.0,
+        wash: 0.18,
+    };
+
+    pub fn key(&self) -> Color {
+        let [r, g, b] = self.key_color;
+        Color::srgb(r, g, b)
+    }
+
+    pub fn ambient(&self) -> Color {
+        let [r, g, b] = self.ambient_color;
+        Color::srgb(r, g, b)
+    }
+
+    /// Blend toward `other` by `t` (0.0 - 1.0)
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: f32, b: f32| a * (1.0 - t) + b * t;
+        let mix3 = |a: [f32; 3], b: [f32; 3]| [mix(a[0], b[0]), mix(a[1], b[1]), mix(a[2], b[2])];
+        Self {
+            key_color: mix3(self.key_color, other.key_color),
+            key_lux: mix(self.key_lux, other.key_lux),
+            key_angle: mix(self.key_angle, other.key_angle),
+            ambient_color: mix3(self.ambient_color, other.ambient_color),
+            ambient_brightness: mix(self.ambient_brightness, other.ambient_brightness),
+            wash: mix(self.wash, other.wash),
+        }
+    }
+}
+
+/// Rust haze of the early raids
+const ACT1_LIGHT: LightingProfile = LightingProfile {
+    key_color: [1.0, 0.72, 0.5],
+    key_lux: 5000.0,
+    key_angle: 35.0,
+    ambient_color: [0.6, 0.4, 0.3],
+    ambient_brightness: 65.0,
+    wash: 0.05,
+};
+
+/// Cold storm light of the invasion
+const ACT2_LIGHT: LightingProfile = LightingProfile {
+    key_color: [0.7, 0.8, 1.0],
+    key_lux: 6500.0,
+    key_angle: -25.0,
+    ambient_color: [0.35, 0.35, 0.6],
+    ambient_brightness: 60.0,
+    wash: 0.06,
+};
+
+/// Harsh gold over Amarr space
+const AMARR_LIGHT: LightingProfile = LightingProfile {
+    key_color: [1.0, 0.85, 0.45],
+    key_lux: 11000.0,
+    key_angle: 15.0,
+    ambient_color: [0.7, 0.55, 0.3],
+    ambient_brightness: 90.0,
+    wash: 0.08,
+};
+
+/// Cold blue of Caldari space
+const CALDARI_LIGHT: LightingProfile = LightingProfile {
+    key_color: [0.6, 0.78, 1.0],
+    key_lux: 7000.0,
+    key_angle: -35.0,
+    ambient_color: [0.3, 0.4, 0.6],
+    ambient_brightness: 65.0,
+    wash: 0.07,
+};
+
+/// Green-lit Gallente space
+const GALLENTE_LIGHT: LightingProfile = LightingProfile {
+    key_color: [0.65, 1.0, 0.8],
+    key_lux: 6500.0,
+    key_angle: 25.0,
+    ambient_color: [0.3, 0.5, 0.4],
+    ambient_brightness: 65.0,
+    wash: 0.06,
+};
+
+/// Lighting for an act of the Elder Fleet campaign
+pub fn act_lighting(act: Act) -> LightingProfile {
+    match act {
+        Act::Act1 => ACT1_LIGHT,
+        Act::Act2 => ACT2_LIGHT,
+        Act::Act3 => AMARR_LIGHT,
+    }
+}
+
+/// Lighting for a fight in `faction`'s home space
+pub fn faction_lighting(faction: Faction) -> LightingProfile {
+    match faction {
+        Faction::Minmatar => ACT1_LIGHT,
+        Faction::Amarr => AMARR_LIGHT,
+        Faction::Caldari => CALDARI_LIGHT,
+        Faction::Gallente => GALLENTE_LIGHT,
+    }
+}
+
+/// Missions that script their own lighting
+pub fn mission_lighting(mission_id: &str) -> Option<LightingProfile> {
+    match mission_id {
+        // Searing white from the Inquisition's flagship
+        "m6_inquisition" => Some(LightingProfile {
+            key_color: [1.0, 0.95, 0.85],
+            key_lux: 12000.0,
+            ..ACT2_LIGHT
+        }),
+        // The battlestation burns like a second sun
+        "m9_battlestation" => Some(LightingProfile {
+            key_lux: 14000.0,
+            wash: 0.12,
+            ..AMARR_LIGHT
+        }),
+        // Flying in the titan's shadow
+        "m11_titan_escort" => Some(LightingProfile {
+            key_lux: 2500.0,
+            ambient_brightness: 35.0,
+            wash: 0.1,
+            ..AMARR_LIGHT
+        }),
+        _ => None,
+    }
+}
+
+/// Lighting for whatever is being flown: a dev override, then the
+/// mission's own, then its act against the Amarr, or the enemy's home space
+pub fn scene_lighting(campaign: &CampaignState, enemy: Faction) -> LightingProfile {
+    if let Some(lighting) = campaign.mission_override.as_ref().and_then(|o| o.lighting) {
+        return lighting;
+    }
+    if let Some(lighting) = campaign
+        .current_mission()
+        .and_then(|m| mission_lighting(m.id))
+    {
+        return lighting;
+    }
+    if enemy == Faction::Amarr {
+        act_lighting(campaign.act)
+    } else {
+        faction_lighting(enemy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn acts_and_factions_set_their_own_mood() {
+        assert_ne!(act_lighting(Act::Act1), act_lighting(Act::Act3));
+        assert_eq!(act_lighting(Act::Act3), faction_lighting(Faction::Amarr));
+        // Gold is warm, Caldari blue is cold
+        let gold = faction_lighting(Faction::Amarr).key_color;
+        let blue = faction_lighting(Faction::Caldari).key_color;
+        assert!(gold[0] > gold[2]);
+        assert!(blue[2] > blue[0]);
+    }
+
+    #[test]
+    fn lerp_runs_from_one_profile_to_the_other() {
+        let a = LightingProfile::WARP_IN;
+        let b = faction_lighting(Faction::Caldari);
+        assert_eq!(a.lerp(&b, 0.0), a);
+        assert_eq!(a.lerp(&b, 1.0), b);
+        assert_eq!(a.lerp(&b, 2.0), b);
+        let mid = a.lerp(&b, 0.5);
+        assert!(mid.key_lux > a.key_lux && mid.key_lux < b.key_lux);
+    }
+
+    #[test]
+    fn overrides_beat_mission_scripts_which_beat_the_act() {
+        let mut campaign = CampaignState::default();
+        assert_eq!(
+            scene_lighting(&campaign, Faction::Amarr),
+            act_lighting(campaign.act)
+        );
+        assert_eq!(
+            scene_lighting(&campaign, Faction::Caldari),
+            faction_lighting(Faction::Caldari)
+        );
+
+        campaign.act = Act::Act3;
+        campaign.mission_index = 1;
+        let id = campaign.current_mission().unwrap().id;
+        assert_eq!(
+            scene_lighting(&campaign, Faction::Amarr),
+            mission_lighting(id).unwrap()
+        );
+
+        // Fields left out of an override's block stay neutral
+        campaign.mission_override = Some(
+            crate::core::MissionOverride::from_ron("(lighting: Some((key_lux: 1.0)))").unwrap(),
+        );
+        let custom = LightingProfile {
+            key_lux: 1.0,
+            ..LightingProfile::NEUTRAL
+        };
+        assert_eq!(scene_lighting(&campaign, Faction::Amarr), custom);
+    }
+}
@@ -0,0 +1,405 @@
+//! Logging Configuration
+//!
+//! Reads `logging.ron` next to the save file before the app is built and
+//! turns it into Bevy's log filter: a level for the game as a whole, levels
+//! per subsystem (`"systems::spawning": Warn`), and an optional log file
+//! that rotates by size and keeps a few old ones. Release builds are quiet
+//! (warnings and errors only) unless the file says otherwise.
+//!
+//! `--debug` on the command line (or `EVE_REBELLION_DEBUG=1`) lifts quiet
+//! mode and logs the game at debug level for that run; `RUST_LOG` still
+//! overrides everything, as with any Bevy app.
+//! Native only - web builds log to the browser console at the defaults.
+
+#![allow(dead_code)]
+
+use bevy::log::{BoxedLayer, LogPlugin, DEFAULT_FILTER};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Root of the game's own log targets
+const GAME_TARGET: &str = env!("CARGO_CRATE_NAME");
+
+/// Command-line flag for a debug-level run
+pub const DEBUG_FLAG: &str = "--debug";
+
+/// Environment variable for a debug-level run
+pub const DEBUG_ENV: &str = "EVE_REBELLION_DEBUG";
+
+/// Log level for the game or one of its subsystems, least verbose first
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    #[default]
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    /// Filter directive spelling
+    pub fn directive(&self) -> &'static str {
+        match self {
+            LogLevel::Error => "error",
+            LogLevel::Warn => "warn",
+            LogLevel::Info => "info",
+            LogLevel::Debug => "debug",
+            LogLevel::Trace => "trace",
+        }
+    }
+
+    fn level(&self) -> bevy::log::Level {
+        match self {
+            LogLevel::Error => bevy::log::Level::ERROR,
+            LogLevel::Warn => bevy::log::Level::WARN,
+            LogLevel::Info => bevy::log::Level::INFO,
+            LogLevel::Debug => bevy::log::Level::DEBUG,
+            LogLevel::Trace => bevy::log::Level::TRACE,
+        }
+    }
+}
+
+/// Contents of `logging.ron`
+#[derive(Resource, Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
+#[serde(default)]
+pub struct LoggingConfig {
+    /// Level for the game's own logs
+    pub level: LogLevel,
+    /// Warnings and errors only; unset means quiet in release builds
+    pub quiet: Option<bool>,
+    /// Levels for single subsystems by module path, e.g. `"entities::drone"`
+    pub subsystems: BTreeMap<String, LogLevel>,
+    pub file: LogFileSection,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct LogFileSection {
+    /// Also write logs to `logs/eve_rebellion.log`
+    pub enabled: bool,
+    /// Size at which the file rotates (KB)
+    pub max_size_kb: u64,
+    /// Rotated files kept alongside the live one
+    pub keep: usize,
+}
+
+impl Default for LogFileSection {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_size_kb: 1024,
+            keep: 3,
+        }
+    }
+}
+
+impl LoggingConfig {
+    /// Parse RON text
+    pub fn from_ron(text: &str) -> Result<Self, String> {
+        ron::from_str(text).map_err(|e| e.to_string())
+    }
+
+    /// Serialize to human-editable RON
+    pub fn to_ron(&self) -> Result<String, String> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|e| e.to_string())
+    }
+
+    /// Load `logging.ron`, writing the defaults out on first launch so
+    /// there is a file to edit. Logging isn't up yet, so problems go to
+    /// stderr.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load() -> Self {
+        let path = config_path();
+        match std::fs::read_to_string(&path) {
+            Ok(text) => Self::from_ron(&text).unwrap_or_else(|e| {
+                eprintln!("Ignoring {}: {}", path.display(), e);
+                Self::default()
+            }),
+            Err(_) => {
+                let config = Self::default();
+                if let Some(dir) = path.parent() {
+                    let _ = std::fs::create_dir_all(dir);
+                }
+                if let Ok(text) = config.to_ron() {
+                    let _ = std::fs::write(&path, text);
+                }
+                config
+            }
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn load() -> Self {
+        Self::default()
+    }
+
+    /// Whether this run logs warnings and errors only
+    pub fn is_quiet(&self, debug: bool) -> bool {
+        !debug && self.quiet.unwrap_or(!cfg!(debug_assertions))
+    }
+
+    /// Level for the game's own logs this run
+    pub fn game_level(&self, debug: bool) -> LogLevel {
+        if debug {
+            LogLevel::Debug.max(self.level)
+        } else if self.is_quiet(debug) {
+            LogLevel::Warn.min(self.level)
+        } else {
+            self.level
+        }
+    }
+
+    /// Filter directives for Bevy's log plugin. Subsystem names that could
+    /// not be a module path are skipped rather than breaking the filter.
+    pub fn filter(&self, debug: bool) -> String {
+        let mut directives = vec![
+            DEFAULT_FILTER.to_string(),
+            format!("{}={}", GAME_TARGET, self.game_level(debug).directive()),
+        ];
+        for (subsystem, level) in &self.subsystems {
+            let valid = !subsystem.is_empty()
+                && subsystem
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == ':');
+            if valid {
+                directives.push(format!(
+                    "{}::{}={}",
+                    GAME_TARGET,
+                    subsystem,
+                    level.directive()
+                ));
+            } else {
+                eprintln!("Ignoring log level for '{}'", subsystem);
+            }
+        }
+        directives.join(",")
+    }
+
+    /// Bevy's log plugin set up from this config. The file layer reads the
+    /// config back out of the app, so insert it as a resource first.
+    pub fn log_plugin(&self, debug: bool) -> LogPlugin {
+        let level = if self.is_quiet(debug) {
+            LogLevel::Warn
+        } else {
+            LogLevel::Info
+        };
+        LogPlugin {
+            level: level.level(),
+            filter: self.filter(debug),
+            custom_layer: log_file_layer,
+        }
+    }
+}
+
+/// Whether this run asked for debug logging on the command line or in the
+/// environment
+pub fn debug_requested() -> bool {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        std::env::args().any(|arg| arg == DEBUG_FLAG)
+            || std::env::var(DEBUG_ENV).is_ok_and(|v| !v.is_empty() && v != "0")
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        false
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn config_path() -> std::path::PathBuf {
+    data_dir().join("logging.ron")
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn data_dir() -> std::path::PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| std::path::PathBuf::from("."))
+        .join("eve_rebellion")
+}
+
+/// `eve_rebellion.log`, then `eve_rebellion.1.log` for the newest rotated
+/// file, and so on
+pub fn log_file_name(generation: usize) -> String {
+    match generation {
+        0 => "eve_rebellion.log".to_string(),
+        n => format!("eve_rebellion.{}.log", n),
+    }
+}
+
+/// Log file that rotates once it passes a size. Every launch starts a fresh
+/// file, so the previous session's log is always the `.1` one.
+#[cfg(not(target_arch = "wasm32"))]
+pub struct RotatingLogFile {
+    dir: std::path::PathBuf,
+    file: std::fs::File,
+    written: u64,
+    max_bytes: u64,
+    keep: usize,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl RotatingLogFile {
+    pub fn open(dir: std::path::PathBuf, max_bytes: u64, keep: usize) -> std::io::Result<Self> {
+        std::fs::create_dir_all(&dir)?;
+        rotate(&dir, keep);
+        let file = std::fs::File::create(dir.join(log_file_name(0)))?;
+        Ok(Self {
+            dir,
+            file,
+            written: 0,
+            max_bytes,
+            keep,
+        })
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl std::io::Write for RotatingLogFile {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if self.written > 0 && self.written + buf.len() as u64 > self.max_bytes {
+            self.file.flush()?;
+            rotate(&self.dir, self.keep);
+            self.file = std::fs::File::create(self.dir.join(log_file_name(0)))?;
+            self.written = 0;
+        }
+        let written = self.file.write(buf)?;
+        self.written += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.file.flush()
+    }
+}
+
+/// Shift each log file one generation older, dropping the oldest
+#[cfg(not(target_arch = "wasm32"))]
+fn rotate(dir: &std::path::Path, keep: usize) {
+    if keep == 0 {
+        let _ = std::fs::remove_file(dir.join(log_file_name(0)));
+        return;
+    }
+    let _ = std::fs::remove_file(dir.join(log_file_name(keep)));
+    for generation in (0..keep).rev() {
+        let _ = std::fs::rename(
+            dir.join(log_file_name(generation)),
+            dir.join(log_file_name(generation + 1)),
+        );
+    }
+}
+
+/// Log file layer for Bevy's log plugin, when the config asks for one
+fn log_file_layer(app: &mut App) -> Option<BoxedLayer> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let config = app.world().get_resource::<LoggingConfig>()?;
+        if !config.file.enabled {
+            return None;
+        }
+        let file = match RotatingLogFile::open(
+            data_dir().join("logs"),
+            config.file.max_size_kb.max(1) * 1024,
+            config.file.keep,
+        ) {
+            Ok(file) => file,
+            Err(e) => {
+                eprintln!("No log file: {}", e);
+                return None;
+            }
+        };
+        Some(Box::new(
+            bevy::log::tracing_subscriber::fmt::layer()
+                .with_ansi(false)
+                .with_writer(std::sync::Mutex::new(file)),
+        ))
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        let _ = app;
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subsystem_levels_become_filter_directives() {
+        let mut config = LoggingConfig {
+            quiet: Some(false),
+            ..default()
+        };
+        config
+            .subsystems
+            .insert("systems::spawning".to_string(), LogLevel::Warn);
+        config
+            .subsystems
+            .insert("bad name=trace".to_string(), LogLevel::Trace);
+
+        let filter = config.filter(false);
+        assert!(filter.starts_with(DEFAULT_FILTER));
+        assert!(filter.contains(&format!("{}=info", GAME_TARGET)));
+        assert!(filter.contains(&format!("{}::systems::spawning=warn", GAME_TARGET)));
+        assert!(!filter.contains("bad name"));
+    }
+
+    #[test]
+    fn quiet_caps_the_game_level_and_debug_lifts_it() {
+        let config = LoggingConfig {
+            quiet: Some(true),
+            level: LogLevel::Info,
+            ..default()
+        };
+        assert_eq!(config.game_level(false), LogLevel::Warn);
+        assert_eq!(config.game_level(true), LogLevel::Debug);
+
+        let errors_only = LoggingConfig {
+            quiet: Some(true),
+            level: LogLevel::Error,
+            ..default()
+        };
+        assert_eq!(errors_only.game_level(false), LogLevel::Error);
+    }
+
+    #[test]
+    fn logging_config_partial_uses_defaults() {
+        let config =
+            LoggingConfig::from_ron("(subsystems: {\"entities::drone\": Error})").expect("parse");
+        assert_eq!(config.level, LogLevel::Info);
+        assert_eq!(config.quiet, None);
+        assert_eq!(config.subsystems["entities::drone"], LogLevel::Error);
+        assert_eq!(config.file, LogFileSection::default());
+
+        let text = config.to_ron().expect("serialize");
+        assert_eq!(LoggingConfig::from_ron(&text).expect("reparse"), config);
+    }
+
+    #[test]
+    fn log_files_rotate_past_their_size() {
+        use std::io::Write;
+
+        let dir = std::env::temp_dir().join(format!("eve_rebellion_logs_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let mut log = RotatingLogFile::open(dir.clone(), 16, 2).expect("open");
+        for line in [
+            "first line 1234\n",
+            "second line 123\n",
+            "third line 1234\n",
+        ] {
+            log.write_all(line.as_bytes()).expect("write");
+        }
+        log.flush().expect("flush");
+
+        let read = |generation| std::fs::read_to_string(dir.join(log_file_name(generation)));
+        assert_eq!(read(0).expect("live"), "third line 1234\n");
+        assert_eq!(read(1).expect("newest"), "second line 123\n");
+        assert_eq!(read(2).expect("oldest"), "first line 1234\n");
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,197 @@
+//! Mission Medals
+//!
+//! Every mission grades its Score Attack and Time Attack runs against four
+//! thresholds: bronze, silver, gold and platinum. The best medal per mission
+//! and mode is kept in the profile, shown on the stage select and results
+//! screens, and gold or better everywhere earns an achievement.
+
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::run_mode::RunMode;
+
+/// Medal tiers, lowest first
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Medal {
+    Bronze,
+    Silver,
+    Gold,
+    Platinum,
+}
+
+impl Medal {
+    pub const ALL: [Medal; 4] = [Medal::Bronze, Medal::Silver, Medal::Gold, Medal::Platinum];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Medal::Bronze => "BRONZE",
+            Medal::Silver => "SILVER",
+            Medal::Gold => "GOLD",
+            Medal::Platinum => "PLATINUM",
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            Medal::Bronze => Color::srgb(0.8, 0.5, 0.25),
+            Medal::Silver => Color::srgb(0.75, 0.78, 0.82),
+            Medal::Gold => Color::srgb(1.0, 0.85, 0.2),
+            Medal::Platinum => Color::srgb(0.7, 0.95, 1.0),
+        }
+    }
+
+    /// The tier above, if any
+    pub fn next(&self) -> Option<Medal> {
+        Medal::ALL.get(*self as usize + 1).copied()
+    }
+}
+
+/// What a mission asks for each medal, indexed like `Medal::ALL`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MedalThresholds {
+    /// Score Attack points
+    pub score: [u64; 4],
+    /// Time Attack seconds
+    pub time: [u64; 4],
+}
+
+impl MedalThresholds {
+    /// Leaderboard value needed for `medal` in `mode` (milliseconds for
+    /// Time Attack, points otherwise); `None` for modes without medals
+    pub fn required(&self, mode: RunMode, medal: Medal) -> Option<u64> {
+        match mode {
+            RunMode::ScoreAttack => Some(self.score[medal as usize]),
+            RunMode::TimeAttack => Some(self.time[medal as usize] * 1000),
+            RunMode::Campaign | RunMode::Daily => None,
+        }
+    }
+
+    /// Best medal a run with leaderboard `value` earns in `mode`
+    pub fn grade(&self, mode: RunMode, value: u64) -> Option<Medal> {
+        Medal::ALL.iter().rev().copied().find(|&medal| {
+            self.required(mode, medal).is_some_and(|needed| match mode {
+                RunMode::TimeAttack => value <= needed,
+                _ => value >= needed,
+            })
+        })
+    }
+}
+
+/// Medal thresholds for a mission. Later missions field tougher ships, so
+/// scores run higher and 40 kills take longer.
+pub fn medal_thresholds(mission_id: &str) -> MedalThresholds {
+    let (score, time) = match mission_id {
+        "m1_convoy_raid" => ([10_000, 25_000, 45_000, 70_000], [150, 110, 80, 60]),
+        "m2_patrol_ambush" => ([12_000, 28_000, 50_000, 78_000], [155, 115, 84, 63]),
+        "m3_station_raid" => ([14_000, 32_000, 56_000, 86_000], [160, 118, 87, 66]),
+        "m4_holder_escape" => ([16_000, 36_000, 62_000, 95_000], [165, 122, 90, 68]),
+        "m5_customs_strike" => ([20_000, 42_000, 72_000, 110_000], [170, 126, 93, 70]),
+        "m6_inquisition" => ([22_000, 46_000, 78_000, 120_000], [175, 130, 96, 72]),
+        "m7_navy_battle" => ([25_000, 52_000, 88_000, 135_000], [180, 134, 99, 75]),
+        "m8_stargate" => ([28_000, 58_000, 96_000, 148_000], [185, 138, 102, 77]),
+        "m9_battlestation" => ([30_000, 62_000, 104_000, 160_000], [190, 142, 105, 80]),
+        "m10_abaddon" => ([35_000, 72_000, 120_000, 180_000], [195, 146, 108, 82]),
+        "m11_titan_escort" => ([38_000, 78_000, 130_000, 195_000], [200, 150, 111, 85]),
+        "m12_champion" => ([42_000, 85_000, 140_000, 210_000], [205, 154, 114, 87]),
+        "m13_avatar" => ([46_000, 92_000, 152_000, 230_000], [210, 158, 117, 90]),
+        _ => ([10_000, 25_000, 45_000, 70_000], [150, 110, 80, 60]),
+    };
+    MedalThresholds { score, time }
+}
+
+/// Best medal held for one mission in one mode
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct MissionMedal {
+    pub mode: RunMode,
+    pub mission_id: String,
+    pub medal: Medal,
+}
+
+/// "GOLD - next: PLATINUM at 70000" style line for a mission's medal so far
+pub fn medal_progress_text(mode: RunMode, mission_id: &str, held: Option<Medal>) -> String {
+    let thresholds = medal_thresholds(mission_id);
+    let next = match held {
+        Some(medal) => medal.next(),
+        None => Some(Medal::Bronze),
+    };
+    let target = next.and_then(|medal| {
+        thresholds
+            .required(mode, medal)
+            .map(|value| format!("{} at {}", medal.name(), mode.format_value(value)))
+    });
+    match (held, target) {
+        (Some(medal), Some(target)) => format!("{} - next: {}", medal.name(), target),
+        (Some(medal), None) => format!("{} - top medal", medal.name()),
+        (None, Some(target)) => format!("No medal - {}", target),
+        (None, None) => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Act;
+
+    #[test]
+    fn runs_grade_against_the_missions_thresholds() {
+        let thresholds = medal_thresholds("m1_convoy_raid");
+        assert_eq!(thresholds.grade(RunMode::ScoreAttack, 9_999), None);
+        assert_eq!(
+            thresholds.grade(RunMode::ScoreAttack, 45_000),
+            Some(Medal::Gold)
+        );
+        assert_eq!(
+            thresholds.grade(RunMode::ScoreAttack, 1_000_000),
+            Some(Medal::Platinum)
+        );
+
+        // Time Attack: faster is better, values in milliseconds
+        assert_eq!(
+            thresholds.grade(RunMode::TimeAttack, 109_500),
+            Some(Medal::Silver)
+        );
+        assert_eq!(thresholds.grade(RunMode::TimeAttack, 151_000), None);
+        assert_eq!(thresholds.grade(RunMode::Daily, 1_000_000), None);
+    }
+
+    #[test]
+    fn thresholds_climb_through_the_tiers_and_the_campaign() {
+        let ids: Vec<&str> = [Act::Act1, Act::Act2, Act::Act3]
+            .iter()
+            .flat_map(|act| act.missions())
+            .map(|m| m.id)
+            .collect();
+        for pair in ids.windows(2) {
+            let (a, b) = (medal_thresholds(pair[0]), medal_thresholds(pair[1]));
+            assert!(a.score.iter().zip(b.score).all(|(a, b)| *a < b));
+            assert!(a.time.iter().zip(b.time).all(|(a, b)| *a < b));
+        }
+        for id in &ids {
+            let t = medal_thresholds(id);
+            assert!(t.score.windows(2).all(|w| w[0] < w[1]));
+            assert!(t.time.windows(2).all(|w| w[0] > w[1]));
+        }
+    }
+
+    #[test]
+    fn progress_names_the_next_medal() {
+        assert_eq!(
+            medal_progress_text(RunMode::ScoreAttack, "m1_convoy_raid", None),
+            "No medal - BRONZE at 10000"
+        );
+        assert_eq!(
+            medal_progress_text(RunMode::TimeAttack, "m1_convoy_raid", Some(Medal::Silver)),
+            "SILVER - next: GOLD at 1:20.00"
+        );
+        assert_eq!(
+            medal_progress_text(
+                RunMode::ScoreAttack,
+                "m1_convoy_raid",
+                Some(Medal::Platinum)
+            ),
+            "PLATINUM - top medal"
+        );
+    }
+}
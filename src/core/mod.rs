@@ -1,24 +1,94 @@
 //! Core game systems and types for EVE Rebellion
 //!
 //! This module contains the fundamental building blocks:
-//! - Game states and transitions
+//! - Game states and transitions (and per-state entity cleanup)
+//! - Mission objectives (primary, bonus and hidden)
+//! - Run modes (campaign, time attack, score attack) and their leaderboards
+//! - Per-mission medal thresholds for the attack modes
+//! - Wave spawn budgets and their pacing curves
+//! - The campaign's fleet roster: ship fatigue and Ironman losses
+//! - The route map between campaign missions and its side-system encounters
+//! - Act interludes and the boons picked at them
+//! - The seeded Daily Challenge, and run codes for flying a run again
+//! - Replay hashes, checksums and turbo checks for leaderboard runs
+//! - The account-level prestige track shared by all modules
+//! - New Game+ depths for replaying a cleared campaign
+//! - The codex of factions, ships, bosses and campaign events met so far
 //! - Shared resources (score, currency)
-//! - Custom events
-//! - Game constants
+//! - Save storage backends and cloud sync
+//! - Custom events and the gameplay event log
+//! - The pilot's battle log and per-mission damage breakdown
+//! - Frame schedule (system sets, fixed-step physics)
+//! - Game constants, and the playfield the window shape decides
+//! - Window mode, monitor and windowed placement
+//! - Per-act, per-faction and scripted mission lighting
+//! - Log levels per subsystem, quiet release builds and the log file
 //! - Faction definitions
+//!
+//! The engine-free rules (scoring, berserk, difficulty, acts and factions)
+//! live in `core_logic` and are re-exported from here.
 
+pub mod battle_log;
 pub mod campaign;
+pub mod cleanup;
+pub mod cloud_sync;
+pub mod codex;
 pub mod constants;
+pub mod crash;
+pub mod daily;
+pub mod display;
+pub mod event_log;
 pub mod events;
 pub mod factions;
+pub mod fleet_roster;
 pub mod game_state;
+pub mod interlude;
+pub mod lighting;
+pub mod logging;
+pub mod medals;
+pub mod new_game_plus;
+pub mod objectives;
+pub mod playfield;
+pub mod prestige;
 pub mod resources;
+pub mod route;
+pub mod run_code;
+pub mod run_integrity;
+pub mod run_mode;
 pub mod save;
+pub mod schedule;
+pub mod settings_file;
+pub mod spawn_budget;
 
+pub use crate::core_logic::*;
+pub use battle_log::*;
 pub use campaign::*;
+pub use cleanup::*;
+pub use cloud_sync::*;
+pub use codex::*;
 pub use constants::*;
+pub use crash::*;
+pub use daily::*;
+pub use display::*;
+pub use event_log::*;
 pub use events::*;
 pub use factions::*;
+pub use fleet_roster::*;
 pub use game_state::*;
+pub use interlude::*;
+pub use lighting::*;
+pub use logging::*;
+pub use medals::*;
+pub use new_game_plus::*;
+pub use objectives::*;
+pub use playfield::*;
+pub use prestige::*;
 pub use resources::*;
+pub use route::*;
+pub use run_code::*;
+pub use run_integrity::*;
+pub use run_mode::*;
 pub use save::*;
+pub use schedule::*;
+pub use settings_file::*;
+pub use spawn_budget::*;
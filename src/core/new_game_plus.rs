@@ -0,0 +1,145 @@
+//! New Game+
+//!
+//! Clearing the Elder Fleet campaign unlocks a replay one depth deeper.
+//! Each depth stacks on top of the chosen difficulty: tougher and faster
+//! firing enemies, waves remixed from later stages with more elites, boss
+//! phases drawn from the tables of bosses further on, and a bigger score
+//! multiplier. The deepest depth unlocked and cleared are kept in the save.
+
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Deepest New Game+ depth the modifiers keep scaling to
+pub const MAX_NG_PLUS_DEPTH: u32 = 5;
+
+/// Depth of the campaign being flown (0 = a regular campaign)
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NewGamePlus {
+    pub depth: u32,
+}
+
+impl NewGamePlus {
+    pub fn is_active(&self) -> bool {
+        self.depth > 0
+    }
+
+    pub fn modifiers(&self) -> NgPlusModifiers {
+        NgPlusModifiers::for_depth(self.depth)
+    }
+
+    /// "NG+2" style tag, or empty for a regular campaign
+    pub fn label(&self) -> String {
+        if self.is_active() {
+            format!("NG+{}", self.depth)
+        } else {
+            String::new()
+        }
+    }
+}
+
+/// New Game+ progress kept in the save
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct NgPlusRecord {
+    /// Deepest depth that can be started (0 = locked)
+    pub unlocked: u32,
+    /// Deepest depth cleared
+    pub deepest_cleared: u32,
+}
+
+impl NgPlusRecord {
+    /// Bank a campaign clear at `depth`, unlocking the next one.
+    /// Returns the newly unlocked depth, if any.
+    pub fn record_clear(&mut self, depth: u32) -> Option<u32> {
+        self.deepest_cleared = self.deepest_cleared.max(depth);
+        let next = (depth + 1).min(MAX_NG_PLUS_DEPTH);
+        (next > self.unlocked).then(|| {
+            self.unlocked = next;
+            next
+        })
+    }
+
+    /// The depth after `depth` when cycling through what's unlocked
+    pub fn cycle(&self, depth: u32) -> u32 {
+        if depth >= self.unlocked {
+            0
+        } else {
+            depth + 1
+        }
+    }
+}
+
+/// Stat scaling for one New Game+ depth, applied on top of difficulty
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NgPlusModifiers {
+    pub enemy_health: f32,
+    pub enemy_fire_rate: f32,
+    pub boss_health: f32,
+    /// Chance a wave enemy spawns as an elite
+    pub elite_chance: f32,
+    /// Extra enemies per wave
+    pub extra_enemies: u32,
+    /// Stages further on that wave behaviors and boss patterns are drawn from
+    pub stage_shift: u32,
+    pub score: f32,
+}
+
+impl NgPlusModifiers {
+    pub fn for_depth(depth: u32) -> Self {
+        let d = depth.min(MAX_NG_PLUS_DEPTH) as f32;
+        Self {
+            enemy_health: 1.0 + 0.3 * d,
+            enemy_fire_rate: 1.0 + 0.2 * d,
+            boss_health: 1.0 + 0.35 * d,
+            elite_chance: (0.12 * d).min(0.5),
+            extra_enemies: 2 * depth.min(MAX_NG_PLUS_DEPTH),
+            stage_shift: 3 * depth.min(MAX_NG_PLUS_DEPTH),
+            score: 1.0 + 0.5 * d,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn depth_zero_changes_nothing() {
+        let base = NgPlusModifiers::for_depth(0);
+        assert_eq!(base.enemy_health, 1.0);
+        assert_eq!(base.elite_chance, 0.0);
+        assert_eq!(base.stage_shift, 0);
+        assert_eq!(base.score, 1.0);
+        assert_eq!(NewGamePlus::default().label(), "");
+    }
+
+    #[test]
+    fn modifiers_stack_with_depth_up_to_the_cap() {
+        let one = NgPlusModifiers::for_depth(1);
+        let two = NgPlusModifiers::for_depth(2);
+        assert!(two.enemy_health > one.enemy_health);
+        assert!(two.elite_chance > one.elite_chance);
+        assert_eq!(
+            NgPlusModifiers::for_depth(MAX_NG_PLUS_DEPTH + 3),
+            NgPlusModifiers::for_depth(MAX_NG_PLUS_DEPTH)
+        );
+        assert!(NgPlusModifiers::for_depth(MAX_NG_PLUS_DEPTH).elite_chance <= 0.5);
+    }
+
+    #[test]
+    fn clears_unlock_one_depth_deeper() {
+        let mut record = NgPlusRecord::default();
+        assert_eq!(record.cycle(0), 0);
+
+        assert_eq!(record.record_clear(0), Some(1));
+        assert_eq!(record.record_clear(0), None);
+        assert_eq!(record.record_clear(1), Some(2));
+        assert_eq!(record.deepest_cleared, 1);
+
+        assert_eq!(record.cycle(0), 1);
+        assert_eq!(record.cycle(1), 2);
+        assert_eq!(record.cycle(2), 0);
+    }
+}
@@ -0,0 +1,598 @@
+//! Mission Objectives
+//!
+//! A mission runs several objectives at once: primary ones that define the
+//! mission, optional bonus ones, and hidden ones that only show up once
+//! earned. Objectives are plain data (`ObjectiveDef`), so mission files can
+//! declare their own; the tracker turns them into live progress counters.
+
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// How long the completion checkmark animates (seconds)
+pub const OBJECTIVE_FLASH_TIME: f32 = 0.8;
+
+/// Objective importance
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ObjectiveTier {
+    /// Required to finish the mission
+    Primary,
+    /// Optional, shown from the start
+    Bonus,
+    /// Optional, only revealed once completed
+    Hidden,
+}
+
+/// What an objective asks for
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ObjectiveKind {
+    /// Destroy the mission boss
+    DefeatBoss,
+    /// Clear this many enemy waves
+    ClearWaves(u32),
+    /// Destroy this many enemies, optionally only one ship type
+    DestroyEnemies { count: u32, type_id: Option<u32> },
+    /// Liberate this many souls
+    LiberateSouls(u32),
+    /// Escort this many escape pods out
+    ExtractPods(u32),
+    /// Finish without taking damage
+    NoDamage,
+    /// Finish without losing an escape pod
+    NoPodsLost,
+    /// Finish within this many seconds
+    TimeLimit(f32),
+    /// Keep a transport alive while it crosses the screen over `duration` seconds
+    Escort { health: f32, duration: f32 },
+    /// Keep a station alive for `duration` seconds
+    Defend { health: f32, duration: f32 },
+    /// Pick up cargo crates and fly them to the drop zone
+    Courier(u32),
+    /// Stay alive for this many seconds
+    Survive(f32),
+    /// Hold position within `radius` of a structure for `duration` seconds;
+    /// progress pauses outside it or while enemies contest it
+    Capture { radius: f32, duration: f32 },
+}
+
+impl ObjectiveKind {
+    /// Counter target (1 for pass/fail objectives)
+    pub fn target(&self) -> u32 {
+        match self {
+            ObjectiveKind::ClearWaves(n)
+            | ObjectiveKind::LiberateSouls(n)
+            | ObjectiveKind::ExtractPods(n)
+            | ObjectiveKind::Courier(n) => *n,
+            ObjectiveKind::DestroyEnemies { count, .. } => *count,
+            // Percent captured
+            ObjectiveKind::Capture { .. } => 100,
+            ObjectiveKind::DefeatBoss
+            | ObjectiveKind::NoDamage
+            | ObjectiveKind::NoPodsLost
+            | ObjectiveKind::TimeLimit(_)
+            | ObjectiveKind::Escort { .. }
+            | ObjectiveKind::Defend { .. }
+            | ObjectiveKind::Survive(_) => 1,
+        }
+    }
+
+    /// Objectives that hold until the mission ends unless something breaks them
+    pub fn completes_at_mission_end(&self) -> bool {
+        matches!(
+            self,
+            ObjectiveKind::NoDamage
+                | ObjectiveKind::NoPodsLost
+                | ObjectiveKind::TimeLimit(_)
+                | ObjectiveKind::Escort { .. }
+                | ObjectiveKind::Defend { .. }
+        )
+    }
+
+    /// Hit points of the allied target this objective puts on the field
+    pub fn protected_health(&self) -> Option<f32> {
+        match self {
+            ObjectiveKind::Escort { health, .. } | ObjectiveKind::Defend { health, .. } => {
+                Some(*health)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Objective as declared in mission data
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ObjectiveDef {
+    pub tier: ObjectiveTier,
+    pub label: String,
+    pub kind: ObjectiveKind,
+}
+
+impl ObjectiveDef {
+    pub fn new(tier: ObjectiveTier, label: impl Into<String>, kind: ObjectiveKind) -> Self {
+        Self {
+            tier,
+            label: label.into(),
+            kind,
+        }
+    }
+}
+
+/// Objective lifecycle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ObjectiveStatus {
+    Active,
+    Complete,
+    Failed,
+}
+
+/// Live objective with its progress
+#[derive(Debug, Clone, PartialEq)]
+pub struct Objective {
+    pub def: ObjectiveDef,
+    pub progress: u32,
+    pub status: ObjectiveStatus,
+    /// Seconds left on the completion animation
+    pub flash: f32,
+    /// Enemies are holding up a capture
+    pub contested: bool,
+}
+
+impl Objective {
+    pub fn new(def: ObjectiveDef) -> Self {
+        Self {
+            def,
+            progress: 0,
+            status: ObjectiveStatus::Active,
+            flash: 0.0,
+            contested: false,
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.status == ObjectiveStatus::Complete
+    }
+
+    /// Hidden objectives stay off the HUD until earned
+    pub fn is_visible(&self) -> bool {
+        self.def.tier != ObjectiveTier::Hidden || self.is_complete()
+    }
+
+    fn complete(&mut self) {
+        if self.status == ObjectiveStatus::Active {
+            self.status = ObjectiveStatus::Complete;
+            self.progress = self.def.kind.target();
+            self.flash = OBJECTIVE_FLASH_TIME;
+        }
+    }
+
+    fn fail(&mut self) {
+        if self.status == ObjectiveStatus::Active {
+            self.status = ObjectiveStatus::Failed;
+        }
+    }
+
+    /// Set a counter and complete on reaching the target
+    fn set_progress(&mut self, value: u32) {
+        if self.status != ObjectiveStatus::Active {
+            return;
+        }
+        self.progress = value.min(self.def.kind.target());
+        if self.progress >= self.def.kind.target() {
+            self.complete();
+        }
+    }
+
+    /// HUD line, e.g. "◯ Turrets destroyed 2/5"
+    pub fn display_text(&self, mission_time: f32) -> String {
+        let mark = match self.status {
+            ObjectiveStatus::Active => "◯",
+            ObjectiveStatus::Complete => "✓",
+            ObjectiveStatus::Failed => "✗",
+        };
+        let star = if self.def.tier == ObjectiveTier::Hidden {
+            "★ "
+        } else {
+            ""
+        };
+        let detail = match self.def.kind {
+            ObjectiveKind::ClearWaves(_)
+            | ObjectiveKind::DestroyEnemies { .. }
+            | ObjectiveKind::LiberateSouls(_)
+            | ObjectiveKind::ExtractPods(_)
+            | ObjectiveKind::Courier(_) => {
+                format!(" {}/{}", self.progress, self.def.kind.target())
+            }
+            ObjectiveKind::TimeLimit(limit)
+            | ObjectiveKind::Defend {
+                duration: limit, ..
+            }
+            | ObjectiveKind::Survive(limit)
+                if self.status == ObjectiveStatus::Active =>
+            {
+                let left = (limit - mission_time).max(0.0) as u32;
+                format!(" {}:{:02}", left / 60, left % 60)
+            }
+            ObjectiveKind::Capture { .. } => {
+                let filled = (self.progress / 10) as usize;
+                let contested = if self.contested && self.status == ObjectiveStatus::Active {
+                    " CONTESTED"
+                } else {
+                    ""
+                };
+                format!(
+                    " [{}{}] {}%{}",
+                    "#".repeat(filled),
+                    ".".repeat(10 - filled),
+                    self.progress,
+                    contested
+                )
+            }
+            _ => String::new(),
+        };
+        format!("{} {}{}{}", mark, star, self.def.label, detail)
+    }
+}
+
+/// Mission-wide values objectives are measured against
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ObjectiveSnapshot {
+    pub waves_cleared: u32,
+    pub souls: u32,
+    pub mission_time: f32,
+    pub boss_defeated: bool,
+}
+
+/// Objectives for the mission in progress
+#[derive(Resource, Debug, Default)]
+pub struct ObjectiveTracker {
+    objectives: Vec<Objective>,
+    kills: Vec<u32>,
+    pods_extracted: u32,
+    deliveries: u32,
+    finished: bool,
+    /// Bumped on every `start` so spawners can tell a new objective set apart
+    generation: u32,
+}
+
+impl ObjectiveTracker {
+    /// Start tracking a fresh set of objectives
+    pub fn start(&mut self, defs: Vec<ObjectiveDef>) {
+        self.objectives = defs.into_iter().map(Objective::new).collect();
+        self.kills.clear();
+        self.pods_extracted = 0;
+        self.deliveries = 0;
+        self.finished = false;
+        self.generation = self.generation.wrapping_add(1);
+    }
+
+    pub fn generation(&self) -> u32 {
+        self.generation
+    }
+
+    pub fn clear(&mut self) {
+        self.start(Vec::new());
+    }
+
+    pub fn objectives(&self) -> &[Objective] {
+        &self.objectives
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.objectives.is_empty()
+    }
+
+    /// Count a kill towards destroy objectives
+    pub fn on_enemy_destroyed(&mut self, type_id: u32) {
+        self.kills.push(type_id);
+        for objective in &mut self.objectives {
+            if let ObjectiveKind::DestroyEnemies {
+                type_id: filter, ..
+            } = objective.def.kind
+            {
+                if filter.is_none_or(|id| id == type_id) {
+                    let progress = objective.progress + 1;
+                    objective.set_progress(progress);
+                }
+            }
+        }
+    }
+
+    pub fn on_player_damaged(&mut self) {
+        self.fail_kind(|kind| kind == ObjectiveKind::NoDamage);
+    }
+
+    pub fn on_pod_extracted(&mut self) {
+        self.pods_extracted += 1;
+        let extracted = self.pods_extracted;
+        for objective in &mut self.objectives {
+            if matches!(objective.def.kind, ObjectiveKind::ExtractPods(_)) {
+                objective.set_progress(extracted);
+            }
+        }
+    }
+
+    pub fn on_pod_lost(&mut self) {
+        self.fail_kind(|kind| kind == ObjectiveKind::NoPodsLost);
+    }
+
+    pub fn on_cargo_delivered(&mut self) {
+        self.deliveries += 1;
+        let delivered = self.deliveries;
+        for objective in &mut self.objectives {
+            if matches!(objective.def.kind, ObjectiveKind::Courier(_)) {
+                objective.set_progress(delivered);
+            }
+        }
+    }
+
+    /// The escorted transport reached the far edge
+    pub fn on_escort_arrived(&mut self, index: usize) {
+        if let Some(objective) = self.objectives.get_mut(index) {
+            objective.complete();
+        }
+    }
+
+    /// Capture progress (0.0 - 1.0) on a structure; complete at 1.0
+    pub fn on_capture_progress(&mut self, index: usize, progress: f32, contested: bool) {
+        if let Some(objective) = self.objectives.get_mut(index) {
+            objective.contested = contested;
+            objective.set_progress((progress.clamp(0.0, 1.0) * 100.0) as u32);
+        }
+    }
+
+    /// An escorted or defended target was destroyed
+    pub fn on_target_destroyed(&mut self, index: usize) {
+        if let Some(objective) = self.objectives.get_mut(index) {
+            objective.fail();
+        }
+    }
+
+    /// Any objective of a tier failed
+    pub fn tier_failed(&self, tier: ObjectiveTier) -> bool {
+        self.objectives
+            .iter()
+            .any(|o| o.def.tier == tier && o.status == ObjectiveStatus::Failed)
+    }
+
+    fn fail_kind(&mut self, matches: impl Fn(ObjectiveKind) -> bool) {
+        for objective in &mut self.objectives {
+            if matches(objective.def.kind) {
+                objective.fail();
+            }
+        }
+    }
+
+    /// Update counters from mission state; the boss going down ends the mission
+    pub fn evaluate(&mut self, snapshot: &ObjectiveSnapshot) {
+        if self.finished {
+            return;
+        }
+
+        for objective in &mut self.objectives {
+            match objective.def.kind {
+                ObjectiveKind::ClearWaves(_) => objective.set_progress(snapshot.waves_cleared),
+                ObjectiveKind::LiberateSouls(_) => objective.set_progress(snapshot.souls),
+                ObjectiveKind::DefeatBoss if snapshot.boss_defeated => objective.complete(),
+                ObjectiveKind::TimeLimit(limit) if snapshot.mission_time > limit => {
+                    objective.fail()
+                }
+                ObjectiveKind::Defend { duration, .. } | ObjectiveKind::Survive(duration)
+                    if snapshot.mission_time >= duration =>
+                {
+                    objective.complete()
+                }
+                _ => {}
+            }
+        }
+
+        if snapshot.boss_defeated {
+            self.finished = true;
+            for objective in &mut self.objectives {
+                if objective.def.kind.completes_at_mission_end() {
+                    objective.complete();
+                }
+            }
+        }
+    }
+
+    /// Advance completion animations
+    pub fn tick(&mut self, dt: f32) {
+        for objective in &mut self.objectives {
+            objective.flash = (objective.flash - dt).max(0.0);
+        }
+    }
+
+    /// All objectives of a tier done (false if the tier is empty)
+    pub fn tier_complete(&self, tier: ObjectiveTier) -> bool {
+        let mut of_tier = self.objectives.iter().filter(|o| o.def.tier == tier);
+        let mut any = false;
+        let all = of_tier.all(|o| {
+            any = true;
+            o.is_complete()
+        });
+        any && all
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tracker(defs: Vec<ObjectiveDef>) -> ObjectiveTracker {
+        let mut tracker = ObjectiveTracker::default();
+        tracker.start(defs);
+        tracker
+    }
+
+    #[test]
+    fn destroy_counter_filters_by_type_and_shows_progress() {
+        let mut tracker = tracker(vec![ObjectiveDef::new(
+            ObjectiveTier::Primary,
+            "Turrets destroyed",
+            ObjectiveKind::DestroyEnemies {
+                count: 5,
+                type_id: Some(42),
+            },
+        )]);
+
+        tracker.on_enemy_destroyed(42);
+        tracker.on_enemy_destroyed(7);
+        tracker.on_enemy_destroyed(42);
+
+        let objective = &tracker.objectives()[0];
+        assert_eq!(objective.progress, 2);
+        assert_eq!(objective.display_text(0.0), "◯ Turrets destroyed 2/5");
+
+        for _ in 0..3 {
+            tracker.on_enemy_destroyed(42);
+        }
+        let objective = &tracker.objectives()[0];
+        assert!(objective.is_complete());
+        assert_eq!(objective.flash, OBJECTIVE_FLASH_TIME);
+        assert_eq!(objective.display_text(0.0), "✓ Turrets destroyed 5/5");
+    }
+
+    #[test]
+    fn conditional_objectives_settle_when_the_boss_falls() {
+        let mut tracker = tracker(vec![
+            ObjectiveDef::new(
+                ObjectiveTier::Primary,
+                "Kill boss",
+                ObjectiveKind::DefeatBoss,
+            ),
+            ObjectiveDef::new(ObjectiveTier::Bonus, "Untouched", ObjectiveKind::NoDamage),
+            ObjectiveDef::new(ObjectiveTier::Bonus, "Fast", ObjectiveKind::TimeLimit(60.0)),
+        ]);
+
+        tracker.evaluate(&ObjectiveSnapshot {
+            mission_time: 30.0,
+            ..default()
+        });
+        assert!(!tracker.tier_complete(ObjectiveTier::Primary));
+
+        tracker.on_player_damaged();
+        tracker.evaluate(&ObjectiveSnapshot {
+            mission_time: 45.0,
+            boss_defeated: true,
+            ..default()
+        });
+
+        let status: Vec<_> = tracker.objectives().iter().map(|o| o.status).collect();
+        assert_eq!(
+            status,
+            vec![
+                ObjectiveStatus::Complete,
+                ObjectiveStatus::Failed,
+                ObjectiveStatus::Complete
+            ]
+        );
+        assert!(tracker.tier_complete(ObjectiveTier::Primary));
+        assert!(!tracker.tier_complete(ObjectiveTier::Bonus));
+    }
+
+    #[test]
+    fn hidden_objectives_reveal_on_completion() {
+        let mut tracker = tracker(vec![ObjectiveDef::new(
+            ObjectiveTier::Hidden,
+            "Ace",
+            ObjectiveKind::DestroyEnemies {
+                count: 1,
+                type_id: None,
+            },
+        )]);
+        assert!(!tracker.objectives()[0].is_visible());
+        assert!(!tracker.tier_complete(ObjectiveTier::Bonus));
+
+        tracker.on_enemy_destroyed(1);
+        assert!(tracker.objectives()[0].is_visible());
+        assert_eq!(tracker.objectives()[0].display_text(0.0), "✓ ★ Ace 1/1");
+
+        tracker.tick(OBJECTIVE_FLASH_TIME * 2.0);
+        assert_eq!(tracker.objectives()[0].flash, 0.0);
+    }
+
+    #[test]
+    fn protected_targets_and_courier_runs() {
+        let mut tracker = tracker(vec![
+            ObjectiveDef::new(
+                ObjectiveTier::Primary,
+                "Escort the transport",
+                ObjectiveKind::Escort {
+                    health: 300.0,
+                    duration: 60.0,
+                },
+            ),
+            ObjectiveDef::new(
+                ObjectiveTier::Primary,
+                "Hold the station",
+                ObjectiveKind::Defend {
+                    health: 500.0,
+                    duration: 90.0,
+                },
+            ),
+            ObjectiveDef::new(ObjectiveTier::Bonus, "Supplies", ObjectiveKind::Courier(2)),
+        ]);
+        let generation = tracker.generation();
+        assert_eq!(
+            tracker.objectives()[0].def.kind.protected_health(),
+            Some(300.0)
+        );
+        assert_eq!(
+            tracker.objectives()[1].display_text(30.0),
+            "◯ Hold the station 1:00"
+        );
+
+        tracker.on_escort_arrived(0);
+        tracker.evaluate(&ObjectiveSnapshot {
+            mission_time: 90.0,
+            ..default()
+        });
+        tracker.on_cargo_delivered();
+        assert_eq!(tracker.objectives()[2].display_text(0.0), "◯ Supplies 1/2");
+        tracker.on_cargo_delivered();
+
+        assert!(tracker.tier_complete(ObjectiveTier::Primary));
+        assert!(tracker.tier_complete(ObjectiveTier::Bonus));
+
+        // A lost target fails its own objective only
+        tracker.start(tracker.objectives().iter().map(|o| o.def.clone()).collect());
+        assert_ne!(tracker.generation(), generation);
+        tracker.on_target_destroyed(0);
+        assert_eq!(tracker.objectives()[0].status, ObjectiveStatus::Failed);
+        assert_eq!(tracker.objectives()[1].status, ObjectiveStatus::Active);
+        assert!(tracker.tier_failed(ObjectiveTier::Primary));
+    }
+
+    #[test]
+    fn capture_shows_a_bar_and_completes_when_full() {
+        let mut tracker = tracker(vec![ObjectiveDef::new(
+            ObjectiveTier::Primary,
+            "Seize the relay",
+            ObjectiveKind::Capture {
+                radius: 100.0,
+                duration: 30.0,
+            },
+        )]);
+        assert_eq!(
+            tracker.objectives()[0].display_text(0.0),
+            "◯ Seize the relay [..........] 0%"
+        );
+
+        tracker.on_capture_progress(0, 0.4, true);
+        assert_eq!(
+            tracker.objectives()[0].display_text(0.0),
+            "◯ Seize the relay [####......] 40% CONTESTED"
+        );
+
+        tracker.on_capture_progress(0, 1.0, false);
+        let objective = &tracker.objectives()[0];
+        assert!(objective.is_complete());
+        assert_eq!(
+            objective.display_text(0.0),
+            "✓ Seize the relay [##########] 100%"
+        );
+        // Not something the boss going down hands out
+        assert!(!objective.def.kind.completes_at_mission_end());
+    }
+}
@@ -0,0 +1,239 @@
+//! Playfield
+//!
+//! The world-space rectangle gameplay happens in. At the base 800x700 it is
+//! exactly the window. Other window shapes either stretch the playfield
+//! along the longer axis (ultrawide gets more room to the sides, portrait
+//! more above and below, up to `MAX_STRETCH` times the base) or keep the
+//! base shape and letterbox it. Spawn bounds, clamping, wrapping and
+//! culling all read the playfield instead of the screen constants.
+
+#![allow(dead_code)]
+
+use super::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use bevy::prelude::*;
+use bevy::render::camera::{ScalingMode, Viewport};
+use bevy::window::PrimaryWindow;
+use serde::{Deserialize, Serialize};
+
+/// Furthest the playfield stretches along one axis, as a multiple of the
+/// base; wider or taller windows letterbox the rest
+pub const MAX_STRETCH: f32 = 2.0;
+
+/// Playfield plugin
+pub struct PlayfieldPlugin;
+
+impl Plugin for PlayfieldPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Playfield>()
+            .init_resource::<AspectMode>()
+            .add_systems(PreUpdate, fit_playfield);
+    }
+}
+
+/// Gameplay area in world units, centered on the origin
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct Playfield {
+    pub size: Vec2,
+}
+
+impl Default for Playfield {
+    fn default() -> Self {
+        Self { size: Self::BASE }
+    }
+}
+
+impl Playfield {
+    /// Playfield at the design resolution
+    pub const BASE: Vec2 = Vec2::new(SCREEN_WIDTH, SCREEN_HEIGHT);
+
+    pub fn half(&self) -> Vec2 {
+        self.size / 2.0
+    }
+
+    pub fn half_width(&self) -> f32 {
+        self.size.x / 2.0
+    }
+
+    pub fn half_height(&self) -> f32 {
+        self.size.y / 2.0
+    }
+
+    /// Whether `pos` lies inside the playfield grown by `margin`
+    pub fn contains(&self, pos: Vec2, margin: f32) -> bool {
+        let half = self.half() + margin;
+        pos.x.abs() <= half.x && pos.y.abs() <= half.y
+    }
+
+    /// `pos` pulled inside the playfield shrunk by `inset`
+    pub fn clamp(&self, pos: Vec2, inset: f32) -> Vec2 {
+        let half = (self.half() - inset).max(Vec2::ZERO);
+        pos.clamp(-half, half)
+    }
+
+    /// A uniformly random point inside the playfield
+    pub fn random_point(&self, rng: &mut fastrand::Rng) -> Vec2 {
+        (Vec2::new(rng.f32(), rng.f32()) - 0.5) * self.size
+    }
+}
+
+/// How the playfield fits windows that aren't the base shape (saved)
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AspectMode {
+    /// Stretch the playfield to the window's shape
+    #[default]
+    Fill,
+    /// Keep the base playfield and bar the rest of the window
+    Letterbox,
+}
+
+impl AspectMode {
+    pub fn name(&self) -> &'static str {
+        match self {
+            AspectMode::Fill => "FILL",
+            AspectMode::Letterbox => "LETTERBOX",
+        }
+    }
+
+    pub fn toggled(&self) -> Self {
+        match self {
+            AspectMode::Fill => AspectMode::Letterbox,
+            AspectMode::Letterbox => AspectMode::Fill,
+        }
+    }
+}
+
+/// How the playfield sits in a window
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlayfieldFit {
+    /// Playfield size in world units
+    pub size: Vec2,
+    /// Part of the window it's drawn into (logical pixels); the window
+    /// outside it is letterboxed
+    pub viewport: Rect,
+}
+
+/// Fit the playfield into a `window`-sized window (logical pixels)
+pub fn fit_window(window: Vec2, mode: AspectMode) -> PlayfieldFit {
+    let base = Playfield::BASE;
+    if window.min_element() <= 0.0 {
+        return PlayfieldFit {
+            size: base,
+            viewport: Rect::from_corners(Vec2::ZERO, base),
+        };
+    }
+
+    // World units scale so the base always fits whole
+    let scale = (window / base).min_element();
+    let size = match mode {
+        AspectMode::Fill => (window / scale).min(base * MAX_STRETCH),
+        AspectMode::Letterbox => base,
+    };
+    let used = size * scale;
+    let offset = (window - used) / 2.0;
+    PlayfieldFit {
+        size,
+        viewport: Rect::from_corners(offset, offset + used),
+    }
+}
+
+/// Refit the playfield and camera when the window or aspect mode changes
+fn fit_playfield(
+    mode: Res<AspectMode>,
+    windows: Query<Ref<Window>, With<PrimaryWindow>>,
+    new_cameras: Query<(), Added<Camera2d>>,
+    mut playfield: ResMut<Playfield>,
+    mut cameras: Query<(&mut Camera, &mut OrthographicProjection), With<Camera2d>>,
+) {
+    let Ok(window) = windows.get_single() else {
+        return;
+    };
+    if !window.is_changed() && !mode.is_changed() && new_cameras.is_empty() {
+        return;
+    }
+
+    let fit = fit_window(Vec2::new(window.width(), window.height()), *mode);
+    if playfield.size != fit.size {
+        playfield.size = fit.size;
+        info!("Playfield {:.0}x{:.0}", fit.size.x, fit.size.y);
+    }
+
+    // Only set a viewport when there are bars to draw
+    let scale = window.scale_factor();
+    let letterboxed =
+        (fit.viewport.size() - Vec2::new(window.width(), window.height())).max_element() < -0.5;
+    let viewport = letterboxed.then(|| Viewport {
+        physical_position: (fit.viewport.min * scale).as_uvec2(),
+        physical_size: (fit.viewport.size() * scale).as_uvec2().max(UVec2::ONE),
+        ..default()
+    });
+
+    for (mut camera, mut projection) in cameras.iter_mut() {
+        projection.scaling_mode = ScalingMode::Fixed {
+            width: fit.size.x,
+            height: fit.size.y,
+        };
+        camera.viewport = viewport.clone();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base_window_is_the_base_playfield() {
+        let fit = fit_window(Playfield::BASE, AspectMode::Fill);
+        assert_eq!(fit.size, Playfield::BASE);
+        assert_eq!(fit.viewport.size(), Playfield::BASE);
+        assert_eq!(
+            fit_window(Playfield::BASE * 2.0, AspectMode::Letterbox).size,
+            Playfield::BASE
+        );
+    }
+
+    #[test]
+    fn fill_stretches_along_the_long_axis() {
+        // Ultrawide: full height, more width
+        let wide = fit_window(Vec2::new(2560.0, 1080.0), AspectMode::Fill);
+        assert!((wide.size.y - SCREEN_HEIGHT).abs() < 0.01);
+        assert!(wide.size.x > SCREEN_WIDTH);
+        assert!(wide
+            .viewport
+            .size()
+            .abs_diff_eq(Vec2::new(2560.0, 1080.0), 0.01));
+
+        // Portrait: full width, more height
+        let tall = fit_window(Vec2::new(800.0, 1400.0), AspectMode::Fill);
+        assert_eq!(tall.size.x, SCREEN_WIDTH);
+        assert!(tall.size.y > SCREEN_HEIGHT);
+    }
+
+    #[test]
+    fn letterbox_keeps_the_base_shape_centered() {
+        let fit = fit_window(Vec2::new(1600.0, 700.0), AspectMode::Letterbox);
+        assert_eq!(fit.size, Playfield::BASE);
+        assert_eq!(fit.viewport.min, Vec2::new(400.0, 0.0));
+        assert_eq!(fit.viewport.size(), Playfield::BASE);
+    }
+
+    #[test]
+    fn extreme_windows_stop_stretching() {
+        let fit = fit_window(Vec2::new(8000.0, 700.0), AspectMode::Fill);
+        assert_eq!(fit.size.x, SCREEN_WIDTH * MAX_STRETCH);
+        // The rest is barred
+        assert!(fit.viewport.min.x > 0.0);
+    }
+
+    #[test]
+    fn playfield_bounds() {
+        let playfield = Playfield {
+            size: Vec2::new(1000.0, 600.0),
+        };
+        assert!(playfield.contains(Vec2::new(499.0, 0.0), 0.0));
+        assert!(!playfield.contains(Vec2::new(520.0, 0.0), 10.0));
+        assert_eq!(
+            playfield.clamp(Vec2::new(900.0, -900.0), 20.0),
+            Vec2::new(480.0, -280.0)
+        );
+    }
+}
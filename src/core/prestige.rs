@@ -0,0 +1,558 @@
+//! Prestige
+//!
+//! An account-level track shared by every campaign module. Clearing any
+//! module's campaign earns prestige points, and each reward unlocks for
+//! good once the running total reaches its threshold: paint jobs, a
+//! starting upgrade and run modifiers. The profile also keeps per-module
+//! stats so the profile screen can show Elder Fleet and Caldari Prime
+//! records side by side.
+//!
+//! Run modifiers (mutators) not on the track unlock with an achievement
+//! instead. Each one scales the run's score, harder rules paying more.
+//! Mission medals from Score and Time Attack are kept here too.
+
+#![allow(dead_code)]
+
+use super::campaign::Act;
+use super::game_state::Difficulty;
+use super::medals::{Medal, MissionMedal};
+use super::run_mode::RunMode;
+use serde::{Deserialize, Serialize};
+
+/// Module id of the Elder Fleet campaign
+pub const ELDER_FLEET: &str = "elder_fleet";
+/// Module id of the Battle of Caldari Prime campaign
+pub const CALDARI_PRIME: &str = "caldari_gallente";
+
+/// Modules on the shared track, with their profile names
+pub const PRESTIGE_MODULES: [(&str, &str); 2] = [
+    (ELDER_FLEET, "ELDER FLEET"),
+    (CALDARI_PRIME, "CALDARI PRIME"),
+];
+
+/// Points for every campaign clear
+pub const PRESTIGE_PER_CLEAR: u32 = 100;
+/// Extra points the first time a module is cleared
+pub const FIRST_CLEAR_BONUS: u32 = 100;
+/// Starting upgrade: armor bonus at launch
+pub const SALVAGED_PLATING_ARMOR: f32 = 1.2;
+
+/// Extra points for clearing on a harder difficulty
+pub fn difficulty_bonus(difficulty: Difficulty) -> u32 {
+    match difficulty {
+        Difficulty::Carebear | Difficulty::Newbro => 0,
+        Difficulty::BitterVet => 50,
+        Difficulty::Triglavian => 100,
+    }
+}
+
+/// Optional rule changes for campaign runs, unlocked by prestige or an
+/// achievement
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunModifier {
+    /// Hit harder, break easier
+    GlassCannon,
+    /// A heavier hull that turns slower
+    Ironclad,
+    /// Enemies fire twice as fast
+    BulletHell,
+    /// No second chances
+    IronClone,
+    /// More score, less loot
+    GoldenAge,
+}
+
+impl RunModifier {
+    pub const ALL: [RunModifier; 5] = [
+        RunModifier::GlassCannon,
+        RunModifier::Ironclad,
+        RunModifier::BulletHell,
+        RunModifier::IronClone,
+        RunModifier::GoldenAge,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            RunModifier::GlassCannon => "Glass Cannon",
+            RunModifier::Ironclad => "Ironclad",
+            RunModifier::BulletHell => "Bullet Hell",
+            RunModifier::IronClone => "Iron Clone",
+            RunModifier::GoldenAge => "Golden Age",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            RunModifier::GlassCannon => "+50% weapon damage, half shields and armor",
+            RunModifier::Ironclad => "+50% armor and hull, 20% slower",
+            RunModifier::BulletHell => "Enemies fire twice as fast",
+            RunModifier::IronClone => "No extra lives, no mission restarts",
+            RunModifier::GoldenAge => "Double score, half the loot",
+        }
+    }
+
+    /// Score multiplier while active
+    pub fn score_mult(&self) -> f32 {
+        match self {
+            RunModifier::GlassCannon => 1.25,
+            RunModifier::Ironclad => 0.9,
+            RunModifier::BulletHell => 1.5,
+            RunModifier::IronClone => 1.5,
+            RunModifier::GoldenAge => 2.0,
+        }
+    }
+
+    /// Loot drop chance multiplier while active
+    pub fn drop_mult(&self) -> f32 {
+        match self {
+            RunModifier::GoldenAge => 0.5,
+            _ => 1.0,
+        }
+    }
+
+    /// The achievement that unlocks it (`None`: it's on the prestige track)
+    pub fn achievement(&self) -> Option<Achievement> {
+        match self {
+            RunModifier::GlassCannon | RunModifier::Ironclad => None,
+            RunModifier::BulletHell => Some(Achievement::FirstClear),
+            RunModifier::IronClone => Some(Achievement::Veteran),
+            RunModifier::GoldenAge => Some(Achievement::HighRoller),
+        }
+    }
+
+    /// How to unlock it, for locked rows
+    pub fn unlock_hint(&self) -> String {
+        match self.achievement() {
+            Some(achievement) => achievement.description().to_string(),
+            None => PRESTIGE_REWARDS
+                .iter()
+                .find(|r| r.kind == PrestigeRewardKind::Modifier(*self))
+                .map_or_else(String::new, |r| format!("Reach {} prestige", r.required)),
+        }
+    }
+}
+
+/// Account milestones, earned from lifetime stats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Achievement {
+    FirstClear,
+    Veteran,
+    HighRoller,
+    GoldStandard,
+}
+
+impl Achievement {
+    pub const ALL: [Achievement; 4] = [
+        Achievement::FirstClear,
+        Achievement::Veteran,
+        Achievement::HighRoller,
+        Achievement::GoldStandard,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Achievement::FirstClear => "Liberator",
+            Achievement::Veteran => "Veteran",
+            Achievement::HighRoller => "High Roller",
+            Achievement::GoldStandard => "Gold Standard",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Achievement::FirstClear => "Clear any campaign",
+            Achievement::Veteran => "Destroy 1,000 ships",
+            Achievement::HighRoller => "Score 250,000 in one run",
+            Achievement::GoldStandard => "Gold in Score and Time Attack on every mission",
+        }
+    }
+
+    pub fn earned(&self, profile: &PrestigeProfile) -> bool {
+        let totals = profile.totals();
+        match self {
+            Achievement::FirstClear => totals.campaign_clears >= 1,
+            Achievement::Veteran => totals.kills >= 1_000,
+            Achievement::HighRoller => totals.best_score >= 250_000,
+            Achievement::GoldStandard => profile.gold_on_every_mission(),
+        }
+    }
+}
+
+/// What a reward unlocks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrestigeRewardKind {
+    /// A paint job (by name in `SHIP_SKINS`)
+    Skin(&'static str),
+    /// Salvaged plating on every campaign launch
+    StartingUpgrade,
+    Modifier(RunModifier),
+}
+
+/// A reward on the prestige track
+#[derive(Debug, Clone, Copy)]
+pub struct PrestigeReward {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Prestige points needed
+    pub required: u32,
+    pub kind: PrestigeRewardKind,
+}
+
+/// The track, in unlock order
+pub const PRESTIGE_REWARDS: &[PrestigeReward] = &[
+    PrestigeReward {
+        name: "Elder's Chosen",
+        description: "Gold-leaf paint job for any hull",
+        required: 100,
+        kind: PrestigeRewardKind::Skin("Elder's Chosen"),
+    },
+    PrestigeReward {
+        name: "Salvaged Plating",
+        description: "Campaign runs launch with +20% armor",
+        required: 200,
+        kind: PrestigeRewardKind::StartingUpgrade,
+    },
+    PrestigeReward {
+        name: "Glass Cannon",
+        description: "Run modifier: +50% damage, half shields and armor",
+        required: 300,
+        kind: PrestigeRewardKind::Modifier(RunModifier::GlassCannon),
+    },
+    PrestigeReward {
+        name: "Caldari Prime Ace",
+        description: "Steel-blue paint job for any hull",
+        required: 450,
+        kind: PrestigeRewardKind::Skin("Caldari Prime Ace"),
+    },
+    PrestigeReward {
+        name: "Ironclad",
+        description: "Run modifier: +50% armor and hull, 20% slower",
+        required: 600,
+        kind: PrestigeRewardKind::Modifier(RunModifier::Ironclad),
+    },
+];
+
+/// Lifetime stats for one module
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ModuleStats {
+    pub module: String,
+    /// Runs that ended (death or campaign clear)
+    pub runs: u32,
+    pub campaign_clears: u32,
+    pub kills: u64,
+    pub best_score: u64,
+}
+
+/// Account-level prestige and stats, shared by all modules
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct PrestigeProfile {
+    /// Lifetime prestige points (never spent)
+    pub points: u32,
+    /// Stats per module
+    pub modules: Vec<ModuleStats>,
+    /// Modifiers switched on for campaign runs
+    #[serde(default)]
+    pub active_modifiers: Vec<RunModifier>,
+    /// Best Score and Time Attack medal per mission
+    #[serde(default)]
+    pub medals: Vec<MissionMedal>,
+}
+
+impl PrestigeProfile {
+    pub fn module(&self, module: &str) -> Option<&ModuleStats> {
+        self.modules.iter().find(|m| m.module == module)
+    }
+
+    fn module_mut(&mut self, module: &str) -> &mut ModuleStats {
+        if let Some(index) = self.modules.iter().position(|m| m.module == module) {
+            return &mut self.modules[index];
+        }
+        let index = self.modules.len();
+        self.modules.push(ModuleStats {
+            module: module.to_string(),
+            ..Default::default()
+        });
+        &mut self.modules[index]
+    }
+
+    /// Bank kills and score from a mission; `run_over` counts a finished run
+    pub fn record(&mut self, module: &str, kills: u64, score: u64, run_over: bool) {
+        let stats = self.module_mut(module);
+        stats.kills += kills;
+        stats.best_score = stats.best_score.max(score);
+        if run_over {
+            stats.runs += 1;
+        }
+    }
+
+    /// Count a campaign clear and award its prestige; returns the rewards
+    /// it unlocked
+    pub fn complete_campaign(
+        &mut self,
+        module: &str,
+        difficulty: Difficulty,
+    ) -> Vec<&'static PrestigeReward> {
+        let stats = self.module_mut(module);
+        let first_clear = stats.campaign_clears == 0;
+        stats.campaign_clears += 1;
+
+        let before = self.points;
+        self.points += PRESTIGE_PER_CLEAR + difficulty_bonus(difficulty);
+        if first_clear {
+            self.points += FIRST_CLEAR_BONUS;
+        }
+
+        PRESTIGE_REWARDS
+            .iter()
+            .filter(|r| r.required > before && r.required <= self.points)
+            .collect()
+    }
+
+    pub fn is_unlocked(&self, reward: &PrestigeReward) -> bool {
+        self.points >= reward.required
+    }
+
+    /// The next reward still to unlock
+    pub fn next_reward(&self) -> Option<&'static PrestigeReward> {
+        PRESTIGE_REWARDS.iter().find(|r| !self.is_unlocked(r))
+    }
+
+    pub fn has_starting_upgrade(&self) -> bool {
+        PRESTIGE_REWARDS
+            .iter()
+            .any(|r| r.kind == PrestigeRewardKind::StartingUpgrade && self.is_unlocked(r))
+    }
+
+    pub fn modifier_unlocked(&self, modifier: RunModifier) -> bool {
+        match modifier.achievement() {
+            Some(achievement) => achievement.earned(self),
+            None => PRESTIGE_REWARDS
+                .iter()
+                .any(|r| r.kind == PrestigeRewardKind::Modifier(modifier) && self.is_unlocked(r)),
+        }
+    }
+
+    pub fn modifier_active(&self, modifier: RunModifier) -> bool {
+        self.active_modifiers.contains(&modifier)
+    }
+
+    /// Switch an unlocked modifier on or off; returns whether it is now on
+    pub fn toggle_modifier(&mut self, modifier: RunModifier) -> bool {
+        if self.modifier_active(modifier) {
+            self.active_modifiers.retain(|&m| m != modifier);
+            false
+        } else if self.modifier_unlocked(modifier) {
+            self.active_modifiers.push(modifier);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Modifiers in force for a run in `mode`; attack and daily runs fly
+    /// stock
+    pub fn run_modifiers(&self, mode: RunMode) -> &[RunModifier] {
+        if mode == RunMode::Campaign {
+            &self.active_modifiers
+        } else {
+            &[]
+        }
+    }
+
+    /// Score multiplier from the modifiers in force
+    pub fn modifier_score_mult(&self, mode: RunMode) -> f32 {
+        self.run_modifiers(mode)
+            .iter()
+            .map(|m| m.score_mult())
+            .product()
+    }
+
+    /// Loot drop multiplier from the modifiers in force
+    pub fn modifier_drop_mult(&self, mode: RunMode) -> f32 {
+        self.run_modifiers(mode)
+            .iter()
+            .map(|m| m.drop_mult())
+            .product()
+    }
+
+    /// All modules added together
+    pub fn totals(&self) -> ModuleStats {
+        self.modules.iter().fold(
+            ModuleStats {
+                module: "all".to_string(),
+                ..Default::default()
+            },
+            |mut total, m| {
+                total.runs += m.runs;
+                total.campaign_clears += m.campaign_clears;
+                total.kills += m.kills;
+                total.best_score = total.best_score.max(m.best_score);
+                total
+            },
+        )
+    }
+
+    /// Best medal held for a mission in an attack mode
+    pub fn medal(&self, mode: RunMode, mission_id: &str) -> Option<Medal> {
+        self.medals
+            .iter()
+            .find(|m| m.mode == mode && m.mission_id == mission_id)
+            .map(|m| m.medal)
+    }
+
+    /// Keep `medal` if it beats the one held; returns whether it did
+    pub fn award_medal(&mut self, mode: RunMode, mission_id: &str, medal: Medal) -> bool {
+        match self
+            .medals
+            .iter_mut()
+            .find(|m| m.mode == mode && m.mission_id == mission_id)
+        {
+            Some(held) if held.medal >= medal => false,
+            Some(held) => {
+                held.medal = medal;
+                true
+            }
+            None => {
+                self.medals.push(MissionMedal {
+                    mode,
+                    mission_id: mission_id.to_string(),
+                    medal,
+                });
+                true
+            }
+        }
+    }
+
+    /// Gold or better in both attack modes on every campaign mission
+    pub fn gold_on_every_mission(&self) -> bool {
+        [Act::Act1, Act::Act2, Act::Act3]
+            .iter()
+            .flat_map(|act| act.missions())
+            .all(|mission| {
+                [RunMode::ScoreAttack, RunMode::TimeAttack]
+                    .iter()
+                    .all(|&mode| self.medal(mode, mission.id) >= Some(Medal::Gold))
+            })
+    }
+
+    pub fn rank(&self) -> &'static str {
+        match self.points {
+            0..100 => "Recruit",
+            100..300 => "Freedom Fighter",
+            300..600 => "Elder's Hand",
+            _ => "Legend of the Fleet",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::SHIP_SKINS;
+
+    #[test]
+    fn clearing_campaigns_climbs_the_shared_track() {
+        let mut profile = PrestigeProfile::default();
+        assert!(profile.next_reward().is_some());
+
+        // First Elder Fleet clear: base + first-clear bonus
+        let unlocked = profile.complete_campaign(ELDER_FLEET, Difficulty::Newbro);
+        assert_eq!(profile.points, 200);
+        let names: Vec<_> = unlocked.iter().map(|r| r.name).collect();
+        assert_eq!(names, vec!["Elder's Chosen", "Salvaged Plating"]);
+        assert!(profile.has_starting_upgrade());
+
+        // The other module counts toward the same track; repeats pay less
+        profile.complete_campaign(CALDARI_PRIME, Difficulty::Triglavian);
+        assert_eq!(profile.points, 500);
+        profile.complete_campaign(ELDER_FLEET, Difficulty::Newbro);
+        assert_eq!(profile.points, 600);
+        assert!(profile.next_reward().is_none());
+        assert_eq!(profile.module(ELDER_FLEET).unwrap().campaign_clears, 2);
+        assert_eq!(profile.rank(), "Legend of the Fleet");
+    }
+
+    #[test]
+    fn prestige_skins_match_the_paint_shop() {
+        for reward in PRESTIGE_REWARDS {
+            if let PrestigeRewardKind::Skin(name) = reward.kind {
+                let skin = SHIP_SKINS.iter().find(|s| s.name == name).unwrap();
+                assert_eq!(skin.prestige, reward.required);
+            }
+        }
+    }
+
+    #[test]
+    fn modifiers_toggle_only_once_unlocked() {
+        let mut profile = PrestigeProfile::default();
+        assert!(!profile.toggle_modifier(RunModifier::GlassCannon));
+        assert!(!profile.modifier_active(RunModifier::GlassCannon));
+
+        profile.points = 300;
+        assert!(profile.toggle_modifier(RunModifier::GlassCannon));
+        assert!(profile.modifier_active(RunModifier::GlassCannon));
+        assert!(!profile.toggle_modifier(RunModifier::Ironclad));
+        assert!(!profile.toggle_modifier(RunModifier::GlassCannon));
+        assert!(profile.active_modifiers.is_empty());
+    }
+
+    #[test]
+    fn mutators_unlock_with_their_achievements() {
+        let mut profile = PrestigeProfile::default();
+        assert!(!profile.toggle_modifier(RunModifier::BulletHell));
+        assert_eq!(RunModifier::GlassCannon.unlock_hint(), "Reach 300 prestige");
+
+        profile.complete_campaign(ELDER_FLEET, Difficulty::Newbro);
+        assert!(profile.toggle_modifier(RunModifier::BulletHell));
+        assert!(!profile.modifier_unlocked(RunModifier::IronClone));
+
+        profile.record(CALDARI_PRIME, 1_000, 300_000, true);
+        assert!(profile.modifier_unlocked(RunModifier::IronClone));
+        assert!(profile.modifier_unlocked(RunModifier::GoldenAge));
+    }
+
+    #[test]
+    fn active_mutators_scale_campaign_score_and_loot_only() {
+        let mut profile = PrestigeProfile::default();
+        profile.active_modifiers = vec![RunModifier::BulletHell, RunModifier::GoldenAge];
+        assert_eq!(profile.modifier_score_mult(RunMode::Campaign), 3.0);
+        assert_eq!(profile.modifier_drop_mult(RunMode::Campaign), 0.5);
+        assert_eq!(profile.modifier_score_mult(RunMode::ScoreAttack), 1.0);
+        assert_eq!(profile.modifier_drop_mult(RunMode::Daily), 1.0);
+    }
+
+    #[test]
+    fn medals_only_improve_and_gold_everywhere_is_an_achievement() {
+        let mut profile = PrestigeProfile::default();
+        assert!(profile.award_medal(RunMode::ScoreAttack, "m1_convoy_raid", Medal::Silver));
+        assert!(!profile.award_medal(RunMode::ScoreAttack, "m1_convoy_raid", Medal::Bronze));
+        assert!(profile.award_medal(RunMode::ScoreAttack, "m1_convoy_raid", Medal::Gold));
+        assert_eq!(
+            profile.medal(RunMode::ScoreAttack, "m1_convoy_raid"),
+            Some(Medal::Gold)
+        );
+        assert_eq!(profile.medal(RunMode::TimeAttack, "m1_convoy_raid"), None);
+        assert!(!Achievement::GoldStandard.earned(&profile));
+
+        for act in [Act::Act1, Act::Act2, Act::Act3] {
+            for mission in act.missions() {
+                profile.award_medal(RunMode::ScoreAttack, mission.id, Medal::Platinum);
+                profile.award_medal(RunMode::TimeAttack, mission.id, Medal::Gold);
+            }
+        }
+        assert!(Achievement::GoldStandard.earned(&profile));
+    }
+
+    #[test]
+    fn stats_total_across_modules() {
+        let mut profile = PrestigeProfile::default();
+        profile.record(ELDER_FLEET, 40, 12_000, false);
+        profile.record(ELDER_FLEET, 10, 9_000, true);
+        profile.record(CALDARI_PRIME, 25, 30_000, true);
+
+        let elder = profile.module(ELDER_FLEET).unwrap();
+        assert_eq!((elder.kills, elder.best_score, elder.runs), (50, 12_000, 1));
+        let total = profile.totals();
+        assert_eq!((total.kills, total.best_score, total.runs), (75, 30_000, 2));
+    }
+}
@@ -0,0 +1,346 @@
+//! Route Map
+//!
+//! Between Elder Fleet missions the fleet jumps through a short stretch of
+//! New Eden. The direct route goes straight to the next mission's system; a
+//! detour through one of two side systems adds an encounter on the way:
+//! refugees to take aboard (souls and credits), a black market (a powerup
+//! bought with credits, delivered at the next warp-in), or an elite wing
+//! lying in wait at the next mission (bounties in credits and ISK). Which
+//! side systems a leg offers is fixed per act and mission, so a route can
+//! be learned.
+
+#![allow(dead_code)]
+
+use super::campaign::Act;
+use super::events::CollectibleType;
+use super::resources::{GameProgress, ScoreSystem};
+use bevy::prelude::*;
+
+/// What waits in a side system
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encounter {
+    /// Escaped slaves to take aboard
+    Refugees,
+    /// Powerup for sale, delivered at the next warp-in
+    Market,
+    /// An elite wing waits at the next mission
+    Ambush,
+}
+
+impl Encounter {
+    pub const ALL: [Encounter; 3] = [Encounter::Refugees, Encounter::Market, Encounter::Ambush];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Encounter::Refugees => "REFUGEE CONVOY",
+            Encounter::Market => "BLACK MARKET",
+            Encounter::Ambush => "ELITE AMBUSH",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Encounter::Refugees => "Escaped slaves adrift. Take them aboard.",
+            Encounter::Market => "A smuggler will sell gear - for credits.",
+            Encounter::Ambush => "Navy elites shadow this route. Bounties on each.",
+        }
+    }
+}
+
+/// A system off the direct route
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SideSystem {
+    pub name: &'static str,
+    /// Security status (0.0 - 1.0)
+    pub security: f32,
+    pub encounter: Encounter,
+}
+
+/// Minmatar space the early raids leave from
+const ACT1_SYSTEMS: [(&str, f32); 4] = [
+    ("Auga", 0.6),
+    ("Hek", 0.5),
+    ("Egghelende", 0.4),
+    ("Frerstorn", 0.6),
+];
+
+/// The contested border of the invasion
+const ACT2_SYSTEMS: [(&str, f32); 4] = [
+    ("Amamake", 0.4),
+    ("Vard", 0.3),
+    ("Ebolfer", 0.3),
+    ("Resbroko", 0.2),
+];
+
+/// Deep in the Empire
+const ACT3_SYSTEMS: [(&str, f32); 4] = [
+    ("Penirgman", 0.8),
+    ("Dresi", 0.9),
+    ("Ashab", 0.9),
+    ("Mabnen", 0.8),
+];
+
+/// Security of the systems missions are flown in, by act
+const MISSION_SECURITY: [f32; 3] = [0.4, 0.3, 1.0];
+
+/// Seconds into the mission the ambush springs
+pub const AMBUSH_DELAY: f32 = 6.0;
+
+/// Health of an ambush elite against a regular ship of its class
+pub const ELITE_HEALTH_MULT: f32 = 1.5;
+
+/// System a story mission is flown in
+pub fn mission_system(mission_id: &str) -> &'static str {
+    match mission_id {
+        "m1_convoy_raid" => "Arzad",
+        "m2_patrol_ambush" => "Kamela",
+        "m3_station_raid" => "Sosala",
+        "m4_holder_escape" => "Huola",
+        "m5_customs_strike" => "Kourmonen",
+        "m6_inquisition" => "Sasiekko",
+        "m7_navy_battle" => "Amdonen",
+        "m8_stargate" => "Arzad Prime",
+        "m9_battlestation" => "Bhizheba",
+        "m10_abaddon" => "Sarum Prime",
+        "m11_titan_escort" => "Kor-Azor Prime",
+        "m12_champion" => "Zoohen",
+        "m13_avatar" => "Amarr",
+        _ => "Unknown",
+    }
+}
+
+/// System the fleet leaves from on the way to `act`'s mission
+/// `mission_index`: wherever the previous mission was flown
+pub fn route_origin(act: Act, mission_index: usize) -> &'static str {
+    let previous = match (mission_index, act) {
+        (0, Act::Act1) => None,
+        (0, Act::Act2) => Act::Act1.missions().last(),
+        (0, Act::Act3) => Act::Act2.missions().last(),
+        (index, act) => act.missions().get(index - 1),
+    };
+    previous.map_or("Pator", |mission| mission_system(mission.id))
+}
+
+/// Security of the systems an act's missions are flown in
+pub fn mission_security(act: Act) -> f32 {
+    MISSION_SECURITY[act.number() as usize - 1]
+}
+
+/// The two side systems on the way to `act`'s mission `mission_index`.
+/// Each leg leaves out a different encounter, so consecutive legs differ.
+pub fn route_leg(act: Act, mission_index: usize) -> [SideSystem; 2] {
+    let pool = match act {
+        Act::Act1 => &ACT1_SYSTEMS,
+        Act::Act2 => &ACT2_SYSTEMS,
+        Act::Act3 => &ACT3_SYSTEMS,
+    };
+    let skipped = mission_index % Encounter::ALL.len();
+    let mut encounters = Encounter::ALL
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| *i != skipped)
+        .map(|(_, e)| *e);
+
+    std::array::from_fn(|slot| {
+        let (name, security) = pool[(mission_index * 2 + slot) % pool.len()];
+        SideSystem {
+            name,
+            security,
+            encounter: encounters.next().unwrap_or(Encounter::Refugees),
+        }
+    })
+}
+
+/// Souls a refugee convoy adds
+pub fn refugee_souls(act: Act) -> u32 {
+    6 + 4 * act.number()
+}
+
+/// Credits the fleet pays per refugee brought in
+const REFUGEE_CREDITS_PER_SOUL: u64 = 25;
+
+/// What the black market charges
+pub fn market_price(act: Act) -> u64 {
+    200 + 200 * act.number() as u64
+}
+
+/// What the black market has on the way to `act`'s mission `mission_index`
+pub fn market_item(act: Act, mission_index: usize) -> CollectibleType {
+    const STOCK: [CollectibleType; 4] = [
+        CollectibleType::Nanite,
+        CollectibleType::DamageBoost,
+        CollectibleType::Overdrive,
+        CollectibleType::ExtraLife,
+    ];
+    STOCK[(mission_index + act.number() as usize) % STOCK.len()]
+}
+
+/// Type IDs of the elite wing lying in wait
+pub fn ambush_wing(act: Act) -> &'static [u32] {
+    match act {
+        Act::Act1 => &[16236, 16236],
+        Act::Act2 => &[16236, 16236, 16236],
+        Act::Act3 => &[24690, 16236, 16236],
+    }
+}
+
+/// Credits and ISK paid per elite downed
+pub fn ambush_bounty(act: Act) -> (u64, u64) {
+    let n = act.number() as u64;
+    (150 * n, 50 * n)
+}
+
+/// What a detour came to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RouteOutcome {
+    /// Refugees taken aboard
+    Refugees { souls: u32, credits: u64 },
+    /// Powerup bought
+    Bought { item: CollectibleType, price: u64 },
+    /// Couldn't pay for it
+    CantAfford { price: u64 },
+    /// An elite wing will be waiting
+    AmbushAhead,
+}
+
+impl std::fmt::Display for RouteOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            RouteOutcome::Refugees { souls, credits } => {
+                write!(f, "{} refugees aboard  +{} credits", souls, credits)
+            }
+            RouteOutcome::Bought { price, .. } => {
+                write!(f, "Bought for {} credits - delivered at warp-in", price)
+            }
+            RouteOutcome::CantAfford { price } => {
+                write!(f, "Not enough credits ({} needed)", price)
+            }
+            RouteOutcome::AmbushAhead => write!(f, "Elites are tracking you. Be ready."),
+        }
+    }
+}
+
+/// Route choices carried into the next mission
+#[derive(Resource, Debug, Default)]
+pub struct RouteState {
+    /// Powerup bought at a market, delivered at the next warp-in
+    pub stocked: Option<CollectibleType>,
+    /// An elite wing waits at the next mission
+    pub ambush_armed: bool,
+    /// Seconds until the armed ambush springs, once its mission is underway
+    pub ambush_timer: Option<f32>,
+    /// Elites from the sprung ambush still flying
+    pub ambush_wing: Vec<Entity>,
+    /// Side systems detoured through this run
+    pub detours: u32,
+}
+
+impl RouteState {
+    /// Play out a detour through `system` on the way to `act`'s mission
+    /// `mission_index`
+    pub fn detour(
+        &mut self,
+        system: &SideSystem,
+        act: Act,
+        mission_index: usize,
+        score: &mut ScoreSystem,
+        progress: &mut GameProgress,
+    ) -> RouteOutcome {
+        let outcome = match system.encounter {
+            Encounter::Refugees => {
+                let souls = refugee_souls(act);
+                let credits = souls as u64 * REFUGEE_CREDITS_PER_SOUL;
+                score.souls_liberated += souls;
+                progress.credits += credits;
+                RouteOutcome::Refugees { souls, credits }
+            }
+            Encounter::Market => {
+                let price = market_price(act);
+                if progress.credits < price {
+                    // Turned away; the route is still open
+                    return RouteOutcome::CantAfford { price };
+                }
+                let item = market_item(act, mission_index);
+                progress.credits -= price;
+                self.stocked = Some(item);
+                RouteOutcome::Bought { item, price }
+            }
+            Encounter::Ambush => {
+                self.ambush_armed = true;
+                RouteOutcome::AmbushAhead
+            }
+        };
+        self.detours += 1;
+        outcome
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_leg_offers_two_different_encounters_in_the_acts_space() {
+        for act in [Act::Act1, Act::Act2, Act::Act3] {
+            for (index, mission) in act.missions().iter().enumerate() {
+                let [a, b] = route_leg(act, index);
+                assert_ne!(a.encounter, b.encounter);
+                assert_ne!(a.name, b.name);
+                assert_ne!(mission_system(mission.id), "Unknown");
+            }
+        }
+        assert_eq!(route_origin(Act::Act2, 0), "Huola");
+        assert_eq!(route_origin(Act::Act2, 1), "Kourmonen");
+        // Consecutive legs don't repeat
+        assert_ne!(route_leg(Act::Act2, 1), route_leg(Act::Act2, 2));
+        // Amarr space is high-sec, the border isn't
+        assert!(route_leg(Act::Act3, 0)[0].security > route_leg(Act::Act2, 0)[0].security);
+    }
+
+    #[test]
+    fn detours_pay_out_spend_credits_or_arm_an_ambush() {
+        let mut route = RouteState::default();
+        let mut score = ScoreSystem::default();
+        let mut progress = GameProgress::default();
+        let system = |encounter| SideSystem {
+            name: "Hek",
+            security: 0.5,
+            encounter,
+        };
+
+        let market = system(Encounter::Market);
+        assert_eq!(
+            route.detour(&market, Act::Act1, 0, &mut score, &mut progress),
+            RouteOutcome::CantAfford {
+                price: market_price(Act::Act1)
+            }
+        );
+        assert_eq!(route.stocked, None);
+
+        let outcome = route.detour(
+            &system(Encounter::Refugees),
+            Act::Act1,
+            0,
+            &mut score,
+            &mut progress,
+        );
+        assert_eq!(score.souls_liberated, refugee_souls(Act::Act1));
+        assert!(progress.credits >= market_price(Act::Act1));
+        assert!(matches!(outcome, RouteOutcome::Refugees { .. }));
+
+        let credits = progress.credits;
+        route.detour(&market, Act::Act1, 0, &mut score, &mut progress);
+        assert_eq!(route.stocked, Some(market_item(Act::Act1, 0)));
+        assert_eq!(progress.credits, credits - market_price(Act::Act1));
+
+        route.detour(
+            &system(Encounter::Ambush),
+            Act::Act1,
+            0,
+            &mut score,
+            &mut progress,
+        );
+        assert!(route.ambush_armed);
+        assert_eq!(route.detours, 3);
+    }
+}
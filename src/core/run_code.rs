@@ -0,0 +1,245 @@
+//! Run Codes
+//!
+//! A short code pinning down everything a run depends on - mode, factions,
+//! mission, difficulty, ship, mutators and seed (the day, for a daily) - so
+//! pilots can hand one around and all fly the same run. Codes are twelve
+//! Crockford base32 characters in groups of four, with a checksum so a
+//! typo is refused rather than quietly flying a different run.
+
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+
+use super::factions::{Faction, GameSession};
+use super::game_state::Difficulty;
+use super::prestige::RunModifier;
+use super::run_mode::RunMode;
+
+/// Characters in a code, not counting separators
+pub const RUN_CODE_LENGTH: usize = 12;
+
+/// Crockford base32: no I, L, O or U to misread
+const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Layout version, bumped whenever the bit layout changes
+const VERSION: u64 = 1;
+
+/// Bits before the checksum
+const PAYLOAD_BITS: u32 = 55;
+
+/// Stages a code can name
+const STAGES: u32 = 13;
+
+const MODES: [RunMode; 4] = [
+    RunMode::Campaign,
+    RunMode::TimeAttack,
+    RunMode::ScoreAttack,
+    RunMode::Daily,
+];
+
+/// Everything needed to fly a run again
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunCode {
+    pub mode: RunMode,
+    pub player_faction: Faction,
+    pub enemy_faction: Faction,
+    /// Stage number (1-13)
+    pub stage: u32,
+    pub difficulty: Difficulty,
+    /// Index into the player faction's ships
+    pub ship_index: usize,
+    /// Campaign mutators in force
+    pub modifiers: Vec<RunModifier>,
+    /// Run seed, or the day number for a daily
+    pub seed: u32,
+}
+
+/// The code of the run being flown, and one entered to fly again
+#[derive(Resource, Debug, Default)]
+pub struct RunCodes {
+    pub current: Option<RunCode>,
+    /// Entered on the mode screen; its seed is used while the run matches
+    pub imported: Option<RunCode>,
+}
+
+impl RunCode {
+    /// Code for a run about to start
+    pub fn for_run(
+        mode: RunMode,
+        session: &GameSession,
+        difficulty: Difficulty,
+        stage: u32,
+        modifiers: &[RunModifier],
+        seed: u32,
+    ) -> Self {
+        let mut modifiers: Vec<RunModifier> = modifiers.to_vec();
+        modifiers.sort_by_key(|m| index_of(&RunModifier::ALL, m));
+        modifiers.dedup();
+        Self {
+            mode,
+            player_faction: session.player_faction,
+            enemy_faction: session.enemy_faction,
+            stage,
+            difficulty,
+            ship_index: session.selected_ship_index,
+            modifiers,
+            seed,
+        }
+    }
+
+    fn payload(&self) -> u64 {
+        let modifiers = self
+            .modifiers
+            .iter()
+            .fold(0u64, |bits, m| bits | 1 << index_of(&RunModifier::ALL, m));
+        let mut bits = self.seed as u64;
+        bits |= modifiers << 32;
+        bits |= (self.ship_index as u64 & 0xf) << 37;
+        bits |= (index_of(Difficulty::all(), &self.difficulty) as u64) << 41;
+        bits |= (self.stage.saturating_sub(1) as u64 & 0xf) << 43;
+        bits |= (index_of(Faction::all(), &self.enemy_faction) as u64) << 47;
+        bits |= (index_of(Faction::all(), &self.player_faction) as u64) << 49;
+        bits |= (index_of(&MODES, &self.mode) as u64) << 51;
+        bits | VERSION << 53
+    }
+
+    /// "7K2M-Q0XA-93HD"
+    pub fn encode(&self) -> String {
+        let payload = self.payload();
+        let bits = payload | (checksum(payload) as u64) << PAYLOAD_BITS;
+        let chars: Vec<char> = (0..RUN_CODE_LENGTH)
+            .rev()
+            .map(|i| ALPHABET[(bits >> (i * 5)) as usize & 31] as char)
+            .collect();
+        chars
+            .chunks(4)
+            .map(|group| group.iter().collect::<String>())
+            .collect::<Vec<_>>()
+            .join("-")
+    }
+
+    /// Read a code back; case, separators and look-alike letters don't matter
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut bits = 0u64;
+        let mut length = 0;
+        for c in text.chars().filter(|c| !c.is_whitespace() && *c != '-') {
+            let c = match c.to_ascii_uppercase() {
+                'O' => '0',
+                'I' | 'L' => '1',
+                c => c,
+            };
+            let Some(value) = ALPHABET.iter().position(|a| *a as char == c) else {
+                return Err(format!("'{}' isn't used in run codes", c));
+            };
+            bits = bits << 5 | value as u64;
+            length += 1;
+        }
+        if length != RUN_CODE_LENGTH {
+            return Err(format!("Run codes are {} characters", RUN_CODE_LENGTH));
+        }
+
+        let payload = bits & ((1 << PAYLOAD_BITS) - 1);
+        if bits >> PAYLOAD_BITS != checksum(payload) as u64 {
+            return Err("Run code doesn't check out - look for a typo".to_string());
+        }
+        if payload >> 53 != VERSION {
+            return Err("Run code is from a different version".to_string());
+        }
+
+        let field = |shift: u32, width: u32| (payload >> shift) as usize & ((1 << width) - 1);
+        let stage = field(43, 4) as u32 + 1;
+        if stage > STAGES {
+            return Err(format!("No stage {}", stage));
+        }
+        let modifiers = RunModifier::ALL
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| field(32 + *i as u32, 1) == 1)
+            .map(|(_, m)| *m)
+            .collect();
+
+        Ok(Self {
+            mode: MODES[field(51, 2)],
+            player_faction: Faction::all()[field(49, 2)],
+            enemy_faction: Faction::all()[field(47, 2)],
+            stage,
+            difficulty: Difficulty::all()[field(41, 2)],
+            ship_index: field(37, 4),
+            modifiers,
+            seed: field(0, 32) as u32,
+        })
+    }
+}
+
+impl std::fmt::Display for RunCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.encode())
+    }
+}
+
+fn index_of<T: PartialEq>(all: &[T], item: &T) -> usize {
+    all.iter().position(|x| x == item).unwrap_or(0)
+}
+
+/// Five check bits, weighted by position so swapped characters are caught
+fn checksum(payload: u64) -> u8 {
+    let sum: u64 = (0..PAYLOAD_BITS.div_ceil(5))
+        .map(|i| ((payload >> (i * 5)) & 31) * (i as u64 + 1))
+        .sum();
+    (sum % 31) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn code() -> RunCode {
+        let mut session = GameSession::new(Faction::Caldari, Faction::Gallente);
+        session.selected_ship_index = 3;
+        RunCode::for_run(
+            RunMode::Campaign,
+            &session,
+            Difficulty::BitterVet,
+            11,
+            &[RunModifier::GoldenAge, RunModifier::GlassCannon],
+            0xdead_beef,
+        )
+    }
+
+    #[test]
+    fn codes_round_trip() {
+        let code = code();
+        let text = code.encode();
+        assert_eq!(text.len(), RUN_CODE_LENGTH + 2);
+        assert_eq!(RunCode::parse(&text), Ok(code.clone()));
+        // However it was typed back in
+        let sloppy = text.replace('-', " ").replace('0', "o").to_lowercase();
+        assert_eq!(RunCode::parse(&sloppy), Ok(code));
+    }
+
+    #[test]
+    fn typos_are_refused() {
+        let text = code().encode().replace('-', "");
+        let chars: Vec<char> = text.chars().collect();
+
+        // Any single wrong character
+        let wrong = if chars[4] == 'Z' { 'Y' } else { 'Z' };
+        let typo: String = chars
+            .iter()
+            .enumerate()
+            .map(|(i, c)| if i == 4 { wrong } else { *c })
+            .collect();
+        assert!(RunCode::parse(&typo).is_err());
+
+        // Two neighbours swapped
+        let i = (1..chars.len() - 1)
+            .find(|i| chars[*i] != chars[i + 1])
+            .unwrap();
+        let mut swapped = chars.clone();
+        swapped.swap(i, i + 1);
+        assert!(RunCode::parse(&swapped.into_iter().collect::<String>()).is_err());
+
+        assert!(RunCode::parse(&text[..9]).is_err());
+        assert!(RunCode::parse("UUUU-UUUU-UUUU").is_err());
+    }
+}
@@ -0,0 +1,318 @@
+//! Run Integrity
+//!
+//! Light checks for leaderboard runs. While a run is flown its inputs are
+//! sampled on every fixed simulation tick and folded into a replay hash:
+//! every change of the pressed buttons and the analog axes and the tick it
+//! landed on, on top of the run's seed. That is the input the seeded
+//! simulation was fed, so a replay feeding the same inputs on the same
+//! ticks gets the same hash, whatever frame rate either side ran at. On
+//! finishing, the hash is sealed with the mode, mission and result into a
+//! checksum shown on the results screen and kept with the leaderboard
+//! entry, for the server (or anyone holding the replay) to check a top
+//! entry against.
+//!
+//! Inputs that no hand can produce - more presses a second than anyone
+//! manages, or a streak of presses whose spacing never drifts - flag the
+//! run as turbo or macro input, and flagged runs aren't ranked. Presses
+//! are only timed to the tick, so a streak may spread by one tick; a hand
+//! drifts further than that long before the streak is up.
+
+#![allow(dead_code)]
+
+use serde::{Deserialize, Serialize};
+
+use super::run_mode::RunMode;
+use super::schedule::FIXED_TIMESTEP_HZ;
+
+/// Simulation ticks in a second, the resolution inputs are timed to
+pub const TICKS_PER_SECOND: u64 = FIXED_TIMESTEP_HZ as u64;
+
+/// Presses of one input in a second past which it isn't a hand
+pub const MAX_PRESSES_PER_SECOND: usize = 20;
+
+/// Presses in a row at the same interval that read as a turbo button
+pub const TURBO_STREAK: usize = 12;
+
+/// Most a streak's intervals (ticks) may spread and still count as the same
+const TURBO_SPREAD_TICKS: u64 = 1;
+
+/// Intervals longer than this (ticks, 150 ms) are deliberate, not turbo
+const TURBO_MAX_INTERVAL_TICKS: u64 = 9;
+
+/// Buttons watched, one bit each in an input mask
+pub const INPUT_CHANNELS: usize = 16;
+
+/// Analog axes watched: both sticks and both triggers
+pub const INPUT_AXES: usize = 6;
+
+/// Steps an analog axis is cut into each way for the replay hash
+pub const AXIS_STEPS: f32 = 8.0;
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// 64-bit FNV-1a, folded a field at a time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fnv64(pub u64);
+
+impl Default for Fnv64 {
+    fn default() -> Self {
+        Self(FNV_OFFSET)
+    }
+}
+
+impl Fnv64 {
+    pub fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    pub fn write_u64(&mut self, value: u64) {
+        self.write(&value.to_le_bytes());
+    }
+}
+
+/// Why a run's input was flagged
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InputFlag {
+    /// More presses in a second than `MAX_PRESSES_PER_SECOND`
+    ImpossibleRate,
+    /// `TURBO_STREAK` presses at a machine-exact interval
+    Turbo,
+}
+
+impl InputFlag {
+    pub fn description(&self) -> &'static str {
+        match self {
+            InputFlag::ImpossibleRate => "impossible input rate",
+            InputFlag::Turbo => "turbo input",
+        }
+    }
+}
+
+/// Press timing for one input
+#[derive(Debug, Clone, Default)]
+struct ChannelPresses {
+    /// Press ticks in the last second
+    recent: Vec<u64>,
+    /// Shortest and longest interval (ticks) in the current streak
+    spread: Option<(u64, u64)>,
+    /// Intervals in the current streak
+    streak: usize,
+}
+
+/// The run in progress: its inputs and what they've set off
+#[derive(Debug, Clone, Default)]
+pub struct InputLog {
+    pub seed: u64,
+    replay: Fnv64,
+    mask: u16,
+    axes: [i8; INPUT_AXES],
+    /// Input changes folded in
+    pub changes: u32,
+    channels: [ChannelPresses; INPUT_CHANNELS],
+    pub flag: Option<InputFlag>,
+}
+
+impl InputLog {
+    pub fn new(seed: u64) -> Self {
+        let mut replay = Fnv64::default();
+        replay.write_u64(seed);
+        Self {
+            seed,
+            replay,
+            ..Default::default()
+        }
+    }
+
+    /// The pressed buttons and analog axes (see `axis_step`) sampled on
+    /// simulation tick `tick` of the run. Only changes go into the replay
+    /// hash. Only button presses count towards the flags: a stick resting
+    /// on its deadzone can flicker faster than any hand.
+    pub fn record(&mut self, tick: u64, mask: u16, axes: [i8; INPUT_AXES]) {
+        if mask == self.mask && axes == self.axes {
+            return;
+        }
+        self.replay.write_u64(tick);
+        self.replay.write(&mask.to_le_bytes());
+        self.replay.write(&axes.map(|axis| axis as u8));
+        self.changes += 1;
+        self.axes = axes;
+
+        let pressed = mask & !self.mask;
+        self.mask = mask;
+        for (bit, channel) in self.channels.iter_mut().enumerate() {
+            if pressed & (1 << bit) == 0 {
+                continue;
+            }
+            if let Some(flag) = channel.press(tick) {
+                self.flag.get_or_insert(flag);
+            }
+        }
+    }
+
+    pub fn replay_hash(&self) -> u64 {
+        self.replay.0
+    }
+
+    /// Seal the finished run
+    pub fn seal(&self, mode: RunMode, mission_id: &str, value: u64) -> RunIntegrity {
+        let replay_hash = self.replay_hash();
+        RunIntegrity {
+            seed: self.seed,
+            replay_hash,
+            checksum: run_checksum(replay_hash, self.seed, mode, mission_id, value),
+            flag: self.flag,
+        }
+    }
+}
+
+impl ChannelPresses {
+    fn press(&mut self, tick: u64) -> Option<InputFlag> {
+        self.recent
+            .retain(|&t| tick.saturating_sub(t) < TICKS_PER_SECOND);
+        self.recent.push(tick);
+        let rate =
+            (self.recent.len() > MAX_PRESSES_PER_SECOND).then_some(InputFlag::ImpossibleRate);
+
+        let interval = self
+            .recent
+            .iter()
+            .rev()
+            .nth(1)
+            .map(|&previous| tick.saturating_sub(previous));
+        (self.spread, self.streak) = match (interval, self.spread) {
+            (Some(now), _) if now > TURBO_MAX_INTERVAL_TICKS => (None, 0),
+            (Some(now), Some((low, high)))
+                if high.max(now) - low.min(now) <= TURBO_SPREAD_TICKS =>
+            {
+                (Some((low.min(now), high.max(now))), self.streak + 1)
+            }
+            // Drifted: a new streak starts from this interval
+            (Some(now), _) => (Some((now, now)), 1),
+            (None, _) => (None, 0),
+        };
+        let turbo = (self.streak + 1 >= TURBO_STREAK).then_some(InputFlag::Turbo);
+
+        rate.or(turbo)
+    }
+}
+
+/// An analog axis (-1.0 to 1.0) in `AXIS_STEPS` steps each way
+pub fn axis_step(axis: f32) -> i8 {
+    (axis.clamp(-1.0, 1.0) * AXIS_STEPS).round() as i8
+}
+
+/// Checksum over a run's replay hash and everything its result depends on
+pub fn run_checksum(
+    replay_hash: u64,
+    seed: u64,
+    mode: RunMode,
+    mission_id: &str,
+    value: u64,
+) -> u64 {
+    let mut hash = Fnv64::default();
+    hash.write_u64(replay_hash);
+    hash.write_u64(seed);
+    hash.write(mode.name().as_bytes());
+    hash.write(mission_id.as_bytes());
+    hash.write_u64(value);
+    hash.0
+}
+
+/// What's submitted with a leaderboard run for checking
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RunIntegrity {
+    pub seed: u64,
+    pub replay_hash: u64,
+    pub checksum: u64,
+    /// Set when the input couldn't have come from a hand
+    pub flag: Option<InputFlag>,
+}
+
+impl RunIntegrity {
+    /// Whether `mode`, `mission_id` and `value` are the run this sealed
+    pub fn verifies(&self, mode: RunMode, mission_id: &str, value: u64) -> bool {
+        self.checksum == run_checksum(self.replay_hash, self.seed, mode, mission_id, value)
+    }
+
+    /// The checksum as "1A2B-3C4D-5E6F-7A8B"
+    pub fn verify_code(&self) -> String {
+        let hex = format!("{:016X}", self.checksum);
+        hex.as_bytes()
+            .chunks(4)
+            .map(|group| std::str::from_utf8(group).unwrap_or_default())
+            .collect::<Vec<_>>()
+            .join("-")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Fire tapped `presses` times, held a tick each, spaced by `intervals`
+    /// (ticks) in turn
+    fn tap(log: &mut InputLog, presses: usize, intervals: &[u64]) {
+        let mut tick = 60;
+        for i in 0..presses {
+            log.record(tick, 1, [0; INPUT_AXES]);
+            log.record(tick + 1, 0, [0; INPUT_AXES]);
+            tick += intervals[i % intervals.len()];
+        }
+    }
+
+    #[test]
+    fn only_input_changes_and_their_ticks_are_hashed() {
+        let mut a = InputLog::new(42);
+        let mut b = InputLog::new(42);
+        for tick in [0, 1, 2, 3] {
+            a.record(tick, 0, [0; INPUT_AXES]);
+        }
+        a.record(6, 0b101, [0; INPUT_AXES]);
+        for tick in [6, 7, 8] {
+            b.record(tick, 0b101, [0; INPUT_AXES]);
+        }
+        a.record(15, 0, [0; INPUT_AXES]);
+        b.record(15, 0, [0; INPUT_AXES]);
+        a.record(18, 0, [axis_step(0.5), 0, 0, 0, 0, 0]);
+        b.record(18, 0, [axis_step(0.52), 0, 0, 0, 0, 0]);
+        assert_eq!(a.replay_hash(), b.replay_hash());
+        assert_ne!(a.replay_hash(), InputLog::new(43).replay_hash());
+
+        // The same press a tick later is a different run
+        let mut late = InputLog::new(42);
+        late.record(7, 0b101, [0; INPUT_AXES]);
+        late.record(15, 0, [0; INPUT_AXES]);
+        late.record(18, 0, [axis_step(0.5), 0, 0, 0, 0, 0]);
+        assert_ne!(a.replay_hash(), late.replay_hash());
+
+        let sealed = a.seal(RunMode::ScoreAttack, "m1_convoy_raid", 12_000);
+        assert!(sealed.verifies(RunMode::ScoreAttack, "m1_convoy_raid", 12_000));
+        assert!(!sealed.verifies(RunMode::ScoreAttack, "m1_convoy_raid", 12_001));
+        assert_eq!(sealed.verify_code().len(), 19);
+    }
+
+    #[test]
+    fn hand_tapping_passes_but_turbo_and_macros_are_flagged() {
+        // A quick, uneven human tap
+        let mut human = InputLog::new(1);
+        tap(&mut human, 40, &[6, 7, 5, 6, 8]);
+        assert_eq!(human.flag, None);
+
+        // Machine-exact rhythm, on the tick or falling between ticks
+        let mut turbo = InputLog::new(1);
+        tap(&mut turbo, TURBO_STREAK, &[4]);
+        assert_eq!(turbo.flag, Some(InputFlag::Turbo));
+        let mut between = InputLog::new(1);
+        tap(&mut between, TURBO_STREAK, &[4, 4, 5]);
+        assert_eq!(between.flag, Some(InputFlag::Turbo));
+
+        // Faster than anyone can press
+        let mut mashed = InputLog::new(1);
+        tap(&mut mashed, 30, &[2, 2, 4]);
+        assert_eq!(mashed.flag, Some(InputFlag::ImpossibleRate));
+    }
+}
@@ -0,0 +1,280 @@
+//! Run Modes
+//!
+//! Besides the campaign, every unlocked mission can be flown as a Time
+//! Attack (destroy a fixed number of ships as fast as possible) or a Score
+//! Attack (highest score in a fixed three-minute run). Both reuse the
+//! mission's enemy pool, skip the boss, and keep a per-mission leaderboard.
+//! The Daily Challenge plays like Score Attack on a date-picked mission with
+//! seeded spawns and drops (see `daily`).
+
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::medals::Medal;
+use super::objectives::{ObjectiveDef, ObjectiveKind, ObjectiveTier};
+use super::run_integrity::RunIntegrity;
+
+/// Kills needed to finish a Time Attack run
+pub const TIME_ATTACK_KILLS: u32 = 40;
+
+/// Length of a Score Attack run (seconds)
+pub const SCORE_ATTACK_DURATION: f32 = 180.0;
+
+/// Entries kept per mission leaderboard
+pub const LEADERBOARD_SIZE: usize = 5;
+
+/// How the selected mission is played
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum RunMode {
+    #[default]
+    Campaign,
+    TimeAttack,
+    ScoreAttack,
+    Daily,
+}
+
+impl RunMode {
+    pub fn name(&self) -> &'static str {
+        match self {
+            RunMode::Campaign => "CAMPAIGN",
+            RunMode::TimeAttack => "TIME ATTACK",
+            RunMode::ScoreAttack => "SCORE ATTACK",
+            RunMode::Daily => "DAILY CHALLENGE",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            RunMode::Campaign => "Clear the waves and bring down the boss",
+            RunMode::TimeAttack => "Destroy 40 ships as fast as you can",
+            RunMode::ScoreAttack => "Score as much as you can in 3 minutes",
+            RunMode::Daily => "Today's mission and modifiers - one attempt",
+        }
+    }
+
+    /// Cycle to the next mode (menu toggle)
+    pub fn next(&self) -> Self {
+        match self {
+            RunMode::Campaign => RunMode::TimeAttack,
+            RunMode::TimeAttack => RunMode::ScoreAttack,
+            RunMode::ScoreAttack => RunMode::Daily,
+            RunMode::Daily => RunMode::Campaign,
+        }
+    }
+
+    /// Attack modes loop waves endlessly and never spawn the boss
+    pub fn is_attack(&self) -> bool {
+        *self != RunMode::Campaign
+    }
+
+    /// Win condition replacing the mission's objectives (`None` for the campaign)
+    pub fn objectives(&self) -> Option<Vec<ObjectiveDef>> {
+        match self {
+            RunMode::Campaign => None,
+            RunMode::TimeAttack => Some(vec![ObjectiveDef::new(
+                ObjectiveTier::Primary,
+                "Ships destroyed",
+                ObjectiveKind::DestroyEnemies {
+                    count: TIME_ATTACK_KILLS,
+                    type_id: None,
+                },
+            )]),
+            RunMode::ScoreAttack | RunMode::Daily => Some(vec![ObjectiveDef::new(
+                ObjectiveTier::Primary,
+                "Survive",
+                ObjectiveKind::Survive(SCORE_ATTACK_DURATION),
+            )]),
+        }
+    }
+
+    /// Whether `a` ranks above `b` on this mode's leaderboard
+    fn ranks_above(&self, a: u64, b: u64) -> bool {
+        match self {
+            // Time Attack stores milliseconds: lower is better
+            RunMode::TimeAttack => a < b,
+            _ => a > b,
+        }
+    }
+
+    /// Leaderboard value as shown to the player
+    pub fn format_value(&self, value: u64) -> String {
+        match self {
+            RunMode::TimeAttack => {
+                let seconds = value / 1000;
+                format!(
+                    "{}:{:02}.{:02}",
+                    seconds / 60,
+                    seconds % 60,
+                    (value % 1000) / 10
+                )
+            }
+            _ => value.to_string(),
+        }
+    }
+}
+
+/// One leaderboard line
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct LeaderboardEntry {
+    /// Milliseconds for Time Attack, points for Score Attack
+    pub value: u64,
+    /// Ship flown
+    pub ship: String,
+    /// Seed, replay hash and checksum for checking the run
+    #[serde(default)]
+    pub integrity: Option<RunIntegrity>,
+}
+
+/// Best runs of one mission in one mode
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct MissionLeaderboard {
+    pub mode: RunMode,
+    pub mission_id: String,
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+impl MissionLeaderboard {
+    pub fn new(mode: RunMode, mission_id: impl Into<String>) -> Self {
+        Self {
+            mode,
+            mission_id: mission_id.into(),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Insert a run, keeping the best `LEADERBOARD_SIZE`. Returns its rank
+    /// (0 = best) if it made the board.
+    pub fn insert(&mut self, entry: LeaderboardEntry) -> Option<usize> {
+        let rank = self
+            .entries
+            .iter()
+            .position(|e| self.mode.ranks_above(entry.value, e.value))
+            .unwrap_or(self.entries.len());
+        if rank >= LEADERBOARD_SIZE {
+            return None;
+        }
+        self.entries.insert(rank, entry);
+        self.entries.truncate(LEADERBOARD_SIZE);
+        Some(rank)
+    }
+}
+
+/// Randomness that must replay identically from the run's seed. Each
+/// stream draws independently, so kill timing can't change which enemies
+/// show up and a lucky crit can't change the next drop. Purely visual
+/// randomness (debris, sparks, chatter lines) stays on the global `fastrand`
+/// generator and never shifts a gameplay stream.
+#[derive(Resource, Debug, Clone)]
+pub struct RunRng {
+    /// Seed the streams were derived from (logged so a run can be replayed)
+    pub seed: u64,
+    /// Enemy picks, spawn positions and capital ship events
+    pub spawns: fastrand::Rng,
+    /// Loot rolls
+    pub drops: fastrand::Rng,
+    /// Critical hits and status effect procs
+    pub combat: fastrand::Rng,
+}
+
+impl Default for RunRng {
+    /// A fresh 32-bit seed, small enough to fit a run code
+    fn default() -> Self {
+        Self::seeded(fastrand::u32(..) as u64)
+    }
+}
+
+impl RunRng {
+    pub fn seeded(seed: u64) -> Self {
+        Self {
+            seed,
+            spawns: fastrand::Rng::with_seed(seed),
+            drops: fastrand::Rng::with_seed(seed.rotate_left(32) ^ 0xd1b5_4a32_d192_ed03),
+            combat: fastrand::Rng::with_seed(seed.rotate_left(16) ^ 0x9e37_79b9_7f4a_7c15),
+        }
+    }
+}
+
+/// Outcome of the attack run that just finished (for the results screen)
+#[derive(Resource, Debug, Clone, Default)]
+pub struct AttackResult {
+    pub mode: RunMode,
+    pub mission_id: &'static str,
+    pub value: u64,
+    /// Leaderboard position, if it placed
+    pub rank: Option<usize>,
+    /// Medal the run earned
+    pub medal: Option<Medal>,
+    /// Whether that medal beat the one held for the mission
+    pub new_medal: bool,
+    /// The sealed run, for the results screen's verify code
+    pub integrity: Option<RunIntegrity>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(value: u64) -> LeaderboardEntry {
+        LeaderboardEntry {
+            value,
+            ship: "Rifter".into(),
+            integrity: None,
+        }
+    }
+
+    #[test]
+    fn time_attack_ranks_faster_runs_first() {
+        let mut board = MissionLeaderboard::new(RunMode::TimeAttack, "m1_convoy_raid");
+        assert_eq!(board.insert(entry(90_000)), Some(0));
+        assert_eq!(board.insert(entry(60_000)), Some(0));
+        assert_eq!(board.insert(entry(75_000)), Some(1));
+
+        let times: Vec<u64> = board.entries.iter().map(|e| e.value).collect();
+        assert_eq!(times, vec![60_000, 75_000, 90_000]);
+        assert_eq!(RunMode::TimeAttack.format_value(83_456), "1:23.45");
+    }
+
+    #[test]
+    fn score_attack_board_keeps_only_the_best() {
+        let mut board = MissionLeaderboard::new(RunMode::ScoreAttack, "m1_convoy_raid");
+        for score in [500, 100, 400, 300, 200] {
+            assert!(board.insert(entry(score)).is_some());
+        }
+        // Worse than everything on a full board
+        assert_eq!(board.insert(entry(50)), None);
+        assert_eq!(board.insert(entry(450)), Some(1));
+        assert_eq!(board.entries.len(), LEADERBOARD_SIZE);
+        assert_eq!(board.entries.last().map(|e| e.value), Some(200));
+    }
+
+    #[test]
+    fn only_attack_modes_replace_objectives() {
+        assert!(RunMode::Campaign.objectives().is_none());
+        assert!(!RunMode::Campaign.is_attack());
+        assert_eq!(RunMode::Daily.next(), RunMode::Campaign);
+
+        let time_attack = RunMode::TimeAttack.objectives().unwrap_or_default();
+        assert_eq!(time_attack[0].kind.target(), TIME_ATTACK_KILLS);
+    }
+
+    #[test]
+    fn run_streams_replay_from_the_seed_independently() {
+        let mut a = RunRng::seeded(42);
+        let mut b = RunRng::seeded(42);
+
+        // Extra loot and combat rolls in one run leave its spawns untouched
+        for _ in 0..10 {
+            a.drops.f32();
+            a.combat.f32();
+        }
+        let spawns_a: Vec<u32> = (0..8).map(|_| a.spawns.u32(..)).collect();
+        let spawns_b: Vec<u32> = (0..8).map(|_| b.spawns.u32(..)).collect();
+        assert_eq!(spawns_a, spawns_b);
+
+        let mut c = RunRng::seeded(42);
+        assert_ne!(c.spawns.u64(..), c.drops.u64(..));
+        assert_ne!(c.drops.u64(..), c.combat.u64(..));
+    }
+}
@@ -1,17 +1,31 @@
 //! Save/Load System
 //!
-//! Persists player progression, unlocks, and settings.
+//! Persists player progression, unlocks, and settings. Native builds go
+//! through `LocalFileBackend`; cloud copies are handled by `cloud_sync`.
 
 #![allow(dead_code)]
 
-use crate::systems::{RumbleSettings, ScreenShake, SoundSettings};
+use super::campaign::CampaignCheckpoint;
+use super::cloud_sync::SaveStamp;
+#[cfg(not(target_arch = "wasm32"))]
+use super::cloud_sync::{LocalFileBackend, SaveBackend};
+use super::codex::CodexLog;
+use super::daily::{DailyResult, DAILY_HISTORY};
+use super::display::DisplaySettings;
+use super::fleet_roster::FleetRoster;
+use super::new_game_plus::NgPlusRecord;
+use super::playfield::AspectMode;
+use super::prestige::PrestigeProfile;
+use super::run_mode::{LeaderboardEntry, MissionLeaderboard, RunMode};
+use crate::systems::{
+    BulletPalette, DialogueSettings, DialogueSpeed, EnemyHealthBarSettings, FocusSettings,
+    GhostSettings, GraphicsQuality, MotionSettings, RumbleSettings, ScreenShake, SoundSettings,
+    SubtitleBackground, SubtitleSize, TargetingAidSettings,
+};
+use crate::ui::{HudLayout, HudStyle};
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
-#[cfg(not(target_arch = "wasm32"))]
-use std::fs;
-#[cfg(not(target_arch = "wasm32"))]
-use std::path::PathBuf;
 
 /// Save system plugin
 pub struct SavePlugin;
@@ -39,6 +53,31 @@ pub struct SaveData {
     pub high_scores: Vec<HighScore>,
     /// Settings
     pub settings: GameSettings,
+    /// Time/Score Attack leaderboards per mission
+    #[serde(default)]
+    pub leaderboards: Vec<MissionLeaderboard>,
+    /// Daily Challenge attempts, oldest first
+    #[serde(default)]
+    pub daily_results: Vec<DailyResult>,
+    /// Prestige track and per-module stats
+    #[serde(default)]
+    pub prestige: PrestigeProfile,
+    /// Codex entries unlocked so far
+    #[serde(default)]
+    pub codex: CodexLog,
+    /// New Game+ depths unlocked and cleared
+    #[serde(default)]
+    pub ng_plus: NgPlusRecord,
+    /// Campaign run to continue from the main menu, as of its last
+    /// completed mission
+    #[serde(default)]
+    pub checkpoint: Option<CampaignCheckpoint>,
+    /// Ships of the campaign in progress, their fatigue and Ironman losses
+    #[serde(default)]
+    pub fleet_roster: FleetRoster,
+    /// When this save was written, for cloud conflict checks
+    #[serde(default)]
+    pub stamp: SaveStamp,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, Default)]
@@ -62,12 +101,61 @@ pub struct GameSettings {
     pub master_volume: f32,
     pub sfx_volume: f32,
     pub music_volume: f32,
+    /// Voice-over volume
+    #[serde(default = "default_voice_volume")]
+    pub voice_volume: f32,
     /// Screen shake intensity (0.0 = off, 1.0 = full)
     #[serde(default = "default_shake_intensity")]
     pub screen_shake_intensity: f32,
     /// Controller rumble intensity (0.0 = off, 1.0 = full)
     #[serde(default = "default_rumble_intensity")]
     pub rumble_intensity: f32,
+    /// Steady overlays and fewer particles for motion-sensitive players
+    #[serde(default)]
+    pub reduced_motion: bool,
+    /// Capacitor wheel or classic bars
+    #[serde(default)]
+    pub hud_style: HudStyle,
+    /// Act nebulae or plain starfield
+    #[serde(default)]
+    pub graphics_quality: GraphicsQuality,
+    /// Boss lead reticle and weapon range rings
+    #[serde(default)]
+    pub targeting_aid: bool,
+    /// Health bars over enemies that have been hit
+    #[serde(default = "default_enemy_health_bars")]
+    pub enemy_health_bars: bool,
+    /// Faction-colored enemy fire or a standard (colorblind-safe) palette
+    #[serde(default)]
+    pub bullet_palette: BulletPalette,
+    /// Race the best run's ghost in Time Attack
+    #[serde(default = "default_race_ghost")]
+    pub race_ghost: bool,
+    /// How quickly dialogue lines auto-advance
+    #[serde(default)]
+    pub dialogue_speed: DialogueSpeed,
+    /// Dialogue subtitle text size
+    #[serde(default)]
+    pub subtitle_size: SubtitleSize,
+    /// Panel behind dialogue subtitles
+    #[serde(default)]
+    pub subtitle_background: SubtitleBackground,
+    /// Standard or compact HUD, or picked from the window size
+    #[serde(default)]
+    pub hud_layout: HudLayout,
+    /// Stretch the playfield to the window or letterbox it
+    #[serde(default)]
+    pub aspect_mode: AspectMode,
+    /// Pause, audio and frame rate while the window is in the background
+    #[serde(default)]
+    pub focus: FocusSettings,
+    /// Window mode, monitor and windowed placement
+    #[serde(default)]
+    pub display: DisplaySettings,
+}
+
+fn default_voice_volume() -> f32 {
+    0.9
 }
 
 fn default_shake_intensity() -> f32 {
@@ -78,43 +166,56 @@ fn default_rumble_intensity() -> f32 {
     1.0
 }
 
+fn default_race_ghost() -> bool {
+    true
+}
+
+fn default_enemy_health_bars() -> bool {
+    true
+}
+
 impl Default for GameSettings {
     fn default() -> Self {
         Self {
             master_volume: 0.7,
             sfx_volume: 0.8,
             music_volume: 0.5,
+            voice_volume: 0.9,
             screen_shake_intensity: 1.0,
             rumble_intensity: 1.0,
+            reduced_motion: false,
+            hud_style: HudStyle::default(),
+            graphics_quality: GraphicsQuality::default(),
+            targeting_aid: false,
+            enemy_health_bars: true,
+            bullet_palette: BulletPalette::default(),
+            race_ghost: true,
+            dialogue_speed: DialogueSpeed::default(),
+            subtitle_size: SubtitleSize::default(),
+            subtitle_background: SubtitleBackground::default(),
+            hud_layout: HudLayout::default(),
+            aspect_mode: AspectMode::default(),
+            focus: FocusSettings::default(),
+            display: DisplaySettings::default(),
         }
     }
 }
 
 impl SaveData {
-    /// Get save file path (native only)
-    #[cfg(not(target_arch = "wasm32"))]
-    fn save_path() -> PathBuf {
-        dirs::data_dir()
-            .unwrap_or_else(|| PathBuf::from("."))
-            .join("eve_rebellion")
-            .join("save.json")
-    }
-
     /// Load from disk (native)
     #[cfg(not(target_arch = "wasm32"))]
     pub fn load() -> Self {
-        let path = Self::save_path();
-        if path.exists() {
-            match fs::read_to_string(&path) {
-                Ok(data) => match serde_json::from_str(&data) {
-                    Ok(save) => {
-                        info!("Loaded save data from {:?}", path);
-                        return save;
-                    }
-                    Err(e) => warn!("Failed to parse save data: {}", e),
-                },
-                Err(e) => warn!("Failed to read save file: {}", e),
-            }
+        let backend = LocalFileBackend::save_file();
+        match backend.read() {
+            Ok(Some(data)) => match serde_json::from_str(&data) {
+                Ok(save) => {
+                    info!("Loaded save data from {:?}", backend.path);
+                    return save;
+                }
+                Err(e) => warn!("Failed to parse save data: {}", e),
+            },
+            Ok(None) => {}
+            Err(e) => warn!("Failed to read save file: {}", e),
         }
         info!("No save data found, using defaults");
         Self::default()
@@ -142,22 +243,13 @@ impl SaveData {
     /// Save to disk (native)
     #[cfg(not(target_arch = "wasm32"))]
     pub fn save(&self) {
-        let path = Self::save_path();
-
-        // Create directory if needed
-        if let Some(parent) = path.parent() {
-            if let Err(e) = fs::create_dir_all(parent) {
-                warn!("Failed to create save directory: {}", e);
-                return;
-            }
-        }
-
+        let backend = LocalFileBackend::save_file();
         match serde_json::to_string_pretty(self) {
             Ok(data) => {
-                if let Err(e) = fs::write(&path, data) {
+                if let Err(e) = backend.write(&data) {
                     warn!("Failed to write save file: {}", e);
                 } else {
-                    info!("Saved progress to {:?}", path);
+                    info!("Saved progress to {:?}", backend.path);
                 }
             }
             Err(e) => warn!("Failed to serialize save data: {}", e),
@@ -285,6 +377,66 @@ impl SaveData {
         }
     }
 
+    /// Best runs for a mission in an attack mode
+    pub fn leaderboard(&self, mode: RunMode, mission_id: &str) -> &[LeaderboardEntry] {
+        self.leaderboards
+            .iter()
+            .find(|b| b.mode == mode && b.mission_id == mission_id)
+            .map(|b| b.entries.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Record an attack run; returns its rank if it made the leaderboard
+    pub fn record_leaderboard(
+        &mut self,
+        mode: RunMode,
+        mission_id: &str,
+        entry: LeaderboardEntry,
+    ) -> Option<usize> {
+        let index = match self
+            .leaderboards
+            .iter()
+            .position(|b| b.mode == mode && b.mission_id == mission_id)
+        {
+            Some(index) => index,
+            None => {
+                self.leaderboards
+                    .push(MissionLeaderboard::new(mode, mission_id));
+                self.leaderboards.len() - 1
+            }
+        };
+        self.leaderboards[index].insert(entry)
+    }
+
+    /// The day's Daily Challenge attempt, if it was flown
+    pub fn daily_result(&self, day: u64) -> Option<&DailyResult> {
+        self.daily_results.iter().find(|r| r.day == day)
+    }
+
+    /// Spend the day's single attempt; false if it was already used
+    pub fn begin_daily(&mut self, day: u64, mission_id: &str) -> bool {
+        if self.daily_result(day).is_some() {
+            return false;
+        }
+        self.daily_results.push(DailyResult {
+            day,
+            mission_id: mission_id.to_string(),
+            score: 0,
+            completed: false,
+        });
+        let excess = self.daily_results.len().saturating_sub(DAILY_HISTORY);
+        self.daily_results.drain(..excess);
+        true
+    }
+
+    /// Record how the day's attempt ended
+    pub fn finish_daily(&mut self, day: u64, score: u64, completed: bool) {
+        if let Some(result) = self.daily_results.iter_mut().find(|r| r.day == day) {
+            result.score = score;
+            result.completed = completed;
+        }
+    }
+
     /// Add credits
     pub fn add_credits(&mut self, amount: u64) {
         self.lifetime_credits += amount;
@@ -298,16 +450,31 @@ fn load_save_data(mut commands: Commands) {
 }
 
 /// Auto-save when data changes
-fn auto_save(save: Res<SaveData>) {
+fn auto_save(mut save: ResMut<SaveData>) {
+    // Restamping isn't a change of its own
+    let stamp = SaveStamp::now(&save);
+    save.bypass_change_detection().stamp = stamp;
     save.save();
 }
 
 /// Apply saved settings to runtime resources (runs after all plugins init)
-fn apply_saved_settings(
+pub(crate) fn apply_saved_settings(
     save: Res<SaveData>,
     mut sound: ResMut<SoundSettings>,
     mut shake: ResMut<ScreenShake>,
     mut rumble: ResMut<RumbleSettings>,
+    mut motion: ResMut<MotionSettings>,
+    mut hud_style: ResMut<HudStyle>,
+    mut quality: ResMut<GraphicsQuality>,
+    mut targeting_aid: ResMut<TargetingAidSettings>,
+    mut health_bars: ResMut<EnemyHealthBarSettings>,
+    mut bullet_palette: ResMut<BulletPalette>,
+    mut ghost: ResMut<GhostSettings>,
+    mut dialogue: ResMut<DialogueSettings>,
+    mut hud_layout: ResMut<HudLayout>,
+    mut aspect_mode: ResMut<AspectMode>,
+    mut focus: ResMut<FocusSettings>,
+    mut display: ResMut<DisplaySettings>,
 ) {
     let settings = &save.settings;
 
@@ -315,6 +482,7 @@ fn apply_saved_settings(
     sound.master_volume = settings.master_volume;
     sound.sfx_volume = settings.sfx_volume;
     sound.music_volume = settings.music_volume;
+    sound.voice_volume = settings.voice_volume;
 
     // Apply screen shake intensity
     shake.multiplier = settings.screen_shake_intensity;
@@ -322,26 +490,69 @@ fn apply_saved_settings(
     // Apply rumble intensity
     rumble.intensity = settings.rumble_intensity;
 
+    motion.reduced_motion = settings.reduced_motion;
+    *hud_style = settings.hud_style;
+    *quality = settings.graphics_quality;
+    targeting_aid.enabled = settings.targeting_aid;
+    health_bars.enabled = settings.enemy_health_bars;
+    *bullet_palette = settings.bullet_palette;
+    ghost.enabled = settings.race_ghost;
+    dialogue.speed = settings.dialogue_speed;
+    dialogue.subtitle_size = settings.subtitle_size;
+    dialogue.subtitle_background = settings.subtitle_background;
+    *hud_layout = settings.hud_layout;
+    *aspect_mode = settings.aspect_mode;
+    *focus = settings.focus;
+    *display = settings.display;
+
     info!(
-        "Applied saved settings: master={:.0}%, sfx={:.0}%, music={:.0}%, shake={:.0}%, rumble={:.0}%",
+        "Applied saved settings: master={:.0}%, sfx={:.0}%, music={:.0}%, shake={:.0}%, rumble={:.0}%, reduced motion={}",
         settings.master_volume * 100.0,
         settings.sfx_volume * 100.0,
         settings.music_volume * 100.0,
         settings.screen_shake_intensity * 100.0,
-        settings.rumble_intensity * 100.0
+        settings.rumble_intensity * 100.0,
+        settings.reduced_motion
     );
 }
 
 /// Sync runtime settings changes back to SaveData
-/// Only runs when SoundSettings, ScreenShake, or RumbleSettings resources change
+/// Only runs when SoundSettings, ScreenShake, RumbleSettings, MotionSettings, HudStyle, GraphicsQuality, TargetingAidSettings, EnemyHealthBarSettings, BulletPalette, GhostSettings, DialogueSettings, HudLayout, AspectMode, FocusSettings, or DisplaySettings resources change
 fn sync_settings_to_save(
     sound: Res<SoundSettings>,
     shake: Res<ScreenShake>,
     rumble: Res<RumbleSettings>,
+    motion: Res<MotionSettings>,
+    hud_style: Res<HudStyle>,
+    quality: Res<GraphicsQuality>,
+    targeting_aid: Res<TargetingAidSettings>,
+    health_bars: Res<EnemyHealthBarSettings>,
+    bullet_palette: Res<BulletPalette>,
+    ghost: Res<GhostSettings>,
+    dialogue: Res<DialogueSettings>,
+    hud_layout: Res<HudLayout>,
+    aspect_mode: Res<AspectMode>,
+    focus: Res<FocusSettings>,
+    display: Res<DisplaySettings>,
     mut save: ResMut<SaveData>,
 ) {
     // Only process if any resource changed this frame
-    if !sound.is_changed() && !shake.is_changed() && !rumble.is_changed() {
+    if !sound.is_changed()
+        && !shake.is_changed()
+        && !rumble.is_changed()
+        && !motion.is_changed()
+        && !hud_style.is_changed()
+        && !quality.is_changed()
+        && !targeting_aid.is_changed()
+        && !health_bars.is_changed()
+        && !bullet_palette.is_changed()
+        && !ghost.is_changed()
+        && !dialogue.is_changed()
+        && !hud_layout.is_changed()
+        && !aspect_mode.is_changed()
+        && !focus.is_changed()
+        && !display.is_changed()
+    {
         return;
     }
 
@@ -349,11 +560,41 @@ fn sync_settings_to_save(
     let settings = &save.settings;
     let sound_changed = (settings.master_volume - sound.master_volume).abs() > 0.001
         || (settings.sfx_volume - sound.sfx_volume).abs() > 0.001
-        || (settings.music_volume - sound.music_volume).abs() > 0.001;
+        || (settings.music_volume - sound.music_volume).abs() > 0.001
+        || (settings.voice_volume - sound.voice_volume).abs() > 0.001;
     let shake_changed = (settings.screen_shake_intensity - shake.multiplier).abs() > 0.001;
     let rumble_changed = (settings.rumble_intensity - rumble.intensity).abs() > 0.001;
-
-    if !sound_changed && !shake_changed && !rumble_changed {
+    let motion_changed = settings.reduced_motion != motion.reduced_motion;
+    let hud_changed = settings.hud_style != *hud_style;
+    let quality_changed = settings.graphics_quality != *quality;
+    let aid_changed = settings.targeting_aid != targeting_aid.enabled;
+    let health_bars_changed = settings.enemy_health_bars != health_bars.enabled;
+    let palette_changed = settings.bullet_palette != *bullet_palette;
+    let ghost_changed = settings.race_ghost != ghost.enabled;
+    let dialogue_changed = settings.dialogue_speed != dialogue.speed
+        || settings.subtitle_size != dialogue.subtitle_size
+        || settings.subtitle_background != dialogue.subtitle_background;
+    let layout_changed = settings.hud_layout != *hud_layout;
+    let aspect_changed = settings.aspect_mode != *aspect_mode;
+    let focus_changed = settings.focus != *focus;
+    let display_changed = settings.display != *display;
+
+    if !sound_changed
+        && !shake_changed
+        && !rumble_changed
+        && !motion_changed
+        && !hud_changed
+        && !quality_changed
+        && !aid_changed
+        && !health_bars_changed
+        && !palette_changed
+        && !ghost_changed
+        && !dialogue_changed
+        && !layout_changed
+        && !aspect_changed
+        && !focus_changed
+        && !display_changed
+    {
         return;
     }
 
@@ -363,6 +604,7 @@ fn sync_settings_to_save(
         settings.master_volume = sound.master_volume;
         settings.sfx_volume = sound.sfx_volume;
         settings.music_volume = sound.music_volume;
+        settings.voice_volume = sound.voice_volume;
     }
     if shake_changed {
         settings.screen_shake_intensity = shake.multiplier;
@@ -370,20 +612,36 @@ fn sync_settings_to_save(
     if rumble_changed {
         settings.rumble_intensity = rumble.intensity;
     }
+    settings.reduced_motion = motion.reduced_motion;
+    settings.hud_style = *hud_style;
+    settings.graphics_quality = *quality;
+    settings.targeting_aid = targeting_aid.enabled;
+    settings.enemy_health_bars = health_bars.enabled;
+    settings.bullet_palette = *bullet_palette;
+    settings.race_ghost = ghost.enabled;
+    settings.dialogue_speed = dialogue.speed;
+    settings.subtitle_size = dialogue.subtitle_size;
+    settings.subtitle_background = dialogue.subtitle_background;
+    settings.hud_layout = *hud_layout;
+    settings.aspect_mode = *aspect_mode;
+    settings.focus = *focus;
+    settings.display = *display;
 
     info!(
-        "Settings synced to save: master={:.0}%, sfx={:.0}%, music={:.0}%, shake={:.0}%, rumble={:.0}%",
+        "Settings synced to save: master={:.0}%, sfx={:.0}%, music={:.0}%, shake={:.0}%, rumble={:.0}%, reduced motion={}",
         settings.master_volume * 100.0,
         settings.sfx_volume * 100.0,
         settings.music_volume * 100.0,
         settings.screen_shake_intensity * 100.0,
-        settings.rumble_intensity * 100.0
+        settings.rumble_intensity * 100.0,
+        settings.reduced_motion
     );
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::display::{DisplayMode, WindowPlacement};
 
     // ==================== Ship Unlock Tests ====================
 
@@ -525,6 +783,7 @@ mod tests {
         assert_eq!(settings.master_volume, 0.7);
         assert_eq!(settings.sfx_volume, 0.8);
         assert_eq!(settings.music_volume, 0.5);
+        assert_eq!(settings.voice_volume, 0.9);
         assert_eq!(settings.screen_shake_intensity, 1.0);
         assert_eq!(settings.rumble_intensity, 1.0);
     }
@@ -550,4 +809,68 @@ mod tests {
         assert!(loaded.unlocked_ships.contains(&587));
         assert_eq!(loaded.lifetime_credits, 10000);
     }
+
+    #[test]
+    fn display_choice_and_window_placement_are_saved() {
+        let mut save = SaveData::default();
+        save.settings.display = DisplaySettings {
+            mode: DisplayMode::Borderless,
+            monitor: 1,
+            placement: Some(WindowPlacement {
+                position: IVec2::new(-1600, 120),
+                size: UVec2::new(1024, 896),
+            }),
+            low_latency: true,
+        };
+
+        let json = serde_json::to_string(&save).expect("serialize");
+        let loaded: SaveData = serde_json::from_str(&json).expect("deserialize");
+        assert_eq!(loaded.settings.display, save.settings.display);
+    }
+
+    #[test]
+    fn leaderboards_are_per_mode_and_mission_and_survive_old_saves() {
+        let mut save = SaveData::default();
+        let run = |value| LeaderboardEntry {
+            value,
+            ship: "Rifter".into(),
+            integrity: None,
+        };
+        assert_eq!(
+            save.record_leaderboard(RunMode::TimeAttack, "m1_convoy_raid", run(70_000)),
+            Some(0)
+        );
+        save.record_leaderboard(RunMode::ScoreAttack, "m1_convoy_raid", run(9_000));
+        save.record_leaderboard(RunMode::TimeAttack, "m2_patrol_ambush", run(50_000));
+
+        assert_eq!(
+            save.leaderboard(RunMode::TimeAttack, "m1_convoy_raid"),
+            &[run(70_000)]
+        );
+        assert!(save
+            .leaderboard(RunMode::ScoreAttack, "m3_station_raid")
+            .is_empty());
+
+        // Saves from before leaderboards existed still load
+        let mut json = serde_json::to_value(SaveData::default()).expect("serialize");
+        json.as_object_mut().map(|o| o.remove("leaderboards"));
+        let loaded: SaveData = serde_json::from_value(json).expect("deserialize");
+        assert!(loaded.leaderboards.is_empty());
+    }
+
+    #[test]
+    fn daily_challenge_allows_one_attempt_per_day() {
+        let mut save = SaveData::default();
+        assert!(save.begin_daily(100, "m3_station_raid"));
+        assert!(!save.begin_daily(100, "m3_station_raid"));
+        save.finish_daily(100, 12_500, true);
+        assert_eq!(save.daily_result(100).map(|r| r.score), Some(12_500));
+
+        // A new day unlocks a new attempt; history stays bounded
+        for day in 101..101 + DAILY_HISTORY as u64 {
+            assert!(save.begin_daily(day, "m1_convoy_raid"));
+        }
+        assert_eq!(save.daily_results.len(), DAILY_HISTORY);
+        assert!(save.daily_result(100).is_none());
+    }
 }
@@ -0,0 +1,284 @@
+//! Frame Schedule
+//!
+//! Gameplay runs in explicit system sets: input → simulation → collision →
+//! scoring → UI. Movement, projectile physics and collision step on
+//! `FixedUpdate` so fast bullets behave the same at any frame rate; the
+//! rendered transforms of moving entities are interpolated between the last
+//! two fixed ticks so motion stays smooth on high refresh displays.
+//!
+//! Low-latency mode trades that smoothing for response on the player ship:
+//! it moves on `Update` the frame its input is polled (keyboard and pads
+//! both land in `PreUpdate`) and renders where it is rather than a tick
+//! behind. Input-to-display latency is measured either way and shown in the
+//! F3 debug overlay.
+
+#![allow(dead_code)]
+
+use bevy::app::{RunFixedMainLoop, RunFixedMainLoopSystem};
+use bevy::input::InputSystem;
+use bevy::prelude::*;
+use bevy::transform::TransformSystem;
+use bevy::utils::Instant;
+use bevy::window::PrimaryWindow;
+use std::collections::VecDeque;
+
+use super::DisplaySettings;
+use crate::entities::{Enemy, Player, ProjectilePhysics};
+use crate::systems::JoystickState;
+
+/// Physics tick rate (Hz)
+pub const FIXED_TIMESTEP_HZ: f64 = 60.0;
+
+/// Latency measurements averaged for the overlay
+const LATENCY_SAMPLES: usize = 30;
+
+/// Frames Bevy lets the GPU queue when the window doesn't say
+const DEFAULT_FRAME_LATENCY: u32 = 2;
+
+/// Ordered gameplay stages, configured identically on `Update` and `FixedUpdate`
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GameSet {
+    /// Read keyboard/joystick and turn it into intents (fire, abilities, pause)
+    Input,
+    /// Movement, AI, spawning and projectile physics
+    Simulation,
+    /// Hit detection and damage
+    Collision,
+    /// Score, combo and berserk bookkeeping
+    Scoring,
+    /// HUD and overlays
+    Ui,
+}
+
+/// Schedule plugin
+pub struct SchedulePlugin;
+
+impl Plugin for SchedulePlugin {
+    fn build(&self, app: &mut App) {
+        let sets = (
+            GameSet::Input,
+            GameSet::Simulation,
+            GameSet::Collision,
+            GameSet::Scoring,
+            GameSet::Ui,
+        );
+
+        app.insert_resource(Time::<Fixed>::from_hz(FIXED_TIMESTEP_HZ))
+            .init_resource::<InputLatency>()
+            .configure_sets(Update, sets.chain())
+            .configure_sets(FixedUpdate, sets.chain())
+            .add_systems(
+                RunFixedMainLoop,
+                restore_simulated_transforms.in_set(RunFixedMainLoopSystem::BeforeFixedMainLoop),
+            )
+            .add_systems(FixedFirst, snapshot_previous_transforms)
+            .add_systems(
+                PostUpdate,
+                (track_interpolated_entities, interpolate_transforms)
+                    .chain()
+                    .before(TransformSystem::TransformPropagate),
+            )
+            .add_systems(PreUpdate, stamp_input.after(InputSystem))
+            .add_systems(Last, finish_input_sample);
+    }
+}
+
+// =============================================================================
+// TRANSFORM INTERPOLATION
+// =============================================================================
+
+/// Simulated translation of an entity moved on `FixedUpdate`.
+///
+/// Between frames `Transform` holds the interpolated (rendered) position; it
+/// is swapped back to `current` before the fixed loop runs, so every gameplay
+/// system only ever sees the simulated position.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct InterpolatedTransform {
+    /// Position at the start of the last fixed tick
+    pub previous: Vec3,
+    /// Simulated position
+    pub current: Vec3,
+}
+
+impl InterpolatedTransform {
+    pub fn new(translation: Vec3) -> Self {
+        Self {
+            previous: translation,
+            current: translation,
+        }
+    }
+
+    /// Rendered position `fraction` (0.0 - 1.0) of the way into the next tick
+    pub fn lerp(&self, fraction: f32) -> Vec3 {
+        self.previous.lerp(self.current, fraction.clamp(0.0, 1.0))
+    }
+
+    /// Skip interpolation for a teleport (respawn, formation snap)
+    pub fn snap(&mut self, translation: Vec3) {
+        self.previous = translation;
+        self.current = translation;
+    }
+}
+
+/// Put the simulated position back before any fixed tick runs
+fn restore_simulated_transforms(mut query: Query<(&mut Transform, &InterpolatedTransform)>) {
+    for (mut transform, interp) in query.iter_mut() {
+        transform.translation = interp.current;
+    }
+}
+
+/// Remember where each entity started this tick
+fn snapshot_previous_transforms(mut query: Query<(&Transform, &mut InterpolatedTransform)>) {
+    for (transform, mut interp) in query.iter_mut() {
+        interp.previous = transform.translation;
+    }
+}
+
+/// Everything moved by fixed-step systems gets interpolated
+fn track_interpolated_entities(
+    mut commands: Commands,
+    query: Query<
+        (Entity, &Transform),
+        (
+            Or<(Added<Player>, Added<Enemy>, Added<ProjectilePhysics>)>,
+            Without<InterpolatedTransform>,
+        ),
+    >,
+) {
+    for (entity, transform) in query.iter() {
+        commands
+            .entity(entity)
+            .insert(InterpolatedTransform::new(transform.translation));
+    }
+}
+
+/// Record the simulated position and render partway into the next tick.
+/// In low-latency mode the player ship renders where it is.
+fn interpolate_transforms(
+    fixed_time: Res<Time<Fixed>>,
+    display: Res<DisplaySettings>,
+    mut query: Query<(&mut Transform, &mut InterpolatedTransform, Has<Player>)>,
+) {
+    let fraction = fixed_time.overstep_fraction();
+    for (mut transform, mut interp, is_player) in query.iter_mut() {
+        if is_player && display.low_latency {
+            interp.snap(transform.translation);
+            continue;
+        }
+        // Picks up Update-schedule nudges (maneuvers, knockback) as well
+        interp.current = transform.translation;
+        transform.translation = interp.lerp(fraction);
+    }
+}
+
+// =============================================================================
+// INPUT LATENCY
+// =============================================================================
+
+/// Input-to-display latency of recent presses
+#[derive(Resource, Debug, Default)]
+pub struct InputLatency {
+    /// When the press being measured was polled
+    pending: Option<Instant>,
+    /// Recent measurements (ms), newest last
+    samples: VecDeque<f32>,
+}
+
+impl InputLatency {
+    pub fn record(&mut self, ms: f32) {
+        if self.samples.len() == LATENCY_SAMPLES {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(ms);
+    }
+
+    pub fn last_ms(&self) -> Option<f32> {
+        self.samples.back().copied()
+    }
+
+    pub fn average_ms(&self) -> Option<f32> {
+        if self.samples.is_empty() {
+            return None;
+        }
+        Some(self.samples.iter().sum::<f32>() / self.samples.len() as f32)
+    }
+}
+
+/// Time from polling a press to the frame that answers it reaching the
+/// screen: the frame's own work, then one frame time per queued frame
+pub fn display_latency_ms(frame_work_ms: f32, frame_time_ms: f32, queued_frames: u32) -> f32 {
+    frame_work_ms + frame_time_ms * queued_frames as f32
+}
+
+/// Note when a fresh press is polled
+fn stamp_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    joystick: Res<JoystickState>,
+    mut latency: ResMut<InputLatency>,
+    mut pad_held: Local<bool>,
+) {
+    let pad_pressed = joystick.in_use() && !*pad_held;
+    *pad_held = joystick.in_use();
+    if latency.pending.is_none() && (keyboard.get_just_pressed().next().is_some() || pad_pressed) {
+        latency.pending = Some(Instant::now());
+    }
+}
+
+/// Close the measurement once the frame that saw the press is submitted
+fn finish_input_sample(
+    time: Res<Time<Real>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut latency: ResMut<InputLatency>,
+) {
+    let Some(polled) = latency.pending.take() else {
+        return;
+    };
+    let queued = windows
+        .get_single()
+        .ok()
+        .and_then(|w| w.desired_maximum_frame_latency)
+        .map_or(DEFAULT_FRAME_LATENCY, |n| n.get());
+    let ms = display_latency_ms(
+        polled.elapsed().as_secs_f32() * 1000.0,
+        time.delta_secs() * 1000.0,
+        queued,
+    );
+    latency.record(ms);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interpolation_blends_between_ticks() {
+        let mut interp = InterpolatedTransform::new(Vec3::ZERO);
+        interp.current = Vec3::new(10.0, -20.0, 5.0);
+
+        assert_eq!(interp.lerp(0.0), Vec3::ZERO);
+        assert_eq!(interp.lerp(0.5), Vec3::new(5.0, -10.0, 2.5));
+        assert_eq!(interp.lerp(1.0), interp.current);
+        // Overstep is clamped so a long frame never extrapolates past the tick
+        assert_eq!(interp.lerp(3.0), interp.current);
+
+        interp.snap(Vec3::ONE);
+        assert_eq!(interp.lerp(0.25), Vec3::ONE);
+    }
+
+    #[test]
+    fn latency_counts_the_frame_queue_and_averages_recent_presses() {
+        // Same work, one frame less in the queue
+        assert_eq!(display_latency_ms(4.0, 16.0, 2), 36.0);
+        assert_eq!(display_latency_ms(4.0, 16.0, 1), 20.0);
+
+        let mut latency = InputLatency::default();
+        assert_eq!(latency.average_ms(), None);
+        for _ in 0..LATENCY_SAMPLES {
+            latency.record(40.0);
+        }
+        latency.record(10.0);
+        assert_eq!(latency.last_ms(), Some(10.0));
+        let expected = (40.0 * (LATENCY_SAMPLES - 1) as f32 + 10.0) / LATENCY_SAMPLES as f32;
+        assert!((latency.average_ms().unwrap() - expected).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,600 @@
+//! Settings File Hot-Reload
+//!
+//! Mirrors player settings to `settings.ron` next to the save file and
+//! polls it for external edits (a text editor on a second monitor, or
+//! validation tooling). Changes are re-applied at runtime: audio volumes,
+//! controller deadzone, rumble, screen shake, reduced motion, graphics
+//! quality, enemy bullet palette, dialogue speed, HUD layout, aspect mode, background behavior,
+//! window mode and monitor, vsync and low-latency mode.
+//! Native only - web builds keep settings in the save blob.
+
+#![allow(dead_code)]
+
+use super::{AspectMode, DisplayMode, DisplaySettings, InputConfig};
+use crate::systems::{
+    BackgroundAudio, BulletPalette, DialogueSettings, DialogueSpeed, FocusSettings,
+    GraphicsQuality, MotionSettings, RumbleSettings, ScreenShake, SoundSettings,
+    TargetingAidSettings,
+};
+use crate::ui::HudLayout;
+use bevy::prelude::*;
+use bevy::window::{PresentMode, PrimaryWindow};
+use serde::{Deserialize, Serialize};
+
+/// How often the settings file is checked for changes (seconds)
+const POLL_INTERVAL: f32 = 1.0;
+
+/// Settings file plugin
+pub struct SettingsFilePlugin;
+
+impl Plugin for SettingsFilePlugin {
+    fn build(&self, app: &mut App) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            app.init_resource::<SettingsFileWatcher>()
+                // After SavePlugin has applied save.json, so the file wins on conflicts
+                .add_systems(
+                    PostStartup,
+                    init_settings_file.after(super::save::apply_saved_settings),
+                )
+                .add_systems(Update, (poll_settings_file, write_settings_file).chain());
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            let _ = app;
+        }
+    }
+}
+
+/// Contents of `settings.ron`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
+#[serde(default)]
+pub struct SettingsFile {
+    pub audio: AudioSection,
+    pub input: InputSection,
+    pub graphics: GraphicsSection,
+    /// While the window is unfocused
+    pub background: FocusSettings,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct AudioSection {
+    pub master_volume: f32,
+    pub sfx_volume: f32,
+    pub music_volume: f32,
+}
+
+impl Default for AudioSection {
+    fn default() -> Self {
+        Self {
+            master_volume: 0.7,
+            sfx_volume: 0.8,
+            music_volume: 0.5,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct InputSection {
+    /// Left stick deadzone (0.0 - 0.9)
+    pub controller_deadzone: f32,
+    /// Controller rumble intensity (0.0 = off, 1.0 = full)
+    pub rumble_intensity: f32,
+}
+
+impl Default for InputSection {
+    fn default() -> Self {
+        Self {
+            controller_deadzone: 0.15,
+            rumble_intensity: 1.0,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct GraphicsSection {
+    /// Windowed, borderless or exclusive fullscreen
+    pub display_mode: DisplayMode,
+    /// Monitor to go fullscreen on (0 = first)
+    pub monitor: usize,
+    pub vsync: bool,
+    /// Same-frame movement and a one-frame GPU queue
+    pub low_latency: bool,
+    /// Screen shake intensity (0.0 = off, 1.0 = full)
+    pub screen_shake_intensity: f32,
+    /// Steady overlays and fewer particles
+    pub reduced_motion: bool,
+    /// Low drops the act nebulae and keeps the plain starfield
+    pub quality: GraphicsQuality,
+    /// Boss lead reticle and weapon range rings
+    pub targeting_aid: bool,
+    /// Faction-colored enemy fire or a standard (colorblind-safe) palette
+    pub bullet_palette: BulletPalette,
+    /// How quickly dialogue lines auto-advance
+    pub dialogue_speed: DialogueSpeed,
+    /// Standard or compact HUD, or picked from the window size
+    pub hud_layout: HudLayout,
+    /// Stretch the playfield to the window or letterbox it
+    pub aspect_mode: AspectMode,
+}
+
+impl Default for GraphicsSection {
+    fn default() -> Self {
+        Self {
+            display_mode: DisplayMode::default(),
+            monitor: 0,
+            vsync: true,
+            low_latency: false,
+            screen_shake_intensity: 1.0,
+            reduced_motion: false,
+            quality: GraphicsQuality::default(),
+            targeting_aid: false,
+            bullet_palette: BulletPalette::default(),
+            dialogue_speed: DialogueSpeed::default(),
+            hud_layout: HudLayout::default(),
+            aspect_mode: AspectMode::default(),
+        }
+    }
+}
+
+impl SettingsFile {
+    /// Parse RON text
+    pub fn from_ron(text: &str) -> Result<Self, String> {
+        ron::from_str(text).map_err(|e| e.to_string())
+    }
+
+    /// Serialize to human-editable RON
+    pub fn to_ron(&self) -> Result<String, String> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|e| e.to_string())
+    }
+
+    /// Clamp values into their valid ranges
+    pub fn sanitized(mut self) -> Self {
+        self.audio.master_volume = self.audio.master_volume.clamp(0.0, 1.0);
+        self.audio.sfx_volume = self.audio.sfx_volume.clamp(0.0, 1.0);
+        self.audio.music_volume = self.audio.music_volume.clamp(0.0, 1.0);
+        self.input.controller_deadzone = self.input.controller_deadzone.clamp(0.0, 0.9);
+        self.input.rumble_intensity = self.input.rumble_intensity.clamp(0.0, 1.0);
+        self.graphics.screen_shake_intensity = self.graphics.screen_shake_intensity.clamp(0.0, 1.0);
+        self
+    }
+}
+
+/// Tracks the on-disk settings file
+#[derive(Resource, Default)]
+pub struct SettingsFileWatcher {
+    /// Modification time of the last read or write
+    last_modified: Option<std::time::SystemTime>,
+    /// Settings as last applied or written (avoids write-back loops)
+    last_applied: Option<SettingsFile>,
+    /// Time until next poll
+    poll_timer: f32,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn settings_path() -> std::path::PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| std::path::PathBuf::from("."))
+        .join("eve_rebellion")
+        .join("settings.ron")
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn modified_time(path: &std::path::Path) -> Option<std::time::SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Snapshot the runtime settings resources
+fn collect_settings(
+    sound: &SoundSettings,
+    shake: &ScreenShake,
+    rumble: &RumbleSettings,
+    motion: &MotionSettings,
+    quality: GraphicsQuality,
+    targeting_aid: &TargetingAidSettings,
+    bullet_palette: BulletPalette,
+    dialogue: &DialogueSettings,
+    hud_layout: HudLayout,
+    aspect_mode: AspectMode,
+    focus: FocusSettings,
+    input: &InputConfig,
+    display: &DisplaySettings,
+    window: Option<&Window>,
+) -> SettingsFile {
+    let graphics_default = GraphicsSection::default();
+    SettingsFile {
+        audio: AudioSection {
+            master_volume: sound.master_volume,
+            sfx_volume: sound.sfx_volume,
+            music_volume: sound.music_volume,
+        },
+        input: InputSection {
+            controller_deadzone: input.controller_deadzone,
+            rumble_intensity: rumble.intensity,
+        },
+        graphics: GraphicsSection {
+            display_mode: display.mode,
+            monitor: display.monitor,
+            vsync: window
+                .map(|w| {
+                    matches!(
+                        w.present_mode,
+                        PresentMode::AutoVsync | PresentMode::Fifo | PresentMode::FifoRelaxed
+                    )
+                })
+                .unwrap_or(graphics_default.vsync),
+            low_latency: display.low_latency,
+            screen_shake_intensity: shake.multiplier,
+            reduced_motion: motion.reduced_motion,
+            quality,
+            targeting_aid: targeting_aid.enabled,
+            bullet_palette,
+            dialogue_speed: dialogue.speed,
+            hud_layout,
+            aspect_mode,
+        },
+        background: focus,
+    }
+}
+
+/// Push settings into the runtime resources
+fn apply_settings(
+    settings: &SettingsFile,
+    sound: &mut SoundSettings,
+    shake: &mut ScreenShake,
+    rumble: &mut RumbleSettings,
+    motion: &mut MotionSettings,
+    quality: &mut GraphicsQuality,
+    targeting_aid: &mut TargetingAidSettings,
+    bullet_palette: &mut BulletPalette,
+    dialogue: &mut DialogueSettings,
+    hud_layout: &mut HudLayout,
+    aspect_mode: &mut AspectMode,
+    focus: &mut FocusSettings,
+    input: &mut InputConfig,
+    display: &mut DisplaySettings,
+    window: Option<Mut<Window>>,
+) {
+    sound.master_volume = settings.audio.master_volume;
+    sound.sfx_volume = settings.audio.sfx_volume;
+    sound.music_volume = settings.audio.music_volume;
+    input.controller_deadzone = settings.input.controller_deadzone;
+    rumble.intensity = settings.input.rumble_intensity;
+    shake.multiplier = settings.graphics.screen_shake_intensity;
+    motion.reduced_motion = settings.graphics.reduced_motion;
+    if *quality != settings.graphics.quality {
+        *quality = settings.graphics.quality;
+    }
+    targeting_aid.enabled = settings.graphics.targeting_aid;
+    if *bullet_palette != settings.graphics.bullet_palette {
+        *bullet_palette = settings.graphics.bullet_palette;
+    }
+    dialogue.speed = settings.graphics.dialogue_speed;
+    *hud_layout = settings.graphics.hud_layout;
+    if *aspect_mode != settings.graphics.aspect_mode {
+        *aspect_mode = settings.graphics.aspect_mode;
+    }
+    if *focus != settings.background {
+        *focus = settings.background;
+    }
+
+    // The windowed placement stays whatever the window last reported
+    if display.mode != settings.graphics.display_mode
+        || display.monitor != settings.graphics.monitor
+    {
+        display.mode = settings.graphics.display_mode;
+        display.monitor = settings.graphics.monitor;
+    }
+    if display.low_latency != settings.graphics.low_latency {
+        display.low_latency = settings.graphics.low_latency;
+    }
+
+    if let Some(mut window) = window {
+        let present_mode = if settings.graphics.vsync {
+            PresentMode::AutoVsync
+        } else {
+            PresentMode::AutoNoVsync
+        };
+        if window.present_mode != present_mode {
+            window.present_mode = present_mode;
+        }
+    }
+}
+
+/// Load `settings.ron` at startup, or create it from the current settings
+#[cfg(not(target_arch = "wasm32"))]
+fn init_settings_file(
+    mut watcher: ResMut<SettingsFileWatcher>,
+    mut sound: ResMut<SoundSettings>,
+    mut shake: ResMut<ScreenShake>,
+    mut rumble: ResMut<RumbleSettings>,
+    mut motion: ResMut<MotionSettings>,
+    mut quality: ResMut<GraphicsQuality>,
+    mut targeting_aid: ResMut<TargetingAidSettings>,
+    mut bullet_palette: ResMut<BulletPalette>,
+    mut dialogue: ResMut<DialogueSettings>,
+    mut hud_layout: ResMut<HudLayout>,
+    mut aspect_mode: ResMut<AspectMode>,
+    mut focus: ResMut<FocusSettings>,
+    mut input: ResMut<InputConfig>,
+    mut display: ResMut<DisplaySettings>,
+    mut window_query: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    let path = settings_path();
+
+    if path.exists() {
+        match std::fs::read_to_string(&path).map_err(|e| e.to_string()) {
+            Ok(text) => match SettingsFile::from_ron(&text) {
+                Ok(settings) => {
+                    let settings = settings.sanitized();
+                    apply_settings(
+                        &settings,
+                        &mut sound,
+                        &mut shake,
+                        &mut rumble,
+                        &mut motion,
+                        &mut quality,
+                        &mut targeting_aid,
+                        &mut bullet_palette,
+                        &mut dialogue,
+                        &mut hud_layout,
+                        &mut aspect_mode,
+                        &mut focus,
+                        &mut input,
+                        &mut display,
+                        window_query.get_single_mut().ok(),
+                    );
+                    info!("Loaded settings from {:?}", path);
+                    watcher.last_applied = Some(settings);
+                }
+                Err(e) => warn!("Failed to parse {:?}: {}", path, e),
+            },
+            Err(e) => warn!("Failed to read {:?}: {}", path, e),
+        }
+        watcher.last_modified = modified_time(&path);
+        return;
+    }
+
+    // First run - write out the current settings so there's something to edit
+    let settings = collect_settings(
+        &sound,
+        &shake,
+        &rumble,
+        &motion,
+        *quality,
+        &targeting_aid,
+        *bullet_palette,
+        &dialogue,
+        *hud_layout,
+        *aspect_mode,
+        *focus,
+        &input,
+        &display,
+        window_query.get_single().ok(),
+    );
+    write_file(&mut watcher, &settings);
+}
+
+/// Check the settings file for external edits and re-apply them
+#[cfg(not(target_arch = "wasm32"))]
+fn poll_settings_file(
+    time: Res<Time>,
+    mut watcher: ResMut<SettingsFileWatcher>,
+    mut sound: ResMut<SoundSettings>,
+    mut shake: ResMut<ScreenShake>,
+    mut rumble: ResMut<RumbleSettings>,
+    mut motion: ResMut<MotionSettings>,
+    mut quality: ResMut<GraphicsQuality>,
+    mut targeting_aid: ResMut<TargetingAidSettings>,
+    mut bullet_palette: ResMut<BulletPalette>,
+    mut dialogue: ResMut<DialogueSettings>,
+    mut hud_layout: ResMut<HudLayout>,
+    mut aspect_mode: ResMut<AspectMode>,
+    mut focus: ResMut<FocusSettings>,
+    mut input: ResMut<InputConfig>,
+    mut display: ResMut<DisplaySettings>,
+    mut window_query: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    watcher.poll_timer -= time.delta_secs();
+    if watcher.poll_timer > 0.0 {
+        return;
+    }
+    watcher.poll_timer = POLL_INTERVAL;
+
+    let path = settings_path();
+    let modified = modified_time(&path);
+    if modified.is_none() || modified == watcher.last_modified {
+        return;
+    }
+    watcher.last_modified = modified;
+
+    let text = match std::fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(e) => {
+            warn!("Failed to read {:?}: {}", path, e);
+            return;
+        }
+    };
+
+    // Keep the current settings on a bad edit - the file is probably mid-save
+    let settings = match SettingsFile::from_ron(&text) {
+        Ok(settings) => settings.sanitized(),
+        Err(e) => {
+            warn!("Ignoring invalid settings.ron: {}", e);
+            return;
+        }
+    };
+
+    if watcher.last_applied.as_ref() == Some(&settings) {
+        return;
+    }
+
+    apply_settings(
+        &settings,
+        &mut sound,
+        &mut shake,
+        &mut rumble,
+        &mut motion,
+        &mut quality,
+        &mut targeting_aid,
+        &mut bullet_palette,
+        &mut dialogue,
+        &mut hud_layout,
+        &mut aspect_mode,
+        &mut focus,
+        &mut input,
+        &mut display,
+        window_query.get_single_mut().ok(),
+    );
+    watcher.last_applied = Some(settings);
+    info!("Hot-reloaded settings from {:?}", path);
+}
+
+/// Write in-game settings changes back to the file
+#[cfg(not(target_arch = "wasm32"))]
+fn write_settings_file(
+    mut watcher: ResMut<SettingsFileWatcher>,
+    sound: Res<SoundSettings>,
+    shake: Res<ScreenShake>,
+    rumble: Res<RumbleSettings>,
+    motion: Res<MotionSettings>,
+    quality: Res<GraphicsQuality>,
+    targeting_aid: Res<TargetingAidSettings>,
+    bullet_palette: Res<BulletPalette>,
+    dialogue: Res<DialogueSettings>,
+    hud_layout: Res<HudLayout>,
+    aspect_mode: Res<AspectMode>,
+    focus: Res<FocusSettings>,
+    input: Res<InputConfig>,
+    display: Res<DisplaySettings>,
+    window_query: Query<Ref<Window>, With<PrimaryWindow>>,
+) {
+    let window = window_query.get_single().ok();
+    let window_changed = window.as_ref().is_some_and(|w| w.is_changed());
+    if !sound.is_changed()
+        && !shake.is_changed()
+        && !rumble.is_changed()
+        && !motion.is_changed()
+        && !quality.is_changed()
+        && !targeting_aid.is_changed()
+        && !bullet_palette.is_changed()
+        && !dialogue.is_changed()
+        && !hud_layout.is_changed()
+        && !aspect_mode.is_changed()
+        && !focus.is_changed()
+        && !input.is_changed()
+        && !display.is_changed()
+        && !window_changed
+    {
+        return;
+    }
+
+    let settings = collect_settings(
+        &sound,
+        &shake,
+        &rumble,
+        &motion,
+        *quality,
+        &targeting_aid,
+        *bullet_palette,
+        &dialogue,
+        *hud_layout,
+        *aspect_mode,
+        *focus,
+        &input,
+        &display,
+        window.as_deref(),
+    );
+    if watcher.last_applied.as_ref() == Some(&settings) {
+        return;
+    }
+    write_file(&mut watcher, &settings);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn write_file(watcher: &mut SettingsFileWatcher, settings: &SettingsFile) {
+    let path = settings_path();
+    if let Some(parent) = path.parent() {
+        if let Err(e) = std::fs::create_dir_all(parent) {
+            warn!("Failed to create settings directory: {}", e);
+            return;
+        }
+    }
+
+    match settings.to_ron() {
+        Ok(text) => {
+            if let Err(e) = std::fs::write(&path, text) {
+                warn!("Failed to write {:?}: {}", path, e);
+                return;
+            }
+        }
+        Err(e) => {
+            warn!("Failed to serialize settings: {}", e);
+            return;
+        }
+    }
+
+    watcher.last_modified = modified_time(&path);
+    watcher.last_applied = Some(settings.clone());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn settings_file_roundtrip() {
+        let mut settings = SettingsFile::default();
+        settings.audio.music_volume = 0.25;
+        settings.input.controller_deadzone = 0.2;
+        settings.graphics.display_mode = DisplayMode::Exclusive;
+        settings.graphics.monitor = 1;
+        settings.graphics.low_latency = true;
+        settings.graphics.quality = GraphicsQuality::Low;
+        settings.graphics.targeting_aid = true;
+        settings.graphics.dialogue_speed = DialogueSpeed::Slow;
+        settings.graphics.hud_layout = HudLayout::Compact;
+        settings.graphics.aspect_mode = AspectMode::Letterbox;
+        settings.background.auto_pause = false;
+        settings.background.audio = BackgroundAudio::Mute;
+
+        let text = settings.to_ron().expect("serialize");
+        let loaded = SettingsFile::from_ron(&text).expect("deserialize");
+        assert_eq!(loaded, settings);
+    }
+
+    #[test]
+    fn settings_file_partial_uses_defaults() {
+        let loaded = SettingsFile::from_ron("(audio: (music_volume: 0.1))").expect("deserialize");
+        assert_eq!(loaded.audio.music_volume, 0.1);
+        assert_eq!(
+            loaded.audio.master_volume,
+            AudioSection::default().master_volume
+        );
+        assert_eq!(loaded.input, InputSection::default());
+        assert_eq!(loaded.graphics, GraphicsSection::default());
+    }
+
+    #[test]
+    fn settings_file_sanitized_clamps() {
+        let mut settings = SettingsFile::default();
+        settings.audio.master_volume = 3.0;
+        settings.input.controller_deadzone = -1.0;
+        settings.graphics.screen_shake_intensity = 2.0;
+
+        let settings = settings.sanitized();
+        assert_eq!(settings.audio.master_volume, 1.0);
+        assert_eq!(settings.input.controller_deadzone, 0.0);
+        assert_eq!(settings.graphics.screen_shake_intensity, 1.0);
+    }
+
+    #[test]
+    fn settings_file_rejects_garbage() {
+        assert!(SettingsFile::from_ron("(audio: ").is_err());
+    }
+}
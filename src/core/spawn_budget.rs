@@ -0,0 +1,168 @@
+//! Spawn Budgets
+//!
+//! A wave is a point budget rather than a ship count. Each enemy class
+//! costs points, and the spawner spends the budget on the faction's ships
+//! by their spawn weights, so a wave of cruisers is fewer hulls than a
+//! wave of frigates for the same threat. A pacing curve shapes the budget
+//! across a mission: it ramps up wave by wave, drops for a lull every few
+//! waves, and swells for the last one. Budgets and curves are plain data,
+//! so tuning a mission is an override file rather than a code change.
+
+#![allow(dead_code)]
+
+use serde::{Deserialize, Serialize};
+
+use super::factions::{EnemyShipDef, ShipClass};
+
+/// Points a frigate costs; the unit the other classes are priced in
+pub const FRIGATE_COST: u32 = 2;
+
+/// Budget of a mission's first wave unless it says otherwise
+pub const DEFAULT_WAVE_BUDGET: u32 = 8;
+
+/// Most ships a single wave can field, however large its budget
+pub const MAX_WAVE_SHIPS: usize = 24;
+
+/// Points an enemy of this class costs
+pub fn class_cost(class: ShipClass) -> u32 {
+    match class {
+        ShipClass::Frigate | ShipClass::Interceptor => FRIGATE_COST,
+        ShipClass::AssaultFrigate => 3,
+        ShipClass::Destroyer => 4,
+        ShipClass::TacticalDestroyer => 5,
+        ShipClass::Cruiser => 6,
+        ShipClass::Battlecruiser => 10,
+        ShipClass::Battleship => 16,
+    }
+}
+
+/// How a mission's wave budgets rise and fall
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PacingCurve {
+    /// Budget added by the last wave, as a share of the first wave's
+    pub ramp: f32,
+    /// Every nth wave is a lull (0 = none)
+    pub lull_every: u32,
+    /// Budget scale on a lull wave
+    pub lull: f32,
+    /// Budget scale on the mission's last wave
+    pub climax: f32,
+}
+
+impl Default for PacingCurve {
+    fn default() -> Self {
+        Self {
+            ramp: 1.0,
+            lull_every: 3,
+            lull: 0.6,
+            climax: 1.4,
+        }
+    }
+}
+
+impl PacingCurve {
+    /// Budget scale for `wave` (1-based) of a mission's `waves`. Waves past
+    /// the last (attack modes keep going) hold the top of the ramp, lulls
+    /// included.
+    pub fn factor(&self, wave: u32, waves: u32) -> f32 {
+        let wave = wave.max(1);
+        let waves = waves.max(1);
+        let progress = if waves > 1 {
+            ((wave - 1) as f32 / (waves - 1) as f32).min(1.0)
+        } else {
+            1.0
+        };
+        let ramped = 1.0 + self.ramp * progress;
+
+        if wave == waves {
+            ramped * self.climax
+        } else if self.lull_every > 0 && wave.is_multiple_of(self.lull_every) {
+            ramped * self.lull
+        } else {
+            ramped
+        }
+    }
+}
+
+/// Points to spend on a wave
+pub fn wave_budget(base: u32, wave: u32, waves: u32, pacing: &PacingCurve, scale: f32) -> u32 {
+    (base as f32 * pacing.factor(wave, waves) * scale).round() as u32
+}
+
+/// Spend a budget on `enemies` by spawn weight, only ever picking what the
+/// rest of the budget still covers. A budget too small for any ship buys
+/// the cheapest one, so a wave is never empty.
+pub fn spend_budget<'a>(
+    budget: u32,
+    enemies: &'a [EnemyShipDef],
+    rng: &mut fastrand::Rng,
+) -> Vec<&'a EnemyShipDef> {
+    let mut roster = Vec::new();
+    let mut left = budget;
+
+    while roster.len() < MAX_WAVE_SHIPS {
+        let affordable: Vec<&EnemyShipDef> = enemies
+            .iter()
+            .filter(|e| e.spawn_weight > 0 && class_cost(e.class) <= left)
+            .collect();
+        let total_weight: u32 = affordable.iter().map(|e| e.spawn_weight).sum();
+        if total_weight == 0 {
+            break;
+        }
+
+        let mut roll = rng.u32(0..total_weight);
+        let Some(pick) = affordable.into_iter().find(|e| {
+            if roll < e.spawn_weight {
+                true
+            } else {
+                roll -= e.spawn_weight;
+                false
+            }
+        }) else {
+            break;
+        };
+        left -= class_cost(pick.class);
+        roster.push(pick);
+    }
+
+    if roster.is_empty() {
+        if let Some(cheapest) = enemies.iter().min_by_key(|e| class_cost(e.class)) {
+            roster.push(cheapest);
+        }
+    }
+    roster
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Faction;
+
+    #[test]
+    fn pacing_ramps_with_lulls_and_a_climax() {
+        let pacing = PacingCurve::default();
+        let factors: Vec<f32> = (1..=6).map(|w| pacing.factor(w, 6)).collect();
+        // Wave 3 is a lull below both its neighbours
+        assert!(factors[2] < factors[1] && factors[2] < factors[3]);
+        // The last wave is the biggest
+        assert!(factors.iter().all(|f| *f <= factors[5]));
+        // Past the end, the top of the ramp without the climax
+        assert_eq!(pacing.factor(7, 6), 1.0 + pacing.ramp);
+    }
+
+    #[test]
+    fn spending_stays_within_the_budget() {
+        let mut rng = fastrand::Rng::with_seed(11);
+        for faction in Faction::all() {
+            let enemies = faction.enemy_ships();
+            for budget in [0, 1, 7, 20, 60] {
+                let roster = spend_budget(budget, enemies, &mut rng);
+                let spent: u32 = roster.iter().map(|e| class_cost(e.class)).sum();
+                assert!(!roster.is_empty());
+                assert!(spent <= budget.max(FRIGATE_COST));
+                assert!(roster.len() <= MAX_WAVE_SHIPS);
+            }
+        }
+    }
+}
@@ -0,0 +1,307 @@
+//! Berserk
+//!
+//! The berserk meter: filled by close kills, spent for a burst of score,
+//! damage and speed.
+
+/// Berserk mode - meter fills from proximity kills, manual activation with B/Y
+/// Based on finishing guide: meter 0-100, manual activation, 5x score, 8 second duration
+#[derive(Debug, Clone)]
+pub struct BerserkSystem {
+    /// Berserk meter (0.0 to 100.0)
+    pub meter: f32,
+    /// Meter gained per proximity kill
+    pub meter_per_kill: f32,
+    /// Proximity range for kills to count (closer = more meter)
+    pub proximity_range: f32,
+    /// Meter decay rate when not killing (per second)
+    pub decay_rate: f32,
+    /// Whether berserk mode is active
+    pub is_active: bool,
+    /// Remaining berserk duration
+    pub timer: f32,
+    /// Total berserk duration
+    pub duration: f32,
+    /// Score multiplier when active
+    pub score_multiplier: f32,
+    /// Flash timer for activation effect
+    pub activation_flash: f32,
+}
+
+impl Default for BerserkSystem {
+    fn default() -> Self {
+        Self {
+            meter: 0.0,
+            meter_per_kill: 15.0,   // ~7 close kills to fill
+            proximity_range: 120.0, // Slightly more forgiving range
+            decay_rate: 5.0,        // Slow decay when not killing
+            is_active: false,
+            timer: 0.0,
+            duration: 8.0,         // 8 seconds when activated
+            score_multiplier: 5.0, // 5x score as per guide
+            activation_flash: 0.0,
+        }
+    }
+}
+
+impl BerserkSystem {
+    /// Register a kill at distance. Fills meter based on proximity.
+    /// Closer kills fill more meter. Returns meter gained.
+    pub fn on_kill_at_distance(&mut self, distance: f32) -> f32 {
+        if self.is_active {
+            return 0.0; // Already active, no meter gain
+        }
+
+        // Calculate meter gain based on proximity (closer = more)
+        let proximity_bonus = if distance <= self.proximity_range {
+            // Linear falloff: point-blank = 100%, max range = 50%
+            let normalized = distance / self.proximity_range;
+            1.0 - (normalized * 0.5)
+        } else {
+            // Outside range: minimal gain
+            0.25
+        };
+
+        let gain = self.meter_per_kill * proximity_bonus;
+        self.meter = (self.meter + gain).min(100.0);
+        gain
+    }
+
+    /// Legacy on_kill for compatibility (assumes point-blank)
+    pub fn on_kill(&mut self) {
+        self.on_kill_at_distance(0.0);
+    }
+
+    /// Check if berserk can be activated (meter full)
+    pub fn can_activate(&self) -> bool {
+        !self.is_active && self.meter >= 100.0
+    }
+
+    /// Try to activate berserk. Returns true if activated.
+    pub fn try_activate(&mut self) -> bool {
+        if self.can_activate() {
+            self.is_active = true;
+            self.timer = self.duration;
+            self.meter = 0.0;
+            self.activation_flash = 0.5; // Half second flash
+            return true;
+        }
+        false
+    }
+
+    /// Update berserk state (call each frame)
+    pub fn update(&mut self, dt: f32) {
+        // Update activation flash
+        if self.activation_flash > 0.0 {
+            self.activation_flash = (self.activation_flash - dt).max(0.0);
+        }
+
+        if self.is_active {
+            self.timer -= dt;
+            if self.timer <= 0.0 {
+                self.is_active = false;
+            }
+        } else {
+            // Decay meter slowly when not killing
+            if self.meter > 0.0 {
+                self.meter = (self.meter - self.decay_rate * dt).max(0.0);
+            }
+        }
+    }
+
+    /// Get score multiplier (5x when active)
+    pub fn score_mult(&self) -> f32 {
+        if self.is_active {
+            self.score_multiplier
+        } else {
+            1.0
+        }
+    }
+
+    /// Get damage multiplier (2x when active)
+    pub fn damage_mult(&self) -> f32 {
+        if self.is_active {
+            2.0
+        } else {
+            1.0
+        }
+    }
+
+    /// Get speed multiplier (1.5x when active)
+    pub fn speed_mult(&self) -> f32 {
+        if self.is_active {
+            1.5
+        } else {
+            1.0
+        }
+    }
+
+    /// Get progress toward berserk (0.0 - 1.0)
+    /// When active, shows remaining duration. When inactive, shows meter fill.
+    pub fn progress(&self) -> f32 {
+        if self.is_active {
+            self.timer / self.duration
+        } else {
+            self.meter / 100.0
+        }
+    }
+
+    /// Get meter percentage (0.0 - 1.0)
+    pub fn meter_percent(&self) -> f32 {
+        self.meter / 100.0
+    }
+
+    /// Check if activation flash is active (for visual effects)
+    pub fn is_flashing(&self) -> bool {
+        self.activation_flash > 0.0
+    }
+
+    /// Reset berserk state (for new stage)
+    pub fn reset(&mut self) {
+        self.meter = 0.0;
+        self.is_active = false;
+        self.timer = 0.0;
+        self.activation_flash = 0.0;
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::field_reassign_with_default)]
+mod tests {
+    use super::*;
+
+    // ==================== BerserkSystem Tests ====================
+
+    #[test]
+    fn berserk_default_values() {
+        let b = BerserkSystem::default();
+        assert_eq!(b.meter, 0.0);
+        assert_eq!(b.meter_per_kill, 15.0);
+        assert_eq!(b.proximity_range, 120.0);
+        assert_eq!(b.duration, 8.0);
+        assert_eq!(b.score_multiplier, 5.0);
+        assert!(!b.is_active);
+    }
+
+    #[test]
+    fn berserk_proximity_kill_fills_meter() {
+        let mut b = BerserkSystem::default();
+        let gain = b.on_kill_at_distance(0.0); // Point blank
+        assert!(gain > 0.0);
+        assert!(b.meter > 0.0);
+    }
+
+    #[test]
+    fn berserk_closer_kills_give_more_meter() {
+        let mut b1 = BerserkSystem::default();
+        let mut b2 = BerserkSystem::default();
+
+        let gain_close = b1.on_kill_at_distance(0.0);
+        let gain_far = b2.on_kill_at_distance(100.0);
+
+        assert!(gain_close > gain_far, "closer kills should give more meter");
+    }
+
+    #[test]
+    fn berserk_cannot_activate_when_meter_not_full() {
+        let mut b = BerserkSystem::default();
+        b.meter = 50.0;
+        assert!(!b.can_activate());
+        assert!(!b.try_activate());
+        assert!(!b.is_active);
+    }
+
+    #[test]
+    fn berserk_activates_when_meter_full_and_triggered() {
+        let mut b = BerserkSystem::default();
+        b.meter = 100.0;
+        assert!(b.can_activate());
+        assert!(b.try_activate());
+        assert!(b.is_active);
+        assert_eq!(b.timer, 8.0);
+        assert_eq!(b.meter, 0.0); // Reset after activation
+    }
+
+    #[test]
+    fn berserk_multipliers_when_active() {
+        let mut b = BerserkSystem::default();
+        assert_eq!(b.score_mult(), 1.0);
+        assert_eq!(b.damage_mult(), 1.0);
+        assert_eq!(b.speed_mult(), 1.0);
+
+        // Activate
+        b.meter = 100.0;
+        b.try_activate();
+
+        assert_eq!(b.score_mult(), 5.0);
+        assert_eq!(b.damage_mult(), 2.0);
+        assert_eq!(b.speed_mult(), 1.5);
+    }
+
+    #[test]
+    fn berserk_duration_decay() {
+        let mut b = BerserkSystem::default();
+        b.meter = 100.0;
+        b.try_activate();
+        assert!(b.is_active);
+
+        b.update(4.0);
+        assert!(b.is_active);
+        assert_eq!(b.timer, 4.0);
+
+        b.update(4.1);
+        assert!(!b.is_active);
+    }
+
+    #[test]
+    fn berserk_meter_decays_when_not_killing() {
+        let mut b = BerserkSystem::default();
+        b.meter = 50.0;
+
+        b.update(2.0); // 2 seconds at 5.0/s decay = -10
+        assert!((b.meter - 40.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn berserk_progress_calculation() {
+        let mut b = BerserkSystem::default();
+        assert_eq!(b.progress(), 0.0);
+
+        b.meter = 50.0;
+        assert!((b.progress() - 0.5).abs() < 0.01); // 50%
+
+        // Activate
+        b.meter = 100.0;
+        b.try_activate();
+        assert_eq!(b.progress(), 1.0); // Full timer
+
+        b.update(4.0);
+        assert!((b.progress() - 0.5).abs() < 0.01); // Half timer
+    }
+
+    #[test]
+    fn berserk_reset() {
+        let mut b = BerserkSystem::default();
+        b.meter = 75.0;
+        b.is_active = true;
+        b.timer = 3.0;
+
+        b.reset();
+
+        assert_eq!(b.meter, 0.0);
+        assert!(!b.is_active);
+        assert_eq!(b.timer, 0.0);
+    }
+
+    #[test]
+    fn berserk_activation_flash() {
+        let mut b = BerserkSystem::default();
+        b.meter = 100.0;
+        b.try_activate();
+
+        assert!(b.is_flashing());
+        assert!(b.activation_flash > 0.0);
+
+        b.update(0.6); // Wait past flash duration
+        assert!(!b.is_flashing());
+    }
+}
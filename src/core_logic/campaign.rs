@@ -0,0 +1,105 @@
+//! Campaign Acts
+//!
+//! The three acts of the Elder Fleet campaign and the order they come in.
+//! Missions, bosses and waves are data the engine side (`core::campaign`)
+//! hangs off each act.
+
+/// Campaign acts - progression through the story
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Act {
+    #[default]
+    Act1, // "The Call" - Rifter, proving yourself
+    Act2, // "The Storm" - Wolf, full assault
+    Act3, // "Liberation" - Jaguar, final push
+}
+
+impl Act {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Act::Act1 => "THE CALL",
+            Act::Act2 => "THE STORM",
+            Act::Act3 => "LIBERATION",
+        }
+    }
+
+    pub fn catchphrase(&self) -> &'static str {
+        match self {
+            Act::Act1 => "In Rust We Trust!",
+            Act::Act2 => "No more rust - just steel!",
+            Act::Act3 => "For Freedom! For the Republic!",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Act::Act1 => {
+                "Prove yourself worthy in a rust-bucket Rifter. Early raids on slave convoys."
+            }
+            Act::Act2 => "The invasion begins in earnest. You've earned an assault frigate.",
+            Act::Act3 => "The final push. Strike at the heart of the Empire.",
+        }
+    }
+
+    pub fn number(&self) -> u32 {
+        match self {
+            Act::Act1 => 1,
+            Act::Act2 => 2,
+            Act::Act3 => 3,
+        }
+    }
+
+    pub fn next(&self) -> Option<Act> {
+        match self {
+            Act::Act1 => Some(Act::Act2),
+            Act::Act2 => Some(Act::Act3),
+            Act::Act3 => None,
+        }
+    }
+
+    /// Act for its number (1-3); anything else is the first act
+    pub fn from_number(number: u32) -> Act {
+        match number {
+            2 => Act::Act2,
+            3 => Act::Act3,
+            _ => Act::Act1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn act_default_is_act1() {
+        assert_eq!(Act::default(), Act::Act1);
+    }
+
+    #[test]
+    fn act_names() {
+        assert_eq!(Act::Act1.name(), "THE CALL");
+        assert_eq!(Act::Act2.name(), "THE STORM");
+        assert_eq!(Act::Act3.name(), "LIBERATION");
+    }
+
+    #[test]
+    fn act_catchphrases() {
+        assert_eq!(Act::Act1.catchphrase(), "In Rust We Trust!");
+        assert_eq!(Act::Act2.catchphrase(), "No more rust - just steel!");
+        assert_eq!(Act::Act3.catchphrase(), "For Freedom! For the Republic!");
+    }
+
+    #[test]
+    fn act_numbers() {
+        assert_eq!(Act::Act1.number(), 1);
+        assert_eq!(Act::Act2.number(), 2);
+        assert_eq!(Act::Act3.number(), 3);
+    }
+
+    #[test]
+    fn act_progression() {
+        assert_eq!(Act::Act1.next(), Some(Act::Act2));
+        assert_eq!(Act::Act2.next(), Some(Act::Act3));
+        assert_eq!(Act::Act3.next(), None); // Final act
+    }
+}
@@ -0,0 +1,413 @@
+//! Difficulty
+//!
+//! Difficulty levels and the player, enemy, boss and scoring modifiers each
+//! one applies.
+
+/// Difficulty levels - EVE-themed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
+pub enum DifficultyLevel {
+    /// Easy - Relaxed gameplay, generous shields, forgiving combat
+    Carebear,
+    /// Normal - Balanced experience for new pilots
+    #[default]
+    Newbro,
+    /// Hard - Punishing difficulty for experienced pilots
+    BitterVet,
+    /// Nightmare - One-shot kills, relentless enemies, no mercy
+    Triglavian,
+}
+
+impl DifficultyLevel {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DifficultyLevel::Carebear => "CAREBEAR",
+            DifficultyLevel::Newbro => "NEWBRO",
+            DifficultyLevel::BitterVet => "BITTER VET",
+            DifficultyLevel::Triglavian => "TRIGLAVIAN",
+        }
+    }
+
+    pub fn tagline(&self) -> &'static str {
+        match self {
+            DifficultyLevel::Carebear => "High-sec living",
+            DifficultyLevel::Newbro => "Welcome to New Eden",
+            DifficultyLevel::BitterVet => "I remember when...",
+            DifficultyLevel::Triglavian => "Clade proving grounds",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            DifficultyLevel::Carebear => {
+                "Relaxed gameplay with generous shields and forgiving combat."
+            }
+            DifficultyLevel::Newbro => {
+                "Balanced experience for new pilots. Fair challenge with room to learn."
+            }
+            DifficultyLevel::BitterVet => {
+                "Punishing difficulty for experienced pilots. Enemies hit hard."
+            }
+            DifficultyLevel::Triglavian => {
+                "Nightmare mode. One-shot kills, relentless enemies, no mercy."
+            }
+        }
+    }
+
+    /// Get all difficulty levels in order
+    pub fn all() -> [DifficultyLevel; 4] {
+        [
+            DifficultyLevel::Carebear,
+            DifficultyLevel::Newbro,
+            DifficultyLevel::BitterVet,
+            DifficultyLevel::Triglavian,
+        ]
+    }
+
+    /// Get the next difficulty (wraps around)
+    pub fn next(&self) -> DifficultyLevel {
+        match self {
+            DifficultyLevel::Carebear => DifficultyLevel::Newbro,
+            DifficultyLevel::Newbro => DifficultyLevel::BitterVet,
+            DifficultyLevel::BitterVet => DifficultyLevel::Triglavian,
+            DifficultyLevel::Triglavian => DifficultyLevel::Carebear,
+        }
+    }
+
+    /// Get the previous difficulty (wraps around)
+    pub fn prev(&self) -> DifficultyLevel {
+        match self {
+            DifficultyLevel::Carebear => DifficultyLevel::Triglavian,
+            DifficultyLevel::Newbro => DifficultyLevel::Carebear,
+            DifficultyLevel::BitterVet => DifficultyLevel::Newbro,
+            DifficultyLevel::Triglavian => DifficultyLevel::BitterVet,
+        }
+    }
+}
+
+/// Player stat modifiers based on difficulty
+#[derive(Debug, Clone, Copy)]
+pub struct PlayerModifiers {
+    pub hull_multiplier: f32,
+    pub shield_multiplier: f32,
+    pub armor_multiplier: f32,
+    pub damage_multiplier: f32,
+    pub capacitor_recharge_multiplier: f32,
+    pub capacitor_drain_multiplier: f32,
+    pub maneuver_cooldown_multiplier: f32,
+    pub invincibility_duration_multiplier: f32,
+}
+
+impl Default for PlayerModifiers {
+    fn default() -> Self {
+        Self {
+            hull_multiplier: 1.0,
+            shield_multiplier: 1.0,
+            armor_multiplier: 1.0,
+            damage_multiplier: 1.0,
+            capacitor_recharge_multiplier: 1.0,
+            capacitor_drain_multiplier: 1.0,
+            maneuver_cooldown_multiplier: 1.0,
+            invincibility_duration_multiplier: 1.0,
+        }
+    }
+}
+
+/// Enemy stat modifiers based on difficulty
+#[derive(Debug, Clone, Copy)]
+pub struct EnemyModifiers {
+    pub health_multiplier: f32,
+    pub damage_multiplier: f32,
+    pub fire_rate_multiplier: f32,
+    pub speed_multiplier: f32,
+    pub accuracy_multiplier: f32,
+    pub spawn_rate_multiplier: f32,
+}
+
+impl Default for EnemyModifiers {
+    fn default() -> Self {
+        Self {
+            health_multiplier: 1.0,
+            damage_multiplier: 1.0,
+            fire_rate_multiplier: 1.0,
+            speed_multiplier: 1.0,
+            accuracy_multiplier: 1.0,
+            spawn_rate_multiplier: 1.0,
+        }
+    }
+}
+
+/// Boss modifiers based on difficulty
+#[derive(Debug, Clone, Copy)]
+pub struct BossModifiers {
+    pub health_multiplier: f32,
+    pub damage_multiplier: f32,
+    pub attack_cooldown_multiplier: f32,
+    /// Damage for flying into the boss's hull
+    pub contact_damage: f32,
+}
+
+impl Default for BossModifiers {
+    fn default() -> Self {
+        Self {
+            health_multiplier: 1.0,
+            damage_multiplier: 1.0,
+            attack_cooldown_multiplier: 1.0,
+            contact_damage: 12.0,
+        }
+    }
+}
+
+/// Scoring modifiers based on difficulty
+#[derive(Debug, Clone, Copy)]
+pub struct ScoringModifiers {
+    pub base_score_multiplier: f32,
+    pub combo_decay_multiplier: f32,
+}
+
+impl Default for ScoringModifiers {
+    fn default() -> Self {
+        Self {
+            base_score_multiplier: 1.0,
+            combo_decay_multiplier: 1.0,
+        }
+    }
+}
+
+/// Complete difficulty settings resource
+#[derive(Debug, Clone)]
+pub struct DifficultySettings {
+    pub level: DifficultyLevel,
+    pub player: PlayerModifiers,
+    pub enemy: EnemyModifiers,
+    pub boss: BossModifiers,
+    pub scoring: ScoringModifiers,
+}
+
+impl Default for DifficultySettings {
+    fn default() -> Self {
+        Self::from_level(DifficultyLevel::default())
+    }
+}
+
+impl DifficultySettings {
+    /// Create settings for a specific difficulty level
+    pub fn from_level(level: DifficultyLevel) -> Self {
+        match level {
+            DifficultyLevel::Carebear => Self {
+                level,
+                player: PlayerModifiers {
+                    hull_multiplier: 1.5,
+                    shield_multiplier: 2.0,
+                    armor_multiplier: 1.5,
+                    damage_multiplier: 1.2,
+                    capacitor_recharge_multiplier: 1.5,
+                    capacitor_drain_multiplier: 0.7,
+                    maneuver_cooldown_multiplier: 0.7,
+                    invincibility_duration_multiplier: 1.5,
+                },
+                enemy: EnemyModifiers {
+                    health_multiplier: 0.7,
+                    damage_multiplier: 0.5,
+                    fire_rate_multiplier: 0.7,
+                    speed_multiplier: 0.85,
+                    accuracy_multiplier: 0.6,
+                    spawn_rate_multiplier: 0.8,
+                },
+                boss: BossModifiers {
+                    health_multiplier: 0.6,
+                    damage_multiplier: 0.5,
+                    attack_cooldown_multiplier: 1.3,
+                    contact_damage: 6.0,
+                },
+                scoring: ScoringModifiers {
+                    base_score_multiplier: 0.5,
+                    combo_decay_multiplier: 0.7,
+                },
+            },
+            DifficultyLevel::Newbro => Self {
+                level,
+                player: PlayerModifiers::default(),
+                enemy: EnemyModifiers::default(),
+                boss: BossModifiers::default(),
+                scoring: ScoringModifiers::default(),
+            },
+            DifficultyLevel::BitterVet => Self {
+                level,
+                player: PlayerModifiers {
+                    hull_multiplier: 0.8,
+                    shield_multiplier: 0.8,
+                    armor_multiplier: 0.8,
+                    damage_multiplier: 0.9,
+                    capacitor_recharge_multiplier: 0.8,
+                    capacitor_drain_multiplier: 1.2,
+                    maneuver_cooldown_multiplier: 1.2,
+                    invincibility_duration_multiplier: 0.8,
+                },
+                enemy: EnemyModifiers {
+                    health_multiplier: 1.3,
+                    damage_multiplier: 1.5,
+                    fire_rate_multiplier: 1.3,
+                    speed_multiplier: 1.15,
+                    accuracy_multiplier: 1.3,
+                    spawn_rate_multiplier: 1.2,
+                },
+                boss: BossModifiers {
+                    health_multiplier: 1.4,
+                    damage_multiplier: 1.5,
+                    attack_cooldown_multiplier: 0.8,
+                    contact_damage: 20.0,
+                },
+                scoring: ScoringModifiers {
+                    base_score_multiplier: 1.5,
+                    combo_decay_multiplier: 1.3,
+                },
+            },
+            DifficultyLevel::Triglavian => Self {
+                level,
+                player: PlayerModifiers {
+                    hull_multiplier: 0.5,
+                    shield_multiplier: 0.5,
+                    armor_multiplier: 0.5,
+                    damage_multiplier: 0.8,
+                    capacitor_recharge_multiplier: 0.6,
+                    capacitor_drain_multiplier: 1.5,
+                    maneuver_cooldown_multiplier: 1.4,
+                    invincibility_duration_multiplier: 0.5,
+                },
+                enemy: EnemyModifiers {
+                    health_multiplier: 1.5,
+                    damage_multiplier: 3.0,
+                    fire_rate_multiplier: 1.5,
+                    speed_multiplier: 1.3,
+                    accuracy_multiplier: 1.5,
+                    spawn_rate_multiplier: 1.5,
+                },
+                boss: BossModifiers {
+                    health_multiplier: 2.0,
+                    damage_multiplier: 2.5,
+                    attack_cooldown_multiplier: 0.6,
+                    contact_damage: 35.0,
+                },
+                scoring: ScoringModifiers {
+                    base_score_multiplier: 3.0,
+                    combo_decay_multiplier: 2.0,
+                },
+            },
+        }
+    }
+
+    /// Set difficulty level and update all modifiers
+    pub fn set_level(&mut self, level: DifficultyLevel) {
+        *self = Self::from_level(level);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== DifficultyLevel Tests ====================
+
+    #[test]
+    fn difficulty_level_cycling() {
+        let d = DifficultyLevel::Carebear;
+        assert_eq!(d.next(), DifficultyLevel::Newbro);
+        assert_eq!(d.next().next(), DifficultyLevel::BitterVet);
+        assert_eq!(d.next().next().next(), DifficultyLevel::Triglavian);
+        assert_eq!(d.next().next().next().next(), DifficultyLevel::Carebear);
+    }
+
+    #[test]
+    fn difficulty_level_prev_cycling() {
+        let d = DifficultyLevel::Carebear;
+        assert_eq!(d.prev(), DifficultyLevel::Triglavian);
+        assert_eq!(DifficultyLevel::Newbro.prev(), DifficultyLevel::Carebear);
+    }
+
+    #[test]
+    fn difficulty_level_names() {
+        assert_eq!(DifficultyLevel::Carebear.name(), "CAREBEAR");
+        assert_eq!(DifficultyLevel::Newbro.name(), "NEWBRO");
+        assert_eq!(DifficultyLevel::BitterVet.name(), "BITTER VET");
+        assert_eq!(DifficultyLevel::Triglavian.name(), "TRIGLAVIAN");
+    }
+
+    // ==================== DifficultySettings Tests ====================
+
+    #[test]
+    fn difficulty_settings_carebear_is_easier() {
+        let settings = DifficultySettings::from_level(DifficultyLevel::Carebear);
+
+        // Player should be stronger
+        assert!(settings.player.hull_multiplier > 1.0);
+        assert!(settings.player.shield_multiplier > 1.0);
+        assert!(settings.player.damage_multiplier > 1.0);
+
+        // Enemies should be weaker
+        assert!(settings.enemy.health_multiplier < 1.0);
+        assert!(settings.enemy.damage_multiplier < 1.0);
+
+        // Score multiplier lower (easy mode = less reward)
+        assert!(settings.scoring.base_score_multiplier < 1.0);
+    }
+
+    #[test]
+    fn difficulty_settings_newbro_is_baseline() {
+        let settings = DifficultySettings::from_level(DifficultyLevel::Newbro);
+
+        assert_eq!(settings.player.hull_multiplier, 1.0);
+        assert_eq!(settings.enemy.health_multiplier, 1.0);
+        assert_eq!(settings.scoring.base_score_multiplier, 1.0);
+    }
+
+    #[test]
+    fn difficulty_settings_bittrevet_is_harder() {
+        let settings = DifficultySettings::from_level(DifficultyLevel::BitterVet);
+
+        // Player should be weaker
+        assert!(settings.player.hull_multiplier < 1.0);
+
+        // Enemies should be stronger
+        assert!(settings.enemy.health_multiplier > 1.0);
+        assert!(settings.enemy.damage_multiplier > 1.0);
+
+        // Score multiplier higher (hard mode = more reward)
+        assert!(settings.scoring.base_score_multiplier > 1.0);
+    }
+
+    #[test]
+    fn difficulty_settings_triglavian_is_nightmare() {
+        let settings = DifficultySettings::from_level(DifficultyLevel::Triglavian);
+
+        // Player very weak
+        assert!(settings.player.hull_multiplier <= 0.5);
+
+        // Enemies very strong - the 3.0x damage
+        assert!(settings.enemy.damage_multiplier >= 3.0);
+
+        // Boss doubled health
+        assert!(settings.boss.health_multiplier >= 2.0);
+
+        // Score multiplier highest
+        assert!(settings.scoring.base_score_multiplier >= 3.0);
+    }
+
+    #[test]
+    fn boss_contact_damage_rises_with_difficulty() {
+        let contact: Vec<f32> = DifficultyLevel::all()
+            .iter()
+            .map(|level| DifficultySettings::from_level(*level).boss.contact_damage)
+            .collect();
+        assert!(contact.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn difficulty_settings_set_level() {
+        let mut settings = DifficultySettings::default();
+        assert_eq!(settings.level, DifficultyLevel::Newbro);
+
+        settings.set_level(DifficultyLevel::Triglavian);
+        assert_eq!(settings.level, DifficultyLevel::Triglavian);
+        assert!(settings.enemy.damage_multiplier >= 3.0);
+    }
+}
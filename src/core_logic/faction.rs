@@ -0,0 +1,178 @@
+//! Factions
+//!
+//! The four factions of New Eden, their names, rivalries and story text.
+
+/// The four major factions of New Eden
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Faction {
+    #[default]
+    Minmatar,
+    Amarr,
+    Caldari,
+    Gallente,
+}
+
+impl Faction {
+    /// All factions
+    pub fn all() -> &'static [Faction] {
+        &[
+            Faction::Minmatar,
+            Faction::Amarr,
+            Faction::Caldari,
+            Faction::Gallente,
+        ]
+    }
+
+    /// Faction display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Faction::Minmatar => "Minmatar Republic",
+            Faction::Amarr => "Amarr Empire",
+            Faction::Caldari => "Caldari State",
+            Faction::Gallente => "Gallente Federation",
+        }
+    }
+
+    /// Short name
+    pub fn short_name(&self) -> &'static str {
+        match self {
+            Faction::Minmatar => "MINMATAR",
+            Faction::Amarr => "AMARR",
+            Faction::Caldari => "CALDARI",
+            Faction::Gallente => "GALLENTE",
+        }
+    }
+
+    /// Faction tagline
+    pub fn tagline(&self) -> &'static str {
+        match self {
+            Faction::Minmatar => "In Rust We Trust",
+            Faction::Amarr => "Amarr Victor",
+            Faction::Caldari => "The State Provides",
+            Faction::Gallente => "Liberty or Death",
+        }
+    }
+
+    /// Default enemy faction
+    pub fn rival(&self) -> Faction {
+        match self {
+            Faction::Minmatar => Faction::Amarr,
+            Faction::Amarr => Faction::Minmatar,
+            Faction::Caldari => Faction::Gallente,
+            Faction::Gallente => Faction::Caldari,
+        }
+    }
+
+    /// Campaign intro text
+    pub fn story_intro(&self) -> &'static str {
+        match self {
+            Faction::Minmatar => "Your ancestors were enslaved by the Amarr Empire. Generations suffered under their golden heel. But the Minmatar spirit cannot be broken. Today, you strike back.",
+            Faction::Amarr => "The Minmatar rebels threaten the divine order of the Empire. As a loyal servant of the Empress, you will crush this insurrection and restore peace through strength.",
+            Faction::Caldari => "The Gallente Federation encroaches on State interests. Corporate profits demand action. You are the blade of the megacorporations.",
+            Faction::Gallente => "The Caldari State oppresses its workers and threatens Federation sovereignty. Fight for liberty against corporate tyranny.",
+        }
+    }
+
+    /// Victory text
+    pub fn victory_text(&self) -> &'static str {
+        match self {
+            Faction::Minmatar => "The Amarr fleet lies in ruins. Slaves are free. The Republic stands defiant. You are legend.",
+            Faction::Amarr => "The rebellion is crushed. Order is restored. The Empire endures eternal. Glory to Amarr.",
+            Faction::Caldari => "Gallente forces are scattered. The trade lanes are secure. The State prospers.",
+            Faction::Gallente => "The Caldari fleet retreats. Freedom rings across the stars. Vive la Fédération!",
+        }
+    }
+
+    /// Get carrier type_id for this faction (used for wave spawning visuals)
+    pub fn carrier_type_id(&self) -> u32 {
+        match self {
+            Faction::Minmatar => 24483, // Nidhoggur
+            Faction::Amarr => 23757,    // Archon
+            Faction::Caldari => 23915,  // Chimera
+            Faction::Gallente => 23911, // Thanatos
+        }
+    }
+
+    /// Get a fighter/drone type_id for this faction (used by boss spawners)
+    /// Returns a small, fast frigate appropriate for the faction
+    pub fn fighter_type_id(&self) -> u32 {
+        match self {
+            Faction::Minmatar => 585, // Slasher - fast interceptor
+            Faction::Amarr => 589,    // Executioner - fast interceptor
+            Faction::Caldari => 583,  // Condor - fast frigate
+            Faction::Gallente => 608, // Atron - fast frigate
+        }
+    }
+
+    /// Get a tougher drone type_id for this faction (used by boss spawners)
+    /// Returns a tankier frigate appropriate for the faction
+    pub fn tough_fighter_type_id(&self) -> u32 {
+        match self {
+            Faction::Minmatar => 598, // Breacher - tanky missile boat
+            Faction::Amarr => 591,    // Tormentor - tanky laser boat
+            Faction::Caldari => 602,  // Kestrel - tanky missile boat
+            Faction::Gallente => 594, // Incursus - tanky blaster boat
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ==================== Faction Basics ====================
+
+    #[test]
+    fn faction_all_returns_four() {
+        assert_eq!(Faction::all().len(), 4);
+    }
+
+    #[test]
+    fn faction_default_is_minmatar() {
+        assert_eq!(Faction::default(), Faction::Minmatar);
+    }
+
+    #[test]
+    fn faction_names() {
+        assert_eq!(Faction::Minmatar.name(), "Minmatar Republic");
+        assert_eq!(Faction::Amarr.name(), "Amarr Empire");
+        assert_eq!(Faction::Caldari.name(), "Caldari State");
+        assert_eq!(Faction::Gallente.name(), "Gallente Federation");
+    }
+
+    #[test]
+    fn faction_short_names() {
+        assert_eq!(Faction::Minmatar.short_name(), "MINMATAR");
+        assert_eq!(Faction::Amarr.short_name(), "AMARR");
+        assert_eq!(Faction::Caldari.short_name(), "CALDARI");
+        assert_eq!(Faction::Gallente.short_name(), "GALLENTE");
+    }
+
+    #[test]
+    fn faction_taglines() {
+        assert_eq!(Faction::Minmatar.tagline(), "In Rust We Trust");
+        assert_eq!(Faction::Amarr.tagline(), "Amarr Victor");
+        assert_eq!(Faction::Caldari.tagline(), "The State Provides");
+        assert_eq!(Faction::Gallente.tagline(), "Liberty or Death");
+    }
+
+    // ==================== Faction Rivals ====================
+
+    #[test]
+    fn faction_rivals_are_bidirectional() {
+        // Minmatar ↔ Amarr
+        assert_eq!(Faction::Minmatar.rival(), Faction::Amarr);
+        assert_eq!(Faction::Amarr.rival(), Faction::Minmatar);
+
+        // Caldari ↔ Gallente
+        assert_eq!(Faction::Caldari.rival(), Faction::Gallente);
+        assert_eq!(Faction::Gallente.rival(), Faction::Caldari);
+    }
+
+    #[test]
+    fn faction_rival_of_rival_is_self() {
+        for faction in Faction::all() {
+            assert_eq!(faction.rival().rival(), *faction);
+        }
+    }
+}
@@ -0,0 +1,23 @@
+//! Core game logic, free of the engine
+//!
+//! The rules of the game as plain Rust with no Bevy types: scoring and kill
+//! chains, berserk, difficulty modifiers, campaign acts and factions. Kept
+//! apart so they test fast, can drive a headless simulation, and can back a
+//! server-side check of leaderboard runs.
+//!
+//! `core` re-exports everything here and layers the engine side on top
+//! (resources, colors, mission tables).
+
+#![allow(dead_code)]
+
+pub mod berserk;
+pub mod campaign;
+pub mod difficulty;
+pub mod faction;
+pub mod score;
+
+pub use berserk::*;
+pub use campaign::*;
+pub use difficulty::*;
+pub use faction::*;
+pub use score::*;
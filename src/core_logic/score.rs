@@ -0,0 +1,351 @@
+//! Scoring
+//!
+//! Points, kill chains and their multiplier, per-category score weights and
+//! style grades.
+
+/// Player score and combo system
+#[derive(Debug, Clone)]
+pub struct ScoreSystem {
+    /// Current score
+    pub score: u64,
+    /// Current multiplier (1.0 - 99.9)
+    pub multiplier: f32,
+    /// Kill chain count
+    pub chain: u32,
+    /// Time remaining to maintain chain
+    pub chain_timer: f32,
+    /// Maximum chain time
+    pub max_chain_time: f32,
+    /// Style points earned
+    pub style_points: u32,
+    /// No damage bonus active
+    pub no_damage_bonus: bool,
+    /// Souls liberated count (Elder Fleet campaign)
+    pub souls_liberated: u32,
+    /// Difficulty (and run modifier) score multiplier, applied to every award
+    pub difficulty_multiplier: f32,
+}
+
+impl Default for ScoreSystem {
+    fn default() -> Self {
+        Self {
+            score: 0,
+            multiplier: 1.0,
+            chain: 0,
+            chain_timer: 0.0,
+            max_chain_time: 2.0,
+            style_points: 0,
+            no_damage_bonus: true,
+            souls_liberated: 0,
+            difficulty_multiplier: 1.0,
+        }
+    }
+}
+
+impl ScoreSystem {
+    /// Add points with current multiplier. Returns the points added.
+    pub fn add_score(&mut self, base_points: u64) -> u64 {
+        self.award((base_points as f32 * self.multiplier) as u64)
+    }
+
+    /// Add points that already carry their own multipliers (boss kills,
+    /// wave bonuses); only difficulty applies. Returns the points added.
+    pub fn award(&mut self, points: u64) -> u64 {
+        let final_points = self.difficulty_scaled(points);
+        self.score += final_points;
+        final_points
+    }
+
+    /// Points after the difficulty multiplier
+    pub fn difficulty_scaled(&self, points: u64) -> u64 {
+        (points as f32 * self.difficulty_multiplier).round() as u64
+    }
+
+    /// Register a kill and extend chain. Returns the points added.
+    pub fn on_kill(&mut self, base_points: u64) -> u64 {
+        self.chain += 1;
+        self.chain_timer = self.max_chain_time;
+        self.multiplier = (1.0 + self.chain as f32 * 0.1).min(99.9);
+        self.add_score(base_points)
+    }
+
+    /// Score a `ScoreEvent`: `weight` carries the module's category
+    /// multiplier (and any heat bonus). Kills extend the chain; everything
+    /// else rides the current multiplier. Returns the points added.
+    pub fn score_event(&mut self, category: ScoreCategory, base_points: u64, weight: f32) -> u64 {
+        let weighted = (base_points as f32 * weight).round() as u64;
+        match category {
+            ScoreCategory::Kill => self.on_kill(weighted),
+            _ => self.add_score(weighted),
+        }
+    }
+
+    /// Update chain timer (call each frame)
+    pub fn update(&mut self, dt: f32) {
+        if self.chain > 0 {
+            self.chain_timer -= dt;
+            if self.chain_timer <= 0.0 {
+                self.chain = 0;
+                self.multiplier = 1.0;
+            }
+        }
+    }
+
+    /// Get style grade based on average multiplier
+    pub fn get_grade(&self) -> StyleGrade {
+        match self.multiplier {
+            m if m >= 50.0 => StyleGrade::SSS,
+            m if m >= 20.0 => StyleGrade::SS,
+            m if m >= 10.0 => StyleGrade::S,
+            m if m >= 5.0 => StyleGrade::A,
+            m if m >= 3.0 => StyleGrade::B,
+            m if m >= 1.5 => StyleGrade::C,
+            _ => StyleGrade::D,
+        }
+    }
+
+    /// Reset for new stage
+    pub fn reset_stage(&mut self) {
+        self.chain = 0;
+        self.chain_timer = 0.0;
+        self.multiplier = 1.0;
+        self.no_damage_bonus = true;
+    }
+
+    /// Reset for new game (difficulty carries over)
+    pub fn reset_game(&mut self) {
+        *self = Self {
+            difficulty_multiplier: self.difficulty_multiplier,
+            ..Self::default()
+        };
+    }
+}
+
+/// What a `ScoreEvent` paid out for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScoreCategory {
+    Kill,
+    /// Shots that passed close by without hitting
+    Graze,
+    /// Chips, wrecks and pods picked up
+    Salvage,
+    /// Progress on a mission objective
+    Objective,
+    /// Escorts, pods and convoys brought through alive
+    Escort,
+    /// One-off bonuses (squadron wipes, hangar kills)
+    Bonus,
+}
+
+/// Per-category score multipliers, set by each game module for balancing
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScoreWeights {
+    pub kill: f32,
+    pub graze: f32,
+    pub salvage: f32,
+    pub objective: f32,
+    pub escort: f32,
+    pub bonus: f32,
+}
+
+impl Default for ScoreWeights {
+    fn default() -> Self {
+        Self {
+            kill: 1.0,
+            graze: 1.0,
+            salvage: 1.0,
+            objective: 1.0,
+            escort: 1.0,
+            bonus: 1.0,
+        }
+    }
+}
+
+impl ScoreWeights {
+    pub fn get(&self, category: ScoreCategory) -> f32 {
+        match category {
+            ScoreCategory::Kill => self.kill,
+            ScoreCategory::Graze => self.graze,
+            ScoreCategory::Salvage => self.salvage,
+            ScoreCategory::Objective => self.objective,
+            ScoreCategory::Escort => self.escort,
+            ScoreCategory::Bonus => self.bonus,
+        }
+    }
+}
+
+/// Style grades (like Devil May Cry)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum StyleGrade {
+    D,
+    C,
+    B,
+    A,
+    S,
+    SS,
+    SSS,
+}
+
+impl StyleGrade {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StyleGrade::D => "D",
+            StyleGrade::C => "C",
+            StyleGrade::B => "B",
+            StyleGrade::A => "A",
+            StyleGrade::S => "S",
+            StyleGrade::SS => "SS",
+            StyleGrade::SSS => "SSS",
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::field_reassign_with_default)]
+mod tests {
+    use super::*;
+
+    // ==================== ScoreSystem Tests ====================
+
+    #[test]
+    fn score_system_default_values() {
+        let s = ScoreSystem::default();
+        assert_eq!(s.score, 0);
+        assert_eq!(s.multiplier, 1.0);
+        assert_eq!(s.chain, 0);
+        assert!(s.no_damage_bonus);
+    }
+
+    #[test]
+    fn score_system_add_score_applies_multiplier() {
+        let mut s = ScoreSystem {
+            multiplier: 2.0,
+            ..Default::default()
+        };
+        s.add_score(100);
+        assert_eq!(s.score, 200);
+    }
+
+    #[test]
+    fn difficulty_scales_every_award() {
+        let mut s = ScoreSystem {
+            multiplier: 2.0,
+            difficulty_multiplier: 1.5,
+            ..Default::default()
+        };
+        s.add_score(100);
+        assert_eq!(s.score, 300);
+        // Pre-multiplied awards skip the chain but not the difficulty
+        assert_eq!(s.award(1000), 1500);
+        assert_eq!(s.score, 1800);
+
+        s.reset_game();
+        assert_eq!(s.score, 0);
+        assert_eq!(s.difficulty_multiplier, 1.5);
+    }
+
+    #[test]
+    fn score_events_are_weighted_and_only_kills_extend_the_chain() {
+        let mut s = ScoreSystem::default();
+        let weights = ScoreWeights {
+            salvage: 0.5,
+            ..Default::default()
+        };
+        let salvage = ScoreCategory::Salvage;
+        assert_eq!(s.score_event(salvage, 200, weights.get(salvage)), 100);
+        assert_eq!(s.chain, 0);
+
+        s.score_event(ScoreCategory::Kill, 100, weights.get(ScoreCategory::Kill));
+        assert_eq!(s.chain, 1);
+        assert_eq!(s.score, 200);
+    }
+
+    #[test]
+    fn score_system_on_kill_extends_chain() {
+        let mut s = ScoreSystem::default();
+        s.on_kill(100);
+        assert_eq!(s.chain, 1);
+        assert_eq!(s.chain_timer, 2.0);
+        assert_eq!(s.multiplier, 1.1); // 1.0 + 1 * 0.1
+    }
+
+    #[test]
+    fn score_system_multiplier_caps_at_99_9() {
+        let mut s = ScoreSystem::default();
+        // Kill 1000 times to push multiplier
+        for _ in 0..1000 {
+            s.on_kill(1);
+        }
+        assert!(s.multiplier <= 99.9);
+        assert!(s.multiplier >= 99.0);
+    }
+
+    #[test]
+    fn score_system_chain_timer_decay_resets_chain() {
+        let mut s = ScoreSystem::default();
+        s.on_kill(100);
+        assert_eq!(s.chain, 1);
+
+        // Simulate time passing
+        s.update(2.1);
+        assert_eq!(s.chain, 0);
+        assert_eq!(s.multiplier, 1.0);
+    }
+
+    #[test]
+    fn score_system_grades() {
+        let mut s = ScoreSystem::default();
+
+        s.multiplier = 0.5;
+        assert_eq!(s.get_grade(), StyleGrade::D);
+
+        s.multiplier = 1.5;
+        assert_eq!(s.get_grade(), StyleGrade::C);
+
+        s.multiplier = 3.0;
+        assert_eq!(s.get_grade(), StyleGrade::B);
+
+        s.multiplier = 5.0;
+        assert_eq!(s.get_grade(), StyleGrade::A);
+
+        s.multiplier = 10.0;
+        assert_eq!(s.get_grade(), StyleGrade::S);
+
+        s.multiplier = 20.0;
+        assert_eq!(s.get_grade(), StyleGrade::SS);
+
+        s.multiplier = 50.0;
+        assert_eq!(s.get_grade(), StyleGrade::SSS);
+    }
+
+    #[test]
+    fn score_system_reset_stage() {
+        let mut s = ScoreSystem::default();
+        s.on_kill(100);
+        s.score = 5000;
+        s.no_damage_bonus = false;
+
+        s.reset_stage();
+
+        assert_eq!(s.chain, 0);
+        assert_eq!(s.multiplier, 1.0);
+        assert!(s.no_damage_bonus);
+        // Score persists through stage reset
+        assert_eq!(s.score, 5000);
+    }
+
+    #[test]
+    fn score_system_reset_game() {
+        let mut s = ScoreSystem::default();
+        s.on_kill(100);
+        s.score = 5000;
+        s.souls_liberated = 42;
+
+        s.reset_game();
+
+        assert_eq!(s.score, 0);
+        assert_eq!(s.souls_liberated, 0);
+        assert_eq!(s.multiplier, 1.0);
+    }
+}
@@ -1,6 +1,10 @@
 //! Boss Entities
 //!
 //! Stage bosses for the Elder Fleet campaign.
+//!
+//! Later bosses bring an arena hazard to their fight: the Stargate's
+//! sweeping grid lasers, the Battlestation's plasma vents and the
+//! lingering burn of the Avatar's doomsday.
 
 #![allow(dead_code)]
 
@@ -11,6 +15,7 @@ use bevy::prelude::*;
 
 /// Marker component for bosses
 #[derive(Component)]
+#[require(StateScope)]
 pub struct Boss;
 
 /// Boss data
@@ -52,6 +57,27 @@ pub struct BossData {
     pub enrage_threshold: f32,
 }
 
+impl BossData {
+    /// Apply dev tuning; a boss already taking damage keeps its health fraction
+    pub fn apply_override(&mut self, tuning: &BossOverride) {
+        if let Some(health) = tuning.health.filter(|h| *h > 0.0) {
+            let fraction = self.health / self.max_health.max(1.0);
+            self.max_health = health;
+            self.health = health * fraction;
+        }
+        if let Some(phases) = tuning.phases {
+            self.total_phases = phases.max(1);
+            self.current_phase = self.current_phase.min(self.total_phases);
+        }
+        if let Some(threshold) = tuning.enrage_threshold {
+            self.enrage_threshold = threshold.clamp(0.0, 1.0);
+        }
+        if let Some(score) = tuning.score_value {
+            self.score_value = score;
+        }
+    }
+}
+
 /// Boss health bar component
 #[derive(Component)]
 pub struct BossHealthBar;
@@ -103,6 +129,35 @@ impl Default for BossMovement {
     }
 }
 
+/// Arena mechanic a boss brings to its fight
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArenaHazardKind {
+    /// Laser lines sweep across the arena from its edges, each with a gap
+    GridLasers,
+    /// Plasma vented from the hull in a cone toward the player
+    PlasmaVents,
+    /// Where the doomsday lands keeps burning for a while
+    BurnZones,
+}
+
+/// A boss's arena hazard and its timing
+#[derive(Component, Debug, Clone)]
+pub struct ArenaHazard {
+    pub kind: ArenaHazardKind,
+    /// Seconds between hazards (burn zones come with the doomsday instead)
+    pub interval: f32,
+    /// Seconds until the next one
+    pub timer: f32,
+    /// Seconds of warning before a hazard goes live
+    pub telegraph: f32,
+    /// Damage per hit, before difficulty scaling
+    pub damage: f32,
+    /// Seconds a vent or burn stays live (lasers last their sweep)
+    pub duration: f32,
+    /// First phase the hazard shows up in
+    pub from_phase: u32,
+}
+
 /// Boss attack timer
 #[derive(Component, Debug)]
 pub struct BossAttack {
@@ -393,9 +448,47 @@ pub fn get_boss_for_stage(stage: u32) -> Option<BossData> {
     }
 }
 
+/// Arena hazard of the boss on `stage`, if it has one
+pub fn arena_hazard_for_stage(stage: u32) -> Option<ArenaHazard> {
+    match stage {
+        // Stage 8 - Stargate Defense: lasers once the grid wakes up
+        8 => Some(ArenaHazard {
+            kind: ArenaHazardKind::GridLasers,
+            interval: 8.0,
+            timer: 4.0,
+            telegraph: 1.2,
+            damage: 25.0,
+            duration: 0.0,
+            from_phase: 2,
+        }),
+        // Stage 9 - Battlestation: vents from the start
+        9 => Some(ArenaHazard {
+            kind: ArenaHazardKind::PlasmaVents,
+            interval: 6.0,
+            timer: 5.0,
+            telegraph: 1.0,
+            damage: 12.0,
+            duration: 1.5,
+            from_phase: 1,
+        }),
+        // Stage 13 - Avatar Titan: the doomsday scorches the arena
+        13 => Some(ArenaHazard {
+            kind: ArenaHazardKind::BurnZones,
+            interval: 0.0,
+            timer: 0.0,
+            telegraph: 0.8,
+            damage: 10.0,
+            duration: 6.0,
+            from_phase: 1,
+        }),
+        _ => None,
+    }
+}
+
 /// Spawn a stage boss
 pub fn spawn_boss(
     commands: &mut Commands,
+    playfield: &Playfield,
     stage: u32,
     enemy_faction: crate::core::Faction,
     sprite_cache: Option<&crate::assets::ShipSpriteCache>,
@@ -424,6 +517,12 @@ pub fn spawn_boss(
 
     let size = 64.0 * scale_mult;
     let stationary = boss_data.stationary;
+    let hardpoints = super::Hardpoints::new(
+        boss_hardpoints(&boss_data.ship_class),
+        size,
+        Vec2::NEG_Y,
+        super::BOSS_TURRET_TURN_RATE,
+    );
 
     let movement = if stationary {
         BossMovement {
@@ -449,6 +548,8 @@ pub fn spawn_boss(
             max_drones: 4,
             active_drones: 0,
             pattern: DroneSpawnPattern::FromSides,
+            phase_gates: true,
+            gates_opened: 0,
         }),
         // Stage 8 - Stargate Defense
         8 => Some(BossDroneSpawner {
@@ -459,6 +560,8 @@ pub fn spawn_boss(
             max_drones: 6,
             active_drones: 0,
             pattern: DroneSpawnPattern::FromSides,
+            phase_gates: true,
+            gates_opened: 0,
         }),
         // Stage 9 - Battlestation (major drone spawner)
         9 => Some(BossDroneSpawner {
@@ -469,6 +572,8 @@ pub fn spawn_boss(
             max_drones: 8,
             active_drones: 0,
             pattern: DroneSpawnPattern::Surround,
+            phase_gates: true,
+            gates_opened: 0,
         }),
         // Stage 11 - Carrier (dedicated drone boat)
         11 => Some(BossDroneSpawner {
@@ -479,6 +584,8 @@ pub fn spawn_boss(
             max_drones: 10,
             active_drones: 0,
             pattern: DroneSpawnPattern::VFormation,
+            phase_gates: true,
+            gates_opened: 0,
         }),
         // Stage 13 - Titan (light drone support)
         13 => Some(BossDroneSpawner {
@@ -489,12 +596,15 @@ pub fn spawn_boss(
             max_drones: 6,
             active_drones: 0,
             pattern: DroneSpawnPattern::Flanking,
+            phase_gates: false, // A warship, not a drone boat
+            gates_opened: 0,
         }),
         _ => None,
     };
+    let arena_hazard = arena_hazard_for_stage(stage);
 
-    // Spawn at top of screen
-    let start_y = SCREEN_HEIGHT / 2.0 + size;
+    // Spawn just above the playfield
+    let start_y = playfield.half_height() + size;
 
     // Try 3D model first
     if boss_data.type_id > 0 {
@@ -513,6 +623,7 @@ pub fn spawn_boss(
                         radius: size / 2.0 * 0.8,
                     },
                     model_rot.clone(),
+                    hardpoints,
                     SceneRoot(scene_handle),
                     Transform::from_xyz(0.0, start_y, 0.0)
                         .with_scale(Vec3::splat(model_scale))
@@ -522,6 +633,9 @@ pub fn spawn_boss(
                 if let Some(spawner) = drone_spawner {
                     entity_commands.insert(spawner);
                 }
+                if let Some(hazard) = arena_hazard {
+                    entity_commands.insert(hazard);
+                }
 
                 return true;
             }
@@ -533,9 +647,8 @@ pub fn spawn_boss(
         if let Some(cache) = sprite_cache {
             if let Some(texture) = cache.get(boss_data.type_id) {
                 Sprite {
-                    image: texture,
                     custom_size: Some(Vec2::splat(size)),
-                    ..default()
+                    ..texture.sprite()
                 }
             } else {
                 Sprite {
@@ -574,14 +687,49 @@ pub fn spawn_boss(
         transform: Transform::from_xyz(0.0, start_y, LAYER_ENEMIES)
             .with_rotation(Quat::from_rotation_z(std::f32::consts::PI)),
     });
+    entity_commands.insert(hardpoints);
 
     if let Some(spawner) = drone_spawner {
         entity_commands.insert(spawner);
     }
+    if let Some(hazard) = arena_hazard {
+        entity_commands.insert(hazard);
+    }
 
     true
 }
 
+/// Broadside batteries on battleships and titans
+const BOSS_HARDPOINTS_BROADSIDE: &[Vec2] = &[
+    Vec2::new(0.0, 0.36),
+    Vec2::new(-0.22, 0.16),
+    Vec2::new(0.22, 0.16),
+    Vec2::new(-0.3, -0.08),
+    Vec2::new(0.3, -0.08),
+    Vec2::new(-0.24, -0.3),
+    Vec2::new(0.24, -0.3),
+];
+
+/// Defence turrets around a station's rim
+const BOSS_HARDPOINTS_STATION: &[Vec2] = &[
+    Vec2::new(0.0, 0.4),
+    Vec2::new(-0.35, 0.2),
+    Vec2::new(0.35, 0.2),
+    Vec2::new(-0.35, -0.2),
+    Vec2::new(0.35, -0.2),
+    Vec2::new(0.0, -0.4),
+];
+
+/// Turret layout for a boss hull
+pub fn boss_hardpoints(ship_class: &str) -> &'static [Vec2] {
+    match ship_class {
+        "Orbital Platform" | "Infrastructure" | "Station" => BOSS_HARDPOINTS_STATION,
+        "Armageddon" | "Apocalypse Navy Issue" | "Archon" | "Avatar" => BOSS_HARDPOINTS_BROADSIDE,
+        "Navy Omen" | "Prophecy" | "Prophecy Variant" | "Maller" | "Harbinger" => HARDPOINTS_QUAD,
+        _ => HARDPOINTS_TRIPLE,
+    }
+}
+
 /// Get phase health threshold
 pub fn get_phase_threshold(phase: u32, total_phases: u32) -> f32 {
     match (phase, total_phases) {
@@ -1,6 +1,14 @@
 //! Collectible Entities
 //!
 //! Power-ups, refugees, credits, etc.
+//!
+//! Pickups inside the player's magnet radius drift toward the ship. Tractor
+//! beam pickups widen that radius, and clearing a wave vacuums up everything
+//! still on screen.
+//!
+//! The rare Nosferatu module leeches hull, then armor, from every kill for a
+//! few seconds. Each repair shrinks as recent leeching piles up, so a fast
+//! kill streak can't outheal everything thrown at the ship.
 
 #![allow(dead_code)]
 
@@ -8,8 +16,36 @@ use crate::core::*;
 use crate::systems::{check_liberation_milestone, ComboHeatSystem, DialogueEvent};
 use bevy::prelude::*;
 
+/// Base radius inside which pickups drift toward the player
+pub const MAGNET_BASE_RADIUS: f32 = 90.0;
+/// Radius added per tractor beam upgrade
+pub const MAGNET_UPGRADE_RADIUS: f32 = 30.0;
+/// Upgrades a ship can stack in one run
+pub const MAX_MAGNET_UPGRADES: u32 = 3;
+/// Radius multiplier while a tractor beam is active
+pub const TRACTOR_RADIUS_MULT: f32 = 2.0;
+/// Tractor beam duration (seconds)
+pub const TRACTOR_DURATION: f32 = 8.0;
+/// Acceleration toward the player at full pull
+const MAGNET_ACCEL: f32 = 1200.0;
+/// Top speed of an attracted pickup
+const MAGNET_MAX_SPEED: f32 = 520.0;
+/// Pull at the edge of the radius, so pickups never stall there
+const MAGNET_EDGE_PULL: f32 = 0.3;
+/// Lifetime granted to vacuumed pickups so none expire mid-flight
+const VACUUM_LIFETIME: f32 = 4.0;
+/// Nosferatu duration (seconds)
+pub const LEECH_DURATION: f32 = 8.0;
+/// Hull/armor repaired per kill while leeching, before diminishing returns
+pub const LEECH_PER_KILL: f32 = 8.0;
+/// Recent leech repairs at which each kill repairs half as much
+const LEECH_SOFT_CAP: f32 = 40.0;
+/// How fast recent leech repairs are forgotten (points per second)
+const LEECH_RECENT_DECAY: f32 = 10.0;
+
 /// Marker component for collectibles
 #[derive(Component, Debug)]
+#[require(StateScope)]
 pub struct Collectible;
 
 /// Collectible data
@@ -42,8 +78,12 @@ impl Default for CollectiblePhysics {
     }
 }
 
+/// Pulled in by the end-of-wave vacuum regardless of distance
+#[derive(Component, Debug)]
+pub struct Vacuumed;
+
 /// Active powerup effects on the player
-#[derive(Component, Debug, Default)]
+#[derive(Component, Debug)]
 pub struct PowerupEffects {
     /// Overdrive timer (speed boost)
     pub overdrive_timer: f32,
@@ -51,6 +91,31 @@ pub struct PowerupEffects {
     pub damage_boost_timer: f32,
     /// Invulnerability timer
     pub invuln_timer: f32,
+    /// Tractor beam timer (wider magnet)
+    pub tractor_timer: f32,
+    /// Base pickup magnet radius
+    pub magnet_radius: f32,
+    /// Tractor beam upgrades collected this run
+    pub magnet_upgrades: u32,
+    /// Nosferatu timer (repairs on kills)
+    pub leech_timer: f32,
+    /// Leech repairs in the last few seconds, for diminishing returns
+    pub leech_recent: f32,
+}
+
+impl Default for PowerupEffects {
+    fn default() -> Self {
+        Self {
+            overdrive_timer: 0.0,
+            damage_boost_timer: 0.0,
+            invuln_timer: 0.0,
+            tractor_timer: 0.0,
+            magnet_radius: MAGNET_BASE_RADIUS,
+            magnet_upgrades: 0,
+            leech_timer: 0.0,
+            leech_recent: 0.0,
+        }
+    }
 }
 
 impl PowerupEffects {
@@ -81,6 +146,66 @@ impl PowerupEffects {
             1.0
         }
     }
+
+    pub fn is_tractoring(&self) -> bool {
+        self.tractor_timer > 0.0
+    }
+
+    pub fn is_leeching(&self) -> bool {
+        self.leech_timer > 0.0
+    }
+
+    /// Repair the next kill leeches: the full amount at first, halved once
+    /// recent repairs reach the soft cap
+    pub fn leech_amount(&self) -> f32 {
+        if !self.is_leeching() {
+            return 0.0;
+        }
+        LEECH_PER_KILL * LEECH_SOFT_CAP / (LEECH_SOFT_CAP + self.leech_recent)
+    }
+
+    /// Current magnet radius: base plus upgrades, doubled by a tractor beam
+    pub fn pickup_magnet_radius(&self) -> f32 {
+        let upgrades = self.magnet_upgrades.min(MAX_MAGNET_UPGRADES) as f32;
+        let radius = self.magnet_radius + upgrades * MAGNET_UPGRADE_RADIUS;
+        if self.is_tractoring() {
+            radius * TRACTOR_RADIUS_MULT
+        } else {
+            radius
+        }
+    }
+}
+
+/// Repair hull first, then armor with what's left. Returns the points
+/// actually repaired.
+pub fn leech_repair(stats: &mut super::player::ShipStats, amount: f32) -> f32 {
+    let hull = amount.min(stats.max_hull - stats.hull).max(0.0);
+    stats.hull += hull;
+    let armor = (amount - hull).min(stats.max_armor - stats.armor).max(0.0);
+    stats.armor += armor;
+    hull + armor
+}
+
+/// Magnet pull on a pickup `distance` away: 0 outside `radius`, rising from
+/// the edge pull to 1 at the ship
+pub fn magnet_pull(distance: f32, radius: f32) -> f32 {
+    if radius <= 0.0 || distance >= radius {
+        return 0.0;
+    }
+    MAGNET_EDGE_PULL + (1.0 - MAGNET_EDGE_PULL) * (1.0 - distance / radius)
+}
+
+/// Steer a pickup's velocity toward the player for one frame, shedding
+/// sideways drift in proportion to the pull
+pub fn attract(velocity: Vec2, to_player: Vec2, pull: f32, dt: f32) -> Vec2 {
+    if pull <= 0.0 {
+        return velocity;
+    }
+    let dir = to_player.normalize_or_zero();
+    let along = velocity.dot(dir);
+    let sideways = velocity - dir * along;
+    let closing = along.max(0.0) + MAGNET_ACCEL * pull * dt;
+    (dir * closing + sideways * (1.0 - pull)).clamp_length_max(MAGNET_MAX_SPEED)
 }
 
 /// Bundle for spawning collectibles
@@ -101,10 +226,13 @@ impl Plugin for CollectiblePlugin {
         app.add_systems(
             Update,
             (
+                vacuum_on_wave_complete,
+                collectible_attraction.before(collectible_movement),
                 collectible_movement,
                 collectible_lifetime,
                 collectible_pickup,
                 handle_pickup_effects,
+                leech_on_kills,
                 update_powerup_timers,
             )
                 .run_if(in_state(GameState::Playing)),
@@ -112,6 +240,51 @@ impl Plugin for CollectiblePlugin {
     }
 }
 
+/// Clearing a wave pulls every remaining pickup to the player
+fn vacuum_on_wave_complete(
+    mut commands: Commands,
+    mut wave_events: EventReader<WaveCompleteEvent>,
+    mut query: Query<(Entity, &mut CollectiblePhysics, &mut Sprite), With<Collectible>>,
+) {
+    if wave_events.read().last().is_none() {
+        return;
+    }
+
+    for (entity, mut physics, mut sprite) in query.iter_mut() {
+        physics.lifetime = physics.lifetime.max(VACUUM_LIFETIME);
+        sprite.color = sprite.color.with_alpha(1.0);
+        commands.entity(entity).insert(Vacuumed);
+    }
+}
+
+/// Draw pickups inside the magnet radius (or vacuumed) toward the player
+fn collectible_attraction(
+    time: Res<Time>,
+    player_query: Query<(&Transform, &PowerupEffects), With<super::Player>>,
+    mut query: Query<
+        (&Transform, &mut CollectiblePhysics, Has<Vacuumed>),
+        (With<Collectible>, Without<super::Player>),
+    >,
+) {
+    let Ok((player_transform, effects)) = player_query.get_single() else {
+        return;
+    };
+
+    let dt = time.delta_secs();
+    let player_pos = player_transform.translation.truncate();
+    let radius = effects.pickup_magnet_radius();
+
+    for (transform, mut physics, vacuumed) in query.iter_mut() {
+        let to_player = player_pos - transform.translation.truncate();
+        let pull = if vacuumed {
+            1.0
+        } else {
+            magnet_pull(to_player.length(), radius)
+        };
+        physics.velocity = attract(physics.velocity, to_player, pull, dt);
+    }
+}
+
 /// Move collectibles with floating effect
 fn collectible_movement(
     time: Res<Time>,
@@ -192,6 +365,7 @@ fn handle_pickup_effects(
         With<super::Player>,
     >,
     mut score: ResMut<ScoreSystem>,
+    mut score_events: EventWriter<ScoreEvent>,
     mut progress: ResMut<GameProgress>,
     mut heat_system: ResMut<ComboHeatSystem>,
     mut dialogue_events: EventWriter<DialogueEvent>,
@@ -206,7 +380,7 @@ fn handle_pickup_effects(
             CollectibleType::LiberationPod => {
                 let old_count = score.souls_liberated;
                 score.souls_liberated += 1;
-                score.add_score(500);
+                score_events.send(ScoreEvent::new(ScoreCategory::Salvage, 500).at(event.position));
 
                 // Check for liberation milestone
                 if let Some(milestone) =
@@ -221,15 +395,15 @@ fn handle_pickup_effects(
             }
             CollectibleType::ShieldBoost => {
                 stats.shield = (stats.shield + event.value as f32).min(stats.max_shield);
-                info!("Shield +{}", event.value);
+                debug!("Shield +{}", event.value);
             }
             CollectibleType::ArmorRepair => {
                 stats.armor = (stats.armor + event.value as f32).min(stats.max_armor);
-                info!("Armor +{}", event.value);
+                debug!("Armor +{}", event.value);
             }
             CollectibleType::HullRepair => {
                 stats.hull = (stats.hull + event.value as f32).min(stats.max_hull);
-                info!("Hull +{}", event.value);
+                debug!("Hull +{}", event.value);
             }
             CollectibleType::CapacitorCharge => {
                 stats.capacitor = (stats.capacitor + event.value as f32).min(stats.max_capacitor);
@@ -251,7 +425,20 @@ fn handle_pickup_effects(
             }
             CollectibleType::Nanite => {
                 heat_system.reduce_heat(50.0);
-                info!("Heat reduced by nanites");
+                debug!("Heat reduced by nanites");
+            }
+            CollectibleType::TractorBeam => {
+                effects.tractor_timer = TRACTOR_DURATION;
+                effects.magnet_upgrades = (effects.magnet_upgrades + 1).min(MAX_MAGNET_UPGRADES);
+                info!(
+                    "TRACTOR BEAM! Magnet radius {:.0}",
+                    effects.pickup_magnet_radius()
+                );
+            }
+            CollectibleType::Nosferatu => {
+                effects.leech_timer = LEECH_DURATION;
+                rumble_events.send(crate::systems::RumbleRequest::powerup());
+                info!("NOSFERATU! Kills repair the ship");
             }
             CollectibleType::ExtraLife => {
                 // Restore all HP
@@ -264,6 +451,29 @@ fn handle_pickup_effects(
     }
 }
 
+/// While a Nosferatu runs, every kill repairs the player's ship
+fn leech_on_kills(
+    mut kills: EventReader<EnemyDestroyedEvent>,
+    mut player_query: Query<
+        (&mut super::player::ShipStats, &mut PowerupEffects),
+        With<super::Player>,
+    >,
+) {
+    let Ok((mut stats, mut effects)) = player_query.get_single_mut() else {
+        kills.clear();
+        return;
+    };
+    if !effects.is_leeching() {
+        kills.clear();
+        return;
+    }
+
+    for _ in kills.read() {
+        let repaired = leech_repair(&mut stats, effects.leech_amount());
+        effects.leech_recent += repaired;
+    }
+}
+
 /// Update powerup effect timers
 fn update_powerup_timers(time: Res<Time>, mut query: Query<&mut PowerupEffects>) {
     let dt = time.delta_secs();
@@ -277,17 +487,21 @@ fn update_powerup_timers(time: Res<Time>, mut query: Query<&mut PowerupEffects>)
         if effects.invuln_timer > 0.0 {
             effects.invuln_timer -= dt;
         }
+        if effects.tractor_timer > 0.0 {
+            effects.tractor_timer -= dt;
+        }
+        if effects.leech_timer > 0.0 {
+            effects.leech_timer -= dt;
+        }
+        if effects.leech_recent > 0.0 {
+            effects.leech_recent = (effects.leech_recent - LEECH_RECENT_DECAY * dt).max(0.0);
+        }
     }
 }
 
-/// Spawn a collectible at position
-pub fn spawn_collectible(
-    commands: &mut Commands,
-    position: Vec2,
-    collectible_type: CollectibleType,
-    icon_cache: Option<&crate::assets::PowerupIconCache>,
-) {
-    let (color, size, value) = match collectible_type {
+/// Color, size and value of a collectible type
+pub fn collectible_style(collectible_type: CollectibleType) -> (Color, f32, u32) {
+    match collectible_type {
         CollectibleType::LiberationPod => (Color::srgb(0.2, 0.9, 0.5), 20.0, 1), // Green glow
         CollectibleType::Credits => (Color::srgb(1.0, 0.84, 0.0), 12.0, 100),
         CollectibleType::ShieldBoost => (COLOR_SHIELD, 28.0, 25),
@@ -298,31 +512,41 @@ pub fn spawn_collectible(
         CollectibleType::DamageBoost => (Color::srgb(1.0, 0.3, 0.3), 28.0, 1),
         CollectibleType::Invulnerability => (Color::srgb(1.0, 1.0, 1.0), 28.0, 1),
         CollectibleType::Nanite => (Color::srgb(0.0, 0.8, 0.6), 28.0, 1),
+        CollectibleType::TractorBeam => (Color::srgb(0.6, 0.4, 1.0), 28.0, 1),
+        CollectibleType::Nosferatu => (Color::srgb(0.7, 0.05, 0.25), 28.0, 1),
         CollectibleType::ExtraLife => (Color::srgb(0.0, 1.0, 0.5), 28.0, 1),
-    };
+    }
+}
 
-    // Try to use icon from cache, fallback to colored sprite
-    let sprite = if let Some(cache) = icon_cache {
-        if let Some(texture) = cache.get(&collectible_type) {
-            Sprite {
-                image: texture,
-                custom_size: Some(Vec2::splat(size)),
-                ..default()
-            }
-        } else {
-            Sprite {
-                color,
-                custom_size: Some(Vec2::splat(size)),
-                ..default()
-            }
-        }
-    } else {
-        Sprite {
+/// Sprite for a collectible: its icon from the cache, or a colored square
+pub fn collectible_sprite(
+    collectible_type: CollectibleType,
+    icon_cache: Option<&crate::assets::PowerupIconCache>,
+) -> Sprite {
+    let (color, size, _) = collectible_style(collectible_type);
+    match icon_cache.and_then(|cache| cache.get(&collectible_type)) {
+        Some(texture) => Sprite {
+            image: texture,
+            custom_size: Some(Vec2::splat(size)),
+            ..default()
+        },
+        None => Sprite {
             color,
             custom_size: Some(Vec2::splat(size)),
             ..default()
-        }
-    };
+        },
+    }
+}
+
+/// Spawn a collectible at position
+pub fn spawn_collectible(
+    commands: &mut Commands,
+    position: Vec2,
+    collectible_type: CollectibleType,
+    icon_cache: Option<&crate::assets::PowerupIconCache>,
+) {
+    let (_, _, value) = collectible_style(collectible_type);
+    let sprite = collectible_sprite(collectible_type, icon_cache);
 
     commands.spawn(CollectibleBundle {
         collectible: Collectible,
@@ -340,8 +564,14 @@ pub fn spawn_collectible(
     });
 }
 
-/// Spawn liberation pods in a burst pattern
-pub fn spawn_liberation_pods(commands: &mut Commands, position: Vec2, count: u32) {
+/// Spawn liberation pods in a burst pattern. Where they scatter decides
+/// which get collected, so it comes from the run's `drops` stream.
+pub fn spawn_liberation_pods(
+    commands: &mut Commands,
+    position: Vec2,
+    count: u32,
+    rng: &mut fastrand::Rng,
+) {
     use std::f32::consts::TAU;
 
     // Cap at reasonable maximum to avoid lag
@@ -349,15 +579,12 @@ pub fn spawn_liberation_pods(commands: &mut Commands, position: Vec2, count: u32
 
     for i in 0..pod_count {
         // Spread pods in a circle burst
-        let angle = (i as f32 / pod_count as f32) * TAU + fastrand::f32() * 0.3;
-        let speed = 40.0 + fastrand::f32() * 30.0;
+        let angle = (i as f32 / pod_count as f32) * TAU + rng.f32() * 0.3;
+        let speed = 40.0 + rng.f32() * 30.0;
         let velocity = Vec2::new(angle.cos() * speed, angle.sin() * speed - 20.0);
 
         // Offset spawn position slightly
-        let offset = Vec2::new(
-            (fastrand::f32() - 0.5) * 20.0,
-            (fastrand::f32() - 0.5) * 20.0,
-        );
+        let offset = Vec2::new((rng.f32() - 0.5) * 20.0, (rng.f32() - 0.5) * 20.0);
 
         commands.spawn(CollectibleBundle {
             collectible: Collectible,
@@ -414,11 +641,11 @@ impl PlayerHealthState {
     }
 
     /// Determine what health type is most needed
-    pub fn most_needed_health(&self) -> CollectibleType {
+    pub fn most_needed_health(&self, rng: &mut fastrand::Rng) -> CollectibleType {
         // Priority: Hull (critical) > Armor > Shield
         if self.hull_percent < 0.5 {
             // Hull is low - could give any health type, weighted toward hull/armor
-            let roll = fastrand::f32();
+            let roll = rng.f32();
             if roll < 0.4 {
                 CollectibleType::HullRepair
             } else if roll < 0.75 {
@@ -428,7 +655,7 @@ impl PlayerHealthState {
             }
         } else if self.armor_percent < 0.5 {
             // Armor is low - give armor or shield
-            let roll = fastrand::f32();
+            let roll = rng.f32();
             if roll < 0.6 {
                 CollectibleType::ArmorRepair
             } else {
@@ -439,7 +666,7 @@ impl PlayerHealthState {
             CollectibleType::ShieldBoost
         } else {
             // Player is healthy - random health type
-            let roll = fastrand::f32();
+            let roll = rng.f32();
             if roll < 0.5 {
                 CollectibleType::ShieldBoost
             } else if roll < 0.8 {
@@ -457,7 +684,13 @@ pub fn spawn_random_powerup(
     position: Vec2,
     icon_cache: Option<&crate::assets::PowerupIconCache>,
 ) {
-    spawn_smart_powerup(commands, position, icon_cache, None);
+    spawn_smart_powerup(
+        commands,
+        position,
+        icon_cache,
+        None,
+        &mut fastrand::Rng::new(),
+    );
 }
 
 /// Spawn powerup that's smart about what the player needs
@@ -466,8 +699,9 @@ pub fn spawn_smart_powerup(
     position: Vec2,
     icon_cache: Option<&crate::assets::PowerupIconCache>,
     player_health: Option<PlayerHealthState>,
+    rng: &mut fastrand::Rng,
 ) {
-    let roll = fastrand::f32();
+    let roll = rng.f32();
 
     // 30% credits, 40% health (smart), 30% special powerups
     let powerup = if roll < 0.25 {
@@ -475,10 +709,10 @@ pub fn spawn_smart_powerup(
     } else if roll < 0.65 {
         // Health drop - be smart about what type
         if let Some(health) = player_health {
-            health.most_needed_health()
+            health.most_needed_health(rng)
         } else {
             // Fallback to random health type
-            let health_roll = fastrand::f32();
+            let health_roll = rng.f32();
             if health_roll < 0.4 {
                 CollectibleType::ShieldBoost
             } else if health_roll < 0.75 {
@@ -491,13 +725,84 @@ pub fn spawn_smart_powerup(
         CollectibleType::Overdrive
     } else if roll < 0.85 {
         CollectibleType::DamageBoost
-    } else if roll < 0.92 {
+    } else if roll < 0.89 {
         CollectibleType::Nanite
-    } else if roll < 0.97 {
+    } else if roll < 0.92 {
+        CollectibleType::TractorBeam
+    } else if roll < 0.96 {
         CollectibleType::Invulnerability
+    } else if roll < 0.98 {
+        CollectibleType::Nosferatu
     } else {
         CollectibleType::ExtraLife
     };
 
     spawn_collectible(commands, position, powerup, icon_cache);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn magnet_pull_is_zero_outside_and_strongest_at_the_ship() {
+        assert_eq!(magnet_pull(100.0, 90.0), 0.0);
+        assert_eq!(magnet_pull(90.0, 90.0), 0.0);
+        assert_eq!(magnet_pull(10.0, 0.0), 0.0);
+        assert!((magnet_pull(89.9, 90.0) - MAGNET_EDGE_PULL).abs() < 0.01);
+        assert!(magnet_pull(30.0, 90.0) > magnet_pull(60.0, 90.0));
+        assert_eq!(magnet_pull(0.0, 90.0), 1.0);
+    }
+
+    #[test]
+    fn attraction_steers_toward_the_player_and_caps_speed() {
+        let drift = Vec2::new(40.0, -20.0);
+        assert_eq!(attract(drift, Vec2::new(0.0, 80.0), 0.0, 0.1), drift);
+
+        // Falling away from a player above: the magnet turns it around
+        let v = attract(drift, Vec2::new(0.0, 80.0), 0.5, 0.1);
+        assert!(v.y > 0.0);
+        assert!(v.x.abs() < drift.x);
+
+        // Full pull sheds all sideways drift
+        let v = attract(drift, Vec2::new(0.0, 80.0), 1.0, 0.1);
+        assert!(v.x.abs() < 1e-4);
+
+        // Repeated frames never exceed the top speed
+        let mut v = Vec2::ZERO;
+        for _ in 0..120 {
+            v = attract(v, Vec2::new(300.0, 0.0), 1.0, 1.0 / 60.0);
+        }
+        assert!((v.length() - MAGNET_MAX_SPEED).abs() < 1e-3);
+    }
+
+    #[test]
+    fn upgrades_and_tractor_beam_widen_the_magnet() {
+        let mut effects = PowerupEffects::default();
+        assert_eq!(effects.pickup_magnet_radius(), MAGNET_BASE_RADIUS);
+
+        effects.magnet_upgrades = 10;
+        let capped = MAGNET_BASE_RADIUS + MAX_MAGNET_UPGRADES as f32 * MAGNET_UPGRADE_RADIUS;
+        assert_eq!(effects.pickup_magnet_radius(), capped);
+
+        effects.tractor_timer = 1.0;
+        assert_eq!(effects.pickup_magnet_radius(), capped * TRACTOR_RADIUS_MULT);
+    }
+
+    #[test]
+    fn leech_repairs_hull_then_armor_with_diminishing_returns() {
+        let mut stats = crate::entities::ShipStats::default();
+        stats.hull = stats.max_hull - 3.0;
+        stats.armor = stats.max_armor - 20.0;
+        assert_eq!(leech_repair(&mut stats, 8.0), 8.0);
+        assert_eq!(stats.hull, stats.max_hull);
+        assert_eq!(stats.armor, stats.max_armor - 15.0);
+
+        let mut effects = PowerupEffects::default();
+        assert_eq!(effects.leech_amount(), 0.0);
+        effects.leech_timer = LEECH_DURATION;
+        assert_eq!(effects.leech_amount(), LEECH_PER_KILL);
+        effects.leech_recent = LEECH_SOFT_CAP;
+        assert_eq!(effects.leech_amount(), LEECH_PER_KILL / 2.0);
+    }
+}
@@ -6,13 +6,14 @@
 
 #![allow(dead_code)]
 
-use super::{Enemy, PlayerProjectile, ProjectileDamage, ProjectilePhysics};
+use super::{Enemy, ProjectileDamage, ProjectileOwner, ProjectilePhysics, Team};
 use crate::core::*;
 use crate::systems::ability::{AbilityActivatedEvent, AbilityType};
 use bevy::prelude::*;
 
 /// Marker for ability-spawned drones
 #[derive(Component, Debug)]
+#[require(StateScope)]
 pub struct Drone;
 
 /// Drone stats and state
@@ -116,7 +117,7 @@ fn spawn_drones_on_ability(
                     DroneFaction::Amarr,
                     15.0, // Duration from ability
                 );
-                info!("Deployed Amarr combat drone");
+                debug!("Deployed Amarr combat drone");
             }
             AbilityType::DroneBay => {
                 // Two Gallente autonomous fighters
@@ -128,7 +129,7 @@ fn spawn_drones_on_ability(
                     DroneFaction::Gallente,
                     20.0,
                 );
-                info!("Deployed 2 Gallente autonomous fighters");
+                debug!("Deployed 2 Gallente autonomous fighters");
             }
             _ => {}
         }
@@ -194,6 +195,7 @@ fn spawn_drone(
 /// Drones orbit around the player
 fn drone_orbit_player(
     time: Res<Time>,
+    playfield: Res<Playfield>,
     player_query: Query<&Transform, With<super::Player>>,
     mut drone_query: Query<
         (&mut Transform, &mut DroneStats),
@@ -230,15 +232,10 @@ fn drone_orbit_player(
             transform.translation.y += move_dir.y * move_speed * dt;
         }
 
-        // Clamp to screen
-        transform.translation.x = transform
-            .translation
-            .x
-            .clamp(-SCREEN_WIDTH / 2.0 + 20.0, SCREEN_WIDTH / 2.0 - 20.0);
-        transform.translation.y = transform
-            .translation
-            .y
-            .clamp(-SCREEN_HEIGHT / 2.0 + 20.0, SCREEN_HEIGHT / 2.0 - 20.0);
+        // Clamp to the playfield
+        let clamped = playfield.clamp(transform.translation.truncate(), 20.0);
+        transform.translation.x = clamped.x;
+        transform.translation.y = clamped.y;
     }
 }
 
@@ -294,7 +291,7 @@ fn drone_target_and_shoot(
             };
 
             commands.spawn((
-                PlayerProjectile,
+                ProjectileOwner::player(),
                 ProjectilePhysics {
                     velocity,
                     lifetime: 1.5,
@@ -320,13 +317,16 @@ fn drone_target_and_shoot(
 /// Drones take damage from enemy projectiles
 fn drone_take_damage(
     mut commands: Commands,
-    projectile_query: Query<(Entity, &Transform, &ProjectileDamage), With<super::EnemyProjectile>>,
+    projectile_query: Query<(Entity, &Transform, &ProjectileDamage, &ProjectileOwner)>,
     mut drone_query: Query<(Entity, &Transform, &mut DroneStats), With<Drone>>,
 ) {
-    for (proj_entity, proj_transform, proj_damage) in projectile_query.iter() {
+    for (proj_entity, proj_transform, proj_damage, owner) in projectile_query.iter() {
         let proj_pos = proj_transform.translation.truncate();
 
         for (drone_entity, drone_transform, mut stats) in drone_query.iter_mut() {
+            if !owner.hits_ship(drone_entity, Team::Player) {
+                continue;
+            }
             let drone_pos = drone_transform.translation.truncate();
             let distance = (proj_pos - drone_pos).length();
 
@@ -339,7 +339,7 @@ fn drone_take_damage(
 
                 if stats.health <= 0.0 {
                     commands.entity(drone_entity).despawn_recursive();
-                    info!("Drone destroyed!");
+                    debug!("Drone destroyed!");
                 }
 
                 break;
@@ -361,7 +361,7 @@ fn drone_lifetime_despawn(
 
         if stats.lifetime <= 0.0 {
             commands.entity(entity).despawn_recursive();
-            info!("Drone lifetime expired");
+            debug!("Drone lifetime expired");
         }
     }
 }
@@ -4,15 +4,29 @@
 
 #![allow(dead_code)]
 
-use crate::assets::{ShipModelCache, ShipModelRotation};
+use crate::assets::{ShipModelCache, ShipModelRotation, ShipSprite};
 use crate::core::*;
-use crate::systems::EngineTrail;
+use crate::systems::{
+    aura_fire_rate_mult, aura_speed_mult, status_speed_mult, AuraBuff, EngineTrail, EntryRun,
+    SquadronMember, StatusEffects, StatusKind,
+};
 use bevy::prelude::*;
 
+/// How far out a homing enemy missile picks up the player (pixels)
+const ENEMY_MISSILE_ACQUIRE_RANGE: f32 = 600.0;
+
+/// How hard doctrine-driven closing pulls off the movement pattern (0-1)
+const CLOSING_PULL: f32 = 0.6;
+
 /// Marker component for enemy entities
 #[derive(Component, Debug)]
+#[require(StateScope)]
 pub struct Enemy;
 
+/// New Game+ elite: tougher, larger and worth more
+#[derive(Component, Debug)]
+pub struct Elite;
+
 /// Enemy AI behavior type
 #[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EnemyBehavior {
@@ -87,6 +101,12 @@ pub struct EnemyWeapon {
     pub damage: f32,
     /// Firing pattern
     pub pattern: FiringPattern,
+    /// Faction firing doctrine (bursts, sweeps, homing, engage range)
+    pub attack: AttackProfile,
+    /// Shots left in the current burst
+    pub burst_left: u32,
+    /// Time until the next shot of the burst
+    pub burst_timer: f32,
 }
 
 /// Enemy firing patterns
@@ -113,6 +133,9 @@ impl Default for EnemyWeapon {
             bullet_speed: ENEMY_BULLET_SPEED,
             damage: 10.0,
             pattern: FiringPattern::Single,
+            attack: AttackProfile::DIRECT,
+            burst_left: 0,
+            burst_timer: 0.0,
         }
     }
 }
@@ -206,13 +229,15 @@ impl Plugin for EnemyPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             Update,
-            (
-                enemy_movement,
-                update_enemy_ship_rotation,
-                enemy_shooting,
-                spawner_update,
-                enemy_bounds_check,
-            )
+            (update_enemy_ship_rotation, enemy_shooting, spawner_update)
+                .in_set(GameSet::Simulation)
+                .run_if(in_state(GameState::Playing)),
+        )
+        .add_systems(
+            FixedUpdate,
+            (enemy_movement, enemy_bounds_check)
+                .chain()
+                .in_set(GameSet::Simulation)
                 .run_if(in_state(GameState::Playing)),
         );
     }
@@ -221,9 +246,20 @@ impl Plugin for EnemyPlugin {
 /// Enemy movement based on AI behavior
 fn enemy_movement(
     time: Res<Time>,
+    playfield: Res<Playfield>,
     player_query: Query<&Transform, With<super::Player>>,
     mut query: Query<
-        (&mut Transform, &EnemyStats, &mut EnemyAI),
+        (
+            &mut Transform,
+            &EnemyStats,
+            &mut EnemyAI,
+            Option<&super::EnemyTactics>,
+            Option<&SquadronMember>,
+            Option<&StatusEffects>,
+            Option<&EntryRun>,
+            Option<&EnemyWeapon>,
+            Option<&AuraBuff>,
+        ),
         (With<Enemy>, Without<super::Player>),
     >,
 ) {
@@ -233,7 +269,9 @@ fn enemy_movement(
         .map(|t| t.translation.truncate())
         .unwrap_or(Vec2::ZERO);
 
-    for (mut transform, stats, mut ai) in query.iter_mut() {
+    for (mut transform, stats, mut ai, tactics, squadron, status, entry, weapon, aura) in
+        query.iter_mut()
+    {
         ai.timer += dt;
         let pos = transform.translation.truncate();
 
@@ -255,7 +293,7 @@ fn enemy_movement(
             }
             EnemyBehavior::Sniper => {
                 // Stay at top, strafe
-                let target_y = SCREEN_HEIGHT / 2.0 - 100.0;
+                let target_y = playfield.half_height() - 100.0;
                 let y_diff = target_y - pos.y;
                 let x = (ai.timer * 1.5 + ai.phase).sin() * stats.speed;
                 Vec2::new(x, y_diff.signum() * stats.speed.min(y_diff.abs()))
@@ -274,7 +312,7 @@ fn enemy_movement(
             }
             EnemyBehavior::Spawner => {
                 // Slow descent, stays in upper area
-                let target_y = SCREEN_HEIGHT / 2.0 - 150.0;
+                let target_y = playfield.half_height() - 150.0;
                 if pos.y > target_y {
                     Vec2::new(0.0, -stats.speed * 0.3)
                 } else {
@@ -291,6 +329,26 @@ fn enemy_movement(
             }
         };
 
+        // Short-ranged doctrines press in until the player is in reach
+        let velocity = match weapon {
+            Some(w) if !w.attack.in_range(pos.distance(player_pos)) => velocity.lerp(
+                (player_pos - pos).normalize_or_zero() * stats.speed,
+                CLOSING_PULL,
+            ),
+            _ => velocity,
+        };
+
+        // An entry from off-screen, then squadron maneuvers, then tactical
+        // AI, override the pattern
+        let velocity = entry
+            .map(|e| e.velocity)
+            .or(squadron.and_then(|s| s.steer))
+            .or(tactics.and_then(|t| t.steer))
+            .unwrap_or(velocity);
+
+        // Webs and EMP drag the ship down; a commander's speed aura drives it on
+        let velocity = velocity * status_speed_mult(status) * aura_speed_mult(aura);
+
         transform.translation.x += velocity.x * dt;
         transform.translation.y += velocity.y * dt;
 
@@ -305,7 +363,18 @@ fn enemy_shooting(
     mut commands: Commands,
     time: Res<Time>,
     player_query: Query<&Transform, With<super::Player>>,
-    mut query: Query<(&Transform, &mut EnemyWeapon, &EnemyAI), With<Enemy>>,
+    mut query: Query<
+        (
+            Entity,
+            &Transform,
+            &mut EnemyWeapon,
+            &EnemyAI,
+            Option<&super::EnemyTactics>,
+            Option<&StatusEffects>,
+            Option<&AuraBuff>,
+        ),
+        With<Enemy>,
+    >,
 ) {
     let dt = time.delta_secs();
     let player_pos = player_query
@@ -313,40 +382,77 @@ fn enemy_shooting(
         .map(|t| t.translation.truncate())
         .unwrap_or(Vec2::ZERO);
 
-    for (transform, mut weapon, ai) in query.iter_mut() {
-        if !ai.active {
+    for (entity, transform, mut weapon, ai, tactics, status, aura) in query.iter_mut() {
+        // Rammers and volley gunships hold their own fire
+        if !ai.active || tactics.is_some_and(|t| !t.tactic.fires_freely()) {
             continue;
         }
 
-        weapon.cooldown -= dt;
-        if weapon.cooldown <= 0.0 {
+        // EMP slows weapon cycling, a fire-rate aura speeds it up
+        let rate = status.map(|s| s.fire_rate_mult()).unwrap_or(1.0) * aura_fire_rate_mult(aura);
+        weapon.cooldown -= dt * rate;
+        let pos = transform.translation.truncate();
+        let attack = weapon.attack;
+
+        // Pull the trigger once the cycle is up and the doctrine's range
+        // is met; blaster boats keep the charge until they close in
+        if weapon.burst_left == 0 {
+            if weapon.cooldown > 0.0 || !attack.in_range(pos.distance(player_pos)) {
+                continue;
+            }
             weapon.cooldown = 1.0 / weapon.fire_rate;
+            weapon.burst_left = attack.burst.max(1);
+            weapon.burst_timer = 0.0;
+        }
 
-            let pos = transform.translation.truncate();
-            let dir = (player_pos - pos).normalize_or_zero();
+        weapon.burst_timer -= dt * rate;
+        if weapon.burst_timer > 0.0 {
+            continue;
+        }
+        let shot = attack.burst.max(1) - weapon.burst_left;
+        weapon.burst_left -= 1;
+        weapon.burst_timer += attack.burst_gap;
+
+        // Streams rake across the aim line shot by shot
+        let dir = Vec2::from_angle(attack.sweep_angle(shot))
+            .rotate((player_pos - pos).normalize_or_zero());
+
+        // ECM-scrambled fire can't tell friend from foe
+        let mut owner = super::ProjectileOwner::enemy().fired_by(entity);
+        if status.is_some_and(|s| s.has(StatusKind::EmpSlow)) {
+            owner = owner.confused();
+        }
 
-            // Spawn enemy projectile with correct weapon type
-            super::projectile::spawn_enemy_projectile_typed(
-                &mut commands,
-                pos,
-                dir,
-                weapon.damage,
-                weapon.bullet_speed,
-                weapon.weapon_type,
-            );
+        // Spawn enemy projectile with correct weapon type
+        let projectile = super::projectile::spawn_enemy_projectile_typed(
+            &mut commands,
+            pos,
+            dir,
+            weapon.damage * attack.damage_mult,
+            weapon.bullet_speed * attack.speed_mult,
+            weapon.weapon_type,
+            owner,
+        );
+        if attack.homing > 0.0 {
+            commands
+                .entity(projectile)
+                .insert(super::SeekingProjectile {
+                    turn_rate: attack.homing,
+                    acquire_range: ENEMY_MISSILE_ACQUIRE_RANGE,
+                });
         }
     }
 }
 
 /// Remove enemies that go off screen
-fn enemy_bounds_check(mut commands: Commands, query: Query<(Entity, &Transform), With<Enemy>>) {
+fn enemy_bounds_check(
+    mut commands: Commands,
+    playfield: Res<Playfield>,
+    query: Query<(Entity, &Transform), With<Enemy>>,
+) {
     let margin = 100.0;
     for (entity, transform) in query.iter() {
-        let pos = transform.translation;
-        if pos.y < -SCREEN_HEIGHT / 2.0 - margin
-            || pos.y > SCREEN_HEIGHT / 2.0 + margin
-            || pos.x.abs() > SCREEN_WIDTH / 2.0 + margin
-        {
+        if !playfield.contains(transform.translation.truncate(), margin) {
             commands.entity(entity).despawn_recursive();
         }
     }
@@ -495,17 +601,25 @@ pub fn get_ship_rotation_correction(type_id: u32) -> f32 {
     }
 }
 
+/// Per-ship jitter (fire delay, weave phase, AI stagger) seeded from the
+/// spawn position, so runs with seeded spawns replay it too
+pub fn spawn_jitter(position: Vec2) -> fastrand::Rng {
+    fastrand::Rng::with_seed(((position.x.to_bits() as u64) << 32) | position.y.to_bits() as u64)
+}
+
 /// Spawn a single enemy with 3D model, EVE sprite, or fallback color
 pub fn spawn_enemy(
     commands: &mut Commands,
     type_id: u32,
     position: Vec2,
     behavior: EnemyBehavior,
-    sprite: Option<Handle<Image>>,
+    sprite: Option<ShipSprite>,
     _model_cache: Option<&ShipModelCache>,
 ) -> Entity {
     use crate::core::ShipClass;
 
+    let mut jitter = spawn_jitter(position);
+
     // Stats: (name, health, speed, score, ship_class)
     let (name, health, speed, score, ship_class) = match type_id {
         // === AMARR ===
@@ -581,8 +695,13 @@ pub fn spawn_enemy(
             WeaponType::Autocannon => 250.0,      // Fast bullets
             _ => 200.0,
         },
-        cooldown: 0.5 + fastrand::f32() * 1.0, // Random initial delay
+        cooldown: 0.5 + jitter.f32() * 1.0, // Random initial delay
         pattern: FiringPattern::Single,
+        attack: Faction::find_enemy_def(type_id)
+            .map(|def| def.attack)
+            .unwrap_or_default(),
+        burst_left: 0,
+        burst_timer: 0.0,
     };
 
     // Liberation value based on ship class
@@ -607,7 +726,7 @@ pub fn spawn_enemy(
 
     let ai = EnemyAI {
         behavior,
-        phase: fastrand::f32() * std::f32::consts::TAU,
+        phase: jitter.f32() * std::f32::consts::TAU,
         ..default()
     };
 
@@ -621,7 +740,7 @@ pub fn spawn_enemy(
     let total_rotation = base_rotation + correction;
 
     // Use sprites (2D camera compatible)
-    if let Some(texture) = sprite {
+    let entity = if let Some(texture) = sprite {
         commands
             .spawn((
                 Enemy,
@@ -630,9 +749,8 @@ pub fn spawn_enemy(
                 ai,
                 engine_trail,
                 Sprite {
-                    image: texture,
                     custom_size: Some(Vec2::splat(sprite_size)),
-                    ..default()
+                    ..texture.sprite()
                 },
                 Transform::from_xyz(position.x, position.y, LAYER_ENEMIES)
                     .with_rotation(Quat::from_rotation_z(total_rotation)),
@@ -655,7 +773,29 @@ pub fn spawn_enemy(
                 Transform::from_xyz(position.x, position.y, LAYER_ENEMIES),
             ))
             .id()
+    };
+
+    // Generic movers get a tactical brain; archetypes (sniper, kamikaze,
+    // spawner, tank, weaver) keep their scripted roles
+    let generic = matches!(
+        behavior,
+        EnemyBehavior::Linear
+            | EnemyBehavior::Zigzag
+            | EnemyBehavior::Homing
+            | EnemyBehavior::Orbital
+    );
+    if let Some(tactics) = super::EnemyTactics::for_type(type_id, &mut jitter).filter(|_| generic) {
+        commands.entity(entity).insert(tactics);
     }
+
+    // Destroyers and up fly with shield, armor and hull layers
+    if let Some(tank) =
+        super::EnemyTank::for_class(ship_class, health, super::enemy_doctrine(type_id))
+    {
+        commands.entity(entity).insert(tank);
+    }
+
+    entity
 }
 
 /// Spawner update - spawns fighter escorts from Spawner enemies
@@ -665,6 +805,7 @@ fn spawner_update(
     sprite_cache: Option<Res<crate::assets::ShipSpriteCache>>,
     model_cache: Option<Res<ShipModelCache>>,
     mut query: Query<(&Transform, &mut EnemySpawner), With<Enemy>>,
+    mut run_rng: ResMut<RunRng>,
 ) {
     let dt = time.delta_secs();
 
@@ -677,7 +818,7 @@ fn spawner_update(
 
             let pos = transform.translation.truncate();
             // Spawn fighters slightly offset from spawner
-            let offset_x = (fastrand::f32() - 0.5) * 60.0;
+            let offset_x = (run_rng.spawns.f32() - 0.5) * 60.0;
             let spawn_pos = Vec2::new(pos.x + offset_x, pos.y - 30.0);
 
             let sprite = sprite_cache
@@ -701,7 +842,7 @@ fn spawner_update(
 pub fn spawn_kamikaze(
     commands: &mut Commands,
     position: Vec2,
-    sprite: Option<Handle<Image>>,
+    sprite: Option<ShipSprite>,
     model_cache: Option<&ShipModelCache>,
 ) -> Entity {
     let type_id = 589; // Executioner - fast, aggressive
@@ -733,7 +874,7 @@ pub fn spawn_kamikaze(
 pub fn spawn_weaver(
     commands: &mut Commands,
     position: Vec2,
-    sprite: Option<Handle<Image>>,
+    sprite: Option<ShipSprite>,
     model_cache: Option<&ShipModelCache>,
 ) -> Entity {
     let type_id = 602; // Kestrel - agile
@@ -764,7 +905,7 @@ pub fn spawn_weaver(
 pub fn spawn_sniper(
     commands: &mut Commands,
     position: Vec2,
-    sprite: Option<Handle<Image>>,
+    sprite: Option<ShipSprite>,
     model_cache: Option<&ShipModelCache>,
 ) -> Entity {
     let type_id = 603; // Merlin - Caldari, railgun platform
@@ -796,6 +937,7 @@ pub fn spawn_sniper(
         bullet_speed: 400.0, // Fast projectiles
         cooldown: 1.0,
         pattern: FiringPattern::Single,
+        ..default()
     });
 
     entity
@@ -805,7 +947,7 @@ pub fn spawn_sniper(
 pub fn spawn_spawner_enemy(
     commands: &mut Commands,
     position: Vec2,
-    sprite: Option<Handle<Image>>,
+    sprite: Option<ShipSprite>,
     model_cache: Option<&ShipModelCache>,
 ) -> Entity {
     let type_id = 593; // Tristan - drone boat
@@ -845,7 +987,7 @@ pub fn spawn_spawner_enemy(
 pub fn spawn_tank(
     commands: &mut Commands,
     position: Vec2,
-    sprite: Option<Handle<Image>>,
+    sprite: Option<ShipSprite>,
     model_cache: Option<&ShipModelCache>,
 ) -> Entity {
     let type_id = 597; // Punisher - heavily armored
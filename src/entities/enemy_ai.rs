@@ -0,0 +1,523 @@
+//! Enemy Tactical AI
+//!
+//! Utility AI layered over `EnemyBehavior`. Every think tick each ship scores
+//! its tactics (flank, retreat, escort, kamikaze, volley) against its
+//! `AiProfile` and the situation, then steers accordingly. `Patrol` hands
+//! movement back to the ship's base behavior pattern.
+
+#![allow(dead_code)]
+
+use super::{Boss, Enemy, EnemyStats, Player, PowerupEffects, ShipStats};
+use crate::core::*;
+use bevy::prelude::*;
+
+/// Seconds between tactic re-evaluations
+const THINK_INTERVAL: f32 = 0.4;
+
+/// Bonus for keeping the current tactic (stops flip-flopping)
+const TACTIC_HYSTERESIS: f32 = 0.15;
+
+/// Baseline utility of just following the movement pattern
+const PATROL_UTILITY: f32 = 0.3;
+
+/// After this long on screen ships give up and resume their pattern
+const MAX_ENGAGEMENT: f32 = 14.0;
+
+/// Bosses further than this don't attract escorts
+const ESCORT_RANGE: f32 = 500.0;
+
+/// Escort orbit radius around the boss
+const ESCORT_RADIUS: f32 = 130.0;
+
+/// Time between coordinated volleys
+const VOLLEY_INTERVAL: f32 = 3.0;
+
+/// Gunships needed before a firing line forms
+const VOLLEY_MIN_SHIPS: usize = 2;
+
+/// Kamikaze detonation radius and damage
+const RAM_RADIUS: f32 = 30.0;
+const RAM_DAMAGE: f32 = 20.0;
+
+/// Enemy AI plugin
+pub struct EnemyAiPlugin;
+
+impl Plugin for EnemyAiPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<VolleyCoordinator>()
+            .add_systems(
+                Update,
+                (enemy_ai_think, volley_fire, kamikaze_detonation)
+                    .chain()
+                    .in_set(GameSet::Simulation)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(OnEnter(GameState::Playing), reset_volley_coordinator);
+    }
+}
+
+// =============================================================================
+// TACTICS
+// =============================================================================
+
+/// What a ship is currently trying to do
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Tactic {
+    /// Follow the base movement pattern
+    #[default]
+    Patrol,
+    /// Swing out to the player's side
+    Flank,
+    /// Pull back to the top of the screen
+    Retreat,
+    /// Orbit the nearest boss
+    Escort,
+    /// Ram the player
+    Kamikaze,
+    /// Hold the firing line and shoot in sync
+    Volley,
+}
+
+impl Tactic {
+    pub const ALL: [Tactic; 6] = [
+        Tactic::Patrol,
+        Tactic::Flank,
+        Tactic::Retreat,
+        Tactic::Escort,
+        Tactic::Kamikaze,
+        Tactic::Volley,
+    ];
+
+    /// Whether the ship's own weapon cycle should fire
+    pub fn fires_freely(&self) -> bool {
+        !matches!(self, Tactic::Kamikaze | Tactic::Volley)
+    }
+}
+
+/// Situation as seen by one ship
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AiContext {
+    /// Current / max health
+    pub health_frac: f32,
+    pub player_distance: f32,
+    /// A boss is within escort range
+    pub boss_near: bool,
+    /// Other volley-capable ships on the field
+    pub volley_partners: usize,
+    /// Seconds since the ship engaged
+    pub engaged: f32,
+}
+
+/// Utility of a tactic for this profile in this situation
+pub fn score_tactic(profile: &AiProfile, tactic: Tactic, ctx: &AiContext) -> f32 {
+    match tactic {
+        Tactic::Patrol => PATROL_UTILITY,
+        Tactic::Flank => {
+            let range = if ctx.player_distance > 180.0 {
+                0.8
+            } else {
+                0.5
+            };
+            profile.flank * range
+        }
+        Tactic::Retreat => {
+            if ctx.health_frac < profile.retreat_health {
+                1.2
+            } else {
+                0.0
+            }
+        }
+        Tactic::Escort => {
+            if ctx.boss_near {
+                profile.escort * 0.9
+            } else {
+                0.0
+            }
+        }
+        Tactic::Kamikaze => {
+            let hurt = if ctx.health_frac < 0.5 { 1.1 } else { 0.35 };
+            let close = if ctx.player_distance < 300.0 {
+                1.0
+            } else {
+                0.7
+            };
+            profile.kamikaze * hurt * close
+        }
+        Tactic::Volley => {
+            if ctx.volley_partners >= VOLLEY_MIN_SHIPS {
+                profile.volley * 0.85
+            } else {
+                0.0
+            }
+        }
+    }
+}
+
+/// Pick the highest-utility tactic, favouring the current one slightly
+pub fn choose_tactic(profile: &AiProfile, ctx: &AiContext, current: Tactic) -> Tactic {
+    // Committed rammers don't change their minds
+    if current == Tactic::Kamikaze {
+        return current;
+    }
+    if ctx.engaged > MAX_ENGAGEMENT {
+        return Tactic::Patrol;
+    }
+
+    let mut best = Tactic::Patrol;
+    let mut best_score = f32::MIN;
+    for tactic in Tactic::ALL {
+        let mut score = score_tactic(profile, tactic, ctx);
+        if tactic == current {
+            score += TACTIC_HYSTERESIS;
+        }
+        if score > best_score {
+            best = tactic;
+            best_score = score;
+        }
+    }
+    best
+}
+
+/// Tactical brain attached to enemies
+#[derive(Component, Debug, Clone)]
+pub struct EnemyTactics {
+    pub profile: AiProfile,
+    pub tactic: Tactic,
+    /// Desired velocity this frame (None = use base behavior)
+    pub steer: Option<Vec2>,
+    think_timer: f32,
+    /// Which side of the player to flank (-1 / 1)
+    side: f32,
+    /// Seconds since entering the playfield
+    engaged: f32,
+}
+
+impl EnemyTactics {
+    pub fn new(profile: AiProfile, rng: &mut fastrand::Rng) -> Self {
+        Self {
+            profile,
+            tactic: Tactic::Patrol,
+            steer: None,
+            // Stagger so a wave doesn't all think on the same frame
+            think_timer: rng.f32() * THINK_INTERVAL,
+            side: if rng.bool() { 1.0 } else { -1.0 },
+            engaged: 0.0,
+        }
+    }
+
+    /// Tactics for a freshly spawned ship, if its type has a profile
+    pub fn for_type(type_id: u32, rng: &mut fastrand::Rng) -> Option<Self> {
+        Faction::find_enemy_def(type_id)
+            .map(|def| def.ai)
+            .filter(|ai| *ai != AiProfile::PASSIVE)
+            .map(|ai| Self::new(ai, rng))
+    }
+}
+
+/// Shared clock for coordinated volley fire
+#[derive(Resource, Debug)]
+pub struct VolleyCoordinator {
+    pub timer: f32,
+}
+
+impl Default for VolleyCoordinator {
+    fn default() -> Self {
+        Self {
+            timer: VOLLEY_INTERVAL,
+        }
+    }
+}
+
+// =============================================================================
+// SYSTEMS
+// =============================================================================
+
+fn reset_volley_coordinator(mut coordinator: ResMut<VolleyCoordinator>) {
+    *coordinator = VolleyCoordinator::default();
+}
+
+/// Re-evaluate tactics and compute steering
+fn enemy_ai_think(
+    time: Res<Time>,
+    playfield: Res<Playfield>,
+    player_query: Query<&Transform, With<Player>>,
+    boss_query: Query<&Transform, (With<Boss>, Without<Enemy>)>,
+    mut enemy_query: Query<(&Transform, &EnemyStats, &mut EnemyTactics), With<Enemy>>,
+) {
+    let dt = time.delta_secs();
+    let Ok(player) = player_query.get_single() else {
+        return;
+    };
+    let player_pos = player.translation.truncate();
+
+    // Bosses: dedicated boss entities plus boss-flagged enemies
+    let mut bosses: Vec<Vec2> = boss_query
+        .iter()
+        .map(|t| t.translation.truncate())
+        .collect();
+    let mut volley_ships: usize = 0;
+    for (transform, stats, tactics) in enemy_query.iter() {
+        if stats.is_boss {
+            bosses.push(transform.translation.truncate());
+        }
+        if tactics.profile.volley > 0.0 {
+            volley_ships += 1;
+        }
+    }
+
+    let top = playfield.half_height();
+    for (transform, stats, mut tactics) in enemy_query.iter_mut() {
+        let pos = transform.translation.truncate();
+
+        // Don't engage until on screen
+        if pos.y > top - 40.0 && tactics.engaged == 0.0 {
+            tactics.steer = None;
+            continue;
+        }
+        tactics.engaged += dt;
+
+        let boss = bosses
+            .iter()
+            .copied()
+            .filter(|b| b.distance(pos) > 1.0) // not ourselves
+            .min_by(|a, b| a.distance(pos).total_cmp(&b.distance(pos)));
+
+        tactics.think_timer -= dt;
+        if tactics.think_timer <= 0.0 {
+            tactics.think_timer = THINK_INTERVAL;
+            let ctx = AiContext {
+                health_frac: stats.health / stats.max_health.max(1.0),
+                player_distance: pos.distance(player_pos),
+                boss_near: boss.is_some_and(|b| b.distance(pos) < ESCORT_RANGE),
+                volley_partners: volley_ships.saturating_sub(1),
+                engaged: tactics.engaged,
+            };
+            let next = choose_tactic(&tactics.profile, &ctx, tactics.tactic);
+            if next != tactics.tactic {
+                if next == Tactic::Flank {
+                    // Flank whichever side we're already on
+                    let dx = pos.x - player_pos.x;
+                    if dx.abs() > 10.0 {
+                        tactics.side = dx.signum();
+                    }
+                }
+                tactics.tactic = next;
+            }
+        }
+
+        let speed = stats.speed;
+        let arrive = |target: Vec2, max_speed: f32| {
+            let to = target - pos;
+            to.normalize_or_zero() * max_speed * (to.length() / 60.0).min(1.0)
+        };
+
+        tactics.steer = match tactics.tactic {
+            Tactic::Patrol => None,
+            Tactic::Flank => {
+                let target = player_pos + Vec2::new(tactics.side * 200.0, 140.0);
+                Some(arrive(target, speed * 1.2))
+            }
+            Tactic::Retreat => {
+                let target = Vec2::new(pos.x, top - 60.0);
+                let drift = Vec2::X * (tactics.engaged * 1.5).sin() * speed * 0.3;
+                Some(arrive(target, speed * 1.1) + drift)
+            }
+            Tactic::Escort => boss.map(|b| {
+                let angle = tactics.side * tactics.engaged * 0.8;
+                let target = b + Vec2::new(angle.cos(), angle.sin()) * ESCORT_RADIUS;
+                arrive(target, speed * 1.3)
+            }),
+            Tactic::Kamikaze => Some((player_pos - pos).normalize_or_zero() * speed * 2.2),
+            Tactic::Volley => {
+                // Spread out along a firing line above the player
+                let x = (pos.x + (player_pos.x - pos.x) * 0.2).clamp(-320.0, 320.0);
+                Some(arrive(Vec2::new(x, top - 150.0), speed * 0.7))
+            }
+        };
+    }
+}
+
+/// Gunships on the firing line shoot together on a shared clock
+fn volley_fire(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut coordinator: ResMut<VolleyCoordinator>,
+    player_query: Query<&Transform, With<Player>>,
+    enemy_query: Query<(Entity, &Transform, &super::EnemyWeapon, &EnemyTactics), With<Enemy>>,
+) {
+    coordinator.timer -= time.delta_secs();
+    if coordinator.timer > 0.0 {
+        return;
+    }
+    coordinator.timer = VOLLEY_INTERVAL;
+
+    let Ok(player) = player_query.get_single() else {
+        return;
+    };
+    let player_pos = player.translation.truncate();
+
+    let mut shooters = 0;
+    for (entity, transform, weapon, tactics) in enemy_query.iter() {
+        if tactics.tactic != Tactic::Volley {
+            continue;
+        }
+        let pos = transform.translation.truncate();
+        let aim = (player_pos - pos).normalize_or_zero();
+        for spread in [-0.15_f32, 0.0, 0.15] {
+            super::projectile::spawn_enemy_projectile_typed(
+                &mut commands,
+                pos,
+                Vec2::from_angle(spread).rotate(aim),
+                weapon.damage,
+                weapon.bullet_speed,
+                weapon.weapon_type,
+                super::ProjectileOwner::enemy().fired_by(entity),
+            );
+        }
+        shooters += 1;
+    }
+    if shooters > 0 {
+        debug!("Coordinated volley from {} ships", shooters);
+    }
+}
+
+/// Rammers blow up on contact with the player
+#[allow(clippy::type_complexity)]
+fn kamikaze_detonation(
+    mut commands: Commands,
+    enemy_query: Query<(Entity, &Transform, &EnemyTactics, &EnemyStats), With<Enemy>>,
+    mut player_query: Query<
+        (
+            &Transform,
+            &mut ShipStats,
+            &PowerupEffects,
+            &crate::systems::ManeuverState,
+        ),
+        With<Player>,
+    >,
+    mut damage_events: EventWriter<PlayerDamagedEvent>,
+    mut explosion_events: EventWriter<ExplosionEvent>,
+    mut destroyed_events: EventWriter<PlayerDestroyedEvent>,
+) {
+    let Ok((player, mut stats, powerups, maneuver)) = player_query.get_single_mut() else {
+        return;
+    };
+    let player_pos = player.translation.truncate();
+
+    for (entity, transform, tactics, enemy) in enemy_query.iter() {
+        let pos = transform.translation.truncate();
+        if tactics.tactic != Tactic::Kamikaze || pos.distance(player_pos) > RAM_RADIUS {
+            continue;
+        }
+
+        commands.entity(entity).despawn_recursive();
+        explosion_events.send(ExplosionEvent {
+            position: pos,
+            size: ExplosionSize::Medium,
+            color: Color::srgb(1.0, 0.4, 0.1),
+        });
+
+        if powerups.is_invulnerable() || maneuver.invincible {
+            continue;
+        }
+        let layers = stats.absorb_damage(RAM_DAMAGE, DamageType::Explosive);
+        damage_events.send(PlayerDamagedEvent {
+            damage: RAM_DAMAGE,
+            damage_type: DamageType::Explosive,
+            source_position: pos,
+            source: format!("{} (ram)", enemy.name),
+            layers,
+        });
+        if stats.hull <= 0.0 {
+            info!("Player destroyed by a rammer!");
+            destroyed_events.send(PlayerDestroyedEvent {
+                position: player_pos,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ctx() -> AiContext {
+        AiContext {
+            health_frac: 1.0,
+            player_distance: 400.0,
+            boss_near: false,
+            volley_partners: 0,
+            engaged: 1.0,
+        }
+    }
+
+    #[test]
+    fn profiles_pick_their_signature_tactic() {
+        let c = ctx();
+        assert_eq!(
+            choose_tactic(&AiProfile::SKIRMISHER, &c, Tactic::Patrol),
+            Tactic::Flank
+        );
+        assert_eq!(
+            choose_tactic(&AiProfile::PASSIVE, &c, Tactic::Patrol),
+            Tactic::Patrol
+        );
+
+        // Gunships only form a line with company
+        assert_eq!(
+            choose_tactic(&AiProfile::GUNLINE, &c, Tactic::Patrol),
+            Tactic::Patrol
+        );
+        let crowded = AiContext {
+            volley_partners: 3,
+            ..c
+        };
+        assert_eq!(
+            choose_tactic(&AiProfile::GUNLINE, &crowded, Tactic::Patrol),
+            Tactic::Volley
+        );
+
+        let boss = AiContext {
+            boss_near: true,
+            ..c
+        };
+        assert_eq!(
+            choose_tactic(&AiProfile::ESCORT, &boss, Tactic::Patrol),
+            Tactic::Escort
+        );
+    }
+
+    #[test]
+    fn damaged_ships_retreat_or_ram() {
+        let hurt = AiContext {
+            health_frac: 0.2,
+            player_distance: 200.0,
+            ..ctx()
+        };
+        assert_eq!(
+            choose_tactic(&AiProfile::GUNLINE, &hurt, Tactic::Volley),
+            Tactic::Retreat
+        );
+        assert_eq!(
+            choose_tactic(&AiProfile::RAIDER, &hurt, Tactic::Flank),
+            Tactic::Kamikaze
+        );
+        // Once committed, a rammer stays committed
+        assert_eq!(
+            choose_tactic(&AiProfile::RAIDER, &ctx(), Tactic::Kamikaze),
+            Tactic::Kamikaze
+        );
+    }
+
+    #[test]
+    fn ships_disengage_after_max_engagement() {
+        let late = AiContext {
+            engaged: MAX_ENGAGEMENT + 1.0,
+            ..ctx()
+        };
+        assert_eq!(
+            choose_tactic(&AiProfile::SKIRMISHER, &late, Tactic::Flank),
+            Tactic::Patrol
+        );
+    }
+}
@@ -0,0 +1,431 @@
+//! Enemy Tank Layers
+//!
+//! Destroyers and anything bigger carry the same shield, armor and hull
+//! layers as the player's ship instead of one health pool. Each layer
+//! resists damage types the EVE way (shields shrug off explosive, armor
+//! shrugs off EM), and the hull's faction decides how it recovers: shield
+//! doctrines recharge once out of fire, armor doctrines run a repairer
+//! through the fight. Three thin bars over the hull show what's left.
+//!
+//! `EnemyStats::health` stays the sum of the layers, so anything that only
+//! knows the single pool (status effects, wrecks, drones) still works; the
+//! layers catch up with it every frame.
+
+#![allow(dead_code)]
+
+use crate::core::*;
+use bevy::prelude::*;
+
+use super::{Enemy, EnemyStats};
+
+/// Seconds out of fire before shields start recharging
+pub const ENEMY_SHIELD_DELAY: f32 = 2.0;
+
+/// Width of the layer bars (pixels)
+const BAR_WIDTH: f32 = 36.0;
+
+/// Height of one layer bar (pixels)
+const BAR_HEIGHT: f32 = 2.5;
+
+/// Gap above the hull sprite (pixels)
+const BAR_MARGIN: f32 = 6.0;
+
+/// Layer bar colours, matching the player HUD
+const SHIELD_COLOR: Color = Color::srgb(0.3, 0.6, 1.0);
+const ARMOR_COLOR: Color = Color::srgb(1.0, 0.6, 0.2);
+const HULL_COLOR: Color = Color::srgb(0.85, 0.85, 0.85);
+const BAR_BACK_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.6);
+
+/// Enemy tank layers plugin
+pub struct EnemyTankPlugin;
+
+impl Plugin for EnemyTankPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                spawn_tank_bars,
+                sync_enemy_tank,
+                regenerate_enemy_tank,
+                update_tank_bars,
+            )
+                .chain()
+                .in_set(GameSet::Simulation)
+                .run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight))),
+        );
+    }
+}
+
+/// Which layer a bar shows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TankLayer {
+    Shield,
+    Armor,
+    Hull,
+}
+
+impl TankLayer {
+    pub const ALL: [TankLayer; 3] = [TankLayer::Shield, TankLayer::Armor, TankLayer::Hull];
+
+    /// Share of incoming damage of each type the layer shrugs off
+    pub fn resistance(&self, damage_type: DamageType) -> f32 {
+        match (self, damage_type) {
+            (TankLayer::Shield, DamageType::EM) => 0.0,
+            (TankLayer::Shield, DamageType::Thermal) => 0.2,
+            (TankLayer::Shield, DamageType::Kinetic) => 0.4,
+            (TankLayer::Shield, DamageType::Explosive) => 0.5,
+            (TankLayer::Armor, DamageType::EM) => 0.5,
+            (TankLayer::Armor, DamageType::Thermal) => 0.35,
+            (TankLayer::Armor, DamageType::Kinetic) => 0.25,
+            (TankLayer::Armor, DamageType::Explosive) => 0.1,
+            (TankLayer::Hull, _) => 0.0,
+        }
+    }
+
+    fn color(&self) -> Color {
+        match self {
+            TankLayer::Shield => SHIELD_COLOR,
+            TankLayer::Armor => ARMOR_COLOR,
+            TankLayer::Hull => HULL_COLOR,
+        }
+    }
+}
+
+/// Shield, armor and hull of a destroyer-or-bigger enemy
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct EnemyTank {
+    pub doctrine: TankDoctrine,
+    pub shield: f32,
+    pub max_shield: f32,
+    pub armor: f32,
+    pub max_armor: f32,
+    pub hull: f32,
+    pub max_hull: f32,
+    /// Counts down to shield recharge after taking fire
+    pub shield_timer: f32,
+}
+
+impl EnemyTank {
+    /// Split `health` across the layers the doctrine favours
+    pub fn new(health: f32, doctrine: TankDoctrine) -> Self {
+        let (shield, armor) = match doctrine {
+            TankDoctrine::Shield => (0.5, 0.2),
+            TankDoctrine::Armor => (0.2, 0.5),
+            TankDoctrine::Speed => (0.35, 0.3),
+        };
+        let mut tank = Self {
+            doctrine,
+            shield: 0.0,
+            max_shield: health * shield,
+            armor: 0.0,
+            max_armor: health * armor,
+            hull: 0.0,
+            max_hull: health * (1.0 - shield - armor),
+            shield_timer: 0.0,
+        };
+        tank.refill();
+        tank
+    }
+
+    /// Layers for a hull, if it's big enough to carry them
+    pub fn for_class(class: ShipClass, health: f32, doctrine: TankDoctrine) -> Option<Self> {
+        matches!(
+            class,
+            ShipClass::Destroyer
+                | ShipClass::TacticalDestroyer
+                | ShipClass::Cruiser
+                | ShipClass::Battlecruiser
+                | ShipClass::Battleship
+        )
+        .then(|| Self::new(health, doctrine))
+    }
+
+    pub fn total(&self) -> f32 {
+        self.shield + self.armor + self.hull
+    }
+
+    pub fn max_total(&self) -> f32 {
+        self.max_shield + self.max_armor + self.max_hull
+    }
+
+    pub fn refill(&mut self) {
+        self.shield = self.max_shield;
+        self.armor = self.max_armor;
+        self.hull = self.max_hull;
+    }
+
+    pub fn fraction(&self, layer: TankLayer) -> f32 {
+        let (current, max) = match layer {
+            TankLayer::Shield => (self.shield, self.max_shield),
+            TankLayer::Armor => (self.armor, self.max_armor),
+            TankLayer::Hull => (self.hull, self.max_hull),
+        };
+        if max > 0.0 {
+            (current / max).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// Run `damage` through shield, armor then hull, each resisting by
+    /// type. Returns the hit points actually taken off.
+    pub fn absorb(&mut self, damage: f32, damage_type: DamageType) -> f32 {
+        self.shield_timer = ENEMY_SHIELD_DELAY;
+        let mut remaining = damage;
+        let mut taken = 0.0;
+        for layer in TankLayer::ALL {
+            if remaining <= 0.0 {
+                break;
+            }
+            let resist = layer.resistance(damage_type);
+            let pool = match layer {
+                TankLayer::Shield => &mut self.shield,
+                TankLayer::Armor => &mut self.armor,
+                TankLayer::Hull => &mut self.hull,
+            };
+            let effective = remaining * (1.0 - resist);
+            let hit = effective.min(*pool);
+            *pool -= hit;
+            taken += hit;
+            // Whatever the layer couldn't hold spills on at full strength
+            remaining = if effective > hit {
+                (effective - hit) / (1.0 - resist)
+            } else {
+                0.0
+            };
+        }
+        taken
+    }
+
+    /// Strip the outer layers down to `health` after damage that went
+    /// straight to the pool
+    pub fn settle(&mut self, health: f32) {
+        let mut excess = self.total() - health.max(0.0);
+        for pool in [&mut self.shield, &mut self.armor, &mut self.hull] {
+            if excess <= 0.0 {
+                break;
+            }
+            let cut = excess.min(*pool);
+            *pool -= cut;
+            excess -= cut;
+        }
+    }
+
+    /// Scale the layers to a new maximum (elite and mutator buffs land after
+    /// spawn), keeping how damaged each one is
+    pub fn rescale(&mut self, max_health: f32) {
+        let max_total = self.max_total();
+        if max_total <= 0.0 {
+            return;
+        }
+        let scale = max_health / max_total;
+        for (pool, max) in [
+            (&mut self.shield, &mut self.max_shield),
+            (&mut self.armor, &mut self.max_armor),
+            (&mut self.hull, &mut self.max_hull),
+        ] {
+            *pool *= scale;
+            *max *= scale;
+        }
+    }
+
+    /// Recover by doctrine over `dt`: shields recharge once out of fire,
+    /// armor repairers cycle throughout, hulls never mend
+    pub fn regenerate(&mut self, dt: f32) {
+        if self.shield_timer > 0.0 {
+            self.shield_timer -= dt;
+        } else {
+            let rate = match self.doctrine {
+                TankDoctrine::Shield => 0.12,
+                TankDoctrine::Speed => 0.06,
+                TankDoctrine::Armor => 0.03,
+            };
+            self.shield = (self.shield + self.max_shield * rate * dt).min(self.max_shield);
+        }
+        if self.doctrine == TankDoctrine::Armor && self.armor > 0.0 {
+            self.armor = (self.armor + self.max_armor * 0.05 * dt).min(self.max_armor);
+        }
+    }
+}
+
+/// Tank doctrine of the faction that flies `type_id`
+pub fn enemy_doctrine(type_id: u32) -> TankDoctrine {
+    Faction::all()
+        .iter()
+        .find(|f| f.enemy_ships().iter().any(|e| e.type_id == type_id))
+        .map_or(TankDoctrine::Armor, |f| f.tank_type())
+}
+
+/// Layer bars over a hull; turned back upright every frame
+#[derive(Component, Debug)]
+pub struct EnemyTankBar {
+    /// Height above the hull's centre
+    pub offset: f32,
+}
+
+/// One layer's fill
+#[derive(Component, Debug)]
+pub struct EnemyTankFill(pub TankLayer);
+
+fn spawn_tank_bars(
+    mut commands: Commands,
+    enemies: Query<(Entity, Option<&Sprite>), (Added<EnemyTank>, With<Enemy>)>,
+) {
+    for (entity, sprite) in enemies.iter() {
+        let size = sprite.and_then(|s| s.custom_size).map_or(40.0, |s| s.y);
+        let offset = size * 0.5 + BAR_MARGIN;
+        commands.entity(entity).with_children(|parent| {
+            parent
+                .spawn((
+                    EnemyTankBar { offset },
+                    Transform::from_xyz(0.0, offset, 1.0),
+                    Visibility::default(),
+                ))
+                .with_children(|bar| {
+                    for (row, layer) in TankLayer::ALL.into_iter().enumerate() {
+                        let y = (1.0 - row as f32) * (BAR_HEIGHT + 1.0);
+                        bar.spawn((
+                            Sprite {
+                                color: BAR_BACK_COLOR,
+                                custom_size: Some(Vec2::new(BAR_WIDTH, BAR_HEIGHT)),
+                                ..default()
+                            },
+                            Transform::from_xyz(0.0, y, 0.0),
+                        ));
+                        bar.spawn((
+                            EnemyTankFill(layer),
+                            Sprite {
+                                color: layer.color(),
+                                custom_size: Some(Vec2::new(BAR_WIDTH, BAR_HEIGHT)),
+                                ..default()
+                            },
+                            Transform::from_xyz(0.0, y, 0.1),
+                        ));
+                    }
+                });
+        });
+    }
+}
+
+/// Keep the layers in step with the health pool: rescale after buffs,
+/// strip them after damage that skipped them
+fn sync_enemy_tank(mut enemies: Query<(&EnemyStats, &mut EnemyTank), With<Enemy>>) {
+    for (stats, mut tank) in enemies.iter_mut() {
+        if (tank.max_total() - stats.max_health).abs() > 0.01 {
+            tank.rescale(stats.max_health);
+        }
+        if tank.total() > stats.health + 0.01 {
+            tank.settle(stats.health);
+        } else if stats.health >= stats.max_health && tank.total() < tank.max_total() {
+            // Topped up to full by a spawn-time buff
+            tank.refill();
+        }
+    }
+}
+
+fn regenerate_enemy_tank(
+    time: Res<Time>,
+    mut enemies: Query<(&mut EnemyStats, &mut EnemyTank), With<Enemy>>,
+) {
+    let dt = time.delta_secs();
+    for (mut stats, mut tank) in enemies.iter_mut() {
+        if stats.health <= 0.0 {
+            continue;
+        }
+        tank.regenerate(dt);
+        stats.health = tank.total().min(stats.max_health);
+    }
+}
+
+fn update_tank_bars(
+    enemies: Query<(&Transform, &EnemyTank, &Children), With<Enemy>>,
+    mut bars: Query<(&EnemyTankBar, &mut Transform, &Children), Without<Enemy>>,
+    mut fills: Query<
+        (&EnemyTankFill, &mut Sprite, &mut Transform),
+        (Without<EnemyTankBar>, Without<Enemy>),
+    >,
+) {
+    for (enemy_transform, tank, children) in enemies.iter() {
+        for &child in children.iter() {
+            let Ok((bar, mut bar_transform, fill_children)) = bars.get_mut(child) else {
+                continue;
+            };
+            // Undo the hull's heading so the bars sit level above it
+            let upright = enemy_transform.rotation.inverse();
+            bar_transform.rotation = upright;
+            bar_transform.translation = upright * Vec3::new(0.0, bar.offset, 1.0);
+
+            for &fill_entity in fill_children.iter() {
+                let Ok((fill, mut sprite, mut transform)) = fills.get_mut(fill_entity) else {
+                    continue;
+                };
+                let width = BAR_WIDTH * tank.fraction(fill.0);
+                sprite.custom_size = Some(Vec2::new(width, BAR_HEIGHT));
+                // Drain from the right
+                transform.translation.x = (width - BAR_WIDTH) * 0.5;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_destroyers_and_up_get_layers() {
+        assert!(EnemyTank::for_class(ShipClass::Frigate, 40.0, TankDoctrine::Armor).is_none());
+        assert!(EnemyTank::for_class(ShipClass::Interceptor, 40.0, TankDoctrine::Armor).is_none());
+        let tank = EnemyTank::for_class(ShipClass::Destroyer, 100.0, TankDoctrine::Shield)
+            .expect("destroyers carry layers");
+        assert!((tank.max_total() - 100.0).abs() < 1e-3);
+        assert!(tank.max_shield > tank.max_armor);
+        assert_eq!(enemy_doctrine(16238), TankDoctrine::Shield);
+    }
+
+    #[test]
+    fn damage_types_matter_per_layer() {
+        let mut em = EnemyTank::new(100.0, TankDoctrine::Armor);
+        let mut explosive = em.clone();
+        // Shields take EM in full and half of explosive
+        assert_eq!(em.absorb(10.0, DamageType::EM), 10.0);
+        assert_eq!(explosive.absorb(10.0, DamageType::Explosive), 5.0);
+
+        // Through the shield and into the armor, where EM is resisted
+        let mut tank = EnemyTank::new(100.0, TankDoctrine::Armor);
+        let taken = tank.absorb(40.0, DamageType::EM);
+        assert_eq!(tank.shield, 0.0);
+        assert!((taken - (20.0 + 10.0)).abs() < 1e-3);
+        assert!((tank.armor - 40.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn shields_wait_out_fire_and_armor_repairs_through_it() {
+        let mut shield = EnemyTank::new(100.0, TankDoctrine::Shield);
+        shield.absorb(30.0, DamageType::EM);
+        let hit = shield.shield;
+        shield.regenerate(1.0);
+        assert_eq!(shield.shield, hit, "still under fire");
+        shield.regenerate(ENEMY_SHIELD_DELAY);
+        shield.regenerate(1.0);
+        assert!(shield.shield > hit);
+
+        let mut armor = EnemyTank::new(100.0, TankDoctrine::Armor);
+        armor.absorb(60.0, DamageType::Explosive);
+        let hit = armor.armor;
+        armor.regenerate(0.5);
+        assert!(armor.armor > hit);
+    }
+
+    #[test]
+    fn layers_follow_the_health_pool() {
+        let mut tank = EnemyTank::new(100.0, TankDoctrine::Speed);
+        tank.settle(50.0);
+        assert_eq!(tank.shield, 0.0);
+        assert!((tank.total() - 50.0).abs() < 1e-3);
+
+        tank.rescale(200.0);
+        assert!((tank.max_total() - 200.0).abs() < 1e-3);
+        assert!((tank.total() - 100.0).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,282 @@
+//! Escape Pod Entities
+//!
+//! Liberated slaves fleeing destroyed transports. Pods drift toward the
+//! nearest screen edge and must survive enemy fire until they extract.
+//! Extracted pods grant their souls plus an escort bonus; destroyed pods
+//! lose them. A transport's souls ride only in its pods - it drops no
+//! liberation pods of its own.
+
+#![allow(dead_code)]
+
+use super::{spawn_liberation_pods, ProjectileDamage, ProjectileOwner, Team};
+use crate::core::*;
+use crate::systems::{check_liberation_milestone, CombatCalloutType, DialogueEvent};
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+/// Transport hulls that launch escape pods when destroyed
+const TRANSPORT_TYPE_IDS: &[u32] = &[
+    20185, // Bestower
+];
+
+/// Maximum souls carried by a single pod
+const SOULS_PER_POD: u32 = 2;
+
+/// Pod hit points (enemy shots deal 8-20 damage)
+const ESCAPE_POD_HEALTH: f32 = 25.0;
+
+/// Pod drift speed toward the extraction edge
+const ESCAPE_POD_SPEED: f32 = 55.0;
+
+/// Pod hit radius for enemy projectiles
+const ESCAPE_POD_RADIUS: f32 = 10.0;
+
+/// Bonus souls per extracted pod for protecting it
+const EXTRACTION_BONUS_SOULS: u32 = 1;
+
+/// Score per soul extracted
+const EXTRACTION_SCORE_PER_SOUL: u64 = 750;
+
+/// Escape pod carrying liberated souls
+#[derive(Component, Debug, Clone)]
+#[require(StateScope)]
+pub struct EscapePod {
+    /// Souls aboard
+    pub souls: u32,
+    /// Current hit points
+    pub health: f32,
+    /// Drift velocity
+    pub velocity: Vec2,
+    /// Beacon blink phase
+    pub blink: f32,
+}
+
+/// Escape pod plugin
+pub struct EscapePodPlugin;
+
+impl Plugin for EscapePodPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                announce_escape_pods,
+                escape_pod_movement,
+                escape_pod_damage,
+                handle_escape_pod_events,
+            )
+                .chain()
+                .run_if(in_state(GameState::Playing)),
+        )
+        .add_systems(OnExit(GameState::Playing), despawn_escape_pods);
+    }
+}
+
+/// Whether a destroyed enemy should launch escape pods
+pub fn is_transport(type_id: u32) -> bool {
+    TRANSPORT_TYPE_IDS.contains(&type_id)
+}
+
+/// Set a destroyed enemy's souls loose: transports launch escape pods that
+/// need escorting out, anything else scatters liberation pods to collect.
+/// Every kill path goes through here, so a transport's souls are never
+/// counted twice.
+pub fn release_souls(
+    commands: &mut Commands,
+    run_rng: &mut RunRng,
+    position: Vec2,
+    type_id: u32,
+    souls: u32,
+) {
+    if is_transport(type_id) {
+        spawn_escape_pods(commands, position, souls, &mut run_rng.spawns);
+    } else {
+        spawn_liberation_pods(commands, position, souls, &mut run_rng.drops);
+    }
+}
+
+/// Spawn escape pods from a destroyed transport, splitting its souls between
+/// them. Pod speed decides which make it out, so it comes from `rng` (the
+/// run's `spawns` stream); the beacon blink is just for show.
+pub fn spawn_escape_pods(
+    commands: &mut Commands,
+    position: Vec2,
+    souls: u32,
+    rng: &mut fastrand::Rng,
+) {
+    let pod_count = souls.div_ceil(SOULS_PER_POD);
+
+    // Head for whichever side edge is closer
+    let side = if position.x >= 0.0 { 1.0 } else { -1.0 };
+
+    for i in 0..pod_count {
+        let pod_souls = (souls - i * SOULS_PER_POD).min(SOULS_PER_POD);
+
+        // Fan pods out so a single burst can't take them all
+        let spread = (i as f32 - (pod_count - 1) as f32 / 2.0) * 0.35;
+        let direction = Vec2::new(side, -0.25 + spread).normalize_or_zero();
+        let speed = ESCAPE_POD_SPEED * (0.85 + rng.f32() * 0.3);
+
+        commands.spawn((
+            EscapePod {
+                souls: pod_souls,
+                health: ESCAPE_POD_HEALTH,
+                velocity: direction * speed,
+                blink: fastrand::f32() * std::f32::consts::TAU,
+            },
+            Sprite {
+                color: Color::srgb(0.9, 0.85, 0.4), // Amber distress beacon
+                custom_size: Some(Vec2::new(10.0, 14.0)),
+                ..default()
+            },
+            Transform::from_xyz(position.x, position.y, LAYER_COLLECTIBLES),
+        ));
+    }
+}
+
+/// Call out freshly launched pods, once a frame however many transports
+/// went down
+fn announce_escape_pods(
+    launched: Query<(), Added<EscapePod>>,
+    mut dialogue_events: EventWriter<DialogueEvent>,
+) {
+    if !launched.is_empty() {
+        dialogue_events.send(DialogueEvent::combat_callout(
+            CombatCalloutType::EscapePodLaunched,
+        ));
+    }
+}
+
+/// Drift pods toward the edge and extract them once they leave the screen
+fn escape_pod_movement(
+    mut commands: Commands,
+    time: Res<Time>,
+    playfield: Res<Playfield>,
+    mut query: Query<(Entity, &mut Transform, &mut EscapePod, &mut Sprite)>,
+    mut extracted_events: EventWriter<EscapePodExtractedEvent>,
+) {
+    let dt = time.delta_secs();
+    let half_w = playfield.half_width();
+    let half_h = playfield.half_height();
+
+    for (entity, mut transform, mut pod, mut sprite) in query.iter_mut() {
+        transform.translation.x += pod.velocity.x * dt;
+        transform.translation.y += pod.velocity.y * dt;
+
+        // Slow tumble and distress beacon blink
+        transform.rotate_z(dt * 1.5);
+        pod.blink += dt * 6.0;
+        let alpha = pod.blink.sin() * 0.3 + 0.7;
+        sprite.color = sprite.color.with_alpha(alpha);
+
+        let pos = transform.translation;
+        if pos.x.abs() > half_w || pos.y.abs() > half_h {
+            extracted_events.send(EscapePodExtractedEvent {
+                position: pos.truncate(),
+                souls: pod.souls,
+            });
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+/// Enemy projectiles hitting escape pods
+fn escape_pod_damage(
+    mut commands: Commands,
+    projectile_query: Query<(Entity, &Transform, &ProjectileDamage, &ProjectileOwner)>,
+    mut pod_query: Query<(Entity, &Transform, &mut EscapePod)>,
+    mut lost_events: EventWriter<EscapePodLostEvent>,
+    mut explosion_events: EventWriter<ExplosionEvent>,
+) {
+    let hit_radius_sq = (ESCAPE_POD_RADIUS + 3.0) * (ESCAPE_POD_RADIUS + 3.0);
+    // Despawns are deferred, so a shot already spent on one pod is still in
+    // the query for the next
+    let mut spent: HashSet<Entity> = HashSet::new();
+
+    for (pod_entity, pod_transform, mut pod) in pod_query.iter_mut() {
+        let pod_pos = pod_transform.translation.truncate();
+
+        for (proj_entity, proj_transform, proj_damage, owner) in projectile_query.iter() {
+            if !owner.hits(Team::Player) || spent.contains(&proj_entity) {
+                continue;
+            }
+            let proj_pos = proj_transform.translation.truncate();
+            if (proj_pos - pod_pos).length_squared() >= hit_radius_sq {
+                continue;
+            }
+
+            spent.insert(proj_entity);
+            commands.entity(proj_entity).despawn_recursive();
+            pod.health -= proj_damage.damage;
+
+            if pod.health <= 0.0 {
+                lost_events.send(EscapePodLostEvent {
+                    position: pod_pos,
+                    souls: pod.souls,
+                });
+                explosion_events.send(ExplosionEvent {
+                    position: pod_pos,
+                    size: ExplosionSize::Tiny,
+                    color: Color::srgb(0.9, 0.85, 0.4),
+                });
+                commands.entity(pod_entity).despawn_recursive();
+                break;
+            }
+        }
+    }
+}
+
+/// Award or forfeit souls for pods that extracted or were destroyed
+fn handle_escape_pod_events(
+    mut extracted_events: EventReader<EscapePodExtractedEvent>,
+    mut lost_events: EventReader<EscapePodLostEvent>,
+    mut score: ResMut<ScoreSystem>,
+    mut score_events: EventWriter<ScoreEvent>,
+    mut campaign: ResMut<CampaignState>,
+    mut dialogue_events: EventWriter<DialogueEvent>,
+) {
+    let mut any_extracted = false;
+    for event in extracted_events.read() {
+        let souls = event.souls + EXTRACTION_BONUS_SOULS;
+        let old_count = score.souls_liberated;
+        score.souls_liberated += souls;
+        score_events.send(
+            ScoreEvent::new(
+                ScoreCategory::Escort,
+                EXTRACTION_SCORE_PER_SOUL * souls as u64,
+            )
+            .at(event.position),
+        );
+        campaign.mission_souls += souls;
+        any_extracted = true;
+
+        if let Some(milestone) = check_liberation_milestone(old_count, score.souls_liberated) {
+            dialogue_events.send(DialogueEvent::liberation_milestone(milestone));
+            info!("Liberation milestone reached: {} souls!", milestone);
+        }
+        debug!("Escape pod extracted: +{} souls", souls);
+    }
+
+    let mut any_lost = false;
+    for event in lost_events.read() {
+        any_lost = true;
+        debug!("Escape pod destroyed: {} souls lost", event.souls);
+    }
+
+    // One callout per frame at most - losses take precedence
+    if any_lost {
+        dialogue_events.send(DialogueEvent::combat_callout(
+            CombatCalloutType::EscapePodLost,
+        ));
+    } else if any_extracted {
+        dialogue_events.send(DialogueEvent::combat_callout(
+            CombatCalloutType::EscapePodExtracted,
+        ));
+    }
+}
+
+/// Clean up pods when leaving gameplay
+fn despawn_escape_pods(mut commands: Commands, query: Query<Entity, With<EscapePod>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -0,0 +1,569 @@
+//! Mission Target Entities
+//!
+//! Allied assets placed by escort, defend and courier objectives: a
+//! Minmatar transport crossing the screen, a station holding position, and
+//! cargo crates the player ferries to a drop zone. Enemy fire damages the
+//! transport and station; losing one fails its objective.
+//!
+//! Capture objectives place a structure the player takes by staying inside
+//! its radius. Progress pauses while they're outside it or while enemies
+//! are inside (contested); a ring around the structure shows both.
+
+#![allow(dead_code)]
+
+use super::{Enemy, Player, ProjectileDamage, ProjectileOwner, Team};
+use crate::core::*;
+use bevy::prelude::*;
+
+/// Transport hit radius for enemy projectiles
+const TRANSPORT_RADIUS: f32 = 28.0;
+
+/// Station hit radius for enemy projectiles
+const STATION_RADIUS: f32 = 38.0;
+
+/// Height the escorted transport travels at
+const TRANSPORT_LANE_Y: f32 = -SCREEN_HEIGHT * 0.15;
+
+/// Where the defended station sits
+const STATION_POSITION: Vec2 = Vec2::new(0.0, -SCREEN_HEIGHT * 0.25);
+
+/// Where a capture structure sits
+const CAPTURE_POSITION: Vec2 = Vec2::new(0.0, -SCREEN_HEIGHT * 0.1);
+
+/// Capture ring colors: waiting, capturing, contested, taken
+const CAPTURE_IDLE_COLOR: Color = Color::srgb(0.6, 0.65, 0.75);
+const CAPTURE_COLOR: Color = Color::srgb(0.3, 0.9, 0.5);
+const CONTESTED_COLOR: Color = Color::srgb(1.0, 0.35, 0.2);
+const CAPTURED_COLOR: Color = Color::srgb(0.4, 0.8, 1.0);
+
+/// Seconds between cargo crate drops while none is in play
+const CARGO_DROP_INTERVAL: f32 = 6.0;
+
+/// Cargo crate fall speed
+const CARGO_FALL_SPEED: f32 = 40.0;
+
+/// Distance at which the player grabs a crate
+const CARGO_PICKUP_RADIUS: f32 = 32.0;
+
+/// Drop zone size (square)
+const DROP_ZONE_SIZE: f32 = 90.0;
+
+/// Which allied asset an objective protects
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProtectedKind {
+    /// Moves across the screen; the objective completes on arrival
+    Transport,
+    /// Holds position until the defend timer runs out
+    Station,
+}
+
+impl ProtectedKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ProtectedKind::Transport => "MINMATAR TRANSPORT",
+            ProtectedKind::Station => "RELAY STATION",
+        }
+    }
+
+    fn radius(&self) -> f32 {
+        match self {
+            ProtectedKind::Transport => TRANSPORT_RADIUS,
+            ProtectedKind::Station => STATION_RADIUS,
+        }
+    }
+}
+
+/// Allied target that must survive
+#[derive(Component, Debug, Clone)]
+#[require(StateScope)]
+pub struct ProtectedTarget {
+    pub kind: ProtectedKind,
+    /// Index of the owning objective in the `ObjectiveTracker`
+    pub objective: usize,
+    pub health: f32,
+    pub max_health: f32,
+    /// Horizontal speed (transports only)
+    pub speed: f32,
+}
+
+impl ProtectedTarget {
+    pub fn health_fraction(&self) -> f32 {
+        if self.max_health > 0.0 {
+            (self.health / self.max_health).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Structure taken by holding position around it
+#[derive(Component, Debug, Clone)]
+#[require(StateScope)]
+pub struct CaptureStructure {
+    /// Index of the owning objective in the `ObjectiveTracker`
+    pub objective: usize,
+    pub radius: f32,
+    /// Seconds of uncontested presence needed
+    pub duration: f32,
+    /// 0.0 - 1.0
+    pub progress: f32,
+    /// Player is inside the radius
+    pub occupied: bool,
+    /// Enemies are inside the radius
+    pub contested: bool,
+}
+
+impl CaptureStructure {
+    pub fn captured(&self) -> bool {
+        self.progress >= 1.0
+    }
+
+    /// Advance progress by `dt` seconds; only moves while the player holds
+    /// the radius alone
+    pub fn advance(&mut self, dt: f32, occupied: bool, contested: bool) {
+        self.occupied = occupied;
+        self.contested = contested;
+        if occupied && !contested && !self.captured() {
+            self.progress = (self.progress + dt / self.duration.max(1.0)).min(1.0);
+        }
+    }
+}
+
+/// Cargo crate waiting to be picked up
+#[derive(Component, Debug)]
+#[require(StateScope)]
+pub struct CargoCrate;
+
+/// Where carried cargo is delivered
+#[derive(Component, Debug)]
+#[require(StateScope)]
+pub struct DropZone;
+
+/// Courier run state for the current mission
+#[derive(Resource, Debug, Default)]
+pub struct CourierRun {
+    /// Mission has a courier objective
+    pub active: bool,
+    /// Player has a crate aboard
+    pub carrying: bool,
+    /// Time until the next crate drops
+    pub drop_timer: f32,
+}
+
+/// Mission target plugin
+pub struct MissionTargetPlugin;
+
+impl Plugin for MissionTargetPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CourierRun>().add_systems(
+            Update,
+            (
+                spawn_mission_targets,
+                move_transports,
+                protected_target_damage,
+                drop_cargo,
+                courier_pickup_and_delivery,
+                update_captures,
+                draw_capture_rings,
+            )
+                .chain()
+                .in_set(GameSet::Simulation)
+                .run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight))),
+        );
+    }
+}
+
+/// Transport speed that crosses a `width`-wide playfield in `duration` seconds
+pub fn transport_speed(width: f32, duration: f32) -> f32 {
+    width / duration.max(1.0)
+}
+
+/// Place the assets a freshly started objective set needs
+fn spawn_mission_targets(
+    mut commands: Commands,
+    tracker: Res<ObjectiveTracker>,
+    mut courier: ResMut<CourierRun>,
+    playfield: Res<Playfield>,
+    existing: Query<
+        Entity,
+        Or<(
+            With<ProtectedTarget>,
+            With<CargoCrate>,
+            With<DropZone>,
+            With<CaptureStructure>,
+        )>,
+    >,
+    mut spawned_generation: Local<Option<u32>>,
+) {
+    if *spawned_generation == Some(tracker.generation()) {
+        return;
+    }
+    *spawned_generation = Some(tracker.generation());
+
+    for entity in existing.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    *courier = CourierRun::default();
+
+    for (index, objective) in tracker.objectives().iter().enumerate() {
+        match objective.def.kind {
+            ObjectiveKind::Escort { health, duration } => {
+                commands.spawn((
+                    ProtectedTarget {
+                        kind: ProtectedKind::Transport,
+                        objective: index,
+                        health,
+                        max_health: health,
+                        speed: transport_speed(playfield.size.x, duration),
+                    },
+                    Sprite {
+                        color: Color::srgb(0.7, 0.45, 0.3), // Minmatar rust
+                        custom_size: Some(Vec2::new(64.0, 24.0)),
+                        ..default()
+                    },
+                    Transform::from_xyz(-playfield.half_width(), TRANSPORT_LANE_Y, LAYER_ENEMIES),
+                ));
+            }
+            ObjectiveKind::Defend { health, .. } => {
+                commands.spawn((
+                    ProtectedTarget {
+                        kind: ProtectedKind::Station,
+                        objective: index,
+                        health,
+                        max_health: health,
+                        speed: 0.0,
+                    },
+                    Sprite {
+                        color: Color::srgb(0.55, 0.5, 0.45),
+                        custom_size: Some(Vec2::splat(STATION_RADIUS * 2.0)),
+                        ..default()
+                    },
+                    Transform::from_xyz(STATION_POSITION.x, STATION_POSITION.y, LAYER_HAZARDS),
+                ));
+            }
+            ObjectiveKind::Capture { radius, duration } => {
+                commands.spawn((
+                    CaptureStructure {
+                        objective: index,
+                        radius,
+                        duration,
+                        progress: 0.0,
+                        occupied: false,
+                        contested: false,
+                    },
+                    Sprite {
+                        color: CAPTURE_IDLE_COLOR,
+                        custom_size: Some(Vec2::splat(36.0)),
+                        ..default()
+                    },
+                    Transform::from_xyz(CAPTURE_POSITION.x, CAPTURE_POSITION.y, LAYER_HAZARDS)
+                        .with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4)),
+                ));
+            }
+            ObjectiveKind::Courier(_) if !courier.active => {
+                courier.active = true;
+                courier.drop_timer = CARGO_DROP_INTERVAL / 2.0;
+                // Lower left corner of the play area
+                let drop_zone = -playfield.half() + DROP_ZONE_SIZE;
+                commands.spawn((
+                    DropZone,
+                    Sprite {
+                        color: Color::srgba(0.3, 0.9, 0.5, 0.2),
+                        custom_size: Some(Vec2::splat(DROP_ZONE_SIZE)),
+                        ..default()
+                    },
+                    Transform::from_xyz(drop_zone.x, drop_zone.y, LAYER_BACKGROUND + 2.0),
+                ));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Fly transports across the screen and report arrivals
+fn move_transports(
+    mut commands: Commands,
+    time: Res<Time>,
+    playfield: Res<Playfield>,
+    mut query: Query<(Entity, &mut Transform, &ProtectedTarget)>,
+    mut arrived_events: EventWriter<EscortArrivedEvent>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut transform, target) in query.iter_mut() {
+        if target.kind != ProtectedKind::Transport {
+            continue;
+        }
+        transform.translation.x += target.speed * dt;
+
+        if transform.translation.x > playfield.half_width() {
+            arrived_events.send(EscortArrivedEvent {
+                objective: target.objective,
+                position: transform.translation.truncate(),
+            });
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+/// Enemy projectiles hitting transports and stations
+fn protected_target_damage(
+    mut commands: Commands,
+    projectile_query: Query<(Entity, &Transform, &ProjectileDamage, &ProjectileOwner)>,
+    mut target_query: Query<(Entity, &Transform, &mut ProtectedTarget, &mut Sprite)>,
+    mut lost_events: EventWriter<ProtectedTargetLostEvent>,
+    mut explosion_events: EventWriter<ExplosionEvent>,
+) {
+    for (target_entity, target_transform, mut target, mut sprite) in target_query.iter_mut() {
+        let target_pos = target_transform.translation.truncate();
+        let hit_radius_sq = (target.kind.radius() + 3.0).powi(2);
+
+        for (proj_entity, proj_transform, proj_damage, owner) in projectile_query.iter() {
+            if !owner.hits(Team::Player) {
+                continue;
+            }
+            let proj_pos = proj_transform.translation.truncate();
+            if (proj_pos - target_pos).length_squared() >= hit_radius_sq {
+                continue;
+            }
+
+            commands.entity(proj_entity).despawn_recursive();
+            target.health -= proj_damage.damage;
+
+            if target.health <= 0.0 {
+                lost_events.send(ProtectedTargetLostEvent {
+                    objective: target.objective,
+                    position: target_pos,
+                });
+                explosion_events.send(ExplosionEvent {
+                    position: target_pos,
+                    size: ExplosionSize::Large,
+                    color: Color::srgb(0.7, 0.45, 0.3),
+                });
+                commands.entity(target_entity).despawn_recursive();
+                break;
+            }
+        }
+
+        // Darken as the hull fails
+        let shade = 0.5 + 0.5 * target.health_fraction();
+        sprite.color = sprite.color.with_alpha(shade);
+    }
+}
+
+/// Drop a crate from the top whenever none is in play
+fn drop_cargo(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut courier: ResMut<CourierRun>,
+    mut crate_query: Query<(Entity, &mut Transform), With<CargoCrate>>,
+    mut run_rng: ResMut<RunRng>,
+    playfield: Res<Playfield>,
+) {
+    if !courier.active {
+        return;
+    }
+    let dt = time.delta_secs();
+
+    let mut in_play = false;
+    for (entity, mut transform) in crate_query.iter_mut() {
+        transform.translation.y -= CARGO_FALL_SPEED * dt;
+        if transform.translation.y < -playfield.half_height() {
+            commands.entity(entity).despawn_recursive();
+        } else {
+            in_play = true;
+        }
+    }
+    if in_play || courier.carrying {
+        return;
+    }
+
+    courier.drop_timer -= dt;
+    if courier.drop_timer > 0.0 {
+        return;
+    }
+    courier.drop_timer = CARGO_DROP_INTERVAL;
+
+    let x = (run_rng.spawns.f32() - 0.5) * playfield.size.x * 0.7;
+    commands.spawn((
+        CargoCrate,
+        Sprite {
+            color: Color::srgb(0.95, 0.75, 0.3),
+            custom_size: Some(Vec2::new(18.0, 18.0)),
+            ..default()
+        },
+        Transform::from_xyz(x, playfield.half_height(), LAYER_COLLECTIBLES),
+    ));
+}
+
+/// Grab crates on contact and hand them over inside the drop zone
+fn courier_pickup_and_delivery(
+    mut commands: Commands,
+    mut courier: ResMut<CourierRun>,
+    player_query: Query<&Transform, With<Player>>,
+    crate_query: Query<(Entity, &Transform), With<CargoCrate>>,
+    mut zone_query: Query<(&Transform, &mut Sprite), With<DropZone>>,
+    mut delivered_events: EventWriter<CargoDeliveredEvent>,
+) {
+    if !courier.active {
+        return;
+    }
+    let Ok(player_transform) = player_query.get_single() else {
+        return;
+    };
+    let player_pos = player_transform.translation.truncate();
+
+    if !courier.carrying {
+        for (entity, transform) in crate_query.iter() {
+            if transform.translation.truncate().distance(player_pos) < CARGO_PICKUP_RADIUS {
+                courier.carrying = true;
+                commands.entity(entity).despawn_recursive();
+                break;
+            }
+        }
+    }
+
+    for (zone_transform, mut sprite) in zone_query.iter_mut() {
+        // Light the zone up while there's something to deliver
+        let alpha = if courier.carrying { 0.45 } else { 0.2 };
+        sprite.color = sprite.color.with_alpha(alpha);
+
+        let offset = (player_pos - zone_transform.translation.truncate()).abs();
+        if courier.carrying && offset.max_element() < DROP_ZONE_SIZE / 2.0 {
+            courier.carrying = false;
+            delivered_events.send(CargoDeliveredEvent {
+                position: player_pos,
+            });
+        }
+    }
+}
+
+/// Advance captures and report progress to the tracker
+fn update_captures(
+    time: Res<Time>,
+    player_query: Query<&Transform, With<Player>>,
+    enemy_query: Query<&Transform, With<Enemy>>,
+    mut capture_query: Query<(&Transform, &mut CaptureStructure, &mut Sprite)>,
+    mut progress_events: EventWriter<CaptureProgressEvent>,
+) {
+    let player_pos = player_query
+        .get_single()
+        .ok()
+        .map(|t| t.translation.truncate());
+
+    for (transform, mut capture, mut sprite) in capture_query.iter_mut() {
+        if capture.captured() {
+            continue;
+        }
+        let pos = transform.translation.truncate();
+        let radius = capture.radius;
+        let occupied = player_pos.is_some_and(|p| p.distance(pos) <= radius);
+        let contested = enemy_query
+            .iter()
+            .any(|t| t.translation.truncate().distance(pos) <= radius);
+
+        let before = (capture.progress, capture.contested);
+        capture.advance(time.delta_secs(), occupied, contested);
+        if (capture.progress, capture.contested) != before {
+            progress_events.send(CaptureProgressEvent {
+                objective: capture.objective,
+                progress: capture.progress,
+                contested: capture.contested,
+            });
+        }
+
+        sprite.color = if capture.captured() {
+            CAPTURED_COLOR
+        } else if capture.contested {
+            CONTESTED_COLOR
+        } else if capture.occupied {
+            CAPTURE_COLOR
+        } else {
+            CAPTURE_IDLE_COLOR
+        };
+    }
+}
+
+/// Capture radius ring with the progress arc around it
+fn draw_capture_rings(
+    mut gizmos: Gizmos,
+    time: Res<Time>,
+    capture_query: Query<(&Transform, &CaptureStructure)>,
+) {
+    for (transform, capture) in capture_query.iter() {
+        let pos = transform.translation.truncate();
+        let color = if capture.captured() {
+            CAPTURED_COLOR
+        } else if capture.contested {
+            // Blink between warning colors while enemies hold it up
+            let blink = (time.elapsed_secs() * 4.0).fract() < 0.5;
+            if blink {
+                CONTESTED_COLOR
+            } else {
+                Color::srgb(1.0, 0.75, 0.2)
+            }
+        } else if capture.occupied {
+            CAPTURE_COLOR
+        } else {
+            CAPTURE_IDLE_COLOR
+        };
+        gizmos.circle_2d(pos, capture.radius, color.with_alpha(0.35));
+
+        // Sweeps clockwise from twelve o'clock
+        let sweep = std::f32::consts::TAU * capture.progress;
+        if sweep > 0.0 {
+            gizmos.arc_2d(
+                Isometry2d::new(pos, Rot2::radians(-sweep)),
+                sweep,
+                capture.radius + 6.0,
+                color.with_alpha(0.9),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transport_crosses_the_screen_in_its_duration() {
+        let speed = transport_speed(SCREEN_WIDTH, 60.0);
+        assert!((speed * 60.0 - SCREEN_WIDTH).abs() < 0.01);
+        // Nonsense durations still move
+        assert!(transport_speed(SCREEN_WIDTH, 0.0) > 0.0);
+
+        let target = ProtectedTarget {
+            kind: ProtectedKind::Station,
+            objective: 0,
+            health: 150.0,
+            max_health: 300.0,
+            speed: 0.0,
+        };
+        assert_eq!(target.health_fraction(), 0.5);
+    }
+
+    #[test]
+    fn capture_only_advances_while_held_uncontested() {
+        let mut capture = CaptureStructure {
+            objective: 0,
+            radius: 100.0,
+            duration: 10.0,
+            progress: 0.0,
+            occupied: false,
+            contested: false,
+        };
+        capture.advance(5.0, false, false);
+        assert_eq!(capture.progress, 0.0);
+
+        capture.advance(5.0, true, false);
+        assert_eq!(capture.progress, 0.5);
+
+        capture.advance(5.0, true, true);
+        assert_eq!(capture.progress, 0.5);
+        assert!(capture.contested);
+
+        capture.advance(8.0, true, false);
+        assert!(capture.captured());
+        assert_eq!(capture.progress, 1.0);
+    }
+}
@@ -6,17 +6,29 @@ pub mod boss;
 pub mod collectible;
 pub mod drone;
 pub mod enemy;
+pub mod enemy_ai;
+pub mod enemy_tank;
+pub mod escape_pod;
+pub mod mission_target;
 pub mod player;
 pub mod projectile;
+pub mod turret;
 pub mod wingman;
+pub mod wingman_pilot;
 
 pub use boss::*;
 pub use collectible::*;
 pub use drone::*;
 pub use enemy::*;
+pub use enemy_ai::*;
+pub use enemy_tank::*;
+pub use escape_pod::*;
+pub use mission_target::*;
 pub use player::*;
 pub use projectile::*;
+pub use turret::*;
 pub use wingman::*;
+pub use wingman_pilot::*;
 
 use bevy::prelude::*;
 
@@ -28,10 +40,15 @@ impl Plugin for EntitiesPlugin {
         app.add_plugins((
             PlayerPlugin,
             EnemyPlugin,
+            EnemyAiPlugin,
+            EnemyTankPlugin,
             ProjectilePlugin,
             CollectiblePlugin,
             WingmanPlugin,
             DronePlugin,
+            EscapePodPlugin,
+            MissionTargetPlugin,
+            TurretPlugin,
         ));
     }
 }
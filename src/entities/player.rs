@@ -5,7 +5,10 @@
 #![allow(dead_code)]
 
 use crate::core::*;
-use crate::systems::{Ability, AbilityEffects, AbilityType, EngineTrail, ManeuverState};
+use crate::systems::{
+    status_speed_mult, Ability, AbilityEffects, AbilityType, EngineTrail, ManeuverState,
+    StatusEffects,
+};
 use bevy::prelude::*;
 
 /// Marker component for the player entity
@@ -43,6 +46,10 @@ pub struct ShipStats {
     pub capacitor: f32,
     /// Capacitor recharge rate per second
     pub capacitor_recharge: f32,
+    /// Tank doctrine (drives regen/repair behavior)
+    pub doctrine: TankDoctrine,
+    /// Time until the next armor repair cycle
+    pub armor_rep_timer: f32,
 }
 
 impl Default for ShipStats {
@@ -62,6 +69,8 @@ impl Default for ShipStats {
             max_capacitor: CAP_FRIGATE,
             capacitor: CAP_FRIGATE,
             capacitor_recharge: 10.0,
+            doctrine: TankDoctrine::Speed,
+            armor_rep_timer: 0.0,
         }
     }
 }
@@ -102,6 +111,14 @@ impl ShipStats {
 
     /// Take damage with EVE-style damage application order
     pub fn take_damage(&mut self, damage: f32, damage_type: DamageType) -> bool {
+        self.absorb_damage(damage, damage_type);
+        // Return true if ship is destroyed
+        self.hull <= 0.0
+    }
+
+    /// Apply damage through shield, armor and hull, reporting what each
+    /// layer absorbed
+    pub fn absorb_damage(&mut self, damage: f32, damage_type: DamageType) -> DamageLayers {
         // Apply damage type resistances (simplified)
         let resistance = match damage_type {
             DamageType::EM => 0.0, // Shield weak to EM
@@ -111,6 +128,7 @@ impl ShipStats {
         };
 
         let mut remaining = damage * (1.0 - resistance);
+        let mut layers = DamageLayers::default();
 
         // Damage order: Shield -> Armor -> Hull
         if self.shield > 0.0 {
@@ -118,23 +136,25 @@ impl ShipStats {
             self.shield -= shield_damage;
             remaining -= shield_damage;
             self.shield_timer = self.shield_recharge_delay;
+            layers.shield = shield_damage;
         }
 
         if remaining > 0.0 && self.armor > 0.0 {
             let armor_damage = remaining.min(self.armor);
             self.armor -= armor_damage;
             remaining -= armor_damage;
+            layers.armor = armor_damage;
         }
 
         if remaining > 0.0 {
             self.hull -= remaining;
+            layers.hull = remaining;
         }
 
-        // Return true if ship is destroyed
-        self.hull <= 0.0
+        layers
     }
 
-    /// Update shield recharge
+    /// Update shield recharge and armor repair
     pub fn update(&mut self, dt: f32) {
         // Shield recharge after delay
         if self.shield_timer > 0.0 {
@@ -143,6 +163,16 @@ impl ShipStats {
             self.shield = (self.shield + self.shield_recharge * dt).min(self.max_shield);
         }
 
+        // Armor doctrine: repairer cycles while damaged and cap allows
+        if self.doctrine.has_armor_repairer() && self.armor < self.max_armor {
+            self.armor_rep_timer -= dt;
+            if self.armor_rep_timer <= 0.0 && self.capacitor >= ARMOR_REPAIR_CAP_COST {
+                self.capacitor -= ARMOR_REPAIR_CAP_COST;
+                self.armor = (self.armor + ARMOR_REPAIR_AMOUNT).min(self.max_armor);
+                self.armor_rep_timer = ARMOR_REPAIR_CYCLE;
+            }
+        }
+
         // Capacitor recharge
         if self.capacitor < self.max_capacitor {
             self.capacitor =
@@ -270,10 +300,32 @@ impl Plugin for PlayerPlugin {
         app.add_systems(OnEnter(GameState::Playing), spawn_player)
             .add_systems(
                 Update,
-                (player_movement, player_shooting, update_player_stats)
+                (
+                    player_shooting.in_set(GameSet::Input),
+                    update_player_stats.in_set(GameSet::Simulation),
+                )
                     .run_if(in_state(GameState::Playing))
                     .run_if(not_last_stand),
             )
+            .add_systems(
+                FixedUpdate,
+                player_movement
+                    .in_set(GameSet::Simulation)
+                    .run_if(in_state(GameState::Playing))
+                    .run_if(not_last_stand)
+                    .run_if(not(low_latency_mode)),
+            )
+            // Low-latency mode: move the frame the input was polled, before
+            // firing so shots leave from where the ship now is
+            .add_systems(
+                Update,
+                player_movement
+                    .in_set(GameSet::Input)
+                    .before(player_shooting)
+                    .run_if(in_state(GameState::Playing))
+                    .run_if(not_last_stand)
+                    .run_if(low_latency_mode),
+            )
             .add_systems(OnExit(GameState::Playing), despawn_player);
     }
 }
@@ -296,20 +348,21 @@ fn spawn_player(
     // Skip player spawn in Last Stand mode (titan is spawned instead)
     match &last_stand {
         Some(ls) => {
-            info!("spawn_player: LastStandState exists, active={}", ls.active);
+            debug!("spawn_player: LastStandState exists, active={}", ls.active);
             if ls.active {
-                info!("Skipping player spawn - Last Stand mode active");
+                debug!("Skipping player spawn - Last Stand mode active");
                 return;
             }
         }
         None => {
-            info!("spawn_player: LastStandState resource not found");
+            debug!("spawn_player: LastStandState resource not found");
         }
     }
 
     let ship_def = session.selected_ship();
     let faction = session.player_faction;
     let type_id = ship_def.type_id;
+    let doctrine = faction.tank_type();
 
     // Create stats from ship definition
     let stats = ShipStats {
@@ -317,8 +370,8 @@ fn spawn_player(
         name: ship_def.name.to_string(),
         max_shield: ship_def.health * 0.4, // 40% shield
         shield: ship_def.health * 0.4,
-        shield_recharge: PLAYER_SHIELD_RECHARGE_RATE,
-        shield_recharge_delay: PLAYER_SHIELD_RECHARGE_DELAY,
+        shield_recharge: PLAYER_SHIELD_RECHARGE_RATE * doctrine.shield_recharge_mult(),
+        shield_recharge_delay: PLAYER_SHIELD_RECHARGE_DELAY * doctrine.shield_delay_mult(),
         shield_timer: 0.0,
         max_armor: ship_def.health * 0.35, // 35% armor
         armor: ship_def.health * 0.35,
@@ -327,16 +380,24 @@ fn spawn_player(
         max_capacitor: CAP_FRIGATE,
         capacitor: CAP_FRIGATE,
         capacitor_recharge: 10.0,
+        doctrine,
+        armor_rep_timer: 0.0,
     };
 
-    // Create movement from ship speed
+    // Create movement from ship speed (speed tanks get a boost)
+    let speed = ship_def.speed * doctrine.speed_mult();
     let movement = Movement {
         velocity: Vec2::ZERO,
-        max_speed: ship_def.speed,
-        acceleration: ship_def.speed * 3.0, // 3x speed for accel
+        max_speed: speed,
+        acceleration: speed * 3.0, // 3x speed for accel
         friction: 8.0,
     };
 
+    // Speed tanks present a smaller signature
+    let hitbox = Hitbox {
+        radius: PLAYER_HITBOX_SIZE * doctrine.hitbox_mult(),
+    };
+
     // Create weapon from ship stats
     let weapon = Weapon {
         fire_rate: ship_def.fire_rate,
@@ -362,6 +423,14 @@ fn spawn_player(
         engine_trail.offset.y = -engine_trail.offset.y;
     }
 
+    // Turrets from the hull's layout, tracking the fire direction
+    let hardpoints = super::Hardpoints::new(
+        ship_def.hardpoints,
+        player_size,
+        Vec2::Y,
+        super::PLAYER_TURRET_TURN_RATE,
+    );
+
     // Create ability from ship definition
     let ability_type = AbilityType::from_special(ship_def.special);
 
@@ -383,14 +452,15 @@ fn spawn_player(
             weapon,
             Ability::new(ability_type),
             AbilityEffects::default(),
-            Hitbox::default(),
+            hitbox,
             super::collectible::PowerupEffects::default(),
             ManeuverState::default(),
             engine_trail,
+            hardpoints,
             Sprite {
-                image: texture,
+                color: session.selected_skin().tint,
                 custom_size: Some(Vec2::splat(player_size)),
-                ..default()
+                ..texture.sprite()
             },
             Transform::from_xyz(0.0, -250.0, LAYER_PLAYER)
                 .with_rotation(Quat::from_rotation_z(rotation)),
@@ -405,12 +475,13 @@ fn spawn_player(
             weapon,
             Ability::new(ability_type),
             AbilityEffects::default(),
-            Hitbox::default(),
+            hitbox,
             super::collectible::PowerupEffects::default(),
             ManeuverState::default(),
             engine_trail,
+            hardpoints,
             Sprite {
-                color: base_color,
+                color: session.selected_skin().apply(base_color),
                 custom_size: Some(Vec2::new(player_size * 0.85, player_size)),
                 ..default()
             },
@@ -455,10 +526,11 @@ fn player_movement(
     time: Res<Time>,
     keyboard: Res<ButtonInput<KeyCode>>,
     joystick: Res<crate::systems::JoystickState>,
-    mut query: Query<(&mut Transform, &mut Movement), With<Player>>,
+    mut query: Query<(&mut Transform, &mut Movement, Option<&StatusEffects>), With<Player>>,
     berserk: Res<BerserkSystem>,
+    playfield: Res<Playfield>,
 ) {
-    let Ok((mut transform, mut movement)) = query.get_single_mut() else {
+    let Ok((mut transform, mut movement, status)) = query.get_single_mut() else {
         return;
     };
 
@@ -484,7 +556,7 @@ fn player_movement(
     }
 
     let dt = time.delta_secs();
-    let speed_mult = berserk.speed_mult();
+    let speed_mult = berserk.speed_mult() * status_speed_mult(status);
 
     // Apply acceleration
     if input != Vec2::ZERO {
@@ -507,11 +579,10 @@ fn player_movement(
     transform.translation.x += movement.velocity.x * dt;
     transform.translation.y += movement.velocity.y * dt;
 
-    // Clamp to screen bounds
-    let half_width = SCREEN_WIDTH / 2.0 - PLAYER_SPRITE_SIZE / 2.0;
-    let half_height = SCREEN_HEIGHT / 2.0 - PLAYER_SPRITE_SIZE / 2.0;
-    transform.translation.x = transform.translation.x.clamp(-half_width, half_width);
-    transform.translation.y = transform.translation.y.clamp(-half_height, half_height);
+    // Clamp to the playfield
+    let clamped = playfield.clamp(transform.translation.truncate(), PLAYER_SPRITE_SIZE / 2.0);
+    transform.translation.x = clamped.x;
+    transform.translation.y = clamped.y;
 }
 
 /// Player shooting system
@@ -520,12 +591,20 @@ fn player_shooting(
     time: Res<Time>,
     keyboard: Res<ButtonInput<KeyCode>>,
     joystick: Res<crate::systems::JoystickState>,
-    mut query: Query<(&Transform, &mut Weapon, &AbilityEffects), With<Player>>,
+    mut query: Query<
+        (
+            &Transform,
+            &mut Weapon,
+            &AbilityEffects,
+            Option<&mut super::Hardpoints>,
+        ),
+        With<Player>,
+    >,
     mut fire_events: EventWriter<PlayerFireEvent>,
     berserk: Res<BerserkSystem>,
     mut heat_system: ResMut<crate::systems::ComboHeatSystem>,
 ) {
-    let Ok((transform, mut weapon, ability_effects)) = query.get_single_mut() else {
+    let Ok((transform, mut weapon, ability_effects, hardpoints)) = query.get_single_mut() else {
         return;
     };
 
@@ -574,7 +653,7 @@ fn player_shooting(
         // Calculate fire rate with modifiers:
         // - Base fire rate
         // - Berserk bonus (1.5x when active)
-        // - Heat penalty (0.7x when overheated)
+        // - Heat penalty (0.7x when overheated) or overload surge (1.5x)
         let berserk_mult = if berserk.is_active { 1.5 } else { 1.0 };
         let heat_mult = heat_system.fire_rate_mult();
         let fire_rate = weapon.fire_rate * berserk_mult * heat_mult;
@@ -591,13 +670,20 @@ fn player_shooting(
             0.0
         };
 
+        // Shots leave from the next turret's muzzle
+        let center = transform.translation.truncate();
+        let position = match hardpoints {
+            Some(mut hardpoints) => hardpoints.fire_from(center),
+            None => center,
+        };
+
         // Send fire event
         fire_events.send(PlayerFireEvent {
-            position: transform.translation.truncate(),
+            position,
             direction: weapon.aim_direction,
             weapon_type: weapon.weapon_type,
             bullet_color: weapon.bullet_color,
-            damage: weapon.damage,
+            damage: weapon.damage * heat_system.damage_mult(),
             burst_count,
             spread_angle,
         });
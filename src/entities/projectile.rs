@@ -6,17 +6,96 @@
 
 use crate::core::*;
 use crate::systems::effects::BulletTrail;
+use crate::systems::InflictsStatus;
 use bevy::prelude::*;
 
-/// Marker for player projectiles
-#[derive(Component, Debug)]
-pub struct PlayerProjectile;
+/// Side a ship or projectile fights on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Team {
+    /// The player, wingmen and player drones
+    Player,
+    /// Everything shooting at them
+    Enemy,
+}
 
-/// Marker for enemy projectiles
-#[derive(Component, Debug)]
-pub struct EnemyProjectile;
+impl Team {
+    pub fn opposing(self) -> Team {
+        match self {
+            Team::Player => Team::Enemy,
+            Team::Enemy => Team::Player,
+        }
+    }
+}
+
+/// Who fired a projectile, and so what it can hit
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+#[require(StateScope)]
+pub struct ProjectileOwner {
+    pub team: Team,
+    /// Ship that fired it - a shot never hits its own shooter
+    pub shooter: Option<Entity>,
+    /// Also hits its own side (enemy fire under ECM confusion)
+    pub friendly_fire: bool,
+}
+
+impl ProjectileOwner {
+    /// Player-side shot (player, wingmen, drones)
+    pub fn player() -> Self {
+        Self {
+            team: Team::Player,
+            shooter: None,
+            friendly_fire: false,
+        }
+    }
+
+    /// Enemy shot
+    pub fn enemy() -> Self {
+        Self {
+            team: Team::Enemy,
+            shooter: None,
+            friendly_fire: false,
+        }
+    }
+
+    pub fn fired_by(mut self, shooter: Entity) -> Self {
+        self.shooter = Some(shooter);
+        self
+    }
 
-/// Seeking/homing projectile - tracks nearest enemy
+    /// Shot that doesn't tell friend from foe
+    pub fn confused(mut self) -> Self {
+        self.friendly_fire = true;
+        self
+    }
+
+    pub fn is_player(&self) -> bool {
+        self.team == Team::Player
+    }
+
+    /// Whether the shot hurts ships on `team`
+    pub fn hits(&self, team: Team) -> bool {
+        self.team != team || self.friendly_fire
+    }
+
+    /// Whether the shot hurts `ship`, which fights on `team`
+    pub fn hits_ship(&self, ship: Entity, team: Team) -> bool {
+        self.shooter != Some(ship) && self.hits(team)
+    }
+
+    /// Turn the shot on the side that fired it
+    pub fn reflect(&mut self, by: Option<Entity>) {
+        self.team = self.team.opposing();
+        self.shooter = by;
+        self.friendly_fire = false;
+    }
+}
+
+/// Weapon that fired a projectile (drives its doctrine visuals)
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProjectileWeapon(pub WeaponType);
+
+/// Seeking/homing projectile - player missiles track the nearest enemy,
+/// enemy missiles the player
 #[derive(Component, Debug)]
 pub struct SeekingProjectile {
     /// Turn rate in radians per second
@@ -61,7 +140,7 @@ impl Default for ProjectileDamage {
 /// Bundle for player projectile
 #[derive(Bundle)]
 pub struct PlayerProjectileBundle {
-    pub marker: PlayerProjectile,
+    pub owner: ProjectileOwner,
     pub physics: ProjectilePhysics,
     pub damage: ProjectileDamage,
     pub sprite: Sprite,
@@ -71,7 +150,7 @@ pub struct PlayerProjectileBundle {
 impl Default for PlayerProjectileBundle {
     fn default() -> Self {
         Self {
-            marker: PlayerProjectile,
+            owner: ProjectileOwner::player(),
             physics: ProjectilePhysics {
                 velocity: Vec2::Y * PLAYER_BULLET_SPEED,
                 lifetime: 2.0,
@@ -95,7 +174,7 @@ impl Default for PlayerProjectileBundle {
 /// Bundle for enemy projectile
 #[derive(Bundle)]
 pub struct EnemyProjectileBundle {
-    pub marker: EnemyProjectile,
+    pub owner: ProjectileOwner,
     pub physics: ProjectilePhysics,
     pub damage: ProjectileDamage,
     pub sprite: Sprite,
@@ -105,7 +184,7 @@ pub struct EnemyProjectileBundle {
 impl Default for EnemyProjectileBundle {
     fn default() -> Self {
         Self {
-            marker: EnemyProjectile,
+            owner: ProjectileOwner::enemy(),
             physics: ProjectilePhysics {
                 velocity: Vec2::NEG_Y * ENEMY_BULLET_SPEED,
                 lifetime: 3.0,
@@ -133,12 +212,15 @@ impl Plugin for ProjectilePlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             Update,
-            (
-                spawn_player_projectiles,
-                seeking_projectile_update,
-                projectile_update,
-            )
+            spawn_player_projectiles
+                .in_set(GameSet::Simulation)
+                .run_if(in_state(GameState::Playing)),
+        )
+        .add_systems(
+            FixedUpdate,
+            (seeking_projectile_update, projectile_update)
                 .chain()
+                .in_set(GameSet::Simulation)
                 .run_if(in_state(GameState::Playing)),
         );
     }
@@ -169,6 +251,9 @@ fn spawn_player_projectiles(
             event.bullet_color
         };
 
+        // Lasers burn, missiles breach armor
+        let inflicts = InflictsStatus::for_weapon(event.weapon_type);
+
         // Check if this is a seeking missile (Kestrel/Caldari missile launcher)
         let is_missile = event.weapon_type == WeaponType::MissileLauncher;
 
@@ -194,14 +279,17 @@ fn spawn_player_projectiles(
             // Small position offset for visual spread
             let pos_offset = Vec2::new((i as f32 - (burst_count - 1) as f32 / 2.0) * 5.0, 0.0);
             let spawn_pos = event.position + pos_offset;
+            // Sprites point along +Y; face the direction of travel
+            let rotation = Quat::from_rotation_z(proj_angle - std::f32::consts::FRAC_PI_2);
 
             if is_missile {
                 // Seeking missile - larger, slower, homes on enemies, more damage
                 let missile_velocity = direction * (PLAYER_BULLET_SPEED * 0.7);
                 let missile_damage = event.damage * damage_mult * 1.25;
 
-                commands.spawn((
-                    PlayerProjectile,
+                let mut missile = commands.spawn((
+                    ProjectileOwner::player(),
+                    ProjectileWeapon(event.weapon_type),
                     SeekingProjectile {
                         turn_rate: 4.0,
                         acquire_range: 400.0,
@@ -222,14 +310,19 @@ fn spawn_player_projectiles(
                         custom_size: Some(Vec2::new(6.0, 14.0)),
                         ..default()
                     },
-                    Transform::from_xyz(spawn_pos.x, spawn_pos.y, LAYER_PLAYER_BULLETS),
+                    Transform::from_xyz(spawn_pos.x, spawn_pos.y, LAYER_PLAYER_BULLETS)
+                        .with_rotation(rotation),
                 ));
+                if let Some(status) = inflicts {
+                    missile.insert(status);
+                }
             } else {
                 // Standard projectile with bullet trail
                 let velocity = direction * PLAYER_BULLET_SPEED;
 
-                commands.spawn((
-                    PlayerProjectile,
+                let mut bullet = commands.spawn((
+                    ProjectileOwner::player(),
+                    ProjectileWeapon(event.weapon_type),
                     ProjectilePhysics {
                         velocity,
                         lifetime: 2.0,
@@ -246,38 +339,50 @@ fn spawn_player_projectiles(
                         custom_size: Some(Vec2::new(4.0, 12.0)),
                         ..default()
                     },
-                    Transform::from_xyz(spawn_pos.x, spawn_pos.y, LAYER_PLAYER_BULLETS),
+                    Transform::from_xyz(spawn_pos.x, spawn_pos.y, LAYER_PLAYER_BULLETS)
+                        .with_rotation(rotation),
                 ));
+                if let Some(status) = inflicts {
+                    bullet.insert(status);
+                }
             }
         }
     }
 }
 
-/// Seeking projectile homing behavior - finds nearest enemy and turns toward it
+/// Seeking projectile homing behavior - finds its target and turns toward it
 fn seeking_projectile_update(
     time: Res<Time>,
-    enemy_query: Query<&Transform, With<super::Enemy>>,
-    mut seeking_query: Query<
-        (&Transform, &mut ProjectilePhysics, &SeekingProjectile),
-        With<PlayerProjectile>,
-    >,
+    enemy_query: Query<&Transform, (With<super::Enemy>, Without<ProjectileOwner>)>,
+    player_query: Query<&Transform, (With<super::Player>, Without<ProjectileOwner>)>,
+    mut seeking_query: Query<(
+        &mut Transform,
+        &mut ProjectilePhysics,
+        &SeekingProjectile,
+        &ProjectileOwner,
+    )>,
 ) {
     let dt = time.delta_secs();
 
-    for (transform, mut physics, seeking) in seeking_query.iter_mut() {
+    for (mut transform, mut physics, seeking, owner) in seeking_query.iter_mut() {
         let missile_pos = transform.translation.truncate();
 
-        // Find nearest enemy within range
+        // Player-side missiles pick the nearest enemy, enemy missiles the player
         let mut nearest_enemy: Option<Vec2> = None;
         let mut nearest_dist = seeking.acquire_range;
+        let targets = if owner.is_player() {
+            enemy_query.iter()
+        } else {
+            player_query.iter()
+        };
 
-        for enemy_transform in enemy_query.iter() {
-            let enemy_pos = enemy_transform.translation.truncate();
-            let dist = (enemy_pos - missile_pos).length();
+        for target_transform in targets {
+            let target_pos = target_transform.translation.truncate();
+            let dist = (target_pos - missile_pos).length();
 
             if dist < nearest_dist {
                 nearest_dist = dist;
-                nearest_enemy = Some(enemy_pos);
+                nearest_enemy = Some(target_pos);
             }
         }
 
@@ -307,6 +412,7 @@ fn seeking_projectile_update(
             let new_angle = current_angle + turn;
             let speed = physics.velocity.length();
             physics.velocity = Vec2::new(new_angle.cos(), new_angle.sin()) * speed;
+            transform.rotation = Quat::from_rotation_z(new_angle - std::f32::consts::FRAC_PI_2);
         }
     }
 }
@@ -316,14 +422,15 @@ fn seeking_projectile_update(
 fn projectile_update(
     mut commands: Commands,
     time: Res<Time>,
+    playfield: Res<Playfield>,
     mut query: Query<(Entity, &mut Transform, &mut ProjectilePhysics)>,
 ) {
     let dt = time.delta_secs();
 
     // Precompute bounds (with margin for off-screen cleanup)
     const MARGIN: f32 = 50.0;
-    let half_w = SCREEN_WIDTH / 2.0 + MARGIN;
-    let half_h = SCREEN_HEIGHT / 2.0 + MARGIN;
+    let half_w = playfield.half_width() + MARGIN;
+    let half_h = playfield.half_height() + MARGIN;
 
     for (entity, mut transform, mut physics) in query.iter_mut() {
         // Update lifetime
@@ -377,7 +484,8 @@ pub fn spawn_enemy_projectile_typed(
     damage: f32,
     speed: f32,
     weapon_type: WeaponType,
-) {
+    owner: ProjectileOwner,
+) -> Entity {
     let velocity = direction.normalize_or_zero() * speed;
     let angle = direction.y.atan2(direction.x) - std::f32::consts::FRAC_PI_2;
 
@@ -410,8 +518,9 @@ pub fn spawn_enemy_projectile_typed(
         ),
     };
 
-    commands.spawn((
-        EnemyProjectile,
+    let mut projectile = commands.spawn((
+        owner,
+        ProjectileWeapon(weapon_type),
         ProjectilePhysics {
             velocity,
             lifetime: 5.0,
@@ -430,4 +539,8 @@ pub fn spawn_enemy_projectile_typed(
         Transform::from_xyz(position.x, position.y, LAYER_ENEMY_BULLETS)
             .with_rotation(Quat::from_rotation_z(angle)),
     ));
+    if let Some(status) = InflictsStatus::for_weapon(weapon_type) {
+        projectile.insert(status);
+    }
+    projectile.id()
 }
@@ -0,0 +1,293 @@
+//! Turret Hardpoints
+//!
+//! Visible turrets on the player ship and bosses. Each hull mounts turrets
+//! at the offsets in its layout; every turret turns on its own (at a
+//! limited rate) toward what its ship is shooting at, and shots leave from
+//! the muzzles in turn rather than from the middle of the hull.
+//!
+//! Player turrets converge on a point down the fire direction, or on a
+//! locked enemy near that line; boss turrets track the player.
+
+#![allow(dead_code)]
+
+use super::{Boss, Enemy, Player, Weapon};
+use crate::core::*;
+use bevy::prelude::*;
+
+/// Player turret turn rate (radians per second)
+pub const PLAYER_TURRET_TURN_RATE: f32 = 9.0;
+
+/// Boss turret turn rate (radians per second)
+pub const BOSS_TURRET_TURN_RATE: f32 = 2.5;
+
+/// Distance down the fire direction where player turrets converge
+const CONVERGENCE_DISTANCE: f32 = 420.0;
+
+/// Furthest enemy a player turret will lock
+const LOCK_RANGE: f32 = 520.0;
+
+/// Widest angle off the fire direction a lock may sit (radians)
+const LOCK_CONE: f32 = 0.35;
+
+/// Barrel length as a fraction of hull size
+const BARREL_LENGTH: f32 = 0.14;
+
+/// Turret base diameter as a fraction of hull size
+const TURRET_SIZE: f32 = 0.12;
+
+/// Turret plugin
+pub struct TurretPlugin;
+
+impl Plugin for TurretPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                mount_turrets,
+                (track_player_turrets, track_boss_turrets),
+                pose_turrets,
+            )
+                .chain()
+                .in_set(GameSet::Simulation)
+                .run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight))),
+        );
+    }
+}
+
+/// A ship's turret layout and where each turret is pointing
+#[derive(Component, Debug, Clone)]
+pub struct Hardpoints {
+    /// Mount offsets as fractions of hull size, bow at +Y
+    pub layout: &'static [Vec2],
+    /// Hull size in pixels
+    pub hull_size: f32,
+    /// World direction the bow points
+    pub forward: Vec2,
+    /// Radians per second each turret can turn
+    pub turn_rate: f32,
+    /// Barrel direction of each turret (world space)
+    pub facing: Vec<Vec2>,
+    /// Turret that fires next
+    next: usize,
+}
+
+impl Hardpoints {
+    pub fn new(layout: &'static [Vec2], hull_size: f32, forward: Vec2, turn_rate: f32) -> Self {
+        Self {
+            layout,
+            hull_size,
+            forward,
+            turn_rate,
+            facing: vec![forward; layout.len()],
+            next: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.layout.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.layout.is_empty()
+    }
+
+    /// Mount offset from the hull centre (world space)
+    pub fn offset(&self, index: usize) -> Vec2 {
+        // Rotation taking the layout's +Y bow onto `forward`
+        let bow = Vec2::new(self.forward.y, -self.forward.x);
+        bow.rotate(self.layout[index] * self.hull_size)
+    }
+
+    /// Where a turret sits for a hull at `center`
+    pub fn mount(&self, index: usize, center: Vec2) -> Vec2 {
+        center + self.offset(index)
+    }
+
+    /// Tip of a turret's barrel for a hull at `center`
+    pub fn muzzle(&self, index: usize, center: Vec2) -> Vec2 {
+        self.mount(index, center) + self.facing[index] * self.hull_size * BARREL_LENGTH
+    }
+
+    /// Muzzle the next shot leaves from, cycling through the turrets
+    /// (hulls without turrets fire from the centre)
+    pub fn fire_from(&mut self, center: Vec2) -> Vec2 {
+        if self.is_empty() {
+            return center;
+        }
+        let index = self.next % self.len();
+        self.next = (index + 1) % self.len();
+        self.muzzle(index, center)
+    }
+
+    /// Turn every turret toward `target` for one frame
+    pub fn track(&mut self, center: Vec2, target: Vec2, dt: f32) {
+        let max_angle = self.turn_rate * dt;
+        for index in 0..self.len() {
+            let wanted = (target - self.mount(index, center)).normalize_or(self.forward);
+            self.facing[index] = turn_toward(self.facing[index], wanted, max_angle);
+        }
+    }
+}
+
+/// Rotate `current` toward `target` by at most `max_angle` radians
+pub fn turn_toward(current: Vec2, target: Vec2, max_angle: f32) -> Vec2 {
+    let angle = current.angle_to(target);
+    if !angle.is_finite() {
+        return target;
+    }
+    Vec2::from_angle(angle.clamp(-max_angle, max_angle)).rotate(current)
+}
+
+/// Turret visual on a ship (child of the hull)
+#[derive(Component, Debug)]
+pub struct TurretMount {
+    pub index: usize,
+}
+
+/// Give new hardpoint hulls their turret sprites
+fn mount_turrets(mut commands: Commands, ships: Query<(Entity, &Hardpoints), Added<Hardpoints>>) {
+    for (entity, hardpoints) in ships.iter() {
+        let size = hardpoints.hull_size * TURRET_SIZE;
+        commands.entity(entity).with_children(|parent| {
+            for index in 0..hardpoints.len() {
+                parent
+                    .spawn((
+                        TurretMount { index },
+                        Sprite {
+                            color: Color::srgb(0.35, 0.37, 0.4),
+                            custom_size: Some(Vec2::splat(size)),
+                            ..default()
+                        },
+                        Transform::from_xyz(0.0, 0.0, 0.2),
+                    ))
+                    .with_children(|turret| {
+                        // Barrel along +Y, poking out past the base
+                        turret.spawn((
+                            Sprite {
+                                color: Color::srgb(0.6, 0.62, 0.66),
+                                custom_size: Some(Vec2::new(
+                                    size * 0.3,
+                                    hardpoints.hull_size * BARREL_LENGTH,
+                                )),
+                                ..default()
+                            },
+                            Transform::from_xyz(
+                                0.0,
+                                hardpoints.hull_size * BARREL_LENGTH * 0.5,
+                                0.1,
+                            ),
+                        ));
+                    });
+            }
+        });
+    }
+}
+
+/// Player turrets: a locked enemy near the fire line, else the convergence point
+fn track_player_turrets(
+    time: Res<Time>,
+    mut player: Query<(&Transform, &Weapon, &mut Hardpoints), With<Player>>,
+    enemies: Query<&Transform, (With<Enemy>, Without<Player>)>,
+) {
+    let Ok((transform, weapon, mut hardpoints)) = player.get_single_mut() else {
+        return;
+    };
+    let center = transform.translation.truncate();
+    let aim = weapon.aim_direction.normalize_or(Vec2::Y);
+
+    let locked = enemies
+        .iter()
+        .map(|t| t.translation.truncate())
+        .filter(|pos| {
+            let to = *pos - center;
+            to.length() <= LOCK_RANGE && aim.angle_to(to).abs() <= LOCK_CONE
+        })
+        .min_by(|a, b| a.distance(center).total_cmp(&b.distance(center)));
+    let target = locked.unwrap_or(center + aim * CONVERGENCE_DISTANCE);
+
+    hardpoints.track(center, target, time.delta_secs());
+}
+
+/// Boss turrets keep the player covered
+fn track_boss_turrets(
+    time: Res<Time>,
+    mut bosses: Query<(&Transform, &mut Hardpoints), (With<Boss>, Without<Player>)>,
+    player: Query<&Transform, With<Player>>,
+) {
+    let Ok(player_transform) = player.get_single() else {
+        return;
+    };
+    let target = player_transform.translation.truncate();
+    for (transform, mut hardpoints) in bosses.iter_mut() {
+        hardpoints.track(transform.translation.truncate(), target, time.delta_secs());
+    }
+}
+
+/// Place turret sprites on their mounts, undoing the hull's own rotation and
+/// scale so they sit and point correctly in world space
+fn pose_turrets(
+    ships: Query<(&Transform, &Hardpoints), Without<TurretMount>>,
+    mut turrets: Query<(&TurretMount, &Parent, &mut Transform)>,
+) {
+    for (mount, parent, mut transform) in turrets.iter_mut() {
+        let Ok((hull, hardpoints)) = ships.get(parent.get()) else {
+            continue;
+        };
+        let Some(facing) = hardpoints.facing.get(mount.index) else {
+            continue;
+        };
+        let inverse = hull.rotation.inverse();
+        let offset = hardpoints.offset(mount.index).extend(0.0) / hull.scale;
+        transform.translation = (inverse * offset).with_z(transform.translation.z);
+        transform.rotation = inverse * Quat::from_rotation_z(Vec2::Y.angle_to(*facing));
+        transform.scale = Vec3::ONE / hull.scale;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TWIN: &[Vec2] = &[Vec2::new(-0.25, 0.25), Vec2::new(0.25, 0.25)];
+
+    #[test]
+    fn mounts_follow_the_bow() {
+        let up = Hardpoints::new(TWIN, 40.0, Vec2::Y, 1.0);
+        assert!(up
+            .mount(1, Vec2::ZERO)
+            .abs_diff_eq(Vec2::new(10.0, 10.0), 1e-4));
+
+        // A boss faces down: the same mount ends up below and mirrored
+        let down = Hardpoints::new(TWIN, 40.0, Vec2::NEG_Y, 1.0);
+        assert!(down
+            .mount(1, Vec2::ZERO)
+            .abs_diff_eq(Vec2::new(-10.0, -10.0), 1e-4));
+    }
+
+    #[test]
+    fn shots_cycle_through_the_muzzles() {
+        let mut hardpoints = Hardpoints::new(TWIN, 40.0, Vec2::Y, 1.0);
+        let first = hardpoints.fire_from(Vec2::ZERO);
+        let second = hardpoints.fire_from(Vec2::ZERO);
+        assert!(first.x < 0.0 && second.x > 0.0);
+        // Muzzles sit a barrel length past the mount
+        assert!(first.abs_diff_eq(Vec2::new(-10.0, 10.0 + 40.0 * BARREL_LENGTH), 1e-4));
+        assert_eq!(hardpoints.fire_from(Vec2::ZERO), first);
+
+        let mut bare = Hardpoints::new(&[], 40.0, Vec2::Y, 1.0);
+        assert_eq!(bare.fire_from(Vec2::new(3.0, 4.0)), Vec2::new(3.0, 4.0));
+    }
+
+    #[test]
+    fn turrets_turn_at_a_limited_rate() {
+        let turned = turn_toward(Vec2::Y, Vec2::X, 0.5);
+        assert!((Vec2::Y.angle_to(turned) + 0.5).abs() < 1e-4);
+        assert_eq!(turn_toward(Vec2::Y, Vec2::Y, 0.5), Vec2::Y);
+
+        // Each turret aims from its own mount
+        let mut hardpoints = Hardpoints::new(TWIN, 40.0, Vec2::Y, 100.0);
+        hardpoints.track(Vec2::ZERO, Vec2::new(0.0, 50.0), 1.0);
+        assert!(hardpoints.facing[0].x > 0.0);
+        assert!(hardpoints.facing[1].x < 0.0);
+    }
+}
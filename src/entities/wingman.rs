@@ -1,17 +1,135 @@
 //! Wingman Entity
 //!
-//! Allied Rifter frigates that assist the player during combat.
-//! Rifter special ability: spawn wingman every 15 kills.
+//! Allied Minmatar frigates that assist the player during combat.
+//! Reinforcements arrive every 15 kills in a Rifter (25 in anything else)
+//! and grow heavier as the wing fills out: Slashers screen ahead on the
+//! first calls, Rifters hold formation after them and one Wolf brings
+//! heavy autocannons.
+//!
+//! Each wingman is flown by a named pilot from the roster who chatters on
+//! comms, flies on into the next mission if they survive and is remembered
+//! if they don't.
 
 #![allow(dead_code)]
 
-use super::{Player, PlayerProjectile, ProjectileDamage, ProjectilePhysics};
+use super::wingman_pilot::{ChatterKind, WingmanPilot, WingmanRoster};
+use super::{Enemy, Player, ProjectileDamage, ProjectileOwner, ProjectilePhysics, Team};
 use crate::assets::{get_model_scale, ShipModelCache, ShipModelRotation};
 use crate::core::*;
+use crate::systems::{DialogueEvent, DialogueSystem, DialogueTrigger};
 use bevy::prelude::*;
 
+/// Minimum time between wingman chatter lines (seconds)
+const CHATTER_COOLDOWN: f32 = 6.0;
+
+/// Chance a kill gets a comment from the wing
+const KILL_CHATTER_CHANCE: f32 = 0.3;
+
+/// Wingmen in the air at once (interlude boons can raise it)
+pub const MAX_WINGMEN: u32 = 4;
+
+/// Formation slots, tried in order
+const FORMATION_OFFSETS: [f32; 6] = [-80.0, -50.0, 50.0, 80.0, -110.0, 110.0];
+
+/// Kills per wingman flying a Rifter, and in any other ship
+const RIFTER_KILLS_PER_WINGMAN: u32 = 15;
+const KILLS_PER_WINGMAN: u32 = 25;
+
+/// How far a Wolf leaves its slot to line up on a target
+const WOLF_LEASH: f32 = 60.0;
+
+/// Ship a reinforcement arrives in
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WingmanTier {
+    /// Fast interceptor that weaves ahead of the formation
+    Slasher,
+    /// Line frigate holding formation
+    Rifter,
+    /// Assault frigate that hunts targets with spread fire
+    Wolf,
+}
+
+impl WingmanTier {
+    /// Tier for the nth reinforcement call of a run (from 0): two
+    /// Slashers, then Rifters, with a Wolf as the fourth
+    pub fn for_call(call: u32) -> Self {
+        match call {
+            0 | 1 => WingmanTier::Slasher,
+            3 => WingmanTier::Wolf,
+            _ => WingmanTier::Rifter,
+        }
+    }
+
+    pub fn type_id(&self) -> u32 {
+        match self {
+            WingmanTier::Slasher => 585,
+            WingmanTier::Rifter => 587,
+            WingmanTier::Wolf => 11371,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            WingmanTier::Slasher => "SLASHER",
+            WingmanTier::Rifter => "RIFTER",
+            WingmanTier::Wolf => "WOLF",
+        }
+    }
+
+    /// Gauge and bullet color
+    pub fn color(&self) -> Color {
+        match self {
+            WingmanTier::Slasher => Color::srgb(0.95, 0.8, 0.35),
+            WingmanTier::Rifter => Color::srgb(0.8, 0.5, 0.3),
+            WingmanTier::Wolf => Color::srgb(1.0, 0.35, 0.2),
+        }
+    }
+
+    pub fn stats(&self, offset_x: f32) -> WingmanStats {
+        let (health, speed) = match self {
+            WingmanTier::Slasher => (35.0, 420.0),
+            WingmanTier::Rifter => (50.0, 320.0),
+            WingmanTier::Wolf => (90.0, 260.0),
+        };
+        WingmanStats {
+            health,
+            max_health: health,
+            offset_x,
+            speed,
+        }
+    }
+
+    pub fn weapon(&self) -> WingmanWeapon {
+        match self {
+            WingmanTier::Slasher => WingmanWeapon {
+                fire_rate: 4.5,
+                damage: 5.0,
+                ..default()
+            },
+            WingmanTier::Rifter => WingmanWeapon::default(),
+            WingmanTier::Wolf => WingmanWeapon {
+                fire_rate: 1.4,
+                damage: 9.0,
+                shots: 3,
+                damage_type: DamageType::Explosive,
+                ..default()
+            },
+        }
+    }
+
+    /// Height above the player the formation slot sits at
+    fn formation_y(&self) -> f32 {
+        match self {
+            WingmanTier::Slasher => 90.0,
+            WingmanTier::Rifter => 40.0,
+            WingmanTier::Wolf => 20.0,
+        }
+    }
+}
+
 /// Marker for wingman entities
 #[derive(Component, Debug)]
+#[require(StateScope)]
 pub struct Wingman;
 
 /// Wingman stats
@@ -43,7 +161,11 @@ impl Default for WingmanStats {
 pub struct WingmanWeapon {
     pub fire_rate: f32,
     pub cooldown: f32,
+    /// Damage per bullet
     pub damage: f32,
+    /// Bullets per volley, fanned out
+    pub shots: u32,
+    pub damage_type: DamageType,
 }
 
 impl Default for WingmanWeapon {
@@ -52,6 +174,8 @@ impl Default for WingmanWeapon {
             fire_rate: 2.5, // Shots per second
             cooldown: 0.0,
             damage: 8.0,
+            shots: 1,
+            damage_type: DamageType::Kinetic,
         }
     }
 }
@@ -61,21 +185,55 @@ impl Default for WingmanWeapon {
 pub struct WingmanTracker {
     /// Kill counter (resets when wingman spawns)
     pub kill_count: u32,
+    /// Reinforcements called this run
+    pub calls: u32,
     /// Kills required per wingman
     pub kills_per_wingman: u32,
     /// Maximum wingmen active at once
     pub max_wingmen: u32,
 }
 
+/// Shared comms cooldown so the wing doesn't talk over itself
+#[derive(Resource, Default)]
+pub struct WingmanChatter {
+    pub cooldown: f32,
+}
+
+impl WingmanChatter {
+    /// Take the channel if it's free, starting the cooldown
+    pub fn try_speak(&mut self) -> bool {
+        if self.cooldown > 0.0 {
+            return false;
+        }
+        self.cooldown = CHATTER_COOLDOWN;
+        true
+    }
+}
+
 impl WingmanTracker {
     pub fn new() -> Self {
         Self {
             kill_count: 0,
-            kills_per_wingman: 15,
-            max_wingmen: 4,
+            calls: 0,
+            kills_per_wingman: RIFTER_KILLS_PER_WINGMAN,
+            max_wingmen: MAX_WINGMEN,
+        }
+    }
+
+    /// Kills per wingman for a ship; Rifters call the wing in fastest
+    pub fn kills_for(ship: MinmatarShip) -> u32 {
+        if ship == MinmatarShip::Rifter {
+            RIFTER_KILLS_PER_WINGMAN
+        } else {
+            KILLS_PER_WINGMAN
         }
     }
 
+    /// Ship the next reinforcement arrives in
+    pub fn next_tier(&self) -> WingmanTier {
+        WingmanTier::for_call(self.calls)
+    }
+
     /// Returns progress toward next wingman (0.0 to 1.0)
     pub fn progress(&self) -> f32 {
         self.kill_count as f32 / self.kills_per_wingman as f32
@@ -87,35 +245,107 @@ pub struct WingmanPlugin;
 
 impl Plugin for WingmanPlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(WingmanTracker::new()).add_systems(
-            Update,
-            (
-                track_kills_for_wingman,
-                wingman_follow_player,
-                wingman_shooting,
-                wingman_damage,
-            )
-                .run_if(in_state(GameState::Playing)),
+        app.insert_resource(WingmanTracker::new())
+            .init_resource::<WingmanRoster>()
+            .init_resource::<WingmanChatter>()
+            .add_systems(OnEnter(GameState::ShipSelect), reset_roster)
+            .add_systems(
+                Update,
+                (
+                    set_wingman_rate.run_if(resource_changed::<SelectedShip>),
+                    redeploy_surviving_pilots,
+                    track_kills_for_wingman,
+                    wingman_follow_player,
+                    wingman_shooting,
+                    wingman_damage,
+                    wingman_chatter,
+                )
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+/// A new run starts with a fresh wing
+fn reset_roster(mut roster: ResMut<WingmanRoster>, mut tracker: ResMut<WingmanTracker>) {
+    roster.reset();
+    tracker.kill_count = 0;
+    tracker.calls = 0;
+}
+
+/// Match the kills per wingman to the ship being flown
+fn set_wingman_rate(selected_ship: Res<SelectedShip>, mut tracker: ResMut<WingmanTracker>) {
+    let kills = WingmanTracker::kills_for(selected_ship.ship);
+    if tracker.kills_per_wingman != kills {
+        tracker.kills_per_wingman = kills;
+    }
+}
+
+/// First free formation slot given the wingmen already flying
+fn free_offset(existing: &[f32]) -> f32 {
+    FORMATION_OFFSETS
+        .into_iter()
+        .find(|&offset| !existing.iter().any(|&x| (x - offset).abs() < 20.0))
+        .unwrap_or(60.0)
+}
+
+/// Pilots who survived the last mission fly into this one
+fn redeploy_surviving_pilots(
+    mut commands: Commands,
+    mut mission_events: EventReader<MissionStartEvent>,
+    roster: Res<WingmanRoster>,
+    player_query: Query<&Transform, With<Player>>,
+    wingmen_query: Query<(&WingmanStats, Option<&WingmanPilot>), With<Wingman>>,
+    sprite_cache: Res<crate::assets::ShipSpriteCache>,
+    model_cache: Res<ShipModelCache>,
+) {
+    if mission_events.read().last().is_none() || roster.flying.is_empty() {
+        return;
+    }
+    let Ok(player_transform) = player_query.get_single() else {
+        return;
+    };
+    let player_pos = player_transform.translation.truncate();
+
+    // Resuming from pause restarts the mission; only fill empty seats
+    let mut offsets: Vec<f32> = wingmen_query.iter().map(|(w, _)| w.offset_x).collect();
+    let present: Vec<usize> = wingmen_query
+        .iter()
+        .filter_map(|(_, pilot)| pilot.map(|p| p.0))
+        .collect();
+
+    for &pilot in roster.flying.iter().filter(|p| !present.contains(p)) {
+        let offset_x = free_offset(&offsets);
+        offsets.push(offset_x);
+        // Pilots join in call order, so a pilot's number is the call that
+        // brought them in
+        spawn_wingman(
+            &mut commands,
+            WingmanTier::for_call(pilot as u32),
+            player_pos,
+            offset_x,
+            Some(WingmanPilot(pilot)),
+            Some(&sprite_cache),
+            Some(&model_cache),
+        );
+        info!(
+            "{} flies with you again",
+            WingmanPilot(pilot).profile().full_name()
         );
     }
 }
 
-/// Track enemy kills and spawn wingmen for Rifter
+/// Track enemy kills and call in reinforcements
 fn track_kills_for_wingman(
     mut commands: Commands,
     mut tracker: ResMut<WingmanTracker>,
     mut destroy_events: EventReader<EnemyDestroyedEvent>,
-    selected_ship: Res<SelectedShip>,
     player_query: Query<&Transform, With<Player>>,
     wingmen_query: Query<&WingmanStats, With<Wingman>>,
     sprite_cache: Res<crate::assets::ShipSpriteCache>,
     model_cache: Res<ShipModelCache>,
+    mut roster: ResMut<WingmanRoster>,
+    mut dialogue_events: EventWriter<DialogueEvent>,
 ) {
-    // Only Rifter gets wingmen
-    if selected_ship.ship != MinmatarShip::Rifter {
-        return;
-    }
-
     // Count kills
     for _event in destroy_events.read() {
         tracker.kill_count += 1;
@@ -138,53 +368,81 @@ fn track_kills_for_wingman(
 
             // Calculate offset to avoid stacking
             let existing_offsets: Vec<f32> = wingmen_query.iter().map(|w| w.offset_x).collect();
-            let possible_offsets = [-80.0, -50.0, 50.0, 80.0, -110.0, 110.0];
-
-            let mut offset_x = 60.0;
-            for &offset in &possible_offsets {
-                if !existing_offsets.iter().any(|&x| (x - offset).abs() < 20.0) {
-                    offset_x = offset;
-                    break;
-                }
-            }
+            let offset_x = free_offset(&existing_offsets);
 
+            // Named pilots until the roster runs dry
+            let tier = tracker.next_tier();
+            tracker.calls += 1;
+            let pilot = roster.recruit().map(WingmanPilot);
             spawn_wingman(
                 &mut commands,
+                tier,
                 player_pos,
                 offset_x,
+                pilot,
                 Some(&sprite_cache),
                 Some(&model_cache),
             );
-            info!("Wingman spawned! (offset: {})", offset_x);
+            debug!("{} wingman spawned! (offset: {})", tier.name(), offset_x);
+
+            if let Some(pilot) = pilot {
+                let profile = pilot.profile();
+                dialogue_events.send(DialogueEvent::pilot_chatter(
+                    profile.full_name(),
+                    profile.chatter(ChatterKind::Deploy),
+                ));
+            }
         }
     }
 }
 
-/// Spawn a wingman
+/// Spawn a wingman in a `tier` ship, flown by `pilot` if one is named
 pub fn spawn_wingman(
     commands: &mut Commands,
+    tier: WingmanTier,
+    player_pos: Vec2,
+    offset_x: f32,
+    pilot: Option<WingmanPilot>,
+    sprite_cache: Option<&crate::assets::ShipSpriteCache>,
+    model_cache: Option<&ShipModelCache>,
+) -> Entity {
+    let entity = spawn_wingman_ship(
+        commands,
+        tier,
+        player_pos,
+        offset_x,
+        sprite_cache,
+        model_cache,
+    );
+    if let Some(pilot) = pilot {
+        commands.entity(entity).insert(pilot);
+    }
+    entity
+}
+
+fn spawn_wingman_ship(
+    commands: &mut Commands,
+    tier: WingmanTier,
     player_pos: Vec2,
     offset_x: f32,
     sprite_cache: Option<&crate::assets::ShipSpriteCache>,
     model_cache: Option<&ShipModelCache>,
 ) -> Entity {
-    let spawn_pos = Vec2::new(player_pos.x + offset_x, player_pos.y + 40.0);
-    let rifter_type_id: u32 = 587;
+    let spawn_pos = Vec2::new(player_pos.x + offset_x, player_pos.y + tier.formation_y());
+    let type_id = tier.type_id();
 
     // Try 3D model first
     if let Some(cache) = model_cache {
-        if let Some(scene_handle) = cache.get(rifter_type_id) {
+        if let Some(scene_handle) = cache.get(type_id) {
             let model_rot = ShipModelRotation::new_player();
-            let scale = get_model_scale(rifter_type_id) * 40.0; // Slightly smaller than player
+            let scale = get_model_scale(type_id) * 40.0; // Slightly smaller than player
 
             return commands
                 .spawn((
                     Wingman,
-                    WingmanStats {
-                        offset_x,
-                        ..default()
-                    },
-                    WingmanWeapon::default(),
+                    tier,
+                    tier.stats(offset_x),
+                    tier.weapon(),
                     model_rot.clone(),
                     SceneRoot(scene_handle),
                     Transform::from_xyz(spawn_pos.x, spawn_pos.y, 0.0)
@@ -200,11 +458,10 @@ pub fn spawn_wingman(
 
     // Fallback to sprite
     let sprite = if let Some(cache) = sprite_cache {
-        if let Some(texture) = cache.get(rifter_type_id) {
+        if let Some(texture) = cache.get(type_id) {
             Sprite {
-                image: texture,
                 custom_size: Some(Vec2::splat(wingman_size)),
-                ..default()
+                ..texture.sprite()
             }
         } else {
             Sprite {
@@ -224,11 +481,9 @@ pub fn spawn_wingman(
     commands
         .spawn((
             Wingman,
-            WingmanStats {
-                offset_x,
-                ..default()
-            },
-            WingmanWeapon::default(),
+            tier,
+            tier.stats(offset_x),
+            tier.weapon(),
             sprite,
             // EVE renders already face UP - no rotation needed
             Transform::from_xyz(spawn_pos.x, spawn_pos.y, LAYER_PLAYER),
@@ -236,11 +491,42 @@ pub fn spawn_wingman(
         .id()
 }
 
+/// Where a wingman wants to be: its formation slot, weaving for
+/// Slashers, leaning toward the nearest target above for a Wolf
+fn formation_target(
+    tier: WingmanTier,
+    slot: Vec2,
+    elapsed: f32,
+    enemies: impl Iterator<Item = Vec2>,
+) -> Vec2 {
+    match tier {
+        WingmanTier::Slasher => slot + Vec2::new((elapsed * 3.0 + slot.x * 0.05).sin() * 30.0, 0.0),
+        WingmanTier::Rifter => slot,
+        WingmanTier::Wolf => enemies
+            .filter(|enemy| enemy.y > slot.y)
+            .min_by(|a, b| {
+                a.distance_squared(slot)
+                    .total_cmp(&b.distance_squared(slot))
+            })
+            .map_or(slot, |enemy| {
+                Vec2::new(
+                    enemy.x.clamp(slot.x - WOLF_LEASH, slot.x + WOLF_LEASH),
+                    slot.y,
+                )
+            }),
+    }
+}
+
 /// Wingmen follow the player
 fn wingman_follow_player(
     time: Res<Time>,
+    playfield: Res<Playfield>,
     player_query: Query<&Transform, With<Player>>,
-    mut wingmen_query: Query<(&mut Transform, &WingmanStats), (With<Wingman>, Without<Player>)>,
+    enemy_query: Query<&Transform, (With<Enemy>, Without<Wingman>)>,
+    mut wingmen_query: Query<
+        (&mut Transform, &WingmanStats, &WingmanTier),
+        (With<Wingman>, Without<Player>, Without<Enemy>),
+    >,
 ) {
     let Ok(player_transform) = player_query.get_single() else {
         return;
@@ -248,13 +534,19 @@ fn wingman_follow_player(
 
     let player_pos = player_transform.translation.truncate();
     let dt = time.delta_secs();
-
-    for (mut transform, stats) in wingmen_query.iter_mut() {
-        let target_x = player_pos.x + stats.offset_x;
-        let target_y = player_pos.y + 40.0; // Slightly behind
+    let elapsed = time.elapsed_secs();
+
+    for (mut transform, stats, &tier) in wingmen_query.iter_mut() {
+        let slot = player_pos + Vec2::new(stats.offset_x, tier.formation_y());
+        let target = formation_target(
+            tier,
+            slot,
+            elapsed,
+            enemy_query.iter().map(|t| t.translation.truncate()),
+        );
 
         let current_pos = transform.translation.truncate();
-        let delta = Vec2::new(target_x, target_y) - current_pos;
+        let delta = target - current_pos;
 
         // Smooth movement toward target
         if delta.length() > 2.0 {
@@ -263,15 +555,10 @@ fn wingman_follow_player(
             transform.translation.y += move_dir.y * stats.speed * dt;
         }
 
-        // Clamp to screen
-        transform.translation.x = transform
-            .translation
-            .x
-            .clamp(-SCREEN_WIDTH / 2.0 + 20.0, SCREEN_WIDTH / 2.0 - 20.0);
-        transform.translation.y = transform
-            .translation
-            .y
-            .clamp(-SCREEN_HEIGHT / 2.0 + 20.0, SCREEN_HEIGHT / 2.0 - 20.0);
+        // Clamp to the playfield
+        let clamped = playfield.clamp(transform.translation.truncate(), 20.0);
+        transform.translation.x = clamped.x;
+        transform.translation.y = clamped.y;
     }
 }
 
@@ -279,11 +566,11 @@ fn wingman_follow_player(
 fn wingman_shooting(
     mut commands: Commands,
     time: Res<Time>,
-    mut wingmen_query: Query<(&Transform, &mut WingmanWeapon), With<Wingman>>,
+    mut wingmen_query: Query<(Entity, &Transform, &mut WingmanWeapon, &WingmanTier), With<Wingman>>,
 ) {
     let dt = time.delta_secs();
 
-    for (transform, mut weapon) in wingmen_query.iter_mut() {
+    for (entity, transform, mut weapon, tier) in wingmen_query.iter_mut() {
         weapon.cooldown -= dt;
 
         if weapon.cooldown <= 0.0 {
@@ -291,28 +578,32 @@ fn wingman_shooting(
 
             let pos = transform.translation.truncate();
 
-            // Fire straight up
-            let velocity = Vec2::Y * PLAYER_BULLET_SPEED * 0.9;
-
-            commands.spawn((
-                PlayerProjectile,
-                ProjectilePhysics {
-                    velocity,
-                    lifetime: 1.5,
-                },
-                ProjectileDamage {
-                    damage: weapon.damage,
-                    damage_type: DamageType::Kinetic,
-                    crit_chance: 0.1, // 10% crit for wingman
-                    crit_multiplier: 1.5,
-                },
-                Sprite {
-                    color: Color::srgb(0.8, 0.5, 0.3), // Rust-colored bullets
-                    custom_size: Some(Vec2::new(3.0, 10.0)),
-                    ..default()
-                },
-                Transform::from_xyz(pos.x, pos.y + 20.0, LAYER_PLAYER_BULLETS),
-            ));
+            // Fire up, fanning multi-shot volleys 8 degrees apart
+            for shot in 0..weapon.shots {
+                let angle = (shot as f32 - (weapon.shots - 1) as f32 / 2.0) * 8f32.to_radians();
+                let velocity = Vec2::from_angle(angle).rotate(Vec2::Y) * PLAYER_BULLET_SPEED * 0.9;
+
+                commands.spawn((
+                    ProjectileOwner::player().fired_by(entity),
+                    ProjectilePhysics {
+                        velocity,
+                        lifetime: 1.5,
+                    },
+                    ProjectileDamage {
+                        damage: weapon.damage,
+                        damage_type: weapon.damage_type,
+                        crit_chance: 0.1, // 10% crit for wingman
+                        crit_multiplier: 1.5,
+                    },
+                    Sprite {
+                        color: tier.color(),
+                        custom_size: Some(Vec2::new(3.0, 10.0)),
+                        ..default()
+                    },
+                    Transform::from_xyz(pos.x, pos.y + 20.0, LAYER_PLAYER_BULLETS)
+                        .with_rotation(Quat::from_rotation_z(angle)),
+                ));
+            }
         }
     }
 }
@@ -320,13 +611,24 @@ fn wingman_shooting(
 /// Wingmen take damage from enemy projectiles
 fn wingman_damage(
     mut commands: Commands,
-    projectile_query: Query<(Entity, &Transform, &ProjectileDamage), With<super::EnemyProjectile>>,
-    mut wingmen_query: Query<(Entity, &Transform, &mut WingmanStats), With<Wingman>>,
+    projectile_query: Query<(Entity, &Transform, &ProjectileDamage, &ProjectileOwner)>,
+    mut wingmen_query: Query<
+        (Entity, &Transform, &mut WingmanStats, Option<&WingmanPilot>),
+        With<Wingman>,
+    >,
+    mut roster: ResMut<WingmanRoster>,
+    mut chatter: ResMut<WingmanChatter>,
+    campaign: Res<CampaignState>,
+    dialogue: Res<DialogueSystem>,
+    mut dialogue_events: EventWriter<DialogueEvent>,
 ) {
-    for (proj_entity, proj_transform, proj_damage) in projectile_query.iter() {
+    for (proj_entity, proj_transform, proj_damage, owner) in projectile_query.iter() {
         let proj_pos = proj_transform.translation.truncate();
 
-        for (wingman_entity, wingman_transform, mut stats) in wingmen_query.iter_mut() {
+        for (wingman_entity, wingman_transform, mut stats, pilot) in wingmen_query.iter_mut() {
+            if !owner.hits_ship(wingman_entity, Team::Player) {
+                continue;
+            }
             let wingman_pos = wingman_transform.translation.truncate();
             let distance = (proj_pos - wingman_pos).length();
 
@@ -339,7 +641,21 @@ fn wingman_damage(
 
                 if stats.health <= 0.0 {
                     commands.entity(wingman_entity).despawn_recursive();
-                    info!("Wingman destroyed!");
+                    debug!("Wingman destroyed!");
+
+                    if let Some(pilot) = pilot {
+                        let mission = campaign.current_mission().map_or("the fight", |m| m.name);
+                        roster.lose(pilot.0, mission);
+                        send_pilot_down(&mut dialogue_events, pilot);
+                    }
+                } else if let Some(pilot) = pilot {
+                    if !dialogue.is_active() && chatter.try_speak() {
+                        let profile = pilot.profile();
+                        dialogue_events.send(DialogueEvent::pilot_chatter(
+                            profile.full_name(),
+                            profile.chatter(ChatterKind::Hit),
+                        ));
+                    }
                 }
 
                 break;
@@ -347,3 +663,88 @@ fn wingman_damage(
         }
     }
 }
+
+/// Last words over comms, then the Elder remembers them
+fn send_pilot_down(dialogue_events: &mut EventWriter<DialogueEvent>, pilot: &WingmanPilot) {
+    let profile = pilot.profile();
+    info!("{} is down", profile.full_name());
+    dialogue_events.send(DialogueEvent {
+        priority: 6,
+        ..DialogueEvent::pilot_chatter(profile.full_name(), profile.chatter(ChatterKind::Down))
+    });
+    let eulogy = format!(
+        "{} has fallen. Speak the name, pilot - the Fleet remembers its own.",
+        profile.callsign
+    );
+    dialogue_events.send(DialogueEvent {
+        trigger: DialogueTrigger::Custom(eulogy.clone()),
+        custom_text: Some(eulogy),
+        priority: 5,
+        ..default()
+    });
+}
+
+/// Cool down the comms channel; now and then a wingman comments on a kill
+fn wingman_chatter(
+    time: Res<Time>,
+    mut destroy_events: EventReader<EnemyDestroyedEvent>,
+    wingmen_query: Query<&WingmanPilot, With<Wingman>>,
+    mut chatter: ResMut<WingmanChatter>,
+    dialogue: Res<DialogueSystem>,
+    mut dialogue_events: EventWriter<DialogueEvent>,
+) {
+    chatter.cooldown = (chatter.cooldown - time.delta_secs()).max(0.0);
+
+    if destroy_events.read().count() == 0 || dialogue.is_active() {
+        return;
+    }
+    let pilots: Vec<&WingmanPilot> = wingmen_query.iter().collect();
+    if pilots.is_empty() || fastrand::f32() >= KILL_CHATTER_CHANCE || !chatter.try_speak() {
+        return;
+    }
+
+    let profile = pilots[fastrand::usize(..pilots.len())].profile();
+    dialogue_events.send(DialogueEvent::pilot_chatter(
+        profile.full_name(),
+        profile.chatter(ChatterKind::Kill),
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reinforcements_get_heavier_as_the_wing_fills() {
+        let tiers: Vec<WingmanTier> = (0..6).map(WingmanTier::for_call).collect();
+        assert_eq!(
+            tiers,
+            [
+                WingmanTier::Slasher,
+                WingmanTier::Slasher,
+                WingmanTier::Rifter,
+                WingmanTier::Wolf,
+                WingmanTier::Rifter,
+                WingmanTier::Rifter,
+            ]
+        );
+        assert!(
+            WingmanTier::Wolf.stats(0.0).max_health > WingmanTier::Slasher.stats(0.0).max_health
+        );
+        assert_eq!(WingmanTier::Wolf.weapon().shots, 3);
+        assert!(
+            WingmanTracker::kills_for(MinmatarShip::Wolf)
+                > WingmanTracker::kills_for(MinmatarShip::Rifter)
+        );
+    }
+
+    #[test]
+    fn wolves_lean_toward_targets_above_within_their_leash() {
+        let slot = Vec2::new(0.0, 0.0);
+        let enemies = [Vec2::new(200.0, 300.0), Vec2::new(-30.0, -100.0)];
+        let target = formation_target(WingmanTier::Wolf, slot, 0.0, enemies.into_iter());
+        assert_eq!(target, Vec2::new(WOLF_LEASH, 0.0));
+        let rifter = formation_target(WingmanTier::Rifter, slot, 0.0, enemies.into_iter());
+        assert_eq!(rifter, slot);
+    }
+}
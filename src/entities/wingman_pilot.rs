@@ -0,0 +1,269 @@
+//! Wingman Pilots
+//!
+//! The Elder Fleet pilots who fly the wingmen: names, callsigns,
+//! personalities and their chatter. The roster carries survivors from one
+//! mission to the next within a run and remembers the fallen.
+
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+
+/// How a pilot talks on comms
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PilotPersonality {
+    /// Old hand, dry and steady
+    Veteran,
+    /// Young and eager for the fight
+    Hothead,
+    /// Few words, all of them grim
+    Stoic,
+    /// Jokes to keep the fear down
+    Joker,
+}
+
+/// Moments a wingman speaks up
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatterKind {
+    Deploy,
+    Kill,
+    Hit,
+    /// Last words
+    Down,
+}
+
+impl PilotPersonality {
+    /// Lines for a chatter moment
+    pub fn lines(&self, kind: ChatterKind) -> &'static [&'static str] {
+        match (self, kind) {
+            (PilotPersonality::Veteran, ChatterKind::Deploy) => &[
+                "Forming up on your wing. Try to keep up.",
+                "Been flying Rifters since before you were born. Let's work.",
+            ],
+            (PilotPersonality::Veteran, ChatterKind::Kill) => {
+                &["Splash one. Steady now.", "That's how it's done."]
+            }
+            (PilotPersonality::Veteran, ChatterKind::Hit) => {
+                &["Took a hit. Seen worse.", "Armor's holding. Mostly."]
+            }
+            (PilotPersonality::Veteran, ChatterKind::Down) => &["Tell the Elders... I flew true."],
+            (PilotPersonality::Hothead, ChatterKind::Deploy) => &[
+                "Finally! Point me at something!",
+                "On your wing - let's burn them!",
+            ],
+            (PilotPersonality::Hothead, ChatterKind::Kill) => {
+                &["Got one! Who's next?", "Ha! Did you see that?"]
+            }
+            (PilotPersonality::Hothead, ChatterKind::Hit) => {
+                &["They scratched my paint!", "Just made it personal!"]
+            }
+            (PilotPersonality::Hothead, ChatterKind::Down) => &["Not like this - not yet!"],
+            (PilotPersonality::Stoic, ChatterKind::Deploy) => &["In position.", "Ready."],
+            (PilotPersonality::Stoic, ChatterKind::Kill) => &["Target down.", "One less."],
+            (PilotPersonality::Stoic, ChatterKind::Hit) => &["Hit. Still flying."],
+            (PilotPersonality::Stoic, ChatterKind::Down) => &["Free... at last."],
+            (PilotPersonality::Joker, ChatterKind::Deploy) => &[
+                "Who ordered the extra Rifter?",
+                "Don't worry, I only crash into enemies. Usually.",
+            ],
+            (PilotPersonality::Joker, ChatterKind::Kill) => &[
+                "Scrap metal, coming right up!",
+                "Amarr ships: now in pieces.",
+            ],
+            (PilotPersonality::Joker, ChatterKind::Hit) => {
+                &["Ow. Rude.", "That one's going on my tab."]
+            }
+            (PilotPersonality::Joker, ChatterKind::Down) => &["Guess the joke's on me..."],
+        }
+    }
+}
+
+/// A named Elder Fleet pilot
+#[derive(Debug, Clone, Copy)]
+pub struct PilotProfile {
+    pub name: &'static str,
+    pub callsign: &'static str,
+    pub personality: PilotPersonality,
+    /// Portrait tint in the dialogue box
+    pub portrait: Color,
+}
+
+impl PilotProfile {
+    /// "Keva 'Ember' Arnoldt"
+    pub fn full_name(&self) -> String {
+        match self.name.split_once(' ') {
+            Some((first, last)) => format!("{} '{}' {}", first, self.callsign, last),
+            None => format!("{} '{}'", self.name, self.callsign),
+        }
+    }
+
+    /// A random line for the moment
+    pub fn chatter(&self, kind: ChatterKind) -> &'static str {
+        let lines = self.personality.lines(kind);
+        lines[fastrand::usize(..lines.len())]
+    }
+}
+
+/// Pilots in the order they join the wing
+pub const ELDER_FLEET_PILOTS: &[PilotProfile] = &[
+    PilotProfile {
+        name: "Keva Arnoldt",
+        callsign: "Ember",
+        personality: PilotPersonality::Hothead,
+        portrait: Color::srgb(0.85, 0.35, 0.15),
+    },
+    PilotProfile {
+        name: "Oruk Tanhar",
+        callsign: "Greybeard",
+        personality: PilotPersonality::Veteran,
+        portrait: Color::srgb(0.55, 0.55, 0.5),
+    },
+    PilotProfile {
+        name: "Ilen Vashoudt",
+        callsign: "Wisp",
+        personality: PilotPersonality::Joker,
+        portrait: Color::srgb(0.35, 0.7, 0.65),
+    },
+    PilotProfile {
+        name: "Sata Kiruun",
+        callsign: "Flint",
+        personality: PilotPersonality::Stoic,
+        portrait: Color::srgb(0.45, 0.35, 0.3),
+    },
+    PilotProfile {
+        name: "Brannoc Esk",
+        callsign: "Rattle",
+        personality: PilotPersonality::Joker,
+        portrait: Color::srgb(0.75, 0.6, 0.25),
+    },
+    PilotProfile {
+        name: "Tarik Sendrasz",
+        callsign: "Anvil",
+        personality: PilotPersonality::Veteran,
+        portrait: Color::srgb(0.5, 0.3, 0.2),
+    },
+    PilotProfile {
+        name: "Mira Hjolfsdottir",
+        callsign: "Spark",
+        personality: PilotPersonality::Hothead,
+        portrait: Color::srgb(0.9, 0.6, 0.3),
+    },
+    PilotProfile {
+        name: "Dren Karsoth",
+        callsign: "Ghost",
+        personality: PilotPersonality::Stoic,
+        portrait: Color::srgb(0.4, 0.45, 0.55),
+    },
+];
+
+/// The pilot flying a wingman (index into `ELDER_FLEET_PILOTS`)
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WingmanPilot(pub usize);
+
+impl WingmanPilot {
+    pub fn profile(&self) -> &'static PilotProfile {
+        &ELDER_FLEET_PILOTS[self.0]
+    }
+}
+
+/// Portrait tint for a dialogue speaker, if it is one of the pilots
+pub fn pilot_portrait(speaker: &str) -> Option<Color> {
+    ELDER_FLEET_PILOTS
+        .iter()
+        .find(|p| p.full_name() == speaker)
+        .map(|p| p.portrait)
+}
+
+/// A pilot lost this run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FallenPilot {
+    pub pilot: usize,
+    /// Mission they fell in
+    pub mission: &'static str,
+}
+
+/// The run's wing: who is flying and who has fallen
+#[derive(Resource, Debug, Default)]
+pub struct WingmanRoster {
+    /// Pilots flying with the player, carried into the next mission
+    pub flying: Vec<usize>,
+    /// Pilots lost this run, in order
+    pub fallen: Vec<FallenPilot>,
+}
+
+impl WingmanRoster {
+    /// Bring the next pilot who is neither flying nor fallen into the wing
+    pub fn recruit(&mut self) -> Option<usize> {
+        let pilot = (0..ELDER_FLEET_PILOTS.len())
+            .find(|i| !self.flying.contains(i) && !self.fallen.iter().any(|f| f.pilot == *i))?;
+        self.flying.push(pilot);
+        Some(pilot)
+    }
+
+    /// Record a pilot's death
+    pub fn lose(&mut self, pilot: usize, mission: &'static str) {
+        self.flying.retain(|&p| p != pilot);
+        self.fallen.push(FallenPilot { pilot, mission });
+    }
+
+    /// Memorial lines for the fallen
+    pub fn memorial(&self) -> Vec<String> {
+        self.fallen
+            .iter()
+            .map(|f| {
+                format!(
+                    "{} - fell during {}",
+                    ELDER_FLEET_PILOTS[f.pilot].full_name(),
+                    f.mission
+                )
+            })
+            .collect()
+    }
+
+    pub fn reset(&mut self) {
+        self.flying.clear();
+        self.fallen.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roster_recruits_in_order_and_never_revives_the_fallen() {
+        let mut roster = WingmanRoster::default();
+        assert_eq!(roster.recruit(), Some(0));
+        assert_eq!(roster.recruit(), Some(1));
+
+        roster.lose(0, "Convoy Ambush");
+        assert_eq!(roster.flying, vec![1]);
+        assert_eq!(roster.recruit(), Some(2));
+        assert_eq!(
+            roster.memorial(),
+            vec!["Keva 'Ember' Arnoldt - fell during Convoy Ambush".to_string()]
+        );
+
+        // Everyone else recruited: the wing runs out of pilots
+        while roster.recruit().is_some() {}
+        assert_eq!(roster.flying.len(), ELDER_FLEET_PILOTS.len() - 1);
+        assert!(!roster.flying.contains(&0));
+
+        roster.reset();
+        assert_eq!(roster.recruit(), Some(0));
+    }
+
+    #[test]
+    fn every_pilot_has_lines_for_every_moment() {
+        for pilot in ELDER_FLEET_PILOTS {
+            for kind in [
+                ChatterKind::Deploy,
+                ChatterKind::Kill,
+                ChatterKind::Hit,
+                ChatterKind::Down,
+            ] {
+                assert!(!pilot.personality.lines(kind).is_empty());
+            }
+            assert_eq!(pilot_portrait(&pilot.full_name()), Some(pilot.portrait));
+        }
+    }
+}
@@ -3,9 +3,11 @@
 //! Caldari vs Gallente faction warfare over Caldari Prime.
 
 use super::{ActiveModule, FactionInfo, GameModuleInfo, ModuleRegistry};
-use crate::core::{Difficulty, Faction, GameSession, GameState, LAYER_PLAYER_BULLETS};
+use crate::core::{
+    Difficulty, Faction, GameSession, GameState, RunRng, ScoreWeights, LAYER_PLAYER_BULLETS,
+};
 use crate::entities::projectile::ProjectilePhysics;
-use crate::systems::JoystickState;
+use crate::systems::{pad_prompt, JoystickState, PadGlyphs};
 use bevy::ecs::schedule::common_conditions::not;
 use bevy::prelude::*;
 
@@ -131,6 +133,7 @@ impl Plugin for CaldariGallentePlugin {
                 update_last_stand_hud,
                 spawn_last_stand_enemies,
                 update_titan_fighters,
+                update_ecm_burst,
             )
                 .chain()
                 .run_if(in_state(GameState::Playing))
@@ -717,6 +720,7 @@ fn spawn_nightmare_enemies(
     nightmare: Res<ShiigeruNightmare>,
     session: Res<GameSession>,
     spawn_requests: Query<(Entity, &NightmareSpawnRequest)>,
+    mut run_rng: ResMut<RunRng>,
 ) {
     use crate::entities::enemy::{spawn_enemy, EnemyBehavior};
 
@@ -740,11 +744,12 @@ fn spawn_nightmare_enemies(
                 for i in 0..count {
                     // Spread spawn positions across top of screen
                     let x = -300.0 + (i as f32 * 600.0 / count.max(1) as f32);
-                    let y = 300.0 + fastrand::f32() * 50.0;
+                    let rng = &mut run_rng.spawns;
+                    let y = 300.0 + rng.f32() * 50.0;
 
                     // Random enemy type and behavior
-                    let type_id = enemy_types[fastrand::usize(..enemy_types.len())];
-                    let behavior = match fastrand::u32(0..4) {
+                    let type_id = enemy_types[rng.usize(..enemy_types.len())];
+                    let behavior = match rng.u32(0..4) {
                         0 => EnemyBehavior::Linear,
                         1 => EnemyBehavior::Zigzag,
                         2 => EnemyBehavior::Homing,
@@ -1011,6 +1016,7 @@ fn spawn_cg_wave(
     enemy_query: Query<Entity, With<crate::entities::Enemy>>,
     boss_query: Query<Entity, With<CGBoss>>,
     mut next_state: ResMut<NextState<GameState>>,
+    mut run_rng: ResMut<RunRng>,
 ) {
     use crate::entities::enemy::{spawn_enemy, EnemyBehavior};
 
@@ -1051,12 +1057,13 @@ fn spawn_cg_wave(
         Faction::Minmatar => vec![587, 585, 598], // Rifter, Slasher, Breacher
     };
 
+    let rng = &mut run_rng.spawns;
     for i in 0..count {
-        let type_id = enemy_types[fastrand::usize(..enemy_types.len())];
+        let type_id = enemy_types[rng.usize(..enemy_types.len())];
         let x = (i as f32 - count as f32 / 2.0) * 80.0;
         let y = 300.0 + 50.0 + (i as f32 * 20.0);
 
-        let behavior = match fastrand::u32(0..4) {
+        let behavior = match rng.u32(0..4) {
             0 => EnemyBehavior::Linear,
             1 => EnemyBehavior::Zigzag,
             2 => EnemyBehavior::Homing,
@@ -1114,9 +1121,8 @@ fn spawn_cg_boss(
     let sprite = if type_id > 0 {
         if let Some(texture) = sprite_cache.get(type_id) {
             Sprite {
-                image: texture,
                 custom_size: Some(Vec2::splat(size)),
-                ..default()
+                ..texture.sprite()
             }
         } else {
             // Fallback color based on enemy faction
@@ -1415,7 +1421,8 @@ fn update_cg_boss(
             let scaled_damage = base_damage * difficulty.enemy_damage_mult();
 
             commands.spawn((
-                crate::entities::EnemyProjectile,
+                crate::entities::ProjectileOwner::enemy(),
+                crate::systems::BossProjectile,
                 crate::entities::ProjectileDamage {
                     damage: scaled_damage,
                     damage_type: crate::core::DamageType::EM,
@@ -1585,7 +1592,7 @@ fn spawn_cg_stage_complete(
 
             // Controller hint
             parent.spawn((
-                Text::new("A/ENTER Continue • B/ESC Main Menu"),
+                crate::ui::PadPrompt::new("A/ENTER Continue • B/ESC Main Menu"),
                 TextFont {
                     font_size: 14.0,
                     ..default()
@@ -1600,6 +1607,7 @@ fn cg_stage_complete_input(
     joystick: Res<JoystickState>,
     mut cg_campaign: ResMut<CGCampaignState>,
     mut transitions: EventWriter<crate::ui::TransitionEvent>,
+    mut campaign_events: EventWriter<crate::core::CampaignCompleteEvent>,
 ) {
     if keyboard.just_pressed(KeyCode::Space)
         || keyboard.just_pressed(KeyCode::Enter)
@@ -1611,6 +1619,9 @@ fn cg_stage_complete_input(
             transitions.send(crate::ui::TransitionEvent::to(GameState::Playing));
         } else {
             // Campaign complete!
+            campaign_events.send(crate::core::CampaignCompleteEvent {
+                module: crate::core::CALDARI_PRIME,
+            });
             transitions.send(crate::ui::TransitionEvent::slow(GameState::Victory));
         }
     }
@@ -1651,6 +1662,7 @@ fn spawn_cg_victory_screen(
     session: Res<GameSession>,
     cg_campaign: Res<CGCampaignState>,
     mut save_data: ResMut<crate::core::SaveData>,
+    playfield: Res<crate::core::Playfield>,
 ) {
     // Determine faction-specific content
     let (header, subtitle, quote, author, motto, particle_color1, particle_color2) =
@@ -1696,8 +1708,8 @@ fn spawn_cg_victory_screen(
 
     // Spawn celebration particles
     for _ in 0..60 {
-        let x = (fastrand::f32() - 0.5) * crate::core::SCREEN_WIDTH;
-        let y = -crate::core::SCREEN_HEIGHT / 2.0 - fastrand::f32() * 100.0;
+        let x = (fastrand::f32() - 0.5) * playfield.size.x;
+        let y = -playfield.half_height() - fastrand::f32() * 100.0;
         let vx = (fastrand::f32() - 0.5) * 100.0;
         let vy = 80.0 + fastrand::f32() * 120.0;
         let size = 4.0 + fastrand::f32() * 8.0;
@@ -2009,6 +2021,12 @@ fn register_module(mut registry: ResMut<ModuleRegistry>) {
                 description: "Freedom through firepower.",
             },
         ],
+        // A front-line war: holding ground counts, scavenging less so
+        score_weights: ScoreWeights {
+            objective: 1.5,
+            salvage: 0.75,
+            ..Default::default()
+        },
     });
 }
 
@@ -2716,6 +2734,8 @@ fn update_last_stand(
                     custom_text: None,
                     duration: 4.0,
                     priority: 2,
+                    speaker: None,
+                    voice: None,
                 });
                 info!("EVACUATION MILESTONE: {}", message);
             }
@@ -2730,6 +2750,8 @@ fn update_last_stand(
                 custom_text: None,
                 duration: 10.0,
                 priority: 3,
+                speaker: None,
+                voice: None,
             });
             info!("EVACUATION COMPLETE - Awaiting descent confirmation");
         }
@@ -2848,6 +2870,7 @@ fn last_stand_input(
 /// Update Last Stand HUD elements
 fn update_last_stand_hud(
     last_stand: Res<LastStandState>,
+    glyphs: Res<PadGlyphs>,
     mut hud_query: Query<(&mut Text, &mut TextColor, &LastStandHudElement)>,
 ) {
     for (mut text, mut color, element) in hud_query.iter_mut() {
@@ -2892,32 +2915,41 @@ fn update_last_stand_hud(
                 match action {
                     LastStandAction::FighterLaunch => {
                         if last_stand.fighter_cooldown > 0.0 {
-                            **text = format!("[RT] Fighter ({:.1}s)", last_stand.fighter_cooldown);
+                            **text = pad_prompt(
+                                &format!("[RT] Fighter ({:.1}s)", last_stand.fighter_cooldown),
+                                *glyphs,
+                            );
                         } else if last_stand.fighters_remaining == 0 {
-                            **text = "[RT] No Fighters".to_string();
+                            **text = pad_prompt("[RT] No Fighters", *glyphs);
                         } else {
-                            **text = "[RT] Fighter Launch".to_string();
+                            **text = pad_prompt("[RT] Fighter Launch", *glyphs);
                         }
                     }
                     LastStandAction::EcmBurst => {
                         if last_stand.ecm_cooldown > 0.0 {
-                            **text = format!("[LB] ECM ({:.1}s)", last_stand.ecm_cooldown);
+                            **text = pad_prompt(
+                                &format!("[LB] ECM ({:.1}s)", last_stand.ecm_cooldown),
+                                *glyphs,
+                            );
                         } else {
-                            **text = "[LB] ECM Burst".to_string();
+                            **text = pad_prompt("[LB] ECM Burst", *glyphs);
                         }
                     }
                     LastStandAction::ShieldBooster => {
                         if last_stand.shield_cooldown > 0.0 {
-                            **text = format!("[RB] Shield ({:.1}s)", last_stand.shield_cooldown);
+                            **text = pad_prompt(
+                                &format!("[RB] Shield ({:.1}s)", last_stand.shield_cooldown),
+                                *glyphs,
+                            );
                         } else {
-                            **text = "[RB] Shield Booster".to_string();
+                            **text = pad_prompt("[RB] Shield Booster", *glyphs);
                         }
                     }
                     LastStandAction::Doomsday => {
                         if !last_stand.doomsday_available {
-                            **text = "[Y] DOOMSDAY (USED)".to_string();
+                            **text = pad_prompt("[Y] DOOMSDAY (USED)", *glyphs);
                         } else {
-                            **text = "[Y] DOOMSDAY".to_string();
+                            **text = pad_prompt("[Y] DOOMSDAY", *glyphs);
                         }
                     }
                     _ => {}
@@ -2926,7 +2958,7 @@ fn update_last_stand_hud(
             }
             LastStandHudElement::Message => {
                 if last_stand.in_descent && !last_stand.descent_confirmed {
-                    **text = "Press [A/SPACE] to confirm descent".to_string();
+                    **text = pad_prompt("Press [A/SPACE] to confirm descent", *glyphs);
                     *color = TextColor(Color::srgb(1.0, 0.8, 0.2));
                 } else if last_stand.descent_confirmed {
                     **text = "Final approach... The State will remember.".to_string();
@@ -2943,6 +2975,7 @@ fn spawn_last_stand_enemies(
     last_stand: Res<LastStandState>,
     mut commands: Commands,
     enemy_query: Query<Entity, With<crate::entities::Enemy>>,
+    mut run_rng: ResMut<RunRng>,
     mut spawn_timer: Local<f32>,
 ) {
     if !last_stand.active || last_stand.in_descent {
@@ -2971,9 +3004,9 @@ fn spawn_last_stand_enemies(
     use crate::entities::enemy::{spawn_enemy, EnemyBehavior};
 
     for _i in 0..count {
-        let x = (fastrand::f32() - 0.5) * 600.0;
+        let x = (run_rng.spawns.f32() - 0.5) * 600.0;
         let y = 350.0;
-        let type_id = [608, 594, 593][fastrand::usize(0..3)]; // Gallente frigates
+        let type_id = [608, 594, 593][run_rng.spawns.usize(0..3)]; // Gallente frigates
 
         spawn_enemy(
             &mut commands,
@@ -2986,6 +3019,41 @@ fn spawn_last_stand_enemies(
     }
 }
 
+/// Expand ECM bursts, EMP-slowing every enemy caught in the ring
+fn update_ecm_burst(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut burst_query: Query<(Entity, &mut last_stand::EcmBurst, &Transform, &mut Sprite)>,
+    enemy_query: Query<(Entity, &Transform), With<crate::entities::Enemy>>,
+    mut status_events: EventWriter<crate::systems::StatusEffectEvent>,
+) {
+    let dt = time.delta_secs();
+
+    for (entity, mut burst, transform, mut sprite) in burst_query.iter_mut() {
+        burst.lifetime -= dt;
+        if burst.lifetime <= 0.0 {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+
+        burst.radius += burst.speed * dt;
+        sprite.custom_size = Some(Vec2::splat(burst.radius * 2.0));
+        sprite.color = sprite.color.with_alpha(0.5 * burst.lifetime);
+
+        let center = transform.translation.truncate();
+        for (enemy, enemy_transform) in enemy_query.iter() {
+            if enemy_transform.translation.truncate().distance(center) <= burst.radius {
+                // Re-sending refreshes the slow; it doesn't stack
+                status_events.send(crate::systems::StatusEffectEvent {
+                    target: enemy,
+                    kind: crate::systems::StatusKind::EmpSlow,
+                    duration: 4.0,
+                });
+            }
+        }
+    }
+}
+
 /// Despawn all Last Stand entities
 fn despawn_last_stand(
     mut commands: Commands,
@@ -4,6 +4,7 @@
 //! The original EVE Rebellion campaign - 13 missions across 3 acts.
 
 use super::{ActiveModule, FactionInfo, GameModuleInfo, ModuleRegistry};
+use crate::core::ScoreWeights;
 use bevy::prelude::*;
 
 pub mod ships;
@@ -52,6 +53,12 @@ fn register_module(mut registry: ResMut<ModuleRegistry>) {
                 description: "Crush the rebel insurrection. Restore order through strength.",
             },
         ],
+        // A liberation war: freeing slaves pays as well as killing masters
+        score_weights: ScoreWeights {
+            salvage: 1.25,
+            escort: 1.5,
+            ..Default::default()
+        },
     });
 }
 
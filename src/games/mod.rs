@@ -5,6 +5,7 @@
 
 #![allow(dead_code)]
 
+use crate::core::ScoreWeights;
 use bevy::prelude::*;
 
 pub mod caldari_gallente;
@@ -36,6 +37,16 @@ impl ModuleRegistry {
     pub fn get(&self, id: &str) -> Option<&GameModuleInfo> {
         self.modules.iter().find(|m| m.id == id)
     }
+
+    /// Score weights for the module being played (even weights outside one)
+    pub fn score_weights(&self, active: &ActiveModule) -> ScoreWeights {
+        active
+            .module_id
+            .as_deref()
+            .and_then(|id| self.get(id))
+            .map(|m| m.score_weights)
+            .unwrap_or_default()
+    }
 }
 
 /// Information about a game module
@@ -46,6 +57,8 @@ pub struct GameModuleInfo {
     pub subtitle: &'static str,
     pub description: &'static str,
     pub factions: Vec<FactionInfo>,
+    /// Multipliers on each scoring category while this module is played
+    pub score_weights: ScoreWeights,
 }
 
 /// Faction information
@@ -12,36 +12,68 @@ use bevy_egui::EguiPlugin;
 
 mod assets;
 mod core;
+mod core_logic;
 mod entities;
 mod games;
 mod systems;
 mod ui;
 
+#[cfg(test)]
+mod app_tests;
+
 use assets::AssetsPlugin;
 use core::{
-    ActCompleteEvent, AudioSettings, BerserkSystem, BossSpawnEvent, CampaignState, CurrentStage,
-    Difficulty, EndlessMode, GameEventsPlugin, GameProgress, GameSession, GameState, InputConfig,
-    MissionCompleteEvent, MissionStartEvent, SavePlugin, ScoreSystem, SelectedShip, ShipUnlocks,
-    WaveCompleteEvent,
+    AttackResult, AudioSettings, BattleLogPlugin, BerserkSystem, CampaignState, CloudSyncPlugin,
+    CrashPlugin, CurrentStage, DailyChallenge, Difficulty, DisplayPlugin, EndlessMode,
+    EventLogPlugin, GameEventsPlugin, GameProgress, GameSession, GameState, InputConfig,
+    LoggingConfig, PlayfieldPlugin, RunMode, RunRng, SavePlugin, SchedulePlugin, ScoreSystem,
+    SelectedShip, SettingsFilePlugin, ShipUnlocks, StateCleanupPlugin, WaveTally,
 };
 use entities::EntitiesPlugin;
 use games::GameModulesPlugin;
-use systems::SystemsPlugin;
+use systems::{GhostFilesPlugin, SystemsPlugin};
 use ui::UiPlugin;
 
 fn main() {
-    App::new()
+    // Logging is set up while the plugins build, so its config comes first
+    let logging = LoggingConfig::load();
+    let log_plugin = logging.log_plugin(core::debug_requested());
+
+    let mut app = App::new();
+    app.insert_resource(logging)
         // Bevy plugins
-        .add_plugins(DefaultPlugins.set(WindowPlugin {
-            primary_window: Some(Window {
-                title: core::WINDOW_TITLE.into(),
-                resolution: (core::SCREEN_WIDTH, core::SCREEN_HEIGHT).into(),
-                resizable: true,
-                ..default()
-            }),
-            ..default()
-        }))
+        .add_plugins(
+            DefaultPlugins
+                .set(WindowPlugin {
+                    primary_window: Some(Window {
+                        title: core::WINDOW_TITLE.into(),
+                        resolution: (core::SCREEN_WIDTH, core::SCREEN_HEIGHT).into(),
+                        resizable: true,
+                        ..default()
+                    }),
+                    ..default()
+                })
+                .set(log_plugin),
+        )
         .add_plugins(EguiPlugin)
+        // Platform plugins: panic hook, save files and cloud sync, race ghosts,
+        // sprite downloads
+        .add_plugins((
+            CrashPlugin,
+            SavePlugin,
+            CloudSyncPlugin,
+            SettingsFilePlugin,
+            GhostFilesPlugin,
+            AssetsPlugin,
+        ));
+    add_game(&mut app);
+    app.run();
+}
+
+/// Game state, resources and gameplay plugins.
+/// Shared with the headless app tests, so nothing here may touch disk or network.
+fn add_game(app: &mut App) {
+    app
         // Game state
         .init_state::<GameState>()
         // Resources
@@ -57,25 +89,28 @@ fn main() {
         .init_resource::<CampaignState>()
         .init_resource::<GameSession>()
         .init_resource::<EndlessMode>()
-        // Campaign events
-        .add_event::<MissionStartEvent>()
-        .add_event::<MissionCompleteEvent>()
-        .add_event::<WaveCompleteEvent>()
-        .add_event::<BossSpawnEvent>()
-        .add_event::<ActCompleteEvent>()
+        .init_resource::<RunMode>()
+        .init_resource::<AttackResult>()
+        .init_resource::<RunRng>()
+        .init_resource::<RunCodes>()
+        .init_resource::<DailyChallenge>()
+        .init_resource::<WaveTally>()
         // Game plugins
         .add_plugins((
-            SavePlugin,
-            AssetsPlugin,
+            SchedulePlugin,
+            PlayfieldPlugin,
+            DisplayPlugin,
+            StateCleanupPlugin,
             GameEventsPlugin,
+            EventLogPlugin,
+            BattleLogPlugin,
             EntitiesPlugin,
             SystemsPlugin,
             UiPlugin,
             GameModulesPlugin,
         ))
         // Setup
-        .add_systems(Startup, setup)
-        .run();
+        .add_systems(Startup, setup);
 }
 
 /// Initial game setup
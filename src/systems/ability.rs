@@ -8,6 +8,7 @@ use bevy::prelude::*;
 use crate::core::game_state::GameState;
 use crate::entities::player::{Movement, Player, ShipStats};
 use crate::systems::joystick::JoystickState;
+use crate::systems::scoring_v2::ComboHeatSystem;
 
 /// Ability types matching ShipDef.special descriptions
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -148,6 +149,25 @@ impl AbilityType {
             AbilityType::None => "",
         }
     }
+
+    /// Module slot label for the capacitor wheel
+    pub fn short_name(&self) -> &'static str {
+        match self {
+            AbilityType::Overdrive => "OVD",
+            AbilityType::Afterburner => "AB",
+            AbilityType::RocketBarrage => "RKT",
+            AbilityType::Scorch => "SCH",
+            AbilityType::ArmorHardener => "HRD",
+            AbilityType::DeployDrone => "DRN",
+            AbilityType::Salvo => "SLV",
+            AbilityType::ShieldBoost => "SB",
+            AbilityType::WarpDisruptor => "WD",
+            AbilityType::DroneBay => "BAY",
+            AbilityType::ArmorRepair => "REP",
+            AbilityType::CloseRange => "CRG",
+            AbilityType::None => "",
+        }
+    }
 }
 
 /// Ability component attached to player
@@ -265,13 +285,15 @@ impl Plugin for AbilityPlugin {
 fn ability_input(
     keyboard: Res<ButtonInput<KeyCode>>,
     joystick: Res<JoystickState>,
+    heat_system: Res<ComboHeatSystem>,
     mut query: Query<(Entity, &mut Ability, &mut ShipStats), With<Player>>,
     mut events: EventWriter<AbilityActivatedEvent>,
 ) {
     let ability_pressed =
         keyboard.just_pressed(KeyCode::ShiftLeft) || joystick.right_trigger_pressed();
 
-    if !ability_pressed {
+    // Module burnt out from an overload
+    if !ability_pressed || heat_system.is_burned_out() {
         return;
     }
 
@@ -359,7 +381,10 @@ fn ability_apply_effects(
             AbilityType::CloseRange => {
                 effects.damage_dealt_multiplier = 2.0;
             }
-            AbilityType::WarpDisruptor | AbilityType::DeployDrone | AbilityType::DroneBay => {
+            AbilityType::WarpDisruptor => {
+                // Webs nearby enemies - handled in status_effects
+            }
+            AbilityType::DeployDrone | AbilityType::DroneBay => {
                 // These spawn entities - handled elsewhere
             }
             AbilityType::None => {}
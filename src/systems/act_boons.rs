@@ -0,0 +1,70 @@
+//! Act Boons
+//!
+//! Puts the boon picked at the last interlude to work for the rest of the
+//! act: a wider wing, heavier rounds or a faster capacitor. Only campaign
+//! runs carry boons; everything else flies stock.
+
+#![allow(dead_code)]
+
+use crate::core::*;
+use crate::entities::{Player, ShipStats, Weapon, WingmanTracker, MAX_WINGMEN};
+use crate::games::ActiveModule;
+use crate::systems::is_campaign_run;
+use bevy::prelude::*;
+
+/// Act boon plugin
+pub struct ActBoonPlugin;
+
+impl Plugin for ActBoonPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Playing), set_wingman_cap)
+            .add_systems(
+                Update,
+                fit_act_boon
+                    .in_set(GameSet::Simulation)
+                    .run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight)))
+                    .run_if(is_campaign_run),
+            );
+    }
+}
+
+/// The boon for the act being flown, if this is a campaign run
+fn active_boon(
+    campaign: &CampaignState,
+    run_mode: Res<RunMode>,
+    active_module: Res<ActiveModule>,
+    endless: Res<EndlessMode>,
+) -> Option<ActBoon> {
+    if !is_campaign_run(run_mode, active_module, endless) {
+        return None;
+    }
+    campaign.flags.boon_for(campaign.act)
+}
+
+/// Wing Command widens the wing; any other run resets it
+fn set_wingman_cap(
+    campaign: Res<CampaignState>,
+    run_mode: Res<RunMode>,
+    active_module: Res<ActiveModule>,
+    endless: Res<EndlessMode>,
+    mut tracker: ResMut<WingmanTracker>,
+) {
+    let bonus = active_boon(&campaign, run_mode, active_module, endless)
+        .map_or(0, |boon| boon.wingman_bonus());
+    tracker.max_wingmen = MAX_WINGMEN + bonus;
+}
+
+/// Fit the launching ship with the act's boon
+fn fit_act_boon(
+    campaign: Res<CampaignState>,
+    mut players: Query<(&mut ShipStats, &mut Weapon), Added<Player>>,
+) {
+    let Some(boon) = campaign.flags.boon_for(campaign.act) else {
+        return;
+    };
+    for (mut stats, mut weapon) in players.iter_mut() {
+        weapon.damage *= boon.damage_mult();
+        stats.capacitor_recharge *= boon.cap_recharge_mult();
+        debug!("{} launches with {}", stats.name, boon.name());
+    }
+}
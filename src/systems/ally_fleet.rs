@@ -0,0 +1,407 @@
+//! Allied Fleet Cameos
+//!
+//! At story beats set in the mission data (`Mission::ally_fleet`) a flight
+//! of Republic ships warps in as a wave spawns, picks off the wave's ships
+//! for a while and warps out again, with their commander on comms both
+//! ways. The fleet leaves the boss to the player: it warps out as soon as
+//! the boss warning sounds, never aims at a boss, and its stray shots can't
+//! land the killing blow (see `boss::boss_health_after_hit`).
+
+#![allow(dead_code)]
+
+use super::{BossWarning, DialogueEvent};
+use crate::assets::ShipSpriteCache;
+use crate::core::*;
+use crate::entities::{Enemy, ProjectileDamage, ProjectileOwner, ProjectilePhysics, Team};
+use crate::games::ActiveModule;
+use bevy::ecs::schedule::common_conditions::not;
+use bevy::prelude::*;
+
+/// Hull points per fleet ship
+const FLEET_HULL: f32 = 120.0;
+
+/// Volleys per second, and damage per shot
+const FLEET_FIRE_RATE: f32 = 1.6;
+const FLEET_DAMAGE: f32 = 7.0;
+
+/// Furthest a fleet ship will shoot at an enemy
+const FLEET_RANGE: f32 = 520.0;
+
+/// How far a ship leaves its slot to line up on a target
+const FLEET_LEASH: f32 = 90.0;
+
+/// Cruising and warping speeds
+const FLEET_SPEED: f32 = 220.0;
+const WARP_SPEED: f32 = 900.0;
+
+/// Widest gap between neighbouring ships
+const SLOT_SPACING: f32 = 110.0;
+
+/// Height of the fleet's line, as a share of the playfield's half height
+const LINE_HEIGHT: f32 = 0.35;
+
+/// Flash where a fleet ship warps in or out
+const WARP_COLOR: Color = Color::srgb(0.6, 0.8, 1.0);
+
+/// Allied fleet plugin
+pub struct AllyFleetPlugin;
+
+impl Plugin for AllyFleetPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AllyFleet>().add_systems(
+            Update,
+            (
+                reset_ally_fleet,
+                call_ally_fleet,
+                dismiss_ally_fleet,
+                fly_ally_fleet,
+                ally_fleet_shooting,
+                ally_fleet_damage,
+            )
+                .chain()
+                .in_set(GameSet::Simulation)
+                .run_if(in_state(GameState::Playing))
+                .run_if(not(is_cg_module)),
+        );
+    }
+}
+
+/// Run condition: the Caldari/Gallente module runs its own campaign
+fn is_cg_module(active_module: Res<ActiveModule>) -> bool {
+    active_module.is_caldari_gallente()
+}
+
+/// What a fleet ship is doing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FleetPhase {
+    /// Dropping out of warp onto its slot
+    WarpIn,
+    /// Holding the line and shooting
+    Engage,
+    /// Leaving the field
+    WarpOut,
+}
+
+/// One ship of an allied fleet
+#[derive(Component, Debug)]
+#[require(StateScope)]
+pub struct AllyFleetShip {
+    pub slot_x: f32,
+    pub health: f32,
+    pub cooldown: f32,
+    pub phase: FleetPhase,
+}
+
+/// A shot fired by the allied fleet
+#[derive(Component, Debug)]
+pub struct FleetShot;
+
+/// The cameo on the field and the ones already called this mission
+#[derive(Resource, Debug, Default)]
+pub struct AllyFleet {
+    /// Fleet on the field, if any
+    pub cameo: Option<&'static FleetCameo>,
+    /// Seconds before it warps out
+    pub remaining: f32,
+    /// Waves whose fleet has already come
+    pub called: Vec<u32>,
+}
+
+/// Slot x positions for `count` ships, spread evenly across the field
+pub fn fleet_slots(count: usize, half_width: f32) -> Vec<f32> {
+    let spacing = SLOT_SPACING.min(half_width * 1.6 / count.max(1) as f32);
+    (0..count)
+        .map(|i| (i as f32 - (count as f32 - 1.0) / 2.0) * spacing)
+        .collect()
+}
+
+/// Nearest enemy within range of `from`
+pub fn fleet_target(from: Vec2, enemies: impl Iterator<Item = Vec2>) -> Option<Vec2> {
+    enemies
+        .filter(|enemy| enemy.distance(from) <= FLEET_RANGE)
+        .min_by(|a, b| {
+            a.distance_squared(from)
+                .total_cmp(&b.distance_squared(from))
+        })
+}
+
+fn reset_ally_fleet(
+    mut events: EventReader<MissionStartEvent>,
+    mut fleet: ResMut<AllyFleet>,
+    mut ships: Query<&mut AllyFleetShip>,
+) {
+    if events.read().last().is_none() {
+        return;
+    }
+    *fleet = AllyFleet::default();
+    for mut ship in ships.iter_mut() {
+        ship.phase = FleetPhase::WarpOut;
+    }
+}
+
+/// Bring the fleet in once its wave has spawned
+fn call_ally_fleet(
+    mut commands: Commands,
+    campaign: Res<CampaignState>,
+    mut fleet: ResMut<AllyFleet>,
+    playfield: Res<Playfield>,
+    sprite_cache: Res<ShipSpriteCache>,
+    mut run_rng: ResMut<RunRng>,
+    ships: Query<(), With<AllyFleetShip>>,
+    mut dialogue_events: EventWriter<DialogueEvent>,
+) {
+    if fleet.cameo.is_some() || !ships.is_empty() {
+        return;
+    }
+    let Some(cameo) = campaign
+        .current_mission()
+        .into_iter()
+        .flat_map(|mission| mission.ally_fleet)
+        .find(|cameo| cameo.wave <= campaign.spawned_wave() && !fleet.called.contains(&cameo.wave))
+    else {
+        return;
+    };
+
+    fleet.called.push(cameo.wave);
+    fleet.cameo = Some(cameo);
+    fleet.remaining = cameo.duration;
+
+    let top = playfield.half_height() + 60.0;
+    for (&type_id, slot_x) in cameo
+        .ships
+        .iter()
+        .zip(fleet_slots(cameo.ships.len(), playfield.half_width()))
+    {
+        let sprite = match sprite_cache.get(type_id) {
+            Some(ship) => Sprite {
+                custom_size: Some(Vec2::splat(SIZE_FRIGATE)),
+                ..ship.sprite()
+            },
+            None => Sprite {
+                color: COLOR_MINMATAR,
+                custom_size: Some(Vec2::new(SIZE_FRIGATE * 0.85, SIZE_FRIGATE)),
+                ..default()
+            },
+        };
+        commands.spawn((
+            AllyFleetShip {
+                slot_x,
+                health: FLEET_HULL,
+                cooldown: run_rng.combat.f32() / FLEET_FIRE_RATE,
+                phase: FleetPhase::WarpIn,
+            },
+            sprite,
+            // Warp in from below, facing up the field like the player
+            Transform::from_xyz(slot_x, -top, LAYER_PLAYER - 0.5),
+        ));
+    }
+
+    info!(
+        "Allied fleet warps in for wave {} ({} ships)",
+        cameo.wave,
+        cameo.ships.len()
+    );
+    dialogue_events.send(DialogueEvent {
+        duration: 4.0,
+        priority: 6,
+        ..DialogueEvent::pilot_chatter(cameo.commander.to_string(), cameo.arrival)
+    });
+}
+
+/// Send the fleet home when its time is up or the boss is on the way
+fn dismiss_ally_fleet(
+    time: Res<Time>,
+    campaign: Res<CampaignState>,
+    boss_warning: Res<BossWarning>,
+    mut fleet: ResMut<AllyFleet>,
+    mut ships: Query<&mut AllyFleetShip>,
+    mut dialogue_events: EventWriter<DialogueEvent>,
+) {
+    let Some(cameo) = fleet.cameo else {
+        return;
+    };
+    fleet.remaining -= time.delta_secs();
+    if fleet.remaining > 0.0 && boss_warning.is_idle() && !campaign.boss_spawned {
+        return;
+    }
+
+    fleet.cameo = None;
+    for mut ship in ships.iter_mut() {
+        ship.phase = FleetPhase::WarpOut;
+    }
+    if !ships.is_empty() {
+        dialogue_events.send(DialogueEvent {
+            duration: 3.5,
+            priority: 5,
+            ..DialogueEvent::pilot_chatter(cameo.commander.to_string(), cameo.departure)
+        });
+    }
+}
+
+/// Warp in, hold the line leaning toward targets, warp out
+fn fly_ally_fleet(
+    mut commands: Commands,
+    time: Res<Time>,
+    playfield: Res<Playfield>,
+    enemies: Query<&Transform, (With<Enemy>, Without<AllyFleetShip>)>,
+    mut ships: Query<(Entity, &mut Transform, &mut AllyFleetShip)>,
+    mut explosion_events: EventWriter<ExplosionEvent>,
+) {
+    let dt = time.delta_secs();
+    let line_y = playfield.half_height() * -LINE_HEIGHT;
+
+    for (entity, mut transform, mut ship) in ships.iter_mut() {
+        let pos = transform.translation.truncate();
+        let slot = Vec2::new(ship.slot_x, line_y);
+
+        let (target, speed) = match ship.phase {
+            FleetPhase::WarpIn => (slot, WARP_SPEED),
+            FleetPhase::Engage => {
+                let target_x = fleet_target(pos, enemies.iter().map(|t| t.translation.truncate()))
+                    .map_or(slot.x, |enemy| {
+                        enemy.x.clamp(slot.x - FLEET_LEASH, slot.x + FLEET_LEASH)
+                    });
+                (Vec2::new(target_x, line_y), FLEET_SPEED)
+            }
+            // Back out the way they came
+            FleetPhase::WarpOut => (
+                Vec2::new(pos.x, -playfield.half_height() - 200.0),
+                WARP_SPEED,
+            ),
+        };
+
+        let delta = target - pos;
+        let step = (speed * dt).min(delta.length());
+        let next = pos + delta.normalize_or_zero() * step;
+        transform.translation.x = next.x;
+        transform.translation.y = next.y;
+
+        match ship.phase {
+            FleetPhase::WarpIn if next.distance(slot) < 2.0 => {
+                ship.phase = FleetPhase::Engage;
+                explosion_events.send(ExplosionEvent {
+                    position: next,
+                    size: ExplosionSize::Tiny,
+                    color: WARP_COLOR,
+                });
+            }
+            FleetPhase::WarpOut if next.y < -playfield.half_height() - 60.0 => {
+                commands.entity(entity).despawn_recursive();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Engaged fleet ships fire aimed shots at the nearest enemy. Bosses aren't
+/// `Enemy`s, so the fleet never aims at one.
+fn ally_fleet_shooting(
+    mut commands: Commands,
+    time: Res<Time>,
+    enemies: Query<&Transform, (With<Enemy>, Without<AllyFleetShip>)>,
+    mut ships: Query<(Entity, &Transform, &mut AllyFleetShip)>,
+) {
+    let dt = time.delta_secs();
+
+    for (entity, transform, mut ship) in ships.iter_mut() {
+        ship.cooldown -= dt;
+        if ship.phase != FleetPhase::Engage || ship.cooldown > 0.0 {
+            continue;
+        }
+        let pos = transform.translation.truncate();
+        let Some(target) = fleet_target(pos, enemies.iter().map(|t| t.translation.truncate()))
+        else {
+            continue;
+        };
+        ship.cooldown = 1.0 / FLEET_FIRE_RATE;
+
+        let dir = (target - pos).normalize_or(Vec2::Y);
+        commands.spawn((
+            FleetShot,
+            ProjectileOwner::player().fired_by(entity),
+            ProjectilePhysics {
+                velocity: dir * PLAYER_BULLET_SPEED * 0.85,
+                lifetime: 1.5,
+            },
+            ProjectileDamage {
+                damage: FLEET_DAMAGE,
+                damage_type: DamageType::Kinetic,
+                crit_chance: 0.1,
+                crit_multiplier: 1.5,
+            },
+            Sprite {
+                color: COLOR_MINMATAR,
+                custom_size: Some(Vec2::new(3.0, 10.0)),
+                ..default()
+            },
+            Transform::from_xyz(pos.x, pos.y + 16.0, LAYER_PLAYER_BULLETS).with_rotation(
+                Quat::from_rotation_z(dir.to_angle() - std::f32::consts::FRAC_PI_2),
+            ),
+        ));
+    }
+}
+
+/// Enemy fire wears the fleet down
+fn ally_fleet_damage(
+    mut commands: Commands,
+    projectiles: Query<(Entity, &Transform, &ProjectileDamage, &ProjectileOwner)>,
+    mut ships: Query<(Entity, &Transform, &mut AllyFleetShip)>,
+    mut explosion_events: EventWriter<ExplosionEvent>,
+) {
+    for (proj_entity, proj_transform, damage, owner) in projectiles.iter() {
+        let proj_pos = proj_transform.translation.truncate();
+
+        for (entity, transform, mut ship) in ships.iter_mut() {
+            if ship.health <= 0.0 || !owner.hits_ship(entity, Team::Player) {
+                continue;
+            }
+            let pos = transform.translation.truncate();
+            if proj_pos.distance(pos) >= 22.0 {
+                continue;
+            }
+
+            commands.entity(proj_entity).despawn_recursive();
+            ship.health -= damage.damage;
+            if ship.health <= 0.0 {
+                commands.entity(entity).despawn_recursive();
+                explosion_events.send(ExplosionEvent {
+                    position: pos,
+                    size: ExplosionSize::Small,
+                    color: COLOR_MINMATAR,
+                });
+                info!("Allied fleet ship destroyed");
+            }
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fleet_spreads_evenly_and_fits_narrow_fields() {
+        let slots = fleet_slots(5, 400.0);
+        assert_eq!(slots, [-220.0, -110.0, 0.0, 110.0, 220.0]);
+
+        let narrow = fleet_slots(6, 200.0);
+        assert!(narrow.iter().all(|x| x.abs() < 200.0));
+        assert_eq!(narrow[0], -narrow[5]);
+    }
+
+    #[test]
+    fn fleet_shoots_the_nearest_enemy_in_range() {
+        let from = Vec2::ZERO;
+        let enemies = [
+            Vec2::new(0.0, 300.0),
+            Vec2::new(-100.0, 100.0),
+            Vec2::new(0.0, FLEET_RANGE + 50.0),
+        ];
+        assert_eq!(
+            fleet_target(from, enemies.into_iter()),
+            Some(Vec2::new(-100.0, 100.0))
+        );
+        assert_eq!(fleet_target(from, enemies[2..].iter().copied()), None);
+    }
+}
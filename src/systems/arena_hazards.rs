@@ -0,0 +1,548 @@
+//! Boss Arena Hazards
+//!
+//! Puts a boss's `ArenaHazard` into the fight. Each hazard is telegraphed
+//! first - a dim, blinking outline that fills as it arms - and only hurts
+//! once it goes live:
+//! - Grid lasers sweep across the arena from an edge, each with a gap to
+//!   slip through; in the Stargate's last phase they come in crossing pairs.
+//! - Plasma vents burn a cone from the hull toward where the player was.
+//! - Burn zones are left where the Avatar's doomsday lands.
+//!
+//! Damage follows enemy damage scaling. Hazards die with the boss.
+
+#![allow(dead_code)]
+
+use super::ManeuverState;
+use crate::core::*;
+use crate::entities::{
+    ArenaHazard, ArenaHazardKind, Boss, BossData, BossState, Hitbox, Player, PowerupEffects,
+    ShipStats,
+};
+use bevy::prelude::*;
+use std::f32::consts::TAU;
+
+/// How fast a grid laser crosses the arena (pixels per second)
+pub const LASER_SWEEP_SPEED: f32 = 150.0;
+
+/// Width of the opening in each grid laser
+pub const LASER_GAP: f32 = 110.0;
+
+/// Half the thickness of a laser, for hits
+const LASER_HALF_WIDTH: f32 = 5.0;
+
+/// How far a plasma vent reaches from the hull's center
+const VENT_LENGTH: f32 = 360.0;
+
+/// Half the spread of a plasma vent (radians)
+const VENT_HALF_ANGLE: f32 = 0.35;
+
+/// Radius of a doomsday burn zone
+const BURN_RADIUS: f32 = 55.0;
+
+/// Spacing of the burn zones either side of the doomsday's target
+const BURN_SPREAD: f32 = 150.0;
+
+/// Seconds between hits while standing in a vent or burn zone
+const HAZARD_TICK: f32 = 0.5;
+
+/// Shorter gaps between hazards once the boss is enraged
+const ENRAGED_INTERVAL_MULT: f32 = 0.7;
+
+/// Phase from which the Stargate's lasers cross
+const GRID_CROSS_PHASE: u32 = 4;
+
+const LASER_COLOR: Color = Color::srgb(1.0, 0.2, 0.25);
+const PLASMA_COLOR: Color = Color::srgb(0.45, 0.75, 1.0);
+const BURN_COLOR: Color = Color::srgb(1.0, 0.45, 0.1);
+
+/// Arena hazard plugin
+pub struct ArenaHazardPlugin;
+
+impl Plugin for ArenaHazardPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                trigger_arena_hazards,
+                update_hazard_zones,
+                draw_hazard_zones,
+            )
+                .chain()
+                .in_set(GameSet::Simulation)
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
+
+/// Where a hazard reaches
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HazardShape {
+    /// A line across the arena through `origin`, running along `along` and
+    /// moving at `velocity`, open for `LASER_GAP` around `gap` along it
+    Laser {
+        origin: Vec2,
+        along: Vec2,
+        velocity: Vec2,
+        gap: f32,
+        /// Half its length
+        reach: f32,
+    },
+    /// A cone from `apex` around `direction`
+    Cone {
+        apex: Vec2,
+        direction: Vec2,
+        length: f32,
+        half_angle: f32,
+    },
+    Circle {
+        center: Vec2,
+        radius: f32,
+    },
+}
+
+impl HazardShape {
+    /// Whether something of radius `margin` at `point` is caught in it
+    pub fn contains(&self, point: Vec2, margin: f32) -> bool {
+        match *self {
+            HazardShape::Laser {
+                origin, along, gap, ..
+            } => {
+                let offset = point - origin;
+                let across = offset.dot(along.perp()).abs();
+                let t = offset.dot(along);
+                across <= LASER_HALF_WIDTH + margin && (t - gap).abs() > LASER_GAP / 2.0 - margin
+            }
+            HazardShape::Cone {
+                apex,
+                direction,
+                length,
+                half_angle,
+            } => {
+                let offset = point - apex;
+                let distance = offset.length();
+                if distance > length + margin {
+                    return false;
+                }
+                // Close to the apex everything is inside
+                if distance <= margin {
+                    return true;
+                }
+                let spread = (margin / distance).asin();
+                direction.angle_to(offset).abs() <= half_angle + spread
+            }
+            HazardShape::Circle { center, radius } => point.distance(center) <= radius + margin,
+        }
+    }
+
+    /// Move lasers along their sweep
+    fn advance(&mut self, dt: f32) {
+        if let HazardShape::Laser {
+            origin, velocity, ..
+        } = self
+        {
+            *origin += *velocity * dt;
+        }
+    }
+
+    fn damage_type(&self) -> DamageType {
+        match self {
+            HazardShape::Laser { .. } => DamageType::EM,
+            HazardShape::Cone { .. } | HazardShape::Circle { .. } => DamageType::Thermal,
+        }
+    }
+
+    fn color(&self) -> Color {
+        match self {
+            HazardShape::Laser { .. } => LASER_COLOR,
+            HazardShape::Cone { .. } => PLASMA_COLOR,
+            HazardShape::Circle { .. } => BURN_COLOR,
+        }
+    }
+}
+
+/// A telegraphed or live hazard in the arena
+#[derive(Component, Debug, Clone)]
+#[require(StateScope)]
+pub struct HazardZone {
+    pub shape: HazardShape,
+    /// Total warning, for drawing the telegraph filling up
+    pub warning: f32,
+    /// Seconds of warning left; live once it runs out
+    pub telegraph: f32,
+    /// Seconds it stays live
+    pub remaining: f32,
+    /// Damage per hit, before difficulty scaling
+    pub damage: f32,
+    /// Seconds until it can hit the player again
+    pub cooldown: f32,
+    /// Name for the damage log
+    pub source: &'static str,
+}
+
+impl HazardZone {
+    fn new(hazard: &ArenaHazard, shape: HazardShape, remaining: f32, source: &'static str) -> Self {
+        Self {
+            shape,
+            warning: hazard.telegraph,
+            telegraph: hazard.telegraph,
+            remaining,
+            damage: hazard.damage,
+            cooldown: 0.0,
+            source,
+        }
+    }
+
+    pub fn is_live(&self) -> bool {
+        self.telegraph <= 0.0
+    }
+}
+
+/// A grid laser sweeping in from `edge` (0 top, 1 bottom, 2 left, 3 right)
+/// with its gap `gap` along the line from its middle
+pub fn grid_laser(edge: u32, gap: f32, playfield: &Playfield) -> (HazardShape, f32) {
+    let half = playfield.half();
+    let (origin, along, direction, span) = match edge % 4 {
+        0 => (Vec2::new(0.0, half.y), Vec2::X, Vec2::NEG_Y, half.y * 2.0),
+        1 => (Vec2::new(0.0, -half.y), Vec2::X, Vec2::Y, half.y * 2.0),
+        2 => (Vec2::new(-half.x, 0.0), Vec2::Y, Vec2::X, half.x * 2.0),
+        _ => (Vec2::new(half.x, 0.0), Vec2::Y, Vec2::NEG_X, half.x * 2.0),
+    };
+    let reach = if along == Vec2::X { half.x } else { half.y };
+    let shape = HazardShape::Laser {
+        origin,
+        along,
+        velocity: direction * LASER_SWEEP_SPEED,
+        gap,
+        reach,
+    };
+    (shape, span / LASER_SWEEP_SPEED)
+}
+
+/// Burn zones where a doomsday fired from `boss_pos` at `target` lands
+pub fn spawn_burn_zones(
+    commands: &mut Commands,
+    hazard: &ArenaHazard,
+    boss_pos: Vec2,
+    target: Vec2,
+) {
+    let side = (target - boss_pos).normalize_or(Vec2::NEG_Y).perp();
+    for offset in [-1.0, 0.0, 1.0] {
+        let shape = HazardShape::Circle {
+            center: target + side * offset * BURN_SPREAD,
+            radius: BURN_RADIUS,
+        };
+        commands.spawn(HazardZone::new(
+            hazard,
+            shape,
+            hazard.duration,
+            "Doomsday burn",
+        ));
+    }
+}
+
+/// Count each boss's hazard down and set it off
+fn trigger_arena_hazards(
+    mut commands: Commands,
+    time: Res<Time>,
+    playfield: Res<Playfield>,
+    mut run_rng: ResMut<RunRng>,
+    mut bosses: Query<(&Transform, &BossData, &BossState, &mut ArenaHazard), With<Boss>>,
+    player: Query<&Transform, (With<Player>, Without<Boss>)>,
+) {
+    let dt = time.delta_secs();
+    let player_pos = player.get_single().map(|t| t.translation.truncate());
+
+    for (transform, data, state, mut hazard) in bosses.iter_mut() {
+        // Burn zones come with the doomsday, not a timer
+        if *state != BossState::Battle
+            || data.current_phase < hazard.from_phase
+            || hazard.kind == ArenaHazardKind::BurnZones
+        {
+            continue;
+        }
+        hazard.timer -= dt;
+        if hazard.timer > 0.0 {
+            continue;
+        }
+        let enraged = data.health / data.max_health <= data.enrage_threshold;
+        hazard.timer = hazard.interval * if enraged { ENRAGED_INTERVAL_MULT } else { 1.0 };
+
+        let boss_pos = transform.translation.truncate();
+        match hazard.kind {
+            ArenaHazardKind::GridLasers => {
+                // One sweep, or a crossing pair in the last phase
+                let first = run_rng.combat.u32(0..4);
+                let edges = if data.current_phase >= GRID_CROSS_PHASE {
+                    vec![first, (first + 2) % 4]
+                } else {
+                    vec![first]
+                };
+                for edge in edges {
+                    let reach = if edge < 2 {
+                        playfield.half_width()
+                    } else {
+                        playfield.half_height()
+                    };
+                    let room = (reach - LASER_GAP).max(0.0);
+                    let gap = (run_rng.combat.f32() * 2.0 - 1.0) * room;
+                    let (shape, sweep) = grid_laser(edge, gap, &playfield);
+                    commands.spawn(HazardZone::new(&hazard, shape, sweep, "Grid laser"));
+                }
+            }
+            ArenaHazardKind::PlasmaVents => {
+                let direction = player_pos
+                    .map(|p| (p - boss_pos).normalize_or(Vec2::NEG_Y))
+                    .unwrap_or(Vec2::NEG_Y);
+                let shape = HazardShape::Cone {
+                    apex: boss_pos,
+                    direction,
+                    length: VENT_LENGTH,
+                    half_angle: VENT_HALF_ANGLE,
+                };
+                commands.spawn(HazardZone::new(
+                    &hazard,
+                    shape,
+                    hazard.duration,
+                    "Plasma vent",
+                ));
+            }
+            ArenaHazardKind::BurnZones => {}
+        }
+    }
+}
+
+/// Arm, sweep and expire hazards, and hurt the player caught in a live one
+#[allow(clippy::too_many_arguments)]
+fn update_hazard_zones(
+    mut commands: Commands,
+    time: Res<Time>,
+    difficulty: Res<Difficulty>,
+    mut score: ResMut<ScoreSystem>,
+    bosses: Query<&BossState, With<Boss>>,
+    mut zones: Query<(Entity, &mut HazardZone)>,
+    mut player_query: Query<
+        (
+            &Transform,
+            &Hitbox,
+            &mut ShipStats,
+            &PowerupEffects,
+            &ManeuverState,
+        ),
+        With<Player>,
+    >,
+    mut damage_events: EventWriter<PlayerDamagedEvent>,
+    mut destroyed_events: EventWriter<PlayerDestroyedEvent>,
+) {
+    // The arena goes quiet with the boss
+    if !bosses.iter().any(|state| *state != BossState::Defeated) {
+        for (entity, _) in zones.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+
+    let dt = time.delta_secs();
+    let mut player = player_query.get_single_mut().ok();
+
+    for (entity, mut zone) in zones.iter_mut() {
+        if !zone.is_live() {
+            zone.telegraph -= dt;
+            continue;
+        }
+        zone.remaining -= dt;
+        if zone.remaining <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        zone.shape.advance(dt);
+        zone.cooldown -= dt;
+
+        let Some((transform, hitbox, stats, powerups, maneuver)) = player.as_mut() else {
+            continue;
+        };
+        let player_pos = transform.translation.truncate();
+        if zone.cooldown > 0.0
+            || stats.hull <= 0.0
+            || powerups.is_invulnerable()
+            || maneuver.invincible
+            || !zone.shape.contains(player_pos, hitbox.radius)
+        {
+            continue;
+        }
+
+        // A laser hits once per pass; vents and burns keep ticking
+        zone.cooldown = match zone.shape {
+            HazardShape::Laser { .. } => f32::INFINITY,
+            _ => HAZARD_TICK,
+        };
+        let damage = zone.damage * difficulty.enemy_damage_mult();
+        let damage_type = zone.shape.damage_type();
+        let layers = stats.absorb_damage(damage, damage_type);
+        score.no_damage_bonus = false;
+        damage_events.send(PlayerDamagedEvent {
+            damage,
+            damage_type,
+            source_position: player_pos,
+            source: zone.source.into(),
+            layers,
+        });
+        if stats.hull <= 0.0 {
+            info!("Player destroyed by a {}", zone.source);
+            destroyed_events.send(PlayerDestroyedEvent {
+                position: player_pos,
+            });
+        }
+    }
+}
+
+/// Telegraphs blink and fill as they arm; live hazards burn bright
+fn draw_hazard_zones(mut gizmos: Gizmos, time: Res<Time>, zones: Query<&HazardZone>) {
+    let flicker = 0.75 + 0.25 * (time.elapsed_secs() * 3.0 * TAU).sin();
+    for zone in zones.iter() {
+        let color = zone.shape.color();
+        let armed = if zone.warning > 0.0 {
+            1.0 - (zone.telegraph / zone.warning).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        let (outline, fill) = if zone.is_live() {
+            (
+                color.with_alpha(0.9 * flicker),
+                color.with_alpha(0.6 * flicker),
+            )
+        } else {
+            let blink = 0.5 + 0.5 * (zone.telegraph * 8.0 * TAU).sin();
+            (
+                color.with_alpha(0.2 + 0.3 * blink),
+                color.with_alpha(0.15 + 0.35 * armed),
+            )
+        };
+
+        match zone.shape {
+            HazardShape::Laser {
+                origin,
+                along,
+                gap,
+                reach,
+                ..
+            } => {
+                let gap_from = gap - LASER_GAP / 2.0;
+                let gap_to = gap + LASER_GAP / 2.0;
+                let thickness: &[f32] = if zone.is_live() {
+                    &[-2.0, 0.0, 2.0]
+                } else {
+                    &[0.0]
+                };
+                for &shift in thickness {
+                    let line = origin + along.perp() * shift;
+                    gizmos.line_2d(line - along * reach, line + along * gap_from, outline);
+                    gizmos.line_2d(line + along * gap_to, line + along * reach, outline);
+                }
+                // Mark the way through while it arms
+                if !zone.is_live() {
+                    let across = along.perp() * 12.0;
+                    for end in [gap_from, gap_to] {
+                        let at = origin + along * end;
+                        gizmos.line_2d(at - across, at + across, fill);
+                    }
+                }
+            }
+            HazardShape::Cone {
+                apex,
+                direction,
+                length,
+                half_angle,
+            } => {
+                let left = Vec2::from_angle(half_angle).rotate(direction);
+                let right = Vec2::from_angle(-half_angle).rotate(direction);
+                gizmos.line_2d(apex, apex + left * length, outline);
+                gizmos.line_2d(apex, apex + right * length, outline);
+                gizmos.short_arc_2d_between(
+                    apex,
+                    apex + right * length,
+                    apex + left * length,
+                    outline,
+                );
+                // Fills outward as it arms; streaks of plasma once live
+                let reach = length * armed;
+                if reach > 1.0 {
+                    gizmos.short_arc_2d_between(
+                        apex,
+                        apex + right * reach,
+                        apex + left * reach,
+                        fill,
+                    );
+                }
+                if zone.is_live() {
+                    for i in 0..7 {
+                        let angle = half_angle * (i as f32 / 3.0 - 1.0);
+                        let ray = Vec2::from_angle(angle).rotate(direction);
+                        gizmos.line_2d(apex, apex + ray * length * flicker, fill);
+                    }
+                }
+            }
+            HazardShape::Circle { center, radius } => {
+                gizmos.circle_2d(center, radius, outline);
+                if zone.is_live() {
+                    gizmos.circle_2d(center, radius * 0.66 * flicker, fill);
+                    gizmos.circle_2d(center, radius * 0.33 * flicker, fill);
+                } else {
+                    gizmos.circle_2d(center, radius * armed.max(0.05), fill);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_lasers_cross_the_arena_and_leave_a_gap() {
+        let playfield = Playfield::default();
+        let (shape, sweep) = grid_laser(0, 100.0, &playfield);
+        assert!((sweep * LASER_SWEEP_SPEED - playfield.size.y).abs() < 1e-3);
+
+        let top = playfield.half_height();
+        assert!(shape.contains(Vec2::new(-200.0, top), 0.0));
+        assert!(!shape.contains(Vec2::new(100.0, top), 0.0), "the gap");
+        assert!(!shape.contains(Vec2::new(-200.0, top - 40.0), 0.0));
+
+        // Sweeps down toward the player
+        let mut moved = shape;
+        moved.advance(1.0);
+        assert!(moved.contains(Vec2::new(-200.0, top - LASER_SWEEP_SPEED), 0.0));
+    }
+
+    #[test]
+    fn vents_burn_a_cone_and_burns_a_circle() {
+        let vent = HazardShape::Cone {
+            apex: Vec2::ZERO,
+            direction: Vec2::NEG_Y,
+            length: VENT_LENGTH,
+            half_angle: VENT_HALF_ANGLE,
+        };
+        assert!(vent.contains(Vec2::new(0.0, -200.0), 0.0));
+        assert!(vent.contains(Vec2::new(40.0, -200.0), 0.0));
+        assert!(
+            !vent.contains(Vec2::new(200.0, -200.0), 0.0),
+            "outside the spread"
+        );
+        assert!(
+            !vent.contains(Vec2::new(0.0, -VENT_LENGTH - 20.0), 0.0),
+            "past its reach"
+        );
+        assert!(
+            !vent.contains(Vec2::new(0.0, 200.0), 0.0),
+            "behind the hull"
+        );
+
+        let burn = HazardShape::Circle {
+            center: Vec2::ZERO,
+            radius: BURN_RADIUS,
+        };
+        assert!(burn.contains(Vec2::new(BURN_RADIUS + 5.0, 0.0), 10.0));
+        assert!(!burn.contains(Vec2::new(BURN_RADIUS + 5.0, 0.0), 0.0));
+    }
+}
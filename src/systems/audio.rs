@@ -13,6 +13,9 @@ use std::io::Cursor;
 
 use crate::core::{BossSpawnEvent, WaveCompleteEvent, *};
 use crate::systems::ability::{AbilityActivatedEvent, AbilityType};
+use crate::systems::boss_warning::BossKlaxonEvent;
+use crate::systems::maneuvers::ParryEvent;
+use crate::systems::scoring_v2::{OverloadActivatedEvent, OverloadBurnoutEvent};
 
 /// Audio plugin
 pub struct AudioPlugin;
@@ -33,7 +36,10 @@ impl Plugin for AudioPlugin {
                     play_health_warnings,
                     play_wave_complete_sound,
                     play_boss_spawn_sound,
+                    play_boss_klaxon,
                     play_ability_sounds,
+                    play_overload_sounds,
+                    play_parry_sound,
                 )
                     .run_if(in_state(GameState::Playing)),
             );
@@ -46,6 +52,8 @@ pub struct SoundSettings {
     pub master_volume: f32,
     pub sfx_volume: f32,
     pub music_volume: f32,
+    /// Voice-over clips on dialogue lines
+    pub voice_volume: f32,
     pub enabled: bool,
 }
 
@@ -55,6 +63,7 @@ impl Default for SoundSettings {
             master_volume: 0.7,
             sfx_volume: 0.8,
             music_volume: 0.5,
+            voice_volume: 0.9,
             enabled: true,
         }
     }
@@ -80,6 +89,7 @@ pub struct SoundAssets {
     // Game events
     pub wave_complete: Option<Handle<AudioSource>>,
     pub boss_spawn: Option<Handle<AudioSource>>,
+    pub boss_klaxon: Option<Handle<AudioSource>>,
     // Powerup-specific sounds
     pub powerup_overdrive: Option<Handle<AudioSource>>,
     pub powerup_damage: Option<Handle<AudioSource>>,
@@ -96,6 +106,11 @@ pub struct SoundAssets {
     pub ability_drone: Option<Handle<AudioSource>>, // Deploy Drone, Drone Bay
     pub ability_debuff: Option<Handle<AudioSource>>, // Warp Disruptor
     pub ability_damage: Option<Handle<AudioSource>>, // Close Range
+    // Heat overload sounds
+    pub overload_surge: Option<Handle<AudioSource>>,
+    pub overload_burnout: Option<Handle<AudioSource>>,
+    // Maneuver sounds
+    pub parry: Option<Handle<AudioSource>>,
 }
 
 /// Tracks when warnings should play (to avoid spamming)
@@ -185,6 +200,9 @@ fn generate_sounds(
     if let Some(source) = generate_boss_spawn() {
         sounds.boss_spawn = Some(audio_sources.add(source));
     }
+    if let Some(source) = generate_boss_klaxon() {
+        sounds.boss_klaxon = Some(audio_sources.add(source));
+    }
 
     // Powerup-specific sounds
     if let Some(source) = generate_powerup_overdrive() {
@@ -231,6 +249,19 @@ fn generate_sounds(
         sounds.ability_damage = Some(audio_sources.add(source));
     }
 
+    // Heat overload sounds
+    if let Some(source) = generate_overload_surge() {
+        sounds.overload_surge = Some(audio_sources.add(source));
+    }
+    if let Some(source) = generate_overload_burnout() {
+        sounds.overload_burnout = Some(audio_sources.add(source));
+    }
+
+    // Maneuver sounds
+    if let Some(source) = generate_parry() {
+        sounds.parry = Some(audio_sources.add(source));
+    }
+
     info!("Sound effects generated!");
 }
 
@@ -426,7 +457,7 @@ fn generate_hull_hit() -> Option<AudioSource> {
 
 /// Create AudioSource from f32 samples using hound for proper WAV encoding
 #[cfg(not(target_arch = "wasm32"))]
-fn create_audio_source(samples: &[f32], sample_rate: u32) -> Option<AudioSource> {
+pub(crate) fn create_audio_source(samples: &[f32], sample_rate: u32) -> Option<AudioSource> {
     use std::sync::Arc;
 
     let spec = hound::WavSpec {
@@ -471,7 +502,7 @@ fn create_audio_source(samples: &[f32], sample_rate: u32) -> Option<AudioSource>
 
 /// WASM stub - no procedural audio generation
 #[cfg(target_arch = "wasm32")]
-fn create_audio_source(_samples: &[f32], _sample_rate: u32) -> Option<AudioSource> {
+pub(crate) fn create_audio_source(_samples: &[f32], _sample_rate: u32) -> Option<AudioSource> {
     None
 }
 
@@ -902,6 +933,33 @@ fn generate_boss_spawn() -> Option<AudioSource> {
     create_audio_source(&samples, sample_rate)
 }
 
+/// Generate boss klaxon - a two-tone siren blast
+fn generate_boss_klaxon() -> Option<AudioSource> {
+    let sample_rate = 44100u32;
+    let duration = 0.7;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // High tone then low, like a ship's general quarters
+        let freq = if t < duration / 2.0 { 660.0 } else { 440.0 };
+
+        // Square-ish wave for a harsh horn
+        let wave = (2.0 * PI * freq * t).sin();
+        let horn = wave.signum() * 0.4 + wave * 0.3;
+        let harm = (2.0 * PI * freq * 1.5 * t).sin() * 0.15;
+
+        let env = (1.0 - (-t * 40.0).exp()) * (1.0 - (t / duration).powf(4.0));
+
+        let sample = ((horn + harm) * env * 0.55).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    create_audio_source(&samples, sample_rate)
+}
+
 /// Generate overdrive powerup sound - engine rev
 fn generate_powerup_overdrive() -> Option<AudioSource> {
     let sample_rate = 44100u32;
@@ -1271,10 +1329,152 @@ fn generate_ability_damage() -> Option<AudioSource> {
     create_audio_source(&samples, sample_rate)
 }
 
+/// Generate overload surge sound - rising electrical overcharge
+fn generate_overload_surge() -> Option<AudioSource> {
+    let sample_rate = 44100u32;
+    let duration = 0.6;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // Pitch climbs as the modules overheat
+        let freq = 180.0 + 520.0 * (t / duration);
+        let surge = (2.0 * PI * freq * t).sin() * 0.4;
+
+        // Harsh square-ish harmonic for the overcharge buzz
+        let buzz = (2.0 * PI * freq * 2.0 * t).sin().signum() * 0.12;
+
+        // Crackle
+        let crackle = (fastrand::f32() * 2.0 - 1.0) * 0.15 * (t / duration);
+
+        let env = (1.0 - (-t * 15.0).exp()) * (1.0 - (t / duration).powf(3.0));
+
+        let sample = ((surge + buzz + crackle) * env * 0.7).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    create_audio_source(&samples, sample_rate)
+}
+
+/// Generate overload burnout sound - venting hiss with a dying whine
+fn generate_overload_burnout() -> Option<AudioSource> {
+    let sample_rate = 44100u32;
+    let duration = 0.8;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // Steam vent hiss
+        let hiss = (fastrand::f32() * 2.0 - 1.0) * 0.35 * (-t * 2.5).exp();
+
+        // Module powering down
+        let freq = 400.0 * (1.0 - t / duration).max(0.1);
+        let whine = (2.0 * PI * freq * t).sin() * 0.3 * (-t * 3.0).exp();
+
+        let env = 1.0 - (-t * 30.0).exp();
+
+        let sample = ((hiss + whine) * env * 0.6).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    create_audio_source(&samples, sample_rate)
+}
+
+/// Generate parry sound - bright metallic ring over a deflection snap
+fn generate_parry() -> Option<AudioSource> {
+    let sample_rate = 44100u32;
+    let duration = 0.45;
+    let num_samples = (sample_rate as f32 * duration) as usize;
+    let mut samples = Vec::with_capacity(num_samples);
+
+    for i in 0..num_samples {
+        let t = i as f32 / sample_rate as f32;
+
+        // Inharmonic partials ring like struck metal
+        let ring = ((2.0 * PI * 1320.0 * t).sin() * 0.35
+            + (2.0 * PI * 1980.0 * t).sin() * 0.2
+            + (2.0 * PI * 3170.0 * t).sin() * 0.1)
+            * (-t * 7.0).exp();
+
+        // Short noisy snap at the moment of deflection
+        let snap = (fastrand::f32() * 2.0 - 1.0) * 0.4 * (-t * 90.0).exp();
+
+        let env = 1.0 - (-t * 400.0).exp();
+
+        let sample = ((ring + snap) * env * 0.7).clamp(-1.0, 1.0);
+        samples.push(sample);
+    }
+
+    create_audio_source(&samples, sample_rate)
+}
+
 // =============================================================================
 // NEW PLAYBACK SYSTEMS
 // =============================================================================
 
+/// Play the parry ring when shots get turned around
+fn play_parry_sound(
+    mut commands: Commands,
+    mut parry_events: EventReader<ParryEvent>,
+    sounds: Res<SoundAssets>,
+    settings: Res<SoundSettings>,
+) {
+    if !settings.enabled {
+        parry_events.clear();
+        return;
+    }
+
+    for _event in parry_events.read() {
+        if let Some(source) = sounds.parry.clone() {
+            commands.spawn((
+                AudioPlayer(source),
+                PlaybackSettings {
+                    mode: PlaybackMode::Despawn,
+                    volume: Volume::new(settings.sfx_volume * settings.master_volume * 0.9),
+                    ..default()
+                },
+            ));
+        }
+    }
+}
+
+/// Play heat overload surge and burnout sounds
+fn play_overload_sounds(
+    mut commands: Commands,
+    mut overload_events: EventReader<OverloadActivatedEvent>,
+    mut burnout_events: EventReader<OverloadBurnoutEvent>,
+    sounds: Res<SoundAssets>,
+    settings: Res<SoundSettings>,
+) {
+    if !settings.enabled {
+        overload_events.clear();
+        burnout_events.clear();
+        return;
+    }
+
+    let surges = overload_events
+        .read()
+        .map(|_| sounds.overload_surge.clone());
+    let burnouts = burnout_events
+        .read()
+        .map(|_| sounds.overload_burnout.clone());
+
+    for source in surges.chain(burnouts).flatten() {
+        commands.spawn((
+            AudioPlayer(source),
+            PlaybackSettings {
+                mode: PlaybackMode::Despawn,
+                volume: Volume::new(settings.sfx_volume * settings.master_volume * 0.85),
+                ..default()
+            },
+        ));
+    }
+}
+
 /// Play ability activation sounds
 fn play_ability_sounds(
     mut commands: Commands,
@@ -1341,6 +1541,33 @@ fn play_wave_complete_sound(
 }
 
 /// Play boss spawn sound
+/// Klaxon blasts through the boss warning
+fn play_boss_klaxon(
+    mut commands: Commands,
+    mut klaxon_events: EventReader<BossKlaxonEvent>,
+    sounds: Res<SoundAssets>,
+    settings: Res<SoundSettings>,
+) {
+    if !settings.enabled {
+        klaxon_events.clear();
+        return;
+    }
+
+    if klaxon_events.read().last().is_none() {
+        return;
+    }
+    if let Some(source) = sounds.boss_klaxon.clone() {
+        commands.spawn((
+            AudioPlayer(source),
+            PlaybackSettings {
+                mode: PlaybackMode::Despawn,
+                volume: Volume::new(settings.sfx_volume * settings.master_volume * 0.85),
+                ..default()
+            },
+        ));
+    }
+}
+
 fn play_boss_spawn_sound(
     mut commands: Commands,
     mut boss_events: EventReader<BossSpawnEvent>,
@@ -0,0 +1,418 @@
+//! Act Backdrops
+//!
+//! Layered nebula clouds behind the starfield, tinted per campaign act:
+//! rust and amber for the early raids, storm violet for the invasion, and
+//! Amarr gold for the final push. The gameplay camera is 2D, so depth comes
+//! from parallax instead of a skybox - each layer follows a slow virtual
+//! camera sway and screen shake by its own fraction. Low graphics quality
+//! drops the clouds and leaves the plain starfield; reduced motion keeps
+//! the clouds but holds the sway still.
+
+#![allow(dead_code)]
+
+use super::effects::MotionSettings;
+use crate::core::*;
+use bevy::prelude::*;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use serde::{Deserialize, Serialize};
+
+/// Side length of the cloud puff mask (pixels)
+const PUFF_SIZE: u32 = 64;
+
+/// Peak virtual camera sway (pixels at parallax 1.0)
+const SWAY_AMPLITUDE: Vec2 = Vec2::new(26.0, 14.0);
+
+/// Sway frequencies (radians per second) - unrelated so the path never repeats visibly
+const SWAY_RATE: Vec2 = Vec2::new(0.23, 0.17);
+
+/// Backdrop plugin
+pub struct BackdropPlugin;
+
+impl Plugin for BackdropPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GraphicsQuality>()
+            .add_systems(Startup, create_puff)
+            .add_systems(
+                Update,
+                (sync_backdrop, drift_nebula)
+                    .chain()
+                    .in_set(GameSet::Ui)
+                    .run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight))),
+            );
+    }
+}
+
+/// Overall graphics detail
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GraphicsQuality {
+    /// Plain starfield only
+    Low,
+    /// Act nebulae behind the starfield
+    #[default]
+    High,
+}
+
+impl GraphicsQuality {
+    pub fn name(&self) -> &'static str {
+        match self {
+            GraphicsQuality::Low => "LOW",
+            GraphicsQuality::High => "HIGH",
+        }
+    }
+
+    pub fn toggled(&self) -> Self {
+        match self {
+            GraphicsQuality::Low => GraphicsQuality::High,
+            GraphicsQuality::High => GraphicsQuality::Low,
+        }
+    }
+
+    pub fn shows_nebula(&self) -> bool {
+        matches!(self, GraphicsQuality::High)
+    }
+}
+
+/// One nebula cloud in an act's backdrop
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NebulaCloud {
+    pub color: Color,
+    /// Starting position (screen space)
+    pub position: Vec2,
+    pub size: Vec2,
+    /// How much of the camera's motion the cloud follows (0.0 = fixed to
+    /// the sky, 1.0 = moves with the action)
+    pub parallax: f32,
+    /// Downward scroll (pixels per second)
+    pub drift: f32,
+}
+
+const fn cloud(
+    rgba: [f32; 4],
+    position: [f32; 2],
+    size: [f32; 2],
+    parallax: f32,
+    drift: f32,
+) -> NebulaCloud {
+    NebulaCloud {
+        color: Color::srgba(rgba[0], rgba[1], rgba[2], rgba[3]),
+        position: Vec2::new(position[0], position[1]),
+        size: Vec2::new(size[0], size[1]),
+        parallax,
+        drift,
+    }
+}
+
+/// Rust-belt haze of the early raids
+const ACT1_CLOUDS: [NebulaCloud; 5] = [
+    cloud(
+        [0.45, 0.18, 0.08, 0.30],
+        [-150.0, 120.0],
+        [900.0, 700.0],
+        0.08,
+        2.0,
+    ),
+    cloud(
+        [0.30, 0.12, 0.18, 0.25],
+        [220.0, -160.0],
+        [700.0, 560.0],
+        0.12,
+        3.0,
+    ),
+    cloud(
+        [0.70, 0.35, 0.12, 0.22],
+        [-240.0, -60.0],
+        [420.0, 340.0],
+        0.2,
+        5.0,
+    ),
+    cloud(
+        [0.85, 0.50, 0.20, 0.16],
+        [180.0, 200.0],
+        [320.0, 260.0],
+        0.28,
+        7.0,
+    ),
+    cloud(
+        [0.60, 0.25, 0.10, 0.12],
+        [40.0, -260.0],
+        [260.0, 200.0],
+        0.35,
+        9.0,
+    ),
+];
+
+/// Storm front of the invasion
+const ACT2_CLOUDS: [NebulaCloud; 5] = [
+    cloud(
+        [0.16, 0.10, 0.36, 0.34],
+        [120.0, 80.0],
+        [950.0, 720.0],
+        0.08,
+        3.0,
+    ),
+    cloud(
+        [0.10, 0.18, 0.40, 0.28],
+        [-200.0, -180.0],
+        [720.0, 600.0],
+        0.12,
+        4.0,
+    ),
+    cloud(
+        [0.40, 0.22, 0.70, 0.20],
+        [-180.0, 180.0],
+        [440.0, 360.0],
+        0.2,
+        6.0,
+    ),
+    cloud(
+        [0.30, 0.65, 0.90, 0.14],
+        [230.0, -40.0],
+        [300.0, 240.0],
+        0.28,
+        9.0,
+    ),
+    cloud(
+        [0.55, 0.30, 0.85, 0.12],
+        [-20.0, -280.0],
+        [280.0, 220.0],
+        0.35,
+        12.0,
+    ),
+];
+
+/// Amarr gold over the heart of the Empire
+const ACT3_CLOUDS: [NebulaCloud; 5] = [
+    cloud(
+        [0.40, 0.28, 0.08, 0.32],
+        [0.0, 160.0],
+        [1000.0, 700.0],
+        0.08,
+        2.0,
+    ),
+    cloud(
+        [0.38, 0.10, 0.10, 0.26],
+        [180.0, -200.0],
+        [700.0, 540.0],
+        0.12,
+        3.0,
+    ),
+    cloud(
+        [0.90, 0.70, 0.30, 0.20],
+        [-220.0, 40.0],
+        [460.0, 360.0],
+        0.2,
+        5.0,
+    ),
+    cloud(
+        [1.00, 0.85, 0.55, 0.14],
+        [210.0, 220.0],
+        [300.0, 240.0],
+        0.28,
+        7.0,
+    ),
+    cloud(
+        [0.80, 0.35, 0.20, 0.12],
+        [-60.0, -250.0],
+        [260.0, 210.0],
+        0.35,
+        9.0,
+    ),
+];
+
+/// Cloud layers for an act, farthest first
+pub fn act_clouds(act: Act) -> &'static [NebulaCloud] {
+    match act {
+        Act::Act1 => &ACT1_CLOUDS,
+        Act::Act2 => &ACT2_CLOUDS,
+        Act::Act3 => &ACT3_CLOUDS,
+    }
+}
+
+/// Virtual camera sway at time `t`, at full parallax
+pub fn camera_sway(t: f32, reduced_motion: bool) -> Vec2 {
+    if reduced_motion {
+        return Vec2::ZERO;
+    }
+    Vec2::new((t * SWAY_RATE.x).sin(), (t * SWAY_RATE.y).sin()) * SWAY_AMPLITUDE
+}
+
+/// Offset of a cloud from its scrolled position. Part of the camera's real
+/// translation (screen shake) is added back so far clouds barely move on
+/// screen while near ones shake with the action.
+pub fn parallax_offset(sway: Vec2, camera: Vec2, parallax: f32) -> Vec2 {
+    sway * parallax + camera * (1.0 - parallax)
+}
+
+/// Scrolled height, wrapped so the cloud re-enters from the top once it
+/// has fully left the bottom of a `screen_height`-tall playfield
+pub fn wrap_cloud_y(y: f32, height: f32, screen_height: f32) -> f32 {
+    let span = screen_height + height;
+    let bottom = -span / 2.0;
+    (y - bottom).rem_euclid(span) + bottom
+}
+
+/// Soft cloud mask shared by every nebula sprite
+#[derive(Resource)]
+struct PuffImage(Handle<Image>);
+
+/// A nebula cloud sprite
+#[derive(Component)]
+#[require(StateScope)]
+pub struct Nebula {
+    pub act: Act,
+    pub cloud: NebulaCloud,
+    /// Distance scrolled so far
+    pub scrolled: f32,
+}
+
+fn create_puff(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    commands.insert_resource(PuffImage(images.add(puff_image())));
+}
+
+/// White mask, opaque in the middle and fading out unevenly to the edges
+fn puff_image() -> Image {
+    let mut data = Vec::with_capacity((PUFF_SIZE * PUFF_SIZE * 4) as usize);
+    let half = PUFF_SIZE as f32 / 2.0;
+
+    for py in 0..PUFF_SIZE {
+        for px in 0..PUFF_SIZE {
+            let x = (px as f32 + 0.5 - half) / half;
+            let y = (py as f32 + 0.5 - half) / half;
+            // A few lobes around the rim so the clouds aren't perfect ovals
+            let angle = y.atan2(x);
+            let rim = 1.0 + 0.12 * (angle * 3.0).sin() + 0.08 * (angle * 5.0 + 1.3).sin();
+            let r = (x * x + y * y).sqrt() / rim;
+            let t = (1.0 - r).clamp(0.0, 1.0);
+            let alpha = (t * t * (3.0 - 2.0 * t) * 255.0) as u8;
+            data.extend_from_slice(&[255, 255, 255, alpha]);
+        }
+    }
+
+    Image::new(
+        Extent3d {
+            width: PUFF_SIZE,
+            height: PUFF_SIZE,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD,
+    )
+}
+
+/// Build the current act's nebula, or clear it on low quality
+fn sync_backdrop(
+    mut commands: Commands,
+    quality: Res<GraphicsQuality>,
+    campaign: Res<CampaignState>,
+    puff: Option<Res<PuffImage>>,
+    existing: Query<(Entity, &Nebula)>,
+) {
+    let wanted = quality.shows_nebula().then_some(campaign.act);
+    let current = existing.iter().next().map(|(_, nebula)| nebula.act);
+    if wanted == current {
+        return;
+    }
+
+    for (entity, _) in existing.iter() {
+        commands.entity(entity).despawn();
+    }
+    let (Some(act), Some(puff)) = (wanted, puff) else {
+        return;
+    };
+
+    for (i, cloud) in act_clouds(act).iter().enumerate() {
+        commands.spawn((
+            Nebula {
+                act,
+                cloud: *cloud,
+                scrolled: 0.0,
+            },
+            Sprite {
+                image: puff.0.clone(),
+                color: cloud.color,
+                custom_size: Some(cloud.size),
+                ..default()
+            },
+            // Behind the starfield (z 0 - 2), farthest cloud deepest
+            Transform::from_translation(cloud.position.extend(-10.0 + i as f32)),
+        ));
+    }
+}
+
+/// Scroll the clouds and apply sway and shake parallax
+fn drift_nebula(
+    time: Res<Time>,
+    motion: Res<MotionSettings>,
+    playfield: Res<Playfield>,
+    camera_query: Query<&Transform, (With<Camera2d>, Without<Nebula>)>,
+    mut clouds: Query<(&mut Nebula, &mut Transform)>,
+) {
+    let dt = time.delta_secs();
+    let sway = camera_sway(time.elapsed_secs(), motion.reduced_motion);
+    let camera = camera_query
+        .get_single()
+        .map(|t| t.translation.truncate())
+        .unwrap_or(Vec2::ZERO);
+
+    for (mut nebula, mut transform) in clouds.iter_mut() {
+        let cloud = nebula.cloud;
+        nebula.scrolled += cloud.drift * dt;
+        let y = wrap_cloud_y(
+            cloud.position.y - nebula.scrolled,
+            cloud.size.y,
+            playfield.size.y,
+        );
+        let position =
+            Vec2::new(cloud.position.x, y) + parallax_offset(sway, camera, cloud.parallax);
+        transform.translation.x = position.x;
+        transform.translation.y = position.y;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_act_has_its_own_clouds_farthest_first() {
+        for act in [Act::Act1, Act::Act2, Act::Act3] {
+            let clouds = act_clouds(act);
+            assert!(!clouds.is_empty());
+            assert!(clouds.windows(2).all(|w| w[0].parallax <= w[1].parallax));
+            assert!(clouds.iter().all(|c| c.parallax > 0.0 && c.parallax < 1.0));
+        }
+        assert_ne!(
+            act_clouds(Act::Act1)[0].color,
+            act_clouds(Act::Act2)[0].color
+        );
+        assert_ne!(
+            act_clouds(Act::Act2)[0].color,
+            act_clouds(Act::Act3)[0].color
+        );
+    }
+
+    #[test]
+    fn far_clouds_sway_less_and_shake_stays_mostly_on_screen_with_them() {
+        let sway = camera_sway(4.0, false);
+        assert!(sway.length() > 0.0);
+        assert_eq!(camera_sway(4.0, true), Vec2::ZERO);
+
+        let camera = Vec2::new(10.0, -6.0);
+        let far = parallax_offset(sway, camera, 0.1);
+        let near = parallax_offset(sway, camera, 0.35);
+        // On screen a cloud moves by its offset minus the camera's own motion
+        assert!((near - camera).length() > (far - camera).length());
+        assert_eq!(parallax_offset(Vec2::ZERO, Vec2::ZERO, 0.3), Vec2::ZERO);
+    }
+
+    #[test]
+    fn clouds_wrap_once_fully_below_the_screen() {
+        let height = 200.0;
+        let bottom = -(SCREEN_HEIGHT + height) / 2.0;
+        assert_eq!(wrap_cloud_y(0.0, height, SCREEN_HEIGHT), 0.0);
+        assert!((wrap_cloud_y(bottom - 1.0, height, SCREEN_HEIGHT) - (-bottom - 1.0)).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,350 @@
+//! Berserk Presentation
+//!
+//! Berserk should look like it hits twice as hard as it does. Going
+//! berserk sets off a shockwave that wipes enemy shots near the ship
+//! (once, on activation), then for as long as it lasts the ship burns
+//! with a hot glow and a red-orange engine trail, speed lines streak down
+//! the playfield and the screen tint cycles red to orange. The music side
+//! lives in `music::stem_targets`.
+
+#![allow(dead_code)]
+
+use super::effects::{CameraZoom, EngineTrail, MotionSettings};
+use crate::core::*;
+use crate::entities::{Player, ProjectileOwner};
+use bevy::prelude::*;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+/// Enemy shots this close to the ship are wiped by the activation burst
+pub const BERSERK_BURST_RADIUS: f32 = 260.0;
+
+/// Seconds the shockwave ring takes to reach the burst radius
+const BURST_DURATION: f32 = 0.45;
+
+/// Berserk engine trail colour
+const BERSERK_TRAIL_COLOR: Color = Color::srgba(1.0, 0.3, 0.08, 0.95);
+
+/// Glow behind the ship
+const GLOW_COLOR: Color = Color::srgb(1.0, 0.35, 0.1);
+
+/// Speed lines per second (halved with reduced motion)
+const SPEED_LINE_RATE: f32 = 40.0;
+
+/// Speed line fall speed (pixels per second)
+const SPEED_LINE_SPEED: f32 = 1400.0;
+
+/// Side length of the glow and ring textures (pixels)
+const GLOW_SIZE: u32 = 64;
+
+/// Berserk presentation plugin
+pub struct BerserkFxPlugin;
+
+impl Plugin for BerserkFxPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, create_berserk_images).add_systems(
+            Update,
+            (
+                berserk_activation_burst,
+                update_burst_rings,
+                berserk_ship_glow,
+                berserk_engine_trail,
+                spawn_speed_lines,
+                update_speed_lines,
+            )
+                .chain()
+                .in_set(GameSet::Scoring)
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
+
+/// Soft glow and ring textures
+#[derive(Resource)]
+struct BerserkImages {
+    glow: Handle<Image>,
+    ring: Handle<Image>,
+}
+
+/// Expanding shockwave from the activation burst
+#[derive(Component, Debug)]
+#[require(StateScope)]
+pub struct BerserkBurstRing {
+    pub age: f32,
+}
+
+/// Glow parented to the ship while berserk lasts
+#[derive(Component, Debug)]
+pub struct BerserkGlow;
+
+/// The ship's own trail colour and rate, put back when berserk ends
+#[derive(Component, Debug, Clone, Copy)]
+pub struct BerserkTrailRestore {
+    pub color: Color,
+    pub spawn_rate: f32,
+}
+
+/// A streak falling past the playfield
+#[derive(Component, Debug)]
+#[require(StateScope)]
+pub struct SpeedLine;
+
+/// Red-to-orange berserk tint for a moment of the pulse (`phase` 0..1)
+pub fn berserk_tint_color(phase: f32) -> Color {
+    let red = Vec3::new(1.0, 0.1, 0.1);
+    let orange = Vec3::new(1.0, 0.5, 0.05);
+    let rgb = red.lerp(orange, phase.clamp(0.0, 1.0));
+    Color::srgb(rgb.x, rgb.y, rgb.z)
+}
+
+/// Shockwave scale (fraction of the burst radius) and alpha at `age`
+pub fn burst_ring_look(age: f32) -> (f32, f32) {
+    let t = (age / BURST_DURATION).clamp(0.0, 1.0);
+    // Fast out, easing to the edge
+    let scale = 1.0 - (1.0 - t).powi(3);
+    (scale, 1.0 - t)
+}
+
+fn create_berserk_images(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    let glow = images.add(radial_image(|r| (1.0 - r).max(0.0).powi(2)));
+    // Bright band just inside the edge
+    let ring = images.add(radial_image(|r| {
+        (1.0 - ((r - 0.88) / 0.12).abs()).clamp(0.0, 1.0)
+    }));
+    commands.insert_resource(BerserkImages { glow, ring });
+}
+
+/// White square texture whose alpha follows `alpha(r)`, r = 0 at the centre
+/// to 1 at the edge
+fn radial_image(alpha: impl Fn(f32) -> f32) -> Image {
+    let mut data = Vec::with_capacity((GLOW_SIZE * GLOW_SIZE * 4) as usize);
+    let half = GLOW_SIZE as f32 / 2.0;
+    for py in 0..GLOW_SIZE {
+        for px in 0..GLOW_SIZE {
+            let x = (px as f32 + 0.5 - half) / half;
+            let y = (py as f32 + 0.5 - half) / half;
+            let r = (x * x + y * y).sqrt();
+            let a = if r > 1.0 { 0.0 } else { alpha(r) };
+            data.extend_from_slice(&[255, 255, 255, (a * 255.0) as u8]);
+        }
+    }
+    Image::new(
+        Extent3d {
+            width: GLOW_SIZE,
+            height: GLOW_SIZE,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD,
+    )
+}
+
+/// Going berserk wipes enemy shots around the ship and sends a shockwave
+/// out to show how far it reached
+fn berserk_activation_burst(
+    mut commands: Commands,
+    mut activations: EventReader<BerserkActivatedEvent>,
+    player_query: Query<&Transform, With<Player>>,
+    projectiles: Query<(Entity, &Transform, &ProjectileOwner), Without<Player>>,
+    images: Option<Res<BerserkImages>>,
+    mut explosions: EventWriter<ExplosionEvent>,
+    mut camera_zoom: ResMut<CameraZoom>,
+) {
+    if activations.read().count() == 0 {
+        return;
+    }
+    let Ok(player) = player_query.get_single() else {
+        return;
+    };
+    let center = player.translation.truncate();
+
+    let mut cleared = 0;
+    for (entity, transform, owner) in projectiles.iter() {
+        let pos = transform.translation.truncate();
+        if owner.is_player() || pos.distance(center) > BERSERK_BURST_RADIUS {
+            continue;
+        }
+        commands.entity(entity).despawn_recursive();
+        explosions.send(ExplosionEvent {
+            position: pos,
+            size: ExplosionSize::Tiny,
+            color: Color::srgb(1.0, 0.5, 0.15),
+        });
+        cleared += 1;
+    }
+    debug!("Berserk burst cleared {} enemy shots", cleared);
+
+    commands.spawn((
+        BerserkBurstRing { age: 0.0 },
+        Sprite {
+            image: images.map(|i| i.ring.clone()).unwrap_or_default(),
+            color: GLOW_COLOR,
+            custom_size: Some(Vec2::splat(BERSERK_BURST_RADIUS * 2.0)),
+            ..default()
+        },
+        Transform::from_xyz(center.x, center.y, LAYER_EFFECTS).with_scale(Vec3::splat(0.05)),
+    ));
+    camera_zoom.pulse(0.06);
+}
+
+fn update_burst_rings(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut rings: Query<(Entity, &mut BerserkBurstRing, &mut Transform, &mut Sprite)>,
+) {
+    for (entity, mut ring, mut transform, mut sprite) in rings.iter_mut() {
+        ring.age += time.delta_secs();
+        if ring.age >= BURST_DURATION {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+        let (scale, alpha) = burst_ring_look(ring.age);
+        transform.scale = Vec3::splat(scale.max(0.05));
+        sprite.color = GLOW_COLOR.with_alpha(alpha);
+    }
+}
+
+/// Hot glow behind the ship while berserk lasts
+fn berserk_ship_glow(
+    mut commands: Commands,
+    berserk: Res<BerserkSystem>,
+    motion: Res<MotionSettings>,
+    images: Option<Res<BerserkImages>>,
+    players: Query<(Entity, &Sprite), (With<Player>, Without<BerserkGlow>)>,
+    mut glows: Query<(Entity, &mut Sprite), With<BerserkGlow>>,
+) {
+    if !berserk.is_active {
+        for (entity, _) in glows.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    }
+
+    let pulse = if motion.reduced_motion {
+        0.0
+    } else {
+        (berserk.timer * 10.0).sin() * 0.15
+    };
+    if glows.is_empty() {
+        let Ok((player, sprite)) = players.get_single() else {
+            return;
+        };
+        let size = sprite.custom_size.map_or(48.0, |s| s.max_element()) * 1.8;
+        let glow = commands
+            .spawn((
+                BerserkGlow,
+                Sprite {
+                    image: images.map(|i| i.glow.clone()).unwrap_or_default(),
+                    color: GLOW_COLOR.with_alpha(0.7),
+                    custom_size: Some(Vec2::splat(size)),
+                    ..default()
+                },
+                // Behind the hull
+                Transform::from_xyz(0.0, 0.0, -0.5),
+            ))
+            .id();
+        commands.entity(player).add_child(glow);
+    }
+    for (_, mut sprite) in glows.iter_mut() {
+        sprite.color = GLOW_COLOR.with_alpha(0.7 + pulse);
+    }
+}
+
+/// Swap the ship's engine trail for a hotter, denser one and put it back
+/// afterwards
+fn berserk_engine_trail(
+    mut commands: Commands,
+    berserk: Res<BerserkSystem>,
+    mut players: Query<(Entity, &mut EngineTrail, Option<&BerserkTrailRestore>), With<Player>>,
+) {
+    for (entity, mut trail, restore) in players.iter_mut() {
+        match (berserk.is_active, restore) {
+            (true, None) => {
+                commands.entity(entity).insert(BerserkTrailRestore {
+                    color: trail.color,
+                    spawn_rate: trail.spawn_rate,
+                });
+                trail.color = BERSERK_TRAIL_COLOR;
+                trail.spawn_rate *= 2.0;
+            }
+            (false, Some(restore)) => {
+                trail.color = restore.color;
+                trail.spawn_rate = restore.spawn_rate;
+                commands.entity(entity).remove::<BerserkTrailRestore>();
+            }
+            _ => {}
+        }
+    }
+}
+
+fn spawn_speed_lines(
+    mut commands: Commands,
+    time: Res<Time>,
+    berserk: Res<BerserkSystem>,
+    motion: Res<MotionSettings>,
+    playfield: Res<Playfield>,
+    mut carry: Local<f32>,
+) {
+    if !berserk.is_active {
+        *carry = 0.0;
+        return;
+    }
+    let rate = if motion.reduced_motion {
+        SPEED_LINE_RATE * 0.5
+    } else {
+        SPEED_LINE_RATE
+    };
+    *carry += rate * time.delta_secs();
+    let half = playfield.half();
+    while *carry >= 1.0 {
+        *carry -= 1.0;
+        let x = (fastrand::f32() * 2.0 - 1.0) * half.x;
+        let length = 60.0 + fastrand::f32() * 90.0;
+        commands.spawn((
+            SpeedLine,
+            Sprite {
+                color: Color::srgba(1.0, 0.75, 0.5, 0.35),
+                custom_size: Some(Vec2::new(1.5, length)),
+                ..default()
+            },
+            Transform::from_xyz(x, half.y + length, LAYER_EFFECTS - 1.0),
+        ));
+    }
+}
+
+fn update_speed_lines(
+    mut commands: Commands,
+    time: Res<Time>,
+    playfield: Res<Playfield>,
+    mut lines: Query<(Entity, &mut Transform), With<SpeedLine>>,
+) {
+    let floor = -playfield.half().y - 200.0;
+    for (entity, mut transform) in lines.iter_mut() {
+        transform.translation.y -= SPEED_LINE_SPEED * time.delta_secs();
+        if transform.translation.y < floor {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tint_runs_red_to_orange() {
+        assert_eq!(berserk_tint_color(0.0), Color::srgb(1.0, 0.1, 0.1));
+        assert_eq!(berserk_tint_color(1.0), Color::srgb(1.0, 0.5, 0.05));
+        assert_eq!(berserk_tint_color(4.0), berserk_tint_color(1.0));
+    }
+
+    #[test]
+    fn burst_ring_grows_and_fades() {
+        assert_eq!(burst_ring_look(0.0), (0.0, 1.0));
+        let (mid_scale, mid_alpha) = burst_ring_look(BURST_DURATION * 0.5);
+        assert!(mid_scale > 0.5 && mid_alpha > 0.0);
+        assert_eq!(burst_ring_look(BURST_DURATION), (1.0, 0.0));
+    }
+}
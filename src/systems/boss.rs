@@ -1,17 +1,39 @@
 //! Boss Battle Systems
 //!
 //! Handles boss movement, attacks, phases, and defeat sequences.
+//!
+//! Carrier and station bosses close each phase change behind a shield gate:
+//! they launch a drone wave (bigger, in a new formation, each gate) and
+//! shrug off all damage until every drone of it is destroyed.
+//!
+//! Aimed shots lead the player - fire at where they'll be when the shot
+//! arrives - by an amount that scales with the difficulty's enemy accuracy.
+//! Below normal accuracy the aim also wanders a little, shot to shot.
+//!
+//! In New Game+ each phase draws its pattern from the table of a boss
+//! further into the campaign.
+//!
+//! From Act 2 on some attacks are phased and pass through shield bubbles.
+//!
+//! Later bosses bring arena hazards (see `arena_hazards`); the Avatar's
+//! doomsday leaves its burn zones behind.
+//!
+//! Boss hulls are solid: a field just outside the hull eases the player
+//! away, and flying into it anyway costs contact damage (scaled by
+//! difficulty) and knocks the player clear.
 
 #![allow(dead_code)]
 
+use super::ally_fleet::FleetShot;
 use super::dialogue::DialogueEvent;
 use super::effects::ScreenShake;
 use crate::assets::ShipModelCache;
 use crate::core::*;
-use crate::entities::projectile::{EnemyProjectile, ProjectileDamage, ProjectilePhysics};
+use crate::entities::projectile::{ProjectileDamage, ProjectileOwner, ProjectilePhysics, Team};
 use crate::entities::{
-    get_phase_threshold, spawn_boss, Boss, BossAttack, BossData, BossMovement, BossState,
-    MovementPattern,
+    get_phase_threshold, spawn_boss, ArenaHazard, ArenaHazardKind, Boss, BossAttack, BossData,
+    BossMovement, BossState, Hardpoints, Hitbox, Movement, MovementPattern, Player, PowerupEffects,
+    ShipStats,
 };
 use crate::systems::ComboHeatSystem;
 use bevy::prelude::*;
@@ -24,6 +46,7 @@ impl Plugin for BossPlugin {
         app.init_resource::<BossEncounter>()
             .add_event::<BossSpawnEvent>()
             .add_event::<BossDefeatedEvent>()
+            .add_event::<BossPhaseChangeEvent>()
             .add_systems(
                 Update,
                 (
@@ -32,8 +55,11 @@ impl Plugin for BossPlugin {
                     boss_movement,
                     boss_attack,
                     boss_phase_check,
+                    boss_phase_transition,
                     boss_drone_spawning,
+                    boss_shield_gates,
                     boss_damage,
+                    boss_contact,
                 )
                     .run_if(in_state(GameState::Playing)),
             );
@@ -67,6 +93,102 @@ pub struct BossDefeatedEvent {
     pub boss_name: String,
     pub score: u64,
     pub liberation_value: u32,
+    pub position: Vec2,
+}
+
+/// Event when a boss crosses into a new phase (the previous phase ended)
+#[derive(Event)]
+pub struct BossPhaseChangeEvent {
+    pub boss_name: String,
+    pub phase: u32,
+    pub total_phases: u32,
+    pub position: Vec2,
+}
+
+/// Total length of a phase transition (boss is invulnerable throughout)
+pub const PHASE_TRANSITION_DURATION: f32 = 2.0;
+
+/// Time spent retreating to the reposition point
+const PHASE_RETREAT_TIME: f32 = 1.2;
+
+/// Share of a full intercept that aimed shots lead by, at accuracy 1.0
+const AIM_LEAD: f32 = 0.5;
+
+/// Furthest ahead (seconds of player movement) a shot leads
+const MAX_LEAD_TIME: f32 = 1.0;
+
+/// Aim error (radians, either way) at accuracy 0; none at 1.0 and above
+const AIM_JITTER: f32 = 0.3;
+
+/// Stage of the Avatar, the last boss with a pattern table
+const FINAL_BOSS_STAGE: u32 = 13;
+
+/// How far past the hull the repulsion field reaches
+const REPULSION_RANGE: f32 = 40.0;
+
+/// Push (pixels per second) at the hull, fading to nothing at the field's edge
+const REPULSION_SPEED: f32 = 220.0;
+
+/// Speed the player is knocked away at on contact
+const CONTACT_KNOCKBACK: f32 = 420.0;
+
+/// Seconds between contact hits while touching the hull
+const CONTACT_COOLDOWN: f32 = 0.75;
+
+/// How a boss aims at the player
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BossAim {
+    /// 0 aims at the player, 1 at the full intercept point
+    pub lead: f32,
+    /// Largest random aim error (radians)
+    pub jitter: f32,
+}
+
+impl BossAim {
+    pub fn from_accuracy(accuracy: f32) -> Self {
+        Self {
+            lead: (AIM_LEAD * accuracy).clamp(0.0, 1.0),
+            jitter: AIM_JITTER * (1.0 - accuracy).clamp(0.0, 1.0),
+        }
+    }
+
+    /// Where to aim a `speed` shot from `from` at a target at `target`
+    /// moving at `velocity`
+    pub fn aim_point(&self, from: Vec2, target: Vec2, velocity: Vec2, speed: f32) -> Vec2 {
+        if speed <= 0.0 {
+            return target;
+        }
+        let flight_time = (from.distance(target) / speed).min(MAX_LEAD_TIME);
+        target + velocity * flight_time * self.lead
+    }
+
+    /// Firing direction for a `speed` shot, with this aim's jitter
+    pub fn direction(
+        &self,
+        from: Vec2,
+        target: Vec2,
+        velocity: Vec2,
+        speed: f32,
+        rng: &mut fastrand::Rng,
+    ) -> Vec2 {
+        let dir = (self.aim_point(from, target, velocity, speed) - from).normalize_or_zero();
+        if self.jitter <= 0.0 {
+            return dir;
+        }
+        Vec2::from_angle((rng.f32() * 2.0 - 1.0) * self.jitter).rotate(dir)
+    }
+}
+
+/// Marker for projectiles fired by a boss
+#[derive(Component)]
+pub struct BossProjectile;
+
+/// Scripted retreat/reposition while a boss changes phase
+#[derive(Component, Debug)]
+pub struct BossPhaseTransition {
+    pub elapsed: f32,
+    pub from: Vec2,
+    pub to: Vec2,
 }
 
 /// Component for bosses that spawn drones/fighters
@@ -86,6 +208,10 @@ pub struct BossDroneSpawner {
     pub active_drones: u32,
     /// Drone spawn pattern
     pub pattern: DroneSpawnPattern,
+    /// Phase changes open a shield gate (carriers and stations)
+    pub phase_gates: bool,
+    /// Shield gates opened so far this fight
+    pub gates_opened: u32,
 }
 
 impl Default for BossDroneSpawner {
@@ -98,10 +224,46 @@ impl Default for BossDroneSpawner {
             max_drones: 6,
             active_drones: 0,
             pattern: DroneSpawnPattern::Flanking,
+            phase_gates: false,
+            gates_opened: 0,
         }
     }
 }
 
+impl BossDroneSpawner {
+    /// Drones in a gate's wave: two more each gate, up to twice the cap
+    pub fn gate_wave_size(&self, gate: u32) -> u32 {
+        (self.drones_per_wave + 2 * (gate + 1)).min(self.max_drones * 2)
+    }
+}
+
+/// Boss is shielded until the drones of this gate's wave are destroyed
+#[derive(Component, Debug)]
+pub struct BossShieldGate {
+    /// Which gate this is (0 = first)
+    pub index: u32,
+    /// Drones launched by the gate (0 until the wave launches)
+    pub total: u32,
+    /// Gate drones still alive
+    pub remaining: u32,
+}
+
+impl BossShieldGate {
+    pub fn new(index: u32) -> Self {
+        Self {
+            index,
+            total: 0,
+            remaining: 0,
+        }
+    }
+}
+
+/// A drone launched by a boss's shield gate
+#[derive(Component, Debug)]
+pub struct GateDrone {
+    pub boss: Entity,
+}
+
 /// How drones are spawned around the boss
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum DroneSpawnPattern {
@@ -116,6 +278,50 @@ pub enum DroneSpawnPattern {
     FromSides,
 }
 
+impl DroneSpawnPattern {
+    const ALL: [DroneSpawnPattern; 4] = [
+        DroneSpawnPattern::Flanking,
+        DroneSpawnPattern::VFormation,
+        DroneSpawnPattern::Surround,
+        DroneSpawnPattern::FromSides,
+    ];
+
+    /// Formation for a shield gate: steps on from the boss's usual pattern
+    /// so each gate looks different
+    pub fn for_gate(self, gate: u32) -> Self {
+        let base = Self::ALL.iter().position(|&p| p == self).unwrap_or(0);
+        Self::ALL[(base + 1 + gate as usize) % Self::ALL.len()]
+    }
+
+    /// Where drone `i` of `count` appears around the boss
+    pub fn spawn_position(self, i: u32, count: u32, boss_pos: Vec2) -> Vec2 {
+        match self {
+            DroneSpawnPattern::Flanking => {
+                let offset = (i as f32 - (count - 1) as f32 / 2.0) * 50.0;
+                Vec2::new(boss_pos.x + offset, boss_pos.y - 60.0)
+            }
+            DroneSpawnPattern::VFormation => {
+                let offset = (i as f32 - (count - 1) as f32 / 2.0) * 40.0;
+                let y_offset = offset.abs() * 0.5;
+                Vec2::new(boss_pos.x + offset, boss_pos.y - 50.0 - y_offset)
+            }
+            DroneSpawnPattern::Surround => {
+                let angle = (i as f32 / count as f32) * std::f32::consts::TAU;
+                let radius = 80.0;
+                Vec2::new(
+                    boss_pos.x + angle.cos() * radius,
+                    boss_pos.y + angle.sin() * radius,
+                )
+            }
+            DroneSpawnPattern::FromSides => {
+                let side = if i.is_multiple_of(2) { -1.0 } else { 1.0 };
+                let offset = (i as f32 / 2.0).floor() * 30.0;
+                Vec2::new(boss_pos.x + side * 100.0, boss_pos.y - offset)
+            }
+        }
+    }
+}
+
 /// Handle boss spawn events
 fn handle_boss_spawn(
     mut commands: Commands,
@@ -124,6 +330,7 @@ fn handle_boss_spawn(
     session: Res<crate::core::GameSession>,
     sprite_cache: Res<crate::assets::ShipSpriteCache>,
     model_cache: Res<ShipModelCache>,
+    playfield: Res<Playfield>,
     boss_query: Query<Entity, With<Boss>>,
 ) {
     for event in spawn_events.read() {
@@ -134,6 +341,7 @@ fn handle_boss_spawn(
 
         if spawn_boss(
             &mut commands,
+            &playfield,
             event.stage,
             session.enemy_faction,
             Some(&sprite_cache),
@@ -205,6 +413,7 @@ fn boss_intro_sequence(
 /// Boss movement patterns
 fn boss_movement(
     time: Res<Time>,
+    playfield: Res<Playfield>,
     mut boss_query: Query<(&mut Transform, &mut BossMovement, &BossState, &BossData), With<Boss>>,
     player_query: Query<&Transform, (With<crate::entities::Player>, Without<Boss>)>,
 ) {
@@ -248,8 +457,8 @@ fn boss_movement(
             }
         }
 
-        // Clamp to screen bounds
-        let half_screen = SCREEN_WIDTH / 2.0 - 100.0;
+        // Clamp to the playfield
+        let half_screen = playfield.half_width() - 100.0;
         transform.translation.x = transform.translation.x.clamp(-half_screen, half_screen);
     }
 }
@@ -258,18 +467,39 @@ fn boss_movement(
 fn boss_attack(
     mut commands: Commands,
     time: Res<Time>,
-    mut boss_query: Query<(&Transform, &BossState, &BossData, &mut BossAttack), With<Boss>>,
-    player_query: Query<&Transform, (With<crate::entities::Player>, Without<Boss>)>,
+    mut boss_query: Query<
+        (
+            &Transform,
+            &BossState,
+            &BossData,
+            &mut BossAttack,
+            Option<&mut Hardpoints>,
+            Option<&ArenaHazard>,
+        ),
+        With<Boss>,
+    >,
+    player_query: Query<
+        (&Transform, Option<&crate::entities::Movement>),
+        (With<crate::entities::Player>, Without<Boss>),
+    >,
     mut explosion_events: EventWriter<ExplosionEvent>,
+    difficulty: Res<Difficulty>,
+    mut run_rng: ResMut<RunRng>,
 ) {
     let dt = time.delta_secs();
     let elapsed = time.elapsed_secs();
-    let player_pos = player_query
+    let (player_pos, player_vel) = player_query
         .get_single()
-        .map(|t| t.translation.truncate())
-        .unwrap_or(Vec2::ZERO);
-
-    for (transform, state, data, mut attack) in boss_query.iter_mut() {
+        .map(|(t, movement)| {
+            (
+                t.translation.truncate(),
+                movement.map_or(Vec2::ZERO, |m| m.velocity),
+            )
+        })
+        .unwrap_or((Vec2::ZERO, Vec2::ZERO));
+    let aim = BossAim::from_accuracy(difficulty.enemy_modifiers().accuracy_multiplier);
+
+    for (transform, state, data, mut attack, mut hardpoints, hazard) in boss_query.iter_mut() {
         if *state != BossState::Battle {
             continue;
         }
@@ -280,15 +510,25 @@ fn boss_attack(
             let boss_pos = transform.translation.truncate();
             let phase = data.current_phase;
             let is_enraged = data.health / data.max_health <= 0.2;
+            let mut aim_at = |from: Vec2, speed: f32| {
+                aim.direction(from, player_pos, player_vel, speed, &mut run_rng.combat)
+            };
+            // Aimed single shots leave from the next turret's muzzle
+            let mut muzzle = || {
+                hardpoints
+                    .as_mut()
+                    .map_or(boss_pos, |hardpoints| hardpoints.fire_from(boss_pos))
+            };
 
             // Fire pattern based on current phase
             match attack.pattern.as_str() {
                 "steady_beam" | "focused_beams" => {
                     // Single aimed shot - basic attack
-                    let dir = (player_pos - boss_pos).normalize_or_zero();
+                    let from = muzzle();
+                    let dir = aim_at(from, 250.0);
                     spawn_boss_projectile_styled(
                         &mut commands,
-                        boss_pos + dir * 40.0,
+                        from,
                         dir,
                         250.0,
                         20.0,
@@ -299,7 +539,7 @@ fn boss_attack(
 
                 "spread" => {
                     // Wide spread shot - fan of bullets toward player
-                    let base_dir = (player_pos - boss_pos).normalize_or_zero();
+                    let base_dir = aim_at(boss_pos, 200.0);
                     let base_angle = base_dir.y.atan2(base_dir.x);
                     let bullet_count = if is_enraged { 11 } else { 7 };
 
@@ -363,7 +603,7 @@ fn boss_attack(
 
                 "barrage" => {
                     // Rapid fire barrage - 5 bullets in tight cluster
-                    let dir = (player_pos - boss_pos).normalize_or_zero();
+                    let dir = aim_at(boss_pos, 280.0);
                     for i in 0..5 {
                         let offset = (i as f32 - 2.0) * 15.0;
                         let spread = (i as f32 - 2.0) * 0.08;
@@ -450,7 +690,7 @@ fn boss_attack(
 
                 "drone_swarm" | "missile_swarm" => {
                     // Multiple missiles aimed at player
-                    let dir = (player_pos - boss_pos).normalize_or_zero();
+                    let dir = aim_at(boss_pos, 180.0);
                     let count = if is_enraged { 5 } else { 3 };
                     for i in 0..count {
                         let offset = (i as f32 - (count - 1) as f32 / 2.0) * 20.0;
@@ -482,7 +722,7 @@ fn boss_attack(
                         );
                     }
                     // Targeted beam component
-                    let dir = (player_pos - boss_pos).normalize_or_zero();
+                    let dir = aim_at(boss_pos, 400.0);
                     for i in 0..7 {
                         spawn_boss_projectile_styled(
                             &mut commands,
@@ -493,6 +733,17 @@ fn boss_attack(
                             BossProjectileStyle::Heavy,
                         );
                     }
+                    // The Avatar's leaves the arena burning where it lands
+                    if let Some(hazard) =
+                        hazard.filter(|hazard| hazard.kind == ArenaHazardKind::BurnZones)
+                    {
+                        super::arena_hazards::spawn_burn_zones(
+                            &mut commands,
+                            hazard,
+                            boss_pos,
+                            player_pos,
+                        );
+                    }
                     // Big visual effect
                     explosion_events.send(ExplosionEvent {
                         position: boss_pos,
@@ -504,8 +755,9 @@ fn boss_attack(
 
                 _ => {
                     // Default pattern
-                    let dir = (player_pos - boss_pos).normalize_or_zero();
-                    spawn_boss_projectile(&mut commands, boss_pos + dir * 40.0, dir, 220.0, 18.0);
+                    let from = muzzle();
+                    let dir = aim_at(from, 220.0);
+                    spawn_boss_projectile(&mut commands, from, dir, 220.0, 18.0);
                     attack.fire_timer = 0.6;
                 }
             }
@@ -575,7 +827,8 @@ fn spawn_boss_projectile_styled(
     let angle = dir.y.atan2(dir.x) - std::f32::consts::FRAC_PI_2;
 
     commands.spawn((
-        EnemyProjectile,
+        ProjectileOwner::enemy(),
+        BossProjectile,
         ProjectilePhysics {
             velocity: dir * speed,
             lifetime: 4.0,
@@ -597,9 +850,12 @@ fn spawn_boss_projectile_styled(
 }
 
 /// Check for phase transitions and enrage
+#[allow(clippy::too_many_arguments)]
 fn boss_phase_check(
+    mut commands: Commands,
     mut boss_query: Query<
         (
+            Entity,
             &Transform,
             &mut BossData,
             &mut BossAttack,
@@ -611,8 +867,12 @@ fn boss_phase_check(
     mut encounter: ResMut<BossEncounter>,
     mut screen_shake: ResMut<ScreenShake>,
     mut explosion_events: EventWriter<ExplosionEvent>,
+    mut phase_events: EventWriter<BossPhaseChangeEvent>,
+    playfield: Res<Playfield>,
+    ng_plus: Res<NewGamePlus>,
 ) {
-    for (transform, mut data, mut attack, mut state, mut movement) in boss_query.iter_mut() {
+    for (entity, transform, mut data, mut attack, mut state, mut movement) in boss_query.iter_mut()
+    {
         if *state != BossState::Battle {
             continue;
         }
@@ -671,15 +931,40 @@ fn boss_phase_check(
             if health_percent <= threshold {
                 data.current_phase = next_phase;
                 *state = BossState::PhaseTransition;
-                encounter.phase_timer = 1.0;
+                encounter.phase_timer = PHASE_TRANSITION_DURATION;
+
+                // Pull back to the top of the arena (stations hold position)
+                let to = if data.stationary {
+                    boss_pos
+                } else {
+                    Vec2::new(boss_pos.x * 0.3, playfield.half_height() - 90.0)
+                };
+                commands.entity(entity).insert(BossPhaseTransition {
+                    elapsed: 0.0,
+                    from: boss_pos,
+                    to,
+                });
+
+                // Outgoing bullets are converted to score by bullet_cancel
+                phase_events.send(BossPhaseChangeEvent {
+                    boss_name: data.name.clone(),
+                    phase: next_phase,
+                    total_phases: data.total_phases,
+                    position: boss_pos,
+                });
 
                 // Update attack pattern based on phase
-                attack.pattern = get_phase_pattern(data.id, next_phase);
+                attack.pattern = phase_pattern(data.id, next_phase, ng_plus.depth);
                 attack.fire_rate *= 0.85; // Speed up attacks
 
-                // Some bosses change movement in later phases
-                if next_phase >= 3 && !data.stationary {
-                    movement.pattern = MovementPattern::Aggressive;
+                // Mobile bosses get faster and change movement in later phases
+                if !data.stationary {
+                    movement.speed *= 1.2;
+                    if next_phase >= 3 {
+                        movement.pattern = MovementPattern::Aggressive;
+                    } else if next_phase == 2 {
+                        movement.pattern = MovementPattern::Strafe;
+                    }
                 }
 
                 // Screen shake on phase change
@@ -704,11 +989,75 @@ fn boss_phase_check(
     }
 }
 
+/// Run the scripted phase transition, then resume battle (behind a shield
+/// gate for carriers and stations)
+fn boss_phase_transition(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut boss_query: Query<
+        (
+            Entity,
+            &mut Transform,
+            &mut BossState,
+            &mut BossMovement,
+            &mut BossPhaseTransition,
+            Option<&mut BossDroneSpawner>,
+        ),
+        With<Boss>,
+    >,
+    mut encounter: ResMut<BossEncounter>,
+) {
+    let dt = time.delta_secs();
+
+    for (entity, mut transform, mut state, mut movement, mut transition, spawner) in
+        boss_query.iter_mut()
+    {
+        transition.elapsed += dt;
+        encounter.phase_timer = (PHASE_TRANSITION_DURATION - transition.elapsed).max(0.0);
+
+        // Eased retreat, then hover in place until the phase starts
+        let t = (transition.elapsed / PHASE_RETREAT_TIME).min(1.0);
+        let eased = t * t * (3.0 - 2.0 * t);
+        let hover = if t >= 1.0 {
+            (transition.elapsed * 6.0).sin() * 4.0
+        } else {
+            0.0
+        };
+        let pos = transition.from.lerp(transition.to, eased);
+        transform.translation.x = pos.x;
+        transform.translation.y = pos.y + hover;
+
+        if transition.elapsed >= PHASE_TRANSITION_DURATION {
+            transform.translation.y = transition.to.y;
+            *state = BossState::Battle;
+            movement.timer = 0.0;
+            encounter.phase_timer = 0.0;
+            commands.entity(entity).remove::<BossPhaseTransition>();
+
+            if let Some(mut spawner) = spawner.filter(|s| s.phase_gates) {
+                commands
+                    .entity(entity)
+                    .insert(BossShieldGate::new(spawner.gates_opened));
+                spawner.gates_opened += 1;
+            }
+        }
+    }
+}
+
 /// Boss drone spawning system
 fn boss_drone_spawning(
     mut commands: Commands,
     time: Res<Time>,
-    mut boss_query: Query<(&Transform, &BossState, &BossData, &mut BossDroneSpawner), With<Boss>>,
+    mut boss_query: Query<
+        (
+            &Transform,
+            &BossState,
+            &BossData,
+            &mut BossDroneSpawner,
+            Has<BossShieldGate>,
+        ),
+        With<Boss>,
+    >,
     enemy_query: Query<Entity, With<crate::entities::Enemy>>,
     sprite_cache: Res<crate::assets::ShipSpriteCache>,
     model_cache: Res<ShipModelCache>,
@@ -716,9 +1065,9 @@ fn boss_drone_spawning(
 ) {
     let dt = time.delta_secs();
 
-    for (transform, state, data, mut spawner) in boss_query.iter_mut() {
-        // Only spawn during battle phase
-        if *state != BossState::Battle {
+    for (transform, state, data, mut spawner, gated) in boss_query.iter_mut() {
+        // Only spawn during battle phase; a shield gate launches its own wave
+        if *state != BossState::Battle || gated {
             continue;
         }
 
@@ -742,66 +1091,159 @@ fn boss_drone_spawning(
 
             // Spawn drones based on pattern
             for i in 0..count {
-                let spawn_pos = match spawner.pattern {
-                    DroneSpawnPattern::Flanking => {
-                        let offset = (i as f32 - (count - 1) as f32 / 2.0) * 50.0;
-                        Vec2::new(boss_pos.x + offset, boss_pos.y - 60.0)
-                    }
-                    DroneSpawnPattern::VFormation => {
-                        let offset = (i as f32 - (count - 1) as f32 / 2.0) * 40.0;
-                        let y_offset = offset.abs() * 0.5;
-                        Vec2::new(boss_pos.x + offset, boss_pos.y - 50.0 - y_offset)
-                    }
-                    DroneSpawnPattern::Surround => {
-                        let angle = (i as f32 / count as f32) * std::f32::consts::TAU;
-                        let radius = 80.0;
-                        Vec2::new(
-                            boss_pos.x + angle.cos() * radius,
-                            boss_pos.y + angle.sin() * radius,
-                        )
-                    }
-                    DroneSpawnPattern::FromSides => {
-                        let side = if i % 2 == 0 { -1.0 } else { 1.0 };
-                        let offset = (i as f32 / 2.0).floor() * 30.0;
-                        Vec2::new(boss_pos.x + side * 100.0, boss_pos.y - offset)
-                    }
-                };
+                let spawn_pos = spawner.pattern.spawn_position(i, count, boss_pos);
+                spawn_boss_drone(
+                    &mut commands,
+                    spawner.drone_type_id,
+                    spawn_pos,
+                    &sprite_cache,
+                    &model_cache,
+                    &mut explosion_events,
+                );
+            }
 
-                // Spawn effect
-                explosion_events.send(ExplosionEvent {
-                    position: spawn_pos,
-                    size: ExplosionSize::Tiny,
-                    color: Color::srgb(0.5, 0.8, 1.0),
-                });
+            debug!("{} launched {} drones!", data.name, count);
+        }
+    }
+}
 
-                // Get sprite for drone type
-                let sprite = sprite_cache.get(spawner.drone_type_id);
-
-                // Determine behavior based on drone type (fast fighters chase, tough fighters snipe)
-                // Fast fighters: 585 (Slasher), 589 (Executioner), 583 (Condor), 608 (Atron)
-                // Tough fighters: 598 (Breacher), 591 (Tormentor), 602 (Kestrel), 594 (Incursus)
-                let behavior = match spawner.drone_type_id {
-                    585 | 589 | 583 | 608 => crate::entities::EnemyBehavior::Homing, // Fast - chase player
-                    598 | 591 | 602 | 594 => crate::entities::EnemyBehavior::Sniper, // Tough - stay at range
-                    593 => crate::entities::EnemyBehavior::Weaver, // Tristan - erratic
-                    _ => crate::entities::EnemyBehavior::Linear,
-                };
+/// Launch one boss drone with a spawn flash
+fn spawn_boss_drone(
+    commands: &mut Commands,
+    drone_type_id: u32,
+    spawn_pos: Vec2,
+    sprite_cache: &crate::assets::ShipSpriteCache,
+    model_cache: &ShipModelCache,
+    explosion_events: &mut EventWriter<ExplosionEvent>,
+) -> Entity {
+    // Spawn effect
+    explosion_events.send(ExplosionEvent {
+        position: spawn_pos,
+        size: ExplosionSize::Tiny,
+        color: Color::srgb(0.5, 0.8, 1.0),
+    });
+
+    // Get sprite for drone type
+    let sprite = sprite_cache.get(drone_type_id);
+
+    // Determine behavior based on drone type (fast fighters chase, tough fighters snipe)
+    // Fast fighters: 585 (Slasher), 589 (Executioner), 583 (Condor), 608 (Atron)
+    // Tough fighters: 598 (Breacher), 591 (Tormentor), 602 (Kestrel), 594 (Incursus)
+    let behavior = match drone_type_id {
+        585 | 589 | 583 | 608 => crate::entities::EnemyBehavior::Homing, // Fast - chase player
+        598 | 591 | 602 | 594 => crate::entities::EnemyBehavior::Sniper, // Tough - stay at range
+        593 => crate::entities::EnemyBehavior::Weaver,                   // Tristan - erratic
+        _ => crate::entities::EnemyBehavior::Linear,
+    };
+
+    crate::entities::spawn_enemy(
+        commands,
+        drone_type_id,
+        spawn_pos,
+        behavior,
+        sprite,
+        Some(model_cache),
+    )
+}
 
-                crate::entities::spawn_enemy(
+/// Launch each shield gate's drone wave and drop the shield once it's cleared
+fn boss_shield_gates(
+    mut commands: Commands,
+    mut boss_query: Query<
+        (
+            Entity,
+            &Transform,
+            &BossData,
+            &mut BossDroneSpawner,
+            &mut BossShieldGate,
+        ),
+        With<Boss>,
+    >,
+    drone_query: Query<&GateDrone>,
+    sprite_cache: Res<crate::assets::ShipSpriteCache>,
+    model_cache: Res<ShipModelCache>,
+    mut explosion_events: EventWriter<ExplosionEvent>,
+    mut screen_shake: ResMut<ScreenShake>,
+) {
+    for (entity, transform, data, mut spawner, mut gate) in boss_query.iter_mut() {
+        let boss_pos = transform.translation.truncate();
+
+        if gate.total == 0 {
+            let count = spawner.gate_wave_size(gate.index);
+            let pattern = spawner.pattern.for_gate(gate.index);
+            for i in 0..count {
+                let drone = spawn_boss_drone(
                     &mut commands,
                     spawner.drone_type_id,
-                    spawn_pos,
-                    behavior,
-                    sprite,
-                    Some(&model_cache),
+                    pattern.spawn_position(i, count, boss_pos),
+                    &sprite_cache,
+                    &model_cache,
+                    &mut explosion_events,
                 );
+                commands.entity(drone).insert(GateDrone { boss: entity });
             }
+            gate.total = count;
+            gate.remaining = count;
+            info!(
+                "{} raises its shields - {} drones ({:?}) guard gate {}",
+                data.name,
+                count,
+                pattern,
+                gate.index + 1
+            );
+            continue;
+        }
 
-            info!("{} launched {} drones!", data.name, count);
+        gate.remaining = drone_query.iter().filter(|d| d.boss == entity).count() as u32;
+        if gate.remaining == 0 {
+            commands.entity(entity).remove::<BossShieldGate>();
+            // A breather before the regular launches resume
+            spawner.spawn_timer = spawner.spawn_interval;
+            screen_shake.trigger(4.0, 0.2);
+            explosion_events.send(ExplosionEvent {
+                position: boss_pos,
+                size: ExplosionSize::Medium,
+                color: Color::srgb(0.4, 0.8, 1.0),
+            });
+            info!("{} shields down!", data.name);
         }
     }
 }
 
+/// First stage whose boss beams are phased (Act 2)
+pub const PHASED_BEAMS_STAGE: u32 = 5;
+
+/// First stage whose heaviest attacks are phased too (Act 3)
+pub const PHASED_HEAVY_STAGE: u32 = 10;
+
+/// Whether shots from a boss on `stage` firing `pattern` pass through
+/// shield bubbles
+pub fn attack_is_phased(stage: u32, pattern: &str) -> bool {
+    let beam = matches!(
+        pattern,
+        "steady_beam" | "focused_beams" | "laser_sweep" | "beam_sweep" | "purifying_beams"
+    );
+    let heavy = matches!(pattern, "mega_beam" | "doomsday");
+    (beam && stage >= PHASED_BEAMS_STAGE) || (heavy && stage >= PHASED_HEAVY_STAGE)
+}
+
+/// Attack pattern for a boss phase. New Game+ shifts to the table of the
+/// boss `stage_shift` stages on; the final boss, with nothing past it,
+/// skips ahead to its own later phases instead.
+pub fn phase_pattern(boss_id: u32, phase: u32, ng_depth: u32) -> String {
+    let shift = NgPlusModifiers::for_depth(ng_depth).stage_shift;
+    if shift == 0 {
+        return get_phase_pattern(boss_id, phase);
+    }
+    let tier = (boss_id + shift).min(FINAL_BOSS_STAGE);
+    let phase = if tier == boss_id {
+        (phase + ng_depth).min(5)
+    } else {
+        phase
+    };
+    get_phase_pattern(tier, phase)
+}
+
 /// Get attack pattern for boss phase
 fn get_phase_pattern(boss_id: u32, phase: u32) -> String {
     match (boss_id, phase) {
@@ -889,11 +1331,23 @@ fn get_phase_pattern(boss_id: u32, phase: u32) -> String {
 /// Handle boss taking damage
 fn boss_damage(
     mut commands: Commands,
-    mut boss_query: Query<(Entity, &Transform, &mut BossData, &mut BossState), With<Boss>>,
-    projectile_query: Query<
-        (Entity, &Transform, &ProjectileDamage),
-        With<crate::entities::PlayerProjectile>,
+    mut boss_query: Query<
+        (
+            Entity,
+            &Transform,
+            &mut BossData,
+            &mut BossState,
+            Has<BossShieldGate>,
+        ),
+        With<Boss>,
     >,
+    projectile_query: Query<(
+        Entity,
+        &Transform,
+        &ProjectileDamage,
+        &ProjectileOwner,
+        Has<FleetShot>,
+    )>,
     mut score: ResMut<ScoreSystem>,
     mut heat_system: ResMut<ComboHeatSystem>,
     mut encounter: ResMut<BossEncounter>,
@@ -902,7 +1356,7 @@ fn boss_damage(
     mut dialogue_events: EventWriter<DialogueEvent>,
     mut screen_shake: ResMut<ScreenShake>,
 ) {
-    for (boss_entity, boss_transform, mut data, mut state) in boss_query.iter_mut() {
+    for (boss_entity, boss_transform, mut data, mut state, gated) in boss_query.iter_mut() {
         if *state == BossState::Defeated {
             continue;
         }
@@ -910,16 +1364,31 @@ fn boss_damage(
         let boss_pos = boss_transform.translation.truncate();
         let boss_radius = 60.0; // Approximate hitbox
 
-        // Check projectile collisions (only player projectiles in this query)
-        for (proj_entity, proj_transform, damage) in projectile_query.iter() {
+        // Check projectile collisions
+        for (proj_entity, proj_transform, damage, owner, fleet_shot) in projectile_query.iter() {
+            if !owner.hits_ship(boss_entity, Team::Enemy) {
+                continue;
+            }
             let proj_pos = proj_transform.translation.truncate();
             let distance = (boss_pos - proj_pos).length();
 
             if distance < boss_radius + 10.0 {
-                // Hit!
-                data.health -= damage.damage;
                 commands.entity(proj_entity).despawn();
 
+                // Shielded while changing phase or behind a gate - shots
+                // deflect harmlessly
+                if *state == BossState::PhaseTransition || gated {
+                    explosion_events.send(ExplosionEvent {
+                        position: proj_pos,
+                        size: ExplosionSize::Tiny,
+                        color: Color::srgb(0.4, 0.8, 1.0),
+                    });
+                    break;
+                }
+
+                // Hit!
+                data.health = boss_health_after_hit(data.health, damage.damage, fleet_shot);
+
                 // Check for defeat
                 if data.health <= 0.0 {
                     *state = BossState::Defeated;
@@ -927,14 +1396,14 @@ fn boss_damage(
 
                     // Add score
                     let mult = heat_system.on_kill();
-                    let final_score = (data.score_value as f32 * mult) as u64;
-                    score.score += final_score;
+                    let final_score = score.award((data.score_value as f32 * mult) as u64);
                     heat_system.souls_liberated += data.liberation_value;
 
                     defeated_events.send(BossDefeatedEvent {
                         boss_name: data.name.clone(),
                         score: final_score,
                         liberation_value: data.liberation_value,
+                        position: boss_pos,
                     });
 
                     // Trigger boss defeat dialogue
@@ -972,3 +1441,223 @@ fn boss_damage(
         }
     }
 }
+
+/// Boss health after a hit; allied fleet shots can wear a boss down but
+/// never finish it, so the kill stays the player's
+pub fn boss_health_after_hit(health: f32, damage: f32, fleet_shot: bool) -> f32 {
+    if fleet_shot {
+        (health - damage).max(health.min(1.0))
+    } else {
+        health - damage
+    }
+}
+
+/// Push on a player at `offset` from the boss, whose hulls touch at
+/// `contact_distance`: straight outward, strongest at (and inside) the hull
+pub fn repulsion(offset: Vec2, contact_distance: f32) -> Vec2 {
+    let depth = contact_distance + REPULSION_RANGE - offset.length();
+    if depth <= 0.0 {
+        return Vec2::ZERO;
+    }
+    // Dead centre: out the bottom, toward the player's side of the screen
+    let away = offset.try_normalize().unwrap_or(Vec2::NEG_Y);
+    away * REPULSION_SPEED * (depth / REPULSION_RANGE).min(1.0)
+}
+
+/// Keep the player off boss hulls, and charge for touching one
+fn boss_contact(
+    time: Res<Time>,
+    difficulty: Res<Difficulty>,
+    boss_query: Query<(&Transform, &Hitbox, &BossData, &BossState), With<Boss>>,
+    mut player_query: Query<
+        (
+            &mut Transform,
+            &mut Movement,
+            &mut ShipStats,
+            &Hitbox,
+            &PowerupEffects,
+            &super::ManeuverState,
+        ),
+        (With<Player>, Without<Boss>),
+    >,
+    mut damage_events: EventWriter<PlayerDamagedEvent>,
+    mut destroyed_events: EventWriter<PlayerDestroyedEvent>,
+    mut explosion_events: EventWriter<ExplosionEvent>,
+    mut screen_shake: ResMut<ScreenShake>,
+    mut cooldown: Local<f32>,
+) {
+    let Ok((mut transform, mut movement, mut stats, hitbox, powerups, maneuver)) =
+        player_query.get_single_mut()
+    else {
+        return;
+    };
+    let dt = time.delta_secs();
+    *cooldown = (*cooldown - dt).max(0.0);
+
+    for (boss_transform, boss_hitbox, data, state) in boss_query.iter() {
+        if *state == BossState::Defeated {
+            continue;
+        }
+        let boss_pos = boss_transform.translation.truncate();
+        let offset = transform.translation.truncate() - boss_pos;
+        let contact_distance = boss_hitbox.radius + hitbox.radius;
+
+        let push = repulsion(offset, contact_distance);
+        transform.translation += (push * dt).extend(0.0);
+
+        // Only a boss that's in the fight hurts to touch
+        let touching = offset.length() < contact_distance;
+        if !touching || *state == BossState::Intro || *cooldown > 0.0 {
+            continue;
+        }
+        *cooldown = CONTACT_COOLDOWN;
+        let away = offset.try_normalize().unwrap_or(Vec2::NEG_Y);
+        movement.velocity = away * CONTACT_KNOCKBACK;
+        explosion_events.send(ExplosionEvent {
+            position: boss_pos + away * boss_hitbox.radius,
+            size: ExplosionSize::Small,
+            color: Color::srgb(1.0, 0.7, 0.3),
+        });
+        screen_shake.small();
+
+        if powerups.is_invulnerable() || maneuver.invincible {
+            continue;
+        }
+        let damage = difficulty.boss_modifiers().contact_damage;
+        let layers = stats.absorb_damage(damage, DamageType::Kinetic);
+        damage_events.send(PlayerDamagedEvent {
+            damage,
+            damage_type: DamageType::Kinetic,
+            source_position: boss_pos,
+            source: format!("{} (collision)", data.name),
+            layers,
+        });
+        if stats.hull <= 0.0 {
+            info!("Player destroyed flying into {}", data.name);
+            destroyed_events.send(PlayerDestroyedEvent {
+                position: transform.translation.truncate(),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allied_fleet_shots_never_land_the_killing_blow() {
+        assert_eq!(boss_health_after_hit(50.0, 7.0, true), 43.0);
+        assert_eq!(boss_health_after_hit(5.0, 7.0, true), 1.0);
+        assert_eq!(boss_health_after_hit(5.0, 7.0, false), -2.0);
+    }
+
+    #[test]
+    fn repulsion_fades_out_past_the_hull() {
+        let at_hull = repulsion(Vec2::new(0.0, -50.0), 50.0);
+        let halfway = repulsion(Vec2::new(0.0, -50.0 - REPULSION_RANGE / 2.0), 50.0);
+        assert_eq!(at_hull, Vec2::new(0.0, -REPULSION_SPEED));
+        assert!(halfway.y < 0.0 && halfway.length() < at_hull.length());
+        assert_eq!(repulsion(Vec2::new(0.0, -200.0), 50.0), Vec2::ZERO);
+        // Overlapping dead centre still gets pushed out
+        assert_eq!(repulsion(Vec2::ZERO, 50.0), at_hull);
+    }
+
+    #[test]
+    fn aimed_shots_lead_more_on_harder_difficulties() {
+        let from = Vec2::new(0.0, 300.0);
+        let target = Vec2::ZERO;
+        let velocity = Vec2::new(200.0, 0.0);
+        let lead_x = |difficulty: Difficulty| {
+            BossAim::from_accuracy(difficulty.enemy_modifiers().accuracy_multiplier)
+                .aim_point(from, target, velocity, 300.0)
+                .x
+        };
+        assert!(lead_x(Difficulty::Carebear) > 0.0);
+        assert!(lead_x(Difficulty::Carebear) < lead_x(Difficulty::Newbro));
+        assert!(lead_x(Difficulty::Newbro) < lead_x(Difficulty::Triglavian));
+        // Never past the full intercept
+        assert!(lead_x(Difficulty::Triglavian) <= 200.0);
+
+        // A still target is aimed at directly
+        let aim = BossAim::from_accuracy(1.5);
+        assert_eq!(aim.aim_point(from, target, Vec2::ZERO, 300.0), target);
+    }
+
+    #[test]
+    fn aim_only_wanders_below_normal_accuracy() {
+        let mut rng = fastrand::Rng::with_seed(7);
+        let (from, target) = (Vec2::new(0.0, 300.0), Vec2::ZERO);
+
+        let steady = BossAim::from_accuracy(1.0);
+        assert_eq!(steady.jitter, 0.0);
+        assert_eq!(
+            steady.direction(from, target, Vec2::ZERO, 300.0, &mut rng),
+            Vec2::NEG_Y
+        );
+
+        let shaky = BossAim::from_accuracy(0.6);
+        let spread: Vec<f32> = (0..20)
+            .map(|_| {
+                shaky
+                    .direction(from, target, Vec2::ZERO, 300.0, &mut rng)
+                    .angle_to(Vec2::NEG_Y)
+            })
+            .collect();
+        assert!(spread.iter().all(|a| a.abs() <= shaky.jitter + 1e-4));
+        assert!(spread.iter().any(|a| a.abs() > 0.01));
+    }
+
+    #[test]
+    fn new_game_plus_draws_phases_from_later_bosses() {
+        assert_eq!(phase_pattern(1, 1, 0), "steady_beam");
+        // Bestower phase 1 borrows the Maller Fleet's
+        assert_eq!(phase_pattern(1, 1, 1), get_phase_pattern(4, 1));
+        // Past the end of the table the Avatar skips ahead a phase
+        assert_eq!(phase_pattern(13, 4, 1), "doomsday");
+        assert_eq!(phase_pattern(12, 2, 2), get_phase_pattern(13, 2));
+    }
+
+    #[test]
+    fn beams_phase_from_act_two_and_heavy_attacks_from_act_three() {
+        assert!(!attack_is_phased(4, "steady_beam"));
+        assert!(attack_is_phased(5, "laser_sweep"));
+        assert!(!attack_is_phased(9, "doomsday"));
+        assert!(attack_is_phased(10, "doomsday"));
+        assert!(!attack_is_phased(13, "spiral"));
+    }
+
+    #[test]
+    fn each_gate_switches_formation() {
+        let base = DroneSpawnPattern::VFormation;
+        let gates: Vec<_> = (0..4).map(|g| base.for_gate(g)).collect();
+        assert_ne!(gates[0], base);
+        for pair in gates.windows(2) {
+            assert_ne!(pair[0], pair[1]);
+        }
+        // Cycles back round after every formation has been used
+        assert_eq!(base.for_gate(3), base);
+    }
+
+    #[test]
+    fn gate_waves_grow_up_to_a_cap() {
+        let spawner = BossDroneSpawner {
+            drones_per_wave: 2,
+            max_drones: 4,
+            ..default()
+        };
+        assert_eq!(spawner.gate_wave_size(0), 4);
+        assert_eq!(spawner.gate_wave_size(1), 6);
+        assert_eq!(spawner.gate_wave_size(2), 8);
+        assert_eq!(spawner.gate_wave_size(5), 8);
+    }
+
+    #[test]
+    fn surround_rings_the_boss() {
+        let boss = Vec2::new(0.0, 200.0);
+        for i in 0..6 {
+            let pos = DroneSpawnPattern::Surround.spawn_position(i, 6, boss);
+            assert!((pos.distance(boss) - 80.0).abs() < 1e-3);
+        }
+    }
+}
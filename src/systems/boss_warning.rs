@@ -0,0 +1,177 @@
+//! Boss Warning
+//!
+//! Once a mission's last wave is done, a klaxon sounds and a flashing
+//! WARNING banner and red screen-edge glow (see the HUD) give fair notice
+//! before the boss intro. For as long as the warning lasts the player can
+//! mop up stragglers; anything still flying when it ends warps out. The
+//! length is set per mission (`Mission::boss_warning`, overridable in dev
+//! mission files).
+
+#![allow(dead_code)]
+
+use crate::core::*;
+use crate::entities::{Boss, Enemy};
+use bevy::prelude::*;
+
+/// Seconds between klaxon blasts
+pub const KLAXON_INTERVAL: f32 = 1.0;
+
+/// Banner flashes per second
+const BANNER_FLASH_RATE: f32 = 2.0;
+
+/// Flash left where a straggler warps out
+const WARP_OUT_COLOR: Color = Color::srgb(0.6, 0.8, 1.0);
+
+/// Boss warning plugin
+pub struct BossWarningPlugin;
+
+impl Plugin for BossWarningPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BossWarning>()
+            .add_event::<BossKlaxonEvent>()
+            .add_systems(
+                Update,
+                (reset_boss_warning, run_boss_warning)
+                    .chain()
+                    .in_set(GameSet::Simulation)
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+/// Where the mission is in its run-up to the boss
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Default)]
+pub enum BossWarning {
+    /// Waves still running
+    #[default]
+    Idle,
+    /// Klaxon sounding
+    Sounding { elapsed: f32, duration: f32 },
+    /// Warning over; the boss is on its way
+    Over,
+}
+
+impl BossWarning {
+    /// Start sounding for `duration` seconds
+    pub fn start(&mut self, duration: f32) {
+        *self = BossWarning::Sounding {
+            elapsed: 0.0,
+            duration,
+        };
+    }
+
+    pub fn is_idle(&self) -> bool {
+        *self == BossWarning::Idle
+    }
+
+    pub fn is_sounding(&self) -> bool {
+        matches!(self, BossWarning::Sounding { .. })
+    }
+
+    /// Seconds into the warning, while it's sounding
+    pub fn elapsed(&self) -> Option<f32> {
+        match *self {
+            BossWarning::Sounding { elapsed, .. } => Some(elapsed),
+            _ => None,
+        }
+    }
+
+    /// Seconds of grace left, while it's sounding
+    pub fn remaining(&self) -> Option<f32> {
+        match *self {
+            BossWarning::Sounding { elapsed, duration } => Some((duration - elapsed).max(0.0)),
+            _ => None,
+        }
+    }
+}
+
+/// Banner and edge glow strength `elapsed` seconds into a warning
+/// (0.0 - 1.0); held steady with reduced motion
+pub fn warning_pulse(elapsed: f32, reduced_motion: bool) -> f32 {
+    if reduced_motion {
+        return 0.8;
+    }
+    let phase = (elapsed * BANNER_FLASH_RATE).fract();
+    if phase < 0.5 {
+        1.0
+    } else {
+        0.25
+    }
+}
+
+/// A klaxon blast is due
+#[derive(Event, Debug)]
+pub struct BossKlaxonEvent;
+
+fn reset_boss_warning(
+    mut events: EventReader<MissionStartEvent>,
+    mut warning: ResMut<BossWarning>,
+) {
+    if events.read().last().is_some() {
+        *warning = BossWarning::Idle;
+    }
+}
+
+/// Count the warning down, sounding the klaxon, then clear the field and
+/// bring the boss in
+fn run_boss_warning(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut warning: ResMut<BossWarning>,
+    enemies: Query<(Entity, &Transform), (With<Enemy>, Without<Boss>)>,
+    mut klaxon_events: EventWriter<BossKlaxonEvent>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let BossWarning::Sounding { elapsed, duration } = *warning else {
+        return;
+    };
+
+    // A blast as it starts, then one per interval
+    let now = elapsed + time.delta_secs();
+    if elapsed == 0.0 || (now / KLAXON_INTERVAL).floor() > (elapsed / KLAXON_INTERVAL).floor() {
+        klaxon_events.send(BossKlaxonEvent);
+    }
+    if now < duration {
+        *warning = BossWarning::Sounding {
+            elapsed: now,
+            duration,
+        };
+        return;
+    }
+
+    for (entity, transform) in enemies.iter() {
+        super::spawn_impact_flash(
+            &mut commands,
+            transform.translation.truncate(),
+            WARP_OUT_COLOR,
+            None,
+        );
+        commands.entity(entity).despawn_recursive();
+    }
+    *warning = BossWarning::Over;
+    next_state.set(GameState::BossIntro);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn warning_runs_idle_to_sounding_to_over() {
+        let mut warning = BossWarning::default();
+        assert!(warning.is_idle());
+        assert_eq!(warning.remaining(), None);
+
+        warning.start(3.0);
+        assert!(warning.is_sounding());
+        assert_eq!(warning.elapsed(), Some(0.0));
+        assert_eq!(warning.remaining(), Some(3.0));
+    }
+
+    #[test]
+    fn banner_flashes_unless_motion_is_reduced() {
+        assert_eq!(warning_pulse(0.1, false), 1.0);
+        assert!(warning_pulse(0.3, false) < 0.5);
+        assert_eq!(warning_pulse(0.1, true), warning_pulse(0.3, true));
+    }
+}
@@ -0,0 +1,171 @@
+//! Bullet Cancel
+//!
+//! When a boss phase ends or a boss dies, every live enemy projectile is
+//! converted into a score chip that gets magnetised to the player.
+
+#![allow(dead_code)]
+
+use super::boss::{BossDefeatedEvent as StageBossDefeatedEvent, BossPhaseChangeEvent};
+use crate::core::*;
+use crate::entities::{Player, ProjectileOwner};
+use bevy::prelude::*;
+
+/// Score per cancelled bullet when a phase ends
+const PHASE_CANCEL_VALUE: u64 = 10;
+
+/// Score per cancelled bullet when the boss dies
+const DEFEAT_CANCEL_VALUE: u64 = 25;
+
+/// Time chips scatter before homing in
+const CHIP_SCATTER_TIME: f32 = 0.3;
+
+/// Homing acceleration (px/s^2)
+const CHIP_MAGNET_ACCEL: f32 = 2400.0;
+
+/// Chips are collected this close to the player
+const CHIP_COLLECT_RADIUS: f32 = 24.0;
+
+/// Chips that haven't arrived by now are collected anyway
+const CHIP_MAX_AGE: f32 = 3.0;
+
+/// Bullet cancel plugin
+pub struct BulletCancelPlugin;
+
+impl Plugin for BulletCancelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (sweep_enemy_projectiles, update_score_chips)
+                .chain()
+                .in_set(GameSet::Scoring)
+                .run_if(in_state(GameState::Playing)),
+        )
+        .add_systems(OnExit(GameState::Playing), despawn_score_chips);
+    }
+}
+
+/// A cancelled bullet flying to the player
+#[derive(Component, Debug)]
+pub struct ScoreChip {
+    pub value: u64,
+    pub velocity: Vec2,
+    pub age: f32,
+}
+
+/// Convert all enemy projectiles to score chips on phase end or boss defeat
+fn sweep_enemy_projectiles(
+    mut commands: Commands,
+    mut phase_events: EventReader<BossPhaseChangeEvent>,
+    mut stage_defeats: EventReader<StageBossDefeatedEvent>,
+    mut defeats: EventReader<BossDefeatedEvent>,
+    projectile_query: Query<(Entity, &Transform, &ProjectileOwner)>,
+) {
+    let phase_ended = phase_events.read().count() > 0;
+    let boss_died = stage_defeats.read().count() > 0 || defeats.read().count() > 0;
+    if !phase_ended && !boss_died {
+        return;
+    }
+
+    let value = if boss_died {
+        DEFEAT_CANCEL_VALUE
+    } else {
+        PHASE_CANCEL_VALUE
+    };
+
+    let mut cancelled = 0;
+    for (entity, transform, owner) in projectile_query.iter() {
+        if owner.is_player() {
+            continue;
+        }
+        let pos = transform.translation.truncate();
+        commands.entity(entity).despawn_recursive();
+
+        // Small random pop outward before the magnet kicks in
+        let angle = fastrand::f32() * std::f32::consts::TAU;
+        let speed = 60.0 + fastrand::f32() * 80.0;
+        commands.spawn((
+            ScoreChip {
+                value,
+                velocity: Vec2::new(angle.cos(), angle.sin()) * speed,
+                age: 0.0,
+            },
+            Sprite {
+                color: Color::srgb(1.0, 0.85, 0.3),
+                custom_size: Some(Vec2::splat(6.0)),
+                ..default()
+            },
+            Transform::from_xyz(pos.x, pos.y, LAYER_EFFECTS)
+                .with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4)),
+        ));
+        cancelled += 1;
+    }
+
+    if cancelled > 0 {
+        info!("Bullet cancel: {} projectiles -> score", cancelled);
+    }
+}
+
+/// Scatter, then home in on the player and pay out on contact
+fn update_score_chips(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut chip_query: Query<(Entity, &mut Transform, &mut ScoreChip, &mut Sprite)>,
+    player_query: Query<&Transform, (With<Player>, Without<ScoreChip>)>,
+    mut score_events: EventWriter<ScoreEvent>,
+    mut explosion_events: EventWriter<ExplosionEvent>,
+) {
+    let dt = time.delta_secs();
+    let player_pos = player_query.get_single().map(|t| t.translation.truncate());
+
+    for (entity, mut transform, mut chip, mut sprite) in chip_query.iter_mut() {
+        chip.age += dt;
+        let pos = transform.translation.truncate();
+
+        let Ok(target) = player_pos else {
+            // No player to collect them - just fade out
+            sprite.color = sprite.color.with_alpha((1.0 - chip.age).max(0.0));
+            if chip.age >= 1.0 {
+                commands.entity(entity).despawn_recursive();
+            }
+            continue;
+        };
+
+        if chip.age < CHIP_SCATTER_TIME {
+            // Drag out the initial pop
+            chip.velocity *= 1.0 - (6.0 * dt).min(1.0);
+        } else {
+            // Magnet: accelerate toward the player, shedding sideways drift
+            let to_player = target - pos;
+            let dir = to_player.normalize_or_zero();
+            let speed = chip.velocity.dot(dir).max(0.0) + CHIP_MAGNET_ACCEL * dt;
+            chip.velocity = dir * speed;
+        }
+
+        let step = chip.velocity * dt;
+        let collected = pos.distance(target) < CHIP_COLLECT_RADIUS.max(step.length())
+            || chip.age >= CHIP_MAX_AGE;
+
+        if collected {
+            score_events.send(ScoreEvent::new(ScoreCategory::Salvage, chip.value).at(target));
+            if fastrand::f32() < 0.25 {
+                explosion_events.send(ExplosionEvent {
+                    position: target,
+                    size: ExplosionSize::Tiny,
+                    color: Color::srgb(1.0, 0.9, 0.4),
+                });
+            }
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+
+        transform.translation.x += step.x;
+        transform.translation.y += step.y;
+        transform.rotate_z(dt * 8.0);
+    }
+}
+
+fn despawn_score_chips(mut commands: Commands, query: Query<Entity, With<ScoreChip>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
@@ -1,6 +1,9 @@
 //! Campaign System
 //!
-//! Manages mission flow, wave spawning, and boss fights.
+//! Manages mission flow, wave spawning, and boss fights. Each wave spends a
+//! spawn budget shaped by the mission's pacing curve, and moves on when its
+//! mission-declared trigger is met: the field cleared, a share of the wave
+//! destroyed, a hold-out timer, or a marked ship destroyed.
 
 use crate::assets::{ShipModelCache, ShipSpriteCache};
 use crate::core::events::BossDefeatedEvent;
@@ -31,6 +34,8 @@ impl Plugin for CampaignPlugin {
                 check_boss_defeated,
                 check_mission_complete,
             )
+                .chain()
+                .in_set(GameSet::Simulation)
                 .run_if(in_state(GameState::Playing))
                 .run_if(not(is_cg_module)),
         )
@@ -51,6 +56,17 @@ impl Plugin for CampaignPlugin {
     }
 }
 
+/// Gold bracket around a wave's marked ship
+const TARGET_BRACKET_COLOR: Color = Color::srgba(1.0, 0.8, 0.25, 0.35);
+
+/// Which campaign wave an enemy spawned with
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WaveMember(pub u32);
+
+/// The ship a `DestroyTarget` wave is waiting on
+#[derive(Component, Debug, Clone, Copy)]
+pub struct WaveTarget;
+
 /// Run condition: is Caldari/Gallente module active?
 fn is_cg_module(active_module: Res<ActiveModule>) -> bool {
     active_module.is_caldari_gallente()
@@ -78,35 +94,51 @@ fn start_mission(
 fn update_mission_timer(time: Res<Time>, mut campaign: ResMut<CampaignState>) {
     if campaign.in_mission {
         campaign.mission_timer += time.delta_secs();
+        campaign.wave_timer += time.delta_secs();
     }
 }
 
-/// Check if current wave is complete
+/// Check the latest wave against its trigger
 fn check_wave_complete(
     mut campaign: ResMut<CampaignState>,
-    enemy_query: Query<Entity, With<Enemy>>,
-    _boss_query: Query<Entity, With<Boss>>,
+    enemy_query: Query<Option<&WaveMember>, With<Enemy>>,
+    target_query: Query<&WaveMember, With<WaveTarget>>,
     mut wave_events: EventWriter<WaveCompleteEvent>,
 ) {
-    // Don't check if we're in boss wave
-    if campaign.is_boss_wave() {
-        return;
-    }
-
     // Count remaining enemies
     let enemy_count = enemy_query.iter().count();
     campaign.enemies_remaining = enemy_count as u32;
 
-    // Wave complete when no enemies remain
-    if enemy_count == 0 && campaign.current_wave > 0 {
-        if let Some(mission) = campaign.current_mission() {
-            if campaign.current_wave <= mission.enemy_waves {
-                wave_events.send(WaveCompleteEvent {
-                    wave_number: campaign.current_wave,
-                });
-                info!("Wave {} complete!", campaign.current_wave);
-            }
-        }
+    if !campaign.wave_in_progress() || campaign.current_mission().is_none() {
+        return;
+    }
+
+    let wave = campaign.spawned_wave();
+    let remaining = enemy_query
+        .iter()
+        .filter(|member| member.is_some_and(|m| m.0 == wave))
+        .count() as u32;
+    let destroyed = campaign.wave_spawned.saturating_sub(remaining);
+    if campaign.wave_destroyed != destroyed {
+        campaign.wave_destroyed = destroyed;
+    }
+
+    let progress = WaveProgress {
+        spawned: campaign.wave_spawned,
+        destroyed,
+        elapsed: campaign.wave_timer,
+        field_clear: enemy_count == 0,
+        target_alive: target_query.iter().any(|m| m.0 == wave),
+    };
+    if !campaign.wave_trigger(wave).is_met(&progress) {
+        return;
+    }
+
+    campaign.waves_cleared = wave;
+    // Attack modes keep spawning past the mission's waves without tallies
+    if wave <= campaign.enemy_waves() {
+        wave_events.send(WaveCompleteEvent { wave_number: wave });
+        info!("Wave {} complete!", wave);
     }
 }
 
@@ -115,50 +147,60 @@ fn spawn_next_wave(
     mut commands: Commands,
     mut campaign: ResMut<CampaignState>,
     session: Res<crate::core::GameSession>,
-    enemy_query: Query<Entity, With<Enemy>>,
     boss_query: Query<Entity, With<Boss>>,
     sprite_cache: Res<ShipSpriteCache>,
     model_cache: Res<ShipModelCache>,
     difficulty: Res<Difficulty>,
-    mut next_state: ResMut<NextState<GameState>>,
+    run_mode: Res<RunMode>,
+    mut run_rng: ResMut<RunRng>,
+    mut boss_warning: ResMut<super::BossWarning>,
+    playfield: Res<Playfield>,
 ) {
-    // Only spawn if no enemies remain
-    if enemy_query.iter().count() > 0 || boss_query.iter().count() > 0 {
+    // Only spawn once the last wave's trigger is met
+    if campaign.wave_in_progress() || boss_query.iter().count() > 0 {
         return;
     }
 
-    let Some(mission) = campaign.current_mission() else {
+    if campaign.current_mission().is_none() {
         return;
-    };
+    }
 
-    // Check if it's boss time
-    if campaign.current_wave > mission.enemy_waves {
-        if !campaign.boss_spawned {
-            // Transition to boss intro
-            next_state.set(GameState::BossIntro);
+    // Check if it's boss time (attack modes keep the waves coming instead);
+    // the warning gives stragglers a last chance, then brings the boss in
+    if campaign.current_wave > campaign.enemy_waves() && !run_mode.is_attack() {
+        if !campaign.boss_spawned && boss_warning.is_idle() {
+            boss_warning.start(campaign.boss_warning());
         }
         return;
     }
 
-    // Spawn wave enemies
+    // Spend the wave's budget on the enemy faction's ships
     let wave = campaign.current_wave;
-    let base_count = 3 + wave as usize;
-    let spawn_mult = difficulty.spawn_rate_mult();
-    let count = (base_count as f32 * spawn_mult) as usize;
-
-    info!("Spawning wave {} with {} enemies", wave, count);
-
-    // Use faction-appropriate enemies from session
-    for i in 0..count {
-        let enemy_def = session.random_enemy();
+    let budget = wave_budget(
+        campaign.wave_budget(),
+        wave,
+        campaign.enemy_waves(),
+        &campaign.wave_pacing(),
+        difficulty.spawn_rate_mult(),
+    );
+    let roster = spend_budget(budget, session.enemy_ships(), &mut run_rng.spawns);
+    let count = roster.len();
+    let trigger = campaign.wave_trigger(wave);
+
+    info!(
+        "Spawning wave {} with {} enemies ({} points, {:?})",
+        wave, count, budget, trigger
+    );
+
+    for (i, enemy_def) in roster.iter().enumerate() {
         let type_id = enemy_def.type_id;
         let x = (i as f32 - count as f32 / 2.0) * 80.0;
-        let y = SCREEN_HEIGHT / 2.0 + 50.0 + (i as f32 * 20.0);
+        let y = playfield.half_height() + 50.0 + (i as f32 * 20.0);
 
         // Get sprite from cache if available
         let sprite_handle = sprite_cache.get(type_id);
 
-        spawn_enemy(
+        let entity = spawn_enemy(
             &mut commands,
             type_id,
             Vec2::new(x, y),
@@ -166,9 +208,30 @@ fn spawn_next_wave(
             sprite_handle,
             Some(&model_cache),
         );
+        commands.entity(entity).insert(WaveMember(wave));
+
+        // The middle ship of a target wave is the one that counts
+        if trigger.marks_target() && i == count / 2 {
+            commands
+                .entity(entity)
+                .insert(WaveTarget)
+                .with_children(|parent| {
+                    parent.spawn((
+                        Sprite {
+                            color: TARGET_BRACKET_COLOR,
+                            custom_size: Some(Vec2::splat(72.0)),
+                            ..default()
+                        },
+                        Transform::from_xyz(0.0, 0.0, -0.1),
+                    ));
+                });
+        }
     }
 
     campaign.current_wave += 1;
+    campaign.wave_spawned = count as u32;
+    campaign.wave_destroyed = 0;
+    campaign.wave_timer = 0.0;
 }
 
 /// Spawn boss for current mission
@@ -178,6 +241,7 @@ fn spawn_mission_boss(
     session: Res<crate::core::GameSession>,
     sprite_cache: Res<ShipSpriteCache>,
     model_cache: Res<ShipModelCache>,
+    playfield: Res<Playfield>,
     mut boss_events: EventWriter<BossSpawnEvent>,
 ) {
     let Some(mission) = campaign.current_mission() else {
@@ -189,6 +253,7 @@ fn spawn_mission_boss(
 
     if spawn_boss(
         &mut commands,
+        &playfield,
         stage,
         session.enemy_faction,
         Some(&sprite_cache),
@@ -205,6 +270,7 @@ fn spawn_mission_boss(
 /// Boss intro sequence - descend and show name
 fn boss_intro_sequence(
     time: Res<Time>,
+    playfield: Res<Playfield>,
     mut boss_query: Query<(&mut Transform, &mut BossState, &BossData), With<Boss>>,
     mut next_state: ResMut<NextState<GameState>>,
     mut timer: Local<f32>,
@@ -214,7 +280,7 @@ fn boss_intro_sequence(
     for (mut transform, mut state, data) in boss_query.iter_mut() {
         if *state == BossState::Intro {
             // Descend boss
-            let target_y = SCREEN_HEIGHT / 2.0 - 100.0;
+            let target_y = playfield.half_height() - 100.0;
             if transform.translation.y > target_y {
                 transform.translation.y -= 100.0 * time.delta_secs();
             }
@@ -243,7 +309,7 @@ fn update_boss_behavior(
     mut boss_query: Query<
         (
             &mut Transform,
-            &mut BossData,
+            &BossData,
             &mut crate::entities::boss::BossMovement,
             &mut crate::entities::boss::BossAttack,
             &BossState,
@@ -258,7 +324,7 @@ fn update_boss_behavior(
         .map(|t| t.translation.truncate())
         .unwrap_or(Vec2::ZERO);
 
-    for (mut transform, mut data, mut movement, mut attack, state) in boss_query.iter_mut() {
+    for (mut transform, data, mut movement, mut attack, state) in boss_query.iter_mut() {
         if *state != BossState::Battle {
             continue;
         }
@@ -293,24 +359,8 @@ fn update_boss_behavior(
             _ => {}
         }
 
-        // Check for phase transitions
-        let health_percent = data.health / data.max_health;
-        let phase_threshold =
-            crate::entities::boss::get_phase_threshold(data.current_phase + 1, data.total_phases);
-
-        if health_percent <= phase_threshold && data.current_phase < data.total_phases {
-            data.current_phase += 1;
-            info!("Boss entering phase {}!", data.current_phase);
-
-            // Change pattern on phase change
-            movement.pattern = match data.current_phase {
-                2 => crate::entities::boss::MovementPattern::Strafe,
-                3 => crate::entities::boss::MovementPattern::Aggressive,
-                _ => crate::entities::boss::MovementPattern::Sweep,
-            };
-            movement.speed *= 1.2;
-            attack.fire_rate *= 0.8; // Fire faster
-        }
+        // Phase changes are driven by BossPlugin (boss_phase_check), which
+        // runs the invulnerable transition sequence
 
         // Attack pattern
         attack.fire_timer += dt;
@@ -322,17 +372,18 @@ fn update_boss_behavior(
             let projectile_speed = 250.0 + (data.current_phase as f32 * 50.0);
 
             commands.spawn((
-                crate::entities::EnemyProjectile,
+                crate::entities::ProjectileOwner::enemy(),
+                // Tagged so phase transitions clear it with the rest
+                super::boss::BossProjectile,
                 crate::entities::ProjectileDamage {
                     damage: 20.0 + (data.current_phase as f32 * 5.0),
                     damage_type: DamageType::EM,
                     crit_chance: 0.08, // 8% crit for boss
                     crit_multiplier: 1.5,
                 },
-                crate::entities::Movement {
+                crate::entities::ProjectilePhysics {
                     velocity: dir * projectile_speed,
-                    max_speed: projectile_speed,
-                    ..default()
+                    lifetime: 4.0,
                 },
                 Sprite {
                     color: Color::srgb(1.0, 0.8, 0.2),
@@ -353,6 +404,7 @@ fn check_boss_defeated(
     mut ship_unlocks: ResMut<ShipUnlocks>,
     mut save_data: ResMut<crate::core::SaveData>,
     session: Res<crate::core::GameSession>,
+    mut run_rng: ResMut<RunRng>,
     boss_query: Query<(Entity, &Transform, &BossData), With<Boss>>,
     mut boss_events: EventWriter<BossDefeatedEvent>,
     mut act_events: EventWriter<ActCompleteEvent>,
@@ -367,7 +419,12 @@ fn check_boss_defeated(
 
             // Spawn massive liberation pod burst for boss defeat
             let pos = transform.translation.truncate();
-            crate::entities::spawn_liberation_pods(&mut commands, pos, data.liberation_value);
+            crate::entities::spawn_liberation_pods(
+                &mut commands,
+                pos,
+                data.liberation_value,
+                &mut run_rng.drops,
+            );
             campaign.mission_souls += data.liberation_value;
 
             // Mark boss defeated
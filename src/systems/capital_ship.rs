@@ -0,0 +1,508 @@
+//! Capital Ship Events
+//!
+//! Rare mid-mission event: an enemy carrier warps onto the grid and
+//! continuously launches fighters from its hangar bays. Destroying every
+//! bay before the carrier warps off stops the launches and pays out a
+//! large score bonus.
+
+#![allow(dead_code)]
+
+use super::dialogue::{CombatCalloutType, DialogueEvent};
+use super::effects::{spawn_damage_number, HitFlash, ScreenShake};
+use super::spawning::WaveManager;
+use crate::assets::{ShipModelCache, ShipSprite, ShipSpriteCache};
+use crate::core::*;
+use crate::entities::{spawn_enemy, EnemyBehavior, ProjectileDamage, ProjectileOwner};
+use crate::games::caldari_gallente::LastStandState;
+use bevy::prelude::*;
+
+/// Chance per wave (from wave 2 onward) that a carrier warps in
+const CARRIER_EVENT_CHANCE: f32 = 0.2;
+
+/// Seconds the player has to destroy the hangar bays
+const CARRIER_EVENT_WINDOW: f32 = 40.0;
+
+/// Seconds between fighter launches from each surviving bay
+const FIGHTER_LAUNCH_INTERVAL: f32 = 4.0;
+
+/// Hangar bay hit points
+const HANGAR_BAY_HEALTH: f32 = 200.0;
+
+/// Hangar bay hit radius
+const HANGAR_BAY_RADIUS: f32 = 16.0;
+
+/// Score for each hangar bay destroyed
+const HANGAR_BAY_SCORE: u64 = 2_000;
+
+/// Bonus for destroying every bay inside the window
+const CARRIER_EVENT_BONUS: u64 = 25_000;
+
+/// Hangar bay offsets relative to the carrier
+const HANGAR_BAY_OFFSETS: [Vec2; 3] = [
+    Vec2::new(-80.0, -30.0),
+    Vec2::new(0.0, -55.0),
+    Vec2::new(80.0, -30.0),
+];
+
+/// Carrier display size (smaller than the backdrop carrier so bays read clearly)
+const HOSTILE_CARRIER_SIZE: f32 = 280.0;
+
+/// How far below the top of the playfield the carrier rests
+const HOSTILE_CARRIER_TOP_INSET: f32 = 130.0;
+
+/// Capital ship event plugin
+pub struct CapitalShipPlugin;
+
+impl Plugin for CapitalShipPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CapitalShipEvent>()
+            .add_systems(OnEnter(GameState::Playing), reset_capital_event)
+            .add_systems(
+                Update,
+                (
+                    trigger_capital_event,
+                    update_capital_event,
+                    animate_hostile_carrier,
+                    hangar_bay_collision,
+                    launch_carrier_fighters,
+                    update_radar_pings,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Playing))
+                    .run_if(not_last_stand),
+            )
+            .add_systems(OnExit(GameState::Playing), cleanup_capital_event);
+    }
+}
+
+/// Run condition: Last Stand mode is NOT active
+fn not_last_stand(last_stand: Option<Res<LastStandState>>) -> bool {
+    last_stand.map(|ls| !ls.active).unwrap_or(true)
+}
+
+/// Capital ship event state
+#[derive(Resource, Debug, Default)]
+pub struct CapitalShipEvent {
+    /// Carrier is on grid
+    pub active: bool,
+    /// Seconds left before the carrier warps off
+    pub time_remaining: f32,
+    /// Hangar bays still launching fighters
+    pub bays_remaining: u32,
+    /// Stage the last event occurred in (one carrier per stage)
+    pub last_stage: u32,
+}
+
+impl CapitalShipEvent {
+    /// Fraction of the event window remaining (0.0 - 1.0)
+    pub fn time_percent(&self) -> f32 {
+        (self.time_remaining / CARRIER_EVENT_WINDOW).clamp(0.0, 1.0)
+    }
+}
+
+/// The event carrier
+#[derive(Component, Debug)]
+pub struct HostileCarrier {
+    /// Animation timer
+    pub timer: f32,
+    /// Warp-in progress (0.0 = warping, 1.0 = arrived)
+    pub warp_progress: f32,
+    /// Warping off (event ended)
+    pub departing: bool,
+}
+
+/// Destructible hangar bay mounted on the carrier
+#[derive(Component, Debug)]
+pub struct HangarBay {
+    /// Offset from the carrier
+    pub offset: Vec2,
+    /// Current HP
+    pub health: f32,
+    /// Time until next fighter launch
+    pub launch_timer: f32,
+}
+
+/// Expanding radar ring marking a new contact
+#[derive(Component, Debug)]
+pub struct RadarPing {
+    /// Elapsed time
+    pub timer: f32,
+    /// Total duration
+    pub duration: f32,
+    /// Ring color
+    pub color: Color,
+}
+
+/// Reset event state at the start of gameplay
+fn reset_capital_event(mut event: ResMut<CapitalShipEvent>) {
+    *event = CapitalShipEvent::default();
+}
+
+/// Roll for a carrier when a new wave begins
+fn trigger_capital_event(
+    mut commands: Commands,
+    mut wave_events: EventReader<SpawnWaveEvent>,
+    mut event: ResMut<CapitalShipEvent>,
+    manager: Res<WaveManager>,
+    session: Res<GameSession>,
+    sprite_cache: Res<ShipSpriteCache>,
+    mut dialogue_events: EventWriter<DialogueEvent>,
+    mut run_rng: ResMut<RunRng>,
+    playfield: Res<Playfield>,
+) {
+    for wave in wave_events.read() {
+        if event.active
+            || manager.boss_active
+            || wave.wave_number < 2
+            || event.last_stage == manager.current_stage
+            || run_rng.spawns.f32() >= CARRIER_EVENT_CHANCE
+        {
+            continue;
+        }
+
+        event.active = true;
+        event.time_remaining = CARRIER_EVENT_WINDOW;
+        event.bays_remaining = HANGAR_BAY_OFFSETS.len() as u32;
+        event.last_stage = manager.current_stage;
+
+        let carrier_id = session.enemy_faction.carrier_type_id();
+        spawn_hostile_carrier(
+            &mut commands,
+            &playfield,
+            carrier_id,
+            sprite_cache.get(carrier_id),
+        );
+
+        dialogue_events.send(DialogueEvent {
+            priority: 7,
+            ..DialogueEvent::combat_callout(CombatCalloutType::CarrierInbound)
+        });
+
+        info!(
+            "Enemy {} carrier on grid - destroy its hangar bays!",
+            session.enemy_faction.short_name()
+        );
+    }
+}
+
+/// Spawn the carrier, its hangar bays, and a radar ping
+fn spawn_hostile_carrier(
+    commands: &mut Commands,
+    playfield: &Playfield,
+    carrier_id: u32,
+    sprite: Option<ShipSprite>,
+) {
+    let rest_y = playfield.half_height() - HOSTILE_CARRIER_TOP_INSET;
+    let start = Vec3::new(0.0, rest_y + 250.0, LAYER_ENEMIES - 2.0);
+
+    let mut entity = commands.spawn((
+        HostileCarrier {
+            timer: 0.0,
+            warp_progress: 0.0,
+            departing: false,
+        },
+        Transform::from_translation(start),
+        Visibility::Visible,
+        Name::new("HostileCarrier"),
+    ));
+
+    // Carrier faces down (rotated 180° + ship-specific correction)
+    if let Some(texture) = sprite {
+        let rotation =
+            std::f32::consts::PI + crate::entities::get_ship_rotation_correction(carrier_id);
+        entity.insert((
+            Sprite {
+                color: Color::srgba(1.0, 1.0, 1.0, 0.0),
+                custom_size: Some(Vec2::splat(HOSTILE_CARRIER_SIZE)),
+                ..texture.sprite()
+            },
+            Transform::from_translation(start).with_rotation(Quat::from_rotation_z(rotation)),
+        ));
+    } else {
+        entity.insert(Sprite {
+            color: Color::srgba(0.5, 0.5, 0.55, 0.0),
+            custom_size: Some(Vec2::new(HOSTILE_CARRIER_SIZE, HOSTILE_CARRIER_SIZE * 0.4)),
+            ..default()
+        });
+    }
+
+    for (i, offset) in HANGAR_BAY_OFFSETS.iter().enumerate() {
+        commands.spawn((
+            HangarBay {
+                offset: *offset,
+                health: HANGAR_BAY_HEALTH,
+                // Stagger first launches so bays don't fire in unison
+                launch_timer: FIGHTER_LAUNCH_INTERVAL * (0.5 + i as f32 * 0.3),
+            },
+            Sprite {
+                color: Color::srgb(1.0, 0.45, 0.2),
+                custom_size: Some(Vec2::splat(HANGAR_BAY_RADIUS * 2.0)),
+                ..default()
+            },
+            Transform::from_xyz(start.x + offset.x, start.y + offset.y, LAYER_ENEMIES - 1.0),
+        ));
+    }
+
+    commands.spawn((
+        RadarPing {
+            timer: 0.0,
+            duration: 1.5,
+            color: Color::srgb(1.0, 0.3, 0.2),
+        },
+        Transform::from_xyz(0.0, rest_y, LAYER_EFFECTS),
+    ));
+}
+
+/// Tick the event window and resolve success or escape
+fn update_capital_event(
+    time: Res<Time>,
+    mut event: ResMut<CapitalShipEvent>,
+    mut score_events: EventWriter<ScoreEvent>,
+    mut carrier_query: Query<&mut HostileCarrier>,
+    mut dialogue_events: EventWriter<DialogueEvent>,
+    mut screen_shake: ResMut<ScreenShake>,
+) {
+    if !event.active {
+        return;
+    }
+
+    event.time_remaining -= time.delta_secs();
+
+    let callout = if event.bays_remaining == 0 {
+        score_events.send(ScoreEvent::new(ScoreCategory::Bonus, CARRIER_EVENT_BONUS));
+        screen_shake.large();
+        info!("Carrier hangars destroyed! +{} bonus", CARRIER_EVENT_BONUS);
+        CombatCalloutType::CarrierHangarsDestroyed
+    } else if event.time_remaining <= 0.0 {
+        info!(
+            "Carrier warped off with {} bays intact",
+            event.bays_remaining
+        );
+        CombatCalloutType::CarrierEscaped
+    } else {
+        return;
+    };
+
+    event.active = false;
+    for mut carrier in carrier_query.iter_mut() {
+        carrier.departing = true;
+    }
+    dialogue_events.send(DialogueEvent {
+        priority: 7,
+        ..DialogueEvent::combat_callout(callout)
+    });
+}
+
+/// Warp-in, bobbing, warp-off, and keep bays attached to the hull
+fn animate_hostile_carrier(
+    mut commands: Commands,
+    time: Res<Time>,
+    playfield: Res<Playfield>,
+    mut carrier_query: Query<
+        (
+            Entity,
+            &mut HostileCarrier,
+            &mut Transform,
+            Option<&mut Sprite>,
+        ),
+        Without<HangarBay>,
+    >,
+    mut bay_query: Query<
+        (Entity, &HangarBay, &mut Transform, &mut Sprite),
+        Without<HostileCarrier>,
+    >,
+) {
+    let dt = time.delta_secs();
+
+    let Ok((carrier_entity, mut carrier, mut transform, sprite)) = carrier_query.get_single_mut()
+    else {
+        return;
+    };
+
+    carrier.timer += dt;
+
+    let alpha = if carrier.departing {
+        carrier.warp_progress = (carrier.warp_progress - dt * 0.6).max(0.0);
+        carrier.warp_progress
+    } else if carrier.warp_progress < 1.0 {
+        carrier.warp_progress = (carrier.warp_progress + dt * 0.5).min(1.0);
+        carrier.warp_progress
+    } else {
+        1.0
+    };
+
+    let bob = (carrier.timer * 0.4).sin() * 6.0;
+    let eased = 1.0 - (1.0 - carrier.warp_progress).powi(3);
+    let rest_y = playfield.half_height() - HOSTILE_CARRIER_TOP_INSET;
+    transform.translation.y = rest_y + 250.0 * (1.0 - eased) + bob * eased;
+
+    if let Some(mut sprite) = sprite {
+        sprite.color = sprite.color.with_alpha(alpha * 0.9);
+    }
+
+    let carrier_pos = transform.translation.truncate();
+    let pulse = 0.7 + (carrier.timer * 5.0).sin() * 0.3;
+    for (bay_entity, bay, mut bay_transform, mut bay_sprite) in bay_query.iter_mut() {
+        bay_transform.translation.x = carrier_pos.x + bay.offset.x;
+        bay_transform.translation.y = carrier_pos.y + bay.offset.y;
+        bay_sprite.color = bay_sprite.color.with_alpha(alpha * pulse);
+
+        if carrier.departing && carrier.warp_progress <= 0.0 {
+            commands.entity(bay_entity).despawn_recursive();
+        }
+    }
+
+    if carrier.departing && carrier.warp_progress <= 0.0 {
+        commands.entity(carrier_entity).despawn_recursive();
+    }
+}
+
+/// Player projectiles hitting hangar bays
+fn hangar_bay_collision(
+    mut commands: Commands,
+    projectile_query: Query<(Entity, &Transform, &ProjectileDamage, &ProjectileOwner)>,
+    mut bay_query: Query<(Entity, &Transform, &mut HangarBay, &Sprite)>,
+    carrier_query: Query<&HostileCarrier>,
+    mut event: ResMut<CapitalShipEvent>,
+    mut score_events: EventWriter<ScoreEvent>,
+    mut explosion_events: EventWriter<ExplosionEvent>,
+    mut screen_shake: ResMut<ScreenShake>,
+) {
+    // Bays are only targetable once the carrier has fully arrived
+    let Ok(carrier) = carrier_query.get_single() else {
+        return;
+    };
+    if carrier.warp_progress < 1.0 || carrier.departing {
+        return;
+    }
+
+    let hit_radius_sq = (HANGAR_BAY_RADIUS + 4.0) * (HANGAR_BAY_RADIUS + 4.0);
+
+    for (bay_entity, bay_transform, mut bay, sprite) in bay_query.iter_mut() {
+        if bay.health <= 0.0 {
+            continue;
+        }
+        let bay_pos = bay_transform.translation.truncate();
+
+        for (proj_entity, proj_transform, proj_damage, owner) in projectile_query.iter() {
+            if !owner.is_player() {
+                continue;
+            }
+            let proj_pos = proj_transform.translation.truncate();
+            if (proj_pos - bay_pos).length_squared() >= hit_radius_sq {
+                continue;
+            }
+
+            commands.entity(proj_entity).despawn_recursive();
+            bay.health -= proj_damage.damage;
+
+            commands
+                .entity(bay_entity)
+                .insert(HitFlash::new(sprite.color));
+            spawn_damage_number(&mut commands, bay_pos, proj_damage.damage, false);
+
+            if bay.health <= 0.0 {
+                event.bays_remaining = event.bays_remaining.saturating_sub(1);
+                score_events
+                    .send(ScoreEvent::new(ScoreCategory::Bonus, HANGAR_BAY_SCORE).at(bay_pos));
+                screen_shake.medium();
+                explosion_events.send(ExplosionEvent {
+                    position: bay_pos,
+                    size: ExplosionSize::Medium,
+                    color: Color::srgb(1.0, 0.5, 0.2),
+                });
+                commands.entity(bay_entity).despawn_recursive();
+                info!("Hangar bay destroyed! {} remaining", event.bays_remaining);
+                break;
+            }
+        }
+    }
+}
+
+/// Surviving bays keep launching fighters while the event is active
+fn launch_carrier_fighters(
+    mut commands: Commands,
+    time: Res<Time>,
+    event: Res<CapitalShipEvent>,
+    session: Res<GameSession>,
+    sprite_cache: Res<ShipSpriteCache>,
+    model_cache: Res<ShipModelCache>,
+    carrier_query: Query<&HostileCarrier>,
+    mut bay_query: Query<(&Transform, &mut HangarBay)>,
+    mut run_rng: ResMut<RunRng>,
+) {
+    if !event.active {
+        return;
+    }
+    let Ok(carrier) = carrier_query.get_single() else {
+        return;
+    };
+    if carrier.warp_progress < 1.0 {
+        return;
+    }
+
+    let dt = time.delta_secs();
+    for (transform, mut bay) in bay_query.iter_mut() {
+        bay.launch_timer -= dt;
+        if bay.launch_timer > 0.0 {
+            continue;
+        }
+        bay.launch_timer = FIGHTER_LAUNCH_INTERVAL;
+
+        let type_id = if run_rng.spawns.f32() < 0.25 {
+            session.enemy_faction.tough_fighter_type_id()
+        } else {
+            session.enemy_faction.fighter_type_id()
+        };
+        let pos = transform.translation.truncate() - Vec2::new(0.0, 20.0);
+        spawn_enemy(
+            &mut commands,
+            type_id,
+            pos,
+            EnemyBehavior::Linear,
+            sprite_cache.get(type_id),
+            Some(&model_cache),
+        );
+    }
+}
+
+/// Expand and fade radar pings
+fn update_radar_pings(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut gizmos: Gizmos,
+    mut query: Query<(Entity, &Transform, &mut RadarPing)>,
+) {
+    for (entity, transform, mut ping) in query.iter_mut() {
+        ping.timer += time.delta_secs();
+        let t = ping.timer / ping.duration;
+        if t >= 1.0 {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+
+        // Two staggered rings sweeping outward
+        let center = transform.translation.truncate();
+        for phase in [0.0, 0.35] {
+            let ring_t = (t - phase).max(0.0) / (1.0 - phase);
+            if ring_t <= 0.0 {
+                continue;
+            }
+            let radius = 20.0 + ring_t * 260.0;
+            let color = ping.color.with_alpha((1.0 - ring_t) * 0.8);
+            gizmos.circle_2d(center, radius, color);
+        }
+    }
+}
+
+/// Remove carrier, bays, and pings when leaving gameplay
+fn cleanup_capital_event(
+    mut commands: Commands,
+    query: Query<Entity, Or<(With<HostileCarrier>, With<HangarBay>, With<RadarPing>)>>,
+    mut event: ResMut<CapitalShipEvent>,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    event.active = false;
+}
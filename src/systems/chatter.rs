@@ -0,0 +1,273 @@
+//! Situational Chatter
+//!
+//! Comms lines driven by how the fight is going rather than by scripted
+//! events: the narrator speaks up when the player's ship is badly hurt, when
+//! a bonus objective is nearly done, after a long stretch without a kill,
+//! and when the score multiplier passes a new mark. Each kind of line has
+//! its own cooldown, and chatter only ever speaks into silence and at low
+//! priority, so briefings, boss lines and warnings always come first.
+
+#![allow(dead_code)]
+
+use super::{CombatCalloutType, DialogueEvent, DialogueSystem};
+use crate::core::*;
+use crate::entities::{Enemy, Player, ShipStats};
+use bevy::prelude::*;
+
+/// Least time between any two chatter lines
+const CHATTER_GAP: f32 = 8.0;
+
+/// Share of total hit points that counts as badly hurt
+const LOW_HEALTH: f32 = 0.4;
+
+/// Share of total hit points that counts as nearly dead
+const NEAR_DEATH: f32 = 0.2;
+
+/// Share of a bonus objective's counter that counts as nearly done
+const BONUS_NEARLY_DONE: f32 = 0.75;
+
+/// Seconds without a kill, enemies about, before the narrator notices
+const KILL_DROUGHT: f32 = 20.0;
+
+/// Multiplier marks worth a comment, lowest first
+const MULTIPLIER_MARKS: [f32; 4] = [5.0, 10.0, 25.0, 50.0];
+
+/// Situational chatter plugin
+pub struct ChatterPlugin;
+
+impl Plugin for ChatterPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ChatterState>().add_systems(
+            Update,
+            (reset_chatter, situational_chatter)
+                .chain()
+                .in_set(GameSet::Ui)
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
+
+/// Kinds of situational line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatterKind {
+    LowHealth,
+    NearDeath,
+    BonusNearlyDone,
+    KillDrought,
+    HighMultiplier,
+}
+
+impl ChatterKind {
+    const ALL: [ChatterKind; 5] = [
+        ChatterKind::NearDeath,
+        ChatterKind::LowHealth,
+        ChatterKind::BonusNearlyDone,
+        ChatterKind::KillDrought,
+        ChatterKind::HighMultiplier,
+    ];
+
+    /// Seconds before this kind of line can come round again
+    pub fn cooldown(&self) -> f32 {
+        match self {
+            ChatterKind::LowHealth => 25.0,
+            ChatterKind::NearDeath => 15.0,
+            ChatterKind::BonusNearlyDone => 10.0,
+            ChatterKind::KillDrought => 30.0,
+            ChatterKind::HighMultiplier => 12.0,
+        }
+    }
+
+    /// Below every scripted line; being hurt matters more than banter
+    pub fn priority(&self) -> u8 {
+        match self {
+            ChatterKind::NearDeath => 4,
+            ChatterKind::LowHealth => 3,
+            _ => 2,
+        }
+    }
+
+    fn callout(&self) -> CombatCalloutType {
+        match self {
+            ChatterKind::LowHealth => CombatCalloutType::LowHealth,
+            ChatterKind::NearDeath => CombatCalloutType::NearDeath,
+            ChatterKind::BonusNearlyDone => CombatCalloutType::BonusObjectiveClose,
+            ChatterKind::KillDrought => CombatCalloutType::KillDrought,
+            ChatterKind::HighMultiplier => CombatCalloutType::HighMultiplier,
+        }
+    }
+
+    fn index(&self) -> usize {
+        Self::ALL.iter().position(|k| k == self).unwrap_or(0)
+    }
+}
+
+/// Cooldowns and what's already been said this mission
+#[derive(Resource, Debug, Default)]
+pub struct ChatterState {
+    /// Seconds left before each kind can speak again
+    cooldowns: [f32; 5],
+    /// Seconds left before any chatter can speak
+    gap: f32,
+    /// Seconds since the last kill
+    pub since_kill: f32,
+    /// Bonus objectives already called out (by index)
+    pub bonus_called: Vec<usize>,
+    /// Highest multiplier mark called out in the current chain
+    pub multiplier_mark: f32,
+}
+
+impl ChatterState {
+    pub fn tick(&mut self, dt: f32) {
+        self.gap = (self.gap - dt).max(0.0);
+        for cooldown in self.cooldowns.iter_mut() {
+            *cooldown = (*cooldown - dt).max(0.0);
+        }
+    }
+
+    pub fn ready(&self, kind: ChatterKind) -> bool {
+        self.gap <= 0.0 && self.cooldowns[kind.index()] <= 0.0
+    }
+
+    pub fn spoke(&mut self, kind: ChatterKind) {
+        self.gap = CHATTER_GAP;
+        self.cooldowns[kind.index()] = kind.cooldown();
+    }
+}
+
+/// Highest mark `multiplier` has passed beyond `last`, if any
+pub fn new_multiplier_mark(multiplier: f32, last: f32) -> Option<f32> {
+    MULTIPLIER_MARKS
+        .iter()
+        .rev()
+        .find(|mark| multiplier >= **mark && **mark > last)
+        .copied()
+}
+
+/// An unfinished bonus counter most of the way to its target
+pub fn bonus_nearly_done(objective: &Objective) -> bool {
+    let target = objective.def.kind.target();
+    objective.def.tier == ObjectiveTier::Bonus
+        && objective.status == ObjectiveStatus::Active
+        && target > 1
+        && objective.progress as f32 >= target as f32 * BONUS_NEARLY_DONE
+}
+
+fn reset_chatter(mut events: EventReader<MissionStartEvent>, mut state: ResMut<ChatterState>) {
+    if events.read().last().is_some() {
+        *state = ChatterState::default();
+    }
+}
+
+/// Check each condition and say the most pressing line that's due
+fn situational_chatter(
+    time: Res<Time>,
+    mut state: ResMut<ChatterState>,
+    dialogue: Res<DialogueSystem>,
+    score: Res<ScoreSystem>,
+    tracker: Res<ObjectiveTracker>,
+    mut kills: EventReader<EnemyDestroyedEvent>,
+    player: Query<&ShipStats, With<Player>>,
+    enemies: Query<(), With<Enemy>>,
+    mut dialogue_events: EventWriter<DialogueEvent>,
+) {
+    state.tick(time.delta_secs());
+    if kills.read().count() > 0 || enemies.is_empty() {
+        state.since_kill = 0.0;
+    } else {
+        state.since_kill += time.delta_secs();
+    }
+    // A broken chain starts the marks over
+    if score.multiplier < MULTIPLIER_MARKS[0] {
+        state.multiplier_mark = 0.0;
+    }
+
+    let health = player.get_single().ok().map(|stats| {
+        let max = stats.max_shield + stats.max_armor + stats.max_hull;
+        (stats.shield + stats.armor + stats.hull) / max.max(1.0)
+    });
+    let bonus = tracker
+        .objectives()
+        .iter()
+        .enumerate()
+        .find(|(i, o)| bonus_nearly_done(o) && !state.bonus_called.contains(i))
+        .map(|(i, _)| i);
+    let mark = new_multiplier_mark(score.multiplier, state.multiplier_mark);
+
+    // Most pressing first
+    let due = ChatterKind::ALL.into_iter().find(|kind| match kind {
+        ChatterKind::NearDeath => health.is_some_and(|h| h < NEAR_DEATH),
+        ChatterKind::LowHealth => health.is_some_and(|h| h < LOW_HEALTH),
+        ChatterKind::BonusNearlyDone => bonus.is_some(),
+        ChatterKind::KillDrought => state.since_kill >= KILL_DROUGHT,
+        ChatterKind::HighMultiplier => mark.is_some(),
+    });
+    let Some(kind) = due else {
+        return;
+    };
+    // Never talk over (or queue behind) anything else
+    if !state.ready(kind) || !dialogue.is_quiet() {
+        return;
+    }
+
+    state.spoke(kind);
+    match kind {
+        ChatterKind::BonusNearlyDone => state.bonus_called.extend(bonus),
+        ChatterKind::KillDrought => state.since_kill = 0.0,
+        ChatterKind::HighMultiplier => state.multiplier_mark = mark.unwrap_or_default(),
+        _ => {}
+    }
+    dialogue_events.send(DialogueEvent {
+        priority: kind.priority(),
+        ..DialogueEvent::combat_callout(kind.callout())
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_kind_waits_out_its_cooldown_and_the_shared_gap() {
+        let mut state = ChatterState::default();
+        assert!(state.ready(ChatterKind::KillDrought));
+        state.spoke(ChatterKind::KillDrought);
+        assert!(!state.ready(ChatterKind::HighMultiplier));
+
+        state.tick(CHATTER_GAP);
+        assert!(state.ready(ChatterKind::HighMultiplier));
+        assert!(!state.ready(ChatterKind::KillDrought));
+        state.tick(ChatterKind::KillDrought.cooldown());
+        assert!(state.ready(ChatterKind::KillDrought));
+    }
+
+    #[test]
+    fn multiplier_marks_are_called_once_each() {
+        assert_eq!(new_multiplier_mark(4.0, 0.0), None);
+        assert_eq!(new_multiplier_mark(12.0, 0.0), Some(10.0));
+        assert_eq!(new_multiplier_mark(12.0, 10.0), None);
+        assert_eq!(new_multiplier_mark(30.0, 10.0), Some(25.0));
+    }
+
+    #[test]
+    fn bonus_counters_are_nearly_done_from_three_quarters() {
+        let def = |tier| {
+            ObjectiveDef::new(
+                tier,
+                "Frigates destroyed",
+                ObjectiveKind::DestroyEnemies {
+                    count: 8,
+                    type_id: None,
+                },
+            )
+        };
+        let mut bonus = Objective::new(def(ObjectiveTier::Bonus));
+        bonus.progress = 5;
+        assert!(!bonus_nearly_done(&bonus));
+        bonus.progress = 6;
+        assert!(bonus_nearly_done(&bonus));
+
+        let mut primary = Objective::new(def(ObjectiveTier::Primary));
+        primary.progress = 7;
+        assert!(!bonus_nearly_done(&primary));
+    }
+}
@@ -0,0 +1,152 @@
+//! Campaign Checkpoints
+//!
+//! After each Elder Fleet mission (boss kill or objective clear alike) the
+//! run is written into the save as a checkpoint: the mission to fly next,
+//! the ship, the score and the wing. The save writes itself out behind the
+//! change, so a quit or a crash between missions loses nothing, and the
+//! main menu offers to continue from it. A lost run or a finished campaign
+//! clears it, except in Ironman, where a lost ship hands the checkpoint on
+//! to the rest of the fleet.
+
+#![allow(dead_code)]
+
+use crate::core::*;
+use crate::entities::WingmanRoster;
+use crate::games::ActiveModule;
+use crate::systems::lose_flown_ship;
+use bevy::prelude::*;
+
+/// Campaign checkpoint plugin
+pub struct CheckpointPlugin;
+
+impl Plugin for CheckpointPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(GameState::StageComplete),
+            write_checkpoint.run_if(is_campaign_run),
+        )
+        .add_systems(
+            OnEnter(GameState::GameOver),
+            fail_checkpoint
+                .after(lose_flown_ship)
+                .run_if(is_campaign_run),
+        )
+        .add_systems(
+            OnEnter(GameState::Victory),
+            clear_checkpoint.run_if(is_campaign_run),
+        );
+    }
+}
+
+/// Run condition: an Elder Fleet campaign run (not Endless, attack or daily)
+pub fn is_campaign_run(
+    run_mode: Res<RunMode>,
+    active_module: Res<ActiveModule>,
+    endless: Res<EndlessMode>,
+) -> bool {
+    *run_mode == RunMode::Campaign && active_module.is_elder_fleet() && !endless.active
+}
+
+/// Checkpoint for the mission after the one just finished, or `None` when
+/// that was the campaign's last
+pub fn next_checkpoint(
+    campaign: &CampaignState,
+    session: &GameSession,
+    difficulty: Difficulty,
+    score: &ScoreSystem,
+    roster: &WingmanRoster,
+) -> Option<CampaignCheckpoint> {
+    let mut next = campaign.clone();
+    if !next.complete_mission() {
+        return None;
+    }
+    Some(CampaignCheckpoint {
+        player_faction: session.player_faction.name().to_string(),
+        enemy_faction: session.enemy_faction.name().to_string(),
+        difficulty: difficulty.name().to_string(),
+        ship_index: session.selected_ship_index,
+        skin_index: session.skin_index,
+        act: next.act.number(),
+        mission_index: next.mission_index,
+        mission_name: next.current_mission_name().to_string(),
+        score: score.score,
+        souls_liberated: score.souls_liberated,
+        wingmen: roster.flying.clone(),
+        flags: next.flags,
+    })
+}
+
+fn write_checkpoint(
+    campaign: Res<CampaignState>,
+    session: Res<GameSession>,
+    difficulty: Res<Difficulty>,
+    score: Res<ScoreSystem>,
+    roster: Res<WingmanRoster>,
+    mut save_data: ResMut<SaveData>,
+) {
+    let checkpoint = next_checkpoint(&campaign, &session, *difficulty, &score, &roster);
+    match &checkpoint {
+        Some(checkpoint) => info!(
+            "Checkpoint: Act {} {} with {} points",
+            checkpoint.act, checkpoint.mission_name, checkpoint.score
+        ),
+        None => info!("Campaign finished - checkpoint cleared"),
+    }
+    save_data.checkpoint = checkpoint;
+}
+
+/// A lost run ends the campaign unless Ironman still has ships to fly
+fn fail_checkpoint(save_data: ResMut<SaveData>) {
+    if save_data.fleet_roster.ironman {
+        return;
+    }
+    clear_checkpoint(save_data);
+}
+
+fn clear_checkpoint(mut save_data: ResMut<SaveData>) {
+    if save_data.checkpoint.is_some() {
+        save_data.checkpoint = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checkpoints_point_at_the_next_mission_until_the_last() {
+        let session = GameSession::new(Faction::Minmatar, Faction::Amarr);
+        let score = ScoreSystem {
+            score: 12_000,
+            ..default()
+        };
+        let roster = WingmanRoster {
+            flying: vec![0, 2],
+            ..default()
+        };
+        let last_of_act = CampaignState {
+            act: Act::Act1,
+            mission_index: Act::Act1.missions().len() - 1,
+            ..default()
+        };
+        let checkpoint = next_checkpoint(
+            &last_of_act,
+            &session,
+            Difficulty::BitterVet,
+            &score,
+            &roster,
+        )
+        .expect("act two follows act one");
+        assert_eq!((checkpoint.act, checkpoint.mission_index), (2, 0));
+        assert_eq!(checkpoint.score, 12_000);
+        assert_eq!(checkpoint.wingmen, vec![0, 2]);
+        assert_eq!(checkpoint.difficulty, "BITTER VET");
+
+        let finale = CampaignState {
+            act: Act::Act3,
+            mission_index: Act::Act3.missions().len() - 1,
+            ..default()
+        };
+        assert!(next_checkpoint(&finale, &session, Difficulty::Newbro, &score, &roster).is_none());
+    }
+}
@@ -0,0 +1,142 @@
+//! Codex Unlocks
+//!
+//! Fills in the player's codex as they play: both factions and the flown
+//! hull on launch, every hull that warps in, the act and mission being
+//! flown, and each campaign boss once it goes down. New entries are saved
+//! straight away and announced with a toast.
+
+#![allow(dead_code)]
+
+use crate::core::*;
+use crate::entities::{EnemyStats, Player};
+use crate::systems::BossDefeatedEvent;
+use crate::ui::ToastEvent;
+use bevy::prelude::*;
+
+/// Codex plugin
+pub struct CodexPlugin;
+
+impl Plugin for CodexPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CodexCatalog>().add_systems(
+            Update,
+            (
+                unlock_launch_entries,
+                unlock_encountered_ships,
+                unlock_mission_entries,
+                unlock_defeated_bosses,
+            )
+                .in_set(GameSet::Scoring),
+        );
+    }
+}
+
+/// The full codex, built once from the game's definitions
+#[derive(Resource, Debug)]
+pub struct CodexCatalog(pub Vec<CodexEntry>);
+
+impl Default for CodexCatalog {
+    fn default() -> Self {
+        Self(codex_entries())
+    }
+}
+
+impl CodexCatalog {
+    pub fn get(&self, id: &str) -> Option<&CodexEntry> {
+        self.0.iter().find(|e| e.id == id)
+    }
+}
+
+/// Unlock catalog entries and announce the new ones
+fn unlock_entries(
+    ids: impl IntoIterator<Item = String>,
+    catalog: &CodexCatalog,
+    save_data: &mut ResMut<SaveData>,
+    toasts: &mut EventWriter<ToastEvent>,
+) {
+    // Check before borrowing mutably so known entries don't trigger a save
+    let new: Vec<_> = ids
+        .into_iter()
+        .filter(|id| catalog.get(id).is_some() && !save_data.codex.is_unlocked(id))
+        .collect();
+    let mut titles = Vec::new();
+    for id in new {
+        let title = catalog
+            .get(&id)
+            .map(|e| e.title.clone())
+            .unwrap_or_default();
+        if save_data.codex.unlock(id) {
+            titles.push(title);
+        }
+    }
+    match titles.as_slice() {
+        [] => {}
+        [title] => {
+            toasts.send(ToastEvent::info(format!("CODEX: {}", title)));
+        }
+        _ => {
+            toasts.send(ToastEvent::info(format!(
+                "CODEX: {} NEW ENTRIES",
+                titles.len()
+            )));
+        }
+    }
+}
+
+/// Both sides of the war and the hull being flown
+fn unlock_launch_entries(
+    session: Res<GameSession>,
+    players: Query<(), Added<Player>>,
+    catalog: Res<CodexCatalog>,
+    mut save_data: ResMut<SaveData>,
+    mut toasts: EventWriter<ToastEvent>,
+) {
+    if players.is_empty() {
+        return;
+    }
+    let ids = [
+        faction_entry_id(session.player_faction),
+        faction_entry_id(session.enemy_faction),
+        ship_entry_id(session.selected_ship().type_id),
+    ];
+    unlock_entries(ids, &catalog, &mut save_data, &mut toasts);
+}
+
+fn unlock_encountered_ships(
+    enemies: Query<&EnemyStats, Added<EnemyStats>>,
+    catalog: Res<CodexCatalog>,
+    mut save_data: ResMut<SaveData>,
+    mut toasts: EventWriter<ToastEvent>,
+) {
+    let ids = enemies.iter().map(|stats| ship_entry_id(stats.type_id));
+    unlock_entries(ids, &catalog, &mut save_data, &mut toasts);
+}
+
+fn unlock_mission_entries(
+    mut mission_events: EventReader<MissionStartEvent>,
+    catalog: Res<CodexCatalog>,
+    mut save_data: ResMut<SaveData>,
+    mut toasts: EventWriter<ToastEvent>,
+) {
+    let mut ids = Vec::new();
+    for event in mission_events.read() {
+        if let Some(act) = act_of_mission(event.mission.id) {
+            ids.push(act_entry_id(act));
+        }
+        ids.push(mission_entry_id(event.mission.id));
+    }
+    unlock_entries(ids, &catalog, &mut save_data, &mut toasts);
+}
+
+fn unlock_defeated_bosses(
+    mut defeated_events: EventReader<BossDefeatedEvent>,
+    catalog: Res<CodexCatalog>,
+    mut save_data: ResMut<SaveData>,
+    mut toasts: EventWriter<ToastEvent>,
+) {
+    let ids: Vec<_> = defeated_events
+        .read()
+        .filter_map(|event| boss_entry_id_by_name(&event.boss_name))
+        .collect();
+    unlock_entries(ids, &catalog, &mut save_data, &mut toasts);
+}
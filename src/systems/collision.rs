@@ -4,28 +4,38 @@
 //! Uses a grid-based approach to reduce O(n²) to O(n).
 
 use crate::core::*;
-use crate::entities::collectible::{spawn_smart_powerup, PlayerHealthState};
+use crate::entities::collectible::PlayerHealthState;
 use crate::entities::*;
 use bevy::prelude::*;
 
 // Spatial grid configuration
 const CELL_SIZE: f32 = 50.0;
-const GRID_WIDTH: usize = 18; // 800 / 50 + padding
-const GRID_HEIGHT: usize = 16; // 700 / 50 + padding
+/// Spare cells past the playfield's far edges
+const GRID_PADDING: usize = 2;
 
-/// Spatial grid for fast collision lookups
+/// Player bullet vs enemy hull radius
+const ENEMY_HIT_RADIUS: f32 = 25.0;
+
+/// Spatial grid for fast collision lookups, covering the playfield
 #[derive(Resource, Default)]
 pub struct SpatialGrid {
     /// Grid cells containing enemy entity indices
     enemy_cells: Vec<Vec<(Entity, Vec2)>>,
+    /// Playfield size the grid was laid out for
+    size: Vec2,
+    width: usize,
+    height: usize,
 }
 
 impl SpatialGrid {
-    fn new() -> Self {
+    fn new(size: Vec2) -> Self {
+        let width = (size.x / CELL_SIZE).ceil() as usize + GRID_PADDING;
+        let height = (size.y / CELL_SIZE).ceil() as usize + GRID_PADDING;
         Self {
-            enemy_cells: (0..GRID_WIDTH * GRID_HEIGHT)
-                .map(|_| Vec::with_capacity(8))
-                .collect(),
+            enemy_cells: (0..width * height).map(|_| Vec::with_capacity(8)).collect(),
+            size,
+            width,
+            height,
         }
     }
 
@@ -36,44 +46,60 @@ impl SpatialGrid {
     }
 
     #[inline]
-    fn pos_to_cell(pos: Vec2) -> Option<usize> {
-        // Convert from centered coords (-400..400, -350..350) to grid coords
-        let gx = ((pos.x + SCREEN_WIDTH / 2.0) / CELL_SIZE) as usize;
-        let gy = ((pos.y + SCREEN_HEIGHT / 2.0) / CELL_SIZE) as usize;
+    fn pos_to_cell(&self, pos: Vec2) -> Option<usize> {
+        // Convert from centered coords (-400..400, -350..350 at the base size) to grid coords
+        let gx = ((pos.x + self.size.x / 2.0) / CELL_SIZE) as usize;
+        let gy = ((pos.y + self.size.y / 2.0) / CELL_SIZE) as usize;
 
-        if gx < GRID_WIDTH && gy < GRID_HEIGHT {
-            Some(gy * GRID_WIDTH + gx)
+        if gx < self.width && gy < self.height {
+            Some(gy * self.width + gx)
         } else {
             None
         }
     }
 
     fn insert_enemy(&mut self, entity: Entity, pos: Vec2) {
-        if let Some(idx) = Self::pos_to_cell(pos) {
+        if let Some(idx) = self.pos_to_cell(pos) {
             self.enemy_cells[idx].push((entity, pos));
         }
     }
 
-    /// Get enemies in the same cell and adjacent cells (for border cases)
-    fn get_nearby_enemies(&self, pos: Vec2) -> impl Iterator<Item = &(Entity, Vec2)> {
-        let gx = ((pos.x + SCREEN_WIDTH / 2.0) / CELL_SIZE) as i32;
-        let gy = ((pos.y + SCREEN_HEIGHT / 2.0) / CELL_SIZE) as i32;
+    /// Push the cell containing `pos` and its neighbours (for border cases)
+    fn push_neighborhood(&self, pos: Vec2, indices: &mut Vec<usize>) {
+        let gx = ((pos.x + self.size.x / 2.0) / CELL_SIZE) as i32;
+        let gy = ((pos.y + self.size.y / 2.0) / CELL_SIZE) as i32;
+        let (width, height) = (self.width as i32, self.height as i32);
 
         // Check 3x3 neighborhood for robustness
-        let mut indices = Vec::with_capacity(9);
         for dy in -1..=1 {
             for dx in -1..=1 {
                 let nx = gx + dx;
                 let ny = gy + dy;
-                if nx >= 0 && nx < GRID_WIDTH as i32 && ny >= 0 && ny < GRID_HEIGHT as i32 {
-                    indices.push((ny * GRID_WIDTH as i32 + nx) as usize);
+                if nx >= 0 && nx < width && ny >= 0 && ny < height {
+                    indices.push((ny * width + nx) as usize);
                 }
             }
         }
+    }
+
+    /// Get enemies near any point of the segment `start..end`, each once
+    fn get_enemies_along(
+        &self,
+        start: Vec2,
+        end: Vec2,
+    ) -> impl Iterator<Item = (Entity, Vec2)> + '_ {
+        // Sample once per cell so long sweeps don't skip a column of cells
+        let steps = ((end - start).length() / CELL_SIZE).ceil().max(1.0) as usize;
+        let mut indices = Vec::with_capacity(9 * (steps + 1));
+        for i in 0..=steps {
+            self.push_neighborhood(start.lerp(end, i as f32 / steps as f32), &mut indices);
+        }
+        indices.sort_unstable();
+        indices.dedup();
 
         indices
             .into_iter()
-            .flat_map(move |idx| self.enemy_cells[idx].iter())
+            .flat_map(move |idx| self.enemy_cells[idx].iter().copied())
     }
 }
 
@@ -82,46 +108,80 @@ pub struct CollisionPlugin;
 
 impl Plugin for CollisionPlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(SpatialGrid::new()).add_systems(
-            Update,
-            (
-                update_spatial_grid,
-                player_projectile_enemy_collision,
-                enemy_projectile_player_collision,
-            )
-                .chain()
-                .run_if(in_state(GameState::Playing)),
-        );
+        app.insert_resource(SpatialGrid::new(Playfield::BASE))
+            .add_systems(
+                FixedUpdate,
+                (
+                    update_spatial_grid,
+                    player_projectile_enemy_collision,
+                    enemy_projectile_player_collision,
+                )
+                    .chain()
+                    .in_set(GameSet::Collision)
+                    .run_if(in_state(GameState::Playing)),
+            );
     }
 }
 
 /// Update spatial grid with current enemy positions
 fn update_spatial_grid(
+    playfield: Res<Playfield>,
     mut grid: ResMut<SpatialGrid>,
     enemy_query: Query<(Entity, &Transform), With<Enemy>>,
 ) {
+    if grid.size != playfield.size {
+        *grid = SpatialGrid::new(playfield.size);
+    }
     grid.clear();
     for (entity, transform) in enemy_query.iter() {
         grid.insert_enemy(entity, transform.translation.truncate());
     }
 }
 
-/// Player projectiles hitting enemies (optimized with spatial grid)
+/// Player projectiles (and confused enemy fire) hitting enemies (optimized with spatial grid)
 fn player_projectile_enemy_collision(
     mut commands: Commands,
+    time: Res<Time>,
     grid: Res<SpatialGrid>,
-    projectile_query: Query<(Entity, &Transform, &ProjectileDamage), With<PlayerProjectile>>,
-    mut enemy_query: Query<(&mut EnemyStats, Option<&Sprite>), With<Enemy>>,
+    projectile_query: Query<(
+        Entity,
+        &Transform,
+        &ProjectilePhysics,
+        &ProjectileDamage,
+        Option<&super::InflictsStatus>,
+        (Option<&Sprite>, Option<&ProjectileWeapon>, &ProjectileOwner),
+    )>,
+    mut enemy_query: Query<
+        (
+            &mut EnemyStats,
+            Option<&Sprite>,
+            Option<&super::StatusEffects>,
+            Option<&super::AuraBuff>,
+            Option<&mut EnemyTank>,
+        ),
+        With<Enemy>,
+    >,
     player_query: Query<(&Transform, &ShipStats), With<Player>>,
     mut score: ResMut<ScoreSystem>,
     mut berserk: ResMut<BerserkSystem>,
     mut destroy_events: EventWriter<EnemyDestroyedEvent>,
     mut explosion_events: EventWriter<ExplosionEvent>,
     mut dialogue_events: EventWriter<super::DialogueEvent>,
-    mut screen_shake: ResMut<super::effects::ScreenShake>,
-    mut screen_flash: ResMut<super::effects::ScreenFlash>,
-    mut camera_zoom: ResMut<super::effects::CameraZoom>,
+    mut status_events: EventWriter<super::StatusEffectEvent>,
+    // Kill feedback, grouped to stay within Bevy's system parameter limit
+    (mut screen_shake, mut screen_flash, mut camera_zoom): (
+        ResMut<super::effects::ScreenShake>,
+        ResMut<super::effects::ScreenFlash>,
+        ResMut<super::effects::CameraZoom>,
+    ),
     icon_cache: Res<crate::assets::PowerupIconCache>,
+    // Grouped to stay within Bevy's system parameter limit
+    (mut run_rng, mut wave_tally, mut loot, difficulty): (
+        ResMut<RunRng>,
+        ResMut<WaveTally>,
+        ResMut<super::LootState>,
+        Res<Difficulty>,
+    ),
     mut boss_callout_sent: Local<bool>,
 ) {
     // Get player position and health for proximity check and smart powerups
@@ -135,129 +195,174 @@ fn player_projectile_enemy_collision(
         })
         .unwrap_or((Vec2::ZERO, None));
 
-    // Collision radius squared for faster distance checks
-    const COLLISION_RADIUS_SQ: f32 = 25.0 * 25.0;
+    let dt = time.delta_secs();
+
+    for (
+        proj_entity,
+        proj_transform,
+        proj_physics,
+        proj_damage,
+        inflicts,
+        (proj_sprite, weapon, owner),
+    ) in projectile_query.iter()
+    {
+        if !owner.hits(Team::Enemy) {
+            continue;
+        }
 
-    for (proj_entity, proj_transform, proj_damage) in projectile_query.iter() {
+        // Sweep this tick's travel so fast bullets can't step over a hull
         let proj_pos = proj_transform.translation.truncate();
+        let proj_start = proj_pos - proj_physics.velocity * dt;
 
-        // Only check enemies in nearby grid cells (O(1) average instead of O(n))
-        for &(enemy_entity, enemy_pos) in grid.get_nearby_enemies(proj_pos) {
-            let dist_sq = (proj_pos - enemy_pos).length_squared();
-
-            // Use squared distance to avoid sqrt
-            if dist_sq < COLLISION_RADIUS_SQ {
-                // Get mutable enemy stats
-                let Ok((mut enemy_stats, sprite)) = enemy_query.get_mut(enemy_entity) else {
-                    continue;
-                };
-
-                // Roll for critical hit
-                let is_crit = fastrand::f32() < proj_damage.crit_chance;
-                let final_damage = if is_crit {
-                    proj_damage.damage * proj_damage.crit_multiplier
-                } else {
-                    proj_damage.damage
-                };
-
-                // Apply damage
-                enemy_stats.health -= final_damage;
-
-                // Boss low health callout (once per boss)
-                if enemy_stats.is_boss && !*boss_callout_sent {
-                    let health_pct = enemy_stats.health / enemy_stats.max_health;
-                    if health_pct > 0.0 && health_pct < 0.25 {
-                        dialogue_events.send(super::DialogueEvent::combat_callout(
-                            super::CombatCalloutType::BossLowHealth,
-                        ));
-                        *boss_callout_sent = true;
-                    }
-                }
+        // First enemy along the path (only nearby grid cells are checked)
+        let mut hit: Option<(Entity, Vec2, f32)> = None;
+        for (enemy_entity, enemy_pos) in grid.get_enemies_along(proj_start, proj_pos) {
+            if !owner.hits_ship(enemy_entity, Team::Enemy) {
+                continue;
+            }
+            let Some(t) = swept_circle_hit(proj_start, proj_pos, enemy_pos, ENEMY_HIT_RADIUS)
+            else {
+                continue;
+            };
+            if hit.is_none_or(|(_, _, best)| t < best) {
+                hit = Some((enemy_entity, enemy_pos, t));
+            }
+        }
+        let Some((enemy_entity, enemy_pos, t)) = hit else {
+            continue;
+        };
+
+        // Get mutable enemy stats
+        let Ok((mut enemy_stats, sprite, status, aura, tank)) = enemy_query.get_mut(enemy_entity)
+        else {
+            continue;
+        };
+
+        // Roll for critical hit
+        let is_crit = run_rng.combat.f32() < proj_damage.crit_chance;
+        let raw_damage = if is_crit {
+            proj_damage.damage * proj_damage.crit_multiplier
+        } else {
+            proj_damage.damage
+        } * super::status_damage_mult(status)
+            * super::aura_damage_mult(aura);
+        // Layered hulls resist by damage type, layer by layer
+        let final_damage = match tank {
+            Some(mut tank) => tank.absorb(raw_damage, proj_damage.damage_type),
+            None => raw_damage,
+        };
+
+        // Apply damage
+        enemy_stats.health -= final_damage;
+
+        // Lasers/missiles may leave a status effect behind
+        if let Some(event) = inflicts.and_then(|i| i.roll(enemy_entity, &mut run_rng.combat)) {
+            status_events.send(event);
+        }
+
+        // Boss low health callout (once per boss)
+        if enemy_stats.is_boss && !*boss_callout_sent {
+            let health_pct = enemy_stats.health / enemy_stats.max_health;
+            if health_pct > 0.0 && health_pct < 0.25 {
+                dialogue_events.send(super::DialogueEvent::combat_callout(
+                    super::CombatCalloutType::BossLowHealth,
+                ));
+                *boss_callout_sent = true;
+            }
+        }
+
+        // Add hit flash effect (white flash when damaged)
+        let original_color = sprite.map(|s| s.color).unwrap_or(Color::WHITE);
+        super::effects::flash_enemy_hit(&mut commands, enemy_entity, original_color);
+
+        // Spawn floating damage number
+        super::effects::spawn_damage_number(&mut commands, enemy_pos, final_damage, is_crit);
+        super::spawn_impact_flash(
+            &mut commands,
+            proj_start.lerp(proj_pos, t),
+            proj_sprite.map_or(Color::WHITE, |s| s.color),
+            weapon.map(|w| w.0),
+        );
+
+        // Despawn projectile
+        commands.entity(proj_entity).despawn_recursive();
+        if owner.is_player() {
+            wave_tally.shots_hit += 1;
+        }
 
-                // Add hit flash effect (white flash when damaged)
-                let original_color = sprite.map(|s| s.color).unwrap_or(Color::WHITE);
-                commands
-                    .entity(enemy_entity)
-                    .insert(super::effects::HitFlash::new(original_color));
-
-                // Spawn floating damage number
-                super::effects::spawn_damage_number(
-                    &mut commands,
-                    enemy_pos,
-                    final_damage,
-                    is_crit,
+        // Check if enemy destroyed
+        if enemy_stats.health <= 0.0 {
+            // Calculate distance from player to enemy for berserk
+            let player_distance = (player_pos - enemy_pos).length();
+
+            // Update score (with berserk multiplier)
+            let base_score = enemy_stats.score_value;
+            let final_score = (base_score as f32 * berserk.score_mult()) as u64;
+            let points_awarded = score.on_kill(final_score);
+
+            // Fill berserk meter based on proximity (closer = more meter)
+            let meter_gained = berserk.on_kill_at_distance(player_distance);
+            if meter_gained > 0.0 && berserk.can_activate() {
+                info!(
+                    "BERSERK READY! Press B to activate! (meter: {:.0}%)",
+                    berserk.meter
                 );
+            }
 
-                // Despawn projectile
-                commands.entity(proj_entity).despawn_recursive();
-
-                // Check if enemy destroyed
-                if enemy_stats.health <= 0.0 {
-                    // Calculate distance from player to enemy for berserk
-                    let player_distance = (player_pos - enemy_pos).length();
-
-                    // Update score (with berserk multiplier)
-                    let base_score = enemy_stats.score_value;
-                    let final_score = (base_score as f32 * berserk.score_mult()) as u64;
-                    score.on_kill(final_score);
-
-                    // Fill berserk meter based on proximity (closer = more meter)
-                    let meter_gained = berserk.on_kill_at_distance(player_distance);
-                    if meter_gained > 0.0 && berserk.can_activate() {
-                        info!(
-                            "BERSERK READY! Press B to activate! (meter: {:.0}%)",
-                            berserk.meter
-                        );
-                    }
-
-                    // Send events
-                    destroy_events.send(EnemyDestroyedEvent {
-                        position: enemy_pos,
-                        enemy_type: enemy_stats.name.clone(),
-                        score_value: enemy_stats.score_value,
-                        was_boss: enemy_stats.is_boss,
-                    });
-
-                    explosion_events.send(ExplosionEvent {
-                        position: enemy_pos,
-                        size: if enemy_stats.is_boss {
-                            ExplosionSize::Massive
-                        } else {
-                            ExplosionSize::Small
-                        },
-                        color: Color::srgb(1.0, 0.5, 0.2),
-                    });
-
-                    // Screen shake, flash, and zoom on kill
-                    if enemy_stats.is_boss {
-                        screen_shake.massive();
-                        screen_flash.massive(); // Big white flash for boss kills
-                        camera_zoom.boss_kill(); // Dramatic zoom pulse
-                        *boss_callout_sent = false; // Reset for next boss
-                    } else {
-                        screen_shake.trigger(3.0, 0.1); // Small shake for regular enemies
-                    }
-
-                    // Spawn liberation pods
-                    spawn_liberation_pods(&mut commands, enemy_pos, enemy_stats.liberation_value);
-
-                    // 30% chance to drop powerup (100% for bosses)
-                    let drop_chance = if enemy_stats.is_boss { 1.0 } else { 0.30 };
-                    if fastrand::f32() < drop_chance {
-                        spawn_smart_powerup(
-                            &mut commands,
-                            enemy_pos,
-                            Some(&icon_cache),
-                            player_health,
-                        );
-                    }
-
-                    // Despawn enemy
-                    commands.entity(enemy_entity).despawn_recursive();
-                }
+            // Send events
+            destroy_events.send(EnemyDestroyedEvent {
+                entity: enemy_entity,
+                position: enemy_pos,
+                enemy_type: enemy_stats.name.clone(),
+                type_id: enemy_stats.type_id,
+                score_value: enemy_stats.score_value,
+                points_awarded,
+                was_boss: enemy_stats.is_boss,
+            });
 
-                break; // Projectile can only hit one enemy
+            explosion_events.send(ExplosionEvent {
+                position: enemy_pos,
+                size: if enemy_stats.is_boss {
+                    ExplosionSize::Massive
+                } else {
+                    ExplosionSize::Small
+                },
+                color: Color::srgb(1.0, 0.5, 0.2),
+            });
+
+            // Screen shake, flash, and zoom on kill
+            if enemy_stats.is_boss {
+                screen_shake.massive();
+                screen_flash.massive(); // Big white flash for boss kills
+                camera_zoom.boss_kill(); // Dramatic zoom pulse
+                *boss_callout_sent = false; // Reset for next boss
+            } else {
+                screen_shake.trigger(3.0, 0.1); // Small shake for regular enemies
             }
+
+            // Liberation pods, or escape pods from a transport
+            release_souls(
+                &mut commands,
+                &mut run_rng,
+                enemy_pos,
+                enemy_stats.type_id,
+                enemy_stats.liberation_value,
+            );
+
+            // Roll the ship class's loot table
+            let class = super::LootClass::for_enemy(enemy_stats.type_id, enemy_stats.is_boss);
+            loot.drop_loot(
+                &mut commands,
+                class.table(),
+                *difficulty,
+                enemy_pos,
+                player_health,
+                Some(&icon_cache),
+                &mut run_rng.drops,
+            );
+
+            // Despawn enemy
+            commands.entity(enemy_entity).despawn_recursive();
         }
     }
 }
@@ -265,7 +370,14 @@ fn player_projectile_enemy_collision(
 /// Enemy projectiles hitting player
 fn enemy_projectile_player_collision(
     mut commands: Commands,
-    projectile_query: Query<(Entity, &Transform, &ProjectileDamage), With<EnemyProjectile>>,
+    projectile_query: Query<(
+        Entity,
+        &Transform,
+        &ProjectilePhysics,
+        &ProjectileDamage,
+        Option<&super::InflictsStatus>,
+        (Option<&Sprite>, Option<&ProjectileWeapon>, &ProjectileOwner),
+    )>,
     mut player_query: Query<
         (
             Entity,
@@ -275,63 +387,107 @@ fn enemy_projectile_player_collision(
             &PowerupEffects,
             &super::ManeuverState,
             Option<&Sprite>,
+            Option<&super::StatusEffects>,
         ),
         With<Player>,
     >,
+    shooters: Query<(Option<&EnemyStats>, Option<&BossData>)>,
     mut score: ResMut<ScoreSystem>,
     mut damage_events: EventWriter<PlayerDamagedEvent>,
-    mut dialogue_events: EventWriter<super::DialogueEvent>,
     mut rumble_events: EventWriter<super::RumbleRequest>,
+    mut status_events: EventWriter<super::StatusEffectEvent>,
     mut screen_shake: ResMut<super::effects::ScreenShake>,
-    mut next_state: ResMut<NextState<GameState>>,
-    mut last_callout: Local<f32>,
+    mut destroyed_events: EventWriter<PlayerDestroyedEvent>,
+    mut run_rng: ResMut<RunRng>,
     time: Res<Time>,
 ) {
-    // Cooldown for health callouts (don't spam)
-    *last_callout += time.delta_secs();
-
-    let Ok((player_entity, player_transform, mut player_stats, hitbox, powerups, maneuver, sprite)) =
-        player_query.get_single_mut()
+    let dt = time.delta_secs();
+
+    let Ok((
+        player_entity,
+        player_transform,
+        mut player_stats,
+        hitbox,
+        powerups,
+        maneuver,
+        sprite,
+        status,
+    )) = player_query.get_single_mut()
     else {
         return;
     };
 
     let player_pos = player_transform.translation.truncate();
-    let hit_radius_sq = (hitbox.radius + 4.0) * (hitbox.radius + 4.0);
+    let hit_radius = hitbox.radius + 4.0;
+
+    for (
+        proj_entity,
+        proj_transform,
+        proj_physics,
+        proj_damage,
+        inflicts,
+        (proj_sprite, weapon, owner),
+    ) in projectile_query.iter()
+    {
+        // Wingman and reflected shots pass through
+        if !owner.hits_ship(player_entity, Team::Player) {
+            continue;
+        }
 
-    for (proj_entity, proj_transform, proj_damage) in projectile_query.iter() {
+        // Sweep this tick's travel so beams can't jump the hitbox
         let proj_pos = proj_transform.translation.truncate();
-        let dist_sq = (proj_pos - player_pos).length_squared();
+        let proj_start = proj_pos - proj_physics.velocity * dt;
 
-        if dist_sq < hit_radius_sq {
+        if let Some(t) = swept_circle_hit(proj_start, proj_pos, player_pos, hit_radius) {
             // Despawn projectile regardless
             commands.entity(proj_entity).despawn_recursive();
+            super::spawn_impact_flash(
+                &mut commands,
+                proj_start.lerp(proj_pos, t),
+                proj_sprite.map_or(Color::WHITE, |s| s.color),
+                weapon.map(|w| w.0),
+            );
 
             // Check invulnerability (powerups OR barrel roll i-frames)
             if powerups.is_invulnerable() || maneuver.invincible {
                 continue;
             }
 
-            // Apply damage
-            let destroyed = player_stats.take_damage(proj_damage.damage, proj_damage.damage_type);
+            // Apply damage (armor breach amplifies it)
+            let damage = proj_damage.damage * super::status_damage_mult(status);
+            let layers = player_stats.absorb_damage(damage, proj_damage.damage_type);
+            let destroyed = player_stats.hull <= 0.0;
+
+            if let Some(event) = inflicts.and_then(|i| i.roll(player_entity, &mut run_rng.combat)) {
+                status_events.send(event);
+            }
 
-            // Add hit flash effect to player (red-white flash when hit)
+            // Flash the ship in the color of the deepest layer hit
             let original_color = sprite.map(|s| s.color).unwrap_or(Color::WHITE);
-            commands
-                .entity(player_entity)
-                .insert(super::effects::HitFlash::with_duration(
-                    original_color,
-                    0.15,
-                ));
+            commands.entity(player_entity).insert(
+                super::effects::HitFlash::with_duration(original_color, 0.15)
+                    .with_color(super::effects::layer_flash_color(&layers)),
+            );
 
             // Lost no-damage bonus
             score.no_damage_bonus = false;
 
-            // Send events
+            // Send events, naming the shooter for the battle log
+            let source = owner
+                .shooter
+                .and_then(|shooter| shooters.get(shooter).ok())
+                .and_then(|(enemy, boss)| {
+                    boss.map(|b| b.name.clone())
+                        .or_else(|| enemy.map(|e| e.name.clone()))
+                })
+                .or_else(|| weapon.map(|w| format!("{:?}", w.0)))
+                .unwrap_or_else(|| "Unknown".to_string());
             damage_events.send(PlayerDamagedEvent {
-                damage: proj_damage.damage,
+                damage,
                 damage_type: proj_damage.damage_type,
                 source_position: proj_pos,
+                source,
+                layers,
             });
 
             // Controller rumble on hit
@@ -340,29 +496,104 @@ fn enemy_projectile_player_collision(
             // Screen shake on hit
             screen_shake.small();
 
-            // Health callouts (with 8 second cooldown)
-            if *last_callout > 8.0 {
-                let total_hp = player_stats.shield + player_stats.armor + player_stats.hull;
-                let max_hp =
-                    player_stats.max_shield + player_stats.max_armor + player_stats.max_hull;
-                let health_pct = total_hp / max_hp;
-                if health_pct < 0.2 {
-                    dialogue_events.send(super::DialogueEvent::combat_callout(
-                        super::CombatCalloutType::NearDeath,
-                    ));
-                    *last_callout = 0.0;
-                } else if health_pct < 0.4 {
-                    dialogue_events.send(super::DialogueEvent::combat_callout(
-                        super::CombatCalloutType::LowHealth,
-                    ));
-                    *last_callout = 0.0;
-                }
-            }
-
             if destroyed {
                 info!("Player destroyed!");
-                next_state.set(GameState::GameOver);
+                destroyed_events.send(PlayerDestroyedEvent {
+                    position: player_pos,
+                });
+            }
+        }
+    }
+}
+
+/// Swept (continuous) hit test: does a point moving from `start` to `end`
+/// pass within `radius` of `center`? Returns how far along the segment
+/// (0.0 - 1.0) the closest approach happens, so the earliest target can win.
+pub fn swept_circle_hit(start: Vec2, end: Vec2, center: Vec2, radius: f32) -> Option<f32> {
+    let travel = end - start;
+    let len_sq = travel.length_squared();
+    let t = if len_sq > f32::EPSILON {
+        ((center - start).dot(travel) / len_sq).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let closest = start + travel * t;
+    (closest.distance_squared(center) < radius * radius).then_some(t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Advance a bullet one step and sweep it against a target
+    fn step_hits(pos: Vec2, velocity: Vec2, dt: f32, target: Vec2, radius: f32) -> bool {
+        swept_circle_hit(pos, pos + velocity * dt, target, radius).is_some()
+    }
+
+    #[test]
+    fn fast_bullet_never_tunnels_through_hitbox() {
+        // Doomsday beam at 400 px/s aimed through a tiny hitbox
+        let target = Vec2::new(0.0, -200.0);
+        let velocity = Vec2::new(0.0, -400.0);
+        let start = Vec2::ZERO;
+
+        for dt in [1.0 / 240.0, 1.0 / 60.0, 1.0 / 10.0, 0.5, 1.0, 5.0] {
+            let mut pos = start;
+            let mut hit = false;
+            while pos.y > -800.0 && !hit {
+                hit = step_hits(pos, velocity, dt, target, 8.0);
+                pos += velocity * dt;
             }
+            assert!(hit, "tunneled at dt = {}", dt);
         }
     }
+
+    #[test]
+    fn swept_hit_misses_wide_shots_and_reports_earliest_contact() {
+        let start = Vec2::new(-100.0, 0.0);
+        let end = Vec2::new(100.0, 0.0);
+
+        // Passing 20px beside a 10px target is a miss no matter the step
+        assert!(swept_circle_hit(start, end, Vec2::new(0.0, 20.0), 10.0).is_none());
+        // Target behind the bullet isn't hit
+        assert!(swept_circle_hit(start, end, Vec2::new(-130.0, 0.0), 10.0).is_none());
+
+        let near = swept_circle_hit(start, end, Vec2::new(-50.0, 0.0), 10.0).unwrap();
+        let far = swept_circle_hit(start, end, Vec2::new(50.0, 0.0), 10.0).unwrap();
+        assert!(near < far);
+    }
+
+    #[test]
+    fn zero_length_sweep_is_a_point_test() {
+        let pos = Vec2::new(5.0, 5.0);
+        assert_eq!(
+            swept_circle_hit(pos, pos, Vec2::new(8.0, 5.0), 4.0),
+            Some(0.0)
+        );
+        assert!(swept_circle_hit(pos, pos, Vec2::new(20.0, 5.0), 4.0).is_none());
+    }
+
+    #[test]
+    fn long_sweeps_find_enemies_in_every_cell_crossed() {
+        let mut grid = SpatialGrid::new(Playfield::BASE);
+        let far = Entity::from_raw(1);
+        grid.insert_enemy(far, Vec2::new(0.0, 300.0));
+
+        // A point check at the bullet's end position can't see that far back
+        let end = Vec2::new(0.0, -300.0);
+        assert!(grid.get_enemies_along(end, end).all(|(e, _)| e != far));
+        assert!(grid
+            .get_enemies_along(Vec2::new(0.0, 340.0), end)
+            .any(|(e, _)| e == far));
+    }
+
+    #[test]
+    fn wide_playfields_grow_the_grid() {
+        let mut grid = SpatialGrid::new(Vec2::new(1600.0, 700.0));
+        let edge = Entity::from_raw(2);
+        // Past where the base-size grid ends
+        let pos = Vec2::new(750.0, 0.0);
+        grid.insert_enemy(edge, pos);
+        assert!(grid.get_enemies_along(pos, pos).any(|(e, _)| e == edge));
+    }
 }
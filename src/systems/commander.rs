@@ -0,0 +1,389 @@
+//! Commander Auras
+//!
+//! Every so often a wave ship is promoted to commander: a little tougher,
+//! and projecting an aura over the allies around it - faster engines,
+//! linked shields or quicker weapon cycling - shown as a faint ring in the
+//! aura's colour. Auras of the same kind don't stack.
+//!
+//! Killing a commander strips its aura from everyone it covered in a burst
+//! and pays a bonus that grows with the allies it was buffing, so it's
+//! worth cutting through the escorts to reach it first.
+
+#![allow(dead_code)]
+
+use super::effects::ScreenShake;
+use crate::core::*;
+use crate::entities::{Enemy, EnemyAI, EnemyBehavior, EnemyStats};
+use bevy::prelude::*;
+
+/// Every this many wave ships, one is promoted
+pub const COMMANDER_EVERY: u32 = 12;
+
+/// Aura reach around a commander
+pub const AURA_RADIUS: f32 = 170.0;
+
+/// Commander health relative to its class
+const COMMANDER_HEALTH_MULT: f32 = 1.6;
+
+/// Commander hull scale (reads as the bigger ship in the group)
+const COMMANDER_SCALE: f32 = 1.15;
+
+/// Flat bonus for a commander kill
+const COMMANDER_BONUS: u64 = 300;
+
+/// Extra bonus per ally under the aura when the commander falls
+const COMMANDER_BONUS_PER_ALLY: u64 = 100;
+
+/// Speed aura: ally movement multiplier
+const SPEED_AURA_MULT: f32 = 1.35;
+
+/// Fire-rate aura: ally weapon cycling multiplier
+const FIRE_RATE_AURA_MULT: f32 = 1.5;
+
+/// Shield link: share of incoming damage linked allies still take
+const SHIELD_LINK_DAMAGE_MULT: f32 = 0.6;
+
+/// Commander plugin
+pub struct CommanderPlugin;
+
+impl Plugin for CommanderPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CommandRoster>()
+            .add_systems(OnEnter(GameState::ShipSelect), reset_commanders)
+            .add_systems(
+                Update,
+                (promote_commanders, project_auras, draw_auras)
+                    .chain()
+                    .in_set(GameSet::Simulation)
+                    .run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight))),
+            )
+            .add_systems(
+                Update,
+                break_auras
+                    .in_set(GameSet::Scoring)
+                    .run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight))),
+            );
+    }
+}
+
+/// What a commander's aura does for its allies
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuraKind {
+    Speed,
+    ShieldLink,
+    FireRate,
+}
+
+impl AuraKind {
+    /// Promotion order
+    pub const ALL: [AuraKind; 3] = [AuraKind::Speed, AuraKind::ShieldLink, AuraKind::FireRate];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            AuraKind::Speed => "Speed",
+            AuraKind::ShieldLink => "Shield Link",
+            AuraKind::FireRate => "Fire Rate",
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            AuraKind::Speed => Color::srgb(0.4, 1.0, 0.55),
+            AuraKind::ShieldLink => Color::srgb(0.4, 0.7, 1.0),
+            AuraKind::FireRate => Color::srgb(1.0, 0.45, 0.3),
+        }
+    }
+}
+
+/// An enemy projecting an aura over nearby allies
+#[derive(Component, Debug, Clone, Copy)]
+#[require(StateScope)]
+pub struct Commander {
+    pub aura: AuraKind,
+}
+
+/// Buffs an enemy is getting from the commanders around it
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct AuraBuff {
+    pub speed_mult: f32,
+    pub fire_rate_mult: f32,
+    pub damage_taken_mult: f32,
+    /// Commanders covering this ship
+    pub commanders: Vec<Entity>,
+}
+
+impl AuraBuff {
+    /// Combined buff from the auras covering a ship (one of each kind counts)
+    pub fn from_auras(auras: &[(Entity, AuraKind)]) -> Self {
+        let has = |kind: AuraKind| auras.iter().any(|(_, k)| *k == kind);
+        Self {
+            speed_mult: if has(AuraKind::Speed) {
+                SPEED_AURA_MULT
+            } else {
+                1.0
+            },
+            fire_rate_mult: if has(AuraKind::FireRate) {
+                FIRE_RATE_AURA_MULT
+            } else {
+                1.0
+            },
+            damage_taken_mult: if has(AuraKind::ShieldLink) {
+                SHIELD_LINK_DAMAGE_MULT
+            } else {
+                1.0
+            },
+            commanders: auras.iter().map(|(e, _)| *e).collect(),
+        }
+    }
+}
+
+pub fn aura_speed_mult(buff: Option<&AuraBuff>) -> f32 {
+    buff.map(|b| b.speed_mult).unwrap_or(1.0)
+}
+
+pub fn aura_fire_rate_mult(buff: Option<&AuraBuff>) -> f32 {
+    buff.map(|b| b.fire_rate_mult).unwrap_or(1.0)
+}
+
+pub fn aura_damage_mult(buff: Option<&AuraBuff>) -> f32 {
+    buff.map(|b| b.damage_taken_mult).unwrap_or(1.0)
+}
+
+/// Bonus for downing a commander that was covering `allies` ships
+pub fn commander_bonus(allies: u32) -> u64 {
+    COMMANDER_BONUS + COMMANDER_BONUS_PER_ALLY * allies as u64
+}
+
+/// Promotion count and the commanders in play
+#[derive(Resource, Debug, Default)]
+pub struct CommandRoster {
+    /// Wave ships seen this run
+    pub spawned: u32,
+    /// Commanders promoted this run
+    pub promoted: u32,
+    /// Living commanders: (entity, aura, allies covered)
+    pub commanders: Vec<(Entity, AuraKind, u32)>,
+}
+
+impl CommandRoster {
+    /// Count a new wave ship; the aura it's promoted with, if its turn
+    pub fn enlist(&mut self) -> Option<AuraKind> {
+        self.spawned += 1;
+        if self.spawned % COMMANDER_EVERY != 0 {
+            return None;
+        }
+        let aura = AuraKind::ALL[self.promoted as usize % AuraKind::ALL.len()];
+        self.promoted += 1;
+        Some(aura)
+    }
+}
+
+/// A new run starts the count over
+fn reset_commanders(mut roster: ResMut<CommandRoster>) {
+    *roster = CommandRoster::default();
+}
+
+/// Promote every Nth wave ship; bosses and suicide rushers never lead
+fn promote_commanders(
+    mut commands: Commands,
+    mut roster: ResMut<CommandRoster>,
+    mut enemies: Query<
+        (
+            Entity,
+            &mut EnemyStats,
+            &EnemyAI,
+            &mut Transform,
+            Option<&Commander>,
+        ),
+        Added<Enemy>,
+    >,
+) {
+    for (entity, mut stats, ai, mut transform, commander) in enemies.iter_mut() {
+        if stats.is_boss || ai.behavior == EnemyBehavior::Kamikaze {
+            continue;
+        }
+        // Placed by hand (or already promoted): just keep track of it
+        let aura = match commander {
+            Some(commander) => commander.aura,
+            None => {
+                let Some(aura) = roster.enlist() else {
+                    continue;
+                };
+                commands.entity(entity).insert(Commander { aura });
+                aura
+            }
+        };
+        stats.max_health *= COMMANDER_HEALTH_MULT;
+        stats.health = stats.max_health;
+        transform.scale *= COMMANDER_SCALE;
+        roster.commanders.push((entity, aura, 0));
+        info!("{} commander: {} aura", stats.name, aura.name());
+    }
+}
+
+/// Buff every ally inside a commander's ring
+fn project_auras(
+    mut commands: Commands,
+    mut roster: ResMut<CommandRoster>,
+    commanders: Query<(Entity, &Transform, &Commander)>,
+    mut allies: Query<
+        (Entity, &Transform, Option<&mut AuraBuff>),
+        (With<Enemy>, Without<Commander>),
+    >,
+) {
+    // Commanders that left without being shot down
+    roster
+        .commanders
+        .retain(|(entity, ..)| commanders.contains(*entity));
+    for entry in roster.commanders.iter_mut() {
+        entry.2 = 0;
+    }
+
+    let sources: Vec<(Entity, Vec2, AuraKind)> = commanders
+        .iter()
+        .map(|(e, t, c)| (e, t.translation.truncate(), c.aura))
+        .collect();
+
+    for (entity, transform, buff) in allies.iter_mut() {
+        let pos = transform.translation.truncate();
+        let covering: Vec<(Entity, AuraKind)> = sources
+            .iter()
+            .filter(|(_, source, _)| source.distance(pos) <= AURA_RADIUS)
+            .map(|(e, _, kind)| (*e, *kind))
+            .collect();
+
+        for (commander, _) in &covering {
+            if let Some(entry) = roster.commanders.iter_mut().find(|c| c.0 == *commander) {
+                entry.2 += 1;
+            }
+        }
+
+        match (buff, covering.is_empty()) {
+            (Some(_), true) => {
+                commands.entity(entity).remove::<AuraBuff>();
+            }
+            (Some(mut buff), false) => {
+                let updated = AuraBuff::from_auras(&covering);
+                if *buff != updated {
+                    *buff = updated;
+                }
+            }
+            (None, false) => {
+                commands
+                    .entity(entity)
+                    .insert(AuraBuff::from_auras(&covering));
+            }
+            (None, true) => {}
+        }
+    }
+}
+
+/// Faint aura rings, and a tether to each ally under one
+fn draw_auras(
+    mut gizmos: Gizmos,
+    time: Res<Time>,
+    commanders: Query<(Entity, &Transform, &Commander)>,
+    allies: Query<(&Transform, &AuraBuff)>,
+) {
+    let pulse = 0.5 + 0.5 * (time.elapsed_secs() * 3.0).sin();
+    for (entity, transform, commander) in commanders.iter() {
+        let pos = transform.translation.truncate();
+        let color = commander.aura.color();
+        gizmos.circle_2d(pos, AURA_RADIUS, color.with_alpha(0.12 + 0.08 * pulse));
+
+        for (ally, buff) in allies.iter() {
+            if buff.commanders.contains(&entity) {
+                gizmos.line_2d(pos, ally.translation.truncate(), color.with_alpha(0.1));
+            }
+        }
+    }
+}
+
+/// A downed commander takes its aura with it, and pays out
+fn break_auras(
+    mut commands: Commands,
+    mut destroy_events: EventReader<EnemyDestroyedEvent>,
+    mut roster: ResMut<CommandRoster>,
+    allies: Query<(Entity, &Transform, &AuraBuff)>,
+    mut score: ResMut<ScoreSystem>,
+    mut explosion_events: EventWriter<ExplosionEvent>,
+    mut screen_shake: ResMut<ScreenShake>,
+) {
+    for event in destroy_events.read() {
+        let Some(index) = roster
+            .commanders
+            .iter()
+            .position(|(e, ..)| *e == event.entity)
+        else {
+            continue;
+        };
+        let (_, aura, covered) = roster.commanders.swap_remove(index);
+        let color = aura.color();
+
+        explosion_events.send(ExplosionEvent {
+            position: event.position,
+            size: ExplosionSize::Large,
+            color,
+        });
+        screen_shake.medium();
+
+        // Every freed ally flickers as its buff drops
+        for (entity, transform, buff) in allies.iter() {
+            if !buff.commanders.contains(&event.entity) {
+                continue;
+            }
+            commands.entity(entity).remove::<AuraBuff>();
+            explosion_events.send(ExplosionEvent {
+                position: transform.translation.truncate(),
+                size: ExplosionSize::Tiny,
+                color,
+            });
+        }
+
+        let bonus = score.add_score(commander_bonus(covered));
+        info!(
+            "Commander down: {} aura broken over {} allies, +{}",
+            aura.name(),
+            covered,
+            bonus
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn auras_of_a_kind_do_not_stack() {
+        let a = Entity::from_raw(1);
+        let b = Entity::from_raw(2);
+        let buff = AuraBuff::from_auras(&[(a, AuraKind::Speed), (b, AuraKind::Speed)]);
+        assert_eq!(buff.speed_mult, SPEED_AURA_MULT);
+        assert_eq!(buff.fire_rate_mult, 1.0);
+        assert_eq!(buff.commanders, vec![a, b]);
+
+        let buff = AuraBuff::from_auras(&[(a, AuraKind::ShieldLink), (b, AuraKind::FireRate)]);
+        assert_eq!(aura_damage_mult(Some(&buff)), SHIELD_LINK_DAMAGE_MULT);
+        assert_eq!(aura_fire_rate_mult(Some(&buff)), FIRE_RATE_AURA_MULT);
+        assert_eq!(aura_speed_mult(None), 1.0);
+    }
+
+    #[test]
+    fn every_nth_ship_is_promoted_cycling_auras() {
+        let mut roster = CommandRoster::default();
+        let promoted: Vec<AuraKind> = (0..COMMANDER_EVERY * 4)
+            .filter_map(|_| roster.enlist())
+            .collect();
+        assert_eq!(
+            promoted,
+            vec![
+                AuraKind::Speed,
+                AuraKind::ShieldLink,
+                AuraKind::FireRate,
+                AuraKind::Speed
+            ]
+        );
+        assert!(commander_bonus(3) > commander_bonus(0));
+    }
+}
@@ -0,0 +1,148 @@
+//! Daily Challenge Runs
+//!
+//! Seeds the run's spawn and drop generators (from an entered run code
+//! when there is one) and notes the run's shareable code, spends the day's
+//! attempt when the mission starts, applies the day's modifiers to every
+//! ship as it spawns, and records a run that ends in death. A completed run
+//! is posted by the objective system like any other attack run.
+
+#![allow(dead_code)]
+
+use crate::core::*;
+use crate::entities::{Enemy, EnemyStats, EnemyWeapon, Player, ShipStats};
+use bevy::prelude::*;
+
+/// Daily Challenge plugin
+pub struct DailyChallengePlugin;
+
+impl Plugin for DailyChallengePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            begin_run
+                .in_set(GameSet::Input)
+                .run_if(in_state(GameState::Playing)),
+        )
+        .add_systems(
+            Update,
+            (apply_player_modifiers, apply_enemy_modifiers)
+                .in_set(GameSet::Simulation)
+                .run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight)))
+                .run_if(resource_equals(RunMode::Daily)),
+        )
+        .add_systems(
+            OnEnter(GameState::GameOver),
+            record_failed_daily.run_if(resource_equals(RunMode::Daily)),
+        );
+    }
+}
+
+/// Reseed the run generators, note the run's code and, for the daily,
+/// spend today's attempt. An entered run code supplies the seed as long as
+/// the run about to start is the one it describes.
+pub fn begin_run(
+    mut events: EventReader<MissionStartEvent>,
+    (run_mode, difficulty, session, campaign): (
+        Res<RunMode>,
+        Res<Difficulty>,
+        Res<GameSession>,
+        Res<CampaignState>,
+    ),
+    daily: Res<DailyChallenge>,
+    mut run_rng: ResMut<RunRng>,
+    mut run_codes: ResMut<RunCodes>,
+    mut save_data: ResMut<SaveData>,
+) {
+    let Some(event) = events.read().last() else {
+        return;
+    };
+
+    let code_for = |seed: u32| {
+        RunCode::for_run(
+            *run_mode,
+            &session,
+            *difficulty,
+            campaign.mission_number() as u32,
+            save_data.prestige.run_modifiers(*run_mode),
+            seed,
+        )
+    };
+
+    if *run_mode != RunMode::Daily {
+        let imported = run_codes
+            .imported
+            .as_ref()
+            .filter(|code| code_for(code.seed) == **code);
+        *run_rng = match imported {
+            Some(code) => RunRng::seeded(code.seed as u64),
+            None => RunRng::default(),
+        };
+        let code = code_for(run_rng.seed as u32);
+        info!("Run seed {:#018x}, run code {}", run_rng.seed, code);
+        run_codes.current = Some(code);
+        return;
+    }
+
+    *run_rng = RunRng::seeded(daily.seed);
+    let code = code_for(daily.day as u32);
+    info!("Run code {}", code);
+    run_codes.current = Some(code);
+    // Resuming from pause restarts the mission; the attempt is only spent once
+    if save_data.begin_daily(daily.day, event.mission.id) {
+        info!(
+            "Daily Challenge {} started: {} ({})",
+            daily.day,
+            event.mission.id,
+            daily.modifier_names()
+        );
+    }
+}
+
+fn apply_player_modifiers(
+    daily: Res<DailyChallenge>,
+    mut players: Query<&mut ShipStats, Added<Player>>,
+) {
+    for mut stats in players.iter_mut() {
+        if daily.has(DailyModifier::NoShields) {
+            stats.max_shield = 0.0;
+            stats.shield = 0.0;
+            stats.shield_recharge = 0.0;
+        }
+        if daily.has(DailyModifier::FragileHull) {
+            stats.max_armor *= 0.5;
+            stats.armor = stats.max_armor;
+            stats.max_hull *= 0.5;
+            stats.hull = stats.max_hull;
+        }
+    }
+}
+
+fn apply_enemy_modifiers(
+    daily: Res<DailyChallenge>,
+    mut enemies: Query<(&mut EnemyStats, Option<&mut EnemyWeapon>), Added<Enemy>>,
+) {
+    for (mut stats, weapon) in enemies.iter_mut() {
+        if daily.has(DailyModifier::DoubleEnemySpeed) {
+            stats.speed *= 2.0;
+        }
+        if daily.has(DailyModifier::ArmoredEnemies) {
+            stats.max_health *= 2.0;
+            stats.health = stats.max_health;
+        }
+        if let Some(mut weapon) = weapon {
+            if daily.has(DailyModifier::RapidFireEnemies) {
+                weapon.fire_rate *= 1.5;
+            }
+        }
+    }
+}
+
+/// A daily that ends in death still keeps its score
+fn record_failed_daily(
+    daily: Res<DailyChallenge>,
+    score: Res<ScoreSystem>,
+    mut save_data: ResMut<SaveData>,
+) {
+    save_data.finish_daily(daily.day, score.score, false);
+    info!("Daily Challenge {} over: {}", daily.day, score.score);
+}
@@ -0,0 +1,213 @@
+//! Player Death Sequence
+//!
+//! Losing the ship no longer cuts straight to the death screen. The hull
+//! breaks apart in a blast of the faction's colors, the capsule fails and
+//! the frozen corpse (the same one the death screen shows) drifts out of the
+//! wreck, and after a short hold the run ends.
+
+#![allow(dead_code)]
+
+use super::effects::ScreenShake;
+use super::RumbleRequest;
+use crate::core::*;
+use crate::entities::Player;
+use bevy::prelude::*;
+use std::f32::consts::TAU;
+
+/// Seconds the wreck is shown before the death screen
+pub const DEATH_HOLD: f32 = 2.0;
+
+/// Hull pieces the ship breaks into
+const FRAGMENT_COUNT: usize = 8;
+
+/// Velocity lost per second by drifting wreckage
+const WRECK_DRAG: f32 = 0.8;
+
+/// Frozen body color, shared with the death screen corpse
+pub const CORPSE_COLOR: Color = Color::srgb(0.27, 0.25, 0.24);
+
+/// Corpse sprite size, shared with the death screen
+pub const CORPSE_SIZE: Vec2 = Vec2::new(40.0, 20.0);
+
+/// Death sequence plugin
+pub struct DeathSequencePlugin;
+
+impl Plugin for DeathSequencePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (start_death_sequence, drift_wreckage, finish_death_sequence)
+                .chain()
+                .in_set(GameSet::Scoring)
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
+
+/// The running death sequence; survives a pause, gone with the battlefield
+#[derive(Component, Debug, Default)]
+#[require(StateScope)]
+pub struct DeathSequence {
+    /// Seconds since the ship was destroyed
+    pub elapsed: f32,
+}
+
+/// A drifting piece of the player's wreck
+#[derive(Component, Debug)]
+#[require(StateScope)]
+pub struct Wreckage {
+    pub velocity: Vec2,
+    pub spin: f32,
+    /// Hull fragments fade out over the hold; the corpse stays
+    pub fades: bool,
+}
+
+/// The corpse ejected from the failed capsule
+#[derive(Component, Debug)]
+pub struct EjectedCorpse;
+
+/// Outward velocity of fragment `i` of `count`, evenly spread around the
+/// wreck with some jitter in speed
+pub fn fragment_velocity(i: usize, count: usize, jitter: f32) -> Vec2 {
+    let angle = i as f32 / count as f32 * TAU;
+    Vec2::from_angle(angle) * (90.0 + jitter * 110.0)
+}
+
+/// Velocity after drifting `dt` seconds through the wreck's drag
+pub fn wreck_drift(velocity: Vec2, dt: f32) -> Vec2 {
+    velocity * (1.0 - WRECK_DRAG * dt).max(0.0)
+}
+
+/// Break up the ship and eject the corpse
+fn start_death_sequence(
+    mut commands: Commands,
+    mut destroyed_events: EventReader<PlayerDestroyedEvent>,
+    sequence_query: Query<(), With<DeathSequence>>,
+    player_query: Query<Entity, With<Player>>,
+    session: Res<GameSession>,
+    mut explosion_events: EventWriter<ExplosionEvent>,
+    mut sound_events: EventWriter<PlaySoundEvent>,
+    mut rumble_events: EventWriter<RumbleRequest>,
+    mut screen_shake: ResMut<ScreenShake>,
+) {
+    // Several killing blows can land on the same frame
+    let Some(event) = destroyed_events.read().last() else {
+        return;
+    };
+    if !sequence_query.is_empty() {
+        return;
+    }
+    let position = event.position;
+
+    for entity in player_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    let faction = session.player_faction;
+    explosion_events.send(ExplosionEvent {
+        position,
+        size: ExplosionSize::Large,
+        color: faction.primary_color(),
+    });
+    explosion_events.send(ExplosionEvent {
+        position,
+        size: ExplosionSize::Medium,
+        color: faction.secondary_color(),
+    });
+    sound_events.send(PlaySoundEvent {
+        sound: SoundType::LargeExplosion,
+        volume: 1.0,
+    });
+    rumble_events.send(RumbleRequest::big_explosion());
+    screen_shake.large();
+
+    for i in 0..FRAGMENT_COUNT {
+        let color = if i % 3 == 0 {
+            faction.secondary_color()
+        } else {
+            faction.primary_color()
+        };
+        let size = 6.0 + fastrand::f32() * 10.0;
+        commands.spawn((
+            Wreckage {
+                velocity: fragment_velocity(i, FRAGMENT_COUNT, fastrand::f32()),
+                spin: (fastrand::f32() - 0.5) * 8.0,
+                fades: true,
+            },
+            Sprite {
+                color,
+                custom_size: Some(Vec2::new(size * 1.6, size)),
+                ..default()
+            },
+            Transform::from_xyz(position.x, position.y, 9.0)
+                .with_rotation(Quat::from_rotation_z(fastrand::f32() * TAU)),
+        ));
+    }
+
+    // The capsule fails; what's left of the pilot drifts clear of the wreck
+    commands.spawn((
+        EjectedCorpse,
+        Wreckage {
+            velocity: Vec2::new((fastrand::f32() - 0.5) * 40.0, 70.0),
+            spin: (fastrand::f32() - 0.5) * 0.6,
+            fades: false,
+        },
+        Sprite {
+            color: CORPSE_COLOR,
+            custom_size: Some(CORPSE_SIZE),
+            ..default()
+        },
+        Transform::from_xyz(position.x, position.y, 10.0),
+    ));
+
+    commands.spawn(DeathSequence::default());
+    info!("Ship lost - capsule ejected");
+}
+
+/// Drift, spin and fade the wreckage
+fn drift_wreckage(time: Res<Time>, mut query: Query<(&mut Transform, &mut Wreckage, &mut Sprite)>) {
+    let dt = time.delta_secs();
+    for (mut transform, mut wreck, mut sprite) in query.iter_mut() {
+        transform.translation += (wreck.velocity * dt).extend(0.0);
+        transform.rotate_z(wreck.spin * dt);
+        wreck.velocity = wreck_drift(wreck.velocity, dt);
+        if wreck.fades {
+            let alpha = sprite.color.alpha();
+            sprite.color.set_alpha((alpha - dt / DEATH_HOLD).max(0.0));
+        }
+    }
+}
+
+/// Hold on the wreck, then end the run
+fn finish_death_sequence(
+    time: Res<Time>,
+    mut query: Query<&mut DeathSequence>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let Ok(mut sequence) = query.get_single_mut() else {
+        return;
+    };
+    sequence.elapsed += time.delta_secs();
+    if sequence.elapsed >= DEATH_HOLD {
+        next_state.set(GameState::GameOver);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fragments_scatter_in_every_direction_and_slow_down() {
+        let velocities: Vec<_> = (0..FRAGMENT_COUNT)
+            .map(|i| fragment_velocity(i, FRAGMENT_COUNT, 0.5))
+            .collect();
+        let sum: Vec2 = velocities.iter().sum();
+        assert!(sum.length() < 1e-3);
+        assert!(velocities.iter().all(|v| v.length() > 90.0));
+
+        let slowed = wreck_drift(Vec2::new(100.0, 0.0), 0.5);
+        assert!((slowed.x - 60.0).abs() < 1e-3);
+        assert_eq!(wreck_drift(Vec2::X, 2.0), Vec2::ZERO);
+    }
+}
@@ -3,12 +3,26 @@
 //! Handles faction-specific dialogue display during gameplay.
 //! - Elder Fleet: "Tribal Elder" speaker (Minmatar vs Amarr)
 //! - Caldari/Gallente: "Fleet Command" speaker
+//!
+//! Lines can be skipped (Enter / Back), shown lines are kept in a short
+//! history for the pause menu's comms log, and how long lines linger is
+//! a saved preference. A line can carry a voice-over clip (see
+//! `voice_over`), in which case it stays up for as long as the clip runs.
 
 #![allow(dead_code)]
 
+use super::{JoystickState, VoicePack};
 use crate::core::*;
 use crate::games::ActiveModule;
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Lines kept for the comms log
+pub const DIALOGUE_HISTORY_LEN: usize = 40;
+
+/// Seconds a voiced line stays up after its clip ends
+pub const VOICE_LINE_TAIL: f32 = 0.4;
 
 /// Dialogue plugin
 pub struct DialoguePlugin;
@@ -16,10 +30,16 @@ pub struct DialoguePlugin;
 impl Plugin for DialoguePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<DialogueSystem>()
+            .init_resource::<DialogueSettings>()
             .add_event::<DialogueEvent>()
             .add_systems(
                 Update,
-                (handle_dialogue_events, update_dialogue_timer)
+                (
+                    handle_dialogue_events,
+                    skip_dialogue_input,
+                    update_dialogue_timer,
+                )
+                    .chain()
                     .run_if(in_state(GameState::Playing)),
             );
     }
@@ -55,11 +75,27 @@ pub enum DialogueTrigger {
 pub enum CombatCalloutType {
     LowHealth,
     NearDeath,
+    /// Hull (not shields or armor) below a quarter
+    HullCritical,
     BerserkActive,
     Overheated,
     BossLowHealth,
     WaveIncoming,
     LiberationPod,
+    EscapePodLaunched,
+    EscapePodLost,
+    EscapePodExtracted,
+    CarrierInbound,
+    CarrierHangarsDestroyed,
+    CarrierEscaped,
+    SquadronLeaderDown,
+    SquadronWipe,
+    /// A bonus objective is close to done
+    BonusObjectiveClose,
+    /// No kills in a long while with enemies about
+    KillDrought,
+    /// The score multiplier passed a new mark
+    HighMultiplier,
 }
 
 /// Event to trigger dialogue
@@ -72,6 +108,10 @@ pub struct DialogueEvent {
     pub duration: f32,
     /// Priority (higher = more important, can interrupt lower)
     pub priority: u8,
+    /// Who is speaking (defaults to the module's narrator)
+    pub speaker: Option<String>,
+    /// Voice-over clip (asset path); looked up in the voice pack when unset
+    pub voice: Option<String>,
 }
 
 impl Default for DialogueEvent {
@@ -81,6 +121,8 @@ impl Default for DialogueEvent {
             custom_text: None,
             duration: 4.0,
             priority: 1,
+            speaker: None,
+            voice: None,
         }
     }
 }
@@ -101,6 +143,7 @@ impl DialogueEvent {
             custom_text: Some(dialogue),
             duration: 3.0,
             priority: 8,
+            ..default()
         }
     }
 
@@ -110,6 +153,7 @@ impl DialogueEvent {
             custom_text: Some(dialogue),
             duration: 4.0,
             priority: 9,
+            ..default()
         }
     }
 
@@ -131,6 +175,18 @@ impl DialogueEvent {
         }
     }
 
+    /// A wingman on comms
+    pub fn pilot_chatter(speaker: String, text: &str) -> Self {
+        Self {
+            trigger: DialogueTrigger::Custom(text.to_string()),
+            custom_text: Some(text.to_string()),
+            duration: 2.5,
+            priority: 2,
+            speaker: Some(speaker),
+            voice: None,
+        }
+    }
+
     pub fn act_complete(act: u32) -> Self {
         Self {
             trigger: DialogueTrigger::ActComplete(act),
@@ -141,6 +197,149 @@ impl DialogueEvent {
     }
 }
 
+/// How quickly lines auto-advance
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DialogueSpeed {
+    /// Lines linger for slower readers
+    Slow,
+    #[default]
+    Normal,
+    /// Lines clear quickly
+    Fast,
+}
+
+impl DialogueSpeed {
+    pub const ALL: [DialogueSpeed; 3] = [
+        DialogueSpeed::Slow,
+        DialogueSpeed::Normal,
+        DialogueSpeed::Fast,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            DialogueSpeed::Slow => "SLOW",
+            DialogueSpeed::Normal => "NORMAL",
+            DialogueSpeed::Fast => "FAST",
+        }
+    }
+
+    /// How fast a line's timer runs down relative to its authored duration
+    pub fn rate(&self) -> f32 {
+        match self {
+            DialogueSpeed::Slow => 0.6,
+            DialogueSpeed::Normal => 1.0,
+            DialogueSpeed::Fast => 1.7,
+        }
+    }
+
+    /// Next speed `step` places along, wrapping
+    pub fn stepped(&self, step: i32) -> Self {
+        let len = Self::ALL.len() as i32;
+        let index = Self::ALL.iter().position(|s| s == self).unwrap_or(0) as i32;
+        Self::ALL[(index + step).rem_euclid(len) as usize]
+    }
+}
+
+/// Subtitle text size
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SubtitleSize {
+    Small,
+    #[default]
+    Medium,
+    Large,
+}
+
+impl SubtitleSize {
+    pub const ALL: [SubtitleSize; 3] = [
+        SubtitleSize::Small,
+        SubtitleSize::Medium,
+        SubtitleSize::Large,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SubtitleSize::Small => "SMALL",
+            SubtitleSize::Medium => "MEDIUM",
+            SubtitleSize::Large => "LARGE",
+        }
+    }
+
+    /// Font size of the line itself; the speaker name sits a little smaller
+    pub fn font_size(&self) -> f32 {
+        match self {
+            SubtitleSize::Small => 14.0,
+            SubtitleSize::Medium => 16.0,
+            SubtitleSize::Large => 21.0,
+        }
+    }
+
+    /// Next size `step` places along, wrapping
+    pub fn stepped(&self, step: i32) -> Self {
+        let len = Self::ALL.len() as i32;
+        let index = Self::ALL.iter().position(|s| s == self).unwrap_or(0) as i32;
+        Self::ALL[(index + step).rem_euclid(len) as usize]
+    }
+}
+
+/// Panel behind the subtitles
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SubtitleBackground {
+    /// Text straight over the playfield
+    Off,
+    /// The usual see-through panel
+    #[default]
+    Panel,
+    /// An opaque panel, for busy screens
+    Solid,
+}
+
+impl SubtitleBackground {
+    pub const ALL: [SubtitleBackground; 3] = [
+        SubtitleBackground::Off,
+        SubtitleBackground::Panel,
+        SubtitleBackground::Solid,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SubtitleBackground::Off => "OFF",
+            SubtitleBackground::Panel => "PANEL",
+            SubtitleBackground::Solid => "SOLID",
+        }
+    }
+
+    /// Opacity of the panel, given the theme's usual panel opacity
+    pub fn alpha(&self, panel: f32) -> f32 {
+        match self {
+            SubtitleBackground::Off => 0.0,
+            SubtitleBackground::Panel => panel,
+            SubtitleBackground::Solid => 1.0,
+        }
+    }
+
+    /// Next background `step` places along, wrapping
+    pub fn stepped(&self, step: i32) -> Self {
+        let len = Self::ALL.len() as i32;
+        let index = Self::ALL.iter().position(|s| s == self).unwrap_or(0) as i32;
+        Self::ALL[(index + step).rem_euclid(len) as usize]
+    }
+}
+
+/// Player dialogue preferences (saved)
+#[derive(Resource, Debug, Clone, Default)]
+pub struct DialogueSettings {
+    pub speed: DialogueSpeed,
+    pub subtitle_size: SubtitleSize,
+    pub subtitle_background: SubtitleBackground,
+}
+
+/// A line that was shown, for the comms log
+#[derive(Debug, Clone, PartialEq)]
+pub struct DialogueLine {
+    pub speaker: String,
+    pub text: String,
+}
+
 /// Dialogue system state
 #[derive(Resource, Default)]
 pub struct DialogueSystem {
@@ -152,12 +351,20 @@ pub struct DialogueSystem {
     pub timer: f32,
     /// Current dialogue priority
     pub priority: u8,
+    /// Voice-over clip for the current line
+    pub voice: Option<String>,
+    /// Set once the current line is timed to its clip rather than its text
+    pub voiced: bool,
+    /// Lines shown so far, so a repeat of the same text still counts as new
+    pub line: u32,
     /// Queue of pending dialogues
-    pub queue: Vec<(String, f32, u8)>, // (text, duration, priority)
+    pub queue: Vec<(String, f32, u8, String, Option<String>)>, // (text, duration, priority, speaker, voice)
     /// Last liberation milestone shown
     pub last_liberation_milestone: u32,
     /// Has shown stage briefing for current stage
     pub shown_stage_briefing: bool,
+    /// Recently shown lines, oldest first
+    pub history: VecDeque<DialogueLine>,
 }
 
 impl DialogueSystem {
@@ -166,6 +373,11 @@ impl DialogueSystem {
         self.active_text.is_some()
     }
 
+    /// Nothing showing and nothing waiting
+    pub fn is_quiet(&self) -> bool {
+        self.active_text.is_none() && self.queue.is_empty()
+    }
+
     /// Show dialogue immediately (respects priority)
     pub fn show(&mut self, text: String, duration: f32, priority: u8) {
         self.show_with_speaker(text, duration, priority, "Tribal Elder");
@@ -173,14 +385,46 @@ impl DialogueSystem {
 
     /// Show dialogue with specific speaker
     pub fn show_with_speaker(&mut self, text: String, duration: f32, priority: u8, speaker: &str) {
+        self.show_voiced(text, duration, priority, speaker, None);
+    }
+
+    /// Show dialogue with a speaker and, if it has one, a voice-over clip
+    pub fn show_voiced(
+        &mut self,
+        text: String,
+        duration: f32,
+        priority: u8,
+        speaker: &str,
+        voice: Option<String>,
+    ) {
         if priority >= self.priority || self.active_text.is_none() {
+            if self.history.len() >= DIALOGUE_HISTORY_LEN {
+                self.history.pop_front();
+            }
+            self.history.push_back(DialogueLine {
+                speaker: speaker.to_string(),
+                text: text.clone(),
+            });
             self.active_text = Some(text);
             self.timer = duration;
             self.priority = priority;
             self.speaker = speaker.to_string();
+            self.voice = voice;
+            self.voiced = false;
+            self.line = self.line.wrapping_add(1);
         } else {
             // Queue lower priority dialogue
-            self.queue.push((text, duration, priority));
+            self.queue
+                .push((text, duration, priority, speaker.to_string(), voice));
+        }
+    }
+
+    /// Keep the current line up for its clip's length instead of its
+    /// authored duration
+    pub fn time_to_clip(&mut self, clip_secs: f32) {
+        if self.active_text.is_some() {
+            self.timer = clip_secs + VOICE_LINE_TAIL;
+            self.voiced = true;
         }
     }
 
@@ -189,12 +433,32 @@ impl DialogueSystem {
         self.active_text = None;
         self.timer = 0.0;
         self.priority = 0;
+        self.voice = None;
+        self.voiced = false;
+    }
+
+    /// Drop the current line and bring up the next queued one
+    /// (highest priority first)
+    pub fn advance(&mut self) {
+        self.clear();
+        // Earliest queued wins a priority tie
+        let next = self
+            .queue
+            .iter()
+            .enumerate()
+            .max_by_key(|(i, entry)| (entry.2, std::cmp::Reverse(*i)))
+            .map(|(i, _)| i);
+        if let Some(i) = next {
+            let (text, duration, priority, speaker, voice) = self.queue.remove(i);
+            self.show_voiced(text, duration, priority, &speaker, voice);
+        }
     }
 
     /// Reset for new game
     pub fn reset(&mut self) {
         self.clear();
         self.queue.clear();
+        self.history.clear();
         self.last_liberation_milestone = 0;
         self.shown_stage_briefing = false;
     }
@@ -205,6 +469,7 @@ fn handle_dialogue_events(
     mut events: EventReader<DialogueEvent>,
     mut dialogue: ResMut<DialogueSystem>,
     active_module: Res<ActiveModule>,
+    voice_pack: Res<VoicePack>,
 ) {
     for event in events.read() {
         let is_cg = active_module.is_caldari_gallente();
@@ -217,31 +482,52 @@ fn handle_dialogue_events(
             get_dialogue_text(&event.trigger)
         };
 
-        let speaker = if is_cg {
-            "Fleet Command"
-        } else {
-            "Tribal Elder"
+        let speaker = match &event.speaker {
+            Some(speaker) => speaker.as_str(),
+            None if is_cg => "Fleet Command",
+            None => "Tribal Elder",
         };
 
-        dialogue.show_with_speaker(text, event.duration, event.priority, speaker);
+        let voice = event
+            .voice
+            .clone()
+            .or_else(|| voice_pack.clip_for(&event.trigger, is_cg));
+
+        dialogue.show_voiced(text, event.duration, event.priority, speaker, voice);
+    }
+}
+
+/// Skip the current line on Enter or the controller's Back button
+fn skip_dialogue_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    joystick: Res<JoystickState>,
+    mut dialogue: ResMut<DialogueSystem>,
+) {
+    if !dialogue.is_active() {
+        return;
+    }
+    if keyboard.just_pressed(KeyCode::Enter) || joystick.select() {
+        dialogue.advance();
     }
 }
 
 /// Update dialogue timer and process queue
-fn update_dialogue_timer(time: Res<Time>, mut dialogue: ResMut<DialogueSystem>) {
+fn update_dialogue_timer(
+    time: Res<Time>,
+    settings: Res<DialogueSettings>,
+    mut dialogue: ResMut<DialogueSystem>,
+) {
     if dialogue.active_text.is_some() {
-        dialogue.timer -= time.delta_secs();
+        // A voiced line runs as long as its clip, whatever the text speed
+        let rate = if dialogue.voiced {
+            1.0
+        } else {
+            settings.speed.rate()
+        };
+        dialogue.timer -= time.delta_secs() * rate;
 
         if dialogue.timer <= 0.0 {
-            dialogue.clear();
-
-            // Process queue (highest priority first)
-            if !dialogue.queue.is_empty() {
-                dialogue.queue.sort_by(|a, b| b.2.cmp(&a.2));
-                if let Some((text, duration, priority)) = dialogue.queue.pop() {
-                    dialogue.show(text, duration, priority);
-                }
-            }
+            dialogue.advance();
         }
     }
 }
@@ -313,11 +599,35 @@ fn get_combat_callout(callout: &CombatCalloutType) -> String {
     match callout {
         CombatCalloutType::LowHealth => "Your ship struggles. Fight smarter.",
         CombatCalloutType::NearDeath => "Do not fall here. Not now. Not when we are so close.",
+        CombatCalloutType::HullCritical => {
+            "Your hull is venting, child. Break away - a dead pilot frees no one."
+        }
         CombatCalloutType::BerserkActive => "The ancestors fill you with rage. Use it!",
         CombatCalloutType::Overheated => "Your weapons strain. But do not stop. Never stop.",
         CombatCalloutType::BossLowHealth => "It weakens! Strike true!",
         CombatCalloutType::WaveIncoming => "More enemies approach. Steel yourself.",
         CombatCalloutType::LiberationPod => "A liberation pod! Collect it - a soul awaits freedom.",
+        CombatCalloutType::EscapePodLaunched => {
+            "Escape pods! Our people flee the wreck - guard them."
+        }
+        CombatCalloutType::EscapePodLost => "A pod is lost. Their chains end in fire. Avenge them.",
+        CombatCalloutType::EscapePodExtracted => "The pod is clear. They breathe free air tonight.",
+        CombatCalloutType::CarrierInbound => {
+            "A carrier warps in! Burn out its hangar bays before the swarm grows."
+        }
+        CombatCalloutType::CarrierHangarsDestroyed => {
+            "Its hangars burn. The beast is toothless - well struck!"
+        }
+        CombatCalloutType::CarrierEscaped => "The carrier escapes. Its fighters will not.",
+        CombatCalloutType::SquadronLeaderDown => {
+            "Their leader falls! Without a head, the pack scatters."
+        }
+        CombatCalloutType::SquadronWipe => "The whole flight, gone in a breath. Magnificent.",
+        CombatCalloutType::BonusObjectiveClose => {
+            "You are close to more than the mission asks. Finish it."
+        }
+        CombatCalloutType::KillDrought => "Your guns are quiet, child. The enemy is not.",
+        CombatCalloutType::HighMultiplier => "The fury builds! Do not let it fade now.",
     }
     .to_string()
 }
@@ -454,11 +764,33 @@ fn get_cg_combat_callout(callout: &CombatCalloutType) -> String {
     match callout {
         CombatCalloutType::LowHealth => "Hull integrity compromised. Recommend evasive action.",
         CombatCalloutType::NearDeath => "Critical damage! Get out of there, pilot!",
+        CombatCalloutType::HullCritical => "Hull breach detected. Disengage and find cover!",
         CombatCalloutType::BerserkActive => "Combat overdrive engaged. Give them hell!",
         CombatCalloutType::Overheated => "Weapon systems overheating. Manage your heat signature.",
         CombatCalloutType::BossLowHealth => "Target is critical! Finish it!",
         CombatCalloutType::WaveIncoming => "New contacts on scope. Hostiles inbound.",
         CombatCalloutType::LiberationPod => "Recovery beacon detected. Secure that asset.",
+        CombatCalloutType::EscapePodLaunched => "Civilian pods launching. Cover their egress.",
+        CombatCalloutType::EscapePodLost => "We lost a pod. Keep those hostiles off the civilians.",
+        CombatCalloutType::EscapePodExtracted => "Pod has cleared the area. Good escort work.",
+        CombatCalloutType::CarrierInbound => {
+            "Capital signature on scope! Take out those hangar bays."
+        }
+        CombatCalloutType::CarrierHangarsDestroyed => {
+            "All hangar bays down. Fighter launches have ceased."
+        }
+        CombatCalloutType::CarrierEscaped => {
+            "Carrier has warped off. Mop up the remaining fighters."
+        }
+        CombatCalloutType::SquadronLeaderDown => {
+            "Flight lead is down. Hostile formation is breaking up."
+        }
+        CombatCalloutType::SquadronWipe => "Entire squadron splashed. Outstanding shooting.",
+        CombatCalloutType::BonusObjectiveClose => {
+            "Secondary objective nearly complete. Close it out."
+        }
+        CombatCalloutType::KillDrought => "No confirmed kills in a while. Press the attack.",
+        CombatCalloutType::HighMultiplier => "Combat rating climbing. Keep the chain alive.",
     }
     .to_string()
 }
@@ -536,3 +868,85 @@ pub enum NightmareDialogueTrigger {
     HullCritical,
     NewRecord,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skipping_brings_up_the_most_urgent_queued_line() {
+        let mut dialogue = DialogueSystem::default();
+        dialogue.show("briefing".into(), 5.0, 10);
+        dialogue.show("chatter".into(), 2.0, 2);
+        dialogue.show("callout".into(), 2.0, 3);
+        assert_eq!(dialogue.queue.len(), 2);
+
+        dialogue.advance();
+        assert_eq!(dialogue.active_text.as_deref(), Some("callout"));
+        dialogue.advance();
+        assert_eq!(dialogue.active_text.as_deref(), Some("chatter"));
+        dialogue.advance();
+        assert!(!dialogue.is_active());
+    }
+
+    #[test]
+    fn history_keeps_shown_lines_up_to_its_cap() {
+        let mut dialogue = DialogueSystem::default();
+        dialogue.show_with_speaker("first".into(), 1.0, 5, "Fleet Command");
+        // Queued behind a higher priority line - not shown yet
+        dialogue.show("later".into(), 1.0, 1);
+        assert_eq!(dialogue.history.len(), 1);
+        assert_eq!(dialogue.history[0].speaker, "Fleet Command");
+        dialogue.advance();
+        assert_eq!(dialogue.history.back().unwrap().text, "later");
+
+        for i in 0..DIALOGUE_HISTORY_LEN {
+            dialogue.show(format!("line {}", i), 1.0, 9);
+        }
+        assert_eq!(dialogue.history.len(), DIALOGUE_HISTORY_LEN);
+        assert_eq!(dialogue.history[0].text, "line 0");
+
+        dialogue.reset();
+        assert!(dialogue.history.is_empty());
+    }
+
+    #[test]
+    fn voiced_lines_keep_their_clip_through_the_queue_and_time_to_it() {
+        let mut dialogue = DialogueSystem::default();
+        dialogue.show("briefing".into(), 5.0, 10);
+        dialogue.show_voiced(
+            "callout".into(),
+            2.0,
+            3,
+            "Tribal Elder",
+            Some("vo/elder/callout_low_health.ogg".into()),
+        );
+        assert_eq!(dialogue.voice, None);
+        let first = dialogue.line;
+
+        dialogue.advance();
+        assert_eq!(
+            dialogue.voice.as_deref(),
+            Some("vo/elder/callout_low_health.ogg")
+        );
+        assert_eq!(dialogue.line, first + 1);
+        assert!(!dialogue.voiced);
+
+        dialogue.time_to_clip(6.0);
+        assert!(dialogue.voiced);
+        assert_eq!(dialogue.timer, 6.0 + VOICE_LINE_TAIL);
+
+        dialogue.advance();
+        assert_eq!(dialogue.voice, None);
+        assert!(!dialogue.voiced);
+    }
+
+    #[test]
+    fn dialogue_speed_cycles_and_orders_its_rates() {
+        assert_eq!(DialogueSpeed::Normal.stepped(1), DialogueSpeed::Fast);
+        assert_eq!(DialogueSpeed::Fast.stepped(1), DialogueSpeed::Slow);
+        assert_eq!(DialogueSpeed::Slow.stepped(-1), DialogueSpeed::Fast);
+        assert!(DialogueSpeed::Slow.rate() < DialogueSpeed::Normal.rate());
+        assert!(DialogueSpeed::Normal.rate() < DialogueSpeed::Fast.rate());
+    }
+}
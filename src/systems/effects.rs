@@ -1,11 +1,14 @@
 //! Visual Effects System
 //!
 //! Starfield, explosions, particle effects, screen shake, engine trails.
+//! Hits on the player read by tank layer: a blue ripple when shields take
+//! it, orange sparks off armor, grey debris from the hull.
 
 #![allow(dead_code)]
 
 use crate::core::*;
 use crate::systems::ability::{AbilityActivatedEvent, AbilityType};
+use crate::systems::scoring_v2::{ComboHeatSystem, OverloadActivatedEvent, OverloadBurnoutEvent};
 use bevy::prelude::*;
 use bevy::text::{Text2d, TextColor, TextFont};
 
@@ -19,6 +22,7 @@ pub struct EffectsPlugin;
 impl Plugin for EffectsPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<ScreenShake>()
+            .init_resource::<MotionSettings>()
             .init_resource::<ScreenFlash>()
             .init_resource::<CameraZoom>()
             .add_systems(OnEnter(GameState::Playing), spawn_starfield)
@@ -37,9 +41,12 @@ impl Plugin for EffectsPlugin {
                     spawn_bullet_trails,
                     update_bullet_trails,
                     update_hit_flash,
+                    (spawn_layer_hit_feedback, update_layer_hit_particles),
                     update_damage_numbers,
                     spawn_ability_effects,
                     update_ability_effects,
+                    handle_overload_effects,
+                    update_overload_burn,
                 )
                     .run_if(in_state(GameState::Playing)),
             )
@@ -59,7 +66,7 @@ pub struct Star {
 }
 
 /// Spawn scrolling starfield background
-fn spawn_starfield(mut commands: Commands) {
+fn spawn_starfield(mut commands: Commands, playfield: Res<Playfield>) {
     let mut rng = fastrand::Rng::new();
 
     // Spawn stars in 3 layers (parallax)
@@ -77,8 +84,7 @@ fn spawn_starfield(mut commands: Commands) {
         };
 
         for _ in 0..count {
-            let x = rng.f32() * SCREEN_WIDTH - SCREEN_WIDTH / 2.0;
-            let y = rng.f32() * SCREEN_HEIGHT - SCREEN_HEIGHT / 2.0;
+            let Vec2 { x, y } = playfield.random_point(&mut rng);
 
             commands.spawn((
                 Star { speed, layer },
@@ -94,16 +100,21 @@ fn spawn_starfield(mut commands: Commands) {
 }
 
 /// Scroll stars downward
-fn update_starfield(time: Res<Time>, mut query: Query<(&mut Transform, &Star)>) {
+fn update_starfield(
+    time: Res<Time>,
+    playfield: Res<Playfield>,
+    mut query: Query<(&mut Transform, &Star)>,
+) {
     let dt = time.delta_secs();
+    let half = playfield.half();
 
     for (mut transform, star) in query.iter_mut() {
         transform.translation.y -= star.speed * dt;
 
         // Wrap around
-        if transform.translation.y < -SCREEN_HEIGHT / 2.0 - 10.0 {
-            transform.translation.y = SCREEN_HEIGHT / 2.0 + 10.0;
-            transform.translation.x = fastrand::f32() * SCREEN_WIDTH - SCREEN_WIDTH / 2.0;
+        if transform.translation.y < -half.y - 10.0 {
+            transform.translation.y = half.y + 10.0;
+            transform.translation.x = (fastrand::f32() - 0.5) * playfield.size.x;
         }
     }
 }
@@ -297,6 +308,13 @@ impl ScreenShake {
     }
 }
 
+/// Motion accessibility setting
+#[derive(Resource, Debug, Default)]
+pub struct MotionSettings {
+    /// Hold pulsing and flashing overlays steady and thin out particles
+    pub reduced_motion: bool,
+}
+
 /// Handle screen shake events
 fn update_screen_shake(
     time: Res<Time>,
@@ -440,6 +458,8 @@ pub struct HitFlash {
     pub duration: f32,
     /// Original sprite color (to restore after flash)
     pub original_color: Color,
+    /// Color the sprite flashes to (white unless tinted)
+    pub flash_color: Color,
 }
 
 impl HitFlash {
@@ -449,6 +469,7 @@ impl HitFlash {
             timer: 0.1,
             duration: 0.1,
             original_color,
+            flash_color: Color::WHITE,
         }
     }
 
@@ -458,8 +479,29 @@ impl HitFlash {
             timer: duration,
             duration,
             original_color,
+            flash_color: Color::WHITE,
         }
     }
+
+    /// Flash to a color other than white
+    pub fn with_color(mut self, flash_color: Color) -> Self {
+        self.flash_color = flash_color;
+        self
+    }
+}
+
+/// Overbright white for enemy hits. A plain white tint leaves a sprite at
+/// its natural colors, so the flash pushes past 1.0 to whiten it.
+pub const DAMAGE_FLASH_COLOR: Color = Color::linear_rgb(4.0, 4.0, 4.0);
+
+/// Flash an enemy white on a hit. A hit mid-flash restarts the flash
+/// rather than taking the half-white color as the one to restore.
+pub fn flash_enemy_hit(commands: &mut Commands, entity: Entity, color: Color) {
+    commands
+        .entity(entity)
+        .entry::<HitFlash>()
+        .and_modify(|mut flash| flash.timer = flash.duration)
+        .or_insert(HitFlash::new(color).with_color(DAMAGE_FLASH_COLOR));
 }
 
 /// Update hit flash effects on sprites
@@ -474,12 +516,12 @@ fn update_hit_flash(
         flash.timer -= dt;
 
         if flash.timer > 0.0 {
-            // Lerp from white to original color
+            // Lerp from the flash color to original color
             let progress = 1.0 - (flash.timer / flash.duration);
-            let white = Color::WHITE;
+            let white = flash.flash_color;
             let original = flash.original_color;
 
-            // Simple lerp between white and original
+            // Simple lerp between flash and original
             let r = white.to_srgba().red * (1.0 - progress) + original.to_srgba().red * progress;
             let g =
                 white.to_srgba().green * (1.0 - progress) + original.to_srgba().green * progress;
@@ -495,6 +537,196 @@ fn update_hit_flash(
     }
 }
 
+// =============================================================================
+// LAYER HIT FEEDBACK
+// =============================================================================
+
+const SHIELD_HIT_COLOR: Color = Color::srgb(0.35, 0.65, 1.0);
+const ARMOR_HIT_COLOR: Color = Color::srgb(1.0, 0.55, 0.15);
+const HULL_HIT_COLOR: Color = Color::srgb(0.55, 0.55, 0.55);
+
+/// Radius of the shield bubble the ripple runs along
+const SHIELD_RIPPLE_RADIUS: f32 = 28.0;
+
+/// Which tank layer a hit feedback particle belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HitLayer {
+    Shield,
+    Armor,
+    Hull,
+}
+
+impl HitLayer {
+    /// Every layer that absorbed part of the hit, outermost first
+    pub fn hit_by(layers: &DamageLayers) -> Vec<HitLayer> {
+        [
+            (HitLayer::Shield, layers.shield),
+            (HitLayer::Armor, layers.armor),
+            (HitLayer::Hull, layers.hull),
+        ]
+        .into_iter()
+        .filter(|(_, amount)| *amount > 0.0)
+        .map(|(layer, _)| layer)
+        .collect()
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            HitLayer::Shield => SHIELD_HIT_COLOR,
+            HitLayer::Armor => ARMOR_HIT_COLOR,
+            HitLayer::Hull => HULL_HIT_COLOR,
+        }
+    }
+}
+
+/// Ship flash color for a hit: the deepest layer it reached
+pub fn layer_flash_color(layers: &DamageLayers) -> Color {
+    HitLayer::hit_by(layers)
+        .last()
+        .map_or(Color::WHITE, |layer| match layer {
+            // Hull hits keep the classic white flash so they read as alarming
+            HitLayer::Hull => Color::WHITE,
+            layer => layer.color(),
+        })
+}
+
+/// A shield ripple segment, armor spark or hull chunk
+#[derive(Component)]
+pub struct LayerHitParticle {
+    pub layer: HitLayer,
+    pub velocity: Vec2,
+    pub spin: f32,
+    pub lifetime: f32,
+    pub max_lifetime: f32,
+}
+
+/// Spawn feedback for each layer a hit on the player went through
+fn spawn_layer_hit_feedback(
+    mut commands: Commands,
+    mut damage_events: EventReader<PlayerDamagedEvent>,
+    player_query: Query<&Transform, With<crate::entities::Player>>,
+) {
+    let Ok(player_transform) = player_query.get_single() else {
+        damage_events.clear();
+        return;
+    };
+    let center = player_transform.translation.truncate();
+
+    for event in damage_events.read() {
+        let toward = (event.source_position - center).normalize_or(Vec2::Y);
+        let impact = center + toward * SHIELD_RIPPLE_RADIUS * 0.6;
+        for layer in HitLayer::hit_by(&event.layers) {
+            match layer {
+                HitLayer::Shield => spawn_shield_ripple(&mut commands, center, toward),
+                HitLayer::Armor => spawn_armor_sparks(&mut commands, impact, toward),
+                HitLayer::Hull => spawn_hull_debris(&mut commands, impact, toward),
+            }
+        }
+    }
+}
+
+/// Arc of blue segments around the bubble, centered on the impact side
+fn spawn_shield_ripple(commands: &mut Commands, center: Vec2, toward: Vec2) {
+    let facing = toward.to_angle();
+    for i in 0..9 {
+        let angle = facing + (i as f32 - 4.0) * 0.22;
+        let dir = Vec2::from_angle(angle);
+        let pos = center + dir * SHIELD_RIPPLE_RADIUS;
+        commands.spawn((
+            LayerHitParticle {
+                layer: HitLayer::Shield,
+                velocity: dir * 40.0,
+                spin: 0.0,
+                lifetime: 0.35,
+                max_lifetime: 0.35,
+            },
+            Sprite {
+                color: SHIELD_HIT_COLOR.with_alpha(0.8),
+                custom_size: Some(Vec2::new(3.0, 9.0)),
+                ..default()
+            },
+            Transform::from_xyz(pos.x, pos.y, LAYER_EFFECTS + 1.0)
+                .with_rotation(Quat::from_rotation_z(angle)),
+        ));
+    }
+}
+
+/// Fast orange streaks glancing off the plating
+fn spawn_armor_sparks(commands: &mut Commands, impact: Vec2, toward: Vec2) {
+    let mut rng = fastrand::Rng::new();
+    for _ in 0..8 {
+        let angle = toward.to_angle() + (rng.f32() - 0.5) * 1.8;
+        let velocity = Vec2::from_angle(angle) * (160.0 + rng.f32() * 120.0);
+        commands.spawn((
+            LayerHitParticle {
+                layer: HitLayer::Armor,
+                velocity,
+                spin: 0.0,
+                lifetime: 0.25,
+                max_lifetime: 0.25,
+            },
+            Sprite {
+                color: ARMOR_HIT_COLOR,
+                custom_size: Some(Vec2::new(7.0, 2.0)),
+                ..default()
+            },
+            Transform::from_xyz(impact.x, impact.y, LAYER_EFFECTS + 1.0)
+                .with_rotation(Quat::from_rotation_z(angle)),
+        ));
+    }
+}
+
+/// Slow tumbling grey chunks torn from the hull
+fn spawn_hull_debris(commands: &mut Commands, impact: Vec2, toward: Vec2) {
+    let mut rng = fastrand::Rng::new();
+    for _ in 0..5 {
+        let angle = toward.to_angle() + (rng.f32() - 0.5) * 2.4;
+        let velocity = Vec2::from_angle(angle) * (50.0 + rng.f32() * 50.0);
+        let size = 3.0 + rng.f32() * 4.0;
+        commands.spawn((
+            LayerHitParticle {
+                layer: HitLayer::Hull,
+                velocity,
+                spin: (rng.f32() - 0.5) * 12.0,
+                lifetime: 0.7,
+                max_lifetime: 0.7,
+            },
+            Sprite {
+                color: HULL_HIT_COLOR,
+                custom_size: Some(Vec2::new(size, size * 0.7)),
+                ..default()
+            },
+            Transform::from_xyz(impact.x, impact.y, LAYER_EFFECTS + 1.0)
+                .with_rotation(Quat::from_rotation_z(rng.f32() * std::f32::consts::TAU)),
+        ));
+    }
+}
+
+fn update_layer_hit_particles(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Transform, &mut LayerHitParticle, &mut Sprite)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut transform, mut particle, mut sprite) in query.iter_mut() {
+        particle.lifetime -= dt;
+        if particle.lifetime <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        transform.translation += (particle.velocity * dt).extend(0.0);
+        transform.rotate_z(particle.spin * dt);
+        // Sparks die fast; debris drifts on
+        let drag = match particle.layer {
+            HitLayer::Armor => 6.0,
+            _ => 2.0,
+        };
+        particle.velocity *= (1.0 - drag * dt).max(0.0);
+        let progress = particle.lifetime / particle.max_lifetime;
+        sprite.color = particle.layer.color().with_alpha(progress);
+    }
+}
+
 // =============================================================================
 // SCREEN FLASH
 // =============================================================================
@@ -552,6 +784,7 @@ fn update_screen_flash(
     mut commands: Commands,
     time: Res<Time>,
     mut flash: ResMut<ScreenFlash>,
+    playfield: Res<Playfield>,
     mut overlay_query: Query<(Entity, &mut Sprite), With<ScreenFlashOverlay>>,
 ) {
     let dt = time.delta_secs();
@@ -569,7 +802,7 @@ fn update_screen_flash(
                 ScreenFlashOverlay,
                 Sprite {
                     color: flash.color.with_alpha(flash.intensity),
-                    custom_size: Some(Vec2::new(SCREEN_WIDTH + 100.0, SCREEN_HEIGHT + 100.0)),
+                    custom_size: Some(playfield.size + 100.0),
                     ..default()
                 },
                 Transform::from_xyz(0.0, 0.0, LAYER_HUD + 10.0), // Above everything
@@ -591,26 +824,27 @@ fn update_screen_flash(
 #[derive(Component)]
 pub struct BerserkTintOverlay;
 
-/// Berserk screen tint effect - red tint while berserk is active
+/// Berserk screen tint effect - red-orange tint while berserk is active
 fn update_berserk_tint(
     mut commands: Commands,
     berserk: Res<BerserkSystem>,
+    playfield: Res<Playfield>,
     mut overlay_query: Query<(Entity, &mut Sprite), With<BerserkTintOverlay>>,
 ) {
     if berserk.is_active {
-        // Pulse the tint based on remaining time
-        let pulse = (berserk.timer * 8.0).sin().abs() * 0.1;
-        let alpha = 0.15 + pulse;
+        // Pulse the tint red to orange based on remaining time
+        let phase = (berserk.timer * 8.0).sin().abs();
+        let color = super::berserk_tint_color(phase).with_alpha(0.15 + phase * 0.1);
 
         if let Ok((_, mut sprite)) = overlay_query.get_single_mut() {
-            sprite.color = Color::srgba(1.0, 0.1, 0.1, alpha);
+            sprite.color = color;
         } else {
             // Spawn tint overlay
             commands.spawn((
                 BerserkTintOverlay,
                 Sprite {
-                    color: Color::srgba(1.0, 0.1, 0.1, alpha),
-                    custom_size: Some(Vec2::new(SCREEN_WIDTH + 100.0, SCREEN_HEIGHT + 100.0)),
+                    color,
+                    custom_size: Some(playfield.size + 100.0),
                     ..default()
                 },
                 Transform::from_xyz(0.0, 0.0, LAYER_HUD + 5.0), // Below flash, above game
@@ -1331,6 +1565,101 @@ fn update_ability_effects(
     }
 }
 
+// =============================================================================
+// HEAT OVERLOAD
+// =============================================================================
+
+/// Scorched glow on the player ship while the overload burnout cools down
+#[derive(Component)]
+pub struct OverloadBurn {
+    /// Sprite color to restore once the module is back online
+    pub original_color: Color,
+    /// Timer for smoke puffs
+    pub smoke_timer: f32,
+}
+
+/// Flash on overload, start the burn effect when it burns out
+fn handle_overload_effects(
+    mut commands: Commands,
+    mut overload_events: EventReader<OverloadActivatedEvent>,
+    mut burnout_events: EventReader<OverloadBurnoutEvent>,
+    player_query: Query<(Entity, &Transform, &Sprite), With<crate::entities::Player>>,
+    mut screen_flash: ResMut<ScreenFlash>,
+) {
+    let Ok((entity, transform, sprite)) = player_query.get_single() else {
+        overload_events.clear();
+        burnout_events.clear();
+        return;
+    };
+
+    for _ in overload_events.read() {
+        screen_flash.colored(Color::srgba(1.0, 0.45, 0.1, 0.8), 0.5);
+        spawn_weapon_charge_effect(&mut commands, transform.translation.truncate());
+    }
+
+    for _ in burnout_events.read() {
+        commands.entity(entity).insert(OverloadBurn {
+            original_color: sprite.color,
+            smoke_timer: 0.0,
+        });
+    }
+}
+
+/// Pulse the ship toward a heat glow and trail smoke until burnout ends
+fn update_overload_burn(
+    mut commands: Commands,
+    time: Res<Time>,
+    heat_system: Res<ComboHeatSystem>,
+    mut query: Query<(Entity, &Transform, &mut Sprite, &mut OverloadBurn), Without<HitFlash>>,
+) {
+    let dt = time.delta_secs();
+
+    for (entity, transform, mut sprite, mut burn) in query.iter_mut() {
+        if !heat_system.is_burned_out() {
+            sprite.color = burn.original_color;
+            commands.entity(entity).remove::<OverloadBurn>();
+            continue;
+        }
+
+        // Glow fades as the module cools
+        let pulse = (time.elapsed_secs() * 6.0).sin() * 0.5 + 0.5;
+        let glow = heat_system.burnout_percent() * (0.35 + pulse * 0.25);
+        let original = burn.original_color.to_srgba();
+        sprite.color = Color::srgba(
+            original.red + (1.0 - original.red) * glow,
+            original.green * (1.0 - glow * 0.6),
+            original.blue * (1.0 - glow * 0.8),
+            original.alpha,
+        );
+
+        // Smoke puffs drifting off the hull
+        burn.smoke_timer -= dt;
+        if burn.smoke_timer <= 0.0 {
+            burn.smoke_timer = 0.08;
+            let pos = transform.translation.truncate()
+                + Vec2::new(
+                    (fastrand::f32() - 0.5) * 20.0,
+                    (fastrand::f32() - 0.5) * 20.0,
+                );
+            let lifetime = 0.5 + fastrand::f32() * 0.3;
+            let shade = 0.25 + fastrand::f32() * 0.15;
+            commands.spawn((
+                ExplosionParticle {
+                    velocity: Vec2::new((fastrand::f32() - 0.5) * 30.0, -40.0),
+                    lifetime,
+                    max_lifetime: lifetime,
+                },
+                Sprite {
+                    color: Color::srgba(shade + 0.2, shade, shade, 0.8),
+                    custom_size: Some(Vec2::splat(4.0 + fastrand::f32() * 4.0)),
+                    ..default()
+                },
+                Transform::from_xyz(pos.x, pos.y, LAYER_EFFECTS - 1.0),
+            ));
+        }
+    }
+}
+
 // =============================================================================
 // CLEANUP
 // =============================================================================
@@ -1344,6 +1673,7 @@ fn cleanup_effects(
     damage_numbers: Query<Entity, With<DamageNumber>>,
     bullet_trail_particles: Query<Entity, With<BulletTrailParticle>>,
     ability_effect_particles: Query<Entity, With<AbilityEffectParticle>>,
+    layer_hit_particles: Query<Entity, With<LayerHitParticle>>,
 ) {
     for entity in stars.iter() {
         commands.entity(entity).despawn();
@@ -1366,4 +1696,7 @@ fn cleanup_effects(
     for entity in ability_effect_particles.iter() {
         commands.entity(entity).despawn();
     }
+    for entity in layer_hit_particles.iter() {
+        commands.entity(entity).despawn();
+    }
 }
@@ -0,0 +1,325 @@
+//! Enemy Health Bars
+//!
+//! A small bar over an enemy once it's been hit, fading out when it's back
+//! to full or has gone a while without taking fire. Bosses have their own
+//! bar and destroyer-class hulls their layer bars, so neither gets one.
+//! Bars come from a pool: a finished bar is hidden and handed to the next
+//! enemy hit rather than despawned. Can be switched off in the options.
+
+#![allow(dead_code)]
+
+use crate::core::*;
+use crate::entities::{Enemy, EnemyStats, EnemyTank};
+use bevy::prelude::*;
+
+/// Bar size over the enemy
+const BAR_WIDTH: f32 = 32.0;
+const BAR_HEIGHT: f32 = 3.0;
+
+/// Gap between the top of the sprite and the bar
+const BAR_MARGIN: f32 = 6.0;
+
+/// Seconds without a hit before the bar starts to fade
+pub const BAR_LINGER: f32 = 2.5;
+
+/// Seconds a bar takes to fade in or out
+pub const BAR_FADE: f32 = 0.35;
+
+/// Bars spawned up front when a run starts
+const POOL_PREWARM: usize = 12;
+
+const BAR_BACK_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.6);
+
+/// Enemy health bar plugin
+pub struct EnemyHealthBarPlugin;
+
+impl Plugin for EnemyHealthBarPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EnemyHealthBarSettings>()
+            .init_resource::<HealthBarPool>()
+            .add_systems(OnEnter(GameState::Playing), prewarm_pool)
+            .add_systems(
+                Update,
+                forget_despawned_bars.run_if(state_changed::<GameState>),
+            )
+            .add_systems(
+                Update,
+                (watch_enemy_health, assign_bars, update_bars)
+                    .chain()
+                    .in_set(GameSet::Ui)
+                    .run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight))),
+            );
+    }
+}
+
+/// Options toggle for the bars
+#[derive(Resource, Debug, Clone)]
+pub struct EnemyHealthBarSettings {
+    pub enabled: bool,
+}
+
+impl Default for EnemyHealthBarSettings {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+/// Hidden bars waiting for an enemy
+#[derive(Resource, Debug, Default)]
+pub struct HealthBarPool {
+    pub free: Vec<Entity>,
+}
+
+/// On an enemy that's been hit: how long since, and its bar if showing
+#[derive(Component, Debug, Clone)]
+pub struct HealthBarTracker {
+    pub last_health: f32,
+    pub since_hit: f32,
+    pub bar: Option<Entity>,
+}
+
+/// A pooled bar; `owner` is the enemy it's over, if any
+#[derive(Component, Debug, Clone, Default)]
+#[require(StateScope)]
+pub struct EnemyHealthBar {
+    pub owner: Option<Entity>,
+    pub alpha: f32,
+}
+
+/// The bar's fill
+#[derive(Component)]
+pub struct EnemyHealthBarFill;
+
+/// How visible a bar should be: full once hit, gone once back to full
+/// health or after lingering out of combat
+pub fn bar_alpha(fraction: f32, since_hit: f32) -> f32 {
+    if fraction >= 1.0 {
+        return 0.0;
+    }
+    1.0 - ((since_hit - BAR_LINGER) / BAR_FADE).clamp(0.0, 1.0)
+}
+
+fn health_fraction(stats: &EnemyStats) -> f32 {
+    if stats.max_health <= 0.0 {
+        return 1.0;
+    }
+    stats.health / stats.max_health
+}
+
+/// Green through yellow to red as health drops
+pub fn bar_color(fraction: f32) -> Color {
+    let fraction = fraction.clamp(0.0, 1.0);
+    if fraction > 0.5 {
+        Color::srgb(2.0 * (1.0 - fraction), 0.85, 0.2)
+    } else {
+        Color::srgb(1.0, 1.7 * fraction, 0.15)
+    }
+}
+
+fn spawn_bar(commands: &mut Commands, owner: Option<Entity>) -> Entity {
+    commands
+        .spawn((
+            EnemyHealthBar { owner, alpha: 0.0 },
+            Sprite {
+                color: BAR_BACK_COLOR.with_alpha(0.0),
+                custom_size: Some(Vec2::new(BAR_WIDTH + 2.0, BAR_HEIGHT + 2.0)),
+                ..default()
+            },
+            Transform::from_xyz(0.0, 0.0, 5.0),
+            if owner.is_some() {
+                Visibility::Visible
+            } else {
+                Visibility::Hidden
+            },
+        ))
+        .with_children(|bar| {
+            bar.spawn((
+                EnemyHealthBarFill,
+                Sprite {
+                    color: bar_color(1.0),
+                    custom_size: Some(Vec2::new(BAR_WIDTH, BAR_HEIGHT)),
+                    anchor: bevy::sprite::Anchor::CenterLeft,
+                    ..default()
+                },
+                Transform::from_xyz(-BAR_WIDTH * 0.5, 0.0, 0.1),
+            ));
+        })
+        .id()
+}
+
+fn prewarm_pool(mut commands: Commands, mut pool: ResMut<HealthBarPool>) {
+    while pool.free.len() < POOL_PREWARM {
+        let bar = spawn_bar(&mut commands, None);
+        pool.free.push(bar);
+    }
+}
+
+/// Pooled bars go with the run that spawned them
+fn forget_despawned_bars(mut pool: ResMut<HealthBarPool>, bars: Query<(), With<EnemyHealthBar>>) {
+    pool.free.retain(|&bar| bars.contains(bar));
+}
+
+/// Start tracking enemies on their first hit, and time since the last
+fn watch_enemy_health(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut enemies: Query<
+        (Entity, &EnemyStats, Option<&mut HealthBarTracker>),
+        (With<Enemy>, Without<EnemyTank>),
+    >,
+) {
+    let dt = time.delta_secs();
+    for (entity, stats, tracker) in enemies.iter_mut() {
+        if stats.is_boss {
+            continue;
+        }
+        match tracker {
+            Some(mut tracker) => {
+                if stats.health < tracker.last_health {
+                    tracker.since_hit = 0.0;
+                } else {
+                    tracker.since_hit += dt;
+                }
+                tracker.last_health = stats.health;
+            }
+            None if stats.health < stats.max_health => {
+                commands.entity(entity).insert(HealthBarTracker {
+                    last_health: stats.health,
+                    since_hit: 0.0,
+                    bar: None,
+                });
+            }
+            None => {}
+        }
+    }
+}
+
+/// Hand a bar to every freshly hit enemy without one
+fn assign_bars(
+    mut commands: Commands,
+    settings: Res<EnemyHealthBarSettings>,
+    mut pool: ResMut<HealthBarPool>,
+    mut enemies: Query<(Entity, &EnemyStats, &mut HealthBarTracker)>,
+    mut bars: Query<(&mut EnemyHealthBar, &mut Visibility)>,
+) {
+    if !settings.enabled {
+        return;
+    }
+    for (entity, stats, mut tracker) in enemies.iter_mut() {
+        if tracker.bar.is_some() || bar_alpha(health_fraction(stats), tracker.since_hit) <= 0.0 {
+            continue;
+        }
+        // A pooled bar if there's one left, otherwise a new one
+        let pooled = loop {
+            match pool.free.pop() {
+                Some(bar) if bars.contains(bar) => break Some(bar),
+                Some(_) => continue,
+                None => break None,
+            }
+        };
+        let bar = match pooled {
+            Some(bar) => {
+                if let Ok((mut state, mut visibility)) = bars.get_mut(bar) {
+                    *state = EnemyHealthBar {
+                        owner: Some(entity),
+                        alpha: 0.0,
+                    };
+                    *visibility = Visibility::Visible;
+                }
+                bar
+            }
+            None => spawn_bar(&mut commands, Some(entity)),
+        };
+        tracker.bar = Some(bar);
+    }
+}
+
+/// Follow, fill and fade each bar; hand finished ones back to the pool
+fn update_bars(
+    time: Res<Time>,
+    settings: Res<EnemyHealthBarSettings>,
+    mut pool: ResMut<HealthBarPool>,
+    mut bars: Query<
+        (
+            Entity,
+            &mut EnemyHealthBar,
+            &mut Transform,
+            &mut Sprite,
+            &mut Visibility,
+            &Children,
+        ),
+        Without<EnemyHealthBarFill>,
+    >,
+    mut fills: Query<(&mut Transform, &mut Sprite), With<EnemyHealthBarFill>>,
+    mut enemies: Query<
+        (
+            &Transform,
+            &EnemyStats,
+            &mut HealthBarTracker,
+            Option<&Sprite>,
+        ),
+        (Without<EnemyHealthBar>, Without<EnemyHealthBarFill>),
+    >,
+) {
+    let step = time.delta_secs() / BAR_FADE;
+    for (bar, mut state, mut transform, mut back, mut visibility, children) in bars.iter_mut() {
+        let Some(owner) = state.owner else {
+            continue;
+        };
+        let target = match enemies.get_mut(owner) {
+            Ok((enemy_transform, stats, tracker, sprite)) if settings.enabled => {
+                let fraction = health_fraction(stats);
+                let size = sprite.and_then(|s| s.custom_size).map_or(40.0, |s| s.y);
+                transform.translation = enemy_transform.translation.truncate().extend(5.0)
+                    + Vec3::Y * (size * 0.5 + BAR_MARGIN);
+                for &child in children.iter() {
+                    if let Ok((mut fill_transform, mut fill)) = fills.get_mut(child) {
+                        fill_transform.scale.x = fraction.clamp(0.0, 1.0);
+                        fill.color = bar_color(fraction).with_alpha(state.alpha);
+                    }
+                }
+                bar_alpha(fraction, tracker.since_hit)
+            }
+            _ => 0.0,
+        };
+
+        state.alpha = if target > state.alpha {
+            (state.alpha + step).min(target)
+        } else {
+            (state.alpha - step).max(target)
+        };
+        back.color = BAR_BACK_COLOR.with_alpha(BAR_BACK_COLOR.alpha() * state.alpha);
+
+        // Faded out (or its enemy's gone): back to the pool
+        if state.alpha <= 0.0 && target <= 0.0 {
+            if let Ok((_, _, mut tracker, _)) = enemies.get_mut(owner) {
+                tracker.bar = None;
+            }
+            state.owner = None;
+            *visibility = Visibility::Hidden;
+            pool.free.push(bar);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bars_show_after_a_hit_and_fade_out_of_combat() {
+        assert_eq!(bar_alpha(1.0, 0.0), 0.0, "full health hides the bar");
+        assert_eq!(bar_alpha(0.6, 0.0), 1.0);
+        assert_eq!(bar_alpha(0.6, BAR_LINGER), 1.0);
+        assert!((bar_alpha(0.6, BAR_LINGER + BAR_FADE * 0.5) - 0.5).abs() < 1e-5);
+        assert_eq!(bar_alpha(0.6, BAR_LINGER + BAR_FADE), 0.0);
+    }
+
+    #[test]
+    fn bar_color_runs_green_to_red() {
+        let full = bar_color(1.0).to_srgba();
+        let empty = bar_color(0.0).to_srgba();
+        assert!(full.green > full.red);
+        assert!(empty.red > empty.green);
+    }
+}
@@ -0,0 +1,134 @@
+//! Fleet Roster Upkeep
+//!
+//! Keeps the campaign's fleet roster in step with the run: unlocked ships
+//! join it, a finished mission tires the ship that flew it and rests the
+//! others, and a launch pays for the flown ship's fatigue in tank and
+//! speed. In Ironman a destroyed ship leaves the roster and the checkpoint
+//! moves on to the next ship in the hangar.
+
+#![allow(dead_code)]
+
+use crate::core::*;
+use crate::entities::{Movement, Player, ShipStats};
+use crate::systems::is_campaign_run;
+use crate::ui::ToastEvent;
+use bevy::prelude::*;
+
+/// Fleet roster plugin
+pub struct FleetRosterPlugin;
+
+impl Plugin for FleetRosterPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            apply_fatigue
+                .in_set(GameSet::Simulation)
+                .run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight)))
+                .run_if(is_campaign_run),
+        )
+        .add_systems(
+            OnEnter(GameState::StageComplete),
+            record_sortie.run_if(is_campaign_run),
+        )
+        .add_systems(
+            OnEnter(GameState::GameOver),
+            lose_flown_ship.run_if(is_campaign_run),
+        );
+    }
+}
+
+/// Whether each of the session's ships is unlocked, in hangar order
+pub fn unlocked_ships(save_data: &SaveData, session: &GameSession) -> Vec<bool> {
+    session
+        .player_ships()
+        .iter()
+        .map(|ship| {
+            save_data.is_ship_unlocked(
+                ship.type_id,
+                ship.unlock_stage,
+                session.player_faction.short_name(),
+                session.enemy_faction.short_name(),
+            )
+        })
+        .collect()
+}
+
+/// Bring any newly unlocked ships onto the roster
+pub fn enlist_unlocked(save_data: &mut SaveData, session: &GameSession) {
+    let unlocked = unlocked_ships(save_data, session);
+    let type_ids = session
+        .player_ships()
+        .iter()
+        .zip(unlocked)
+        .filter(|(_, unlocked)| *unlocked)
+        .map(|(ship, _)| ship.type_id);
+    save_data.fleet_roster.enlist(type_ids);
+}
+
+/// A tired ship launches with thinner tank and slower engines
+fn apply_fatigue(
+    save_data: Res<SaveData>,
+    mut players: Query<(&mut ShipStats, &mut Movement), Added<Player>>,
+) {
+    for (mut stats, mut movement) in players.iter_mut() {
+        let (tank, speed) = save_data.fleet_roster.launch_mults(stats.type_id);
+        if tank >= 1.0 {
+            continue;
+        }
+        stats.max_shield *= tank;
+        stats.shield = stats.max_shield;
+        stats.max_armor *= tank;
+        stats.armor = stats.max_armor;
+        stats.max_hull *= tank;
+        stats.hull = stats.max_hull;
+        movement.max_speed *= speed;
+        debug!(
+            "{} launches at {:.0}% fatigue",
+            stats.name,
+            save_data.fleet_roster.fatigue(stats.type_id) * 100.0
+        );
+    }
+}
+
+fn record_sortie(session: Res<GameSession>, mut save_data: ResMut<SaveData>) {
+    enlist_unlocked(&mut save_data, &session);
+    let ship = session.selected_ship();
+    save_data.fleet_roster.fly_sortie(ship.type_id);
+    info!(
+        "{} back from its sortie at {:.0}% fatigue",
+        ship.name,
+        save_data.fleet_roster.fatigue(ship.type_id) * 100.0
+    );
+}
+
+/// Ironman: the ship that died is gone; the checkpoint carries on in the
+/// next one still flying, or ends with the fleet
+pub fn lose_flown_ship(
+    session: Res<GameSession>,
+    mut save_data: ResMut<SaveData>,
+    mut toasts: EventWriter<ToastEvent>,
+) {
+    let ship = session.selected_ship();
+    if !save_data.fleet_roster.lose(ship.type_id) {
+        return;
+    }
+    info!("Ironman: {} lost", ship.name);
+    toasts.send(ToastEvent::info(format!("SHIP LOST: {}", ship.name)));
+
+    let unlocked = unlocked_ships(&save_data, &session);
+    let next = save_data
+        .fleet_roster
+        .first_available(session.player_ships(), &unlocked);
+    match next {
+        Some(index) => {
+            if let Some(checkpoint) = save_data.checkpoint.as_mut() {
+                checkpoint.ship_index = index;
+            }
+        }
+        None => {
+            info!("Ironman: the whole fleet is lost");
+            toasts.send(ToastEvent::info("FLEET LOST - CAMPAIGN OVER"));
+            save_data.checkpoint = None;
+        }
+    }
+}
@@ -0,0 +1,246 @@
+//! Window Focus
+//!
+//! What the game does while its window is in the background: pause a run
+//! in progress, duck or mute the audio, and drop menus to a low frame rate.
+//! Each part can be turned off for streaming setups that capture the game
+//! while another window has focus.
+
+#![allow(dead_code)]
+
+use crate::core::*;
+use bevy::audio::AudioSink;
+use bevy::prelude::*;
+use bevy::utils::Duration;
+use bevy::window::WindowFocused;
+use bevy::winit::{UpdateMode, WinitSettings};
+use serde::{Deserialize, Serialize};
+
+/// Frame rate cap for menus while unfocused
+const MENU_BACKGROUND_FPS: f64 = 10.0;
+
+/// Frame rate cap for gameplay while unfocused (Bevy's default for games)
+const GAMEPLAY_BACKGROUND_FPS: f64 = 60.0;
+
+/// Audio level while ducked
+const DUCK_GAIN: f32 = 0.25;
+
+/// Window focus plugin
+pub struct FocusPlugin;
+
+impl Plugin for FocusPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FocusSettings>()
+            .init_resource::<WindowFocus>()
+            .init_resource::<WinitSettings>()
+            .add_systems(
+                PreUpdate,
+                (
+                    track_window_focus,
+                    pause_on_focus_loss
+                        .run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight))),
+                )
+                    .chain(),
+            )
+            .add_systems(Update, throttle_background_frames)
+            .add_systems(PostUpdate, duck_background_audio);
+    }
+}
+
+/// What happens to audio while unfocused
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BackgroundAudio {
+    /// Quieter, still audible
+    #[default]
+    Duck,
+    /// Silent
+    Mute,
+    /// Full volume
+    Keep,
+}
+
+impl BackgroundAudio {
+    pub const ALL: [BackgroundAudio; 3] = [
+        BackgroundAudio::Duck,
+        BackgroundAudio::Mute,
+        BackgroundAudio::Keep,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            BackgroundAudio::Duck => "DUCK",
+            BackgroundAudio::Mute => "MUTE",
+            BackgroundAudio::Keep => "FULL",
+        }
+    }
+
+    /// Volume multiplier while unfocused
+    pub fn gain(&self) -> f32 {
+        match self {
+            BackgroundAudio::Duck => DUCK_GAIN,
+            BackgroundAudio::Mute => 0.0,
+            BackgroundAudio::Keep => 1.0,
+        }
+    }
+
+    /// The next (or previous, for negative steps) option
+    pub fn stepped(&self, step: i32) -> Self {
+        let index = Self::ALL.iter().position(|a| a == self).unwrap_or(0) as i32;
+        Self::ALL[(index + step).rem_euclid(Self::ALL.len() as i32) as usize]
+    }
+}
+
+/// Background behavior settings (saved)
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct FocusSettings {
+    /// Pause a run when the window loses focus
+    pub auto_pause: bool,
+    /// Audio while unfocused
+    pub audio: BackgroundAudio,
+    /// Cap menus at a low frame rate while unfocused
+    pub throttle_menus: bool,
+}
+
+impl Default for FocusSettings {
+    fn default() -> Self {
+        Self {
+            auto_pause: true,
+            audio: BackgroundAudio::default(),
+            throttle_menus: true,
+        }
+    }
+}
+
+impl FocusSettings {
+    /// Keep everything running in the background, for capture setups
+    pub fn streaming() -> Self {
+        Self {
+            auto_pause: false,
+            audio: BackgroundAudio::Keep,
+            throttle_menus: false,
+        }
+    }
+}
+
+/// Whether the game window has focus
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowFocus {
+    pub focused: bool,
+}
+
+impl Default for WindowFocus {
+    fn default() -> Self {
+        Self { focused: true }
+    }
+}
+
+/// A sink's volume before ducking, and the ducked volume last set on it
+#[derive(Component, Debug, Clone, Copy)]
+struct FocusDucked {
+    base: f32,
+    set: f32,
+}
+
+/// Update mode while unfocused: menus idle along, gameplay keeps pace
+pub fn background_update_mode(settings: &FocusSettings, state: GameState) -> UpdateMode {
+    let in_action = matches!(
+        state,
+        GameState::Playing | GameState::BossIntro | GameState::BossFight
+    );
+    let fps = if settings.throttle_menus && !in_action {
+        MENU_BACKGROUND_FPS
+    } else {
+        GAMEPLAY_BACKGROUND_FPS
+    };
+    UpdateMode::reactive_low_power(Duration::from_secs_f64(1.0 / fps))
+}
+
+fn track_window_focus(mut events: EventReader<WindowFocused>, mut focus: ResMut<WindowFocus>) {
+    if let Some(event) = events.read().last() {
+        if focus.focused != event.focused {
+            focus.focused = event.focused;
+        }
+    }
+}
+
+/// Open the pause menu when the player tabs away mid-run
+fn pause_on_focus_loss(
+    settings: Res<FocusSettings>,
+    focus: Res<WindowFocus>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if focus.is_changed() && !focus.focused && settings.auto_pause {
+        info!("Window lost focus - pausing");
+        next_state.set(GameState::Paused);
+    }
+}
+
+fn throttle_background_frames(
+    settings: Res<FocusSettings>,
+    state: Res<State<GameState>>,
+    mut winit: ResMut<WinitSettings>,
+) {
+    if !settings.is_changed() && !state.is_changed() {
+        return;
+    }
+    winit.unfocused_mode = background_update_mode(&settings, *state.get());
+}
+
+/// Scale every playing sound while unfocused. Mixers that rewrite a sink's
+/// volume each frame are followed; sinks left alone keep their own level.
+fn duck_background_audio(
+    mut commands: Commands,
+    settings: Res<FocusSettings>,
+    focus: Res<WindowFocus>,
+    sinks: Query<(Entity, &AudioSink, Option<&FocusDucked>)>,
+) {
+    let gain = if focus.focused {
+        1.0
+    } else {
+        settings.audio.gain()
+    };
+
+    for (entity, sink, ducked) in sinks.iter() {
+        // A volume other than the one we set came from the sink's owner
+        let base = match ducked {
+            Some(ducked) if sink.volume() == ducked.set => ducked.base,
+            _ => sink.volume(),
+        };
+        if gain < 1.0 {
+            let set = base * gain;
+            sink.set_volume(set);
+            commands.entity(entity).insert(FocusDucked { base, set });
+        } else if ducked.is_some() {
+            sink.set_volume(base);
+            commands.entity(entity).remove::<FocusDucked>();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn menus_idle_in_the_background_unless_streaming() {
+        let settings = FocusSettings::default();
+        let menu = background_update_mode(&settings, GameState::Paused);
+        let action = background_update_mode(&settings, GameState::Playing);
+        let wait = |mode: UpdateMode| match mode {
+            UpdateMode::Reactive { wait, .. } => wait,
+            UpdateMode::Continuous => Duration::ZERO,
+        };
+        assert!(wait(menu) > wait(action));
+
+        let streaming = background_update_mode(&FocusSettings::streaming(), GameState::MainMenu);
+        assert_eq!(wait(streaming), wait(action));
+    }
+
+    #[test]
+    fn background_audio_steps_and_gains() {
+        assert_eq!(BackgroundAudio::Duck.stepped(1), BackgroundAudio::Mute);
+        assert_eq!(BackgroundAudio::Duck.stepped(-1), BackgroundAudio::Keep);
+        assert_eq!(BackgroundAudio::Mute.gain(), 0.0);
+        assert!(BackgroundAudio::Duck.gain() < BackgroundAudio::Keep.gain());
+    }
+}
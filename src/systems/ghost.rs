@@ -0,0 +1,443 @@
+//! Race Ghost
+//!
+//! Time Attack runs are raced against a translucent ghost of the pilot's
+//! best run on the same mission. The player's position is sampled at
+//! 20 Hz against the mission clock; a run that sets a new best time
+//! replaces the mission's ghost. The ghost can be switched off from the
+//! options menu.
+//!
+//! `GhostFilesPlugin` (a platform plugin) keeps one file per mission under
+//! `<data_dir>/eve_rebellion/ghosts/`, loading them at startup and pruning
+//! ghosts of missions that no longer exist and files that no longer parse.
+//! Native only; web builds keep ghosts for the session.
+
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::core::*;
+use crate::entities::Player;
+
+/// Position samples per second
+pub const GHOST_SAMPLE_RATE: f32 = 20.0;
+
+/// Longest run a ghost keeps (seconds); anything past it isn't recorded
+const MAX_GHOST_SECONDS: f32 = 15.0 * 60.0;
+
+/// Ghost opacity
+const GHOST_ALPHA: f32 = 0.35;
+
+/// Ghost tint, so it never reads as the player
+const GHOST_TINT: Color = Color::srgb(0.55, 0.85, 1.0);
+
+/// Race ghost plugin
+pub struct GhostPlugin;
+
+impl Plugin for GhostPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GhostSettings>()
+            .init_resource::<GhostLibrary>()
+            .init_resource::<GhostRecorder>()
+            .init_resource::<GhostPlayback>()
+            .add_systems(
+                Update,
+                (start_ghost_run, record_ghost, move_ghost)
+                    .chain()
+                    .in_set(GameSet::Ui)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(OnEnter(GameState::StageComplete), keep_best_ghost);
+    }
+}
+
+/// Reads and writes the ghost files
+pub struct GhostFilesPlugin;
+
+impl Plugin for GhostFilesPlugin {
+    fn build(&self, app: &mut App) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            app.add_systems(Startup, load_ghost_files).add_systems(
+                Update,
+                save_ghost_files.run_if(resource_changed::<GhostLibrary>),
+            );
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            let _ = app;
+        }
+    }
+}
+
+/// Whether Time Attack runs show the best run's ghost
+#[derive(Resource, Debug, Clone)]
+pub struct GhostSettings {
+    pub enabled: bool,
+}
+
+impl Default for GhostSettings {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+/// A recorded run: where the player was, every 1/20 s of the mission clock
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(default)]
+pub struct GhostRun {
+    pub mission_id: String,
+    /// Finishing time in milliseconds
+    pub time_ms: u64,
+    /// Ship flown, for the ghost's sprite
+    pub ship_type_id: u32,
+    pub samples: Vec<[f32; 2]>,
+}
+
+impl GhostRun {
+    /// Where the ghost was `seconds` into the mission, between samples;
+    /// `None` once its run is over
+    pub fn position_at(&self, seconds: f32) -> Option<Vec2> {
+        let at = seconds.max(0.0) * GHOST_SAMPLE_RATE;
+        let index = at.floor() as usize;
+        let from = Vec2::from(*self.samples.get(index)?);
+        let to = self
+            .samples
+            .get(index + 1)
+            .map_or(from, |sample| Vec2::from(*sample));
+        Some(from.lerp(to, at.fract()))
+    }
+
+    /// Whether this run should replace `best`
+    pub fn beats(&self, best: Option<&GhostRun>) -> bool {
+        best.is_none_or(|best| self.time_ms < best.time_ms)
+    }
+}
+
+/// Best run per mission
+#[derive(Resource, Debug, Default)]
+pub struct GhostLibrary {
+    pub runs: HashMap<String, GhostRun>,
+    /// Missions whose ghost changed since it was last written
+    pub unsaved: Vec<String>,
+}
+
+impl GhostLibrary {
+    pub fn best(&self, mission_id: &str) -> Option<&GhostRun> {
+        self.runs.get(mission_id)
+    }
+
+    /// Keep `run` if it beats the mission's ghost; returns whether it did
+    pub fn offer(&mut self, run: GhostRun) -> bool {
+        if !run.beats(self.best(&run.mission_id)) {
+            return false;
+        }
+        if !self.unsaved.contains(&run.mission_id) {
+            self.unsaved.push(run.mission_id.clone());
+        }
+        self.runs.insert(run.mission_id.clone(), run);
+        true
+    }
+}
+
+/// The run in progress, sampled as it's flown
+#[derive(Resource, Debug, Default)]
+pub struct GhostRecorder {
+    /// Recording this run (Time Attack only)
+    pub active: bool,
+    pub ship_type_id: u32,
+    pub samples: Vec<[f32; 2]>,
+}
+
+/// The best run on the current mission, if there is one
+#[derive(Resource, Debug, Default)]
+pub struct GhostPlayback(pub Option<GhostRun>);
+
+/// The ghost ship
+#[derive(Component, Debug)]
+#[require(StateScope)]
+pub struct Ghost;
+
+/// Mission ghost files that should go: unknown missions and unreadable files
+pub fn stale_ghost_files(files: &[(String, Option<GhostRun>)], missions: &[&str]) -> Vec<String> {
+    files
+        .iter()
+        .filter(|(name, run)| match run {
+            Some(run) => {
+                !missions.contains(&run.mission_id.as_str())
+                    || *name != format!("{}.json", run.mission_id)
+            }
+            None => true,
+        })
+        .map(|(name, _)| name.clone())
+        .collect()
+}
+
+/// Ids of every campaign mission
+fn mission_ids() -> Vec<&'static str> {
+    [Act::Act1, Act::Act2, Act::Act3]
+        .iter()
+        .flat_map(|act| act.missions().iter().map(|m| m.id))
+        .collect()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn ghost_dir() -> std::path::PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| std::path::PathBuf::from("."))
+        .join("eve_rebellion")
+        .join("ghosts")
+}
+
+/// Load every mission's ghost, pruning the ones nothing will race again
+#[cfg(not(target_arch = "wasm32"))]
+fn load_ghost_files(mut library: ResMut<GhostLibrary>) {
+    let dir = ghost_dir();
+    let Ok(entries) = std::fs::read_dir(&dir) else {
+        return;
+    };
+    let files: Vec<(String, Option<GhostRun>)> = entries
+        .filter_map(|e| e.ok())
+        .filter_map(|e| e.file_name().into_string().ok())
+        .map(|name| {
+            let run = std::fs::read_to_string(dir.join(&name))
+                .ok()
+                .and_then(|data| serde_json::from_str(&data).ok());
+            (name, run)
+        })
+        .collect();
+
+    let stale = stale_ghost_files(&files, &mission_ids());
+    for name in &stale {
+        info!("Pruning race ghost {}", name);
+        let _ = std::fs::remove_file(dir.join(name));
+    }
+    for (_, run) in files.into_iter().filter(|(name, _)| !stale.contains(name)) {
+        if let Some(run) = run {
+            library.runs.insert(run.mission_id.clone(), run);
+        }
+    }
+    info!("Loaded {} race ghosts", library.runs.len());
+}
+
+/// Write ghosts that changed
+#[cfg(not(target_arch = "wasm32"))]
+fn save_ghost_files(mut library: ResMut<GhostLibrary>) {
+    if library.unsaved.is_empty() {
+        return;
+    }
+    let dir = ghost_dir();
+    if let Err(e) = std::fs::create_dir_all(&dir) {
+        warn!("Failed to create ghost directory: {}", e);
+        return;
+    }
+    let library = library.as_mut();
+    for mission_id in library.unsaved.drain(..) {
+        let Some(run) = library.runs.get(&mission_id) else {
+            continue;
+        };
+        let result = serde_json::to_string(run)
+            .map_err(|e| e.to_string())
+            .and_then(|data| {
+                std::fs::write(dir.join(format!("{}.json", mission_id)), data)
+                    .map_err(|e| e.to_string())
+            });
+        match result {
+            Ok(()) => info!("Saved race ghost for {}", mission_id),
+            Err(e) => warn!("Failed to write race ghost for {}: {}", mission_id, e),
+        }
+    }
+}
+
+/// Load the mission's ghost and start recording when a Time Attack begins
+fn start_ghost_run(
+    mut commands: Commands,
+    mut events: EventReader<MissionStartEvent>,
+    run_mode: Res<RunMode>,
+    session: Res<GameSession>,
+    settings: Res<GhostSettings>,
+    library: Res<GhostLibrary>,
+    sprite_cache: Res<crate::assets::ShipSpriteCache>,
+    mut recorder: ResMut<GhostRecorder>,
+    mut playback: ResMut<GhostPlayback>,
+    ghosts: Query<Entity, With<Ghost>>,
+) {
+    let Some(event) = events.read().last() else {
+        return;
+    };
+    for ghost in ghosts.iter() {
+        commands.entity(ghost).despawn_recursive();
+    }
+
+    *recorder = GhostRecorder {
+        active: *run_mode == RunMode::TimeAttack,
+        ship_type_id: session.selected_ship().type_id,
+        samples: Vec::new(),
+    };
+    playback.0 = None;
+    if !recorder.active {
+        return;
+    }
+
+    playback.0 = library.best(event.mission.id).cloned();
+    let Some(best) = &playback.0 else {
+        return;
+    };
+    if !settings.enabled {
+        return;
+    }
+
+    // Sized like the player's own hull
+    let hull = Faction::all()
+        .iter()
+        .flat_map(|f| f.player_ships())
+        .find(|ship| ship.type_id == best.ship_type_id)
+        .map_or(ShipClass::Frigate, |ship| ship.class);
+    let size = Vec2::splat(hull.sprite_size() * PLAYER_SIZE_BONUS);
+    let rotation = crate::entities::get_ship_rotation_correction(best.ship_type_id);
+    let sprite = match sprite_cache.get(best.ship_type_id) {
+        Some(ship) => Sprite {
+            color: GHOST_TINT.with_alpha(GHOST_ALPHA),
+            custom_size: Some(size),
+            ..ship.sprite()
+        },
+        None => Sprite {
+            color: GHOST_TINT.with_alpha(GHOST_ALPHA * 0.6),
+            custom_size: Some(size * 0.5),
+            ..default()
+        },
+    };
+    commands.spawn((
+        Ghost,
+        sprite,
+        Transform::from_xyz(0.0, 0.0, LAYER_PLAYER - 1.0)
+            .with_rotation(Quat::from_rotation_z(rotation)),
+        Visibility::Hidden,
+    ));
+    info!(
+        "Racing the {} ghost ({})",
+        best.mission_id,
+        RunMode::TimeAttack.format_value(best.time_ms)
+    );
+}
+
+/// Sample the player against the mission clock
+fn record_ghost(
+    campaign: Res<CampaignState>,
+    mut recorder: ResMut<GhostRecorder>,
+    player: Query<&Transform, With<Player>>,
+) {
+    if !recorder.active || campaign.mission_timer > MAX_GHOST_SECONDS {
+        return;
+    }
+    let Ok(transform) = player.get_single() else {
+        return;
+    };
+    let due = (campaign.mission_timer * GHOST_SAMPLE_RATE) as usize + 1;
+    let position = transform.translation.truncate().to_array();
+    while recorder.samples.len() < due {
+        recorder.samples.push(position);
+    }
+}
+
+/// Fly the ghost along its run, gone once the run is over
+fn move_ghost(
+    campaign: Res<CampaignState>,
+    settings: Res<GhostSettings>,
+    playback: Res<GhostPlayback>,
+    mut ghosts: Query<(&mut Transform, &mut Visibility), With<Ghost>>,
+) {
+    for (mut transform, mut visibility) in ghosts.iter_mut() {
+        let position = playback
+            .0
+            .as_ref()
+            .and_then(|run| run.position_at(campaign.mission_timer))
+            .filter(|_| settings.enabled);
+        match position {
+            Some(position) => {
+                transform.translation = position.extend(transform.translation.z);
+                *visibility = Visibility::Visible;
+            }
+            None => *visibility = Visibility::Hidden,
+        }
+    }
+}
+
+/// A finished Time Attack that beat the mission's ghost becomes the new one
+fn keep_best_ghost(
+    result: Res<AttackResult>,
+    mut recorder: ResMut<GhostRecorder>,
+    mut library: ResMut<GhostLibrary>,
+) {
+    if !recorder.active || result.mode != RunMode::TimeAttack {
+        return;
+    }
+    recorder.active = false;
+
+    let run = GhostRun {
+        mission_id: result.mission_id.to_string(),
+        time_ms: result.value,
+        ship_type_id: recorder.ship_type_id,
+        samples: std::mem::take(&mut recorder.samples),
+    };
+    if library.offer(run) {
+        info!("New best ghost for {}", result.mission_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(time_ms: u64) -> GhostRun {
+        GhostRun {
+            mission_id: "m1_convoy_raid".to_string(),
+            time_ms,
+            ship_type_id: 587,
+            samples: vec![[0.0, 0.0], [10.0, 20.0], [20.0, 20.0]],
+        }
+    }
+
+    #[test]
+    fn the_ghost_moves_between_samples_and_stops_at_the_end() {
+        let ghost = run(1_000);
+        assert_eq!(ghost.position_at(0.0), Some(Vec2::ZERO));
+        let between = ghost.position_at(0.025).unwrap();
+        assert!(between.distance(Vec2::new(5.0, 10.0)) < 1e-3);
+        assert_eq!(ghost.position_at(0.1), Some(Vec2::new(20.0, 20.0)));
+        assert_eq!(ghost.position_at(0.15), None);
+    }
+
+    #[test]
+    fn only_faster_runs_replace_the_ghost() {
+        let mut library = GhostLibrary::default();
+        assert!(library.offer(run(60_000)));
+        assert!(!library.offer(run(61_000)));
+        assert!(library.offer(run(59_000)));
+        assert_eq!(
+            library.best("m1_convoy_raid").map(|r| r.time_ms),
+            Some(59_000)
+        );
+        // Written once, however many times it improved
+        assert_eq!(library.unsaved, vec!["m1_convoy_raid"]);
+    }
+
+    #[test]
+    fn ghosts_of_unknown_missions_and_broken_files_are_pruned() {
+        let files = vec![
+            ("m1_convoy_raid.json".to_string(), Some(run(1))),
+            ("m99_cut_mission.json".to_string(), {
+                let mut cut = run(1);
+                cut.mission_id = "m99_cut_mission".to_string();
+                Some(cut)
+            }),
+            ("notes.txt".to_string(), None),
+        ];
+        assert_eq!(
+            stale_ghost_files(&files, &["m1_convoy_raid"]),
+            vec!["m99_cut_mission.json", "notes.txt"]
+        );
+    }
+}
@@ -1,8 +1,13 @@
 //! Raw Linux Joystick Input
 //!
-//! Reads from /dev/input/js0 directly without needing libudev-dev.
+//! Reads from /dev/input/js* directly without needing libudev-dev.
 //! On non-Unix platforms, provides a no-op implementation.
 //!
+//! Pads are picked up and dropped as they're plugged in and pulled out.
+//! Each local player is assigned a pad - player one's is the one picked in
+//! the options menu, if it's connected - and `JoystickState` follows player
+//! one's. On-screen button prompts use the glyphs of that pad's family.
+//!
 //! Also provides rumble/haptic feedback via Bevy's gamepad system.
 
 #![allow(dead_code)]
@@ -11,8 +16,180 @@ use bevy::input::gamepad::{GamepadRumbleIntensity, GamepadRumbleRequest};
 use bevy::prelude::*;
 use std::time::Duration;
 
+/// Default left stick deadzone (overridden by `InputConfig::controller_deadzone`)
 const DEADZONE: f32 = 0.15;
 
+/// Local players a pad can be assigned to (co-op)
+pub const MAX_LOCAL_PLAYERS: usize = 2;
+
+/// Button family of a pad, for on-screen prompts
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PadGlyphs {
+    #[default]
+    Xbox,
+    PlayStation,
+    SteamDeck,
+}
+
+impl PadGlyphs {
+    /// Family of a pad from the name its driver reports
+    pub fn from_name(name: &str) -> Self {
+        let name = name.to_lowercase();
+        if name.contains("steam deck") || name.contains("valve") {
+            PadGlyphs::SteamDeck
+        } else if [
+            "sony",
+            "playstation",
+            "dualshock",
+            "dualsense",
+            "ps4",
+            "ps5",
+        ]
+        .iter()
+        .any(|hint| name.contains(hint))
+        {
+            PadGlyphs::PlayStation
+        } else {
+            PadGlyphs::Xbox
+        }
+    }
+
+    /// This family's name for an Xbox button label
+    pub fn button(&self, xbox: &str) -> Option<&'static str> {
+        let labels: [&'static str; 9] = match self {
+            PadGlyphs::Xbox => ["A", "B", "X", "Y", "LB", "RB", "LT", "RT", "START"],
+            PadGlyphs::PlayStation => [
+                "CROSS", "CIRCLE", "SQUARE", "TRIANGLE", "L1", "R1", "L2", "R2", "OPTIONS",
+            ],
+            PadGlyphs::SteamDeck => ["A", "B", "X", "Y", "L1", "R1", "L2", "R2", "MENU"],
+        };
+        ["A", "B", "X", "Y", "LB", "RB", "LT", "RT", "START"]
+            .iter()
+            .position(|b| *b == xbox)
+            .map(|i| labels[i])
+    }
+}
+
+/// A prompt written with Xbox buttons ("A/ENTER Select"), relabelled for
+/// `glyphs`. Only whole words are buttons.
+pub fn pad_prompt(template: &str, glyphs: PadGlyphs) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut word = String::new();
+    let flush = |word: &mut String, out: &mut String| {
+        out.push_str(glyphs.button(word).unwrap_or(word.as_str()));
+        word.clear();
+    };
+    for c in template.chars() {
+        if c.is_ascii_alphanumeric() {
+            word.push(c);
+        } else {
+            flush(&mut word, &mut out);
+            out.push(c);
+        }
+    }
+    flush(&mut word, &mut out);
+    out
+}
+
+/// A connected pad
+#[derive(Debug, Clone, PartialEq)]
+pub struct PadInfo {
+    /// Device number (js0 is 0)
+    pub id: u32,
+    pub name: String,
+    pub glyphs: PadGlyphs,
+}
+
+impl PadInfo {
+    pub fn new(id: u32, name: &str) -> Self {
+        Self {
+            id,
+            name: name.to_string(),
+            glyphs: PadGlyphs::from_name(name),
+        }
+    }
+}
+
+/// Pads connected right now, by device number
+#[derive(Resource, Debug, Default)]
+pub struct ConnectedPads(pub Vec<PadInfo>);
+
+/// Which pad each local player flies with
+#[derive(Resource, Debug, Default, Clone, PartialEq, Eq)]
+pub struct PadAssignment {
+    pub players: [Option<u32>; MAX_LOCAL_PLAYERS],
+    /// Player one's pad as picked in the options menu
+    pub preferred: Option<u32>,
+}
+
+impl PadAssignment {
+    /// Reassign after pads come and go: players keep a pad that's still
+    /// there, player one takes the picked pad, and anyone without a pad
+    /// gets the first free one
+    pub fn assign(&mut self, pads: &[PadInfo]) {
+        let connected = |id: &u32| pads.iter().any(|p| p.id == *id);
+        for slot in self.players.iter_mut() {
+            *slot = slot.filter(connected);
+        }
+        // Player one never goes without while someone else has a pad
+        if self.players[0].is_none() {
+            if let Some(other) = (1..MAX_LOCAL_PLAYERS).find(|i| self.players[*i].is_some()) {
+                self.players[0] = self.players[other].take();
+            }
+        }
+        if let Some(preferred) = self.preferred.filter(connected) {
+            if self.players[0] != Some(preferred) {
+                // Whoever had it swaps with player one
+                let previous = self.players[0];
+                for slot in self.players.iter_mut().skip(1) {
+                    if *slot == Some(preferred) {
+                        *slot = previous;
+                    }
+                }
+                self.players[0] = Some(preferred);
+            }
+        }
+        for player in 0..MAX_LOCAL_PLAYERS {
+            if self.players[player].is_none() {
+                self.players[player] = pads
+                    .iter()
+                    .map(|p| p.id)
+                    .find(|id| !self.players.contains(&Some(*id)));
+            }
+        }
+    }
+
+    /// Pick the next or previous connected pad for player one
+    pub fn step_player_one(&mut self, step: i32, pads: &[PadInfo]) {
+        if pads.is_empty() {
+            return;
+        }
+        let current = pads
+            .iter()
+            .position(|p| Some(p.id) == self.players[0])
+            .unwrap_or(0) as i32;
+        let next = (current + step).rem_euclid(pads.len() as i32) as usize;
+        self.preferred = Some(pads[next].id);
+        self.assign(pads);
+    }
+
+    /// Player one's pad, if they have one
+    pub fn player_one<'a>(&self, pads: &'a [PadInfo]) -> Option<&'a PadInfo> {
+        pads.iter().find(|p| Some(p.id) == self.players[0])
+    }
+}
+
+/// Options label for player one's pad
+pub fn controller_label(pads: &[PadInfo], assignment: &PadAssignment) -> String {
+    match assignment.player_one(pads) {
+        Some(pad) => {
+            let name: String = pad.name.trim().chars().take(24).collect();
+            format!("{}: {}", pad.id + 1, name)
+        }
+        None => "NONE".to_string(),
+    }
+}
+
 /// Rumble/haptic feedback settings
 #[derive(Resource, Debug)]
 pub struct RumbleSettings {
@@ -33,13 +210,30 @@ impl Plugin for JoystickPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<JoystickState>()
             .init_resource::<RumbleSettings>()
+            .init_resource::<ConnectedPads>()
+            .init_resource::<PadAssignment>()
+            .init_resource::<PadGlyphs>()
             .add_event::<RumbleRequest>()
-            .add_systems(Update, process_rumble_requests);
+            .add_systems(Update, process_rumble_requests)
+            .add_systems(
+                PreUpdate,
+                sync_deadzone.run_if(resource_changed::<crate::core::InputConfig>),
+            )
+            .add_systems(
+                PreUpdate,
+                (
+                    assign_pads.run_if(resource_changed::<ConnectedPads>),
+                    sync_pad_glyphs.run_if(resource_changed::<PadAssignment>),
+                )
+                    .chain(),
+            );
 
         #[cfg(unix)]
         {
-            app.add_systems(Startup, setup_joystick)
-                .add_systems(PreUpdate, poll_joystick);
+            app.init_resource::<JoystickHandle>().add_systems(
+                PreUpdate,
+                (scan_joysticks, poll_joystick).chain().before(assign_pads),
+            );
         }
 
         #[cfg(not(unix))]
@@ -54,14 +248,15 @@ impl Plugin for JoystickPlugin {
 /// - Left stick: movement
 /// - Right stick: aim AND fire (push to shoot in that direction)
 /// - RT (right trigger): special ability (FREE - was fire)
-/// - LT (left trigger): available
+/// - LT (left trigger): heat overload
 /// - A (button 0): context action / menu confirm
 /// - B (button 1): emergency burn / menu back
 /// - Y (button 3): formation switch
 /// - RB (button 5): barrel roll
 /// - LB (button 4): thrust
 /// - Start (button 7): pause
-#[derive(Resource, Default, Debug)]
+/// - Right stick click (button 10): show/hide HUD details
+#[derive(Resource, Debug, Clone)]
 pub struct JoystickState {
     /// Left stick X axis (-1.0 to 1.0)
     pub left_x: f32,
@@ -84,12 +279,39 @@ pub struct JoystickState {
     pub prev_dpad_y: i8,
     /// Previous analog stick Y for edge detection
     pub prev_left_y: f32,
+    /// Previous left trigger for edge detection
+    pub prev_left_trigger: f32,
     /// Buttons (indexed by button number) - current frame
     pub buttons: [bool; 16],
     /// Buttons from previous frame (for just_pressed detection)
     pub prev_buttons: [bool; 16],
     /// Whether joystick is connected
     pub connected: bool,
+    /// Left stick deadzone
+    pub deadzone: f32,
+}
+
+impl Default for JoystickState {
+    fn default() -> Self {
+        Self {
+            left_x: 0.0,
+            left_y: 0.0,
+            right_x: 0.0,
+            right_y: 0.0,
+            left_trigger: 0.0,
+            right_trigger: 0.0,
+            dpad_x: 0,
+            dpad_y: 0,
+            prev_dpad_x: 0,
+            prev_dpad_y: 0,
+            prev_left_y: 0.0,
+            prev_left_trigger: 0.0,
+            buttons: [false; 16],
+            prev_buttons: [false; 16],
+            connected: false,
+            deadzone: DEADZONE,
+        }
+    }
 }
 
 impl JoystickState {
@@ -131,10 +353,10 @@ impl JoystickState {
         let mut y = -self.left_y; // Invert Y for game coordinates
 
         // Apply deadzone
-        if x.abs() < DEADZONE {
+        if x.abs() < self.deadzone {
             x = 0.0;
         }
-        if y.abs() < DEADZONE {
+        if y.abs() < self.deadzone {
             y = 0.0;
         }
 
@@ -201,16 +423,38 @@ impl JoystickState {
         self.just_pressed(1)
     }
 
+    /// Check if back/view/select button just pressed (edge triggered)
+    pub fn select(&self) -> bool {
+        self.just_pressed(6)
+    }
+
     /// Check if start/menu button just pressed (edge triggered)
     pub fn start(&self) -> bool {
         self.just_pressed(7) || self.just_pressed(9) // Start or Menu
     }
 
+    /// Any button held, d-pad pushed or stick off centre
+    pub fn in_use(&self) -> bool {
+        self.buttons.iter().any(|b| *b)
+            || self.dpad_x != 0
+            || self.dpad_y != 0
+            || self.left_x.abs() > 0.5
+            || self.left_y.abs() > 0.5
+            || self.right_x.abs() > 0.5
+            || self.right_y.abs() > 0.5
+    }
+
     /// Check if left bumper pressed (LB - thrust) - held state
     pub fn left_bumper(&self) -> bool {
         self.buttons[4]
     }
 
+    /// Check if B button just pressed in flight (shield bubble) - edge triggered
+    /// Xbox: B (button 1), PlayStation: Circle
+    pub fn bubble(&self) -> bool {
+        self.just_pressed(1)
+    }
+
     /// Check if right bumper just pressed (RB - barrel roll) - edge triggered
     pub fn right_bumper(&self) -> bool {
         self.just_pressed(5)
@@ -236,10 +480,20 @@ impl JoystickState {
         self.just_pressed(3)
     }
 
+    /// Check if the right stick was just clicked in (HUD details) - edge triggered
+    pub fn right_stick_click(&self) -> bool {
+        self.just_pressed(10)
+    }
+
     /// Check if left trigger is pressed (held state)
     pub fn left_trigger_pressed(&self) -> bool {
         self.left_trigger > 0.1
     }
+
+    /// Check if left trigger was just pulled (heat overload) - edge triggered
+    pub fn left_trigger_just_pressed(&self) -> bool {
+        self.left_trigger > 0.5 && self.prev_left_trigger <= 0.5
+    }
 }
 
 // ============================================================================
@@ -317,6 +571,36 @@ impl RumbleRequest {
     }
 }
 
+/// Keep pad assignments in step with what's plugged in
+fn assign_pads(pads: Res<ConnectedPads>, mut assignment: ResMut<PadAssignment>) {
+    let mut next = assignment.clone();
+    next.assign(&pads.0);
+    // Only a real change should reach the prompts
+    if next != *assignment {
+        *assignment = next;
+    }
+}
+
+/// Prompts follow player one's pad; the last pad's style stays after it's
+/// unplugged
+fn sync_pad_glyphs(
+    pads: Res<ConnectedPads>,
+    assignment: Res<PadAssignment>,
+    mut glyphs: ResMut<PadGlyphs>,
+) {
+    if let Some(pad) = assignment.player_one(&pads.0) {
+        if *glyphs != pad.glyphs {
+            info!("Button prompts switched to {:?}", pad.glyphs);
+            *glyphs = pad.glyphs;
+        }
+    }
+}
+
+/// Apply the configured stick deadzone when input settings change
+fn sync_deadzone(config: Res<crate::core::InputConfig>, mut joystick: ResMut<JoystickState>) {
+    joystick.deadzone = config.controller_deadzone.clamp(0.0, 0.9);
+}
+
 /// System to process rumble requests and send to Bevy's gamepad system
 fn process_rumble_requests(
     mut rumble_events: EventReader<RumbleRequest>,
@@ -361,12 +645,25 @@ mod unix_impl {
     use std::io::Read;
     use std::os::unix::io::AsRawFd;
 
-    const JOYSTICK_DEVICE: &str = "/dev/input/js0";
+    /// Device numbers checked for pads (/dev/input/js0 to js7)
+    const JOYSTICK_DEVICES: u32 = 8;
 
-    /// Joystick file handle resource
+    /// Seconds between looks for newly plugged-in pads
+    const HOTPLUG_SCAN_INTERVAL: f32 = 2.0;
+
+    /// An open pad and its own input state
+    struct OpenPad {
+        info: PadInfo,
+        file: File,
+        state: JoystickState,
+    }
+
+    /// Joystick file handles resource
     #[derive(Resource, Default)]
     pub struct JoystickHandle {
-        file: Option<File>,
+        pads: Vec<OpenPad>,
+        /// Seconds until the next hot-plug scan
+        scan_timer: f32,
     }
 
     /// Linux joystick event structure
@@ -382,118 +679,235 @@ mod unix_impl {
     const JS_EVENT_AXIS: u8 = 0x02;
     const JS_EVENT_INIT: u8 = 0x80;
 
-    pub fn setup_joystick(mut commands: Commands, mut state: ResMut<JoystickState>) {
-        match File::open(JOYSTICK_DEVICE) {
-            Ok(file) => {
-                // Set non-blocking mode
-                // SAFETY: file is a valid open file descriptor obtained from File::open().
-                // fcntl with F_GETFL/F_SETFL is safe on valid file descriptors.
-                // The file handle remains valid for the lifetime of this resource.
-                unsafe {
-                    let fd = file.as_raw_fd();
-                    let flags = libc::fcntl(fd, libc::F_GETFL);
-                    libc::fcntl(fd, libc::F_SETFL, flags | libc::O_NONBLOCK);
-                }
+    /// JSIOCGNAME(len): _IOC(_IOC_READ, 'j', 0x13, len)
+    fn jsiocgname(len: usize) -> u64 {
+        (2 << 30) | ((len as u64) << 16) | ((b'j' as u64) << 8) | 0x13
+    }
+
+    fn open_pad(id: u32) -> Option<OpenPad> {
+        let path = format!("/dev/input/js{}", id);
+        let file = File::open(&path).ok()?;
+        let mut name = [0u8; 128];
+        // SAFETY: file is a valid open file descriptor obtained from File::open().
+        // fcntl with F_GETFL/F_SETFL is safe on valid file descriptors, and
+        // JSIOCGNAME writes at most `name.len()` bytes into the buffer.
+        // The file handle remains valid for the lifetime of this resource.
+        unsafe {
+            let fd = file.as_raw_fd();
+            let flags = libc::fcntl(fd, libc::F_GETFL);
+            libc::fcntl(fd, libc::F_SETFL, flags | libc::O_NONBLOCK);
+            libc::ioctl(fd, jsiocgname(name.len()) as _, name.as_mut_ptr());
+        }
+        let end = name.iter().position(|b| *b == 0).unwrap_or(name.len());
+        let name = String::from_utf8_lossy(&name[..end]);
+        let name = if name.trim().is_empty() {
+            format!("Joystick {}", id + 1)
+        } else {
+            name.trim().to_string()
+        };
+
+        info!("Joystick connected: {} ({})", path, name);
+        Some(OpenPad {
+            info: PadInfo::new(id, &name),
+            file,
+            state: JoystickState {
+                connected: true,
+                ..default()
+            },
+        })
+    }
+
+    /// Open pads that have been plugged in since the last look
+    pub fn scan_joysticks(
+        time: Res<Time>,
+        mut handle: ResMut<JoystickHandle>,
+        mut connected: ResMut<ConnectedPads>,
+    ) {
+        handle.scan_timer -= time.delta_secs();
+        if handle.scan_timer > 0.0 {
+            return;
+        }
+        handle.scan_timer = HOTPLUG_SCAN_INTERVAL;
 
-                info!("Joystick connected: {}", JOYSTICK_DEVICE);
-                state.connected = true;
-                commands.insert_resource(JoystickHandle { file: Some(file) });
+        let mut added = false;
+        for id in 0..JOYSTICK_DEVICES {
+            if handle.pads.iter().any(|p| p.info.id == id) {
+                continue;
             }
-            Err(e) => {
-                info!("No joystick found: {} ({})", JOYSTICK_DEVICE, e);
-                commands.insert_resource(JoystickHandle::default());
+            if let Some(pad) = open_pad(id) {
+                handle.pads.push(pad);
+                added = true;
             }
         }
+        if added {
+            handle.pads.sort_by_key(|p| p.info.id);
+            connected.0 = handle.pads.iter().map(|p| p.info.clone()).collect();
+        }
     }
 
-    pub fn poll_joystick(mut handle: ResMut<JoystickHandle>, mut state: ResMut<JoystickState>) {
-        // Save previous state for edge detection
-        state.prev_buttons = state.buttons;
-        state.prev_dpad_x = state.dpad_x;
-        state.prev_dpad_y = state.dpad_y;
-        state.prev_left_y = state.left_y;
+    /// Apply one joystick event to a pad's state
+    fn apply_event(state: &mut JoystickState, event: &JsEvent) {
+        let event_type = event.event_type & !JS_EVENT_INIT;
 
-        let Some(ref mut file) = handle.file else {
-            return;
-        };
-
-        // Read all pending events
-        let mut buffer = [0u8; 8];
-        loop {
-            match file.read_exact(&mut buffer) {
-                Ok(_) => {
-                    // Parse event
-                    // SAFETY: buffer is exactly 8 bytes (size of JsEvent struct).
-                    // JsEvent is repr(C) with known layout matching Linux joystick API.
-                    // read_exact ensures buffer is fully populated before we read it.
-                    let event = unsafe { std::ptr::read(buffer.as_ptr() as *const JsEvent) };
-
-                    let event_type = event.event_type & !JS_EVENT_INIT;
-
-                    match event_type {
-                        JS_EVENT_BUTTON => {
-                            let pressed = event.value != 0;
-                            let button = event.number as usize;
-                            if button < 16 {
-                                if pressed {
-                                    info!("Joystick button {} pressed", button);
-                                }
-                                state.buttons[button] = pressed;
-                            }
+        match event_type {
+            JS_EVENT_BUTTON => {
+                let pressed = event.value != 0;
+                let button = event.number as usize;
+                if button < 16 {
+                    if pressed {
+                        info!("Joystick button {} pressed", button);
+                    }
+                    state.buttons[button] = pressed;
+                }
+            }
+            JS_EVENT_AXIS => {
+                let value = event.value as f32 / 32767.0;
+                match event.number {
+                    // Left stick
+                    0 => state.left_x = value,
+                    1 => state.left_y = value,
+                    // Left trigger (LT) - axis 2
+                    // Triggers go from -1 (released) to +1 (pressed), normalize to 0-1
+                    2 => state.left_trigger = (value + 1.0) / 2.0,
+                    // Right stick
+                    3 => state.right_x = value,
+                    4 => state.right_y = value,
+                    // Right trigger (RT) - axis 5
+                    5 => state.right_trigger = (value + 1.0) / 2.0,
+                    // D-pad as axes
+                    6 => {
+                        state.dpad_x = if value < -0.5 {
+                            -1
+                        } else if value > 0.5 {
+                            1
+                        } else {
+                            0
                         }
-                        JS_EVENT_AXIS => {
-                            let value = event.value as f32 / 32767.0;
-                            match event.number {
-                                // Left stick
-                                0 => state.left_x = value,
-                                1 => state.left_y = value,
-                                // Left trigger (LT) - axis 2
-                                // Triggers go from -1 (released) to +1 (pressed), normalize to 0-1
-                                2 => state.left_trigger = (value + 1.0) / 2.0,
-                                // Right stick
-                                3 => state.right_x = value,
-                                4 => state.right_y = value,
-                                // Right trigger (RT) - axis 5
-                                5 => state.right_trigger = (value + 1.0) / 2.0,
-                                // D-pad as axes
-                                6 => {
-                                    state.dpad_x = if value < -0.5 {
-                                        -1
-                                    } else if value > 0.5 {
-                                        1
-                                    } else {
-                                        0
-                                    }
-                                }
-                                7 => {
-                                    state.dpad_y = if value < -0.5 {
-                                        -1
-                                    } else if value > 0.5 {
-                                        1
-                                    } else {
-                                        0
-                                    }
-                                }
-                                _ => {}
-                            }
+                    }
+                    7 => {
+                        state.dpad_y = if value < -0.5 {
+                            -1
+                        } else if value > 0.5 {
+                            1
+                        } else {
+                            0
                         }
-                        _ => {}
                     }
+                    _ => {}
                 }
-                Err(ref e) if e.kind() == std::io::ErrorKind::WouldBlock => {
-                    // No more events
-                    break;
-                }
-                Err(_) => {
-                    // Device disconnected
-                    state.connected = false;
-                    handle.file = None;
-                    break;
+            }
+            _ => {}
+        }
+    }
+
+    /// Read every pad, drop the ones pulled out, and hand player one's
+    /// input to `JoystickState`
+    pub fn poll_joystick(
+        mut handle: ResMut<JoystickHandle>,
+        mut connected: ResMut<ConnectedPads>,
+        assignment: Res<PadAssignment>,
+        mut state: ResMut<JoystickState>,
+    ) {
+        let mut removed = false;
+        handle.pads.retain_mut(|pad| {
+            let state = &mut pad.state;
+            // Save previous state for edge detection
+            state.prev_buttons = state.buttons;
+            state.prev_dpad_x = state.dpad_x;
+            state.prev_dpad_y = state.dpad_y;
+            state.prev_left_y = state.left_y;
+            state.prev_left_trigger = state.left_trigger;
+
+            // Read all pending events
+            let mut buffer = [0u8; 8];
+            loop {
+                match pad.file.read_exact(&mut buffer) {
+                    Ok(_) => {
+                        // SAFETY: buffer is exactly 8 bytes (size of JsEvent struct).
+                        // JsEvent is repr(C) with known layout matching Linux joystick API.
+                        // read_exact ensures buffer is fully populated before we read it.
+                        let event = unsafe { std::ptr::read(buffer.as_ptr() as *const JsEvent) };
+                        apply_event(state, &event);
+                    }
+                    Err(ref e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                        // No more events
+                        return true;
+                    }
+                    Err(_) => {
+                        info!("Joystick disconnected: {}", pad.info.name);
+                        removed = true;
+                        return false;
+                    }
                 }
             }
+        });
+        if removed {
+            connected.0 = handle.pads.iter().map(|p| p.info.clone()).collect();
         }
+
+        // Player one's pad drives the game; the deadzone is a setting, not
+        // part of the pad
+        let deadzone = state.deadzone;
+        *state = handle
+            .pads
+            .iter()
+            .find(|p| Some(p.info.id) == assignment.players[0])
+            .map(|p| p.state.clone())
+            .unwrap_or_default();
+        state.deadzone = deadzone;
     }
 }
 
 #[cfg(unix)]
-use unix_impl::{poll_joystick, setup_joystick};
+use unix_impl::{poll_joystick, scan_joysticks, JoystickHandle};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prompts_follow_the_pad_family() {
+        assert_eq!(
+            PadGlyphs::from_name("Sony Interactive Entertainment Wireless Controller"),
+            PadGlyphs::PlayStation
+        );
+        assert_eq!(PadGlyphs::from_name("Steam Deck"), PadGlyphs::SteamDeck);
+        assert_eq!(
+            PadGlyphs::from_name("Microsoft X-Box 360 pad"),
+            PadGlyphs::Xbox
+        );
+
+        let prompt = "↑↓ Navigate • A/ENTER Select • B/ESC Back • TAB/Y Skin";
+        assert_eq!(pad_prompt(prompt, PadGlyphs::Xbox), prompt);
+        assert_eq!(
+            pad_prompt(prompt, PadGlyphs::PlayStation),
+            "↑↓ Navigate • CROSS/ENTER Select • CIRCLE/ESC Back • TAB/TRIANGLE Skin"
+        );
+        // Only whole words are buttons
+        assert_eq!(
+            pad_prompt("BACK Stage A1", PadGlyphs::PlayStation),
+            "BACK Stage A1"
+        );
+    }
+
+    #[test]
+    fn players_keep_their_pads_and_player_one_gets_the_picked_one() {
+        let pads = vec![
+            PadInfo::new(0, "Xbox Wireless Controller"),
+            PadInfo::new(1, "DualSense Wireless Controller"),
+        ];
+        let mut assignment = PadAssignment::default();
+        assignment.assign(&pads);
+        assert_eq!(assignment.players, [Some(0), Some(1)]);
+
+        // Picking the other pad swaps the players over
+        assignment.step_player_one(1, &pads);
+        assert_eq!(assignment.players, [Some(1), Some(0)]);
+
+        // Player one's pad pulled out: they take the free one until it's back
+        assignment.assign(&pads[..1]);
+        assert_eq!(assignment.players, [Some(0), None]);
+        assignment.assign(&pads);
+        assert_eq!(assignment.players, [Some(1), Some(0)]);
+    }
+}
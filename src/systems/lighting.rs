@@ -0,0 +1,326 @@
+//! Scene Lighting Systems
+//!
+//! Drives the key light, the ambient fill and a faint wash of the key
+//! light's color over the playfield from the mission's lighting profile
+//! (see `core::lighting`). A mission fades up from near-dark as it starts,
+//! later changes of profile blend over a second, and large explosions
+//! flash the light toward their color. Reduced motion tones the flashes
+//! down.
+
+#![allow(dead_code)]
+
+use super::effects::MotionSettings;
+use crate::core::*;
+use bevy::prelude::*;
+
+/// Seconds a mission takes to fade up from near-dark
+pub const MISSION_FADE: f32 = 2.0;
+
+/// Seconds to blend to a new profile mid-mission
+const RETARGET_BLEND: f32 = 1.0;
+
+/// Flash strength lost per second
+const FLASH_DECAY: f32 = 3.0;
+
+/// Extra key light at full flash (multiple of the profile's)
+const FLASH_BOOST: f32 = 3.0;
+
+/// Extra wash alpha at full flash
+const FLASH_WASH: f32 = 0.25;
+
+/// Flash strength kept with reduced motion
+const REDUCED_FLASH: f32 = 0.3;
+
+/// Scene lighting plugin
+pub struct LightingPlugin;
+
+impl Plugin for LightingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SceneLighting>()
+            .add_systems(Startup, spawn_key_light)
+            .add_systems(
+                Update,
+                (
+                    fade_in_on_mission_start,
+                    follow_scene_lighting.run_if(
+                        resource_changed::<CampaignState>.or(resource_changed::<GameSession>),
+                    ),
+                    flash_on_explosions,
+                    advance_scene_lighting,
+                    apply_key_light,
+                    update_light_wash,
+                )
+                    .chain()
+                    .in_set(GameSet::Ui)
+                    .run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight))),
+            )
+            .add_systems(
+                OnEnter(GameState::MainMenu),
+                (reset_scene_lighting, apply_key_light).chain(),
+            );
+    }
+}
+
+/// The lighting being shown and where it's heading
+#[derive(Resource, Debug, Clone)]
+pub struct SceneLighting {
+    /// Profile the running transition started from
+    pub from: LightingProfile,
+    /// Profile it ends on
+    pub target: LightingProfile,
+    /// Seconds into the transition
+    pub elapsed: f32,
+    /// Transition length (0 = already there)
+    pub duration: f32,
+    /// Explosion flash, 0.0 - 1.0, fading
+    pub flash: f32,
+    pub flash_color: Color,
+}
+
+impl Default for SceneLighting {
+    fn default() -> Self {
+        Self {
+            from: LightingProfile::NEUTRAL,
+            target: LightingProfile::NEUTRAL,
+            elapsed: 0.0,
+            duration: 0.0,
+            flash: 0.0,
+            flash_color: Color::WHITE,
+        }
+    }
+}
+
+impl SceneLighting {
+    /// The profile right now, eased along the transition
+    pub fn current(&self) -> LightingProfile {
+        if self.duration <= 0.0 {
+            return self.target;
+        }
+        let t = (self.elapsed / self.duration).clamp(0.0, 1.0);
+        self.from.lerp(&self.target, t * t * (3.0 - 2.0 * t))
+    }
+
+    pub fn transitioning(&self) -> bool {
+        self.elapsed < self.duration
+    }
+
+    /// Blend from wherever the light is now to `target`
+    pub fn blend_to(&mut self, target: LightingProfile, duration: f32) {
+        if target == self.target {
+            return;
+        }
+        self.from = self.current();
+        self.target = target;
+        self.elapsed = 0.0;
+        self.duration = duration;
+    }
+
+    /// Fade up from near-dark to `target`
+    pub fn fade_in(&mut self, target: LightingProfile) {
+        self.from = LightingProfile::WARP_IN;
+        self.target = target;
+        self.elapsed = 0.0;
+        self.duration = MISSION_FADE;
+    }
+
+    /// Flash toward `color`; a weaker flash doesn't cut a stronger one short
+    pub fn flash(&mut self, strength: f32, color: Color) {
+        if strength >= self.flash {
+            self.flash = strength.min(1.0);
+            self.flash_color = color;
+        }
+    }
+}
+
+/// How hard an explosion flashes the light (only the big ones do)
+pub fn flash_strength(size: ExplosionSize) -> f32 {
+    match size {
+        ExplosionSize::Large => 0.45,
+        ExplosionSize::Massive => 1.0,
+        _ => 0.0,
+    }
+}
+
+/// Direction the key light shines for an angle off straight down the screen
+pub fn key_light_direction(angle_degrees: f32) -> Vec3 {
+    let angle = angle_degrees.to_radians();
+    Vec3::new(angle.sin(), -angle.cos(), -1.0).normalize()
+}
+
+/// The scene's key light
+#[derive(Component)]
+pub struct KeyLight;
+
+/// Key light color over the playfield
+#[derive(Component)]
+#[require(StateScope)]
+pub struct LightWash;
+
+fn spawn_key_light(mut commands: Commands) {
+    let profile = LightingProfile::NEUTRAL;
+    commands.spawn((
+        KeyLight,
+        DirectionalLight {
+            color: profile.key(),
+            illuminance: profile.key_lux,
+            ..default()
+        },
+        Transform::default().looking_to(key_light_direction(profile.key_angle), Vec3::Y),
+    ));
+}
+
+fn fade_in_on_mission_start(
+    mut events: EventReader<MissionStartEvent>,
+    campaign: Res<CampaignState>,
+    session: Res<GameSession>,
+    mut lighting: ResMut<SceneLighting>,
+) {
+    if events.read().last().is_some() {
+        lighting.fade_in(scene_lighting(&campaign, session.enemy_faction));
+    }
+}
+
+/// Pick up new act, mission or override lighting
+fn follow_scene_lighting(
+    campaign: Res<CampaignState>,
+    session: Res<GameSession>,
+    mut lighting: ResMut<SceneLighting>,
+) {
+    let target = scene_lighting(&campaign, session.enemy_faction);
+    if target != lighting.target {
+        lighting.blend_to(target, RETARGET_BLEND);
+    }
+}
+
+fn flash_on_explosions(
+    mut events: EventReader<ExplosionEvent>,
+    motion: Res<MotionSettings>,
+    mut lighting: ResMut<SceneLighting>,
+) {
+    for event in events.read() {
+        let mut strength = flash_strength(event.size);
+        if strength <= 0.0 {
+            continue;
+        }
+        if motion.reduced_motion {
+            strength *= REDUCED_FLASH;
+        }
+        lighting.flash(strength, event.color);
+    }
+}
+
+fn advance_scene_lighting(time: Res<Time>, mut lighting: ResMut<SceneLighting>) {
+    let dt = time.delta_secs();
+    if lighting.transitioning() {
+        lighting.elapsed += dt;
+    }
+    if lighting.flash > 0.0 {
+        lighting.flash = (lighting.flash - FLASH_DECAY * dt).max(0.0);
+    }
+}
+
+fn apply_key_light(
+    lighting: Res<SceneLighting>,
+    ambient: Option<ResMut<AmbientLight>>,
+    mut lights: Query<(&mut DirectionalLight, &mut Transform), With<KeyLight>>,
+) {
+    let profile = lighting.current();
+    let flash = lighting.flash;
+    let key = profile.key().mix(&lighting.flash_color, flash * 0.6);
+
+    for (mut light, mut transform) in lights.iter_mut() {
+        light.color = key;
+        light.illuminance = profile.key_lux * (1.0 + FLASH_BOOST * flash);
+        transform.look_to(key_light_direction(profile.key_angle), Vec3::Y);
+    }
+    if let Some(mut ambient) = ambient {
+        ambient.color = profile.ambient();
+        ambient.brightness = profile.ambient_brightness * (1.0 + flash);
+    }
+}
+
+/// Tint the playfield with the key light, brighter through a flash
+fn update_light_wash(
+    mut commands: Commands,
+    lighting: Res<SceneLighting>,
+    playfield: Res<Playfield>,
+    mut wash_query: Query<(Entity, &mut Sprite), With<LightWash>>,
+) {
+    let profile = lighting.current();
+    let flash = lighting.flash;
+    let alpha = profile.wash + FLASH_WASH * flash;
+    if alpha <= 0.001 {
+        for (entity, _) in wash_query.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+    let color = profile
+        .key()
+        .mix(&lighting.flash_color, flash)
+        .with_alpha(alpha);
+
+    if let Ok((_, mut sprite)) = wash_query.get_single_mut() {
+        sprite.color = color;
+        sprite.custom_size = Some(playfield.size + 100.0);
+    } else {
+        commands.spawn((
+            LightWash,
+            Sprite {
+                color,
+                custom_size: Some(playfield.size + 100.0),
+                ..default()
+            },
+            Transform::from_xyz(0.0, 0.0, LAYER_EFFECTS + 5.0), // Over the action, under the HUD
+        ));
+    }
+}
+
+/// Menus go back to plain light
+fn reset_scene_lighting(mut lighting: ResMut<SceneLighting>) {
+    *lighting = SceneLighting::default();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transitions_ease_from_the_current_light() {
+        let gold = act_lighting(Act::Act3);
+        let mut lighting = SceneLighting::default();
+        lighting.fade_in(gold);
+        assert_eq!(lighting.current(), LightingProfile::WARP_IN);
+
+        lighting.elapsed = MISSION_FADE / 2.0;
+        let mid = lighting.current();
+        assert!(mid.key_lux > LightingProfile::WARP_IN.key_lux && mid.key_lux < gold.key_lux);
+
+        // Retargeting mid-fade starts from where the light is
+        let blue = faction_lighting(Faction::Caldari);
+        lighting.blend_to(blue, 1.0);
+        assert_eq!(lighting.current(), mid);
+        lighting.elapsed = 1.0;
+        assert_eq!(lighting.current(), blue);
+        assert!(!lighting.transitioning());
+    }
+
+    #[test]
+    fn only_big_explosions_flash_and_small_ones_dont_cut_them_short() {
+        assert_eq!(flash_strength(ExplosionSize::Small), 0.0);
+        assert!(flash_strength(ExplosionSize::Massive) > flash_strength(ExplosionSize::Large));
+
+        let mut lighting = SceneLighting::default();
+        lighting.flash(1.0, Color::WHITE);
+        lighting.flash(0.45, Color::BLACK);
+        assert_eq!(lighting.flash, 1.0);
+        assert_eq!(lighting.flash_color, Color::WHITE);
+    }
+
+    #[test]
+    fn key_light_points_down_the_screen_and_into_it() {
+        let dir = key_light_direction(0.0);
+        assert!(dir.y < 0.0 && dir.z < 0.0 && dir.x.abs() < 1e-6);
+        assert!(key_light_direction(30.0).x > 0.0);
+    }
+}
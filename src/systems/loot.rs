@@ -0,0 +1,453 @@
+//! Loot Tables
+//!
+//! What a destroyed ship drops comes from its class's table: a drop chance
+//! scaled by difficulty, weighted entries, and for bosses guaranteed items on
+//! top of the roll. A repair entry turns into whichever repair the player
+//! needs most. A pity timer guarantees a repair when the player is hurting
+//! and hasn't seen one drop for a while.
+
+#![allow(dead_code)]
+
+use super::boss::BossDefeatedEvent;
+use crate::assets::PowerupIconCache;
+use crate::core::*;
+use crate::entities::collectible::{spawn_collectible, PlayerHealthState};
+use crate::entities::{Player, ShipStats};
+use bevy::prelude::*;
+
+/// Seconds without a repair drop before the pity timer can fire
+pub const PITY_WINDOW: f32 = 20.0;
+/// Armor or hull fraction below which the player counts as hurting
+pub const PITY_HEALTH: f32 = 0.4;
+/// Spacing between items dropped by the same kill
+const DROP_SPREAD: f32 = 24.0;
+
+/// Loot plugin
+pub struct LootPlugin;
+
+impl Plugin for LootPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LootState>()
+            .add_systems(
+                Update,
+                reset_loot_state
+                    .in_set(GameSet::Input)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                tick_pity_timer
+                    .in_set(GameSet::Simulation)
+                    .run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight))),
+            )
+            .add_systems(
+                Update,
+                drop_boss_loot
+                    .in_set(GameSet::Scoring)
+                    .run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight))),
+            );
+    }
+}
+
+/// Which table a destroyed ship rolls on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LootClass {
+    /// Frigates and interceptors
+    Light,
+    /// Destroyers and cruisers
+    Medium,
+    /// Battlecruisers and battleships
+    Heavy,
+    Boss,
+}
+
+impl LootClass {
+    pub fn for_enemy(type_id: u32, is_boss: bool) -> Self {
+        if is_boss {
+            return LootClass::Boss;
+        }
+        match Faction::find_enemy_def(type_id).map(|def| def.class) {
+            Some(ShipClass::Destroyer | ShipClass::TacticalDestroyer | ShipClass::Cruiser) => {
+                LootClass::Medium
+            }
+            Some(ShipClass::Battlecruiser | ShipClass::Battleship) => LootClass::Heavy,
+            _ => LootClass::Light,
+        }
+    }
+
+    pub fn table(&self) -> &'static LootTable {
+        match self {
+            LootClass::Light => &LIGHT_LOOT,
+            LootClass::Medium => &MEDIUM_LOOT,
+            LootClass::Heavy => &HEAVY_LOOT,
+            LootClass::Boss => &BOSS_LOOT,
+        }
+    }
+}
+
+/// One thing a table can drop
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LootItem {
+    /// Whichever repair the player needs most
+    Repair,
+    Item(CollectibleType),
+}
+
+impl LootItem {
+    /// The collectible to spawn for this drop
+    pub fn resolve(
+        &self,
+        health: Option<PlayerHealthState>,
+        rng: &mut fastrand::Rng,
+    ) -> CollectibleType {
+        match self {
+            LootItem::Repair => health
+                .unwrap_or(PlayerHealthState {
+                    shield_percent: 1.0,
+                    armor_percent: 1.0,
+                    hull_percent: 1.0,
+                })
+                .most_needed_health(rng),
+            LootItem::Item(kind) => *kind,
+        }
+    }
+}
+
+/// A weighted entry in a loot table
+#[derive(Debug, Clone, Copy)]
+pub struct LootEntry {
+    pub item: LootItem,
+    pub weight: u32,
+}
+
+const fn repair(weight: u32) -> LootEntry {
+    LootEntry {
+        item: LootItem::Repair,
+        weight,
+    }
+}
+
+const fn item(kind: CollectibleType, weight: u32) -> LootEntry {
+    LootEntry {
+        item: LootItem::Item(kind),
+        weight,
+    }
+}
+
+/// Drop table for one class of ship
+#[derive(Debug)]
+pub struct LootTable {
+    /// Chance of a roll on Newbro (scaled by difficulty)
+    pub drop_chance: f32,
+    /// Dropped on every kill, on top of the roll
+    pub guaranteed: &'static [LootItem],
+    pub entries: &'static [LootEntry],
+}
+
+impl LootTable {
+    /// Weighted pick from the entries
+    pub fn pick(&self, rng: &mut fastrand::Rng) -> LootItem {
+        let total: u32 = self.entries.iter().map(|e| e.weight).sum();
+        let mut roll = rng.u32(0..total);
+        for entry in self.entries {
+            if roll < entry.weight {
+                return entry.item;
+            }
+            roll -= entry.weight;
+        }
+        self.entries[0].item
+    }
+
+    /// Everything a kill drops; `pity` adds a repair if the roll had none
+    pub fn roll(
+        &self,
+        difficulty: Difficulty,
+        pity: bool,
+        rng: &mut fastrand::Rng,
+    ) -> Vec<LootItem> {
+        self.roll_scaled(difficulty.loot_drop_mult(), pity, rng)
+    }
+
+    /// `roll` with the drop chance scaled by `drop_mult`
+    pub fn roll_scaled(
+        &self,
+        drop_mult: f32,
+        pity: bool,
+        rng: &mut fastrand::Rng,
+    ) -> Vec<LootItem> {
+        let mut drops = self.guaranteed.to_vec();
+        if rng.f32() < self.drop_chance * drop_mult {
+            drops.push(self.pick(rng));
+        }
+        if pity && !drops.contains(&LootItem::Repair) {
+            drops.push(LootItem::Repair);
+        }
+        drops
+    }
+}
+
+pub const LIGHT_LOOT: LootTable = LootTable {
+    drop_chance: 0.25,
+    guaranteed: &[],
+    entries: &[
+        item(CollectibleType::Credits, 30),
+        repair(40),
+        item(CollectibleType::Overdrive, 10),
+        item(CollectibleType::DamageBoost, 10),
+        item(CollectibleType::Nanite, 4),
+        item(CollectibleType::TractorBeam, 3),
+        item(CollectibleType::Invulnerability, 2),
+        item(CollectibleType::ExtraLife, 1),
+    ],
+};
+
+pub const MEDIUM_LOOT: LootTable = LootTable {
+    drop_chance: 0.45,
+    guaranteed: &[],
+    entries: &[
+        item(CollectibleType::Credits, 25),
+        repair(35),
+        item(CollectibleType::Overdrive, 10),
+        item(CollectibleType::DamageBoost, 14),
+        item(CollectibleType::Nanite, 6),
+        item(CollectibleType::TractorBeam, 5),
+        item(CollectibleType::Invulnerability, 4),
+        item(CollectibleType::Nosferatu, 1),
+        item(CollectibleType::ExtraLife, 1),
+    ],
+};
+
+pub const HEAVY_LOOT: LootTable = LootTable {
+    drop_chance: 0.8,
+    guaranteed: &[],
+    entries: &[
+        item(CollectibleType::Credits, 15),
+        repair(35),
+        item(CollectibleType::Overdrive, 10),
+        item(CollectibleType::DamageBoost, 15),
+        item(CollectibleType::Nanite, 8),
+        item(CollectibleType::TractorBeam, 7),
+        item(CollectibleType::Invulnerability, 7),
+        item(CollectibleType::Nosferatu, 2),
+        item(CollectibleType::ExtraLife, 3),
+    ],
+};
+
+pub const BOSS_LOOT: LootTable = LootTable {
+    drop_chance: 1.0,
+    guaranteed: &[LootItem::Repair, LootItem::Item(CollectibleType::Credits)],
+    entries: &[
+        item(CollectibleType::DamageBoost, 30),
+        item(CollectibleType::Invulnerability, 25),
+        item(CollectibleType::TractorBeam, 20),
+        item(CollectibleType::ExtraLife, 25),
+    ],
+};
+
+/// Pity timer for repair drops, and the run's loot rules
+#[derive(Resource, Debug)]
+pub struct LootState {
+    /// Seconds since a repair last dropped
+    pub since_repair: f32,
+    /// Drop chance multiplier from run modifiers
+    pub drop_mult: f32,
+    /// Whether extra lives can drop (Iron Clone turns them into credits)
+    pub extra_lives: bool,
+}
+
+impl Default for LootState {
+    fn default() -> Self {
+        Self {
+            since_repair: 0.0,
+            drop_mult: 1.0,
+            extra_lives: true,
+        }
+    }
+}
+
+impl LootState {
+    /// The player is hurting and hasn't seen a repair in a while
+    pub fn needs_pity(&self, health: Option<PlayerHealthState>) -> bool {
+        self.since_repair >= PITY_WINDOW
+            && health.is_some_and(|h| h.armor_percent.min(h.hull_percent) < PITY_HEALTH)
+    }
+
+    /// Roll a kill's table and spawn what it drops
+    pub fn drop_loot(
+        &mut self,
+        commands: &mut Commands,
+        table: &LootTable,
+        difficulty: Difficulty,
+        position: Vec2,
+        health: Option<PlayerHealthState>,
+        icon_cache: Option<&PowerupIconCache>,
+        rng: &mut fastrand::Rng,
+    ) {
+        let drops = table.roll_scaled(
+            difficulty.loot_drop_mult() * self.drop_mult,
+            self.needs_pity(health),
+            rng,
+        );
+        let offset = (drops.len() as f32 - 1.0) * DROP_SPREAD / 2.0;
+        for (i, drop) in drops.iter().enumerate() {
+            if *drop == LootItem::Repair {
+                self.since_repair = 0.0;
+            }
+            let kind = match drop.resolve(health, rng) {
+                CollectibleType::ExtraLife if !self.extra_lives => CollectibleType::Credits,
+                kind => kind,
+            };
+            let x = position.x + i as f32 * DROP_SPREAD - offset;
+            spawn_collectible(commands, Vec2::new(x, position.y), kind, icon_cache);
+        }
+    }
+}
+
+fn reset_loot_state(
+    mut events: EventReader<MissionStartEvent>,
+    save_data: Res<SaveData>,
+    run_mode: Res<RunMode>,
+    mut loot: ResMut<LootState>,
+) {
+    if events.read().last().is_some() {
+        let prestige = &save_data.prestige;
+        *loot = LootState {
+            drop_mult: prestige.modifier_drop_mult(*run_mode),
+            extra_lives: !prestige
+                .run_modifiers(*run_mode)
+                .contains(&RunModifier::IronClone),
+            ..default()
+        };
+    }
+}
+
+fn tick_pity_timer(time: Res<Time>, mut loot: ResMut<LootState>) {
+    loot.since_repair += time.delta_secs();
+}
+
+/// Stage bosses die outside the collision system; they still pay out
+fn drop_boss_loot(
+    mut commands: Commands,
+    mut defeats: EventReader<BossDefeatedEvent>,
+    player_query: Query<&ShipStats, With<Player>>,
+    difficulty: Res<Difficulty>,
+    icon_cache: Res<PowerupIconCache>,
+    mut run_rng: ResMut<RunRng>,
+    mut loot: ResMut<LootState>,
+) {
+    let health = player_query
+        .get_single()
+        .ok()
+        .map(PlayerHealthState::from_stats);
+    for defeat in defeats.read() {
+        loot.drop_loot(
+            &mut commands,
+            &BOSS_LOOT,
+            *difficulty,
+            defeat.position,
+            health,
+            Some(&icon_cache),
+            &mut run_rng.drops,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HURT: PlayerHealthState = PlayerHealthState {
+        shield_percent: 0.0,
+        armor_percent: 0.1,
+        hull_percent: 0.9,
+    };
+
+    #[test]
+    fn ships_roll_on_their_class_table() {
+        assert_eq!(LootClass::for_enemy(597, false), LootClass::Light);
+        assert_eq!(LootClass::for_enemy(16236, false), LootClass::Medium);
+        assert_eq!(LootClass::for_enemy(24690, false), LootClass::Heavy);
+        assert_eq!(LootClass::for_enemy(597, true), LootClass::Boss);
+        // Transports and anything without a definition count as light
+        assert_eq!(LootClass::for_enemy(20185, false), LootClass::Light);
+
+        for table in [&LIGHT_LOOT, &MEDIUM_LOOT, &HEAVY_LOOT, &BOSS_LOOT] {
+            assert!(table.drop_chance > 0.0 && table.drop_chance <= 1.0);
+            assert!(table.entries.iter().all(|e| e.weight > 0));
+        }
+        const {
+            assert!(LIGHT_LOOT.drop_chance < MEDIUM_LOOT.drop_chance);
+            assert!(MEDIUM_LOOT.drop_chance < HEAVY_LOOT.drop_chance);
+        }
+    }
+
+    #[test]
+    fn bosses_always_drop_their_guaranteed_items() {
+        let mut rng = fastrand::Rng::with_seed(7);
+        for difficulty in Difficulty::all() {
+            let drops = BOSS_LOOT.roll(*difficulty, false, &mut rng);
+            assert_eq!(&drops[..2], BOSS_LOOT.guaranteed);
+        }
+        // The bonus roll is certain on the easier settings
+        let drops = BOSS_LOOT.roll(Difficulty::Newbro, false, &mut rng);
+        assert_eq!(drops.len(), 3);
+    }
+
+    #[test]
+    fn harder_difficulties_drop_less() {
+        let drops = |difficulty: Difficulty| {
+            let mut rng = fastrand::Rng::with_seed(11);
+            (0..2000)
+                .map(|_| LIGHT_LOOT.roll(difficulty, false, &mut rng).len())
+                .sum::<usize>()
+        };
+        assert!(drops(Difficulty::Carebear) > drops(Difficulty::Newbro));
+        assert!(drops(Difficulty::Newbro) > drops(Difficulty::Triglavian));
+    }
+
+    #[test]
+    fn golden_age_halves_the_drop_chance() {
+        let drops = |drop_mult: f32| {
+            let mut rng = fastrand::Rng::with_seed(11);
+            (0..2000)
+                .map(|_| MEDIUM_LOOT.roll_scaled(drop_mult, false, &mut rng).len())
+                .sum::<usize>() as f32
+        };
+        let ratio = drops(RunModifier::GoldenAge.drop_mult()) / drops(1.0);
+        assert!((0.4..0.6).contains(&ratio));
+    }
+
+    #[test]
+    fn pity_timer_guarantees_a_repair_when_hurting() {
+        let mut loot = LootState::default();
+        assert!(!loot.needs_pity(Some(HURT)));
+
+        loot.since_repair = PITY_WINDOW;
+        assert!(loot.needs_pity(Some(HURT)));
+        let healthy = PlayerHealthState {
+            armor_percent: 1.0,
+            ..HURT
+        };
+        assert!(!loot.needs_pity(Some(healthy)));
+        assert!(!loot.needs_pity(None));
+
+        // Even a roll that misses pays out the repair
+        let dry = LootTable {
+            drop_chance: 0.0,
+            guaranteed: &[],
+            entries: LIGHT_LOOT.entries,
+        };
+        let mut rng = fastrand::Rng::with_seed(3);
+        assert!(dry.roll(Difficulty::Newbro, false, &mut rng).is_empty());
+        assert_eq!(
+            dry.roll(Difficulty::Newbro, true, &mut rng),
+            vec![LootItem::Repair]
+        );
+
+        let kind = LootItem::Repair.resolve(Some(HURT), &mut rng);
+        assert!(matches!(
+            kind,
+            CollectibleType::ArmorRepair | CollectibleType::ShieldBoost
+        ));
+    }
+}
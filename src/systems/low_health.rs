@@ -0,0 +1,367 @@
+//! Low Hull Feedback
+//!
+//! Escalating warnings as the player's hull (not shields or armor) runs
+//! down: a red screen-edge vignette that pulses faster the closer to death,
+//! muffled audio, sparks and venting atmosphere from the ship, and a
+//! warning from the Elder below a quarter hull. Everything scales with one
+//! intensity value; reduced motion holds the vignette steady and drops the
+//! sparks. bevy_audio has no filters, so the muffle is a duck plus a slight
+//! pitch drop on new sounds.
+
+#![allow(dead_code)]
+
+use super::effects::{EngineParticle, MotionSettings};
+use super::music::{MusicStem, MusicTrack, MusicType};
+use super::{CombatCalloutType, DialogueEvent, SoundSettings};
+use crate::core::*;
+use crate::entities::{Player, ShipStats};
+use bevy::audio::Volume;
+use bevy::prelude::*;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy::transform::TransformSystem;
+use std::f32::consts::TAU;
+
+/// Hull fraction where the feedback starts
+pub const LOW_HULL_START: f32 = 0.5;
+
+/// Hull fraction where the feedback is at full strength
+pub const LOW_HULL_FULL: f32 = 0.1;
+
+/// Hull fraction that triggers the Elder's warning
+pub const HULL_WARNING: f32 = 0.25;
+
+/// Hull fraction that re-arms the warning once repaired
+const HULL_WARNING_REARM: f32 = 0.4;
+
+/// Intensity change per second (fades rather than snapping on a hit)
+const INTENSITY_RATE: f32 = 2.0;
+
+/// Particles per second at full intensity
+const VENT_RATE: f32 = 40.0;
+
+/// Side length of the vignette mask (pixels)
+const VIGNETTE_SIZE: u32 = 64;
+
+/// Low hull feedback plugin
+pub struct LowHullPlugin;
+
+impl Plugin for LowHullPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LowHullFeedback>()
+            .add_systems(Startup, create_vignette)
+            .add_systems(
+                Update,
+                (
+                    track_low_hull,
+                    update_vignette,
+                    vent_hull_particles,
+                    muffle_gameplay_music,
+                )
+                    .chain()
+                    .in_set(GameSet::Ui)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            // Sinks are created after transform propagation
+            .add_systems(
+                PostUpdate,
+                muffle_new_sounds
+                    .before(TransformSystem::TransformPropagate)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(OnExit(GameState::Playing), clear_low_hull);
+    }
+}
+
+/// Current low hull state
+#[derive(Resource, Debug, Default)]
+pub struct LowHullFeedback {
+    /// 0.0 (healthy) to 1.0 (about to die), eased toward the hull level
+    pub intensity: f32,
+    /// Vignette pulse phase (cycles)
+    pub pulse_phase: f32,
+    /// Particle spawn accumulator
+    pub vent_timer: f32,
+    /// Warning already given on this trip below the threshold
+    pub warned: bool,
+}
+
+impl LowHullFeedback {
+    /// Music level multiplier
+    pub fn music_gain(&self) -> f32 {
+        1.0 - 0.5 * self.intensity
+    }
+
+    /// Sound effect level multiplier
+    pub fn sfx_gain(&self) -> f32 {
+        1.0 - 0.35 * self.intensity
+    }
+
+    /// Playback speed for new sounds (lower reads as muffled)
+    pub fn sfx_speed(&self) -> f32 {
+        1.0 - 0.08 * self.intensity
+    }
+}
+
+/// Feedback strength for a hull fraction
+pub fn low_hull_intensity(hull_fraction: f32) -> f32 {
+    ((LOW_HULL_START - hull_fraction) / (LOW_HULL_START - LOW_HULL_FULL)).clamp(0.0, 1.0)
+}
+
+/// Vignette alpha; `pulse` is 0.0 - 1.0 and ignored with reduced motion
+pub fn vignette_alpha(intensity: f32, pulse: f32, reduced_motion: bool) -> f32 {
+    if reduced_motion {
+        intensity * 0.5
+    } else {
+        intensity * (0.35 + 0.3 * pulse)
+    }
+}
+
+/// Vignette mask shared by every overlay
+#[derive(Resource)]
+struct VignetteImage(Handle<Image>);
+
+/// Marker for the vignette overlay sprite
+#[derive(Component)]
+pub struct LowHullVignette;
+
+fn create_vignette(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    commands.insert_resource(VignetteImage(images.add(vignette_image())));
+}
+
+/// White mask, clear in the middle and opaque at the edges
+fn vignette_image() -> Image {
+    let mut data = Vec::with_capacity((VIGNETTE_SIZE * VIGNETTE_SIZE * 4) as usize);
+    let half = VIGNETTE_SIZE as f32 / 2.0;
+
+    for py in 0..VIGNETTE_SIZE {
+        for px in 0..VIGNETTE_SIZE {
+            let x = (px as f32 + 0.5 - half) / half;
+            let y = (py as f32 + 0.5 - half) / half;
+            // Squared-off falloff so the corners and edges both darken
+            let edge = (x.powi(4) + y.powi(4)).powf(0.25);
+            let t = ((edge - 0.55) / 0.45).clamp(0.0, 1.0);
+            let alpha = (t * t * (3.0 - 2.0 * t) * 255.0) as u8;
+            data.extend_from_slice(&[255, 255, 255, alpha]);
+        }
+    }
+
+    Image::new(
+        Extent3d {
+            width: VIGNETTE_SIZE,
+            height: VIGNETTE_SIZE,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD,
+    )
+}
+
+/// Follow the player's hull and give the Elder's warning
+fn track_low_hull(
+    time: Res<Time>,
+    mut feedback: ResMut<LowHullFeedback>,
+    player_query: Query<&ShipStats, With<Player>>,
+    mut dialogue_events: EventWriter<DialogueEvent>,
+) {
+    let hull_fraction = player_query
+        .get_single()
+        .ok()
+        .filter(|stats| stats.max_hull > 0.0)
+        .map(|stats| stats.hull / stats.max_hull);
+
+    let target = hull_fraction.map(low_hull_intensity).unwrap_or(0.0);
+    let step = INTENSITY_RATE * time.delta_secs();
+    feedback.intensity += (target - feedback.intensity).clamp(-step, step);
+
+    let Some(hull_fraction) = hull_fraction else {
+        return;
+    };
+    if hull_fraction >= HULL_WARNING_REARM {
+        feedback.warned = false;
+    } else if hull_fraction < HULL_WARNING && !feedback.warned {
+        feedback.warned = true;
+        dialogue_events.send(DialogueEvent {
+            priority: 6,
+            duration: 3.5,
+            ..DialogueEvent::combat_callout(CombatCalloutType::HullCritical)
+        });
+    }
+}
+
+/// Pulse the red screen edges
+fn update_vignette(
+    mut commands: Commands,
+    time: Res<Time>,
+    motion: Res<MotionSettings>,
+    image: Option<Res<VignetteImage>>,
+    mut feedback: ResMut<LowHullFeedback>,
+    playfield: Res<Playfield>,
+    mut overlay_query: Query<(Entity, &mut Sprite), With<LowHullVignette>>,
+) {
+    if feedback.intensity <= 0.0 {
+        feedback.pulse_phase = 0.0;
+        for (entity, _) in overlay_query.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+
+    // Heartbeat quickens from ~1 to ~3 beats a second
+    let rate = 1.0 + 2.0 * feedback.intensity;
+    feedback.pulse_phase = (feedback.pulse_phase + rate * time.delta_secs()).fract();
+    let pulse = 0.5 + 0.5 * (feedback.pulse_phase * TAU).sin();
+    let alpha = vignette_alpha(feedback.intensity, pulse, motion.reduced_motion);
+    let color = Color::srgba(0.9, 0.05, 0.05, alpha);
+
+    if let Ok((_, mut sprite)) = overlay_query.get_single_mut() {
+        sprite.color = color;
+    } else if let Some(image) = image {
+        commands.spawn((
+            LowHullVignette,
+            Sprite {
+                image: image.0.clone(),
+                color,
+                custom_size: Some(playfield.size + 100.0),
+                ..default()
+            },
+            Transform::from_xyz(0.0, 0.0, LAYER_HUD + 4.0), // Under the berserk tint
+        ));
+    }
+}
+
+/// Sparks and venting atmosphere from the damaged ship
+fn vent_hull_particles(
+    mut commands: Commands,
+    time: Res<Time>,
+    motion: Res<MotionSettings>,
+    mut feedback: ResMut<LowHullFeedback>,
+    player_query: Query<&Transform, With<Player>>,
+) {
+    let Ok(transform) = player_query.get_single() else {
+        feedback.vent_timer = 0.0;
+        return;
+    };
+    if feedback.intensity <= 0.0 {
+        feedback.vent_timer = 0.0;
+        return;
+    }
+
+    let rate = VENT_RATE * feedback.intensity * if motion.reduced_motion { 0.3 } else { 1.0 };
+    feedback.vent_timer += rate * time.delta_secs();
+
+    let origin = transform.translation.truncate();
+    while feedback.vent_timer >= 1.0 {
+        feedback.vent_timer -= 1.0;
+
+        let offset = Vec2::new(
+            (fastrand::f32() - 0.5) * PLAYER_SPRITE_SIZE * 0.6,
+            (fastrand::f32() - 0.5) * PLAYER_SPRITE_SIZE * 0.4,
+        );
+        let spark = !motion.reduced_motion && fastrand::f32() < 0.5;
+
+        let (velocity, lifetime, size, color) = if spark {
+            let angle = fastrand::f32() * TAU;
+            let speed = 120.0 + fastrand::f32() * 160.0;
+            (
+                Vec2::from_angle(angle) * speed,
+                0.12 + fastrand::f32() * 0.12,
+                2.0 + fastrand::f32() * 2.0,
+                Color::srgb(1.0, 0.75 + fastrand::f32() * 0.25, 0.3),
+            )
+        } else {
+            // Venting drifts behind the ship
+            (
+                Vec2::new(
+                    (fastrand::f32() - 0.5) * 30.0,
+                    -40.0 - fastrand::f32() * 30.0,
+                ),
+                0.4 + fastrand::f32() * 0.3,
+                4.0 + fastrand::f32() * 5.0,
+                Color::srgba(0.6, 0.62, 0.65, 0.7),
+            )
+        };
+
+        commands.spawn((
+            EngineParticle {
+                velocity,
+                lifetime,
+                max_lifetime: lifetime,
+            },
+            Sprite {
+                color,
+                custom_size: Some(Vec2::splat(size)),
+                ..default()
+            },
+            Transform::from_xyz(origin.x + offset.x, origin.y + offset.y, LAYER_EFFECTS),
+        ));
+    }
+}
+
+/// Duck and slow sounds as they start playing
+fn muffle_new_sounds(
+    feedback: Res<LowHullFeedback>,
+    mut sounds: Query<&mut PlaybackSettings, (Added<AudioPlayer>, Without<MusicTrack>)>,
+) {
+    if feedback.intensity <= 0.0 {
+        return;
+    }
+    for mut playback in sounds.iter_mut() {
+        playback.volume = Volume::new(playback.volume.get() * feedback.sfx_gain());
+        playback.speed *= feedback.sfx_speed();
+    }
+}
+
+/// Duck the single-track gameplay music (adaptive stems duck in their mixer)
+fn muffle_gameplay_music(
+    feedback: Res<LowHullFeedback>,
+    settings: Res<SoundSettings>,
+    tracks: Query<(&MusicTrack, &AudioSink), Without<MusicStem>>,
+) {
+    let volume = settings.music_volume * settings.master_volume * 0.35 * feedback.music_gain();
+    for (track, sink) in tracks.iter() {
+        if matches!(track.music_type, MusicType::Gameplay | MusicType::Boss) {
+            sink.set_volume(volume);
+        }
+    }
+}
+
+fn clear_low_hull(
+    mut commands: Commands,
+    mut feedback: ResMut<LowHullFeedback>,
+    overlay_query: Query<Entity, With<LowHullVignette>>,
+) {
+    *feedback = LowHullFeedback {
+        warned: feedback.warned,
+        ..default()
+    };
+    for entity in overlay_query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intensity_ramps_between_the_thresholds() {
+        assert_eq!(low_hull_intensity(1.0), 0.0);
+        assert_eq!(low_hull_intensity(LOW_HULL_START), 0.0);
+        assert!((low_hull_intensity(0.3) - 0.5).abs() < 1e-5);
+        assert_eq!(low_hull_intensity(LOW_HULL_FULL), 1.0);
+        assert_eq!(low_hull_intensity(0.0), 1.0);
+    }
+
+    #[test]
+    fn reduced_motion_holds_the_vignette_steady() {
+        let low = vignette_alpha(0.8, 0.0, true);
+        assert_eq!(low, vignette_alpha(0.8, 1.0, true));
+        assert!(low > 0.0);
+
+        assert!(vignette_alpha(0.8, 1.0, false) > vignette_alpha(0.8, 0.0, false));
+        assert_eq!(vignette_alpha(0.0, 1.0, false), 0.0);
+    }
+}
@@ -2,11 +2,16 @@
 //!
 //! Evasive maneuvers: Thrust burst and Barrel roll with i-frames.
 //! Based on design doc config/maneuvers/evasion.json
+//!
+//! Parry: a tight timing window that turns nearby enemy shots around as
+//! player shots with bonus damage. The window shrinks on harder difficulties.
 
 #![allow(dead_code)]
 
 use crate::core::*;
-use crate::entities::{Movement, Player, ShipStats};
+use crate::entities::{
+    Movement, Player, ProjectileDamage, ProjectileOwner, ProjectilePhysics, ShipStats, Team,
+};
 use bevy::prelude::*;
 
 /// Maneuver system plugin
@@ -16,13 +21,16 @@ impl Plugin for ManeuverPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<ThrustEvent>()
             .add_event::<BarrelRollEvent>()
+            .add_event::<ParryEvent>()
             .add_systems(
                 Update,
                 (
                     handle_maneuver_input,
                     update_thrust,
                     update_barrel_roll,
+                    update_parry,
                     update_maneuver_cooldowns,
+                    draw_roll_cooldown,
                 )
                     .chain()
                     .run_if(in_state(GameState::Playing)),
@@ -46,11 +54,29 @@ pub struct ManeuverState {
     pub barrel_roll_direction: f32, // -1 left, +1 right
     pub barrel_roll_start_x: f32,
 
+    // Parry
+    pub parry_timer: f32,
+    pub parry_cooldown: f32,
+    /// Something was reflected during the current window
+    pub parry_landed: bool,
+
     // Invincibility
     pub invincible: bool,
     pub invincibility_timer: f32,
 }
 
+impl ManeuverState {
+    /// Parry window is open
+    pub fn parrying(&self) -> bool {
+        self.parry_timer > 0.0
+    }
+
+    /// Barrel roll recharge (0.0 = just rolled, 1.0 = ready)
+    pub fn roll_ready(&self) -> f32 {
+        1.0 - (self.barrel_roll_cooldown / ManeuverConfig::BARREL_ROLL_COOLDOWN).clamp(0.0, 1.0)
+    }
+}
+
 impl Default for ManeuverState {
     fn default() -> Self {
         Self {
@@ -65,6 +91,10 @@ impl Default for ManeuverState {
             barrel_roll_direction: 0.0,
             barrel_roll_start_x: 0.0,
 
+            parry_timer: 0.0,
+            parry_cooldown: 0.0,
+            parry_landed: false,
+
             invincible: false,
             invincibility_timer: 0.0,
         }
@@ -88,8 +118,22 @@ impl ManeuverConfig {
     pub const BARREL_ROLL_COOLDOWN: f32 = 3.0;
     pub const BARREL_ROLL_INVINCIBILITY: f32 = 0.3;
     pub const BARREL_ROLL_CAP_COST: f32 = 20.0;
+
+    // Parry settings
+    pub const PARRY_WINDOW: f32 = 0.15; // seconds on Newbro
+    pub const PARRY_RADIUS: f32 = 70.0;
+    pub const PARRY_COOLDOWN: f32 = 2.5;
+    pub const PARRY_CAP_COST: f32 = 25.0;
+    pub const PARRY_DAMAGE_MULT: f32 = 2.0;
 }
 
+/// Reflected shots and the parry ring
+const PARRY_COLOR: Color = Color::srgb(0.55, 0.95, 1.0);
+
+/// Roll cooldown dial, offset below and right of the ship
+const ROLL_DIAL_OFFSET: Vec2 = Vec2::new(30.0, -26.0);
+const ROLL_DIAL_RADIUS: f32 = 7.0;
+
 /// Event fired when thrust is activated
 #[derive(Event)]
 pub struct ThrustEvent {
@@ -103,6 +147,13 @@ pub struct BarrelRollEvent {
     pub direction: f32,
 }
 
+/// Event fired when a parry first turns shots around
+#[derive(Event)]
+pub struct ParryEvent {
+    pub position: Vec2,
+    pub reflected: u32,
+}
+
 /// Handle maneuver input
 fn handle_maneuver_input(
     keyboard: Res<ButtonInput<KeyCode>>,
@@ -110,6 +161,7 @@ fn handle_maneuver_input(
     mut query: Query<(&Transform, &mut ManeuverState, &mut ShipStats, &Movement), With<Player>>,
     mut thrust_events: EventWriter<ThrustEvent>,
     mut roll_events: EventWriter<BarrelRollEvent>,
+    difficulty: Res<Difficulty>,
 ) {
     let Ok((transform, mut maneuver, mut stats, movement)) = query.get_single_mut() else {
         return;
@@ -146,7 +198,7 @@ fn handle_maneuver_input(
     // Barrel Roll: RB (button 5) or Q/E
     let roll_left = keyboard.just_pressed(KeyCode::KeyQ);
     let roll_right = keyboard.just_pressed(KeyCode::KeyE);
-    let roll_rb = joystick.buttons[5];
+    let roll_rb = joystick.right_bumper();
 
     // Determine roll direction
     let roll_dir = if roll_left {
@@ -178,9 +230,12 @@ fn handle_maneuver_input(
             maneuver.barrel_roll_direction = dir;
             maneuver.barrel_roll_start_x = pos.x;
 
-            // Grant invincibility
+            // Grant invincibility (longer on easier difficulties)
             maneuver.invincible = true;
-            maneuver.invincibility_timer = ManeuverConfig::BARREL_ROLL_INVINCIBILITY;
+            maneuver.invincibility_timer = ManeuverConfig::BARREL_ROLL_INVINCIBILITY
+                * difficulty
+                    .player_modifiers()
+                    .invincibility_duration_multiplier;
 
             // Consume capacitor
             stats.capacitor -= ManeuverConfig::BARREL_ROLL_CAP_COST;
@@ -191,11 +246,26 @@ fn handle_maneuver_input(
             });
         }
     }
+
+    // Parry: X button or C
+    let parry_pressed = keyboard.just_pressed(KeyCode::KeyC) || joystick.x_button();
+
+    if parry_pressed
+        && !maneuver.parrying()
+        && maneuver.parry_cooldown <= 0.0
+        && stats.capacitor >= ManeuverConfig::PARRY_CAP_COST
+    {
+        maneuver.parry_timer = ManeuverConfig::PARRY_WINDOW * difficulty.parry_window_mult();
+        maneuver.parry_cooldown = ManeuverConfig::PARRY_COOLDOWN;
+        maneuver.parry_landed = false;
+        stats.capacitor -= ManeuverConfig::PARRY_CAP_COST;
+    }
 }
 
 /// Update thrust movement
 fn update_thrust(
     time: Res<Time>,
+    playfield: Res<Playfield>,
     mut query: Query<(&mut Transform, &mut Movement, &mut ManeuverState), With<Player>>,
 ) {
     let Ok((mut transform, mut movement, mut maneuver)) = query.get_single_mut() else {
@@ -225,16 +295,16 @@ fn update_thrust(
     transform.translation.x += thrust_velocity.x * dt;
     transform.translation.y += thrust_velocity.y * dt;
 
-    // Clamp to screen
-    let half_width = SCREEN_WIDTH / 2.0 - 32.0;
-    let half_height = SCREEN_HEIGHT / 2.0 - 32.0;
-    transform.translation.x = transform.translation.x.clamp(-half_width, half_width);
-    transform.translation.y = transform.translation.y.clamp(-half_height, half_height);
+    // Clamp to the playfield
+    let clamped = playfield.clamp(transform.translation.truncate(), 32.0);
+    transform.translation.x = clamped.x;
+    transform.translation.y = clamped.y;
 }
 
 /// Update barrel roll movement and rotation
 fn update_barrel_roll(
     time: Res<Time>,
+    playfield: Res<Playfield>,
     mut query: Query<(&mut Transform, &mut ManeuverState), With<Player>>,
 ) {
     let Ok((mut transform, mut maneuver)) = query.get_single_mut() else {
@@ -272,8 +342,8 @@ fn update_barrel_roll(
 
     transform.translation.x = lerp(maneuver.barrel_roll_start_x, target_x, eased_progress);
 
-    // Clamp to screen
-    let half_width = SCREEN_WIDTH / 2.0 - 32.0;
+    // Clamp to the playfield
+    let half_width = playfield.half_width() - 32.0;
     transform.translation.x = transform.translation.x.clamp(-half_width, half_width);
 
     // Visual rotation (full 360 roll)
@@ -286,6 +356,80 @@ fn update_barrel_roll(
     // transform.rotation = Quat::from_rotation_z(roll_angle);
 }
 
+/// Turn enemy shots inside the parry radius back on their shooters
+fn update_parry(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut gizmos: Gizmos,
+    mut player_query: Query<(Entity, &Transform, &mut ManeuverState), With<Player>>,
+    mut projectile_query: Query<
+        (
+            &mut Transform,
+            &mut ProjectilePhysics,
+            &mut ProjectileOwner,
+            &mut ProjectileDamage,
+            &mut Sprite,
+        ),
+        Without<Player>,
+    >,
+    mut parry_events: EventWriter<ParryEvent>,
+) {
+    let Ok((player_entity, player_transform, mut maneuver)) = player_query.get_single_mut() else {
+        return;
+    };
+    if !maneuver.parrying() {
+        return;
+    }
+    maneuver.parry_timer -= time.delta_secs();
+
+    let player_pos = player_transform.translation.truncate();
+    let mut reflected = 0;
+
+    for (mut transform, mut physics, mut owner, mut damage, mut sprite) in
+        projectile_query.iter_mut()
+    {
+        if !owner.hits_ship(player_entity, Team::Player) {
+            continue;
+        }
+        let pos = transform.translation.truncate();
+        if pos.distance(player_pos) > ManeuverConfig::PARRY_RADIUS {
+            continue;
+        }
+
+        // Back the way it came, at least as fast as a player shot
+        let direction = (-physics.velocity).try_normalize().unwrap_or(Vec2::Y);
+        let speed = physics.velocity.length().max(PLAYER_BULLET_SPEED * 0.8);
+        physics.velocity = direction * speed;
+        physics.lifetime = physics.lifetime.max(2.0);
+
+        owner.reflect(Some(player_entity));
+        damage.damage *= ManeuverConfig::PARRY_DAMAGE_MULT;
+        sprite.color = PARRY_COLOR;
+        transform.translation.z = LAYER_PLAYER_BULLETS;
+        transform.rotation =
+            Quat::from_rotation_z(direction.y.atan2(direction.x) - std::f32::consts::FRAC_PI_2);
+
+        super::spawn_impact_flash(&mut commands, pos, PARRY_COLOR, None);
+        reflected += 1;
+    }
+
+    if reflected > 0 && !maneuver.parry_landed {
+        maneuver.parry_landed = true;
+        parry_events.send(ParryEvent {
+            position: player_pos,
+            reflected,
+        });
+    }
+
+    // Ring fades as the window closes
+    let fade = (maneuver.parry_timer / ManeuverConfig::PARRY_WINDOW).clamp(0.2, 1.0);
+    gizmos.circle_2d(
+        player_pos,
+        ManeuverConfig::PARRY_RADIUS,
+        PARRY_COLOR.with_alpha(0.8 * fade),
+    );
+}
+
 /// Update cooldown timers
 fn update_maneuver_cooldowns(time: Res<Time>, mut query: Query<&mut ManeuverState, With<Player>>) {
     let Ok(mut maneuver) = query.get_single_mut() else {
@@ -301,6 +445,36 @@ fn update_maneuver_cooldowns(time: Res<Time>, mut query: Query<&mut ManeuverStat
     if maneuver.barrel_roll_cooldown > 0.0 {
         maneuver.barrel_roll_cooldown -= dt;
     }
+
+    if maneuver.parry_cooldown > 0.0 {
+        maneuver.parry_cooldown -= dt;
+    }
+}
+
+/// Small radial dial beside the ship that fills as the roll recharges
+fn draw_roll_cooldown(
+    mut gizmos: Gizmos,
+    query: Query<(&Transform, &ManeuverState), With<Player>>,
+) {
+    let Ok((transform, maneuver)) = query.get_single() else {
+        return;
+    };
+    let ready = maneuver.roll_ready();
+    if ready >= 1.0 {
+        return;
+    }
+
+    let center = transform.translation.truncate() + ROLL_DIAL_OFFSET;
+    gizmos.circle_2d(center, ROLL_DIAL_RADIUS, Color::srgba(0.5, 0.5, 0.6, 0.3));
+
+    // Sweeps clockwise from twelve o'clock
+    let sweep = std::f32::consts::TAU * ready;
+    gizmos.arc_2d(
+        Isometry2d::new(center, Rot2::radians(-sweep)),
+        sweep,
+        ROLL_DIAL_RADIUS,
+        Color::srgba(0.4, 0.8, 1.0, 0.8),
+    );
 }
 
 /// Quadratic ease in/out
@@ -0,0 +1,236 @@
+//! Mission Hot-Reload (dev builds)
+//!
+//! Watches `assets/missions/<mission_id>.ron` for the active mission.
+//! Edits are parsed as a `MissionOverride` and applied at the next wave
+//! boundary, so wave budgets, pacing and objectives can be tuned without
+//! restarting. Boss tuning is applied as the boss spawns; once it is on
+//! the grid there are no more wave boundaries, so edits land straight away
+//! on the live boss.
+//!
+//! Example `assets/missions/m1_convoy_raid.ron`:
+//! ```text
+//! (
+//!     enemy_waves: Some(5),
+//!     wave_budget: Some(10),
+//!     pacing: Some((ramp: 1.5, lull_every: 0, climax: 1.2)),
+//!     primary_objective: Some("Destroy the convoy"),
+//!     objectives: Some([
+//!         (tier: Primary, label: "Turrets destroyed",
+//!          kind: DestroyEnemies(count: 5, type_id: None)),
+//!         (tier: Primary, label: "Escort the convoy",
+//!          kind: Escort(health: 400.0, duration: 60.0)),
+//!         (tier: Bonus, label: "Untouched", kind: NoDamage),
+//!     ]),
+//!     lighting: Some((key_color: (0.6, 0.78, 1.0), key_lux: 7000.0)),
+//!     boss_warning: Some(5.0),
+//!     boss: Some((health: Some(800.0), phases: Some(3), enrage_threshold: Some(0.3))),
+//! )
+//! ```
+
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use crate::core::*;
+use crate::entities::{Boss, BossData};
+use crate::ui::ToastEvent;
+
+/// Seconds between file checks
+const POLL_INTERVAL: f32 = 0.5;
+
+/// Directory holding mission override files
+const MISSION_DIR: &str = "assets/missions";
+
+/// Mission hot-reload plugin
+pub struct MissionReloadPlugin;
+
+impl Plugin for MissionReloadPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MissionReloadWatcher>().add_systems(
+            Update,
+            (
+                load_on_mission_start,
+                poll_mission_file,
+                apply_on_wave_boundary,
+                tune_spawned_boss,
+                apply_to_live_boss,
+            )
+                .chain()
+                .in_set(GameSet::Simulation)
+                .run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight))),
+        );
+    }
+}
+
+/// Tracks the watched mission file and any staged reload
+#[derive(Resource, Default)]
+pub struct MissionReloadWatcher {
+    /// Mission currently being watched
+    mission_id: Option<&'static str>,
+    /// Modification time when last read
+    last_modified: Option<SystemTime>,
+    /// Parsed edit waiting for the next wave boundary
+    pending: Option<MissionOverride>,
+    /// Time until next poll
+    poll_timer: f32,
+}
+
+fn mission_path(mission_id: &str) -> PathBuf {
+    PathBuf::from(MISSION_DIR).join(format!("{}.ron", mission_id))
+}
+
+fn modified_time(path: &std::path::Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+fn read_override(path: &std::path::Path) -> Option<Result<MissionOverride, String>> {
+    let text = std::fs::read_to_string(path).ok()?;
+    Some(MissionOverride::from_ron(&text))
+}
+
+/// Mission start is itself a boundary: apply the file immediately
+fn load_on_mission_start(
+    mut events: EventReader<MissionStartEvent>,
+    mut watcher: ResMut<MissionReloadWatcher>,
+    mut campaign: ResMut<CampaignState>,
+    mut toasts: EventWriter<ToastEvent>,
+) {
+    let Some(event) = events.read().last() else {
+        return;
+    };
+
+    let mission_id = event.mission.id;
+    let path = mission_path(mission_id);
+    watcher.mission_id = Some(mission_id);
+    watcher.last_modified = modified_time(&path);
+    watcher.pending = None;
+    watcher.poll_timer = POLL_INTERVAL;
+
+    match read_override(&path) {
+        Some(Ok(mission_override)) => {
+            info!("Loaded mission override {}", path.display());
+            campaign.mission_override = Some(mission_override);
+        }
+        Some(Err(e)) => {
+            warn!("Failed to parse {}: {}", path.display(), e);
+            toasts.send(ToastEvent::warning(format!("{}.ron: {}", mission_id, e)));
+        }
+        None => {}
+    }
+}
+
+/// Poll the watched file and stage edits
+fn poll_mission_file(
+    time: Res<Time>,
+    mut watcher: ResMut<MissionReloadWatcher>,
+    mut toasts: EventWriter<ToastEvent>,
+) {
+    let Some(mission_id) = watcher.mission_id else {
+        return;
+    };
+
+    watcher.poll_timer -= time.delta_secs();
+    if watcher.poll_timer > 0.0 {
+        return;
+    }
+    watcher.poll_timer = POLL_INTERVAL;
+
+    let path = mission_path(mission_id);
+    let modified = modified_time(&path);
+    if modified.is_none() || modified == watcher.last_modified {
+        return;
+    }
+    watcher.last_modified = modified;
+
+    match read_override(&path) {
+        Some(Ok(mission_override)) => {
+            info!("{} changed, applying at next wave", path.display());
+            watcher.pending = Some(mission_override);
+        }
+        Some(Err(e)) => {
+            warn!("Failed to parse {}: {}", path.display(), e);
+            toasts.send(ToastEvent::warning(format!("{}.ron: {}", mission_id, e)));
+        }
+        None => {}
+    }
+}
+
+/// Apply a staged edit when a wave completes
+fn apply_on_wave_boundary(
+    mut wave_events: EventReader<WaveCompleteEvent>,
+    mut watcher: ResMut<MissionReloadWatcher>,
+    mut campaign: ResMut<CampaignState>,
+    mut toasts: EventWriter<ToastEvent>,
+) {
+    if wave_events.read().count() == 0 {
+        return;
+    }
+    let Some(mission_override) = watcher.pending.take() else {
+        return;
+    };
+
+    // Ignore stale edits if the mission changed underneath us
+    let current_id = campaign.current_mission().map(|m| m.id);
+    if current_id != watcher.mission_id {
+        return;
+    }
+
+    campaign.mission_override = Some(mission_override);
+    let text = format!(
+        "Reloaded {}.ron: {} waves, {}",
+        current_id.unwrap_or_default(),
+        campaign.enemy_waves(),
+        campaign.primary_objective()
+    );
+    info!("{}", text);
+    toasts.send(ToastEvent::debug(text));
+}
+
+/// A freshly spawned boss takes the mission's boss tuning
+fn tune_spawned_boss(
+    campaign: Res<CampaignState>,
+    mut boss_query: Query<&mut BossData, Added<Boss>>,
+) {
+    let tuning = campaign.boss_override();
+    for mut data in boss_query.iter_mut() {
+        data.apply_override(&tuning);
+    }
+}
+
+/// With the boss on the grid, apply a staged edit right away
+fn apply_to_live_boss(
+    mut watcher: ResMut<MissionReloadWatcher>,
+    mut campaign: ResMut<CampaignState>,
+    mut boss_query: Query<&mut BossData, With<Boss>>,
+    mut toasts: EventWriter<ToastEvent>,
+) {
+    if boss_query.is_empty() {
+        return;
+    }
+    let Some(mission_override) = watcher.pending.take() else {
+        return;
+    };
+
+    let current_id = campaign.current_mission().map(|m| m.id);
+    if current_id != watcher.mission_id {
+        return;
+    }
+
+    campaign.mission_override = Some(mission_override);
+    let tuning = campaign.boss_override();
+    for mut data in boss_query.iter_mut() {
+        data.apply_override(&tuning);
+        let text = format!(
+            "Reloaded {}.ron: {} at {:.0}/{:.0}, {} phases",
+            current_id.unwrap_or_default(),
+            data.name,
+            data.health,
+            data.max_health,
+            data.total_phases
+        );
+        info!("{}", text);
+        toasts.send(ToastEvent::debug(text));
+    }
+}
@@ -1,38 +1,116 @@
 //! Game Systems
 //!
-//! Core gameplay systems: collision, spawning, scoring, effects, input, dialogue, audio.
+//! Core gameplay systems: collision, spawning, scoring, objectives, loot, daily challenge, leaderboard run integrity, berserk presentation, prestige, campaign checkpoints, act interlude boons, fleet roster fatigue and Ironman losses, New Game+, codex unlocks, spawn edges, enemy health bars, act backdrops, effects, scene lighting, projectile visuals, score popups, supply caches, shield bubbles, volatile wrecks, boss warning, boss arena hazards, boss targeting aid, Time Attack race ghosts, low hull feedback, death sequence, window focus, input, dialogue, voice-over clips and subtitle timing, situational chatter, allied fleet cameos, audio.
 
 pub mod ability;
+pub mod act_boons;
+pub mod ally_fleet;
+pub mod arena_hazards;
 pub mod audio;
+pub mod backdrop;
+pub mod berserk_fx;
 pub mod boss;
+pub mod boss_warning;
+pub mod bullet_cancel;
 pub mod campaign;
+pub mod capital_ship;
+pub mod chatter;
+pub mod checkpoint;
+pub mod codex;
 pub mod collision;
+pub mod commander;
+pub mod daily;
+pub mod death_sequence;
 pub mod dialogue;
 pub mod effects;
+pub mod enemy_health_bars;
+pub mod fleet_roster;
+pub mod focus;
+pub mod ghost;
 pub mod joystick;
+pub mod lighting;
+pub mod loot;
+pub mod low_health;
 pub mod maneuvers;
+#[cfg(all(debug_assertions, not(target_arch = "wasm32")))]
+pub mod mission_reload;
 pub mod music;
+pub mod new_game_plus;
+pub mod objective_markers;
+pub mod objectives;
+pub mod prestige;
+pub mod projectile_fx;
+pub mod route;
+pub mod run_integrity;
+pub mod score_popups;
 pub mod scoring;
 pub mod scoring_v2;
+pub mod shield_bubble;
+pub mod spawn_edges;
 pub mod spawning;
+pub mod squadron;
+pub mod status_effects;
+pub mod supply_cache;
+pub mod targeting_aid;
+pub mod voice_over;
+pub mod volatile_wrecks;
 
 pub use ability::*;
+pub use act_boons::*;
+pub use ally_fleet::*;
+pub use arena_hazards::*;
 pub use audio::*;
+pub use backdrop::*;
+pub use berserk_fx::*;
 pub use boss::*;
-pub use campaign::CampaignPlugin;
+pub use boss_warning::*;
+pub use bullet_cancel::*;
+pub use campaign::{CampaignPlugin, WaveMember, WaveTarget};
+pub use capital_ship::*;
+pub use chatter::*;
+pub use checkpoint::*;
+pub use codex::*;
 pub use collision::*;
+pub use commander::*;
+pub use daily::*;
+pub use death_sequence::*;
 pub use dialogue::*;
 pub use effects::*;
+pub use enemy_health_bars::*;
+pub use fleet_roster::*;
+pub use focus::*;
+pub use ghost::*;
 pub use joystick::*;
+pub use lighting::*;
+pub use loot::*;
+pub use low_health::*;
 pub use maneuvers::*;
+#[cfg(all(debug_assertions, not(target_arch = "wasm32")))]
+pub use mission_reload::*;
 pub use music::*;
+pub use new_game_plus::*;
+pub use objective_markers::*;
+pub use objectives::*;
+pub use prestige::*;
+pub use projectile_fx::*;
+pub use route::*;
+pub use run_integrity::*;
+pub use score_popups::*;
 pub use scoring::*;
 pub use scoring_v2::*;
+pub use shield_bubble::*;
+pub use spawn_edges::*;
 pub use spawning::*;
+pub use squadron::*;
+pub use status_effects::*;
+pub use supply_cache::*;
+pub use targeting_aid::*;
+pub use voice_over::*;
+pub use volatile_wrecks::*;
 
 use bevy::prelude::*;
 
-use crate::core::GameState;
+use crate::core::{GameSet, GameState};
 
 /// Plugin that registers all gameplay systems
 pub struct SystemsPlugin;
@@ -53,13 +131,61 @@ impl Plugin for SystemsPlugin {
             MusicPlugin,
             ManeuverPlugin,
             CampaignPlugin,
+            CapitalShipPlugin,
+            BulletCancelPlugin,
+        ))
+        .add_plugins((
+            StatusEffectsPlugin,
+            SquadronPlugin,
+            ObjectivePlugin,
+            DailyChallengePlugin,
+            LowHullPlugin,
+            DeathSequencePlugin,
+            PrestigePlugin,
+            LootPlugin,
+            CodexPlugin,
+            SpawnEdgePlugin,
+            BackdropPlugin,
+            ProjectileFxPlugin,
+            ScorePopupPlugin,
+            TargetingAidPlugin,
+            FocusPlugin,
+        ))
+        .add_plugins((
+            SupplyCachePlugin,
+            NewGamePlusPlugin,
+            ShieldBubblePlugin,
+            LightingPlugin,
+            VolatileWreckPlugin,
+            BossWarningPlugin,
+            RoutePlugin,
+            CommanderPlugin,
+            ObjectiveMarkerPlugin,
+            GhostPlugin,
+            ChatterPlugin,
+            AllyFleetPlugin,
+            CheckpointPlugin,
+            FleetRosterPlugin,
+            BerserkFxPlugin,
+        ))
+        .add_plugins((
+            ActBoonPlugin,
+            EnemyHealthBarPlugin,
+            RunIntegrityPlugin,
+            ArenaHazardPlugin,
+            VoiceOverPlugin,
         ))
         // Pause system - ESC during gameplay triggers pause
         .add_systems(
             Update,
             pause_trigger_system
+                .in_set(GameSet::Input)
                 .run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight))),
         );
+
+        // Dev-only: hot-reload mission data files
+        #[cfg(all(debug_assertions, not(target_arch = "wasm32")))]
+        app.add_plugins(MissionReloadPlugin);
     }
 }
 
@@ -2,10 +2,16 @@
 //!
 //! Procedural ambient music for EVE Rebellion.
 //! Generates atmospheric tracks for menu, gameplay, and boss fights.
+//!
+//! When stem files are present in `assets/audio/music/stems/`, gameplay
+//! switches to an adaptive mix: base, combat, high-heat, and boss stems
+//! loop in sync and are cross-faded by heat, berserk, and boss state.
+//! Without stems the single procedural tracks are used as before. Either
+//! way berserk pushes the music up a notch in tempo and pitch.
 
 #![allow(dead_code)]
 
-use bevy::audio::{PlaybackMode, PlaybackSettings, Volume};
+use bevy::audio::{AudioSinkPlayback, PlaybackMode, PlaybackSettings, Volume};
 use bevy::prelude::*;
 use std::f32::consts::PI;
 #[cfg(not(target_arch = "wasm32"))]
@@ -22,12 +28,15 @@ impl Plugin for MusicPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<MusicAssets>()
             .init_resource::<MusicState>()
-            .add_systems(Startup, generate_music)
+            .init_resource::<AdaptiveMusicConfig>()
+            .add_systems(Startup, (generate_music, load_music_stems))
             .add_systems(
                 Update,
                 (
                     manage_menu_music.run_if(in_state(GameState::MainMenu)),
                     manage_gameplay_music.run_if(in_state(GameState::Playing)),
+                    mix_music_stems.run_if(in_state(GameState::Playing)),
+                    berserk_music_rate.run_if(in_state(GameState::Playing)),
                     handle_state_music_transitions,
                 ),
             );
@@ -42,6 +51,103 @@ pub struct MusicAssets {
     pub boss_ambient: Option<Handle<AudioSource>>,
     pub victory_sting: Option<Handle<AudioSource>>,
     pub defeat_sting: Option<Handle<AudioSource>>,
+    /// Adaptive gameplay stems (None = fall back to single tracks)
+    pub stems: Option<MusicStems>,
+}
+
+/// Synchronized gameplay stems for the adaptive mix
+#[derive(Clone)]
+pub struct MusicStems {
+    pub base: Handle<AudioSource>,
+    pub combat: Handle<AudioSource>,
+    pub high_heat: Handle<AudioSource>,
+    pub boss: Handle<AudioSource>,
+}
+
+/// Adaptive music tuning
+#[derive(Resource, Debug, Clone)]
+pub struct AdaptiveMusicConfig {
+    /// Use stems when available
+    pub enabled: bool,
+    /// Heat (0-100) where the combat stem starts fading in
+    pub combat_heat_start: f32,
+    /// Heat (0-100) where the high-heat stem starts fading in
+    pub high_heat_start: f32,
+    /// Combo count that alone brings the combat stem to full
+    pub combat_combo_full: u32,
+    /// Stem volume change per second
+    pub fade_speed: f32,
+}
+
+impl Default for AdaptiveMusicConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            combat_heat_start: 10.0,
+            high_heat_start: 60.0,
+            combat_combo_full: 10,
+            fade_speed: 1.5,
+        }
+    }
+}
+
+/// Stem layer in the adaptive mix
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StemLayer {
+    Base,
+    Combat,
+    HighHeat,
+    Boss,
+}
+
+impl StemLayer {
+    pub const ALL: [StemLayer; 4] = [
+        StemLayer::Base,
+        StemLayer::Combat,
+        StemLayer::HighHeat,
+        StemLayer::Boss,
+    ];
+
+    fn file_name(&self) -> &'static str {
+        match self {
+            StemLayer::Base => "base.ogg",
+            StemLayer::Combat => "combat.ogg",
+            StemLayer::HighHeat => "heat.ogg",
+            StemLayer::Boss => "boss.ogg",
+        }
+    }
+}
+
+/// A looping stem whose volume follows gameplay intensity
+#[derive(Component)]
+pub struct MusicStem {
+    pub layer: StemLayer,
+    /// Current mix level (0.0 - 1.0), eased toward the target
+    pub level: f32,
+}
+
+/// Target mix levels for the stems given current intensity
+pub fn stem_targets(
+    config: &AdaptiveMusicConfig,
+    heat: f32,
+    combo_count: u32,
+    berserk: bool,
+    boss: bool,
+) -> [f32; 4] {
+    let heat_ramp = |start: f32| ((heat - start) / (100.0 - start).max(1.0)).clamp(0.0, 1.0);
+    let combo = (combo_count as f32 / config.combat_combo_full.max(1) as f32).min(1.0);
+
+    let mut combat = heat_ramp(config.combat_heat_start).max(combo);
+    let mut high_heat = heat_ramp(config.high_heat_start);
+    if berserk {
+        combat = 1.0;
+        high_heat = 1.0;
+    }
+
+    // Boss stem carries the fight; duck the base so it doesn't mud the mix
+    let (base, boss_level) = if boss { (0.6, 1.0) } else { (1.0, 0.0) };
+
+    [base, combat, high_heat, boss_level]
 }
 
 /// Current music state
@@ -61,6 +167,8 @@ pub enum MusicType {
     Menu,
     Gameplay,
     Boss,
+    /// Layered stems mixed by intensity
+    Adaptive,
 }
 
 /// Marker for music entities
@@ -101,6 +209,43 @@ fn generate_music(mut music: ResMut<MusicAssets>, mut audio_sources: ResMut<Asse
     info!("Music generation complete!");
 }
 
+/// Directory (relative to `assets/`) holding the adaptive gameplay stems
+const STEM_DIR: &str = "audio/music/stems";
+
+/// Load adaptive stems if every stem file is present
+fn load_music_stems(mut music: ResMut<MusicAssets>, asset_server: Res<AssetServer>) {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let missing = StemLayer::ALL.iter().any(|layer| {
+            !std::path::Path::new("assets")
+                .join(STEM_DIR)
+                .join(layer.file_name())
+                .exists()
+        });
+        if missing {
+            info!("No music stems found, using single-track music");
+            return;
+        }
+
+        let load = |layer: StemLayer| -> Handle<AudioSource> {
+            asset_server.load(format!("{}/{}", STEM_DIR, layer.file_name()))
+        };
+        music.stems = Some(MusicStems {
+            base: load(StemLayer::Base),
+            combat: load(StemLayer::Combat),
+            high_heat: load(StemLayer::HighHeat),
+            boss: load(StemLayer::Boss),
+        });
+        info!("Adaptive music stems loaded");
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        // No vorbis decoding on web builds
+        let _ = (&mut music, &asset_server);
+    }
+}
+
 // =============================================================================
 // MUSIC GENERATORS
 // =============================================================================
@@ -368,13 +513,17 @@ fn manage_menu_music(
     music_assets: Res<MusicAssets>,
     mut music_state: ResMut<MusicState>,
     settings: Res<crate::systems::audio::SoundSettings>,
+    stem_query: Query<Entity, With<MusicStem>>,
 ) {
     // Only start music if not already playing menu music
     if music_state.current_type != MusicType::Menu {
-        // Despawn old track
+        // Despawn old track (and any adaptive stems)
         if let Some(entity) = music_state.current_track {
             commands.entity(entity).despawn();
         }
+        for entity in stem_query.iter() {
+            commands.entity(entity).despawn();
+        }
 
         // Spawn menu music
         if let Some(source) = music_assets.menu_ambient.clone() {
@@ -408,8 +557,54 @@ fn manage_gameplay_music(
     music_assets: Res<MusicAssets>,
     mut music_state: ResMut<MusicState>,
     settings: Res<crate::systems::audio::SoundSettings>,
+    config: Res<AdaptiveMusicConfig>,
     boss_query: Query<&crate::entities::Boss>,
+    stem_query: Query<Entity, With<MusicStem>>,
 ) {
+    // Adaptive stems take over the whole gameplay soundtrack when available
+    if let Some(stems) = music_assets.stems.as_ref().filter(|_| config.enabled) {
+        if music_state.current_type == MusicType::Adaptive || !settings.enabled {
+            return;
+        }
+        if let Some(entity) = music_state.current_track.take() {
+            commands.entity(entity).despawn();
+        }
+
+        for layer in StemLayer::ALL {
+            let source = match layer {
+                StemLayer::Base => stems.base.clone(),
+                StemLayer::Combat => stems.combat.clone(),
+                StemLayer::HighHeat => stems.high_heat.clone(),
+                StemLayer::Boss => stems.boss.clone(),
+            };
+            let level = if layer == StemLayer::Base { 1.0 } else { 0.0 };
+            commands.spawn((
+                MusicTrack {
+                    music_type: MusicType::Adaptive,
+                },
+                MusicStem { layer, level },
+                AudioPlayer(source),
+                PlaybackSettings {
+                    mode: PlaybackMode::Loop,
+                    volume: Volume::new(
+                        level * settings.music_volume * settings.master_volume * 0.35,
+                    ),
+                    ..default()
+                },
+            ));
+        }
+        music_state.current_type = MusicType::Adaptive;
+        return;
+    }
+
+    // Stems were disabled mid-run - drop them before starting a single track
+    if music_state.current_type == MusicType::Adaptive {
+        for entity in stem_query.iter() {
+            commands.entity(entity).despawn();
+        }
+        music_state.current_type = MusicType::None;
+    }
+
     let has_boss = !boss_query.is_empty();
     let target_type = if has_boss {
         MusicType::Boss
@@ -455,6 +650,64 @@ fn manage_gameplay_music(
     }
 }
 
+/// Playback rate for the gameplay music: a touch faster and higher while
+/// berserk lasts
+pub fn music_rate(berserk: bool) -> f32 {
+    if berserk {
+        1.06
+    } else {
+        1.0
+    }
+}
+
+/// Speed the track (or every stem, keeping them in sync) up for berserk
+fn berserk_music_rate(
+    berserk: Res<BerserkSystem>,
+    music_state: Res<MusicState>,
+    sinks: Query<&AudioSink>,
+    stems: Query<&AudioSink, With<MusicStem>>,
+) {
+    let rate = music_rate(berserk.is_active);
+    let track = music_state.current_track.and_then(|e| sinks.get(e).ok());
+    for sink in stems.iter().chain(track) {
+        if (sink.speed() - rate).abs() > f32::EPSILON {
+            sink.set_speed(rate);
+        }
+    }
+}
+
+/// Cross-fade adaptive stems toward the current intensity
+fn mix_music_stems(
+    time: Res<Time>,
+    config: Res<AdaptiveMusicConfig>,
+    settings: Res<crate::systems::audio::SoundSettings>,
+    heat: Res<crate::systems::ComboHeatSystem>,
+    berserk: Res<BerserkSystem>,
+    boss_query: Query<&crate::entities::Boss>,
+    mut stem_query: Query<(&mut MusicStem, &AudioSink)>,
+    low_hull: Res<super::LowHullFeedback>,
+) {
+    if stem_query.is_empty() {
+        return;
+    }
+
+    let targets = stem_targets(
+        &config,
+        heat.heat,
+        heat.combo_count,
+        berserk.is_active,
+        !boss_query.is_empty(),
+    );
+    let step = config.fade_speed * time.delta_secs();
+    let master = settings.music_volume * settings.master_volume * 0.35 * low_hull.music_gain();
+
+    for (mut stem, sink) in stem_query.iter_mut() {
+        let target = targets[stem.layer as usize];
+        stem.level += (target - stem.level).clamp(-step, step);
+        sink.set_volume(stem.level * master);
+    }
+}
+
 /// Handle music transitions on state changes
 fn handle_state_music_transitions(
     mut commands: Commands,
@@ -462,6 +715,7 @@ fn handle_state_music_transitions(
     mut music_state: ResMut<MusicState>,
     settings: Res<crate::systems::audio::SoundSettings>,
     game_state: Res<State<GameState>>,
+    stem_query: Query<Entity, With<MusicStem>>,
 ) {
     // Play victory sting on victory
     if *game_state.get() == GameState::Victory && music_state.current_type != MusicType::None {
@@ -469,6 +723,9 @@ fn handle_state_music_transitions(
         if let Some(entity) = music_state.current_track {
             commands.entity(entity).despawn();
         }
+        for entity in stem_query.iter() {
+            commands.entity(entity).despawn();
+        }
         music_state.current_track = None;
         music_state.current_type = MusicType::None;
 
@@ -493,6 +750,9 @@ fn handle_state_music_transitions(
         if let Some(entity) = music_state.current_track {
             commands.entity(entity).despawn();
         }
+        for entity in stem_query.iter() {
+            commands.entity(entity).despawn();
+        }
         music_state.current_track = None;
         music_state.current_type = MusicType::None;
 
@@ -560,3 +820,40 @@ fn create_audio_source(samples: &[f32], sample_rate: u32) -> Option<AudioSource>
 fn create_audio_source(_samples: &[f32], _sample_rate: u32) -> Option<AudioSource> {
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stems_follow_heat_combo_berserk_and_boss() {
+        let config = AdaptiveMusicConfig::default();
+
+        // Calm: the base carries the track alone
+        assert_eq!(
+            stem_targets(&config, 0.0, 0, false, false),
+            [1.0, 0.0, 0.0, 0.0]
+        );
+
+        // Heat fades the combat stem in first, then the high-heat stem
+        let [_, combat, high_heat, _] = stem_targets(&config, 40.0, 0, false, false);
+        assert!(combat > 0.0 && combat < 1.0);
+        assert_eq!(high_heat, 0.0);
+        let [_, combat, high_heat, _] = stem_targets(&config, 100.0, 0, false, false);
+        assert_eq!((combat, high_heat), (1.0, 1.0));
+
+        // A long enough combo brings in the combat stem on its own
+        let [_, combat, high_heat, _] =
+            stem_targets(&config, 0.0, config.combat_combo_full, false, false);
+        assert_eq!((combat, high_heat), (1.0, 0.0));
+
+        // Berserk opens everything up
+        let [base, combat, high_heat, boss] = stem_targets(&config, 0.0, 0, true, false);
+        assert_eq!((base, combat, high_heat, boss), (1.0, 1.0, 1.0, 0.0));
+
+        // Bosses bring their stem in and duck the base
+        let [base, _, _, boss] = stem_targets(&config, 0.0, 0, false, true);
+        assert!(base < 1.0);
+        assert_eq!(boss, 1.0);
+    }
+}
@@ -0,0 +1,120 @@
+//! New Game+ Systems
+//!
+//! Scales enemies and bosses for the depth being flown as they spawn,
+//! dresses up elites, and unlocks the next depth when an Elder Fleet
+//! campaign is cleared. Wave remixing lives in `spawning` and boss phase
+//! tables in `boss`.
+
+#![allow(dead_code)]
+
+use crate::core::*;
+use crate::entities::{Boss, BossAttack, BossData, Elite, Enemy, EnemyStats, EnemyWeapon};
+use crate::ui::ToastEvent;
+use bevy::prelude::*;
+
+/// Extra health, size and score for elites, on top of the depth scaling
+const ELITE_HEALTH: f32 = 2.0;
+const ELITE_SCALE: f32 = 1.2;
+const ELITE_SCORE: u64 = 2;
+
+/// New Game+ plugin
+pub struct NewGamePlusPlugin;
+
+impl Plugin for NewGamePlusPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<NewGamePlus>()
+            .add_systems(
+                Update,
+                (apply_ng_plus_enemies, apply_ng_plus_bosses)
+                    .in_set(GameSet::Simulation)
+                    .run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight)))
+                    .run_if(resource_equals(RunMode::Campaign))
+                    .run_if(ng_plus_active),
+            )
+            .add_systems(Update, record_ng_plus_clear)
+            .add_systems(OnEnter(GameState::MainMenu), reset_ng_plus);
+    }
+}
+
+fn ng_plus_active(ng_plus: Res<NewGamePlus>) -> bool {
+    ng_plus.is_active()
+}
+
+/// Elite tint over the ship's sprite
+fn elite_tint() -> Color {
+    Color::srgb(1.0, 0.8, 0.45)
+}
+
+fn apply_ng_plus_enemies(
+    ng_plus: Res<NewGamePlus>,
+    mut enemies: Query<
+        (
+            &mut EnemyStats,
+            Option<&mut EnemyWeapon>,
+            Option<&mut Sprite>,
+            &mut Transform,
+            Has<Elite>,
+        ),
+        Added<Enemy>,
+    >,
+) {
+    let mods = ng_plus.modifiers();
+    for (mut stats, weapon, sprite, mut transform, elite) in enemies.iter_mut() {
+        let health = if elite {
+            mods.enemy_health * ELITE_HEALTH
+        } else {
+            mods.enemy_health
+        };
+        stats.max_health *= health;
+        stats.health = stats.max_health;
+        if let Some(mut weapon) = weapon {
+            weapon.fire_rate *= mods.enemy_fire_rate;
+        }
+
+        if elite {
+            stats.score_value *= ELITE_SCORE;
+            transform.scale *= ELITE_SCALE;
+            if let Some(mut sprite) = sprite {
+                sprite.color = elite_tint();
+            }
+        }
+    }
+}
+
+/// Tougher bosses that open on a higher-tier pattern
+fn apply_ng_plus_bosses(
+    ng_plus: Res<NewGamePlus>,
+    mut bosses: Query<(&mut BossData, &mut BossAttack), Added<Boss>>,
+) {
+    let mods = ng_plus.modifiers();
+    for (mut data, mut attack) in bosses.iter_mut() {
+        data.max_health *= mods.boss_health;
+        data.health = data.max_health;
+        attack.pattern = super::boss::phase_pattern(data.id, 1, ng_plus.depth);
+    }
+}
+
+/// Clearing the Elder Fleet campaign opens the next depth
+fn record_ng_plus_clear(
+    mut events: EventReader<CampaignCompleteEvent>,
+    ng_plus: Res<NewGamePlus>,
+    mut save_data: ResMut<SaveData>,
+    mut toasts: EventWriter<ToastEvent>,
+) {
+    for event in events.read() {
+        if event.module != ELDER_FLEET {
+            continue;
+        }
+        if let Some(depth) = save_data.ng_plus.record_clear(ng_plus.depth) {
+            info!("New Game+ depth {} unlocked", depth);
+            toasts.send(ToastEvent::info(format!("UNLOCKED: NEW GAME+ {}", depth)));
+        }
+    }
+}
+
+/// Runs started from the main menu are regular campaigns until picked
+fn reset_ng_plus(mut ng_plus: ResMut<NewGamePlus>) {
+    if ng_plus.is_active() {
+        *ng_plus = NewGamePlus::default();
+    }
+}
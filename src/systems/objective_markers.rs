@@ -0,0 +1,322 @@
+//! Objective Markers
+//!
+//! A labelled chevron over every objective entity - the escorted transport,
+//! the defended station, capture structures, cargo crates, the drop zone
+//! and escape pods - so objective missions read from the playfield rather
+//! than the HUD text. A target off the screen gets its marker clamped to
+//! the nearest edge instead, pointing at it with the distance from the
+//! player.
+
+use crate::core::*;
+use crate::entities::{
+    CaptureStructure, CargoCrate, DropZone, EscapePod, Player, ProtectedKind, ProtectedTarget,
+};
+use bevy::prelude::*;
+use std::f32::consts::FRAC_PI_2;
+
+/// Marker distance from the screen edge while its target is off screen
+const MARKER_INSET: f32 = 26.0;
+
+/// Gap between a target's sprite and its marker
+const MARKER_GAP: f32 = 14.0;
+
+/// Marker bob height while over an on-screen target
+const MARKER_BOB: f32 = 3.0;
+
+/// Metres shown per pixel of distance
+const METRES_PER_PIXEL: f32 = 10.0;
+
+/// Objective marker plugin
+pub struct ObjectiveMarkerPlugin;
+
+impl Plugin for ObjectiveMarkerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (attach_objective_markers, update_objective_markers)
+                .chain()
+                .in_set(GameSet::Ui)
+                .run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight))),
+        );
+    }
+}
+
+/// What an objective marker is pointing out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarkerKind {
+    Transport,
+    Station,
+    Capture,
+    Cargo,
+    DropZone,
+    EscapePod,
+}
+
+impl MarkerKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            MarkerKind::Transport => "ESCORT",
+            MarkerKind::Station => "DEFEND",
+            MarkerKind::Capture => "CAPTURE",
+            MarkerKind::Cargo => "CARGO",
+            MarkerKind::DropZone => "DROP ZONE",
+            MarkerKind::EscapePod => "POD",
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            MarkerKind::Transport | MarkerKind::Station => Color::srgb(0.45, 0.85, 1.0),
+            MarkerKind::Capture => Color::srgb(0.4, 0.95, 0.55),
+            MarkerKind::Cargo | MarkerKind::DropZone => Color::srgb(1.0, 0.8, 0.3),
+            MarkerKind::EscapePod => Color::srgb(0.95, 0.95, 0.95),
+        }
+    }
+}
+
+/// Marker for one objective entity
+#[derive(Component, Debug)]
+#[require(StateScope)]
+pub struct ObjectiveMarker {
+    pub target: Entity,
+    pub kind: MarkerKind,
+    /// Height above the target's centre while it's on screen
+    pub height: f32,
+}
+
+/// The marker's chevron (child)
+#[derive(Component, Debug)]
+pub struct ObjectiveMarkerArrow;
+
+/// The marker's label and distance (child)
+#[derive(Component, Debug)]
+pub struct ObjectiveMarkerLabel;
+
+/// Where a marker sits and how it shows its target
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MarkerPlacement {
+    pub position: Vec2,
+    /// Angle the chevron points at
+    pub angle: f32,
+    pub on_screen: bool,
+}
+
+/// Over the target while it's on screen, else on the edge toward it
+pub fn marker_placement(playfield: &Playfield, target: Vec2, height: f32) -> MarkerPlacement {
+    if playfield.contains(target, 0.0) {
+        let above = target + Vec2::Y * height;
+        return MarkerPlacement {
+            position: playfield.clamp(above, MARKER_INSET),
+            angle: -FRAC_PI_2,
+            on_screen: true,
+        };
+    }
+    let position = playfield.clamp(target, MARKER_INSET);
+    MarkerPlacement {
+        position,
+        angle: (target - position).to_angle(),
+        on_screen: false,
+    }
+}
+
+/// Distance readout for a gap of `pixels`
+pub fn format_distance(pixels: f32) -> String {
+    let metres = pixels * METRES_PER_PIXEL;
+    if metres >= 1000.0 {
+        format!("{:.1} km", metres / 1000.0)
+    } else {
+        format!("{:.0} m", (metres / 10.0).round() * 10.0)
+    }
+}
+
+/// Mark each objective entity as it appears
+fn attach_objective_markers(
+    mut commands: Commands,
+    targets: Query<
+        (
+            Entity,
+            Option<&Sprite>,
+            Option<&ProtectedTarget>,
+            Has<CaptureStructure>,
+            Has<CargoCrate>,
+            Has<DropZone>,
+        ),
+        Or<(
+            Added<ProtectedTarget>,
+            Added<CaptureStructure>,
+            Added<CargoCrate>,
+            Added<DropZone>,
+            Added<EscapePod>,
+        )>,
+    >,
+) {
+    for (entity, sprite, protected, capture, cargo, drop_zone) in targets.iter() {
+        let kind = match protected.map(|p| p.kind) {
+            Some(ProtectedKind::Transport) => MarkerKind::Transport,
+            Some(ProtectedKind::Station) => MarkerKind::Station,
+            None if capture => MarkerKind::Capture,
+            None if cargo => MarkerKind::Cargo,
+            None if drop_zone => MarkerKind::DropZone,
+            None => MarkerKind::EscapePod,
+        };
+        let half_height = sprite
+            .and_then(|s| s.custom_size)
+            .map_or(10.0, |size| size.y / 2.0);
+        spawn_objective_marker(&mut commands, entity, kind, half_height + MARKER_GAP);
+    }
+}
+
+fn spawn_objective_marker(commands: &mut Commands, target: Entity, kind: MarkerKind, height: f32) {
+    let color = kind.color();
+    commands
+        .spawn((
+            ObjectiveMarker {
+                target,
+                kind,
+                height,
+            },
+            Transform::from_xyz(0.0, 0.0, LAYER_EFFECTS + 3.0),
+            // Shown once placed
+            Visibility::Hidden,
+        ))
+        .with_children(|marker| {
+            marker
+                .spawn((
+                    ObjectiveMarkerArrow,
+                    Transform::default(),
+                    Visibility::Inherited,
+                ))
+                .with_children(|arrow| {
+                    // Chevron pointing along +X
+                    for side in [-1.0, 1.0] {
+                        arrow.spawn((
+                            Sprite {
+                                color: color.with_alpha(0.85),
+                                custom_size: Some(Vec2::new(12.0, 3.0)),
+                                ..default()
+                            },
+                            Transform::from_xyz(-4.0, side * 4.0, 0.0)
+                                .with_rotation(Quat::from_rotation_z(-side * 0.7)),
+                        ));
+                    }
+                });
+            marker.spawn((
+                ObjectiveMarkerLabel,
+                Text2d::new(kind.label()),
+                TextFont {
+                    font_size: 10.0,
+                    ..default()
+                },
+                TextColor(color.with_alpha(0.9)),
+                Transform::from_xyz(0.0, 14.0, 0.0),
+            ));
+        });
+}
+
+/// Follow each target, or clamp to the edge toward it; drop markers whose
+/// target is gone
+fn update_objective_markers(
+    mut commands: Commands,
+    time: Res<Time>,
+    playfield: Res<Playfield>,
+    player: Query<Entity, With<Player>>,
+    mut markers: Query<
+        (
+            Entity,
+            &ObjectiveMarker,
+            &Children,
+            &mut Transform,
+            &mut Visibility,
+        ),
+        (Without<ObjectiveMarkerArrow>, Without<ObjectiveMarkerLabel>),
+    >,
+    targets: Query<
+        &Transform,
+        (
+            Without<ObjectiveMarker>,
+            Without<ObjectiveMarkerArrow>,
+            Without<ObjectiveMarkerLabel>,
+        ),
+    >,
+    mut arrows: Query<&mut Transform, (With<ObjectiveMarkerArrow>, Without<ObjectiveMarkerLabel>)>,
+    mut labels: Query<(&mut Transform, &mut Text2d), With<ObjectiveMarkerLabel>>,
+) {
+    let player_pos = player
+        .get_single()
+        .ok()
+        .and_then(|player| targets.get(player).ok())
+        .map(|t| t.translation.truncate());
+    let bob = (time.elapsed_secs() * 4.0).sin() * MARKER_BOB;
+
+    for (entity, marker, children, mut transform, mut visibility) in markers.iter_mut() {
+        let Ok(target) = targets.get(marker.target) else {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        };
+        let target_pos = target.translation.truncate();
+        let placement = marker_placement(&playfield, target_pos, marker.height);
+
+        let position = if placement.on_screen {
+            placement.position + Vec2::Y * bob
+        } else {
+            placement.position
+        };
+        transform.translation = position.extend(transform.translation.z);
+        *visibility = Visibility::Visible;
+
+        // Off screen the label sits inward of the arrow, with the distance
+        let (label_offset, text) = if placement.on_screen {
+            (Vec2::Y * 14.0, marker.kind.label().to_string())
+        } else {
+            let inward = -Vec2::from_angle(placement.angle) * 22.0;
+            let text = match player_pos {
+                Some(player) => format!(
+                    "{} {}",
+                    marker.kind.label(),
+                    format_distance(player.distance(target_pos))
+                ),
+                None => marker.kind.label().to_string(),
+            };
+            (inward, text)
+        };
+
+        for child in children.iter() {
+            if let Ok(mut arrow) = arrows.get_mut(*child) {
+                arrow.rotation = Quat::from_rotation_z(placement.angle);
+            }
+            if let Ok((mut label_transform, mut label)) = labels.get_mut(*child) {
+                label_transform.translation = label_offset.extend(label_transform.translation.z);
+                if label.0 != text {
+                    label.0 = text.clone();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn markers_sit_over_visible_targets_and_clamp_to_the_edge_otherwise() {
+        let playfield = Playfield::default();
+        let over = marker_placement(&playfield, Vec2::new(50.0, 0.0), 30.0);
+        assert!(over.on_screen);
+        assert_eq!(over.position, Vec2::new(50.0, 30.0));
+        assert_eq!(over.angle, -FRAC_PI_2);
+
+        // Off the right edge: on the edge, pointing right
+        let target = Vec2::new(playfield.half_width() + 300.0, 0.0);
+        let edge = marker_placement(&playfield, target, 30.0);
+        assert!(!edge.on_screen);
+        assert_eq!(edge.position.x, playfield.half_width() - MARKER_INSET);
+        assert!(edge.angle.abs() < 1e-4);
+    }
+
+    #[test]
+    fn distances_read_in_metres_then_kilometres() {
+        assert_eq!(format_distance(42.0), "420 m");
+        assert_eq!(format_distance(250.0), "2.5 km");
+    }
+}
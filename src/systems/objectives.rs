@@ -0,0 +1,222 @@
+//! Objective Tracking
+//!
+//! Feeds gameplay events into the `ObjectiveTracker` and evaluates the
+//! mission's objectives each frame. Bonus objectives drive
+//! `CampaignState::bonus_complete` for the results screen; a failed primary
+//! (an escort or station lost) ends the run. In Time/Score Attack the mode's
+//! own win condition replaces the mission objectives and finishing it posts
+//! the run to the mission leaderboard with its checksum (unless its input
+//! was flagged) and grades it for the mission's medals; a finished Daily
+//! Challenge records the day's score instead.
+
+#![allow(dead_code)]
+
+use crate::core::*;
+use crate::ui::ToastEvent;
+use bevy::prelude::*;
+
+/// Points for each cargo run delivered
+const CARGO_DELIVERY_SCORE: u64 = 750;
+
+/// Points for an escort brought home alive
+const ESCORT_ARRIVAL_SCORE: u64 = 2000;
+
+/// Objective tracking plugin
+pub struct ObjectivePlugin;
+
+impl Plugin for ObjectivePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ObjectiveTracker>().add_systems(
+            Update,
+            (
+                start_objectives,
+                count_objective_events,
+                evaluate_objectives,
+                finish_attack_run,
+            )
+                .chain()
+                .in_set(GameSet::Scoring)
+                .run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight))),
+        );
+    }
+}
+
+/// Load the new mission's objectives
+fn start_objectives(
+    mut events: EventReader<MissionStartEvent>,
+    campaign: Res<CampaignState>,
+    run_mode: Res<RunMode>,
+    mut tracker: ResMut<ObjectiveTracker>,
+) {
+    if events.read().last().is_some() {
+        tracker.start(
+            run_mode
+                .objectives()
+                .unwrap_or_else(|| campaign.objectives()),
+        );
+    }
+}
+
+/// Count kills, hits, pods and mission targets towards objectives, and pay
+/// out for deliveries and escorts that make it
+fn count_objective_events(
+    mut tracker: ResMut<ObjectiveTracker>,
+    mut score_events: EventWriter<ScoreEvent>,
+    mut destroyed: EventReader<EnemyDestroyedEvent>,
+    mut damaged: EventReader<PlayerDamagedEvent>,
+    mut extracted: EventReader<EscapePodExtractedEvent>,
+    mut lost: EventReader<EscapePodLostEvent>,
+    (mut arrived, mut targets_lost, mut delivered, mut captures): (
+        EventReader<EscortArrivedEvent>,
+        EventReader<ProtectedTargetLostEvent>,
+        EventReader<CargoDeliveredEvent>,
+        EventReader<CaptureProgressEvent>,
+    ),
+) {
+    for event in destroyed.read() {
+        tracker.on_enemy_destroyed(event.type_id);
+    }
+    if damaged.read().count() > 0 {
+        tracker.on_player_damaged();
+    }
+    for _ in extracted.read() {
+        tracker.on_pod_extracted();
+    }
+    if lost.read().count() > 0 {
+        tracker.on_pod_lost();
+    }
+    for event in arrived.read() {
+        tracker.on_escort_arrived(event.objective);
+        score_events
+            .send(ScoreEvent::new(ScoreCategory::Escort, ESCORT_ARRIVAL_SCORE).at(event.position));
+    }
+    for event in targets_lost.read() {
+        tracker.on_target_destroyed(event.objective);
+    }
+    for event in delivered.read() {
+        tracker.on_cargo_delivered();
+        score_events.send(
+            ScoreEvent::new(ScoreCategory::Objective, CARGO_DELIVERY_SCORE).at(event.position),
+        );
+    }
+    for event in captures.read() {
+        tracker.on_capture_progress(event.objective, event.progress, event.contested);
+    }
+}
+
+/// Measure mission progress and publish the bonus result
+fn evaluate_objectives(
+    time: Res<Time>,
+    mut tracker: ResMut<ObjectiveTracker>,
+    mut campaign: ResMut<CampaignState>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if tracker.is_empty() {
+        return;
+    }
+
+    tracker.evaluate(&ObjectiveSnapshot {
+        waves_cleared: campaign.waves_cleared.min(campaign.enemy_waves()),
+        souls: campaign.mission_souls,
+        mission_time: campaign.mission_timer,
+        boss_defeated: campaign.boss_defeated,
+    });
+    tracker.tick(time.delta_secs());
+
+    let bonus_complete = tracker.tier_complete(ObjectiveTier::Bonus);
+    if campaign.bonus_complete != bonus_complete {
+        campaign.bonus_complete = bonus_complete;
+    }
+
+    if tracker.tier_failed(ObjectiveTier::Primary) {
+        info!("Primary objective failed - mission lost");
+        next_state.set(GameState::GameOver);
+    }
+}
+
+/// Time/Score Attack: the run ends once the mode's objective is met
+fn finish_attack_run(
+    run_mode: Res<RunMode>,
+    mut tracker: ResMut<ObjectiveTracker>,
+    campaign: Res<CampaignState>,
+    score: Res<ScoreSystem>,
+    session: Res<GameSession>,
+    daily: Res<DailyChallenge>,
+    input_log: Res<super::RunInputLog>,
+    mut save_data: ResMut<SaveData>,
+    mut result: ResMut<AttackResult>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut toasts: EventWriter<ToastEvent>,
+) {
+    if !run_mode.is_attack() || !tracker.tier_complete(ObjectiveTier::Primary) {
+        return;
+    }
+    let Some(mission) = campaign.current_mission() else {
+        return;
+    };
+
+    let value = match *run_mode {
+        RunMode::TimeAttack => (campaign.mission_timer * 1000.0) as u64,
+        _ => score.score,
+    };
+    let integrity = input_log.log.seal(*run_mode, mission.id, value);
+    info!(
+        "Run seed {:#x}, replay {:016X}, checksum {}",
+        integrity.seed,
+        integrity.replay_hash,
+        integrity.verify_code()
+    );
+    let rank = if *run_mode == RunMode::Daily {
+        save_data.finish_daily(daily.day, value, true);
+        None
+    } else if let Some(flag) = integrity.flag {
+        // Turbo and macro runs still count for medals, just not the board
+        toasts.send(ToastEvent::info(format!(
+            "Run not ranked: {}",
+            flag.description()
+        )));
+        None
+    } else {
+        save_data.record_leaderboard(
+            *run_mode,
+            mission.id,
+            LeaderboardEntry {
+                value,
+                ship: session.selected_ship().name.to_string(),
+                integrity: Some(integrity),
+            },
+        )
+    };
+    info!(
+        "{} {} finished: {} (rank {:?})",
+        run_mode.name(),
+        mission.id,
+        run_mode.format_value(value),
+        rank
+    );
+
+    let medal = medal_thresholds(mission.id).grade(*run_mode, value);
+    let gold_before = Achievement::GoldStandard.earned(&save_data.prestige);
+    let new_medal =
+        medal.is_some_and(|medal| save_data.prestige.award_medal(*run_mode, mission.id, medal));
+    if !gold_before && Achievement::GoldStandard.earned(&save_data.prestige) {
+        toasts.send(ToastEvent::info(format!(
+            "Achievement: {} - {}",
+            Achievement::GoldStandard.name(),
+            Achievement::GoldStandard.description()
+        )));
+    }
+
+    *result = AttackResult {
+        mode: *run_mode,
+        mission_id: mission.id,
+        value,
+        rank,
+        medal,
+        new_medal,
+        integrity: Some(integrity),
+    };
+    // Done with this run; don't post it again while the state changes
+    tracker.clear();
+    next_state.set(GameState::StageComplete);
+}
@@ -0,0 +1,175 @@
+//! Prestige Tracking
+//!
+//! Banks each run's kills and score into the player's profile under the
+//! active module, awards prestige when a module's campaign is cleared, and
+//! fits campaign launches with the unlocked starting upgrade and any run
+//! modifiers switched on from the profile or mutators screen. Attack and
+//! daily runs fly stock so their leaderboards stay fair.
+
+#![allow(dead_code)]
+
+use crate::core::*;
+use crate::entities::{Enemy, EnemyWeapon, Movement, Player, ShipStats, Weapon};
+use crate::games::ActiveModule;
+use crate::ui::ToastEvent;
+use bevy::prelude::*;
+
+/// Prestige plugin
+pub struct PrestigePlugin;
+
+impl Plugin for PrestigePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ProfileSession>()
+            .add_systems(
+                Update,
+                count_profile_kills
+                    .in_set(GameSet::Scoring)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                (apply_prestige_loadout, apply_enemy_mutators)
+                    .in_set(GameSet::Simulation)
+                    .run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight)))
+                    .run_if(resource_equals(RunMode::Campaign)),
+            )
+            .add_systems(Update, award_campaign_prestige)
+            .add_systems(OnEnter(GameState::StageComplete), bank_mission)
+            .add_systems(OnEnter(GameState::GameOver), bank_failed_run)
+            .add_systems(OnEnter(GameState::MainMenu), reset_profile_session);
+    }
+}
+
+/// Kills not yet banked into the profile
+#[derive(Resource, Debug, Default)]
+pub struct ProfileSession {
+    pub kills: u64,
+}
+
+/// Profile id of the module being flown (Endless flies Elder Fleet)
+pub fn active_module_id(active: &ActiveModule) -> &'static str {
+    if active.is_caldari_gallente() {
+        CALDARI_PRIME
+    } else {
+        ELDER_FLEET
+    }
+}
+
+fn count_profile_kills(
+    mut session: ResMut<ProfileSession>,
+    mut destroy_events: EventReader<EnemyDestroyedEvent>,
+) {
+    session.kills += destroy_events.read().count() as u64;
+}
+
+fn bank_mission(
+    mut session: ResMut<ProfileSession>,
+    score: Res<ScoreSystem>,
+    active: Res<ActiveModule>,
+    mut save_data: ResMut<SaveData>,
+) {
+    let module = active_module_id(&active);
+    save_data
+        .prestige
+        .record(module, session.kills, score.score, false);
+    session.kills = 0;
+}
+
+fn bank_failed_run(
+    mut session: ResMut<ProfileSession>,
+    score: Res<ScoreSystem>,
+    active: Res<ActiveModule>,
+    mut save_data: ResMut<SaveData>,
+) {
+    let module = active_module_id(&active);
+    save_data
+        .prestige
+        .record(module, session.kills, score.score, true);
+    session.kills = 0;
+}
+
+/// Runs abandoned from the pause menu bank nothing
+fn reset_profile_session(mut session: ResMut<ProfileSession>) {
+    session.kills = 0;
+}
+
+/// Close out a cleared campaign and announce what it unlocked
+fn award_campaign_prestige(
+    mut events: EventReader<CampaignCompleteEvent>,
+    mut session: ResMut<ProfileSession>,
+    score: Res<ScoreSystem>,
+    difficulty: Res<Difficulty>,
+    mut save_data: ResMut<SaveData>,
+    mut toasts: EventWriter<ToastEvent>,
+) {
+    for event in events.read() {
+        let prestige = &mut save_data.prestige;
+        prestige.record(event.module, session.kills, score.score, true);
+        session.kills = 0;
+
+        let before = prestige.points;
+        let unlocked = prestige.complete_campaign(event.module, *difficulty);
+        info!(
+            "Campaign {} cleared: +{} prestige ({} total)",
+            event.module,
+            prestige.points - before,
+            prestige.points
+        );
+        toasts.send(ToastEvent::info(format!(
+            "+{} PRESTIGE - {}",
+            prestige.points - before,
+            prestige.rank()
+        )));
+        for reward in unlocked {
+            toasts.send(ToastEvent::info(format!("UNLOCKED: {}", reward.name)));
+        }
+    }
+}
+
+/// Fit the player's ship with the starting upgrade and active modifiers
+fn apply_prestige_loadout(
+    save_data: Res<SaveData>,
+    mut players: Query<(&mut ShipStats, &mut Weapon, &mut Movement), Added<Player>>,
+) {
+    let prestige = &save_data.prestige;
+    for (mut stats, mut weapon, mut movement) in players.iter_mut() {
+        if prestige.has_starting_upgrade() {
+            stats.max_armor *= SALVAGED_PLATING_ARMOR;
+            stats.armor = stats.max_armor;
+        }
+        for modifier in prestige.active_modifiers.iter() {
+            match modifier {
+                RunModifier::GlassCannon => {
+                    weapon.damage *= 1.5;
+                    stats.max_shield *= 0.5;
+                    stats.shield = stats.max_shield;
+                    stats.max_armor *= 0.5;
+                    stats.armor = stats.max_armor;
+                }
+                RunModifier::Ironclad => {
+                    stats.max_armor *= 1.5;
+                    stats.armor = stats.max_armor;
+                    stats.max_hull *= 1.5;
+                    stats.hull = stats.max_hull;
+                    movement.max_speed *= 0.8;
+                    movement.acceleration *= 0.8;
+                }
+                // Rules for enemies, scoring and loot, not the ship
+                RunModifier::BulletHell | RunModifier::IronClone | RunModifier::GoldenAge => {}
+            }
+        }
+    }
+}
+
+/// Bullet Hell doubles every enemy's rate of fire as it spawns
+fn apply_enemy_mutators(
+    save_data: Res<SaveData>,
+    mut weapons: Query<&mut EnemyWeapon, Added<Enemy>>,
+) {
+    if !save_data.prestige.modifier_active(RunModifier::BulletHell) {
+        return;
+    }
+    for mut weapon in weapons.iter_mut() {
+        weapon.fire_rate *= 2.0;
+    }
+}
@@ -0,0 +1,409 @@
+//! Projectile Visuals
+//!
+//! Dresses each shot by its weapon doctrine as it spawns: the doctrine's
+//! bolt sprite when one is bundled (the colored quad stays otherwise), a
+//! short glowing streak behind it, and a muzzle flash where it left the
+//! barrel. Hits leave a brief impact flash. Sprites only alpha-blend, so
+//! the glow is layered - a wide faint halo under a thin bright core -
+//! rather than truly additive.
+//!
+//! Enemy fire can trade its faction colors for one standard palette (or a
+//! colorblind-safe one) before it's dressed, so dense patterns read the
+//! same whoever fires them: round shots take one color, needles the other.
+
+#![allow(dead_code)]
+
+use super::effects::MotionSettings;
+use crate::assets::ProjectileSpriteCache;
+use crate::core::*;
+use crate::entities::{ProjectileOwner, ProjectileWeapon, Team};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Flashes alive at once; muzzle flashes are skipped past this
+const MAX_FLASHES: usize = 120;
+
+/// Projectile visuals plugin
+pub struct ProjectileFxPlugin;
+
+impl Plugin for ProjectileFxPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BulletPalette>().add_systems(
+            Update,
+            (
+                standardize_enemy_fire,
+                dress_projectiles,
+                skin_flashes,
+                update_flashes,
+            )
+                .chain()
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
+
+/// Colors for enemy fire that can hurt the player
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BulletPalette {
+    /// Each weapon keeps its faction's color
+    #[default]
+    Faction,
+    /// Classic shmup pink and cyan
+    Classic,
+    /// Orange and blue, apart for red-green colorblindness
+    RedGreenSafe,
+    /// Red and white, apart for blue-yellow colorblindness
+    BlueYellowSafe,
+}
+
+impl BulletPalette {
+    const ALL: [BulletPalette; 4] = [
+        BulletPalette::Faction,
+        BulletPalette::Classic,
+        BulletPalette::RedGreenSafe,
+        BulletPalette::BlueYellowSafe,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            BulletPalette::Faction => "FACTION",
+            BulletPalette::Classic => "CLASSIC",
+            BulletPalette::RedGreenSafe => "DEUTAN/PROTAN",
+            BulletPalette::BlueYellowSafe => "TRITAN",
+        }
+    }
+
+    pub fn stepped(&self, step: i32) -> Self {
+        let index = Self::ALL.iter().position(|p| p == self).unwrap_or(0) as i32;
+        Self::ALL[(index + step).rem_euclid(Self::ALL.len() as i32) as usize]
+    }
+
+    /// Round shots' and needles' colors, or `None` to keep faction colors
+    pub fn colors(&self) -> Option<(Color, Color)> {
+        match self {
+            BulletPalette::Faction => None,
+            BulletPalette::Classic => {
+                Some((Color::srgb(1.0, 0.35, 0.8), Color::srgb(0.3, 0.95, 1.0)))
+            }
+            BulletPalette::RedGreenSafe => {
+                Some((Color::srgb(1.0, 0.6, 0.1), Color::srgb(0.3, 0.55, 1.0)))
+            }
+            BulletPalette::BlueYellowSafe => {
+                Some((Color::srgb(1.0, 0.2, 0.35), Color::srgb(0.95, 0.95, 0.95)))
+            }
+        }
+    }
+
+    /// Color for a shot of `size`: needles are at least twice as long as
+    /// they are wide
+    pub fn tint(&self, size: Vec2) -> Option<Color> {
+        let (round, needle) = self.colors()?;
+        Some(if size.y >= size.x * 2.0 {
+            needle
+        } else {
+            round
+        })
+    }
+}
+
+/// How a doctrine's shots look in flight
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoltStyle {
+    /// Streak length behind the bolt (pixels)
+    pub streak: f32,
+    /// Halo width as a multiple of the bolt's width
+    pub glow_width: f32,
+    /// Muzzle flash size (pixels)
+    pub muzzle: f32,
+    /// Impact flash size (pixels)
+    pub impact: f32,
+}
+
+impl BoltStyle {
+    pub fn for_doctrine(doctrine: WeaponDoctrine) -> Self {
+        match doctrine {
+            // Thin tracers
+            WeaponDoctrine::Projectile => Self {
+                streak: 18.0,
+                glow_width: 2.0,
+                muzzle: 14.0,
+                impact: 16.0,
+            },
+            // Long beam pulses
+            WeaponDoctrine::Laser => Self {
+                streak: 34.0,
+                glow_width: 3.0,
+                muzzle: 18.0,
+                impact: 20.0,
+            },
+            // Exhaust plume and a fat warhead burst
+            WeaponDoctrine::Missile => Self {
+                streak: 24.0,
+                glow_width: 2.5,
+                muzzle: 20.0,
+                impact: 28.0,
+            },
+            // Short plasma blobs with a wide bloom
+            WeaponDoctrine::Hybrid => Self {
+                streak: 12.0,
+                glow_width: 3.5,
+                muzzle: 16.0,
+                impact: 22.0,
+            },
+        }
+    }
+
+    pub fn for_weapon(weapon: WeaponType) -> Self {
+        Self::for_doctrine(WeaponDoctrine::for_weapon(weapon))
+    }
+}
+
+/// Glow layer behind a projectile
+#[derive(Component)]
+pub struct ProjectileStreak;
+
+/// Where a flash came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlashKind {
+    Muzzle,
+    Impact,
+}
+
+impl FlashKind {
+    /// Seconds on screen
+    pub fn duration(&self) -> f32 {
+        match self {
+            FlashKind::Muzzle => 0.06,
+            FlashKind::Impact => 0.12,
+        }
+    }
+}
+
+/// Short-lived muzzle or impact flash
+#[derive(Component)]
+#[require(StateScope)]
+pub struct ProjectileFlash {
+    pub kind: FlashKind,
+    pub lifetime: f32,
+    /// Full size (pixels) the flash grows to
+    pub size: f32,
+}
+
+impl ProjectileFlash {
+    /// Fraction of the flash's life left (1.0 = just spawned)
+    pub fn remaining(&self) -> f32 {
+        (self.lifetime / self.kind.duration()).clamp(0.0, 1.0)
+    }
+}
+
+/// `color` washed toward white, at `alpha`
+fn glow(color: Color, whiten: f32, alpha: f32) -> Color {
+    let c = color.to_srgba();
+    Color::srgba(
+        c.red + (1.0 - c.red) * whiten,
+        c.green + (1.0 - c.green) * whiten,
+        c.blue + (1.0 - c.blue) * whiten,
+        alpha,
+    )
+}
+
+/// Brief flash where a shot hit, sized by the weapon that fired it
+pub fn spawn_impact_flash(
+    commands: &mut Commands,
+    position: Vec2,
+    color: Color,
+    weapon: Option<WeaponType>,
+) {
+    let size = weapon.map_or(16.0, |w| BoltStyle::for_weapon(w).impact);
+    spawn_flash(commands, FlashKind::Impact, position, color, size);
+}
+
+fn spawn_flash(commands: &mut Commands, kind: FlashKind, position: Vec2, color: Color, size: f32) {
+    commands.spawn((
+        ProjectileFlash {
+            kind,
+            lifetime: kind.duration(),
+            size,
+        },
+        Sprite {
+            color: glow(color, 0.5, 0.9),
+            custom_size: Some(Vec2::splat(size * 0.5)),
+            ..default()
+        },
+        // A diamond until a flash sprite replaces the quad
+        Transform::from_xyz(position.x, position.y, LAYER_EFFECTS)
+            .with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4)),
+    ));
+}
+
+/// Recolor fresh enemy fire with the chosen palette, ahead of its streak
+fn standardize_enemy_fire(
+    palette: Res<BulletPalette>,
+    mut shots: Query<(&ProjectileOwner, &mut Sprite), Added<ProjectileOwner>>,
+) {
+    if *palette == BulletPalette::Faction {
+        return;
+    }
+    for (owner, mut sprite) in shots.iter_mut() {
+        if !owner.hits(Team::Player) {
+            continue;
+        }
+        let size = sprite.custom_size.unwrap_or(Vec2::splat(6.0));
+        if let Some(color) = palette.tint(size) {
+            sprite.color = color.with_alpha(sprite.color.alpha());
+        }
+    }
+}
+
+/// Give fresh shots their doctrine sprite, streak and muzzle flash
+fn dress_projectiles(
+    mut commands: Commands,
+    cache: Res<ProjectileSpriteCache>,
+    motion: Res<MotionSettings>,
+    flashes: Query<(), With<ProjectileFlash>>,
+    mut shots: Query<(Entity, &ProjectileWeapon, &Transform, &mut Sprite), Added<ProjectileWeapon>>,
+) {
+    let mut flash_budget = MAX_FLASHES.saturating_sub(flashes.iter().count());
+
+    for (entity, weapon, transform, mut sprite) in shots.iter_mut() {
+        let doctrine = WeaponDoctrine::for_weapon(weapon.0);
+        let style = BoltStyle::for_doctrine(doctrine);
+        if let Some(image) = cache.bolt(doctrine) {
+            sprite.image = image;
+        }
+        let color = sprite.color;
+        let width = sprite.custom_size.map_or(4.0, |size| size.x);
+
+        // Sprites point along +Y, so the streak trails down the local axis
+        commands.entity(entity).with_children(|parent| {
+            parent.spawn((
+                ProjectileStreak,
+                Sprite {
+                    color: glow(color, 0.2, 0.18),
+                    custom_size: Some(Vec2::new(width * style.glow_width, style.streak)),
+                    ..default()
+                },
+                Transform::from_xyz(0.0, -style.streak / 2.0, -0.2),
+            ));
+            parent.spawn((
+                ProjectileStreak,
+                Sprite {
+                    color: glow(color, 0.6, 0.5),
+                    custom_size: Some(Vec2::new(width * 0.5, style.streak * 0.6)),
+                    ..default()
+                },
+                Transform::from_xyz(0.0, -style.streak * 0.3, -0.1),
+            ));
+        });
+
+        // Reduced motion skips the per-shot pop
+        if motion.reduced_motion || flash_budget == 0 {
+            continue;
+        }
+        flash_budget -= 1;
+        spawn_flash(
+            &mut commands,
+            FlashKind::Muzzle,
+            transform.translation.truncate(),
+            color,
+            style.muzzle,
+        );
+    }
+}
+
+/// Swap the fallback quad for a bundled flash sprite
+fn skin_flashes(
+    cache: Res<ProjectileSpriteCache>,
+    mut flashes: Query<(&ProjectileFlash, &mut Sprite), Added<ProjectileFlash>>,
+) {
+    for (flash, mut sprite) in flashes.iter_mut() {
+        let image = match flash.kind {
+            FlashKind::Muzzle => cache.muzzle_flash.clone(),
+            FlashKind::Impact => cache.impact_flash.clone(),
+        };
+        if let Some(image) = image {
+            sprite.image = image;
+        }
+    }
+}
+
+/// Grow and fade flashes out
+fn update_flashes(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut flashes: Query<(Entity, &mut ProjectileFlash, &mut Sprite)>,
+) {
+    let dt = time.delta_secs();
+
+    for (entity, mut flash, mut sprite) in flashes.iter_mut() {
+        flash.lifetime -= dt;
+        if flash.lifetime <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let remaining = flash.remaining();
+        sprite.custom_size = Some(Vec2::splat(flash.size * (1.0 - remaining * 0.5)));
+        sprite.color = sprite.color.with_alpha(remaining * 0.9);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn palettes_split_round_shots_from_needles() {
+        assert_eq!(BulletPalette::Faction.tint(Vec2::splat(6.0)), None);
+        for palette in [
+            BulletPalette::Classic,
+            BulletPalette::RedGreenSafe,
+            BulletPalette::BlueYellowSafe,
+        ] {
+            let (round, needle) = palette.colors().unwrap();
+            assert_ne!(round, needle);
+            assert_eq!(palette.tint(Vec2::new(12.0, 12.0)), Some(round));
+            assert_eq!(palette.tint(Vec2::new(3.0, 16.0)), Some(needle));
+        }
+        assert_eq!(
+            BulletPalette::Faction.stepped(-1),
+            BulletPalette::BlueYellowSafe
+        );
+        assert_eq!(
+            BulletPalette::BlueYellowSafe.stepped(1),
+            BulletPalette::Faction
+        );
+    }
+
+    #[test]
+    fn each_doctrine_has_its_own_look() {
+        let styles = [
+            WeaponDoctrine::Projectile,
+            WeaponDoctrine::Laser,
+            WeaponDoctrine::Missile,
+            WeaponDoctrine::Hybrid,
+        ]
+        .map(BoltStyle::for_doctrine);
+        for (i, a) in styles.iter().enumerate() {
+            assert!(a.streak > 0.0 && a.muzzle > 0.0 && a.impact > 0.0);
+            assert!(styles[i + 1..].iter().all(|b| b != a));
+        }
+        // Drones share the hybrid look, artillery the autocannon one
+        assert_eq!(
+            BoltStyle::for_weapon(WeaponType::Drone),
+            BoltStyle::for_doctrine(WeaponDoctrine::Hybrid)
+        );
+        assert_eq!(
+            BoltStyle::for_weapon(WeaponType::Artillery),
+            BoltStyle::for_weapon(WeaponType::Autocannon)
+        );
+    }
+
+    #[test]
+    fn glow_whitens_toward_white_without_overshooting() {
+        let red = Color::srgb(1.0, 0.0, 0.0);
+        assert_eq!(glow(red, 0.0, 0.5), Color::srgba(1.0, 0.0, 0.0, 0.5));
+        assert_eq!(glow(red, 1.0, 0.5), Color::srgba(1.0, 1.0, 1.0, 0.5));
+        let half = glow(red, 0.5, 1.0).to_srgba();
+        assert!((half.green - 0.5).abs() < 1e-6);
+    }
+}
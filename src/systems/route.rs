@@ -0,0 +1,162 @@
+//! Route Detours
+//!
+//! Carries what the route map decided into the next mission: a powerup
+//! bought at a black market is waiting at warp-in, and an elite wing that
+//! shadowed the route drops in a few seconds after it. Elites are tougher
+//! than their regular class, tinted gold, and pay a bounty in credits and
+//! ISK when downed.
+
+#![allow(dead_code)]
+
+use crate::assets::PowerupIconCache;
+use crate::core::*;
+use crate::entities::{spawn_collectible, spawn_enemy, EnemyBehavior, EnemyStats};
+use crate::systems::{DialogueEvent, DialogueTrigger};
+use bevy::prelude::*;
+
+/// Where a bought powerup waits, below the middle of the playfield
+const DELIVERY_POSITION: Vec2 = Vec2::new(0.0, -60.0);
+
+/// Spacing between ambushing elites
+const AMBUSH_SPACING: f32 = 140.0;
+
+/// Gold tint marking an elite
+const ELITE_TINT: Color = Color::srgb(1.0, 0.85, 0.45);
+
+/// Route detour plugin
+pub struct RoutePlugin;
+
+impl Plugin for RoutePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RouteState>()
+            .add_systems(OnEnter(GameState::ShipSelect), reset_route)
+            .add_systems(
+                Update,
+                (start_route_detours, spring_ambush, toughen_elites)
+                    .chain()
+                    .in_set(GameSet::Simulation)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                pay_elite_bounties
+                    .in_set(GameSet::Scoring)
+                    .run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight))),
+            );
+    }
+}
+
+/// An elite from a route ambush
+#[derive(Component, Debug)]
+#[require(StateScope)]
+pub struct AmbushElite;
+
+/// A new run starts with a clean route
+fn reset_route(mut route: ResMut<RouteState>) {
+    *route = RouteState::default();
+}
+
+/// Deliver what was bought and set the ambush clock as the mission starts
+fn start_route_detours(
+    mut commands: Commands,
+    mut events: EventReader<MissionStartEvent>,
+    mut route: ResMut<RouteState>,
+    icon_cache: Option<Res<PowerupIconCache>>,
+) {
+    if events.read().last().is_none() {
+        return;
+    }
+
+    route.ambush_wing.clear();
+    route.ambush_timer = None;
+    if let Some(item) = route.stocked.take() {
+        info!("Black market delivery: {:?}", item);
+        spawn_collectible(
+            &mut commands,
+            DELIVERY_POSITION,
+            item,
+            icon_cache.as_deref(),
+        );
+    }
+    if std::mem::take(&mut route.ambush_armed) {
+        route.ambush_timer = Some(AMBUSH_DELAY);
+    }
+}
+
+/// Drop the elite wing in once its delay runs out
+fn spring_ambush(
+    mut commands: Commands,
+    time: Res<Time>,
+    campaign: Res<CampaignState>,
+    playfield: Res<Playfield>,
+    mut route: ResMut<RouteState>,
+    mut dialogue_events: EventWriter<DialogueEvent>,
+) {
+    let Some(left) = route.ambush_timer.as_mut() else {
+        return;
+    };
+    *left -= time.delta_secs();
+    if *left > 0.0 {
+        return;
+    }
+    route.ambush_timer = None;
+
+    let wing = ambush_wing(campaign.act);
+    let top = playfield.half().y + 40.0;
+    let offset = (wing.len() as f32 - 1.0) * 0.5;
+    for (i, &type_id) in wing.iter().enumerate() {
+        let position = Vec2::new((i as f32 - offset) * AMBUSH_SPACING, top);
+        let entity = spawn_enemy(
+            &mut commands,
+            type_id,
+            position,
+            EnemyBehavior::Sniper,
+            None,
+            None,
+        );
+        commands.entity(entity).insert(AmbushElite);
+        route.ambush_wing.push(entity);
+    }
+
+    info!("Route ambush: {} elites", wing.len());
+    let line = "Navy elites decloaking - they followed us in!";
+    dialogue_events.send(DialogueEvent {
+        trigger: DialogueTrigger::Custom(line.to_string()),
+        custom_text: Some(line.to_string()),
+        duration: 3.0,
+        priority: 6,
+        speaker: None,
+        voice: None,
+    });
+}
+
+/// Elites are harder to bring down than their class and stand out
+fn toughen_elites(mut elites: Query<(&mut EnemyStats, Option<&mut Sprite>), Added<AmbushElite>>) {
+    for (mut stats, sprite) in elites.iter_mut() {
+        stats.max_health *= ELITE_HEALTH_MULT;
+        stats.health = stats.max_health;
+        if let Some(mut sprite) = sprite {
+            sprite.color = ELITE_TINT;
+        }
+    }
+}
+
+/// Bounties for downed elites
+fn pay_elite_bounties(
+    mut destroy_events: EventReader<EnemyDestroyedEvent>,
+    campaign: Res<CampaignState>,
+    mut route: ResMut<RouteState>,
+    mut progress: ResMut<GameProgress>,
+) {
+    for event in destroy_events.read() {
+        let Some(index) = route.ambush_wing.iter().position(|e| *e == event.entity) else {
+            continue;
+        };
+        route.ambush_wing.swap_remove(index);
+
+        let (credits, isk) = ambush_bounty(campaign.act);
+        progress.credits += credits;
+        progress.isk += isk;
+        info!("Elite bounty: {} credits, {} ISK", credits, isk);
+    }
+}
@@ -0,0 +1,167 @@
+//! Run Integrity Recording
+//!
+//! Feeds an attack run's inputs into its `InputLog` once per fixed
+//! simulation tick, stamped with the tick's number, from the moment the
+//! run is seeded to the moment it's posted. The tick count runs through
+//! boss fights as well as the mission, unlike the mission clock.
+//! The objective system seals the log into the checksum that goes on the
+//! leaderboard, and leaves runs flagged as turbo or macro input unranked.
+
+#![allow(dead_code)]
+
+use crate::core::*;
+use crate::systems::JoystickState;
+use bevy::prelude::*;
+
+/// Keyboard keys watched for presses, one bit each from bit 0
+const WATCHED_KEYS: [KeyCode; 8] = [
+    KeyCode::Space,
+    KeyCode::ShiftLeft,
+    KeyCode::KeyQ,
+    KeyCode::KeyE,
+    KeyCode::KeyB,
+    KeyCode::KeyC,
+    KeyCode::KeyV,
+    KeyCode::KeyH,
+];
+
+/// Pad buttons watched, one bit each after the keys
+const WATCHED_BUTTONS: usize = 8;
+
+/// Run integrity plugin
+pub struct RunIntegrityPlugin;
+
+impl Plugin for RunIntegrityPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RunInputLog>()
+            .add_systems(
+                Update,
+                // After the run is seeded, so the log starts from its seed
+                start_input_log
+                    .after(super::daily::begin_run)
+                    .in_set(GameSet::Input)
+                    .run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight))),
+            )
+            .add_systems(
+                FixedUpdate,
+                record_inputs
+                    .in_set(GameSet::Input)
+                    .run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight))),
+            );
+    }
+}
+
+/// The attack run being flown and its inputs so far
+#[derive(Resource, Debug, Default)]
+pub struct RunInputLog {
+    /// Logging this run (attack modes only)
+    pub active: bool,
+    /// Simulation ticks flown since the run was seeded
+    pub tick: u64,
+    pub log: InputLog,
+}
+
+/// Watched keys and pad buttons held this frame
+pub fn input_mask(keyboard: &ButtonInput<KeyCode>, joystick: &JoystickState) -> u16 {
+    let keys = WATCHED_KEYS
+        .iter()
+        .enumerate()
+        .filter(|(_, key)| keyboard.pressed(**key))
+        .fold(0u16, |mask, (bit, _)| mask | (1 << bit));
+    joystick.buttons[..WATCHED_BUTTONS]
+        .iter()
+        .enumerate()
+        .filter(|(_, held)| **held)
+        .fold(keys, |mask, (bit, _)| {
+            mask | (1 << (WATCHED_KEYS.len() + bit))
+        })
+}
+
+/// Movement, aim and triggers as the ship reads them, in replay steps
+pub fn input_axes(keyboard: &ButtonInput<KeyCode>, joystick: &JoystickState) -> [i8; INPUT_AXES] {
+    let held = |a: KeyCode, b: KeyCode| keyboard.pressed(a) || keyboard.pressed(b);
+    let mut movement = Vec2::ZERO;
+    if held(KeyCode::KeyW, KeyCode::ArrowUp) {
+        movement.y += 1.0;
+    }
+    if held(KeyCode::KeyS, KeyCode::ArrowDown) {
+        movement.y -= 1.0;
+    }
+    if held(KeyCode::KeyA, KeyCode::ArrowLeft) {
+        movement.x -= 1.0;
+    }
+    if held(KeyCode::KeyD, KeyCode::ArrowRight) {
+        movement.x += 1.0;
+    }
+    // The stick wins when pushed further, as in player movement
+    let stick = joystick.movement();
+    if stick.length_squared() > movement.length_squared() {
+        movement = stick;
+    }
+
+    // Arrows and IJKL aim, and a pushed right stick takes over, as in
+    // player shooting
+    let mut aim = Vec2::ZERO;
+    if held(KeyCode::ArrowUp, KeyCode::KeyI) {
+        aim.y += 1.0;
+    }
+    if held(KeyCode::ArrowDown, KeyCode::KeyK) {
+        aim.y -= 1.0;
+    }
+    if held(KeyCode::ArrowLeft, KeyCode::KeyJ) {
+        aim.x -= 1.0;
+    }
+    if held(KeyCode::ArrowRight, KeyCode::KeyL) {
+        aim.x += 1.0;
+    }
+    if let Some(stick_aim) = joystick.aim_direction() {
+        aim = stick_aim;
+    }
+
+    [
+        axis_step(movement.x),
+        axis_step(movement.y),
+        axis_step(aim.x),
+        axis_step(aim.y),
+        axis_step(joystick.left_trigger),
+        axis_step(joystick.right_trigger),
+    ]
+}
+
+/// Start a fresh log when an attack run starts
+fn start_input_log(
+    mut events: EventReader<MissionStartEvent>,
+    run_mode: Res<RunMode>,
+    run_rng: Res<RunRng>,
+    mut input_log: ResMut<RunInputLog>,
+) {
+    if events.read().last().is_none() {
+        return;
+    }
+    *input_log = RunInputLog {
+        active: run_mode.is_attack(),
+        tick: 0,
+        log: InputLog::new(run_rng.seed),
+    };
+}
+
+/// Sample the held inputs on this simulation tick
+fn record_inputs(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    joystick: Res<JoystickState>,
+    campaign: Res<CampaignState>,
+    mut input_log: ResMut<RunInputLog>,
+) {
+    if !input_log.active || !campaign.in_mission {
+        return;
+    }
+    input_log.tick += 1;
+    let tick = input_log.tick;
+    let mask = input_mask(&keyboard, &joystick);
+    let axes = input_axes(&keyboard, &joystick);
+    let before = input_log.log.flag;
+    input_log.log.record(tick, mask, axes);
+    if let (None, Some(flag)) = (before, input_log.log.flag) {
+        warn!("Run flagged at tick {}: {}", tick, flag.description());
+    }
+}
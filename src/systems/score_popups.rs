@@ -0,0 +1,294 @@
+//! Score Popups
+//!
+//! Floating "+points" where kills land. Kills close together in time and
+//! space fold into one popup that shows the running sum and a kill count,
+//! so a berserk chain reads as a few climbing totals instead of a cloud of
+//! numbers. Popup entities are pooled: finished ones are hidden and reused
+//! rather than despawned, and the text is only rewritten when it changes.
+
+#![allow(dead_code)]
+
+use crate::core::*;
+use bevy::prelude::*;
+
+/// A kill folds into a popup whose last kill was at most this long ago (seconds)
+const MERGE_WINDOW: f32 = 0.4;
+
+/// ...and at most this far from where the popup started (pixels)
+const MERGE_RADIUS: f32 = 90.0;
+
+/// Seconds a popup stays up after its last kill
+const POPUP_LIFETIME: f32 = 0.9;
+
+/// Rise speed (pixels per second)
+const POPUP_RISE: f32 = 45.0;
+
+/// Popup entities kept around; past this the oldest is recycled
+const MAX_POPUPS: usize = 24;
+
+/// Score popup plugin
+pub struct ScorePopupPlugin;
+
+impl Plugin for ScorePopupPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (show_score_popups, update_score_popups)
+                .chain()
+                .in_set(GameSet::Ui)
+                .run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight))),
+        );
+    }
+}
+
+/// A pooled popup; idle while `kills` is zero
+#[derive(Component, Debug, Clone, Default)]
+#[require(StateScope)]
+pub struct ScorePopup {
+    pub points: u64,
+    pub kills: u32,
+    /// Where the first kill landed
+    pub anchor: Vec2,
+    /// Seconds since the last kill folded in
+    pub age: f32,
+}
+
+impl ScorePopup {
+    pub fn is_active(&self) -> bool {
+        self.kills > 0
+    }
+
+    /// Whether a kill at `position` folds into this popup
+    pub fn absorbs(&self, position: Vec2) -> bool {
+        self.is_active()
+            && self.age <= MERGE_WINDOW
+            && self.anchor.distance(position) <= MERGE_RADIUS
+    }
+
+    /// Fold in a batch of kills, restarting the fade
+    pub fn add(&mut self, group: &KillGroup) {
+        self.points += group.points;
+        self.kills += group.kills;
+        self.age = 0.0;
+    }
+
+    /// Start over at a new spot
+    pub fn restart(&mut self, group: &KillGroup) {
+        *self = Self {
+            anchor: group.position,
+            ..default()
+        };
+        self.add(group);
+    }
+
+    pub fn label(&self) -> String {
+        if self.kills > 1 {
+            format!("+{} x{}", self.points, self.kills)
+        } else {
+            format!("+{}", self.points)
+        }
+    }
+
+    /// Bigger chains get bigger text
+    pub fn font_size(&self) -> f32 {
+        14.0 + self.kills.min(6) as f32 * 1.5
+    }
+}
+
+/// Kills from one frame that landed close together
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KillGroup {
+    pub position: Vec2,
+    pub points: u64,
+    pub kills: u32,
+}
+
+/// Fold a frame's `(position, points)` kills into groups by `MERGE_RADIUS`
+/// from each group's first kill
+pub fn group_kills(kills: impl IntoIterator<Item = (Vec2, u64)>) -> Vec<KillGroup> {
+    let mut groups: Vec<KillGroup> = Vec::new();
+    for (position, points) in kills {
+        match groups
+            .iter_mut()
+            .find(|g| g.position.distance(position) <= MERGE_RADIUS)
+        {
+            Some(group) => {
+                group.points += points;
+                group.kills += 1;
+            }
+            None => groups.push(KillGroup {
+                position,
+                points,
+                kills: 1,
+            }),
+        }
+    }
+    groups
+}
+
+fn popup_color() -> Color {
+    Color::srgb(1.0, 0.85, 0.35)
+}
+
+/// Fold this frame's kills into live popups, waking idle ones (or spawning,
+/// up to the pool size) for kills that land somewhere new
+fn show_score_popups(
+    mut commands: Commands,
+    mut destroy_events: EventReader<EnemyDestroyedEvent>,
+    mut popups: Query<(
+        Entity,
+        &mut ScorePopup,
+        &mut Text2d,
+        &mut TextFont,
+        &mut Transform,
+        &mut Visibility,
+    )>,
+) {
+    let groups = group_kills(
+        destroy_events
+            .read()
+            .filter(|e| e.points_awarded > 0)
+            .map(|e| (e.position, e.points_awarded)),
+    );
+    if groups.is_empty() {
+        return;
+    }
+
+    let mut pool_size = popups.iter().count();
+    for group in &groups {
+        // Fold into a recent popup nearby
+        if let Some((_, mut popup, mut text, mut font, ..)) = popups
+            .iter_mut()
+            .find(|(_, p, ..)| p.absorbs(group.position))
+        {
+            popup.add(group);
+            text.0 = popup.label();
+            font.font_size = popup.font_size();
+            continue;
+        }
+
+        // Wake an idle popup, or the oldest once the pool is full
+        let idle = popups
+            .iter()
+            .find(|(_, p, ..)| !p.is_active())
+            .map(|(entity, ..)| entity);
+        let reused = idle.or_else(|| {
+            (pool_size >= MAX_POPUPS)
+                .then(|| {
+                    popups
+                        .iter()
+                        .max_by(|(_, a, ..), (_, b, ..)| a.age.total_cmp(&b.age))
+                        .map(|(entity, ..)| entity)
+                })
+                .flatten()
+        });
+
+        match reused.and_then(|entity| popups.get_mut(entity).ok()) {
+            Some((_, mut popup, mut text, mut font, mut transform, mut visibility)) => {
+                popup.restart(group);
+                text.0 = popup.label();
+                font.font_size = popup.font_size();
+                transform.translation.x = group.position.x;
+                transform.translation.y = group.position.y + 20.0;
+                transform.scale = Vec3::ONE;
+                *visibility = Visibility::Inherited;
+            }
+            None => {
+                let mut popup = ScorePopup::default();
+                popup.restart(group);
+                commands.spawn((
+                    Text2d::new(popup.label()),
+                    TextFont {
+                        font_size: popup.font_size(),
+                        ..default()
+                    },
+                    TextColor(popup_color()),
+                    Transform::from_xyz(
+                        group.position.x,
+                        group.position.y + 20.0,
+                        LAYER_EFFECTS + 6.0,
+                    ),
+                    popup,
+                ));
+                pool_size += 1;
+            }
+        }
+    }
+}
+
+/// Rise, pulse on fresh kills, fade out, then go idle
+fn update_score_popups(
+    time: Res<Time>,
+    mut popups: Query<(
+        &mut ScorePopup,
+        &mut Transform,
+        &mut TextColor,
+        &mut Visibility,
+    )>,
+) {
+    let dt = time.delta_secs();
+
+    for (mut popup, mut transform, mut color, mut visibility) in popups.iter_mut() {
+        if !popup.is_active() {
+            continue;
+        }
+
+        popup.age += dt;
+        if popup.age >= POPUP_LIFETIME {
+            popup.kills = 0;
+            *visibility = Visibility::Hidden;
+            continue;
+        }
+
+        transform.translation.y += POPUP_RISE * dt;
+        let t = popup.age / POPUP_LIFETIME;
+        // Pops in large, settles, then fades over the back half
+        transform.scale = Vec3::splat(1.0 + (1.0 - (t * 4.0).min(1.0)) * 0.3);
+        color.0 = popup_color().with_alpha((2.0 - t * 2.0).min(1.0));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nearby_kills_in_one_frame_group_together() {
+        let groups = group_kills([
+            (Vec2::ZERO, 100),
+            (Vec2::new(40.0, 10.0), 150),
+            (Vec2::new(300.0, 0.0), 80),
+            (Vec2::new(-30.0, -20.0), 50),
+        ]);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].points, 300);
+        assert_eq!(groups[0].kills, 3);
+        assert_eq!(groups[1].position, Vec2::new(300.0, 0.0));
+        assert_eq!(groups[1].kills, 1);
+    }
+
+    #[test]
+    fn popups_absorb_recent_nearby_kills_only() {
+        let mut popup = ScorePopup::default();
+        assert!(!popup.absorbs(Vec2::ZERO));
+
+        popup.restart(&KillGroup {
+            position: Vec2::ZERO,
+            points: 200,
+            kills: 1,
+        });
+        assert_eq!(popup.label(), "+200");
+        assert!(popup.absorbs(Vec2::new(50.0, 0.0)));
+        assert!(!popup.absorbs(Vec2::new(MERGE_RADIUS + 1.0, 0.0)));
+
+        popup.add(&KillGroup {
+            position: Vec2::new(20.0, 0.0),
+            points: 300,
+            kills: 2,
+        });
+        assert_eq!(popup.label(), "+500 x3");
+
+        popup.age = MERGE_WINDOW + 0.01;
+        assert!(!popup.absorbs(Vec2::ZERO));
+    }
+}
@@ -1,8 +1,12 @@
 //! Scoring System
 //!
-//! Handles score, multipliers, chain combos, and berserk meter.
+//! Handles score, multipliers, chain combos, and berserk meter, and pays
+//! out the `ScoreEvent`s other systems send for grazes, salvage, objectives
+//! and escorts at the active module's category weights.
 
+use super::scoring_v2::ComboHeatSystem;
 use crate::core::*;
+use crate::games::{ActiveModule, ModuleRegistry};
 use bevy::prelude::*;
 
 /// Scoring plugin
@@ -12,14 +16,64 @@ impl Plugin for ScoringPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             Update,
-            (update_score_system, update_berserk_system).run_if(in_state(GameState::Playing)),
+            (update_score_system, update_berserk_system)
+                .in_set(GameSet::Scoring)
+                .run_if(in_state(GameState::Playing)),
+        )
+        .add_systems(
+            Update,
+            apply_score_events
+                .in_set(GameSet::Scoring)
+                .run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight))),
         );
     }
 }
 
-/// Update score chain timer
-fn update_score_system(time: Res<Time>, mut score: ResMut<ScoreSystem>) {
-    score.update(time.delta_secs());
+/// Update score chain timer; harder difficulties pay more but drop chains
+/// faster. Run modifiers scale the payout on top.
+fn update_score_system(
+    time: Res<Time>,
+    difficulty: Res<Difficulty>,
+    save_data: Res<SaveData>,
+    run_mode: Res<RunMode>,
+    ng_plus: Res<NewGamePlus>,
+    mut score: ResMut<ScoreSystem>,
+) {
+    // Only touch the score while something moves, so the HUD's change
+    // detection stays quiet between kills
+    let scoring = difficulty.scoring();
+    let ng_plus_mult = if *run_mode == RunMode::Campaign {
+        ng_plus.modifiers().score
+    } else {
+        1.0
+    };
+    let multiplier = scoring.base_score_multiplier
+        * save_data.prestige.modifier_score_mult(*run_mode)
+        * ng_plus_mult;
+    if score.difficulty_multiplier != multiplier {
+        score.difficulty_multiplier = multiplier;
+    }
+    if score.chain > 0 {
+        score.update(time.delta_secs() * scoring.combo_decay_multiplier);
+    }
+}
+
+/// Score every `ScoreEvent` at the module's weight for its category
+fn apply_score_events(
+    mut events: EventReader<ScoreEvent>,
+    registry: Res<ModuleRegistry>,
+    active: Res<ActiveModule>,
+    mut score: ResMut<ScoreSystem>,
+    mut heat: ResMut<ComboHeatSystem>,
+) {
+    if events.is_empty() {
+        return;
+    }
+    let weights = registry.score_weights(&active);
+    for event in events.read() {
+        let weight = weights.get(event.category) * heat.on_score(event.category);
+        score.score_event(event.category, event.base, weight);
+    }
 }
 
 /// Update berserk meter and handle activation input
@@ -29,6 +83,7 @@ fn update_berserk_system(
     joystick: Res<crate::systems::JoystickState>,
     mut berserk: ResMut<BerserkSystem>,
     mut end_events: EventWriter<BerserkEndedEvent>,
+    mut start_events: EventWriter<BerserkActivatedEvent>,
     mut screen_flash: ResMut<crate::systems::ScreenFlash>,
     mut dialogue_events: EventWriter<super::DialogueEvent>,
     mut rumble_events: EventWriter<super::RumbleRequest>,
@@ -47,6 +102,7 @@ fn update_berserk_system(
 
     if activate_pressed && berserk.can_activate() && berserk.try_activate() {
         info!("BERSERK MODE ACTIVATED! 5x score for 8 seconds!");
+        start_events.send(BerserkActivatedEvent);
         screen_flash.berserk(); // Red flash on activation
         rumble_events.send(super::RumbleRequest::berserk()); // Controller rumble
         dialogue_events.send(super::DialogueEvent::combat_callout(
@@ -3,9 +3,11 @@
 //! Adds to existing BerserkSystem:
 //! - Combo: Consecutive kill chains with timer
 //! - Heat: Weapon overheating for bonus multiplier
+//! - Overload: Spend max heat for a weapon surge, then burn out the ability module
 
 #![allow(dead_code)]
 
+use crate::core::ScoreCategory;
 use bevy::prelude::*;
 
 /// Combo timeout in seconds
@@ -17,6 +19,15 @@ pub const COMBO_TIER_2: u32 = 10; // 1.5x
 pub const COMBO_TIER_3: u32 = 20; // 2.0x
 pub const COMBO_TIER_4: u32 = 50; // 3.0x
 
+/// Overload surge duration in seconds
+pub const OVERLOAD_DURATION: f32 = 5.0;
+/// Ability lockout after the overload burns out (EVE module burnout)
+pub const OVERLOAD_BURNOUT_DURATION: f32 = 10.0;
+/// Fire rate multiplier while overloaded
+pub const OVERLOAD_FIRE_RATE_MULT: f32 = 1.5;
+/// Weapon damage multiplier while overloaded
+pub const OVERLOAD_DAMAGE_MULT: f32 = 1.5;
+
 /// Heat level classification
 /// Heat system matches Python EVE Rebellion:
 /// - Overheat at 100, exit at 50
@@ -81,6 +92,8 @@ pub struct ComboHeatSystem {
     pub combo_count: u32,
     pub combo_timer: f32,
     pub max_combo: u32,
+    /// How fast the combo timer runs out (difficulty)
+    pub combo_decay: f32,
 
     // === Heat System (matches Python game) ===
     pub heat: f32,
@@ -88,6 +101,12 @@ pub struct ComboHeatSystem {
     pub heat_decay_rate: f32, // ~72/second (1.2/frame at 60fps)
     pub heat_level: HeatLevel,
 
+    // === Overload ===
+    /// Time remaining on the weapon surge (0 = not overloaded)
+    pub overload_timer: f32,
+    /// Time remaining until the special ability comes back online
+    pub burnout_timer: f32,
+
     // === Stats ===
     pub total_kills: u32,
     pub souls_liberated: u32,
@@ -99,10 +118,13 @@ impl Default for ComboHeatSystem {
             combo_count: 0,
             combo_timer: 0.0,
             max_combo: 0,
+            combo_decay: 1.0,
             heat: 0.0,
             heat_per_shot: 2.0,    // Python: 2.0 per shot
             heat_decay_rate: 72.0, // Python: 1.2/frame * 60fps
             heat_level: HeatLevel::Cool,
+            overload_timer: 0.0,
+            burnout_timer: 0.0,
             total_kills: 0,
             souls_liberated: 0,
         }
@@ -116,6 +138,8 @@ impl ComboHeatSystem {
         self.combo_timer = 0.0;
         self.heat = 0.0;
         self.heat_level = HeatLevel::Cool;
+        self.overload_timer = 0.0;
+        self.burnout_timer = 0.0;
         self.total_kills = 0;
         self.souls_liberated = 0;
     }
@@ -124,7 +148,7 @@ impl ComboHeatSystem {
     pub fn update(&mut self, dt: f32) {
         // Update combo timer
         if self.combo_count > 0 {
-            self.combo_timer -= dt;
+            self.combo_timer -= dt * self.combo_decay;
             if self.combo_timer <= 0.0 {
                 if self.combo_count > self.max_combo {
                     self.max_combo = self.combo_count;
@@ -133,6 +157,25 @@ impl ComboHeatSystem {
             }
         }
 
+        // Tick burnout before a new one can start so it gets its full duration
+        if self.burnout_timer > 0.0 {
+            self.burnout_timer = (self.burnout_timer - dt).max(0.0);
+        }
+
+        // Overload pins heat at max, then vents it and burns out the ability module
+        if self.overload_timer > 0.0 {
+            self.overload_timer = (self.overload_timer - dt).max(0.0);
+            if self.overload_timer > 0.0 {
+                self.heat = 100.0;
+                self.heat_level = HeatLevel::Overheated;
+            } else {
+                self.heat = 0.0;
+                self.heat_level = HeatLevel::Cool;
+                self.burnout_timer = OVERLOAD_BURNOUT_DURATION;
+            }
+            return;
+        }
+
         // Decay heat
         let was_overheated = self.heat_level == HeatLevel::Overheated;
         if self.heat > 0.0 {
@@ -150,9 +193,22 @@ impl ComboHeatSystem {
         self.heat_level = HeatLevel::from_heat(self.heat, was_overheated);
     }
 
-    /// Get fire rate multiplier (1.0 = normal, 0.7 = overheated)
+    /// Get fire rate multiplier (1.0 = normal, 0.7 = overheated, 1.5 = overloaded)
     pub fn fire_rate_mult(&self) -> f32 {
-        self.heat_level.fire_rate_mult()
+        if self.is_overloaded() {
+            OVERLOAD_FIRE_RATE_MULT
+        } else {
+            self.heat_level.fire_rate_mult()
+        }
+    }
+
+    /// Get weapon damage multiplier (1.5 while overloaded)
+    pub fn damage_mult(&self) -> f32 {
+        if self.is_overloaded() {
+            OVERLOAD_DAMAGE_MULT
+        } else {
+            1.0
+        }
     }
 
     /// Check if currently overheated
@@ -160,6 +216,42 @@ impl ComboHeatSystem {
         self.heat_level == HeatLevel::Overheated
     }
 
+    /// Overload is available at max heat, when not already surging or burnt out
+    pub fn can_overload(&self) -> bool {
+        self.heat >= 100.0 && !self.is_overloaded() && !self.is_burned_out()
+    }
+
+    /// Trigger an overload - returns true if it was activated
+    pub fn try_overload(&mut self) -> bool {
+        if !self.can_overload() {
+            return false;
+        }
+        self.overload_timer = OVERLOAD_DURATION;
+        self.heat = 100.0;
+        self.heat_level = HeatLevel::Overheated;
+        true
+    }
+
+    /// Weapons currently supercharged
+    pub fn is_overloaded(&self) -> bool {
+        self.overload_timer > 0.0
+    }
+
+    /// Special ability offline after an overload
+    pub fn is_burned_out(&self) -> bool {
+        self.burnout_timer > 0.0
+    }
+
+    /// Get overload surge remaining (0.0 - 1.0)
+    pub fn overload_percent(&self) -> f32 {
+        (self.overload_timer / OVERLOAD_DURATION).clamp(0.0, 1.0)
+    }
+
+    /// Get burnout remaining (0.0 - 1.0)
+    pub fn burnout_percent(&self) -> f32 {
+        (self.burnout_timer / OVERLOAD_BURNOUT_DURATION).clamp(0.0, 1.0)
+    }
+
     /// Called when enemy is killed - returns score multiplier
     pub fn on_kill(&mut self) -> f32 {
         self.total_kills += 1;
@@ -169,6 +261,22 @@ impl ComboHeatSystem {
         self.combo_multiplier() * self.heat_level.score_multiplier()
     }
 
+    /// Called for every scoring event - returns score multiplier. Kills
+    /// build the combo; grazes and salvage keep it alive and pay the heat
+    /// bonus for playing hot; objective and bonus points are flat.
+    pub fn on_score(&mut self, category: ScoreCategory) -> f32 {
+        match category {
+            ScoreCategory::Kill => self.on_kill(),
+            ScoreCategory::Graze | ScoreCategory::Salvage => {
+                if self.combo_count > 0 {
+                    self.combo_timer = COMBO_TIMEOUT;
+                }
+                self.heat_level.score_multiplier()
+            }
+            _ => 1.0,
+        }
+    }
+
     /// Get current combo multiplier
     pub fn combo_multiplier(&self) -> f32 {
         if self.combo_count >= COMBO_TIER_4 {
@@ -225,18 +333,72 @@ impl ComboHeatSystem {
     }
 }
 
+/// Event: Player triggered a heat overload
+#[derive(Event)]
+pub struct OverloadActivatedEvent;
+
+/// Event: Overload surge ended and the ability module burnt out
+#[derive(Event)]
+pub struct OverloadBurnoutEvent;
+
 /// Plugin to register combo/heat system
 pub struct ScoringSystemPlugin;
 
 impl Plugin for ScoringSystemPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<ComboHeatSystem>()
-            .add_systems(Update, update_combo_heat_system);
+            .add_event::<OverloadActivatedEvent>()
+            .add_event::<OverloadBurnoutEvent>()
+            .add_systems(
+                Update,
+                update_combo_heat_system.in_set(crate::core::GameSet::Scoring),
+            )
+            .add_systems(
+                Update,
+                overload_input
+                    .in_set(crate::core::GameSet::Input)
+                    .run_if(in_state(crate::core::GameState::Playing)),
+            );
     }
 }
 
-fn update_combo_heat_system(time: Res<Time>, mut system: ResMut<ComboHeatSystem>) {
+fn update_combo_heat_system(
+    time: Res<Time>,
+    difficulty: Res<crate::core::Difficulty>,
+    mut system: ResMut<ComboHeatSystem>,
+    mut burnout_events: EventWriter<OverloadBurnoutEvent>,
+) {
+    system.combo_decay = difficulty.scoring().combo_decay_multiplier;
+    let was_overloaded = system.is_overloaded();
     system.update(time.delta_secs());
+
+    if was_overloaded && !system.is_overloaded() {
+        info!(
+            "Overload burnout! Ability offline for {:.0}s",
+            OVERLOAD_BURNOUT_DURATION
+        );
+        burnout_events.send(OverloadBurnoutEvent);
+    }
+}
+
+/// H key or gamepad LT triggers an overload at max heat
+fn overload_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    joystick: Res<super::JoystickState>,
+    mut system: ResMut<ComboHeatSystem>,
+    mut events: EventWriter<OverloadActivatedEvent>,
+    mut rumble_events: EventWriter<super::RumbleRequest>,
+) {
+    let pressed = keyboard.just_pressed(KeyCode::KeyH) || joystick.left_trigger_just_pressed();
+
+    if pressed && system.try_overload() {
+        info!(
+            "OVERLOAD! Weapons supercharged for {:.0}s",
+            OVERLOAD_DURATION
+        );
+        events.send(OverloadActivatedEvent);
+        rumble_events.send(super::RumbleRequest::explosion());
+    }
 }
 
 #[cfg(test)]
@@ -324,6 +486,21 @@ mod tests {
         assert_eq!(mult, 1.0); // Below tier 1 threshold
     }
 
+    #[test]
+    fn combo_heat_salvage_keeps_a_combo_alive_without_growing_it() {
+        let mut c = ComboHeatSystem::default();
+        assert_eq!(c.on_score(ScoreCategory::Salvage), 1.0);
+        assert_eq!(c.combo_count, 0);
+
+        c.on_score(ScoreCategory::Kill);
+        c.update(1.5);
+        c.heat_level = HeatLevel::Hot;
+        assert_eq!(c.on_score(ScoreCategory::Graze), 1.5);
+        assert_eq!(c.combo_count, 1);
+        assert_eq!(c.combo_timer, COMBO_TIMEOUT);
+        assert_eq!(c.on_score(ScoreCategory::Objective), 1.0);
+    }
+
     #[test]
     fn combo_heat_combo_timer_decay() {
         let mut c = ComboHeatSystem::default();
@@ -535,4 +712,71 @@ mod tests {
         assert_eq!(c.souls_liberated, 0);
         assert_eq!(c.heat_level, HeatLevel::Cool);
     }
+
+    // ==================== Overload Tests ====================
+
+    #[test]
+    fn overload_requires_max_heat() {
+        let mut c = ComboHeatSystem::default();
+        c.heat = 90.0;
+        assert!(!c.can_overload());
+        assert!(!c.try_overload());
+
+        c.heat = 100.0;
+        assert!(c.can_overload());
+        assert!(c.try_overload());
+        assert!(c.is_overloaded());
+        assert!(!c.can_overload()); // Already surging
+    }
+
+    #[test]
+    fn overload_supercharges_weapons() {
+        let mut c = ComboHeatSystem::default();
+        c.heat = 100.0;
+        c.heat_level = HeatLevel::Overheated;
+        assert_eq!(c.fire_rate_mult(), 0.7);
+        assert_eq!(c.damage_mult(), 1.0);
+
+        c.try_overload();
+        assert_eq!(c.fire_rate_mult(), OVERLOAD_FIRE_RATE_MULT);
+        assert_eq!(c.damage_mult(), OVERLOAD_DAMAGE_MULT);
+
+        // Heat stays pinned at max during the surge
+        c.update(1.0);
+        assert_eq!(c.heat, 100.0);
+        assert!(c.is_overloaded());
+    }
+
+    #[test]
+    fn overload_ends_in_burnout() {
+        let mut c = ComboHeatSystem::default();
+        c.heat = 100.0;
+        c.try_overload();
+
+        c.update(OVERLOAD_DURATION + 0.1);
+        assert!(!c.is_overloaded());
+        assert!(c.is_burned_out());
+        assert_eq!(c.heat, 0.0);
+        assert_eq!(c.burnout_timer, OVERLOAD_BURNOUT_DURATION);
+        assert_eq!(c.damage_mult(), 1.0);
+
+        // Can't overload again while burnt out, even at max heat
+        c.heat = 100.0;
+        assert!(!c.can_overload());
+
+        c.update(OVERLOAD_BURNOUT_DURATION);
+        assert!(!c.is_burned_out());
+    }
+
+    #[test]
+    fn overload_reset_clears_timers() {
+        let mut c = ComboHeatSystem::default();
+        c.overload_timer = 2.0;
+        c.burnout_timer = 4.0;
+
+        c.reset();
+
+        assert!(!c.is_overloaded());
+        assert!(!c.is_burned_out());
+    }
 }
@@ -0,0 +1,314 @@
+//! Shield Bubble
+//!
+//! A deployable bubble (V / B button) left where the ship was when it went
+//! up. For four seconds it swallows enemy shots that reach it while the
+//! player's own fire passes straight out. Costs capacitor and has a long
+//! recharge.
+//!
+//! From Act 2 on, boss beams are phased and pass through the bubble; in
+//! Act 3 the heaviest attacks do too (see `boss::attack_is_phased`). Phased
+//! shots are tinted violet so they read differently at a glance.
+
+#![allow(dead_code)]
+
+use super::boss::{attack_is_phased, BossProjectile};
+use super::JoystickState;
+use crate::core::*;
+use crate::entities::{Boss, BossAttack, BossData, Player, ProjectileOwner, ShipStats, Team};
+use bevy::prelude::*;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use std::f32::consts::TAU;
+
+/// Seconds a bubble holds
+pub const BUBBLE_DURATION: f32 = 4.0;
+
+/// Seconds before another bubble can go up
+pub const BUBBLE_COOLDOWN: f32 = 18.0;
+
+/// Capacitor spent deploying one
+pub const BUBBLE_CAP_COST: f32 = 30.0;
+
+/// Bubble radius (pixels)
+pub const BUBBLE_RADIUS: f32 = 110.0;
+
+/// Last seconds the bubble flickers before it drops
+const BUBBLE_WARNING: f32 = 1.0;
+
+/// Side length of the sphere texture (pixels)
+const SPHERE_SIZE: u32 = 128;
+
+/// Bubble tint
+const BUBBLE_COLOR: Color = Color::srgb(0.45, 0.8, 1.0);
+
+/// Tint for shots that pass through the bubble
+const PHASED_COLOR: Color = Color::srgb(0.75, 0.35, 1.0);
+
+/// Shield bubble plugin
+pub struct ShieldBubblePlugin;
+
+impl Plugin for ShieldBubblePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ShieldBubbleState>()
+            .add_systems(Startup, create_sphere_image)
+            .add_systems(
+                Update,
+                (reset_shield_bubble, deploy_shield_bubble)
+                    .chain()
+                    .in_set(GameSet::Input)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                (
+                    phase_boss_projectiles,
+                    block_enemy_projectiles,
+                    update_bubbles,
+                )
+                    .chain()
+                    .in_set(GameSet::Simulation)
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+/// Bubble recharge, shared by the HUD
+#[derive(Resource, Debug, Default)]
+pub struct ShieldBubbleState {
+    /// Seconds until another bubble can go up
+    pub cooldown: f32,
+}
+
+impl ShieldBubbleState {
+    pub fn ready(&self) -> bool {
+        self.cooldown <= 0.0
+    }
+}
+
+/// A deployed bubble
+#[derive(Component, Debug)]
+#[require(StateScope)]
+pub struct ShieldBubble {
+    /// Seconds left before it drops
+    pub remaining: f32,
+}
+
+/// An enemy shot the bubble can't stop
+#[derive(Component, Debug)]
+pub struct Phased;
+
+/// HUD line for the bubble: time left while one is up, otherwise the
+/// recharge or a ready prompt
+pub struct BubbleLabel {
+    pub remaining: Option<f32>,
+    pub cooldown: f32,
+}
+
+impl std::fmt::Display for BubbleLabel {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.remaining {
+            Some(remaining) => write!(f, "BUBBLE {:.1}s", remaining.max(0.0)),
+            None if self.cooldown > 0.0 => {
+                write!(f, "BUBBLE RECHARGE {:.0}s", self.cooldown.ceil())
+            }
+            None => write!(f, "BUBBLE READY [V/B]"),
+        }
+    }
+}
+
+/// Bubble alpha; `age` in seconds, flickering through the last second
+pub fn bubble_alpha(remaining: f32, age: f32) -> f32 {
+    let shimmer = 0.85 + 0.15 * (age * 3.0 * TAU).sin();
+    if remaining < BUBBLE_WARNING {
+        let flicker = if (remaining * 10.0).fract() < 0.5 {
+            1.0
+        } else {
+            0.4
+        };
+        shimmer * flicker * remaining.max(0.2)
+    } else {
+        shimmer
+    }
+}
+
+/// Sphere texture shared by every bubble
+#[derive(Resource)]
+struct SphereImage(Handle<Image>);
+
+fn create_sphere_image(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    commands.insert_resource(SphereImage(images.add(sphere_image())));
+}
+
+/// White sphere: a faint fill that brightens toward a soft rim
+fn sphere_image() -> Image {
+    let mut data = Vec::with_capacity((SPHERE_SIZE * SPHERE_SIZE * 4) as usize);
+    let half = SPHERE_SIZE as f32 / 2.0;
+
+    for py in 0..SPHERE_SIZE {
+        for px in 0..SPHERE_SIZE {
+            let x = (px as f32 + 0.5 - half) / half;
+            let y = (py as f32 + 0.5 - half) / half;
+            let r = (x * x + y * y).sqrt();
+            let alpha = if r > 1.0 {
+                0.0
+            } else {
+                // Edge-on glass is brighter; fade the last few pixels out
+                let rim = r.powi(4);
+                let edge = ((1.0 - r) / 0.06).clamp(0.0, 1.0);
+                (0.12 + 0.75 * rim) * edge
+            };
+            data.extend_from_slice(&[255, 255, 255, (alpha * 255.0) as u8]);
+        }
+    }
+
+    Image::new(
+        Extent3d {
+            width: SPHERE_SIZE,
+            height: SPHERE_SIZE,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD,
+    )
+}
+
+fn reset_shield_bubble(
+    mut events: EventReader<MissionStartEvent>,
+    mut state: ResMut<ShieldBubbleState>,
+) {
+    if events.read().last().is_some() {
+        *state = ShieldBubbleState::default();
+    }
+}
+
+/// Put a bubble up where the ship is
+fn deploy_shield_bubble(
+    mut commands: Commands,
+    time: Res<Time>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    joystick: Res<JoystickState>,
+    image: Option<Res<SphereImage>>,
+    mut state: ResMut<ShieldBubbleState>,
+    mut player_query: Query<(&Transform, &mut ShipStats), With<Player>>,
+    bubbles: Query<(), With<ShieldBubble>>,
+) {
+    state.cooldown = (state.cooldown - time.delta_secs()).max(0.0);
+
+    let pressed = keyboard.just_pressed(KeyCode::KeyV) || joystick.bubble();
+    if !pressed || !state.ready() || !bubbles.is_empty() {
+        return;
+    }
+    let Ok((transform, mut stats)) = player_query.get_single_mut() else {
+        return;
+    };
+    if stats.capacitor < BUBBLE_CAP_COST {
+        return;
+    }
+
+    stats.capacitor -= BUBBLE_CAP_COST;
+    state.cooldown = BUBBLE_COOLDOWN;
+
+    let pos = transform.translation.truncate();
+    commands.spawn((
+        ShieldBubble {
+            remaining: BUBBLE_DURATION,
+        },
+        Sprite {
+            image: image.map(|image| image.0.clone()).unwrap_or_default(),
+            color: BUBBLE_COLOR,
+            custom_size: Some(Vec2::splat(BUBBLE_RADIUS * 2.0)),
+            ..default()
+        },
+        Transform::from_xyz(pos.x, pos.y, LAYER_EFFECTS),
+    ));
+}
+
+/// Mark new boss shots whose attack slips through bubbles
+fn phase_boss_projectiles(
+    mut commands: Commands,
+    bosses: Query<(&BossData, &BossAttack), With<Boss>>,
+    mut projectiles: Query<(Entity, &mut Sprite), Added<BossProjectile>>,
+) {
+    let Ok((data, attack)) = bosses.get_single() else {
+        return;
+    };
+    if !attack_is_phased(data.stage, &attack.pattern) {
+        return;
+    }
+    for (entity, mut sprite) in projectiles.iter_mut() {
+        sprite.color = PHASED_COLOR;
+        commands.entity(entity).insert(Phased);
+    }
+}
+
+/// Swallow enemy shots that reach a bubble
+fn block_enemy_projectiles(
+    mut commands: Commands,
+    bubbles: Query<&Transform, With<ShieldBubble>>,
+    projectiles: Query<(Entity, &Transform, &ProjectileOwner), Without<Phased>>,
+) {
+    if bubbles.is_empty() {
+        return;
+    }
+    let centers: Vec<Vec2> = bubbles.iter().map(|t| t.translation.truncate()).collect();
+
+    for (entity, transform, owner) in projectiles.iter() {
+        if !owner.hits(Team::Player) {
+            continue;
+        }
+        let pos = transform.translation.truncate();
+        if centers
+            .iter()
+            .any(|center| center.distance(pos) <= BUBBLE_RADIUS)
+        {
+            super::spawn_impact_flash(&mut commands, pos, BUBBLE_COLOR, None);
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Count bubbles down, shimmer them and drop the expired ones
+fn update_bubbles(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut bubbles: Query<(Entity, &mut ShieldBubble, &mut Sprite)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut bubble, mut sprite) in bubbles.iter_mut() {
+        bubble.remaining -= dt;
+        if bubble.remaining <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let age = BUBBLE_DURATION - bubble.remaining;
+        sprite.color.set_alpha(bubble_alpha(bubble.remaining, age));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn label_shows_time_left_then_recharge_then_ready() {
+        let label = |remaining, cooldown| {
+            BubbleLabel {
+                remaining,
+                cooldown,
+            }
+            .to_string()
+        };
+        assert_eq!(label(Some(3.26), 0.0), "BUBBLE 3.3s");
+        assert_eq!(label(None, 11.2), "BUBBLE RECHARGE 12s");
+        assert_eq!(label(None, 0.0), "BUBBLE READY [V/B]");
+    }
+
+    #[test]
+    fn bubble_flickers_and_fades_before_dropping() {
+        let steady = bubble_alpha(BUBBLE_DURATION, 0.0);
+        assert!(steady > 0.8);
+        assert!(bubble_alpha(0.3, 3.7) < 0.5);
+    }
+}
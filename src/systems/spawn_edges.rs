@@ -0,0 +1,390 @@
+//! Spawn Edges
+//!
+//! Where a wave's ships come from. Most still launch from the carrier at the
+//! top, but scattered waves also fly in from the sides, warp in diagonally
+//! from the top corners and, on the harder settings, ambush from behind the
+//! player. Ships entering from off-screen hold their course and their fire
+//! until they're in the fight, and an arrow at the screen edge points at
+//! each one for a second so nothing arrives unannounced. Entry paths that
+//! would start or end on top of the player are rerolled.
+
+#![allow(dead_code)]
+
+use crate::core::*;
+use crate::entities::EnemyAI;
+use bevy::prelude::*;
+
+/// Closest a ship may spawn or finish its entry to the player
+pub const SPAWN_CLEARANCE: f32 = 140.0;
+/// How long the incoming arrow shows
+pub const INDICATOR_TIME: f32 = 1.0;
+/// How far beyond the screen edge off-screen spawns start
+const EDGE_OFFSET: f32 = 40.0;
+/// Arrow distance from the screen edge
+const INDICATOR_INSET: f32 = 22.0;
+/// Entry speed from the sides and from behind
+const ENTRY_SPEED: f32 = 220.0;
+/// Entry speed of a diagonal warp
+const WARP_SPEED: f32 = 520.0;
+/// Rerolls before an unsafe entry falls back to the carrier
+const ENTRY_ATTEMPTS: usize = 4;
+
+/// Spawn edge plugin
+pub struct SpawnEdgePlugin;
+
+impl Plugin for SpawnEdgePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            advance_entry_runs
+                .in_set(GameSet::Simulation)
+                .run_if(in_state(GameState::Playing)),
+        )
+        .add_systems(
+            Update,
+            update_spawn_indicators
+                .in_set(GameSet::Ui)
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
+
+/// Where a ship enters the battlefield
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpawnEdge {
+    /// Launched from the carrier
+    Top,
+    Left,
+    Right,
+    /// Diagonal warp from the top-left corner
+    WarpLeft,
+    /// Diagonal warp from the top-right corner
+    WarpRight,
+    /// Ambush from below the player
+    Behind,
+}
+
+impl SpawnEdge {
+    /// Roll an edge for a scattered spawn
+    pub fn pick(difficulty: Difficulty, rng: &mut fastrand::Rng) -> Self {
+        if rng.f32() < difficulty.ambush_chance() {
+            return SpawnEdge::Behind;
+        }
+        match rng.u32(0..100) {
+            0..60 => SpawnEdge::Top,
+            60..72 => SpawnEdge::Left,
+            72..84 => SpawnEdge::Right,
+            84..92 => SpawnEdge::WarpLeft,
+            _ => SpawnEdge::WarpRight,
+        }
+    }
+
+    /// Ambushers come from behind the player
+    pub fn is_ambush(&self) -> bool {
+        *self == SpawnEdge::Behind
+    }
+
+    /// Off-screen start and on-screen destination of an entry from this
+    /// edge of `playfield`; `None` for carrier launches
+    pub fn entry_path(
+        &self,
+        playfield: &Playfield,
+        rng: &mut fastrand::Rng,
+    ) -> Option<(Vec2, Vec2)> {
+        let half_w = playfield.half_width();
+        let half_h = playfield.half_height();
+        let path = match self {
+            SpawnEdge::Top => return None,
+            SpawnEdge::Left | SpawnEdge::Right => {
+                let side = if *self == SpawnEdge::Left { -1.0 } else { 1.0 };
+                let y = rng.f32() * (half_h - 80.0);
+                (
+                    Vec2::new(side * (half_w + EDGE_OFFSET), y),
+                    Vec2::new(side * (half_w - 120.0 - rng.f32() * 80.0), y - 40.0),
+                )
+            }
+            SpawnEdge::WarpLeft | SpawnEdge::WarpRight => {
+                let side = if *self == SpawnEdge::WarpLeft {
+                    -1.0
+                } else {
+                    1.0
+                };
+                (
+                    Vec2::new(side * (half_w + EDGE_OFFSET), half_h + EDGE_OFFSET),
+                    Vec2::new(
+                        side * (half_w - 150.0 - rng.f32() * 150.0),
+                        half_h - 150.0 - rng.f32() * 100.0,
+                    ),
+                )
+            }
+            SpawnEdge::Behind => {
+                let x = (rng.f32() * 2.0 - 1.0) * (half_w - 60.0);
+                (
+                    Vec2::new(x, -half_h - EDGE_OFFSET),
+                    Vec2::new(x, -half_h + 140.0 + rng.f32() * 60.0),
+                )
+            }
+        };
+        Some(path)
+    }
+
+    /// Entry speed from this edge
+    pub fn entry_speed(&self) -> f32 {
+        match self {
+            SpawnEdge::WarpLeft | SpawnEdge::WarpRight => WARP_SPEED,
+            _ => ENTRY_SPEED,
+        }
+    }
+
+    /// Entry path that keeps clear of the player, rerolled a few times;
+    /// `None` means launch from the carrier instead
+    pub fn safe_entry_path(
+        &self,
+        playfield: &Playfield,
+        player: Vec2,
+        rng: &mut fastrand::Rng,
+    ) -> Option<(Vec2, Vec2)> {
+        (0..ENTRY_ATTEMPTS)
+            .filter_map(|_| self.entry_path(playfield, rng))
+            .find(|(from, to)| {
+                from.distance(player) >= SPAWN_CLEARANCE && to.distance(player) >= SPAWN_CLEARANCE
+            })
+    }
+}
+
+/// Push a spawn point out of the player's clearance radius
+pub fn clear_of_player(position: Vec2, player: Vec2) -> Vec2 {
+    let offset = position - player;
+    if offset.length() >= SPAWN_CLEARANCE {
+        return position;
+    }
+    player + offset.normalize_or(Vec2::Y) * SPAWN_CLEARANCE
+}
+
+/// A ship flying in from off-screen; it holds this course and its fire
+/// until the run ends
+#[derive(Component, Debug, Clone, Copy)]
+pub struct EntryRun {
+    pub velocity: Vec2,
+    /// Seconds of entry left
+    pub remaining: f32,
+}
+
+impl EntryRun {
+    pub fn between(from: Vec2, to: Vec2, speed: f32) -> Self {
+        let offset = to - from;
+        Self {
+            velocity: offset.normalize_or_zero() * speed,
+            remaining: offset.length() / speed,
+        }
+    }
+}
+
+/// Edge arrow pointing at an incoming ship
+#[derive(Component, Debug)]
+#[require(StateScope)]
+pub struct SpawnIndicator {
+    pub target: Entity,
+    pub lifetime: f32,
+}
+
+/// Where the arrow for a ship at `target` sits and the angle it points
+/// at; `None` once the ship is on screen
+pub fn indicator_placement(playfield: &Playfield, target: Vec2) -> Option<(Vec2, f32)> {
+    let clamped = playfield.clamp(target, INDICATOR_INSET);
+    let toward = target - clamped;
+    if toward.length_squared() <= f32::EPSILON {
+        return None;
+    }
+    Some((clamped, toward.to_angle()))
+}
+
+/// Send a freshly spawned ship in from off-screen and announce it
+pub fn begin_entry(
+    commands: &mut Commands,
+    playfield: &Playfield,
+    enemy: Entity,
+    from: Vec2,
+    to: Vec2,
+    edge: SpawnEdge,
+) {
+    commands
+        .entity(enemy)
+        .insert(EntryRun::between(from, to, edge.entry_speed()));
+
+    let color = if edge.is_ambush() {
+        Color::srgb(1.0, 0.2, 0.15)
+    } else {
+        Color::srgb(1.0, 0.65, 0.15)
+    };
+    let (position, angle) = indicator_placement(playfield, from).unwrap_or((from, 0.0));
+    commands
+        .spawn((
+            SpawnIndicator {
+                target: enemy,
+                lifetime: INDICATOR_TIME,
+            },
+            Transform::from_xyz(position.x, position.y, LAYER_EFFECTS + 2.0)
+                .with_rotation(Quat::from_rotation_z(angle)),
+            Visibility::Visible,
+        ))
+        .with_children(|arrow| {
+            // Chevron pointing along +X
+            for side in [-1.0, 1.0] {
+                arrow.spawn((
+                    Sprite {
+                        color,
+                        custom_size: Some(Vec2::new(16.0, 4.0)),
+                        ..default()
+                    },
+                    Transform::from_xyz(-5.0, side * 5.0, 0.0)
+                        .with_rotation(Quat::from_rotation_z(-side * 0.7)),
+                ));
+            }
+        });
+}
+
+/// Count down entries; ships join the fight when theirs ends
+fn advance_entry_runs(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut EntryRun, &mut EnemyAI)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut entry, mut ai) in query.iter_mut() {
+        entry.remaining -= dt;
+        ai.active = entry.remaining <= 0.0;
+        if ai.active {
+            commands.entity(entity).remove::<EntryRun>();
+        }
+    }
+}
+
+/// Keep each arrow on the edge nearest its ship, blinking, for its second
+fn update_spawn_indicators(
+    mut commands: Commands,
+    time: Res<Time>,
+    playfield: Res<Playfield>,
+    mut indicators: Query<(Entity, &mut SpawnIndicator, &mut Transform, &mut Visibility)>,
+    targets: Query<&Transform, Without<SpawnIndicator>>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut indicator, mut transform, mut visibility) in indicators.iter_mut() {
+        indicator.lifetime -= dt;
+        let Ok(target) = targets.get(indicator.target) else {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        };
+        if indicator.lifetime <= 0.0 {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+        match indicator_placement(&playfield, target.translation.truncate()) {
+            Some((position, angle)) => {
+                transform.translation.x = position.x;
+                transform.translation.y = position.y;
+                transform.rotation = Quat::from_rotation_z(angle);
+                let blink = (indicator.lifetime * 12.0).sin() > -0.3;
+                *visibility = if blink {
+                    Visibility::Visible
+                } else {
+                    Visibility::Hidden
+                };
+            }
+            None => *visibility = Visibility::Hidden,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ambushes_only_come_on_the_harder_settings() {
+        let edges = |difficulty: Difficulty| {
+            let mut rng = fastrand::Rng::with_seed(5);
+            (0..2000)
+                .map(|_| SpawnEdge::pick(difficulty, &mut rng))
+                .collect::<Vec<_>>()
+        };
+        for difficulty in [Difficulty::Carebear, Difficulty::Newbro] {
+            assert!(!edges(difficulty).iter().any(SpawnEdge::is_ambush));
+        }
+        let vet = edges(Difficulty::BitterVet);
+        assert!(vet.iter().any(SpawnEdge::is_ambush));
+        // The carrier still launches most ships
+        let top = vet.iter().filter(|e| **e == SpawnEdge::Top).count();
+        assert!(top > vet.len() / 2);
+        for edge in [SpawnEdge::Left, SpawnEdge::Right, SpawnEdge::WarpLeft] {
+            assert!(vet.contains(&edge));
+        }
+    }
+
+    #[test]
+    fn entries_start_off_screen_and_end_on_it() {
+        let mut rng = fastrand::Rng::with_seed(9);
+        // Ultrawide too: entries use the whole width
+        for playfield in [
+            Playfield::default(),
+            Playfield {
+                size: Vec2::new(1600.0, 700.0),
+            },
+        ] {
+            let half = playfield.half();
+            for edge in [
+                SpawnEdge::Left,
+                SpawnEdge::Right,
+                SpawnEdge::WarpLeft,
+                SpawnEdge::WarpRight,
+                SpawnEdge::Behind,
+            ] {
+                let (from, to) = edge.entry_path(&playfield, &mut rng).unwrap();
+                assert!(from.x.abs() > half.x || from.y.abs() > half.y, "{:?}", edge);
+                assert!(to.x.abs() < half.x && to.y.abs() < half.y, "{:?}", edge);
+
+                let run = EntryRun::between(from, to, edge.entry_speed());
+                assert!((from + run.velocity * run.remaining).distance(to) < 0.01);
+            }
+        }
+        assert!(SpawnEdge::Top
+            .entry_path(&Playfield::default(), &mut rng)
+            .is_none());
+    }
+
+    #[test]
+    fn spawns_keep_clear_of_the_player() {
+        let player = Vec2::new(10.0, -250.0);
+        let pushed = clear_of_player(Vec2::new(20.0, -240.0), player);
+        assert!((pushed.distance(player) - SPAWN_CLEARANCE).abs() < 0.01);
+        // Dead center still goes somewhere
+        assert_eq!(
+            clear_of_player(player, player),
+            player + Vec2::Y * SPAWN_CLEARANCE
+        );
+        let far = Vec2::new(0.0, 200.0);
+        assert_eq!(clear_of_player(far, player), far);
+
+        let mut rng = fastrand::Rng::with_seed(1);
+        for _ in 0..200 {
+            if let Some((from, to)) =
+                SpawnEdge::Behind.safe_entry_path(&Playfield::default(), player, &mut rng)
+            {
+                assert!(from.distance(player) >= SPAWN_CLEARANCE);
+                assert!(to.distance(player) >= SPAWN_CLEARANCE);
+            }
+        }
+    }
+
+    #[test]
+    fn arrows_sit_on_the_edge_facing_the_ship() {
+        let playfield = Playfield::default();
+        let (position, angle) = indicator_placement(&playfield, Vec2::new(-500.0, 100.0)).unwrap();
+        assert_eq!(
+            position,
+            Vec2::new(-SCREEN_WIDTH / 2.0 + INDICATOR_INSET, 100.0)
+        );
+        assert!((angle - std::f32::consts::PI).abs() < 0.01);
+        assert!(indicator_placement(&playfield, Vec2::ZERO).is_none());
+    }
+}
@@ -1,14 +1,19 @@
 //! Enemy Spawning System
 //!
 //! Handles wave-based enemy spawning with carrier visuals.
-//! Enemy waves launch from faction-appropriate carriers in the background.
+//! Enemy waves launch from faction-appropriate carriers in the background;
+//! scattered waves also come in from the other edges (see `spawn_edges`).
+//! Each wave is a spawn budget spent on the enemy faction's ships up front;
+//! New Game+ remixes each wave from a later stage, adds to its budget and
+//! rolls elites.
 
 use super::dialogue::{DialogueEvent, DialogueSystem};
+use super::spawn_edges::{begin_entry, clear_of_player, SpawnEdge};
 use crate::assets::ShipModelCache;
 use crate::core::*;
 use crate::entities::{
     spawn_enemy, spawn_kamikaze, spawn_sniper, spawn_spawner_enemy, spawn_tank, spawn_weaver,
-    EnemyBehavior,
+    Elite, EnemyBehavior,
 };
 use crate::games::caldari_gallente::LastStandState;
 use bevy::prelude::*;
@@ -54,12 +59,13 @@ fn spawn_enemy_carrier(
     mut commands: Commands,
     session: Res<GameSession>,
     sprite_cache: Res<crate::assets::ShipSpriteCache>,
+    playfield: Res<Playfield>,
 ) {
     let carrier_id = session.enemy_faction.carrier_type_id();
     let sprite = sprite_cache.get(carrier_id);
 
     // Position carrier in upper background
-    let carrier_y = SCREEN_HEIGHT / 2.0 - 100.0;
+    let carrier_y = playfield.half_height() - 100.0;
 
     // Carrier size from constants
     let carrier_size = crate::core::SIZE_CARRIER;
@@ -83,10 +89,9 @@ fn spawn_enemy_carrier(
         let total_rotation = base_rotation + correction;
 
         entity.insert((Sprite {
-            image: texture,
             color: Color::srgba(1.0, 1.0, 1.0, 0.0), // Start invisible for warp-in
             custom_size: Some(Vec2::splat(carrier_size)),
-            ..default()
+            ..texture.sprite()
         },));
         entity.insert(
             Transform::from_xyz(0.0, carrier_y + 200.0, -50.0)
@@ -94,7 +99,7 @@ fn spawn_enemy_carrier(
         );
     }
 
-    info!(
+    debug!(
         "Enemy {} carrier warping in!",
         session.enemy_faction.short_name()
     );
@@ -159,6 +164,8 @@ pub struct WaveManager {
     pub current_stage: u32,
     /// Enemies remaining in current wave
     pub enemies_remaining: u32,
+    /// Type IDs the current wave's budget bought, in launch order
+    pub roster: Vec<u32>,
     /// Time until next spawn
     pub spawn_timer: f32,
     /// Time between spawns
@@ -184,6 +191,7 @@ impl Default for WaveManager {
             waves_per_stage: 5, // 5 waves then boss
             current_stage: 1,
             enemies_remaining: 0,
+            roster: Vec::new(),
             spawn_timer: 0.0,
             spawn_interval: 0.8,
             wave_delay: 0.0,
@@ -199,7 +207,8 @@ impl Default for WaveManager {
 /// Wave definition
 #[derive(Debug, Clone)]
 pub struct WaveDefinition {
-    pub enemy_count: u32,
+    /// Spawn budget in points
+    pub budget: u32,
     pub enemy_types: Vec<u32>,
     pub behaviors: Vec<EnemyBehavior>,
     pub spawn_pattern: SpawnPattern,
@@ -246,7 +255,7 @@ fn wave_spawning(
     mut manager: ResMut<WaveManager>,
     mut endless: ResMut<crate::core::EndlessMode>,
     mut next_state: ResMut<NextState<GameState>>,
-    _stage: Res<CurrentStage>,
+    run_mode: Res<crate::core::RunMode>,
     session: Res<crate::core::GameSession>,
     enemy_query: Query<Entity, With<crate::entities::Enemy>>,
     boss_query: Query<Entity, With<crate::entities::Boss>>,
@@ -254,15 +263,29 @@ fn wave_spawning(
     mut wave_events: EventWriter<SpawnWaveEvent>,
     mut boss_spawn_events: EventWriter<super::boss::BossSpawnEvent>,
     mut boss_defeated_events: EventReader<super::boss::BossDefeatedEvent>,
-    mut dialogue_events: EventWriter<DialogueEvent>,
-    sprite_cache: Res<crate::assets::ShipSpriteCache>,
-    model_cache: Res<ShipModelCache>,
+    // Grouped to stay within Bevy's system parameter limit
+    (mut dialogue_events, mut campaign_events): (
+        EventWriter<DialogueEvent>,
+        EventWriter<crate::core::CampaignCompleteEvent>,
+    ),
+    (sprite_cache, model_cache): (Res<crate::assets::ShipSpriteCache>, Res<ShipModelCache>),
+    (mut run_rng, difficulty, player_query, playfield, ng_plus): (
+        ResMut<crate::core::RunRng>,
+        Res<Difficulty>,
+        Query<&Transform, With<crate::entities::Player>>,
+        Res<Playfield>,
+        Res<NewGamePlus>,
+    ),
 ) {
     // Get carrier position for spawning enemies
     let carrier_pos = carrier_query
         .get_single()
         .map(|t| Vec2::new(t.translation.x, t.translation.y))
-        .unwrap_or(Vec2::new(0.0, SCREEN_HEIGHT / 2.0 - 100.0));
+        .unwrap_or(Vec2::new(0.0, playfield.half_height() - 100.0));
+    let player_pos = player_query
+        .get_single()
+        .map(|t| t.translation.truncate())
+        .ok();
     let dt = time.delta_secs();
 
     // Update endless mode timer
@@ -320,6 +343,9 @@ fn wave_spawning(
             manager.current_stage += 1;
             if manager.current_stage > 13 {
                 info!("CAMPAIGN COMPLETE! The Elder Fleet has liberated the Minmatar people!");
+                campaign_events.send(crate::core::CampaignCompleteEvent {
+                    module: crate::core::ELDER_FLEET,
+                });
                 next_state.set(GameState::Victory);
                 return;
             }
@@ -359,7 +385,12 @@ fn wave_spawning(
                 }
 
                 // Setup endless wave with escalating difficulty
-                let enemy_count = endless.wave_enemy_count();
+                let budget = endless.wave_budget();
+                manager.roster = spend_budget(budget, session.enemy_ships(), &mut run_rng.spawns)
+                    .iter()
+                    .map(|e| e.type_id)
+                    .collect();
+                let enemy_count = manager.roster.len() as u32;
                 manager.enemies_remaining = enemy_count;
                 manager.spawn_interval = (0.6 - endless.wave as f32 * 0.01).max(0.2);
 
@@ -370,14 +401,14 @@ fn wave_spawning(
                 });
 
                 info!(
-                    "ENDLESS Wave {}: {} enemies ({}x escalation)",
-                    endless.wave, enemy_count, endless.escalation
+                    "ENDLESS Wave {}: {} enemies, {} points ({}x escalation)",
+                    endless.wave, enemy_count, budget, endless.escalation
                 );
                 return;
             }
 
-            // CAMPAIGN MODE: Check if time for boss
-            if manager.wave > manager.waves_per_stage {
+            // CAMPAIGN MODE: Check if time for boss (attack modes have none)
+            if manager.wave > manager.waves_per_stage && !run_mode.is_attack() {
                 manager.boss_active = true;
                 boss_spawn_events.send(super::boss::BossSpawnEvent {
                     stage: manager.current_stage,
@@ -386,19 +417,26 @@ fn wave_spawning(
                 return;
             }
 
-            // Setup new wave
-            let wave_def = get_wave_definition(manager.current_stage, manager.wave);
-            manager.enemies_remaining = wave_def.enemy_count;
+            // Setup new wave: its budget buys the whole roster up front
+            let wave_def = wave_definition(
+                manager.current_stage,
+                manager.wave,
+                manager.waves_per_stage,
+                &ng_plus,
+            );
+            let budget = (wave_def.budget as f32 * difficulty.spawn_rate_mult()).round() as u32;
+            manager.roster = spend_budget(budget, session.enemy_ships(), &mut run_rng.spawns)
+                .iter()
+                .map(|e| e.type_id)
+                .collect();
+            let enemy_count = manager.roster.len() as u32;
+            manager.enemies_remaining = enemy_count;
             manager.spawn_interval = 0.5 + 0.3 / (manager.wave as f32).sqrt();
 
             wave_events.send(SpawnWaveEvent {
                 wave_number: manager.wave,
-                enemy_count: wave_def.enemy_count,
-                enemy_types: wave_def
-                    .enemy_types
-                    .iter()
-                    .map(|id| format!("{}", id))
-                    .collect(),
+                enemy_count,
+                enemy_types: manager.roster.iter().map(|id| format!("{}", id)).collect(),
             });
 
             // Wave incoming callout on significant waves (every 5th or last before boss)
@@ -408,9 +446,9 @@ fn wave_spawning(
                 ));
             }
 
-            info!(
-                "Stage {} Wave {}/{}: {} enemies",
-                manager.current_stage, manager.wave, manager.waves_per_stage, wave_def.enemy_count
+            debug!(
+                "Stage {} Wave {}/{}: {} enemies ({} points)",
+                manager.current_stage, manager.wave, manager.waves_per_stage, enemy_count, budget
             );
         }
         return;
@@ -423,34 +461,40 @@ fn wave_spawning(
             manager.spawn_timer = manager.spawn_interval;
 
             // Get wave definition for behaviors and patterns
-            let wave_def = get_wave_definition(manager.current_stage, manager.wave);
+            let wave_def = wave_definition(
+                manager.current_stage,
+                manager.wave,
+                manager.waves_per_stage,
+                &ng_plus,
+            );
 
-            // Get random enemy from enemy faction using GameSession
-            let enemy_def = session.random_enemy();
-            let type_id = enemy_def.type_id;
+            // Next ship the wave's budget bought
+            // Seeded so a Daily Challenge replays the same spawns
+            let rng = &mut run_rng.spawns;
+            let wave_size = manager.roster.len() as u32;
+            let idx = wave_size.saturating_sub(manager.enemies_remaining);
+            let type_id = manager.roster.get(idx as usize).copied().unwrap_or(597);
 
             // Pick behavior based on stage progression
-            let behavior_idx = fastrand::usize(..wave_def.behaviors.len());
+            let behavior_idx = rng.usize(..wave_def.behaviors.len());
             let behavior = wave_def.behaviors[behavior_idx];
 
             // Spawn position based on pattern - enemies launch from carrier
             let pos = match wave_def.spawn_pattern {
                 SpawnPattern::Single | SpawnPattern::Random => {
                     // Spawn near carrier with random spread
-                    let x = carrier_pos.x + fastrand::f32() * 200.0 - 100.0;
+                    let x = carrier_pos.x + rng.f32() * 200.0 - 100.0;
                     Vec2::new(x, carrier_pos.y - 50.0)
                 }
                 SpawnPattern::Line => {
                     // Line formation emanating from carrier
-                    let spacing = 300.0 / (wave_def.enemy_count as f32 + 1.0);
-                    let idx = wave_def.enemy_count - manager.enemies_remaining;
+                    let spacing = 300.0 / (wave_size as f32 + 1.0);
                     let x = carrier_pos.x + spacing * (idx as f32 + 1.0) - 150.0;
                     Vec2::new(x, carrier_pos.y - 40.0)
                 }
                 SpawnPattern::VFormation => {
                     // V formation launching from carrier bay
-                    let idx = wave_def.enemy_count - manager.enemies_remaining;
-                    let center_idx = wave_def.enemy_count / 2;
+                    let center_idx = wave_size / 2;
                     let offset = (idx as i32 - center_idx as i32) as f32;
                     let x = carrier_pos.x + offset * 50.0;
                     let y = carrier_pos.y - 30.0 - offset.abs() * 25.0;
@@ -458,7 +502,7 @@ fn wave_spawning(
                 }
                 SpawnPattern::Circle => {
                     // Circle around carrier
-                    let angle = (manager.enemies_remaining as f32) / (wave_def.enemy_count as f32)
+                    let angle = (manager.enemies_remaining as f32) / (wave_size as f32)
                         * std::f32::consts::TAU;
                     let x = carrier_pos.x + angle.cos() * 150.0;
                     let y = carrier_pos.y + angle.sin() * 80.0 - 20.0;
@@ -466,41 +510,74 @@ fn wave_spawning(
                 }
                 SpawnPattern::Swarm => {
                     // Swarm bursting from carrier bay
-                    let x = carrier_pos.x + fastrand::f32() * 300.0 - 150.0;
-                    let y = carrier_pos.y - 20.0 - fastrand::f32() * 60.0;
+                    let x = carrier_pos.x + rng.f32() * 300.0 - 150.0;
+                    let y = carrier_pos.y - 20.0 - rng.f32() * 60.0;
                     Vec2::new(x, y)
                 }
             };
 
+            // Scattered waves also fly in from the sides, warp in from the
+            // corners and, on the harder settings, ambush from behind
+            let scattered = matches!(
+                wave_def.spawn_pattern,
+                SpawnPattern::Single | SpawnPattern::Random | SpawnPattern::Swarm
+            );
+            let edge = if scattered {
+                SpawnEdge::pick(*difficulty, rng)
+            } else {
+                SpawnEdge::Top
+            };
+            let entry = match player_pos {
+                Some(player) => edge.safe_entry_path(&playfield, player, rng),
+                None => edge.entry_path(&playfield, rng),
+            };
+            let pos = match (entry, player_pos) {
+                (Some((from, _)), _) => from,
+                (None, Some(player)) => clear_of_player(pos, player),
+                (None, None) => pos,
+            };
+
+            // Ambushers hunt the player instead of flying on past them
+            let behavior = match behavior {
+                EnemyBehavior::Linear | EnemyBehavior::Zigzag | EnemyBehavior::Weaver
+                    if entry.is_some() && edge.is_ambush() =>
+                {
+                    EnemyBehavior::Homing
+                }
+                other => other,
+            };
+
             let sprite = sprite_cache.get(type_id);
 
             // Use specialized spawn functions for special enemy types
-            match behavior {
+            let enemy = match behavior {
                 EnemyBehavior::Kamikaze => {
-                    spawn_kamikaze(&mut commands, pos, sprite, Some(&model_cache));
+                    spawn_kamikaze(&mut commands, pos, sprite, Some(&model_cache))
                 }
                 EnemyBehavior::Weaver => {
-                    spawn_weaver(&mut commands, pos, sprite, Some(&model_cache));
+                    spawn_weaver(&mut commands, pos, sprite, Some(&model_cache))
                 }
                 EnemyBehavior::Sniper => {
-                    spawn_sniper(&mut commands, pos, sprite, Some(&model_cache));
+                    spawn_sniper(&mut commands, pos, sprite, Some(&model_cache))
                 }
                 EnemyBehavior::Spawner => {
-                    spawn_spawner_enemy(&mut commands, pos, sprite, Some(&model_cache));
-                }
-                EnemyBehavior::Tank => {
-                    spawn_tank(&mut commands, pos, sprite, Some(&model_cache));
-                }
-                _ => {
-                    spawn_enemy(
-                        &mut commands,
-                        type_id,
-                        pos,
-                        behavior,
-                        sprite,
-                        Some(&model_cache),
-                    );
+                    spawn_spawner_enemy(&mut commands, pos, sprite, Some(&model_cache))
                 }
+                EnemyBehavior::Tank => spawn_tank(&mut commands, pos, sprite, Some(&model_cache)),
+                _ => spawn_enemy(
+                    &mut commands,
+                    type_id,
+                    pos,
+                    behavior,
+                    sprite,
+                    Some(&model_cache),
+                ),
+            };
+            if let Some((from, to)) = entry {
+                begin_entry(&mut commands, &playfield, enemy, from, to, edge);
+            }
+            if rng.f32() < ng_plus.modifiers().elite_chance {
+                commands.entity(enemy).insert(Elite);
             }
             manager.enemies_remaining -= 1;
         }
@@ -510,7 +587,7 @@ fn wave_spawning(
     if manager.enemies_remaining == 0 && enemy_query.is_empty() && !manager.in_delay {
         manager.in_delay = true;
         manager.wave_delay = WAVE_DELAY;
-        info!("Wave {} complete!", manager.wave);
+        debug!("Wave {} complete!", manager.wave);
     }
 }
 
@@ -544,8 +621,22 @@ fn handle_spawn_events(
     }
 }
 
-/// Get wave definition based on stage and wave number
-fn get_wave_definition(stage: u32, wave: u32) -> WaveDefinition {
+/// Wave definition, remixed in New Game+: enemy types and behaviors come
+/// from a later stage, and budgets run larger
+fn wave_definition(stage: u32, wave: u32, waves: u32, ng_plus: &NewGamePlus) -> WaveDefinition {
+    let mods = ng_plus.modifiers();
+    let mut def = get_wave_definition(stage, wave, waves);
+    if mods.stage_shift > 0 {
+        let later = get_wave_definition((stage + mods.stage_shift).min(13), wave, waves);
+        def.enemy_types = later.enemy_types;
+        def.behaviors = later.behaviors;
+        def.budget += mods.extra_enemies * FRIGATE_COST;
+    }
+    def
+}
+
+/// Get wave definition based on stage and wave number (of `waves`)
+fn get_wave_definition(stage: u32, wave: u32, waves: u32) -> WaveDefinition {
     // Amarr enemy type IDs
     const PUNISHER: u32 = 597;
     const EXECUTIONER: u32 = 589;
@@ -554,8 +645,8 @@ fn get_wave_definition(stage: u32, wave: u32) -> WaveDefinition {
     const MALLER: u32 = 624;
     const OMEN: u32 = 625;
 
-    // Base enemy count scales with stage and wave
-    let base_count = 3 + wave + (stage / 2);
+    // Opening budget scales with stage; the pacing curve shapes the waves
+    let base_budget = (4 + stage / 2) * FRIGATE_COST;
 
     // Enemy types based on stage (Acts 1, 2, 3)
     let enemy_types = match stage {
@@ -627,7 +718,7 @@ fn get_wave_definition(stage: u32, wave: u32) -> WaveDefinition {
     };
 
     WaveDefinition {
-        enemy_count: base_count.min(12 + stage / 2), // Max scales with stage
+        budget: wave_budget(base_budget, wave, waves, &PacingCurve::default(), 1.0),
         enemy_types,
         behaviors,
         spawn_pattern,
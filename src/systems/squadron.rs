@@ -0,0 +1,486 @@
+//! Enemy Squadrons
+//!
+//! Enemies spawned in the same wave share a squadron entity. While the
+//! leader lives, the squadron periodically fans out around the player and
+//! fires a crossfire volley. Killing the leader breaks formation; killing
+//! the whole squadron within two seconds pays a wipe bonus.
+
+#![allow(dead_code)]
+
+use crate::core::*;
+use crate::entities::{Enemy, EnemyStats, EnemyWeapon, Player};
+use bevy::prelude::*;
+
+/// Roster closes when no ship has joined for this long
+const SQUADRON_SEAL_TIME: f32 = 3.0;
+
+/// Every member must die within this window for a wipe
+const WIPE_WINDOW: f32 = 2.0;
+
+/// Smallest squadron that can be wiped for a bonus
+const WIPE_MIN_MEMBERS: u32 = 3;
+
+/// Bonus per ship for a squadron wipe
+const WIPE_BONUS_PER_SHIP: u64 = 200;
+
+/// Time between crossfire runs
+const FOCUS_INTERVAL: f32 = 6.0;
+
+/// Time members get to reach their firing slots
+const FOCUS_APPROACH: f32 = 1.2;
+
+/// Distance from the player for crossfire slots
+const FOCUS_RANGE: f32 = 240.0;
+
+/// How long members scatter after the leader dies
+const SCATTER_TIME: f32 = 1.5;
+
+/// Squadron plugin
+pub struct SquadronPlugin;
+
+impl Plugin for SquadronPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SquadronRoster>()
+            .add_systems(
+                Update,
+                (
+                    open_squadrons,
+                    join_squadrons,
+                    track_squadron_losses,
+                    squadron_orders,
+                    resolve_squadrons,
+                )
+                    .chain()
+                    .in_set(GameSet::Simulation)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(OnExit(GameState::Playing), cleanup_squadrons);
+    }
+}
+
+// =============================================================================
+// COMPONENTS
+// =============================================================================
+
+/// A wave's worth of enemies fighting together
+#[derive(Component, Debug, Default)]
+pub struct Squadron {
+    pub leader: Option<Entity>,
+    /// Everyone who ever joined, in join order
+    pub members: Vec<Entity>,
+    /// Members shot down (entity, time)
+    pub kills: Vec<(Entity, f32)>,
+    /// Members that left without being shot down
+    pub lost: u32,
+    /// Leader is dead - no more coordinated runs
+    pub broken: bool,
+    /// Roster closed to new members
+    pub sealed: bool,
+    /// Seconds since the last member joined
+    idle: f32,
+    focus_timer: f32,
+    /// Seconds into the current crossfire run
+    focus_elapsed: Option<f32>,
+}
+
+impl Squadron {
+    fn new() -> Self {
+        Self {
+            focus_timer: FOCUS_INTERVAL,
+            ..default()
+        }
+    }
+
+    pub fn is_member(&self, entity: Entity) -> bool {
+        self.members.contains(&entity)
+    }
+
+    pub fn was_killed(&self, entity: Entity) -> bool {
+        self.kills.iter().any(|(e, _)| *e == entity)
+    }
+
+    pub fn record_kill(&mut self, entity: Entity, time: f32) {
+        if self.is_member(entity) && !self.was_killed(entity) {
+            self.kills.push((entity, time));
+        }
+    }
+
+    /// Every member shot down, all within the wipe window
+    pub fn is_wipe(&self) -> bool {
+        let count = self.members.len() as u32;
+        if !self.sealed || count < WIPE_MIN_MEMBERS || self.lost > 0 {
+            return false;
+        }
+        if self.kills.len() as u32 != count {
+            return false;
+        }
+        let first = self.kills.iter().map(|k| k.1).fold(f32::MAX, f32::min);
+        let last = self.kills.iter().map(|k| k.1).fold(f32::MIN, f32::max);
+        last - first <= WIPE_WINDOW
+    }
+
+    pub fn wipe_bonus(&self) -> u64 {
+        self.members.len() as u64 * WIPE_BONUS_PER_SHIP
+    }
+}
+
+/// Membership of an enemy in a squadron
+#[derive(Component, Debug)]
+pub struct SquadronMember {
+    pub squadron: Entity,
+    /// Desired velocity during squadron maneuvers (None = fly solo)
+    pub steer: Option<Vec2>,
+    scatter: f32,
+    scatter_dir: Vec2,
+    fired: bool,
+}
+
+/// Marker for the squadron leader's pennant
+#[derive(Component, Debug)]
+pub struct SquadronLeaderMark;
+
+/// Squadron currently accepting new members
+#[derive(Resource, Debug, Default)]
+pub struct SquadronRoster {
+    pub active: Option<Entity>,
+    last_campaign_wave: u32,
+}
+
+// =============================================================================
+// SYSTEMS
+// =============================================================================
+
+/// Open a new squadron at the start of each wave
+fn open_squadrons(
+    mut commands: Commands,
+    mut roster: ResMut<SquadronRoster>,
+    mut wave_events: EventReader<SpawnWaveEvent>,
+    campaign: Res<CampaignState>,
+    mut squadrons: Query<&mut Squadron>,
+) {
+    let stage_wave = wave_events.read().count() > 0;
+    let campaign_wave = campaign.in_mission && campaign.current_wave != roster.last_campaign_wave;
+    roster.last_campaign_wave = campaign.current_wave;
+
+    if !stage_wave && !campaign_wave {
+        return;
+    }
+
+    if let Some(mut previous) = roster.active.and_then(|e| squadrons.get_mut(e).ok()) {
+        previous.sealed = true;
+    }
+    roster.active = Some(commands.spawn(Squadron::new()).id());
+}
+
+/// Newly spawned enemies join the open squadron; the first becomes leader
+fn join_squadrons(
+    mut commands: Commands,
+    roster: Res<SquadronRoster>,
+    new_enemies: Query<(Entity, &EnemyStats), (Added<Enemy>, Without<SquadronMember>)>,
+    mut squadrons: Query<&mut Squadron>,
+) {
+    let Some(squadron_entity) = roster.active else {
+        return;
+    };
+    let Ok(mut squadron) = squadrons.get_mut(squadron_entity) else {
+        return;
+    };
+    if squadron.sealed {
+        return;
+    }
+
+    for (entity, stats) in new_enemies.iter() {
+        if stats.is_boss {
+            continue;
+        }
+        squadron.members.push(entity);
+        squadron.idle = 0.0;
+        commands.entity(entity).insert(SquadronMember {
+            squadron: squadron_entity,
+            steer: None,
+            scatter: 0.0,
+            scatter_dir: Vec2::Y,
+            fired: false,
+        });
+
+        if squadron.leader.is_none() {
+            squadron.leader = Some(entity);
+            let pennant = commands
+                .spawn((
+                    SquadronLeaderMark,
+                    Sprite {
+                        color: Color::srgba(1.0, 0.8, 0.2, 0.8),
+                        custom_size: Some(Vec2::splat(7.0)),
+                        ..default()
+                    },
+                    Transform::from_xyz(0.0, -28.0, 1.0)
+                        .with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4)),
+                ))
+                .id();
+            commands.entity(entity).add_child(pennant);
+        }
+    }
+}
+
+/// Record kills, and count members that left any other way
+fn track_squadron_losses(
+    time: Res<Time>,
+    mut destroyed: EventReader<EnemyDestroyedEvent>,
+    mut squadrons: Query<&mut Squadron>,
+    alive: Query<(), With<Enemy>>,
+    mut gone: Local<Vec<Entity>>,
+) {
+    let now = time.elapsed_secs();
+    let kills: Vec<Entity> = destroyed.read().map(|e| e.entity).collect();
+
+    for mut squadron in squadrons.iter_mut() {
+        for &entity in &kills {
+            squadron.record_kill(entity, now);
+        }
+
+        // Despawned but never shot down: flew off screen or rammed
+        gone.clear();
+        gone.extend(
+            squadron
+                .members
+                .iter()
+                .copied()
+                .filter(|e| alive.get(*e).is_err() && !squadron.was_killed(*e)),
+        );
+        if !gone.is_empty() {
+            squadron.lost += gone.len() as u32;
+            squadron.members.retain(|e| !gone.contains(e));
+        }
+    }
+}
+
+/// Leader checks, scatter on leader death, and crossfire runs
+fn squadron_orders(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut squadrons: Query<(Entity, &mut Squadron)>,
+    mut members: Query<(&Transform, &EnemyStats, &EnemyWeapon, &mut SquadronMember)>,
+    player_query: Query<&Transform, With<Player>>,
+    mut dialogue_events: EventWriter<super::DialogueEvent>,
+) {
+    let dt = time.delta_secs();
+    let player_pos = player_query.get_single().map(|t| t.translation.truncate());
+
+    for (squadron_entity, mut squadron) in squadrons.iter_mut() {
+        let alive: Vec<Entity> = squadron
+            .members
+            .iter()
+            .copied()
+            .filter(|e| members.contains(*e))
+            .collect();
+        if alive.is_empty() {
+            continue;
+        }
+
+        // Leader down: break formation and scatter
+        if !squadron.broken && squadron.leader.is_some_and(|l| !alive.contains(&l)) {
+            squadron.broken = true;
+            squadron.focus_elapsed = None;
+
+            let center = alive
+                .iter()
+                .filter_map(|e| members.get(*e).ok())
+                .map(|(t, ..)| t.translation.truncate())
+                .sum::<Vec2>()
+                / alive.len() as f32;
+            for &entity in &alive {
+                if let Ok((transform, _, _, mut member)) = members.get_mut(entity) {
+                    let away = transform.translation.truncate() - center;
+                    member.scatter = SCATTER_TIME;
+                    member.scatter_dir = away.normalize_or(Vec2::Y);
+                }
+            }
+
+            if squadron.leader.is_some_and(|l| squadron.was_killed(l)) && alive.len() > 1 {
+                dialogue_events.send(super::DialogueEvent::combat_callout(
+                    super::CombatCalloutType::SquadronLeaderDown,
+                ));
+            }
+            info!(
+                "Squadron {:?} leader down - formation broken",
+                squadron_entity
+            );
+        }
+
+        // Scatter: fly apart at speed, then resume solo behavior
+        if squadron.broken {
+            for &entity in &alive {
+                if let Ok((_, stats, _, mut member)) = members.get_mut(entity) {
+                    member.scatter -= dt;
+                    member.steer =
+                        (member.scatter > 0.0).then_some(member.scatter_dir * stats.speed * 1.5);
+                }
+            }
+            continue;
+        }
+
+        let Ok(target) = player_pos else {
+            continue;
+        };
+        if alive.len() < 2 {
+            continue;
+        }
+
+        // Crossfire runs: fan out around the player, then fire together
+        let Some(elapsed) = squadron.focus_elapsed else {
+            squadron.focus_timer -= dt;
+            if squadron.focus_timer <= 0.0 {
+                squadron.focus_elapsed = Some(0.0);
+                for &entity in &alive {
+                    if let Ok((.., mut member)) = members.get_mut(entity) {
+                        member.fired = false;
+                    }
+                }
+            }
+            continue;
+        };
+        let elapsed = elapsed + dt;
+        squadron.focus_elapsed = Some(elapsed);
+
+        let count = alive.len();
+        for (slot, &entity) in alive.iter().enumerate() {
+            let Ok((transform, stats, weapon, mut member)) = members.get_mut(entity) else {
+                continue;
+            };
+            let pos = transform.translation.truncate();
+
+            // Slots spread across the upper arc, 30-150 degrees
+            let t = (slot as f32 + 0.5) / count as f32;
+            let angle = (30.0 + 120.0 * t).to_radians();
+            let slot_pos = target + Vec2::new(angle.cos(), angle.sin()) * FOCUS_RANGE;
+            let to = slot_pos - pos;
+            member.steer =
+                Some(to.normalize_or_zero() * stats.speed * 1.4 * (to.length() / 60.0).min(1.0));
+
+            if elapsed >= FOCUS_APPROACH && !member.fired {
+                member.fired = true;
+                crate::entities::projectile::spawn_enemy_projectile_typed(
+                    &mut commands,
+                    pos,
+                    (target - pos).normalize_or_zero(),
+                    weapon.damage,
+                    weapon.bullet_speed,
+                    weapon.weapon_type,
+                    crate::entities::ProjectileOwner::enemy().fired_by(entity),
+                );
+            }
+        }
+
+        // Run over: back to solo flying
+        if elapsed >= FOCUS_APPROACH + 0.3 {
+            squadron.focus_elapsed = None;
+            squadron.focus_timer = FOCUS_INTERVAL;
+            for &entity in &alive {
+                if let Ok((.., mut member)) = members.get_mut(entity) {
+                    member.steer = None;
+                }
+            }
+        }
+    }
+}
+
+/// Seal idle rosters; score wipes and tidy up finished squadrons
+fn resolve_squadrons(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut roster: ResMut<SquadronRoster>,
+    mut squadrons: Query<(Entity, &mut Squadron)>,
+    alive: Query<(), With<Enemy>>,
+    mut score_events: EventWriter<ScoreEvent>,
+    mut dialogue_events: EventWriter<super::DialogueEvent>,
+) {
+    for (entity, mut squadron) in squadrons.iter_mut() {
+        if !squadron.sealed {
+            squadron.idle += time.delta_secs();
+            if squadron.idle >= SQUADRON_SEAL_TIME && !squadron.members.is_empty() {
+                squadron.sealed = true;
+            }
+        }
+        if squadron.sealed && roster.active == Some(entity) {
+            roster.active = None;
+        }
+        if !squadron.sealed || squadron.members.iter().any(|e| alive.contains(*e)) {
+            continue;
+        }
+
+        if squadron.is_wipe() {
+            let bonus = squadron.wipe_bonus();
+            score_events.send(ScoreEvent::new(ScoreCategory::Bonus, bonus));
+            dialogue_events.send(super::DialogueEvent::combat_callout(
+                super::CombatCalloutType::SquadronWipe,
+            ));
+            info!(
+                "SQUADRON WIPE! {} ships, +{}",
+                squadron.members.len(),
+                bonus
+            );
+        }
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn cleanup_squadrons(
+    mut commands: Commands,
+    mut roster: ResMut<SquadronRoster>,
+    squadrons: Query<Entity, With<Squadron>>,
+) {
+    for entity in squadrons.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    *roster = SquadronRoster::default();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn squadron_of(n: u32) -> Squadron {
+        Squadron {
+            members: (0..n).map(Entity::from_raw).collect(),
+            sealed: true,
+            ..Squadron::new()
+        }
+    }
+
+    #[test]
+    fn wipe_needs_every_member_inside_window() {
+        let mut quick = squadron_of(4);
+        for (i, t) in [10.0, 10.5, 11.2, 11.9].iter().enumerate() {
+            quick.record_kill(Entity::from_raw(i as u32), *t);
+        }
+        assert!(quick.is_wipe());
+        assert_eq!(quick.wipe_bonus(), 4 * WIPE_BONUS_PER_SHIP);
+
+        let mut slow = squadron_of(3);
+        for (i, t) in [10.0, 11.0, 12.5].iter().enumerate() {
+            slow.record_kill(Entity::from_raw(i as u32), *t);
+        }
+        assert!(!slow.is_wipe());
+    }
+
+    #[test]
+    fn no_wipe_with_escapes_or_tiny_squadrons() {
+        let mut escaped = squadron_of(3);
+        escaped.record_kill(Entity::from_raw(0), 1.0);
+        escaped.record_kill(Entity::from_raw(1), 1.2);
+        escaped.lost = 1;
+        assert!(!escaped.is_wipe());
+
+        let mut pair = squadron_of(2);
+        pair.record_kill(Entity::from_raw(0), 1.0);
+        pair.record_kill(Entity::from_raw(1), 1.1);
+        assert!(!pair.is_wipe());
+
+        // Kills of outsiders and double-counting are ignored
+        let mut squad = squadron_of(3);
+        squad.record_kill(Entity::from_raw(99), 1.0);
+        squad.record_kill(Entity::from_raw(0), 1.0);
+        squad.record_kill(Entity::from_raw(0), 1.5);
+        assert_eq!(squad.kills.len(), 1);
+    }
+}
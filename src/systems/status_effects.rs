@@ -0,0 +1,564 @@
+//! Status Effects
+//!
+//! Damage over time and debuffs on ships: burning (lasers), EMP slow (ECM),
+//! webbed (Warp Disruptor) and armor breach (explosives). Sources send a
+//! `StatusEffectEvent`; stacking rules live on `StatusEffects`.
+
+#![allow(dead_code)]
+
+use crate::core::*;
+use crate::entities::release_souls;
+use crate::entities::{Enemy, EnemyStats, Player, PowerupEffects, ShipStats};
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Burn damage per stack per second
+const BURN_DPS_PER_STACK: f32 = 4.0;
+
+/// Seconds between damage-over-time ticks
+const DOT_TICK_INTERVAL: f32 = 0.5;
+
+/// Speed multiplier while EMP'd
+const EMP_SPEED_MULT: f32 = 0.6;
+
+/// Weapon cycle multiplier while EMP'd
+const EMP_FIRE_RATE_MULT: f32 = 0.5;
+
+/// Speed multiplier while webbed
+const WEB_SPEED_MULT: f32 = 0.35;
+
+/// Extra damage taken per armor breach stack
+const BREACH_DAMAGE_PER_STACK: f32 = 0.1;
+
+/// Warp Disruptor web radius around the player
+pub const WARP_DISRUPTOR_RADIUS: f32 = 260.0;
+
+/// Status effects plugin
+pub struct StatusEffectsPlugin;
+
+impl Plugin for StatusEffectsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<StatusEffectEvent>().add_systems(
+            Update,
+            (
+                warp_disruptor_web,
+                apply_status_events,
+                tick_enemy_status,
+                tick_player_status,
+                sync_status_icons,
+            )
+                .chain()
+                .in_set(GameSet::Simulation)
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
+
+// =============================================================================
+// STATUS TYPES
+// =============================================================================
+
+/// Kind of status effect
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatusKind {
+    /// Thermal damage over time (stacks intensity)
+    Burning,
+    /// Slowed movement and weapon cycling
+    EmpSlow,
+    /// Heavily slowed movement
+    Webbed,
+    /// Takes extra damage from all sources (stacks)
+    ArmorBreach,
+}
+
+impl StatusKind {
+    pub const ALL: [StatusKind; 4] = [
+        StatusKind::Burning,
+        StatusKind::EmpSlow,
+        StatusKind::Webbed,
+        StatusKind::ArmorBreach,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            StatusKind::Burning => "BURNING",
+            StatusKind::EmpSlow => "EMP",
+            StatusKind::Webbed => "WEBBED",
+            StatusKind::ArmorBreach => "BREACH",
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            StatusKind::Burning => Color::srgb(1.0, 0.45, 0.1),
+            StatusKind::EmpSlow => Color::srgb(0.4, 0.7, 1.0),
+            StatusKind::Webbed => Color::srgb(0.7, 0.4, 1.0),
+            StatusKind::ArmorBreach => Color::srgb(0.9, 0.85, 0.3),
+        }
+    }
+
+    /// Maximum stacks (1 = refresh only)
+    pub fn max_stacks(&self) -> u32 {
+        match self {
+            StatusKind::Burning => 3,
+            StatusKind::ArmorBreach => 5,
+            StatusKind::EmpSlow | StatusKind::Webbed => 1,
+        }
+    }
+}
+
+/// One active effect
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusEffect {
+    pub kind: StatusKind,
+    pub stacks: u32,
+    /// Seconds remaining
+    pub remaining: f32,
+    /// Longest duration applied (for timer bars)
+    pub duration: f32,
+    /// Time until the next damage tick
+    pub tick_timer: f32,
+}
+
+/// Active status effects on a ship
+#[derive(Component, Debug, Clone, Default)]
+pub struct StatusEffects {
+    effects: Vec<StatusEffect>,
+}
+
+impl StatusEffects {
+    /// Apply an effect. Re-applying refreshes the duration (never shortens it)
+    /// and adds a stack up to the kind's cap.
+    pub fn apply(&mut self, kind: StatusKind, duration: f32) {
+        if let Some(effect) = self.effects.iter_mut().find(|e| e.kind == kind) {
+            effect.stacks = (effect.stacks + 1).min(kind.max_stacks());
+            effect.remaining = effect.remaining.max(duration);
+            effect.duration = effect.duration.max(duration);
+            return;
+        }
+        self.effects.push(StatusEffect {
+            kind,
+            stacks: 1,
+            remaining: duration,
+            duration,
+            tick_timer: DOT_TICK_INTERVAL,
+        });
+    }
+
+    /// Advance timers, drop expired effects, and return damage due this frame
+    pub fn tick(&mut self, dt: f32) -> f32 {
+        let mut damage = 0.0;
+        for effect in &mut self.effects {
+            effect.remaining -= dt;
+            if effect.kind != StatusKind::Burning {
+                continue;
+            }
+            effect.tick_timer -= dt;
+            while effect.tick_timer <= 0.0 {
+                effect.tick_timer += DOT_TICK_INTERVAL;
+                damage += BURN_DPS_PER_STACK * effect.stacks as f32 * DOT_TICK_INTERVAL;
+            }
+        }
+        self.effects.retain(|e| e.remaining > 0.0);
+        damage
+    }
+
+    pub fn get(&self, kind: StatusKind) -> Option<&StatusEffect> {
+        self.effects.iter().find(|e| e.kind == kind)
+    }
+
+    pub fn has(&self, kind: StatusKind) -> bool {
+        self.get(kind).is_some()
+    }
+
+    pub fn stacks(&self, kind: StatusKind) -> u32 {
+        self.get(kind).map(|e| e.stacks).unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.effects.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &StatusEffect> {
+        self.effects.iter()
+    }
+
+    /// Movement multiplier. Slows don't stack - the strongest one wins.
+    pub fn speed_mult(&self) -> f32 {
+        let mut mult: f32 = 1.0;
+        if self.has(StatusKind::EmpSlow) {
+            mult = mult.min(EMP_SPEED_MULT);
+        }
+        if self.has(StatusKind::Webbed) {
+            mult = mult.min(WEB_SPEED_MULT);
+        }
+        mult
+    }
+
+    /// Weapon cycle multiplier
+    pub fn fire_rate_mult(&self) -> f32 {
+        if self.has(StatusKind::EmpSlow) {
+            EMP_FIRE_RATE_MULT
+        } else {
+            1.0
+        }
+    }
+
+    /// Incoming damage multiplier
+    pub fn damage_taken_mult(&self) -> f32 {
+        1.0 + self.stacks(StatusKind::ArmorBreach) as f32 * BREACH_DAMAGE_PER_STACK
+    }
+}
+
+/// Multipliers for an optional `StatusEffects` (ships without one are unaffected)
+pub fn status_speed_mult(status: Option<&StatusEffects>) -> f32 {
+    status.map(|s| s.speed_mult()).unwrap_or(1.0)
+}
+
+pub fn status_damage_mult(status: Option<&StatusEffects>) -> f32 {
+    status.map(|s| s.damage_taken_mult()).unwrap_or(1.0)
+}
+
+/// Attached to projectiles that can inflict a status on hit
+#[derive(Component, Debug, Clone, Copy)]
+pub struct InflictsStatus {
+    pub kind: StatusKind,
+    /// Chance to apply per hit (0.0 - 1.0)
+    pub chance: f32,
+    pub duration: f32,
+}
+
+impl InflictsStatus {
+    /// Laser hits set the target alight
+    pub fn burning() -> Self {
+        Self {
+            kind: StatusKind::Burning,
+            chance: 0.3,
+            duration: 3.0,
+        }
+    }
+
+    /// Explosive hits crack armor plating
+    pub fn armor_breach() -> Self {
+        Self {
+            kind: StatusKind::ArmorBreach,
+            chance: 0.35,
+            duration: 5.0,
+        }
+    }
+
+    /// Status for a weapon's projectiles, if any
+    pub fn for_weapon(weapon_type: WeaponType) -> Option<Self> {
+        match weapon_type {
+            WeaponType::Laser => Some(Self::burning()),
+            WeaponType::MissileLauncher => Some(Self::armor_breach()),
+            _ => None,
+        }
+    }
+
+    /// Roll the proc chance and build the event
+    pub fn roll(&self, target: Entity, rng: &mut fastrand::Rng) -> Option<StatusEffectEvent> {
+        (rng.f32() < self.chance).then_some(StatusEffectEvent {
+            target,
+            kind: self.kind,
+            duration: self.duration,
+        })
+    }
+}
+
+/// Request to apply a status effect to an entity
+#[derive(Event, Debug, Clone)]
+pub struct StatusEffectEvent {
+    pub target: Entity,
+    pub kind: StatusKind,
+    pub duration: f32,
+}
+
+/// Floating icon over an affected ship
+#[derive(Component, Debug)]
+pub struct StatusIcon {
+    pub kind: StatusKind,
+}
+
+// =============================================================================
+// SYSTEMS
+// =============================================================================
+
+/// Warp Disruptor: web every enemy near the player
+fn warp_disruptor_web(
+    mut ability_events: EventReader<super::AbilityActivatedEvent>,
+    player_query: Query<&Transform, With<Player>>,
+    enemy_query: Query<(Entity, &Transform), With<Enemy>>,
+    mut status_events: EventWriter<StatusEffectEvent>,
+    mut explosion_events: EventWriter<ExplosionEvent>,
+) {
+    for event in ability_events.read() {
+        if event.ability_type != super::AbilityType::WarpDisruptor {
+            continue;
+        }
+        let Ok(player_transform) = player_query.get(event.player_entity) else {
+            continue;
+        };
+        let player_pos = player_transform.translation.truncate();
+        let duration = event.ability_type.duration();
+
+        let mut webbed = 0;
+        for (entity, transform) in enemy_query.iter() {
+            if transform.translation.truncate().distance(player_pos) <= WARP_DISRUPTOR_RADIUS {
+                status_events.send(StatusEffectEvent {
+                    target: entity,
+                    kind: StatusKind::Webbed,
+                    duration,
+                });
+                webbed += 1;
+            }
+        }
+
+        explosion_events.send(ExplosionEvent {
+            position: player_pos,
+            size: ExplosionSize::Medium,
+            color: StatusKind::Webbed.color(),
+        });
+        info!("Warp Disruptor: {} enemies webbed", webbed);
+    }
+}
+
+/// Apply queued status events, adding the component where missing
+fn apply_status_events(
+    mut commands: Commands,
+    mut events: EventReader<StatusEffectEvent>,
+    mut query: Query<&mut StatusEffects>,
+) {
+    // Entities without a component yet (may receive several events this frame)
+    let mut fresh: HashMap<Entity, StatusEffects> = HashMap::new();
+
+    for event in events.read() {
+        if let Ok(mut status) = query.get_mut(event.target) {
+            status.apply(event.kind, event.duration);
+        } else {
+            fresh
+                .entry(event.target)
+                .or_default()
+                .apply(event.kind, event.duration);
+        }
+    }
+
+    for (entity, status) in fresh {
+        if let Some(mut entity_commands) = commands.get_entity(entity) {
+            entity_commands.insert(status);
+        }
+    }
+}
+
+/// Tick enemy effects; burning can finish them off
+#[allow(clippy::too_many_arguments)]
+fn tick_enemy_status(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &Transform, &mut StatusEffects, &mut EnemyStats), With<Enemy>>,
+    mut score: ResMut<ScoreSystem>,
+    berserk: Res<BerserkSystem>,
+    mut run_rng: ResMut<RunRng>,
+    mut destroy_events: EventWriter<EnemyDestroyedEvent>,
+    mut explosion_events: EventWriter<ExplosionEvent>,
+) {
+    let dt = time.delta_secs();
+
+    for (entity, transform, mut status, mut stats) in query.iter_mut() {
+        let damage = status.tick(dt) * status.damage_taken_mult();
+        if damage <= 0.0 || stats.health <= 0.0 {
+            continue;
+        }
+
+        let pos = transform.translation.truncate();
+        stats.health -= damage;
+        super::effects::spawn_damage_number(&mut commands, pos, damage, false);
+
+        if stats.health > 0.0 {
+            continue;
+        }
+
+        let final_score = (stats.score_value as f32 * berserk.score_mult()) as u64;
+        let points_awarded = score.on_kill(final_score);
+
+        destroy_events.send(EnemyDestroyedEvent {
+            entity,
+            position: pos,
+            enemy_type: stats.name.clone(),
+            type_id: stats.type_id,
+            score_value: stats.score_value,
+            points_awarded,
+            was_boss: stats.is_boss,
+        });
+        explosion_events.send(ExplosionEvent {
+            position: pos,
+            size: if stats.is_boss {
+                ExplosionSize::Massive
+            } else {
+                ExplosionSize::Small
+            },
+            color: StatusKind::Burning.color(),
+        });
+
+        release_souls(
+            &mut commands,
+            &mut run_rng,
+            pos,
+            stats.type_id,
+            stats.liberation_value,
+        );
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Tick player effects; burning damages through shields like any thermal hit
+fn tick_player_status(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<
+        (
+            &Transform,
+            &mut StatusEffects,
+            &mut ShipStats,
+            &PowerupEffects,
+        ),
+        With<Player>,
+    >,
+    mut destroyed_events: EventWriter<PlayerDestroyedEvent>,
+) {
+    let Ok((transform, mut status, mut stats, powerups)) = query.get_single_mut() else {
+        return;
+    };
+
+    let damage = status.tick(time.delta_secs()) * status.damage_taken_mult();
+    if damage <= 0.0 || powerups.is_invulnerable() {
+        return;
+    }
+
+    super::effects::spawn_damage_number(
+        &mut commands,
+        transform.translation.truncate(),
+        damage,
+        false,
+    );
+    if stats.take_damage(damage, DamageType::Thermal) {
+        info!("Player burned to death!");
+        destroyed_events.send(PlayerDestroyedEvent {
+            position: transform.translation.truncate(),
+        });
+    }
+}
+
+/// Keep one floating icon per active effect above each affected ship
+fn sync_status_icons(
+    mut commands: Commands,
+    time: Res<Time>,
+    ships: Query<(Entity, &StatusEffects)>,
+    mut icons: Query<(Entity, &StatusIcon, &Parent, &mut Transform, &mut Sprite)>,
+) {
+    let pulse = (time.elapsed_secs() * 6.0).sin() * 0.25 + 0.75;
+    let mut shown: Vec<(Entity, StatusKind)> = Vec::new();
+
+    for (icon_entity, icon, parent, mut transform, mut sprite) in icons.iter_mut() {
+        let Ok((_, status)) = ships.get(parent.get()) else {
+            continue;
+        };
+        let Some(index) = status.iter().position(|e| e.kind == icon.kind) else {
+            commands.entity(icon_entity).despawn_recursive();
+            continue;
+        };
+        let Some(effect) = status.get(icon.kind) else {
+            continue;
+        };
+
+        // Center the row of icons over the ship
+        let count = status.iter().count();
+        transform.translation.x = (index as f32 - (count - 1) as f32 / 2.0) * 9.0;
+        sprite.custom_size = Some(Vec2::splat(5.0 + effect.stacks as f32));
+        // Blink out the last second
+        let alpha = if effect.remaining < 1.0 {
+            pulse * effect.remaining.max(0.3)
+        } else {
+            pulse
+        };
+        sprite.color = icon.kind.color().with_alpha(alpha);
+        shown.push((parent.get(), icon.kind));
+    }
+
+    for (ship, status) in ships.iter() {
+        for effect in status.iter() {
+            if shown.contains(&(ship, effect.kind)) {
+                continue;
+            }
+            let icon = commands
+                .spawn((
+                    StatusIcon { kind: effect.kind },
+                    Sprite {
+                        color: effect.kind.color(),
+                        custom_size: Some(Vec2::splat(6.0)),
+                        ..default()
+                    },
+                    Transform::from_xyz(0.0, 30.0, 1.0)
+                        .with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4)),
+                ))
+                .id();
+            commands.entity(ship).add_child(icon);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn burning_stacks_to_cap_and_refreshes() {
+        let mut status = StatusEffects::default();
+        for _ in 0..5 {
+            status.apply(StatusKind::Burning, 3.0);
+        }
+        assert_eq!(status.stacks(StatusKind::Burning), 3);
+
+        status.tick(2.0);
+        status.apply(StatusKind::Burning, 3.0);
+        let remaining = status.get(StatusKind::Burning).map(|e| e.remaining);
+        assert_eq!(remaining, Some(3.0));
+
+        // A shorter application never cuts the timer down
+        status.apply(StatusKind::Burning, 1.0);
+        assert_eq!(
+            status.get(StatusKind::Burning).map(|e| e.remaining),
+            Some(3.0)
+        );
+    }
+
+    #[test]
+    fn burning_ticks_damage_per_stack_then_expires() {
+        let mut status = StatusEffects::default();
+        status.apply(StatusKind::Burning, 1.0);
+        status.apply(StatusKind::Burning, 1.0);
+
+        let damage = status.tick(1.0);
+        let expected = BURN_DPS_PER_STACK * 2.0;
+        assert!((damage - expected).abs() < 0.01);
+        assert!(!status.has(StatusKind::Burning));
+        assert!(status.is_empty());
+    }
+
+    #[test]
+    fn slows_take_strongest_and_breach_stacks() {
+        let mut status = StatusEffects::default();
+        status.apply(StatusKind::EmpSlow, 2.0);
+        status.apply(StatusKind::EmpSlow, 2.0);
+        assert_eq!(status.stacks(StatusKind::EmpSlow), 1);
+        assert_eq!(status.speed_mult(), EMP_SPEED_MULT);
+
+        status.apply(StatusKind::Webbed, 2.0);
+        assert_eq!(status.speed_mult(), WEB_SPEED_MULT);
+
+        status.apply(StatusKind::ArmorBreach, 5.0);
+        status.apply(StatusKind::ArmorBreach, 5.0);
+        assert!((status.damage_taken_mult() - 1.2).abs() < 0.001);
+        assert_eq!(status_damage_mult(None), 1.0);
+    }
+}
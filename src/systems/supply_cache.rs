@@ -0,0 +1,378 @@
+//! Supply Caches
+//!
+//! A rare drop that offers a choice instead of a pickup. Flying into a cache
+//! slows time and fans out three different powerups above the ship, each
+//! labelled; the one the player flies through is granted and the other two
+//! vanish. An offer left alone lapses after a few seconds of real time.
+
+#![allow(dead_code)]
+
+use crate::assets::PowerupIconCache;
+use crate::core::*;
+use crate::entities::{collectible_sprite, collectible_style, Player};
+use bevy::prelude::*;
+
+/// Chance a kill drops a cache on Newbro (scaled by difficulty)
+const CACHE_DROP_CHANCE: f32 = 0.02;
+
+/// Seconds between caches at the least
+const CACHE_COOLDOWN: f32 = 40.0;
+
+/// Cache drift speed (pixels per second, downward)
+const CACHE_DRIFT: f32 = 40.0;
+
+/// Game speed while an offer is up
+const OFFER_TIME_SCALE: f32 = 0.3;
+
+/// Real seconds an offer stays up
+const OFFER_DURATION: f32 = 6.0;
+
+/// Spacing between the offered powerups
+const CHOICE_SPACING: f32 = 110.0;
+
+/// How far above the ship the offer fans out
+const CHOICE_RISE: f32 = 120.0;
+
+/// Distance at which the ship opens a cache or takes a choice
+const PICKUP_RADIUS: f32 = 34.0;
+
+/// Powerups a cache can offer
+pub const CACHE_POOL: [CollectibleType; 7] = [
+    CollectibleType::Overdrive,
+    CollectibleType::DamageBoost,
+    CollectibleType::Invulnerability,
+    CollectibleType::Nanite,
+    CollectibleType::TractorBeam,
+    CollectibleType::Nosferatu,
+    CollectibleType::ExtraLife,
+];
+
+/// Supply cache plugin
+pub struct SupplyCachePlugin;
+
+impl Plugin for SupplyCachePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SupplyCacheState>()
+            .add_systems(
+                Update,
+                reset_supply_caches
+                    .in_set(GameSet::Input)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                drop_supply_caches
+                    .in_set(GameSet::Scoring)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                (
+                    move_supply_caches,
+                    open_supply_caches,
+                    take_cache_choice,
+                    run_cache_offer,
+                )
+                    .chain()
+                    .in_set(GameSet::Simulation)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(OnExit(GameState::Playing), restore_game_speed);
+    }
+}
+
+/// Cache drop timing and the open offer
+#[derive(Resource, Debug, Default)]
+pub struct SupplyCacheState {
+    /// Seconds since the last cache dropped
+    pub since_cache: f32,
+    /// Real seconds left on the open offer, if any
+    pub offer: Option<f32>,
+}
+
+impl SupplyCacheState {
+    /// Whether a kill may drop a cache
+    pub fn can_drop(&self, caches_out: bool) -> bool {
+        !caches_out && self.offer.is_none() && self.since_cache >= CACHE_COOLDOWN
+    }
+}
+
+/// A cache drifting down the playfield
+#[derive(Component, Debug)]
+#[require(StateScope)]
+pub struct SupplyCache;
+
+/// One of an open offer's powerups
+#[derive(Component, Debug, Clone, Copy)]
+#[require(StateScope)]
+pub struct CacheChoice(pub CollectibleType);
+
+/// Everything drawn for an open offer
+#[derive(Component, Debug)]
+#[require(StateScope)]
+pub struct CacheOffer;
+
+/// World-space label under an offered powerup
+pub fn choice_label(kind: CollectibleType) -> &'static str {
+    match kind {
+        CollectibleType::Overdrive => "OVERDRIVE",
+        CollectibleType::DamageBoost => "DAMAGE BOOST",
+        CollectibleType::Invulnerability => "INVULNERABLE",
+        CollectibleType::Nanite => "NANITES",
+        CollectibleType::TractorBeam => "TRACTOR BEAM",
+        CollectibleType::Nosferatu => "NOSFERATU",
+        CollectibleType::ExtraLife => "FULL REPAIR",
+        _ => "SUPPLIES",
+    }
+}
+
+/// Three different powerups from the pool
+pub fn roll_choices(rng: &mut fastrand::Rng) -> [CollectibleType; 3] {
+    let mut pool = CACHE_POOL;
+    rng.shuffle(&mut pool);
+    [pool[0], pool[1], pool[2]]
+}
+
+/// Where the three choices sit: side by side above the ship, kept inside
+/// the playfield
+pub fn choice_positions(player: Vec2, playfield: &Playfield) -> [Vec2; 3] {
+    let inset = Vec2::new(CHOICE_SPACING + PICKUP_RADIUS, PICKUP_RADIUS * 2.0);
+    let half = (playfield.half() - inset).max(Vec2::ZERO);
+    let center = (player + Vec2::Y * CHOICE_RISE).clamp(-half, half);
+    [-1.0, 0.0, 1.0].map(|i| center + Vec2::X * i * CHOICE_SPACING)
+}
+
+fn reset_supply_caches(
+    mut events: EventReader<MissionStartEvent>,
+    mut state: ResMut<SupplyCacheState>,
+) {
+    if events.read().last().is_some() {
+        *state = SupplyCacheState::default();
+    }
+}
+
+/// Now and then a kill leaves a cache behind
+fn drop_supply_caches(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut destroy_events: EventReader<EnemyDestroyedEvent>,
+    caches: Query<(), With<SupplyCache>>,
+    difficulty: Res<Difficulty>,
+    mut run_rng: ResMut<RunRng>,
+    mut state: ResMut<SupplyCacheState>,
+) {
+    state.since_cache += time.delta_secs();
+
+    for event in destroy_events.read() {
+        if event.was_boss || !state.can_drop(!caches.is_empty()) {
+            continue;
+        }
+        if run_rng.drops.f32() >= CACHE_DROP_CHANCE * difficulty.loot_drop_mult() {
+            continue;
+        }
+
+        state.since_cache = 0.0;
+        info!("Supply cache dropped");
+        commands
+            .spawn((
+                SupplyCache,
+                Sprite {
+                    color: Color::srgb(0.95, 0.75, 0.25),
+                    custom_size: Some(Vec2::splat(26.0)),
+                    ..default()
+                },
+                Transform::from_xyz(event.position.x, event.position.y, LAYER_EFFECTS),
+            ))
+            .with_children(|cache| {
+                cache.spawn((
+                    Text2d::new("SUPPLY CACHE"),
+                    TextFont {
+                        font_size: 11.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.95, 0.85, 0.5)),
+                    Transform::from_xyz(0.0, -24.0, 1.0),
+                ));
+            });
+    }
+}
+
+fn move_supply_caches(
+    mut commands: Commands,
+    time: Res<Time>,
+    playfield: Res<Playfield>,
+    mut caches: Query<(Entity, &mut Transform), With<SupplyCache>>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut transform) in caches.iter_mut() {
+        transform.translation.y -= CACHE_DRIFT * dt;
+        if !playfield.contains(transform.translation.truncate(), 40.0) {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+/// Flying into a cache slows time and lays out the offer
+fn open_supply_caches(
+    mut commands: Commands,
+    playfield: Res<Playfield>,
+    icon_cache: Res<PowerupIconCache>,
+    player_query: Query<&Transform, With<Player>>,
+    caches: Query<(Entity, &Transform), (With<SupplyCache>, Without<Player>)>,
+    mut run_rng: ResMut<RunRng>,
+    mut state: ResMut<SupplyCacheState>,
+) {
+    let Ok(player) = player_query.get_single() else {
+        return;
+    };
+    let player_pos = player.translation.truncate();
+
+    for (entity, transform) in caches.iter() {
+        if state.offer.is_some()
+            || transform.translation.truncate().distance(player_pos) > PICKUP_RADIUS
+        {
+            continue;
+        }
+        commands.entity(entity).despawn_recursive();
+
+        let choices = roll_choices(&mut run_rng.drops);
+        let positions = choice_positions(player_pos, &playfield);
+        info!("Supply cache opened: {:?}", choices);
+
+        for (kind, position) in choices.into_iter().zip(positions) {
+            let mut sprite = collectible_sprite(kind, Some(&icon_cache));
+            sprite.custom_size = sprite.custom_size.map(|size| size * 1.4);
+            commands
+                .spawn((
+                    CacheOffer,
+                    CacheChoice(kind),
+                    sprite,
+                    Transform::from_xyz(position.x, position.y, LAYER_EFFECTS + 1.0),
+                ))
+                .with_children(|choice| {
+                    choice.spawn((
+                        Text2d::new(choice_label(kind)),
+                        TextFont {
+                            font_size: 13.0,
+                            ..default()
+                        },
+                        TextColor(collectible_style(kind).0),
+                        Transform::from_xyz(0.0, -32.0, 1.0),
+                    ));
+                });
+        }
+        commands.spawn((
+            CacheOffer,
+            Text2d::new("SUPPLY CACHE - CHOOSE ONE"),
+            TextFont {
+                font_size: 15.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.95, 0.85, 0.5)),
+            Transform::from_xyz(positions[1].x, positions[1].y + 40.0, LAYER_EFFECTS + 1.0),
+        ));
+
+        state.offer = Some(OFFER_DURATION);
+    }
+}
+
+/// The choice the ship flies through is granted like a pickup; the rest go
+fn take_cache_choice(
+    player_query: Query<&Transform, With<Player>>,
+    choices: Query<(&Transform, &CacheChoice), Without<Player>>,
+    mut pickup_events: EventWriter<CollectiblePickedUpEvent>,
+    mut state: ResMut<SupplyCacheState>,
+) {
+    let Ok(player) = player_query.get_single() else {
+        return;
+    };
+    let player_pos = player.translation.truncate();
+
+    let taken = choices.iter().find(|(transform, _)| {
+        transform.translation.truncate().distance(player_pos) <= PICKUP_RADIUS
+    });
+    if let Some((transform, choice)) = taken {
+        info!("Supply cache: took {}", choice_label(choice.0));
+        pickup_events.send(CollectiblePickedUpEvent {
+            collectible_type: choice.0,
+            position: transform.translation.truncate(),
+            value: collectible_style(choice.0).2,
+        });
+        // Ends the offer below
+        state.offer = Some(0.0);
+    }
+}
+
+/// Keep time slowed while an offer is up; clear it when taken or lapsed
+fn run_cache_offer(
+    mut commands: Commands,
+    real_time: Res<Time<Real>>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    offer_ui: Query<Entity, With<CacheOffer>>,
+    mut state: ResMut<SupplyCacheState>,
+) {
+    let Some(left) = state.offer.as_mut() else {
+        return;
+    };
+
+    *left -= real_time.delta_secs();
+    if *left > 0.0 {
+        if virtual_time.relative_speed() != OFFER_TIME_SCALE {
+            virtual_time.set_relative_speed(OFFER_TIME_SCALE);
+        }
+        return;
+    }
+
+    for entity in offer_ui.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    state.offer = None;
+    virtual_time.set_relative_speed(1.0);
+}
+
+/// Pauses, menus and boss fights run at full speed; an open offer slows
+/// time again when play resumes
+fn restore_game_speed(mut virtual_time: ResMut<Time<Virtual>>) {
+    virtual_time.set_relative_speed(1.0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offers_are_three_different_powerups() {
+        let mut rng = fastrand::Rng::with_seed(3);
+        for _ in 0..20 {
+            let [a, b, c] = roll_choices(&mut rng);
+            assert!(a != b && b != c && a != c);
+            assert!([a, b, c].iter().all(|kind| CACHE_POOL.contains(kind)));
+        }
+    }
+
+    #[test]
+    fn choices_fan_out_above_the_ship_inside_the_playfield() {
+        let playfield = Playfield::default();
+        let [left, middle, right] = choice_positions(Vec2::ZERO, &playfield);
+        assert_eq!(middle, Vec2::new(0.0, CHOICE_RISE));
+        assert_eq!(right - middle, Vec2::X * CHOICE_SPACING);
+        assert_eq!(middle - left, Vec2::X * CHOICE_SPACING);
+
+        // Hugging the top corner still leaves every choice reachable
+        let corner = playfield.half();
+        for position in choice_positions(corner, &playfield) {
+            assert!(playfield.contains(position, -PICKUP_RADIUS));
+        }
+    }
+
+    #[test]
+    fn caches_wait_out_the_cooldown_and_any_open_offer() {
+        let mut state = SupplyCacheState::default();
+        assert!(!state.can_drop(false));
+        state.since_cache = CACHE_COOLDOWN;
+        assert!(state.can_drop(false));
+        assert!(!state.can_drop(true));
+        state.offer = Some(1.0);
+        assert!(!state.can_drop(false));
+    }
+}
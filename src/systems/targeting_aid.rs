@@ -0,0 +1,218 @@
+//! Targeting Aid
+//!
+//! Optional assistive overlay for boss fights. A faint reticle marks where
+//! a shot fired now would meet the moving boss, tied back to the hull by a
+//! thin line, and two rings around the player mark the weapon doctrine's
+//! optimal range and the edge of its falloff. Ring color says which band
+//! the boss sits in. Off by default; toggled from the options and pause
+//! menus.
+
+use crate::core::*;
+use crate::entities::{Boss, Player, Weapon};
+use bevy::prelude::*;
+
+/// How quickly the boss velocity estimate follows direction changes (1/s)
+const VELOCITY_SMOOTHING: f32 = 8.0;
+
+/// Lead reticle radius (pixels)
+const RETICLE_RADIUS: f32 = 12.0;
+
+/// Overlay opacity - faint enough to read past
+const AID_ALPHA: f32 = 0.35;
+
+/// Targeting aid plugin
+pub struct TargetingAidPlugin;
+
+impl Plugin for TargetingAidPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TargetingAidSettings>().add_systems(
+            Update,
+            draw_targeting_aid
+                .in_set(GameSet::Ui)
+                .run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight))),
+        );
+    }
+}
+
+/// Accessibility toggle for the lead reticle and range rings
+#[derive(Resource, Debug, Clone, Default)]
+pub struct TargetingAidSettings {
+    pub enabled: bool,
+}
+
+/// Which part of a doctrine's range a target sits in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeBand {
+    Optimal,
+    Falloff,
+    OutOfRange,
+}
+
+impl RangeBand {
+    pub fn of(distance: f32, doctrine: WeaponDoctrine) -> Self {
+        let optimal = doctrine.optimal_range();
+        if distance <= optimal {
+            RangeBand::Optimal
+        } else if distance <= optimal + doctrine.falloff() {
+            RangeBand::Falloff
+        } else {
+            RangeBand::OutOfRange
+        }
+    }
+
+    fn color(&self) -> Color {
+        match self {
+            RangeBand::Optimal => Color::srgba(0.4, 1.0, 0.5, AID_ALPHA),
+            RangeBand::Falloff => Color::srgba(1.0, 0.8, 0.3, AID_ALPHA),
+            RangeBand::OutOfRange => Color::srgba(0.6, 0.6, 0.65, AID_ALPHA * 0.6),
+        }
+    }
+}
+
+/// Where a shot fired from `shooter` at `speed` meets a target at `target`
+/// moving at `velocity`. None when the shot can never catch it.
+pub fn lead_point(shooter: Vec2, speed: f32, target: Vec2, velocity: Vec2) -> Option<Vec2> {
+    // Solve |offset + velocity * t| = speed * t for the earliest t > 0
+    let offset = target - shooter;
+    let a = velocity.length_squared() - speed * speed;
+    let b = 2.0 * offset.dot(velocity);
+    let c = offset.length_squared();
+
+    let t = if a.abs() < 1e-4 {
+        // Target as fast as the shot: only catchable while closing
+        if b >= 0.0 {
+            return None;
+        }
+        -c / b
+    } else {
+        let discriminant = b * b - 4.0 * a * c;
+        if discriminant < 0.0 {
+            return None;
+        }
+        let root = discriminant.sqrt();
+        [(-b - root) / (2.0 * a), (-b + root) / (2.0 * a)]
+            .into_iter()
+            .filter(|t| *t > 0.0)
+            .fold(f32::INFINITY, f32::min)
+    };
+
+    t.is_finite().then(|| target + velocity * t)
+}
+
+/// Draw the lead reticle and range rings while a boss is on the field
+fn draw_targeting_aid(
+    settings: Res<TargetingAidSettings>,
+    time: Res<Time>,
+    mut gizmos: Gizmos,
+    player_query: Query<(&Transform, &Weapon), With<Player>>,
+    boss_query: Query<(Entity, &Transform), With<Boss>>,
+    // Boss being tracked, its last position and smoothed velocity
+    mut tracked: Local<Option<(Entity, Vec2, Vec2)>>,
+) {
+    let Some((boss, boss_transform)) = boss_query.iter().next() else {
+        *tracked = None;
+        return;
+    };
+    let boss_pos = boss_transform.translation.truncate();
+
+    // Keep the estimate warm even while the aid is off
+    let dt = time.delta_secs();
+    let velocity = match *tracked {
+        Some((entity, last, velocity)) if entity == boss && dt > 0.0 => {
+            let sample = (boss_pos - last) / dt;
+            velocity.lerp(sample, (VELOCITY_SMOOTHING * dt).min(1.0))
+        }
+        Some((entity, _, velocity)) if entity == boss => velocity,
+        _ => Vec2::ZERO,
+    };
+    *tracked = Some((boss, boss_pos, velocity));
+
+    if !settings.enabled {
+        return;
+    }
+    let Ok((player_transform, weapon)) = player_query.get_single() else {
+        return;
+    };
+    let player_pos = player_transform.translation.truncate();
+    let doctrine = WeaponDoctrine::for_weapon(weapon.weapon_type);
+
+    // Range rings around the player, tinted by where the boss sits
+    let band = RangeBand::of(player_pos.distance(boss_pos), doctrine);
+    let ring = band.color();
+    let optimal = doctrine.optimal_range();
+    gizmos.circle_2d(player_pos, optimal, ring);
+    gizmos.circle_2d(
+        player_pos,
+        optimal + doctrine.falloff(),
+        ring.with_alpha(ring.alpha() * 0.5),
+    );
+
+    // Missiles steer themselves, so leading them would mislead
+    if doctrine == WeaponDoctrine::Missile {
+        return;
+    }
+    let Some(lead) = lead_point(player_pos, weapon.bullet_speed, boss_pos, velocity) else {
+        return;
+    };
+    let reticle = Color::srgba(1.0, 0.9, 0.5, AID_ALPHA);
+    gizmos.line_2d(boss_pos, lead, reticle.with_alpha(AID_ALPHA * 0.5));
+    gizmos.circle_2d(lead, RETICLE_RADIUS, reticle);
+    for dir in [Vec2::X, Vec2::NEG_X, Vec2::Y, Vec2::NEG_Y] {
+        gizmos.line_2d(
+            lead + dir * RETICLE_RADIUS * 0.5,
+            lead + dir * RETICLE_RADIUS * 1.5,
+            reticle,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lead_point_is_the_target_when_it_holds_still() {
+        let lead = lead_point(Vec2::ZERO, 900.0, Vec2::new(0.0, 300.0), Vec2::ZERO);
+        assert_eq!(lead, Some(Vec2::new(0.0, 300.0)));
+    }
+
+    #[test]
+    fn lead_point_meets_a_crossing_target() {
+        let shooter = Vec2::ZERO;
+        let target = Vec2::new(0.0, 400.0);
+        let velocity = Vec2::new(120.0, 0.0);
+        let speed = 900.0;
+        let lead = lead_point(shooter, speed, target, velocity).unwrap();
+        // Leads ahead of the target, and shot and target arrive together
+        assert!(lead.x > 0.0);
+        let t = (lead - target).length() / velocity.length();
+        assert!(((lead - shooter).length() / speed - t).abs() < 1e-3);
+    }
+
+    #[test]
+    fn lead_point_gives_up_on_a_target_outrunning_the_shot() {
+        let lead = lead_point(
+            Vec2::ZERO,
+            100.0,
+            Vec2::new(0.0, 300.0),
+            Vec2::new(0.0, 200.0),
+        );
+        assert_eq!(lead, None);
+    }
+
+    #[test]
+    fn range_bands_follow_doctrine_ranges() {
+        let laser = WeaponDoctrine::Laser;
+        assert_eq!(RangeBand::of(100.0, laser), RangeBand::Optimal);
+        assert_eq!(
+            RangeBand::of(laser.optimal_range() + 1.0, laser),
+            RangeBand::Falloff
+        );
+        assert_eq!(
+            RangeBand::of(laser.optimal_range() + laser.falloff() + 1.0, laser),
+            RangeBand::OutOfRange
+        );
+        // Blasters fight closer than beams
+        assert!(WeaponDoctrine::Hybrid.optimal_range() < laser.optimal_range());
+    }
+}
@@ -0,0 +1,275 @@
+//! Voice-Over
+//!
+//! Plays the clip a dialogue line carries and times the subtitle to it: once
+//! the clip has loaded and its length is known the line stays up for just
+//! that long, whatever the text speed. Lines without a clip, or whose clip
+//! fails to load, keep their text-speed timing.
+//!
+//! Clips come from a line's `DialogueEvent::voice` or, failing that, from the
+//! `VoicePack`, keyed by `voice_key`. No voice pack ships with the game; the
+//! pack is the hook a community pack loader fills in, and until one does
+//! every line plays silent, as before.
+
+#![allow(dead_code)]
+
+use super::dialogue::{CombatCalloutType, DialogueSystem, DialogueTrigger};
+use super::SoundSettings;
+use crate::core::*;
+use bevy::audio::{AudioSinkPlayback, Decodable, PlaybackMode, Source, Volume};
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Voice-over plugin
+pub struct VoiceOverPlugin;
+
+impl Plugin for VoiceOverPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<VoicePack>()
+            .init_resource::<VoiceOverState>()
+            .add_systems(OnEnter(GameState::Paused), pause_voice_clip)
+            .add_systems(OnExit(GameState::Paused), resume_voice_clip)
+            .add_systems(
+                Update,
+                (
+                    play_voice_lines,
+                    time_voice_lines,
+                    follow_voice_volume.run_if(resource_changed::<SoundSettings>),
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+/// Clips voicing dialogue lines (asset paths), by `voice_key`
+#[derive(Resource, Debug, Clone, Default)]
+pub struct VoicePack {
+    /// Name of the installed pack; `None` while there is none
+    pub name: Option<String>,
+    pub clips: HashMap<String, String>,
+}
+
+impl VoicePack {
+    /// The clip voicing a trigger's line, if the pack has one
+    pub fn clip_for(&self, trigger: &DialogueTrigger, is_cg: bool) -> Option<String> {
+        let key = voice_key(trigger, is_cg)?;
+        self.clips.get(&key).cloned()
+    }
+}
+
+/// The clip for the line on screen
+#[derive(Resource, Debug, Default)]
+pub struct VoiceOverState {
+    /// `DialogueSystem::line` the clip belongs to
+    pub line: u32,
+    /// Loading clip, until the line has been timed to it
+    pub clip: Option<Handle<AudioSource>>,
+    pub player: Option<Entity>,
+}
+
+/// A playing voice-over clip
+#[derive(Component)]
+#[require(StateScope)]
+pub struct VoiceOverClip;
+
+/// Stable name for a trigger's line, e.g. "elder/briefing_01" or
+/// "fleet_command/callout_low_health". Lines picked at random or written
+/// on the spot have none.
+pub fn voice_key(trigger: &DialogueTrigger, is_cg: bool) -> Option<String> {
+    let line = match trigger {
+        DialogueTrigger::StageBriefing(stage) => format!("briefing_{:02}", stage),
+        DialogueTrigger::BossIntro(name) => format!("boss_intro_{}", snake_case(name)),
+        DialogueTrigger::BossDefeated(name) => format!("boss_defeated_{}", snake_case(name)),
+        DialogueTrigger::ActComplete(act) => format!("act_complete_{}", act),
+        DialogueTrigger::LiberationMilestone(count) => format!("liberation_{}", count),
+        DialogueTrigger::CombatCallout(callout) => format!("callout_{}", callout_key(callout)),
+        DialogueTrigger::MissionSuccess
+        | DialogueTrigger::PerfectRun
+        | DialogueTrigger::PlayerDeath
+        | DialogueTrigger::Custom(_) => return None,
+    };
+    let narrator = if is_cg { "fleet_command" } else { "elder" };
+    Some(format!("{}/{}", narrator, line))
+}
+
+fn callout_key(callout: &CombatCalloutType) -> String {
+    snake_case(&format!("{:?}", callout))
+}
+
+/// "LowHealth" or "Lord Admiral Kor-Azor" as "low_health" or
+/// "lord_admiral_kor_azor"
+fn snake_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len() + 4);
+    let mut previous_lower = false;
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            if c.is_ascii_uppercase() && previous_lower {
+                out.push('_');
+            }
+            out.push(c.to_ascii_lowercase());
+            previous_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+        } else {
+            if !out.is_empty() && !out.ends_with('_') {
+                out.push('_');
+            }
+            previous_lower = false;
+        }
+    }
+    out.trim_end_matches('_').to_string()
+}
+
+/// Seconds a clip runs. Vorbis doesn't know its length up front, so those
+/// are counted out.
+pub fn clip_length(source: &AudioSource) -> Option<f32> {
+    let decoder = source.decoder();
+    if let Some(length) = decoder.total_duration() {
+        return Some(length.as_secs_f32());
+    }
+    let rate = decoder.sample_rate() as f32 * decoder.channels() as f32;
+    (rate > 0.0).then(|| decoder.count() as f32 / rate)
+}
+
+fn voice_volume(settings: &SoundSettings) -> f32 {
+    settings.voice_volume * settings.master_volume
+}
+
+/// Start the clip for each new line, cutting off the last one's
+fn play_voice_lines(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Res<SoundSettings>,
+    dialogue: Res<DialogueSystem>,
+    mut state: ResMut<VoiceOverState>,
+) {
+    let new_line = dialogue.line != state.line;
+    if !new_line && dialogue.is_active() {
+        return;
+    }
+    // Skipped, cleared or replaced: the clip goes with its line
+    if let Some(player) = state.player.take() {
+        if let Some(mut entity) = commands.get_entity(player) {
+            entity.despawn();
+        }
+    }
+    state.clip = None;
+    state.line = dialogue.line;
+
+    let Some(path) = dialogue.voice.as_ref().filter(|_| dialogue.is_active()) else {
+        return;
+    };
+    if !new_line || !settings.enabled {
+        return;
+    }
+    let clip: Handle<AudioSource> = asset_server.load(path.clone());
+    let player = commands
+        .spawn((
+            VoiceOverClip,
+            AudioPlayer(clip.clone()),
+            PlaybackSettings {
+                mode: PlaybackMode::Despawn,
+                volume: Volume::new(voice_volume(&settings)),
+                ..default()
+            },
+        ))
+        .id();
+    state.clip = Some(clip);
+    state.player = Some(player);
+}
+
+/// Once the clip has loaded, keep the line up for as long as it runs
+fn time_voice_lines(
+    asset_server: Res<AssetServer>,
+    sources: Res<Assets<AudioSource>>,
+    mut dialogue: ResMut<DialogueSystem>,
+    mut state: ResMut<VoiceOverState>,
+) {
+    let Some(clip) = state.clip.clone() else {
+        return;
+    };
+    if let Some(source) = sources.get(&clip) {
+        if let Some(length) = clip_length(source) {
+            dialogue.time_to_clip(length);
+        }
+        state.clip = None;
+    } else if asset_server.load_state(&clip).is_failed() {
+        // The line keeps its text-speed timing
+        warn!("Voice-over clip failed to load: {:?}", dialogue.voice);
+        state.clip = None;
+    }
+}
+
+/// Apply volume changes to a clip already playing
+fn follow_voice_volume(
+    settings: Res<SoundSettings>,
+    sinks: Query<&AudioSink, With<VoiceOverClip>>,
+) {
+    for sink in sinks.iter() {
+        sink.set_volume(voice_volume(&settings));
+    }
+}
+
+fn pause_voice_clip(sinks: Query<&AudioSink, With<VoiceOverClip>>) {
+    for sink in sinks.iter() {
+        sink.pause();
+    }
+}
+
+fn resume_voice_clip(sinks: Query<&AudioSink, With<VoiceOverClip>>) {
+    for sink in sinks.iter() {
+        sink.play();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn voice_keys_name_the_narrator_and_the_line() {
+        assert_eq!(
+            voice_key(&DialogueTrigger::StageBriefing(3), false).as_deref(),
+            Some("elder/briefing_03")
+        );
+        assert_eq!(
+            voice_key(
+                &DialogueTrigger::CombatCallout(CombatCalloutType::HullCritical),
+                true
+            )
+            .as_deref(),
+            Some("fleet_command/callout_hull_critical")
+        );
+        assert_eq!(
+            voice_key(
+                &DialogueTrigger::BossIntro("Lord Admiral Kor-Azor".into()),
+                false
+            )
+            .as_deref(),
+            Some("elder/boss_intro_lord_admiral_kor_azor")
+        );
+        // Random picks can't be matched to a recording
+        assert_eq!(voice_key(&DialogueTrigger::MissionSuccess, false), None);
+
+        let mut pack = VoicePack::default();
+        pack.clips.insert(
+            "elder/briefing_03".into(),
+            "vo/elder/briefing_03.ogg".into(),
+        );
+        assert_eq!(
+            pack.clip_for(&DialogueTrigger::StageBriefing(3), false)
+                .as_deref(),
+            Some("vo/elder/briefing_03.ogg")
+        );
+        assert_eq!(
+            pack.clip_for(&DialogueTrigger::StageBriefing(3), true),
+            None
+        );
+    }
+
+    #[test]
+    fn clip_length_reads_the_recording() {
+        let half_second = crate::systems::audio::create_audio_source(&[0.0; 22_050], 44_100)
+            .expect("wav encodes");
+        let length = clip_length(&half_second).expect("wav knows its length");
+        assert!((length - 0.5).abs() < 0.01, "{}", length);
+    }
+}
@@ -0,0 +1,284 @@
+//! Volatile Wrecks
+//!
+//! Destroyers and battlecruisers don't die cleanly: they leave a wreck
+//! that cooks off a second later. The blast hurts every enemy in range -
+//! and the player, if they're too close - so a well-placed kill in a tight
+//! formation can chain through it. Blast kills count as the player's and
+//! feed the berserk meter by the player's distance like any other kill.
+//!
+//! Blast damage to enemies follows their difficulty health scaling so
+//! chains stay viable; damage to the player follows enemy damage.
+
+#![allow(dead_code)]
+
+use super::effects::ScreenShake;
+use super::ManeuverState;
+use crate::core::*;
+use crate::entities::release_souls;
+use crate::entities::{Enemy, EnemyStats, Player, PowerupEffects, ShipStats};
+use bevy::prelude::*;
+use std::f32::consts::TAU;
+
+/// Seconds from the kill to the blast
+pub const WRECK_FUSE: f32 = 1.0;
+
+/// Share of the blast the player takes
+const PLAYER_BLAST_SHARE: f32 = 0.5;
+
+/// Blast damage at the edge of the radius, relative to the center
+const EDGE_FALLOFF: f32 = 0.5;
+
+/// Fuse glow color
+const FUSE_COLOR: Color = Color::srgb(1.0, 0.45, 0.1);
+
+/// Volatile wreck plugin
+pub struct VolatileWreckPlugin;
+
+impl Plugin for VolatileWreckPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (spawn_volatile_wrecks, burn_wreck_fuses, draw_wreck_fuses)
+                .chain()
+                .in_set(GameSet::Simulation)
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
+
+/// How hard a wreck goes up
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WreckBlast {
+    /// Damage at the center
+    pub damage: f32,
+    pub radius: f32,
+}
+
+/// The blast a ship class leaves behind, if it's volatile
+pub fn wreck_blast(class: ShipClass) -> Option<WreckBlast> {
+    match class {
+        ShipClass::Destroyer | ShipClass::TacticalDestroyer => Some(WreckBlast {
+            damage: 60.0,
+            radius: 120.0,
+        }),
+        ShipClass::Battlecruiser => Some(WreckBlast {
+            damage: 110.0,
+            radius: 170.0,
+        }),
+        _ => None,
+    }
+}
+
+/// Damage `distance` from the center: full through the inner half, then
+/// falling off toward the edge
+pub fn blast_damage(blast: WreckBlast, distance: f32) -> f32 {
+    if distance > blast.radius {
+        return 0.0;
+    }
+    let t = ((distance / blast.radius - 0.5) / 0.5).clamp(0.0, 1.0);
+    blast.damage * (1.0 - (1.0 - EDGE_FALLOFF) * t)
+}
+
+/// A wreck about to cook off
+#[derive(Component, Debug)]
+#[require(StateScope)]
+pub struct VolatileWreck {
+    pub blast: WreckBlast,
+    /// Seconds left on the fuse
+    pub fuse: f32,
+}
+
+/// Volatile kills leave a burning wreck
+fn spawn_volatile_wrecks(mut commands: Commands, mut events: EventReader<EnemyDestroyedEvent>) {
+    for event in events.read() {
+        if event.was_boss {
+            continue;
+        }
+        let Some(blast) = Faction::find_enemy_def(event.type_id).and_then(|d| wreck_blast(d.class))
+        else {
+            continue;
+        };
+        commands.spawn((
+            VolatileWreck {
+                blast,
+                fuse: WRECK_FUSE,
+            },
+            Sprite {
+                color: FUSE_COLOR,
+                custom_size: Some(Vec2::new(22.0, 14.0)),
+                ..default()
+            },
+            Transform::from_xyz(event.position.x, event.position.y, LAYER_ENEMIES - 0.5)
+                .with_rotation(Quat::from_rotation_z(fastrand::f32() * TAU)),
+        ));
+    }
+}
+
+/// Count fuses down and set off the ones that run out
+#[allow(clippy::too_many_arguments)]
+fn burn_wreck_fuses(
+    mut commands: Commands,
+    time: Res<Time>,
+    difficulty: Res<Difficulty>,
+    mut wrecks: Query<(Entity, &Transform, &mut VolatileWreck, &mut Sprite)>,
+    mut enemies: Query<(Entity, &Transform, &mut EnemyStats), With<Enemy>>,
+    mut player_query: Query<
+        (&Transform, &mut ShipStats, &PowerupEffects, &ManeuverState),
+        (With<Player>, Without<Enemy>, Without<VolatileWreck>),
+    >,
+    // Grouped to stay within Bevy's system parameter limit
+    (mut score, mut berserk, mut screen_shake, mut run_rng): (
+        ResMut<ScoreSystem>,
+        ResMut<BerserkSystem>,
+        ResMut<ScreenShake>,
+        ResMut<RunRng>,
+    ),
+    mut destroy_events: EventWriter<EnemyDestroyedEvent>,
+    mut explosion_events: EventWriter<ExplosionEvent>,
+    mut damage_events: EventWriter<PlayerDamagedEvent>,
+    mut destroyed_events: EventWriter<PlayerDestroyedEvent>,
+) {
+    let dt = time.delta_secs();
+
+    for (wreck_entity, transform, mut wreck, mut sprite) in wrecks.iter_mut() {
+        wreck.fuse -= dt;
+        if wreck.fuse > 0.0 {
+            // Blinks faster as it runs down
+            let rate = 4.0 + 16.0 * (1.0 - wreck.fuse / WRECK_FUSE);
+            let blink = 0.5 + 0.5 * (wreck.fuse * rate * TAU).sin();
+            sprite.color = FUSE_COLOR.mix(&Color::WHITE, blink * 0.6);
+            continue;
+        }
+
+        let pos = transform.translation.truncate();
+        commands.entity(wreck_entity).despawn();
+        explosion_events.send(ExplosionEvent {
+            position: pos,
+            size: ExplosionSize::Large,
+            color: FUSE_COLOR,
+        });
+        screen_shake.trigger(6.0, 0.2);
+
+        // Enemies caught in it
+        let enemy_mult = difficulty.enemy_health_mult();
+        for (entity, enemy_transform, mut stats) in enemies.iter_mut() {
+            if stats.health <= 0.0 || stats.is_boss {
+                continue;
+            }
+            let enemy_pos = enemy_transform.translation.truncate();
+            let damage = blast_damage(wreck.blast, enemy_pos.distance(pos)) * enemy_mult;
+            if damage <= 0.0 {
+                continue;
+            }
+            stats.health -= damage;
+            super::effects::spawn_damage_number(&mut commands, enemy_pos, damage, false);
+            if stats.health > 0.0 {
+                continue;
+            }
+
+            let final_score = (stats.score_value as f32 * berserk.score_mult()) as u64;
+            let points_awarded = score.on_kill(final_score);
+            if let Ok((player_transform, ..)) = player_query.get_single() {
+                berserk.on_kill_at_distance(
+                    player_transform.translation.truncate().distance(enemy_pos),
+                );
+            }
+            destroy_events.send(EnemyDestroyedEvent {
+                entity,
+                position: enemy_pos,
+                enemy_type: stats.name.clone(),
+                type_id: stats.type_id,
+                score_value: stats.score_value,
+                points_awarded,
+                was_boss: false,
+            });
+            explosion_events.send(ExplosionEvent {
+                position: enemy_pos,
+                size: ExplosionSize::Small,
+                color: FUSE_COLOR,
+            });
+            release_souls(
+                &mut commands,
+                &mut run_rng,
+                enemy_pos,
+                stats.type_id,
+                stats.liberation_value,
+            );
+            // Its own wreck picks up the chain
+            commands.entity(entity).despawn_recursive();
+        }
+
+        // The player, if they stayed close
+        let Ok((player_transform, mut stats, powerups, maneuver)) = player_query.get_single_mut()
+        else {
+            continue;
+        };
+        if powerups.is_invulnerable() || maneuver.invincible || stats.hull <= 0.0 {
+            continue;
+        }
+        let player_pos = player_transform.translation.truncate();
+        let damage = blast_damage(wreck.blast, player_pos.distance(pos))
+            * PLAYER_BLAST_SHARE
+            * difficulty.enemy_damage_mult();
+        if damage <= 0.0 {
+            continue;
+        }
+        let layers = stats.absorb_damage(damage, DamageType::Explosive);
+        score.no_damage_bonus = false;
+        damage_events.send(PlayerDamagedEvent {
+            damage,
+            damage_type: DamageType::Explosive,
+            source_position: pos,
+            source: "Wreck detonation".into(),
+            layers,
+        });
+        if stats.hull <= 0.0 {
+            info!("Player caught in a wreck blast!");
+            destroyed_events.send(PlayerDestroyedEvent {
+                position: player_pos,
+            });
+        }
+    }
+}
+
+/// Blast radius rings that close in as the fuse burns
+fn draw_wreck_fuses(mut gizmos: Gizmos, wrecks: Query<(&Transform, &VolatileWreck)>) {
+    for (transform, wreck) in wrecks.iter() {
+        let pos = transform.translation.truncate();
+        let burned = 1.0 - (wreck.fuse / WRECK_FUSE).clamp(0.0, 1.0);
+        gizmos.circle_2d(
+            pos,
+            wreck.blast.radius,
+            FUSE_COLOR.with_alpha(0.15 + 0.35 * burned),
+        );
+        gizmos.circle_2d(
+            pos,
+            wreck.blast.radius * (1.0 - burned).max(0.05),
+            FUSE_COLOR.with_alpha(0.6),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_destroyers_and_battlecruisers_are_volatile() {
+        assert!(wreck_blast(ShipClass::Frigate).is_none());
+        assert!(wreck_blast(ShipClass::Battleship).is_none());
+        let destroyer = wreck_blast(ShipClass::Destroyer).unwrap();
+        let battlecruiser = wreck_blast(ShipClass::Battlecruiser).unwrap();
+        assert!(battlecruiser.damage > destroyer.damage);
+        assert!(battlecruiser.radius > destroyer.radius);
+    }
+
+    #[test]
+    fn blast_is_full_near_the_middle_and_halves_at_the_edge() {
+        let blast = wreck_blast(ShipClass::Destroyer).unwrap();
+        assert_eq!(blast_damage(blast, 0.0), blast.damage);
+        assert_eq!(blast_damage(blast, blast.radius * 0.5), blast.damage);
+        assert!((blast_damage(blast, blast.radius) - blast.damage * EDGE_FALLOFF).abs() < 1e-4);
+        assert_eq!(blast_damage(blast, blast.radius + 1.0), 0.0);
+    }
+}
@@ -0,0 +1,214 @@
+//! Battle Log Panel
+//!
+//! In-flight combat feed from `BattleLog`, toggled with G: the latest hits,
+//! kills, module activations and boss phases down the left edge, coloured
+//! by kind. The mission complete screen adds a damage-taken breakdown by
+//! source.
+
+#![allow(dead_code)]
+
+use crate::core::*;
+use crate::ui::UiTheme;
+use bevy::prelude::*;
+
+/// Feed lines shown at once
+const PANEL_LINES: usize = 8;
+
+/// Sources listed in the post-mission breakdown
+const BREAKDOWN_ROWS: usize = 4;
+
+/// Battle log panel plugin
+pub struct BattleLogPanelPlugin;
+
+impl Plugin for BattleLogPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BattleLogPanel>().add_systems(
+            Update,
+            (
+                toggle_battle_log,
+                refresh_battle_log_panel.run_if(
+                    resource_changed::<BattleLog>
+                        .or(resource_changed::<BattleLogPanel>)
+                        .or(state_changed::<GameState>),
+                ),
+            )
+                .chain()
+                .in_set(GameSet::Ui)
+                .run_if(in_state(GameState::Playing).or(in_state(GameState::BossFight))),
+        );
+    }
+}
+
+/// Whether the in-flight feed is shown
+#[derive(Resource, Debug, Default)]
+pub struct BattleLogPanel {
+    pub visible: bool,
+}
+
+/// Battle log panel root
+#[derive(Component)]
+pub struct BattleLogRoot;
+
+/// Feed colour for an entry kind
+pub fn entry_color(kind: BattleEntryKind) -> Color {
+    match kind {
+        BattleEntryKind::Hit => Color::srgb(1.0, 0.45, 0.35),
+        BattleEntryKind::Kill => Color::srgb(0.45, 0.95, 0.5),
+        BattleEntryKind::Ability => Color::srgb(0.45, 0.8, 1.0),
+        BattleEntryKind::Boss => Color::srgb(1.0, 0.6, 0.95),
+    }
+}
+
+fn toggle_battle_log(keyboard: Res<ButtonInput<KeyCode>>, mut panel: ResMut<BattleLogPanel>) {
+    if keyboard.just_pressed(KeyCode::KeyG) {
+        panel.visible = !panel.visible;
+    }
+}
+
+/// Rebuild the feed from the latest entries
+fn refresh_battle_log_panel(
+    mut commands: Commands,
+    panel: Res<BattleLogPanel>,
+    log: Res<BattleLog>,
+    theme: Res<UiTheme>,
+    existing: Query<Entity, With<BattleLogRoot>>,
+) {
+    for entity in existing.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    if !panel.visible {
+        return;
+    }
+
+    let mut latest: Vec<&BattleEntry> = log.entries().rev().take(PANEL_LINES).collect();
+    latest.reverse();
+
+    commands
+        .spawn((
+            BattleLogRoot,
+            StateScope::Gameplay,
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(12.0),
+                top: Val::Percent(35.0),
+                width: Val::Px(300.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(8.0)),
+                border: UiRect::all(Val::Px(1.0)),
+                row_gap: Val::Px(2.0),
+                ..default()
+            },
+            BorderColor(theme.frame()),
+            BackgroundColor(theme.panel_background().with_alpha(0.75)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("[G] BATTLE LOG"),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(theme.accent),
+            ));
+            if latest.is_empty() {
+                parent.spawn((
+                    Text::new("No combat yet."),
+                    TextFont {
+                        font_size: 11.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.45, 0.45, 0.5)),
+                ));
+            }
+            for entry in latest {
+                parent.spawn((
+                    Text::new(entry.message.clone()),
+                    TextFont {
+                        font_size: 11.0,
+                        ..default()
+                    },
+                    TextColor(entry_color(entry.kind)),
+                ));
+            }
+        });
+}
+
+/// Breakdown row for one source
+pub fn breakdown_line(tally: &DamageTally) -> String {
+    format!(
+        "{}: {:.0} in {} hit{}",
+        tally.source,
+        tally.total(),
+        tally.hits,
+        if tally.hits == 1 { "" } else { "s" }
+    )
+}
+
+/// Damage taken this mission by source, for the mission complete screen
+pub fn spawn_damage_breakdown(parent: &mut ChildBuilder, log: &BattleLog) {
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(2.0),
+            ..default()
+        })
+        .with_children(|section| {
+            let breakdown = log.breakdown();
+            let header = if breakdown.is_empty() {
+                "DAMAGE TAKEN: NONE".to_string()
+            } else {
+                format!("DAMAGE TAKEN: {:.0}", log.damage_taken())
+            };
+            section.spawn((
+                Text::new(header),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.55, 0.45)),
+            ));
+            for tally in breakdown.iter().take(BREAKDOWN_ROWS) {
+                section.spawn((
+                    Text::new(breakdown_line(tally)),
+                    TextFont {
+                        font_size: 13.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.75, 0.75, 0.8)),
+                ));
+            }
+            let others = breakdown.len().saturating_sub(BREAKDOWN_ROWS);
+            if others > 0 {
+                section.spawn((
+                    Text::new(format!("+{} more sources", others)),
+                    TextFont {
+                        font_size: 11.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.5, 0.5, 0.55)),
+                ));
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn breakdown_lines_count_hits() {
+        let tally = DamageTally {
+            source: "Punisher".to_string(),
+            hits: 1,
+            layers: DamageLayers {
+                shield: 12.4,
+                armor: 0.0,
+                hull: 0.0,
+            },
+        };
+        assert_eq!(breakdown_line(&tally), "Punisher: 12 in 1 hit");
+        let tally = DamageTally { hits: 3, ..tally };
+        assert_eq!(breakdown_line(&tally), "Punisher: 12 in 3 hits");
+    }
+}
@@ -2,34 +2,137 @@
 //!
 //! EVE Online-inspired HUD display:
 //! - Three concentric semicircular health arcs (Shield/Armor/Structure)
-//! - Central HEAT gauge with radial spoke pattern (fills as heat builds)
+//! - Segmented HEAT arc along the bottom semicircle
+//! - Central capacitor with radial spoke cells (drain as capacitor is spent)
+//! - Module slots around the lower rim with cooldown sweeps
 //! - Speed display at bottom center
 //! - Percentage readouts on left
 //! - Heat status indicators
+//!
+//! The classic HUD, and the compact layout, swap the wheel for plain bars
+//! (see `HudStyle` and `HudMetrics`).
 
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
-use std::f32::consts::PI;
+use serde::{Deserialize, Serialize};
+use std::f32::consts::{PI, TAU};
 
 use crate::core::*;
 use crate::entities::{Movement, Player, ShipStats};
-use crate::systems::ComboHeatSystem;
+use crate::systems::{Ability, AbilityType, ComboHeatSystem, ManeuverConfig, ManeuverState};
 
 /// Capacitor wheel plugin
 pub struct CapacitorWheelPlugin;
 
 impl Plugin for CapacitorWheelPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<CapacitorAnimation>().add_systems(
-            Update,
-            (update_capacitor_animation, draw_capacitor_wheel)
-                .chain()
-                .run_if(in_state(GameState::Playing))
-                .after(bevy_egui::EguiSet::ProcessInput),
-        );
+        app.init_resource::<CapacitorAnimation>()
+            .init_resource::<HudStyle>()
+            .add_systems(
+                Update,
+                (update_capacitor_animation, draw_capacitor_wheel)
+                    .chain()
+                    .run_if(in_state(GameState::Playing))
+                    .run_if(super::wheel_shown)
+                    .after(bevy_egui::EguiSet::ProcessInput),
+            );
+    }
+}
+
+/// Ship status layout at the bottom of the HUD
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HudStyle {
+    /// EVE capacitor wheel with module slots
+    #[default]
+    Wheel,
+    /// Flat shield/armor/hull/capacitor bars
+    Classic,
+}
+
+impl HudStyle {
+    pub fn name(&self) -> &'static str {
+        match self {
+            HudStyle::Wheel => "WHEEL",
+            HudStyle::Classic => "CLASSIC",
+        }
+    }
+
+    pub fn toggled(&self) -> Self {
+        match self {
+            HudStyle::Wheel => HudStyle::Classic,
+            HudStyle::Classic => HudStyle::Wheel,
+        }
     }
 }
 
+/// One activatable module shown around the wheel
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModuleSlot {
+    /// Short label drawn in the slot
+    pub label: &'static str,
+    /// Cooldown or charge progress (0.0 = just used, 1.0 = ready)
+    pub ready: f32,
+    /// Effect currently running
+    pub active: bool,
+    /// Disabled (burnt out after an overload)
+    pub offline: bool,
+}
+
+/// Modules fitted to the player's ship, left to right around the rim
+pub fn module_slots(
+    ability: Option<&Ability>,
+    maneuver: Option<&ManeuverState>,
+    heat: &ComboHeatSystem,
+) -> Vec<ModuleSlot> {
+    let mut slots = Vec::with_capacity(4);
+    let burned_out = heat.is_burned_out();
+
+    if let Some(ability) = ability.filter(|a| a.ability_type != AbilityType::None) {
+        slots.push(ModuleSlot {
+            label: ability.ability_type.short_name(),
+            ready: if burned_out {
+                1.0 - heat.burnout_percent()
+            } else {
+                ability.cooldown_progress()
+            },
+            active: ability.is_active,
+            offline: burned_out,
+        });
+    }
+
+    // Overload charges with heat and sweeps down while surging
+    slots.push(ModuleSlot {
+        label: "OVL",
+        ready: if heat.is_overloaded() {
+            heat.overload_percent()
+        } else if burned_out {
+            1.0 - heat.burnout_percent()
+        } else {
+            (heat.heat / 100.0).clamp(0.0, 1.0)
+        },
+        active: heat.is_overloaded(),
+        offline: burned_out,
+    });
+
+    if let Some(maneuver) = maneuver {
+        slots.push(ModuleSlot {
+            label: "MWD",
+            ready: 1.0
+                - (maneuver.thrust_cooldown / ManeuverConfig::THRUST_COOLDOWN).clamp(0.0, 1.0),
+            active: maneuver.thrust_active,
+            offline: false,
+        });
+        slots.push(ModuleSlot {
+            label: "ROLL",
+            ready: maneuver.roll_ready(),
+            active: maneuver.barrel_roll_active,
+            offline: false,
+        });
+    }
+
+    slots
+}
+
 /// Animation state for capacitor effects
 #[derive(Resource)]
 pub struct CapacitorAnimation {
@@ -72,12 +175,20 @@ fn update_capacitor_animation(time: Res<Time>, mut anim: ResMut<CapacitorAnimati
 /// Draw EVE-style capacitor wheel using egui
 fn draw_capacitor_wheel(
     mut egui_ctx: EguiContexts,
-    player_query: Query<(&ShipStats, Option<&Movement>), With<Player>>,
+    player_query: Query<
+        (
+            &ShipStats,
+            Option<&Movement>,
+            Option<&Ability>,
+            Option<&ManeuverState>,
+        ),
+        With<Player>,
+    >,
     heat_system: Res<ComboHeatSystem>,
     anim: Res<CapacitorAnimation>,
     windows: Query<&Window>,
 ) {
-    let Ok((stats, movement)) = player_query.get_single() else {
+    let Ok((stats, movement, ability, maneuver)) = player_query.get_single() else {
         return;
     };
 
@@ -104,6 +215,8 @@ fn draw_capacitor_wheel(
     // Get speed
     let speed = movement.map(|m| m.velocity.length()).unwrap_or(0.0);
 
+    let slots = module_slots(ability, maneuver, &heat_system);
+
     // Draw using egui Area
     egui::Area::new(egui::Id::new("capacitor_wheel"))
         .fixed_pos(egui::pos2(
@@ -185,6 +298,20 @@ fn draw_capacitor_wheel(
                 16,
             );
 
+            // === HEAT ARC (bottom semicircle, mirrors the health arcs) ===
+            draw_eve_health_arc(
+                &painter,
+                center,
+                shield_radius,
+                arc_width,
+                heat_pct,
+                0.0,
+                PI,
+                heat_color(heat_pct, anim.pulse),
+                egui::Color32::from_rgb(30, 35, 45),
+                24,
+            );
+
             // === CAPACITOR RINGS (concentric circles of dashes) ===
             let cap_inner_radius = 18.0;
             let cap_outer_radius = structure_radius - arc_width - 5.0;
@@ -292,6 +419,9 @@ fn draw_capacitor_wheel(
                 }
             }
 
+            // === MODULE SLOTS (lower rim, cooldown sweeps) ===
+            draw_module_slots(&painter, center, wheel_radius + 22.0, &slots, anim.pulse);
+
             // === SPEED DISPLAY (center) ===
             painter.text(
                 egui::pos2(center.x, center.y + 2.0),
@@ -478,28 +608,9 @@ fn draw_arc_segment(
     }
 }
 
-/// Draw HEAT display as radial spoke gauges (EVE Online style)
-/// Empty = cool, Filled = hot
-/// Color shifts: teal (cool) → golden (warm) → red (overheating)
-fn draw_capacitor_rings(
-    painter: &egui::Painter,
-    center: egui::Pos2,
-    inner_radius: f32,
-    outer_radius: f32,
-    _cap_pct: f32, // Unused - kept for API compatibility
-    heat_pct: f32,
-    pulse: f32,
-) {
-    // Radial spoke pattern - rectangular gauges arranged like wheel spokes
-    let num_layers = 3;
-    let gauges_per_layer = 16;
-    let total_gauges = num_layers * gauges_per_layer;
-
-    // Heat fills from inside-out (heat builds up from core)
-    let filled_gauges = (heat_pct * total_gauges as f32).round() as u32;
-
-    // Heat color gradient: teal → golden → orange → red
-    let heat_color = if heat_pct < 0.3 {
+/// Heat color gradient: teal (cool) → golden (warm) → orange → red (overheating)
+fn heat_color(heat_pct: f32, pulse: f32) -> egui::Color32 {
+    if heat_pct < 0.3 {
         // Cool - teal/cyan
         let t = heat_pct / 0.3;
         egui::Color32::from_rgb(
@@ -531,9 +642,38 @@ fn draw_capacitor_rings(
             (60.0 * critical_pulse) as u8,
             (30.0 * critical_pulse) as u8,
         )
+    }
+}
+
+/// Draw the capacitor as radial spoke cells (EVE Online style)
+/// Cells drain from the outside in as capacitor is spent, like EVE;
+/// the core glows with heat color when running hot
+fn draw_capacitor_rings(
+    painter: &egui::Painter,
+    center: egui::Pos2,
+    inner_radius: f32,
+    outer_radius: f32,
+    cap_pct: f32,
+    heat_pct: f32,
+    pulse: f32,
+) {
+    // Radial spoke pattern - rectangular gauges arranged like wheel spokes
+    let num_layers = 3;
+    let gauges_per_layer = 16;
+    let total_gauges = num_layers * gauges_per_layer;
+
+    // Capacitor fills from the core outward
+    let filled_gauges = (cap_pct * total_gauges as f32).round() as u32;
+
+    // Capacitor gold, flashing red when nearly dry
+    let cap_color = if cap_pct < 0.25 {
+        let low_pulse = 0.7 + 0.3 * ((pulse - 0.9) * 5.0).sin().abs();
+        egui::Color32::from_rgb(255, (110.0 * low_pulse) as u8, (60.0 * low_pulse) as u8)
+    } else {
+        egui::Color32::from_rgb(240, 215, 150)
     };
 
-    let empty_color = egui::Color32::from_rgb(20, 35, 45); // Dark teal-gray (cool look)
+    let empty_color = egui::Color32::from_rgb(20, 35, 45); // Dark teal-gray
     let border_color = egui::Color32::from_rgb(40, 60, 75);
 
     // Layer spacing
@@ -541,10 +681,8 @@ fn draw_capacitor_rings(
     let gauge_gap = 2.0;
     let gauge_height = layer_height - gauge_gap;
 
-    // Draw gauges - fill from inside-out (heat builds from core)
     let mut gauge_index = 0;
 
-    // Inner layers first (heat builds from inside out)
     for layer in 0..num_layers {
         let layer_inner = inner_radius + 2.0 + layer as f32 * layer_height;
         let layer_outer = layer_inner + gauge_height;
@@ -554,21 +692,16 @@ fn draw_capacitor_rings(
             let angle = -PI / 2.0 + (i as f32 / gauges_per_layer as f32) * PI * 2.0;
             let gauge_arc = (PI * 2.0 / gauges_per_layer as f32) * 0.75;
 
-            // Fill from inside-out
             let is_filled = gauge_index < filled_gauges;
             gauge_index += 1;
 
             let fill_color = if is_filled {
-                // Apply subtle pulse to filled gauges
-                let pulse_factor = if heat_pct > 0.85 {
-                    pulse
-                } else {
-                    0.95 + 0.05 * pulse
-                };
+                // Subtle pulse on charged cells
+                let pulse_factor = 0.95 + 0.05 * pulse;
                 egui::Color32::from_rgb(
-                    (heat_color.r() as f32 * pulse_factor).min(255.0) as u8,
-                    (heat_color.g() as f32 * pulse_factor).min(255.0) as u8,
-                    (heat_color.b() as f32 * pulse_factor).min(255.0) as u8,
+                    (cap_color.r() as f32 * pulse_factor).min(255.0) as u8,
+                    (cap_color.g() as f32 * pulse_factor).min(255.0) as u8,
+                    (cap_color.b() as f32 * pulse_factor).min(255.0) as u8,
                 )
             } else {
                 empty_color
@@ -589,6 +722,7 @@ fn draw_capacitor_rings(
 
     // Center glow when heat is high (warning)
     if heat_pct > 0.5 {
+        let heat_color = heat_color(heat_pct, pulse);
         let glow_intensity = (heat_pct - 0.5) * 2.0; // 0.0 at 50%, 1.0 at 100%
         let glow_alpha = (glow_intensity * 0.5 * 255.0 * pulse) as u8;
         let glow_color = egui::Color32::from_rgba_unmultiplied(
@@ -601,6 +735,94 @@ fn draw_capacitor_rings(
     }
 }
 
+/// Draw module slots spread over the lower rim, each with an EVE-style
+/// cooldown sweep darkening the part of the dial still recharging
+fn draw_module_slots(
+    painter: &egui::Painter,
+    center: egui::Pos2,
+    rim_radius: f32,
+    slots: &[ModuleSlot],
+    pulse: f32,
+) {
+    let slot_radius = 12.0;
+    // Lower-left to lower-right, clear of the speed controls at the bottom
+    let angles = [PI * 0.88, PI * 0.7, PI * 0.3, PI * 0.12];
+    let first = (angles.len() - slots.len().min(angles.len())) / 2;
+
+    for (slot, &angle) in slots.iter().zip(&angles[first..]) {
+        let pos = egui::pos2(
+            center.x + rim_radius * angle.cos(),
+            center.y + rim_radius * angle.sin(),
+        );
+
+        let (fill, ring) = if slot.offline {
+            (
+                egui::Color32::from_rgb(60, 20, 18),
+                egui::Color32::from_rgb(200, 60, 40),
+            )
+        } else if slot.active {
+            let glow = (200.0 + 55.0 * (pulse - 0.9) * 5.0).clamp(0.0, 255.0) as u8;
+            (
+                egui::Color32::from_rgb(40, 70, 90),
+                egui::Color32::from_rgb(glow, 245, 255),
+            )
+        } else if slot.ready >= 1.0 {
+            (
+                egui::Color32::from_rgb(25, 45, 60),
+                egui::Color32::from_rgb(80, 200, 230),
+            )
+        } else {
+            (
+                egui::Color32::from_rgb(22, 28, 36),
+                egui::Color32::from_rgb(55, 65, 80),
+            )
+        };
+
+        painter.circle_filled(pos, slot_radius, fill);
+
+        // Cooldown sweep: dark wedge over the part still recharging
+        if slot.ready < 1.0 {
+            let start = -PI / 2.0 + slot.ready.clamp(0.0, 1.0) * TAU;
+            let end = -PI / 2.0 + TAU;
+            let steps = ((end - start) / TAU * 32.0).ceil().max(1.0) as u32;
+            let step = (end - start) / steps as f32;
+            let sweep_color = egui::Color32::from_rgba_unmultiplied(0, 0, 0, 150);
+            for i in 0..steps {
+                let a0 = start + step * i as f32;
+                let a1 = a0 + step;
+                painter.add(egui::Shape::convex_polygon(
+                    vec![
+                        pos,
+                        egui::pos2(
+                            pos.x + slot_radius * a0.cos(),
+                            pos.y + slot_radius * a0.sin(),
+                        ),
+                        egui::pos2(
+                            pos.x + slot_radius * a1.cos(),
+                            pos.y + slot_radius * a1.sin(),
+                        ),
+                    ],
+                    sweep_color,
+                    egui::Stroke::NONE,
+                ));
+            }
+        }
+
+        painter.circle_stroke(pos, slot_radius, egui::Stroke::new(1.5, ring));
+        painter.text(
+            pos,
+            egui::Align2::CENTER_CENTER,
+            slot.label,
+            egui::FontId::monospace(7.0),
+            if slot.offline {
+                egui::Color32::from_rgb(230, 110, 90)
+            } else {
+                egui::Color32::from_rgb(190, 205, 220)
+            },
+        );
+    }
+}
+
 /// Draw a single radial gauge (rectangular cell pointing outward)
 fn draw_radial_gauge(
     painter: &egui::Painter,
@@ -694,3 +916,38 @@ fn draw_heat_indicators(
         painter.rect_filled(rect, 1.0, color);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn module_slots_follow_cooldowns_and_burnout() {
+        let mut ability = Ability::new(AbilityType::ShieldBoost);
+        let maneuver = ManeuverState::default();
+        let mut heat = ComboHeatSystem::default();
+
+        let slots = module_slots(Some(&ability), Some(&maneuver), &heat);
+        let labels: Vec<_> = slots.iter().map(|s| s.label).collect();
+        assert_eq!(labels, ["SB", "OVL", "MWD", "ROLL"]);
+        assert_eq!(slots[0].ready, 1.0);
+        assert_eq!(slots[1].ready, 0.0);
+
+        // Just fired: active with the sweep at the start of its cooldown
+        ability.activate();
+        let slots = module_slots(Some(&ability), None, &heat);
+        assert_eq!(slots.len(), 2);
+        assert!(slots[0].active);
+        assert_eq!(slots[0].ready, 0.0);
+
+        heat.heat = 100.0;
+        assert!(heat.try_overload());
+        let slots = module_slots(None, None, &heat);
+        assert!(slots[0].active && !slots[0].offline);
+
+        heat.overload_timer = 0.0;
+        heat.burnout_timer = 1.0;
+        let slots = module_slots(Some(&ability), None, &heat);
+        assert!(slots.iter().all(|s| s.offline));
+    }
+}
@@ -0,0 +1,413 @@
+//! Codex Screen
+//!
+//! A full-screen journal opened from the main menu or the pause menu. Tabs
+//! for factions, ships, bosses and the campaign; entries the player hasn't
+//! met yet are listed as unknown. Tabs and entries can be picked with the
+//! mouse too, and the wheel steps through the list. While it's open the
+//! menu underneath ignores input.
+
+#![allow(dead_code)]
+
+use crate::core::*;
+use crate::systems::{CodexCatalog, JoystickState};
+use crate::ui::menu::{get_nav_input, MENU_NAV_COOLDOWN};
+use crate::ui::{MenuPointer, UiTheme};
+use bevy::prelude::*;
+
+/// Entries listed at once; the list scrolls with the selection
+const VISIBLE_ENTRIES: usize = 12;
+
+/// Codex screen plugin
+pub struct CodexScreenPlugin;
+
+impl Plugin for CodexScreenPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                close_codex_on_state_change.run_if(state_changed::<GameState>),
+                codex_input.run_if(resource_exists::<CodexView>),
+                refresh_codex.run_if(resource_exists_and_changed::<CodexView>),
+                despawn_closed_codex.run_if(resource_removed::<CodexView>),
+            )
+                .chain(),
+        );
+    }
+}
+
+/// The open codex and what's selected in it; absent while closed
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CodexView {
+    /// Index into `CodexCategory::ALL`
+    pub tab: usize,
+    /// Entry within the tab
+    pub index: usize,
+    /// First entry listed; only moves once the selection leaves the list,
+    /// so rows stay put under the pointer
+    pub first: usize,
+}
+
+impl CodexView {
+    pub fn category(&self) -> CodexCategory {
+        CodexCategory::ALL[self.tab]
+    }
+
+    /// Whether the selection is among the listed entries
+    pub fn selection_listed(&self) -> bool {
+        (self.first..self.first + VISIBLE_ENTRIES).contains(&self.index)
+    }
+}
+
+/// Codex screen root
+#[derive(Component)]
+pub struct CodexRoot;
+
+/// A tab along the top (index into `CodexCategory::ALL`)
+#[derive(Component)]
+#[require(Interaction)]
+pub struct CodexTab(pub usize);
+
+/// An entry row in the list (index within the tab)
+#[derive(Component)]
+#[require(Interaction)]
+pub struct CodexRow(pub usize);
+
+/// First entry shown in a list of `len` so `selected` stays in view
+pub fn list_window_start(selected: usize, len: usize) -> usize {
+    let max_start = len.saturating_sub(VISIBLE_ENTRIES);
+    selected.saturating_sub(VISIBLE_ENTRIES / 2).min(max_start)
+}
+
+fn close_codex_on_state_change(mut commands: Commands) {
+    commands.remove_resource::<CodexView>();
+}
+
+fn codex_input(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    joystick: Res<JoystickState>,
+    pointer: Res<MenuPointer>,
+    catalog: Res<CodexCatalog>,
+    mut view: ResMut<CodexView>,
+    time: Res<Time>,
+    mut cooldown: Local<f32>,
+    tabs: Query<(&Interaction, &CodexTab), Changed<Interaction>>,
+    rows: Query<(&Interaction, &CodexRow), Changed<Interaction>>,
+) {
+    *cooldown -= time.delta_secs();
+
+    // Click a tab; hover or click an entry
+    for (interaction, tab) in tabs.iter() {
+        if *interaction == Interaction::Pressed && view.tab != tab.0 {
+            view.tab = tab.0;
+            view.index = 0;
+        }
+    }
+    for (interaction, row) in rows.iter() {
+        let pointed = *interaction == Interaction::Pressed
+            || (*interaction == Interaction::Hovered && pointer.active);
+        if pointed && view.index != row.0 {
+            view.index = row.0;
+        }
+    }
+
+    if keyboard.just_pressed(KeyCode::Escape) || joystick.back() {
+        commands.remove_resource::<CodexView>();
+        return;
+    }
+
+    let tab_nav = if keyboard.just_pressed(KeyCode::ArrowLeft)
+        || keyboard.just_pressed(KeyCode::KeyA)
+        || joystick.dpad_just_left()
+    {
+        -1
+    } else if keyboard.just_pressed(KeyCode::ArrowRight)
+        || keyboard.just_pressed(KeyCode::KeyD)
+        || joystick.dpad_just_right()
+    {
+        1
+    } else {
+        0
+    };
+    if tab_nav != 0 {
+        let tabs = CodexCategory::ALL.len() as i32;
+        view.tab = (view.tab as i32 + tab_nav).rem_euclid(tabs) as usize;
+        view.index = 0;
+    }
+
+    let wheel = if pointer.scroll > 0.0 {
+        1
+    } else if pointer.scroll < 0.0 {
+        -1
+    } else {
+        0
+    };
+    let nav = match get_nav_input(&keyboard, &joystick) {
+        0 => wheel,
+        nav => nav,
+    };
+    if nav != 0 && *cooldown <= 0.0 {
+        let category = view.category();
+        let len = catalog.0.iter().filter(|e| e.category == category).count();
+        if len > 0 {
+            view.index = (view.index as i32 + nav).rem_euclid(len as i32) as usize;
+        }
+        *cooldown = MENU_NAV_COOLDOWN;
+    }
+
+    // Re-centre the list only when the selection has left it
+    if !view.selection_listed() {
+        let category = view.category();
+        let len = catalog.0.iter().filter(|e| e.category == category).count();
+        view.first = list_window_start(view.index, len);
+    }
+}
+
+fn despawn_closed_codex(mut commands: Commands, query: Query<Entity, With<CodexRoot>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Rebuild the screen for the current tab and selection
+fn refresh_codex(
+    mut commands: Commands,
+    view: Res<CodexView>,
+    catalog: Res<CodexCatalog>,
+    save_data: Res<SaveData>,
+    theme: Res<UiTheme>,
+    existing: Query<Entity, With<CodexRoot>>,
+) {
+    for entity in existing.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    let log = &save_data.codex;
+    let category = view.category();
+    let entries: Vec<_> = catalog
+        .0
+        .iter()
+        .filter(|e| e.category == category)
+        .collect();
+    let selected = entries.get(view.index).copied();
+    let start = view.first;
+
+    commands
+        .spawn((
+            CodexRoot,
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(30.0)),
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(theme.panel_background().with_alpha(0.97)),
+            ZIndex(500), // Above menus, below toasts
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("CODEX"),
+                TextFont {
+                    font_size: 40.0,
+                    ..default()
+                },
+                TextColor(theme.primary),
+            ));
+
+            // Tabs with discovery progress
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(10.0),
+                    ..default()
+                })
+                .with_children(|tabs| {
+                    for (i, tab) in CodexCategory::ALL.iter().enumerate() {
+                        let (found, total) = log.progress(&catalog.0, *tab);
+                        let active = i == view.tab;
+                        tabs.spawn((
+                            CodexTab(i),
+                            Node {
+                                padding: UiRect::axes(Val::Px(14.0), Val::Px(6.0)),
+                                border: UiRect::all(Val::Px(2.0)),
+                                ..default()
+                            },
+                            BackgroundColor(if active {
+                                theme.selected_background()
+                            } else {
+                                Color::srgba(0.1, 0.1, 0.1, 0.8)
+                            }),
+                            BorderColor(if active {
+                                theme.primary
+                            } else {
+                                theme.frame().with_alpha(0.4)
+                            }),
+                        ))
+                        .with_children(|t| {
+                            t.spawn((
+                                Text::new(format!("{} {}/{}", tab.name(), found, total)),
+                                TextFont {
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(if active {
+                                    theme.accent
+                                } else {
+                                    Color::srgb(0.5, 0.5, 0.5)
+                                }),
+                            ));
+                        });
+                    }
+                });
+
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(20.0),
+                    width: Val::Percent(90.0),
+                    flex_grow: 1.0,
+                    ..default()
+                })
+                .with_children(|columns| {
+                    // Entry list
+                    columns
+                        .spawn(Node {
+                            flex_direction: FlexDirection::Column,
+                            row_gap: Val::Px(4.0),
+                            width: Val::Px(260.0),
+                            ..default()
+                        })
+                        .with_children(|list| {
+                            for (i, entry) in
+                                entries.iter().enumerate().skip(start).take(VISIBLE_ENTRIES)
+                            {
+                                let known = log.is_unlocked(&entry.id);
+                                let is_selected = i == view.index;
+                                let label = if known { entry.title.as_str() } else { "???" };
+                                list.spawn((
+                                    CodexRow(i),
+                                    Node {
+                                        padding: UiRect::axes(Val::Px(10.0), Val::Px(4.0)),
+                                        border: UiRect::left(Val::Px(3.0)),
+                                        ..default()
+                                    },
+                                    BackgroundColor(if is_selected {
+                                        theme.selected_background()
+                                    } else {
+                                        Color::NONE
+                                    }),
+                                    BorderColor(if is_selected {
+                                        theme.primary
+                                    } else {
+                                        Color::NONE
+                                    }),
+                                ))
+                                .with_children(|row| {
+                                    row.spawn((
+                                        Text::new(label),
+                                        TextFont {
+                                            font_size: 15.0,
+                                            ..default()
+                                        },
+                                        TextColor(match (known, is_selected) {
+                                            (true, true) => theme.accent,
+                                            (true, false) => Color::srgb(0.75, 0.75, 0.75),
+                                            (false, _) => Color::srgb(0.35, 0.35, 0.35),
+                                        }),
+                                    ));
+                                });
+                            }
+                        });
+
+                    // Selected page
+                    columns
+                        .spawn((
+                            Node {
+                                flex_direction: FlexDirection::Column,
+                                row_gap: Val::Px(8.0),
+                                flex_grow: 1.0,
+                                padding: UiRect::all(Val::Px(16.0)),
+                                border: UiRect::all(Val::Px(1.0)),
+                                ..default()
+                            },
+                            BorderColor(theme.frame()),
+                        ))
+                        .with_children(|page| {
+                            let (title, subtitle, body) = match selected {
+                                Some(entry) if log.is_unlocked(&entry.id) => (
+                                    entry.title.as_str(),
+                                    entry.subtitle.as_str(),
+                                    entry.body.as_str(),
+                                ),
+                                Some(_) => {
+                                    ("UNKNOWN", "", "Not yet encountered. Keep flying, pilot.")
+                                }
+                                None => ("", "", ""),
+                            };
+                            page.spawn((
+                                Text::new(title),
+                                TextFont {
+                                    font_size: 26.0,
+                                    ..default()
+                                },
+                                TextColor(theme.accent),
+                            ));
+                            page.spawn((
+                                Text::new(subtitle),
+                                TextFont {
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(theme.primary),
+                            ));
+                            page.spawn((
+                                Text::new(body),
+                                TextFont {
+                                    font_size: 16.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.85, 0.85, 0.8)),
+                            ));
+                        });
+                });
+
+            parent.spawn((
+                Text::new("←→ Section • ↑↓/Wheel Entry • Click to pick • ESC Close"),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.4, 0.4, 0.4)),
+            ));
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn list_window_keeps_the_selection_in_view() {
+        assert_eq!(list_window_start(0, 40), 0);
+        assert_eq!(list_window_start(20, 40), 14);
+        assert_eq!(list_window_start(39, 40), 40 - VISIBLE_ENTRIES);
+        assert_eq!(list_window_start(3, 5), 0);
+    }
+
+    #[test]
+    fn list_holds_still_until_the_selection_leaves_it() {
+        let mut view = CodexView {
+            index: VISIBLE_ENTRIES - 1,
+            ..default()
+        };
+        assert!(view.selection_listed());
+        view.index = VISIBLE_ENTRIES;
+        assert!(!view.selection_listed());
+        view.first = list_window_start(view.index, 40);
+        assert!(view.selection_listed());
+    }
+}
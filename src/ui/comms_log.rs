@@ -0,0 +1,220 @@
+//! Comms Log Screen
+//!
+//! Opened from the pause menu: the run's recent dialogue, newest at the
+//! bottom, scrolled with up/down. While it's open the pause menu
+//! underneath ignores input.
+
+#![allow(dead_code)]
+
+use crate::core::*;
+use crate::systems::{DialogueSystem, JoystickState};
+use crate::ui::menu::{get_nav_input, MENU_NAV_COOLDOWN};
+use crate::ui::UiTheme;
+use bevy::prelude::*;
+
+/// Lines shown at once
+const VISIBLE_LINES: usize = 10;
+
+/// Comms log screen plugin
+pub struct CommsLogPlugin;
+
+impl Plugin for CommsLogPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                close_comms_log_on_state_change.run_if(state_changed::<GameState>),
+                comms_log_input.run_if(resource_exists::<CommsLogView>),
+                refresh_comms_log.run_if(resource_exists_and_changed::<CommsLogView>),
+                despawn_closed_comms_log.run_if(resource_removed::<CommsLogView>),
+            )
+                .chain(),
+        );
+    }
+}
+
+/// The open comms log; absent while closed
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CommsLogView {
+    /// Lines scrolled back from the newest
+    pub scroll: usize,
+}
+
+/// Comms log screen root
+#[derive(Component)]
+pub struct CommsLogRoot;
+
+/// Range of a `len`-line history to show, `scroll` lines back from the end
+pub fn log_window(len: usize, scroll: usize) -> std::ops::Range<usize> {
+    let end = len - scroll.min(len.saturating_sub(VISIBLE_LINES));
+    end.saturating_sub(VISIBLE_LINES)..end
+}
+
+fn close_comms_log_on_state_change(mut commands: Commands) {
+    commands.remove_resource::<CommsLogView>();
+}
+
+fn comms_log_input(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    joystick: Res<JoystickState>,
+    dialogue: Res<DialogueSystem>,
+    mut view: ResMut<CommsLogView>,
+    time: Res<Time>,
+    mut cooldown: Local<f32>,
+) {
+    *cooldown -= time.delta_secs();
+
+    if keyboard.just_pressed(KeyCode::Escape) || joystick.back() {
+        commands.remove_resource::<CommsLogView>();
+        return;
+    }
+
+    // Up scrolls back in time
+    let nav = get_nav_input(&keyboard, &joystick);
+    if nav != 0 && *cooldown <= 0.0 {
+        let max_scroll = dialogue.history.len().saturating_sub(VISIBLE_LINES);
+        let scroll = (view.scroll as i32 - nav).clamp(0, max_scroll as i32) as usize;
+        if scroll != view.scroll {
+            view.scroll = scroll;
+        }
+        *cooldown = MENU_NAV_COOLDOWN;
+    }
+}
+
+fn despawn_closed_comms_log(mut commands: Commands, query: Query<Entity, With<CommsLogRoot>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Rebuild the screen for the current scroll position
+fn refresh_comms_log(
+    mut commands: Commands,
+    view: Res<CommsLogView>,
+    dialogue: Res<DialogueSystem>,
+    theme: Res<UiTheme>,
+    existing: Query<Entity, With<CommsLogRoot>>,
+) {
+    for entity in existing.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    let history = &dialogue.history;
+    let window = log_window(history.len(), view.scroll);
+    let (older, newer) = (window.start, history.len() - window.end);
+
+    commands
+        .spawn((
+            CommsLogRoot,
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(30.0)),
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(theme.panel_background().with_alpha(0.97)),
+            ZIndex(500), // Above menus, below toasts
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("COMMS LOG"),
+                TextFont {
+                    font_size: 40.0,
+                    ..default()
+                },
+                TextColor(theme.primary),
+            ));
+
+            parent
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(10.0),
+                        width: Val::Percent(80.0),
+                        flex_grow: 1.0,
+                        padding: UiRect::all(Val::Px(16.0)),
+                        border: UiRect::all(Val::Px(1.0)),
+                        ..default()
+                    },
+                    BorderColor(theme.frame()),
+                ))
+                .with_children(|list| {
+                    let hint = |text: String| {
+                        (
+                            Text::new(text),
+                            TextFont {
+                                font_size: 12.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(0.4, 0.4, 0.4)),
+                        )
+                    };
+
+                    if history.is_empty() {
+                        list.spawn(hint("No transmissions yet.".into()));
+                        return;
+                    }
+                    if older > 0 {
+                        list.spawn(hint(format!("▲ {} earlier", older)));
+                    }
+                    for line in history.range(window) {
+                        list.spawn(Node {
+                            flex_direction: FlexDirection::Column,
+                            row_gap: Val::Px(2.0),
+                            ..default()
+                        })
+                        .with_children(|entry| {
+                            entry.spawn((
+                                Text::new(line.speaker.clone()),
+                                TextFont {
+                                    font_size: 13.0,
+                                    ..default()
+                                },
+                                TextColor(theme.accent),
+                            ));
+                            entry.spawn((
+                                Text::new(line.text.clone()),
+                                TextFont {
+                                    font_size: 15.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.85, 0.85, 0.8)),
+                            ));
+                        });
+                    }
+                    if newer > 0 {
+                        list.spawn(hint(format!("▼ {} later", newer)));
+                    }
+                });
+
+            parent.spawn((
+                Text::new("↑↓ Scroll • ESC Close"),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.4, 0.4, 0.4)),
+            ));
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn log_window_ends_on_the_newest_and_scrolls_back() {
+        assert_eq!(log_window(0, 0), 0..0);
+        assert_eq!(log_window(4, 0), 0..4);
+        assert_eq!(log_window(4, 3), 0..4);
+        assert_eq!(log_window(30, 0), 20..30);
+        assert_eq!(log_window(30, 5), 15..25);
+        // Can't scroll past the oldest line
+        assert_eq!(log_window(30, 99), 0..VISIBLE_LINES);
+    }
+}
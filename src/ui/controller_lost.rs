@@ -0,0 +1,155 @@
+//! Controller Lost Overlay
+//!
+//! Pulling player one's pad out mid-run pauses the game and puts a
+//! "reconnect or press any key" card over the pause menu; the run picks up
+//! again on the first key or the first input from a pad plugged back in.
+//! Losing the pad in a menu just hands navigation back to the keyboard.
+
+#![allow(dead_code)]
+
+use crate::core::*;
+use crate::systems::{ConnectedPads, JoystickState, PadAssignment, PadInfo};
+use crate::ui::{MenuPointer, UiTheme};
+use bevy::prelude::*;
+
+/// Controller lost overlay plugin
+pub struct ControllerLostPlugin;
+
+impl Plugin for ControllerLostPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PreUpdate, watch_player_one_pad)
+            .add_systems(
+                Update,
+                (
+                    spawn_controller_lost.run_if(resource_added::<ControllerLostView>),
+                    controller_lost_input.run_if(resource_exists::<ControllerLostView>),
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Paused)),
+            )
+            .add_systems(OnExit(GameState::Paused), close_controller_lost);
+    }
+}
+
+/// The run is paused waiting for input to come back; absent otherwise
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ControllerLostView;
+
+/// Controller lost overlay root
+#[derive(Component)]
+pub struct ControllerLostRoot;
+
+/// Whether the pad player one had (`watched`) is gone from `pads`
+pub fn pad_lost(watched: Option<u32>, pads: &[PadInfo]) -> bool {
+    watched.is_some_and(|id| !pads.iter().any(|p| p.id == id))
+}
+
+/// Notice player one's pad going away: pause a run in progress, and drop
+/// menus back to keyboard focus
+fn watch_player_one_pad(
+    mut commands: Commands,
+    mut watched: Local<Option<u32>>,
+    pads: Res<ConnectedPads>,
+    assignment: Res<PadAssignment>,
+    state: Res<State<GameState>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut joystick: ResMut<JoystickState>,
+    mut pointer: ResMut<MenuPointer>,
+) {
+    if pad_lost(*watched, &pads.0) {
+        info!("Player one's controller disconnected");
+        // Nothing held on the old pad carries over
+        let deadzone = joystick.deadzone;
+        *joystick = JoystickState {
+            deadzone,
+            ..default()
+        };
+        pointer.active = false;
+
+        if matches!(state.get(), GameState::Playing | GameState::BossFight) {
+            next_state.set(GameState::Paused);
+            commands.init_resource::<ControllerLostView>();
+        }
+    }
+    *watched = assignment.player_one(&pads.0).map(|p| p.id);
+}
+
+fn spawn_controller_lost(mut commands: Commands, theme: Res<UiTheme>) {
+    commands
+        .spawn((
+            ControllerLostRoot,
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(theme.panel_background().with_alpha(0.97)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("CONTROLLER DISCONNECTED"),
+                TextFont {
+                    font_size: 36.0,
+                    ..default()
+                },
+                TextColor(theme.primary),
+            ));
+            parent.spawn((
+                Text::new("Reconnect or press any key"),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.6, 0.6, 0.6)),
+            ));
+        });
+}
+
+/// Any key, or any input from a pad plugged back in, resumes the run. The
+/// press is used up here so the pause menu underneath never sees it.
+fn controller_lost_input(
+    mut commands: Commands,
+    mut keyboard: ResMut<ButtonInput<KeyCode>>,
+    mut joystick: ResMut<JoystickState>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let keys: Vec<KeyCode> = keyboard.get_just_pressed().copied().collect();
+    if keys.is_empty() && !joystick.in_use() {
+        return;
+    }
+    for key in keys {
+        keyboard.clear_just_pressed(key);
+    }
+    joystick.prev_buttons = joystick.buttons;
+    joystick.prev_dpad_x = joystick.dpad_x;
+    joystick.prev_dpad_y = joystick.dpad_y;
+
+    info!("Input back - resuming");
+    commands.remove_resource::<ControllerLostView>();
+    next_state.set(GameState::Playing);
+}
+
+fn close_controller_lost(mut commands: Commands, query: Query<Entity, With<ControllerLostRoot>>) {
+    commands.remove_resource::<ControllerLostView>();
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_the_watched_pad_going_away_counts() {
+        let pads = [PadInfo::new(1, "Xbox Wireless Controller")];
+        assert!(pad_lost(Some(0), &pads));
+        assert!(!pad_lost(Some(1), &pads));
+        assert!(!pad_lost(None, &[]));
+    }
+}
@@ -0,0 +1,139 @@
+//! Crash Restore Prompt
+//!
+//! Shown on the main menu after a crash. Offers to drop the player back
+//! into the interrupted campaign mission, or dismiss and keep going.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+
+use crate::core::*;
+use crate::games::ActiveModule;
+use crate::systems::JoystickState;
+use crate::ui::{ToastEvent, TransitionEvent};
+
+/// Crash restore prompt plugin
+pub struct CrashRestorePlugin;
+
+impl Plugin for CrashRestorePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            crash_restore_prompt
+                .run_if(in_state(GameState::MainMenu))
+                .run_if(resource_exists::<PendingCrashRestore>)
+                .after(bevy_egui::EguiSet::ProcessInput),
+        );
+    }
+}
+
+#[derive(PartialEq)]
+enum RestoreChoice {
+    Restore,
+    Dismiss,
+}
+
+#[allow(clippy::too_many_arguments)]
+fn crash_restore_prompt(
+    mut commands: Commands,
+    mut egui_ctx: EguiContexts,
+    pending: Res<PendingCrashRestore>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    joystick: Res<JoystickState>,
+    mut save: ResMut<SaveData>,
+    mut campaign: ResMut<CampaignState>,
+    mut session: ResMut<GameSession>,
+    mut active_module: ResMut<ActiveModule>,
+    mut endless: ResMut<EndlessMode>,
+    mut transitions: EventWriter<TransitionEvent>,
+    mut toasts: EventWriter<ToastEvent>,
+) {
+    let Some(ctx) = egui_ctx.try_ctx_mut() else {
+        return;
+    };
+
+    let run = &pending.0.run;
+    let restorable = run.is_restorable();
+    let mut choice = None;
+
+    egui::Window::new("Welcome back, pilot")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+        .show(ctx, |ui| {
+            ui.label("EVE Rebellion closed unexpectedly last time. Sorry about that.");
+            ui.add_space(6.0);
+            if restorable {
+                ui.label(format!(
+                    "You were in Act {}, {} (wave {}) with {} points.",
+                    run.act, run.mission_name, run.wave, run.score
+                ));
+                ui.label("Restore your campaign progress and return to that mission?");
+            } else {
+                ui.label("Your progress up to the crash has been kept.");
+            }
+            ui.add_space(4.0);
+            ui.small("A crash report was saved to the crashes folder next to your save file.");
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                if restorable && ui.button("Restore  [Enter]").clicked() {
+                    choice = Some(RestoreChoice::Restore);
+                }
+                let dismiss = if restorable {
+                    "Dismiss  [Esc]"
+                } else {
+                    "OK  [Enter]"
+                };
+                if ui.button(dismiss).clicked() {
+                    choice = Some(RestoreChoice::Dismiss);
+                }
+            });
+        });
+
+    let confirm = keyboard.just_pressed(KeyCode::Enter) || joystick.confirm();
+    let back = keyboard.just_pressed(KeyCode::Escape) || joystick.back();
+    if choice.is_none() {
+        if confirm && restorable {
+            choice = Some(RestoreChoice::Restore);
+        } else if confirm || back {
+            choice = Some(RestoreChoice::Dismiss);
+        }
+    }
+
+    let Some(choice) = choice else {
+        return;
+    };
+
+    merge_crash_save(&mut save, &pending.0.save);
+
+    if choice == RestoreChoice::Restore {
+        let player = Faction::all()
+            .iter()
+            .copied()
+            .find(|f| f.name() == run.player_faction)
+            .unwrap_or_default();
+        active_module.set_module("elder_fleet");
+        endless.active = false;
+        *session = GameSession::new(player, player.rival());
+        *campaign = CampaignState {
+            act: run.campaign_act(),
+            mission_index: run.mission_index,
+            // Interlude boons live on in the checkpoint, if one was written
+            flags: save
+                .checkpoint
+                .as_ref()
+                .map(|checkpoint| checkpoint.flags.clone())
+                .unwrap_or_default(),
+            ..default()
+        };
+
+        info!(
+            "Restoring crashed run: Act {} mission {}",
+            run.act,
+            run.mission_index + 1
+        );
+        toasts.send(ToastEvent::info(format!("Restored: {}", run.mission_name)));
+        transitions.send(TransitionEvent::to(GameState::ShipSelect));
+    }
+
+    clear_pending_crash(&mut commands);
+}
@@ -0,0 +1,64 @@
+//! Faction Emblem Widget
+//!
+//! The faction insignia in a palette-colored frame, shared by the faction
+//! and ship select screens, the HUD and the victory screen.
+
+#![allow(dead_code)]
+
+use crate::assets::FactionEmblemCache;
+use crate::core::Faction;
+use bevy::prelude::*;
+
+/// A spawned faction emblem
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FactionEmblem(pub Faction);
+
+/// Spawn a `size`-pixel emblem: the mask tinted with the faction's primary
+/// color on its secondary color, or the faction initial if no mask loaded
+pub fn spawn_faction_emblem(
+    parent: &mut ChildBuilder,
+    emblems: &FactionEmblemCache,
+    faction: Faction,
+    size: f32,
+) {
+    let primary = faction.primary_color();
+    let secondary = faction.secondary_color();
+
+    parent
+        .spawn((
+            FactionEmblem(faction),
+            Node {
+                width: Val::Px(size),
+                height: Val::Px(size),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                border: UiRect::all(Val::Px(2.0)),
+                padding: UiRect::all(Val::Px(size * 0.08)),
+                ..default()
+            },
+            BackgroundColor(secondary.with_alpha(0.45)),
+            BorderColor(primary.with_alpha(0.8)),
+        ))
+        .with_children(|frame| match emblems.get(faction) {
+            Some(image) => {
+                frame.spawn((
+                    ImageNode::new(image).with_color(primary),
+                    Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                ));
+            }
+            None => {
+                frame.spawn((
+                    Text::new(&faction.short_name()[..1]),
+                    TextFont {
+                        font_size: size * 0.6,
+                        ..default()
+                    },
+                    TextColor(primary),
+                ));
+            }
+        });
+}
@@ -0,0 +1,155 @@
+//! Event Log Overlay
+//!
+//! Debug panel listing recent gameplay events from `EventLog`; both it and
+//! the `BattleLog` can be copied out for bug reports. A line above the log
+//! shows the frame rate and the measured input-to-display latency.
+//! Toggle with F3; works in every state.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+
+use crate::core::{
+    BattleLog, DisplaySettings, EventCategory, EventLog, InputLatency, EVENT_LOG_CAPACITY,
+};
+
+/// Event log overlay plugin
+pub struct EventLogOverlayPlugin;
+
+impl Plugin for EventLogOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EventLogOverlay>().add_systems(
+            Update,
+            (toggle_event_log, draw_event_log)
+                .chain()
+                .after(bevy_egui::EguiSet::ProcessInput),
+        );
+    }
+}
+
+/// Overlay visibility and filters
+#[derive(Resource)]
+pub struct EventLogOverlay {
+    pub visible: bool,
+    /// Categories shown (indexed like `EventCategory::ALL`)
+    pub shown: [bool; 6],
+}
+
+impl Default for EventLogOverlay {
+    fn default() -> Self {
+        Self {
+            visible: false,
+            shown: [true; 6],
+        }
+    }
+}
+
+fn toggle_event_log(keyboard: Res<ButtonInput<KeyCode>>, mut overlay: ResMut<EventLogOverlay>) {
+    if keyboard.just_pressed(KeyCode::F3) {
+        overlay.visible = !overlay.visible;
+    }
+}
+
+/// Frame rate, input latency and whether low-latency mode is on
+fn frame_timing_line(frame_secs: f32, latency: &InputLatency, low_latency: bool) -> String {
+    let fps = if frame_secs > 0.0 {
+        1.0 / frame_secs
+    } else {
+        0.0
+    };
+    let input = match (latency.average_ms(), latency.last_ms()) {
+        (Some(average), Some(last)) => format!("{:.1} ms avg, {:.1} last", average, last),
+        _ => "press a key".to_string(),
+    };
+    let mode = if low_latency {
+        "low latency"
+    } else {
+        "smoothed"
+    };
+    format!("{:>4.0} fps | input {} | {}", fps, input, mode)
+}
+
+fn category_color(category: EventCategory) -> egui::Color32 {
+    match category {
+        EventCategory::Mission => egui::Color32::from_rgb(120, 200, 255),
+        EventCategory::Wave => egui::Color32::from_rgb(180, 180, 190),
+        EventCategory::Boss => egui::Color32::from_rgb(255, 110, 90),
+        EventCategory::Pickup => egui::Color32::from_rgb(120, 230, 140),
+        EventCategory::Dialogue => egui::Color32::from_rgb(230, 200, 120),
+        EventCategory::Combat => egui::Color32::from_rgb(255, 160, 60),
+    }
+}
+
+fn draw_event_log(
+    mut egui_ctx: EguiContexts,
+    mut overlay: ResMut<EventLogOverlay>,
+    mut log: ResMut<EventLog>,
+    battle_log: Res<BattleLog>,
+    latency: Res<InputLatency>,
+    display: Res<DisplaySettings>,
+    time: Res<Time<Real>>,
+) {
+    if !overlay.visible {
+        return;
+    }
+    let Some(ctx) = egui_ctx.try_ctx_mut() else {
+        return;
+    };
+
+    let mut open = true;
+    egui::Window::new("Event Log")
+        .open(&mut open)
+        .default_pos(egui::pos2(20.0, 120.0))
+        .default_size(egui::vec2(460.0, 320.0))
+        .show(ctx, |ui| {
+            ui.monospace(frame_timing_line(
+                time.delta_secs(),
+                &latency,
+                display.low_latency,
+            ));
+            ui.horizontal_wrapped(|ui| {
+                for (i, category) in EventCategory::ALL.iter().enumerate() {
+                    ui.checkbox(&mut overlay.shown[i], category.label());
+                }
+            });
+            ui.horizontal(|ui| {
+                ui.label(format!("{}/{} events", log.len(), EVENT_LOG_CAPACITY));
+                if ui.button("Copy").clicked() {
+                    ui.ctx().copy_text(log.to_report());
+                }
+                if ui.button("Copy battle log").clicked() {
+                    ui.ctx().copy_text(battle_log.to_report());
+                }
+                if ui.button("Clear").clicked() {
+                    log.clear();
+                }
+            });
+            ui.separator();
+
+            egui::ScrollArea::vertical()
+                .stick_to_bottom(true)
+                .auto_shrink([false, false])
+                .show(ui, |ui| {
+                    for entry in log.entries() {
+                        let index = EventCategory::ALL
+                            .iter()
+                            .position(|c| *c == entry.category)
+                            .unwrap_or(0);
+                        if !overlay.shown[index] {
+                            continue;
+                        }
+                        ui.horizontal(|ui| {
+                            ui.monospace(format!("{:>8.2}", entry.time));
+                            ui.colored_label(
+                                category_color(entry.category),
+                                egui::RichText::new(entry.category.label()).monospace(),
+                            );
+                            ui.label(&entry.message);
+                        });
+                    }
+                });
+        });
+
+    if !open {
+        overlay.visible = false;
+    }
+}
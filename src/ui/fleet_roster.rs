@@ -0,0 +1,275 @@
+//! Fleet Roster Screen
+//!
+//! Opened from the route map between campaign missions: every ship in the
+//! hangar with its condition, fatigue and sorties flown, and in Ironman the
+//! ones already lost. Picking a ship sets it to fly the next mission (and
+//! moves the checkpoint onto it). While it's open the route map underneath
+//! ignores input.
+
+#![allow(dead_code)]
+
+use crate::core::*;
+use crate::systems::{unlocked_ships, JoystickState};
+use crate::ui::menu::{get_nav_input, is_confirm, MENU_NAV_COOLDOWN};
+use crate::ui::route_map::route_map_input;
+use crate::ui::{PadPrompt, UiTheme};
+use bevy::prelude::*;
+
+/// Fleet roster screen plugin
+pub struct FleetRosterScreenPlugin;
+
+impl Plugin for FleetRosterScreenPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                close_roster_on_state_change.run_if(state_changed::<GameState>),
+                roster_input.run_if(resource_exists::<RosterView>),
+                refresh_roster.run_if(resource_exists_and_changed::<RosterView>),
+                despawn_closed_roster.run_if(resource_removed::<RosterView>),
+            )
+                .chain()
+                // The closing press mustn't reach the map underneath
+                .after(route_map_input),
+        );
+    }
+}
+
+/// The open roster screen and its selected row; absent while closed
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RosterView {
+    /// Index into the hangar (the session's unlocked ships)
+    pub index: usize,
+}
+
+/// Roster screen root
+#[derive(Component)]
+pub struct RosterRoot;
+
+/// Indices into the session's ships of the ones in the hangar
+pub fn hangar(save_data: &SaveData, session: &GameSession) -> Vec<usize> {
+    unlocked_ships(save_data, session)
+        .into_iter()
+        .enumerate()
+        .filter(|(_, unlocked)| *unlocked)
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// "TIRED - fatigue 55% - 3 sorties" for a roster row
+pub fn condition_line(member: Option<&RosterMember>) -> String {
+    let member = member.cloned().unwrap_or_else(|| RosterMember::new(0));
+    let sorties = match member.sorties {
+        1 => "1 sortie".to_string(),
+        n => format!("{} sorties", n),
+    };
+    format!(
+        "{} - fatigue {:.0}% - {}",
+        member.condition(),
+        member.fatigue * 100.0,
+        sorties
+    )
+}
+
+fn close_roster_on_state_change(mut commands: Commands) {
+    commands.remove_resource::<RosterView>();
+}
+
+fn roster_input(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    joystick: Res<JoystickState>,
+    mut view: ResMut<RosterView>,
+    mut session: ResMut<GameSession>,
+    mut save_data: ResMut<SaveData>,
+    time: Res<Time>,
+    mut cooldown: Local<f32>,
+) {
+    *cooldown -= time.delta_secs();
+
+    if keyboard.just_pressed(KeyCode::Escape) || joystick.back() {
+        commands.remove_resource::<RosterView>();
+        return;
+    }
+
+    let hangar = hangar(&save_data, &session);
+    if hangar.is_empty() {
+        return;
+    }
+    let nav = get_nav_input(&keyboard, &joystick);
+    if nav != 0 && *cooldown <= 0.0 {
+        view.index = (view.index as i32 + nav).rem_euclid(hangar.len() as i32) as usize;
+        *cooldown = MENU_NAV_COOLDOWN;
+    }
+
+    if is_confirm(&keyboard, &joystick) {
+        let index = hangar[view.index.min(hangar.len() - 1)];
+        let ship = &session.player_ships()[index];
+        if !save_data.fleet_roster.is_available(ship.type_id) {
+            info!("{} was lost - it can't fly again this campaign", ship.name);
+            return;
+        }
+        session.selected_ship_index = index;
+        if let Some(checkpoint) = save_data.checkpoint.as_mut() {
+            checkpoint.ship_index = index;
+        }
+        info!("{} will fly the next mission", ship.name);
+        // Redraw the rows
+        view.set_changed();
+    }
+}
+
+fn despawn_closed_roster(mut commands: Commands, query: Query<Entity, With<RosterRoot>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Rebuild the screen for the current selection and roster
+fn refresh_roster(
+    mut commands: Commands,
+    view: Res<RosterView>,
+    session: Res<GameSession>,
+    save_data: Res<SaveData>,
+    theme: Res<UiTheme>,
+    existing: Query<Entity, With<RosterRoot>>,
+) {
+    for entity in existing.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    let roster = &save_data.fleet_roster;
+    let ships = session.player_ships();
+    commands
+        .spawn((
+            RosterRoot,
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Px(10.0),
+                ..default()
+            },
+            BackgroundColor(theme.panel_background().with_alpha(0.97)),
+            ZIndex(500), // Above menus, below toasts
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("FLEET ROSTER"),
+                TextFont {
+                    font_size: 40.0,
+                    ..default()
+                },
+                TextColor(theme.primary),
+            ));
+            parent.spawn((
+                Text::new(if roster.ironman {
+                    "IRONMAN - ships destroyed are lost for the campaign"
+                } else {
+                    "Rested ships launch at full strength"
+                }),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(if roster.ironman {
+                    Color::srgb(0.9, 0.35, 0.3)
+                } else {
+                    Color::srgb(0.6, 0.6, 0.65)
+                }),
+            ));
+
+            for (row_index, ship_index) in hangar(&save_data, &session).into_iter().enumerate() {
+                let ship = &ships[ship_index];
+                let member = roster.member(ship.type_id);
+                let selected = row_index == view.index;
+                let lost = member.is_some_and(|m| m.lost);
+                let flying = ship_index == session.selected_ship_index;
+
+                parent
+                    .spawn((
+                        Node {
+                            width: Val::Px(480.0),
+                            flex_direction: FlexDirection::Column,
+                            padding: UiRect::axes(Val::Px(14.0), Val::Px(8.0)),
+                            border: UiRect::all(Val::Px(if selected { 2.0 } else { 1.0 })),
+                            row_gap: Val::Px(2.0),
+                            ..default()
+                        },
+                        BorderColor(if selected {
+                            theme.accent
+                        } else {
+                            theme.frame()
+                        }),
+                    ))
+                    .with_children(|row| {
+                        row.spawn((
+                            Text::new(format!(
+                                "{}   {}{}",
+                                ship.name.to_uppercase(),
+                                ship.class.name(),
+                                if flying { "   FLYING NEXT" } else { "" }
+                            )),
+                            TextFont {
+                                font_size: 18.0,
+                                ..default()
+                            },
+                            TextColor(if lost {
+                                Color::srgb(0.45, 0.45, 0.45)
+                            } else {
+                                Color::WHITE
+                            }),
+                        ));
+                        row.spawn((
+                            Text::new(condition_line(member)),
+                            TextFont {
+                                font_size: 13.0,
+                                ..default()
+                            },
+                            TextColor(if lost {
+                                Color::srgb(0.9, 0.35, 0.3)
+                            } else {
+                                Color::srgb(0.6, 0.6, 0.65)
+                            }),
+                        ));
+                    });
+            }
+
+            parent.spawn((
+                PadPrompt::new("↑↓ Select • A/ENTER Fly Next • B/ESC Done"),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.4, 0.4, 0.4)),
+            ));
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn condition_line_reads_out_the_member() {
+        assert_eq!(condition_line(None), "FRESH - fatigue 0% - 0 sorties");
+        let member = RosterMember {
+            type_id: 587,
+            fatigue: 0.55,
+            sorties: 1,
+            lost: false,
+        };
+        assert_eq!(
+            condition_line(Some(&member)),
+            "TIRED - fatigue 55% - 1 sortie"
+        );
+        let lost = RosterMember {
+            lost: true,
+            ..member
+        };
+        assert!(condition_line(Some(&lost)).starts_with("LOST"));
+    }
+}
@@ -1,20 +1,44 @@
 //! Heads-Up Display
 //!
-//! In-game UI: health bars, score, combo, heat, berserk meter, powerup indicators.
-//! EVE-style status panel with capacitor and health rings.
+//! In-game UI: health bars, score, combo, heat, berserk meter, powerup indicators,
+//! shield bubble timer, boss warning banner.
+//! EVE-style status panel with capacitor and health rings, or classic bars.
+//!
+//! The compact layout (picked in options, or automatically on a Steam
+//! Deck-sized display) enlarges the health and boss bars, keeps every label
+//! at 12pt or more, swaps the dense wheel for the classic bars, and folds
+//! the objective list and souls count away behind a toggle.
+//!
+//! Labels backed by event-driven resources only update when those change
+//! (or the HUD was just rebuilt). Everything else formats into a reused
+//! buffer and only writes `Text` and `Node` values that actually moved, so
+//! a busy frame doesn't re-lay out the whole HUD.
 
 #![allow(dead_code)]
 
 use crate::core::*;
-use crate::entities::{Boss, BossData, BossState, Player, PowerupEffects, Wingman, WingmanTracker};
-use crate::systems::{Ability, AbilityType, ComboHeatSystem, DialogueSystem};
+use crate::entities::{
+    get_phase_threshold, pilot_portrait, Boss, BossData, BossState, Player, PowerupEffects,
+    ProtectedTarget, ShipStats, Wingman, WingmanPilot, WingmanTracker, LEECH_DURATION,
+};
+use crate::systems::{
+    warning_pulse, Ability, AbilityType, BossPhaseChangeEvent, BossShieldGate, BossWarning,
+    BubbleLabel, ComboHeatSystem, DialogueSettings, DialogueSystem, JoystickState, MotionSettings,
+    ShieldBubble, ShieldBubbleState, StatusEffects, StatusKind, PHASE_TRANSITION_DURATION,
+};
 use bevy::prelude::*;
+use bevy::window::{Monitor, PrimaryMonitor};
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
 
 /// HUD plugin
 pub struct HudPlugin;
 
 impl Plugin for HudPlugin {
     fn build(&self, app: &mut App) {
+        app.init_resource::<HudLayout>()
+            .init_resource::<HudMetrics>()
+            .init_resource::<HudDetails>();
         app.add_systems(
             OnEnter(GameState::Playing),
             spawn_hud.run_if(not_last_stand),
@@ -22,20 +46,55 @@ impl Plugin for HudPlugin {
         .add_systems(
             Update,
             (
-                update_score_display,
+                update_score_display.run_if(resource_changed::<ScoreSystem>.or(hud_spawned)),
                 update_berserk_meter,
-                update_combo_display,
+                update_combo_display.run_if(resource_changed::<ScoreSystem>.or(hud_spawned)),
                 update_heat_display,
+                update_overload_display,
                 update_combo_kills,
                 update_combo_timer_bar,
                 update_powerup_indicators,
-                update_wave_display,
-                update_mission_display,
+                update_wave_display.run_if(wave_label_changed.or(hud_spawned)),
+                update_mission_display.run_if(
+                    mission_label_changed
+                        .or(resource_changed::<ScoreSystem>)
+                        .or(hud_spawned),
+                ),
                 update_boss_health_bar,
-                update_dialogue_display,
-                update_wingman_gauge,
+                update_phase_banner,
+                update_dialogue_display.run_if(resource_changed::<DialogueSystem>.or(hud_spawned)),
+                update_wingman_gauge.run_if(
+                    resource_changed::<WingmanTracker>
+                        .or(any_added::<Wingman>)
+                        .or(any_component_removed::<Wingman>)
+                        .or(hud_spawned),
+                ),
                 update_ability_indicator,
             )
+                .in_set(GameSet::Ui)
+                .run_if(in_state(GameState::Playing))
+                .run_if(not_last_stand),
+        )
+        .add_systems(
+            Update,
+            (
+                update_difficulty_chip.run_if(resource_changed::<ScoreSystem>.or(hud_spawned)),
+                update_debuff_indicators,
+                update_bubble_display,
+                update_objective_panel,
+                update_ally_health_bar,
+                update_classic_status_bars,
+                update_boss_phase_ticks,
+                update_boss_warning.run_if(resource_changed::<BossWarning>.or(hud_spawned)),
+                update_subtitle_style.run_if(resource_changed::<DialogueSettings>.or(hud_spawned)),
+                (shatter_boss_bar_on_phase, update_boss_bar_shards).chain(),
+                (
+                    toggle_hud_details,
+                    update_hud_details.run_if(resource_changed::<HudDetails>.or(hud_spawned)),
+                )
+                    .chain(),
+            )
+                .in_set(GameSet::Ui)
                 .run_if(in_state(GameState::Playing))
                 .run_if(not_last_stand),
         )
@@ -55,10 +114,242 @@ fn not_last_stand(last_stand: Option<Res<crate::games::caldari_gallente::LastSta
 #[derive(Component)]
 pub struct HudRoot;
 
+/// HUD size preset (saved)
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HudLayout {
+    /// Compact on a Steam Deck-sized display, standard otherwise
+    #[default]
+    Auto,
+    Standard,
+    /// Bigger bars and text, secondary details folded away
+    Compact,
+}
+
+/// Largest display (physical pixels) Auto treats as handheld
+const COMPACT_DISPLAY: UVec2 = UVec2::new(1280, 800);
+
+/// Smallest font the compact layout allows
+pub const COMPACT_MIN_FONT: f32 = 12.0;
+
+/// How much the compact layout enlarges the health and boss bars
+const COMPACT_BAR_SCALE: f32 = 1.6;
+
+impl HudLayout {
+    pub const ALL: [HudLayout; 3] = [HudLayout::Auto, HudLayout::Standard, HudLayout::Compact];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            HudLayout::Auto => "AUTO",
+            HudLayout::Standard => "STANDARD",
+            HudLayout::Compact => "COMPACT",
+        }
+    }
+
+    /// Next layout `step` places along, wrapping
+    pub fn stepped(&self, step: i32) -> Self {
+        let len = Self::ALL.len() as i32;
+        let index = Self::ALL.iter().position(|l| l == self).unwrap_or(0) as i32;
+        Self::ALL[(index + step).rem_euclid(len) as usize]
+    }
+
+    /// Whether to build the compact HUD on a display of `display` physical
+    /// pixels (None when the monitor is unknown)
+    pub fn is_compact(&self, display: Option<UVec2>) -> bool {
+        match self {
+            HudLayout::Auto => display.is_some_and(|size| size.cmple(COMPACT_DISPLAY).all()),
+            HudLayout::Standard => false,
+            HudLayout::Compact => true,
+        }
+    }
+}
+
+/// Sizes the current HUD was built with
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HudMetrics {
+    pub compact: bool,
+}
+
+impl HudMetrics {
+    /// Font size, raised to the legibility floor in the compact layout
+    pub fn font(&self, size: f32) -> f32 {
+        if self.compact {
+            size.max(COMPACT_MIN_FONT)
+        } else {
+            size
+        }
+    }
+
+    /// Critical bar dimension, enlarged in the compact layout
+    pub fn bar(&self, px: f32) -> f32 {
+        if self.compact {
+            px * COMPACT_BAR_SCALE
+        } else {
+            px
+        }
+    }
+
+    /// Whether the capacitor wheel is drawn; its small print has no room
+    /// to grow, so the compact layout always uses the classic bars
+    pub fn shows_wheel(&self, style: super::HudStyle) -> bool {
+        style == super::HudStyle::Wheel && !self.compact
+    }
+}
+
+/// Run condition: the capacitor wheel is the ship status display
+pub fn wheel_shown(style: Res<super::HudStyle>, metrics: Res<HudMetrics>) -> bool {
+    metrics.shows_wheel(*style)
+}
+
+/// Whether the objective list and souls count are folded away
+#[derive(Resource, Debug, Clone, Default)]
+pub struct HudDetails {
+    pub collapsed: bool,
+}
+
+/// Objective list and souls count, folded away on request
+#[derive(Component)]
+pub struct HudDetailsPanel;
+
+/// Key hint shown in place of the folded details
+#[derive(Component)]
+pub struct HudDetailsHint;
+
+/// Run condition: the HUD was rebuilt and every label needs filling in
+fn hud_spawned(query: Query<(), Added<HudRoot>>) -> bool {
+    !query.is_empty()
+}
+
+/// Campaign fields behind the wave label. The mission timer writes
+/// `CampaignState` every frame, so its change flag alone can't gate it.
+#[derive(Clone, Copy, PartialEq)]
+struct WaveLabelKey {
+    mission: Option<usize>,
+    current_wave: u32,
+    waves_cleared: u32,
+    enemy_waves: u32,
+    wave_spawned: u32,
+    wave_destroyed: u32,
+    trigger: WaveTrigger,
+    /// Whole seconds left on a hold-out wave
+    hold_left: u32,
+}
+
+impl WaveLabelKey {
+    fn of(campaign: &CampaignState) -> Self {
+        let trigger = campaign.wave_trigger(campaign.spawned_wave());
+        let hold_left = match trigger {
+            WaveTrigger::Survive(seconds) if campaign.wave_in_progress() => {
+                (seconds - campaign.wave_timer).max(0.0).ceil() as u32
+            }
+            _ => 0,
+        };
+        Self {
+            mission: campaign
+                .current_mission()
+                .map(|_| campaign.mission_number()),
+            current_wave: campaign.current_wave,
+            waves_cleared: campaign.waves_cleared,
+            enemy_waves: campaign.enemy_waves(),
+            wave_spawned: campaign.wave_spawned,
+            wave_destroyed: campaign.wave_destroyed,
+            trigger,
+            hold_left,
+        }
+    }
+}
+
+/// Run condition: something the wave label shows has changed
+fn wave_label_changed(
+    campaign: Res<CampaignState>,
+    mut shown: Local<Option<WaveLabelKey>>,
+) -> bool {
+    if !campaign.is_changed() {
+        return false;
+    }
+    let key = Some(WaveLabelKey::of(&campaign));
+    let changed = *shown != key;
+    *shown = key;
+    changed
+}
+
+/// Campaign fields behind the mission name and souls labels
+#[derive(Clone, Copy, PartialEq)]
+struct MissionLabelKey {
+    mission: Option<usize>,
+    in_mission: bool,
+    souls_met: bool,
+}
+
+/// Run condition: something the mission labels show has changed
+fn mission_label_changed(
+    campaign: Res<CampaignState>,
+    mut shown: Local<Option<MissionLabelKey>>,
+) -> bool {
+    if !campaign.is_changed() {
+        return false;
+    }
+    let key = Some(MissionLabelKey {
+        mission: campaign
+            .current_mission()
+            .map(|_| campaign.mission_number()),
+        in_mission: campaign.in_mission,
+        souls_met: campaign.mission_souls >= campaign.souls_to_liberate(),
+    });
+    let changed = *shown != key;
+    *shown = key;
+    changed
+}
+
+/// Run condition: a `C` appeared since the last check
+fn any_added<C: Component>(query: Query<(), Added<C>>) -> bool {
+    !query.is_empty()
+}
+
+/// Format a label into `buf` and copy it over only if it changed, so an
+/// unchanged label neither reallocates nor triggers a re-layout
+fn write_label(text: &mut Mut<Text>, buf: &mut String, args: std::fmt::Arguments) {
+    buf.clear();
+    let _ = buf.write_fmt(args);
+    if text.0 != *buf {
+        text.0.clone_from(buf);
+    }
+}
+
+/// Show or hide a node; only a flip marks it changed
+fn set_shown(node: &mut Mut<Node>, shown: bool) {
+    let display = if shown { Display::Flex } else { Display::None };
+    if node.display != display {
+        node.display = display;
+    }
+}
+
+/// Recolor a label; only a new color marks it changed
+fn set_color(color: &mut Mut<TextColor>, value: Color) {
+    if color.0 != value {
+        color.0 = value;
+    }
+}
+
+/// Set a bar's fill; only a move marks it changed
+fn set_fill(node: &mut Mut<Node>, percent: f32) {
+    let width = Val::Percent(percent);
+    if node.width != width {
+        node.width = width;
+    }
+}
+
 /// Score text
 #[derive(Component)]
 pub struct ScoreText;
 
+/// Difficulty and the run's score multiplier, always shown under the score
+#[derive(Component)]
+pub struct DifficultyChip;
+
+/// Label inside the difficulty chip
+#[derive(Component)]
+pub struct DifficultyChipText;
+
 /// Combo/multiplier text
 #[derive(Component)]
 pub struct ComboText;
@@ -71,10 +362,37 @@ pub struct GradeText;
 #[derive(Component)]
 pub struct BerserkBar;
 
+/// Boss phase banner (center screen, shown during phase transitions)
+#[derive(Component)]
+pub struct PhaseBannerText;
+
+/// Boss warning banner (shown while the klaxon sounds)
+#[derive(Component)]
+pub struct BossWarningBanner;
+
+/// Flashing "WARNING" line of the boss warning banner
+#[derive(Component)]
+pub struct BossWarningText;
+
+/// Red glow around the screen edge during the boss warning
+#[derive(Component)]
+pub struct BossWarningGlow;
+
+/// Boss warning red
+const WARNING_RED: Color = Color::srgb(1.0, 0.15, 0.1);
+
 /// Heat bar
 #[derive(Component)]
 pub struct HeatBar;
 
+/// Overload status text (ready / surging / burnout)
+#[derive(Component)]
+pub struct OverloadText;
+
+/// Marker for the shield bubble timer
+#[derive(Component)]
+pub struct BubbleText;
+
 /// Combo kill count text
 #[derive(Component)]
 pub struct ComboKillsText;
@@ -95,9 +413,19 @@ pub struct WaveText;
 #[derive(Component)]
 pub struct MissionNameText;
 
-/// Mission objective text
+/// Mission objective list container
+#[derive(Component)]
+pub struct ObjectivePanel;
+
+/// One line of the objective list (index among visible objectives)
 #[derive(Component)]
-pub struct ObjectiveText;
+pub struct ObjectiveRow(pub usize);
+
+/// Objective lines kept in the panel
+const OBJECTIVE_ROWS: usize = 6;
+
+/// Objective line font size (pulses up on completion)
+const OBJECTIVE_FONT_SIZE: f32 = 12.0;
 
 /// Souls liberated text
 #[derive(Component)]
@@ -132,6 +460,19 @@ pub enum PowerupType {
     Overdrive,
     DamageBoost,
     Invulnerability,
+    Leech,
+}
+
+/// Player debuff box (shown while the status is active)
+#[derive(Component)]
+pub struct DebuffIndicator {
+    pub kind: StatusKind,
+}
+
+/// Debuff label (name, stacks, time left)
+#[derive(Component)]
+pub struct DebuffText {
+    pub kind: StatusKind,
 }
 
 /// Container for a single powerup status box
@@ -148,6 +489,58 @@ pub struct BossHealthContainer;
 #[derive(Component)]
 pub struct BossHealthFill;
 
+/// Shield overlay on the boss health bar while a shield gate is up
+#[derive(Component)]
+pub struct BossShieldOverlay;
+
+/// Boss health bar track (the fill, ticks and shards live inside it)
+#[derive(Component)]
+pub struct BossHealthTrack;
+
+/// Tick on the boss health bar where `phase` begins
+#[derive(Component)]
+pub struct BossPhaseTick(pub u32);
+
+/// A piece of the boss health bar breaking off at a phase change
+#[derive(Component)]
+pub struct BossBarShard {
+    /// Position along the bar (percent) and below its top edge (px)
+    pub x: f32,
+    pub y: f32,
+    /// Percent/s along the bar, px/s down
+    pub velocity: Vec2,
+    pub lifetime: f32,
+}
+
+/// Most phases a boss can have
+const MAX_BOSS_PHASES: u32 = 5;
+
+/// How long phase shards take to fall away
+const SHARD_LIFETIME: f32 = 0.7;
+
+/// Boss health fill color for a phase, hotter as the fight goes on
+pub fn boss_phase_color(phase: u32) -> Color {
+    match phase {
+        0 | 1 => Color::srgb(0.9, 0.2, 0.2), // Red
+        2 => Color::srgb(0.95, 0.5, 0.15),   // Orange
+        3 => Color::srgb(0.95, 0.8, 0.2),    // Amber
+        4 => Color::srgb(0.85, 0.25, 0.75),  // Magenta
+        _ => Color::srgb(0.75, 0.55, 1.0),   // Violet
+    }
+}
+
+/// Escorted/defended ally health bar container
+#[derive(Component)]
+pub struct AllyHealthContainer;
+
+/// Ally health bar fill
+#[derive(Component)]
+pub struct AllyHealthFill;
+
+/// Ally name text
+#[derive(Component)]
+pub struct AllyNameText;
+
 /// Boss name text
 #[derive(Component)]
 pub struct BossNameText;
@@ -168,6 +561,13 @@ pub struct DialogueSpeakerText;
 #[derive(Component)]
 pub struct DialogueContentText;
 
+/// Dialogue speaker portrait
+#[derive(Component)]
+pub struct DialoguePortrait;
+
+/// Portrait color for the narrators (rust/bronze for Minmatar)
+const NARRATOR_PORTRAIT: Color = Color::srgb(0.6, 0.35, 0.2);
+
 /// Wingman gauge container
 #[derive(Component)]
 pub struct WingmanGauge;
@@ -180,6 +580,30 @@ pub struct WingmanGaugeFill;
 #[derive(Component)]
 pub struct WingmanCountText;
 
+/// Wingman gauge label, naming the incoming reinforcement
+#[derive(Component)]
+pub struct WingmanTierText;
+
+/// Classic HUD status bars (hidden while the capacitor wheel is shown)
+#[derive(Component)]
+pub struct ClassicStatusBars;
+
+/// Classic shield bar fill
+#[derive(Component)]
+pub struct ShieldBar;
+
+/// Classic armor bar fill
+#[derive(Component)]
+pub struct ArmorBar;
+
+/// Classic hull bar fill
+#[derive(Component)]
+pub struct HullBar;
+
+/// Classic capacitor bar fill
+#[derive(Component)]
+pub struct CapacitorBar;
+
 /// Ability indicator container
 #[derive(Component)]
 pub struct AbilityIndicatorContainer;
@@ -196,7 +620,26 @@ pub struct AbilityIndicatorText;
 #[derive(Component)]
 pub struct AbilityKeyHint;
 
-fn spawn_hud(mut commands: Commands) {
+fn spawn_hud(
+    mut commands: Commands,
+    session: Res<GameSession>,
+    emblems: Res<crate::assets::FactionEmblemCache>,
+    hud_style: Res<super::HudStyle>,
+    difficulty: Res<Difficulty>,
+    theme: Res<super::UiTheme>,
+    layout: Res<HudLayout>,
+    monitors: Query<&Monitor, With<PrimaryMonitor>>,
+) {
+    let frame = theme.frame();
+    let display = monitors.get_single().ok().map(Monitor::physical_size);
+    let metrics = HudMetrics {
+        compact: layout.is_compact(display),
+    };
+    commands.insert_resource(metrics);
+    commands.insert_resource(HudDetails {
+        collapsed: metrics.compact,
+    });
+
     commands
         .spawn((
             HudRoot,
@@ -236,6 +679,28 @@ fn spawn_hud(mut commands: Commands) {
                             },
                             TextColor(Color::WHITE),
                         ));
+                        left.spawn((
+                            DifficultyChip,
+                            Node {
+                                padding: UiRect::axes(Val::Px(6.0), Val::Px(1.0)),
+                                margin: UiRect::bottom(Val::Px(2.0)),
+                                border: UiRect::all(Val::Px(1.0)),
+                                ..default()
+                            },
+                            BackgroundColor(difficulty.color().with_alpha(0.2)),
+                            BorderColor(difficulty.color()),
+                        ))
+                        .with_children(|chip| {
+                            chip.spawn((
+                                DifficultyChipText,
+                                Text::new(difficulty.name()),
+                                TextFont {
+                                    font_size: metrics.font(11.0),
+                                    ..default()
+                                },
+                                TextColor(difficulty.color()),
+                            ));
+                        });
                         left.spawn((
                             MissionNameText,
                             Text::new(""),
@@ -243,7 +708,7 @@ fn spawn_hud(mut commands: Commands) {
                                 font_size: 14.0,
                                 ..default()
                             },
-                            TextColor(Color::srgb(0.8, 0.6, 0.3)), // Rust/amber
+                            TextColor(theme.accent),
                         ));
                         left.spawn((
                             WaveText,
@@ -255,22 +720,52 @@ fn spawn_hud(mut commands: Commands) {
                             TextColor(Color::srgb(0.6, 0.6, 0.6)),
                         ));
                         left.spawn((
-                            ObjectiveText,
-                            Text::new(""),
-                            TextFont {
-                                font_size: 12.0,
+                            HudDetailsPanel,
+                            Node {
+                                flex_direction: FlexDirection::Column,
                                 ..default()
                             },
-                            TextColor(Color::srgb(0.5, 0.8, 0.5)), // Green for objectives
-                        ));
+                        ))
+                        .with_children(|details| {
+                            details
+                                .spawn((
+                                    ObjectivePanel,
+                                    Node {
+                                        flex_direction: FlexDirection::Column,
+                                        ..default()
+                                    },
+                                ))
+                                .with_children(|panel| {
+                                    for index in 0..OBJECTIVE_ROWS {
+                                        panel.spawn((
+                                            ObjectiveRow(index),
+                                            Text::new(""),
+                                            TextFont {
+                                                font_size: OBJECTIVE_FONT_SIZE,
+                                                ..default()
+                                            },
+                                            TextColor(Color::srgb(0.5, 0.8, 0.5)), // Green for objectives
+                                        ));
+                                    }
+                                });
+                            details.spawn((
+                                SoulsText,
+                                Text::new(""),
+                                TextFont {
+                                    font_size: 12.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.4, 0.7, 1.0)), // Blue for souls
+                            ));
+                        });
                         left.spawn((
-                            SoulsText,
-                            Text::new(""),
+                            HudDetailsHint,
+                            Text::new("[TAB] OBJECTIVES"),
                             TextFont {
                                 font_size: 12.0,
                                 ..default()
                             },
-                            TextColor(Color::srgb(0.4, 0.7, 1.0)), // Blue for souls
+                            TextColor(Color::srgb(0.45, 0.45, 0.5)),
                         ));
                     });
 
@@ -352,7 +847,7 @@ fn spawn_hud(mut commands: Commands) {
                     BossHealthContainer,
                     Node {
                         width: Val::Percent(100.0),
-                        height: Val::Px(50.0),
+                        height: Val::Px(metrics.bar(50.0)),
                         flex_direction: FlexDirection::Column,
                         align_items: AlignItems::Center,
                         margin: UiRect::top(Val::Px(10.0)),
@@ -374,9 +869,10 @@ fn spawn_hud(mut commands: Commands) {
                     // Health bar background
                     boss_ui
                         .spawn((
+                            BossHealthTrack,
                             Node {
-                                width: Val::Percent(60.0),
-                                height: Val::Px(16.0),
+                                width: Val::Percent(if metrics.compact { 80.0 } else { 60.0 }),
+                                height: Val::Px(metrics.bar(16.0)),
                                 margin: UiRect::top(Val::Px(5.0)),
                                 ..default()
                             },
@@ -392,6 +888,80 @@ fn spawn_hud(mut commands: Commands) {
                                 },
                                 BackgroundColor(Color::srgb(0.9, 0.2, 0.2)),
                             ));
+                            bar.spawn((
+                                BossShieldOverlay,
+                                Node {
+                                    position_type: PositionType::Absolute,
+                                    width: Val::Percent(100.0),
+                                    height: Val::Percent(100.0),
+                                    border: UiRect::all(Val::Px(2.0)),
+                                    display: Display::None,
+                                    ..default()
+                                },
+                                BackgroundColor(Color::srgba(0.3, 0.7, 1.0, 0.35)),
+                                BorderColor(Color::srgb(0.5, 0.85, 1.0)),
+                            ));
+                            // Phase ticks, placed once a boss is on the field
+                            for phase in 2..=MAX_BOSS_PHASES {
+                                bar.spawn((
+                                    BossPhaseTick(phase),
+                                    Node {
+                                        position_type: PositionType::Absolute,
+                                        width: Val::Px(2.0),
+                                        height: Val::Px(metrics.bar(22.0)),
+                                        top: Val::Px(-metrics.bar(3.0)),
+                                        display: Display::None,
+                                        ..default()
+                                    },
+                                    BackgroundColor(Color::WHITE),
+                                ));
+                            }
+                        });
+                });
+
+            // === ALLY HEALTH BAR (escort/defend missions only) ===
+            parent
+                .spawn((
+                    AllyHealthContainer,
+                    Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Px(34.0),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        display: Display::None, // Hidden until an ally is on the field
+                        ..default()
+                    },
+                ))
+                .with_children(|ally_ui| {
+                    ally_ui.spawn((
+                        AllyNameText,
+                        Text::new(""),
+                        TextFont {
+                            font_size: 13.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.5, 0.9, 0.6)),
+                    ));
+                    ally_ui
+                        .spawn((
+                            Node {
+                                width: Val::Percent(30.0),
+                                height: Val::Px(8.0),
+                                margin: UiRect::top(Val::Px(3.0)),
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgba(0.0, 0.2, 0.05, 0.8)),
+                        ))
+                        .with_children(|bar| {
+                            bar.spawn((
+                                AllyHealthFill,
+                                Node {
+                                    width: Val::Percent(100.0),
+                                    height: Val::Percent(100.0),
+                                    ..default()
+                                },
+                                BackgroundColor(Color::srgb(0.3, 0.85, 0.4)),
+                            ));
                         });
                 });
 
@@ -417,6 +987,7 @@ fn spawn_hud(mut commands: Commands) {
                         "OVERDRIVE",
                         Color::srgb(0.3, 0.9, 1.0),
                         5.0, // max duration
+                        metrics,
                     );
                     // Damage boost status box (red/orange)
                     spawn_powerup_status_box(
@@ -425,6 +996,7 @@ fn spawn_hud(mut commands: Commands) {
                         "DAMAGE x2",
                         Color::srgb(1.0, 0.4, 0.2),
                         10.0, // max duration
+                        metrics,
                     );
                     // Invulnerability status box (gold/white)
                     spawn_powerup_status_box(
@@ -433,46 +1005,221 @@ fn spawn_hud(mut commands: Commands) {
                         "INVULN",
                         Color::srgb(1.0, 0.9, 0.4),
                         3.0, // max duration
+                        metrics,
                     );
+                    // Nosferatu leech status box (crimson)
+                    spawn_powerup_status_box(
+                        indicators,
+                        PowerupType::Leech,
+                        "LEECH",
+                        Color::srgb(0.85, 0.1, 0.3),
+                        LEECH_DURATION,
+                        metrics,
+                    );
+
+                    // Debuffs stack below the powerups
+                    for kind in StatusKind::ALL {
+                        spawn_debuff_indicator(indicators, kind, metrics);
+                    }
                 });
 
-            // === BOTTOM BAR: Meters only (health is shown in capacitor wheel) ===
+            // === PHASE BANNER: Floats over the playfield during boss phase shifts ===
             parent
                 .spawn(Node {
+                    position_type: PositionType::Absolute,
                     width: Val::Percent(100.0),
-                    height: Val::Px(80.0),
-                    flex_direction: FlexDirection::Row,
-                    justify_content: JustifyContent::SpaceBetween,
-                    padding: UiRect::all(Val::Px(10.0)),
+                    top: Val::Percent(28.0),
+                    justify_content: JustifyContent::Center,
                     ..default()
                 })
-                .with_children(|bottom| {
-                    // Left side: Status meters (Heat, Berserk)
-                    bottom
-                        .spawn(Node {
-                            flex_direction: FlexDirection::Column,
-                            row_gap: Val::Px(3.0),
-                            align_items: AlignItems::FlexStart,
+                .with_children(|banner| {
+                    banner.spawn((
+                        PhaseBannerText,
+                        Text::new(""),
+                        TextFont {
+                            font_size: 36.0,
                             ..default()
-                        })
-                        .with_children(|left| {
-                            // Heat meter (orange/red)
-                            spawn_health_bar(left, HeatBar, Color::srgb(1.0, 0.5, 0.0), "HEAT");
-                            // Berserk meter (purple)
-                            spawn_health_bar(
-                                left,
-                                BerserkBar,
-                                Color::srgb(0.8, 0.2, 0.8),
-                                "BERSERK",
-                            );
-                            // Ship ability indicator (blue/cyan)
-                            spawn_ability_indicator(left);
-                        });
+                        },
+                        TextColor(Color::srgba(1.0, 0.8, 0.3, 0.0)),
+                        TextLayout::new_with_justify(JustifyText::Center),
+                    ));
+                });
 
-                    // Center: Spacer to push wingman gauge right
-                    bottom
-                        .spawn((
-                            WingmanGauge,
+            // === BOSS WARNING: Edge glow and banner before the boss arrives ===
+            parent.spawn((
+                BossWarningGlow,
+                Node {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    border: UiRect::all(Val::Px(14.0)),
+                    display: Display::None,
+                    ..default()
+                },
+                BorderColor(WARNING_RED.with_alpha(0.0)),
+            ));
+            parent
+                .spawn((
+                    BossWarningBanner,
+                    Node {
+                        position_type: PositionType::Absolute,
+                        width: Val::Percent(100.0),
+                        top: Val::Percent(18.0),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        display: Display::None,
+                        ..default()
+                    },
+                ))
+                .with_children(|banner| {
+                    banner.spawn((
+                        BossWarningText,
+                        Text::new("WARNING"),
+                        TextFont {
+                            font_size: 56.0,
+                            ..default()
+                        },
+                        TextColor(WARNING_RED),
+                    ));
+                    banner.spawn((
+                        Text::new("BOSS INCOMING"),
+                        TextFont {
+                            font_size: metrics.font(18.0),
+                            ..default()
+                        },
+                        TextColor(Color::srgb(1.0, 0.75, 0.6)),
+                    ));
+                });
+
+            // === FACTION EMBLEM: Bottom-right corner, above the meters ===
+            parent
+                .spawn(Node {
+                    position_type: PositionType::Absolute,
+                    bottom: Val::Px(90.0),
+                    right: Val::Px(10.0),
+                    ..default()
+                })
+                .with_children(|corner| {
+                    super::spawn_faction_emblem(corner, &emblems, session.player_faction, 36.0);
+                });
+
+            // === BOTTOM BAR: Meters (health is in the capacitor wheel or classic bars) ===
+            parent
+                .spawn(Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Px(80.0),
+                    flex_direction: FlexDirection::Row,
+                    justify_content: JustifyContent::SpaceBetween,
+                    padding: UiRect::all(Val::Px(10.0)),
+                    ..default()
+                })
+                .with_children(|bottom| {
+                    // Left side: Status meters (Heat, Berserk)
+                    bottom
+                        .spawn(Node {
+                            flex_direction: FlexDirection::Column,
+                            row_gap: Val::Px(3.0),
+                            align_items: AlignItems::FlexStart,
+                            ..default()
+                        })
+                        .with_children(|left| {
+                            // Heat meter (orange/red)
+                            spawn_health_bar(
+                                left,
+                                HeatBar,
+                                Color::srgb(1.0, 0.5, 0.0),
+                                "HEAT",
+                                frame,
+                                metrics,
+                            );
+                            // Overload status (hidden while heat is low)
+                            left.spawn((
+                                OverloadText,
+                                Text::new(""),
+                                TextFont {
+                                    font_size: metrics.font(10.0),
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(1.0, 0.5, 0.0)),
+                            ));
+                            // Berserk meter (purple)
+                            spawn_health_bar(
+                                left,
+                                BerserkBar,
+                                Color::srgb(0.8, 0.2, 0.8),
+                                "BERSERK",
+                                frame,
+                                metrics,
+                            );
+                            // Ship ability indicator (blue/cyan)
+                            spawn_ability_indicator(left, metrics);
+                            // Shield bubble time left or recharge
+                            left.spawn((
+                                BubbleText,
+                                Text::new(""),
+                                TextFont {
+                                    font_size: metrics.font(10.0),
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.45, 0.8, 1.0)),
+                            ));
+                        });
+
+                    // Center: Classic status bars, where the wheel sits otherwise
+                    bottom
+                        .spawn((
+                            ClassicStatusBars,
+                            Node {
+                                flex_direction: FlexDirection::Column,
+                                row_gap: Val::Px(3.0),
+                                align_items: AlignItems::FlexStart,
+                                display: if metrics.shows_wheel(*hud_style) {
+                                    Display::None
+                                } else {
+                                    Display::Flex
+                                },
+                                ..default()
+                            },
+                        ))
+                        .with_children(|center| {
+                            spawn_health_bar(
+                                center,
+                                ShieldBar,
+                                Color::srgb(0.4, 0.7, 1.0),
+                                "SHLD",
+                                frame,
+                                metrics,
+                            );
+                            spawn_health_bar(
+                                center,
+                                ArmorBar,
+                                Color::srgb(0.9, 0.6, 0.3),
+                                "ARMR",
+                                frame,
+                                metrics,
+                            );
+                            spawn_health_bar(
+                                center,
+                                HullBar,
+                                Color::srgb(0.8, 0.8, 0.8),
+                                "HULL",
+                                frame,
+                                metrics,
+                            );
+                            spawn_health_bar(
+                                center,
+                                CapacitorBar,
+                                Color::srgb(0.95, 0.85, 0.55),
+                                "CAP",
+                                frame,
+                                metrics,
+                            );
+                        });
+
+                    // Center: Spacer to push wingman gauge right
+                    bottom
+                        .spawn((
+                            WingmanGauge,
                             Node {
                                 flex_direction: FlexDirection::Column,
                                 row_gap: Val::Px(4.0),
@@ -483,12 +1230,13 @@ fn spawn_hud(mut commands: Commands) {
                         .with_children(|right| {
                             // Label
                             right.spawn((
+                                WingmanTierText,
                                 Text::new("WINGMAN"),
                                 TextFont {
                                     font_size: 12.0,
                                     ..default()
                                 },
-                                TextColor(Color::srgb(0.8, 0.6, 0.3)),
+                                TextColor(theme.accent),
                             ));
 
                             // Progress bar container
@@ -500,8 +1248,8 @@ fn spawn_hud(mut commands: Commands) {
                                         border: UiRect::all(Val::Px(1.0)),
                                         ..default()
                                     },
-                                    BackgroundColor(Color::srgba(0.15, 0.1, 0.05, 0.9)),
-                                    BorderColor(Color::srgb(0.5, 0.35, 0.2)),
+                                    BackgroundColor(theme.panel_background()),
+                                    BorderColor(frame),
                                     BorderRadius::all(Val::Px(2.0)),
                                 ))
                                 .with_children(|bar| {
@@ -512,7 +1260,7 @@ fn spawn_hud(mut commands: Commands) {
                                             height: Val::Percent(100.0),
                                             ..default()
                                         },
-                                        BackgroundColor(Color::srgb(0.8, 0.5, 0.2)),
+                                        BackgroundColor(theme.primary),
                                         BorderRadius::all(Val::Px(2.0)),
                                     ));
                                 });
@@ -522,7 +1270,7 @@ fn spawn_hud(mut commands: Commands) {
                                 WingmanCountText,
                                 Text::new("0/15"),
                                 TextFont {
-                                    font_size: 11.0,
+                                    font_size: metrics.font(11.0),
                                     ..default()
                                 },
                                 TextColor(Color::srgb(0.6, 0.5, 0.35)),
@@ -555,21 +1303,24 @@ fn spawn_hud(mut commands: Commands) {
                 align_items: AlignItems::FlexStart,
                 padding: UiRect::all(Val::Px(15.0)),
                 column_gap: Val::Px(15.0),
+                border: UiRect::all(Val::Px(1.0)),
                 display: Display::None, // Hidden by default
                 ..default()
             },
-            BackgroundColor(Color::srgba(0.05, 0.05, 0.1, 0.9)),
+            BackgroundColor(theme.panel_background()),
+            BorderColor(theme.primary.with_alpha(0.6)),
             BorderRadius::all(Val::Px(8.0)),
         ))
         .with_children(|dialogue| {
-            // Elder portrait placeholder (rust-colored square)
+            // Speaker portrait placeholder (tinted per speaker)
             dialogue.spawn((
+                DialoguePortrait,
                 Node {
                     width: Val::Px(64.0),
                     height: Val::Px(64.0),
                     ..default()
                 },
-                BackgroundColor(Color::srgb(0.6, 0.35, 0.2)), // Rust/bronze color for Minmatar
+                BackgroundColor(NARRATOR_PORTRAIT),
                 BorderRadius::all(Val::Px(4.0)),
             ));
 
@@ -590,7 +1341,7 @@ fn spawn_hud(mut commands: Commands) {
                             font_size: 14.0,
                             ..default()
                         },
-                        TextColor(Color::srgb(0.8, 0.6, 0.4)), // Rust/amber color
+                        TextColor(theme.accent),
                     ));
 
                     // Dialogue text
@@ -609,11 +1360,18 @@ fn spawn_hud(mut commands: Commands) {
     info!("HUD spawned");
 }
 
-fn spawn_health_bar<M: Component>(parent: &mut ChildBuilder, marker: M, color: Color, label: &str) {
+fn spawn_health_bar<M: Component>(
+    parent: &mut ChildBuilder,
+    marker: M,
+    color: Color,
+    label: &str,
+    frame: Color,
+    metrics: HudMetrics,
+) {
     parent
         .spawn(Node {
-            width: Val::Px(200.0),
-            height: Val::Px(12.0),
+            width: Val::Px(metrics.bar(200.0)),
+            height: Val::Px(metrics.bar(12.0)),
             flex_direction: FlexDirection::Row,
             align_items: AlignItems::Center,
             column_gap: Val::Px(5.0),
@@ -624,7 +1382,7 @@ fn spawn_health_bar<M: Component>(parent: &mut ChildBuilder, marker: M, color: C
             parent.spawn((
                 Text::new(label),
                 TextFont {
-                    font_size: 10.0,
+                    font_size: metrics.font(10.0),
                     ..default()
                 },
                 TextColor(color),
@@ -634,11 +1392,13 @@ fn spawn_health_bar<M: Component>(parent: &mut ChildBuilder, marker: M, color: C
             parent
                 .spawn((
                     Node {
-                        width: Val::Px(150.0),
-                        height: Val::Px(8.0),
+                        width: Val::Px(metrics.bar(150.0)),
+                        height: Val::Px(metrics.bar(8.0)),
+                        border: UiRect::all(Val::Px(1.0)),
                         ..default()
                     },
                     BackgroundColor(Color::srgba(0.2, 0.2, 0.2, 0.8)),
+                    BorderColor(frame),
                 ))
                 .with_children(|parent| {
                     // Bar fill
@@ -662,12 +1422,14 @@ fn spawn_powerup_status_box(
     label: &str,
     color: Color,
     _max_duration: f32,
+    metrics: HudMetrics,
 ) {
     // Get the appropriate marker component based on type
     let (marker_overdrive, marker_damage, marker_invuln) = match powerup_type {
         PowerupType::Overdrive => (Some(OverdriveIndicator), None, None),
         PowerupType::DamageBoost => (None, Some(DamageBoostIndicator), None),
         PowerupType::Invulnerability => (None, None, Some(InvulnIndicator)),
+        PowerupType::Leech => (None, None, None),
     };
 
     // Main container - hidden by default
@@ -723,7 +1485,7 @@ fn spawn_powerup_status_box(
                 right.spawn((
                     Text::new(label),
                     TextFont {
-                        font_size: 11.0,
+                        font_size: metrics.font(11.0),
                         ..default()
                     },
                     TextColor(color),
@@ -757,9 +1519,76 @@ fn spawn_powerup_status_box(
     });
 }
 
-fn update_score_display(score: Res<ScoreSystem>, mut query: Query<&mut Text, With<ScoreText>>) {
+/// Spawn a debuff box: colored icon plus "NAME xN  T.Ts" label
+fn spawn_debuff_indicator(parent: &mut ChildBuilder, kind: StatusKind, metrics: HudMetrics) {
+    parent
+        .spawn((
+            DebuffIndicator { kind },
+            Node {
+                width: Val::Px(140.0),
+                height: Val::Px(24.0),
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(4.0)),
+                column_gap: Val::Px(6.0),
+                border: UiRect::all(Val::Px(1.0)),
+                display: Display::None, // Hidden until afflicted
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.2, 0.05, 0.05, 0.9)),
+            BorderColor(kind.color().with_alpha(0.6)),
+            BorderRadius::all(Val::Px(4.0)),
+        ))
+        .with_children(|row| {
+            row.spawn((
+                Node {
+                    width: Val::Px(12.0),
+                    height: Val::Px(12.0),
+                    ..default()
+                },
+                BackgroundColor(kind.color()),
+                BorderRadius::all(Val::Px(2.0)),
+            ));
+            row.spawn((
+                DebuffText { kind },
+                Text::new(kind.name()),
+                TextFont {
+                    font_size: metrics.font(11.0),
+                    ..default()
+                },
+                TextColor(kind.color()),
+            ));
+        });
+}
+
+fn update_score_display(
+    score: Res<ScoreSystem>,
+    mut query: Query<&mut Text, With<ScoreText>>,
+    mut buf: Local<String>,
+) {
+    for mut text in query.iter_mut() {
+        write_label(&mut text, &mut buf, format_args!("SCORE: {}", score.score));
+    }
+}
+
+/// The multiplier scores are actually paid at: difficulty, mutators and
+/// New Game+ together
+fn update_difficulty_chip(
+    difficulty: Res<Difficulty>,
+    score: Res<ScoreSystem>,
+    mut query: Query<&mut Text, With<DifficultyChipText>>,
+    mut buf: Local<String>,
+) {
     for mut text in query.iter_mut() {
-        **text = format!("SCORE: {}", score.score);
+        write_label(
+            &mut text,
+            &mut buf,
+            format_args!(
+                "{}  x{:.1} SCORE",
+                difficulty.name(),
+                score.difficulty_multiplier
+            ),
+        );
     }
 }
 
@@ -767,25 +1596,31 @@ fn update_combo_display(
     score: Res<ScoreSystem>,
     mut combo_query: Query<(&mut Text, &mut TextColor), (With<ComboText>, Without<GradeText>)>,
     mut grade_query: Query<(&mut Text, &mut TextColor), (With<GradeText>, Without<ComboText>)>,
+    mut buf: Local<String>,
 ) {
+    // Color based on multiplier
+    let combo_color = if score.multiplier >= 10.0 {
+        Color::srgb(1.0, 0.3, 0.3)
+    } else if score.multiplier >= 5.0 {
+        Color::srgb(1.0, 0.6, 0.2)
+    } else if score.multiplier >= 2.0 {
+        Color::srgb(1.0, 0.9, 0.3)
+    } else {
+        Color::WHITE
+    };
     for (mut text, mut color) in combo_query.iter_mut() {
-        **text = format!("x{:.1}", score.multiplier);
-        // Color based on multiplier
-        color.0 = if score.multiplier >= 10.0 {
-            Color::srgb(1.0, 0.3, 0.3)
-        } else if score.multiplier >= 5.0 {
-            Color::srgb(1.0, 0.6, 0.2)
-        } else if score.multiplier >= 2.0 {
-            Color::srgb(1.0, 0.9, 0.3)
-        } else {
-            Color::WHITE
-        };
+        write_label(
+            &mut text,
+            &mut buf,
+            format_args!("x{:.1}", score.multiplier),
+        );
+        set_color(&mut color, combo_color);
     }
 
+    let grade = score.get_grade();
     for (mut text, mut text_color) in grade_query.iter_mut() {
-        let grade = score.get_grade();
-        **text = grade.as_str().to_string();
-        text_color.0 = grade.color();
+        write_label(&mut text, &mut buf, format_args!("{}", grade.as_str()));
+        set_color(&mut text_color, grade.color());
     }
 }
 
@@ -794,28 +1629,86 @@ fn update_berserk_meter(
     mut query: Query<(&mut Node, &mut BackgroundColor), With<BerserkBar>>,
 ) {
     for (mut node, mut bg) in query.iter_mut() {
+        set_fill(&mut node, berserk.progress() * 100.0);
         if berserk.is_active {
             // Pulsing effect when active - show remaining time
             let pulse = (berserk.timer * 10.0).sin().abs();
-            node.width = Val::Percent(berserk.progress() * 100.0);
             bg.0 = Color::srgb(0.8 + pulse * 0.2, 0.2, 0.8 + pulse * 0.2);
         } else {
             // Show proximity kills progress toward berserk
-            node.width = Val::Percent(berserk.progress() * 100.0);
-            bg.0 = Color::srgb(0.8, 0.2, 0.8);
+            bg.set_if_neq(BackgroundColor(Color::srgb(0.8, 0.2, 0.8)));
         }
     }
 }
 
 /// Update heat display bar
 fn update_heat_display(
+    time: Res<Time>,
     heat_system: Res<ComboHeatSystem>,
     mut query: Query<(&mut Node, &mut BackgroundColor), With<HeatBar>>,
 ) {
     for (mut node, mut bg) in query.iter_mut() {
-        node.width = Val::Percent(heat_system.heat);
-        // Color changes with heat level
-        bg.0 = heat_system.heat_level.color();
+        if heat_system.is_overloaded() {
+            // Bar drains with the surge, flickering white-hot
+            let pulse = (time.elapsed_secs() * 12.0).sin() * 0.5 + 0.5;
+            set_fill(&mut node, heat_system.overload_percent() * 100.0);
+            bg.0 = Color::srgb(1.0, 0.6 + pulse * 0.4, 0.2 + pulse * 0.6);
+        } else {
+            set_fill(&mut node, heat_system.heat);
+            // Color changes with heat level
+            bg.set_if_neq(BackgroundColor(heat_system.heat_level.color()));
+        }
+    }
+}
+
+/// Update overload status text under the heat bar
+fn update_overload_display(
+    time: Res<Time>,
+    heat_system: Res<ComboHeatSystem>,
+    mut query: Query<(&mut Text, &mut TextColor), With<OverloadText>>,
+    mut buf: Local<String>,
+) {
+    for (mut text, mut color) in query.iter_mut() {
+        if heat_system.is_overloaded() {
+            let timer = heat_system.overload_timer;
+            write_label(&mut text, &mut buf, format_args!("OVERLOAD {:.1}s", timer));
+            set_color(&mut color, Color::srgb(1.0, 0.9, 0.5));
+        } else if heat_system.is_burned_out() {
+            let timer = heat_system.burnout_timer;
+            write_label(&mut text, &mut buf, format_args!("BURNOUT {:.1}s", timer));
+            set_color(&mut color, Color::srgb(0.7, 0.3, 0.2));
+        } else if heat_system.can_overload() {
+            let pulse = (time.elapsed_secs() * 8.0).sin() * 0.5 + 0.5;
+            write_label(&mut text, &mut buf, format_args!("OVERLOAD READY [H/LT]"));
+            color.0 = Color::srgb(1.0, 0.3 + pulse * 0.4, 0.0);
+        } else if !text.is_empty() {
+            text.0.clear();
+        }
+    }
+}
+
+/// Update the shield bubble timer under the ability indicator
+fn update_bubble_display(
+    state: Res<ShieldBubbleState>,
+    bubbles: Query<&ShieldBubble>,
+    mut query: Query<(&mut Text, &mut TextColor), With<BubbleText>>,
+    mut buf: Local<String>,
+) {
+    let remaining = bubbles.iter().map(|b| b.remaining).reduce(f32::max);
+    let label = BubbleLabel {
+        remaining,
+        cooldown: state.cooldown,
+    };
+    let color = if remaining.is_some() {
+        Color::srgb(0.45, 0.8, 1.0)
+    } else if state.ready() {
+        Color::srgb(0.3, 0.9, 1.0)
+    } else {
+        Color::srgb(0.35, 0.45, 0.55)
+    };
+    for (mut text, mut text_color) in query.iter_mut() {
+        write_label(&mut text, &mut buf, format_args!("{}", label));
+        set_color(&mut text_color, color);
     }
 }
 
@@ -823,14 +1716,20 @@ fn update_heat_display(
 fn update_combo_kills(
     heat_system: Res<ComboHeatSystem>,
     mut query: Query<&mut Text, With<ComboKillsText>>,
+    mut buf: Local<String>,
 ) {
+    let count = heat_system.combo_count;
     for mut text in query.iter_mut() {
         if let Some(tier_name) = heat_system.combo_tier_name() {
-            **text = format!("{} x{}", tier_name, heat_system.combo_count);
-        } else if heat_system.combo_count > 0 {
-            **text = format!("{}x", heat_system.combo_count);
-        } else {
-            **text = String::new();
+            write_label(
+                &mut text,
+                &mut buf,
+                format_args!("{} x{}", tier_name, count),
+            );
+        } else if count > 0 {
+            write_label(&mut text, &mut buf, format_args!("{}x", count));
+        } else if !text.is_empty() {
+            text.0.clear();
         }
     }
 }
@@ -849,43 +1748,82 @@ fn update_combo_timer_bar(
 
     // Show/hide container
     for mut node in container_query.iter_mut() {
-        node.display = if has_combo {
-            Display::Flex
-        } else {
-            Display::None
-        };
+        set_shown(&mut node, has_combo);
     }
 
+    // Color changes as timer runs low
+    let color = if timer_percent < 0.3 {
+        Color::srgb(1.0, 0.3, 0.2) // Red when low
+    } else if timer_percent < 0.5 {
+        Color::srgb(1.0, 0.6, 0.2) // Orange when getting low
+    } else {
+        Color::srgb(1.0, 0.8, 0.2) // Gold when healthy
+    };
+
     // Update fill width and color
     for (mut node, mut bg) in fill_query.iter_mut() {
-        node.width = Val::Percent(timer_percent * 100.0);
-
-        // Color changes as timer runs low
-        bg.0 = if timer_percent < 0.3 {
-            Color::srgb(1.0, 0.3, 0.2) // Red when low
-        } else if timer_percent < 0.5 {
-            Color::srgb(1.0, 0.6, 0.2) // Orange when getting low
-        } else {
-            Color::srgb(1.0, 0.8, 0.2) // Gold when healthy
-        };
+        set_fill(&mut node, timer_percent * 100.0);
+        bg.set_if_neq(BackgroundColor(color));
     }
 }
 
 /// Update wave display (with stage info)
-fn update_wave_display(campaign: Res<CampaignState>, mut query: Query<&mut Text, With<WaveText>>) {
+fn update_wave_display(
+    campaign: Res<CampaignState>,
+    mut query: Query<&mut Text, With<WaveText>>,
+    mut buf: Local<String>,
+) {
+    // The wave being fought until its trigger is met, then the next one
+    let wave = if campaign.wave_in_progress() {
+        campaign.spawned_wave()
+    } else {
+        campaign.current_wave
+    };
+    let total_waves = campaign.enemy_waves() + 1;
     for mut text in query.iter_mut() {
-        if let Some(mission) = campaign.current_mission() {
-            if campaign.is_boss_wave() {
-                **text = format!(
-                    "WAVE {}/{} - BOSS",
-                    campaign.current_wave,
-                    mission.enemy_waves + 1
-                );
-            } else {
-                **text = format!("WAVE {}/{}", campaign.current_wave, mission.enemy_waves + 1);
-            }
+        if campaign.current_mission().is_none() {
+            write_label(&mut text, &mut buf, format_args!("WAVE {}", wave));
+        } else if campaign.is_boss_wave() && !campaign.wave_in_progress() {
+            write_label(
+                &mut text,
+                &mut buf,
+                format_args!("WAVE {}/{} - BOSS", wave, total_waves),
+            );
         } else {
-            **text = format!("WAVE {}", campaign.current_wave);
+            write_label(
+                &mut text,
+                &mut buf,
+                format_args!("WAVE {}/{}{}", wave, total_waves, WaveGoal(&campaign)),
+            );
+        }
+    }
+}
+
+/// What the running wave still needs, after its number (nothing for
+/// kill-all waves)
+struct WaveGoal<'a>(&'a CampaignState);
+
+impl std::fmt::Display for WaveGoal<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let campaign = self.0;
+        if !campaign.wave_in_progress() {
+            return Ok(());
+        }
+        let trigger = campaign.wave_trigger(campaign.spawned_wave());
+        match trigger {
+            WaveTrigger::KillAll => Ok(()),
+            WaveTrigger::KillPercent(_) => write!(
+                f,
+                " - KILLS {}/{}",
+                campaign.wave_destroyed,
+                trigger.kills_needed(campaign.wave_spawned)
+            ),
+            WaveTrigger::Survive(seconds) => write!(
+                f,
+                " - HOLD {:.0}s",
+                (seconds - campaign.wave_timer).max(0.0).ceil()
+            ),
+            WaveTrigger::DestroyTarget => write!(f, " - DESTROY TARGET"),
         }
     }
 }
@@ -894,76 +1832,130 @@ fn update_wave_display(campaign: Res<CampaignState>, mut query: Query<&mut Text,
 fn update_mission_display(
     campaign: Res<CampaignState>,
     score: Res<ScoreSystem>,
-    mut mission_query: Query<
-        &mut Text,
-        (
-            With<MissionNameText>,
-            Without<ObjectiveText>,
-            Without<SoulsText>,
-        ),
-    >,
-    mut objective_query: Query<
-        (&mut Text, &mut TextColor),
-        (
-            With<ObjectiveText>,
-            Without<MissionNameText>,
-            Without<SoulsText>,
-        ),
-    >,
-    mut souls_query: Query<
-        &mut Text,
-        (
-            With<SoulsText>,
-            Without<MissionNameText>,
-            Without<ObjectiveText>,
-        ),
-    >,
+    mut mission_query: Query<&mut Text, (With<MissionNameText>, Without<SoulsText>)>,
+    mut souls_query: Query<&mut Text, (With<SoulsText>, Without<MissionNameText>)>,
+    mut buf: Local<String>,
 ) {
     // Update mission name
     for mut text in mission_query.iter_mut() {
         if let Some(mission) = campaign.current_mission() {
-            **text = format!(
-                "M{}: {} - {}",
-                campaign.mission_number(),
-                mission.name,
-                campaign.act.name()
+            write_label(
+                &mut text,
+                &mut buf,
+                format_args!(
+                    "M{}: {} - {}",
+                    campaign.mission_number(),
+                    mission.name,
+                    campaign.act.name()
+                ),
             );
-        } else {
-            **text = String::new();
-        }
-    }
-
-    // Update objective
-    for (mut text, mut color) in objective_query.iter_mut() {
-        if let Some(mission) = campaign.current_mission() {
-            if campaign.primary_complete {
-                **text = format!("✓ {}", mission.primary_objective);
-                color.0 = Color::srgb(0.3, 1.0, 0.3); // Bright green when complete
-            } else {
-                **text = format!("◯ {}", mission.primary_objective);
-                color.0 = Color::srgb(0.5, 0.8, 0.5); // Dim green when incomplete
-            }
-        } else {
-            **text = String::new();
+        } else if !text.is_empty() {
+            text.0.clear();
         }
     }
 
     // Update souls liberated
     for mut text in souls_query.iter_mut() {
         if campaign.in_mission {
-            let bonus = if let Some(mission) = campaign.current_mission() {
-                if campaign.mission_souls >= mission.souls_to_liberate {
-                    " ✓"
-                } else {
-                    ""
-                }
+            let bonus = if campaign.current_mission().is_some()
+                && campaign.mission_souls >= campaign.souls_to_liberate()
+            {
+                " ✓"
             } else {
                 ""
             };
-            **text = format!("SOULS LIBERATED: {}{}", score.souls_liberated, bonus);
-        } else {
-            **text = String::new();
+            write_label(
+                &mut text,
+                &mut buf,
+                format_args!("SOULS LIBERATED: {}{}", score.souls_liberated, bonus),
+            );
+        } else if !text.is_empty() {
+            text.0.clear();
+        }
+    }
+}
+
+/// Show the escorted transport's or defended station's health
+fn update_ally_health_bar(
+    target_query: Query<&ProtectedTarget>,
+    mut container_query: Query<&mut Node, With<AllyHealthContainer>>,
+    mut fill_query: Query<
+        (&mut Node, &mut BackgroundColor),
+        (With<AllyHealthFill>, Without<AllyHealthContainer>),
+    >,
+    mut name_query: Query<&mut Text, With<AllyNameText>>,
+    mut buf: Local<String>,
+) {
+    // Several allies at once is rare; track whichever is worst off
+    let target = target_query
+        .iter()
+        .min_by(|a, b| a.health_fraction().total_cmp(&b.health_fraction()));
+
+    for mut node in container_query.iter_mut() {
+        set_shown(&mut node, target.is_some());
+    }
+    let Some(target) = target else {
+        return;
+    };
+
+    let fraction = target.health_fraction();
+    let fill_color = if fraction < 0.3 {
+        Color::srgb(0.95, 0.35, 0.2) // Critical
+    } else {
+        Color::srgb(0.3, 0.85, 0.4)
+    };
+    for (mut node, mut color) in fill_query.iter_mut() {
+        set_fill(&mut node, fraction * 100.0);
+        color.set_if_neq(BackgroundColor(fill_color));
+    }
+    for mut text in name_query.iter_mut() {
+        write_label(
+            &mut text,
+            &mut buf,
+            format_args!("{} {:.0}%", target.kind.name(), fraction * 100.0),
+        );
+    }
+}
+
+/// Fill the objective list; completed lines pulse and flash white
+fn update_objective_panel(
+    campaign: Res<CampaignState>,
+    tracker: Res<ObjectiveTracker>,
+    mut row_query: Query<(&ObjectiveRow, &mut Text, &mut TextFont, &mut TextColor)>,
+    mut buf: Local<String>,
+) {
+    let visible: Vec<&Objective> = tracker
+        .objectives()
+        .iter()
+        .filter(|o| o.is_visible())
+        .collect();
+
+    for (row, mut text, mut font, mut color) in row_query.iter_mut() {
+        let Some(objective) = visible.get(row.0) else {
+            if !text.is_empty() {
+                text.0.clear();
+            }
+            continue;
+        };
+
+        let line = objective.display_text(campaign.mission_timer);
+        write_label(&mut text, &mut buf, format_args!("{}", line));
+
+        let base = match (objective.status, objective.def.tier) {
+            (ObjectiveStatus::Failed, _) => Color::srgb(0.6, 0.3, 0.3), // Muted red
+            (ObjectiveStatus::Complete, _) => Color::srgb(0.3, 1.0, 0.3), // Bright green
+            _ if objective.contested => Color::srgb(1.0, 0.5, 0.25),    // Contested capture
+            (_, ObjectiveTier::Primary) => Color::srgb(0.5, 0.8, 0.5),  // Dim green
+            _ => Color::srgb(0.8, 0.7, 0.4),                            // Gold for bonus
+        };
+
+        // Checkmark animation: swell then settle while fading from white
+        let t = objective.flash / OBJECTIVE_FLASH_TIME;
+        let size = OBJECTIVE_FONT_SIZE * (1.0 + 0.5 * (t * std::f32::consts::PI).sin());
+        if font.font_size != size {
+            font.font_size = size;
         }
+        set_color(&mut color, base.mix(&Color::WHITE, t));
     }
 }
 
@@ -992,6 +1984,7 @@ fn update_powerup_indicators(
             PowerupType::Overdrive => (effects.overdrive_timer, OVERDRIVE_MAX),
             PowerupType::DamageBoost => (effects.damage_boost_timer, DAMAGE_BOOST_MAX),
             PowerupType::Invulnerability => (effects.invuln_timer, INVULN_MAX),
+            PowerupType::Leech => (effects.leech_timer, LEECH_DURATION),
         }
     };
 
@@ -999,18 +1992,17 @@ fn update_powerup_indicators(
     for (status_box, mut node, mut bg_color) in status_box_query.iter_mut() {
         let (timer, _max) = get_timer(status_box.powerup_type);
 
-        if timer > 0.0 {
-            node.display = Display::Flex;
+        set_shown(&mut node, timer > 0.0);
+        if timer <= 0.0 {
+            continue;
+        }
 
-            // Pulse background when timer is low (< 1.5 seconds)
-            if timer < 1.5 {
-                let pulse = (time.elapsed_secs() * 8.0).sin() * 0.5 + 0.5;
-                bg_color.0 = Color::srgba(0.3 + pulse * 0.2, 0.1, 0.1, 0.95);
-            } else {
-                bg_color.0 = Color::srgba(0.1, 0.1, 0.15, 0.9);
-            }
+        // Pulse background when timer is low (< 1.5 seconds)
+        if timer < 1.5 {
+            let pulse = (time.elapsed_secs() * 8.0).sin() * 0.5 + 0.5;
+            bg_color.0 = Color::srgba(0.3 + pulse * 0.2, 0.1, 0.1, 0.95);
         } else {
-            node.display = Display::None;
+            bg_color.set_if_neq(BackgroundColor(Color::srgba(0.1, 0.1, 0.15, 0.9)));
         }
     }
 
@@ -1019,8 +2011,7 @@ fn update_powerup_indicators(
         let (timer, max) = get_timer(timer_bar.powerup_type);
 
         if timer > 0.0 {
-            let percent = (timer / max * 100.0).clamp(0.0, 100.0);
-            node.width = Val::Percent(percent);
+            set_fill(&mut node, (timer / max * 100.0).clamp(0.0, 100.0));
 
             // Color changes when timer is low
             if timer < 1.5 {
@@ -1031,51 +2022,261 @@ fn update_powerup_indicators(
     }
 }
 
+/// Show the player's active debuffs with stacks and time left
+fn update_debuff_indicators(
+    player_query: Query<Option<&StatusEffects>, With<Player>>,
+    mut box_query: Query<(&DebuffIndicator, &mut Node)>,
+    mut text_query: Query<(&DebuffText, &mut Text)>,
+    mut buf: Local<String>,
+) {
+    let status = player_query.get_single().ok().flatten();
+    let active = |kind: StatusKind| status.and_then(|s| s.get(kind));
+
+    for (indicator, mut node) in box_query.iter_mut() {
+        set_shown(&mut node, active(indicator.kind).is_some());
+    }
+
+    for (label, mut text) in text_query.iter_mut() {
+        let Some(effect) = active(label.kind) else {
+            continue;
+        };
+        let name = label.kind.name();
+        if effect.stacks > 1 {
+            write_label(
+                &mut text,
+                &mut buf,
+                format_args!("{} x{}  {:.1}s", name, effect.stacks, effect.remaining),
+            );
+        } else {
+            write_label(
+                &mut text,
+                &mut buf,
+                format_args!("{}  {:.1}s", name, effect.remaining),
+            );
+        }
+    }
+}
+
 /// Update boss health bar
 fn update_boss_health_bar(
-    boss_query: Query<(&BossData, &BossState), With<Boss>>,
+    time: Res<Time>,
+    boss_query: Query<(&BossData, &BossState, Option<&BossShieldGate>), With<Boss>>,
     mut container_query: Query<&mut Node, With<BossHealthContainer>>,
-    mut fill_query: Query<&mut Node, (With<BossHealthFill>, Without<BossHealthContainer>)>,
+    mut fill_query: Query<
+        (&mut Node, &mut BackgroundColor),
+        (With<BossHealthFill>, Without<BossHealthContainer>),
+    >,
+    mut shield_query: Query<
+        (&mut Node, &mut BackgroundColor),
+        (
+            With<BossShieldOverlay>,
+            Without<BossHealthContainer>,
+            Without<BossHealthFill>,
+        ),
+    >,
     mut name_query: Query<&mut Text, With<BossNameText>>,
+    mut buf: Local<String>,
 ) {
     let has_boss = boss_query.get_single().is_ok();
 
     // Show/hide boss health bar
     for mut node in container_query.iter_mut() {
-        node.display = if has_boss {
-            Display::Flex
-        } else {
-            Display::None
-        };
+        set_shown(&mut node, has_boss);
     }
 
-    if let Ok((data, state)) = boss_query.get_single() {
-        // Update health bar fill
-        for mut node in fill_query.iter_mut() {
-            let health_percent = (data.health / data.max_health * 100.0).max(0.0);
-            node.width = Val::Percent(health_percent);
+    if let Ok((data, state, gate)) = boss_query.get_single() {
+        // Update health bar fill, colored by phase
+        let health_percent = (data.health / data.max_health * 100.0).max(0.0);
+        for (mut node, mut bg) in fill_query.iter_mut() {
+            set_fill(&mut node, health_percent);
+            bg.set_if_neq(BackgroundColor(boss_phase_color(data.current_phase)));
+        }
+
+        // Pulse the shield over the bar while a gate holds
+        for (mut node, mut bg) in shield_query.iter_mut() {
+            set_shown(&mut node, gate.is_some());
+            let pulse = (time.elapsed_secs() * 4.0).sin() * 0.5 + 0.5;
+            bg.0 = bg.0.with_alpha(0.25 + pulse * 0.2);
         }
 
         // Update boss name
+        let phase_info = if data.total_phases > 1 {
+            format!(" (Phase {}/{})", data.current_phase, data.total_phases)
+        } else {
+            String::new()
+        };
         for mut text in name_query.iter_mut() {
-            let phase_info = if data.total_phases > 1 {
-                format!(" (Phase {}/{})", data.current_phase, data.total_phases)
-            } else {
-                String::new()
+            let (name, title) = (&data.name, &data.title);
+            let label = match *state {
+                BossState::Intro => format_args!("{} - {}", name, title),
+                BossState::Battle => match gate {
+                    Some(gate) if gate.total > 0 => format_args!(
+                        "{}{} - SHIELDED: CLEAR DRONES ({}/{})",
+                        name,
+                        phase_info,
+                        gate.total - gate.remaining,
+                        gate.total
+                    ),
+                    Some(_) => format_args!("{}{} - SHIELDED", name, phase_info),
+                    None => format_args!("{}{}", name, phase_info),
+                },
+                BossState::PhaseTransition => format_args!("{}{} - SHIELDED", name, phase_info),
+                BossState::Defeated => format_args!("{} DEFEATED!", name),
             };
+            write_label(&mut text, &mut buf, label);
+        }
+    }
+}
+
+/// Place a tick where each later phase begins; ticks already passed dim
+fn update_boss_phase_ticks(
+    boss_query: Query<&BossData, With<Boss>>,
+    mut tick_query: Query<(&BossPhaseTick, &mut Node, &mut BackgroundColor)>,
+) {
+    let Ok(data) = boss_query.get_single() else {
+        return;
+    };
+    for (tick, mut node, mut bg) in tick_query.iter_mut() {
+        if tick.0 > data.total_phases {
+            node.display = Display::None;
+            continue;
+        }
+        node.display = Display::Flex;
+        let threshold = get_phase_threshold(tick.0, data.total_phases);
+        node.left = Val::Percent(threshold * 100.0);
+        bg.0 = if tick.0 <= data.current_phase {
+            Color::srgba(1.0, 1.0, 1.0, 0.25)
+        } else {
+            Color::WHITE
+        };
+    }
+}
+
+/// Break the bar apart at the tick a boss just crossed
+fn shatter_boss_bar_on_phase(
+    mut commands: Commands,
+    mut phase_events: EventReader<BossPhaseChangeEvent>,
+    track_query: Query<Entity, With<BossHealthTrack>>,
+) {
+    let Ok(track) = track_query.get_single() else {
+        phase_events.clear();
+        return;
+    };
+    for event in phase_events.read() {
+        let at = get_phase_threshold(event.phase, event.total_phases) * 100.0;
+        let color = boss_phase_color(event.phase - 1);
+        commands.entity(track).with_children(|bar| {
+            for i in 0..10 {
+                let spread = (i as f32 / 9.0 - 0.5) * 6.0;
+                bar.spawn((
+                    BossBarShard {
+                        x: at + spread,
+                        y: 4.0,
+                        velocity: Vec2::new(
+                            spread * 4.0 + (fastrand::f32() - 0.5) * 10.0,
+                            -40.0 - fastrand::f32() * 50.0,
+                        ),
+                        lifetime: SHARD_LIFETIME,
+                    },
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Percent(at + spread),
+                        top: Val::Px(4.0),
+                        width: Val::Px(4.0 + fastrand::f32() * 5.0),
+                        height: Val::Px(4.0 + fastrand::f32() * 6.0),
+                        ..default()
+                    },
+                    BackgroundColor(color),
+                ));
+            }
+        });
+    }
+}
+
+/// Shards pop up, fall and fade
+fn update_boss_bar_shards(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut BossBarShard, &mut Node, &mut BackgroundColor)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut shard, mut node, mut bg) in query.iter_mut() {
+        shard.lifetime -= dt;
+        if shard.lifetime <= 0.0 {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+        shard.velocity.y += 300.0 * dt;
+        shard.x += shard.velocity.x * dt;
+        shard.y += shard.velocity.y * dt;
+        node.left = Val::Percent(shard.x);
+        node.top = Val::Px(shard.y);
+        bg.0 = bg.0.with_alpha(shard.lifetime / SHARD_LIFETIME);
+    }
+}
+
+/// Show the phase banner when a boss changes phase, fading out as the
+/// transition ends
+fn update_phase_banner(
+    time: Res<Time>,
+    mut phase_events: EventReader<BossPhaseChangeEvent>,
+    mut query: Query<(&mut Text, &mut TextColor), With<PhaseBannerText>>,
+    mut remaining: Local<f32>,
+) {
+    for event in phase_events.read() {
+        *remaining = PHASE_TRANSITION_DURATION;
+        let title = if event.phase == event.total_phases {
+            "FINAL PHASE".to_string()
+        } else {
+            format!("PHASE {}/{}", event.phase, event.total_phases)
+        };
+        for (mut text, _) in query.iter_mut() {
+            **text = format!("{}\n{}", title, event.boss_name.to_uppercase());
+        }
+    }
 
-            match *state {
-                BossState::Intro => {
-                    **text = format!("{} - {}", data.name, data.title);
-                }
-                BossState::Battle | BossState::PhaseTransition => {
-                    **text = format!("{}{}", data.name, phase_info);
-                }
-                BossState::Defeated => {
-                    **text = format!("{} DEFEATED!", data.name);
-                }
+    *remaining = (*remaining - time.delta_secs()).max(0.0);
+    let alpha = (*remaining / 0.5).min(1.0);
+    let pulse = (time.elapsed_secs() * 10.0).sin() * 0.15 + 0.85;
+    for (mut text, mut color) in query.iter_mut() {
+        if *remaining <= 0.0 {
+            if !text.is_empty() {
+                **text = String::new();
             }
+            continue;
         }
+        color.0 = Color::srgba(1.0, 0.8 * pulse, 0.3, alpha);
+    }
+}
+
+/// Flash the boss warning banner and edge glow while the klaxon sounds
+fn update_boss_warning(
+    warning: Res<BossWarning>,
+    motion: Res<MotionSettings>,
+    mut banner_query: Query<&mut Node, With<BossWarningBanner>>,
+    mut glow_query: Query<
+        (&mut Node, &mut BorderColor),
+        (With<BossWarningGlow>, Without<BossWarningBanner>),
+    >,
+    mut text_query: Query<&mut TextColor, With<BossWarningText>>,
+) {
+    let elapsed = warning.elapsed();
+    for mut node in banner_query.iter_mut() {
+        set_shown(&mut node, elapsed.is_some());
+    }
+    for (mut node, _) in glow_query.iter_mut() {
+        set_shown(&mut node, elapsed.is_some());
+    }
+    let Some(elapsed) = elapsed else {
+        return;
+    };
+
+    let pulse = warning_pulse(elapsed, motion.reduced_motion);
+    for mut color in text_query.iter_mut() {
+        color.set_if_neq(TextColor(WARNING_RED.with_alpha(pulse)));
+    }
+    for (_, mut border) in glow_query.iter_mut() {
+        border.set_if_neq(BorderColor(WARNING_RED.with_alpha(0.5 * pulse)));
     }
 }
 
@@ -1085,73 +2286,127 @@ fn update_dialogue_display(
     mut container_query: Query<&mut Node, With<DialogueContainer>>,
     mut speaker_query: Query<&mut Text, (With<DialogueSpeakerText>, Without<DialogueContentText>)>,
     mut content_query: Query<&mut Text, (With<DialogueContentText>, Without<DialogueSpeakerText>)>,
+    mut portrait_query: Query<&mut BackgroundColor, With<DialoguePortrait>>,
 ) {
     let is_active = dialogue_system.is_active();
 
     // Show/hide dialogue container
     for mut node in container_query.iter_mut() {
-        node.display = if is_active {
-            Display::Flex
-        } else {
-            Display::None
-        };
+        set_shown(&mut node, is_active);
     }
 
+    // The timer ticks every frame a line is up; only copy on a new line
     if let Some(text) = &dialogue_system.active_text {
         // Update speaker name
         for mut speaker in speaker_query.iter_mut() {
-            **speaker = dialogue_system.speaker.clone();
+            if speaker.0 != dialogue_system.speaker {
+                speaker.0.clone_from(&dialogue_system.speaker);
+            }
         }
 
         // Update dialogue content
         for mut content in content_query.iter_mut() {
-            **content = text.clone();
+            if content.0 != *text {
+                content.0.clone_from(text);
+            }
         }
+
+        // Wingman pilots get their own portrait color
+        let portrait = pilot_portrait(&dialogue_system.speaker).unwrap_or(NARRATOR_PORTRAIT);
+        for mut background in portrait_query.iter_mut() {
+            background.set_if_neq(BackgroundColor(portrait));
+        }
+    }
+}
+
+/// Size the dialogue text and set the panel behind it from the settings
+fn update_subtitle_style(
+    settings: Res<DialogueSettings>,
+    theme: Res<super::UiTheme>,
+    mut container_query: Query<(&mut BackgroundColor, &mut BorderColor), With<DialogueContainer>>,
+    mut speaker_query: Query<
+        &mut TextFont,
+        (With<DialogueSpeakerText>, Without<DialogueContentText>),
+    >,
+    mut content_query: Query<
+        &mut TextFont,
+        (With<DialogueContentText>, Without<DialogueSpeakerText>),
+    >,
+) {
+    let size = settings.subtitle_size.font_size();
+    let panel = theme.panel_background();
+    let alpha = settings.subtitle_background.alpha(panel.alpha());
+    for (mut background, mut border) in container_query.iter_mut() {
+        background.0 = panel.with_alpha(alpha);
+        // The frame goes with the panel
+        border.0 = theme
+            .primary
+            .with_alpha(if alpha > 0.0 { 0.6 } else { 0.0 });
+    }
+    for mut font in speaker_query.iter_mut() {
+        font.font_size = size - 2.0;
+    }
+    for mut font in content_query.iter_mut() {
+        font.font_size = size;
     }
 }
 
-/// Update wingman gauge (Rifter only)
+/// Update wingman gauge and the incoming tier
 fn update_wingman_gauge(
     tracker: Res<WingmanTracker>,
-    selected_ship: Res<SelectedShip>,
-    wingmen_query: Query<Entity, With<Wingman>>,
-    mut gauge_query: Query<&mut Node, With<WingmanGauge>>,
-    mut fill_query: Query<&mut Node, (With<WingmanGaugeFill>, Without<WingmanGauge>)>,
+    wingmen_query: Query<Option<&WingmanPilot>, With<Wingman>>,
+    mut fill_query: Query<(&mut Node, &mut BackgroundColor), With<WingmanGaugeFill>>,
+    mut tier_query: Query<&mut Text, (With<WingmanTierText>, Without<WingmanCountText>)>,
     mut count_query: Query<&mut Text, With<WingmanCountText>>,
+    mut buf: Local<String>,
 ) {
-    let is_rifter = selected_ship.ship == MinmatarShip::Rifter;
-
-    // Show/hide wingman gauge
-    for mut node in gauge_query.iter_mut() {
-        node.display = if is_rifter {
-            Display::Flex
-        } else {
-            Display::None
-        };
-    }
+    let tier = tracker.next_tier();
 
-    if !is_rifter {
-        return;
+    // Update fill bar in the incoming ship's color
+    let progress = tracker.progress() * 100.0;
+    for (mut node, mut background) in fill_query.iter_mut() {
+        set_fill(&mut node, progress);
+        background.set_if_neq(BackgroundColor(tier.color()));
     }
 
-    // Update fill bar
-    let progress = tracker.progress() * 100.0;
-    for mut node in fill_query.iter_mut() {
-        node.width = Val::Percent(progress);
+    let full = wingmen_query.iter().count() as u32 >= tracker.max_wingmen;
+    for mut text in tier_query.iter_mut() {
+        if full {
+            write_label(&mut text, &mut buf, format_args!("WING FULL"));
+        } else {
+            write_label(
+                &mut text,
+                &mut buf,
+                format_args!("WINGMAN: {}", tier.name()),
+            );
+        }
     }
 
-    // Update count text
-    let wingman_count = wingmen_query.iter().count();
+    // Update count text, naming the wing where pilots are known
+    let callsigns: Vec<&str> = wingmen_query
+        .iter()
+        .flatten()
+        .map(|pilot| pilot.profile().callsign)
+        .collect();
+    let wing = if callsigns.is_empty() {
+        format!("Active: {}", wingmen_query.iter().count())
+    } else {
+        callsigns.join(", ")
+    };
     for mut text in count_query.iter_mut() {
-        **text = format!(
-            "{}/{} | Active: {}",
-            tracker.kill_count, tracker.kills_per_wingman, wingman_count
+        write_label(
+            &mut text,
+            &mut buf,
+            format_args!(
+                "{}/{} | {}",
+                tracker.kill_count, tracker.kills_per_wingman, wing
+            ),
         );
     }
 }
 
 /// Spawn the ability indicator UI
-fn spawn_ability_indicator(parent: &mut ChildBuilder) {
+fn spawn_ability_indicator(parent: &mut ChildBuilder, metrics: HudMetrics) {
     // Container with label, key hint, and cooldown bar
     parent
         .spawn((
@@ -1179,7 +2434,7 @@ fn spawn_ability_indicator(parent: &mut ChildBuilder) {
                         AbilityIndicatorText,
                         Text::new("ABILITY"),
                         TextFont {
-                            font_size: 11.0,
+                            font_size: metrics.font(11.0),
                             ..default()
                         },
                         TextColor(Color::srgb(0.3, 0.8, 1.0)), // Cyan
@@ -1190,7 +2445,7 @@ fn spawn_ability_indicator(parent: &mut ChildBuilder) {
                         AbilityKeyHint,
                         Text::new("[SHIFT/RT]"),
                         TextFont {
-                            font_size: 9.0,
+                            font_size: metrics.font(9.0),
                             ..default()
                         },
                         TextColor(Color::srgb(0.5, 0.5, 0.5)),
@@ -1229,6 +2484,9 @@ fn spawn_ability_indicator(parent: &mut ChildBuilder) {
 /// Update ability indicator display based on player's ability state
 fn update_ability_indicator(
     player_query: Query<&Ability, With<Player>>,
+    heat_system: Res<ComboHeatSystem>,
+    hud_style: Res<super::HudStyle>,
+    metrics: Res<HudMetrics>,
     mut container_query: Query<&mut Node, With<AbilityIndicatorContainer>>,
     mut fill_query: Query<
         (&mut Node, &mut BackgroundColor),
@@ -1238,18 +2496,16 @@ fn update_ability_indicator(
         ),
     >,
     mut text_query: Query<&mut Text, With<AbilityIndicatorText>>,
+    mut buf: Local<String>,
 ) {
     let Ok(ability) = player_query.get_single() else {
         return;
     };
 
-    // Hide if no ability
+    // Hide if no ability, or when the wheel shows it as a module slot
+    let shown = ability.ability_type != AbilityType::None && !metrics.shows_wheel(*hud_style);
     for mut node in container_query.iter_mut() {
-        node.display = if ability.ability_type == AbilityType::None {
-            Display::None
-        } else {
-            Display::Flex
-        };
+        set_shown(&mut node, shown);
     }
 
     if ability.ability_type == AbilityType::None {
@@ -1257,26 +2513,124 @@ fn update_ability_indicator(
     }
 
     // Update ability name
+    let burned_out = heat_system.is_burned_out();
+    let name = ability.ability_type.name();
     for mut text in text_query.iter_mut() {
-        **text = ability.ability_type.name().to_string();
+        if burned_out {
+            write_label(&mut text, &mut buf, format_args!("{} - BURNED OUT", name));
+        } else {
+            write_label(&mut text, &mut buf, format_args!("{}", name));
+        }
     }
 
-    // Update cooldown bar
-    let progress = ability.cooldown_progress();
+    // Update cooldown bar (burnout overrides the ability cooldown)
+    let progress = if burned_out {
+        1.0 - heat_system.burnout_percent()
+    } else {
+        ability.cooldown_progress()
+    };
+    // Color changes: cyan when ready, dark blue when on cooldown, pulsing when active
+    let fill_color = if burned_out {
+        // Scorched red while the module is offline
+        Color::srgb(0.7, 0.25, 0.15)
+    } else if ability.is_active {
+        // Pulsing white/cyan when active
+        Color::srgb(0.8, 0.95, 1.0)
+    } else if progress >= 1.0 {
+        // Ready - bright cyan
+        Color::srgb(0.3, 0.9, 1.0)
+    } else {
+        // Cooldown - darker blue
+        Color::srgb(0.2, 0.4, 0.6)
+    };
     for (mut node, mut bg_color) in fill_query.iter_mut() {
-        node.width = Val::Percent(progress * 100.0);
-
-        // Color changes: cyan when ready, dark blue when on cooldown, pulsing when active
-        if ability.is_active {
-            // Pulsing white/cyan when active
-            bg_color.0 = Color::srgb(0.8, 0.95, 1.0);
-        } else if progress >= 1.0 {
-            // Ready - bright cyan
-            bg_color.0 = Color::srgb(0.3, 0.9, 1.0);
+        set_fill(&mut node, progress * 100.0);
+        bg_color.set_if_neq(BackgroundColor(fill_color));
+    }
+}
+
+/// Show the classic bars in place of the wheel and keep them filled
+fn update_classic_status_bars(
+    hud_style: Res<super::HudStyle>,
+    metrics: Res<HudMetrics>,
+    player_query: Query<&ShipStats, With<Player>>,
+    mut container_query: Query<&mut Node, With<ClassicStatusBars>>,
+    mut fill_query: Query<
+        (
+            &mut Node,
+            Has<ShieldBar>,
+            Has<ArmorBar>,
+            Has<HullBar>,
+            Has<CapacitorBar>,
+        ),
+        (
+            Or<(
+                With<ShieldBar>,
+                With<ArmorBar>,
+                With<HullBar>,
+                With<CapacitorBar>,
+            )>,
+            Without<ClassicStatusBars>,
+        ),
+    >,
+) {
+    let classic = !metrics.shows_wheel(*hud_style);
+    for mut node in container_query.iter_mut() {
+        set_shown(&mut node, classic);
+    }
+    if !classic {
+        return;
+    }
+
+    let Ok(stats) = player_query.get_single() else {
+        return;
+    };
+    let fraction = |current: f32, max: f32| {
+        if max > 0.0 {
+            (current / max).clamp(0.0, 1.0)
         } else {
-            // Cooldown - darker blue
-            bg_color.0 = Color::srgb(0.2, 0.4, 0.6);
+            0.0
         }
+    };
+
+    for (mut node, shield, armor, hull, capacitor) in fill_query.iter_mut() {
+        let value = if shield {
+            fraction(stats.shield, stats.max_shield)
+        } else if armor {
+            fraction(stats.armor, stats.max_armor)
+        } else if hull {
+            fraction(stats.hull, stats.max_hull)
+        } else if capacitor {
+            fraction(stats.capacitor, stats.max_capacitor)
+        } else {
+            continue;
+        };
+        set_fill(&mut node, value * 100.0);
+    }
+}
+
+/// Fold the objective list and souls count away, or bring them back
+fn toggle_hud_details(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    joystick: Res<JoystickState>,
+    mut details: ResMut<HudDetails>,
+) {
+    if keyboard.just_pressed(KeyCode::Tab) || joystick.right_stick_click() {
+        details.collapsed = !details.collapsed;
+    }
+}
+
+/// Show either the details or the hint that brings them back
+fn update_hud_details(
+    details: Res<HudDetails>,
+    mut panel_query: Query<&mut Node, (With<HudDetailsPanel>, Without<HudDetailsHint>)>,
+    mut hint_query: Query<&mut Node, With<HudDetailsHint>>,
+) {
+    for mut node in panel_query.iter_mut() {
+        set_shown(&mut node, !details.collapsed);
+    }
+    for mut node in hint_query.iter_mut() {
+        set_shown(&mut node, details.collapsed);
     }
 }
 
@@ -1292,3 +2646,39 @@ fn despawn_hud(
         commands.entity(entity).despawn_recursive();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn auto_layout_goes_compact_on_handheld_displays() {
+        let deck = Some(UVec2::new(1280, 800));
+        let desktop = Some(UVec2::new(1920, 1080));
+        assert!(HudLayout::Auto.is_compact(deck));
+        assert!(!HudLayout::Auto.is_compact(desktop));
+        // Unknown monitor keeps the standard HUD
+        assert!(!HudLayout::Auto.is_compact(None));
+        // Explicit choices ignore the display
+        assert!(HudLayout::Compact.is_compact(desktop));
+        assert!(!HudLayout::Standard.is_compact(deck));
+    }
+
+    #[test]
+    fn compact_metrics_floor_fonts_and_grow_bars() {
+        let compact = HudMetrics { compact: true };
+        let standard = HudMetrics::default();
+        assert_eq!(compact.font(9.0), COMPACT_MIN_FONT);
+        assert_eq!(compact.font(28.0), 28.0);
+        assert_eq!(standard.font(9.0), 9.0);
+        assert!(compact.bar(16.0) > standard.bar(16.0));
+        assert!(!compact.shows_wheel(crate::ui::HudStyle::Wheel));
+        assert!(standard.shows_wheel(crate::ui::HudStyle::Wheel));
+    }
+
+    #[test]
+    fn layouts_step_and_wrap() {
+        assert_eq!(HudLayout::Auto.stepped(1), HudLayout::Standard);
+        assert_eq!(HudLayout::Auto.stepped(-1), HudLayout::Compact);
+    }
+}
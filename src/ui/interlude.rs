@@ -0,0 +1,289 @@
+//! Act Interlude Screen
+//!
+//! Opens over the route map at the start of Act 2 and Act 3: where the
+//! story stands, the missions the act holds, and three boons to pick one
+//! from for the act. The pick is stored in the campaign flags and the
+//! checkpoint. While it's open the route map underneath ignores input.
+
+#![allow(dead_code)]
+
+use crate::core::*;
+use crate::systems::{is_campaign_run, JoystickState};
+use crate::ui::menu::{get_horizontal_input, is_confirm, MENU_NAV_COOLDOWN};
+use crate::ui::route_map::route_map_input;
+use crate::ui::{PadPrompt, ToastEvent, UiTheme};
+use bevy::prelude::*;
+
+/// Interlude screen plugin
+pub struct InterludeScreenPlugin;
+
+impl Plugin for InterludeScreenPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                close_interlude_on_state_change.run_if(state_changed::<GameState>),
+                // After the close above, which sees the same state change
+                open_interlude
+                    .run_if(state_changed::<GameState>)
+                    .run_if(in_state(GameState::RouteMap))
+                    .run_if(is_campaign_run),
+                interlude_input.run_if(resource_exists::<InterludeView>),
+                refresh_interlude.run_if(resource_exists_and_changed::<InterludeView>),
+                despawn_closed_interlude.run_if(resource_removed::<InterludeView>),
+            )
+                .chain()
+                // The picking press mustn't reach the map underneath
+                .after(route_map_input),
+        );
+    }
+}
+
+/// The open interlude and its highlighted boon; absent while closed
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InterludeView {
+    /// Index into `ActBoon::ALL`
+    pub index: usize,
+}
+
+impl Default for InterludeView {
+    fn default() -> Self {
+        // Start on the middle card
+        Self { index: 1 }
+    }
+}
+
+/// Interlude screen root
+#[derive(Component)]
+pub struct InterludeRoot;
+
+/// "1. Breaking the Blockade" lines for an act's missions
+pub fn mission_preview(act: Act) -> Vec<String> {
+    act.missions()
+        .iter()
+        .enumerate()
+        .map(|(index, mission)| format!("{}. {}", index + 1, mission.name))
+        .collect()
+}
+
+fn open_interlude(mut commands: Commands, campaign: Res<CampaignState>) {
+    if campaign
+        .flags
+        .wants_interlude(campaign.act, campaign.mission_index)
+    {
+        commands.init_resource::<InterludeView>();
+    }
+}
+
+fn close_interlude_on_state_change(mut commands: Commands) {
+    commands.remove_resource::<InterludeView>();
+}
+
+fn interlude_input(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    joystick: Res<JoystickState>,
+    mut view: ResMut<InterludeView>,
+    mut campaign: ResMut<CampaignState>,
+    mut save_data: ResMut<SaveData>,
+    mut toasts: EventWriter<ToastEvent>,
+    time: Res<Time>,
+    mut cooldown: Local<f32>,
+) {
+    *cooldown -= time.delta_secs();
+
+    let nav = get_horizontal_input(&keyboard, &joystick);
+    if nav != 0 && *cooldown <= 0.0 {
+        view.index = (view.index as i32 + nav).clamp(0, ActBoon::ALL.len() as i32 - 1) as usize;
+        *cooldown = MENU_NAV_COOLDOWN;
+    }
+
+    if !is_confirm(&keyboard, &joystick) {
+        return;
+    }
+    let boon = ActBoon::ALL[view.index];
+    let act = campaign.act;
+    campaign.flags.choose(act, boon);
+    if let Some(checkpoint) = save_data.checkpoint.as_mut() {
+        checkpoint.flags = campaign.flags.clone();
+    }
+    info!("Act {} boon: {}", act.number(), boon.name());
+    toasts.send(ToastEvent::info(format!("BOON: {}", boon.name())));
+    commands.remove_resource::<InterludeView>();
+}
+
+fn despawn_closed_interlude(mut commands: Commands, query: Query<Entity, With<InterludeRoot>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Rebuild the screen for the highlighted boon
+fn refresh_interlude(
+    mut commands: Commands,
+    view: Res<InterludeView>,
+    campaign: Res<CampaignState>,
+    theme: Res<UiTheme>,
+    existing: Query<Entity, With<InterludeRoot>>,
+) {
+    for entity in existing.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    let act = campaign.act;
+    commands
+        .spawn((
+            InterludeRoot,
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(theme.panel_background().with_alpha(0.97)),
+            ZIndex(500), // Above menus, below toasts
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(format!("ACT {} - {}", act.number(), act.name())),
+                TextFont {
+                    font_size: 40.0,
+                    ..default()
+                },
+                TextColor(theme.primary),
+            ));
+            parent.spawn((
+                Text::new(act.catchphrase()),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(theme.accent),
+            ));
+            parent.spawn((
+                Text::new(interlude_vignette(act)),
+                TextFont {
+                    font_size: 15.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.75, 0.75, 0.78)),
+                TextLayout::new_with_justify(JustifyText::Center),
+                Node {
+                    max_width: Val::Px(620.0),
+                    margin: UiRect::vertical(Val::Px(8.0)),
+                    ..default()
+                },
+            ));
+
+            parent.spawn((
+                Text::new("CHOOSE A BOON FOR THIS ACT"),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.6, 0.6, 0.65)),
+            ));
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(14.0),
+                    ..default()
+                })
+                .with_children(|row| {
+                    for (index, boon) in ActBoon::ALL.iter().enumerate() {
+                        let selected = index == view.index;
+                        row.spawn((
+                            Node {
+                                width: Val::Px(200.0),
+                                flex_direction: FlexDirection::Column,
+                                align_items: AlignItems::Center,
+                                padding: UiRect::all(Val::Px(12.0)),
+                                border: UiRect::all(Val::Px(if selected { 2.0 } else { 1.0 })),
+                                row_gap: Val::Px(6.0),
+                                ..default()
+                            },
+                            BorderColor(if selected {
+                                theme.accent
+                            } else {
+                                theme.frame()
+                            }),
+                            BackgroundColor(if selected {
+                                theme.selected_background()
+                            } else {
+                                Color::NONE
+                            }),
+                        ))
+                        .with_children(|card| {
+                            card.spawn((
+                                Text::new(boon.name()),
+                                TextFont {
+                                    font_size: 17.0,
+                                    ..default()
+                                },
+                                TextColor(Color::WHITE),
+                            ));
+                            card.spawn((
+                                Text::new(boon.description()),
+                                TextFont {
+                                    font_size: 13.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.6, 0.6, 0.65)),
+                            ));
+                        });
+                    }
+                });
+
+            parent.spawn((
+                Text::new("AHEAD"),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.6, 0.6, 0.65)),
+                Node {
+                    margin: UiRect::top(Val::Px(10.0)),
+                    ..default()
+                },
+            ));
+            for line in mission_preview(act) {
+                parent.spawn((
+                    Text::new(line),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                ));
+            }
+
+            parent.spawn((
+                PadPrompt::new("←→ Select • A/ENTER Choose"),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.4, 0.4, 0.4)),
+                Node {
+                    margin: UiRect::top(Val::Px(10.0)),
+                    ..default()
+                },
+            ));
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn preview_lists_the_acts_missions_in_order() {
+        let lines = mission_preview(Act::Act2);
+        assert_eq!(lines.len(), Act::Act2.missions().len());
+        assert_eq!(lines[0], format!("1. {}", Act::Act2.missions()[0].name));
+    }
+}
@@ -0,0 +1,389 @@
+//! Loading Screen
+//!
+//! Holds the Loading state until the assets the first mission needs are in:
+//! the ship sprites and their atlas for the session's faction pair, and that
+//! pair's 3D models. The rest (other factions' models, the title backdrop,
+//! music stems) gets a short grace period and otherwise finishes streaming
+//! behind the menus. A progress bar tracks both, with a rotating tip or
+//! piece of lore underneath.
+
+#![allow(dead_code)]
+
+use crate::assets::{faction_pair_type_ids, ShipAtlasJob, ShipModelCache, ShipSpriteCache};
+use crate::core::*;
+use crate::systems::MusicAssets;
+use crate::ui::BackgroundAssets;
+use bevy::asset::{LoadState, RecursiveDependencyLoadState, UntypedAssetId};
+use bevy::prelude::*;
+
+/// Shortest time on the loading screen, so it doesn't flash and the first
+/// frame's asset queues land before we count them
+pub const MIN_LOADING_TIME: f32 = 0.5;
+
+/// How long optional assets may hold the menu back once critical ones are in
+pub const OPTIONAL_GRACE: f32 = 3.0;
+
+/// Leave regardless after this long (past the sprite download timeout)
+pub const LOADING_TIMEOUT: f32 = 20.0;
+
+/// Seconds each tip stays up
+const TIP_INTERVAL: f32 = 4.0;
+
+/// Tips and lore shown while loading
+pub const LOADING_TIPS: &[&str] = &[
+    "Shields regenerate after a few seconds out of fire - armor and hull do not.",
+    "The Minmatar Republic was born when the tribes rose against Amarr slavers.",
+    "Close-range kills fill the berserk meter; B or Y sets it off when it's full.",
+    "Caldari doctrine favours shields and missiles; Gallente, drones and armor.",
+    "Rotate your fleet between campaign missions - tired ships launch weaker.",
+    "Rifters have flown for the Republic since before the rebellion.",
+    "Berserk pays 5x score for eight seconds - save it for a crowded wave.",
+    "Each faction pair has its own campaign, route map and medals.",
+];
+
+/// Loading screen plugin
+pub struct LoadingScreenPlugin;
+
+impl Plugin for LoadingScreenPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LoadingProgress>()
+            .add_systems(OnEnter(GameState::Loading), spawn_loading_screen)
+            .add_systems(
+                Update,
+                (track_loading, update_loading_screen, leave_loading)
+                    .chain()
+                    .run_if(in_state(GameState::Loading)),
+            )
+            .add_systems(OnExit(GameState::Loading), despawn_loading_screen);
+    }
+}
+
+/// What's loaded so far; recounted every frame of the Loading state
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct LoadingProgress {
+    pub critical_done: usize,
+    pub critical_total: usize,
+    pub optional_done: usize,
+    pub optional_total: usize,
+    /// First critical step still outstanding, for the status line
+    pub waiting_on: Option<&'static str>,
+    /// Seconds spent in the Loading state
+    pub elapsed: f32,
+}
+
+impl LoadingProgress {
+    /// Clear the counts for a fresh tally
+    pub fn begin(&mut self) {
+        *self = Self {
+            elapsed: self.elapsed,
+            ..default()
+        };
+    }
+
+    /// Count one asset or step
+    pub fn track(&mut self, label: &'static str, critical: bool, settled: bool) {
+        if critical {
+            self.critical_total += 1;
+            if settled {
+                self.critical_done += 1;
+            } else if self.waiting_on.is_none() {
+                self.waiting_on = Some(label);
+            }
+        } else {
+            self.optional_total += 1;
+            if settled {
+                self.optional_done += 1;
+            }
+        }
+    }
+
+    /// Share of everything tracked that's settled, 0.0 to 1.0
+    pub fn fraction(&self) -> f32 {
+        let total = self.critical_total + self.optional_total;
+        if total == 0 {
+            return 1.0;
+        }
+        (self.critical_done + self.optional_done) as f32 / total as f32
+    }
+
+    pub fn critical_ready(&self) -> bool {
+        self.critical_done == self.critical_total
+    }
+
+    /// Whether the menu can open: critical assets in and optional ones
+    /// either done or out of grace time, or the hard timeout hit
+    pub fn can_leave(&self) -> bool {
+        if self.elapsed >= LOADING_TIMEOUT {
+            return true;
+        }
+        self.elapsed >= MIN_LOADING_TIME
+            && self.critical_ready()
+            && (self.optional_done == self.optional_total || self.elapsed >= OPTIONAL_GRACE)
+    }
+}
+
+/// The tip on screen `elapsed` seconds into loading
+pub fn loading_tip(elapsed: f32) -> &'static str {
+    LOADING_TIPS[(elapsed / TIP_INTERVAL) as usize % LOADING_TIPS.len()]
+}
+
+/// Loaded (with everything it depends on) or failed for good. A failed
+/// asset won't get any better; the game falls back without it.
+fn is_settled(asset_server: &AssetServer, id: impl Into<UntypedAssetId>) -> bool {
+    let id = id.into();
+    match asset_server.get_load_state(id) {
+        Some(LoadState::Loaded) => {
+            asset_server.is_loaded_with_dependencies(id)
+                || matches!(
+                    asset_server.get_recursive_dependency_load_state(id),
+                    Some(RecursiveDependencyLoadState::Failed(_))
+                )
+        }
+        Some(LoadState::Failed(_)) | None => true,
+        _ => false,
+    }
+}
+
+/// Loading screen root
+#[derive(Component)]
+struct LoadingRoot;
+
+/// Progress bar fill
+#[derive(Component)]
+struct LoadingBarFill;
+
+/// "Packing ship atlas... 40%" line
+#[derive(Component)]
+struct LoadingStatus;
+
+/// Tip / lore line
+#[derive(Component)]
+struct LoadingTip;
+
+fn spawn_loading_screen(mut commands: Commands, mut progress: ResMut<LoadingProgress>) {
+    *progress = LoadingProgress::default();
+
+    commands
+        .spawn((
+            LoadingRoot,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(Color::BLACK),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("EVE REBELLION"),
+                TextFont {
+                    font_size: 48.0,
+                    ..default()
+                },
+                TextColor(COLOR_MINMATAR),
+            ));
+
+            // Bar
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(420.0),
+                        height: Val::Px(10.0),
+                        margin: UiRect::top(Val::Px(24.0)),
+                        border: UiRect::all(Val::Px(1.0)),
+                        ..default()
+                    },
+                    BorderColor(Color::srgb(0.35, 0.35, 0.35)),
+                    BackgroundColor(Color::srgb(0.08, 0.08, 0.08)),
+                ))
+                .with_children(|bar| {
+                    bar.spawn((
+                        LoadingBarFill,
+                        Node {
+                            width: Val::Percent(0.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        BackgroundColor(COLOR_MINMATAR),
+                    ));
+                });
+
+            parent.spawn((
+                LoadingStatus,
+                Text::new("Loading..."),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+
+            parent.spawn((
+                LoadingTip,
+                Text::new(loading_tip(0.0)),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.55, 0.55, 0.6)),
+                Node {
+                    max_width: Val::Px(560.0),
+                    margin: UiRect::top(Val::Px(32.0)),
+                    ..default()
+                },
+            ));
+        });
+}
+
+/// Recount every tracked asset
+#[allow(clippy::too_many_arguments)]
+fn track_loading(
+    asset_server: Res<AssetServer>,
+    session: Res<GameSession>,
+    sprites: Res<ShipSpriteCache>,
+    atlas: Option<Res<ShipAtlasJob>>,
+    models: Res<ShipModelCache>,
+    backgrounds: Res<BackgroundAssets>,
+    music: Res<MusicAssets>,
+    time: Res<Time>,
+    mut progress: ResMut<LoadingProgress>,
+) {
+    progress.elapsed += time.delta_secs();
+    progress.begin();
+
+    // The sprite loader only runs with the asset plugins
+    if let Some(atlas) = atlas {
+        progress.track("Loading ship sprites", true, sprites.ready);
+        progress.track(
+            "Packing ship atlas",
+            true,
+            sprites.ready && atlas.settled(&session),
+        );
+    }
+
+    // The session's own hulls are needed on the first launch; the rest can
+    // stream in behind the menus
+    let pair = faction_pair_type_ids(&session);
+    for (type_id, handle) in models.models.iter() {
+        progress.track(
+            "Loading ship models",
+            pair.contains(type_id),
+            is_settled(&asset_server, handle),
+        );
+    }
+
+    if let Some(title) = backgrounds.title.as_ref() {
+        progress.track("Loading backdrop", false, is_settled(&asset_server, title));
+    }
+    if let Some(stems) = music.stems.as_ref() {
+        for stem in [&stems.base, &stems.combat, &stems.high_heat, &stems.boss] {
+            progress.track("Loading music", false, is_settled(&asset_server, stem));
+        }
+    }
+}
+
+fn update_loading_screen(
+    progress: Res<LoadingProgress>,
+    mut fills: Query<&mut Node, With<LoadingBarFill>>,
+    mut status: Query<&mut Text, (With<LoadingStatus>, Without<LoadingTip>)>,
+    mut tips: Query<&mut Text, (With<LoadingTip>, Without<LoadingStatus>)>,
+) {
+    let percent = progress.fraction() * 100.0;
+    for mut node in fills.iter_mut() {
+        node.width = Val::Percent(percent);
+    }
+    let label = progress.waiting_on.unwrap_or(if progress.critical_ready() {
+        "Finishing up"
+    } else {
+        "Loading"
+    });
+    for mut text in status.iter_mut() {
+        text.0 = format!("{}... {:.0}%", label, percent);
+    }
+    let tip = loading_tip(progress.elapsed);
+    for mut text in tips.iter_mut() {
+        if text.0 != tip {
+            text.0 = tip.to_string();
+        }
+    }
+}
+
+fn leave_loading(progress: Res<LoadingProgress>, mut next_state: ResMut<NextState<GameState>>) {
+    if !progress.can_leave() {
+        return;
+    }
+    if !progress.critical_ready() {
+        warn!(
+            "Loading timed out with {}/{} critical assets, continuing",
+            progress.critical_done, progress.critical_total
+        );
+    } else if progress.optional_done < progress.optional_total {
+        info!(
+            "{} optional assets still streaming",
+            progress.optional_total - progress.optional_done
+        );
+    }
+    next_state.set(GameState::MainMenu);
+}
+
+fn despawn_loading_screen(mut commands: Commands, query: Query<Entity, With<LoadingRoot>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tally(critical: (usize, usize), optional: (usize, usize), elapsed: f32) -> LoadingProgress {
+        let mut progress = LoadingProgress {
+            elapsed,
+            ..default()
+        };
+        for i in 0..critical.1 {
+            progress.track("critical", true, i < critical.0);
+        }
+        for i in 0..optional.1 {
+            progress.track("optional", false, i < optional.0);
+        }
+        progress
+    }
+
+    #[test]
+    fn critical_assets_gate_the_menu() {
+        let progress = tally((1, 2), (4, 4), 1.0);
+        assert_eq!(progress.fraction(), 5.0 / 6.0);
+        assert_eq!(progress.waiting_on, Some("critical"));
+        assert!(!progress.can_leave());
+
+        let progress = tally((2, 2), (4, 4), 1.0);
+        assert!(progress.can_leave());
+        assert!(!tally((2, 2), (4, 4), 0.1).can_leave(), "too quick");
+
+        // The hard timeout wins over anything outstanding
+        assert!(tally((0, 2), (0, 4), LOADING_TIMEOUT).can_leave());
+    }
+
+    #[test]
+    fn optional_assets_only_get_a_grace_period() {
+        assert!(!tally((2, 2), (1, 4), 1.0).can_leave());
+        assert!(tally((2, 2), (1, 4), OPTIONAL_GRACE).can_leave());
+        // Nothing tracked at all counts as done
+        assert_eq!(LoadingProgress::default().fraction(), 1.0);
+    }
+
+    #[test]
+    fn tips_rotate_and_wrap() {
+        assert_eq!(loading_tip(0.0), LOADING_TIPS[0]);
+        assert_eq!(loading_tip(TIP_INTERVAL + 0.1), LOADING_TIPS[1]);
+        assert_eq!(
+            loading_tip(TIP_INTERVAL * LOADING_TIPS.len() as f32),
+            LOADING_TIPS[0]
+        );
+    }
+}
@@ -9,8 +9,13 @@ use crate::core::*;
 use crate::entities::boss::get_boss_for_stage;
 use crate::games::ActiveModule;
 use crate::systems::JoystickState;
-use crate::ui::TransitionEvent;
+use crate::ui::{
+    hidden_focus_outline, spawn_damage_breakdown, spawn_faction_emblem, spawn_ship_preview_pane,
+    MenuPointer, MenuScroll, PadPrompt, RevealStagger, ScreenReveal, ShipPreviewImages,
+    TransitionEvent,
+};
 use bevy::prelude::*;
+use bevy::window::Monitor;
 
 /// Menu plugin
 pub struct MenuPlugin;
@@ -18,19 +23,15 @@ pub struct MenuPlugin;
 impl Plugin for MenuPlugin {
     fn build(&self, app: &mut App) {
         app
-            // Loading
-            .add_systems(OnEnter(GameState::Loading), spawn_loading_screen)
-            .add_systems(
-                Update,
-                loading_progress.run_if(in_state(GameState::Loading)),
-            )
-            .add_systems(OnExit(GameState::Loading), despawn_menu::<LoadingRoot>)
             // Main Menu
             .add_systems(OnEnter(GameState::MainMenu), spawn_main_menu)
             .add_systems(
                 Update,
                 (main_menu_input, update_menu_selection::<MainMenuRoot>)
-                    .run_if(in_state(GameState::MainMenu)),
+                    .run_if(in_state(GameState::MainMenu))
+                    // Crash restore prompt and the codex own input while up
+                    .run_if(not(resource_exists::<PendingCrashRestore>))
+                    .run_if(not(resource_exists::<super::CodexView>)),
             )
             .add_systems(OnExit(GameState::MainMenu), despawn_menu::<MainMenuRoot>)
             // Module Select
@@ -51,9 +52,31 @@ impl Plugin for MenuPlugin {
             .add_systems(OnEnter(GameState::Options), spawn_options_menu)
             .add_systems(
                 Update,
-                options_menu_input.run_if(in_state(GameState::Options)),
+                (
+                    options_menu_input,
+                    refresh_controller_row.run_if(
+                        resource_changed::<crate::systems::ConnectedPads>
+                            .or(resource_changed::<crate::systems::PadAssignment>),
+                    ),
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Options)),
             )
             .add_systems(OnExit(GameState::Options), despawn_menu::<OptionsMenuRoot>)
+            // Profile
+            .add_systems(OnEnter(GameState::Profile), spawn_profile_screen)
+            .add_systems(
+                Update,
+                (
+                    profile_input,
+                    update_profile_modifiers,
+                    update_profile_sync_status,
+                    update_menu_selection::<ProfileRoot>,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Profile)),
+            )
+            .add_systems(OnExit(GameState::Profile), despawn_menu::<ProfileRoot>)
             // Faction Select (unified 4-faction) - only for Elder Fleet module
             .add_systems(
                 OnEnter(GameState::FactionSelect),
@@ -87,7 +110,11 @@ impl Plugin for MenuPlugin {
             .add_systems(OnEnter(GameState::StageSelect), spawn_stage_select)
             .add_systems(
                 Update,
-                (stage_select_input, update_menu_selection::<StageSelectRoot>)
+                (
+                    stage_select_input.run_if(not(resource_exists::<super::RunCodeEntry>)),
+                    update_menu_selection::<StageSelectRoot>,
+                    update_run_mode_panel,
+                )
                     .run_if(in_state(GameState::StageSelect)),
             )
             .add_systems(
@@ -103,12 +130,21 @@ impl Plugin for MenuPlugin {
                     update_menu_selection::<ShipMenuRoot>,
                     update_ship_detail_panel,
                 )
-                    .run_if(in_state(GameState::ShipSelect)),
+                    .run_if(in_state(GameState::ShipSelect))
+                    .run_if(not(resource_exists::<super::MutatorsView>)),
             )
             .add_systems(OnExit(GameState::ShipSelect), despawn_menu::<ShipMenuRoot>)
             // Pause Menu
             .add_systems(OnEnter(GameState::Paused), spawn_pause_menu)
-            .add_systems(Update, pause_menu_input.run_if(in_state(GameState::Paused)))
+            .add_systems(
+                Update,
+                (pause_menu_pointer, pause_menu_input)
+                    .chain()
+                    .run_if(in_state(GameState::Paused))
+                    .run_if(not(resource_exists::<super::CodexView>))
+                    .run_if(not(resource_exists::<super::CommsLogView>))
+                    .run_if(not(resource_exists::<super::ControllerLostView>)),
+            )
             .add_systems(OnExit(GameState::Paused), despawn_menu::<PauseMenuRoot>)
             // Game Over (Death Screen with corpse and debris)
             .add_systems(OnEnter(GameState::GameOver), spawn_death_screen)
@@ -185,32 +221,48 @@ impl Plugin for MenuPlugin {
 // ============================================================================
 
 #[derive(Resource, Default)]
-struct MenuSelection {
-    index: usize,
+pub(crate) struct MenuSelection {
+    pub(crate) index: usize,
     total: usize,
     cooldown: f32,
 }
 
-const MENU_NAV_COOLDOWN: f32 = 0.15;
+pub(crate) const MENU_NAV_COOLDOWN: f32 = 0.15;
 
 // ============================================================================
 // Marker Components
 // ============================================================================
 
 #[derive(Component)]
-struct LoadingRoot;
+pub(crate) struct MainMenuRoot;
 
 #[derive(Component)]
-struct MainMenuRoot;
+struct ModuleSelectRoot;
 
 #[derive(Component)]
-struct ModuleSelectRoot;
+pub(crate) struct FactionSelectRoot;
+
+#[derive(Component)]
+pub(crate) struct DifficultyMenuRoot;
+
+#[derive(Component)]
+pub(crate) struct ProfileRoot;
 
+/// New Game+ depth line on ship select
 #[derive(Component)]
-struct FactionSelectRoot;
+struct ShipNgPlusLabel;
 
+/// Ironman on/off line on ship select
 #[derive(Component)]
-struct DifficultyMenuRoot;
+struct ShipIronmanLabel;
+
+/// Cloud save status line on the profile screen
+#[derive(Component)]
+struct ProfileSyncLabel;
+
+/// Status text of a run modifier row on the profile screen
+#[derive(Component)]
+struct ProfileModifierLabel(RunModifier);
 
 #[derive(Component)]
 struct StageSelectRoot;
@@ -221,14 +273,22 @@ struct StageCard {
     locked: bool,
 }
 
+/// Stage select: current run mode line
 #[derive(Component)]
-struct ShipMenuRoot;
+struct RunModeText;
+
+/// Stage select: leaderboard for the highlighted stage in the current mode
+#[derive(Component)]
+struct RunModeBoardText;
+
+#[derive(Component)]
+pub(crate) struct ShipMenuRoot;
 
 #[derive(Component)]
 struct PauseMenuRoot;
 
 #[derive(Component)]
-struct GameOverRoot;
+pub(crate) struct GameOverRoot;
 
 #[derive(Component)]
 struct BossIntroRoot;
@@ -307,9 +367,24 @@ struct VictoryButton {
 #[derive(Clone, Copy, PartialEq)]
 enum VictoryAction {
     PlayAgain,
+    NewGamePlus,
     MainMenu,
 }
 
+impl VictoryAction {
+    /// Buttons left to right
+    const ALL: [VictoryAction; 3] = [
+        VictoryAction::PlayAgain,
+        VictoryAction::NewGamePlus,
+        VictoryAction::MainMenu,
+    ];
+
+    fn stepped(self, step: i32) -> Self {
+        let index = Self::ALL.iter().position(|a| *a == self).unwrap_or(0) as i32;
+        Self::ALL[(index + step).clamp(0, Self::ALL.len() as i32 - 1) as usize]
+    }
+}
+
 /// Victory screen selection state
 #[derive(Resource)]
 struct VictorySelection {
@@ -324,66 +399,17 @@ impl Default for VictorySelection {
     }
 }
 
-/// Menu item that can be selected
+/// Menu item that can be selected (hovered, clicked or focused)
 #[derive(Component)]
-struct MenuItem {
-    index: usize,
+#[require(Interaction, Outline(hidden_focus_outline))]
+pub(crate) struct MenuItem {
+    pub(crate) index: usize,
 }
 
 /// Marker for selected menu item highlight
 #[derive(Component)]
 struct SelectionIndicator;
 
-// ============================================================================
-// Loading Screen
-// ============================================================================
-
-fn spawn_loading_screen(mut commands: Commands) {
-    commands
-        .spawn((
-            LoadingRoot,
-            Node {
-                width: Val::Percent(100.0),
-                height: Val::Percent(100.0),
-                justify_content: JustifyContent::Center,
-                align_items: AlignItems::Center,
-                flex_direction: FlexDirection::Column,
-                ..default()
-            },
-            BackgroundColor(Color::BLACK),
-        ))
-        .with_children(|parent| {
-            parent.spawn((
-                Text::new("EVE REBELLION"),
-                TextFont {
-                    font_size: 48.0,
-                    ..default()
-                },
-                TextColor(COLOR_MINMATAR),
-            ));
-
-            parent.spawn((
-                Text::new("Loading..."),
-                TextFont {
-                    font_size: 24.0,
-                    ..default()
-                },
-                TextColor(Color::WHITE),
-            ));
-        });
-}
-
-fn loading_progress(
-    time: Res<Time>,
-    mut timer: Local<f32>,
-    mut next_state: ResMut<NextState<GameState>>,
-) {
-    *timer += time.delta_secs();
-    if *timer > 1.0 {
-        next_state.set(GameState::MainMenu);
-    }
-}
-
 // ============================================================================
 // Main Menu
 // ============================================================================
@@ -393,8 +419,9 @@ fn spawn_main_menu(
     mut selection: ResMut<MenuSelection>,
     save_data: Res<SaveData>,
 ) {
+    let entries = main_menu_entries(save_data.checkpoint.is_some());
     selection.index = 0;
-    selection.total = 3;
+    selection.total = entries.len();
 
     // Get best high score across all faction pairs
     let best_score = save_data
@@ -407,6 +434,7 @@ fn spawn_main_menu(
     commands
         .spawn((
             MainMenuRoot,
+            ScreenReveal::default(),
             Node {
                 width: Val::Percent(100.0),
                 height: Val::Percent(100.0),
@@ -445,9 +473,26 @@ fn spawn_main_menu(
             });
 
             // Menu buttons
-            spawn_menu_item(parent, "PLAY", 0);
-            spawn_menu_item(parent, "OPTIONS", 1);
-            spawn_menu_item(parent, "QUIT", 2);
+            for (index, entry) in entries.iter().enumerate() {
+                spawn_menu_item(parent, entry.label(), index);
+            }
+
+            // Where Continue picks up
+            if let Some(checkpoint) = &save_data.checkpoint {
+                parent.spawn((
+                    Text::new(format!(
+                        "Continue: Act {} • {} • {}",
+                        checkpoint.act,
+                        checkpoint.mission_name,
+                        format_score(checkpoint.score)
+                    )),
+                    TextFont {
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.5, 0.4, 0.3)),
+                ));
+            }
 
             // High score display
             if best_score > 0 {
@@ -500,7 +545,7 @@ fn spawn_main_menu(
             });
 
             parent.spawn((
-                Text::new("↑↓ Navigate • A/ENTER Select • ESC Quit"),
+                PadPrompt::new("↑↓ Navigate • A/ENTER Select • ESC Quit"),
                 TextFont {
                     font_size: 14.0,
                     ..default()
@@ -519,14 +564,81 @@ fn spawn_main_menu(
         });
 }
 
+/// Main menu rows, top to bottom
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MainMenuEntry {
+    Continue,
+    Play,
+    Options,
+    Profile,
+    Codex,
+    Quit,
+}
+
+impl MainMenuEntry {
+    fn label(&self) -> &'static str {
+        match self {
+            MainMenuEntry::Continue => "CONTINUE CAMPAIGN",
+            MainMenuEntry::Play => "PLAY",
+            MainMenuEntry::Options => "OPTIONS",
+            MainMenuEntry::Profile => "PROFILE",
+            MainMenuEntry::Codex => "CODEX",
+            MainMenuEntry::Quit => "QUIT",
+        }
+    }
+}
+
+/// Continue leads the menu while there's a checkpoint to pick up
+fn main_menu_entries(can_continue: bool) -> Vec<MainMenuEntry> {
+    let mut entries = vec![
+        MainMenuEntry::Play,
+        MainMenuEntry::Options,
+        MainMenuEntry::Profile,
+        MainMenuEntry::Codex,
+        MainMenuEntry::Quit,
+    ];
+    if can_continue {
+        entries.insert(0, MainMenuEntry::Continue);
+    }
+    entries
+}
+
 fn main_menu_input(
+    mut commands: Commands,
     keyboard: Res<ButtonInput<KeyCode>>,
     joystick: Res<JoystickState>,
+    pointer: Res<MenuPointer>,
     mut selection: ResMut<MenuSelection>,
     time: Res<Time>,
-    _active_module: ResMut<ActiveModule>,
+    save_data: Res<SaveData>,
     mut exit: EventWriter<AppExit>,
     mut transitions: EventWriter<TransitionEvent>,
+    // Grouped to stay within Bevy's system parameter limit
+    (
+        mut campaign,
+        mut session,
+        mut active_module,
+        mut endless,
+        mut run_mode,
+        mut difficulty,
+        mut score,
+        mut roster,
+        mut route,
+        mut commanders,
+        mut toasts,
+    ): (
+        ResMut<CampaignState>,
+        ResMut<GameSession>,
+        ResMut<ActiveModule>,
+        ResMut<EndlessMode>,
+        ResMut<RunMode>,
+        ResMut<Difficulty>,
+        ResMut<ScoreSystem>,
+        ResMut<crate::entities::WingmanRoster>,
+        ResMut<RouteState>,
+        ResMut<crate::systems::CommandRoster>,
+        EventWriter<super::ToastEvent>,
+    ),
 ) {
     selection.cooldown -= time.delta_secs();
 
@@ -539,20 +651,78 @@ fn main_menu_input(
     }
 
     // Selection
-    if is_confirm(&keyboard, &joystick) {
-        match selection.index {
-            0 => {
-                // PLAY - go to module select
+    let entries = main_menu_entries(save_data.checkpoint.is_some());
+    if is_confirm(&keyboard, &joystick) || pointer.clicked {
+        match entries.get(selection.index) {
+            Some(MainMenuEntry::Continue) => {
+                let Some(checkpoint) = &save_data.checkpoint else {
+                    return;
+                };
+                // Straight back into the cockpit: ship select would reset
+                // the wing, so the run-level resets it does happen here
+                let player = Faction::all()
+                    .iter()
+                    .copied()
+                    .find(|f| f.name() == checkpoint.player_faction)
+                    .unwrap_or_default();
+                let enemy = Faction::all()
+                    .iter()
+                    .copied()
+                    .find(|f| f.name() == checkpoint.enemy_faction)
+                    .unwrap_or_else(|| player.rival());
+                active_module.set_module("elder_fleet");
+                endless.active = false;
+                *run_mode = RunMode::Campaign;
+                *difficulty = Difficulty::all()
+                    .iter()
+                    .copied()
+                    .find(|d| d.name() == checkpoint.difficulty)
+                    .unwrap_or_default();
+                *session = GameSession {
+                    selected_ship_index: checkpoint.ship_index,
+                    skin_index: checkpoint.skin_index,
+                    ..GameSession::new(player, enemy)
+                };
+                *campaign = checkpoint.campaign();
+                score.reset_game();
+                score.score = checkpoint.score;
+                score.souls_liberated = checkpoint.souls_liberated;
+                *roster = crate::entities::WingmanRoster {
+                    flying: checkpoint.wingmen.clone(),
+                    ..default()
+                };
+                *route = RouteState::default();
+                *commanders = default();
+
+                info!(
+                    "Continuing campaign: Act {} {}",
+                    checkpoint.act, checkpoint.mission_name
+                );
+                toasts.send(super::ToastEvent::info(format!(
+                    "Continuing: {}",
+                    checkpoint.mission_name
+                )));
+                transitions.send(TransitionEvent::slow(GameState::Playing));
+            }
+            Some(MainMenuEntry::Play) => {
+                // Go to module select
                 transitions.send(TransitionEvent::to(GameState::ModuleSelect));
             }
-            1 => {
-                // OPTIONS - go to options menu
+            Some(MainMenuEntry::Options) => {
                 transitions.send(TransitionEvent::to(GameState::Options));
             }
-            2 => {
+            Some(MainMenuEntry::Profile) => {
+                // Prestige track and lifetime stats
+                transitions.send(TransitionEvent::to(GameState::Profile));
+            }
+            Some(MainMenuEntry::Codex) => {
+                // Opens over the menu
+                commands.init_resource::<super::CodexView>();
+            }
+            Some(MainMenuEntry::Quit) => {
                 exit.send(AppExit::Success);
             }
-            _ => {}
+            None => {}
         }
     }
 
@@ -578,6 +748,7 @@ fn spawn_module_select(mut commands: Commands, mut selection: ResMut<MenuSelecti
     commands
         .spawn((
             ModuleSelectRoot,
+            ScreenReveal::default(),
             Node {
                 width: Val::Percent(100.0),
                 height: Val::Percent(100.0),
@@ -654,7 +825,7 @@ fn spawn_module_select(mut commands: Commands, mut selection: ResMut<MenuSelecti
 
             // Instructions
             parent.spawn((
-                Text::new("← → Navigate • A/ENTER Select • B/ESC Back"),
+                PadPrompt::new("← → Navigate • A/ENTER Select • B/ESC Back"),
                 TextFont {
                     font_size: 14.0,
                     ..default()
@@ -675,6 +846,7 @@ fn spawn_module_card(
 ) {
     parent
         .spawn((
+            RevealStagger,
             MenuItem { index },
             Node {
                 width: Val::Px(280.0),
@@ -754,6 +926,7 @@ fn spawn_module_card(
 fn module_select_input(
     keyboard: Res<ButtonInput<KeyCode>>,
     joystick: Res<JoystickState>,
+    pointer: Res<MenuPointer>,
     mut selection: ResMut<MenuSelection>,
     mut active_module: ResMut<ActiveModule>,
     mut endless: ResMut<crate::core::EndlessMode>,
@@ -792,7 +965,7 @@ fn module_select_input(
     }
 
     // Confirm selection
-    if is_confirm(&keyboard, &joystick) {
+    if is_confirm(&keyboard, &joystick) || pointer.clicked {
         match selection.index {
             0 => {
                 // Elder Fleet
@@ -842,11 +1015,38 @@ struct VolumeLabel {
     setting: VolumeSetting,
 }
 
+/// Options menu row cycled by left/right, by its row index
+#[derive(Component)]
+struct OptionsChoiceRow(usize);
+
+/// Value text on an options choice row, by its row index
+#[derive(Component)]
+struct OptionsChoiceValue(usize);
+
+/// Volume rows (master, music, SFX and voice), then the window mode,
+/// monitor and low-latency toggle, the targeting aid and enemy health bar
+/// toggles, the enemy bullet palette, the HUD layout, the aspect mode, the
+/// background behavior, the Time Attack ghost and player one's controller
+const OPTIONS_ROW_COUNT: i32 = 16;
+const OPTIONS_IDX_DISPLAY_MODE: usize = 4;
+const OPTIONS_IDX_MONITOR: usize = 5;
+const OPTIONS_IDX_LOW_LATENCY: usize = 6;
+const OPTIONS_IDX_TARGETING_AID: usize = 7;
+const OPTIONS_IDX_HEALTH_BARS: usize = 8;
+const OPTIONS_IDX_BULLET_PALETTE: usize = 9;
+const OPTIONS_IDX_HUD_LAYOUT: usize = 10;
+const OPTIONS_IDX_ASPECT: usize = 11;
+const OPTIONS_IDX_AUTO_PAUSE: usize = 12;
+const OPTIONS_IDX_BACKGROUND_AUDIO: usize = 13;
+const OPTIONS_IDX_RACE_GHOST: usize = 14;
+const OPTIONS_IDX_CONTROLLER: usize = 15;
+
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum VolumeSetting {
     Master,
     Music,
     Sfx,
+    Voice,
 }
 
 #[derive(Resource)]
@@ -867,6 +1067,19 @@ impl Default for OptionsMenuState {
 fn spawn_options_menu(
     mut commands: Commands,
     sound_settings: Res<crate::systems::audio::SoundSettings>,
+    targeting_aid: Res<crate::systems::TargetingAidSettings>,
+    health_bars: Res<crate::systems::EnemyHealthBarSettings>,
+    bullet_palette: Res<crate::systems::BulletPalette>,
+    hud_layout: Res<super::HudLayout>,
+    aspect_mode: Res<AspectMode>,
+    focus: Res<crate::systems::FocusSettings>,
+    display: Res<DisplaySettings>,
+    monitors: Query<&Monitor>,
+    ghost: Res<crate::systems::GhostSettings>,
+    (pads, pad_assignment): (
+        Res<crate::systems::ConnectedPads>,
+        Res<crate::systems::PadAssignment>,
+    ),
 ) {
     commands.init_resource::<OptionsMenuState>();
 
@@ -874,13 +1087,14 @@ fn spawn_options_menu(
     commands
         .spawn((
             OptionsMenuRoot,
+            ScreenReveal::default(),
             Node {
                 width: Val::Percent(100.0),
                 height: Val::Percent(100.0),
                 flex_direction: FlexDirection::Column,
                 align_items: AlignItems::Center,
                 justify_content: JustifyContent::Center,
-                row_gap: Val::Px(20.0),
+                row_gap: Val::Px(8.0),
                 ..default()
             },
             BackgroundColor(Color::srgba(0.02, 0.02, 0.05, 0.95)),
@@ -936,6 +1150,139 @@ fn spawn_options_menu(
                 sound_settings.sfx_volume,
                 2,
             );
+            spawn_volume_row(
+                parent,
+                "Voice Volume",
+                VolumeSetting::Voice,
+                sound_settings.voice_volume,
+                3,
+            );
+
+            // Display section header
+            parent.spawn((
+                Text::new("DISPLAY"),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.6, 0.6, 0.7)),
+                Node {
+                    margin: UiRect::vertical(Val::Px(20.0)),
+                    ..default()
+                },
+            ));
+
+            spawn_options_choice_row(
+                parent,
+                "Window Mode",
+                display.mode.name(),
+                OPTIONS_IDX_DISPLAY_MODE,
+            );
+            spawn_options_choice_row(
+                parent,
+                "Monitor",
+                &selected_monitor_label(&display, &monitors),
+                OPTIONS_IDX_MONITOR,
+            );
+            spawn_options_choice_row(
+                parent,
+                "Low Latency",
+                aid_label(display.low_latency),
+                OPTIONS_IDX_LOW_LATENCY,
+            );
+
+            // Accessibility section header
+            parent.spawn((
+                Text::new("ACCESSIBILITY"),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.6, 0.6, 0.7)),
+                Node {
+                    margin: UiRect::vertical(Val::Px(20.0)),
+                    ..default()
+                },
+            ));
+
+            // Boss lead reticle and range rings
+            spawn_options_choice_row(
+                parent,
+                "Targeting Aid",
+                aid_label(targeting_aid.enabled),
+                OPTIONS_IDX_TARGETING_AID,
+            );
+
+            // Bars over enemies once they've been hit
+            spawn_options_choice_row(
+                parent,
+                "Enemy Health Bars",
+                aid_label(health_bars.enabled),
+                OPTIONS_IDX_HEALTH_BARS,
+            );
+
+            // One enemy bullet palette in place of faction colors
+            spawn_options_choice_row(
+                parent,
+                "Enemy Bullets",
+                bullet_palette.name(),
+                OPTIONS_IDX_BULLET_PALETTE,
+            );
+
+            // Bigger HUD for small screens
+            spawn_options_choice_row(
+                parent,
+                "HUD Layout",
+                hud_layout.name(),
+                OPTIONS_IDX_HUD_LAYOUT,
+            );
+
+            // Stretch to wide or tall windows, or keep the base shape
+            spawn_options_choice_row(parent, "Aspect", aspect_mode.name(), OPTIONS_IDX_ASPECT);
+
+            // Streaming setups turn these off to keep the game running
+            spawn_options_choice_row(
+                parent,
+                "Pause When Unfocused",
+                aid_label(focus.auto_pause),
+                OPTIONS_IDX_AUTO_PAUSE,
+            );
+            spawn_options_choice_row(
+                parent,
+                "Background Audio",
+                focus.audio.name(),
+                OPTIONS_IDX_BACKGROUND_AUDIO,
+            );
+
+            // Best run's ghost in Time Attack
+            spawn_options_choice_row(
+                parent,
+                "Race Ghost",
+                aid_label(ghost.enabled),
+                OPTIONS_IDX_RACE_GHOST,
+            );
+
+            // Controls section header
+            parent.spawn((
+                Text::new("CONTROLS"),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.6, 0.6, 0.7)),
+                Node {
+                    margin: UiRect::vertical(Val::Px(20.0)),
+                    ..default()
+                },
+            ));
+
+            // Which pad flies player one, when more than one is plugged in
+            spawn_options_choice_row(
+                parent,
+                "Controller",
+                &crate::systems::controller_label(&pads.0, &pad_assignment),
+                OPTIONS_IDX_CONTROLLER,
+            );
 
             // Back instruction
             parent.spawn((
@@ -953,6 +1300,82 @@ fn spawn_options_menu(
         });
 }
 
+/// Label for the chosen monitor, numbered like the monitor list
+fn selected_monitor_label(display: &DisplaySettings, monitors: &Query<&Monitor>) -> String {
+    let count = monitors.iter().count();
+    if count > 0 && display.monitor >= count {
+        // Unplugged; fullscreen falls back to the primary display
+        return "PRIMARY".to_string();
+    }
+    let name = monitors
+        .iter()
+        .nth(display.monitor)
+        .and_then(|m| m.name.as_deref());
+    monitor_label(display.monitor, name)
+}
+
+/// Show `value` on the options choice row at `index`
+fn set_choice_value(
+    values: &mut Query<(&OptionsChoiceValue, &mut Text), Without<VolumeLabel>>,
+    index: usize,
+    value: &str,
+) {
+    for (choice, mut text) in values.iter_mut() {
+        if choice.0 == index {
+            **text = value.to_string();
+        }
+    }
+}
+
+/// Keep the controller row current as pads come and go
+fn refresh_controller_row(
+    pads: Res<crate::systems::ConnectedPads>,
+    pad_assignment: Res<crate::systems::PadAssignment>,
+    mut choice_values: Query<(&OptionsChoiceValue, &mut Text), Without<VolumeLabel>>,
+) {
+    let label = crate::systems::controller_label(&pads.0, &pad_assignment);
+    set_choice_value(&mut choice_values, OPTIONS_IDX_CONTROLLER, &label);
+}
+
+/// A row with a label and a value cycled by left/right
+fn spawn_options_choice_row(parent: &mut ChildBuilder, label: &str, value: &str, index: usize) {
+    parent
+        .spawn((
+            Node {
+                width: Val::Px(400.0),
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::SpaceBetween,
+                padding: UiRect::all(Val::Px(10.0)),
+                margin: UiRect::bottom(Val::Px(10.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.1, 0.1, 0.15, 0.8)),
+            BorderColor(Color::srgba(0.3, 0.3, 0.4, 0.5)),
+            OptionsChoiceRow(index),
+        ))
+        .with_children(|row| {
+            row.spawn((
+                Text::new(label),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.8, 0.8, 0.8)),
+            ));
+            row.spawn((
+                OptionsChoiceValue(index),
+                Text::new(value),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+        });
+}
+
 fn spawn_volume_row(
     parent: &mut ChildBuilder,
     label: &str,
@@ -1046,6 +1469,24 @@ fn options_menu_input(
     mut sliders: Query<(&VolumeSlider, &mut BorderColor), Without<VolumeLabel>>,
     mut bars: Query<(&VolumeSlider, &mut Node), (Without<VolumeLabel>, Without<BorderColor>)>,
     mut labels: Query<(&VolumeLabel, &mut Text)>,
+    mut targeting_aid: ResMut<crate::systems::TargetingAidSettings>,
+    // Grouped to stay within Bevy's system parameter limit
+    (mut hud_layout, mut aspect_mode, mut display, monitors, mut bullet_palette): (
+        ResMut<super::HudLayout>,
+        ResMut<AspectMode>,
+        ResMut<DisplaySettings>,
+        Query<&Monitor>,
+        ResMut<crate::systems::BulletPalette>,
+    ),
+    mut focus: ResMut<crate::systems::FocusSettings>,
+    (mut ghost, pads, mut pad_assignment, mut health_bars): (
+        ResMut<crate::systems::GhostSettings>,
+        Res<crate::systems::ConnectedPads>,
+        ResMut<crate::systems::PadAssignment>,
+        ResMut<crate::systems::EnemyHealthBarSettings>,
+    ),
+    mut choice_rows: Query<(&OptionsChoiceRow, &mut BorderColor), Without<VolumeSlider>>,
+    mut choice_values: Query<(&OptionsChoiceValue, &mut Text), Without<VolumeLabel>>,
 ) {
     let dt = time.delta_secs();
     state.cooldown = (state.cooldown - dt).max(0.0);
@@ -1054,10 +1495,88 @@ fn options_menu_input(
     if state.cooldown <= 0.0 {
         let nav = get_nav_input(&keyboard, &joystick);
         if nav != 0 {
-            state.selected = (state.selected as i32 + nav).rem_euclid(3) as usize;
+            state.selected = (state.selected as i32 + nav).rem_euclid(OPTIONS_ROW_COUNT) as usize;
             state.cooldown = 0.15;
         }
+    }
 
+    // Choice rows cycle on confirm or left/right (toggles flip either way)
+    if state.selected >= OPTIONS_IDX_DISPLAY_MODE {
+        let step = if keyboard.just_pressed(KeyCode::ArrowLeft) || joystick.dpad_just_left() {
+            -1
+        } else if is_confirm(&keyboard, &joystick)
+            || keyboard.just_pressed(KeyCode::ArrowRight)
+            || joystick.dpad_just_right()
+        {
+            1
+        } else {
+            0
+        };
+        if step != 0 {
+            let monitor;
+            let controller;
+            let value = match state.selected {
+                OPTIONS_IDX_DISPLAY_MODE => {
+                    display.mode = display.mode.stepped(step);
+                    display.mode.name()
+                }
+                OPTIONS_IDX_MONITOR => {
+                    let count = monitors.iter().count();
+                    let next = display.stepped_monitor(step, count);
+                    if next != display.monitor {
+                        display.monitor = next;
+                    }
+                    monitor = selected_monitor_label(&display, &monitors);
+                    &monitor
+                }
+                OPTIONS_IDX_LOW_LATENCY => {
+                    display.low_latency = !display.low_latency;
+                    aid_label(display.low_latency)
+                }
+                OPTIONS_IDX_TARGETING_AID => {
+                    targeting_aid.enabled = !targeting_aid.enabled;
+                    aid_label(targeting_aid.enabled)
+                }
+                OPTIONS_IDX_HEALTH_BARS => {
+                    health_bars.enabled = !health_bars.enabled;
+                    aid_label(health_bars.enabled)
+                }
+                OPTIONS_IDX_BULLET_PALETTE => {
+                    *bullet_palette = bullet_palette.stepped(step);
+                    bullet_palette.name()
+                }
+                OPTIONS_IDX_HUD_LAYOUT => {
+                    *hud_layout = hud_layout.stepped(step);
+                    hud_layout.name()
+                }
+                OPTIONS_IDX_ASPECT => {
+                    *aspect_mode = aspect_mode.toggled();
+                    aspect_mode.name()
+                }
+                OPTIONS_IDX_AUTO_PAUSE => {
+                    focus.auto_pause = !focus.auto_pause;
+                    aid_label(focus.auto_pause)
+                }
+                OPTIONS_IDX_RACE_GHOST => {
+                    ghost.enabled = !ghost.enabled;
+                    aid_label(ghost.enabled)
+                }
+                OPTIONS_IDX_CONTROLLER => {
+                    if pads.0.len() > 1 {
+                        pad_assignment.step_player_one(step, &pads.0);
+                    }
+                    controller = crate::systems::controller_label(&pads.0, &pad_assignment);
+                    &controller
+                }
+                // OPTIONS_IDX_BACKGROUND_AUDIO
+                _ => {
+                    focus.audio = focus.audio.stepped(step);
+                    focus.audio.name()
+                }
+            };
+            set_choice_value(&mut choice_values, state.selected, value);
+        }
+    } else if state.cooldown <= 0.0 {
         // Adjust volume (left/right)
         let adjust = if keyboard.pressed(KeyCode::ArrowLeft) || joystick.dpad_x < 0 {
             -0.05
@@ -1072,6 +1591,7 @@ fn options_menu_input(
                 0 => VolumeSetting::Master,
                 1 => VolumeSetting::Music,
                 2 => VolumeSetting::Sfx,
+                3 => VolumeSetting::Voice,
                 _ => VolumeSetting::Master,
             };
 
@@ -1092,6 +1612,11 @@ fn options_menu_input(
                         (sound_settings.sfx_volume + adjust).clamp(0.0, 1.0);
                     sound_settings.sfx_volume
                 }
+                VolumeSetting::Voice => {
+                    sound_settings.voice_volume =
+                        (sound_settings.voice_volume + adjust).clamp(0.0, 1.0);
+                    sound_settings.voice_volume
+                }
             };
 
             // Update bar width
@@ -1113,11 +1638,19 @@ fn options_menu_input(
     }
 
     // Update selection highlighting
+    for (row, mut border) in choice_rows.iter_mut() {
+        *border = if state.selected == row.0 {
+            BorderColor(Color::srgb(0.4, 0.6, 0.8))
+        } else {
+            BorderColor(Color::srgba(0.3, 0.3, 0.4, 0.5))
+        };
+    }
     for (slider, mut border) in sliders.iter_mut() {
         let is_selected = match slider.setting {
             VolumeSetting::Master => state.selected == 0,
             VolumeSetting::Music => state.selected == 1,
             VolumeSetting::Sfx => state.selected == 2,
+            VolumeSetting::Voice => state.selected == 3,
         };
         *border = if is_selected {
             BorderColor(Color::srgb(0.4, 0.6, 0.8))
@@ -1126,9 +1659,354 @@ fn options_menu_input(
         };
     }
 
-    // Back to main menu
+    // Back to main menu
+    if keyboard.just_pressed(KeyCode::Escape) || joystick.back() {
+        next_state.set(GameState::MainMenu);
+    }
+}
+
+// ============================================================================
+// Profile
+// ============================================================================
+
+/// Run modifiers in the order their rows are listed
+fn profile_modifiers() -> Vec<RunModifier> {
+    RunModifier::ALL.to_vec()
+}
+
+pub(crate) fn modifier_status(prestige: &PrestigeProfile, modifier: RunModifier) -> &'static str {
+    if prestige.modifier_active(modifier) {
+        "ON"
+    } else if prestige.modifier_unlocked(modifier) {
+        "OFF"
+    } else {
+        "LOCKED"
+    }
+}
+
+fn spawn_profile_screen(
+    mut commands: Commands,
+    mut selection: ResMut<MenuSelection>,
+    save_data: Res<SaveData>,
+    cloud: Option<Res<CloudSync>>,
+) {
+    let prestige = &save_data.prestige;
+    let modifiers = profile_modifiers();
+    selection.index = 0;
+    selection.total = modifiers.len();
+
+    let next = match prestige.next_reward() {
+        Some(reward) => format!(
+            "NEXT: {} in {} points",
+            reward.name,
+            reward.required - prestige.points
+        ),
+        None => "Every reward unlocked".to_string(),
+    };
+
+    commands
+        .spawn((
+            ProfileRoot,
+            ScreenReveal::default(),
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.02, 0.02, 0.05, 0.95)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("PROFILE"),
+                TextFont {
+                    font_size: 48.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.9, 0.9, 0.9)),
+            ));
+
+            parent.spawn((
+                Text::new(format!(
+                    "{} - {} PRESTIGE",
+                    prestige.rank().to_uppercase(),
+                    prestige.points
+                )),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.85, 0.3)),
+            ));
+
+            parent.spawn((
+                Text::new(next),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.6, 0.6, 0.7)),
+            ));
+
+            let (sync_text, sync_color) = profile_sync_status(cloud.as_deref());
+            parent.spawn((
+                ProfileSyncLabel,
+                Text::new(sync_text),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(sync_color),
+            ));
+
+            // Lifetime stats, one column per module plus the total
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(20.0),
+                    margin: UiRect::vertical(Val::Px(16.0)),
+                    ..default()
+                })
+                .with_children(|columns| {
+                    for (id, name) in PRESTIGE_MODULES {
+                        let stats = prestige.module(id).cloned().unwrap_or_default();
+                        spawn_profile_stats(columns, name, &stats);
+                    }
+                    spawn_profile_stats(columns, "ALL MODULES", &prestige.totals());
+                });
+
+            // Prestige track
+            for reward in PRESTIGE_REWARDS {
+                let unlocked = prestige.is_unlocked(reward);
+                parent.spawn((
+                    Text::new(format!(
+                        "{:>4}  {} - {}",
+                        reward.required, reward.name, reward.description
+                    )),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(if unlocked {
+                        Color::srgb(0.8, 0.8, 0.6)
+                    } else {
+                        Color::srgb(0.35, 0.35, 0.35)
+                    }),
+                ));
+            }
+
+            // Achievements, earned ones lit
+            let achievements = Achievement::ALL
+                .iter()
+                .map(|a| {
+                    let mark = if a.earned(prestige) { "[x]" } else { "[ ]" };
+                    format!("{} {} ({})", mark, a.name(), a.description())
+                })
+                .collect::<Vec<_>>()
+                .join("   ");
+            parent.spawn((
+                Text::new(achievements),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.6, 0.6, 0.7)),
+                Node {
+                    max_width: Val::Px(900.0),
+                    margin: UiRect::top(Val::Px(6.0)),
+                    ..default()
+                },
+            ));
+
+            // Run modifier toggles
+            parent.spawn(Node {
+                height: Val::Px(10.0),
+                ..default()
+            });
+            for (index, modifier) in modifiers.into_iter().enumerate() {
+                parent
+                    .spawn((
+                        ProfileRoot,
+                        MenuItem { index },
+                        Node {
+                            width: Val::Px(420.0),
+                            height: Val::Px(40.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            border: UiRect::all(Val::Px(2.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgba(0.15, 0.15, 0.15, 0.9)),
+                        BorderColor(Color::srgb(0.3, 0.3, 0.3)),
+                    ))
+                    .with_children(|row| {
+                        row.spawn((
+                            ProfileModifierLabel(modifier),
+                            Text::new(format!(
+                                "{}: {}",
+                                modifier.name().to_uppercase(),
+                                modifier_status(prestige, modifier)
+                            )),
+                            TextFont {
+                                font_size: 18.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                        ));
+                    });
+            }
+
+            parent.spawn((
+                Text::new("Modifiers apply to campaign runs only"),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.4, 0.4, 0.4)),
+            ));
+
+            parent.spawn((
+                Text::new("[ESC] Back   [ENTER] Toggle   [↑/↓] Select   [C] Sync"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.5, 0.5, 0.5)),
+                Node {
+                    margin: UiRect::top(Val::Px(20.0)),
+                    ..default()
+                },
+            ));
+        });
+}
+
+fn spawn_profile_stats(parent: &mut ChildBuilder, title: &str, stats: &ModuleStats) {
+    parent
+        .spawn((
+            Node {
+                width: Val::Px(200.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(10.0)),
+                border: UiRect::all(Val::Px(1.0)),
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+            BorderColor(Color::srgb(0.3, 0.25, 0.15)),
+            BackgroundColor(Color::srgba(0.1, 0.08, 0.05, 0.8)),
+        ))
+        .with_children(|column| {
+            column.spawn((
+                Text::new(title),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.8, 0.5, 0.2)),
+            ));
+            for line in [
+                format!("Runs: {}", stats.runs),
+                format!("Clears: {}", stats.campaign_clears),
+                format!("Kills: {}", stats.kills),
+                format!("Best: {}", format_score(stats.best_score)),
+            ] {
+                column.spawn((
+                    Text::new(line),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                ));
+            }
+        });
+}
+
+/// Cloud save line: backend and last sync outcome, with conflict keys
+fn profile_sync_status(cloud: Option<&CloudSync>) -> (String, Color) {
+    let Some(cloud) = cloud.filter(|c| c.is_enabled()) else {
+        let status = SyncStatus::LocalOnly;
+        return (status.label(), status.color());
+    };
+    let mut text = format!("CLOUD ({}): {}", cloud.backend_name(), cloud.status.label());
+    if cloud.status == SyncStatus::Conflict {
+        text.push_str("   [C] Keep this machine's   [V] Use cloud");
+    }
+    (text, cloud.status.color())
+}
+
+fn profile_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    joystick: Res<JoystickState>,
+    pointer: Res<MenuPointer>,
+    time: Res<Time>,
+    mut selection: ResMut<MenuSelection>,
+    mut save_data: ResMut<SaveData>,
+    mut transitions: EventWriter<TransitionEvent>,
+    cloud: Option<ResMut<CloudSync>>,
+) {
+    if let Some(mut cloud) = cloud.filter(|c| c.is_enabled()) {
+        let conflict = cloud.status == SyncStatus::Conflict;
+        if keyboard.just_pressed(KeyCode::KeyC) {
+            cloud.request = Some(if conflict {
+                SyncRequest::KeepLocal
+            } else {
+                SyncRequest::Sync
+            });
+        } else if conflict && keyboard.just_pressed(KeyCode::KeyV) {
+            cloud.request = Some(SyncRequest::UseCloud);
+        }
+    }
+
+    selection.cooldown -= time.delta_secs();
+
+    let nav = get_nav_input(&keyboard, &joystick);
+    if nav != 0 && selection.cooldown <= 0.0 && selection.total > 0 {
+        selection.index =
+            (selection.index as i32 + nav).rem_euclid(selection.total as i32) as usize;
+        selection.cooldown = MENU_NAV_COOLDOWN;
+    }
+
+    if is_confirm(&keyboard, &joystick) || pointer.clicked {
+        if let Some(&modifier) = profile_modifiers().get(selection.index) {
+            save_data.prestige.toggle_modifier(modifier);
+        }
+    }
+
     if keyboard.just_pressed(KeyCode::Escape) || joystick.back() {
-        next_state.set(GameState::MainMenu);
+        transitions.send(TransitionEvent::to(GameState::MainMenu));
+    }
+}
+
+fn update_profile_modifiers(
+    save_data: Res<SaveData>,
+    mut labels: Query<(&ProfileModifierLabel, &mut Text)>,
+) {
+    if !save_data.is_changed() {
+        return;
+    }
+    for (label, mut text) in labels.iter_mut() {
+        **text = format!(
+            "{}: {}",
+            label.0.name().to_uppercase(),
+            modifier_status(&save_data.prestige, label.0)
+        );
+    }
+}
+
+fn update_profile_sync_status(
+    cloud: Option<Res<CloudSync>>,
+    mut labels: Query<(&mut Text, &mut TextColor), With<ProfileSyncLabel>>,
+) {
+    let Some(cloud) = cloud.filter(|c| c.is_changed()) else {
+        return;
+    };
+    let (text, color) = profile_sync_status(Some(&cloud));
+    for (mut label, mut label_color) in labels.iter_mut() {
+        **label = text.clone();
+        label_color.0 = color;
     }
 }
 
@@ -1140,6 +2018,7 @@ fn spawn_faction_select(
     mut commands: Commands,
     mut selection: ResMut<MenuSelection>,
     mut session: ResMut<GameSession>,
+    emblems: Res<crate::assets::FactionEmblemCache>,
 ) {
     selection.index = 0;
     selection.total = 2; // Elder Fleet: Minmatar vs Amarr only
@@ -1150,6 +2029,7 @@ fn spawn_faction_select(
     commands
         .spawn((
             FactionSelectRoot,
+            ScreenReveal::default(),
             Node {
                 width: Val::Percent(100.0),
                 height: Val::Percent(100.0),
@@ -1196,7 +2076,7 @@ fn spawn_faction_select(
                     ..default()
                 },))
                 .with_children(|row| {
-                    spawn_faction_card(row, Faction::Minmatar, 0);
+                    spawn_faction_card(row, &emblems, Faction::Minmatar, 0);
 
                     // VS divider
                     row.spawn((
@@ -1208,7 +2088,7 @@ fn spawn_faction_select(
                         TextColor(Color::srgb(0.5, 0.5, 0.5)),
                     ));
 
-                    spawn_faction_card(row, Faction::Amarr, 1);
+                    spawn_faction_card(row, &emblems, Faction::Amarr, 1);
                 });
 
             parent.spawn(Node {
@@ -1218,7 +2098,7 @@ fn spawn_faction_select(
 
             // Instructions
             parent.spawn((
-                Text::new("← → Navigate • A/ENTER Select • B/ESC Back"),
+                PadPrompt::new("← → Navigate • A/ENTER Select • B/ESC Back"),
                 TextFont {
                     font_size: 14.0,
                     ..default()
@@ -1228,9 +2108,13 @@ fn spawn_faction_select(
         });
 }
 
-fn spawn_faction_card(parent: &mut ChildBuilder, faction: Faction, index: usize) {
+fn spawn_faction_card(
+    parent: &mut ChildBuilder,
+    emblems: &crate::assets::FactionEmblemCache,
+    faction: Faction,
+    index: usize,
+) {
     let primary = faction.primary_color();
-    let secondary = faction.secondary_color();
     let rival = faction.rival();
     let ship_count = faction.player_ships().len();
 
@@ -1245,6 +2129,7 @@ fn spawn_faction_card(parent: &mut ChildBuilder, faction: Faction, index: usize)
     parent
         .spawn((
             FactionSelectRoot,
+            RevealStagger,
             MenuItem { index },
             Node {
                 width: Val::Px(320.0),
@@ -1259,7 +2144,7 @@ fn spawn_faction_card(parent: &mut ChildBuilder, faction: Faction, index: usize)
             BorderColor(primary.with_alpha(0.4)),
         ))
         .with_children(|card| {
-            // Header row: Faction name + emblem placeholder
+            // Header row: Faction name + emblem
             card.spawn(Node {
                 width: Val::Percent(100.0),
                 justify_content: JustifyContent::SpaceBetween,
@@ -1276,17 +2161,7 @@ fn spawn_faction_card(parent: &mut ChildBuilder, faction: Faction, index: usize)
                     TextColor(primary),
                 ));
 
-                // Faction emblem placeholder (colored square)
-                header.spawn((
-                    Node {
-                        width: Val::Px(40.0),
-                        height: Val::Px(40.0),
-                        border: UiRect::all(Val::Px(2.0)),
-                        ..default()
-                    },
-                    BackgroundColor(secondary.with_alpha(0.6)),
-                    BorderColor(primary.with_alpha(0.8)),
-                ));
+                spawn_faction_emblem(header, emblems, faction, 40.0);
             });
 
             // Full name
@@ -1355,11 +2230,7 @@ fn spawn_faction_card(parent: &mut ChildBuilder, faction: Faction, index: usize)
                 ));
 
                 // Tank doctrine
-                let tank_text = match faction.tank_type() {
-                    TankDoctrine::Shield => "Shield",
-                    TankDoctrine::Armor => "Armor",
-                    TankDoctrine::Speed => "Speed",
-                };
+                let tank_text = faction.tank_type().name();
                 stats.spawn((
                     Text::new(tank_text),
                     TextFont {
@@ -1433,6 +2304,7 @@ fn spawn_faction_card(parent: &mut ChildBuilder, faction: Faction, index: usize)
 fn faction_select_input(
     keyboard: Res<ButtonInput<KeyCode>>,
     joystick: Res<JoystickState>,
+    pointer: Res<MenuPointer>,
     mut selection: ResMut<MenuSelection>,
     mut session: ResMut<GameSession>,
     endless: Res<crate::core::EndlessMode>,
@@ -1486,7 +2358,7 @@ fn faction_select_input(
     }
 
     // Confirm selection
-    if is_confirm(&keyboard, &joystick) {
+    if is_confirm(&keyboard, &joystick) || pointer.clicked {
         let player_faction = factions[selection.index];
         let enemy_faction = player_faction.rival();
 
@@ -1523,6 +2395,7 @@ fn spawn_difficulty_menu(mut commands: Commands, mut selection: ResMut<MenuSelec
     commands
         .spawn((
             DifficultyMenuRoot,
+            ScreenReveal::default(),
             Node {
                 width: Val::Percent(100.0),
                 height: Val::Percent(100.0),
@@ -1560,7 +2433,7 @@ fn spawn_difficulty_menu(mut commands: Commands, mut selection: ResMut<MenuSelec
             });
 
             parent.spawn((
-                Text::new("↑↓ Navigate • A/ENTER Select • B/ESC Back"),
+                PadPrompt::new("↑↓ Navigate • A/ENTER Select • B/ESC Back"),
                 TextFont {
                     font_size: 14.0,
                     ..default()
@@ -1622,6 +2495,7 @@ fn spawn_difficulty_item(parent: &mut ChildBuilder, diff: Difficulty, index: usi
 fn difficulty_menu_input(
     keyboard: Res<ButtonInput<KeyCode>>,
     joystick: Res<JoystickState>,
+    pointer: Res<MenuPointer>,
     mut selection: ResMut<MenuSelection>,
     mut difficulty: ResMut<Difficulty>,
     time: Res<Time>,
@@ -1636,7 +2510,7 @@ fn difficulty_menu_input(
         selection.cooldown = MENU_NAV_COOLDOWN;
     }
 
-    if is_confirm(&keyboard, &joystick) {
+    if is_confirm(&keyboard, &joystick) || pointer.clicked {
         *difficulty = Difficulty::all()[selection.index.min(3)];
         info!(
             "Selected difficulty: {} - {}",
@@ -1777,6 +2651,7 @@ fn spawn_stage_select(
     commands
         .spawn((
             StageSelectRoot,
+            ScreenReveal::default(),
             Node {
                 width: Val::Percent(100.0),
                 height: Val::Percent(100.0),
@@ -1810,28 +2685,67 @@ fn spawn_stage_select(
                 TextColor(Color::srgb(0.5, 0.5, 0.5)),
             ));
 
+            // Run mode toggle and leaderboard for the highlighted stage
+            parent.spawn((
+                RunModeText,
+                Text::new(""),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.9, 0.7, 0.3)),
+            ));
+            parent.spawn((
+                RunModeBoardText,
+                Text::new(""),
+                TextFont {
+                    font_size: 13.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.6, 0.6, 0.6)),
+            ));
+
             parent.spawn(Node {
                 height: Val::Px(10.0),
                 ..default()
             });
 
-            // Act 1 row
-            spawn_act_row(parent, 1, "THE CALL", &STAGES[0..4], highest);
+            // Act rows scroll when the window is too short for all three
+            parent
+                .spawn((
+                    MenuScroll,
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        row_gap: Val::Px(20.0),
+                        padding: UiRect::all(Val::Px(6.0)),
+                        overflow: Overflow::scroll_y(),
+                        ..default()
+                    },
+                ))
+                .with_children(|rows| {
+                    spawn_act_row(rows, 1, "THE CALL", &STAGES[0..4], highest);
+                    spawn_act_row(rows, 2, "THE STORM", &STAGES[4..9], highest);
+                    spawn_act_row(rows, 3, "LIBERATION", &STAGES[9..13], highest);
+                });
 
-            // Act 2 row
-            spawn_act_row(parent, 2, "THE STORM", &STAGES[4..9], highest);
+            parent.spawn(Node {
+                height: Val::Px(15.0),
+                ..default()
+            });
 
-            // Act 3 row
-            spawn_act_row(parent, 3, "LIBERATION", &STAGES[9..13], highest);
+            super::spawn_run_code_box(parent);
 
             parent.spawn(Node {
-                height: Val::Px(15.0),
+                height: Val::Px(10.0),
                 ..default()
             });
 
             // Instructions
             parent.spawn((
-                Text::new("← → ↑ ↓ Navigate • A/ENTER Select • B/ESC Back"),
+                PadPrompt::new(
+                    "← → ↑ ↓ Navigate • Y/TAB Mode • A/ENTER/Click Select • C Run Code • Wheel Scroll • B/ESC Back",
+                ),
                 TextFont {
                     font_size: 14.0,
                     ..default()
@@ -1913,6 +2827,7 @@ fn spawn_stage_card(parent: &mut ChildBuilder, stage: &StageInfo, locked: bool,
             MenuItem {
                 index: (stage.stage - 1) as usize,
             },
+            RevealStagger,
             StageCard {
                 stage: stage.stage,
                 locked,
@@ -1980,10 +2895,15 @@ fn spawn_stage_card(parent: &mut ChildBuilder, stage: &StageInfo, locked: bool,
 }
 
 fn stage_select_input(
+    mut commands: Commands,
     keyboard: Res<ButtonInput<KeyCode>>,
     joystick: Res<JoystickState>,
+    pointer: Res<MenuPointer>,
     mut selection: ResMut<MenuSelection>,
     mut campaign: ResMut<CampaignState>,
+    mut run_mode: ResMut<RunMode>,
+    mut daily: ResMut<crate::core::DailyChallenge>,
+    mut run_codes: ResMut<RunCodes>,
     session: Res<GameSession>,
     save_data: Res<crate::core::SaveData>,
     time: Res<Time>,
@@ -1997,6 +2917,12 @@ fn stage_select_input(
 ) {
     selection.cooldown -= time.delta_secs();
 
+    // Typing a run code takes over the screen until it's flown or cancelled
+    if keyboard.just_pressed(KeyCode::KeyC) {
+        commands.init_resource::<super::RunCodeEntry>();
+        return;
+    }
+
     let faction = session.player_faction;
     let enemy = session.enemy_faction;
     let highest = save_data.get_highest_stage(faction.short_name(), enemy.short_name());
@@ -2079,35 +3005,50 @@ fn stage_select_input(
         }
     }
 
-    // Confirm selection
-    if is_confirm(&keyboard, &joystick) {
+    // Cycle campaign / time attack / score attack / daily
+    if keyboard.just_pressed(KeyCode::Tab) || joystick.y_button() {
+        *run_mode = run_mode.next();
+    }
+
+    // The daily picks its own mission and allows one attempt
+    if *run_mode == RunMode::Daily {
+        if is_confirm(&keyboard, &joystick) || pointer.clicked {
+            let today = crate::core::DailyChallenge::today();
+            if save_data.daily_result(today.day).is_some() {
+                info!("Daily Challenge {} already flown", today.day);
+            } else {
+                let (act, mission_idx) = stage_mission(today.stage);
+                campaign.act = act;
+                campaign.mission_index = mission_idx;
+                *daily = today;
+                run_codes.imported = None;
+                info!(
+                    "Daily Challenge {}: Stage {} ({})",
+                    today.day,
+                    today.stage,
+                    today.modifier_names()
+                );
+                next_state.set(GameState::ShipSelect);
+            }
+        }
+    } else if is_confirm(&keyboard, &joystick) || pointer.clicked {
         let stage = (selection.index + 1) as u32;
         let locked = stage > highest + 1;
 
         if !locked {
             // Set campaign state to selected stage
-            let act = if stage <= 4 {
-                crate::core::Act::Act1
-            } else if stage <= 9 {
-                crate::core::Act::Act2
-            } else {
-                crate::core::Act::Act3
-            };
-
-            let mission_idx = match act {
-                crate::core::Act::Act1 => (stage - 1) as usize,
-                crate::core::Act::Act2 => (stage - 5) as usize,
-                crate::core::Act::Act3 => (stage - 10) as usize,
-            };
+            let (act, mission_idx) = stage_mission(stage);
 
             campaign.act = act;
             campaign.mission_index = mission_idx;
+            run_codes.imported = None;
 
             info!(
-                "Selected Stage {} (Act {:?}, Mission {})",
+                "Selected Stage {} (Act {:?}, Mission {}) - {}",
                 stage,
                 act,
-                mission_idx + 1
+                mission_idx + 1,
+                run_mode.name()
             );
             next_state.set(GameState::ShipSelect);
         }
@@ -2119,6 +3060,96 @@ fn stage_select_input(
     }
 }
 
+/// Act and mission index for a stage number (1-13)
+pub(crate) fn stage_mission(stage: u32) -> (crate::core::Act, usize) {
+    if stage <= 4 {
+        (crate::core::Act::Act1, (stage - 1) as usize)
+    } else if stage <= 9 {
+        (crate::core::Act::Act2, (stage - 5) as usize)
+    } else {
+        (crate::core::Act::Act3, (stage - 10) as usize)
+    }
+}
+
+/// Show the selected run mode and the highlighted stage's leaderboard
+fn update_run_mode_panel(
+    run_mode: Res<RunMode>,
+    selection: Res<MenuSelection>,
+    save_data: Res<crate::core::SaveData>,
+    mut mode_query: Query<&mut Text, (With<RunModeText>, Without<RunModeBoardText>)>,
+    mut board_query: Query<&mut Text, (With<RunModeBoardText>, Without<RunModeText>)>,
+) {
+    for mut text in mode_query.iter_mut() {
+        **text = format!("MODE: {} - {}", run_mode.name(), run_mode.description());
+    }
+
+    let board = if *run_mode == RunMode::Daily {
+        daily_panel_text(&save_data)
+    } else if run_mode.is_attack() {
+        let (act, mission_idx) = stage_mission((selection.index + 1) as u32);
+        let mission = act.missions().get(mission_idx);
+        let entries = mission
+            .map(|m| save_data.leaderboard(*run_mode, m.id))
+            .unwrap_or(&[]);
+        let board = if entries.is_empty() {
+            "No runs yet".to_string()
+        } else {
+            entries
+                .iter()
+                .enumerate()
+                .map(|(i, e)| format!("{}. {} {}", i + 1, run_mode.format_value(e.value), e.ship))
+                .collect::<Vec<_>>()
+                .join("   ")
+        };
+        match mission {
+            Some(mission) => format!(
+                "MEDAL: {}\n{}",
+                medal_progress_text(
+                    *run_mode,
+                    mission.id,
+                    save_data.prestige.medal(*run_mode, mission.id)
+                ),
+                board
+            ),
+            None => board,
+        }
+    } else {
+        String::new()
+    };
+    for mut text in board_query.iter_mut() {
+        if **text != board {
+            **text = board.clone();
+        }
+    }
+}
+
+/// Today's daily: mission and modifiers, or the score if already flown,
+/// plus the countdown to the next one
+fn daily_panel_text(save_data: &crate::core::SaveData) -> String {
+    let now = crate::core::unix_now();
+    let today = crate::core::DailyChallenge::for_day(crate::core::day_of(now));
+    let countdown = crate::core::format_countdown(crate::core::seconds_until_next_daily(now));
+
+    if let Some(result) = save_data.daily_result(today.day) {
+        return format!(
+            "Flown today: {} points   Next daily in {}",
+            result.score, countdown
+        );
+    }
+    let (act, mission_idx) = stage_mission(today.stage);
+    let mission = act
+        .missions()
+        .get(mission_idx)
+        .map(|m| m.name)
+        .unwrap_or("MISSION");
+    format!(
+        "Today: {} - {}   Next daily in {}",
+        mission,
+        today.modifier_names(),
+        countdown
+    )
+}
+
 // ============================================================================
 // Ship Select
 // ============================================================================
@@ -2157,15 +3188,25 @@ fn spawn_ship_menu(
     difficulty: Res<Difficulty>,
     session: Res<GameSession>,
     save_data: Res<crate::core::SaveData>,
+    emblems: Res<crate::assets::FactionEmblemCache>,
+    mut images: ResMut<Assets<Image>>,
+    (ng_plus, run_mode, run_codes): (Res<NewGamePlus>, Res<RunMode>, Res<RunCodes>),
 ) {
     let ships = session.player_ships();
     let faction = session.player_faction;
     let enemy = session.enemy_faction;
     let faction_color = faction.primary_color();
 
-    selection.index = 0;
+    // A run code arrives with its ship already picked
+    selection.index = run_codes
+        .imported
+        .as_ref()
+        .map_or(0, |code| code.ship_index.min(ships.len() - 1));
     selection.total = ships.len();
 
+    let preview = ShipPreviewImages::new(&mut images);
+    commands.insert_resource(preview.clone());
+
     // Calculate stat ranges for normalization
     let max_speed = ships.iter().map(|s| s.speed).fold(0.0_f32, f32::max);
     let max_damage = ships.iter().map(|s| s.damage).fold(0.0_f32, f32::max);
@@ -2175,6 +3216,7 @@ fn spawn_ship_menu(
     commands
         .spawn((
             ShipMenuRoot,
+            ScreenReveal::default(),
             Node {
                 width: Val::Percent(100.0),
                 height: Val::Percent(100.0),
@@ -2188,15 +3230,25 @@ fn spawn_ship_menu(
             BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
         ))
         .with_children(|parent| {
-            // Title with faction name
-            parent.spawn((
-                Text::new(format!("{} FLEET - SELECT SHIP", faction.short_name())),
-                TextFont {
-                    font_size: 36.0,
+            // Title with faction emblem and name
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(14.0),
                     ..default()
-                },
-                TextColor(faction_color),
-            ));
+                })
+                .with_children(|title| {
+                    spawn_faction_emblem(title, &emblems, faction, 44.0);
+                    title.spawn((
+                        Text::new(format!("{} FLEET - SELECT SHIP", faction.short_name())),
+                        TextFont {
+                            font_size: 36.0,
+                            ..default()
+                        },
+                        TextColor(faction_color),
+                    ));
+                });
 
             // Subtitle with weapon doctrine and difficulty
             parent.spawn((
@@ -2212,35 +3264,45 @@ fn spawn_ship_menu(
                 TextColor(Color::srgb(0.5, 0.5, 0.5)),
             ));
 
-            // Main content: Detail panel (left) + Ship list (right)
+            // Main content: Preview (left) + Detail panel + Ship list (right)
             parent
                 .spawn(Node {
                     width: Val::Percent(100.0),
-                    max_width: Val::Px(900.0),
+                    max_width: Val::Px(1200.0),
                     flex_direction: FlexDirection::Row,
                     justify_content: JustifyContent::Center,
                     column_gap: Val::Px(30.0),
                     ..default()
                 })
                 .with_children(|content| {
-                    // Left: Selected ship detail panel
+                    // Left: Turntable, radar chart, ability and skin
+                    spawn_ship_preview_pane(content, &preview, faction_color);
+
+                    // Selected ship detail panel
                     spawn_ship_detail_panel(
                         content,
                         &ships[0],
                         faction_color,
+                        faction.tank_type(),
                         max_speed,
                         max_damage,
                         max_health,
                         max_fire_rate,
                     );
 
-                    // Right: Ship list
+                    // Right: Ship list, scrolling if the hangar outgrows it
                     content
-                        .spawn(Node {
-                            flex_direction: FlexDirection::Column,
-                            row_gap: Val::Px(8.0),
-                            ..default()
-                        })
+                        .spawn((
+                            MenuScroll,
+                            Node {
+                                flex_direction: FlexDirection::Column,
+                                row_gap: Val::Px(8.0),
+                                padding: UiRect::all(Val::Px(6.0)),
+                                max_height: Val::Px(520.0),
+                                overflow: Overflow::scroll_y(),
+                                ..default()
+                            },
+                        ))
                         .with_children(|list| {
                             for (i, ship) in ships.iter().enumerate() {
                                 let is_unlocked = save_data.is_ship_unlocked(
@@ -2254,9 +3316,42 @@ fn spawn_ship_menu(
                         });
                 });
 
+            // New Game+ depth, once a campaign has been cleared
+            let ng_plus_unlocked =
+                *run_mode == RunMode::Campaign && save_data.ng_plus.unlocked > 0;
+            if ng_plus_unlocked {
+                parent.spawn((
+                    ShipNgPlusLabel,
+                    Text::new(ng_plus_choice_label(&ng_plus)),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(1.0, 0.5, 0.3)),
+                ));
+            }
+
+            // Ironman, for campaign runs
+            let campaign = *run_mode == RunMode::Campaign;
+            if campaign {
+                parent.spawn((
+                    ShipIronmanLabel,
+                    Text::new(ironman_label(save_data.fleet_roster.ironman)),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.9, 0.35, 0.3)),
+                ));
+            }
+
             // Navigation hint
             parent.spawn((
-                Text::new("↑↓ Navigate • A/ENTER Select • B/ESC Back"),
+                PadPrompt::new(match (ng_plus_unlocked, campaign) {
+                    (true, _) => "↑↓ Navigate • TAB/Y Skin • X/M Mutators • N New Game+ • SELECT/I Ironman • A/ENTER Select • B/ESC Back",
+                    (false, true) => "↑↓ Navigate • TAB/Y Skin • X/M Mutators • SELECT/I Ironman • A/ENTER Select • B/ESC Back",
+                    (false, false) => "↑↓ Navigate • TAB/Y Skin • X/M Mutators • A/ENTER Select • B/ESC Back",
+                }),
                 TextFont {
                     font_size: 12.0,
                     ..default()
@@ -2267,10 +3362,12 @@ fn spawn_ship_menu(
 }
 
 /// Spawn the detailed ship info panel (left side)
+#[allow(clippy::too_many_arguments)]
 fn spawn_ship_detail_panel(
     parent: &mut ChildBuilder,
     ship: &ShipDef,
     faction_color: Color,
+    doctrine: TankDoctrine,
     max_speed: f32,
     max_damage: f32,
     max_health: f32,
@@ -2401,6 +3498,32 @@ fn spawn_ship_detail_panel(
                 BackgroundColor(Color::srgb(0.25, 0.25, 0.3)),
             ));
 
+            // Tank doctrine (faction-wide)
+            panel
+                .spawn(Node {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(4.0),
+                    ..default()
+                })
+                .with_children(|tank| {
+                    tank.spawn((
+                        Text::new(format!("TANK DOCTRINE: {}", doctrine.name().to_uppercase())),
+                        TextFont {
+                            font_size: 10.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.5, 0.5, 0.5)),
+                    ));
+                    tank.spawn((
+                        Text::new(doctrine.description()),
+                        TextFont {
+                            font_size: 13.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.5, 0.7, 0.9)),
+                    ));
+                });
+
             // Special ability
             panel
                 .spawn(Node {
@@ -2527,6 +3650,7 @@ fn spawn_ship_list_item(
     parent
         .spawn((
             ShipMenuRoot,
+            RevealStagger,
             MenuItem { index },
             Node {
                 width: Val::Px(280.0),
@@ -2695,17 +3819,62 @@ fn update_ship_detail_panel(
     }
 }
 
+/// New Game+ line on ship select
+fn ng_plus_choice_label(ng_plus: &NewGamePlus) -> String {
+    if ng_plus.is_active() {
+        format!("NEW GAME+ {}", ng_plus.depth)
+    } else {
+        "NEW GAME+ OFF".to_string()
+    }
+}
+
+/// Ironman line on ship select
+fn ironman_label(ironman: bool) -> String {
+    if ironman {
+        "IRONMAN ON - ships destroyed are lost for the campaign".to_string()
+    } else {
+        "IRONMAN OFF".to_string()
+    }
+}
+
 fn ship_menu_input(
     keyboard: Res<ButtonInput<KeyCode>>,
     joystick: Res<JoystickState>,
+    pointer: Res<MenuPointer>,
     mut selection: ResMut<MenuSelection>,
     mut session: ResMut<GameSession>,
     time: Res<Time>,
     mut transitions: EventWriter<TransitionEvent>,
-    save_data: Res<crate::core::SaveData>,
+    mut save_data: ResMut<crate::core::SaveData>,
+    run_mode: Res<RunMode>,
+    mut commands: Commands,
+    mut ng_plus: ResMut<NewGamePlus>,
+    mut ng_plus_labels: Query<&mut Text, (With<ShipNgPlusLabel>, Without<ShipIronmanLabel>)>,
+    mut ironman_labels: Query<&mut Text, With<ShipIronmanLabel>>,
 ) {
     selection.cooldown -= time.delta_secs();
 
+    // Ironman is picked before the campaign starts
+    if (keyboard.just_pressed(KeyCode::KeyI) || joystick.select()) && *run_mode == RunMode::Campaign
+    {
+        let roster = &mut save_data.fleet_roster;
+        roster.ironman = !roster.ironman;
+        for mut label in ironman_labels.iter_mut() {
+            **label = ironman_label(roster.ironman);
+        }
+    }
+
+    // New Game+ depth steps through whatever's unlocked, then back off
+    if keyboard.just_pressed(KeyCode::KeyN)
+        && *run_mode == RunMode::Campaign
+        && save_data.ng_plus.unlocked > 0
+    {
+        ng_plus.depth = save_data.ng_plus.cycle(ng_plus.depth);
+        for mut label in ng_plus_labels.iter_mut() {
+            **label = ng_plus_choice_label(&ng_plus);
+        }
+    }
+
     let nav = get_nav_input(&keyboard, &joystick);
     if nav != 0 && selection.cooldown <= 0.0 {
         selection.index =
@@ -2713,11 +3882,21 @@ fn ship_menu_input(
         selection.cooldown = MENU_NAV_COOLDOWN;
     }
 
+    // Mutators (opens over the menu); attack and daily runs fly stock
+    if keyboard.just_pressed(KeyCode::KeyM) || joystick.x_button() {
+        if *run_mode == RunMode::Campaign {
+            commands.init_resource::<super::MutatorsView>();
+        } else {
+            info!("Mutators apply to campaign runs only");
+        }
+        return;
+    }
+
     let ships = session.player_ships();
     let faction = session.player_faction;
     let enemy = session.enemy_faction;
 
-    if is_confirm(&keyboard, &joystick) && selection.index < ships.len() {
+    if (is_confirm(&keyboard, &joystick) || pointer.clicked) && selection.index < ships.len() {
         let ship = &ships[selection.index];
         let is_unlocked = save_data.is_ship_unlocked(
             ship.type_id,
@@ -2729,6 +3908,11 @@ fn ship_menu_input(
         if is_unlocked {
             session.selected_ship_index = selection.index;
             info!("Selected ship: {} ({})", ship.name, ship.class.name());
+            // A fresh campaign starts with the whole hangar rested
+            if *run_mode == RunMode::Campaign {
+                save_data.fleet_roster.begin_campaign();
+                crate::systems::enlist_unlocked(&mut save_data, &session);
+            }
             // Slow transition into gameplay
             transitions.send(TransitionEvent::slow(GameState::Playing));
         } else {
@@ -2755,15 +3939,25 @@ struct PauseSelection {
 }
 
 /// Pause menu items
-const PAUSE_ITEM_COUNT: usize = 8;
+const PAUSE_ITEM_COUNT: usize = 18;
 const PAUSE_IDX_RESUME: usize = 0;
 const PAUSE_IDX_MASTER: usize = 1;
 const PAUSE_IDX_MUSIC: usize = 2;
 const PAUSE_IDX_SFX: usize = 3;
-const PAUSE_IDX_SHAKE: usize = 4;
-const PAUSE_IDX_RUMBLE: usize = 5;
-const PAUSE_IDX_RESTART: usize = 6;
-const PAUSE_IDX_QUIT: usize = 7;
+const PAUSE_IDX_VOICE: usize = 4;
+const PAUSE_IDX_SHAKE: usize = 5;
+const PAUSE_IDX_RUMBLE: usize = 6;
+const PAUSE_IDX_MOTION: usize = 7;
+const PAUSE_IDX_HUD: usize = 8;
+const PAUSE_IDX_GRAPHICS: usize = 9;
+const PAUSE_IDX_AIM: usize = 10;
+const PAUSE_IDX_DIALOGUE: usize = 11;
+const PAUSE_IDX_SUBTITLE_SIZE: usize = 12;
+const PAUSE_IDX_SUBTITLE_BACKGROUND: usize = 13;
+const PAUSE_IDX_CODEX: usize = 14;
+const PAUSE_IDX_COMMS: usize = 15;
+const PAUSE_IDX_RESTART: usize = 16;
+const PAUSE_IDX_QUIT: usize = 17;
 
 /// Slider type for identifying which setting to adjust
 #[derive(Clone, Copy, PartialEq)]
@@ -2771,6 +3965,7 @@ enum SliderType {
     MasterVolume,
     MusicVolume,
     SfxVolume,
+    VoiceVolume,
     ScreenShake,
     Rumble,
 }
@@ -2787,14 +3982,40 @@ struct SliderValueText {
     slider_type: SliderType,
 }
 
+/// Marker for a toggle row's value text (pause item index)
+#[derive(Component)]
+struct ToggleValueText(usize);
+
+fn motion_label(reduced_motion: bool) -> &'static str {
+    if reduced_motion {
+        "REDUCED"
+    } else {
+        "FULL"
+    }
+}
+
+fn aid_label(enabled: bool) -> &'static str {
+    if enabled {
+        "ON"
+    } else {
+        "OFF"
+    }
+}
+
 fn spawn_pause_menu(
     mut commands: Commands,
     campaign: Res<CampaignState>,
     score: Res<ScoreSystem>,
-    session: Res<GameSession>,
+    theme: Res<super::UiTheme>,
     sound_settings: Res<crate::systems::SoundSettings>,
     screen_shake: Res<crate::systems::ScreenShake>,
     rumble_settings: Res<crate::systems::RumbleSettings>,
+    motion: Res<crate::systems::MotionSettings>,
+    hud_style: Res<super::HudStyle>,
+    quality: Res<crate::systems::GraphicsQuality>,
+    targeting_aid: Res<crate::systems::TargetingAidSettings>,
+    dialogue: Res<crate::systems::DialogueSettings>,
+    run_codes: Res<RunCodes>,
 ) {
     commands.insert_resource(PauseSelection::default());
 
@@ -2803,11 +4024,10 @@ fn spawn_pause_menu(
         .map(|m| m.name)
         .unwrap_or("MISSION");
 
-    let faction_color = session.player_faction.primary_color();
-
     commands
         .spawn((
             PauseMenuRoot,
+            ScreenReveal::default(),
             Node {
                 width: Val::Percent(100.0),
                 height: Val::Percent(100.0),
@@ -2817,7 +4037,7 @@ fn spawn_pause_menu(
                 row_gap: Val::Px(8.0),
                 ..default()
             },
-            BackgroundColor(Color::srgba(0.0, 0.0, 0.05, 0.85)),
+            BackgroundColor(theme.panel_background().with_alpha(0.85)),
         ))
         .with_children(|parent| {
             // Title
@@ -2827,7 +4047,7 @@ fn spawn_pause_menu(
                     font_size: 48.0,
                     ..default()
                 },
-                TextColor(faction_color),
+                TextColor(theme.primary),
             ));
 
             // Mission info
@@ -2853,6 +4073,17 @@ fn spawn_pause_menu(
                 TextColor(Color::srgb(0.4, 0.6, 0.8)),
             ));
 
+            if let Some(code) = &run_codes.current {
+                parent.spawn((
+                    Text::new(format!("Run Code: {}", code)),
+                    TextFont {
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.9, 0.7, 0.3)),
+                ));
+            }
+
             // Spacer
             parent.spawn(Node {
                 height: Val::Px(12.0),
@@ -2895,6 +4126,15 @@ fn spawn_pause_menu(
                 SliderType::SfxVolume,
             );
 
+            // Voice-over volume slider
+            spawn_settings_slider(
+                parent,
+                PAUSE_IDX_VOICE,
+                "VOICE",
+                sound_settings.voice_volume,
+                SliderType::VoiceVolume,
+            );
+
             // Screen shake slider
             spawn_settings_slider(
                 parent,
@@ -2913,11 +4153,61 @@ fn spawn_pause_menu(
                 SliderType::Rumble,
             );
 
+            // Reduced motion toggle
+            spawn_settings_toggle(
+                parent,
+                PAUSE_IDX_MOTION,
+                "MOTION",
+                motion_label(motion.reduced_motion),
+            );
+
+            // Capacitor wheel / classic bars
+            spawn_settings_toggle(parent, PAUSE_IDX_HUD, "HUD", hud_style.name());
+
+            // Act nebulae / plain starfield
+            spawn_settings_toggle(parent, PAUSE_IDX_GRAPHICS, "GRAPHICS", quality.name());
+
+            // Boss lead reticle and range rings
+            spawn_settings_toggle(
+                parent,
+                PAUSE_IDX_AIM,
+                "AIM AID",
+                aid_label(targeting_aid.enabled),
+            );
+
+            // How long dialogue lines linger
+            spawn_settings_toggle(
+                parent,
+                PAUSE_IDX_DIALOGUE,
+                "DIALOGUE",
+                dialogue.speed.name(),
+            );
+
+            // Subtitle text size and the panel behind it
+            spawn_settings_toggle(
+                parent,
+                PAUSE_IDX_SUBTITLE_SIZE,
+                "SUBTITLE SIZE",
+                dialogue.subtitle_size.name(),
+            );
+            spawn_settings_toggle(
+                parent,
+                PAUSE_IDX_SUBTITLE_BACKGROUND,
+                "SUBTITLE PANEL",
+                dialogue.subtitle_background.name(),
+            );
+
             parent.spawn(Node {
                 height: Val::Px(4.0),
                 ..default()
             });
 
+            // Codex (opens over the pause menu)
+            spawn_pause_menu_item(parent, PAUSE_IDX_CODEX, "CODEX");
+
+            // Recent dialogue (opens over the pause menu)
+            spawn_pause_menu_item(parent, PAUSE_IDX_COMMS, "COMMS LOG");
+
             // Restart button
             spawn_pause_menu_item(parent, PAUSE_IDX_RESTART, "RESTART MISSION");
 
@@ -2932,7 +4222,7 @@ fn spawn_pause_menu(
 
             // Controls hint
             parent.spawn((
-                Text::new("↑↓ Navigate • ←→ Adjust • A/ENTER Select"),
+                PadPrompt::new("↑↓ Navigate • ←→ Adjust • A/ENTER Select"),
                 TextFont {
                     font_size: 11.0,
                     ..default()
@@ -3045,12 +4335,72 @@ fn spawn_settings_slider(
         });
 }
 
+/// Spawn an on/off settings row
+fn spawn_settings_toggle(parent: &mut ChildBuilder, index: usize, label: &str, value: &str) {
+    parent
+        .spawn((
+            PauseMenuItem(index),
+            Node {
+                padding: UiRect::axes(Val::Px(15.0), Val::Px(6.0)),
+                min_width: Val::Px(260.0),
+                flex_direction: FlexDirection::Row,
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 0.8)),
+        ))
+        .with_children(|row| {
+            row.spawn((
+                PauseMenuItemText(index),
+                Text::new(label),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.6, 0.6, 0.6)),
+            ));
+
+            row.spawn((
+                ToggleValueText(index),
+                Text::new(value),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.4, 0.6, 0.8)),
+            ));
+        });
+}
+
 #[derive(Component)]
+#[require(Interaction, Outline(hidden_focus_outline))]
 struct PauseMenuItem(usize);
 
 #[derive(Component)]
 struct PauseMenuItemText(usize);
 
+/// Hovering a pause menu row selects it, clicking also confirms it
+fn pause_menu_pointer(
+    mut pointer: ResMut<MenuPointer>,
+    mut selection: ResMut<PauseSelection>,
+    items: Query<(&Interaction, &PauseMenuItem), Changed<Interaction>>,
+) {
+    for (interaction, item) in items.iter() {
+        match interaction {
+            Interaction::Pressed => {
+                selection.index = item.0;
+                pointer.clicked = true;
+            }
+            Interaction::Hovered if pointer.active && selection.index != item.0 => {
+                selection.index = item.0;
+            }
+            _ => {}
+        }
+    }
+}
+
 fn pause_menu_input(
     keyboard: Res<ButtonInput<KeyCode>>,
     joystick: Res<JoystickState>,
@@ -3060,12 +4410,37 @@ fn pause_menu_input(
     mut sound_settings: ResMut<crate::systems::SoundSettings>,
     mut screen_shake: ResMut<crate::systems::ScreenShake>,
     mut rumble_settings: ResMut<crate::systems::RumbleSettings>,
-    mut item_query: Query<(&PauseMenuItem, &mut BackgroundColor)>,
+    mut item_query: Query<(&PauseMenuItem, &mut BackgroundColor, &mut Outline)>,
     mut text_query: Query<(&PauseMenuItemText, &mut TextColor)>,
     mut slider_fill_query: Query<(&SliderFill, &mut Node)>,
     mut slider_text_query: Query<(&SliderValueText, &mut Text)>,
     time: Res<Time>,
     mut cooldown: Local<f32>,
+    // Grouped to stay within Bevy's system parameter limit
+    (
+        mut motion,
+        mut hud_style,
+        mut quality,
+        mut targeting_aid,
+        mut dialogue,
+        theme,
+        mut commands,
+        save_data,
+        run_mode,
+        pointer,
+    ): (
+        ResMut<crate::systems::MotionSettings>,
+        ResMut<super::HudStyle>,
+        ResMut<crate::systems::GraphicsQuality>,
+        ResMut<crate::systems::TargetingAidSettings>,
+        ResMut<crate::systems::DialogueSettings>,
+        Res<super::UiTheme>,
+        Commands,
+        Res<crate::core::SaveData>,
+        Res<RunMode>,
+        Res<MenuPointer>,
+    ),
+    mut toggle_text_query: Query<(&ToggleValueText, &mut Text), Without<SliderValueText>>,
 ) {
     *cooldown -= time.delta_secs();
 
@@ -3096,6 +4471,10 @@ fn pause_menu_input(
                 sound_settings.sfx_volume = (sound_settings.sfx_volume + delta).clamp(0.0, 1.0);
                 *cooldown = 0.08;
             }
+            PAUSE_IDX_VOICE => {
+                sound_settings.voice_volume = (sound_settings.voice_volume + delta).clamp(0.0, 1.0);
+                *cooldown = 0.08;
+            }
             PAUSE_IDX_SHAKE => {
                 screen_shake.multiplier = (screen_shake.multiplier + delta).clamp(0.0, 1.0);
                 *cooldown = 0.08;
@@ -3104,6 +4483,34 @@ fn pause_menu_input(
                 rumble_settings.intensity = (rumble_settings.intensity + delta).clamp(0.0, 1.0);
                 *cooldown = 0.08;
             }
+            PAUSE_IDX_MOTION => {
+                motion.reduced_motion = !motion.reduced_motion;
+                *cooldown = MENU_NAV_COOLDOWN;
+            }
+            PAUSE_IDX_HUD => {
+                *hud_style = hud_style.toggled();
+                *cooldown = MENU_NAV_COOLDOWN;
+            }
+            PAUSE_IDX_GRAPHICS => {
+                *quality = quality.toggled();
+                *cooldown = MENU_NAV_COOLDOWN;
+            }
+            PAUSE_IDX_AIM => {
+                targeting_aid.enabled = !targeting_aid.enabled;
+                *cooldown = MENU_NAV_COOLDOWN;
+            }
+            PAUSE_IDX_DIALOGUE => {
+                dialogue.speed = dialogue.speed.stepped(h_input);
+                *cooldown = MENU_NAV_COOLDOWN;
+            }
+            PAUSE_IDX_SUBTITLE_SIZE => {
+                dialogue.subtitle_size = dialogue.subtitle_size.stepped(h_input);
+                *cooldown = MENU_NAV_COOLDOWN;
+            }
+            PAUSE_IDX_SUBTITLE_BACKGROUND => {
+                dialogue.subtitle_background = dialogue.subtitle_background.stepped(h_input);
+                *cooldown = MENU_NAV_COOLDOWN;
+            }
             _ => {}
         }
     }
@@ -3114,6 +4521,7 @@ fn pause_menu_input(
             SliderType::MasterVolume => sound_settings.master_volume,
             SliderType::MusicVolume => sound_settings.music_volume,
             SliderType::SfxVolume => sound_settings.sfx_volume,
+            SliderType::VoiceVolume => sound_settings.voice_volume,
             SliderType::ScreenShake => screen_shake.multiplier,
             SliderType::Rumble => rumble_settings.intensity,
         };
@@ -3124,49 +4532,115 @@ fn pause_menu_input(
             SliderType::MasterVolume => sound_settings.master_volume,
             SliderType::MusicVolume => sound_settings.music_volume,
             SliderType::SfxVolume => sound_settings.sfx_volume,
+            SliderType::VoiceVolume => sound_settings.voice_volume,
             SliderType::ScreenShake => screen_shake.multiplier,
             SliderType::Rumble => rumble_settings.intensity,
         };
         **text = format!("{}%", (value * 100.0) as i32);
     }
 
-    // Update visual selection
-    let session_faction_color = Color::srgb(0.8, 0.5, 0.2); // Default orange
-    for (item, mut bg) in item_query.iter_mut() {
-        if item.0 == selection.index {
-            bg.0 = Color::srgba(0.3, 0.25, 0.15, 0.9);
+    // Update visual selection (outlined while keyboard or controller drive)
+    for (item, mut bg, mut outline) in item_query.iter_mut() {
+        let selected = item.0 == selection.index;
+        if selected {
+            bg.0 = theme.selected_background();
         } else {
             bg.0 = Color::srgba(0.1, 0.1, 0.1, 0.8);
         }
+        let focus = if selected && !pointer.active {
+            theme.accent
+        } else {
+            Color::NONE
+        };
+        if outline.color != focus {
+            outline.color = focus;
+        }
     }
     for (item, mut color) in text_query.iter_mut() {
         if item.0 == selection.index {
-            color.0 = session_faction_color;
+            color.0 = theme.accent;
         } else {
             color.0 = Color::srgb(0.6, 0.6, 0.6);
         }
     }
 
-    // Selection (confirm button)
-    if is_confirm(&keyboard, &joystick) {
+    // Selection (confirm button or click)
+    if is_confirm(&keyboard, &joystick) || pointer.clicked {
         match selection.index {
             PAUSE_IDX_RESUME => {
                 next_state.set(GameState::Playing);
             }
+            PAUSE_IDX_CODEX => {
+                commands.init_resource::<super::CodexView>();
+            }
+            PAUSE_IDX_COMMS => {
+                commands.init_resource::<super::CommsLogView>();
+            }
+            // Iron Clone runs get no second attempt at a mission
+            PAUSE_IDX_RESTART
+                if save_data
+                    .prestige
+                    .run_modifiers(*run_mode)
+                    .contains(&RunModifier::IronClone) =>
+            {
+                info!("Iron Clone: no mission restarts");
+            }
             PAUSE_IDX_RESTART => {
                 transitions.send(TransitionEvent::quick(GameState::Playing));
             }
             PAUSE_IDX_QUIT => {
                 transitions.send(TransitionEvent::to(GameState::MainMenu));
             }
-            PAUSE_IDX_MASTER | PAUSE_IDX_MUSIC | PAUSE_IDX_SFX | PAUSE_IDX_SHAKE
-            | PAUSE_IDX_RUMBLE => {
+            PAUSE_IDX_MOTION => {
+                motion.reduced_motion = !motion.reduced_motion;
+            }
+            PAUSE_IDX_HUD => {
+                *hud_style = hud_style.toggled();
+            }
+            PAUSE_IDX_GRAPHICS => {
+                *quality = quality.toggled();
+            }
+            PAUSE_IDX_AIM => {
+                targeting_aid.enabled = !targeting_aid.enabled;
+            }
+            PAUSE_IDX_DIALOGUE => {
+                dialogue.speed = dialogue.speed.stepped(1);
+            }
+            PAUSE_IDX_SUBTITLE_SIZE => {
+                dialogue.subtitle_size = dialogue.subtitle_size.stepped(1);
+            }
+            PAUSE_IDX_SUBTITLE_BACKGROUND => {
+                dialogue.subtitle_background = dialogue.subtitle_background.stepped(1);
+            }
+            PAUSE_IDX_MASTER | PAUSE_IDX_MUSIC | PAUSE_IDX_SFX | PAUSE_IDX_VOICE
+            | PAUSE_IDX_SHAKE | PAUSE_IDX_RUMBLE => {
                 // Pressing confirm on sliders does nothing (use left/right)
             }
             _ => {}
         }
     }
 
+    if motion.is_changed()
+        || hud_style.is_changed()
+        || quality.is_changed()
+        || targeting_aid.is_changed()
+        || dialogue.is_changed()
+    {
+        for (toggle, mut text) in toggle_text_query.iter_mut() {
+            let value = match toggle.0 {
+                PAUSE_IDX_MOTION => motion_label(motion.reduced_motion),
+                PAUSE_IDX_HUD => hud_style.name(),
+                PAUSE_IDX_GRAPHICS => quality.name(),
+                PAUSE_IDX_AIM => aid_label(targeting_aid.enabled),
+                PAUSE_IDX_DIALOGUE => dialogue.speed.name(),
+                PAUSE_IDX_SUBTITLE_SIZE => dialogue.subtitle_size.name(),
+                PAUSE_IDX_SUBTITLE_BACKGROUND => dialogue.subtitle_background.name(),
+                _ => continue,
+            };
+            **text = value.to_string();
+        }
+    }
+
     // Quick resume with ESC or Start
     if keyboard.just_pressed(KeyCode::Escape) || joystick.start() {
         next_state.set(GameState::Playing);
@@ -3174,7 +4648,10 @@ fn pause_menu_input(
 }
 
 /// Get horizontal input (-1 left, 0 none, 1 right)
-fn get_horizontal_input(keyboard: &ButtonInput<KeyCode>, joystick: &JoystickState) -> i32 {
+pub(crate) fn get_horizontal_input(
+    keyboard: &ButtonInput<KeyCode>,
+    joystick: &JoystickState,
+) -> i32 {
     let mut h = 0;
 
     // Keyboard
@@ -3218,6 +4695,7 @@ fn spawn_death_screen(
     mut nightmare: ResMut<crate::games::caldari_gallente::ShiigeruNightmare>,
     session: Res<GameSession>,
     save_data: Res<SaveData>,
+    playfield: Res<Playfield>,
 ) {
     // Initialize selection resource
     commands.insert_resource(DeathSelection::default());
@@ -3269,8 +4747,8 @@ fn spawn_death_screen(
     ];
 
     for i in 0..25 {
-        let x = (fastrand::f32() - 0.5) * SCREEN_WIDTH;
-        let y = (fastrand::f32() - 0.5) * SCREEN_HEIGHT;
+        let x = (fastrand::f32() - 0.5) * playfield.size.x;
+        let y = (fastrand::f32() - 0.5) * playfield.size.y;
         let size = 4.0 + fastrand::f32() * 12.0;
         let color = debris_colors[i % debris_colors.len()];
 
@@ -3299,8 +4777,8 @@ fn spawn_death_screen(
             spin: (fastrand::f32() - 0.5) * 0.2,
         },
         Sprite {
-            color: Color::srgb(0.27, 0.25, 0.24), // Frozen body color
-            custom_size: Some(Vec2::new(40.0, 20.0)),
+            color: crate::systems::CORPSE_COLOR,
+            custom_size: Some(crate::systems::CORPSE_SIZE),
             ..default()
         },
         Transform::from_xyz(0.0, 50.0, 5.0)
@@ -3311,6 +4789,7 @@ fn spawn_death_screen(
     commands
         .spawn((
             GameOverRoot,
+            ScreenReveal::default(),
             Node {
                 width: Val::Percent(100.0),
                 height: Val::Percent(100.0),
@@ -3597,7 +5076,7 @@ fn spawn_death_screen(
 
             // Controller hint
             parent.spawn((
-                Text::new("← → Navigate • A/ENTER Select • B/ESC Quit"),
+                PadPrompt::new("← → Navigate • A/ENTER Select • B/ESC Quit"),
                 TextFont {
                     font_size: 12.0,
                     ..default()
@@ -3613,8 +5092,10 @@ fn update_death_screen_animation(
     mut corpse_query: Query<(&mut Transform, &DeathCorpse), Without<DeathDebris>>,
     selection: Res<DeathSelection>,
     mut button_query: Query<(&DeathButton, &mut BorderColor, &mut BackgroundColor)>,
+    playfield: Res<Playfield>,
 ) {
     let dt = time.delta_secs();
+    let wrap = playfield.half() + 20.0;
 
     // Animate debris
     for (mut transform, debris) in debris_query.iter_mut() {
@@ -3623,17 +5104,17 @@ fn update_death_screen_animation(
         transform.rotate_z(debris.spin * dt);
 
         // Wrap around screen
-        if transform.translation.x < -SCREEN_WIDTH / 2.0 - 20.0 {
-            transform.translation.x = SCREEN_WIDTH / 2.0 + 20.0;
+        if transform.translation.x < -wrap.x {
+            transform.translation.x = wrap.x;
         }
-        if transform.translation.x > SCREEN_WIDTH / 2.0 + 20.0 {
-            transform.translation.x = -SCREEN_WIDTH / 2.0 - 20.0;
+        if transform.translation.x > wrap.x {
+            transform.translation.x = -wrap.x;
         }
-        if transform.translation.y < -SCREEN_HEIGHT / 2.0 - 20.0 {
-            transform.translation.y = SCREEN_HEIGHT / 2.0 + 20.0;
+        if transform.translation.y < -wrap.y {
+            transform.translation.y = wrap.y;
         }
-        if transform.translation.y > SCREEN_HEIGHT / 2.0 + 20.0 {
-            transform.translation.y = -SCREEN_HEIGHT / 2.0 - 20.0;
+        if transform.translation.y > wrap.y {
+            transform.translation.y = -wrap.y;
         }
     }
 
@@ -3670,6 +5151,7 @@ fn death_screen_input(
     mut selection: ResMut<DeathSelection>,
     mut score: ResMut<ScoreSystem>,
     mut campaign: ResMut<CampaignState>,
+    run_mode: Res<RunMode>,
     mut transitions: EventWriter<TransitionEvent>,
 ) {
     // Navigation
@@ -3692,6 +5174,10 @@ fn death_screen_input(
         || joystick.confirm()
     {
         match selection.selected {
+            // The daily's one attempt is spent; pick something else to fly
+            DeathAction::Retry if *run_mode == RunMode::Daily => {
+                transitions.send(TransitionEvent::to(GameState::StageSelect));
+            }
             DeathAction::Retry => {
                 score.reset_game();
                 *campaign = CampaignState::default();
@@ -3884,7 +5370,24 @@ fn spawn_stage_complete(
     campaign: Res<CampaignState>,
     score: Res<ScoreSystem>,
     session: Res<GameSession>,
+    run_mode: Res<RunMode>,
+    result: Res<AttackResult>,
+    save_data: Res<SaveData>,
+    roster: Res<crate::entities::WingmanRoster>,
+    battle_log: Res<BattleLog>,
+    run_codes: Res<RunCodes>,
 ) {
+    if run_mode.is_attack() {
+        spawn_attack_results(
+            &mut commands,
+            &campaign,
+            &result,
+            &save_data,
+            run_codes.current.as_ref(),
+        );
+        return;
+    }
+
     let mission_name = campaign
         .current_mission()
         .map(|m| m.name)
@@ -3910,6 +5413,7 @@ fn spawn_stage_complete(
     commands
         .spawn((
             StageCompleteRoot,
+            ScreenReveal::default(),
             Node {
                 width: Val::Percent(100.0),
                 height: Val::Percent(100.0),
@@ -3974,6 +5478,8 @@ fn spawn_stage_complete(
                 TextColor(Color::srgb(0.6, 0.6, 0.6)),
             ));
 
+            spawn_damage_breakdown(parent, &battle_log);
+
             // Bonus objective
             if !bonus_text.is_empty() {
                 parent.spawn(Node {
@@ -4040,6 +5546,35 @@ fn spawn_stage_complete(
                     });
             }
 
+            // Wingmen lost this run
+            let memorial = roster.memorial();
+            if !memorial.is_empty() {
+                parent.spawn(Node {
+                    height: Val::Px(10.0),
+                    ..default()
+                });
+
+                parent.spawn((
+                    Text::new("IN MEMORIAM"),
+                    TextFont {
+                        font_size: 18.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.8, 0.6, 0.4)),
+                ));
+
+                for line in memorial {
+                    parent.spawn((
+                        Text::new(line),
+                        TextFont {
+                            font_size: 14.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.6, 0.55, 0.5)),
+                    ));
+                }
+            }
+
             parent.spawn(Node {
                 height: Val::Px(20.0),
                 ..default()
@@ -4047,7 +5582,222 @@ fn spawn_stage_complete(
 
             // Continue prompt
             parent.spawn((
-                Text::new("A/ENTER Continue • B/ESC Quit"),
+                PadPrompt::new("A/ENTER Continue • B/ESC Quit"),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.5, 0.5, 0.5)),
+            ));
+        });
+}
+
+/// Results for a Time/Score Attack run: the result, its rank and the board
+fn spawn_attack_results(
+    commands: &mut Commands,
+    campaign: &CampaignState,
+    result: &AttackResult,
+    save_data: &SaveData,
+    run_code: Option<&RunCode>,
+) {
+    let mode = result.mode;
+    let mission_name = campaign
+        .current_mission()
+        .map(|m| m.name)
+        .unwrap_or("MISSION");
+    let daily = mode == RunMode::Daily;
+    let flag = result.integrity.and_then(|integrity| integrity.flag);
+    let rank_text = match (result.rank, flag) {
+        _ if daily => "Today's attempt is recorded".to_string(),
+        (None, Some(flag)) => format!("Not ranked: {}", flag.description()),
+        (Some(0), _) => "NEW RECORD!".to_string(),
+        (Some(rank), _) => format!("#{} on the leaderboard", rank + 1),
+        (None, None) => "Not on the leaderboard".to_string(),
+    };
+    // Daily: recent days instead of a per-mission board
+    let board: Vec<(String, bool)> = if daily {
+        let today = crate::core::day_of(crate::core::unix_now());
+        save_data
+            .daily_results
+            .iter()
+            .rev()
+            .take(crate::core::LEADERBOARD_SIZE)
+            .map(|r| {
+                let when = match today.saturating_sub(r.day) {
+                    0 => "Today".to_string(),
+                    1 => "Yesterday".to_string(),
+                    days => format!("{} days ago", days),
+                };
+                (format!("{:>10}  {}", r.score, when), r.day == today)
+            })
+            .collect()
+    } else {
+        save_data
+            .leaderboard(mode, result.mission_id)
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                (
+                    format!(
+                        "{}. {:>10}  {}",
+                        i + 1,
+                        mode.format_value(entry.value),
+                        entry.ship
+                    ),
+                    result.rank == Some(i),
+                )
+            })
+            .collect()
+    };
+    let footer = if daily {
+        format!(
+            "Next daily in {} • A/B Stage Select",
+            crate::core::format_countdown(crate::core::seconds_until_next_daily(
+                crate::core::unix_now()
+            ))
+        )
+    } else {
+        "A/ENTER Retry • B/ESC Stage Select".to_string()
+    };
+
+    commands
+        .spawn((
+            StageCompleteRoot,
+            ScreenReveal::default(),
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.02, 0.05, 0.9)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(format!("{} COMPLETE", mode.name())),
+                TextFont {
+                    font_size: 48.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.3, 0.8, 1.0)),
+            ));
+
+            parent.spawn((
+                Text::new(mission_name),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(COLOR_MINMATAR),
+            ));
+
+            parent.spawn((
+                Text::new(mode.format_value(result.value)),
+                TextFont {
+                    font_size: 40.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+
+            parent.spawn((
+                Text::new(rank_text),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(if result.rank == Some(0) {
+                    Color::srgb(1.0, 0.85, 0.2) // Gold
+                } else {
+                    Color::srgb(0.6, 0.6, 0.6)
+                }),
+            ));
+
+            // The run's medal, and what the next one asks for
+            if !daily {
+                let (medal_text, medal_color) = match result.medal {
+                    Some(medal) if result.new_medal => {
+                        (format!("NEW {} MEDAL!", medal.name()), medal.color())
+                    }
+                    Some(medal) => (format!("{} MEDAL", medal.name()), medal.color()),
+                    None => ("NO MEDAL".to_string(), Color::srgb(0.5, 0.5, 0.5)),
+                };
+                parent.spawn((
+                    Text::new(medal_text),
+                    TextFont {
+                        font_size: 22.0,
+                        ..default()
+                    },
+                    TextColor(medal_color),
+                ));
+                parent.spawn((
+                    Text::new(medal_progress_text(
+                        mode,
+                        result.mission_id,
+                        save_data.prestige.medal(mode, result.mission_id),
+                    )),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.6, 0.6, 0.6)),
+                ));
+            }
+
+            parent.spawn(Node {
+                height: Val::Px(10.0),
+                ..default()
+            });
+
+            for (line, highlight) in board {
+                parent.spawn((
+                    Text::new(line),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(if highlight {
+                        Color::srgb(1.0, 0.85, 0.2)
+                    } else {
+                        Color::srgb(0.7, 0.7, 0.7)
+                    }),
+                ));
+            }
+
+            parent.spawn(Node {
+                height: Val::Px(20.0),
+                ..default()
+            });
+
+            // So the run can be handed to someone else
+            if let Some(code) = run_code {
+                parent.spawn((
+                    Text::new(format!("RUN CODE {}", code)),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.9, 0.7, 0.3)),
+                ));
+            }
+
+            // For checking the run against its replay
+            if let Some(integrity) = &result.integrity {
+                parent.spawn((
+                    Text::new(format!("VERIFY {}", integrity.verify_code())),
+                    TextFont {
+                        font_size: 13.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.5, 0.5, 0.55)),
+                ));
+            }
+
+            parent.spawn((
+                PadPrompt::new(footer),
                 TextFont {
                     font_size: 14.0,
                     ..default()
@@ -4061,18 +5811,45 @@ fn stage_complete_input(
     keyboard: Res<ButtonInput<KeyCode>>,
     joystick: Res<JoystickState>,
     mut campaign: ResMut<CampaignState>,
+    run_mode: Res<RunMode>,
     mut transitions: EventWriter<TransitionEvent>,
+    mut campaign_events: EventWriter<CampaignCompleteEvent>,
 ) {
+    // One attempt per daily: straight back to stage select
+    if *run_mode == RunMode::Daily {
+        if is_confirm(&keyboard, &joystick)
+            || keyboard.just_pressed(KeyCode::Escape)
+            || joystick.back()
+        {
+            transitions.send(TransitionEvent::to(GameState::StageSelect));
+        }
+        return;
+    }
+
+    // Attack modes replay the same mission or go back to pick another
+    if run_mode.is_attack() {
+        if is_confirm(&keyboard, &joystick) {
+            transitions.send(TransitionEvent::to(GameState::Playing));
+        }
+        if keyboard.just_pressed(KeyCode::Escape) || joystick.back() {
+            transitions.send(TransitionEvent::to(GameState::StageSelect));
+        }
+        return;
+    }
+
     if keyboard.just_pressed(KeyCode::Space)
         || keyboard.just_pressed(KeyCode::Enter)
         || joystick.confirm()
     {
         // Advance to next mission
         if campaign.complete_mission() {
-            // More missions available
-            transitions.send(TransitionEvent::to(GameState::Playing));
+            // More missions available; plot the route there
+            transitions.send(TransitionEvent::to(GameState::RouteMap));
         } else {
             // Campaign complete!
+            campaign_events.send(CampaignCompleteEvent {
+                module: ELDER_FLEET,
+            });
             transitions.send(TransitionEvent::slow(GameState::Victory));
         }
     }
@@ -4100,10 +5877,17 @@ fn spawn_victory_screen(
     session: Res<GameSession>,
     campaign: Res<CampaignState>,
     mut save_data: ResMut<SaveData>,
+    emblems: Res<crate::assets::FactionEmblemCache>,
+    playfield: Res<Playfield>,
+    ng_plus: Res<NewGamePlus>,
 ) {
     // Initialize selection
     commands.insert_resource(VictorySelection::default());
 
+    // The clear itself is banked by the New Game+ systems; don't wait on them
+    let next_depth = (ng_plus.depth + 1).min(MAX_NG_PLUS_DEPTH);
+    let deepest_cleared = save_data.ng_plus.deepest_cleared.max(ng_plus.depth);
+
     // Check for new high score
     let previous_high =
         save_data.get_high_score(session.player_faction.name(), session.enemy_faction.name());
@@ -4121,8 +5905,8 @@ fn spawn_victory_screen(
 
     // Spawn celebration particles
     for _ in 0..60 {
-        let x = (fastrand::f32() - 0.5) * SCREEN_WIDTH;
-        let y = -SCREEN_HEIGHT / 2.0 - fastrand::f32() * 100.0;
+        let x = (fastrand::f32() - 0.5) * playfield.size.x;
+        let y = -playfield.half_height() - fastrand::f32() * 100.0;
         let vx = (fastrand::f32() - 0.5) * 100.0;
         let vy = 80.0 + fastrand::f32() * 120.0;
         let size = 4.0 + fastrand::f32() * 8.0;
@@ -4155,6 +5939,7 @@ fn spawn_victory_screen(
     commands
         .spawn((
             VictoryRoot,
+            ScreenReveal::default(),
             Node {
                 width: Val::Percent(100.0),
                 height: Val::Percent(100.0),
@@ -4167,6 +5952,8 @@ fn spawn_victory_screen(
             BackgroundColor(Color::srgba(0.0, 0.02, 0.05, 0.9)),
         ))
         .with_children(|parent| {
+            spawn_faction_emblem(parent, &emblems, session.player_faction, 96.0);
+
             // Victory header
             parent.spawn((
                 Text::new("LIBERATION COMPLETE"),
@@ -4177,6 +5964,17 @@ fn spawn_victory_screen(
                 TextColor(Color::srgb(1.0, 0.85, 0.2)), // Gold
             ));
 
+            if ng_plus.is_active() {
+                parent.spawn((
+                    Text::new(format!("NEW GAME+ DEPTH {}", ng_plus.depth)),
+                    TextFont {
+                        font_size: 28.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(1.0, 0.5, 0.3)),
+                ));
+            }
+
             parent.spawn((
                 Text::new("The Amarr Empire Has Fallen"),
                 TextFont {
@@ -4256,6 +6054,19 @@ fn spawn_victory_screen(
                         },
                         TextColor(Color::srgb(1.0, 0.6, 0.3)),
                     ));
+
+                    stats.spawn((
+                        Text::new(if deepest_cleared > 0 {
+                            format!("Deepest NG+ Cleared: {}", deepest_cleared)
+                        } else {
+                            format!("New Game+ {} Unlocked", next_depth)
+                        }),
+                        TextFont {
+                            font_size: 18.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(1.0, 0.5, 0.3)),
+                    ));
                 });
 
             parent.spawn(Node {
@@ -4322,6 +6133,33 @@ fn spawn_victory_screen(
                         ));
                     });
 
+                    // NEW GAME+ button
+                    row.spawn((
+                        VictoryButton {
+                            action: VictoryAction::NewGamePlus,
+                        },
+                        Node {
+                            width: Val::Px(160.0),
+                            height: Val::Px(50.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            border: UiRect::all(Val::Px(2.0)),
+                            ..default()
+                        },
+                        BorderColor(Color::srgb(1.0, 0.85, 0.2)),
+                        BackgroundColor(Color::NONE),
+                    ))
+                    .with_children(|btn| {
+                        btn.spawn((
+                            Text::new(format!("NEW GAME+ {}", next_depth)),
+                            TextFont {
+                                font_size: 22.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(1.0, 0.85, 0.2)),
+                        ));
+                    });
+
                     // MAIN MENU button
                     row.spawn((
                         VictoryButton {
@@ -4367,7 +6205,7 @@ fn spawn_victory_screen(
 
             // Controller hint
             parent.spawn((
-                Text::new("← → Navigate • A/ENTER Select"),
+                PadPrompt::new("← → Navigate • A/ENTER Select"),
                 TextFont {
                     font_size: 12.0,
                     ..default()
@@ -4393,9 +6231,11 @@ fn format_score(score: u64) -> String {
 /// Update victory celebration particles
 fn update_victory_particles(
     time: Res<Time>,
+    playfield: Res<Playfield>,
     mut query: Query<(&mut Transform, &mut VictoryParticle, &mut Sprite)>,
 ) {
     let dt = time.delta_secs();
+    let half = playfield.half();
 
     for (mut transform, mut particle, mut sprite) in query.iter_mut() {
         // Move particle upward
@@ -4414,9 +6254,9 @@ fn update_victory_particles(
         sprite.color = sprite.color.with_alpha(alpha);
 
         // Reset if off screen or dead
-        if particle.lifetime <= 0.0 || transform.translation.y > SCREEN_HEIGHT / 2.0 + 50.0 {
-            transform.translation.x = (fastrand::f32() - 0.5) * SCREEN_WIDTH;
-            transform.translation.y = -SCREEN_HEIGHT / 2.0 - fastrand::f32() * 50.0;
+        if particle.lifetime <= 0.0 || transform.translation.y > half.y + 50.0 {
+            transform.translation.x = (fastrand::f32() - 0.5) * playfield.size.x;
+            transform.translation.y = -half.y - fastrand::f32() * 50.0;
             particle.lifetime = particle.max_lifetime;
             particle.velocity.y = 80.0 + fastrand::f32() * 120.0;
         }
@@ -4430,19 +6270,20 @@ fn victory_input(
     mut score: ResMut<ScoreSystem>,
     mut campaign: ResMut<CampaignState>,
     mut transitions: EventWriter<TransitionEvent>,
+    mut ng_plus: ResMut<NewGamePlus>,
 ) {
     // Navigation (left/right for button selection)
     if keyboard.just_pressed(KeyCode::ArrowLeft)
         || keyboard.just_pressed(KeyCode::KeyA)
-        || joystick.dpad_x < 0
+        || joystick.dpad_just_left()
     {
-        selection.selected = VictoryAction::PlayAgain;
+        selection.selected = selection.selected.stepped(-1);
     }
     if keyboard.just_pressed(KeyCode::ArrowRight)
         || keyboard.just_pressed(KeyCode::KeyD)
-        || joystick.dpad_x > 0
+        || joystick.dpad_just_right()
     {
-        selection.selected = VictoryAction::MainMenu;
+        selection.selected = selection.selected.stepped(1);
     }
 
     // Confirm selection
@@ -4456,6 +6297,13 @@ fn victory_input(
                 *campaign = CampaignState::default();
                 transitions.send(TransitionEvent::to(GameState::ShipSelect));
             }
+            VictoryAction::NewGamePlus => {
+                score.reset_game();
+                *campaign = CampaignState::default();
+                ng_plus.depth = (ng_plus.depth + 1).min(MAX_NG_PLUS_DEPTH);
+                info!("Starting New Game+ {}", ng_plus.depth);
+                transitions.send(TransitionEvent::to(GameState::ShipSelect));
+            }
             VictoryAction::MainMenu => {
                 score.reset_game();
                 *campaign = CampaignState::default();
@@ -4767,20 +6615,21 @@ fn spawn_menu_item(parent: &mut ChildBuilder, text: &str, index: usize) {
 
 fn update_menu_selection<T: Component>(
     selection: Res<MenuSelection>,
+    theme: Res<super::UiTheme>,
     mut query: Query<(&MenuItem, &mut BorderColor, &mut BackgroundColor), With<T>>,
 ) {
     for (item, mut border, mut bg) in query.iter_mut() {
         if item.index == selection.index {
-            border.0 = COLOR_MINMATAR;
-            bg.0 = Color::srgba(0.25, 0.15, 0.1, 0.95);
+            border.0 = theme.primary;
+            bg.0 = theme.selected_background();
         } else {
-            border.0 = Color::srgb(0.3, 0.3, 0.3);
+            border.0 = theme.frame().with_alpha(0.5);
             bg.0 = Color::srgba(0.1, 0.1, 0.1, 0.9);
         }
     }
 }
 
-fn get_nav_input(keyboard: &ButtonInput<KeyCode>, joystick: &JoystickState) -> i32 {
+pub(crate) fn get_nav_input(keyboard: &ButtonInput<KeyCode>, joystick: &JoystickState) -> i32 {
     let mut nav = 0;
 
     // Keyboard (edge triggered)
@@ -4811,7 +6660,7 @@ fn get_nav_input(keyboard: &ButtonInput<KeyCode>, joystick: &JoystickState) -> i
     nav
 }
 
-fn is_confirm(keyboard: &ButtonInput<KeyCode>, joystick: &JoystickState) -> bool {
+pub(crate) fn is_confirm(keyboard: &ButtonInput<KeyCode>, joystick: &JoystickState) -> bool {
     keyboard.just_pressed(KeyCode::Space)
         || keyboard.just_pressed(KeyCode::Enter)
         || joystick.confirm()
@@ -1,18 +1,59 @@
 //! UI Systems
 //!
-//! HUD, menus, and visual feedback.
+//! HUD, the loading screen, menus (mouse, keyboard or controller), the
+//! codex, the comms log, the mutators screen, act interludes, the fleet
+//! roster, the controller lost overlay, faction theming, and visual
+//! feedback.
 
 pub mod backgrounds;
+pub mod battle_log;
 pub mod capacitor;
+pub mod codex;
+pub mod comms_log;
+pub mod controller_lost;
+pub mod crash_restore;
+pub mod emblem;
+pub mod event_log;
+pub mod fleet_roster;
 pub mod hud;
+pub mod interlude;
+pub mod loading;
 pub mod menu;
+pub mod mutators;
+pub mod pad_prompts;
+pub mod pointer;
+pub mod route_map;
+pub mod run_code;
+pub mod ship_preview;
+pub mod theme;
+pub mod toast;
 pub mod transitions;
+pub mod wave_tally;
 
 pub use backgrounds::*;
+pub use battle_log::*;
 pub use capacitor::*;
+pub use codex::*;
+pub use comms_log::*;
+pub use controller_lost::*;
+pub use crash_restore::*;
+pub use emblem::*;
+pub use event_log::*;
+pub use fleet_roster::*;
 pub use hud::*;
+pub use interlude::*;
+pub use loading::*;
 pub use menu::*;
+pub use mutators::*;
+pub use pad_prompts::*;
+pub use pointer::*;
+pub use route_map::*;
+pub use run_code::*;
+pub use ship_preview::*;
+pub use theme::*;
+pub use toast::*;
 pub use transitions::*;
+pub use wave_tally::*;
 
 use bevy::prelude::*;
 
@@ -27,6 +68,26 @@ impl Plugin for UiPlugin {
             CapacitorWheelPlugin,
             BackgroundPlugin,
             TransitionPlugin,
+            ToastPlugin,
+            EventLogOverlayPlugin,
+            CrashRestorePlugin,
+            ShipPreviewPlugin,
+            WaveTallyPlugin,
+            UiThemePlugin,
+            CodexScreenPlugin,
+            CommsLogPlugin,
+            MutatorsPlugin,
+            RouteMapPlugin,
+        ))
+        .add_plugins((
+            BattleLogPanelPlugin,
+            MenuPointerPlugin,
+            RunCodeEntryPlugin,
+            PadPromptPlugin,
+            ControllerLostPlugin,
+            FleetRosterScreenPlugin,
+            LoadingScreenPlugin,
+            InterludeScreenPlugin,
         ));
     }
 }
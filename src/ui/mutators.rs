@@ -0,0 +1,217 @@
+//! Mutators Screen
+//!
+//! Opened from ship select before a campaign run: every run modifier with
+//! its rules, score multiplier and whether it's on, off or still locked
+//! (with what unlocks it). Toggles are saved to the profile. While it's
+//! open the ship menu underneath ignores input.
+
+#![allow(dead_code)]
+
+use crate::core::*;
+use crate::systems::JoystickState;
+use crate::ui::menu::{get_nav_input, is_confirm, modifier_status, MENU_NAV_COOLDOWN};
+use crate::ui::{PadPrompt, UiTheme};
+use bevy::prelude::*;
+
+/// Mutators screen plugin
+pub struct MutatorsPlugin;
+
+impl Plugin for MutatorsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                close_mutators_on_state_change.run_if(state_changed::<GameState>),
+                mutators_input.run_if(resource_exists::<MutatorsView>),
+                refresh_mutators.run_if(resource_exists_and_changed::<MutatorsView>),
+                despawn_closed_mutators.run_if(resource_removed::<MutatorsView>),
+            )
+                .chain(),
+        );
+    }
+}
+
+/// The open mutators screen and its selected row; absent while closed
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MutatorsView {
+    /// Index into `RunModifier::ALL`
+    pub index: usize,
+}
+
+/// Mutators screen root
+#[derive(Component)]
+pub struct MutatorsRoot;
+
+/// Score multiplier line for the active modifiers
+pub fn score_mult_label(mult: f32) -> String {
+    format!("SCORE x{:.2}", mult)
+}
+
+fn close_mutators_on_state_change(mut commands: Commands) {
+    commands.remove_resource::<MutatorsView>();
+}
+
+fn mutators_input(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    joystick: Res<JoystickState>,
+    mut view: ResMut<MutatorsView>,
+    mut save_data: ResMut<SaveData>,
+    time: Res<Time>,
+    mut cooldown: Local<f32>,
+) {
+    *cooldown -= time.delta_secs();
+
+    if keyboard.just_pressed(KeyCode::Escape) || joystick.back() {
+        commands.remove_resource::<MutatorsView>();
+        return;
+    }
+
+    let count = RunModifier::ALL.len() as i32;
+    let nav = get_nav_input(&keyboard, &joystick);
+    if nav != 0 && *cooldown <= 0.0 {
+        view.index = (view.index as i32 + nav).rem_euclid(count) as usize;
+        *cooldown = MENU_NAV_COOLDOWN;
+    }
+
+    if is_confirm(&keyboard, &joystick) {
+        let modifier = RunModifier::ALL[view.index];
+        if save_data.prestige.toggle_modifier(modifier) {
+            info!("Mutator on: {}", modifier.name());
+        }
+        // Redraw the rows
+        view.set_changed();
+    }
+}
+
+fn despawn_closed_mutators(mut commands: Commands, query: Query<Entity, With<MutatorsRoot>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Rebuild the screen for the current selection and toggles
+fn refresh_mutators(
+    mut commands: Commands,
+    view: Res<MutatorsView>,
+    save_data: Res<SaveData>,
+    theme: Res<UiTheme>,
+    existing: Query<Entity, With<MutatorsRoot>>,
+) {
+    for entity in existing.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    let prestige = &save_data.prestige;
+    commands
+        .spawn((
+            MutatorsRoot,
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Px(10.0),
+                ..default()
+            },
+            BackgroundColor(theme.panel_background().with_alpha(0.97)),
+            ZIndex(500), // Above menus, below toasts
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("MUTATORS"),
+                TextFont {
+                    font_size: 40.0,
+                    ..default()
+                },
+                TextColor(theme.primary),
+            ));
+
+            for (index, modifier) in RunModifier::ALL.into_iter().enumerate() {
+                let selected = index == view.index;
+                let unlocked = prestige.modifier_unlocked(modifier);
+                let detail = if unlocked {
+                    modifier.description().to_string()
+                } else {
+                    format!("LOCKED - {}", modifier.unlock_hint())
+                };
+
+                parent
+                    .spawn((
+                        Node {
+                            width: Val::Px(480.0),
+                            flex_direction: FlexDirection::Column,
+                            padding: UiRect::axes(Val::Px(14.0), Val::Px(8.0)),
+                            border: UiRect::all(Val::Px(if selected { 2.0 } else { 1.0 })),
+                            row_gap: Val::Px(2.0),
+                            ..default()
+                        },
+                        BorderColor(if selected {
+                            theme.accent
+                        } else {
+                            theme.frame()
+                        }),
+                    ))
+                    .with_children(|row| {
+                        row.spawn((
+                            Text::new(format!(
+                                "{}: {}   x{:.2}",
+                                modifier.name().to_uppercase(),
+                                modifier_status(prestige, modifier),
+                                modifier.score_mult()
+                            )),
+                            TextFont {
+                                font_size: 18.0,
+                                ..default()
+                            },
+                            TextColor(if unlocked {
+                                Color::WHITE
+                            } else {
+                                Color::srgb(0.45, 0.45, 0.45)
+                            }),
+                        ));
+                        row.spawn((
+                            Text::new(detail),
+                            TextFont {
+                                font_size: 13.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(0.6, 0.6, 0.65)),
+                        ));
+                    });
+            }
+
+            parent.spawn((
+                Text::new(score_mult_label(
+                    prestige.modifier_score_mult(RunMode::Campaign),
+                )),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.85, 0.3)),
+            ));
+
+            parent.spawn((
+                PadPrompt::new("↑↓ Select • A/ENTER Toggle • B/ESC Done"),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.4, 0.4, 0.4)),
+            ));
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn score_mult_label_shows_two_places() {
+        assert_eq!(score_mult_label(1.0), "SCORE x1.00");
+        assert_eq!(score_mult_label(1.25 * 1.5), "SCORE x1.88");
+    }
+}
@@ -0,0 +1,37 @@
+//! Pad Prompts
+//!
+//! Button hints are written with Xbox labels ("A/ENTER Select") and shown
+//! with the labels of the pad in use - PlayStation or Steam Deck names when
+//! one of those is player one's pad - switching as soon as that changes.
+
+use bevy::prelude::*;
+
+use crate::systems::{pad_prompt, PadGlyphs};
+
+/// Pad prompt plugin
+pub struct PadPromptPlugin;
+
+impl Plugin for PadPromptPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, refresh_pad_prompts);
+    }
+}
+
+/// A button hint, as written with Xbox buttons
+#[derive(Component, Debug, Clone)]
+#[require(Text)]
+pub struct PadPrompt(pub String);
+
+impl PadPrompt {
+    pub fn new(template: impl Into<String>) -> Self {
+        Self(template.into())
+    }
+}
+
+fn refresh_pad_prompts(glyphs: Res<PadGlyphs>, mut prompts: Query<(Ref<PadPrompt>, &mut Text)>) {
+    for (prompt, mut text) in prompts.iter_mut() {
+        if glyphs.is_changed() || prompt.is_changed() {
+            **text = pad_prompt(&prompt.0, *glyphs);
+        }
+    }
+}
@@ -0,0 +1,206 @@
+//! Menu Pointer
+//!
+//! Mouse parity for the index-based menus: hovering a `MenuItem` moves the
+//! `MenuSelection` onto it and clicking confirms it, through the same input
+//! systems the keyboard and controller drive. Whichever device was used
+//! last decides how the selection shows: keyboard and controller get a
+//! focus outline, the mouse just its hover highlight.
+//!
+//! Lists marked `MenuScroll` clip to their box, scroll with the wheel and
+//! follow the selection when it moves off the visible part.
+
+use bevy::input::mouse::{MouseButtonInput, MouseMotion, MouseScrollUnit, MouseWheel};
+use bevy::input::InputSystem;
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+
+use super::menu::{MenuItem, MenuSelection};
+use super::UiTheme;
+use crate::systems::JoystickState;
+
+/// Pixels scrolled per wheel notch
+pub const WHEEL_LINE: f32 = 40.0;
+
+/// Focus outline thickness
+const FOCUS_OUTLINE_WIDTH: f32 = 2.0;
+
+/// Gap between a focused item and its outline
+const FOCUS_OUTLINE_OFFSET: f32 = 3.0;
+
+/// Menu pointer plugin
+pub struct MenuPointerPlugin;
+
+impl Plugin for MenuPointerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MenuPointer>()
+            .add_systems(
+                PreUpdate,
+                (
+                    track_input_device.after(InputSystem),
+                    select_under_pointer.after(UiSystem::Focus),
+                )
+                    .chain(),
+            )
+            .add_systems(Update, (draw_focus_outlines, scroll_menu_lists));
+    }
+}
+
+/// Pointer state the menus read alongside keyboard and controller input
+#[derive(Resource, Debug, Default)]
+pub struct MenuPointer {
+    /// The mouse was used more recently than the keyboard or a controller
+    pub active: bool,
+    /// A menu item was clicked this frame (it's already selected)
+    pub clicked: bool,
+    /// Wheel travel this frame in pixels, positive down the list
+    pub scroll: f32,
+}
+
+/// A menu list that clips to its box and scrolls
+#[derive(Component, Debug, Default)]
+#[require(ScrollPosition)]
+pub struct MenuScroll;
+
+/// Outline every menu item carries, shown only on keyboard/controller focus
+pub fn hidden_focus_outline() -> Outline {
+    Outline::new(
+        Val::Px(FOCUS_OUTLINE_WIDTH),
+        Val::Px(FOCUS_OUTLINE_OFFSET),
+        Color::NONE,
+    )
+}
+
+/// Wheel travel in pixels for one wheel event, positive down the list
+pub fn wheel_pixels(event: &MouseWheel) -> f32 {
+    match event.unit {
+        MouseScrollUnit::Line => -event.y * WHEEL_LINE,
+        MouseScrollUnit::Pixel => -event.y,
+    }
+}
+
+/// Follow whichever device was used last and gather this frame's wheel
+fn track_input_device(
+    mut pointer: ResMut<MenuPointer>,
+    mut motion: EventReader<MouseMotion>,
+    mut buttons: EventReader<MouseButtonInput>,
+    mut wheel: EventReader<MouseWheel>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    joystick: Res<JoystickState>,
+) {
+    pointer.scroll = wheel.read().map(wheel_pixels).sum();
+    let mouse_used = motion.read().count() > 0 || buttons.read().count() > 0;
+
+    if mouse_used || pointer.scroll != 0.0 {
+        pointer.active = true;
+    }
+    if keyboard.get_just_pressed().next().is_some() || joystick.in_use() {
+        pointer.active = false;
+    }
+}
+
+/// Hovering selects, pressing selects and confirms
+fn select_under_pointer(
+    mut pointer: ResMut<MenuPointer>,
+    mut selection: ResMut<MenuSelection>,
+    items: Query<(&Interaction, &MenuItem), Changed<Interaction>>,
+) {
+    pointer.clicked = false;
+    for (interaction, item) in items.iter() {
+        match interaction {
+            Interaction::Pressed => {
+                selection.index = item.index;
+                pointer.clicked = true;
+                pointer.active = true;
+            }
+            Interaction::Hovered if pointer.active && selection.index != item.index => {
+                selection.index = item.index;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Outline the selected item while the keyboard or a controller is driving
+fn draw_focus_outlines(
+    pointer: Res<MenuPointer>,
+    selection: Res<MenuSelection>,
+    theme: Res<UiTheme>,
+    mut items: Query<(&MenuItem, &mut Outline)>,
+) {
+    for (item, mut outline) in items.iter_mut() {
+        let focused = !pointer.active && item.index == selection.index;
+        let color = if focused { theme.accent } else { Color::NONE };
+        if outline.color != color {
+            outline.color = color;
+        }
+    }
+}
+
+/// Offset change that brings `item` (top, bottom) inside `view` (top, bottom)
+pub fn scroll_into_view(view: (f32, f32), item: (f32, f32)) -> f32 {
+    if item.0 < view.0 {
+        item.0 - view.0
+    } else if item.1 > view.1 {
+        (item.1 - view.1).min(item.0 - view.0)
+    } else {
+        0.0
+    }
+}
+
+/// Wheel scrolling, and keeping a newly selected item in view
+fn scroll_menu_lists(
+    pointer: Res<MenuPointer>,
+    selection: Res<MenuSelection>,
+    mut shown: Local<Option<usize>>,
+    mut lists: Query<
+        (Entity, &ComputedNode, &GlobalTransform, &mut ScrollPosition),
+        With<MenuScroll>,
+    >,
+    children: Query<&Children>,
+    items: Query<(&MenuItem, &ComputedNode, &GlobalTransform)>,
+) {
+    let moved = shown.replace(selection.index) != Some(selection.index);
+
+    for (list, node, transform, mut scroll) in lists.iter_mut() {
+        if pointer.scroll != 0.0 {
+            scroll.offset_y = (scroll.offset_y + pointer.scroll).max(0.0);
+            continue;
+        }
+        if !moved {
+            continue;
+        }
+        let Some((_, item_node, item_transform)) = children
+            .iter_descendants(list)
+            .filter_map(|entity| items.get(entity).ok())
+            .find(|(item, ..)| item.index == selection.index)
+        else {
+            continue;
+        };
+
+        // Layout positions are physical pixels; scroll offsets are logical
+        let span = |center: f32, height: f32| (center - height / 2.0, center + height / 2.0);
+        let view = span(transform.translation().y, node.size().y);
+        let item = span(item_transform.translation().y, item_node.size().y);
+        let delta = scroll_into_view(view, item) * node.inverse_scale_factor();
+        if delta != 0.0 {
+            scroll.offset_y = (scroll.offset_y + delta).max(0.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scrolling_brings_the_item_fully_into_view() {
+        let view = (100.0, 300.0);
+        assert_eq!(scroll_into_view(view, (150.0, 200.0)), 0.0);
+        // Below: scroll down until its bottom edge shows
+        assert_eq!(scroll_into_view(view, (320.0, 360.0)), 60.0);
+        // Above: scroll up to its top edge
+        assert_eq!(scroll_into_view(view, (60.0, 90.0)), -40.0);
+        // Taller than the view: line its top up with the view's
+        assert_eq!(scroll_into_view(view, (200.0, 500.0)), 100.0);
+    }
+}
@@ -0,0 +1,481 @@
+//! Route Map Screen
+//!
+//! Shown between campaign missions: a small star map from the system just
+//! cleared to the next mission's, with two side systems off the direct
+//! jump. Systems are coloured by security status. Picking a side system
+//! plays out its encounter before jumping on; the direct route jumps
+//! straight away. A black market the fleet can't pay for leaves the route
+//! open. The fleet roster opens from here to pick the next mission's ship,
+//! and a new act opens on its interlude before the map takes input.
+
+#![allow(dead_code)]
+
+use crate::core::*;
+use crate::systems::{choice_label, JoystickState};
+use crate::ui::menu::{get_horizontal_input, is_confirm, MENU_NAV_COOLDOWN};
+use crate::ui::{InterludeView, PadPrompt, RosterView, UiTheme};
+use bevy::prelude::*;
+
+/// Route index of the direct jump; side systems sit either side of it
+const DIRECT: usize = 1;
+
+/// Size of a system dot
+const NODE_SIZE: f32 = 12.0;
+
+/// Ring drawn around the selected system
+const SELECTED_RING: f32 = 18.0;
+
+/// Jump line colours
+const ROUTE_SELECTED: Color = Color::srgb(1.0, 0.85, 0.3);
+const ROUTE_IDLE: Color = Color::srgba(0.5, 0.55, 0.6, 0.45);
+
+/// Route map plugin
+pub struct RouteMapPlugin;
+
+impl Plugin for RouteMapPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::RouteMap), spawn_route_map)
+            .add_systems(
+                Update,
+                (
+                    route_map_input
+                        .run_if(not(resource_exists::<RosterView>))
+                        .run_if(not(resource_exists::<InterludeView>)),
+                    refresh_route_panel.run_if(resource_changed::<RouteMapView>),
+                    draw_route_lines,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::RouteMap)),
+            )
+            .add_systems(OnExit(GameState::RouteMap), close_route_map);
+    }
+}
+
+/// The open route map
+#[derive(Resource, Debug, Clone)]
+pub struct RouteMapView {
+    /// Side systems on this leg, left then right
+    pub leg: [SideSystem; 2],
+    /// 0 = left side system, 1 = direct, 2 = right side system
+    pub selected: usize,
+    /// Side system already detoured through, if any
+    pub taken: Option<usize>,
+    /// How the last detour went
+    pub outcome: Option<RouteOutcome>,
+}
+
+impl RouteMapView {
+    pub fn new(leg: [SideSystem; 2]) -> Self {
+        Self {
+            leg,
+            selected: DIRECT,
+            taken: None,
+            outcome: None,
+        }
+    }
+
+    /// Side system behind a route index (`None` for the direct jump)
+    pub fn side(&self, route: usize) -> Option<&SideSystem> {
+        match route {
+            0 => Some(&self.leg[0]),
+            2 => Some(&self.leg[1]),
+            _ => None,
+        }
+    }
+}
+
+/// Everything drawn for the route map
+#[derive(Component)]
+pub struct RouteMapRoot;
+
+/// The text panel, rebuilt on every change
+#[derive(Component)]
+pub struct RouteMapPanel;
+
+/// EVE's security colours: red in null, yellow at 0.5, blue at 1.0
+pub fn security_color(security: f32) -> Color {
+    let red = Vec3::new(0.85, 0.2, 0.1);
+    let yellow = Vec3::new(0.95, 0.85, 0.2);
+    let blue = Vec3::new(0.2, 0.55, 1.0);
+    let rgb = if security < 0.5 {
+        red.lerp(yellow, security.max(0.0) / 0.5)
+    } else {
+        yellow.lerp(blue, (security.min(1.0) - 0.5) / 0.5)
+    };
+    Color::srgb(rgb.x, rgb.y, rgb.z)
+}
+
+/// Where the systems sit: leaving from the bottom, the mission at the top,
+/// side systems off to either side
+fn node_positions(playfield: &Playfield) -> (Vec2, [Vec2; 3], Vec2) {
+    let half = playfield.half();
+    let origin = Vec2::new(0.0, -half.y * 0.45);
+    let destination = Vec2::new(0.0, half.y * 0.45);
+    let sides = [
+        Vec2::new(-half.x * 0.55, 0.0),
+        Vec2::ZERO,
+        Vec2::new(half.x * 0.55, 0.0),
+    ];
+    (origin, sides, destination)
+}
+
+fn spawn_route_map(
+    mut commands: Commands,
+    campaign: Res<CampaignState>,
+    playfield: Res<Playfield>,
+) {
+    let view = RouteMapView::new(route_leg(campaign.act, campaign.mission_index));
+    let security = mission_security(campaign.act);
+    let destination = campaign
+        .current_mission()
+        .map_or("Unknown", |mission| mission_system(mission.id));
+    let (origin_pos, side_pos, destination_pos) = node_positions(&playfield);
+
+    let mut systems = vec![
+        (
+            route_origin(campaign.act, campaign.mission_index),
+            security,
+            origin_pos,
+        ),
+        (destination, security, destination_pos),
+    ];
+    for (system, position) in view.leg.iter().zip([side_pos[0], side_pos[2]]) {
+        systems.push((system.name, system.security, position));
+    }
+
+    // Black out the playfield behind the map
+    commands.spawn((
+        RouteMapRoot,
+        StateScope::Only(GameState::RouteMap),
+        Sprite {
+            color: Color::srgba(0.0, 0.02, 0.05, 0.95),
+            custom_size: Some(playfield.size),
+            ..default()
+        },
+        Transform::from_xyz(0.0, 0.0, LAYER_EFFECTS),
+    ));
+
+    for (name, security, position) in systems {
+        commands
+            .spawn((
+                RouteMapRoot,
+                StateScope::Only(GameState::RouteMap),
+                Sprite {
+                    color: security_color(security),
+                    custom_size: Some(Vec2::splat(NODE_SIZE)),
+                    ..default()
+                },
+                Transform::from_xyz(position.x, position.y, LAYER_EFFECTS + 1.0),
+            ))
+            .with_children(|node| {
+                node.spawn((
+                    Text2d::new(format!("{}  {:.1}", name, security)),
+                    TextFont {
+                        font_size: 13.0,
+                        ..default()
+                    },
+                    TextColor(security_color(security)),
+                    Transform::from_xyz(0.0, -NODE_SIZE * 1.5, 1.0),
+                ));
+            });
+    }
+
+    commands.insert_resource(view);
+}
+
+fn close_route_map(mut commands: Commands) {
+    commands.remove_resource::<RouteMapView>();
+}
+
+pub(crate) fn route_map_input(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    joystick: Res<JoystickState>,
+    campaign: Res<CampaignState>,
+    mut view: ResMut<RouteMapView>,
+    mut route: ResMut<RouteState>,
+    mut score: ResMut<ScoreSystem>,
+    mut progress: ResMut<GameProgress>,
+    mut transitions: EventWriter<TransitionEvent>,
+    time: Res<Time>,
+    mut cooldown: Local<f32>,
+) {
+    *cooldown -= time.delta_secs();
+
+    if keyboard.just_pressed(KeyCode::Escape) || joystick.back() {
+        transitions.send(TransitionEvent::to(GameState::MainMenu));
+        return;
+    }
+
+    // Fleet roster (opens over the map)
+    if keyboard.just_pressed(KeyCode::KeyR) || joystick.y_button() {
+        commands.init_resource::<RosterView>();
+        return;
+    }
+
+    // The detour's done; only the jump on is left
+    if view.taken.is_some() {
+        if is_confirm(&keyboard, &joystick) {
+            transitions.send(TransitionEvent::to(GameState::Playing));
+        }
+        return;
+    }
+
+    let nav = get_horizontal_input(&keyboard, &joystick);
+    if nav != 0 && *cooldown <= 0.0 {
+        view.selected = (view.selected as i32 + nav).clamp(0, 2) as usize;
+        *cooldown = MENU_NAV_COOLDOWN;
+    }
+
+    if !is_confirm(&keyboard, &joystick) {
+        return;
+    }
+    let Some(&system) = view.side(view.selected) else {
+        transitions.send(TransitionEvent::to(GameState::Playing));
+        return;
+    };
+
+    let outcome = route.detour(
+        &system,
+        campaign.act,
+        campaign.mission_index,
+        &mut score,
+        &mut progress,
+    );
+    info!("Route via {}: {}", system.name, outcome);
+    if !matches!(outcome, RouteOutcome::CantAfford { .. }) {
+        view.taken = Some(view.selected);
+    }
+    view.outcome = Some(outcome);
+}
+
+/// What a side system offers, spelled out
+fn encounter_terms(system: &SideSystem, act: Act, mission_index: usize) -> String {
+    match system.encounter {
+        Encounter::Refugees => format!("+{} souls liberated", refugee_souls(act)),
+        Encounter::Market => format!(
+            "{} for {} credits",
+            choice_label(market_item(act, mission_index)),
+            market_price(act)
+        ),
+        Encounter::Ambush => {
+            let (credits, isk) = ambush_bounty(act);
+            format!(
+                "{} elites - {} credits + {} ISK each",
+                ambush_wing(act).len(),
+                credits,
+                isk
+            )
+        }
+    }
+}
+
+fn refresh_route_panel(
+    mut commands: Commands,
+    view: Res<RouteMapView>,
+    campaign: Res<CampaignState>,
+    score: Res<ScoreSystem>,
+    progress: Res<GameProgress>,
+    theme: Res<UiTheme>,
+    existing: Query<Entity, With<RouteMapPanel>>,
+) {
+    for entity in existing.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    let (mission_name, destination) = campaign
+        .current_mission()
+        .map_or(("MISSION", "Unknown"), |mission| {
+            (mission.name, mission_system(mission.id))
+        });
+    let (heading, encounter, terms) = match view.side(view.selected) {
+        Some(system) => (
+            format!("{}  {:.1}", system.name.to_uppercase(), system.security),
+            format!(
+                "{} - {}",
+                system.encounter.name(),
+                system.encounter.description()
+            ),
+            encounter_terms(system, campaign.act, campaign.mission_index),
+        ),
+        None => (
+            format!("{}  DIRECT", destination.to_uppercase()),
+            "No detour - jump straight to the mission.".to_string(),
+            String::new(),
+        ),
+    };
+    let hint = if view.taken.is_some() {
+        format!(
+            "A/ENTER Jump to {} • R/Y Fleet • B/ESC Main Menu",
+            destination
+        )
+    } else {
+        "←→ Route • A/ENTER Jump • R/Y Fleet • B/ESC Main Menu".to_string()
+    };
+
+    commands
+        .spawn((
+            RouteMapRoot,
+            RouteMapPanel,
+            StateScope::Only(GameState::RouteMap),
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::SpaceBetween,
+                padding: UiRect::axes(Val::Px(20.0), Val::Px(24.0)),
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(4.0),
+                    ..default()
+                })
+                .with_children(|top| {
+                    top.spawn((
+                        Text::new(format!("ROUTE TO {}", destination.to_uppercase())),
+                        TextFont {
+                            font_size: 32.0,
+                            ..default()
+                        },
+                        TextColor(theme.primary),
+                    ));
+                    top.spawn((
+                        Text::new(format!("ACT {} - {}", campaign.act.number(), mission_name)),
+                        TextFont {
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.7, 0.7, 0.75)),
+                    ));
+                });
+
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(460.0),
+                        flex_direction: FlexDirection::Column,
+                        padding: UiRect::axes(Val::Px(14.0), Val::Px(10.0)),
+                        border: UiRect::all(Val::Px(1.0)),
+                        row_gap: Val::Px(4.0),
+                        ..default()
+                    },
+                    BorderColor(theme.frame()),
+                    BackgroundColor(theme.panel_background()),
+                ))
+                .with_children(|panel| {
+                    panel.spawn((
+                        Text::new(heading),
+                        TextFont {
+                            font_size: 20.0,
+                            ..default()
+                        },
+                        TextColor(theme.accent),
+                    ));
+                    panel.spawn((
+                        Text::new(encounter),
+                        TextFont {
+                            font_size: 14.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                    if !terms.is_empty() {
+                        panel.spawn((
+                            Text::new(terms),
+                            TextFont {
+                                font_size: 14.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(1.0, 0.85, 0.3)),
+                        ));
+                    }
+                    if let Some(outcome) = view.outcome {
+                        let color = match outcome {
+                            RouteOutcome::CantAfford { .. } => Color::srgb(1.0, 0.4, 0.3),
+                            RouteOutcome::AmbushAhead => Color::srgb(1.0, 0.6, 0.25),
+                            _ => Color::srgb(0.4, 1.0, 0.5),
+                        };
+                        panel.spawn((
+                            Text::new(outcome.to_string()),
+                            TextFont {
+                                font_size: 14.0,
+                                ..default()
+                            },
+                            TextColor(color),
+                        ));
+                    }
+                    panel.spawn((
+                        Text::new(format!(
+                            "CREDITS {}   SOULS {}",
+                            progress.credits, score.souls_liberated
+                        )),
+                        TextFont {
+                            font_size: 13.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.6, 0.6, 0.65)),
+                    ));
+                    panel.spawn((
+                        PadPrompt::new(hint),
+                        TextFont {
+                            font_size: 12.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.5, 0.5, 0.55)),
+                    ));
+                });
+        });
+}
+
+/// Jump lines between the systems, the chosen route lit
+fn draw_route_lines(mut gizmos: Gizmos, view: Res<RouteMapView>, playfield: Res<Playfield>) {
+    let (origin, sides, destination) = node_positions(&playfield);
+    let chosen = view.taken.unwrap_or(view.selected);
+
+    for (route, waypoint) in sides.into_iter().enumerate() {
+        let color = if route == chosen {
+            ROUTE_SELECTED
+        } else {
+            ROUTE_IDLE
+        };
+        if route == DIRECT {
+            gizmos.line_2d(origin, destination, color);
+        } else {
+            gizmos.line_2d(origin, waypoint, color);
+            gizmos.line_2d(waypoint, destination, color);
+        }
+    }
+
+    let ring = if chosen == DIRECT {
+        destination
+    } else {
+        sides[chosen]
+    };
+    gizmos.circle_2d(ring, SELECTED_RING, ROUTE_SELECTED);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn security_runs_red_through_yellow_to_blue() {
+        let null = security_color(0.0).to_srgba();
+        let low = security_color(0.5).to_srgba();
+        let high = security_color(1.0).to_srgba();
+        assert!(null.red > null.blue);
+        assert!(low.green > low.blue);
+        assert!(high.blue > high.red);
+
+        let view = RouteMapView::new(route_leg(Act::Act1, 0));
+        assert!(view.side(DIRECT).is_none());
+        assert_eq!(view.side(2), Some(&view.leg[1]));
+    }
+}
@@ -0,0 +1,299 @@
+//! Run Code Entry
+//!
+//! The box on the stage select screen for flying someone else's run: press
+//! C (or click the box), type the code and press Enter. A valid code sets
+//! the mode, factions, mission, difficulty and mutators and moves on to
+//! ship select with the code's ship picked; the run then uses the code's
+//! seed as long as nothing was changed on the way. While typing, the stage
+//! select underneath ignores input.
+
+#![allow(dead_code)]
+
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+
+use super::menu::stage_mission;
+use crate::core::*;
+
+/// Run code entry plugin
+pub struct RunCodeEntryPlugin;
+
+impl Plugin for RunCodeEntryPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                open_run_code_on_click,
+                run_code_entry_input,
+                refresh_run_code_box,
+            )
+                .chain()
+                .run_if(in_state(GameState::StageSelect)),
+        )
+        .add_systems(OnExit(GameState::StageSelect), close_run_code_entry);
+    }
+}
+
+/// Code being typed; absent while the box is closed
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct RunCodeEntry {
+    pub text: String,
+    /// Why the last code was refused
+    pub error: Option<String>,
+}
+
+/// Run code box on the stage select screen
+#[derive(Component)]
+#[require(Interaction)]
+pub struct RunCodeBox;
+
+/// The box's text
+#[derive(Component)]
+pub struct RunCodeText;
+
+/// Hint shown while the box is closed
+const CLOSED_HINT: &str = "[C] ENTER A RUN CODE";
+
+/// Stage select's run code box
+pub fn spawn_run_code_box(parent: &mut ChildBuilder) {
+    parent
+        .spawn((
+            RunCodeBox,
+            Node {
+                padding: UiRect::axes(Val::Px(14.0), Val::Px(6.0)),
+                border: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            BorderColor(Color::srgb(0.3, 0.3, 0.3)),
+            BackgroundColor(Color::srgba(0.1, 0.1, 0.12, 0.8)),
+        ))
+        .with_children(|code_box| {
+            code_box.spawn((
+                RunCodeText,
+                Text::new(CLOSED_HINT),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.6, 0.6, 0.6)),
+            ));
+        });
+}
+
+/// What the box reads: the hint, or the code so far padded with blanks
+pub fn run_code_box_label(entry: Option<&RunCodeEntry>) -> String {
+    let Some(entry) = entry else {
+        return CLOSED_HINT.to_string();
+    };
+    let typed: Vec<char> = entry.text.chars().collect();
+    let slots: String = (0..RUN_CODE_LENGTH)
+        .map(|i| typed.get(i).copied().unwrap_or('_'))
+        .collect::<Vec<_>>()
+        .chunks(4)
+        .map(|group| group.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("-");
+    match &entry.error {
+        Some(error) => format!("RUN CODE {}   {}", slots, error),
+        None => format!("RUN CODE {}   ENTER Fly • ESC Cancel", slots),
+    }
+}
+
+/// Whether a code can be flown from this save, and why not
+pub fn check_run_code(code: &RunCode, save_data: &SaveData, today: u64) -> Result<(), String> {
+    if code.enemy_faction != code.player_faction.rival() {
+        return Err("Those factions don't fight each other".to_string());
+    }
+
+    let player = code.player_faction.short_name();
+    let enemy = code.enemy_faction.short_name();
+    if code.mode == RunMode::Daily {
+        let day = code.seed as u64;
+        if day != today {
+            return Err("Only today's daily can be flown".to_string());
+        }
+        if save_data.daily_result(day).is_some() {
+            return Err("That daily is already flown".to_string());
+        }
+    } else if code.stage > save_data.get_highest_stage(player, enemy) + 1 {
+        return Err(format!("Stage {} is still locked", code.stage));
+    }
+
+    let Some(ship) = code.player_faction.player_ships().get(code.ship_index) else {
+        return Err("No such ship".to_string());
+    };
+    if !save_data.is_ship_unlocked(ship.type_id, ship.unlock_stage, player, enemy) {
+        return Err(format!("The {} is still locked", ship.name));
+    }
+
+    if let Some(locked) = code
+        .modifiers
+        .iter()
+        .find(|m| !save_data.prestige.modifier_unlocked(**m))
+    {
+        return Err(format!("{} is still locked", locked.name()));
+    }
+    Ok(())
+}
+
+fn open_run_code_on_click(
+    mut commands: Commands,
+    entry: Option<Res<RunCodeEntry>>,
+    boxes: Query<&Interaction, (With<RunCodeBox>, Changed<Interaction>)>,
+) {
+    if entry.is_none() && boxes.iter().any(|i| *i == Interaction::Pressed) {
+        commands.init_resource::<RunCodeEntry>();
+    }
+}
+
+/// Typing, and flying the code on Enter
+fn run_code_entry_input(
+    mut commands: Commands,
+    mut typed: EventReader<KeyboardInput>,
+    mut keyboard: ResMut<ButtonInput<KeyCode>>,
+    entry: Option<ResMut<RunCodeEntry>>,
+    (mut session, mut campaign, mut run_mode, mut difficulty, mut daily): (
+        ResMut<GameSession>,
+        ResMut<CampaignState>,
+        ResMut<RunMode>,
+        ResMut<Difficulty>,
+        ResMut<DailyChallenge>,
+    ),
+    mut run_codes: ResMut<RunCodes>,
+    mut save_data: ResMut<SaveData>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    // Keys pressed before the box opened aren't part of the code
+    let Some(mut entry) = entry else {
+        typed.clear();
+        return;
+    };
+
+    for event in typed.read() {
+        if !event.state.is_pressed() {
+            continue;
+        }
+        if event.key_code == KeyCode::Backspace {
+            entry.text.pop();
+            entry.error = None;
+        } else if let Key::Character(chars) = &event.logical_key {
+            for c in chars.chars().filter(|c| c.is_ascii_alphanumeric()) {
+                if entry.text.len() < RUN_CODE_LENGTH {
+                    entry.text.push(c.to_ascii_uppercase());
+                    entry.error = None;
+                }
+            }
+        }
+    }
+
+    // Don't let the screen underneath act on the same press
+    if keyboard.clear_just_pressed(KeyCode::Escape) {
+        commands.remove_resource::<RunCodeEntry>();
+        return;
+    }
+    if !keyboard.clear_just_pressed(KeyCode::Enter) {
+        return;
+    }
+
+    let code = match RunCode::parse(&entry.text) {
+        Ok(code) => code,
+        Err(error) => {
+            entry.error = Some(error);
+            return;
+        }
+    };
+    if let Err(error) = check_run_code(&code, &save_data, day_of(unix_now())) {
+        entry.error = Some(error);
+        return;
+    }
+
+    let skin_index = session.skin_index;
+    *session = GameSession::new(code.player_faction, code.enemy_faction);
+    session.selected_ship_index = code.ship_index;
+    session.skin_index = skin_index;
+    *run_mode = code.mode;
+    *difficulty = code.difficulty;
+
+    let stage = if code.mode == RunMode::Daily {
+        *daily = DailyChallenge::for_day(code.seed as u64);
+        daily.stage
+    } else {
+        code.stage
+    };
+    let (act, mission_index) = stage_mission(stage);
+    campaign.act = act;
+    campaign.mission_index = mission_index;
+
+    // Campaign codes carry their mutators; the others always fly stock
+    if code.mode == RunMode::Campaign {
+        save_data.prestige.active_modifiers = code.modifiers.clone();
+    }
+
+    info!("Flying run code {}", code);
+    run_codes.imported = Some(code);
+    commands.remove_resource::<RunCodeEntry>();
+    next_state.set(GameState::ShipSelect);
+}
+
+fn refresh_run_code_box(
+    entry: Option<Res<RunCodeEntry>>,
+    mut shown: Local<Option<RunCodeEntry>>,
+    mut texts: Query<(&mut Text, &mut TextColor), With<RunCodeText>>,
+    added: Query<(), Added<RunCodeText>>,
+) {
+    let current = entry.as_deref().cloned();
+    if *shown == current && added.is_empty() {
+        return;
+    }
+    let label = run_code_box_label(current.as_ref());
+    let color = match &current {
+        Some(entry) if entry.error.is_some() => Color::srgb(1.0, 0.45, 0.35),
+        Some(_) => Color::srgb(0.9, 0.7, 0.3),
+        None => Color::srgb(0.6, 0.6, 0.6),
+    };
+    for (mut text, mut text_color) in texts.iter_mut() {
+        **text = label.clone();
+        text_color.0 = color;
+    }
+    *shown = current;
+}
+
+fn close_run_code_entry(mut commands: Commands) {
+    commands.remove_resource::<RunCodeEntry>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_box_shows_the_code_so_far() {
+        assert_eq!(run_code_box_label(None), CLOSED_HINT);
+        let entry = RunCodeEntry {
+            text: "7K2MQ".to_string(),
+            error: None,
+        };
+        assert!(run_code_box_label(Some(&entry)).starts_with("RUN CODE 7K2M-Q___-____"));
+    }
+
+    #[test]
+    fn locked_stages_and_ships_are_refused() {
+        let save_data = SaveData::default();
+        let mut session = GameSession::new(Faction::Minmatar, Faction::Minmatar.rival());
+        let code = |session: &GameSession, stage: u32| {
+            RunCode::for_run(
+                RunMode::ScoreAttack,
+                session,
+                Difficulty::Newbro,
+                stage,
+                &[],
+                7,
+            )
+        };
+        assert_eq!(check_run_code(&code(&session, 1), &save_data, 0), Ok(()));
+        assert!(check_run_code(&code(&session, 9), &save_data, 0).is_err());
+
+        session.enemy_faction = Faction::Minmatar;
+        assert!(check_run_code(&code(&session, 1), &save_data, 0).is_err());
+    }
+}
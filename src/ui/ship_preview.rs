@@ -0,0 +1,535 @@
+//! Ship Select Preview
+//!
+//! The preview pane on the ship select screen: the highlighted ship turning
+//! on a turntable (its 3D model rendered to a texture, or the large sprite
+//! when no model is loaded), a stat radar chart, the ship's ability and the
+//! selected paint job. Everything follows the highlight live.
+
+#![allow(dead_code)]
+
+use std::f32::consts::TAU;
+
+use crate::assets::{get_model_scale, ShipModelCache, ShipSpriteCache};
+use crate::core::*;
+use crate::systems::{AbilityType, JoystickState};
+use bevy::prelude::*;
+use bevy::render::camera::RenderTarget;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat, TextureUsages};
+use bevy::render::view::RenderLayers;
+
+use super::menu::MenuSelection;
+
+/// Render layer seen only by the preview camera
+const PREVIEW_LAYER: usize = 7;
+
+/// Side of the model render target (pixels)
+const PREVIEW_SIZE: u32 = 256;
+
+/// Side of the radar chart texture (pixels)
+pub const RADAR_SIZE: u32 = 160;
+
+/// Turntable speed (radians per second)
+const SPIN_SPEED: f32 = 0.8;
+
+/// Radar chart axes, clockwise from the top
+pub const RADAR_AXES: [&str; 5] = ["SPD", "DMG", "HP", "ROF", "ABL"];
+
+/// Chart radius as a fraction of the half-size (room for anti-aliasing)
+const RADAR_RADIUS: f32 = 0.92;
+
+/// Ship preview plugin
+pub struct ShipPreviewPlugin;
+
+impl Plugin for ShipPreviewPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            spawn_preview_stage
+                .run_if(in_state(GameState::ShipSelect))
+                .run_if(resource_added::<ShipPreviewImages>),
+        )
+        .add_systems(
+            Update,
+            (
+                ship_skin_input,
+                update_ship_preview,
+                spin_preview_model,
+                tag_preview_meshes,
+            )
+                .chain()
+                .run_if(in_state(GameState::ShipSelect))
+                .run_if(resource_exists::<ShipPreviewImages>),
+        )
+        .add_systems(OnExit(GameState::ShipSelect), despawn_preview_stage);
+    }
+}
+
+/// Textures shown in the preview pane
+#[derive(Resource, Clone)]
+pub struct ShipPreviewImages {
+    /// The preview camera draws the model here
+    pub render: Handle<Image>,
+    pub radar: Handle<Image>,
+}
+
+impl ShipPreviewImages {
+    pub fn new(images: &mut Assets<Image>) -> Self {
+        let mut render = Image::new_fill(
+            Extent3d {
+                width: PREVIEW_SIZE,
+                height: PREVIEW_SIZE,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            &[0, 0, 0, 0],
+            TextureFormat::Bgra8UnormSrgb,
+            RenderAssetUsages::default(),
+        );
+        render.texture_descriptor.usage = TextureUsages::TEXTURE_BINDING
+            | TextureUsages::COPY_DST
+            | TextureUsages::RENDER_ATTACHMENT;
+
+        Self {
+            render: images.add(render),
+            radar: images.add(radar_image(&[0.0; RADAR_AXES.len()])),
+        }
+    }
+}
+
+/// 3D model viewport (hidden when the sprite fallback is shown)
+#[derive(Component)]
+struct PreviewViewport;
+
+/// Large sprite shown when the ship has no loaded model
+#[derive(Component)]
+struct PreviewSprite;
+
+#[derive(Component)]
+pub struct ShipRadarChart;
+
+#[derive(Component)]
+pub struct ShipAbilityText;
+
+#[derive(Component)]
+pub struct ShipSkinText;
+
+/// Camera, light and model rendering into the viewport texture
+#[derive(Component)]
+struct PreviewStage;
+
+/// The model on the turntable
+#[derive(Component)]
+struct PreviewModel;
+
+/// Spawn the preview pane (left column of the ship select screen)
+pub(crate) fn spawn_ship_preview_pane(
+    parent: &mut ChildBuilder,
+    preview: &ShipPreviewImages,
+    faction_color: Color,
+) {
+    parent
+        .spawn((
+            Node {
+                width: Val::Px(260.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(12.0)),
+                row_gap: Val::Px(8.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.08, 0.08, 0.12, 0.95)),
+            BorderRadius::all(Val::Px(8.0)),
+        ))
+        .with_children(|pane| {
+            // Turntable: model viewport with the sprite fallback on top
+            pane.spawn(Node {
+                width: Val::Px(220.0),
+                height: Val::Px(220.0),
+                ..default()
+            })
+            .with_children(|view| {
+                view.spawn((
+                    PreviewViewport,
+                    ImageNode::new(preview.render.clone()),
+                    Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                ));
+                view.spawn((
+                    PreviewSprite,
+                    ImageNode::default(),
+                    Node {
+                        position_type: PositionType::Absolute,
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                ));
+            });
+
+            pane.spawn((
+                ShipSkinText,
+                Text::new(""),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.8, 0.8, 0.8)),
+            ));
+
+            // Radar chart with axis labels around it
+            pane.spawn(Node {
+                width: Val::Px(RADAR_SIZE as f32),
+                height: Val::Px(RADAR_SIZE as f32),
+                margin: UiRect::vertical(Val::Px(10.0)),
+                ..default()
+            })
+            .with_children(|chart| {
+                chart.spawn((
+                    ShipRadarChart,
+                    ImageNode::new(preview.radar.clone()).with_color(faction_color),
+                    Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                ));
+                let half = RADAR_SIZE as f32 / 2.0;
+                for (i, label) in RADAR_AXES.iter().enumerate() {
+                    let dir = radar_axis(i);
+                    let center =
+                        Vec2::new(half + dir.x * (half + 8.0), half - dir.y * (half + 8.0));
+                    chart.spawn((
+                        Text::new(*label),
+                        TextFont {
+                            font_size: 10.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.6, 0.6, 0.6)),
+                        Node {
+                            position_type: PositionType::Absolute,
+                            left: Val::Px(center.x - 9.0),
+                            top: Val::Px(center.y - 6.0),
+                            ..default()
+                        },
+                    ));
+                }
+            });
+
+            pane.spawn((
+                ShipAbilityText,
+                Text::new(""),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                TextLayout::new_with_justify(JustifyText::Center),
+            ));
+        });
+}
+
+/// Offscreen camera and light for the model viewport
+fn spawn_preview_stage(mut commands: Commands, preview: Res<ShipPreviewImages>) {
+    let layer = RenderLayers::layer(PREVIEW_LAYER);
+    commands.spawn((
+        PreviewStage,
+        Camera3d::default(),
+        Camera {
+            target: RenderTarget::Image(preview.render.clone()),
+            order: -1,
+            clear_color: ClearColorConfig::Custom(Color::NONE),
+            ..default()
+        },
+        Transform::from_xyz(0.0, 0.6, 2.4).looking_at(Vec3::ZERO, Vec3::Y),
+        layer.clone(),
+    ));
+    commands.spawn((
+        PreviewStage,
+        DirectionalLight {
+            illuminance: 8000.0,
+            ..default()
+        },
+        Transform::from_xyz(2.0, 3.0, 2.0).looking_at(Vec3::ZERO, Vec3::Y),
+        layer,
+    ));
+}
+
+/// Tab / Y cycles the paint job
+fn ship_skin_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    joystick: Res<JoystickState>,
+    mut session: ResMut<GameSession>,
+    save_data: Res<SaveData>,
+) {
+    if keyboard.just_pressed(KeyCode::Tab) || joystick.y_button() {
+        session.next_skin(save_data.prestige.points);
+    }
+}
+
+/// Swap model, sprite, radar and texts when the highlight or skin changes
+fn update_ship_preview(
+    mut commands: Commands,
+    selection: Res<MenuSelection>,
+    session: Res<GameSession>,
+    model_cache: Res<ShipModelCache>,
+    sprite_cache: Res<ShipSpriteCache>,
+    asset_server: Res<AssetServer>,
+    preview: Res<ShipPreviewImages>,
+    mut images: ResMut<Assets<Image>>,
+    models: Query<Entity, With<PreviewModel>>,
+    mut viewport: Query<
+        (&mut Node, &mut ImageNode),
+        (With<PreviewViewport>, Without<PreviewSprite>),
+    >,
+    mut sprite: Query<(&mut Node, &mut ImageNode), (With<PreviewSprite>, Without<PreviewViewport>)>,
+    mut texts: Query<
+        (&mut Text, Has<ShipSkinText>),
+        Or<(With<ShipSkinText>, With<ShipAbilityText>)>,
+    >,
+    mut shown: Local<Option<(usize, usize, bool)>>,
+) {
+    let ships = session.player_ships();
+    let Some(ship) = ships.get(selection.index) else {
+        return;
+    };
+    let model = model_cache
+        .get(ship.type_id)
+        .filter(|handle| asset_server.is_loaded_with_dependencies(handle));
+
+    // Models finish loading in the background, so that counts as a change too
+    let key = (selection.index, session.skin_index, model.is_some());
+    if *shown == Some(key) {
+        return;
+    }
+    *shown = Some(key);
+
+    let skin = session.selected_skin();
+    let faction_color = session.player_faction.primary_color();
+
+    // Turntable: model if loaded, otherwise the sprite (or a faction block)
+    for entity in models.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    if let Some(scene) = model.clone() {
+        let scale =
+            get_model_scale(ship.type_id) * (ship.class.sprite_size() / SIZE_FRIGATE).min(1.6);
+        commands.spawn((
+            PreviewStage,
+            PreviewModel,
+            SceneRoot(scene),
+            Transform::from_scale(Vec3::splat(scale)),
+            RenderLayers::layer(PREVIEW_LAYER),
+        ));
+    }
+    for (mut node, mut image) in viewport.iter_mut() {
+        node.display = if model.is_some() {
+            Display::Flex
+        } else {
+            Display::None
+        };
+        image.color = skin.tint;
+    }
+    for (mut node, mut image) in sprite.iter_mut() {
+        node.display = if model.is_some() {
+            Display::None
+        } else {
+            Display::Flex
+        };
+        *image = match sprite_cache.get(ship.type_id) {
+            Some(texture) => ImageNode {
+                texture_atlas: texture.texture_atlas,
+                ..ImageNode::new(texture.image).with_color(skin.tint)
+            },
+            None => ImageNode::default().with_color(skin.apply(faction_color)),
+        };
+    }
+
+    images.insert(&preview.radar, radar_image(&radar_values(ships, ship)));
+
+    let ability = AbilityType::from_special(ship.special);
+    for (mut text, is_skin) in texts.iter_mut() {
+        **text = if is_skin {
+            format!("< {} >", skin.name)
+        } else if ability == AbilityType::None {
+            ship.special.to_string()
+        } else {
+            format!(
+                "{}\n{}s cooldown • {} GJ",
+                ability.name(),
+                ability.cooldown(),
+                ability.capacitor_cost()
+            )
+        };
+    }
+}
+
+fn spin_preview_model(time: Res<Time>, mut models: Query<&mut Transform, With<PreviewModel>>) {
+    for mut transform in models.iter_mut() {
+        transform.rotation = Quat::from_rotation_y(time.elapsed_secs() * SPIN_SPEED % TAU)
+            * Quat::from_rotation_x(0.15);
+    }
+}
+
+/// Scene meshes spawn as children without the preview layer; tag them
+fn tag_preview_meshes(
+    mut commands: Commands,
+    models: Query<Entity, With<PreviewModel>>,
+    children: Query<&Children>,
+    untagged: Query<(), Without<RenderLayers>>,
+) {
+    for model in models.iter() {
+        for entity in children.iter_descendants(model) {
+            if untagged.contains(entity) {
+                commands
+                    .entity(entity)
+                    .insert(RenderLayers::layer(PREVIEW_LAYER));
+            }
+        }
+    }
+}
+
+fn despawn_preview_stage(mut commands: Commands, stage: Query<Entity, With<PreviewStage>>) {
+    for entity in stage.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    commands.remove_resource::<ShipPreviewImages>();
+}
+
+// ============================================================================
+// Radar chart
+// ============================================================================
+
+/// Each axis scaled against the best ship in the lineup (0..=1)
+pub fn radar_values(ships: &[ShipDef], ship: &ShipDef) -> [f32; 5] {
+    let max = |f: fn(&ShipDef) -> f32| ships.iter().map(f).fold(f32::EPSILON, f32::max);
+    // Ability axis: quicker recharge scores higher
+    let recharge = |s: &ShipDef| {
+        let cooldown = AbilityType::from_special(s.special).cooldown();
+        if cooldown > 0.0 {
+            1.0 / cooldown
+        } else {
+            0.0
+        }
+    };
+
+    [
+        ship.speed / max(|s| s.speed),
+        ship.damage / max(|s| s.damage),
+        ship.health / max(|s| s.health),
+        ship.fire_rate / max(|s| s.fire_rate),
+        recharge(ship) / max(recharge),
+    ]
+}
+
+/// Unit vector of axis `i` (y up, first axis straight up)
+fn radar_axis(i: usize) -> Vec2 {
+    let angle = i as f32 / RADAR_AXES.len() as f32 * TAU;
+    Vec2::new(angle.sin(), angle.cos())
+}
+
+/// Mask alpha at `p` (each axis -1..1, y up); `px` is one pixel in those units
+pub fn radar_alpha(values: &[f32], p: Vec2, px: f32) -> u8 {
+    let n = values.len();
+    let vertex = |i: usize, scale: f32| radar_axis(i % n) * scale * RADAR_RADIUS;
+    let near_segment = |a: Vec2, b: Vec2| {
+        let length_sq = (b - a).length_squared();
+        let t = if length_sq > 0.0 {
+            ((p - a).dot(b - a) / length_sq).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        p.distance(a + (b - a) * t) < px
+    };
+
+    // The stat polygon: outline, then fill
+    let outline = (0..n).any(|i| {
+        near_segment(
+            vertex(i, values[i].clamp(0.0, 1.0)),
+            vertex(i + 1, values[(i + 1) % n].clamp(0.0, 1.0)),
+        )
+    });
+    if outline {
+        return 255;
+    }
+    let inside = (0..n).any(|i| {
+        let a = vertex(i, values[i].clamp(0.0, 1.0));
+        let b = vertex(i + 1, values[(i + 1) % n].clamp(0.0, 1.0));
+        // Inside the triangle (center, a, b): same side of all three edges
+        let sides = [a.perp_dot(p), (b - a).perp_dot(p - a), (-b).perp_dot(p - b)];
+        a.perp_dot(b).abs() > f32::EPSILON
+            && (sides.iter().all(|s| *s >= 0.0) || sides.iter().all(|s| *s <= 0.0))
+    });
+    if inside {
+        return 130;
+    }
+
+    // Grid: outer and half rings plus spokes
+    let grid = (0..n).any(|i| {
+        near_segment(vertex(i, 1.0), vertex(i + 1, 1.0))
+            || near_segment(vertex(i, 0.5), vertex(i + 1, 0.5))
+            || near_segment(Vec2::ZERO, vertex(i, 1.0))
+    });
+    if grid {
+        60
+    } else {
+        0
+    }
+}
+
+/// Rasterize the radar chart as a white mask
+pub fn radar_image(values: &[f32]) -> Image {
+    let px = 2.0 / RADAR_SIZE as f32;
+    let mut data = Vec::with_capacity((RADAR_SIZE * RADAR_SIZE * 4) as usize);
+    for y in 0..RADAR_SIZE {
+        for x in 0..RADAR_SIZE {
+            let p = Vec2::new(-1.0 + (x as f32 + 0.5) * px, 1.0 - (y as f32 + 0.5) * px);
+            data.extend_from_slice(&[255, 255, 255, radar_alpha(values, p, px)]);
+        }
+    }
+    Image::new(
+        Extent3d {
+            width: RADAR_SIZE,
+            height: RADAR_SIZE,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn radar_fill_follows_the_stats() {
+        let px = 2.0 / RADAR_SIZE as f32;
+        let full = [1.0; 5];
+        let empty = [0.0; 5];
+
+        // Near the tip of the top axis: inside a maxed chart, grid only when empty
+        let top = Vec2::new(0.0, 0.8);
+        assert_eq!(radar_alpha(&full, top, px), 130);
+        assert_eq!(radar_alpha(&empty, top, px), 60);
+        // Outside the chart entirely
+        assert_eq!(radar_alpha(&full, Vec2::new(0.99, 0.99), px), 0);
+    }
+
+    #[test]
+    fn best_ship_in_each_stat_maxes_its_axis() {
+        let ships = Faction::Minmatar.player_ships();
+        let fastest = ships
+            .iter()
+            .max_by(|a, b| a.speed.total_cmp(&b.speed))
+            .expect("ships");
+        let values = radar_values(ships, fastest);
+        assert_eq!(values[0], 1.0);
+        assert!(values.iter().all(|v| (0.0..=1.0).contains(v)));
+    }
+}
@@ -0,0 +1,101 @@
+//! Faction UI Theme
+//!
+//! Menu borders, the pause screen, HUD bar frames and the dialogue box take
+//! their colors from the player's faction instead of hardcoded rust/amber,
+//! so a Caldari pilot flies a steel-blue interface. The theme follows the
+//! `GameSession`; before a faction is picked it stays Minmatar.
+
+#![allow(dead_code)]
+
+use crate::core::{Faction, GameSession};
+use bevy::prelude::*;
+
+/// UI theme plugin
+pub struct UiThemePlugin;
+
+impl Plugin for UiThemePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<UiTheme>().add_systems(
+            PreUpdate,
+            sync_ui_theme.run_if(resource_changed::<GameSession>),
+        );
+    }
+}
+
+/// Interface palette derived from a faction
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct UiTheme {
+    pub faction: Faction,
+    /// Selected borders, titles, gauge fills
+    pub primary: Color,
+    /// Frames and tinted panel backgrounds
+    pub secondary: Color,
+    /// Labels and highlighted text
+    pub accent: Color,
+}
+
+impl Default for UiTheme {
+    fn default() -> Self {
+        Self::for_faction(Faction::Minmatar)
+    }
+}
+
+impl UiTheme {
+    pub fn for_faction(faction: Faction) -> Self {
+        Self {
+            faction,
+            primary: faction.primary_color(),
+            secondary: faction.secondary_color(),
+            accent: faction.accent_color(),
+        }
+    }
+
+    /// Background of a highlighted menu item
+    pub fn selected_background(&self) -> Color {
+        darken(self.secondary, 0.45).with_alpha(0.95)
+    }
+
+    /// Background of dialogue and other floating panels
+    pub fn panel_background(&self) -> Color {
+        darken(self.secondary, 0.2).with_alpha(0.9)
+    }
+
+    /// Frame around bars and panels
+    pub fn frame(&self) -> Color {
+        self.secondary.with_alpha(0.9)
+    }
+}
+
+/// Scale a color's RGB toward black
+fn darken(color: Color, factor: f32) -> Color {
+    let c = color.to_srgba();
+    Color::srgba(c.red * factor, c.green * factor, c.blue * factor, c.alpha)
+}
+
+fn sync_ui_theme(session: Res<GameSession>, mut theme: ResMut<UiTheme>) {
+    if theme.faction != session.player_faction {
+        *theme = UiTheme::for_faction(session.player_faction);
+        info!("UI theme: {}", session.player_faction.name());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_faction_gets_its_own_palette() {
+        let minmatar = UiTheme::default();
+        let caldari = UiTheme::for_faction(Faction::Caldari);
+        assert_eq!(minmatar.faction, Faction::Minmatar);
+        assert_eq!(caldari.primary, Faction::Caldari.primary_color());
+        assert_ne!(
+            minmatar.selected_background(),
+            caldari.selected_background()
+        );
+
+        // Caldari panels lean blue
+        let panel = caldari.panel_background().to_srgba();
+        assert!(panel.blue > panel.red);
+    }
+}
@@ -0,0 +1,163 @@
+//! Toast Notifications
+//!
+//! Small stacked messages in the top-right corner for debug and system
+//! notices (asset reloads, crash recovery, etc). Works in every state.
+
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+
+/// Maximum toasts on screen at once (oldest dropped first)
+const MAX_TOASTS: usize = 5;
+
+/// Fade-out time at the end of a toast's life
+const TOAST_FADE: f32 = 0.5;
+
+/// Toast plugin
+pub struct ToastPlugin;
+
+impl Plugin for ToastPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ToastEvent>()
+            .add_systems(Startup, setup_toast_container)
+            .add_systems(Update, (spawn_toasts, update_toasts).chain());
+    }
+}
+
+/// Event to show a toast
+#[derive(Event, Clone, Debug)]
+pub struct ToastEvent {
+    pub text: String,
+    /// Seconds on screen
+    pub duration: f32,
+    pub color: Color,
+}
+
+impl ToastEvent {
+    /// Neutral info toast
+    pub fn info(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            duration: 3.0,
+            color: Color::srgb(0.7, 0.9, 1.0),
+        }
+    }
+
+    /// Developer/debug toast
+    pub fn debug(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            duration: 3.0,
+            color: Color::srgb(0.6, 1.0, 0.6),
+        }
+    }
+
+    /// Warning toast (stays up longer)
+    pub fn warning(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            duration: 5.0,
+            color: Color::srgb(1.0, 0.75, 0.3),
+        }
+    }
+}
+
+/// Container holding the toast stack
+#[derive(Component)]
+struct ToastContainer;
+
+/// A single toast
+#[derive(Component)]
+struct Toast {
+    remaining: f32,
+    color: Color,
+}
+
+fn setup_toast_container(mut commands: Commands) {
+    commands.spawn((
+        ToastContainer,
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0),
+            right: Val::Px(10.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::FlexEnd,
+            row_gap: Val::Px(4.0),
+            ..default()
+        },
+        ZIndex(900), // Above HUD and menus, below screen transitions
+    ));
+}
+
+fn spawn_toasts(
+    mut commands: Commands,
+    mut events: EventReader<ToastEvent>,
+    container_query: Query<(Entity, Option<&Children>), With<ToastContainer>>,
+) {
+    let Ok((container, children)) = container_query.get_single() else {
+        return;
+    };
+    let mut count = children.map(|c| c.len()).unwrap_or(0);
+
+    for event in events.read() {
+        // Drop the oldest toast when the stack is full
+        if count >= MAX_TOASTS {
+            if let Some(&oldest) = children.and_then(|c| c.first()) {
+                commands.entity(oldest).despawn_recursive();
+                count -= 1;
+            }
+        }
+
+        commands.entity(container).with_children(|parent| {
+            parent
+                .spawn((
+                    Toast {
+                        remaining: event.duration,
+                        color: event.color,
+                    },
+                    Node {
+                        padding: UiRect::axes(Val::Px(10.0), Val::Px(5.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.05, 0.08, 0.12, 0.85)),
+                ))
+                .with_children(|toast| {
+                    toast.spawn((
+                        Text::new(event.text.clone()),
+                        TextFont {
+                            font_size: 13.0,
+                            ..default()
+                        },
+                        TextColor(event.color),
+                    ));
+                });
+        });
+        count += 1;
+        info!("Toast: {}", event.text);
+    }
+}
+
+fn update_toasts(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut toast_query: Query<(Entity, &mut Toast, &mut BackgroundColor, &Children)>,
+    mut text_query: Query<&mut TextColor>,
+) {
+    let dt = time.delta_secs();
+
+    for (entity, mut toast, mut background, children) in toast_query.iter_mut() {
+        toast.remaining -= dt;
+        if toast.remaining <= 0.0 {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+
+        let alpha = (toast.remaining / TOAST_FADE).min(1.0);
+        background.0 = background.0.with_alpha(0.85 * alpha);
+        for &child in children.iter() {
+            if let Ok(mut color) = text_query.get_mut(child) {
+                color.0 = toast.color.with_alpha(alpha);
+            }
+        }
+    }
+}
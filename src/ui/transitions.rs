@@ -1,12 +1,30 @@
 //! Screen Transitions
 //!
-//! Smooth fade effects between game states.
+//! Smooth fade effects between game states, plus the reveal layer for menu
+//! screens: roots tagged with `ScreenReveal` slide and fade in when they
+//! appear and slide out while the screen fades away, and cards tagged with
+//! `RevealStagger` come in one after another. Reveal timing follows the
+//! transition that brought the screen in; reduced motion skips it all.
 
 #![allow(dead_code)]
 
 use bevy::prelude::*;
 
 use crate::core::GameState;
+use crate::systems::MotionSettings;
+
+/// Reveal time for screens that appear without a transition (pause menu)
+const DEFAULT_REVEAL: f32 = 0.25;
+/// How far a screen root slides up into place
+const ROOT_SLIDE: f32 = 24.0;
+/// How far a screen root slides up on its way out
+const EXIT_SLIDE: f32 = 16.0;
+/// How far each card rises into place
+const CARD_SLIDE: f32 = 18.0;
+/// Delay between consecutive cards
+const CARD_STAGGER: f32 = 0.06;
+/// Cards past this many share the last delay
+const MAX_STAGGERED: usize = 8;
 
 /// Transition plugin
 pub struct TransitionPlugin;
@@ -18,7 +36,13 @@ impl Plugin for TransitionPlugin {
             .add_systems(Startup, setup_transition_overlay)
             .add_systems(
                 Update,
-                (handle_transition_events, update_transition).chain(),
+                (
+                    handle_transition_events,
+                    update_transition,
+                    start_reveals,
+                    animate_reveals,
+                )
+                    .chain(),
             );
     }
 }
@@ -38,6 +62,8 @@ pub struct TransitionState {
     pub progress: f32,
     /// Fade duration in seconds
     pub duration: f32,
+    /// Fade in duration once the state switches
+    pub fade_in: f32,
     /// Target state after fade out
     pub target_state: Option<GameState>,
 }
@@ -119,6 +145,7 @@ fn handle_transition_events(
             state.phase = TransitionPhase::FadeOut;
             state.progress = 0.0;
             state.duration = event.fade_out;
+            state.fade_in = event.fade_in;
             state.target_state = Some(event.target);
         }
     }
@@ -157,7 +184,7 @@ fn update_transition(
                 }
                 state.phase = TransitionPhase::FadeIn;
                 state.progress = 0.0;
-                state.duration = 0.3; // Default fade in
+                state.duration = state.fade_in;
             }
         }
         TransitionPhase::FadeIn => {
@@ -180,3 +207,170 @@ fn update_transition(
 pub fn transition_active(state: &TransitionState) -> bool {
     state.active
 }
+
+/// Slide-and-fade reveal on a menu screen root or card
+#[derive(Component, Debug, Default)]
+pub struct ScreenReveal {
+    /// Seconds since the element appeared
+    pub elapsed: f32,
+    /// Seconds the reveal takes; zero once skipped or not yet started
+    pub duration: f32,
+    /// Seconds before this element starts (staggered cards)
+    pub delay: f32,
+    /// Slide distance in pixels
+    slide: f32,
+    /// `Node::top` to settle back to
+    base_top: f32,
+    veil: Option<Entity>,
+    veil_alpha: f32,
+}
+
+impl ScreenReveal {
+    /// Finished (or skipped) revealing
+    pub fn is_done(&self) -> bool {
+        self.elapsed >= self.delay + self.duration
+    }
+}
+
+/// Card in a grid; revealed after the cards spawned before it
+#[derive(Component, Debug, Default)]
+#[require(ScreenReveal)]
+pub struct RevealStagger;
+
+/// Cover faded off a revealing element
+#[derive(Component)]
+pub struct RevealVeil;
+
+/// Eased 0..1 progress of a reveal
+pub fn reveal_progress(elapsed: f32, delay: f32, duration: f32) -> f32 {
+    if duration <= 0.0 {
+        return 1.0;
+    }
+    let t = ((elapsed - delay) / duration).clamp(0.0, 1.0);
+    1.0 - (1.0 - t).powi(3)
+}
+
+/// Stagger delay of the `order`th card on a screen
+pub fn stagger_delay(order: usize) -> f32 {
+    order.min(MAX_STAGGERED) as f32 * CARD_STAGGER
+}
+
+/// Time revealing screens and cards, veil them and start them offset
+fn start_reveals(
+    mut commands: Commands,
+    transition: Res<TransitionState>,
+    motion: Res<MotionSettings>,
+    mut query: Query<
+        (Entity, &mut ScreenReveal, &mut Node, Has<RevealStagger>),
+        Added<ScreenReveal>,
+    >,
+) {
+    let duration = if transition.active && transition.phase == TransitionPhase::FadeIn {
+        transition.fade_in
+    } else {
+        DEFAULT_REVEAL
+    };
+
+    let mut cards = 0;
+    for (entity, mut reveal, mut node, staggered) in query.iter_mut() {
+        if motion.reduced_motion {
+            continue;
+        }
+        reveal.duration = duration;
+        reveal.base_top = match node.top {
+            Val::Px(top) => top,
+            _ => 0.0,
+        };
+        if staggered {
+            reveal.delay = stagger_delay(cards);
+            reveal.slide = CARD_SLIDE;
+            reveal.veil_alpha = 0.9;
+            cards += 1;
+        } else {
+            reveal.slide = ROOT_SLIDE;
+            reveal.veil_alpha = if transition.active { 1.0 } else { 0.8 };
+        }
+        node.top = Val::Px(reveal.base_top + reveal.slide);
+
+        let veil = commands
+            .spawn((
+                RevealVeil,
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(0.0),
+                    top: Val::Px(0.0),
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(Color::BLACK.with_alpha(reveal.veil_alpha)),
+                ZIndex(100),
+            ))
+            .id();
+        commands.entity(entity).add_child(veil);
+        reveal.veil = Some(veil);
+    }
+}
+
+/// Ease revealing elements into place; screen roots ease back out while
+/// the screen fades away
+fn animate_reveals(
+    time: Res<Time>,
+    transition: Res<TransitionState>,
+    motion: Res<MotionSettings>,
+    mut query: Query<(&mut ScreenReveal, &mut Node, Has<RevealStagger>)>,
+    mut veils: Query<&mut BackgroundColor, With<RevealVeil>>,
+) {
+    let leaving = transition.active && transition.phase == TransitionPhase::FadeOut;
+    let dt = time.delta_secs();
+
+    for (mut reveal, mut node, staggered) in query.iter_mut() {
+        let Some(veil) = reveal.veil else {
+            continue;
+        };
+        let (offset, cover) = if leaving && !staggered && !motion.reduced_motion {
+            let t = transition.progress.clamp(0.0, 1.0);
+            (-EXIT_SLIDE * t, t)
+        } else if !reveal.is_done() {
+            reveal.elapsed += dt;
+            let t = reveal_progress(reveal.elapsed, reveal.delay, reveal.duration);
+            (reveal.slide * (1.0 - t), 1.0 - t)
+        } else {
+            continue;
+        };
+
+        let top = Val::Px(reveal.base_top + offset);
+        if node.top != top {
+            node.top = top;
+        }
+        if let Ok(mut color) = veils.get_mut(veil) {
+            color.0 = Color::BLACK.with_alpha(reveal.veil_alpha * cover);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reveals_ease_in_after_their_delay() {
+        assert_eq!(reveal_progress(0.1, 0.2, 0.3), 0.0);
+        assert_eq!(reveal_progress(0.5, 0.2, 0.3), 1.0);
+        let halfway = reveal_progress(0.35, 0.2, 0.3);
+        assert!(halfway > 0.5 && halfway < 1.0);
+        // Skipped reveals are already done
+        assert_eq!(reveal_progress(0.0, 0.0, 0.0), 1.0);
+        assert!(ScreenReveal::default().is_done());
+    }
+
+    #[test]
+    fn cards_stagger_up_to_a_cap() {
+        assert_eq!(stagger_delay(0), 0.0);
+        assert!(stagger_delay(1) < stagger_delay(2));
+        assert_eq!(
+            stagger_delay(MAX_STAGGERED),
+            stagger_delay(MAX_STAGGERED + 5)
+        );
+    }
+}
@@ -0,0 +1,344 @@
+//! Wave Tally Overlay
+//!
+//! A small panel that slides in when a wave is cleared: kills, accuracy,
+//! souls liberated and the no-damage bonus, with the wave bonus (scaled by
+//! difficulty) counting up into the score. It never pauses play, and firing
+//! skips straight to the total.
+
+#![allow(dead_code)]
+
+use crate::core::*;
+use crate::systems::JoystickState;
+use bevy::prelude::*;
+
+/// Slide-in time (seconds)
+const SLIDE_TIME: f32 = 0.35;
+/// When the bonus starts counting up
+const COUNT_START: f32 = 0.5;
+/// Count-up duration
+const COUNT_TIME: f32 = 1.2;
+/// When the panel starts fading out
+const FADE_START: f32 = 3.5;
+/// Fade-out duration
+const FADE_TIME: f32 = 0.5;
+
+/// Panel width (pixels)
+const PANEL_WIDTH: f32 = 250.0;
+/// Resting distance from the right edge
+const PANEL_RIGHT: f32 = 24.0;
+
+/// Wave tally plugin
+pub struct WaveTallyPlugin;
+
+impl Plugin for WaveTallyPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            track_wave_tally
+                .in_set(GameSet::Scoring)
+                .run_if(in_state(GameState::Playing)),
+        )
+        .add_systems(
+            Update,
+            (show_wave_tally, update_wave_tally)
+                .chain()
+                .in_set(GameSet::Ui)
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
+
+/// The tally panel
+#[derive(Component, Debug)]
+#[require(StateScope)]
+pub struct WaveTallyOverlay {
+    pub bonus: WaveBonus,
+    /// Bonus after the difficulty multiplier
+    pub total: u64,
+    /// Seconds since the panel appeared
+    pub age: f32,
+    /// Bonus already added to the score
+    pub awarded: u64,
+}
+
+/// The counting-up bonus total
+#[derive(Component)]
+struct WaveTallyTotalText;
+
+/// Full-opacity color of a panel text, faded with the panel
+#[derive(Component)]
+struct TallyColor(Color);
+
+/// Slide-in progress (0 off-screen, 1 in place), eased out
+pub fn tally_slide(age: f32) -> f32 {
+    let t = (age / SLIDE_TIME).clamp(0.0, 1.0);
+    1.0 - (1.0 - t).powi(3)
+}
+
+/// Fraction of the bonus counted up so far
+pub fn tally_count_progress(age: f32) -> f32 {
+    ((age - COUNT_START) / COUNT_TIME).clamp(0.0, 1.0)
+}
+
+/// Panel opacity, fading out at the end of its life
+pub fn tally_alpha(age: f32) -> f32 {
+    1.0 - ((age - FADE_START) / FADE_TIME).clamp(0.0, 1.0)
+}
+
+/// Count the wave's shots, hits (counted by collision), kills and damage
+fn track_wave_tally(
+    mut tally: ResMut<WaveTally>,
+    score: Res<ScoreSystem>,
+    mut mission_events: EventReader<MissionStartEvent>,
+    mut fire_events: EventReader<PlayerFireEvent>,
+    mut destroy_events: EventReader<EnemyDestroyedEvent>,
+    mut damage_events: EventReader<PlayerDamagedEvent>,
+) {
+    if mission_events.read().last().is_some() {
+        tally.start_mission(score.souls_liberated);
+    }
+    for event in fire_events.read() {
+        tally.shots_fired += event.burst_count.max(1);
+    }
+    tally.kills += destroy_events.read().count() as u32;
+    if damage_events.read().last().is_some() {
+        tally.damaged = true;
+    }
+}
+
+/// Close out the wave and slide in its tally
+fn show_wave_tally(
+    mut commands: Commands,
+    mut wave_events: EventReader<WaveCompleteEvent>,
+    mut tally: ResMut<WaveTally>,
+    mut score: ResMut<ScoreSystem>,
+    overlay_query: Query<(Entity, &WaveTallyOverlay)>,
+) {
+    if wave_events.read().last().is_none() {
+        return;
+    }
+    let Some(bonus) = tally.close_wave(score.souls_liberated) else {
+        return;
+    };
+
+    // Waves cleared back to back: pay out the old panel before replacing it
+    for (entity, overlay) in overlay_query.iter() {
+        score.score += overlay.total - overlay.awarded;
+        commands.entity(entity).despawn_recursive();
+    }
+
+    let total = score.difficulty_scaled(bonus.total());
+    info!(
+        "Wave {} tally: {} kills, {:.0}% accuracy, {} souls, +{}",
+        bonus.wave,
+        bonus.kills,
+        bonus.accuracy * 100.0,
+        bonus.souls,
+        total
+    );
+    spawn_tally_panel(&mut commands, bonus, total, score.difficulty_multiplier);
+}
+
+fn spawn_tally_panel(commands: &mut Commands, bonus: WaveBonus, total: u64, difficulty: f32) {
+    let gold = Color::srgb(1.0, 0.84, 0.3);
+    let label = Color::srgb(0.75, 0.8, 0.85);
+    let dim = Color::srgb(0.45, 0.45, 0.5);
+
+    let no_damage_color = if bonus.no_damage { COLOR_SHIELD } else { dim };
+    let rows = [
+        (format!("KILLS  {}", bonus.kills), bonus.kill_bonus(), label),
+        (
+            format!("ACCURACY  {:.0}%", bonus.accuracy * 100.0),
+            bonus.accuracy_bonus(),
+            label,
+        ),
+        (
+            format!("SOULS  {}", bonus.souls),
+            bonus.soul_bonus(),
+            Color::srgb(0.2, 0.9, 0.5),
+        ),
+        (
+            "NO DAMAGE".to_string(),
+            bonus.no_damage_bonus(),
+            no_damage_color,
+        ),
+    ];
+
+    commands
+        .spawn((
+            WaveTallyOverlay {
+                bonus,
+                total,
+                age: 0.0,
+                awarded: 0,
+            },
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Percent(28.0),
+                right: Val::Px(-PANEL_WIDTH),
+                width: Val::Px(PANEL_WIDTH),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(4.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                border: UiRect::left(Val::Px(3.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.07, 0.1, 0.85)),
+            BorderColor(gold),
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                Text::new(format!("WAVE {} CLEAR", bonus.wave)),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(gold),
+                TallyColor(gold),
+            ));
+
+            for (text, points, color) in rows {
+                spawn_tally_row(panel, text, format!("+{}", points), color, 14.0);
+            }
+            if difficulty != 1.0 {
+                spawn_tally_row(
+                    panel,
+                    "DIFFICULTY".to_string(),
+                    format!("x{:.1}", difficulty),
+                    label,
+                    14.0,
+                );
+            }
+
+            panel
+                .spawn(Node {
+                    justify_content: JustifyContent::SpaceBetween,
+                    margin: UiRect::top(Val::Px(4.0)),
+                    ..default()
+                })
+                .with_children(|row| {
+                    row.spawn((
+                        Text::new("WAVE BONUS"),
+                        TextFont {
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(gold),
+                        TallyColor(gold),
+                    ));
+                    row.spawn((
+                        WaveTallyTotalText,
+                        Text::new("+0"),
+                        TextFont {
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(gold),
+                        TallyColor(gold),
+                    ));
+                });
+        });
+}
+
+fn spawn_tally_row(
+    panel: &mut ChildBuilder,
+    label: String,
+    points: String,
+    color: Color,
+    font_size: f32,
+) {
+    panel
+        .spawn(Node {
+            justify_content: JustifyContent::SpaceBetween,
+            ..default()
+        })
+        .with_children(|row| {
+            for text in [label, points] {
+                row.spawn((
+                    Text::new(text),
+                    TextFont {
+                        font_size,
+                        ..default()
+                    },
+                    TextColor(color),
+                    TallyColor(color),
+                ));
+            }
+        });
+}
+
+/// Slide, count up, pay out and fade the panel; firing skips to the total
+fn update_wave_tally(
+    mut commands: Commands,
+    time: Res<Time>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    joystick: Res<JoystickState>,
+    mut score: ResMut<ScoreSystem>,
+    mut overlay_query: Query<(
+        Entity,
+        &mut WaveTallyOverlay,
+        &mut Node,
+        &mut BackgroundColor,
+    )>,
+    mut total_query: Query<&mut Text, With<WaveTallyTotalText>>,
+    mut text_query: Query<(&TallyColor, &mut TextColor)>,
+    mut was_firing: Local<bool>,
+) {
+    // Only a fresh press skips, so holding fire through the last kill doesn't
+    let firing = joystick.fire();
+    let skip = keyboard.just_pressed(KeyCode::Space) || (firing && !*was_firing);
+    *was_firing = firing;
+
+    let Ok((entity, mut overlay, mut node, mut background)) = overlay_query.get_single_mut() else {
+        return;
+    };
+
+    overlay.age += time.delta_secs();
+    if skip && overlay.age < FADE_START {
+        overlay.age = FADE_START;
+    }
+
+    let total = overlay.total;
+    let counted = (total as f32 * tally_count_progress(overlay.age)).round() as u64;
+    let counted = counted.min(total);
+    if counted > overlay.awarded {
+        score.score += counted - overlay.awarded;
+        overlay.awarded = counted;
+    }
+    if let Ok(mut text) = total_query.get_single_mut() {
+        text.0 = format!("+{}", counted);
+    }
+
+    let slide = tally_slide(overlay.age);
+    node.right = Val::Px(-PANEL_WIDTH + (PANEL_WIDTH + PANEL_RIGHT) * slide);
+
+    let alpha = tally_alpha(overlay.age);
+    background.0 = background.0.with_alpha(0.85 * alpha);
+    for (base, mut color) in text_query.iter_mut() {
+        color.0 = base.0.with_alpha(alpha);
+    }
+
+    if alpha <= 0.0 {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn panel_slides_in_counts_up_then_fades() {
+        assert_eq!(tally_slide(0.0), 0.0);
+        assert!(tally_slide(SLIDE_TIME * 0.5) > 0.5);
+        assert_eq!(tally_slide(SLIDE_TIME), 1.0);
+
+        assert_eq!(tally_count_progress(COUNT_START), 0.0);
+        assert!((tally_count_progress(COUNT_START + COUNT_TIME * 0.5) - 0.5).abs() < 1e-5);
+        // Fully counted before the fade begins
+        assert_eq!(tally_count_progress(FADE_START), 1.0);
+
+        assert_eq!(tally_alpha(FADE_START), 1.0);
+        assert_eq!(tally_alpha(FADE_START + FADE_TIME), 0.0);
+    }
+}